@@ -4,6 +4,8 @@ import (
 	"context"
 	"errors"
 	"fmt"
+	"math"
+	"sort"
 	"sync"
 	"time"
 
@@ -14,6 +16,7 @@ import (
 	"github.com/PeerDB-io/peer-flow/connectors/utils/monitoring"
 	"github.com/PeerDB-io/peer-flow/generated/protos"
 	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
 	"github.com/PeerDB-io/peer-flow/shared"
 	"github.com/jackc/pglogrepl"
 	log "github.com/sirupsen/logrus"
@@ -189,8 +192,15 @@ func (a *FlowableActivity) StartFlow(ctx context.Context,
 	}).Info("pulling records...")
 
 	tblNameMapping := make(map[string]string)
+	excludedColumns := make(map[string]map[string]struct{})
 	for _, v := range input.FlowConnectionConfigs.TableMappings {
 		tblNameMapping[v.SourceTableIdentifier] = v.DestinationTableIdentifier
+		if len(v.Exclude) > 0 {
+			excludedColumns[v.SourceTableIdentifier] = make(map[string]struct{}, len(v.Exclude))
+			for _, column := range v.Exclude {
+				excludedColumns[v.SourceTableIdentifier][column] = struct{}{}
+			}
+		}
 	}
 
 	startTime := time.Now()
@@ -198,6 +208,7 @@ func (a *FlowableActivity) StartFlow(ctx context.Context,
 		FlowJobName:                 input.FlowConnectionConfigs.FlowJobName,
 		SrcTableIDNameMapping:       input.FlowConnectionConfigs.SrcTableIdNameMapping,
 		TableNameMapping:            tblNameMapping,
+		ExcludedColumns:             excludedColumns,
 		LastSyncState:               input.LastSyncState,
 		MaxBatchSize:                uint32(input.SyncFlowOptions.BatchSize),
 		IdleTimeout:                 10 * time.Second,
@@ -262,14 +273,41 @@ func (a *FlowableActivity) StartFlow(ctx context.Context,
 		shutdown <- true
 	}()
 
+	// changes to tables paused with the skip policy never reach the destination.
+	skippedTables := make(map[string]struct{})
+	for tableName, policy := range input.FlowConnectionConfigs.PausedTables {
+		if policy == protos.TablePausePolicy_TABLE_PAUSE_POLICY_SKIP {
+			skippedTables[tableName] = struct{}{}
+		}
+	}
+	if len(skippedTables) > 0 {
+		recordBatch.DropTables(skippedTables)
+		log.WithFields(log.Fields{
+			"flowName": input.FlowConnectionConfigs.FlowJobName,
+		}).Infof("skipped %d records of paused tables", numRecords-len(recordBatch.Records))
+	}
+
 	syncStartTime := time.Now()
+	if input.FlowConnectionConfigs.TrackLatency {
+		stampLatencyColumns(recordBatch, syncStartTime)
+	}
+	partitionKeys := make(map[string]string, len(input.FlowConnectionConfigs.TableMappings))
+	for _, tableMapping := range input.FlowConnectionConfigs.TableMappings {
+		if tableMapping.PartitionKey != "" {
+			partitionKeys[tableMapping.DestinationTableIdentifier] = tableMapping.PartitionKey
+		}
+	}
+
 	res, err := dstConn.SyncRecords(&model.SyncRecordsRequest{
-		Records:         recordBatch,
-		FlowJobName:     input.FlowConnectionConfigs.FlowJobName,
-		SyncMode:        input.FlowConnectionConfigs.CdcSyncMode,
-		StagingPath:     input.FlowConnectionConfigs.CdcStagingPath,
-		PushBatchSize:   input.FlowConnectionConfigs.PushBatchSize,
-		PushParallelism: input.FlowConnectionConfigs.PushParallelism,
+		Records:            recordBatch,
+		FlowJobName:        input.FlowConnectionConfigs.FlowJobName,
+		SyncMode:           input.FlowConnectionConfigs.CdcSyncMode,
+		StagingPath:        input.FlowConnectionConfigs.CdcStagingPath,
+		StagingIntegration: input.FlowConnectionConfigs.StagingIntegration,
+		PushBatchSize:      input.FlowConnectionConfigs.PushBatchSize,
+		PushParallelism:    input.FlowConnectionConfigs.PushParallelism,
+		OrderingKey:        input.FlowConnectionConfigs.OrderingKey,
+		PartitionKeys:      partitionKeys,
 	})
 	if err != nil {
 		log.Warnf("failed to push records: %v", err)
@@ -297,6 +335,17 @@ func (a *FlowableActivity) StartFlow(ctx context.Context,
 	if err != nil {
 		return nil, err
 	}
+	if input.FlowConnectionConfigs.TrackLatency {
+		p50, p99, ok := commitLatencies(recordBatch, time.Now())
+		if ok {
+			metrics.LogLatencyMetrics(ctx, input.FlowConnectionConfigs.FlowJobName, p50, p99)
+			err = a.CatalogMirrorMonitor.UpdateLatencyForCDCBatch(ctx, input.FlowConnectionConfigs.FlowJobName,
+				res.CurrentSyncBatchID, p50, p99)
+			if err != nil {
+				return nil, err
+			}
+		}
+	}
 	res.TableSchemaDeltas = recordsWithTableSchemaDelta.TableSchemaDeltas
 	res.RelationMessageMapping = recordsWithTableSchemaDelta.RelationMessageMapping
 
@@ -309,6 +358,42 @@ func (a *FlowableActivity) StartFlow(ctx context.Context,
 	return res, nil
 }
 
+// stampLatencyColumns sets the commit time of the inserted and updated rows of the batch and the time
+// they are applied at, which is when the batch starts being synced.
+func stampLatencyColumns(recordBatch *model.RecordBatch, appliedAt time.Time) {
+	for _, record := range recordBatch.Records {
+		commitTime := model.GetCommitTime(record)
+		if _, isDelete := record.(*model.DeleteRecord); isDelete || commitTime.IsZero() {
+			continue
+		}
+		items := record.GetItems()
+		items.AddColumn(shared.CommitTimeColumnName,
+			&qvalue.QValue{Kind: qvalue.QValueKindTimestampTZ, Value: commitTime})
+		items.AddColumn(shared.AppliedAtColumnName,
+			&qvalue.QValue{Kind: qvalue.QValueKindTimestampTZ, Value: appliedAt})
+	}
+}
+
+// commitLatencies returns the median and 99th percentile of the time between the records of the batch
+// being committed at the source and the batch being applied, and false for batches without commit times.
+func commitLatencies(recordBatch *model.RecordBatch, appliedAt time.Time) (time.Duration, time.Duration, bool) {
+	latencies := make([]time.Duration, 0, len(recordBatch.Records))
+	for _, record := range recordBatch.Records {
+		if commitTime := model.GetCommitTime(record); !commitTime.IsZero() {
+			latencies = append(latencies, appliedAt.Sub(commitTime))
+		}
+	}
+	if len(latencies) == 0 {
+		return 0, 0, false
+	}
+
+	sort.Slice(latencies, func(i, j int) bool { return latencies[i] < latencies[j] })
+	percentile := func(p float64) time.Duration {
+		return latencies[int(math.Ceil(p*float64(len(latencies))))-1]
+	}
+	return percentile(0.5), percentile(0.99), true
+}
+
 func (a *FlowableActivity) StartNormalize(
 	ctx context.Context,
 	input *protos.StartNormalizeInput,
@@ -350,9 +435,19 @@ func (a *FlowableActivity) StartNormalize(
 		return nil, fmt.Errorf("failed to initialize table schema: %w", err)
 	}
 
+	pausedTables := make(map[string]struct{})
+	for tableName, policy := range input.FlowConnectionConfigs.PausedTables {
+		if policy == protos.TablePausePolicy_TABLE_PAUSE_POLICY_BUFFER {
+			pausedTables[tableName] = struct{}{}
+		}
+	}
 	res, err := dstConn.NormalizeRecords(&model.NormalizeRecordsRequest{
-		FlowJobName: input.FlowConnectionConfigs.FlowJobName,
-		SoftDelete:  input.FlowConnectionConfigs.SoftDelete,
+		FlowJobName:       input.FlowConnectionConfigs.FlowJobName,
+		SoftDelete:        input.FlowConnectionConfigs.SoftDelete,
+		SoftDeleteColName: input.FlowConnectionConfigs.SoftDeleteColName,
+		SyncedAtColName:   input.FlowConnectionConfigs.SyncedAtColName,
+		PausedTables:      pausedTables,
+		ResumedTables:     input.FlowConnectionConfigs.ResumedTables,
 	})
 	if err != nil {
 		return nil, fmt.Errorf("failed to normalized records: %w", err)
@@ -379,7 +474,34 @@ func (a *FlowableActivity) ReplayTableSchemaDeltas(
 	ctx context.Context,
 	input *protos.ReplayTableSchemaDeltaInput,
 ) error {
+	flowJobName := input.FlowConnectionConfigs.FlowJobName
+	err := a.CatalogMirrorMonitor.AddSchemaDeltas(ctx, flowJobName, input.TableSchemaDeltas)
+	if err != nil {
+		return fmt.Errorf("failed to record schema deltas: %w", err)
+	}
+
 	dest, err := connectors.GetCDCNormalizeConnector(ctx, input.FlowConnectionConfigs.Destination)
+	if errors.Is(err, connectors.ErrUnsupportedFunctionality) {
+		return a.publishSchemaChangeEvents(ctx, input)
+	} else if err != nil {
+		return err
+	}
+	defer connectors.CloseConnector(dest)
+
+	return dest.ReplayTableSchemaDeltas(flowJobName, input.TableSchemaDeltas)
+}
+
+// publishSchemaChangeEvents lets consumers of queue destinations know about schema
+// changes, since there is no destination table to replay them on.
+func (a *FlowableActivity) publishSchemaChangeEvents(
+	ctx context.Context,
+	input *protos.ReplayTableSchemaDeltaInput,
+) error {
+	if len(input.TableSchemaDeltas) == 0 {
+		return nil
+	}
+
+	dest, err := connectors.GetCDCSchemaEventConnector(ctx, input.FlowConnectionConfigs.Destination)
 	if errors.Is(err, connectors.ErrUnsupportedFunctionality) {
 		return nil
 	} else if err != nil {
@@ -387,7 +509,7 @@ func (a *FlowableActivity) ReplayTableSchemaDeltas(
 	}
 	defer connectors.CloseConnector(dest)
 
-	return dest.ReplayTableSchemaDeltas(input.FlowConnectionConfigs.FlowJobName, input.TableSchemaDeltas)
+	return dest.PublishSchemaChangeEvents(input.FlowConnectionConfigs.FlowJobName, input.TableSchemaDeltas)
 }
 
 // SetupQRepMetadataTables sets up the metadata tables for QReplication.
@@ -606,6 +728,22 @@ func (a *FlowableActivity) ConsolidateQRepPartitions(ctx context.Context, config
 	return a.CatalogMirrorMonitor.UpdateEndTimeForQRepRun(ctx, runUUID)
 }
 
+// RefreshTableStatistics refreshes the statistics of the destination tables that had at least
+// threshold rows loaded since they were last refreshed, warehouses keep their statistics current
+// on their own.
+func (a *FlowableActivity) RefreshTableStatistics(ctx context.Context, peer *protos.Peer,
+	tableIdentifiers []string, threshold uint32) error {
+	dstConn, err := connectors.GetTableStatisticsConnector(ctx, peer)
+	if errors.Is(err, connectors.ErrUnsupportedFunctionality) {
+		return nil
+	} else if err != nil {
+		return fmt.Errorf("failed to get destination connector: %w", err)
+	}
+	defer connectors.CloseConnector(dstConn)
+
+	return dstConn.RefreshTableStatistics(tableIdentifiers, threshold)
+}
+
 func (a *FlowableActivity) CleanupQRepFlow(ctx context.Context, config *protos.QRepConfig) error {
 	dst, err := connectors.GetQRepConsolidateConnector(ctx, config.DestinationPeer)
 	if errors.Is(err, connectors.ErrUnsupportedFunctionality) {
@@ -641,6 +779,172 @@ func (a *FlowableActivity) DropFlow(ctx context.Context, config *protos.Shutdown
 	return nil
 }
 
+// AddTablesToPublication adds the source tables of the table mappings to the
+// publication of the flow. Publications that were given to the flow are managed
+// by the user and left as they are.
+func (a *FlowableActivity) AddTablesToPublication(ctx context.Context,
+	config *protos.FlowConnectionConfigs, tableMappings []*protos.TableMapping) error {
+	if config.PublicationName != "" {
+		log.WithFields(log.Fields{
+			"flowName": config.FlowJobName,
+		}).Infof("not altering existing publication %s, it must include the added tables", config.PublicationName)
+		return nil
+	}
+
+	srcConn, err := connectors.GetCDCPullConnector(ctx, config.Source)
+	if err != nil {
+		return fmt.Errorf("failed to get source connector: %w", err)
+	}
+	defer connectors.CloseConnector(srcConn)
+
+	tableNames := make([]string, 0, len(tableMappings))
+	for _, tableMapping := range tableMappings {
+		tableNames = append(tableNames, tableMapping.SourceTableIdentifier)
+	}
+
+	err = srcConn.AddTablesToPublication(config.FlowJobName, tableNames)
+	if err != nil {
+		return fmt.Errorf("failed to add tables to publication: %w", err)
+	}
+	return nil
+}
+
+// GetNewSchemaTables returns the table mappings of the tables created in the schemas the peer flow
+// mirrors as a whole since it last looked, the tables that are excluded or already mapped are left out.
+func (a *FlowableActivity) GetNewSchemaTables(ctx context.Context,
+	config *protos.FlowConnectionConfigs) ([]*protos.TableMapping, error) {
+	if config.Source.Type != protos.DBType_POSTGRES || len(config.SchemaMappings) == 0 {
+		return nil, nil
+	}
+
+	srcConn, err := connectors.GetCDCPullConnector(ctx, config.Source)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get source connector: %w", err)
+	}
+	defer connectors.CloseConnector(srcConn)
+	pgSrcConn := srcConn.(*connpostgres.PostgresConnector)
+
+	mappedTables := make(map[string]bool, len(config.TableMappings))
+	for _, tableMapping := range config.TableMappings {
+		mappedTables[tableMapping.SourceTableIdentifier] = true
+	}
+
+	newTableMappings := make([]*protos.TableMapping, 0)
+	for _, schemaMapping := range config.SchemaMappings {
+		tables, err := pgSrcConn.GetTablesInSchema(schemaMapping.SourceSchema)
+		if err != nil {
+			return nil, fmt.Errorf("failed to get tables of schema %s: %w", schemaMapping.SourceSchema, err)
+		}
+		excludedTables := make(map[string]bool, len(schemaMapping.ExcludeTables))
+		for _, table := range schemaMapping.ExcludeTables {
+			excludedTables[table] = true
+		}
+
+		for _, table := range tables {
+			quotedTable := utils.QuoteIdentifierIfNeeded(table)
+			srcTableIdentifier := fmt.Sprintf("%s.%s",
+				utils.QuoteIdentifierIfNeeded(schemaMapping.SourceSchema), quotedTable)
+			if excludedTables[table] || mappedTables[srcTableIdentifier] {
+				continue
+			}
+			// destinations without schemas keep the name of the table as it is.
+			dstTableIdentifier := table
+			if schemaMapping.DestinationSchema != "" {
+				dstTableIdentifier = fmt.Sprintf("%s.%s",
+					utils.QuoteIdentifierIfNeeded(schemaMapping.DestinationSchema), quotedTable)
+			}
+			newTableMappings = append(newTableMappings, &protos.TableMapping{
+				SourceTableIdentifier:      srcTableIdentifier,
+				DestinationTableIdentifier: dstTableIdentifier,
+			})
+			mappedTables[srcTableIdentifier] = true
+		}
+	}
+
+	if len(newTableMappings) > 0 {
+		log.WithFields(log.Fields{
+			"flowName": config.FlowJobName,
+		}).Infof("found %d new tables in the mirrored schemas", len(newTableMappings))
+	}
+	return newTableMappings, nil
+}
+
+func (a *FlowableActivity) RemoveTablesFromPublication(ctx context.Context,
+	config *protos.FlowConnectionConfigs, tableMappings []*protos.TableMapping) error {
+	if config.PublicationName != "" {
+		log.WithFields(log.Fields{
+			"flowName": config.FlowJobName,
+		}).Infof("not altering existing publication %s, changes to the removed tables are ignored",
+			config.PublicationName)
+		return nil
+	}
+
+	srcConn, err := connectors.GetCDCPullConnector(ctx, config.Source)
+	if err != nil {
+		return fmt.Errorf("failed to get source connector: %w", err)
+	}
+	defer connectors.CloseConnector(srcConn)
+
+	tableNames := make([]string, 0, len(tableMappings))
+	for _, tableMapping := range tableMappings {
+		tableNames = append(tableNames, tableMapping.SourceTableIdentifier)
+	}
+
+	err = srcConn.RemoveTablesFromPublication(config.FlowJobName, tableNames)
+	if err != nil {
+		return fmt.Errorf("failed to remove tables from publication: %w", err)
+	}
+	return nil
+}
+
+// DropDestinationTables drops the destination tables that exist.
+func (a *FlowableActivity) DropDestinationTables(ctx context.Context,
+	config *protos.FlowConnectionConfigs, tableIdentifiers []string) error {
+	dstConn, err := connectors.GetCDCResyncConnector(ctx, config.Destination)
+	if err != nil {
+		return fmt.Errorf("failed to get destination connector: %w", err)
+	}
+	defer connectors.CloseConnector(dstConn)
+
+	err = dstConn.DropTables(tableIdentifiers)
+	if err != nil {
+		return fmt.Errorf("failed to drop destination tables: %w", err)
+	}
+	return nil
+}
+
+// TruncateDestinationTables removes every row of the destination tables.
+func (a *FlowableActivity) TruncateDestinationTables(ctx context.Context,
+	config *protos.FlowConnectionConfigs, tableIdentifiers []string) error {
+	dstConn, err := connectors.GetCDCResyncConnector(ctx, config.Destination)
+	if err != nil {
+		return fmt.Errorf("failed to get destination connector: %w", err)
+	}
+	defer connectors.CloseConnector(dstConn)
+
+	err = dstConn.TruncateTables(tableIdentifiers)
+	if err != nil {
+		return fmt.Errorf("failed to truncate destination tables: %w", err)
+	}
+	return nil
+}
+
+// ReplaceDestinationTables replaces each destination table with its copy.
+func (a *FlowableActivity) ReplaceDestinationTables(ctx context.Context,
+	config *protos.FlowConnectionConfigs, replacements map[string]string) error {
+	dstConn, err := connectors.GetCDCResyncConnector(ctx, config.Destination)
+	if err != nil {
+		return fmt.Errorf("failed to get destination connector: %w", err)
+	}
+	defer connectors.CloseConnector(dstConn)
+
+	err = dstConn.ReplaceTables(replacements)
+	if err != nil {
+		return fmt.Errorf("failed to replace destination tables: %w", err)
+	}
+	return nil
+}
+
 func (a *FlowableActivity) SendWALHeartbeat(ctx context.Context, config *protos.FlowConnectionConfigs) error {
 	srcConn, err := connectors.GetCDCPullConnector(ctx, config.Source)
 	if err != nil {
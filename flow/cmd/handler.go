@@ -2,18 +2,22 @@ package main
 
 import (
 	"context"
+	"errors"
 	"fmt"
-	"strings"
 	"time"
 
 	"github.com/PeerDB-io/peer-flow/connectors"
+	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	catalog "github.com/PeerDB-io/peer-flow/connectors/utils/catalog"
 	"github.com/PeerDB-io/peer-flow/generated/protos"
 	"github.com/PeerDB-io/peer-flow/shared"
 	peerflow "github.com/PeerDB-io/peer-flow/workflows"
 	backoff "github.com/cenkalti/backoff/v4"
 	"github.com/google/uuid"
 	"github.com/jackc/pgx/v5/pgxpool"
+	"github.com/robfig/cron"
 	log "github.com/sirupsen/logrus"
+	"go.temporal.io/api/serviceerror"
 	"go.temporal.io/sdk/client"
 	"google.golang.org/protobuf/proto"
 )
@@ -43,12 +47,17 @@ func (h *FlowRequestHandler) getPeerID(ctx context.Context, peerName string) (in
 	return id, peerType, nil
 }
 
+// schemaForTableIdentifier qualifies a table without a schema with the public schema, which bigquery
+// tables don't have.
 func schemaForTableIdentifier(tableIdentifier string, peerDBType int32) string {
-	tableIdentifierParts := strings.Split(tableIdentifier, ".")
-	if len(tableIdentifierParts) == 1 && peerDBType != int32(protos.DBType_BIGQUERY) {
-		tableIdentifierParts = append([]string{"public"}, tableIdentifierParts...)
+	if peerDBType == int32(protos.DBType_BIGQUERY) {
+		return tableIdentifier
 	}
-	return strings.Join(tableIdentifierParts, ".")
+	parts, err := utils.ParseIdentifier(tableIdentifier)
+	if err == nil && len(parts) == 1 {
+		return "public." + tableIdentifier
+	}
+	return tableIdentifier
 }
 
 func (h *FlowRequestHandler) createCdcJobEntry(ctx context.Context,
@@ -122,6 +131,19 @@ func (h *FlowRequestHandler) Close() {
 
 func (h *FlowRequestHandler) CreateCDCFlow(
 	ctx context.Context, req *protos.CreateCDCFlowRequest) (*protos.CreateCDCFlowResponse, error) {
+	if req.IdempotencyKey != "" {
+		resp := &protos.CreateCDCFlowResponse{}
+		replayed, err := h.reserveKey(ctx, createCDCFlowRPC, req.IdempotencyKey,
+			req.ConnectionConfigs.FlowJobName, resp)
+		if err != nil {
+			return nil, err
+		} else if replayed {
+			return resp, nil
+		}
+		// kept once the response is recorded, otherwise a retry tries to create the flow again.
+		defer h.releaseKey(req.IdempotencyKey)
+	}
+
 	cfg := req.ConnectionConfigs
 	workflowID := fmt.Sprintf("%s-peerflow-%s", cfg.FlowJobName, uuid.New())
 	workflowOptions := client.StartWorkflowOptions{
@@ -141,6 +163,11 @@ func (h *FlowRequestHandler) CreateCDCFlow(
 		MaxBatchSize:        maxBatchSize,
 	}
 
+	err := h.preflightCDCFlow(ctx, cfg)
+	if err != nil {
+		return nil, mirrorErrorStatus(ctx, err)
+	}
+
 	if req.CreateCatalogEntry {
 		err := h.createCdcJobEntry(ctx, req, workflowID)
 		if err != nil {
@@ -148,7 +175,6 @@ func (h *FlowRequestHandler) CreateCDCFlow(
 		}
 	}
 
-	var err error
 	err = h.updateFlowConfigInCatalog(cfg)
 	if err != nil {
 		return nil, fmt.Errorf("unable to update flow config in catalog: %w", err)
@@ -167,9 +193,13 @@ func (h *FlowRequestHandler) CreateCDCFlow(
 		return nil, fmt.Errorf("unable to start PeerFlow workflow: %w", err)
 	}
 
-	return &protos.CreateCDCFlowResponse{
+	resp := &protos.CreateCDCFlowResponse{
 		WorflowId: workflowID,
-	}, nil
+	}
+	if req.IdempotencyKey != "" {
+		h.recordResponse(ctx, req.IdempotencyKey, cfg.FlowJobName, resp)
+	}
+	return resp, nil
 }
 
 func (h *FlowRequestHandler) updateFlowConfigInCatalog(
@@ -182,6 +212,11 @@ func (h *FlowRequestHandler) updateFlowConfigInCatalog(
 	if err != nil {
 		return fmt.Errorf("unable to marshal flow config: %w", err)
 	}
+	// the config carries the configs of the source and destination peers.
+	cfgBytes, err = catalog.SealConfig(cfg.FlowJobName, cfgBytes)
+	if err != nil {
+		return fmt.Errorf("unable to encrypt flow config: %w", err)
+	}
 
 	_, err = h.pool.Exec(context.Background(),
 		"UPDATE flows SET config_proto = $1 WHERE name = $2",
@@ -195,6 +230,19 @@ func (h *FlowRequestHandler) updateFlowConfigInCatalog(
 
 func (h *FlowRequestHandler) CreateQRepFlow(
 	ctx context.Context, req *protos.CreateQRepFlowRequest) (*protos.CreateQRepFlowResponse, error) {
+	if req.IdempotencyKey != "" {
+		resp := &protos.CreateQRepFlowResponse{}
+		replayed, err := h.reserveKey(ctx, createQRepFlowRPC, req.IdempotencyKey,
+			req.QrepConfig.FlowJobName, resp)
+		if err != nil {
+			return nil, err
+		} else if replayed {
+			return resp, nil
+		}
+		// kept once the response is recorded, otherwise a retry tries to create the flow again.
+		defer h.releaseKey(req.IdempotencyKey)
+	}
+
 	lastPartition := &protos.QRepPartition{
 		PartitionId: "not-applicable-partition",
 		Range:       nil,
@@ -202,6 +250,11 @@ func (h *FlowRequestHandler) CreateQRepFlow(
 
 	cfg := req.QrepConfig
 	log.Infof("Config for QRepFlow: %+v", cfg)
+	if cfg.CronSchedule != "" {
+		if _, err := cron.ParseStandard(cfg.CronSchedule); err != nil {
+			return nil, fmt.Errorf("invalid schedule %s: %w", cfg.CronSchedule, err)
+		}
+	}
 	workflowID := fmt.Sprintf("%s-qrepflow-%s", cfg.FlowJobName, uuid.New())
 	workflowOptions := client.StartWorkflowOptions{
 		ID:        workflowID,
@@ -231,9 +284,13 @@ func (h *FlowRequestHandler) CreateQRepFlow(
 		return nil, fmt.Errorf("unable to update qrep config in catalog: %w", err)
 	}
 
-	return &protos.CreateQRepFlowResponse{
+	resp := &protos.CreateQRepFlowResponse{
 		WorflowId: workflowID,
-	}, nil
+	}
+	if req.IdempotencyKey != "" {
+		h.recordResponse(ctx, req.IdempotencyKey, cfg.FlowJobName, resp)
+	}
+	return resp, nil
 }
 
 // updateQRepConfigInCatalog updates the qrep config in the catalog
@@ -247,6 +304,10 @@ func (h *FlowRequestHandler) updateQRepConfigInCatalog(
 	if err != nil {
 		return fmt.Errorf("unable to marshal qrep config: %w", err)
 	}
+	cfgBytes, err = catalog.SealConfig(cfg.FlowJobName, cfgBytes)
+	if err != nil {
+		return fmt.Errorf("unable to encrypt qrep config: %w", err)
+	}
 
 	_, err = h.pool.Exec(context.Background(),
 		"UPDATE flows SET config_proto = $1 WHERE name = $2",
@@ -313,11 +374,287 @@ func (h *FlowRequestHandler) ShutdownFlow(
 		}
 	}
 
+	h.forgetResponses(ctx, req.FlowJobName)
 	return &protos.ShutdownResponse{
 		Ok: true,
 	}, nil
 }
 
+func (h *FlowRequestHandler) PauseFlow(
+	ctx context.Context,
+	req *protos.PauseFlowRequest,
+) (*protos.PauseFlowResponse, error) {
+	err := h.setFlowPaused(ctx, req.WorkflowId, req.FlowJobName, true)
+	if err != nil {
+		return &protos.PauseFlowResponse{
+			Ok:           false,
+			ErrorMessage: err.Error(),
+		}, nil
+	}
+	return &protos.PauseFlowResponse{
+		Ok: true,
+	}, nil
+}
+
+func (h *FlowRequestHandler) ResumeFlow(
+	ctx context.Context,
+	req *protos.ResumeFlowRequest,
+) (*protos.ResumeFlowResponse, error) {
+	err := h.setFlowPaused(ctx, req.WorkflowId, req.FlowJobName, false)
+	if err != nil {
+		return &protos.ResumeFlowResponse{
+			Ok:           false,
+			ErrorMessage: err.Error(),
+		}, nil
+	}
+	return &protos.ResumeFlowResponse{
+		Ok: true,
+	}, nil
+}
+
+// setFlowPaused signals the cdc flow to pause, it runs no more sync flows until it is signalled to resume,
+// and records in the catalog whether it is paused.
+func (h *FlowRequestHandler) setFlowPaused(
+	ctx context.Context,
+	workflowID string,
+	flowJobName string,
+	paused bool,
+) error {
+	signal := shared.PauseSignal
+	if !paused {
+		// a resumed flow goes back to not having been signalled.
+		signal = shared.NoopSignal
+	}
+	err := h.temporalClient.SignalWorkflow(
+		ctx,
+		workflowID,
+		"",
+		shared.CDCFlowSignalName,
+		signal,
+	)
+	if err != nil {
+		return fmt.Errorf("unable to signal PeerFlow workflow: %w", err)
+	}
+
+	tag, err := h.pool.Exec(ctx, "UPDATE flows SET paused = $1 WHERE name = $2", paused, flowJobName)
+	if err != nil {
+		return fmt.Errorf("unable to update paused state of flow %s in catalog: %w", flowJobName, err)
+	}
+	if tag.RowsAffected() == 0 {
+		return fmt.Errorf("no such flow: %s", flowJobName)
+	}
+	return nil
+}
+
+func (h *FlowRequestHandler) AddTablesToFlow(
+	ctx context.Context,
+	req *protos.AddTablesToFlowRequest,
+) (*protos.AddTablesToFlowResponse, error) {
+	cfg, err := h.getFlowConfigFromCatalog(req.FlowJobName)
+	if err != nil {
+		return nil, fmt.Errorf("unable to get config of flow %s: %w", req.FlowJobName, err)
+	}
+
+	err = h.temporalClient.SignalWorkflow(
+		ctx,
+		req.WorkflowId,
+		"",
+		shared.CDCFlowAddTablesSignalName,
+		req.TableMappings,
+	)
+	if err != nil {
+		return nil, fmt.Errorf("unable to signal PeerFlow workflow: %w", err)
+	}
+
+	cfg.TableMappings = append(cfg.TableMappings, req.TableMappings...)
+	err = h.updateFlowConfigInCatalog(cfg)
+	if err != nil {
+		return nil, fmt.Errorf("unable to update flow config in catalog: %w", err)
+	}
+
+	return &protos.AddTablesToFlowResponse{
+		Ok: true,
+	}, nil
+}
+
+func (h *FlowRequestHandler) PauseTable(
+	ctx context.Context,
+	req *protos.PauseTableRequest,
+) (*protos.PauseTableResponse, error) {
+	cfg, err := h.getFlowConfigFromCatalog(req.FlowJobName)
+	if err != nil {
+		return nil, fmt.Errorf("unable to get config of flow %s: %w", req.FlowJobName, err)
+	}
+
+	// the flow keys paused tables by their destination table.
+	var destinationTableIdentifier string
+	for _, mapping := range cfg.TableMappings {
+		if sameTable(mapping.SourceTableIdentifier, req.TableIdentifier) ||
+			sameTable(mapping.DestinationTableIdentifier, req.TableIdentifier) {
+			destinationTableIdentifier = mapping.DestinationTableIdentifier
+			break
+		}
+	}
+	if destinationTableIdentifier == "" {
+		return nil, fmt.Errorf("flow %s does not replicate table %s", req.FlowJobName, req.TableIdentifier)
+	}
+
+	_, paused := cfg.PausedTables[destinationTableIdentifier]
+	if req.Paused && paused {
+		return nil, fmt.Errorf("table %s is already paused", req.TableIdentifier)
+	}
+	if !req.Paused && !paused {
+		return nil, fmt.Errorf("table %s is not paused", req.TableIdentifier)
+	}
+	// buffered records are only held back on destinations that normalize a raw table.
+	if req.Paused && req.Policy == protos.TablePausePolicy_TABLE_PAUSE_POLICY_BUFFER {
+		switch cfg.Destination.Type {
+		case protos.DBType_POSTGRES, protos.DBType_SNOWFLAKE, protos.DBType_BIGQUERY:
+		default:
+			return nil, fmt.Errorf("changes to tables can't be buffered on %s destinations, use the skip policy",
+				cfg.Destination.Type)
+		}
+	}
+
+	err = h.temporalClient.SignalWorkflow(
+		ctx,
+		req.WorkflowId,
+		"",
+		shared.CDCFlowPauseTableSignalName,
+		&protos.PauseTableRequest{
+			WorkflowId:      req.WorkflowId,
+			FlowJobName:     req.FlowJobName,
+			TableIdentifier: destinationTableIdentifier,
+			Paused:          req.Paused,
+			Policy:          req.Policy,
+		},
+	)
+	if err != nil {
+		return nil, fmt.Errorf("unable to signal PeerFlow workflow: %w", err)
+	}
+
+	if req.Paused {
+		if cfg.PausedTables == nil {
+			cfg.PausedTables = make(map[string]protos.TablePausePolicy)
+		}
+		cfg.PausedTables[destinationTableIdentifier] = req.Policy
+	} else {
+		delete(cfg.PausedTables, destinationTableIdentifier)
+	}
+	err = h.updateFlowConfigInCatalog(cfg)
+	if err != nil {
+		return nil, fmt.Errorf("unable to update flow config in catalog: %w", err)
+	}
+
+	return &protos.PauseTableResponse{
+		Ok: true,
+	}, nil
+}
+
+func (h *FlowRequestHandler) RemoveTableFromFlow(
+	ctx context.Context,
+	req *protos.RemoveTableFromFlowRequest,
+) (*protos.RemoveTableFromFlowResponse, error) {
+	cfg, err := h.getFlowConfigFromCatalog(req.FlowJobName)
+	if err != nil {
+		return nil, fmt.Errorf("unable to get config of flow %s: %w", req.FlowJobName, err)
+	}
+
+	var tableMapping *protos.TableMapping
+	for _, mapping := range cfg.TableMappings {
+		if sameTable(mapping.SourceTableIdentifier, req.SourceTableIdentifier) {
+			tableMapping = mapping
+			break
+		}
+	}
+	if tableMapping == nil {
+		return nil, fmt.Errorf("flow %s does not replicate table %s", req.FlowJobName, req.SourceTableIdentifier)
+	}
+	if len(cfg.TableMappings) == 1 {
+		return nil, fmt.Errorf("table %s is the only table of flow %s, drop the flow instead",
+			req.SourceTableIdentifier, req.FlowJobName)
+	}
+
+	err = h.temporalClient.SignalWorkflow(
+		ctx,
+		req.WorkflowId,
+		"",
+		shared.CDCFlowRemoveTableSignalName,
+		tableMapping,
+	)
+	if err != nil {
+		return nil, fmt.Errorf("unable to signal PeerFlow workflow: %w", err)
+	}
+
+	peerflow.RemoveTableMapping(cfg, tableMapping)
+	err = h.updateFlowConfigInCatalog(cfg)
+	if err != nil {
+		return nil, fmt.Errorf("unable to update flow config in catalog: %w", err)
+	}
+
+	return &protos.RemoveTableFromFlowResponse{
+		Ok: true,
+	}, nil
+}
+
+// ResyncMirror copies all tables of a flow, or a single table, over again and swaps the copies in,
+// or truncates the tables and copies them over again in place.
+func (h *FlowRequestHandler) ResyncMirror(
+	ctx context.Context,
+	req *protos.ResyncMirrorRequest,
+) (*protos.ResyncMirrorResponse, error) {
+	cfg, err := h.getFlowConfigFromCatalog(req.FlowJobName)
+	if err != nil {
+		return nil, fmt.Errorf("unable to get config of flow %s: %w", req.FlowJobName, err)
+	}
+
+	switch cfg.Destination.Type {
+	case protos.DBType_POSTGRES, protos.DBType_SNOWFLAKE, protos.DBType_BIGQUERY:
+	default:
+		return nil, fmt.Errorf("flow %s does not replicate to a destination whose tables can be resynced",
+			req.FlowJobName)
+	}
+
+	tableMappings := cfg.TableMappings
+	if req.SourceTableIdentifier != "" {
+		tableMappings = nil
+		for _, mapping := range cfg.TableMappings {
+			if sameTable(mapping.SourceTableIdentifier, req.SourceTableIdentifier) {
+				tableMappings = []*protos.TableMapping{mapping}
+				break
+			}
+		}
+		if tableMappings == nil {
+			return nil, fmt.Errorf("flow %s does not replicate table %s", req.FlowJobName, req.SourceTableIdentifier)
+		}
+	}
+
+	signalName := shared.CDCFlowResyncSignalName
+	if req.TruncateDestinationTables {
+		signalName = shared.CDCFlowTruncateResyncSignalName
+	}
+	err = h.temporalClient.SignalWorkflow(
+		ctx,
+		req.WorkflowId,
+		"",
+		signalName,
+		tableMappings,
+	)
+	if err != nil {
+		return nil, fmt.Errorf("unable to signal PeerFlow workflow: %w", err)
+	}
+
+	return &protos.ResyncMirrorResponse{
+		Ok: true,
+	}, nil
+}
+
+// tables without a schema are in the public schema.
+func sameTable(a, b string) bool {
+	return schemaForTableIdentifier(a, int32(protos.DBType_POSTGRES)) ==
+		schemaForTableIdentifier(b, int32(protos.DBType_POSTGRES))
+}
+
 func (h *FlowRequestHandler) waitForWorkflowClose(ctx context.Context, workflowID string) error {
 	expBackoff := backoff.NewExponentialBackOff()
 	expBackoff.InitialInterval = 3 * time.Second
@@ -403,34 +740,78 @@ func (h *FlowRequestHandler) ValidatePeer(
 
 	conn, err := connectors.GetConnector(ctx, req.Peer)
 	if err != nil {
+		message := fmt.Sprintf("peer type is missing or "+
+			"your requested configuration for %s peer %s was invalidated: %s",
+			req.Peer.Type, req.Peer.Name, err)
 		return &protos.ValidatePeerResponse{
-			Status: protos.ValidatePeerStatus_INVALID,
-			Message: fmt.Sprintf("peer type is missing or "+
-				"your requested configuration for %s peer %s was invalidated: %s",
-				req.Peer.Type, req.Peer.Name, err),
+			Status:  protos.ValidatePeerStatus_INVALID,
+			Message: message,
+			Checks: []*protos.PeerValidationCheck{
+				{Name: "config", Passed: false, Message: message, Required: true},
+			},
 		}, nil
 	}
+	defer connectors.CloseConnector(conn)
+	checks := []*protos.PeerValidationCheck{
+		{Name: "config", Passed: true, Message: "configuration is valid", Required: true},
+	}
 
 	status := conn.ConnectionActive()
 	if !status {
+		message := fmt.Sprintf("failed to establish active connection to %s peer %s.",
+			req.Peer.Type, req.Peer.Name)
 		return &protos.ValidatePeerResponse{
-			Status: protos.ValidatePeerStatus_INVALID,
-			Message: fmt.Sprintf("failed to establish active connection to %s peer %s.",
-				req.Peer.Type, req.Peer.Name),
+			Status:  protos.ValidatePeerStatus_INVALID,
+			Message: message,
+			Checks: append(checks,
+				&protos.PeerValidationCheck{Name: "connection", Passed: false, Message: message, Required: true}),
 		}, nil
 	}
+	checks = append(checks,
+		&protos.PeerValidationCheck{Name: "connection", Passed: true, Message: "connected", Required: true})
 
 	return &protos.ValidatePeerResponse{
 		Status: protos.ValidatePeerStatus_VALID,
 		Message: fmt.Sprintf("%s peer %s is valid",
 			req.Peer.Type, req.Peer.Name),
+		Checks: append(checks, replicationChecks(ctx, req.Peer)...),
 	}, nil
 }
 
+// replicationChecks checks what the peer needs to be the source of a cdc mirror, peers that can't be a
+// source have no checks. The checks don't make the peer invalid, it may only be used as a destination.
+func replicationChecks(ctx context.Context, peer *protos.Peer) []*protos.PeerValidationCheck {
+	conn, err := connectors.GetCDCPreflightConnector(ctx, peer)
+	if errors.Is(err, connectors.ErrUnsupportedFunctionality) {
+		return nil
+	} else if err != nil {
+		return []*protos.PeerValidationCheck{{Name: "replication", Passed: false, Message: err.Error()}}
+	}
+	defer connectors.CloseConnector(conn)
+
+	checks, err := conn.ReplicationChecks()
+	if err != nil {
+		return []*protos.PeerValidationCheck{{Name: "replication", Passed: false, Message: err.Error()}}
+	}
+	return checks
+}
+
 func (h *FlowRequestHandler) CreatePeer(
 	ctx context.Context,
 	req *protos.CreatePeerRequest,
 ) (*protos.CreatePeerResponse, error) {
+	if req.IdempotencyKey != "" {
+		resp := &protos.CreatePeerResponse{}
+		replayed, err := h.reserveKey(ctx, createPeerRPC, req.IdempotencyKey, req.Peer.Name, resp)
+		if err != nil {
+			return nil, err
+		} else if replayed {
+			return resp, nil
+		}
+		// kept once the response is recorded, otherwise a retry tries to create the peer again.
+		defer h.releaseKey(req.IdempotencyKey)
+	}
+
 	status, validateErr := h.ValidatePeer(ctx, &protos.ValidatePeerRequest{Peer: req.Peer})
 	if validateErr != nil {
 		return nil, validateErr
@@ -442,62 +823,208 @@ func (h *FlowRequestHandler) CreatePeer(
 		}, nil
 	}
 
-	config := req.Peer.Config
-	wrongConfigResponse := &protos.CreatePeerResponse{
-		Status: protos.CreatePeerStatus_FAILED,
-		Message: fmt.Sprintf("invalid config for %s peer %s",
-			req.Peer.Type, req.Peer.Name),
+	encodedConfig, err := encodePeerConfig(req.Peer)
+	if errors.Is(err, errInvalidPeerConfig) {
+		return &protos.CreatePeerResponse{
+			Status:  protos.CreatePeerStatus_FAILED,
+			Message: err.Error(),
+		}, nil
+	} else if err != nil {
+		return nil, err
 	}
-	var encodedConfig []byte
-	var encodingErr error
-	peerType := req.Peer.Type
-	switch peerType {
-	case protos.DBType_POSTGRES:
-		pgConfigObject, ok := config.(*protos.Peer_PostgresConfig)
-		if !ok {
-			return wrongConfigResponse, nil
-		}
-		pgConfig := pgConfigObject.PostgresConfig
+	encodedConfig, err = catalog.SealConfig(req.Peer.Name, encodedConfig)
+	if err != nil {
+		return nil, fmt.Errorf("unable to encrypt config of peer %s: %w", req.Peer.Name, err)
+	}
+
+	_, err = h.pool.Exec(ctx, "INSERT INTO peers (name, type, options) VALUES ($1, $2, $3)",
+		req.Peer.Name, req.Peer.Type, encodedConfig,
+	)
+	if err != nil {
+		return &protos.CreatePeerResponse{
+			Status: protos.CreatePeerStatus_FAILED,
+			Message: fmt.Sprintf("failed to insert into peers table for %s peer %s: %s",
+				req.Peer.Type, req.Peer.Name, err.Error()),
+		}, nil
+	}
+
+	// failed requests are not recorded, a retry tries to create the peer again.
+	resp := &protos.CreatePeerResponse{
+		Status:  protos.CreatePeerStatus_CREATED,
+		Message: "",
+	}
+	if req.IdempotencyKey != "" {
+		h.recordResponse(ctx, req.IdempotencyKey, req.Peer.Name, resp)
+	}
+	return resp, nil
+}
 
-		encodedConfig, encodingErr = proto.Marshal(pgConfig)
+var errInvalidPeerConfig = errors.New("invalid config")
 
+// encodePeerConfig encodes the config of the peer the way the catalog stores it.
+func encodePeerConfig(peer *protos.Peer) ([]byte, error) {
+	var config proto.Message
+	switch peer.Type {
+	case protos.DBType_POSTGRES:
+		if pgConfigObject, ok := peer.Config.(*protos.Peer_PostgresConfig); ok {
+			config = pgConfigObject.PostgresConfig
+		}
 	case protos.DBType_SNOWFLAKE:
-		sfConfigObject, ok := config.(*protos.Peer_SnowflakeConfig)
-		if !ok {
-			return wrongConfigResponse, nil
+		if sfConfigObject, ok := peer.Config.(*protos.Peer_SnowflakeConfig); ok {
+			config = sfConfigObject.SnowflakeConfig
 		}
-		sfConfig := sfConfigObject.SnowflakeConfig
-		encodedConfig, encodingErr = proto.Marshal(sfConfig)
 	case protos.DBType_SQLSERVER:
-		sqlServerConfigObject, ok := config.(*protos.Peer_SqlserverConfig)
-		if !ok {
-			return wrongConfigResponse, nil
+		if sqlServerConfigObject, ok := peer.Config.(*protos.Peer_SqlserverConfig); ok {
+			config = sqlServerConfigObject.SqlserverConfig
 		}
-		sqlServerConfig := sqlServerConfigObject.SqlserverConfig
-		encodedConfig, encodingErr = proto.Marshal(sqlServerConfig)
-
-	default:
-		return wrongConfigResponse, nil
 	}
-	if encodingErr != nil {
+	if config == nil {
+		return nil, fmt.Errorf("%w for %s peer %s", errInvalidPeerConfig, peer.Type, peer.Name)
+	}
+
+	encodedConfig, err := proto.Marshal(config)
+	if err != nil {
 		log.Errorf("failed to encode peer configuration for %s peer %s : %v",
-			req.Peer.Type, req.Peer.Name, encodingErr)
-		return nil, encodingErr
+			peer.Type, peer.Name, err)
+		return nil, err
 	}
+	return encodedConfig, nil
+}
 
-	_, err := h.pool.Exec(ctx, "INSERT INTO peers (name, type, options) VALUES ($1, $2, $3)",
-		req.Peer.Name, peerType, encodedConfig,
-	)
+func (h *FlowRequestHandler) UpdatePeer(
+	ctx context.Context,
+	req *protos.UpdatePeerRequest,
+) (*protos.UpdatePeerResponse, error) {
+	if req.Peer == nil {
+		return &protos.UpdatePeerResponse{
+			Ok:           false,
+			ErrorMessage: "no peer provided",
+		}, nil
+	}
+
+	if !req.CatalogUpdated {
+		status, err := h.ValidatePeer(ctx, &protos.ValidatePeerRequest{Peer: req.Peer})
+		if err != nil {
+			return nil, err
+		}
+		if status.Status != protos.ValidatePeerStatus_VALID {
+			return &protos.UpdatePeerResponse{
+				Ok:           false,
+				ErrorMessage: status.Message,
+			}, nil
+		}
+
+		encodedConfig, err := encodePeerConfig(req.Peer)
+		if errors.Is(err, errInvalidPeerConfig) {
+			return &protos.UpdatePeerResponse{
+				Ok:           false,
+				ErrorMessage: err.Error(),
+			}, nil
+		} else if err != nil {
+			return nil, err
+		}
+		// encrypted with the master key of the catalog, like nexus stores the configs it alters.
+		encodedConfig, err = catalog.SealConfig(req.Peer.Name, encodedConfig)
+		if err != nil {
+			return nil, fmt.Errorf("unable to encrypt config of peer %s: %w", req.Peer.Name, err)
+		}
+
+		// the old config is kept in peer_versions, like nexus does on ALTER PEER.
+		tag, err := h.pool.Exec(ctx, `WITH old AS (
+				SELECT id, version, options FROM peers WHERE name = $1 FOR UPDATE
+			), archived AS (
+				INSERT INTO peer_versions (peer_id, version, options)
+				SELECT id, version, options FROM old
+			)
+			UPDATE peers SET options = $2, version = old.version + 1
+			FROM old WHERE peers.id = old.id`,
+			req.Peer.Name, encodedConfig)
+		if err != nil {
+			return nil, fmt.Errorf("unable to update peer %s in catalog: %w", req.Peer.Name, err)
+		}
+		if tag.RowsAffected() == 0 {
+			return &protos.UpdatePeerResponse{
+				Ok:           false,
+				ErrorMessage: fmt.Sprintf("no such peer: %s", req.Peer.Name),
+			}, nil
+		}
+	}
+
+	flowJobNames, err := h.updatePeerOfFlows(ctx, req.Peer)
 	if err != nil {
-		return &protos.CreatePeerResponse{
-			Status: protos.CreatePeerStatus_FAILED,
-			Message: fmt.Sprintf("failed to insert into peers table for %s peer %s: %s",
-				req.Peer.Type, req.Peer.Name, err.Error()),
+		return &protos.UpdatePeerResponse{
+			Ok:           false,
+			ErrorMessage: err.Error(),
+			FlowJobNames: flowJobNames,
 		}, nil
 	}
 
-	return &protos.CreatePeerResponse{
-		Status:  protos.CreatePeerStatus_CREATED,
-		Message: "",
+	return &protos.UpdatePeerResponse{
+		Ok:           true,
+		FlowJobNames: flowJobNames,
 	}, nil
 }
+
+// updatePeerOfFlows saves the new config of the peer in the configs of its cdc flows, which they are
+// restarted with, and signals the running ones to reconnect with it at their next batch. The configs are
+// stored encrypted, like the peer's own. Returns the flows that were updated.
+func (h *FlowRequestHandler) updatePeerOfFlows(ctx context.Context, peer *protos.Peer) ([]string, error) {
+	rows, err := h.pool.Query(ctx, `SELECT f.name, f.workflow_id FROM flows f
+		JOIN peers p ON p.id = f.source_peer OR p.id = f.destination_peer
+		WHERE p.name = $1 AND f.query_string IS NULL AND f.config_proto IS NOT NULL`, peer.Name)
+	if err != nil {
+		return nil, fmt.Errorf("unable to query flows of peer %s: %w", peer.Name, err)
+	}
+	type flowWorkflow struct {
+		flowJobName string
+		workflowID  string
+	}
+	var flows []flowWorkflow
+	for rows.Next() {
+		var flow flowWorkflow
+		if err := rows.Scan(&flow.flowJobName, &flow.workflowID); err != nil {
+			rows.Close()
+			return nil, fmt.Errorf("unable to scan flows of peer %s: %w", peer.Name, err)
+		}
+		flows = append(flows, flow)
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return nil, fmt.Errorf("unable to query flows of peer %s: %w", peer.Name, err)
+	}
+
+	flowJobNames := make([]string, 0, len(flows))
+	for _, flow := range flows {
+		cfg, err := h.getFlowConfigFromCatalog(flow.flowJobName)
+		if err != nil {
+			return flowJobNames, fmt.Errorf("unable to get config of flow %s: %w", flow.flowJobName, err)
+		}
+		if !peerflow.ReplacePeerConfig(cfg, peer) {
+			continue
+		}
+		err = h.updateFlowConfigInCatalog(cfg)
+		if err != nil {
+			return flowJobNames, fmt.Errorf("unable to update flow config in catalog: %w", err)
+		}
+
+		// flows that aren't running pick up the new config from the catalog when they are restarted.
+		err = h.temporalClient.SignalWorkflow(
+			ctx,
+			flow.workflowID,
+			"",
+			shared.CDCFlowUpdatePeerSignalName,
+			peer,
+		)
+		if err != nil {
+			var notFound *serviceerror.NotFound
+			if !errors.As(err, &notFound) {
+				return flowJobNames, fmt.Errorf("unable to signal PeerFlow workflow of flow %s: %w",
+					flow.flowJobName, err)
+			}
+			log.Infof("flow %s is not running, it gets the new config of peer %s when it restarts",
+				flow.flowJobName, peer.Name)
+		}
+		flowJobNames = append(flowJobNames, flow.flowJobName)
+	}
+	return flowJobNames, nil
+}
@@ -0,0 +1,121 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	log "github.com/sirupsen/logrus"
+	"google.golang.org/protobuf/proto"
+)
+
+// the rpcs idempotency keys are recorded for, a key can't be reused across them.
+const (
+	createCDCFlowRPC  = "CreateCDCFlow"
+	createQRepFlowRPC = "CreateQRepFlow"
+	createPeerRPC     = "CreatePeer"
+)
+
+// idempotencyKeyTTL is how long the response to a request is replayed for retries with its key.
+const idempotencyKeyTTL = 24 * time.Hour
+
+// idempotencyKeyReservationTTL is how long a key is reserved for a request that neither records its
+// response nor releases the key, e.g. because the flow api went away while handling it.
+const idempotencyKeyReservationTTL = 10 * time.Minute
+
+// idempotencyKeyPollInterval is how often a retry checks whether the request holding its key is done.
+const idempotencyKeyPollInterval = time.Second
+
+func intervalOf(d time.Duration) string {
+	return fmt.Sprintf("%d seconds", int64(d.Seconds()))
+}
+
+// reserveKey reserves the idempotency key for a request, unless a response was recorded for it, which is
+// read into resp. It returns whether there was one. While the key is reserved by another request with it,
+// this waits for that request to record its response or release the key. A reserved key is released with
+// releaseKey unless a response is recorded for it.
+func (h *FlowRequestHandler) reserveKey(ctx context.Context, rpc string, key string, name string,
+	resp proto.Message) (bool, error) {
+	for {
+		// keys past their ttl, and reservations nothing released, are taken over.
+		tag, err := h.pool.Exec(ctx, `INSERT INTO idempotency_keys (key, rpc, name) VALUES ($1, $2, $3)
+			ON CONFLICT (key) DO UPDATE SET rpc = $2, name = $3, response = NULL, created_at = now()
+			WHERE idempotency_keys.created_at <= now() - $4::interval
+			OR (idempotency_keys.response IS NULL AND idempotency_keys.created_at <= now() - $5::interval)`,
+			key, rpc, name, intervalOf(idempotencyKeyTTL), intervalOf(idempotencyKeyReservationTTL))
+		if err != nil {
+			return false, fmt.Errorf("unable to reserve idempotency key %s: %w", key, err)
+		}
+		if tag.RowsAffected() == 1 {
+			return false, nil
+		}
+
+		var recordedRPC string
+		var response []byte
+		err = h.pool.QueryRow(ctx, "SELECT rpc, response FROM idempotency_keys WHERE key = $1",
+			key).Scan(&recordedRPC, &response)
+		if errors.Is(err, pgx.ErrNoRows) {
+			// released since, reserve it again.
+			continue
+		} else if err != nil {
+			return false, fmt.Errorf("unable to query idempotency key %s: %w", key, err)
+		}
+
+		if recordedRPC != rpc {
+			return false, fmt.Errorf("idempotency key %s was already used for %s", key, recordedRPC)
+		}
+		if response != nil {
+			if err := proto.Unmarshal(response, resp); err != nil {
+				return false, fmt.Errorf("unable to unmarshal response for idempotency key %s: %w", key, err)
+			}
+			log.Infof("replaying response of %s for idempotency key %s", rpc, key)
+			return true, nil
+		}
+
+		select {
+		case <-ctx.Done():
+			return false, ctx.Err()
+		case <-time.After(idempotencyKeyPollInterval):
+		}
+	}
+}
+
+// recordResponse records the response to a request for retries with its idempotency key. The request
+// already went through, so failing to record it is only logged.
+func (h *FlowRequestHandler) recordResponse(ctx context.Context, key string, name string,
+	resp proto.Message) {
+	response, err := proto.Marshal(resp)
+	if err != nil {
+		log.Warnf("unable to marshal response for idempotency key %s: %v", key, err)
+		return
+	}
+	_, err = h.pool.Exec(ctx,
+		"UPDATE idempotency_keys SET name = $2, response = $3, created_at = now() WHERE key = $1",
+		key, name, response)
+	if err != nil {
+		log.Warnf("unable to record response for idempotency key %s: %v", key, err)
+	}
+}
+
+// releaseKey releases the idempotency key reserved for a request that failed, so that a retry with it
+// tries the request again. A key with a recorded response is kept.
+func (h *FlowRequestHandler) releaseKey(key string) {
+	// released even if the request was cancelled, retries would wait for the reservation to expire.
+	_, err := h.pool.Exec(context.Background(),
+		"DELETE FROM idempotency_keys WHERE key = $1 AND response IS NULL", key)
+	if err != nil {
+		log.Warnf("unable to release idempotency key %s: %v", key, err)
+	}
+}
+
+// forgetResponses removes the responses recorded for a flow, so that a flow created again under its
+// name isn't answered with the response for the old one.
+func (h *FlowRequestHandler) forgetResponses(ctx context.Context, name string) {
+	_, err := h.pool.Exec(ctx, "DELETE FROM idempotency_keys WHERE name = $1 AND rpc IN ($2, $3)",
+		name, createCDCFlowRPC, createQRepFlowRPC)
+	if err != nil {
+		log.Warnf("unable to remove idempotency keys of flow %s: %v", name, err)
+	}
+}
@@ -0,0 +1,49 @@
+package main
+
+import (
+	"fmt"
+
+	catalog "github.com/PeerDB-io/peer-flow/connectors/utils/catalog"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	log "github.com/sirupsen/logrus"
+)
+
+// RotateEncryptionKey rotates the master key the configs in the catalog are encrypted with, and sends the
+// progress of each table as its configs are rotated, then the counts once every config is verified. The
+// flow api must run with the new key as PEERDB_CATALOG_MASTER_KEY and the old one as
+// PEERDB_CATALOG_PREVIOUS_MASTER_KEY.
+func (h *FlowRequestHandler) RotateEncryptionKey(
+	req *protos.RotateEncryptionKeyRequest,
+	stream protos.FlowService_RotateEncryptionKeyServer,
+) error {
+	oldKey, err := catalog.DecodeMasterKey(req.OldKey)
+	if err != nil {
+		return fmt.Errorf("the old key %w", err)
+	}
+	newKey, err := catalog.DecodeMasterKey(req.NewKey)
+	if err != nil {
+		return fmt.Errorf("the new key %w", err)
+	}
+
+	rotation, err := catalog.RotateMasterKey(stream.Context(), h.pool, oldKey, newKey,
+		func(table string, done int, total int) error {
+			return stream.Send(&protos.RotateEncryptionKeyProgress{
+				Table: table,
+				Done:  int64(done),
+				Total: int64(total),
+			})
+		})
+	if err != nil {
+		log.Errorf("unable to rotate the master key of the catalog: %s", err.Error())
+		return fmt.Errorf("unable to rotate the master key of the catalog: %w", err)
+	}
+
+	log.Infof("rotated the master key of the catalog: %d configs rotated, %d encrypted, %d verified",
+		rotation.Rotated, rotation.Encrypted, rotation.Verified)
+	return stream.Send(&protos.RotateEncryptionKeyProgress{
+		Rotated:   rotation.Rotated,
+		Encrypted: rotation.Encrypted,
+		Verified:  rotation.Verified,
+		Completed: true,
+	})
+}
@@ -0,0 +1,124 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/PeerDB-io/peer-flow/connectors"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/shared"
+	"github.com/jackc/pgx/v5/pgconn"
+	log "github.com/sirupsen/logrus"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+// preflightCDCFlow checks that a mirror can be set up before its workflow is
+// started, so that the common failures of a new mirror are reported to the
+// client of CREATE MIRROR instead of failing the workflow later.
+func (h *FlowRequestHandler) preflightCDCFlow(ctx context.Context, cfg *protos.FlowConnectionConfigs) error {
+	err := checkDestination(ctx, cfg.Destination)
+	if err != nil {
+		return err
+	}
+
+	srcConn, err := connectors.GetCDCPreflightConnector(ctx, cfg.Source)
+	if errors.Is(err, connectors.ErrUnsupportedFunctionality) {
+		return nil
+	} else if err != nil {
+		return fmt.Errorf("failed to connect to source peer %s: %w", cfg.Source.Name, err)
+	}
+	defer connectors.CloseConnector(srcConn)
+
+	slotName := fmt.Sprintf("peerflow_slot_%s", cfg.FlowJobName)
+	if cfg.ReplicationSlotName != "" {
+		slotName = cfg.ReplicationSlotName
+	}
+	err = srcConn.CheckSlotCreation(slotName)
+	if err != nil {
+		return err
+	}
+
+	dstConn, err := connectors.GetCDCPreflightConnector(ctx, cfg.Destination)
+	if errors.Is(err, connectors.ErrUnsupportedFunctionality) {
+		return nil
+	} else if err != nil {
+		return fmt.Errorf("failed to connect to destination peer %s: %w", cfg.Destination.Name, err)
+	}
+	defer connectors.CloseConnector(dstConn)
+
+	srcPullConn, ok := srcConn.(connectors.CDCPullConnector)
+	if !ok {
+		return nil
+	}
+	for _, mapping := range cfg.TableMappings {
+		schemas, err := srcPullConn.GetTableSchema(&protos.GetTableSchemaBatchInput{
+			TableIdentifiers: []string{mapping.SourceTableIdentifier},
+		})
+		if err != nil {
+			return fmt.Errorf("failed to get schema of source table %s: %w", mapping.SourceTableIdentifier, err)
+		}
+		sourceSchema := schemas.TableNameSchemaMapping[mapping.SourceTableIdentifier]
+		err = dstConn.CheckTableTypes(mapping.DestinationTableIdentifier, sourceSchema)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// checkDestination checks that the destination peer accepts its credentials.
+func checkDestination(ctx context.Context, peer *protos.Peer) error {
+	conn, err := connectors.GetConnector(ctx, peer)
+	if err != nil {
+		if isAuthError(err) {
+			return &shared.MirrorError{
+				Category: shared.MirrorErrorDestinationAuth,
+				Message:  fmt.Sprintf("destination peer %s rejected its credentials", peer.Name),
+				Detail:   err.Error(),
+				Hint:     "update the credentials of the destination peer",
+			}
+		}
+		return fmt.Errorf("failed to connect to destination peer %s: %w", peer.Name, err)
+	}
+	defer connectors.CloseConnector(conn)
+
+	if !conn.ConnectionActive() {
+		return &shared.MirrorError{
+			Category: shared.MirrorErrorDestinationAuth,
+			Message:  fmt.Sprintf("failed to establish active connection to destination peer %s", peer.Name),
+			Hint:     "check that the destination is reachable and that the credentials of its peer are valid",
+		}
+	}
+	return nil
+}
+
+// isAuthError is whether the error is the server rejecting a login, as
+// reported by postgres.
+func isAuthError(err error) bool {
+	var pgErr *pgconn.PgError
+	return errors.As(err, &pgErr) && strings.HasPrefix(pgErr.Code, "28")
+}
+
+// mirrorErrorStatus returns the grpc status for an error of a flow request. A
+// MirrorError is sent with its category, detail and hint in the trailers of the
+// response, other errors are returned as they are.
+func mirrorErrorStatus(ctx context.Context, err error) error {
+	var mirrorErr *shared.MirrorError
+	if !errors.As(err, &mirrorErr) {
+		return err
+	}
+	trailer := metadata.Pairs(
+		shared.MirrorErrorCategoryTrailer, string(mirrorErr.Category),
+		shared.MirrorErrorDetailTrailer, mirrorErr.Detail,
+		shared.MirrorErrorHintTrailer, mirrorErr.Hint,
+	)
+	if trailerErr := grpc.SetTrailer(ctx, trailer); trailerErr != nil {
+		log.Warnf("failed to set trailer for mirror error: %v", trailerErr)
+	}
+	return status.Error(codes.FailedPrecondition, mirrorErr.Message)
+}
@@ -4,10 +4,14 @@ import (
 	"context"
 	"database/sql"
 	"fmt"
+	"time"
 
+	catalog "github.com/PeerDB-io/peer-flow/connectors/utils/catalog"
 	"github.com/PeerDB-io/peer-flow/generated/protos"
+	peerflow "github.com/PeerDB-io/peer-flow/workflows"
 	"github.com/jackc/pgx/v5/pgtype"
 	"github.com/sirupsen/logrus"
+	"go.temporal.io/api/enums/v1"
 	"google.golang.org/protobuf/proto"
 	"google.golang.org/protobuf/types/known/timestamppb"
 )
@@ -54,6 +58,40 @@ func (h *FlowRequestHandler) MirrorStatus(
 	}
 }
 
+// mirrorStatusWatchInterval is how often the status of a watched mirror is checked for changes.
+const mirrorStatusWatchInterval = 2 * time.Second
+
+// WatchMirrorStatus sends the status of the mirror and then every change to it, until the
+// client goes away.
+func (h *FlowRequestHandler) WatchMirrorStatus(
+	req *protos.MirrorStatusRequest,
+	stream protos.FlowService_WatchMirrorStatusServer,
+) error {
+	ctx := stream.Context()
+	ticker := time.NewTicker(mirrorStatusWatchInterval)
+	defer ticker.Stop()
+
+	var lastStatus *protos.MirrorStatusResponse
+	for {
+		status, err := h.MirrorStatus(ctx, req)
+		if err != nil {
+			return err
+		}
+		if lastStatus == nil || !proto.Equal(lastStatus, status) {
+			if err := stream.Send(status); err != nil {
+				return fmt.Errorf("unable to send status of mirror: %w", err)
+			}
+			lastStatus = status
+		}
+
+		select {
+		case <-ctx.Done():
+			return nil
+		case <-ticker.C:
+		}
+	}
+}
+
 func (h *FlowRequestHandler) CDCFlowStatus(
 	ctx context.Context,
 	req *protos.MirrorStatusRequest,
@@ -86,10 +124,136 @@ func (h *FlowRequestHandler) CDCFlowStatus(
 		}
 	}
 
-	return &protos.CDCMirrorStatus{
-		Config:         config,
-		SnapshotStatus: initialCopyStatus,
-	}, nil
+	cdcSyncs, err := h.getCDCSyncStatuses(ctx, req.FlowJobName)
+	if err != nil {
+		return nil, err
+	}
+
+	var applyBacklog peerflow.ApplyBacklog
+	if config.ApplyWindow != nil {
+		applyBacklog, err = h.getApplyBacklog(ctx, req.FlowJobName)
+		if err != nil {
+			// the flow isn't running, e.g. it is between runs or was shut down.
+			logrus.Warnf("unable to get apply backlog of flow %s: %v", req.FlowJobName, err)
+		}
+	}
+
+	status := &protos.CDCMirrorStatus{
+		Config:              config,
+		SnapshotStatus:      initialCopyStatus,
+		CdcSyncs:            cdcSyncs,
+		ApplyBacklogBatches: applyBacklog.Batches,
+		ApplyBacklogRecords: applyBacklog.Records,
+	}
+	// the latency of the mirror is that of its most recent batch that measured it.
+	for _, cdcSync := range cdcSyncs {
+		if cdcSync.LatencyP50Seconds != nil {
+			status.LatencyP50Seconds = cdcSync.LatencyP50Seconds
+			status.LatencyP99Seconds = cdcSync.LatencyP99Seconds
+			break
+		}
+	}
+	if config.InitialCopyOnly {
+		status.Completed, err = h.isWorkflowCompleted(ctx, req.FlowJobName)
+		if err != nil {
+			return nil, err
+		}
+	}
+	return status, nil
+}
+
+// isWorkflowCompleted returns whether the workflow of the flow ran to completion,
+// as a snapshot-only flow does once its initial copy is done.
+func (h *FlowRequestHandler) isWorkflowCompleted(ctx context.Context, flowJobName string) (bool, error) {
+	var workflowID string
+	err := h.pool.QueryRow(ctx, "SELECT workflow_id FROM flows WHERE name = $1", flowJobName).Scan(&workflowID)
+	if err != nil {
+		return false, fmt.Errorf("unable to query workflow id of flow: %w", err)
+	}
+
+	workflowRes, err := h.temporalClient.DescribeWorkflowExecution(ctx, workflowID, "")
+	if err != nil {
+		return false, fmt.Errorf("unable to describe workflow of flow: %w", err)
+	}
+	return workflowRes.WorkflowExecutionInfo.Status == enums.WORKFLOW_EXECUTION_STATUS_COMPLETED, nil
+}
+
+// getApplyBacklog returns what the flow has buffered in the raw table outside its apply window.
+func (h *FlowRequestHandler) getApplyBacklog(ctx context.Context, flowJobName string) (peerflow.ApplyBacklog, error) {
+	var applyBacklog peerflow.ApplyBacklog
+	var workflowID string
+	err := h.pool.QueryRow(ctx, "SELECT workflow_id FROM flows WHERE name = $1", flowJobName).Scan(&workflowID)
+	if err != nil {
+		return applyBacklog, fmt.Errorf("unable to query workflow id of flow: %w", err)
+	}
+
+	res, err := h.temporalClient.QueryWorkflow(ctx, workflowID, "", peerflow.CDCFlowApplyBacklogQuery, flowJobName)
+	if err != nil {
+		return applyBacklog, fmt.Errorf("unable to query workflow of flow: %w", err)
+	}
+	if err := res.Get(&applyBacklog); err != nil {
+		return applyBacklog, fmt.Errorf("unable to decode apply backlog: %w", err)
+	}
+	return applyBacklog, nil
+}
+
+// maxCDCSyncStatuses is how many of the most recent cdc batches are reported.
+const maxCDCSyncStatuses = 100
+
+// getCDCSyncStatuses returns the most recent cdc batches of the flow, newest first.
+// The lag of a batch is measured from the last sample of the source's LSN that
+// doesn't go past the end of the batch, so it never under-reports.
+func (h *FlowRequestHandler) getCDCSyncStatuses(
+	ctx context.Context,
+	flowJobName string,
+) ([]*protos.CDCSyncStatus, error) {
+	q := `SELECT b.batch_start_lsn, b.batch_end_lsn, b.rows_in_batch, b.start_time, b.end_time,
+		EXTRACT(EPOCH FROM b.end_time - (
+			SELECT s.sampled_at FROM peerdb_stats.lsn_timestamps s
+			WHERE s.flow_name = b.flow_name AND s.lsn <= b.batch_end_lsn
+			ORDER BY s.lsn DESC LIMIT 1))::float8,
+		b.latency_p50_seconds, b.latency_p99_seconds
+		FROM peerdb_stats.cdc_batches b WHERE b.flow_name = $1
+		ORDER BY b.batch_id DESC LIMIT $2`
+	rows, err := h.pool.Query(ctx, q, flowJobName, maxCDCSyncStatuses)
+	if err != nil {
+		return nil, fmt.Errorf("unable to query cdc batches: %w", err)
+	}
+	defer rows.Close()
+
+	cdcSyncs := []*protos.CDCSyncStatus{}
+	for rows.Next() {
+		var startLSN, endLSN int64
+		var numRows int32
+		var startTime, endTime pgtype.Timestamp
+		var lagSeconds, latencyP50Seconds, latencyP99Seconds pgtype.Float8
+		if err := rows.Scan(&startLSN, &endLSN, &numRows, &startTime, &endTime, &lagSeconds,
+			&latencyP50Seconds, &latencyP99Seconds); err != nil {
+			return nil, fmt.Errorf("unable to scan cdc batch row: %w", err)
+		}
+
+		cdcSync := &protos.CDCSyncStatus{
+			StartLsn: startLSN,
+			EndLsn:   endLSN,
+			NumRows:  numRows,
+		}
+		if startTime.Valid {
+			cdcSync.StartTime = timestamppb.New(startTime.Time)
+		}
+		if endTime.Valid {
+			cdcSync.EndTime = timestamppb.New(endTime.Time)
+		}
+		if lagSeconds.Valid {
+			cdcSync.LagSeconds = &lagSeconds.Float64
+		}
+		if latencyP50Seconds.Valid && latencyP99Seconds.Valid {
+			cdcSync.LatencyP50Seconds = &latencyP50Seconds.Float64
+			cdcSync.LatencyP99Seconds = &latencyP99Seconds.Float64
+		}
+		cdcSyncs = append(cdcSyncs, cdcSync)
+	}
+
+	return cdcSyncs, nil
 }
 
 func (h *FlowRequestHandler) QRepFlowStatus(
@@ -189,6 +353,11 @@ func (h *FlowRequestHandler) getFlowConfigFromCatalog(
 		return nil, fmt.Errorf("unable to query flow config from catalog: %w", err)
 	}
 
+	configBytes, err = catalog.OpenConfig(flowJobName, configBytes)
+	if err != nil {
+		return nil, fmt.Errorf("unable to decrypt flow config: %w", err)
+	}
+
 	err = proto.Unmarshal(configBytes, &config)
 	if err != nil {
 		return nil, fmt.Errorf("unable to unmarshal flow config: %w", err)
@@ -229,6 +398,12 @@ func (h *FlowRequestHandler) getQRepConfigFromCatalog(flowJobName string) *proto
 		return nil
 	}
 
+	configBytes, err := catalog.OpenConfig(flowJobName, configBytes)
+	if err != nil {
+		logrus.Warnf("failed to decrypt config for %s: %s", flowJobName, err.Error())
+		return nil
+	}
+
 	// Try unmarshaling
 	if err := proto.Unmarshal(configBytes, &config); err != nil {
 		logrus.Warnf("failed to unmarshal config for %s: %s", flowJobName, err.Error())
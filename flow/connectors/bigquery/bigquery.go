@@ -898,12 +898,22 @@ func (c *BigQueryConnector) NormalizeRecords(req *model.NormalizeRecordsRequest)
 			EndBatchID:   syncBatchID,
 		}, nil
 	}
-	distinctTableNames, err := c.getDistinctTableNamesInBatch(req.FlowJobName, syncBatchID, normalizeBatchID)
+	minNormalizeBatchID := req.MinNormalizeBatchID(normalizeBatchID)
+	batchTableNames, err := c.getDistinctTableNamesInBatch(req.FlowJobName, syncBatchID, minNormalizeBatchID)
 	if err != nil {
 		return nil, fmt.Errorf("couldn't get distinct table names to normalize: %w", err)
 	}
+	// records of paused tables stay in the raw table until they are resumed, tables removed from the
+	// mirror leave theirs behind.
+	distinctTableNames := make([]string, 0, len(batchTableNames))
+	for _, tableName := range batchTableNames {
+		if _, ok := c.tableNameSchemaMapping[tableName]; ok && !req.IsPaused(tableName) {
+			distinctTableNames = append(distinctTableNames, tableName)
+		}
+	}
 
-	tableNametoUnchangedToastCols, err := c.getTableNametoUnchangedCols(req.FlowJobName, syncBatchID, normalizeBatchID)
+	tableNametoUnchangedToastCols, err := c.getTableNametoUnchangedCols(req.FlowJobName, syncBatchID,
+		minNormalizeBatchID)
 	if err != nil {
 		return nil, fmt.Errorf("couldn't get tablename to unchanged cols mapping: %w", err)
 	}
@@ -921,7 +931,7 @@ func (c *BigQueryConnector) NormalizeRecords(req *model.NormalizeRecordsRequest)
 			RawTable:              rawTableName,
 			NormalizedTableSchema: c.tableNameSchemaMapping[tableName],
 			SyncBatchID:           syncBatchID,
-			NormalizeBatchID:      normalizeBatchID,
+			NormalizeBatchID:      req.TableNormalizeBatchID(tableName, normalizeBatchID),
 			UnchangedToastColumns: tableNametoUnchangedToastCols[tableName],
 		}
 		// normalize anything between last normalized batch id to last sync batchid
@@ -1150,6 +1160,56 @@ func (c *BigQueryConnector) SyncFlowCleanup(jobName string) error {
 	return nil
 }
 
+// DropTables drops the tables that exist.
+func (c *BigQueryConnector) DropTables(tableIdentifiers []string) error {
+	for _, tableIdentifier := range tableIdentifiers {
+		query := fmt.Sprintf("DROP TABLE IF EXISTS %s.%s", c.datasetID, tableIdentifier)
+		_, err := c.client.Query(query).Read(c.ctx)
+		if err != nil {
+			return fmt.Errorf("failed to drop table %s: %w", tableIdentifier, err)
+		}
+	}
+	return nil
+}
+
+// TruncateTables removes every row of the tables.
+func (c *BigQueryConnector) TruncateTables(tableIdentifiers []string) error {
+	for _, tableIdentifier := range tableIdentifiers {
+		query := fmt.Sprintf("TRUNCATE TABLE %s.%s", c.datasetID, tableIdentifier)
+		_, err := c.client.Query(query).Read(c.ctx)
+		if err != nil {
+			return fmt.Errorf("failed to truncate table %s: %w", tableIdentifier, err)
+		}
+	}
+	return nil
+}
+
+// ReplaceTables overwrites each table with its copy, then drops the copy.
+func (c *BigQueryConnector) ReplaceTables(replacements map[string]string) error {
+	dataset := c.client.Dataset(c.datasetID)
+	for tableIdentifier, copyIdentifier := range replacements {
+		copier := dataset.Table(tableIdentifier).CopierFrom(dataset.Table(copyIdentifier))
+		copier.WriteDisposition = bigquery.WriteTruncate
+		job, err := copier.Run(c.ctx)
+		if err != nil {
+			return fmt.Errorf("failed to copy table %s to %s: %w", copyIdentifier, tableIdentifier, err)
+		}
+		status, err := job.Wait(c.ctx)
+		if err != nil {
+			return fmt.Errorf("failed to wait for copy of table %s: %w", copyIdentifier, err)
+		}
+		if err := status.Err(); err != nil {
+			return fmt.Errorf("failed to copy table %s to %s: %w", copyIdentifier, tableIdentifier, err)
+		}
+
+		err = dataset.Table(copyIdentifier).Delete(c.ctx)
+		if err != nil {
+			return fmt.Errorf("failed to delete table %s: %w", copyIdentifier, err)
+		}
+	}
+	return nil
+}
+
 // getRawTableName returns the raw table name for the given table identifier.
 func (c *BigQueryConnector) getRawTableName(flowJobName string) string {
 	// replace all non-alphanumeric characters with _
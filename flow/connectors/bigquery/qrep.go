@@ -50,7 +50,7 @@ func (c *BigQueryConnector) SyncQRepRecords(
 		stagingTableSync := &QRepStagingTableSync{connector: c}
 		return stagingTableSync.SyncQRepRecords(config.FlowJobName, destTable, partition, tblMetadata, stream)
 	case protos.QRepSyncMode_QREP_SYNC_MODE_STORAGE_AVRO:
-		avroSync := &QRepAvroSyncMethod{connector: c, gcsBucket: config.StagingPath}
+		avroSync := NewQRepAvroSyncMethod(c, config.StagingPath)
 		return avroSync.SyncQRepRecords(config.FlowJobName, destTable, partition, tblMetadata, stream)
 	default:
 		return 0, fmt.Errorf("unsupported sync mode: %s", syncMode)
@@ -22,15 +22,29 @@ import (
 type QRepAvroSyncMethod struct {
 	connector *BigQueryConnector
 	gcsBucket string
+	// objects are staged under this prefix of the bucket, if any.
+	gcsPrefix string
 }
 
-func NewQRepAvroSyncMethod(connector *BigQueryConnector, gcsBucket string) *QRepAvroSyncMethod {
+// NewQRepAvroSyncMethod stages through the staging path of a mirror, either a bucket
+// name or a gs://bucket/prefix URL, so mirrors can stage through buckets of their own.
+func NewQRepAvroSyncMethod(connector *BigQueryConnector, stagingPath string) *QRepAvroSyncMethod {
+	gcsBucket, gcsPrefix := gcsBucketAndPrefix(stagingPath)
 	return &QRepAvroSyncMethod{
 		connector: connector,
 		gcsBucket: gcsBucket,
+		gcsPrefix: gcsPrefix,
 	}
 }
 
+func gcsBucketAndPrefix(stagingPath string) (string, string) {
+	splitPath := strings.SplitN(strings.TrimPrefix(stagingPath, "gs://"), "/", 2)
+	if len(splitPath) == 1 {
+		return splitPath[0], ""
+	}
+	return splitPath[0], strings.Trim(splitPath[1], "/")
+}
+
 func (s *QRepAvroSyncMethod) SyncRecords(
 	dstTableName string,
 	flowJobName string,
@@ -325,9 +339,16 @@ func (s *QRepAvroSyncMethod) writeToStage(
 	defer func() {
 		shutdown <- true
 	}()
+	if s.gcsBucket == "" {
+		return 0, fmt.Errorf("syncing through GCS requires a staging path, " +
+			"set snapshot_staging_path or cdc_staging_path on the mirror")
+	}
 	ctx := context.Background()
 	bucket := s.connector.storageClient.Bucket(s.gcsBucket)
 	gcsObjectName := fmt.Sprintf("%s/%s.avro", objectFolder, syncID)
+	if s.gcsPrefix != "" {
+		gcsObjectName = fmt.Sprintf("%s/%s", s.gcsPrefix, gcsObjectName)
+	}
 
 	obj := bucket.Object(gcsObjectName)
 	w := obj.NewWriter(ctx)
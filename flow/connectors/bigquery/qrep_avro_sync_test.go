@@ -0,0 +1,19 @@
+package connbigquery
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestGCSBucketAndPrefix(t *testing.T) {
+	for stagingPath, expected := range map[string][2]string{
+		"peerdb_staging":                   {"peerdb_staging", ""},
+		"gs://team-bucket":                 {"team-bucket", ""},
+		"gs://team-bucket/mirrors/orders/": {"team-bucket", "mirrors/orders"},
+	} {
+		bucket, prefix := gcsBucketAndPrefix(stagingPath)
+		require.Equal(t, expected[0], bucket, stagingPath)
+		require.Equal(t, expected[1], prefix, stagingPath)
+	}
+}
@@ -7,10 +7,14 @@ import (
 
 	connbigquery "github.com/PeerDB-io/peer-flow/connectors/bigquery"
 	conneventhub "github.com/PeerDB-io/peer-flow/connectors/eventhub"
+	connfile "github.com/PeerDB-io/peer-flow/connectors/file"
+	connpinecone "github.com/PeerDB-io/peer-flow/connectors/pinecone"
 	connpostgres "github.com/PeerDB-io/peer-flow/connectors/postgres"
 	conns3 "github.com/PeerDB-io/peer-flow/connectors/s3"
 	connsnowflake "github.com/PeerDB-io/peer-flow/connectors/snowflake"
 	connsqlserver "github.com/PeerDB-io/peer-flow/connectors/sqlserver"
+	connweaviate "github.com/PeerDB-io/peer-flow/connectors/weaviate"
+	connwebhook "github.com/PeerDB-io/peer-flow/connectors/webhook"
 	"github.com/PeerDB-io/peer-flow/generated/protos"
 	"github.com/PeerDB-io/peer-flow/model"
 )
@@ -41,6 +45,14 @@ type CDCPullConnector interface {
 	// PullFlowCleanup drops both the Postgres publication and replication slot, as a part of DROP MIRROR
 	PullFlowCleanup(jobName string) error
 
+	// AddTablesToPublication adds tables to the publication created for the flow job, as a part of
+	// ALTER MIRROR ... ADD TABLE
+	AddTablesToPublication(jobName string, tableNames []string) error
+
+	// RemoveTablesFromPublication drops tables from the publication created for the flow job, as a part of
+	// ALTER MIRROR ... DROP TABLE
+	RemoveTablesFromPublication(jobName string, tableNames []string) error
+
 	// SendWALHeartbeat allows for activity to progress restart_lsn on postgres.
 	SendWALHeartbeat() error
 }
@@ -93,6 +105,55 @@ type CDCNormalizeConnector interface {
 	ReplayTableSchemaDeltas(flowJobName string, schemaDeltas []*protos.TableSchemaDelta) error
 }
 
+// CDCSchemaEventConnector is implemented by queue destinations, which have no
+// tables to alter and instead tell downstream consumers about schema changes.
+type CDCSchemaEventConnector interface {
+	Connector
+
+	// PublishSchemaChangeEvents publishes an event for each schema delta to its destination.
+	PublishSchemaChangeEvents(flowJobName string, schemaDeltas []*protos.TableSchemaDelta) error
+}
+
+// CDCResyncConnector is implemented by destinations whose tables can be
+// replaced by fresh copies, which is how resynced tables are swapped in.
+type CDCResyncConnector interface {
+	Connector
+
+	// DropTables drops the tables that exist.
+	DropTables(tableIdentifiers []string) error
+
+	// ReplaceTables replaces each table with its copy, the replaced tables are dropped.
+	ReplaceTables(replacements map[string]string) error
+
+	// TruncateTables removes every row of the tables, which are copied into again in place.
+	TruncateTables(tableIdentifiers []string) error
+}
+
+// TableStatisticsConnector is implemented by destinations that don't keep the
+// statistics of freshly loaded tables current on their own.
+type TableStatisticsConnector interface {
+	Connector
+
+	// RefreshTableStatistics refreshes the statistics of the tables with at least threshold rows
+	// loaded since they were last refreshed.
+	RefreshTableStatistics(tableIdentifiers []string, threshold uint32) error
+}
+
+// CDCPreflightConnector runs the checks that CREATE MIRROR makes on the peers of a
+// mirror before it starts the mirror, so that they fail with a MirrorError.
+type CDCPreflightConnector interface {
+	Connector
+
+	// CheckSlotCreation checks, on the source, that the replication slot of the mirror exists or can be created.
+	CheckSlotCreation(slotName string) error
+
+	// CheckTableTypes checks, on the destination, that an existing table has the column types of its source.
+	CheckTableTypes(tableIdentifier string, sourceSchema *protos.TableSchema) error
+
+	// ReplicationChecks checks the settings and permissions a source of a cdc mirror needs.
+	ReplicationChecks() ([]*protos.PeerValidationCheck, error)
+}
+
 type QRepPullConnector interface {
 	Connector
 
@@ -150,6 +211,14 @@ func GetCDCSyncConnector(ctx context.Context, config *protos.Peer) (CDCSyncConne
 		return conneventhub.NewEventHubConnector(ctx, config.GetEventhubGroupConfig())
 	case *protos.Peer_S3Config:
 		return conns3.NewS3Connector(ctx, config.GetS3Config())
+	case *protos.Peer_PineconeConfig:
+		return connpinecone.NewPineconeConnector(ctx, config.GetPineconeConfig())
+	case *protos.Peer_WeaviateConfig:
+		return connweaviate.NewWeaviateConnector(ctx, config.GetWeaviateConfig())
+	case *protos.Peer_FileConfig:
+		return connfile.NewFileConnector(ctx, config.GetFileConfig())
+	case *protos.Peer_WebhookConfig:
+		return connwebhook.NewWebhookConnector(ctx, config.GetWebhookConfig())
 	default:
 		return nil, ErrUnsupportedFunctionality
 	}
@@ -170,6 +239,54 @@ func GetCDCNormalizeConnector(ctx context.Context,
 	}
 }
 
+func GetCDCSchemaEventConnector(ctx context.Context,
+	config *protos.Peer) (CDCSchemaEventConnector, error) {
+	inner := config.Config
+	switch inner.(type) {
+	case *protos.Peer_EventhubGroupConfig:
+		return conneventhub.NewEventHubConnector(ctx, config.GetEventhubGroupConfig())
+	default:
+		return nil, ErrUnsupportedFunctionality
+	}
+}
+
+func GetCDCResyncConnector(ctx context.Context,
+	config *protos.Peer) (CDCResyncConnector, error) {
+	inner := config.Config
+	switch inner.(type) {
+	case *protos.Peer_PostgresConfig:
+		return connpostgres.NewPostgresConnector(ctx, config.GetPostgresConfig())
+	case *protos.Peer_BigqueryConfig:
+		return connbigquery.NewBigQueryConnector(ctx, config.GetBigqueryConfig())
+	case *protos.Peer_SnowflakeConfig:
+		return connsnowflake.NewSnowflakeConnector(ctx, config.GetSnowflakeConfig())
+	default:
+		return nil, ErrUnsupportedFunctionality
+	}
+}
+
+func GetTableStatisticsConnector(ctx context.Context,
+	config *protos.Peer) (TableStatisticsConnector, error) {
+	inner := config.Config
+	switch inner.(type) {
+	case *protos.Peer_PostgresConfig:
+		return connpostgres.NewPostgresConnector(ctx, config.GetPostgresConfig())
+	default:
+		return nil, ErrUnsupportedFunctionality
+	}
+}
+
+func GetCDCPreflightConnector(ctx context.Context,
+	config *protos.Peer) (CDCPreflightConnector, error) {
+	inner := config.Config
+	switch inner.(type) {
+	case *protos.Peer_PostgresConfig:
+		return connpostgres.NewPostgresConnector(ctx, config.GetPostgresConfig())
+	default:
+		return nil, ErrUnsupportedFunctionality
+	}
+}
+
 func GetQRepPullConnector(ctx context.Context, config *protos.Peer) (QRepPullConnector, error) {
 	inner := config.Config
 	switch inner.(type) {
@@ -193,6 +310,8 @@ func GetQRepSyncConnector(ctx context.Context, config *protos.Peer) (QRepSyncCon
 		return connsnowflake.NewSnowflakeConnector(ctx, config.GetSnowflakeConfig())
 	case *protos.Peer_S3Config:
 		return conns3.NewS3Connector(ctx, config.GetS3Config())
+	case *protos.Peer_FileConfig:
+		return connfile.NewFileConnector(ctx, config.GetFileConfig())
 	default:
 		return nil, ErrUnsupportedFunctionality
 	}
@@ -228,6 +347,30 @@ func GetConnector(ctx context.Context, peer *protos.Peer) (Connector, error) {
 			return nil, fmt.Errorf("missing sqlserver config for %s peer %s", peer.Type.String(), peer.Name)
 		}
 		return connsqlserver.NewSQLServerConnector(ctx, sqlServerConfig)
+	case protos.DBType_PINECONE:
+		pineconeConfig := peer.GetPineconeConfig()
+		if pineconeConfig == nil {
+			return nil, fmt.Errorf("missing pinecone config for %s peer %s", peer.Type.String(), peer.Name)
+		}
+		return connpinecone.NewPineconeConnector(ctx, pineconeConfig)
+	case protos.DBType_WEAVIATE:
+		weaviateConfig := peer.GetWeaviateConfig()
+		if weaviateConfig == nil {
+			return nil, fmt.Errorf("missing weaviate config for %s peer %s", peer.Type.String(), peer.Name)
+		}
+		return connweaviate.NewWeaviateConnector(ctx, weaviateConfig)
+	case protos.DBType_FILE:
+		fileConfig := peer.GetFileConfig()
+		if fileConfig == nil {
+			return nil, fmt.Errorf("missing file config for %s peer %s", peer.Type.String(), peer.Name)
+		}
+		return connfile.NewFileConnector(ctx, fileConfig)
+	case protos.DBType_WEBHOOK:
+		webhookConfig := peer.GetWebhookConfig()
+		if webhookConfig == nil {
+			return nil, fmt.Errorf("missing webhook config for %s peer %s", peer.Type.String(), peer.Name)
+		}
+		return connwebhook.NewWebhookConnector(ctx, webhookConfig)
 	// case protos.DBType_S3:
 	// 	return conns3.NewS3Connector(ctx, config.GetS3Config())
 	// case protos.DBType_EVENTHUB:
@@ -2,8 +2,10 @@ package conneventhub
 
 import (
 	"context"
+	"encoding/json"
 	"errors"
 	"fmt"
+	"strings"
 	"sync"
 	"sync/atomic"
 	"time"
@@ -122,12 +124,13 @@ func (c *EventHubConnector) updateLastOffset(jobName string, offset int64) error
 }
 
 func (c *EventHubConnector) processBatch(
-	flowJobName string,
-	batch *model.RecordBatch,
+	req *model.SyncRecordsRequest,
 	eventsPerBatch int,
 	maxParallelism int64,
 ) error {
 	ctx := context.Background()
+	flowJobName := req.FlowJobName
+	batch := req.Records
 
 	tableNameRowsMapping := cmap.New[uint32]()
 	batchPerTopic := NewHubBatches(c.hubManager)
@@ -162,7 +165,15 @@ func (c *EventHubConnector) processBatch(
 			return err
 		}
 
-		err = batchPerTopic.AddEvent(ctx, topicName, json)
+		partitionKey, err := c.partitionKey(req, record)
+		if err != nil {
+			log.WithFields(log.Fields{
+				"flowName": flowJobName,
+			}).Infof("failed to get partition key: %v", err)
+			return err
+		}
+
+		err = batchPerTopic.AddEvent(ctx, topicName, partitionKey, json)
 		if err != nil {
 			log.WithFields(log.Fields{
 				"flowName": flowJobName,
@@ -192,6 +203,41 @@ func (c *EventHubConnector) processBatch(
 	return nil
 }
 
+// partitionKey returns the key the record is delivered in order with, the values of the
+// ordering key columns of its row. Records of mirrors without an ordering key have none.
+func (c *EventHubConnector) partitionKey(req *model.SyncRecordsRequest, record model.Record) (string, error) {
+	destinationTable := model.DestinationTableName(record)
+	var keyColumns []string
+	switch req.OrderingKey {
+	case protos.OrderingKey_ORDERING_KEY_TABLE:
+		return destinationTable, nil
+	case protos.OrderingKey_ORDERING_KEY_PRIMARY_KEY:
+		tableSchema, ok := c.tableSchemas[destinationTable]
+		if !ok {
+			return "", fmt.Errorf("no schema for table %s", destinationTable)
+		}
+		keyColumns = tableSchema.PrimaryKeyColumns
+	case protos.OrderingKey_ORDERING_KEY_PARTITION_KEY:
+		partitionKey, ok := req.PartitionKeys[destinationTable]
+		if !ok {
+			return "", fmt.Errorf("no partition_key for table %s", destinationTable)
+		}
+		keyColumns = []string{partitionKey}
+	default:
+		return "", nil
+	}
+
+	keyValues := make([]string, 0, len(keyColumns))
+	for _, keyColumn := range keyColumns {
+		value, err := record.GetItems().GetValueByColName(keyColumn)
+		if err != nil {
+			return "", fmt.Errorf("failed to get key column of table %s: %w", destinationTable, err)
+		}
+		keyValues = append(keyValues, fmt.Sprint(value.Value))
+	}
+	return strings.Join(keyValues, ","), nil
+}
+
 func (c *EventHubConnector) SyncRecords(req *model.SyncRecordsRequest) (*model.SyncResponse, error) {
 	shutdown := utils.HeartbeatRoutine(c.ctx, 10*time.Second, func() string {
 		return fmt.Sprintf("syncing records to eventhub with"+
@@ -221,13 +267,13 @@ func (c *EventHubConnector) SyncRecords(req *model.SyncRecordsRequest) (*model.S
 	// otherwise, we block until processBatch is done.
 	if utils.GetEnvBool("PEERDB_BETA_EVENTHUB_PUSH_ASYNC", false) {
 		go func() {
-			err = c.processBatch(req.FlowJobName, batch, eventsPerBatch, maxParallelism)
+			err = c.processBatch(req, eventsPerBatch, maxParallelism)
 			if err != nil {
 				log.Errorf("[async] failed to process batch: %v", err)
 			}
 		}()
 	} else {
-		err = c.processBatch(req.FlowJobName, batch, eventsPerBatch, maxParallelism)
+		err = c.processBatch(req, eventsPerBatch, maxParallelism)
 		if err != nil {
 			log.Errorf("failed to process batch: %v", err)
 			return nil, err
@@ -276,33 +322,37 @@ func (c *EventHubConnector) sendEventBatch(
 	// Limiting concurrent sends
 	guard := make(chan struct{}, maxParallelism)
 
-	events.ForEach(func(tblName ScopedEventhub, eventBatch *azeventhubs.EventDataBatch) {
+	events.ForEach(func(tblName ScopedEventhub, eventBatches []*azeventhubs.EventDataBatch) {
 		guard <- struct{}{}
 		wg.Add(1)
-		go func(tblName ScopedEventhub, eventBatch *azeventhubs.EventDataBatch) {
+		go func(tblName ScopedEventhub, eventBatches []*azeventhubs.EventDataBatch) {
 			defer func() {
 				<-guard
 				wg.Done()
 			}()
 
-			numEvents := eventBatch.NumEvents()
-			err := c.sendBatch(ctx, tblName, eventBatch)
-			if err != nil {
-				once.Do(func() { firstErr = err })
-				return
-			}
-
-			atomic.AddInt32(&numEventsPushed, numEvents)
-			log.WithFields(log.Fields{
-				"flowName": flowName,
-			}).Infof("pushed %d events to event hub: %s", numEvents, tblName)
-			rowCount, ok := tableNameRowsMapping.Get(tblName.ToString())
-			if !ok {
-				rowCount = uint32(0)
+			// the batches of a partition key are sent in order, a batch is only sent once
+			// the ones before it were.
+			for _, eventBatch := range eventBatches {
+				numEvents := eventBatch.NumEvents()
+				err := c.sendBatch(ctx, tblName, eventBatch)
+				if err != nil {
+					once.Do(func() { firstErr = err })
+					return
+				}
+
+				atomic.AddInt32(&numEventsPushed, numEvents)
+				log.WithFields(log.Fields{
+					"flowName": flowName,
+				}).Infof("pushed %d events to event hub: %s", numEvents, tblName)
+				rowCount, ok := tableNameRowsMapping.Get(tblName.ToString())
+				if !ok {
+					rowCount = uint32(0)
+				}
+				rowCount += uint32(numEvents)
+				tableNameRowsMapping.Set(tblName.ToString(), rowCount)
 			}
-			rowCount += uint32(numEvents)
-			tableNameRowsMapping.Set(tblName.ToString(), rowCount)
-		}(tblName, eventBatch)
+		}(tblName, eventBatches)
 	})
 
 	wg.Wait()
@@ -386,3 +436,58 @@ func (c *EventHubConnector) SyncFlowCleanup(jobName string) error {
 	}
 	return nil
 }
+
+type schemaChangeEvent struct {
+	Kind             string               `json:"kind"`
+	FlowJobName      string               `json:"flow_job_name"`
+	SourceTable      string               `json:"source_table"`
+	DestinationTable string               `json:"destination_table"`
+	AddedColumns     []schemaChangeColumn `json:"added_columns"`
+}
+
+type schemaChangeColumn struct {
+	Name string `json:"name"`
+	Type string `json:"type"`
+}
+
+// PublishSchemaChangeEvents sends an event describing each schema delta to the
+// eventhub of its destination table.
+func (c *EventHubConnector) PublishSchemaChangeEvents(flowJobName string,
+	schemaDeltas []*protos.TableSchemaDelta) error {
+	batchPerTopic := NewHubBatches(c.hubManager)
+	for _, schemaDelta := range schemaDeltas {
+		if schemaDelta == nil || len(schemaDelta.AddedColumns) == 0 {
+			continue
+		}
+
+		topicName, err := NewScopedEventhub(schemaDelta.DstTableName)
+		if err != nil {
+			return fmt.Errorf("failed to get topic name for schema change event: %w", err)
+		}
+
+		event := schemaChangeEvent{
+			Kind:             "schema_change",
+			FlowJobName:      flowJobName,
+			SourceTable:      schemaDelta.SrcTableName,
+			DestinationTable: schemaDelta.DstTableName,
+			AddedColumns:     make([]schemaChangeColumn, 0, len(schemaDelta.AddedColumns)),
+		}
+		for _, addedColumn := range schemaDelta.AddedColumns {
+			event.AddedColumns = append(event.AddedColumns, schemaChangeColumn{
+				Name: addedColumn.ColumnName,
+				Type: addedColumn.ColumnType,
+			})
+		}
+		eventJSON, err := json.Marshal(event)
+		if err != nil {
+			return fmt.Errorf("failed to marshal schema change event: %w", err)
+		}
+
+		err = batchPerTopic.AddEvent(c.ctx, topicName, "", string(eventJSON))
+		if err != nil {
+			return fmt.Errorf("failed to add schema change event to batch: %w", err)
+		}
+	}
+
+	return c.sendEventBatch(c.ctx, batchPerTopic, 1, flowJobName, cmap.New[uint32]())
+}
@@ -0,0 +1,80 @@
+package conneventhub
+
+import (
+	"testing"
+
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+	"github.com/stretchr/testify/require"
+)
+
+func testConnector() *EventHubConnector {
+	return &EventHubConnector{
+		tableSchemas: map[string]*protos.TableSchema{
+			"eh.orders": {
+				TableIdentifier:   "eh.orders",
+				PrimaryKeyColumns: []string{"id", "region"},
+			},
+		},
+	}
+}
+
+func orderRow(customer string) *model.RecordItems {
+	items := model.NewRecordItems()
+	items.AddColumn("id", &qvalue.QValue{Kind: qvalue.QValueKindInt64, Value: int64(1)})
+	items.AddColumn("region", &qvalue.QValue{Kind: qvalue.QValueKindString, Value: "eu"})
+	items.AddColumn("customer", &qvalue.QValue{Kind: qvalue.QValueKindString, Value: customer})
+	return items
+}
+
+func insertRecord(destinationTable string) *model.InsertRecord {
+	return &model.InsertRecord{DestinationTableName: destinationTable, Items: orderRow("a")}
+}
+
+func TestPartitionKeyUnspecified(t *testing.T) {
+	c := testConnector()
+	req := &model.SyncRecordsRequest{}
+	key, err := c.partitionKey(req, insertRecord("eh.orders"))
+	require.NoError(t, err)
+	require.Empty(t, key)
+}
+
+func TestPartitionKeyTable(t *testing.T) {
+	c := testConnector()
+	req := &model.SyncRecordsRequest{OrderingKey: protos.OrderingKey_ORDERING_KEY_TABLE}
+	key, err := c.partitionKey(req, insertRecord("eh.orders"))
+	require.NoError(t, err)
+	require.Equal(t, "eh.orders", key)
+}
+
+func TestPartitionKeyPrimaryKey(t *testing.T) {
+	c := testConnector()
+	req := &model.SyncRecordsRequest{OrderingKey: protos.OrderingKey_ORDERING_KEY_PRIMARY_KEY}
+	insertKey, err := c.partitionKey(req, insertRecord("eh.orders"))
+	require.NoError(t, err)
+	require.Equal(t, "1,eu", insertKey)
+
+	// deletes are keyed by the destination table, not the source table they report.
+	deleteKey, err := c.partitionKey(req, &model.DeleteRecord{
+		SourceTableName:      "public.orders",
+		DestinationTableName: "eh.orders",
+		Items:                orderRow("b"),
+	})
+	require.NoError(t, err)
+	require.Equal(t, insertKey, deleteKey)
+}
+
+func TestPartitionKeyPartitionKey(t *testing.T) {
+	c := testConnector()
+	req := &model.SyncRecordsRequest{
+		OrderingKey:   protos.OrderingKey_ORDERING_KEY_PARTITION_KEY,
+		PartitionKeys: map[string]string{"eh.orders": "customer"},
+	}
+	key, err := c.partitionKey(req, insertRecord("eh.orders"))
+	require.NoError(t, err)
+	require.Equal(t, "a", key)
+
+	_, err = c.partitionKey(req, insertRecord("eh.customers"))
+	require.Error(t, err)
+}
@@ -8,27 +8,36 @@ import (
 	azeventhubs "github.com/Azure/azure-sdk-for-go/sdk/messaging/azeventhubs"
 )
 
-// multimap from ScopedEventhub to *azeventhubs.EventDataBatch
+// the batches of an eventhub are created for a partition key, the events of a key
+// land in order on the partition the key hashes to. Events without a key are
+// spread across the partitions.
+type hubBatchKey struct {
+	name         ScopedEventhub
+	partitionKey string
+}
+
+// multimap from ScopedEventhub and partition key to *azeventhubs.EventDataBatch
 type HubBatches struct {
-	batches map[ScopedEventhub][]*azeventhubs.EventDataBatch
+	batches map[hubBatchKey][]*azeventhubs.EventDataBatch
 	manager *EventHubManager
 }
 
 func NewHubBatches(manager *EventHubManager) *HubBatches {
 	return &HubBatches{
-		batches: make(map[ScopedEventhub][]*azeventhubs.EventDataBatch),
+		batches: make(map[hubBatchKey][]*azeventhubs.EventDataBatch),
 		manager: manager,
 	}
 }
 
-func (h *HubBatches) AddEvent(ctx context.Context, name ScopedEventhub, event string) error {
-	batches, ok := h.batches[name]
+func (h *HubBatches) AddEvent(ctx context.Context, name ScopedEventhub, partitionKey string, event string) error {
+	key := hubBatchKey{name: name, partitionKey: partitionKey}
+	batches, ok := h.batches[key]
 	if !ok {
 		batches = []*azeventhubs.EventDataBatch{}
 	}
 
 	if len(batches) == 0 {
-		newBatch, err := h.manager.CreateEventDataBatch(ctx, name)
+		newBatch, err := h.manager.CreateEventDataBatch(ctx, name, partitionKey)
 		if err != nil {
 			return err
 		}
@@ -37,7 +46,7 @@ func (h *HubBatches) AddEvent(ctx context.Context, name ScopedEventhub, event st
 
 	if err := tryAddEventToBatch(event, batches[len(batches)-1]); err != nil {
 		if strings.Contains(err.Error(), "too large for the batch") {
-			overflowBatch, err := h.handleBatchOverflow(ctx, name, event)
+			overflowBatch, err := h.handleBatchOverflow(ctx, key, event)
 			if err != nil {
 				return fmt.Errorf("failed to handle batch overflow: %v", err)
 			}
@@ -47,16 +56,16 @@ func (h *HubBatches) AddEvent(ctx context.Context, name ScopedEventhub, event st
 		}
 	}
 
-	h.batches[name] = batches
+	h.batches[key] = batches
 	return nil
 }
 
 func (h *HubBatches) handleBatchOverflow(
 	ctx context.Context,
-	name ScopedEventhub,
+	key hubBatchKey,
 	event string,
 ) (*azeventhubs.EventDataBatch, error) {
-	newBatch, err := h.manager.CreateEventDataBatch(ctx, name)
+	newBatch, err := h.manager.CreateEventDataBatch(ctx, key.name, key.partitionKey)
 	if err != nil {
 		return nil, err
 	}
@@ -70,18 +79,24 @@ func (h *HubBatches) Len() int {
 	return len(h.batches)
 }
 
-// ForEach calls the given function for each ScopedEventhub and batch pair
-func (h *HubBatches) ForEach(fn func(ScopedEventhub, *azeventhubs.EventDataBatch)) {
-	for name, batches := range h.batches {
+// ForEach calls the given function for each ScopedEventhub and the batches of it that
+// have to be sent one after the other, in order. Those are all the batches of a partition
+// key, and each batch of the events without a key on its own.
+func (h *HubBatches) ForEach(fn func(ScopedEventhub, []*azeventhubs.EventDataBatch)) {
+	for key, batches := range h.batches {
+		if key.partitionKey != "" {
+			fn(key.name, batches)
+			continue
+		}
 		for _, batch := range batches {
-			fn(name, batch)
+			fn(key.name, []*azeventhubs.EventDataBatch{batch})
 		}
 	}
 }
 
 // Clear removes all batches from the HubBatches
 func (h *HubBatches) Clear() {
-	h.batches = make(map[ScopedEventhub][]*azeventhubs.EventDataBatch)
+	h.batches = make(map[hubBatchKey][]*azeventhubs.EventDataBatch)
 }
 
 func tryAddEventToBatch(event string, batch *azeventhubs.EventDataBatch) error {
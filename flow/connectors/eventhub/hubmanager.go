@@ -67,13 +67,22 @@ func (m *EventHubManager) GetOrCreateHubClient(name ScopedEventhub) (*azeventhub
 	return hub.(*azeventhubs.ProducerClient), nil
 }
 
-func (m *EventHubManager) CreateEventDataBatch(ctx context.Context, name ScopedEventhub) (*azeventhubs.EventDataBatch, error) {
+// CreateEventDataBatch creates a batch of events for the eventhub, with the partition key
+// of the events when it isn't empty.
+func (m *EventHubManager) CreateEventDataBatch(
+	ctx context.Context,
+	name ScopedEventhub,
+	partitionKey string,
+) (*azeventhubs.EventDataBatch, error) {
 	hub, err := m.GetOrCreateHubClient(name)
 	if err != nil {
 		return nil, err
 	}
 
 	opts := &azeventhubs.EventDataBatchOptions{}
+	if partitionKey != "" {
+		opts.PartitionKey = &partitionKey
+	}
 	batch, err := hub.NewEventDataBatch(ctx, opts)
 	if err != nil {
 		return nil, fmt.Errorf("failed to create event data batch: %v", err)
@@ -0,0 +1,243 @@
+package connfile
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	metadataStore "github.com/PeerDB-io/peer-flow/connectors/external_metadata"
+	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	catalog "github.com/PeerDB-io/peer-flow/connectors/utils/catalog"
+	"github.com/PeerDB-io/peer-flow/connectors/utils/metrics"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	log "github.com/sirupsen/logrus"
+)
+
+// FileConnector writes the rows of mirrors to csv or parquet files under the directory of the peer, a
+// directory for each table with a file for each partition or, for cdc, each batch of changes. File peers
+// have no database to keep the state of their mirrors in, it is kept in the catalog.
+type FileConnector struct {
+	ctx        context.Context
+	config     *protos.FileConfig
+	pgMetadata *metadataStore.PostgresMetadataStore
+}
+
+func NewFileConnector(ctx context.Context,
+	config *protos.FileConfig) (*FileConnector, error) {
+	catalogConfig, err := catalog.GetCatalogPostgresConfigFromEnv()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get catalog config for file metadata: %w", err)
+	}
+	metadataSchemaName := "peerdb_file_metadata" // #nosec G101
+	pgMetadata, err := metadataStore.NewPostgresMetadataStore(ctx, catalogConfig, metadataSchemaName)
+	if err != nil {
+		log.Errorf("failed to create postgres metadata store: %v", err)
+		return nil, err
+	}
+
+	return &FileConnector{
+		ctx:        ctx,
+		config:     config,
+		pgMetadata: pgMetadata,
+	}, nil
+}
+
+func (c *FileConnector) Close() error {
+	return c.pgMetadata.Close()
+}
+
+// ConnectionActive checks that the directory of the peer exists and files can be created in it.
+func (c *FileConnector) ConnectionActive() bool {
+	probe, err := os.CreateTemp(c.config.Path, ".peerdb_probe_*")
+	if err != nil {
+		log.Errorf("failed to create file in %s: %v", c.config.Path, err)
+		return false
+	}
+	err = probe.Close()
+	if err == nil {
+		err = os.Remove(probe.Name())
+	}
+	if err != nil {
+		log.Errorf("failed to remove file %s: %v", probe.Name(), err)
+	}
+	return err == nil
+}
+
+func (c *FileConnector) NeedsSetupMetadataTables() bool {
+	return c.pgMetadata.NeedsSetupMetadata()
+}
+
+func (c *FileConnector) SetupMetadataTables() error {
+	err := c.pgMetadata.SetupMetadata()
+	if err != nil {
+		log.Errorf("failed to setup metadata tables: %v", err)
+		return err
+	}
+
+	return nil
+}
+
+func (c *FileConnector) GetLastSyncBatchID(jobName string) (int64, error) {
+	return c.pgMetadata.GetLastBatchID(jobName)
+}
+
+func (c *FileConnector) GetLastOffset(jobName string) (*protos.LastSyncState, error) {
+	return c.pgMetadata.FetchLastOffset(jobName)
+}
+
+func (c *FileConnector) InitializeTableSchema(req map[string]*protos.TableSchema) error {
+	log.Infof("InitializeTableSchema for file is a no-op")
+	return nil
+}
+
+func (c *FileConnector) CreateRawTable(req *protos.CreateRawTableInput) (*protos.CreateRawTableOutput, error) {
+	rawTable := getRawTableIdentifier(req.FlowJobName)
+	_, err := c.tableDir(rawTable)
+	if err != nil {
+		return nil, err
+	}
+	return &protos.CreateRawTableOutput{
+		TableIdentifier: rawTable,
+	}, nil
+}
+
+// SetupNormalizedTables is a no-op, changes are only written to the files of the raw table.
+func (c *FileConnector) SetupNormalizedTables(req *protos.SetupNormalizedTableBatchInput) (
+	*protos.SetupNormalizedTableBatchOutput, error) {
+	log.Infof("SetupNormalizedTables for file is a no-op")
+	return &protos.SetupNormalizedTableBatchOutput{
+		TableExistsMapping: nil,
+	}, nil
+}
+
+// SyncRecords writes the changes of the batch to a file of the raw table, as the raw records S3 peers
+// are written.
+func (c *FileConnector) SyncRecords(req *model.SyncRecordsRequest) (*model.SyncResponse, error) {
+	if len(req.Records.Records) == 0 {
+		return &model.SyncResponse{
+			FirstSyncedCheckPointID: 0,
+			LastSyncedCheckPointID:  0,
+			NumRecordsSynced:        0,
+		}, nil
+	}
+
+	syncBatchID, err := c.GetLastSyncBatchID(req.FlowJobName)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get previous syncBatchID: %w", err)
+	}
+	syncBatchID = syncBatchID + 1
+	lastCP := req.Records.LastCheckPointID
+
+	tableNameRowsMapping := make(map[string]uint32)
+	streamRes, err := utils.RecordsToRawTableStream(model.RecordsToStreamRequest{
+		Records:      req.Records.Records,
+		TableMapping: tableNameRowsMapping,
+		CP:           0,
+		BatchID:      syncBatchID,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert records to raw table stream: %w", err)
+	}
+	firstCP := streamRes.CP
+	recordStream := streamRes.Stream
+	qrepConfig := &protos.QRepConfig{
+		FlowJobName:                req.FlowJobName,
+		DestinationTableIdentifier: getRawTableIdentifier(req.FlowJobName),
+	}
+	partition := &protos.QRepPartition{
+		PartitionId: fmt.Sprint(syncBatchID),
+	}
+	startTime := time.Now()
+	close(recordStream.Records)
+	numRecords, err := c.SyncQRepRecords(qrepConfig, partition, recordStream)
+	if err != nil {
+		return nil, err
+	}
+
+	err = c.pgMetadata.UpdateLastOffset(req.FlowJobName, lastCP)
+	if err != nil {
+		log.Errorf("failed to update last offset for file cdc: %v", err)
+		return nil, err
+	}
+	err = c.pgMetadata.IncrementID(req.FlowJobName)
+	if err != nil {
+		log.Errorf("%v", err)
+		return nil, err
+	}
+	metrics.LogSyncMetrics(c.ctx, req.FlowJobName, int64(numRecords), time.Since(startTime))
+	return &model.SyncResponse{
+		FirstSyncedCheckPointID: firstCP,
+		LastSyncedCheckPointID:  lastCP,
+		NumRecordsSynced:        int64(numRecords),
+		TableNameRowsMapping:    tableNameRowsMapping,
+	}, nil
+}
+
+func (c *FileConnector) SyncFlowCleanup(jobName string) error {
+	return c.pgMetadata.DropMetadata(jobName)
+}
+
+// SetupQRepMetadataTables creates the directory of the destination table.
+func (c *FileConnector) SetupQRepMetadataTables(config *protos.QRepConfig) error {
+	_, err := c.tableDir(config.DestinationTableIdentifier)
+	return err
+}
+
+// SyncQRepRecords writes the partition to a file of the destination table named after the partition, so
+// that a partition that is synced again replaces its file.
+func (c *FileConnector) SyncQRepRecords(
+	config *protos.QRepConfig,
+	partition *protos.QRepPartition,
+	stream *model.QRecordStream,
+) (int, error) {
+	dir, err := c.tableDir(config.DestinationTableIdentifier)
+	if err != nil {
+		return 0, err
+	}
+	err = validateFileName(partition.PartitionId)
+	if err != nil {
+		return 0, fmt.Errorf("invalid partition id: %w", err)
+	}
+
+	path := filepath.Join(dir, fmt.Sprintf("%s.%s", partition.PartitionId, fileExtension(c.config.Format)))
+	numRecords, err := writeStream(stream, path, c.config.Format)
+	if err != nil {
+		log.WithFields(log.Fields{
+			"flowName":    config.FlowJobName,
+			"partitionID": partition.PartitionId,
+		}).Errorf("failed to write partition to %s: %v", path, err)
+		return 0, err
+	}
+	return numRecords, nil
+}
+
+// tableDir creates, if it doesn't exist, the directory the files of a table are written to.
+func (c *FileConnector) tableDir(table string) (string, error) {
+	err := validateFileName(table)
+	if err != nil {
+		return "", fmt.Errorf("invalid table name: %w", err)
+	}
+	dir := filepath.Join(c.config.Path, table)
+	err = os.MkdirAll(dir, 0o750)
+	if err != nil {
+		return "", fmt.Errorf("failed to create directory %s: %w", dir, err)
+	}
+	return dir, nil
+}
+
+// validateFileName checks that a table name or partition id names a file in a single directory, rather
+// than a path that could escape the directory of the peer.
+func validateFileName(name string) error {
+	if name == "" || name == "." || name == ".." || strings.ContainsAny(name, `/\`) {
+		return fmt.Errorf("%q is not a valid file name", name)
+	}
+	return nil
+}
+
+func getRawTableIdentifier(jobName string) string {
+	return fmt.Sprintf("raw_table_%s", jobName)
+}
@@ -0,0 +1,81 @@
+package connfile
+
+import (
+	"math/big"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+	"github.com/apache/arrow/go/v12/parquet/file"
+	"github.com/stretchr/testify/require"
+)
+
+func testStream(t *testing.T) *model.QRecordStream {
+	stream := model.NewQRecordStream(2)
+	err := stream.SetSchema(model.NewQRecordSchema([]*model.QField{
+		{Name: "id", Type: qvalue.QValueKindInt64, Nullable: false},
+		{Name: "title", Type: qvalue.QValueKindString, Nullable: true},
+		{Name: "price", Type: qvalue.QValueKindNumeric, Nullable: true},
+		{Name: "data", Type: qvalue.QValueKindBytes, Nullable: true},
+		{Name: "tags", Type: qvalue.QValueKindArrayString, Nullable: true},
+	}))
+	require.NoError(t, err)
+
+	first := model.NewQRecord(5)
+	first.Set(0, qvalue.QValue{Kind: qvalue.QValueKindInt64, Value: int64(1)})
+	first.Set(1, qvalue.QValue{Kind: qvalue.QValueKindString, Value: "a, \"quoted\" title"})
+	first.Set(2, qvalue.QValue{Kind: qvalue.QValueKindNumeric, Value: big.NewRat(5, 2)})
+	first.Set(3, qvalue.QValue{Kind: qvalue.QValueKindBytes, Value: []byte{0xde, 0xad}})
+	first.Set(4, qvalue.QValue{Kind: qvalue.QValueKindArrayString, Value: []string{"x", "y"}})
+	second := model.NewQRecord(5)
+	second.Set(0, qvalue.QValue{Kind: qvalue.QValueKindInt64, Value: int64(2)})
+	second.Set(1, qvalue.QValue{Kind: qvalue.QValueKindString, Value: nil})
+	second.Set(2, qvalue.QValue{Kind: qvalue.QValueKindNumeric, Value: nil})
+	second.Set(3, qvalue.QValue{Kind: qvalue.QValueKindBytes, Value: nil})
+	second.Set(4, qvalue.QValue{Kind: qvalue.QValueKindArrayString, Value: nil})
+
+	stream.Records <- &model.QRecordOrError{Record: first}
+	stream.Records <- &model.QRecordOrError{Record: second}
+	close(stream.Records)
+	return stream
+}
+
+func TestWriteCSV(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "1.csv")
+	numRecords, err := writeStream(testStream(t), path, protos.FileFormat_CSV)
+	require.NoError(t, err)
+	require.Equal(t, 2, numRecords)
+
+	contents, err := os.ReadFile(path)
+	require.NoError(t, err)
+	require.Equal(t, "id,title,price,data,tags\n"+
+		"1,\"a, \"\"quoted\"\" title\",2.500000000,\\xdead,\"[\"\"x\"\",\"\"y\"\"]\"\n"+
+		"2,,,,\n", string(contents))
+	_, err = os.Stat(path + ".tmp")
+	require.True(t, os.IsNotExist(err))
+}
+
+func TestWriteParquet(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "1.parquet")
+	numRecords, err := writeStream(testStream(t), path, protos.FileFormat_PARQUET)
+	require.NoError(t, err)
+	require.Equal(t, 2, numRecords)
+
+	reader, err := file.OpenParquetFile(path, false)
+	require.NoError(t, err)
+	defer reader.Close()
+	require.EqualValues(t, 2, reader.NumRows())
+	require.Equal(t, 5, reader.MetaData().Schema.NumColumns())
+}
+
+func TestValidateFileName(t *testing.T) {
+	require.NoError(t, validateFileName("public.users"))
+	require.NoError(t, validateFileName("3"))
+	require.Error(t, validateFileName(""))
+	require.Error(t, validateFileName(".."))
+	require.Error(t, validateFileName("../users"))
+	require.Error(t, validateFileName(`a\b`))
+}
@@ -0,0 +1,381 @@
+package connfile
+
+import (
+	"encoding/csv"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"math/big"
+	"os"
+	"time"
+
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+	"github.com/apache/arrow/go/v12/arrow"
+	"github.com/apache/arrow/go/v12/arrow/array"
+	"github.com/apache/arrow/go/v12/arrow/memory"
+	"github.com/apache/arrow/go/v12/parquet"
+	"github.com/apache/arrow/go/v12/parquet/compress"
+	"github.com/apache/arrow/go/v12/parquet/pqarrow"
+	"github.com/google/uuid"
+)
+
+// rows are written to parquet files in row groups of this many rows.
+const parquetRowGroupSize = 64 * 1024
+
+func fileExtension(format protos.FileFormat) string {
+	if format == protos.FileFormat_PARQUET {
+		return "parquet"
+	}
+	return "csv"
+}
+
+// writeStream writes the records of the stream to the file of the path, in the format. The file is
+// written next to the path and moved there once it is complete, so that scans never read part of a file
+// and a partition that is synced again replaces its file.
+func writeStream(stream *model.QRecordStream, path string, format protos.FileFormat) (int, error) {
+	schema, err := stream.Schema()
+	if err != nil {
+		return 0, fmt.Errorf("failed to get schema from stream: %w", err)
+	}
+
+	tmpPath := path + ".tmp"
+	file, err := os.Create(tmpPath)
+	if err != nil {
+		return 0, fmt.Errorf("failed to create file %s: %w", tmpPath, err)
+	}
+	defer os.Remove(tmpPath)
+
+	var numRecords int
+	if format == protos.FileFormat_PARQUET {
+		numRecords, err = writeParquet(file, stream, schema)
+	} else {
+		numRecords, err = writeCSV(file, stream, schema)
+	}
+	closeErr := file.Close()
+	if err != nil {
+		return 0, err
+	}
+	if closeErr != nil {
+		return 0, fmt.Errorf("failed to close file %s: %w", tmpPath, closeErr)
+	}
+
+	err = os.Rename(tmpPath, path)
+	if err != nil {
+		return 0, fmt.Errorf("failed to move file to %s: %w", path, err)
+	}
+	return numRecords, nil
+}
+
+func writeCSV(file *os.File, stream *model.QRecordStream, schema *model.QRecordSchema) (int, error) {
+	writer := csv.NewWriter(file)
+	header := make([]string, len(schema.Fields))
+	for i, field := range schema.Fields {
+		header[i] = field.Name
+	}
+	err := writer.Write(header)
+	if err != nil {
+		return 0, fmt.Errorf("failed to write csv header: %w", err)
+	}
+
+	numRecords := 0
+	row := make([]string, len(schema.Fields))
+	for recordOrErr := range stream.Records {
+		if recordOrErr.Err != nil {
+			return 0, fmt.Errorf("failed to read record from stream: %w", recordOrErr.Err)
+		}
+		for i, entry := range recordOrErr.Record.Entries {
+			row[i], err = textValue(entry)
+			if err != nil {
+				return 0, fmt.Errorf("failed to convert column %s: %w", schema.Fields[i].Name, err)
+			}
+		}
+		err = writer.Write(row)
+		if err != nil {
+			return 0, fmt.Errorf("failed to write csv row: %w", err)
+		}
+		numRecords++
+	}
+
+	writer.Flush()
+	if err := writer.Error(); err != nil {
+		return 0, fmt.Errorf("failed to write csv: %w", err)
+	}
+	return numRecords, nil
+}
+
+func writeParquet(file *os.File, stream *model.QRecordStream, schema *model.QRecordSchema) (int, error) {
+	fields := make([]arrow.Field, len(schema.Fields))
+	for i, field := range schema.Fields {
+		fields[i] = arrow.Field{Name: field.Name, Type: arrowType(field.Type), Nullable: true}
+	}
+	arrowSchema := arrow.NewSchema(fields, nil)
+
+	writer, err := pqarrow.NewFileWriter(arrowSchema, file,
+		parquet.NewWriterProperties(parquet.WithCompression(compress.Codecs.Snappy)),
+		pqarrow.DefaultWriterProps())
+	if err != nil {
+		return 0, fmt.Errorf("failed to create parquet writer: %w", err)
+	}
+	builder := array.NewRecordBuilder(memory.DefaultAllocator, arrowSchema)
+	defer builder.Release()
+
+	flush := func() error {
+		record := builder.NewRecord()
+		defer record.Release()
+		return writer.Write(record)
+	}
+
+	numRecords := 0
+	for recordOrErr := range stream.Records {
+		if recordOrErr.Err != nil {
+			return 0, fmt.Errorf("failed to read record from stream: %w", recordOrErr.Err)
+		}
+		for i, entry := range recordOrErr.Record.Entries {
+			err = appendValue(builder.Field(i), entry)
+			if err != nil {
+				return 0, fmt.Errorf("failed to convert column %s: %w", schema.Fields[i].Name, err)
+			}
+		}
+		numRecords++
+		if numRecords%parquetRowGroupSize == 0 {
+			err = flush()
+			if err != nil {
+				return 0, fmt.Errorf("failed to write parquet row group: %w", err)
+			}
+		}
+	}
+	if numRecords%parquetRowGroupSize != 0 {
+		err = flush()
+		if err != nil {
+			return 0, fmt.Errorf("failed to write parquet row group: %w", err)
+		}
+	}
+
+	err = writer.Close()
+	if err != nil {
+		return 0, fmt.Errorf("failed to close parquet writer: %w", err)
+	}
+	return numRecords, nil
+}
+
+// arrowType is the type a column is written to parquet files as, the values of kinds without an
+// equivalent are written as text.
+func arrowType(kind qvalue.QValueKind) arrow.DataType {
+	switch kind {
+	case qvalue.QValueKindBoolean:
+		return arrow.FixedWidthTypes.Boolean
+	case qvalue.QValueKindInt16:
+		return arrow.PrimitiveTypes.Int16
+	case qvalue.QValueKindInt32:
+		return arrow.PrimitiveTypes.Int32
+	case qvalue.QValueKindInt64:
+		return arrow.PrimitiveTypes.Int64
+	case qvalue.QValueKindFloat32:
+		return arrow.PrimitiveTypes.Float32
+	case qvalue.QValueKindFloat64:
+		return arrow.PrimitiveTypes.Float64
+	case qvalue.QValueKindBytes, qvalue.QValueKindBit:
+		return arrow.BinaryTypes.Binary
+	case qvalue.QValueKindDate:
+		return arrow.FixedWidthTypes.Date32
+	case qvalue.QValueKindTime:
+		return arrow.FixedWidthTypes.Time64us
+	case qvalue.QValueKindTimestamp:
+		return &arrow.TimestampType{Unit: arrow.Microsecond}
+	case qvalue.QValueKindTimestampTZ:
+		return &arrow.TimestampType{Unit: arrow.Microsecond, TimeZone: "UTC"}
+	case qvalue.QValueKindArrayInt32:
+		return arrow.ListOf(arrow.PrimitiveTypes.Int32)
+	case qvalue.QValueKindArrayInt64:
+		return arrow.ListOf(arrow.PrimitiveTypes.Int64)
+	case qvalue.QValueKindArrayFloat32:
+		return arrow.ListOf(arrow.PrimitiveTypes.Float32)
+	case qvalue.QValueKindArrayFloat64:
+		return arrow.ListOf(arrow.PrimitiveTypes.Float64)
+	case qvalue.QValueKindArrayString:
+		return arrow.ListOf(arrow.BinaryTypes.String)
+	default:
+		return arrow.BinaryTypes.String
+	}
+}
+
+func appendValue(builder array.Builder, value qvalue.QValue) error {
+	if value.Value == nil {
+		builder.AppendNull()
+		return nil
+	}
+
+	switch b := builder.(type) {
+	case *array.BooleanBuilder:
+		v, ok := value.Value.(bool)
+		if !ok {
+			return fmt.Errorf("invalid boolean value %v", value.Value)
+		}
+		b.Append(v)
+	case *array.Int16Builder:
+		v, err := intValue(value.Value)
+		if err != nil {
+			return err
+		}
+		b.Append(int16(v))
+	case *array.Int32Builder:
+		v, err := intValue(value.Value)
+		if err != nil {
+			return err
+		}
+		b.Append(int32(v))
+	case *array.Int64Builder:
+		v, err := intValue(value.Value)
+		if err != nil {
+			return err
+		}
+		b.Append(v)
+	case *array.Float32Builder:
+		v, err := floatValue(value.Value)
+		if err != nil {
+			return err
+		}
+		b.Append(float32(v))
+	case *array.Float64Builder:
+		v, err := floatValue(value.Value)
+		if err != nil {
+			return err
+		}
+		b.Append(v)
+	case *array.BinaryBuilder:
+		v, ok := value.Value.([]byte)
+		if !ok {
+			return fmt.Errorf("invalid bytes value %v", value.Value)
+		}
+		b.Append(v)
+	case *array.StringBuilder:
+		v, err := textValue(value)
+		if err != nil {
+			return err
+		}
+		b.Append(v)
+	case *array.Date32Builder:
+		t, ok := value.Value.(time.Time)
+		if !ok {
+			return fmt.Errorf("invalid date value %v", value.Value)
+		}
+		b.Append(arrow.Date32FromTime(t))
+	case *array.Time64Builder:
+		t, ok := value.Value.(time.Time)
+		if !ok {
+			return fmt.Errorf("invalid time value %v", value.Value)
+		}
+		sinceMidnight := time.Duration(t.Hour())*time.Hour + time.Duration(t.Minute())*time.Minute +
+			time.Duration(t.Second())*time.Second + time.Duration(t.Nanosecond())
+		b.Append(arrow.Time64(sinceMidnight.Microseconds()))
+	case *array.TimestampBuilder:
+		t, ok := value.Value.(time.Time)
+		if !ok {
+			return fmt.Errorf("invalid timestamp value %v", value.Value)
+		}
+		b.Append(arrow.Timestamp(t.UnixMicro()))
+	case *array.ListBuilder:
+		return appendList(b, value.Value)
+	default:
+		return fmt.Errorf("unsupported parquet column builder %T", builder)
+	}
+	return nil
+}
+
+func appendList(b *array.ListBuilder, value interface{}) error {
+	b.Append(true)
+	switch values := value.(type) {
+	case []int32:
+		elements := b.ValueBuilder().(*array.Int32Builder)
+		elements.AppendValues(values, nil)
+	case []int64:
+		elements := b.ValueBuilder().(*array.Int64Builder)
+		elements.AppendValues(values, nil)
+	case []float32:
+		elements := b.ValueBuilder().(*array.Float32Builder)
+		elements.AppendValues(values, nil)
+	case []float64:
+		elements := b.ValueBuilder().(*array.Float64Builder)
+		elements.AppendValues(values, nil)
+	case []string:
+		elements := b.ValueBuilder().(*array.StringBuilder)
+		elements.AppendValues(values, nil)
+	default:
+		return fmt.Errorf("invalid array value %v", value)
+	}
+	return nil
+}
+
+func intValue(value interface{}) (int64, error) {
+	switch v := value.(type) {
+	case int16:
+		return int64(v), nil
+	case int32:
+		return int64(v), nil
+	case int64:
+		return v, nil
+	case int:
+		return int64(v), nil
+	default:
+		return 0, fmt.Errorf("invalid integer value %v", value)
+	}
+}
+
+func floatValue(value interface{}) (float64, error) {
+	switch v := value.(type) {
+	case float32:
+		return float64(v), nil
+	case float64:
+		return v, nil
+	default:
+		return 0, fmt.Errorf("invalid float value %v", value)
+	}
+}
+
+// textValue is how a value is written to csv files, null is an empty field. Bytes are written the way
+// Postgres writes bytea, as hex with a \x prefix, and arrays as json.
+func textValue(value qvalue.QValue) (string, error) {
+	if value.Value == nil {
+		return "", nil
+	}
+
+	switch value.Kind {
+	case qvalue.QValueKindTime, qvalue.QValueKindTimeTZ, qvalue.QValueKindDate,
+		qvalue.QValueKindTimestamp, qvalue.QValueKindTimestampTZ:
+		if _, ok := value.Value.(time.Time); !ok {
+			return "", fmt.Errorf("invalid time value %v", value.Value)
+		}
+		return value.GoTimeConvert()
+	case qvalue.QValueKindNumeric:
+		num, ok := value.Value.(*big.Rat)
+		if !ok {
+			return "", fmt.Errorf("invalid numeric value %v", value.Value)
+		}
+		return num.FloatString(9), nil
+	case qvalue.QValueKindUUID:
+		switch v := value.Value.(type) {
+		case [16]byte:
+			return uuid.UUID(v).String(), nil
+		case uuid.UUID:
+			return v.String(), nil
+		}
+	case qvalue.QValueKindArrayInt32, qvalue.QValueKindArrayInt64, qvalue.QValueKindArrayFloat32,
+		qvalue.QValueKindArrayFloat64, qvalue.QValueKindArrayString:
+		encoded, err := json.Marshal(value.Value)
+		if err != nil {
+			return "", err
+		}
+		return string(encoded), nil
+	}
+
+	switch v := value.Value.(type) {
+	case string:
+		return v, nil
+	case []byte:
+		return `\x` + hex.EncodeToString(v), nil
+	default:
+		return fmt.Sprint(v), nil
+	}
+}
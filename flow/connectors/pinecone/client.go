@@ -0,0 +1,134 @@
+package connpinecone
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"time"
+
+	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+)
+
+const (
+	// Pinecone recommends upserting at most 100 vectors per request.
+	upsertBatchSize = 100
+	// the most ids a delete request takes.
+	deleteBatchSize = 1000
+	maxRetries      = 5
+	requestTimeout  = time.Minute
+)
+
+type vector struct {
+	ID       string                 `json:"id"`
+	Values   []float32              `json:"values,omitempty"`
+	Metadata map[string]interface{} `json:"metadata,omitempty"`
+}
+
+type upsertRequest struct {
+	Vectors   []*vector `json:"vectors"`
+	Namespace string    `json:"namespace,omitempty"`
+}
+
+type deleteRequest struct {
+	IDs       []string `json:"ids"`
+	Namespace string   `json:"namespace,omitempty"`
+}
+
+type updateRequest struct {
+	ID          string                 `json:"id"`
+	Values      []float32              `json:"values,omitempty"`
+	SetMetadata map[string]interface{} `json:"setMetadata,omitempty"`
+	Namespace   string                 `json:"namespace,omitempty"`
+}
+
+// pineconeClient talks to the data plane of a pod-based Pinecone index.
+type pineconeClient struct {
+	httpClient *http.Client
+	baseURL    string
+	apiKey     string
+	namespace  string
+}
+
+func newPineconeClient(config *protos.PineconeConfig) *pineconeClient {
+	return &pineconeClient{
+		httpClient: &http.Client{Timeout: requestTimeout},
+		baseURL: fmt.Sprintf("https://%s-%s.svc.%s.pinecone.io",
+			config.IndexName, config.ProjectId, config.Environment),
+		apiKey:    config.ApiKey,
+		namespace: config.Namespace,
+	}
+}
+
+func (c *pineconeClient) describeIndexStats(ctx context.Context) error {
+	return c.post(ctx, "/describe_index_stats", struct{}{})
+}
+
+func (c *pineconeClient) upsert(ctx context.Context, vectors []*vector) error {
+	for start := 0; start < len(vectors); start += upsertBatchSize {
+		end := start + upsertBatchSize
+		if end > len(vectors) {
+			end = len(vectors)
+		}
+		err := c.post(ctx, "/vectors/upsert", &upsertRequest{
+			Vectors:   vectors[start:end],
+			Namespace: c.namespace,
+		})
+		if err != nil {
+			return fmt.Errorf("failed to upsert vectors: %w", err)
+		}
+	}
+	return nil
+}
+
+func (c *pineconeClient) delete(ctx context.Context, ids []string) error {
+	for start := 0; start < len(ids); start += deleteBatchSize {
+		end := start + deleteBatchSize
+		if end > len(ids) {
+			end = len(ids)
+		}
+		err := c.post(ctx, "/vectors/delete", &deleteRequest{
+			IDs:       ids[start:end],
+			Namespace: c.namespace,
+		})
+		if err != nil {
+			return fmt.Errorf("failed to delete vectors: %w", err)
+		}
+	}
+	return nil
+}
+
+// update sets the values, if any, and the given metadata of a vector, leaving the rest of its metadata.
+func (c *pineconeClient) update(ctx context.Context, v *vector) error {
+	err := c.post(ctx, "/vectors/update", &updateRequest{
+		ID:          v.ID,
+		Values:      v.Values,
+		SetMetadata: v.Metadata,
+		Namespace:   c.namespace,
+	})
+	if err != nil {
+		return fmt.Errorf("failed to update vector %s: %w", v.ID, err)
+	}
+	return nil
+}
+
+// post sends a request to the index, retrying it when Pinecone is throttling or unavailable.
+func (c *pineconeClient) post(ctx context.Context, path string, body interface{}) error {
+	payload, err := json.Marshal(body)
+	if err != nil {
+		return fmt.Errorf("failed to marshal request: %w", err)
+	}
+
+	_, err = utils.SendHTTPRequest(ctx, c.httpClient, maxRetries, func() (*http.Request, error) {
+		req, err := http.NewRequest(http.MethodPost, c.baseURL+path, bytes.NewReader(payload))
+		if err != nil {
+			return nil, err
+		}
+		req.Header.Set("Api-Key", c.apiKey)
+		req.Header.Set("Content-Type", "application/json")
+		return req, nil
+	})
+	return err
+}
@@ -0,0 +1,262 @@
+package connpinecone
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"time"
+
+	metadataStore "github.com/PeerDB-io/peer-flow/connectors/external_metadata"
+	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	catalog "github.com/PeerDB-io/peer-flow/connectors/utils/catalog"
+	"github.com/PeerDB-io/peer-flow/connectors/utils/metrics"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	log "github.com/sirupsen/logrus"
+)
+
+// PineconeConnector mirrors rows to the vectors of a Pinecone index. Each row is a vector whose values are
+// the vector column, with the other columns, or the metadata columns of the peer, as its metadata.
+// Pinecone peers have no database to keep the state of their mirrors in, it is kept in the catalog.
+type PineconeConnector struct {
+	ctx          context.Context
+	config       *protos.PineconeConfig
+	client       *pineconeClient
+	pgMetadata   *metadataStore.PostgresMetadataStore
+	tableSchemas map[string]*protos.TableSchema
+}
+
+func NewPineconeConnector(ctx context.Context,
+	config *protos.PineconeConfig) (*PineconeConnector, error) {
+	if config.VectorColumn == "" {
+		return nil, errors.New("pinecone peer has no vector column")
+	}
+	catalogConfig, err := catalog.GetCatalogPostgresConfigFromEnv()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get catalog config for pinecone metadata: %w", err)
+	}
+	metadataSchemaName := "peerdb_pinecone_metadata" // #nosec G101
+	pgMetadata, err := metadataStore.NewPostgresMetadataStore(ctx, catalogConfig, metadataSchemaName)
+	if err != nil {
+		log.Errorf("failed to create postgres metadata store: %v", err)
+		return nil, err
+	}
+
+	return &PineconeConnector{
+		ctx:        ctx,
+		config:     config,
+		client:     newPineconeClient(config),
+		pgMetadata: pgMetadata,
+	}, nil
+}
+
+func (c *PineconeConnector) Close() error {
+	return c.pgMetadata.Close()
+}
+
+func (c *PineconeConnector) ConnectionActive() bool {
+	err := c.client.describeIndexStats(c.ctx)
+	if err != nil {
+		log.Errorf("failed to describe pinecone index %s: %v", c.config.IndexName, err)
+	}
+	return err == nil
+}
+
+func (c *PineconeConnector) NeedsSetupMetadataTables() bool {
+	return c.pgMetadata.NeedsSetupMetadata()
+}
+
+func (c *PineconeConnector) SetupMetadataTables() error {
+	err := c.pgMetadata.SetupMetadata()
+	if err != nil {
+		log.Errorf("failed to setup metadata tables: %v", err)
+		return err
+	}
+
+	return nil
+}
+
+func (c *PineconeConnector) GetLastSyncBatchID(jobName string) (int64, error) {
+	return c.pgMetadata.GetLastBatchID(jobName)
+}
+
+func (c *PineconeConnector) GetLastOffset(jobName string) (*protos.LastSyncState, error) {
+	return c.pgMetadata.FetchLastOffset(jobName)
+}
+
+func (c *PineconeConnector) InitializeTableSchema(req map[string]*protos.TableSchema) error {
+	c.tableSchemas = req
+	return nil
+}
+
+func (c *PineconeConnector) CreateRawTable(req *protos.CreateRawTableInput) (*protos.CreateRawTableOutput, error) {
+	log.Infof("CreateRawTable for pinecone is a no-op")
+	return &protos.CreateRawTableOutput{
+		TableIdentifier: "n/a",
+	}, nil
+}
+
+func (c *PineconeConnector) SetupNormalizedTables(req *protos.SetupNormalizedTableBatchInput) (
+	*protos.SetupNormalizedTableBatchOutput, error) {
+	for tableIdentifier, schema := range req.TableNameSchemaMapping {
+		if len(schema.PrimaryKeyColumns) == 0 {
+			return nil, fmt.Errorf("table %s has no primary key to identify its vectors by", tableIdentifier)
+		}
+		if _, ok := schema.Columns[c.config.VectorColumn]; !ok {
+			return nil, fmt.Errorf("table %s has no vector column %s", tableIdentifier, c.config.VectorColumn)
+		}
+	}
+	return &protos.SetupNormalizedTableBatchOutput{
+		TableExistsMapping: nil,
+	}, nil
+}
+
+// SyncRecords upserts the vectors of inserted and updated rows and deletes the ones of deleted rows.
+// Only the last change of each row in the batch is applied.
+func (c *PineconeConnector) SyncRecords(req *model.SyncRecordsRequest) (*model.SyncResponse, error) {
+	startTime := time.Now()
+	batch := req.Records
+
+	changes, err := c.collectChanges(batch.Records)
+	if err != nil {
+		return nil, err
+	}
+
+	// partial updates never follow a delete of their row in a batch, an insert would come in between.
+	err = c.client.delete(c.ctx, changes.Deletes)
+	if err != nil {
+		return nil, err
+	}
+	err = c.client.upsert(c.ctx, changes.Upserts)
+	if err != nil {
+		return nil, err
+	}
+	for _, v := range changes.Updates {
+		err = c.client.update(c.ctx, v)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	err = c.pgMetadata.UpdateLastOffset(req.FlowJobName, batch.LastCheckPointID)
+	if err != nil {
+		log.Errorf("failed to update last offset: %v", err)
+		return nil, err
+	}
+	err = c.pgMetadata.IncrementID(req.FlowJobName)
+	if err != nil {
+		log.Errorf("%v", err)
+		return nil, err
+	}
+
+	rowsSynced := int64(len(batch.Records))
+	metrics.LogSyncMetrics(c.ctx, req.FlowJobName, rowsSynced, time.Since(startTime))
+	metrics.LogNormalizeMetrics(c.ctx, req.FlowJobName, rowsSynced, time.Since(startTime), rowsSynced)
+	return &model.SyncResponse{
+		FirstSyncedCheckPointID: batch.FirstCheckPointID,
+		LastSyncedCheckPointID:  batch.LastCheckPointID,
+		NumRecordsSynced:        rowsSynced,
+		TableNameRowsMapping:    changes.TableNameRowsMapping,
+	}, nil
+}
+
+func (c *PineconeConnector) SyncFlowCleanup(jobName string) error {
+	return c.pgMetadata.DropMetadata(jobName)
+}
+
+func (c *PineconeConnector) collectChanges(records []model.Record) (*utils.RowChanges[vector], error) {
+	return utils.CollapseRowChanges(records,
+		func(destinationTable string, items *model.RecordItems, deleted bool, partial bool) (string, *vector, error) {
+			v, err := c.recordToVector(destinationTable, items)
+			if err != nil {
+				return "", nil, err
+			}
+			// a row without a vector has none in the index.
+			if deleted || (!partial && v.Values == nil) {
+				return v.ID, nil, nil
+			}
+			return v.ID, v, nil
+		},
+		func(earlier *vector, update *vector) {
+			if update.Values != nil {
+				earlier.Values = update.Values
+			}
+			for k, value := range update.Metadata {
+				earlier.Metadata[k] = value
+			}
+		})
+}
+
+// recordToVector returns the vector of a row, identified by its destination table and primary key.
+// The values and metadata are left out for deleted rows, and for columns that the change doesn't have.
+func (c *PineconeConnector) recordToVector(destinationTable string, items *model.RecordItems) (*vector, error) {
+	schema, ok := c.tableSchemas[destinationTable]
+	if !ok {
+		return nil, fmt.Errorf("schema of table %s is not known", destinationTable)
+	}
+
+	row, err := utils.RowValues(items)
+	if err != nil {
+		return nil, err
+	}
+	id, err := utils.RowKey(destinationTable, schema.PrimaryKeyColumns, row)
+	if err != nil {
+		return nil, err
+	}
+
+	v := &vector{
+		ID:       id,
+		Metadata: make(map[string]interface{}),
+	}
+	if value := items.GetColumnValue(c.config.VectorColumn); value != nil && value.Value != nil {
+		v.Values, err = utils.VectorValues(value)
+		if err != nil {
+			return nil, fmt.Errorf("invalid vector in column %s of table %s: %w",
+				c.config.VectorColumn, destinationTable, err)
+		}
+	}
+
+	metadataColumns := c.config.MetadataColumns
+	if len(metadataColumns) == 0 {
+		for col := range row {
+			if col != c.config.VectorColumn {
+				metadataColumns = append(metadataColumns, col)
+			}
+		}
+	}
+	for _, col := range metadataColumns {
+		value, ok := row[col]
+		// Pinecone has no null metadata, the field is left out.
+		if !ok || value == nil {
+			continue
+		}
+		v.Metadata[col] = metadataValue(value)
+	}
+	return v, nil
+}
+
+// metadataValue converts a value to one Pinecone metadata can hold, a string, number, boolean or list of
+// strings. Anything else is stored as its json.
+func metadataValue(value interface{}) interface{} {
+	switch v := value.(type) {
+	case string, json.Number, bool:
+		return v
+	case []interface{}:
+		values := make([]string, 0, len(v))
+		for _, element := range v {
+			if s, ok := element.(string); ok {
+				values = append(values, s)
+			} else {
+				values = append(values, fmt.Sprint(element))
+			}
+		}
+		return values
+	default:
+		encoded, err := json.Marshal(v)
+		if err != nil {
+			return fmt.Sprint(v)
+		}
+		return string(encoded)
+	}
+}
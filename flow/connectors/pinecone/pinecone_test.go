@@ -0,0 +1,96 @@
+package connpinecone
+
+import (
+	"testing"
+
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+	"github.com/stretchr/testify/require"
+)
+
+func testConnector() *PineconeConnector {
+	return &PineconeConnector{
+		config: &protos.PineconeConfig{VectorColumn: "embedding"},
+		tableSchemas: map[string]*protos.TableSchema{
+			"docs": {
+				TableIdentifier:   "docs",
+				PrimaryKeyColumns: []string{"id"},
+			},
+		},
+	}
+}
+
+func row(id int64, embedding string, title string) *model.RecordItems {
+	items := model.NewRecordItems()
+	items.AddColumn("id", &qvalue.QValue{Kind: qvalue.QValueKindInt64, Value: id})
+	if embedding != "" {
+		items.AddColumn("embedding", &qvalue.QValue{Kind: qvalue.QValueKindString, Value: embedding})
+	}
+	if title != "" {
+		items.AddColumn("title", &qvalue.QValue{Kind: qvalue.QValueKindString, Value: title})
+	}
+	return items
+}
+
+func TestCollectChangesKeepsLastChange(t *testing.T) {
+	c := testConnector()
+	records := []model.Record{
+		&model.InsertRecord{DestinationTableName: "docs", Items: row(1, "[1,2]", "a")},
+		&model.InsertRecord{DestinationTableName: "docs", Items: row(2, "[3,4]", "b")},
+		&model.UpdateRecord{DestinationTableName: "docs", NewItems: row(1, "[5,6]", "c")},
+		&model.DeleteRecord{DestinationTableName: "docs", Items: row(2, "", "")},
+	}
+
+	changes, err := c.collectChanges(records)
+	require.NoError(t, err)
+	require.Equal(t, map[string]uint32{"docs": 4}, changes.TableNameRowsMapping)
+	require.Equal(t, []string{"docs:2"}, changes.Deletes)
+	require.Len(t, changes.Upserts, 1)
+	require.Equal(t, "docs:1", changes.Upserts[0].ID)
+	require.Equal(t, []float32{5, 6}, changes.Upserts[0].Values)
+	require.Equal(t, "c", changes.Upserts[0].Metadata["title"])
+	require.NotContains(t, changes.Upserts[0].Metadata, "embedding")
+	require.Empty(t, changes.Updates)
+}
+
+func TestCollectChangesUnchangedToastColumns(t *testing.T) {
+	c := testConnector()
+	records := []model.Record{
+		// the embedding is toasted and unchanged, only the title can be updated.
+		&model.UpdateRecord{
+			DestinationTableName:  "docs",
+			NewItems:              row(1, "", "a"),
+			UnchangedToastColumns: map[string]struct{}{"embedding": {}},
+		},
+		&model.InsertRecord{DestinationTableName: "docs", Items: row(2, "[1,2]", "b")},
+		&model.UpdateRecord{
+			DestinationTableName:  "docs",
+			NewItems:              row(2, "", "c"),
+			UnchangedToastColumns: map[string]struct{}{"embedding": {}},
+		},
+	}
+
+	changes, err := c.collectChanges(records)
+	require.NoError(t, err)
+	require.Empty(t, changes.Deletes)
+	require.Len(t, changes.Upserts, 1)
+	require.Equal(t, []float32{1, 2}, changes.Upserts[0].Values)
+	require.Equal(t, "c", changes.Upserts[0].Metadata["title"])
+	require.Len(t, changes.Updates, 1)
+	require.Equal(t, "docs:1", changes.Updates[0].ID)
+	require.Nil(t, changes.Updates[0].Values)
+	require.Equal(t, "a", changes.Updates[0].Metadata["title"])
+}
+
+func TestCollectChangesRowWithoutVector(t *testing.T) {
+	c := testConnector()
+	records := []model.Record{
+		&model.InsertRecord{DestinationTableName: "docs", Items: row(1, "", "a")},
+	}
+
+	changes, err := c.collectChanges(records)
+	require.NoError(t, err)
+	require.Equal(t, []string{"docs:1"}, changes.Deletes)
+	require.Empty(t, changes.Upserts)
+}
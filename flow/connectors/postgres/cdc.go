@@ -25,13 +25,16 @@ type PostgresCDCSource struct {
 	replPool               *pgxpool.Pool
 	SrcTableIDNameMapping  map[uint32]string
 	TableNameMapping       map[string]string
+	excludedColumns        map[string]map[string]struct{}
 	slot                   string
 	publication            string
 	relationMessageMapping model.RelationMessageMapping
 	typeMap                *pgtype.Map
 	startLSN               pglogrepl.LSN
 	commitLock             bool
+	commitTime             time.Time
 	customTypeMapping      map[uint32]string
+	types                  *postgresTypes
 }
 
 type PostgresCDCConfig struct {
@@ -41,22 +44,26 @@ type PostgresCDCConfig struct {
 	Publication            string
 	SrcTableIDNameMapping  map[uint32]string
 	TableNameMapping       map[string]string
+	ExcludedColumns        map[string]map[string]struct{}
 	RelationMessageMapping model.RelationMessageMapping
 }
 
 // Create a new PostgresCDCSource
-func NewPostgresCDCSource(cdcConfig *PostgresCDCConfig, customTypeMap map[uint32]string) (*PostgresCDCSource, error) {
+func NewPostgresCDCSource(cdcConfig *PostgresCDCConfig, customTypeMap map[uint32]string,
+	types *postgresTypes) (*PostgresCDCSource, error) {
 	return &PostgresCDCSource{
 		ctx:                    cdcConfig.AppContext,
 		replPool:               cdcConfig.Connection,
 		SrcTableIDNameMapping:  cdcConfig.SrcTableIDNameMapping,
 		TableNameMapping:       cdcConfig.TableNameMapping,
+		excludedColumns:        cdcConfig.ExcludedColumns,
 		slot:                   cdcConfig.Slot,
 		publication:            cdcConfig.Publication,
 		relationMessageMapping: cdcConfig.RelationMessageMapping,
 		typeMap:                pgtype.NewMap(),
 		commitLock:             false,
 		customTypeMapping:      customTypeMap,
+		types:                  types,
 	}, nil
 }
 
@@ -318,6 +325,7 @@ func (p *PostgresCDCSource) processMessage(batch *model.RecordBatch, xld pglogre
 		log.Debugf("BeginMessage => FinalLSN: %v, XID: %v", msg.FinalLSN, msg.Xid)
 		log.Debugf("Locking PullRecords at BeginMessage, awaiting CommitMessage")
 		p.commitLock = true
+		p.commitTime = msg.CommitTime
 	case *pglogrepl.InsertMessage:
 		return p.processInsertMessage(xld.WALStart, msg)
 	case *pglogrepl.UpdateMessage:
@@ -380,6 +388,7 @@ func (p *PostgresCDCSource) processInsertMessage(
 		Items:                items,
 		DestinationTableName: p.TableNameMapping[tableName],
 		SourceTableName:      tableName,
+		CommitTime:           p.commitTime,
 	}, nil
 }
 
@@ -419,6 +428,7 @@ func (p *PostgresCDCSource) processUpdateMessage(
 		DestinationTableName:  p.TableNameMapping[tableName],
 		SourceTableName:       tableName,
 		UnchangedToastColumns: unchangedToastColumns,
+		CommitTime:            p.commitTime,
 	}, nil
 }
 
@@ -451,6 +461,7 @@ func (p *PostgresCDCSource) processDeleteMessage(
 		Items:                items,
 		DestinationTableName: p.TableNameMapping[tableName],
 		SourceTableName:      tableName,
+		CommitTime:           p.commitTime,
 	}, nil
 }
 
@@ -476,10 +487,16 @@ func (p *PostgresCDCSource) convertTupleToMap(
 
 	for idx, col := range tuple.Columns {
 		colName := rel.Columns[idx].Name
+		if p.isExcluded(rel.RelationId, colName) {
+			continue
+		}
 		switch col.DataType {
 		case 'n': // null
 			val := &qvalue.QValue{Kind: qvalue.QValueKindInvalid, Value: nil}
 			items.AddColumn(colName, val)
+			if currencyColumn, ok := p.types.currencyColumn(colName, rel.Columns[idx].DataType); ok {
+				items.AddColumn(currencyColumn, p.types.currencyValue(true))
+			}
 		case 't': // text
 			/* bytea also appears here as a hex */
 			data, err := p.decodeColumnData(col.Data, rel.Columns[idx].DataType, pgtype.TextFormatCode)
@@ -487,6 +504,9 @@ func (p *PostgresCDCSource) convertTupleToMap(
 				return nil, nil, fmt.Errorf("error decoding text column data: %w", err)
 			}
 			items.AddColumn(colName, data)
+			if currencyColumn, ok := p.types.currencyColumn(colName, rel.Columns[idx].DataType); ok {
+				items.AddColumn(currencyColumn, p.types.currencyValue(false))
+			}
 		case 'b': // binary
 			data, err := p.decodeColumnData(col.Data, rel.Columns[idx].DataType, pgtype.BinaryFormatCode)
 			if err != nil {
@@ -502,9 +522,23 @@ func (p *PostgresCDCSource) convertTupleToMap(
 	return items, unchangedToastColumns, nil
 }
 
+// isExcluded is whether the table mapping of the relation excludes the column from replication.
+func (p *PostgresCDCSource) isExcluded(relID uint32, colName string) bool {
+	_, ok := p.excludedColumns[p.SrcTableIDNameMapping[relID]][colName]
+	return ok
+}
+
 func (p *PostgresCDCSource) decodeColumnData(data []byte, dataType uint32, formatCode int16) (*qvalue.QValue, error) {
 	var parsedData any
 	var err error
+	dataType = p.types.baseType(dataType)
+	// money is always sent in the text format as pgoutput runs without the binary option.
+	if p.types.isNumericMoney(dataType) {
+		return p.types.moneyValue(string(data))
+	}
+	if p.types.isComposite(dataType) {
+		return p.types.compositeValue(dataType, string(data))
+	}
 	if dt, ok := p.typeMap.TypeForOID(dataType); ok {
 		if dt.Name == "uuid" {
 			// below is required to decode uuid to string
@@ -590,11 +624,15 @@ func (p *PostgresCDCSource) processRelationMessage(
 		AddedColumns: make([]*protos.DeltaAddedColumn, 0),
 	}
 	for _, column := range currRel.Columns {
+		if p.isExcluded(currRel.RelationId, column.Name) {
+			continue
+		}
 		// not present in previous relation message, but in current one, so added.
 		if prevRelMap[column.Name] == nil {
-			qKind := postgresOIDToQValueKind(column.DataType)
+			dataType := p.types.baseType(column.DataType)
+			qKind := p.types.qValueKind(dataType)
 			if qKind == qvalue.QValueKindInvalid {
-				typeName, ok := p.customTypeMapping[column.DataType]
+				typeName, ok := p.customTypeMapping[dataType]
 				if ok {
 					qKind = customTypeToQKind(typeName)
 				}
@@ -603,6 +641,12 @@ func (p *PostgresCDCSource) processRelationMessage(
 				ColumnName: column.Name,
 				ColumnType: string(qKind),
 			})
+			if currencyColumn, ok := p.types.currencyColumn(column.Name, dataType); ok {
+				schemaDelta.AddedColumns = append(schemaDelta.AddedColumns, &protos.DeltaAddedColumn{
+					ColumnName: currencyColumn,
+					ColumnType: string(qvalue.QValueKindString),
+				})
+			}
 			// present in previous and current relation messages, but data types have changed.
 			// so we add it to AddedColumns and DroppedColumns, knowing that we process DroppedColumns first.
 		} else if prevRelMap[column.Name].RelId != currRelMap[column.Name].RelId {
@@ -5,6 +5,7 @@ import (
 	"fmt"
 	"regexp"
 	"strings"
+	"time"
 
 	"github.com/PeerDB-io/peer-flow/connectors/utils"
 	"github.com/PeerDB-io/peer-flow/generated/protos"
@@ -34,6 +35,15 @@ const (
 	getLastSyncBatchID_SQL      = "SELECT sync_batch_id FROM %s.%s WHERE mirror_job_name=$1"
 	getLastNormalizeBatchID_SQL = "SELECT normalize_batch_id FROM %s.%s WHERE mirror_job_name=$1"
 	createNormalizedTableSQL    = "CREATE TABLE IF NOT EXISTS %s(%s)"
+	createTypeSQL               = "CREATE TYPE %s AS %s"
+	getUserDefinedColumnsSQL    = `SELECT a.attname,format_type(a.atttypid,NULL),t.typtype='c' FROM pg_attribute a
+		JOIN pg_type t ON t.oid=a.atttypid WHERE a.attrelid=$1::regclass AND a.attnum>0 AND NOT a.attisdropped
+		AND t.typtype IN ('e','c')`
+	getModifiedRowsSinceAnalyzeSQL = `SELECT n_mod_since_analyze FROM pg_stat_user_tables
+		WHERE schemaname=$1 AND relname=$2`
+	analyzeTableSQL      = "ANALYZE %s"
+	getTablesInSchemaSQL = `SELECT c.relname FROM pg_class c JOIN pg_namespace n ON n.oid=c.relnamespace
+		WHERE c.relkind IN ('r','p') AND NOT c.relispartition AND n.nspname=$1 ORDER BY 1`
 
 	insertJobMetadataSQL                 = "INSERT INTO %s.%s VALUES ($1,$2,$3,$4)"
 	checkIfJobMetadataExistsSQL          = "SELECT COUNT(1)::TEXT::BOOL FROM %s.%s WHERE mirror_job_name=$1"
@@ -81,7 +91,7 @@ func (c *PostgresConnector) getRelIDForTable(schemaTable *SchemaTable) (uint32,
 	err := c.pool.QueryRow(c.ctx,
 		`SELECT c.oid FROM pg_class c JOIN pg_namespace n
 		 ON n.oid = c.relnamespace WHERE n.nspname = $1 AND c.relname = $2`,
-		strings.ToLower(schemaTable.Schema), strings.ToLower(schemaTable.Table)).Scan(&relID)
+		schemaTable.Schema, schemaTable.Table).Scan(&relID)
 	if err != nil {
 		return 0, fmt.Errorf("error getting relation ID for table %s: %w", schemaTable, err)
 	}
@@ -216,7 +226,7 @@ func (c *PostgresConnector) createSlotAndPublication(
 	*/
 	srcTableNames := make([]string, 0, len(tableNameMapping))
 	for srcTableName := range tableNameMapping {
-		if len(strings.Split(srcTableName, ".")) != 2 {
+		if _, err := parseSchemaTable(srcTableName); err != nil {
 			return fmt.Errorf("source tables identifier is invalid: %v", srcTableName)
 		}
 		srcTableNames = append(srcTableNames, srcTableName)
@@ -303,12 +313,15 @@ func getRawTableIdentifier(jobName string) string {
 	return fmt.Sprintf("%s_%s", rawTablePrefix, strings.ToLower(jobName))
 }
 
-func generateCreateTableSQLForNormalizedTable(sourceTableIdentifier string,
+func generateCreateTableSQLForNormalizedTable(sourceTableIdentifier string, typeSchema string,
 	sourceTableSchema *protos.TableSchema) string {
 	createTableSQLArray := make([]string, 0, len(sourceTableSchema.Columns))
 	for columnName, genericColumnType := range sourceTableSchema.Columns {
-		createTableSQLArray = append(createTableSQLArray, fmt.Sprintf("\"%s\" %s,", columnName,
-			qValueKindToPostgresType(genericColumnType)))
+		pgType := qValueKindToPostgresType(genericColumnType)
+		if userDefinedType, ok := sourceTableSchema.ColumnTypes[columnName]; ok {
+			pgType = userDefinedTypeIdentifier(typeSchema, userDefinedType)
+		}
+		createTableSQLArray = append(createTableSQLArray, fmt.Sprintf("\"%s\" %s,", columnName, pgType))
 	}
 
 	// add composite primary key to the table
@@ -324,6 +337,89 @@ func generateCreateTableSQLForNormalizedTable(sourceTableIdentifier string,
 		strings.TrimSuffix(strings.Join(createTableSQLArray, ""), ","))
 }
 
+func userDefinedTypeIdentifier(typeSchema string, pgType *protos.PostgresType) string {
+	return fmt.Sprintf(`"%s"."%s"`, typeSchema, pgType.Name)
+}
+
+// createUserDefinedTypes creates the enum and composite types of the columns of a table, types that
+// already exist are left as they are.
+func (c *PostgresConnector) createUserDefinedTypes(tx pgx.Tx, typeSchema string,
+	tableSchema *protos.TableSchema) error {
+	for _, pgType := range tableSchema.ColumnTypes {
+		typeIdentifier := userDefinedTypeIdentifier(typeSchema, pgType)
+		var exists bool
+		err := tx.QueryRow(c.ctx, "SELECT to_regtype($1) IS NOT NULL", typeIdentifier).Scan(&exists)
+		if err != nil {
+			return fmt.Errorf("error checking if type %s exists: %w", typeIdentifier, err)
+		}
+		if exists {
+			continue
+		}
+
+		var definition string
+		if len(pgType.Attributes) > 0 {
+			attributes := make([]string, 0, len(pgType.Attributes))
+			for _, attribute := range pgType.Attributes {
+				attributes = append(attributes, fmt.Sprintf(`"%s" %s`, attribute.Name, attribute.Type))
+			}
+			definition = fmt.Sprintf("(%s)", strings.Join(attributes, ","))
+		} else {
+			labels := make([]string, 0, len(pgType.EnumLabels))
+			for _, label := range pgType.EnumLabels {
+				labels = append(labels, fmt.Sprintf("'%s'", strings.ReplaceAll(label, "'", "''")))
+			}
+			definition = fmt.Sprintf("ENUM (%s)", strings.Join(labels, ","))
+		}
+		_, err = tx.Exec(c.ctx, fmt.Sprintf(createTypeSQL, typeIdentifier, definition))
+		if err != nil {
+			return fmt.Errorf("error while creating type %s: %w", typeIdentifier, err)
+		}
+	}
+	return nil
+}
+
+// userDefinedColumn is a column of a destination table that is of an enum or composite type.
+type userDefinedColumn struct {
+	typeName  string
+	composite bool
+}
+
+// getUserDefinedColumns returns the columns of a table that are of enum or composite types, by name.
+func (c *PostgresConnector) getUserDefinedColumns(tableIdentifier string) (map[string]userDefinedColumn, error) {
+	rows, err := c.pool.Query(c.ctx, getUserDefinedColumnsSQL, tableIdentifier)
+	if err != nil {
+		return nil, fmt.Errorf("error getting enum and composite columns of %s: %w", tableIdentifier, err)
+	}
+	defer rows.Close()
+
+	userDefinedColumns := make(map[string]userDefinedColumn)
+	for rows.Next() {
+		var columnName string
+		var column userDefinedColumn
+		if err := rows.Scan(&columnName, &column.typeName, &column.composite); err != nil {
+			return nil, fmt.Errorf("error scanning enum and composite columns of %s: %w", tableIdentifier, err)
+		}
+		userDefinedColumns[columnName] = column
+	}
+	return userDefinedColumns, rows.Err()
+}
+
+// rawColumnCastSQL reads a column from the _peerdb_data of the raw table as its destination type, composite
+// values are replicated as json objects.
+func rawColumnCastSQL(columnName string, genericColumnType string,
+	userDefinedColumns map[string]userDefinedColumn) string {
+	columnName = strings.Trim(columnName, "\"")
+	column, ok := userDefinedColumns[columnName]
+	switch {
+	case ok && column.composite:
+		return fmt.Sprintf("json_populate_record(NULL::%s,(_peerdb_data->>'%s')::JSON)", column.typeName, columnName)
+	case ok:
+		return fmt.Sprintf("(_peerdb_data->>'%s')::%s", columnName, column.typeName)
+	default:
+		return fmt.Sprintf("(_peerdb_data->>'%s')::%s", columnName, qValueKindToPostgresType(genericColumnType))
+	}
+}
+
 func (c *PostgresConnector) GetLastSyncBatchID(jobName string) (int64, error) {
 	rows, err := c.pool.Query(c.ctx, fmt.Sprintf(
 		getLastSyncBatchID_SQL,
@@ -470,28 +566,29 @@ func (c *PostgresConnector) getTableNametoUnchangedCols(flowJobName string, sync
 }
 
 func (c *PostgresConnector) generateNormalizeStatements(destinationTableIdentifier string,
-	unchangedToastColumns []string, rawTableIdentifier string, supportsMerge bool) []string {
+	unchangedToastColumns []string, rawTableIdentifier string, supportsMerge bool,
+	userDefinedColumns map[string]userDefinedColumn) []string {
 	if supportsMerge {
-		return []string{c.generateMergeStatement(destinationTableIdentifier, unchangedToastColumns, rawTableIdentifier)}
+		return []string{c.generateMergeStatement(destinationTableIdentifier, unchangedToastColumns, rawTableIdentifier,
+			userDefinedColumns)}
 	}
 	log.Warnf("Postgres version is not high enough to support MERGE, falling back to UPSERT + DELETE")
 	log.Warnf("TOAST columns will not be updated properly, use REPLICA IDENTITY FULL or upgrade Postgres")
-	return c.generateFallbackStatements(destinationTableIdentifier, rawTableIdentifier)
+	return c.generateFallbackStatements(destinationTableIdentifier, rawTableIdentifier, userDefinedColumns)
 }
 
 func (c *PostgresConnector) generateFallbackStatements(destinationTableIdentifier string,
-	rawTableIdentifier string) []string {
+	rawTableIdentifier string, userDefinedColumns map[string]userDefinedColumn) []string {
 	normalizedTableSchema := c.tableSchemaMapping[destinationTableIdentifier]
 	columnNames := make([]string, 0, len(normalizedTableSchema.Columns))
 	flattenedCastsSQLArray := make([]string, 0, len(normalizedTableSchema.Columns))
 	primaryKeyColumnCasts := make(map[string]string)
 	for columnName, genericColumnType := range normalizedTableSchema.Columns {
 		columnNames = append(columnNames, fmt.Sprintf("\"%s\"", columnName))
-		pgType := qValueKindToPostgresType(genericColumnType)
-		flattenedCastsSQLArray = append(flattenedCastsSQLArray, fmt.Sprintf("(_peerdb_data->>'%s')::%s AS \"%s\"",
-			columnName, pgType, columnName))
+		columnCast := rawColumnCastSQL(columnName, genericColumnType, userDefinedColumns)
+		flattenedCastsSQLArray = append(flattenedCastsSQLArray, fmt.Sprintf("%s AS \"%s\"", columnCast, columnName))
 		if slices.Contains(normalizedTableSchema.PrimaryKeyColumns, columnName) {
-			primaryKeyColumnCasts[columnName] = fmt.Sprintf("(_peerdb_data->>'%s')::%s", columnName, pgType)
+			primaryKeyColumnCasts[columnName] = columnCast
 		}
 	}
 	flattenedCastsSQL := strings.TrimSuffix(strings.Join(flattenedCastsSQLArray, ","), ",")
@@ -521,7 +618,7 @@ func (c *PostgresConnector) generateFallbackStatements(destinationTableIdentifie
 }
 
 func (c *PostgresConnector) generateMergeStatement(destinationTableIdentifier string, unchangedToastColumns []string,
-	rawTableIdentifier string) string {
+	rawTableIdentifier string, userDefinedColumns map[string]userDefinedColumn) string {
 	normalizedTableSchema := c.tableSchemaMapping[destinationTableIdentifier]
 	columnNames := maps.Keys(normalizedTableSchema.Columns)
 	for i, columnName := range columnNames {
@@ -538,11 +635,11 @@ func (c *PostgresConnector) generateMergeStatement(destinationTableIdentifier st
 				fmt.Sprintf("ARRAY(SELECT * FROM JSON_ARRAY_ELEMENTS_TEXT((_peerdb_data->>'%s')::JSON))::%s AS \"%s\"",
 					strings.Trim(columnName, "\""), pgType, columnName))
 		} else {
-			flattenedCastsSQLArray = append(flattenedCastsSQLArray, fmt.Sprintf("(_peerdb_data->>'%s')::%s AS \"%s\"",
-				strings.Trim(columnName, "\""), pgType, columnName))
+			flattenedCastsSQLArray = append(flattenedCastsSQLArray, fmt.Sprintf("%s AS \"%s\"",
+				rawColumnCastSQL(columnName, genericColumnType, userDefinedColumns), columnName))
 		}
 		if slices.Contains(normalizedTableSchema.PrimaryKeyColumns, columnName) {
-			primaryKeyColumnCasts[columnName] = fmt.Sprintf("(_peerdb_data->>'%s')::%s", columnName, pgType)
+			primaryKeyColumnCasts[columnName] = rawColumnCastSQL(columnName, genericColumnType, userDefinedColumns)
 			primaryKeySelectSQLArray = append(primaryKeySelectSQLArray, fmt.Sprintf("src.%s=dst.%s",
 				columnName, columnName))
 		}
@@ -617,6 +714,19 @@ func (c *PostgresConnector) getApproxTableCounts(tables []string) (int64, error)
 	return totalCount, nil
 }
 
+// getCurrentLSNWithTimestamp returns the current LSN along with the time the source read it at.
+func (c *PostgresConnector) getCurrentLSNWithTimestamp() (pglogrepl.LSN, time.Time, error) {
+	row := c.pool.QueryRow(c.ctx, "SELECT pg_current_wal_lsn(), now();")
+	var result string
+	var sampledAt time.Time
+	err := row.Scan(&result, &sampledAt)
+	if err != nil {
+		return 0, time.Time{}, fmt.Errorf("error while running query: %w", err)
+	}
+	lsn, err := pglogrepl.ParseLSN(result)
+	return lsn, sampledAt, err
+}
+
 func (c *PostgresConnector) getCurrentLSN() (pglogrepl.LSN, error) {
 	row := c.pool.QueryRow(c.ctx, "SELECT pg_current_wal_lsn();")
 	var result string
@@ -21,6 +21,7 @@ import (
 	"github.com/jackc/pgx/v5/pgxpool"
 	log "github.com/sirupsen/logrus"
 	"golang.org/x/exp/maps"
+	"golang.org/x/exp/slices"
 )
 
 // PostgresConnector is a Connector implementation for Postgres.
@@ -32,6 +33,7 @@ type PostgresConnector struct {
 	replPool           *pgxpool.Pool
 	tableSchemaMapping map[string]*protos.TableSchema
 	customTypesMapping map[uint32]string
+	types              *postgresTypes
 }
 
 // SchemaTable is a table in a schema.
@@ -41,9 +43,7 @@ type SchemaTable struct {
 }
 
 func (t *SchemaTable) String() string {
-	quotedSchema := fmt.Sprintf(`"%s"`, t.Schema)
-	quotedTable := fmt.Sprintf(`"%s"`, t.Table)
-	return fmt.Sprintf("%s.%s", quotedSchema, quotedTable)
+	return fmt.Sprintf("%s.%s", utils.QuoteIdentifier(t.Schema), utils.QuoteIdentifier(t.Table))
 }
 
 // NewPostgresConnector creates a new instance of PostgresConnector.
@@ -72,6 +72,11 @@ func NewPostgresConnector(ctx context.Context, pgConfig *protos.PostgresConfig)
 		return nil, fmt.Errorf("failed to get custom type map: %w", err)
 	}
 
+	types, err := loadPostgresTypes(ctx, pool, pgConfig.MoneyMapping)
+	if err != nil {
+		return nil, fmt.Errorf("failed to load types: %w", err)
+	}
+
 	// ensure that replication is set to database
 	replConnConfig, err := pgxpool.ParseConfig(connectionString)
 	if err != nil {
@@ -94,6 +99,7 @@ func NewPostgresConnector(ctx context.Context, pgConfig *protos.PostgresConfig)
 		pool:               pool,
 		replPool:           replPool,
 		customTypesMapping: customTypeMap,
+		types:              types,
 	}, nil
 }
 
@@ -233,8 +239,9 @@ func (c *PostgresConnector) PullRecords(req *model.PullRecordsRequest) (*model.R
 		Slot:                   slotName,
 		Publication:            publicationName,
 		TableNameMapping:       req.TableNameMapping,
+		ExcludedColumns:        req.ExcludedColumns,
 		RelationMessageMapping: req.RelationMessageMapping,
-	}, c.customTypesMapping)
+	}, c.customTypesMapping, c.types)
 	if err != nil {
 		return nil, fmt.Errorf("failed to create cdc source: %w", err)
 	}
@@ -254,7 +261,7 @@ func (c *PostgresConnector) PullRecords(req *model.PullRecordsRequest) (*model.R
 	if len(recordsWithSchemaDelta.RecordBatch.Records) > 0 {
 		cdcMirrorMonitor, ok := c.ctx.Value(shared.CDCMirrorMonitorKey).(*monitoring.CatalogMirrorMonitor)
 		if ok {
-			latestLSN, err := c.getCurrentLSN()
+			latestLSN, sampledAt, err := c.getCurrentLSNWithTimestamp()
 			if err != nil {
 				return nil, err
 			}
@@ -262,6 +269,10 @@ func (c *PostgresConnector) PullRecords(req *model.PullRecordsRequest) (*model.R
 			if err != nil {
 				return nil, err
 			}
+			err = cdcMirrorMonitor.AddLSNTimestampSample(c.ctx, req.FlowJobName, latestLSN, sampledAt)
+			if err != nil {
+				return nil, err
+			}
 		}
 	}
 
@@ -439,10 +450,18 @@ func (c *PostgresConnector) NormalizeRecords(req *model.NormalizeRecordsRequest)
 		}, nil
 	}
 
-	unchangedToastColsMap, err := c.getTableNametoUnchangedCols(req.FlowJobName, syncBatchID, normalizeBatchID)
+	unchangedToastColsMap, err := c.getTableNametoUnchangedCols(req.FlowJobName, syncBatchID,
+		req.MinNormalizeBatchID(normalizeBatchID))
 	if err != nil {
 		return nil, err
 	}
+	// records of paused tables stay in the raw table until they are resumed, tables removed from the
+	// mirror leave theirs behind.
+	for destinationTableName := range unchangedToastColsMap {
+		if _, ok := c.tableSchemaMapping[destinationTableName]; !ok || req.IsPaused(destinationTableName) {
+			delete(unchangedToastColsMap, destinationTableName)
+		}
+	}
 
 	normalizeRecordsTx, err := c.pool.Begin(c.ctx)
 	if err != nil {
@@ -464,10 +483,21 @@ func (c *PostgresConnector) NormalizeRecords(req *model.NormalizeRecordsRequest)
 	mergeStatementsBatch := &pgx.Batch{}
 	totalRowsAffected := 0
 	for destinationTableName, unchangedToastCols := range unchangedToastColsMap {
+		// the destination decides whether enum and composite columns got equivalent types, tables created
+		// before they were supported have text columns for them.
+		var userDefinedColumns map[string]userDefinedColumn
+		if len(c.tableSchemaMapping[destinationTableName].GetColumnTypes()) > 0 {
+			userDefinedColumns, err = c.getUserDefinedColumns(destinationTableName)
+			if err != nil {
+				return nil, err
+			}
+		}
 		normalizeStatements := c.generateNormalizeStatements(destinationTableName, unchangedToastCols,
-			rawTableIdentifier, supportsMerge)
+			rawTableIdentifier, supportsMerge, userDefinedColumns)
+		tableNormalizeBatchID := req.TableNormalizeBatchID(destinationTableName, normalizeBatchID)
 		for _, normalizeStatement := range normalizeStatements {
-			mergeStatementsBatch.Queue(normalizeStatement, normalizeBatchID, syncBatchID, destinationTableName).Exec(
+			mergeStatementsBatch.Queue(normalizeStatement, tableNormalizeBatchID, syncBatchID,
+				destinationTableName).Exec(
 				func(ct pgconn.CommandTag) error {
 					totalRowsAffected += int(ct.RowsAffected())
 					return nil
@@ -610,12 +640,14 @@ func (c *PostgresConnector) getTableSchemaForTable(
 		Columns:               make(map[string]string),
 		PrimaryKeyColumns:     pKeyCols,
 		IsReplicaIdentityFull: isFullReplica,
+		ColumnTypes:           make(map[string]*protos.PostgresType),
 	}
 
 	for _, fieldDescription := range rows.FieldDescriptions() {
-		genericColType := postgresOIDToQValueKind(fieldDescription.DataTypeOID)
+		dataType := c.types.baseType(fieldDescription.DataTypeOID)
+		genericColType := c.types.qValueKind(dataType)
 		if genericColType == qvalue.QValueKindInvalid {
-			typeName, ok := c.customTypesMapping[fieldDescription.DataTypeOID]
+			typeName, ok := c.customTypesMapping[dataType]
 			if ok {
 				genericColType = customTypeToQKind(typeName)
 			} else {
@@ -624,6 +656,12 @@ func (c *PostgresConnector) getTableSchemaForTable(
 		}
 
 		res.Columns[fieldDescription.Name] = string(genericColType)
+		if pgType, ok := c.types.userDefinedType(dataType); ok {
+			res.ColumnTypes[fieldDescription.Name] = pgType
+		}
+		if currencyColumn, ok := c.types.currencyColumn(fieldDescription.Name, dataType); ok {
+			res.Columns[currencyColumn] = string(qvalue.QValueKindString)
+		}
 	}
 
 	if err = rows.Err(); err != nil {
@@ -666,8 +704,15 @@ func (c *PostgresConnector) SetupNormalizedTables(req *protos.SetupNormalizedTab
 			continue
 		}
 
+		// enum and composite types are created next to the table
+		err = c.createUserDefinedTypes(createNormalizedTablesTx, normalizedTableNameComponents.Schema, tableSchema)
+		if err != nil {
+			return nil, err
+		}
+
 		// convert the column names and types to Postgres types
-		normalizedTableCreateSQL := generateCreateTableSQLForNormalizedTable(tableIdentifier, tableSchema)
+		normalizedTableCreateSQL := generateCreateTableSQLForNormalizedTable(tableIdentifier,
+			normalizedTableNameComponents.Schema, tableSchema)
 		_, err = createNormalizedTablesTx.Exec(c.ctx, normalizedTableCreateSQL)
 		if err != nil {
 			return nil, fmt.Errorf("error while creating normalized table: %w", err)
@@ -809,6 +854,296 @@ func (c *PostgresConnector) SetupReplication(signal *SlotSignal, req *protos.Set
 	return nil
 }
 
+// replicationSettings are the settings of the server and the permissions of the role of the peer that
+// logical replication depends on.
+type replicationSettings struct {
+	walLevel     string
+	freeSlots    int64
+	role         string
+	canReplicate bool
+}
+
+func (c *PostgresConnector) getReplicationSettings() (*replicationSettings, error) {
+	var settings replicationSettings
+	err := c.pool.QueryRow(c.ctx, `SELECT
+		current_setting('wal_level'),
+		current_setting('max_replication_slots')::int8 - (SELECT count(*) FROM pg_replication_slots),
+		current_user::text,
+		(SELECT rolreplication OR rolsuper FROM pg_roles WHERE rolname = current_user) OR
+			EXISTS(SELECT 1 FROM pg_roles WHERE rolname = 'rds_replication'
+				AND pg_has_role(current_user, oid, 'member'))`).Scan(
+		&settings.walLevel, &settings.freeSlots, &settings.role, &settings.canReplicate)
+	if err != nil {
+		return nil, fmt.Errorf("error getting replication settings: %w", err)
+	}
+	return &settings, nil
+}
+
+// CheckSlotCreation checks that the replication slot of a mirror exists or can be
+// created: the server must run with logical wal_level and have a free slot, and
+// the role of the peer must be allowed to replicate.
+func (c *PostgresConnector) CheckSlotCreation(slotName string) error {
+	var slotExists bool
+	err := c.pool.QueryRow(c.ctx, "SELECT EXISTS(SELECT 1 FROM pg_replication_slots WHERE slot_name = $1)",
+		slotName).Scan(&slotExists)
+	if err != nil {
+		return fmt.Errorf("error checking replication slot %s: %w", slotName, err)
+	}
+	if slotExists {
+		return nil
+	}
+	settings, err := c.getReplicationSettings()
+	if err != nil {
+		return err
+	}
+
+	slotErr := &shared.MirrorError{
+		Category: shared.MirrorErrorSlotCreation,
+		Message:  fmt.Sprintf("source peer can't create replication slot %s", slotName),
+	}
+	switch {
+	case settings.walLevel != "logical":
+		slotErr.Detail = fmt.Sprintf("wal_level is %s", settings.walLevel)
+		slotErr.Hint = "set wal_level to logical and restart the server"
+	case settings.freeSlots <= 0:
+		slotErr.Detail = "all slots allowed by max_replication_slots are in use"
+		slotErr.Hint = "increase max_replication_slots or drop unused replication slots"
+	case !settings.canReplicate:
+		slotErr.Detail = fmt.Sprintf("role %s doesn't have the REPLICATION attribute", settings.role)
+		slotErr.Hint = fmt.Sprintf("ALTER ROLE %s WITH REPLICATION", settings.role)
+	default:
+		return nil
+	}
+	return slotErr
+}
+
+// ReplicationChecks checks the settings and permissions the source of a cdc mirror needs.
+func (c *PostgresConnector) ReplicationChecks() ([]*protos.PeerValidationCheck, error) {
+	settings, err := c.getReplicationSettings()
+	if err != nil {
+		return nil, err
+	}
+
+	walLevelCheck := &protos.PeerValidationCheck{
+		Name:    "wal_level",
+		Passed:  settings.walLevel == "logical",
+		Message: fmt.Sprintf("wal_level is %s", settings.walLevel),
+	}
+	if !walLevelCheck.Passed {
+		walLevelCheck.Message += ", set wal_level to logical and restart the server"
+	}
+
+	roleCheck := &protos.PeerValidationCheck{
+		Name:    "replication_role",
+		Passed:  settings.canReplicate,
+		Message: fmt.Sprintf("role %s can replicate", settings.role),
+	}
+	if !roleCheck.Passed {
+		roleCheck.Message = fmt.Sprintf(
+			"role %s doesn't have the REPLICATION attribute, ALTER ROLE %s WITH REPLICATION",
+			settings.role, settings.role)
+	}
+
+	slotsCheck := &protos.PeerValidationCheck{
+		Name:    "replication_slots",
+		Passed:  settings.freeSlots > 0,
+		Message: fmt.Sprintf("%d replication slots are free", settings.freeSlots),
+	}
+	if !slotsCheck.Passed {
+		slotsCheck.Message += ", increase max_replication_slots or drop unused replication slots"
+	}
+
+	return []*protos.PeerValidationCheck{walLevelCheck, roleCheck, slotsCheck}, nil
+}
+
+// CheckTableTypes checks that the columns a destination table shares with its
+// source table have the same types, tables that don't exist yet are created
+// from the source schema and always match.
+func (c *PostgresConnector) CheckTableTypes(tableIdentifier string, sourceSchema *protos.TableSchema) error {
+	schemaTable, err := parseSchemaTable(tableIdentifier)
+	if err != nil {
+		return err
+	}
+	exists, err := c.tableExists(schemaTable)
+	if err != nil || !exists {
+		return err
+	}
+	destinationSchema, err := c.getTableSchemaForTable(tableIdentifier)
+	if err != nil {
+		return err
+	}
+
+	columns := maps.Keys(sourceSchema.Columns)
+	slices.Sort(columns)
+	for _, column := range columns {
+		sourceType := sourceSchema.Columns[column]
+		destinationType, ok := destinationSchema.Columns[column]
+		if ok && destinationType != sourceType {
+			return &shared.MirrorError{
+				Category: shared.MirrorErrorTypeMismatch,
+				Message: fmt.Sprintf("column %s of destination table %s doesn't match its source column",
+					column, tableIdentifier),
+				Detail: fmt.Sprintf("destination column is of type %s, source column of type %s",
+					destinationType, sourceType),
+				Hint: "drop the destination table, or alter the column to the type of the source column",
+			}
+		}
+	}
+	return nil
+}
+
+// AddTablesToPublication adds tables to the publication of the flow job.
+func (c *PostgresConnector) AddTablesToPublication(jobName string, tableNames []string) error {
+	// Publication name would be the job name prefixed with "peerflow_pub_"
+	publicationName := fmt.Sprintf("peerflow_pub_%s", jobName)
+
+	for _, tableName := range tableNames {
+		if _, err := parseSchemaTable(tableName); err != nil {
+			return fmt.Errorf("source tables identifier is invalid: %v", tableName)
+		}
+	}
+
+	stmt := fmt.Sprintf("ALTER PUBLICATION %s ADD TABLE %s", publicationName, strings.Join(tableNames, ", "))
+	_, err := c.pool.Exec(c.ctx, stmt)
+	if err != nil {
+		return fmt.Errorf("error adding tables to publication %s: %w", publicationName, err)
+	}
+
+	return nil
+}
+
+// RemoveTablesFromPublication drops tables from the publication of the flow job.
+func (c *PostgresConnector) RemoveTablesFromPublication(jobName string, tableNames []string) error {
+	// Publication name would be the job name prefixed with "peerflow_pub_"
+	publicationName := fmt.Sprintf("peerflow_pub_%s", jobName)
+
+	for _, tableName := range tableNames {
+		if _, err := parseSchemaTable(tableName); err != nil {
+			return fmt.Errorf("source tables identifier is invalid: %v", tableName)
+		}
+	}
+
+	stmt := fmt.Sprintf("ALTER PUBLICATION %s DROP TABLE %s", publicationName, strings.Join(tableNames, ", "))
+	_, err := c.pool.Exec(c.ctx, stmt)
+	if err != nil {
+		return fmt.Errorf("error removing tables from publication %s: %w", publicationName, err)
+	}
+
+	return nil
+}
+
+// RefreshTableStatistics analyzes the tables with at least threshold rows inserted, updated or
+// deleted since they were last analyzed, by us or by autovacuum.
+func (c *PostgresConnector) RefreshTableStatistics(tableIdentifiers []string, threshold uint32) error {
+	for _, tableIdentifier := range tableIdentifiers {
+		table, err := parseSchemaTable(tableIdentifier)
+		if err != nil {
+			return fmt.Errorf("error while parsing table schema and name: %w", err)
+		}
+
+		var modifiedRows int64
+		err = c.pool.QueryRow(c.ctx, getModifiedRowsSinceAnalyzeSQL, table.Schema, table.Table).Scan(&modifiedRows)
+		if err == pgx.ErrNoRows {
+			continue
+		} else if err != nil {
+			return fmt.Errorf("error getting rows modified in table %s: %w", tableIdentifier, err)
+		}
+		if modifiedRows < int64(threshold) {
+			continue
+		}
+
+		_, err = c.pool.Exec(c.ctx, fmt.Sprintf(analyzeTableSQL, tableIdentifier))
+		if err != nil {
+			return fmt.Errorf("error analyzing table %s: %w", tableIdentifier, err)
+		}
+		log.Infof("analyzed table %s after %d modified rows", tableIdentifier, modifiedRows)
+	}
+
+	return nil
+}
+
+// GetTablesInSchema returns the names of the tables of the schema, without the partitions of partitioned
+// tables, which are replicated through their parent.
+func (c *PostgresConnector) GetTablesInSchema(schema string) ([]string, error) {
+	rows, err := c.pool.Query(c.ctx, getTablesInSchemaSQL, schema)
+	if err != nil {
+		return nil, fmt.Errorf("error getting tables of schema %s: %w", schema, err)
+	}
+	defer rows.Close()
+
+	tables := make([]string, 0)
+	for rows.Next() {
+		var table string
+		if err := rows.Scan(&table); err != nil {
+			return nil, fmt.Errorf("error scanning table of schema %s: %w", schema, err)
+		}
+		tables = append(tables, table)
+	}
+	return tables, rows.Err()
+}
+
+// DropTables drops the tables that exist.
+func (c *PostgresConnector) DropTables(tableIdentifiers []string) error {
+	for _, tableIdentifier := range tableIdentifiers {
+		_, err := c.pool.Exec(c.ctx, fmt.Sprintf("DROP TABLE IF EXISTS %s", tableIdentifier))
+		if err != nil {
+			return fmt.Errorf("error dropping table %s: %w", tableIdentifier, err)
+		}
+	}
+
+	return nil
+}
+
+// TruncateTables removes every row of the tables.
+func (c *PostgresConnector) TruncateTables(tableIdentifiers []string) error {
+	for _, tableIdentifier := range tableIdentifiers {
+		_, err := c.pool.Exec(c.ctx, fmt.Sprintf("TRUNCATE TABLE %s", tableIdentifier))
+		if err != nil {
+			return fmt.Errorf("error truncating table %s: %w", tableIdentifier, err)
+		}
+	}
+
+	return nil
+}
+
+// ReplaceTables replaces each table with its copy in a single transaction, the
+// copies are renamed to the tables they replace.
+func (c *PostgresConnector) ReplaceTables(replacements map[string]string) error {
+	replaceTablesTx, err := c.pool.Begin(c.ctx)
+	if err != nil {
+		return fmt.Errorf("error starting transaction for replacing tables: %w", err)
+	}
+	defer func() {
+		deferErr := replaceTablesTx.Rollback(c.ctx)
+		if deferErr != pgx.ErrTxClosed && deferErr != nil {
+			log.Errorf("unexpected error rolling back transaction for replacing tables: %v", deferErr)
+		}
+	}()
+
+	for tableIdentifier, copyIdentifier := range replacements {
+		table, err := parseSchemaTable(tableIdentifier)
+		if err != nil {
+			return fmt.Errorf("error while parsing table schema and name: %w", err)
+		}
+		_, err = replaceTablesTx.Exec(c.ctx, fmt.Sprintf("DROP TABLE IF EXISTS %s", tableIdentifier))
+		if err != nil {
+			return fmt.Errorf("error dropping table %s: %w", tableIdentifier, err)
+		}
+		_, err = replaceTablesTx.Exec(c.ctx, fmt.Sprintf("ALTER TABLE %s RENAME TO %s", copyIdentifier,
+			utils.QuoteIdentifier(table.Table)))
+		if err != nil {
+			return fmt.Errorf("error renaming table %s to %s: %w", copyIdentifier, tableIdentifier, err)
+		}
+	}
+
+	err = replaceTablesTx.Commit(c.ctx)
+	if err != nil {
+		return fmt.Errorf("error committing transaction for replacing tables: %w", err)
+	}
+
+	return nil
+}
+
 func (c *PostgresConnector) PullFlowCleanup(jobName string) error {
 	// Slotname would be the job name prefixed with "peerflow_slot_"
 	slotName := fmt.Sprintf("peerflow_slot_%s", jobName)
@@ -895,15 +1230,27 @@ func (c *PostgresConnector) SendWALHeartbeat() error {
 	return nil
 }
 
-// parseSchemaTable parses a table name into schema and table name.
+// parseSchemaTable parses a table name into schema and table name, unquoted parts fold to lower case
+// like they do in postgres.
 func parseSchemaTable(tableName string) (*SchemaTable, error) {
-	parts := strings.Split(tableName, ".")
+	parts, err := utils.ParseIdentifier(tableName)
+	if err != nil {
+		return nil, err
+	}
 	if len(parts) != 2 {
 		return nil, fmt.Errorf("invalid table name: %s", tableName)
 	}
 
+	names := make([]string, 0, len(parts))
+	for _, part := range parts {
+		if part.Quoted {
+			names = append(names, part.Value)
+		} else {
+			names = append(names, strings.ToLower(part.Value))
+		}
+	}
 	return &SchemaTable{
-		Schema: parts[0],
-		Table:  parts[1],
+		Schema: names[0],
+		Table:  names[1],
 	}, nil
 }
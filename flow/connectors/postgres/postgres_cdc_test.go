@@ -360,6 +360,21 @@ func (suite *PostgresCDCTestSuite) TestParseSchemaTable() {
 	schemaTest3, err := parseSchemaTable("database.schema.table")
 	suite.Nil(schemaTest3)
 	suite.NotNil(err)
+
+	schemaTest4, err := parseSchemaTable(`"Weird Schema"."My.Table"`)
+	suite.Equal(&SchemaTable{
+		Schema: "Weird Schema",
+		Table:  "My.Table",
+	}, schemaTest4)
+	suite.Equal(`"Weird Schema"."My.Table"`, schemaTest4.String())
+	suite.Nil(err)
+
+	schemaTest5, err := parseSchemaTable(`Public."MyTable"`)
+	suite.Equal(&SchemaTable{
+		Schema: "public",
+		Table:  "MyTable",
+	}, schemaTest5)
+	suite.Nil(err)
 }
 
 func (suite *PostgresCDCTestSuite) TestErrorForInvalidConfig() {
@@ -340,7 +340,7 @@ func (c *PostgresConnector) PullQRepRecords(
 			"partitionId": partition.PartitionId,
 		}).Infof("pulling full table partition for flow job %s", config.FlowJobName)
 		executor, err := NewQRepQueryExecutorSnapshot(c.pool, c.ctx, c.config.TransactionSnapshot,
-			config.FlowJobName, partition.PartitionId)
+			config.FlowJobName, partition.PartitionId, c.config.MoneyMapping)
 		if err != nil {
 			return nil, err
 		}
@@ -386,7 +386,7 @@ func (c *PostgresConnector) PullQRepRecords(
 	}
 
 	executor, err := NewQRepQueryExecutorSnapshot(c.pool, c.ctx, c.config.TransactionSnapshot,
-		config.FlowJobName, partition.PartitionId)
+		config.FlowJobName, partition.PartitionId, c.config.MoneyMapping)
 	if err != nil {
 		return nil, err
 	}
@@ -416,7 +416,7 @@ func (c *PostgresConnector) PullQRepRecordStream(
 			"partitionId": partition.PartitionId,
 		}).Infof("pulling full table partition for flow job %s", config.FlowJobName)
 		executor, err := NewQRepQueryExecutorSnapshot(c.pool, c.ctx, c.config.TransactionSnapshot,
-			config.FlowJobName, partition.PartitionId)
+			config.FlowJobName, partition.PartitionId, c.config.MoneyMapping)
 		if err != nil {
 			return 0, err
 		}
@@ -464,7 +464,7 @@ func (c *PostgresConnector) PullQRepRecordStream(
 	}
 
 	executor, err := NewQRepQueryExecutorSnapshot(c.pool, c.ctx, c.config.TransactionSnapshot,
-		config.FlowJobName, partition.PartitionId)
+		config.FlowJobName, partition.PartitionId, c.config.MoneyMapping)
 	if err != nil {
 		return 0, err
 	}
@@ -6,6 +6,7 @@ import (
 	"time"
 
 	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
 	"github.com/PeerDB-io/peer-flow/model"
 	"github.com/PeerDB-io/peer-flow/model/qvalue"
 	"github.com/PeerDB-io/peer-flow/shared"
@@ -25,6 +26,7 @@ type QRepQueryExecutor struct {
 	flowJobName   string
 	partitionID   string
 	customTypeMap map[uint32]string
+	types         *postgresTypes
 }
 
 func NewQRepQueryExecutor(pool *pgxpool.Pool, ctx context.Context,
@@ -39,7 +41,7 @@ func NewQRepQueryExecutor(pool *pgxpool.Pool, ctx context.Context,
 }
 
 func NewQRepQueryExecutorSnapshot(pool *pgxpool.Pool, ctx context.Context, snapshot string,
-	flowJobName string, partitionID string) (*QRepQueryExecutor, error) {
+	flowJobName string, partitionID string, moneyMapping protos.MoneyMapping) (*QRepQueryExecutor, error) {
 	log.WithFields(log.Fields{
 		"flowName":    flowJobName,
 		"partitionID": partitionID,
@@ -48,6 +50,10 @@ func NewQRepQueryExecutorSnapshot(pool *pgxpool.Pool, ctx context.Context, snaps
 	if err != nil {
 		return nil, fmt.Errorf("failed to get custom data types: %w", err)
 	}
+	types, err := loadPostgresTypes(ctx, pool, moneyMapping)
+	if err != nil {
+		return nil, fmt.Errorf("failed to load types: %w", err)
+	}
 	return &QRepQueryExecutor{
 		pool:          pool,
 		ctx:           ctx,
@@ -55,6 +61,7 @@ func NewQRepQueryExecutorSnapshot(pool *pgxpool.Pool, ctx context.Context, snaps
 		flowJobName:   flowJobName,
 		partitionID:   partitionID,
 		customTypeMap: CustomTypeMap,
+		types:         types,
 	}, nil
 }
 
@@ -98,15 +105,17 @@ func (qe *QRepQueryExecutor) executeQueryInTx(tx pgx.Tx, cursorName string, fetc
 // FieldDescriptionsToSchema converts a slice of pgconn.FieldDescription to a QRecordSchema.
 func (qe *QRepQueryExecutor) fieldDescriptionsToSchema(fds []pgconn.FieldDescription) *model.QRecordSchema {
 	qfields := make([]*model.QField, len(fds))
+	currencyFields := make([]*model.QField, 0)
 	for i, fd := range fds {
 		cname := fd.Name
-		ctype := postgresOIDToQValueKind(fd.DataTypeOID)
+		dataType := qe.types.baseType(fd.DataTypeOID)
+		ctype := qe.types.qValueKind(dataType)
 		if ctype == qvalue.QValueKindInvalid {
 			var err error
-			ctype = qvalue.QValueKind(qe.customTypeMap[fd.DataTypeOID])
+			ctype = qvalue.QValueKind(qe.customTypeMap[dataType])
 			if err != nil {
 				ctype = qvalue.QValueKindInvalid
-				typeName, ok := qe.customTypeMap[fd.DataTypeOID]
+				typeName, ok := qe.customTypeMap[dataType]
 				if ok {
 					ctype = customTypeToQKind(typeName)
 				}
@@ -120,8 +129,16 @@ func (qe *QRepQueryExecutor) fieldDescriptionsToSchema(fds []pgconn.FieldDescrip
 			Type:     ctype,
 			Nullable: cnullable,
 		}
+		if currencyColumn, ok := qe.types.currencyColumn(cname, dataType); ok {
+			currencyFields = append(currencyFields, &model.QField{
+				Name:     currencyColumn,
+				Type:     qvalue.QValueKindString,
+				Nullable: true,
+			})
+		}
 	}
-	return model.NewQRecordSchema(qfields)
+	// currency columns of money columns come after all the queried columns, like in mapRowToQRecord.
+	return model.NewQRecordSchema(append(qfields, currencyFields...))
 }
 
 func (qe *QRepQueryExecutor) ProcessRows(
@@ -136,7 +153,7 @@ func (qe *QRepQueryExecutor) ProcessRows(
 	}).Info("Processing rows")
 	// Iterate over the rows
 	for rows.Next() {
-		record, err := mapRowToQRecord(rows, fieldDescriptions, qe.customTypeMap)
+		record, err := mapRowToQRecord(rows, fieldDescriptions, qe.customTypeMap, qe.types)
 		if err != nil {
 			return nil, fmt.Errorf("failed to map row to QRecord: %w", err)
 		}
@@ -173,7 +190,7 @@ func (qe *QRepQueryExecutor) processRowsStream(
 
 	// Iterate over the rows
 	for rows.Next() {
-		record, err := mapRowToQRecord(rows, fieldDescriptions, qe.customTypeMap)
+		record, err := mapRowToQRecord(rows, fieldDescriptions, qe.customTypeMap, qe.types)
 		if err != nil {
 			stream.Records <- &model.QRecordOrError{
 				Err: fmt.Errorf("failed to map row to QRecord: %w", err),
@@ -414,9 +431,16 @@ func (qe *QRepQueryExecutor) ExecuteAndProcessQueryStream(
 }
 
 func mapRowToQRecord(row pgx.Rows, fds []pgconn.FieldDescription,
-	customTypeMap map[uint32]string) (*model.QRecord, error) {
-	// make vals an empty array of QValue of size len(fds)
-	record := model.NewQRecord(len(fds))
+	customTypeMap map[uint32]string, types *postgresTypes) (*model.QRecord, error) {
+	currencyColumns := 0
+	for _, fd := range fds {
+		if _, ok := types.currencyColumn(fd.Name, fd.DataTypeOID); ok {
+			currencyColumns++
+		}
+	}
+	// make vals an empty array of QValue of size len(fds), plus the currency columns of money columns
+	record := model.NewQRecord(len(fds) + currencyColumns)
+	currencyIdx := len(fds)
 
 	values, err := row.Values()
 	if err != nil {
@@ -424,10 +448,38 @@ func mapRowToQRecord(row pgx.Rows, fds []pgconn.FieldDescription,
 	}
 
 	for i, fd := range fds {
+		dataType := types.baseType(fd.DataTypeOID)
+		if types.isNumericMoney(dataType) {
+			tmp, err := types.moneyValue(values[i])
+			if err != nil {
+				return nil, fmt.Errorf("failed to parse field: %w", err)
+			}
+			record.Set(i, *tmp)
+			if _, ok := types.currencyColumn(fd.Name, dataType); ok {
+				record.Set(currencyIdx, *types.currencyValue(values[i] == nil))
+				currencyIdx++
+			}
+			continue
+		}
+		if types.isComposite(dataType) {
+			tmp, err := types.compositeValue(dataType, values[i])
+			if err != nil {
+				return nil, fmt.Errorf("failed to parse field: %w", err)
+			}
+			record.Set(i, *tmp)
+			continue
+		}
+		if dataType != fd.DataTypeOID {
+			values[i], err = types.decodeDomainValue(fd.DataTypeOID, values[i])
+			if err != nil {
+				return nil, fmt.Errorf("failed to decode domain value: %w", err)
+			}
+		}
+
 		// Check if it's a custom type first
-		typeName, ok := customTypeMap[fd.DataTypeOID]
+		typeName, ok := customTypeMap[dataType]
 		if !ok {
-			tmp, err := parseFieldFromPostgresOID(fd.DataTypeOID, values[i])
+			tmp, err := parseFieldFromPostgresOID(dataType, values[i])
 			if err != nil {
 				return nil, fmt.Errorf("failed to parse field: %w", err)
 			}
@@ -3,6 +3,7 @@ package connpostgres
 import (
 	"context"
 	"fmt"
+	"strings"
 	"time"
 
 	"github.com/PeerDB-io/peer-flow/connectors/utils/metrics"
@@ -63,6 +64,22 @@ func (s *QRepStagingTableSync) SyncQRepRecords(
 		}
 	}()
 
+	// enum and composite columns can't be copied into directly, the values of
+	// those go through a staging table as text and json.
+	userDefinedColumns, err := s.connector.getUserDefinedColumns(dstTableName.String())
+	if err != nil {
+		return -1, err
+	}
+	copyTable := pgx.Identifier{dstTableName.Schema, dstTableName.Table}
+	if len(userDefinedColumns) > 0 {
+		copyTable = pgx.Identifier{qRepStagingTableName}
+		_, err = tx.Exec(context.Background(),
+			stagingTableSQL(dstTableName, schema.GetColumnNames(), userDefinedColumns))
+		if err != nil {
+			return -1, fmt.Errorf("failed to create staging table: %v", err)
+		}
+	}
+
 	// Step 2: Insert records into the destination table.
 	copySource := model.NewQRecordBatchCopyFromSource(stream)
 
@@ -70,7 +87,7 @@ func (s *QRepStagingTableSync) SyncQRepRecords(
 	syncRecordsStartTime := time.Now()
 	syncedRows, err := tx.CopyFrom(
 		context.Background(),
-		pgx.Identifier{dstTableName.Schema, dstTableName.Table},
+		copyTable,
 		schema.GetColumnNames(),
 		copySource,
 	)
@@ -78,6 +95,13 @@ func (s *QRepStagingTableSync) SyncQRepRecords(
 	if err != nil {
 		return -1, fmt.Errorf("failed to copy records into destination table: %v", err)
 	}
+	if len(userDefinedColumns) > 0 {
+		_, err = tx.Exec(context.Background(),
+			insertFromStagingTableSQL(dstTableName, schema.GetColumnNames(), userDefinedColumns))
+		if err != nil {
+			return -1, fmt.Errorf("failed to insert records from staging table: %v", err)
+		}
+	}
 	metrics.LogQRepSyncMetrics(s.connector.ctx, flowJobName, syncedRows, time.Since(syncRecordsStartTime))
 
 	// marshal the partition to json using protojson
@@ -128,3 +152,43 @@ func (s *QRepStagingTableSync) SyncQRepRecords(
 	}).Infof("pushed %d records to %s", numRowsInserted, dstTableName)
 	return numRowsInserted, nil
 }
+
+const qRepStagingTableName = "_peerdb_qrep_staging"
+
+func stagingTableSQL(dstTableName *SchemaTable, columnNames []string,
+	userDefinedColumns map[string]userDefinedColumn) string {
+	columns := make([]string, 0, len(columnNames))
+	for _, columnName := range columnNames {
+		column, ok := userDefinedColumns[columnName]
+		switch {
+		case ok && column.composite:
+			columns = append(columns, fmt.Sprintf(`NULL::JSON AS "%s"`, columnName))
+		case ok:
+			columns = append(columns, fmt.Sprintf(`NULL::TEXT AS "%s"`, columnName))
+		default:
+			columns = append(columns, fmt.Sprintf(`"%s"`, columnName))
+		}
+	}
+	return fmt.Sprintf("CREATE TEMP TABLE %s ON COMMIT DROP AS SELECT %s FROM %s WITH NO DATA",
+		qRepStagingTableName, strings.Join(columns, ","), dstTableName.String())
+}
+
+func insertFromStagingTableSQL(dstTableName *SchemaTable, columnNames []string,
+	userDefinedColumns map[string]userDefinedColumn) string {
+	columns := make([]string, 0, len(columnNames))
+	values := make([]string, 0, len(columnNames))
+	for _, columnName := range columnNames {
+		columns = append(columns, fmt.Sprintf(`"%s"`, columnName))
+		column, ok := userDefinedColumns[columnName]
+		switch {
+		case ok && column.composite:
+			values = append(values, fmt.Sprintf(`json_populate_record(NULL::%s,"%s")`, column.typeName, columnName))
+		case ok:
+			values = append(values, fmt.Sprintf(`"%s"::%s`, columnName, column.typeName))
+		default:
+			values = append(values, fmt.Sprintf(`"%s"`, columnName))
+		}
+	}
+	return fmt.Sprintf("INSERT INTO %s(%s) SELECT %s FROM %s", dstTableName.String(),
+		strings.Join(columns, ","), strings.Join(values, ","), qRepStagingTableName)
+}
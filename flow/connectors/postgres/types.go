@@ -0,0 +1,223 @@
+package connpostgres
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+	"github.com/PeerDB-io/peer-flow/shared"
+	"github.com/jackc/pgx/v5/pgtype"
+	"github.com/jackc/pgx/v5/pgxpool"
+	"github.com/lib/pq/oid"
+)
+
+// postgresTypes resolves the source types that don't map onto a single kind of
+// the value model. Domains are replicated as the type they are defined over,
+// money as configured on the peer, as a string by default, and composites as
+// json objects. A nil *postgresTypes keeps every type as it is.
+type postgresTypes struct {
+	domainBaseTypes map[uint32]uint32
+	// enum and composite types.
+	userDefinedTypes map[uint32]*protos.PostgresType
+	moneyMapping    protos.MoneyMapping
+	moneyFormat     qvalue.MoneyFormat
+	typeMap         *pgtype.Map
+}
+
+func loadPostgresTypes(ctx context.Context, pool *pgxpool.Pool,
+	moneyMapping protos.MoneyMapping) (*postgresTypes, error) {
+	domainBaseTypes, err := utils.GetDomainBaseTypes(ctx, pool)
+	if err != nil {
+		return nil, err
+	}
+
+	userDefinedTypes, err := utils.GetUserDefinedTypes(ctx, pool)
+	if err != nil {
+		return nil, err
+	}
+
+	types := &postgresTypes{
+		domainBaseTypes:  domainBaseTypes,
+		userDefinedTypes: userDefinedTypes,
+		moneyMapping:     moneyMapping,
+		typeMap:          pgtype.NewMap(),
+	}
+	if moneyMapping != protos.MoneyMapping_MONEY_MAPPING_STRING {
+		types.moneyFormat, err = utils.GetMoneyFormat(ctx, pool)
+		if err != nil {
+			return nil, err
+		}
+	}
+	return types, nil
+}
+
+// baseType returns the oid of the type a domain is defined over, other oids
+// are returned as they are.
+func (t *postgresTypes) baseType(dataType uint32) uint32 {
+	if t == nil {
+		return dataType
+	}
+	if baseType, ok := t.domainBaseTypes[dataType]; ok {
+		return baseType
+	}
+	return dataType
+}
+
+// decodeDomainValue decodes a value of a domain read by a query, pgx doesn't
+// know the oids of domains and returns their values in the text format.
+func (t *postgresTypes) decodeDomainValue(dataType uint32, value any) (any, error) {
+	baseType := t.baseType(dataType)
+	text, ok := value.(string)
+	if baseType == dataType || !ok {
+		return value, nil
+	}
+	dt, ok := t.typeMap.TypeForOID(baseType)
+	if !ok {
+		return value, nil
+	}
+	return dt.Codec.DecodeValue(t.typeMap, baseType, pgtype.TextFormatCode, []byte(text))
+}
+
+// isNumericMoney is whether values of the type are replicated as numeric money.
+func (t *postgresTypes) isNumericMoney(dataType uint32) bool {
+	return t != nil && t.moneyMapping != protos.MoneyMapping_MONEY_MAPPING_STRING &&
+		t.baseType(dataType) == uint32(oid.T_money)
+}
+
+// userDefinedType returns the enum or composite type of the oid.
+func (t *postgresTypes) userDefinedType(dataType uint32) (*protos.PostgresType, bool) {
+	if t == nil {
+		return nil, false
+	}
+	pgType, ok := t.userDefinedTypes[t.baseType(dataType)]
+	return pgType, ok
+}
+
+func (t *postgresTypes) isComposite(dataType uint32) bool {
+	pgType, ok := t.userDefinedType(dataType)
+	return ok && len(pgType.Attributes) > 0
+}
+
+func (t *postgresTypes) qValueKind(dataType uint32) qvalue.QValueKind {
+	if t.isNumericMoney(dataType) {
+		return qvalue.QValueKindNumeric
+	}
+	if t.isComposite(dataType) {
+		return qvalue.QValueKindJSON
+	}
+	return postgresOIDToQValueKind(t.baseType(dataType))
+}
+
+// currencyColumn returns the name of the column that carries the currency of a
+// money column, if the peer replicates it.
+func (t *postgresTypes) currencyColumn(columnName string, dataType uint32) (string, bool) {
+	if !t.isNumericMoney(dataType) || t.moneyMapping != protos.MoneyMapping_MONEY_MAPPING_NUMERIC_WITH_CURRENCY {
+		return "", false
+	}
+	return columnName + shared.MoneyCurrencyColumnSuffix, true
+}
+
+// moneyValue parses a money value in the text format of the server, nil values
+// stay nil.
+func (t *postgresTypes) moneyValue(value any) (*qvalue.QValue, error) {
+	if value == nil {
+		return &qvalue.QValue{Kind: qvalue.QValueKindNumeric, Value: nil}, nil
+	}
+	text, ok := value.(string)
+	if !ok {
+		return nil, fmt.Errorf("unexpected money value of type %T", value)
+	}
+	rat, err := qvalue.ParseMoney(text, t.moneyFormat)
+	if err != nil {
+		return nil, err
+	}
+	return &qvalue.QValue{Kind: qvalue.QValueKindNumeric, Value: rat}, nil
+}
+
+// currencyValue is the value of the currency column of a money value, the
+// currency of a null value is null too.
+func (t *postgresTypes) currencyValue(null bool) *qvalue.QValue {
+	if null {
+		return &qvalue.QValue{Kind: qvalue.QValueKindString, Value: nil}
+	}
+	return &qvalue.QValue{Kind: qvalue.QValueKindString, Value: t.moneyFormat.CurrencySymbol}
+}
+
+// compositeValue converts a composite value in the text format of the server
+// to a json object of its attributes. Attributes are kept as strings, Postgres
+// destinations cast them back with json_populate_record.
+func (t *postgresTypes) compositeValue(dataType uint32, value any) (*qvalue.QValue, error) {
+	if value == nil {
+		return &qvalue.QValue{Kind: qvalue.QValueKindJSON, Value: nil}, nil
+	}
+	text, ok := value.(string)
+	if !ok {
+		return nil, fmt.Errorf("unexpected composite value of type %T", value)
+	}
+	pgType, _ := t.userDefinedType(dataType)
+	attributes, err := parseCompositeLiteral(text)
+	if err != nil {
+		return nil, err
+	}
+	if len(attributes) != len(pgType.Attributes) {
+		return nil, fmt.Errorf("composite value %q doesn't match the attributes of type %s", text, pgType.Name)
+	}
+
+	object := make(map[string]*string, len(attributes))
+	for i, attribute := range pgType.Attributes {
+		object[attribute.Name] = attributes[i]
+	}
+	jsonVal, err := json.Marshal(object)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert composite value to json: %w", err)
+	}
+	return &qvalue.QValue{Kind: qvalue.QValueKindJSON, Value: string(jsonVal)}, nil
+}
+
+// parseCompositeLiteral splits a composite value in the text format, e.g.
+// (1,"a b",), into its attributes, with nil for null attributes.
+func parseCompositeLiteral(text string) ([]*string, error) {
+	if len(text) < 2 || text[0] != '(' || text[len(text)-1] != ')' {
+		return nil, fmt.Errorf("invalid composite value %q", text)
+	}
+	body := text[1 : len(text)-1]
+
+	attributes := make([]*string, 0)
+	var attribute strings.Builder
+	quoted, inQuotes := false, false
+	for i := 0; i <= len(body); i++ {
+		if i == len(body) || (!inQuotes && body[i] == ',') {
+			if attribute.Len() == 0 && !quoted {
+				attributes = append(attributes, nil)
+			} else {
+				value := attribute.String()
+				attributes = append(attributes, &value)
+			}
+			attribute.Reset()
+			quoted = false
+			continue
+		}
+
+		switch c := body[i]; {
+		case c == '"' && inQuotes && i+1 < len(body) && body[i+1] == '"':
+			attribute.WriteByte('"')
+			i++
+		case c == '"':
+			inQuotes = !inQuotes
+			quoted = true
+		case c == '\\' && i+1 < len(body):
+			attribute.WriteByte(body[i+1])
+			i++
+		default:
+			attribute.WriteByte(c)
+		}
+	}
+	if inQuotes {
+		return nil, fmt.Errorf("unterminated quote in composite value %q", text)
+	}
+	return attributes, nil
+}
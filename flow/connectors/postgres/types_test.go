@@ -0,0 +1,56 @@
+package connpostgres
+
+import (
+	"testing"
+
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/stretchr/testify/assert"
+)
+
+func TestCompositeValue(t *testing.T) {
+	types := &postgresTypes{
+		userDefinedTypes: map[uint32]*protos.PostgresType{
+			1: {
+				Name: "address",
+				Attributes: []*protos.PostgresTypeAttribute{
+					{Name: "street", Type: "text"},
+					{Name: "number", Type: "integer"},
+					{Name: "note", Type: "text"},
+				},
+			},
+		},
+	}
+
+	testCases := []struct {
+		name     string
+		value    string
+		expected string
+	}{
+		{
+			name:     "Plain attributes",
+			value:    "(main,12,x)",
+			expected: `{"note":"x","number":"12","street":"main"}`,
+		},
+		{
+			name:     "Quoted attributes",
+			value:    `("main ""st"", 1",12,"a\\b")`,
+			expected: `{"note":"a\\b","number":"12","street":"main \"st\", 1"}`,
+		},
+		{
+			name:     "Null and empty attributes",
+			value:    `(,12,"")`,
+			expected: `{"note":"","number":"12","street":null}`,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			actual, err := types.compositeValue(1, tc.value)
+			assert.NoError(t, err)
+			assert.Equal(t, tc.expected, actual.Value)
+		})
+	}
+
+	_, err := types.compositeValue(1, "(main,12)")
+	assert.Error(t, err)
+}
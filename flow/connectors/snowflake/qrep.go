@@ -221,7 +221,11 @@ func (c *SnowflakeConnector) createExternalStage(stageName string, config *proto
 
 	cleanURL := fmt.Sprintf("s3://%s/%s/%s", s3o.Bucket, s3o.Prefix, config.FlowJobName)
 
-	s3Int := config.DestinationPeer.GetSnowflakeConfig().S3Integration
+	// a storage integration set on the mirror takes precedence over the peer's.
+	s3Int := config.StagingIntegration
+	if s3Int == "" {
+		s3Int = config.DestinationPeer.GetSnowflakeConfig().GetS3Integration()
+	}
 	if s3Int == "" {
 		credsStr := fmt.Sprintf("CREDENTIALS=(AWS_KEY_ID='%s' AWS_SECRET_KEY='%s')",
 			awsCreds.AccessKeyID, awsCreds.SecretAccessKey)
@@ -291,18 +295,13 @@ func (c *SnowflakeConnector) getColsFromTable(tableName string) (*model.ColumnIn
 		return nil, fmt.Errorf("failed to parse table name: %w", err)
 	}
 
-	// convert tableIdentifier and schemaIdentifier to upper case
-	components.tableIdentifier = strings.ToUpper(components.tableIdentifier)
-	components.schemaIdentifier = strings.ToUpper(components.schemaIdentifier)
-
-	//nolint:gosec
-	queryString := fmt.Sprintf(`
+	queryString := `
 	SELECT column_name, data_type
 	FROM information_schema.columns
-	WHERE UPPER(table_name) = '%s' AND UPPER(table_schema) = '%s'
-	`, components.tableIdentifier, components.schemaIdentifier)
+	WHERE table_name = ? AND table_schema = ?
+	`
 
-	rows, err := c.database.Query(queryString)
+	rows, err := c.database.Query(queryString, components.tableIdentifier, components.schemaIdentifier)
 	if err != nil {
 		return nil, fmt.Errorf("failed to execute query: %w", err)
 	}
@@ -259,7 +259,10 @@ func (s *SnowflakeAvroSyncMethod) putFileToStage(localFilePath string, stage str
 	return nil
 }
 
-func (sc *SnowflakeConnector) GetCopyTransformation(dstTableName string) (*CopyInfo, error) {
+// GetCopyTransformation maps the columns of the destination table to the fields of the staged avro
+// files, the metadata columns of cdc mirrors are left to their defaults.
+func (sc *SnowflakeConnector) GetCopyTransformation(dstTableName string, config *protos.QRepConfig) (
+	*CopyInfo, error) {
 	colInfo, colsErr := sc.getColsFromTable(dstTableName)
 	if colsErr != nil {
 		return nil, fmt.Errorf("failed to get columns from  destination table: %w", colsErr)
@@ -268,7 +271,8 @@ func (sc *SnowflakeConnector) GetCopyTransformation(dstTableName string) (*CopyI
 	var transformations []string
 	var columnOrder []string
 	for colName, colType := range colInfo.ColumnMap {
-		if colName == "_PEERDB_IS_DELETED" {
+		if colName == softDeleteColumnName(config.SoftDeleteColName) ||
+			(config.SyncedAtColName != "" && colName == strings.ToUpper(config.SyncedAtColName)) {
 			continue
 		}
 		columnOrder = append(columnOrder, fmt.Sprintf("\"%s\"", colName))
@@ -318,7 +322,7 @@ func CopyStageToDestination(
 		}
 	}
 
-	copyTransformation, err := connector.GetCopyTransformation(dstTableName)
+	copyTransformation, err := connector.GetCopyTransformation(dstTableName, config)
 	if err != nil {
 		return fmt.Errorf("failed to get copy transformation: %w", err)
 	}
@@ -409,7 +409,8 @@ func (c *SnowflakeConnector) SetupNormalizedTables(
 			continue
 		}
 
-		normalizedTableCreateSQL := generateCreateTableSQLForNormalizedTable(tableIdentifier, tableSchema)
+		normalizedTableCreateSQL := generateCreateTableSQLForNormalizedTable(tableIdentifier, tableSchema,
+			req.SoftDeleteColName, req.SyncedAtColName)
 		_, err = c.database.ExecContext(c.ctx, normalizedTableCreateSQL)
 		if err != nil {
 			return nil, fmt.Errorf("[sf] error while creating normalized table: %w", err)
@@ -659,9 +660,12 @@ func (c *SnowflakeConnector) syncRecordsViaAvro(req *model.SyncRecordsRequest, r
 	}
 	firstCP := streamRes.CP
 	recordStream := streamRes.Stream
+	// the records are staged through the cdc staging path of the mirror, or the
+	// internal stage if it has none.
 	qrepConfig := &protos.QRepConfig{
-		StagingPath: "",
-		FlowJobName: req.FlowJobName,
+		StagingPath:        req.StagingPath,
+		StagingIntegration: req.StagingIntegration,
+		FlowJobName:        req.FlowJobName,
 		DestinationTableIdentifier: fmt.Sprintf("%s.%s", peerDBInternalSchema,
 			rawTableIdentifier),
 	}
@@ -716,12 +720,22 @@ func (c *SnowflakeConnector) NormalizeRecords(req *model.NormalizeRecordsRequest
 			Done: false,
 		}, nil
 	}
-	destinationTableNames, err := c.getDistinctTableNamesInBatch(req.FlowJobName, syncBatchID, normalizeBatchID)
+	minNormalizeBatchID := req.MinNormalizeBatchID(normalizeBatchID)
+	batchTableNames, err := c.getDistinctTableNamesInBatch(req.FlowJobName, syncBatchID, minNormalizeBatchID)
 	if err != nil {
 		return nil, err
 	}
+	// records of paused tables stay in the raw table until they are resumed, tables removed from the
+	// mirror leave theirs behind.
+	destinationTableNames := make([]string, 0, len(batchTableNames))
+	for _, destinationTableName := range batchTableNames {
+		if _, ok := c.tableSchemaMapping[destinationTableName]; ok && !req.IsPaused(destinationTableName) {
+			destinationTableNames = append(destinationTableNames, destinationTableName)
+		}
+	}
 
-	tableNametoUnchangedToastCols, err := c.getTableNametoUnchangedCols(req.FlowJobName, syncBatchID, normalizeBatchID)
+	tableNametoUnchangedToastCols, err := c.getTableNametoUnchangedCols(req.FlowJobName, syncBatchID,
+		minNormalizeBatchID)
 	if err != nil {
 		return nil, fmt.Errorf("couldn't tablename to unchanged cols mapping: %w", err)
 	}
@@ -749,8 +763,8 @@ func (c *SnowflakeConnector) NormalizeRecords(req *model.NormalizeRecordsRequest
 			destinationTableName,
 			tableNametoUnchangedToastCols[destinationTableName],
 			getRawTableIdentifier(req.FlowJobName),
-			syncBatchID, normalizeBatchID,
-			req.SoftDelete,
+			syncBatchID, req.TableNormalizeBatchID(destinationTableName, normalizeBatchID),
+			req,
 			normalizeRecordsTx)
 		if err != nil {
 			return nil, err
@@ -866,6 +880,61 @@ func (c *SnowflakeConnector) SyncFlowCleanup(jobName string) error {
 	return nil
 }
 
+// DropTables drops the tables that exist.
+func (c *SnowflakeConnector) DropTables(tableIdentifiers []string) error {
+	for _, tableIdentifier := range tableIdentifiers {
+		_, err := c.database.ExecContext(c.ctx, fmt.Sprintf("DROP TABLE IF EXISTS %s", tableIdentifier))
+		if err != nil {
+			return fmt.Errorf("unable to drop table %s: %w", tableIdentifier, err)
+		}
+	}
+	return nil
+}
+
+// TruncateTables removes every row of the tables.
+func (c *SnowflakeConnector) TruncateTables(tableIdentifiers []string) error {
+	for _, tableIdentifier := range tableIdentifiers {
+		_, err := c.database.ExecContext(c.ctx, fmt.Sprintf("TRUNCATE TABLE IF EXISTS %s", tableIdentifier))
+		if err != nil {
+			return fmt.Errorf("unable to truncate table %s: %w", tableIdentifier, err)
+		}
+	}
+	return nil
+}
+
+// ReplaceTables swaps each table with its copy, then drops the copies which
+// now hold the replaced tables.
+func (c *SnowflakeConnector) ReplaceTables(replacements map[string]string) error {
+	replaceTablesTx, err := c.database.BeginTx(c.ctx, nil)
+	if err != nil {
+		return fmt.Errorf("unable to begin transaction for replacing tables: %w", err)
+	}
+	defer func() {
+		deferErr := replaceTablesTx.Rollback()
+		if deferErr != sql.ErrTxDone && deferErr != nil {
+			log.Errorf("unexpected error while rolling back transaction for replacing tables: %v", deferErr)
+		}
+	}()
+
+	for tableIdentifier, copyIdentifier := range replacements {
+		_, err = replaceTablesTx.ExecContext(c.ctx,
+			fmt.Sprintf("ALTER TABLE %s SWAP WITH %s", tableIdentifier, copyIdentifier))
+		if err != nil {
+			return fmt.Errorf("unable to swap table %s with %s: %w", tableIdentifier, copyIdentifier, err)
+		}
+		_, err = replaceTablesTx.ExecContext(c.ctx, fmt.Sprintf("DROP TABLE IF EXISTS %s", copyIdentifier))
+		if err != nil {
+			return fmt.Errorf("unable to drop table %s: %w", copyIdentifier, err)
+		}
+	}
+
+	err = replaceTablesTx.Commit()
+	if err != nil {
+		return fmt.Errorf("unable to commit transaction for replacing tables: %w", err)
+	}
+	return nil
+}
+
 func (c *SnowflakeConnector) checkIfTableExists(schemaIdentifier string, tableIdentifier string) (bool, error) {
 	rows, err := c.database.QueryContext(c.ctx, checkIfTableExistsSQL, schemaIdentifier, tableIdentifier)
 	if err != nil {
@@ -885,6 +954,8 @@ func (c *SnowflakeConnector) checkIfTableExists(schemaIdentifier string, tableId
 func generateCreateTableSQLForNormalizedTable(
 	sourceTableIdentifier string,
 	sourceTableSchema *protos.TableSchema,
+	softDeleteColName string,
+	syncedAtColName string,
 ) string {
 	createTableSQLArray := make([]string, 0, len(sourceTableSchema.Columns))
 	for columnName, genericColumnType := range sourceTableSchema.Columns {
@@ -896,7 +967,13 @@ func generateCreateTableSQLForNormalizedTable(
 	// add a _peerdb_is_deleted column to the normalized table
 	// this is boolean default false, and is used to mark records as deleted
 	createTableSQLArray = append(createTableSQLArray,
-		fmt.Sprintf(`"%s" BOOLEAN DEFAULT FALSE,`, isDeletedColumnName))
+		fmt.Sprintf(`"%s" BOOLEAN DEFAULT FALSE,`, softDeleteColumnName(softDeleteColName)))
+
+	// rows copied in by the initial load get the time they were copied from the default
+	if syncedAtColName != "" {
+		createTableSQLArray = append(createTableSQLArray,
+			fmt.Sprintf(`"%s" TIMESTAMP_NTZ DEFAULT SYSDATE(),`, strings.ToUpper(syncedAtColName)))
+	}
 
 	// add composite primary key to the table
 	primaryKeyColsUpperQuoted := make([]string, 0)
@@ -947,7 +1024,7 @@ func (c *SnowflakeConnector) generateAndExecuteMergeStatement(
 	rawTableIdentifier string,
 	syncBatchID int64,
 	normalizeBatchID int64,
-	softDelete bool,
+	req *model.NormalizeRecordsRequest,
 	normalizeRecordsTx *sql.Tx,
 ) (int64, error) {
 	normalizedTableSchema := c.tableSchemaMapping[destinationTableIdentifier]
@@ -979,6 +1056,18 @@ func (c *SnowflakeConnector) generateAndExecuteMergeStatement(
 				toVariantColumnName, columnName, sfType, targetColumnName))
 		}
 	}
+	// the metadata columns aren't in the raw table, they are set as the records are merged and
+	// otherwise inserted and updated like the columns of the source table.
+	softDeleteColName := softDeleteColumnName(req.SoftDeleteColName)
+	if req.SoftDelete {
+		flattenedCastsSQLArray = append(flattenedCastsSQLArray, fmt.Sprintf(`FALSE AS "%s",`, softDeleteColName))
+		columnNames = append(columnNames, softDeleteColName)
+	}
+	syncedAtColName := strings.ToUpper(req.SyncedAtColName)
+	if syncedAtColName != "" {
+		flattenedCastsSQLArray = append(flattenedCastsSQLArray, fmt.Sprintf(`SYSDATE() AS "%s",`, syncedAtColName))
+		columnNames = append(columnNames, syncedAtColName)
+	}
 	flattenedCastsSQL := strings.TrimSuffix(strings.Join(flattenedCastsSQLArray, ""), ",")
 
 	quotedUpperColNames := make([]string, 0, len(columnNames))
@@ -1006,8 +1095,11 @@ func (c *SnowflakeConnector) generateAndExecuteMergeStatement(
 	pkeySelectSQL := strings.Join(pkeySelectSQLArray, " AND ")
 
 	deletePart := "DELETE"
-	if softDelete {
-		deletePart = fmt.Sprintf("UPDATE SET %s = TRUE", isDeletedColumnName)
+	if req.SoftDelete {
+		deletePart = fmt.Sprintf(`UPDATE SET "%s" = TRUE`, softDeleteColName)
+		if syncedAtColName != "" {
+			deletePart += fmt.Sprintf(`, "%s" = SYSDATE()`, syncedAtColName)
+		}
 	}
 
 	mergeStatement := fmt.Sprintf(mergeStatementSQL, destinationTableIdentifier, toVariantColumnName,
@@ -1024,16 +1116,36 @@ func (c *SnowflakeConnector) generateAndExecuteMergeStatement(
 	return result.RowsAffected()
 }
 
-// parseTableName parses a table name into schema and table name.
+// softDeleteColumnName is the column of the normalized tables deleted records are marked in.
+func softDeleteColumnName(softDeleteColName string) string {
+	if softDeleteColName == "" {
+		return isDeletedColumnName
+	}
+	return strings.ToUpper(softDeleteColName)
+}
+
+// parseTableName parses a table name into schema and table name, unquoted parts fold to upper case
+// like they do in snowflake.
 func parseTableName(tableName string) (*tableNameComponents, error) {
-	parts := strings.Split(tableName, ".")
+	parts, err := utils.ParseIdentifier(tableName)
+	if err != nil {
+		return nil, err
+	}
 	if len(parts) != 2 {
 		return nil, fmt.Errorf("invalid table name: %s", tableName)
 	}
 
+	names := make([]string, 0, len(parts))
+	for _, part := range parts {
+		if part.Quoted {
+			names = append(names, part.Value)
+		} else {
+			names = append(names, strings.ToUpper(part.Value))
+		}
+	}
 	return &tableNameComponents{
-		schemaIdentifier: parts[0],
-		tableIdentifier:  parts[1],
+		schemaIdentifier: names[0],
+		tableIdentifier:  names[1],
 	}, nil
 }
 
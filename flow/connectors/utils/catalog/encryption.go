@@ -0,0 +1,271 @@
+package utils
+
+import (
+	"bytes"
+	"crypto/aes"
+	"crypto/cipher"
+	"crypto/rand"
+	"encoding/base64"
+	"errors"
+	"fmt"
+	"os"
+	"strings"
+	"sync"
+)
+
+// The configs of peers, and the mirror configs that embed them, are encrypted in the catalog the way nexus
+// encrypts them (see catalog/src/encryption.rs), with a data key of their own wrapped by the master key:
+//
+//	magic | key nonce | wrapped data key | data nonce | encrypted config
+//
+// The data key is AES-256-GCM with the name of the peer or mirror as additional data, so a config can't be
+// moved to another one. Configs without the magic prefix are plaintext, a protobuf message can't start with
+// a zero byte.
+var configMagic = []byte("\x00PE1")
+
+const (
+	masterKeyLen  = 32
+	gcmNonceLen   = 12
+	gcmTagLen     = 16
+	wrappedKeyLen = masterKeyLen + gcmTagLen
+)
+
+var (
+	masterKeysOnce sync.Once
+	masterKey      []byte
+	previousKey    []byte
+	masterKeysErr  error
+)
+
+func decodeMasterKey(env string) ([]byte, error) {
+	encoded, ok := os.LookupEnv(env)
+	if !ok || strings.TrimSpace(encoded) == "" {
+		return nil, nil
+	}
+	key, err := DecodeMasterKey(encoded)
+	if err != nil {
+		return nil, fmt.Errorf("%s %w", env, err)
+	}
+	return key, nil
+}
+
+// DecodeMasterKey returns the master key of its base64 encoding, 32 random bytes, e.g. of
+// `openssl rand -base64 32`.
+func DecodeMasterKey(encoded string) ([]byte, error) {
+	key, err := base64.StdEncoding.DecodeString(strings.TrimSpace(encoded))
+	if err != nil {
+		return nil, fmt.Errorf("is not valid base64: %w", err)
+	}
+	if len(key) != masterKeyLen {
+		return nil, fmt.Errorf("must be %d bytes", masterKeyLen)
+	}
+	return key, nil
+}
+
+// catalogMasterKeys returns the master key of PEERDB_CATALOG_MASTER_KEY, nil if it is not set, and the one of
+// PEERDB_CATALOG_PREVIOUS_MASTER_KEY, which configs are also decrypted with while the master key is rotated.
+func catalogMasterKeys() ([]byte, []byte, error) {
+	masterKeysOnce.Do(func() {
+		masterKey, masterKeysErr = decodeMasterKey("PEERDB_CATALOG_MASTER_KEY")
+		if masterKeysErr != nil {
+			return
+		}
+		previousKey, masterKeysErr = decodeMasterKey("PEERDB_CATALOG_PREVIOUS_MASTER_KEY")
+	})
+	return masterKey, previousKey, masterKeysErr
+}
+
+func newGCM(key []byte) (cipher.AEAD, error) {
+	block, err := aes.NewCipher(key)
+	if err != nil {
+		return nil, err
+	}
+	return cipher.NewGCM(block)
+}
+
+// IsConfigEncrypted reports whether a config stored in the catalog is encrypted.
+func IsConfigEncrypted(blob []byte) bool {
+	return bytes.HasPrefix(blob, configMagic)
+}
+
+// SealConfig encrypts a config to store in the catalog, for the peer or mirror of the name, with a new
+// data key. The config is stored in plaintext if no master key is set.
+func SealConfig(name string, config []byte) ([]byte, error) {
+	key, _, err := catalogMasterKeys()
+	if err != nil {
+		return nil, err
+	}
+	if key == nil {
+		return config, nil
+	}
+	return sealConfig(key, name, config)
+}
+
+// sealConfig encrypts a config with a new data key wrapped by the master key.
+func sealConfig(key []byte, name string, config []byte) ([]byte, error) {
+	dataKey := make([]byte, masterKeyLen)
+	if _, err := rand.Read(dataKey); err != nil {
+		return nil, fmt.Errorf("unable to generate a data key: %w", err)
+	}
+	keyNonce := make([]byte, gcmNonceLen)
+	dataNonce := make([]byte, gcmNonceLen)
+	if _, err := rand.Read(keyNonce); err != nil {
+		return nil, fmt.Errorf("unable to generate a nonce: %w", err)
+	}
+	if _, err := rand.Read(dataNonce); err != nil {
+		return nil, fmt.Errorf("unable to generate a nonce: %w", err)
+	}
+	return sealConfigWith(key, name, config, dataKey, keyNonce, dataNonce)
+}
+
+// sealConfigWith encrypts a config with the data key and nonces given, which are random but in tests.
+func sealConfigWith(
+	key []byte, name string, config []byte, dataKey []byte, keyNonce []byte, dataNonce []byte,
+) ([]byte, error) {
+	keyGCM, err := newGCM(key)
+	if err != nil {
+		return nil, err
+	}
+	dataGCM, err := newGCM(dataKey)
+	if err != nil {
+		return nil, err
+	}
+
+	blob := make([]byte, 0, len(configMagic)+2*gcmNonceLen+wrappedKeyLen+len(config)+gcmTagLen)
+	blob = append(blob, configMagic...)
+	blob = append(blob, keyNonce...)
+	blob = keyGCM.Seal(blob, keyNonce, dataKey, configMagic)
+	blob = append(blob, dataNonce...)
+	blob = dataGCM.Seal(blob, dataNonce, config, []byte(name))
+	return blob, nil
+}
+
+// OpenConfig returns a config stored in the catalog for the peer or mirror of the name, decrypted if it is
+// encrypted.
+func OpenConfig(name string, blob []byte) ([]byte, error) {
+	if !IsConfigEncrypted(blob) {
+		return blob, nil
+	}
+	key, previous, err := catalogMasterKeys()
+	if err != nil {
+		return nil, err
+	}
+	if key == nil {
+		return nil, fmt.Errorf("the config of %s is encrypted, but PEERDB_CATALOG_MASTER_KEY is not set", name)
+	}
+
+	env, err := parseEnvelope(blob)
+	if err != nil {
+		return nil, err
+	}
+	dataKey, err := unwrapDataKey(key, env.keyNonce, env.wrappedKey)
+	if err != nil && previous != nil {
+		// the data key may still be wrapped by the previous key while the master key is rotated.
+		dataKey, err = unwrapDataKey(previous, env.keyNonce, env.wrappedKey)
+	}
+	if err != nil {
+		return nil, fmt.Errorf("unable to unwrap the data key of %s, is the master key the one it was "+
+			"encrypted with?", name)
+	}
+	return openData(dataKey, name, env)
+}
+
+// envelope is the parts of an encrypted config.
+type envelope struct {
+	keyNonce   []byte
+	wrappedKey []byte
+	dataNonce  []byte
+	encrypted  []byte
+}
+
+func parseEnvelope(blob []byte) (envelope, error) {
+	if !IsConfigEncrypted(blob) {
+		return envelope{}, errors.New("the config is not encrypted")
+	}
+	rest := blob[len(configMagic):]
+	if len(rest) < 2*gcmNonceLen+wrappedKeyLen+gcmTagLen {
+		return envelope{}, errors.New("the encrypted config is truncated")
+	}
+	keyNonce, rest := rest[:gcmNonceLen], rest[gcmNonceLen:]
+	wrappedKey, rest := rest[:wrappedKeyLen], rest[wrappedKeyLen:]
+	dataNonce, encrypted := rest[:gcmNonceLen], rest[gcmNonceLen:]
+	return envelope{
+		keyNonce:   keyNonce,
+		wrappedKey: wrappedKey,
+		dataNonce:  dataNonce,
+		encrypted:  encrypted,
+	}, nil
+}
+
+func openData(dataKey []byte, name string, env envelope) ([]byte, error) {
+	dataGCM, err := newGCM(dataKey)
+	if err != nil {
+		return nil, err
+	}
+	config, err := dataGCM.Open(nil, env.dataNonce, env.encrypted, []byte(name))
+	if err != nil {
+		return nil, fmt.Errorf("the encrypted config of %s is not authentic", name)
+	}
+	return config, nil
+}
+
+// openConfigWith decrypts an encrypted config whose data key is wrapped by the master key.
+func openConfigWith(key []byte, name string, blob []byte) ([]byte, error) {
+	env, err := parseEnvelope(blob)
+	if err != nil {
+		return nil, err
+	}
+	dataKey, err := unwrapDataKey(key, env.keyNonce, env.wrappedKey)
+	if err != nil {
+		return nil, fmt.Errorf("unable to unwrap the data key of %s, is the master key the one it was "+
+			"encrypted with?", name)
+	}
+	return openData(dataKey, name, env)
+}
+
+// wrapsConfig reports whether the data key of an encrypted config is wrapped by the master key.
+func wrapsConfig(key []byte, blob []byte) bool {
+	env, err := parseEnvelope(blob)
+	if err != nil {
+		return false
+	}
+	_, err = unwrapDataKey(key, env.keyNonce, env.wrappedKey)
+	return err == nil
+}
+
+// rewrapConfig returns an encrypted config with its data key wrapped by the new master key instead of the
+// old one. The config itself stays encrypted as it is.
+func rewrapConfig(oldKey []byte, newKey []byte, blob []byte) ([]byte, error) {
+	env, err := parseEnvelope(blob)
+	if err != nil {
+		return nil, err
+	}
+	dataKey, err := unwrapDataKey(oldKey, env.keyNonce, env.wrappedKey)
+	if err != nil {
+		return nil, errors.New("unable to unwrap the data key with the old key")
+	}
+	keyGCM, err := newGCM(newKey)
+	if err != nil {
+		return nil, err
+	}
+	keyNonce := make([]byte, gcmNonceLen)
+	if _, err := rand.Read(keyNonce); err != nil {
+		return nil, fmt.Errorf("unable to generate a nonce: %w", err)
+	}
+
+	rewrapped := make([]byte, 0, len(blob))
+	rewrapped = append(rewrapped, configMagic...)
+	rewrapped = append(rewrapped, keyNonce...)
+	rewrapped = keyGCM.Seal(rewrapped, keyNonce, dataKey, configMagic)
+	rewrapped = append(rewrapped, env.dataNonce...)
+	rewrapped = append(rewrapped, env.encrypted...)
+	return rewrapped, nil
+}
+
+func unwrapDataKey(key []byte, nonce []byte, wrappedKey []byte) ([]byte, error) {
+	keyGCM, err := newGCM(key)
+	if err != nil {
+		return nil, err
+	}
+	return keyGCM.Open(nil, nonce, wrappedKey, configMagic)
+}
@@ -0,0 +1,92 @@
+package utils
+
+import (
+	"encoding/base64"
+	"encoding/json"
+	"os"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+// envelopesFixture is nexus/catalog/tests/assets/envelopes.json, configs nexus encrypted with the data keys
+// and nonces they list, which nexus tests against as well.
+type envelopesFixture struct {
+	MasterKey string `json:"master_key"`
+	Envelopes []struct {
+		Name      string `json:"name"`
+		Config    string `json:"config"`
+		DataKey   string `json:"data_key"`
+		KeyNonce  string `json:"key_nonce"`
+		DataNonce string `json:"data_nonce"`
+		Envelope  string `json:"envelope"`
+	} `json:"envelopes"`
+}
+
+func decodeBase64(t *testing.T, encoded string) []byte {
+	t.Helper()
+	decoded, err := base64.StdEncoding.DecodeString(encoded)
+	require.NoError(t, err)
+	return decoded
+}
+
+func TestEnvelopesMatchNexus(t *testing.T) {
+	data, err := os.ReadFile("../../../../nexus/catalog/tests/assets/envelopes.json")
+	require.NoError(t, err)
+	var fixture envelopesFixture
+	require.NoError(t, json.Unmarshal(data, &fixture))
+	key, err := DecodeMasterKey(fixture.MasterKey)
+	require.NoError(t, err)
+	require.NotEmpty(t, fixture.Envelopes)
+
+	for _, envelope := range fixture.Envelopes {
+		t.Run(envelope.Name, func(t *testing.T) {
+			config := decodeBase64(t, envelope.Config)
+			blob := decodeBase64(t, envelope.Envelope)
+
+			// configs nexus encrypted open here, and the same data key and nonces encrypt them the same way.
+			opened, err := openConfigWith(key, envelope.Name, blob)
+			require.NoError(t, err)
+			require.Equal(t, string(config), string(opened))
+			sealed, err := sealConfigWith(key, envelope.Name, config, decodeBase64(t, envelope.DataKey),
+				decodeBase64(t, envelope.KeyNonce), decodeBase64(t, envelope.DataNonce))
+			require.NoError(t, err)
+			require.Equal(t, blob, sealed)
+
+			_, err = openConfigWith(key, envelope.Name+"_other", blob)
+			require.Error(t, err)
+		})
+	}
+}
+
+func TestSealedConfigsOpen(t *testing.T) {
+	key := make([]byte, masterKeyLen)
+	otherKey := make([]byte, masterKeyLen)
+	for i := range key {
+		key[i] = 1
+		otherKey[i] = 2
+	}
+	config := []byte("\x0a\x09localhost")
+
+	blob, err := sealConfig(key, "pg_peer", config)
+	require.NoError(t, err)
+	require.True(t, IsConfigEncrypted(blob))
+	require.False(t, IsConfigEncrypted(config))
+	opened, err := openConfigWith(key, "pg_peer", blob)
+	require.NoError(t, err)
+	require.Equal(t, config, opened)
+
+	// each config has a data key of its own.
+	again, err := sealConfig(key, "pg_peer", config)
+	require.NoError(t, err)
+	require.NotEqual(t, blob, again)
+
+	require.True(t, wrapsConfig(key, blob))
+	require.False(t, wrapsConfig(otherKey, blob))
+	_, err = openConfigWith(otherKey, "pg_peer", blob)
+	require.Error(t, err)
+	_, err = openConfigWith(key, "pg_peer", blob[:len(blob)-1])
+	require.Error(t, err)
+	_, err = openConfigWith(key, "pg_peer", config)
+	require.Error(t, err)
+}
@@ -31,36 +31,46 @@ func GetCatalogConnectionPoolFromEnv() (*pgxpool.Pool, error) {
 }
 
 func genCatalogConnectionString() (string, error) {
+	catalogConfig, err := GetCatalogPostgresConfigFromEnv()
+	if err != nil {
+		return "", err
+	}
+	return utils.GetPGConnectionString(catalogConfig), nil
+}
+
+// GetCatalogPostgresConfigFromEnv returns the config of the catalog, for connectors whose peers have no
+// metadata database of their own to keep the state of their mirrors in.
+func GetCatalogPostgresConfigFromEnv() (*protos.PostgresConfig, error) {
 	host, ok := os.LookupEnv("PEERDB_CATALOG_HOST")
 	if !ok {
-		return "", fmt.Errorf("PEERDB_CATALOG_HOST is not set")
+		return nil, fmt.Errorf("PEERDB_CATALOG_HOST is not set")
 	}
 	portStr, ok := os.LookupEnv("PEERDB_CATALOG_PORT")
 	if !ok {
-		return "", fmt.Errorf("PEERDB_CATALOG_PORT is not set")
+		return nil, fmt.Errorf("PEERDB_CATALOG_PORT is not set")
 	}
 	port, err := strconv.ParseUint(portStr, 10, 32)
 	if err != nil {
-		return "", fmt.Errorf("unable to parse PEERDB_CATALOG_PORT as unsigned integer")
+		return nil, fmt.Errorf("unable to parse PEERDB_CATALOG_PORT as unsigned integer")
 	}
 	user, ok := os.LookupEnv("PEERDB_CATALOG_USER")
 	if !ok {
-		return "", fmt.Errorf("PEERDB_CATALOG_USER is not set")
+		return nil, fmt.Errorf("PEERDB_CATALOG_USER is not set")
 	}
 	password, ok := os.LookupEnv("PEERDB_CATALOG_PASSWORD")
 	if !ok {
-		return "", fmt.Errorf("PEERDB_CATALOG_PASSWORD is not set")
+		return nil, fmt.Errorf("PEERDB_CATALOG_PASSWORD is not set")
 	}
 	database, ok := os.LookupEnv("PEERDB_CATALOG_DATABASE")
 	if !ok {
-		return "", fmt.Errorf("PEERDB_CATALOG_DATABASE is not set")
+		return nil, fmt.Errorf("PEERDB_CATALOG_DATABASE is not set")
 	}
 
-	return utils.GetPGConnectionString(&protos.PostgresConfig{
+	return &protos.PostgresConfig{
 		Host:     host,
 		Port:     uint32(port),
 		User:     user,
 		Password: password,
 		Database: database,
-	}), nil
+	}, nil
 }
@@ -0,0 +1,180 @@
+package utils
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// the configs stored in the catalog, with the query that selects them by the name they are encrypted for
+// and the one that replaces a config, as long as it is still the one that was selected. Kept the same as
+// STORED_CONFIGS of nexus (see catalog/src/lib.rs).
+var storedConfigs = []struct {
+	table  string
+	query  string
+	update string
+}{
+	{
+		table:  "peers",
+		query:  "SELECT name, options FROM peers",
+		update: "UPDATE peers SET options = $1 WHERE name = $2 AND options = $3",
+	},
+	{
+		table:  "peer_versions",
+		query:  "SELECT DISTINCT p.name, v.options FROM peer_versions v JOIN peers p ON p.id = v.peer_id",
+		update: "UPDATE peer_versions SET options = $1 " +
+			"WHERE peer_id = (SELECT id FROM peers WHERE name = $2) AND options = $3",
+	},
+	{
+		table:  "flows",
+		query:  "SELECT DISTINCT name, config_proto FROM flows WHERE config_proto IS NOT NULL",
+		update: "UPDATE flows SET config_proto = $1 WHERE name = $2 AND config_proto = $3",
+	},
+	{
+		table:  "peerdb_stats.qrep_runs",
+		query:  "SELECT DISTINCT flow_name, config_proto FROM peerdb_stats.qrep_runs " +
+			"WHERE config_proto IS NOT NULL",
+		update: "UPDATE peerdb_stats.qrep_runs SET config_proto = $1 WHERE flow_name = $2 AND config_proto = $3",
+	},
+}
+
+// how many configs of a table are rotated between two calls of the progress of a rotation.
+const keyRotationProgressInterval = 100
+
+// KeyRotation is the configs RotateMasterKey rotated.
+type KeyRotation struct {
+	// configs whose data key is now wrapped by the new key.
+	Rotated int64
+	// configs stored in plaintext that are now encrypted with the new key.
+	Encrypted int64
+	// configs checked to decrypt with the new key once all are rotated.
+	Verified int64
+}
+
+type storedConfig struct {
+	name   string
+	config []byte
+}
+
+func selectConfigs(ctx context.Context, pool *pgxpool.Pool, query string) ([]storedConfig, error) {
+	rows, err := pool.Query(ctx, query)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var configs []storedConfig
+	for rows.Next() {
+		var config storedConfig
+		if err := rows.Scan(&config.name, &config.config); err != nil {
+			return nil, err
+		}
+		configs = append(configs, config)
+	}
+	return configs, rows.Err()
+}
+
+// RotateMasterKey wraps the data keys of the configs stored in the catalog with the new master key instead
+// of the old one, and encrypts the configs stored in plaintext with it, the way nexus rotates them. Each
+// config is checked to decrypt to the same config with the new key before it replaces the old one, and once
+// all are, every config in the catalog is checked to decrypt with the new key.
+//
+// The new key must be PEERDB_CATALOG_MASTER_KEY, so that the configs stored while they are rotated are
+// encrypted with it, and the old key PEERDB_CATALOG_PREVIOUS_MASTER_KEY. progress is called as the configs
+// of each table are rotated, with its name, how many of its configs are done and how many it has.
+func RotateMasterKey(
+	ctx context.Context,
+	pool *pgxpool.Pool,
+	oldKey []byte,
+	newKey []byte,
+	progress func(table string, done int, total int) error,
+) (KeyRotation, error) {
+	masterKey, previousKey, err := catalogMasterKeys()
+	if err != nil {
+		return KeyRotation{}, err
+	}
+	if masterKey == nil || !bytes.Equal(masterKey, newKey) {
+		return KeyRotation{}, errors.New("the new key is not the master key of the catalog, configs stored " +
+			"while they are rotated would be encrypted with another key")
+	}
+	if previousKey == nil || !bytes.Equal(previousKey, oldKey) {
+		return KeyRotation{}, errors.New("the old key is not the previous master key of the catalog, the " +
+			"configs still encrypted with it couldn't be read while they are rotated")
+	}
+
+	var rotation KeyRotation
+	for _, stored := range storedConfigs {
+		configs, err := selectConfigs(ctx, pool, stored.query)
+		if err != nil {
+			return rotation, fmt.Errorf("unable to select the configs of %s: %w", stored.table, err)
+		}
+		for done, config := range configs {
+			storedEncrypted := IsConfigEncrypted(config.config)
+			// the configs stored with the new key since the rotation started, or rotated by an earlier one,
+			// are left as they are.
+			if !storedEncrypted || !wrapsConfig(newKey, config.config) {
+				var replacement, plaintext []byte
+				if storedEncrypted {
+					plaintext, err = openConfigWith(oldKey, config.name, config.config)
+					if err != nil {
+						return rotation, fmt.Errorf("the config of %s in %s is encrypted with neither key: %w",
+							config.name, stored.table, err)
+					}
+					replacement, err = rewrapConfig(oldKey, newKey, config.config)
+				} else {
+					plaintext = config.config
+					replacement, err = sealConfig(newKey, config.name, config.config)
+				}
+				if err != nil {
+					return rotation, err
+				}
+				rotated, err := openConfigWith(newKey, config.name, replacement)
+				if err != nil || !bytes.Equal(rotated, plaintext) {
+					return rotation, fmt.Errorf("the rotated config of %s in %s doesn't decrypt to the config, "+
+						"it is left as it was", config.name, stored.table)
+				}
+
+				tag, err := pool.Exec(ctx, stored.update, replacement, config.name, config.config)
+				if err != nil {
+					return rotation, fmt.Errorf("unable to replace the config of %s in %s: %w",
+						config.name, stored.table, err)
+				}
+				if storedEncrypted {
+					rotation.Rotated += tag.RowsAffected()
+				} else {
+					rotation.Encrypted += tag.RowsAffected()
+				}
+			}
+			if (done+1)%keyRotationProgressInterval == 0 || done+1 == len(configs) {
+				if err := progress(stored.table, done+1, len(configs)); err != nil {
+					return rotation, err
+				}
+			}
+		}
+	}
+
+	// configs an update raced with, or stored by a process running with the old key, are left for another
+	// rotation.
+	for _, stored := range storedConfigs {
+		configs, err := selectConfigs(ctx, pool, stored.query)
+		if err != nil {
+			return rotation, fmt.Errorf("unable to select the configs of %s: %w", stored.table, err)
+		}
+		for _, config := range configs {
+			if !IsConfigEncrypted(config.config) || !wrapsConfig(newKey, config.config) {
+				return rotation, fmt.Errorf("the config of %s in %s was stored with another key while the "+
+					"configs were rotated, rotate them again once every server runs with the new key",
+					config.name, stored.table)
+			}
+			if _, err := openConfigWith(newKey, config.name, config.config); err != nil {
+				return rotation, fmt.Errorf("the config of %s in %s doesn't decrypt: %w",
+					config.name, stored.table, err)
+			}
+			rotation.Verified++
+		}
+	}
+	return rotation, nil
+}
@@ -0,0 +1,60 @@
+package utils
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/http"
+
+	"github.com/cenkalti/backoff/v4"
+)
+
+// bodies of error responses are cut short in errors.
+const maxErrorBodyLen = 4096
+
+// SendHTTPRequest sends the request made by newRequest and returns the body of the response, retrying
+// with exponential backoff up to maxRetries times while the request fails to be sent or the server is
+// throttling or unavailable. Other unsuccessful responses fail right away.
+func SendHTTPRequest(ctx context.Context, client *http.Client, maxRetries uint64,
+	newRequest func() (*http.Request, error)) ([]byte, error) {
+	var body []byte
+	operation := func() error {
+		req, err := newRequest()
+		if err != nil {
+			return backoff.Permanent(err)
+		}
+		resp, err := client.Do(req.WithContext(ctx))
+		if err != nil {
+			return err
+		}
+		defer resp.Body.Close()
+
+		if resp.StatusCode >= 200 && resp.StatusCode < 300 {
+			body, err = io.ReadAll(resp.Body)
+			return err
+		}
+		errBody, _ := io.ReadAll(io.LimitReader(resp.Body, maxErrorBodyLen))
+		err = fmt.Errorf("%s %s returned %s: %s", req.Method, req.URL.Path, resp.Status, errBody)
+		if resp.StatusCode == http.StatusTooManyRequests || resp.StatusCode >= 500 {
+			return err
+		}
+		return backoff.Permanent(&HTTPStatusError{StatusCode: resp.StatusCode, err: err})
+	}
+
+	err := backoff.Retry(operation,
+		backoff.WithContext(backoff.WithMaxRetries(backoff.NewExponentialBackOff(), maxRetries), ctx))
+	if err != nil {
+		return nil, err
+	}
+	return body, nil
+}
+
+// HTTPStatusError is a request that the server refused with a status that isn't retried.
+type HTTPStatusError struct {
+	StatusCode int
+	err        error
+}
+
+func (e *HTTPStatusError) Error() string {
+	return e.err.Error()
+}
@@ -1,7 +1,68 @@
 package utils
 
-import "fmt"
+import (
+	"fmt"
+	"strings"
+)
 
 func QuoteIdentifier(identifier string) string {
-	return fmt.Sprintf(`"%s"`, identifier)
+	return fmt.Sprintf(`"%s"`, strings.ReplaceAll(identifier, `"`, `""`))
+}
+
+// QuoteIdentifierIfNeeded quotes the identifier unless it is a plain lower case name, the way the table
+// identifiers of a mirror are written.
+func QuoteIdentifierIfNeeded(identifier string) string {
+	if identifier == "" {
+		return QuoteIdentifier(identifier)
+	}
+	for i, c := range identifier {
+		isPlain := (c >= 'a' && c <= 'z') || c == '_' || (i > 0 && c >= '0' && c <= '9')
+		if !isPlain {
+			return QuoteIdentifier(identifier)
+		}
+	}
+	return identifier
+}
+
+// IdentifierPart is a part of a dotted identifier, with the quotes of a quoted part removed.
+type IdentifierPart struct {
+	Value  string
+	Quoted bool
+}
+
+// ParseIdentifier splits an identifier like schema."My Table" into its parts on the dots outside of
+// double quotes. Quoted parts keep their case, two double quotes in them stand for one.
+func ParseIdentifier(identifier string) ([]IdentifierPart, error) {
+	parts := make([]IdentifierPart, 0, 2)
+	var part strings.Builder
+	quoted, inQuotes := false, false
+	for i := 0; i < len(identifier); i++ {
+		c := identifier[i]
+		switch {
+		case inQuotes && c == '"' && i+1 < len(identifier) && identifier[i+1] == '"':
+			part.WriteByte('"')
+			i++
+		case c == '"' && (inQuotes || part.Len() == 0):
+			inQuotes = !inQuotes
+			quoted = true
+		case c == '.' && !inQuotes:
+			if part.Len() == 0 && !quoted {
+				return nil, fmt.Errorf("empty part in identifier %s", identifier)
+			}
+			parts = append(parts, IdentifierPart{Value: part.String(), Quoted: quoted})
+			part.Reset()
+			quoted = false
+		case quoted && !inQuotes:
+			return nil, fmt.Errorf("unexpected character after quoted part in identifier %s", identifier)
+		default:
+			part.WriteByte(c)
+		}
+	}
+	if inQuotes {
+		return nil, fmt.Errorf("unterminated quoted part in identifier %s", identifier)
+	}
+	if part.Len() == 0 && !quoted {
+		return nil, fmt.Errorf("empty part in identifier %s", identifier)
+	}
+	return append(parts, IdentifierPart{Value: part.String(), Quoted: quoted}), nil
 }
@@ -79,6 +79,21 @@ func LogNormalizeMetrics(
 	totalRecordsAtTargetGauge.Update(float64(totalRecordsAtTarget))
 }
 
+// LogLatencyMetrics reports the percentiles of the time between the changes of a batch being committed at
+// the source and being applied to the destination.
+func LogLatencyMetrics(ctx context.Context, flowJobName string, p50 time.Duration, p99 time.Duration) {
+	if ctx.Value(shared.EnableMetricsKey) != true {
+		return
+	}
+
+	metricsHandler := activity.GetMetricsHandler(ctx)
+	latencyP50Gauge := metricsHandler.Gauge(fmt.Sprintf("cdcflow.%s.latency_p50_seconds", flowJobName))
+	latencyP99Gauge := metricsHandler.Gauge(fmt.Sprintf("cdcflow.%s.latency_p99_seconds", flowJobName))
+
+	latencyP50Gauge.Update(p50.Seconds())
+	latencyP99Gauge.Update(p99.Seconds())
+}
+
 func LogQRepPullMetrics(ctx context.Context, flowJobName string,
 	numRecords int, totalRecordsAtSource int64) {
 	if ctx.Value(shared.EnableMetricsKey) != true {
@@ -5,12 +5,14 @@ import (
 	"fmt"
 	"time"
 
+	catalog "github.com/PeerDB-io/peer-flow/connectors/utils/catalog"
 	"github.com/PeerDB-io/peer-flow/generated/protos"
 	"github.com/jackc/pglogrepl"
 	"github.com/jackc/pgx/v5"
 	"github.com/jackc/pgx/v5/pgtype"
 	"github.com/jackc/pgx/v5/pgxpool"
 	log "github.com/sirupsen/logrus"
+	"google.golang.org/protobuf/encoding/protojson"
 	"google.golang.org/protobuf/proto"
 )
 
@@ -72,6 +74,32 @@ func (c *CatalogMirrorMonitor) UpdateLatestLSNAtSourceForCDCFlow(ctx context.Con
 	return nil
 }
 
+// lsnTimestampRetention is how long samples of a source's LSN are kept for.
+const lsnTimestampRetention = 7 * 24 * time.Hour
+
+// AddLSNTimestampSample records that the source of the flow was at the given LSN
+// at sampledAt, and drops samples older than the retention period.
+func (c *CatalogMirrorMonitor) AddLSNTimestampSample(ctx context.Context, flowJobName string,
+	lsn pglogrepl.LSN, sampledAt time.Time) error {
+	if c == nil || c.catalogConn == nil {
+		return nil
+	}
+
+	_, err := c.catalogConn.Exec(ctx,
+		`INSERT INTO peerdb_stats.lsn_timestamps(flow_name,lsn,sampled_at) VALUES($1,$2,$3)
+		 ON CONFLICT DO NOTHING`, flowJobName, uint64(lsn), sampledAt)
+	if err != nil {
+		return fmt.Errorf("error while inserting into lsn_timestamps: %w", err)
+	}
+	_, err = c.catalogConn.Exec(ctx,
+		"DELETE FROM peerdb_stats.lsn_timestamps WHERE flow_name=$1 AND sampled_at<$2",
+		flowJobName, sampledAt.Add(-lsnTimestampRetention))
+	if err != nil {
+		return fmt.Errorf("error while pruning lsn_timestamps: %w", err)
+	}
+	return nil
+}
+
 func (c *CatalogMirrorMonitor) UpdateLatestLSNAtTargetForCDCFlow(ctx context.Context, flowJobName string,
 	latestLSNAtTarget pglogrepl.LSN) error {
 	if c == nil || c.catalogConn == nil {
@@ -119,6 +147,24 @@ func (c *CatalogMirrorMonitor) UpdateEndTimeForCDCBatch(ctx context.Context, flo
 	return nil
 }
 
+// UpdateLatencyForCDCBatch records the percentiles of the time between the changes of a batch being
+// committed at the source and being applied to the destination.
+func (c *CatalogMirrorMonitor) UpdateLatencyForCDCBatch(ctx context.Context, flowJobName string,
+	batchID int64, p50 time.Duration, p99 time.Duration) error {
+	if c == nil || c.catalogConn == nil {
+		return nil
+	}
+
+	_, err := c.catalogConn.Exec(ctx,
+		`UPDATE peerdb_stats.cdc_batches SET latency_p50_seconds=$1,latency_p99_seconds=$2
+		 WHERE flow_name=$3 AND batch_id=$4`,
+		p50.Seconds(), p99.Seconds(), flowJobName, batchID)
+	if err != nil {
+		return fmt.Errorf("error while updating latency of batch in cdc_batch: %w", err)
+	}
+	return nil
+}
+
 func (c *CatalogMirrorMonitor) AddCDCBatchTablesForFlow(ctx context.Context, flowJobName string,
 	batchID int64, tableNameRowsMapping map[string]uint32) error {
 	if c == nil || c.catalogConn == nil {
@@ -175,6 +221,11 @@ func (c *CatalogMirrorMonitor) InitializeQRepRun(
 	if err != nil {
 		return fmt.Errorf("unable to marshal flow config: %w", err)
 	}
+	// the config carries the configs of the source and destination peers.
+	cfgBytes, err = catalog.SealConfig(flowJobName, cfgBytes)
+	if err != nil {
+		return fmt.Errorf("unable to encrypt flow config: %w", err)
+	}
 
 	_, err = c.catalogConn.Exec(ctx,
 		"UPDATE peerdb_stats.qrep_runs SET config_proto = $1 WHERE flow_name = $2",
@@ -322,3 +373,28 @@ func (c *CatalogMirrorMonitor) UpdateEndTimeForPartition(ctx context.Context, ru
 	}
 	return nil
 }
+
+func (c *CatalogMirrorMonitor) AddSchemaDeltas(ctx context.Context, flowJobName string,
+	schemaDeltas []*protos.TableSchemaDelta) error {
+	if c == nil || c.catalogConn == nil {
+		return nil
+	}
+
+	for _, schemaDelta := range schemaDeltas {
+		if schemaDelta == nil || len(schemaDelta.AddedColumns) == 0 {
+			continue
+		}
+
+		deltaInfo, err := protojson.Marshal(schemaDelta)
+		if err != nil {
+			return fmt.Errorf("unable to marshal schema delta for table %s: %w", schemaDelta.SrcTableName, err)
+		}
+		_, err = c.catalogConn.Exec(ctx,
+			"INSERT INTO peerdb_stats.schema_deltas_audit_log(flow_job_name,delta_info) VALUES($1,$2)",
+			flowJobName, deltaInfo)
+		if err != nil {
+			return fmt.Errorf("error while inserting into schema_deltas_audit_log: %w", err)
+		}
+	}
+	return nil
+}
@@ -6,6 +6,7 @@ import (
 	"net/url"
 
 	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
 	"github.com/jackc/pgx/v5/pgxpool"
 )
 
@@ -30,7 +31,8 @@ func GetCustomDataTypes(ctx context.Context, pool *pgxpool.Pool) (map[uint32]str
 		LEFT JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
 		WHERE (t.typrelid = 0 OR (SELECT c.relkind = 'c' FROM pg_catalog.pg_class c WHERE c.oid = t.typrelid))
 		AND NOT EXISTS(SELECT 1 FROM pg_catalog.pg_type el WHERE el.oid = t.typelem AND el.typarray = t.oid)
-		AND n.nspname NOT IN ('pg_catalog', 'information_schema');
+		AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+		AND t.typtype <> 'd';
 	`)
 	if err != nil {
 		return nil, fmt.Errorf("failed to get custom types: %w", err)
@@ -47,3 +49,84 @@ func GetCustomDataTypes(ctx context.Context, pool *pgxpool.Pool) (map[uint32]str
 	}
 	return customTypeMap, nil
 }
+
+// GetDomainBaseTypes maps the oid of every domain to the oid of the type it is
+// ultimately defined over, resolving domains over other domains.
+func GetDomainBaseTypes(ctx context.Context, pool *pgxpool.Pool) (map[uint32]uint32, error) {
+	rows, err := pool.Query(ctx, `
+		WITH RECURSIVE domains AS (
+			SELECT t.oid, t.typbasetype FROM pg_type t WHERE t.typtype = 'd'
+			UNION ALL
+			SELECT d.oid, t.typbasetype FROM domains d
+			JOIN pg_type t ON t.oid = d.typbasetype WHERE t.typtype = 'd'
+		)
+		SELECT d.oid, d.typbasetype FROM domains d
+		JOIN pg_type t ON t.oid = d.typbasetype WHERE t.typtype <> 'd';
+	`)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get domain types: %w", err)
+	}
+
+	domainBaseTypes := map[uint32]uint32{}
+	for rows.Next() {
+		var typeID uint32
+		var baseTypeID uint32
+		if err := rows.Scan(&typeID, &baseTypeID); err != nil {
+			return nil, fmt.Errorf("failed to scan row: %w", err)
+		}
+		domainBaseTypes[typeID] = baseTypeID
+	}
+	return domainBaseTypes, nil
+}
+
+// GetMoneyFormat returns how the server renders money values.
+func GetMoneyFormat(ctx context.Context, pool *pgxpool.Pool) (qvalue.MoneyFormat, error) {
+	var sample string
+	if err := pool.QueryRow(ctx, "SELECT 0.5::money::text").Scan(&sample); err != nil {
+		return qvalue.MoneyFormat{}, fmt.Errorf("failed to get money format: %w", err)
+	}
+	return qvalue.DetectMoneyFormat(sample), nil
+}
+
+// GetUserDefinedTypes returns the enum and composite types of the database by oid.
+func GetUserDefinedTypes(ctx context.Context, pool *pgxpool.Pool) (map[uint32]*protos.PostgresType, error) {
+	rows, err := pool.Query(ctx, `
+		SELECT t.oid, t.typname,
+			COALESCE((SELECT array_agg(e.enumlabel ORDER BY e.enumsortorder)
+				FROM pg_enum e WHERE e.enumtypid = t.oid), '{}'),
+			COALESCE((SELECT array_agg(a.attname ORDER BY a.attnum)
+				FROM pg_attribute a WHERE a.attrelid = t.typrelid AND a.attnum > 0 AND NOT a.attisdropped), '{}'),
+			COALESCE((SELECT array_agg(format_type(a.atttypid, a.atttypmod) ORDER BY a.attnum)
+				FROM pg_attribute a WHERE a.attrelid = t.typrelid AND a.attnum > 0 AND NOT a.attisdropped), '{}')
+		FROM pg_type t
+		JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+		LEFT JOIN pg_catalog.pg_class c ON c.oid = t.typrelid
+		WHERE (t.typtype = 'e' OR (t.typtype = 'c' AND c.relkind = 'c'))
+		AND n.nspname NOT IN ('pg_catalog', 'information_schema');
+	`)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get user defined types: %w", err)
+	}
+
+	userDefinedTypes := map[uint32]*protos.PostgresType{}
+	for rows.Next() {
+		var typeID uint32
+		var typeName string
+		var enumLabels, attributeNames, attributeTypes []string
+		if err := rows.Scan(&typeID, &typeName, &enumLabels, &attributeNames, &attributeTypes); err != nil {
+			return nil, fmt.Errorf("failed to scan row: %w", err)
+		}
+		pgType := &protos.PostgresType{
+			Name:       typeName,
+			EnumLabels: enumLabels,
+		}
+		for i, attributeName := range attributeNames {
+			pgType.Attributes = append(pgType.Attributes, &protos.PostgresTypeAttribute{
+				Name: attributeName,
+				Type: attributeTypes[i],
+			})
+		}
+		userDefinedTypes[typeID] = pgType
+	}
+	return userDefinedTypes, nil
+}
@@ -0,0 +1,157 @@
+package utils
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+)
+
+// RowChanges are the changes of a batch to a destination that writes whole rows by a key, such as the
+// vectors of a vector database, with only the last change of each row left.
+type RowChanges[T any] struct {
+	// keys of the rows to delete.
+	Deletes []string
+	// rows to insert or replace.
+	Upserts []*T
+	// changes to rows that have unchanged toast columns, which don't come with the change, so they can only
+	// be applied to the rows as they are. They are applied after the deletes and upserts.
+	Updates              []*T
+	TableNameRowsMapping map[string]uint32
+}
+
+// CollapseRowChanges returns the changes of the records, in the order of the first change of each row.
+// convert returns the key of the row a record changes and what it is written as, nil if the row is to be
+// deleted. Partial changes, of rows with unchanged toast columns, are never deleted, and a partial change
+// to a row changed earlier in the batch is merged into that change.
+func CollapseRowChanges[T any](records []model.Record,
+	convert func(destinationTable string, items *model.RecordItems, deleted bool, partial bool) (string, *T, error),
+	merge func(earlier *T, update *T)) (*RowChanges[T], error) {
+	tableNameRowsMapping := make(map[string]uint32)
+	// the last full change of each row, a delete if it is nil.
+	lastChange := make(map[string]*T)
+	var order []string
+	// the partial changes of rows without a full change since.
+	updates := make(map[string]*T)
+	var updateOrder []string
+
+	for _, record := range records {
+		var destinationTable string
+		var items *model.RecordItems
+		deleted := false
+		partial := false
+		switch r := record.(type) {
+		case *model.InsertRecord:
+			destinationTable = r.DestinationTableName
+			items = r.Items
+		case *model.UpdateRecord:
+			destinationTable = r.DestinationTableName
+			items = r.NewItems
+			partial = len(r.UnchangedToastColumns) > 0
+		case *model.DeleteRecord:
+			destinationTable = r.DestinationTableName
+			items = r.Items
+			deleted = true
+		default:
+			continue
+		}
+		tableNameRowsMapping[destinationTable]++
+
+		key, row, err := convert(destinationTable, items, deleted, partial)
+		if err != nil {
+			return nil, err
+		}
+
+		if !partial {
+			if _, ok := lastChange[key]; !ok {
+				order = append(order, key)
+			}
+			lastChange[key] = row
+			delete(updates, key)
+			continue
+		}
+
+		earlier := lastChange[key]
+		if earlier == nil {
+			earlier = updates[key]
+		}
+		if earlier == nil {
+			updates[key] = row
+			updateOrder = append(updateOrder, key)
+		} else {
+			merge(earlier, row)
+		}
+	}
+
+	changes := &RowChanges[T]{TableNameRowsMapping: tableNameRowsMapping}
+	for _, key := range order {
+		if row := lastChange[key]; row == nil {
+			changes.Deletes = append(changes.Deletes, key)
+		} else {
+			changes.Upserts = append(changes.Upserts, row)
+		}
+	}
+	for _, key := range updateOrder {
+		if row, ok := updates[key]; ok {
+			changes.Updates = append(changes.Updates, row)
+			delete(updates, key)
+		}
+	}
+	return changes, nil
+}
+
+// RowKey is the destination table and the primary key of a row, so that the rows of the tables of a mirror
+// don't collide in a destination that keeps them together.
+func RowKey(destinationTable string, primaryKeyColumns []string, row map[string]interface{}) (string, error) {
+	parts := make([]string, 0, len(primaryKeyColumns)+1)
+	parts = append(parts, destinationTable)
+	for _, col := range primaryKeyColumns {
+		value, ok := row[col]
+		if !ok || value == nil {
+			return "", fmt.Errorf("primary key column %s of table %s has no value", col, destinationTable)
+		}
+		parts = append(parts, fmt.Sprint(value))
+	}
+	return strings.Join(parts, ":"), nil
+}
+
+// VectorValues reads a pgvector column, which is replicated in its text form, or a real or double array.
+func VectorValues(value *qvalue.QValue) ([]float32, error) {
+	switch v := value.Value.(type) {
+	case string:
+		var values []float32
+		err := json.Unmarshal([]byte(v), &values)
+		if err != nil {
+			return nil, err
+		}
+		return values, nil
+	case []float32:
+		return v, nil
+	case []float64:
+		values := make([]float32, len(v))
+		for i, f := range v {
+			values[i] = float32(f)
+		}
+		return values, nil
+	default:
+		return nil, fmt.Errorf("unsupported vector type %s", value.Kind)
+	}
+}
+
+// RowValues returns the columns of a row as json values, keeping numbers as they are.
+func RowValues(items *model.RecordItems) (map[string]interface{}, error) {
+	encoded, err := items.ToJSON()
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert record to json: %w", err)
+	}
+	decoder := json.NewDecoder(strings.NewReader(encoded))
+	decoder.UseNumber()
+	var row map[string]interface{}
+	err = decoder.Decode(&row)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert record to json: %w", err)
+	}
+	return row, nil
+}
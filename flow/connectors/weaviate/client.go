@@ -0,0 +1,210 @@
+package connweaviate
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+)
+
+const (
+	objectBatchSize = 100
+	// ids are deleted by a filter with an operand for each of them.
+	deleteBatchSize = 100
+	maxRetries      = 5
+	requestTimeout  = time.Minute
+)
+
+type object struct {
+	Class      string                 `json:"class"`
+	ID         string                 `json:"id"`
+	Properties map[string]interface{} `json:"properties,omitempty"`
+	Vector     []float32              `json:"vector,omitempty"`
+}
+
+type batchObjectsRequest struct {
+	Objects []*object `json:"objects"`
+}
+
+type batchObjectResult struct {
+	ID     string `json:"id"`
+	Result struct {
+		Errors *struct {
+			Error []struct {
+				Message string `json:"message"`
+			} `json:"error"`
+		} `json:"errors"`
+	} `json:"result"`
+}
+
+type whereFilter struct {
+	Operator  string         `json:"operator"`
+	Path      []string       `json:"path,omitempty"`
+	ValueText string         `json:"valueText,omitempty"`
+	Operands  []*whereFilter `json:"operands,omitempty"`
+}
+
+type batchDeleteRequest struct {
+	Match struct {
+		Class string       `json:"class"`
+		Where *whereFilter `json:"where"`
+	} `json:"match"`
+	// only the objects that failed to be deleted are returned.
+	Output string `json:"output"`
+}
+
+type batchDeleteResponse struct {
+	Results struct {
+		Failed  int64 `json:"failed"`
+		Objects []struct {
+			ID     string `json:"id"`
+			Errors *struct {
+				Error []struct {
+					Message string `json:"message"`
+				} `json:"error"`
+			} `json:"errors"`
+		} `json:"objects"`
+	} `json:"results"`
+}
+
+// weaviateClient writes the objects of a class through the REST API of Weaviate.
+type weaviateClient struct {
+	httpClient *http.Client
+	baseURL    string
+	apiKey     string
+	className  string
+}
+
+func newWeaviateClient(config *protos.WeaviateConfig) *weaviateClient {
+	return &weaviateClient{
+		httpClient: &http.Client{Timeout: requestTimeout},
+		baseURL:    strings.TrimSuffix(config.Url, "/"),
+		apiKey:     config.GetApiKey(),
+		className:  config.ClassName,
+	}
+}
+
+func (c *weaviateClient) meta(ctx context.Context) error {
+	_, err := c.send(ctx, http.MethodGet, "/v1/meta", nil)
+	return err
+}
+
+// upsert creates the objects, replacing the ones that exist.
+func (c *weaviateClient) upsert(ctx context.Context, objects []*object) error {
+	for start := 0; start < len(objects); start += objectBatchSize {
+		end := start + objectBatchSize
+		if end > len(objects) {
+			end = len(objects)
+		}
+		body, err := c.send(ctx, http.MethodPost, "/v1/batch/objects",
+			&batchObjectsRequest{Objects: objects[start:end]})
+		if err != nil {
+			return fmt.Errorf("failed to upsert objects: %w", err)
+		}
+
+		// objects that fail to be written don't fail the request.
+		var results []*batchObjectResult
+		err = json.Unmarshal(body, &results)
+		if err != nil {
+			return fmt.Errorf("failed to parse batch response: %w", err)
+		}
+		for _, result := range results {
+			if result.Result.Errors != nil && len(result.Result.Errors.Error) > 0 {
+				return fmt.Errorf("failed to upsert object %s: %s", result.ID, result.Result.Errors.Error[0].Message)
+			}
+		}
+	}
+	return nil
+}
+
+func (c *weaviateClient) delete(ctx context.Context, ids []string) error {
+	for start := 0; start < len(ids); start += deleteBatchSize {
+		end := start + deleteBatchSize
+		if end > len(ids) {
+			end = len(ids)
+		}
+		req := &batchDeleteRequest{Output: "minimal"}
+		req.Match.Class = c.className
+		req.Match.Where = idFilter(ids[start:end])
+		body, err := c.send(ctx, http.MethodDelete, "/v1/batch/objects", req)
+		if err != nil {
+			return fmt.Errorf("failed to delete objects: %w", err)
+		}
+
+		var resp batchDeleteResponse
+		err = json.Unmarshal(body, &resp)
+		if err != nil {
+			return fmt.Errorf("failed to parse batch delete response: %w", err)
+		}
+		if resp.Results.Failed > 0 {
+			message := "unknown error"
+			for _, failed := range resp.Results.Objects {
+				if failed.Errors != nil && len(failed.Errors.Error) > 0 {
+					message = fmt.Sprintf("%s: %s", failed.ID, failed.Errors.Error[0].Message)
+					break
+				}
+			}
+			return fmt.Errorf("failed to delete %d objects, %s", resp.Results.Failed, message)
+		}
+	}
+	return nil
+}
+
+// update sets the given properties, and the vector if there is one, of an object that exists, it returns
+// false if it doesn't exist.
+func (c *weaviateClient) update(ctx context.Context, o *object) (bool, error) {
+	_, err := c.send(ctx, http.MethodPatch,
+		fmt.Sprintf("/v1/objects/%s/%s", url.PathEscape(c.className), url.PathEscape(o.ID)), o)
+	var statusErr *utils.HTTPStatusError
+	if errors.As(err, &statusErr) && statusErr.StatusCode == http.StatusNotFound {
+		return false, nil
+	} else if err != nil {
+		return false, fmt.Errorf("failed to update object %s: %w", o.ID, err)
+	}
+	return true, nil
+}
+
+// idFilter matches the objects of the ids, with Equal operands rather than ContainsAny, which older versions
+// of Weaviate lack.
+func idFilter(ids []string) *whereFilter {
+	operands := make([]*whereFilter, 0, len(ids))
+	for _, id := range ids {
+		operands = append(operands, &whereFilter{Operator: "Equal", Path: []string{"id"}, ValueText: id})
+	}
+	if len(operands) == 1 {
+		return operands[0]
+	}
+	return &whereFilter{Operator: "Or", Operands: operands}
+}
+
+// send sends a request to Weaviate, retrying it when Weaviate is throttling or unavailable.
+func (c *weaviateClient) send(ctx context.Context, method string, path string, body interface{}) ([]byte, error) {
+	var payload []byte
+	if body != nil {
+		var err error
+		payload, err = json.Marshal(body)
+		if err != nil {
+			return nil, fmt.Errorf("failed to marshal request: %w", err)
+		}
+	}
+
+	return utils.SendHTTPRequest(ctx, c.httpClient, maxRetries, func() (*http.Request, error) {
+		req, err := http.NewRequest(method, c.baseURL+path, bytes.NewReader(payload))
+		if err != nil {
+			return nil, err
+		}
+		if c.apiKey != "" {
+			req.Header.Set("Authorization", "Bearer "+c.apiKey)
+		}
+		req.Header.Set("Content-Type", "application/json")
+		return req, nil
+	})
+}
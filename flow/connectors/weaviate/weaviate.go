@@ -0,0 +1,251 @@
+package connweaviate
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"time"
+
+	metadataStore "github.com/PeerDB-io/peer-flow/connectors/external_metadata"
+	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	catalog "github.com/PeerDB-io/peer-flow/connectors/utils/catalog"
+	"github.com/PeerDB-io/peer-flow/connectors/utils/metrics"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/google/uuid"
+	log "github.com/sirupsen/logrus"
+)
+
+// WeaviateConnector mirrors rows to the objects of a Weaviate class, with the columns of a row as the
+// properties of its object and the vector column, if the peer has one, as its vector. Weaviate peers have
+// no database to keep the state of their mirrors in, it is kept in the catalog.
+type WeaviateConnector struct {
+	ctx          context.Context
+	config       *protos.WeaviateConfig
+	client       *weaviateClient
+	pgMetadata   *metadataStore.PostgresMetadataStore
+	tableSchemas map[string]*protos.TableSchema
+}
+
+func NewWeaviateConnector(ctx context.Context,
+	config *protos.WeaviateConfig) (*WeaviateConnector, error) {
+	catalogConfig, err := catalog.GetCatalogPostgresConfigFromEnv()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get catalog config for weaviate metadata: %w", err)
+	}
+	metadataSchemaName := "peerdb_weaviate_metadata" // #nosec G101
+	pgMetadata, err := metadataStore.NewPostgresMetadataStore(ctx, catalogConfig, metadataSchemaName)
+	if err != nil {
+		log.Errorf("failed to create postgres metadata store: %v", err)
+		return nil, err
+	}
+
+	return &WeaviateConnector{
+		ctx:        ctx,
+		config:     config,
+		client:     newWeaviateClient(config),
+		pgMetadata: pgMetadata,
+	}, nil
+}
+
+func (c *WeaviateConnector) Close() error {
+	return c.pgMetadata.Close()
+}
+
+func (c *WeaviateConnector) ConnectionActive() bool {
+	err := c.client.meta(c.ctx)
+	if err != nil {
+		log.Errorf("failed to reach weaviate at %s: %v", c.config.Url, err)
+	}
+	return err == nil
+}
+
+func (c *WeaviateConnector) NeedsSetupMetadataTables() bool {
+	return c.pgMetadata.NeedsSetupMetadata()
+}
+
+func (c *WeaviateConnector) SetupMetadataTables() error {
+	err := c.pgMetadata.SetupMetadata()
+	if err != nil {
+		log.Errorf("failed to setup metadata tables: %v", err)
+		return err
+	}
+
+	return nil
+}
+
+func (c *WeaviateConnector) GetLastSyncBatchID(jobName string) (int64, error) {
+	return c.pgMetadata.GetLastBatchID(jobName)
+}
+
+func (c *WeaviateConnector) GetLastOffset(jobName string) (*protos.LastSyncState, error) {
+	return c.pgMetadata.FetchLastOffset(jobName)
+}
+
+func (c *WeaviateConnector) InitializeTableSchema(req map[string]*protos.TableSchema) error {
+	c.tableSchemas = req
+	return nil
+}
+
+func (c *WeaviateConnector) CreateRawTable(req *protos.CreateRawTableInput) (*protos.CreateRawTableOutput, error) {
+	log.Infof("CreateRawTable for weaviate is a no-op")
+	return &protos.CreateRawTableOutput{
+		TableIdentifier: "n/a",
+	}, nil
+}
+
+// SetupNormalizedTables checks that the rows of the tables can be identified, the class is created by
+// Weaviate when objects are first written to it.
+func (c *WeaviateConnector) SetupNormalizedTables(req *protos.SetupNormalizedTableBatchInput) (
+	*protos.SetupNormalizedTableBatchOutput, error) {
+	for tableIdentifier, schema := range req.TableNameSchemaMapping {
+		if len(schema.PrimaryKeyColumns) == 0 {
+			return nil, fmt.Errorf("table %s has no primary key to identify its objects by", tableIdentifier)
+		}
+		if c.config.VectorColumn == "" {
+			continue
+		}
+		if _, ok := schema.Columns[c.config.VectorColumn]; !ok {
+			return nil, fmt.Errorf("table %s has no vector column %s", tableIdentifier, c.config.VectorColumn)
+		}
+	}
+	return &protos.SetupNormalizedTableBatchOutput{
+		TableExistsMapping: nil,
+	}, nil
+}
+
+// SyncRecords upserts the objects of inserted and updated rows and deletes the ones of deleted rows. Only
+// the last change of each row in the batch is applied.
+func (c *WeaviateConnector) SyncRecords(req *model.SyncRecordsRequest) (*model.SyncResponse, error) {
+	startTime := time.Now()
+	batch := req.Records
+
+	changes, err := c.collectChanges(batch.Records)
+	if err != nil {
+		return nil, err
+	}
+
+	err = c.client.delete(c.ctx, changes.Deletes)
+	if err != nil {
+		return nil, err
+	}
+	err = c.client.upsert(c.ctx, changes.Upserts)
+	if err != nil {
+		return nil, err
+	}
+	for _, o := range changes.Updates {
+		exists, err := c.client.update(c.ctx, o)
+		if err != nil {
+			return nil, err
+		}
+		if !exists {
+			log.WithFields(log.Fields{
+				"flowName": req.FlowJobName,
+			}).Warnf("object %s has unchanged toast columns but doesn't exist, its update is skipped", o.ID)
+		}
+	}
+
+	err = c.pgMetadata.UpdateLastOffset(req.FlowJobName, batch.LastCheckPointID)
+	if err != nil {
+		log.Errorf("failed to update last offset: %v", err)
+		return nil, err
+	}
+	err = c.pgMetadata.IncrementID(req.FlowJobName)
+	if err != nil {
+		log.Errorf("%v", err)
+		return nil, err
+	}
+
+	rowsSynced := int64(len(batch.Records))
+	metrics.LogSyncMetrics(c.ctx, req.FlowJobName, rowsSynced, time.Since(startTime))
+	metrics.LogNormalizeMetrics(c.ctx, req.FlowJobName, rowsSynced, time.Since(startTime), rowsSynced)
+	return &model.SyncResponse{
+		FirstSyncedCheckPointID: batch.FirstCheckPointID,
+		LastSyncedCheckPointID:  batch.LastCheckPointID,
+		NumRecordsSynced:        rowsSynced,
+		TableNameRowsMapping:    changes.TableNameRowsMapping,
+	}, nil
+}
+
+func (c *WeaviateConnector) SyncFlowCleanup(jobName string) error {
+	return c.pgMetadata.DropMetadata(jobName)
+}
+
+func (c *WeaviateConnector) collectChanges(records []model.Record) (*utils.RowChanges[object], error) {
+	return utils.CollapseRowChanges(records,
+		func(destinationTable string, items *model.RecordItems, deleted bool, partial bool) (string, *object, error) {
+			o, err := c.recordToObject(destinationTable, items)
+			if err != nil {
+				return "", nil, err
+			}
+			if deleted {
+				return o.ID, nil, nil
+			}
+			return o.ID, o, nil
+		},
+		func(earlier *object, update *object) {
+			if update.Vector != nil {
+				earlier.Vector = update.Vector
+			}
+			for k, value := range update.Properties {
+				earlier.Properties[k] = value
+			}
+		})
+}
+
+// recordToObject returns the object of a row, whose id is derived from its destination table and primary
+// key since Weaviate ids are uuids. Null columns, and columns that the change doesn't have, are left out.
+func (c *WeaviateConnector) recordToObject(destinationTable string, items *model.RecordItems) (*object, error) {
+	schema, ok := c.tableSchemas[destinationTable]
+	if !ok {
+		return nil, fmt.Errorf("schema of table %s is not known", destinationTable)
+	}
+
+	row, err := utils.RowValues(items)
+	if err != nil {
+		return nil, err
+	}
+	key, err := utils.RowKey(destinationTable, schema.PrimaryKeyColumns, row)
+	if err != nil {
+		return nil, err
+	}
+
+	o := &object{
+		Class:      c.config.ClassName,
+		ID:         objectID(key),
+		Properties: make(map[string]interface{}),
+	}
+	for col, value := range row {
+		if col == c.config.VectorColumn || value == nil {
+			continue
+		}
+		o.Properties[col] = propertyValue(value)
+	}
+	if c.config.VectorColumn != "" {
+		if value := items.GetColumnValue(c.config.VectorColumn); value != nil && value.Value != nil {
+			o.Vector, err = utils.VectorValues(value)
+			if err != nil {
+				return nil, fmt.Errorf("invalid vector in column %s of table %s: %w",
+					c.config.VectorColumn, destinationTable, err)
+			}
+		}
+	}
+	return o, nil
+}
+
+// objectID is the same uuid for a row every time it is written.
+func objectID(key string) string {
+	return uuid.NewSHA1(uuid.NameSpaceURL, []byte("peerdb:"+key)).String()
+}
+
+// propertyValue stores json objects, which would need a nested property in the class, as their json.
+func propertyValue(value interface{}) interface{} {
+	if nested, ok := value.(map[string]interface{}); ok {
+		encoded, err := json.Marshal(nested)
+		if err != nil {
+			return fmt.Sprint(nested)
+		}
+		return string(encoded)
+	}
+	return value
+}
@@ -0,0 +1,74 @@
+package connweaviate
+
+import (
+	"testing"
+
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+	"github.com/stretchr/testify/require"
+)
+
+func testConnector() *WeaviateConnector {
+	return &WeaviateConnector{
+		config: &protos.WeaviateConfig{ClassName: "Doc", VectorColumn: "embedding"},
+		tableSchemas: map[string]*protos.TableSchema{
+			"docs": {
+				TableIdentifier:   "docs",
+				PrimaryKeyColumns: []string{"id"},
+			},
+		},
+	}
+}
+
+func row(id int64, embedding string, title string) *model.RecordItems {
+	items := model.NewRecordItems()
+	items.AddColumn("id", &qvalue.QValue{Kind: qvalue.QValueKindInt64, Value: id})
+	if embedding != "" {
+		items.AddColumn("embedding", &qvalue.QValue{Kind: qvalue.QValueKindString, Value: embedding})
+	}
+	items.AddColumn("title", &qvalue.QValue{Kind: qvalue.QValueKindString, Value: title})
+	return items
+}
+
+func TestCollectChanges(t *testing.T) {
+	c := testConnector()
+	records := []model.Record{
+		&model.InsertRecord{DestinationTableName: "docs", Items: row(1, "[1,2]", "a")},
+		&model.InsertRecord{DestinationTableName: "docs", Items: row(2, "", "b")},
+		&model.DeleteRecord{DestinationTableName: "docs", Items: row(1, "", "")},
+		&model.UpdateRecord{
+			DestinationTableName:  "docs",
+			NewItems:              row(2, "", "c"),
+			UnchangedToastColumns: map[string]struct{}{"body": {}},
+		},
+	}
+
+	changes, err := c.collectChanges(records)
+	require.NoError(t, err)
+	require.Equal(t, []string{objectID("docs:1")}, changes.Deletes)
+	// objects without a vector are vectorized by weaviate.
+	require.Len(t, changes.Upserts, 1)
+	require.Equal(t, objectID("docs:2"), changes.Upserts[0].ID)
+	require.Equal(t, "Doc", changes.Upserts[0].Class)
+	require.Nil(t, changes.Upserts[0].Vector)
+	require.Equal(t, "c", changes.Upserts[0].Properties["title"])
+	require.Empty(t, changes.Updates)
+}
+
+func TestObjectID(t *testing.T) {
+	require.Equal(t, objectID("docs:1"), objectID("docs:1"))
+	require.NotEqual(t, objectID("docs:1"), objectID("docs:2"))
+	require.Len(t, objectID("docs:1"), 36)
+}
+
+func TestIDFilter(t *testing.T) {
+	single := idFilter([]string{"a"})
+	require.Equal(t, "Equal", single.Operator)
+	require.Equal(t, "a", single.ValueText)
+
+	several := idFilter([]string{"a", "b"})
+	require.Equal(t, "Or", several.Operator)
+	require.Len(t, several.Operands, 2)
+	require.Equal(t, []string{"id"}, several.Operands[1].Path)
+}
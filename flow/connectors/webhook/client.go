@@ -0,0 +1,133 @@
+package connwebhook
+
+import (
+	"bytes"
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/PeerDB-io/peer-flow/connectors/utils"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+)
+
+const (
+	defaultMaxRetries         = 5
+	defaultTimeout            = 30 * time.Second
+	defaultMaxInflightBatches = 1
+	// the hex encoded HMAC-SHA256 of the body, keyed by the signing secret of the peer.
+	signatureHeader = "X-PeerDB-Signature"
+	// the same for every attempt to deliver a batch, so that endpoints can tell retries apart.
+	deliveryIDHeader = "X-PeerDB-Delivery-Id"
+)
+
+// webhookClient POSTs batches of changes to the endpoint of a webhook peer.
+type webhookClient struct {
+	httpClient    *http.Client
+	url           string
+	signingSecret string
+	headers       map[string]string
+	maxRetries    uint64
+	maxInflight   int
+}
+
+func newWebhookClient(config *protos.WebhookConfig) *webhookClient {
+	timeout := defaultTimeout
+	if config.TimeoutSeconds > 0 {
+		timeout = time.Duration(config.TimeoutSeconds) * time.Second
+	}
+	maxRetries := uint64(defaultMaxRetries)
+	if config.MaxRetries > 0 {
+		maxRetries = uint64(config.MaxRetries)
+	}
+	maxInflight := defaultMaxInflightBatches
+	if config.MaxInflightBatches > 0 {
+		maxInflight = int(config.MaxInflightBatches)
+	}
+
+	return &webhookClient{
+		httpClient:    &http.Client{Timeout: timeout},
+		url:           config.Url,
+		signingSecret: config.GetSigningSecret(),
+		headers:       config.Headers,
+		maxRetries:    maxRetries,
+		maxInflight:   maxInflight,
+	}
+}
+
+// sign returns the signature of a body, which endpoints recompute with the secret to check that the
+// request came from the peer.
+func sign(secret string, body []byte) string {
+	mac := hmac.New(sha256.New, []byte(secret))
+	mac.Write(body)
+	return hex.EncodeToString(mac.Sum(nil))
+}
+
+// deliver POSTs the body, retrying it with exponential backoff when it fails to be sent or the endpoint
+// is throttling or unavailable.
+func (c *webhookClient) deliver(ctx context.Context, deliveryID string, body []byte) error {
+	_, err := utils.SendHTTPRequest(ctx, c.httpClient, c.maxRetries, func() (*http.Request, error) {
+		req, err := http.NewRequest(http.MethodPost, c.url, bytes.NewReader(body))
+		if err != nil {
+			return nil, err
+		}
+		for name, value := range c.headers {
+			req.Header.Set(name, value)
+		}
+		req.Header.Set("Content-Type", "application/json")
+		req.Header.Set(deliveryIDHeader, deliveryID)
+		if c.signingSecret != "" {
+			req.Header.Set(signatureHeader, sign(c.signingSecret, body))
+		}
+		return req, nil
+	})
+	if err != nil {
+		return fmt.Errorf("failed to deliver %s to webhook: %w", deliveryID, err)
+	}
+	return nil
+}
+
+// deliverAll delivers the bodies with at most maxInflight of them awaiting delivery at a time, it returns
+// once all of them are delivered or one of them fails, so a mirror stops pulling changes while its
+// endpoint falls behind. Bodies are delivered in order when only one can be in flight.
+func (c *webhookClient) deliverAll(ctx context.Context, deliveryIDs []string, bodies [][]byte) error {
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+
+	inflight := make(chan struct{}, c.maxInflight)
+	var wg sync.WaitGroup
+	var once sync.Once
+	var firstErr error
+	for i := range bodies {
+		select {
+		case inflight <- struct{}{}:
+		case <-ctx.Done():
+		}
+		if ctx.Err() != nil {
+			break
+		}
+
+		wg.Add(1)
+		go func(i int) {
+			defer wg.Done()
+			defer func() { <-inflight }()
+			err := c.deliver(ctx, deliveryIDs[i], bodies[i])
+			if err != nil {
+				once.Do(func() {
+					firstErr = err
+					cancel()
+				})
+			}
+		}(i)
+	}
+	wg.Wait()
+
+	if firstErr != nil {
+		return firstErr
+	}
+	return ctx.Err()
+}
@@ -0,0 +1,263 @@
+package connwebhook
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/url"
+	"sort"
+	"time"
+
+	metadataStore "github.com/PeerDB-io/peer-flow/connectors/external_metadata"
+	catalog "github.com/PeerDB-io/peer-flow/connectors/utils/catalog"
+	"github.com/PeerDB-io/peer-flow/connectors/utils/metrics"
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	log "github.com/sirupsen/logrus"
+)
+
+// the most changes sent in a single request.
+const changesPerDelivery = 1000
+
+// change is a change to a row as it is delivered, the row as json with its old values, if the source
+// sends them, for updates and deletes.
+type change struct {
+	Action                string          `json:"action"`
+	SourceTable           string          `json:"source_table"`
+	DestinationTable      string          `json:"destination_table"`
+	CheckpointID          int64           `json:"checkpoint_id"`
+	CommitTime            time.Time       `json:"commit_time"`
+	Data                  json.RawMessage `json:"data,omitempty"`
+	OldData               json.RawMessage `json:"old_data,omitempty"`
+	UnchangedToastColumns []string        `json:"unchanged_toast_columns,omitempty"`
+}
+
+// delivery is the body of a request, which carries a part of the changes of a batch.
+type delivery struct {
+	FlowJobName string    `json:"flow_job_name"`
+	BatchID     int64     `json:"batch_id"`
+	Part        int       `json:"part"`
+	Parts       int       `json:"parts"`
+	Changes     []*change `json:"changes"`
+}
+
+// WebhookConnector delivers the changes of mirrors to an HTTP endpoint as JSON, in the order they were
+// made at the source when one batch is in flight at a time. Webhook peers have no database to keep the state
+// of their mirrors in, it is kept in the catalog.
+type WebhookConnector struct {
+	ctx        context.Context
+	config     *protos.WebhookConfig
+	client     *webhookClient
+	pgMetadata *metadataStore.PostgresMetadataStore
+}
+
+func NewWebhookConnector(ctx context.Context,
+	config *protos.WebhookConfig) (*WebhookConnector, error) {
+	catalogConfig, err := catalog.GetCatalogPostgresConfigFromEnv()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get catalog config for webhook metadata: %w", err)
+	}
+	metadataSchemaName := "peerdb_webhook_metadata" // #nosec G101
+	pgMetadata, err := metadataStore.NewPostgresMetadataStore(ctx, catalogConfig, metadataSchemaName)
+	if err != nil {
+		log.Errorf("failed to create postgres metadata store: %v", err)
+		return nil, err
+	}
+
+	return &WebhookConnector{
+		ctx:        ctx,
+		config:     config,
+		client:     newWebhookClient(config),
+		pgMetadata: pgMetadata,
+	}, nil
+}
+
+func (c *WebhookConnector) Close() error {
+	return c.pgMetadata.Close()
+}
+
+// ConnectionActive only checks the url of the endpoint, which may accept nothing but deliveries.
+func (c *WebhookConnector) ConnectionActive() bool {
+	u, err := url.Parse(c.config.Url)
+	if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+		log.Errorf("webhook url %s is not an http or https url", c.config.Url)
+		return false
+	}
+	return true
+}
+
+func (c *WebhookConnector) NeedsSetupMetadataTables() bool {
+	return c.pgMetadata.NeedsSetupMetadata()
+}
+
+func (c *WebhookConnector) SetupMetadataTables() error {
+	err := c.pgMetadata.SetupMetadata()
+	if err != nil {
+		log.Errorf("failed to setup metadata tables: %v", err)
+		return err
+	}
+
+	return nil
+}
+
+func (c *WebhookConnector) GetLastSyncBatchID(jobName string) (int64, error) {
+	return c.pgMetadata.GetLastBatchID(jobName)
+}
+
+func (c *WebhookConnector) GetLastOffset(jobName string) (*protos.LastSyncState, error) {
+	return c.pgMetadata.FetchLastOffset(jobName)
+}
+
+func (c *WebhookConnector) InitializeTableSchema(req map[string]*protos.TableSchema) error {
+	log.Infof("InitializeTableSchema for webhook is a no-op")
+	return nil
+}
+
+func (c *WebhookConnector) CreateRawTable(req *protos.CreateRawTableInput) (*protos.CreateRawTableOutput, error) {
+	log.Infof("CreateRawTable for webhook is a no-op")
+	return &protos.CreateRawTableOutput{
+		TableIdentifier: "n/a",
+	}, nil
+}
+
+func (c *WebhookConnector) SetupNormalizedTables(req *protos.SetupNormalizedTableBatchInput) (
+	*protos.SetupNormalizedTableBatchOutput, error) {
+	log.Infof("SetupNormalizedTables for webhook is a no-op")
+	return &protos.SetupNormalizedTableBatchOutput{
+		TableExistsMapping: nil,
+	}, nil
+}
+
+// SyncRecords delivers the changes of the batch, and only moves the mirror past them once the endpoint
+// accepted all of them. A batch that fails is pulled and delivered again, under the same batch id.
+func (c *WebhookConnector) SyncRecords(req *model.SyncRecordsRequest) (*model.SyncResponse, error) {
+	startTime := time.Now()
+	batch := req.Records
+
+	syncBatchID, err := c.GetLastSyncBatchID(req.FlowJobName)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get previous syncBatchID: %w", err)
+	}
+	syncBatchID = syncBatchID + 1
+
+	changes, tableNameRowsMapping, err := collectChanges(batch.Records)
+	if err != nil {
+		return nil, err
+	}
+	deliveryIDs, bodies, err := deliveries(req.FlowJobName, syncBatchID, changes)
+	if err != nil {
+		return nil, err
+	}
+	err = c.client.deliverAll(c.ctx, deliveryIDs, bodies)
+	if err != nil {
+		return nil, err
+	}
+
+	err = c.pgMetadata.UpdateLastOffset(req.FlowJobName, batch.LastCheckPointID)
+	if err != nil {
+		log.Errorf("failed to update last offset: %v", err)
+		return nil, err
+	}
+	err = c.pgMetadata.IncrementID(req.FlowJobName)
+	if err != nil {
+		log.Errorf("%v", err)
+		return nil, err
+	}
+
+	rowsSynced := int64(len(changes))
+	metrics.LogSyncMetrics(c.ctx, req.FlowJobName, rowsSynced, time.Since(startTime))
+	return &model.SyncResponse{
+		FirstSyncedCheckPointID: batch.FirstCheckPointID,
+		LastSyncedCheckPointID:  batch.LastCheckPointID,
+		NumRecordsSynced:        rowsSynced,
+		CurrentSyncBatchID:      syncBatchID,
+		TableNameRowsMapping:    tableNameRowsMapping,
+	}, nil
+}
+
+func (c *WebhookConnector) SyncFlowCleanup(jobName string) error {
+	return c.pgMetadata.DropMetadata(jobName)
+}
+
+// collectChanges returns the changes to rows in the records, in order, leaving out other records such as
+// schema changes.
+func collectChanges(records []model.Record) ([]*change, map[string]uint32, error) {
+	tableNameRowsMapping := make(map[string]uint32)
+	changes := make([]*change, 0, len(records))
+	for _, record := range records {
+		var ch *change
+		var data, oldData *model.RecordItems
+		switch r := record.(type) {
+		case *model.InsertRecord:
+			ch = &change{Action: "insert", SourceTable: r.SourceTableName, DestinationTable: r.DestinationTableName,
+				CheckpointID: r.CheckPointID, CommitTime: r.CommitTime}
+			data = r.Items
+		case *model.UpdateRecord:
+			ch = &change{Action: "update", SourceTable: r.SourceTableName, DestinationTable: r.DestinationTableName,
+				CheckpointID: r.CheckPointID, CommitTime: r.CommitTime}
+			data = r.NewItems
+			oldData = r.OldItems
+			for col := range r.UnchangedToastColumns {
+				ch.UnchangedToastColumns = append(ch.UnchangedToastColumns, col)
+			}
+			sort.Strings(ch.UnchangedToastColumns)
+		case *model.DeleteRecord:
+			ch = &change{Action: "delete", SourceTable: r.SourceTableName, DestinationTable: r.DestinationTableName,
+				CheckpointID: r.CheckPointID, CommitTime: r.CommitTime}
+			oldData = r.Items
+		default:
+			continue
+		}
+
+		var err error
+		ch.Data, err = rowJSON(data)
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to convert row of %s to json: %w", ch.SourceTable, err)
+		}
+		ch.OldData, err = rowJSON(oldData)
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to convert row of %s to json: %w", ch.SourceTable, err)
+		}
+		tableNameRowsMapping[ch.DestinationTable]++
+		changes = append(changes, ch)
+	}
+	return changes, tableNameRowsMapping, nil
+}
+
+func rowJSON(items *model.RecordItems) (json.RawMessage, error) {
+	if items == nil || items.Len() == 0 {
+		return nil, nil
+	}
+	row, err := items.ToJSON()
+	if err != nil {
+		return nil, err
+	}
+	return json.RawMessage(row), nil
+}
+
+// deliveries splits the changes of a batch into the bodies of the requests that deliver them, with the
+// id of each delivery.
+func deliveries(flowJobName string, batchID int64, changes []*change) ([]string, [][]byte, error) {
+	parts := (len(changes) + changesPerDelivery - 1) / changesPerDelivery
+	deliveryIDs := make([]string, 0, parts)
+	bodies := make([][]byte, 0, parts)
+	for part := 0; part < parts; part++ {
+		end := (part + 1) * changesPerDelivery
+		if end > len(changes) {
+			end = len(changes)
+		}
+		body, err := json.Marshal(&delivery{
+			FlowJobName: flowJobName,
+			BatchID:     batchID,
+			Part:        part + 1,
+			Parts:       parts,
+			Changes:     changes[part*changesPerDelivery : end],
+		})
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to marshal delivery: %w", err)
+		}
+		deliveryIDs = append(deliveryIDs, fmt.Sprintf("%s:%d:%d", flowJobName, batchID, part+1))
+		bodies = append(bodies, body)
+	}
+	return deliveryIDs, bodies, nil
+}
@@ -0,0 +1,179 @@
+package connwebhook
+
+import (
+	"context"
+	"encoding/json"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+	"github.com/stretchr/testify/require"
+)
+
+func testClient(url string) *webhookClient {
+	secret := "secret"
+	return newWebhookClient(&protos.WebhookConfig{
+		Url:           url,
+		SigningSecret: &secret,
+		Headers:       map[string]string{"Authorization": "Bearer token"},
+		MaxRetries:    3,
+	})
+}
+
+func TestSign(t *testing.T) {
+	require.Equal(t, "aa9e2e3575f5d7098b6caccd790888c36d5fdb63342a73bada2d6a51747a8494",
+		sign("secret", []byte(`{"a":1}`)))
+}
+
+func TestDeliverSignsRequests(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		body, err := io.ReadAll(r.Body)
+		require.NoError(t, err)
+		require.Equal(t, `{"a":1}`, string(body))
+		require.Equal(t, sign("secret", body), r.Header.Get(signatureHeader))
+		require.Equal(t, "flow:1:1", r.Header.Get(deliveryIDHeader))
+		require.Equal(t, "Bearer token", r.Header.Get("Authorization"))
+		w.WriteHeader(http.StatusNoContent)
+	}))
+	defer server.Close()
+
+	err := testClient(server.URL).deliver(context.Background(), "flow:1:1", []byte(`{"a":1}`))
+	require.NoError(t, err)
+}
+
+func TestDeliverWithoutSecretIsUnsigned(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		require.Empty(t, r.Header.Get(signatureHeader))
+	}))
+	defer server.Close()
+
+	client := newWebhookClient(&protos.WebhookConfig{Url: server.URL})
+	require.NoError(t, client.deliver(context.Background(), "flow:1:1", []byte(`{}`)))
+}
+
+func TestDeliverRetriesUnavailableEndpoint(t *testing.T) {
+	var attempts int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		// the same signature is sent with every attempt.
+		body, err := io.ReadAll(r.Body)
+		require.NoError(t, err)
+		require.Equal(t, sign("secret", body), r.Header.Get(signatureHeader))
+		if atomic.AddInt32(&attempts, 1) < 3 {
+			w.WriteHeader(http.StatusServiceUnavailable)
+		}
+	}))
+	defer server.Close()
+
+	err := testClient(server.URL).deliver(context.Background(), "flow:1:1", []byte(`{}`))
+	require.NoError(t, err)
+	require.EqualValues(t, 3, atomic.LoadInt32(&attempts))
+}
+
+func TestDeliverGivesUpAfterMaxRetries(t *testing.T) {
+	var attempts int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		atomic.AddInt32(&attempts, 1)
+		w.WriteHeader(http.StatusTooManyRequests)
+	}))
+	defer server.Close()
+
+	err := testClient(server.URL).deliver(context.Background(), "flow:1:1", []byte(`{}`))
+	require.Error(t, err)
+	// the first attempt and 3 retries.
+	require.EqualValues(t, 4, atomic.LoadInt32(&attempts))
+}
+
+func TestDeliverDoesNotRetryRejectedRequests(t *testing.T) {
+	var attempts int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		atomic.AddInt32(&attempts, 1)
+		w.WriteHeader(http.StatusUnauthorized)
+	}))
+	defer server.Close()
+
+	err := testClient(server.URL).deliver(context.Background(), "flow:1:1", []byte(`{}`))
+	require.Error(t, err)
+	require.EqualValues(t, 1, atomic.LoadInt32(&attempts))
+}
+
+func TestDeliverAllBoundsBatchesInFlight(t *testing.T) {
+	var inflight, maxInflight int32
+	var mu sync.Mutex
+	var delivered []string
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		n := atomic.AddInt32(&inflight, 1)
+		defer atomic.AddInt32(&inflight, -1)
+		for {
+			m := atomic.LoadInt32(&maxInflight)
+			if n <= m || atomic.CompareAndSwapInt32(&maxInflight, m, n) {
+				break
+			}
+		}
+		time.Sleep(20 * time.Millisecond)
+		mu.Lock()
+		delivered = append(delivered, r.Header.Get(deliveryIDHeader))
+		mu.Unlock()
+	}))
+	defer server.Close()
+
+	client := newWebhookClient(&protos.WebhookConfig{Url: server.URL, MaxInflightBatches: 2})
+	ids := []string{"1", "2", "3", "4", "5"}
+	err := client.deliverAll(context.Background(), ids, [][]byte{{}, {}, {}, {}, {}})
+	require.NoError(t, err)
+	require.ElementsMatch(t, ids, delivered)
+	require.LessOrEqual(t, atomic.LoadInt32(&maxInflight), int32(2))
+}
+
+func TestDeliverAllStopsAtFailure(t *testing.T) {
+	var delivered []string
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		id := r.Header.Get(deliveryIDHeader)
+		delivered = append(delivered, id)
+		if id == "2" {
+			w.WriteHeader(http.StatusBadRequest)
+		}
+	}))
+	defer server.Close()
+
+	// one batch in flight at a time delivers them in order.
+	client := newWebhookClient(&protos.WebhookConfig{Url: server.URL})
+	err := client.deliverAll(context.Background(), []string{"1", "2", "3"}, [][]byte{{}, {}, {}})
+	require.Error(t, err)
+	require.Equal(t, []string{"1", "2"}, delivered)
+}
+
+func TestDeliveries(t *testing.T) {
+	items := model.NewRecordItems()
+	items.AddColumn("id", &qvalue.QValue{Kind: qvalue.QValueKindInt64, Value: int64(1)})
+	records := make([]model.Record, 0, changesPerDelivery+1)
+	for i := 0; i < changesPerDelivery; i++ {
+		records = append(records, &model.InsertRecord{SourceTableName: "public.t", DestinationTableName: "t",
+			Items: items})
+	}
+	records = append(records, &model.DeleteRecord{SourceTableName: "public.t", DestinationTableName: "t",
+		Items: items})
+
+	changes, tableNameRowsMapping, err := collectChanges(records)
+	require.NoError(t, err)
+	require.EqualValues(t, changesPerDelivery+1, tableNameRowsMapping["t"])
+
+	ids, bodies, err := deliveries("flow", 7, changes)
+	require.NoError(t, err)
+	require.Equal(t, []string{"flow:7:1", "flow:7:2"}, ids)
+
+	var last delivery
+	require.NoError(t, json.Unmarshal(bodies[1], &last))
+	require.Equal(t, 2, last.Part)
+	require.Equal(t, 2, last.Parts)
+	require.Len(t, last.Changes, 1)
+	require.Equal(t, "delete", last.Changes[0].Action)
+	require.Nil(t, last.Changes[0].Data)
+	require.JSONEq(t, `{"id":1}`, string(last.Changes[0].OldData))
+}
@@ -19,12 +19,21 @@ import (
 const _ = grpc.SupportPackageIsVersion7
 
 const (
-	FlowService_ValidatePeer_FullMethodName   = "/peerdb_route.FlowService/ValidatePeer"
-	FlowService_CreatePeer_FullMethodName     = "/peerdb_route.FlowService/CreatePeer"
-	FlowService_CreateCDCFlow_FullMethodName  = "/peerdb_route.FlowService/CreateCDCFlow"
-	FlowService_CreateQRepFlow_FullMethodName = "/peerdb_route.FlowService/CreateQRepFlow"
-	FlowService_ShutdownFlow_FullMethodName   = "/peerdb_route.FlowService/ShutdownFlow"
-	FlowService_MirrorStatus_FullMethodName   = "/peerdb_route.FlowService/MirrorStatus"
+	FlowService_ValidatePeer_FullMethodName        = "/peerdb_route.FlowService/ValidatePeer"
+	FlowService_CreatePeer_FullMethodName          = "/peerdb_route.FlowService/CreatePeer"
+	FlowService_UpdatePeer_FullMethodName          = "/peerdb_route.FlowService/UpdatePeer"
+	FlowService_CreateCDCFlow_FullMethodName       = "/peerdb_route.FlowService/CreateCDCFlow"
+	FlowService_CreateQRepFlow_FullMethodName      = "/peerdb_route.FlowService/CreateQRepFlow"
+	FlowService_ShutdownFlow_FullMethodName        = "/peerdb_route.FlowService/ShutdownFlow"
+	FlowService_PauseFlow_FullMethodName           = "/peerdb_route.FlowService/PauseFlow"
+	FlowService_ResumeFlow_FullMethodName          = "/peerdb_route.FlowService/ResumeFlow"
+	FlowService_AddTablesToFlow_FullMethodName     = "/peerdb_route.FlowService/AddTablesToFlow"
+	FlowService_PauseTable_FullMethodName          = "/peerdb_route.FlowService/PauseTable"
+	FlowService_RemoveTableFromFlow_FullMethodName = "/peerdb_route.FlowService/RemoveTableFromFlow"
+	FlowService_ResyncMirror_FullMethodName        = "/peerdb_route.FlowService/ResyncMirror"
+	FlowService_MirrorStatus_FullMethodName        = "/peerdb_route.FlowService/MirrorStatus"
+	FlowService_WatchMirrorStatus_FullMethodName   = "/peerdb_route.FlowService/WatchMirrorStatus"
+	FlowService_RotateEncryptionKey_FullMethodName = "/peerdb_route.FlowService/RotateEncryptionKey"
 )
 
 // FlowServiceClient is the client API for FlowService service.
@@ -33,10 +42,29 @@ const (
 type FlowServiceClient interface {
 	ValidatePeer(ctx context.Context, in *ValidatePeerRequest, opts ...grpc.CallOption) (*ValidatePeerResponse, error)
 	CreatePeer(ctx context.Context, in *CreatePeerRequest, opts ...grpc.CallOption) (*CreatePeerResponse, error)
+	// saves a new config of a peer, and has its running cdc mirrors reconnect with
+	// it at their next batch instead of being dropped and created again.
+	UpdatePeer(ctx context.Context, in *UpdatePeerRequest, opts ...grpc.CallOption) (*UpdatePeerResponse, error)
 	CreateCDCFlow(ctx context.Context, in *CreateCDCFlowRequest, opts ...grpc.CallOption) (*CreateCDCFlowResponse, error)
 	CreateQRepFlow(ctx context.Context, in *CreateQRepFlowRequest, opts ...grpc.CallOption) (*CreateQRepFlowResponse, error)
 	ShutdownFlow(ctx context.Context, in *ShutdownRequest, opts ...grpc.CallOption) (*ShutdownResponse, error)
+	PauseFlow(ctx context.Context, in *PauseFlowRequest, opts ...grpc.CallOption) (*PauseFlowResponse, error)
+	ResumeFlow(ctx context.Context, in *ResumeFlowRequest, opts ...grpc.CallOption) (*ResumeFlowResponse, error)
+	// snapshots the tables and adds them to a running cdc flow.
+	AddTablesToFlow(ctx context.Context, in *AddTablesToFlowRequest, opts ...grpc.CallOption) (*AddTablesToFlowResponse, error)
+	// pauses or resumes replication of one table of a running cdc flow.
+	PauseTable(ctx context.Context, in *PauseTableRequest, opts ...grpc.CallOption) (*PauseTableResponse, error)
+	// stops replicating one table of a running cdc flow.
+	RemoveTableFromFlow(ctx context.Context, in *RemoveTableFromFlowRequest, opts ...grpc.CallOption) (*RemoveTableFromFlowResponse, error)
+	// copies tables of a running cdc flow again and replaces the destination
+	// tables with the copies.
+	ResyncMirror(ctx context.Context, in *ResyncMirrorRequest, opts ...grpc.CallOption) (*ResyncMirrorResponse, error)
 	MirrorStatus(ctx context.Context, in *MirrorStatusRequest, opts ...grpc.CallOption) (*MirrorStatusResponse, error)
+	// streams the status of the mirror whenever it changes.
+	WatchMirrorStatus(ctx context.Context, in *MirrorStatusRequest, opts ...grpc.CallOption) (FlowService_WatchMirrorStatusClient, error)
+	// rotates the master key the configs in the catalog are encrypted with, the
+	// way rotate_encryption_key of nexus does, and streams its progress.
+	RotateEncryptionKey(ctx context.Context, in *RotateEncryptionKeyRequest, opts ...grpc.CallOption) (FlowService_RotateEncryptionKeyClient, error)
 }
 
 type flowServiceClient struct {
@@ -65,6 +93,15 @@ func (c *flowServiceClient) CreatePeer(ctx context.Context, in *CreatePeerReques
 	return out, nil
 }
 
+func (c *flowServiceClient) UpdatePeer(ctx context.Context, in *UpdatePeerRequest, opts ...grpc.CallOption) (*UpdatePeerResponse, error) {
+	out := new(UpdatePeerResponse)
+	err := c.cc.Invoke(ctx, FlowService_UpdatePeer_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *flowServiceClient) CreateCDCFlow(ctx context.Context, in *CreateCDCFlowRequest, opts ...grpc.CallOption) (*CreateCDCFlowResponse, error) {
 	out := new(CreateCDCFlowResponse)
 	err := c.cc.Invoke(ctx, FlowService_CreateCDCFlow_FullMethodName, in, out, opts...)
@@ -92,6 +129,60 @@ func (c *flowServiceClient) ShutdownFlow(ctx context.Context, in *ShutdownReques
 	return out, nil
 }
 
+func (c *flowServiceClient) PauseFlow(ctx context.Context, in *PauseFlowRequest, opts ...grpc.CallOption) (*PauseFlowResponse, error) {
+	out := new(PauseFlowResponse)
+	err := c.cc.Invoke(ctx, FlowService_PauseFlow_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *flowServiceClient) ResumeFlow(ctx context.Context, in *ResumeFlowRequest, opts ...grpc.CallOption) (*ResumeFlowResponse, error) {
+	out := new(ResumeFlowResponse)
+	err := c.cc.Invoke(ctx, FlowService_ResumeFlow_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *flowServiceClient) AddTablesToFlow(ctx context.Context, in *AddTablesToFlowRequest, opts ...grpc.CallOption) (*AddTablesToFlowResponse, error) {
+	out := new(AddTablesToFlowResponse)
+	err := c.cc.Invoke(ctx, FlowService_AddTablesToFlow_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *flowServiceClient) PauseTable(ctx context.Context, in *PauseTableRequest, opts ...grpc.CallOption) (*PauseTableResponse, error) {
+	out := new(PauseTableResponse)
+	err := c.cc.Invoke(ctx, FlowService_PauseTable_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *flowServiceClient) RemoveTableFromFlow(ctx context.Context, in *RemoveTableFromFlowRequest, opts ...grpc.CallOption) (*RemoveTableFromFlowResponse, error) {
+	out := new(RemoveTableFromFlowResponse)
+	err := c.cc.Invoke(ctx, FlowService_RemoveTableFromFlow_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *flowServiceClient) ResyncMirror(ctx context.Context, in *ResyncMirrorRequest, opts ...grpc.CallOption) (*ResyncMirrorResponse, error) {
+	out := new(ResyncMirrorResponse)
+	err := c.cc.Invoke(ctx, FlowService_ResyncMirror_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *flowServiceClient) MirrorStatus(ctx context.Context, in *MirrorStatusRequest, opts ...grpc.CallOption) (*MirrorStatusResponse, error) {
 	out := new(MirrorStatusResponse)
 	err := c.cc.Invoke(ctx, FlowService_MirrorStatus_FullMethodName, in, out, opts...)
@@ -101,16 +192,99 @@ func (c *flowServiceClient) MirrorStatus(ctx context.Context, in *MirrorStatusRe
 	return out, nil
 }
 
+func (c *flowServiceClient) WatchMirrorStatus(ctx context.Context, in *MirrorStatusRequest, opts ...grpc.CallOption) (FlowService_WatchMirrorStatusClient, error) {
+	stream, err := c.cc.NewStream(ctx, &FlowService_ServiceDesc.Streams[0], FlowService_WatchMirrorStatus_FullMethodName, opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &flowServiceWatchMirrorStatusClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type FlowService_WatchMirrorStatusClient interface {
+	Recv() (*MirrorStatusResponse, error)
+	grpc.ClientStream
+}
+
+type flowServiceWatchMirrorStatusClient struct {
+	grpc.ClientStream
+}
+
+func (x *flowServiceWatchMirrorStatusClient) Recv() (*MirrorStatusResponse, error) {
+	m := new(MirrorStatusResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
+func (c *flowServiceClient) RotateEncryptionKey(ctx context.Context, in *RotateEncryptionKeyRequest, opts ...grpc.CallOption) (FlowService_RotateEncryptionKeyClient, error) {
+	stream, err := c.cc.NewStream(ctx, &FlowService_ServiceDesc.Streams[1], FlowService_RotateEncryptionKey_FullMethodName, opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &flowServiceRotateEncryptionKeyClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type FlowService_RotateEncryptionKeyClient interface {
+	Recv() (*RotateEncryptionKeyProgress, error)
+	grpc.ClientStream
+}
+
+type flowServiceRotateEncryptionKeyClient struct {
+	grpc.ClientStream
+}
+
+func (x *flowServiceRotateEncryptionKeyClient) Recv() (*RotateEncryptionKeyProgress, error) {
+	m := new(RotateEncryptionKeyProgress)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 // FlowServiceServer is the server API for FlowService service.
 // All implementations must embed UnimplementedFlowServiceServer
 // for forward compatibility
 type FlowServiceServer interface {
 	ValidatePeer(context.Context, *ValidatePeerRequest) (*ValidatePeerResponse, error)
 	CreatePeer(context.Context, *CreatePeerRequest) (*CreatePeerResponse, error)
+	// saves a new config of a peer, and has its running cdc mirrors reconnect with
+	// it at their next batch instead of being dropped and created again.
+	UpdatePeer(context.Context, *UpdatePeerRequest) (*UpdatePeerResponse, error)
 	CreateCDCFlow(context.Context, *CreateCDCFlowRequest) (*CreateCDCFlowResponse, error)
 	CreateQRepFlow(context.Context, *CreateQRepFlowRequest) (*CreateQRepFlowResponse, error)
 	ShutdownFlow(context.Context, *ShutdownRequest) (*ShutdownResponse, error)
+	PauseFlow(context.Context, *PauseFlowRequest) (*PauseFlowResponse, error)
+	ResumeFlow(context.Context, *ResumeFlowRequest) (*ResumeFlowResponse, error)
+	// snapshots the tables and adds them to a running cdc flow.
+	AddTablesToFlow(context.Context, *AddTablesToFlowRequest) (*AddTablesToFlowResponse, error)
+	// pauses or resumes replication of one table of a running cdc flow.
+	PauseTable(context.Context, *PauseTableRequest) (*PauseTableResponse, error)
+	// stops replicating one table of a running cdc flow.
+	RemoveTableFromFlow(context.Context, *RemoveTableFromFlowRequest) (*RemoveTableFromFlowResponse, error)
+	// copies tables of a running cdc flow again and replaces the destination
+	// tables with the copies.
+	ResyncMirror(context.Context, *ResyncMirrorRequest) (*ResyncMirrorResponse, error)
 	MirrorStatus(context.Context, *MirrorStatusRequest) (*MirrorStatusResponse, error)
+	// streams the status of the mirror whenever it changes.
+	WatchMirrorStatus(*MirrorStatusRequest, FlowService_WatchMirrorStatusServer) error
+	// rotates the master key the configs in the catalog are encrypted with, the
+	// way rotate_encryption_key of nexus does, and streams its progress.
+	RotateEncryptionKey(*RotateEncryptionKeyRequest, FlowService_RotateEncryptionKeyServer) error
 	mustEmbedUnimplementedFlowServiceServer()
 }
 
@@ -124,6 +298,9 @@ func (UnimplementedFlowServiceServer) ValidatePeer(context.Context, *ValidatePee
 func (UnimplementedFlowServiceServer) CreatePeer(context.Context, *CreatePeerRequest) (*CreatePeerResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method CreatePeer not implemented")
 }
+func (UnimplementedFlowServiceServer) UpdatePeer(context.Context, *UpdatePeerRequest) (*UpdatePeerResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdatePeer not implemented")
+}
 func (UnimplementedFlowServiceServer) CreateCDCFlow(context.Context, *CreateCDCFlowRequest) (*CreateCDCFlowResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method CreateCDCFlow not implemented")
 }
@@ -133,9 +310,33 @@ func (UnimplementedFlowServiceServer) CreateQRepFlow(context.Context, *CreateQRe
 func (UnimplementedFlowServiceServer) ShutdownFlow(context.Context, *ShutdownRequest) (*ShutdownResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method ShutdownFlow not implemented")
 }
+func (UnimplementedFlowServiceServer) PauseFlow(context.Context, *PauseFlowRequest) (*PauseFlowResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PauseFlow not implemented")
+}
+func (UnimplementedFlowServiceServer) ResumeFlow(context.Context, *ResumeFlowRequest) (*ResumeFlowResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ResumeFlow not implemented")
+}
+func (UnimplementedFlowServiceServer) AddTablesToFlow(context.Context, *AddTablesToFlowRequest) (*AddTablesToFlowResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AddTablesToFlow not implemented")
+}
+func (UnimplementedFlowServiceServer) PauseTable(context.Context, *PauseTableRequest) (*PauseTableResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PauseTable not implemented")
+}
+func (UnimplementedFlowServiceServer) RemoveTableFromFlow(context.Context, *RemoveTableFromFlowRequest) (*RemoveTableFromFlowResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RemoveTableFromFlow not implemented")
+}
+func (UnimplementedFlowServiceServer) ResyncMirror(context.Context, *ResyncMirrorRequest) (*ResyncMirrorResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ResyncMirror not implemented")
+}
 func (UnimplementedFlowServiceServer) MirrorStatus(context.Context, *MirrorStatusRequest) (*MirrorStatusResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method MirrorStatus not implemented")
 }
+func (UnimplementedFlowServiceServer) WatchMirrorStatus(*MirrorStatusRequest, FlowService_WatchMirrorStatusServer) error {
+	return status.Errorf(codes.Unimplemented, "method WatchMirrorStatus not implemented")
+}
+func (UnimplementedFlowServiceServer) RotateEncryptionKey(*RotateEncryptionKeyRequest, FlowService_RotateEncryptionKeyServer) error {
+	return status.Errorf(codes.Unimplemented, "method RotateEncryptionKey not implemented")
+}
 func (UnimplementedFlowServiceServer) mustEmbedUnimplementedFlowServiceServer() {}
 
 // UnsafeFlowServiceServer may be embedded to opt out of forward compatibility for this service.
@@ -185,6 +386,24 @@ func _FlowService_CreatePeer_Handler(srv interface{}, ctx context.Context, dec f
 	return interceptor(ctx, in, info, handler)
 }
 
+func _FlowService_UpdatePeer_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdatePeerRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(FlowServiceServer).UpdatePeer(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: FlowService_UpdatePeer_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(FlowServiceServer).UpdatePeer(ctx, req.(*UpdatePeerRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _FlowService_CreateCDCFlow_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(CreateCDCFlowRequest)
 	if err := dec(in); err != nil {
@@ -239,6 +458,114 @@ func _FlowService_ShutdownFlow_Handler(srv interface{}, ctx context.Context, dec
 	return interceptor(ctx, in, info, handler)
 }
 
+func _FlowService_PauseFlow_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PauseFlowRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(FlowServiceServer).PauseFlow(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: FlowService_PauseFlow_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(FlowServiceServer).PauseFlow(ctx, req.(*PauseFlowRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _FlowService_ResumeFlow_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ResumeFlowRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(FlowServiceServer).ResumeFlow(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: FlowService_ResumeFlow_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(FlowServiceServer).ResumeFlow(ctx, req.(*ResumeFlowRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _FlowService_AddTablesToFlow_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AddTablesToFlowRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(FlowServiceServer).AddTablesToFlow(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: FlowService_AddTablesToFlow_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(FlowServiceServer).AddTablesToFlow(ctx, req.(*AddTablesToFlowRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _FlowService_PauseTable_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PauseTableRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(FlowServiceServer).PauseTable(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: FlowService_PauseTable_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(FlowServiceServer).PauseTable(ctx, req.(*PauseTableRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _FlowService_RemoveTableFromFlow_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RemoveTableFromFlowRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(FlowServiceServer).RemoveTableFromFlow(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: FlowService_RemoveTableFromFlow_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(FlowServiceServer).RemoveTableFromFlow(ctx, req.(*RemoveTableFromFlowRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _FlowService_ResyncMirror_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ResyncMirrorRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(FlowServiceServer).ResyncMirror(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: FlowService_ResyncMirror_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(FlowServiceServer).ResyncMirror(ctx, req.(*ResyncMirrorRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _FlowService_MirrorStatus_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(MirrorStatusRequest)
 	if err := dec(in); err != nil {
@@ -257,6 +584,48 @@ func _FlowService_MirrorStatus_Handler(srv interface{}, ctx context.Context, dec
 	return interceptor(ctx, in, info, handler)
 }
 
+func _FlowService_WatchMirrorStatus_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(MirrorStatusRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(FlowServiceServer).WatchMirrorStatus(m, &flowServiceWatchMirrorStatusServer{stream})
+}
+
+type FlowService_WatchMirrorStatusServer interface {
+	Send(*MirrorStatusResponse) error
+	grpc.ServerStream
+}
+
+type flowServiceWatchMirrorStatusServer struct {
+	grpc.ServerStream
+}
+
+func (x *flowServiceWatchMirrorStatusServer) Send(m *MirrorStatusResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func _FlowService_RotateEncryptionKey_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(RotateEncryptionKeyRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(FlowServiceServer).RotateEncryptionKey(m, &flowServiceRotateEncryptionKeyServer{stream})
+}
+
+type FlowService_RotateEncryptionKeyServer interface {
+	Send(*RotateEncryptionKeyProgress) error
+	grpc.ServerStream
+}
+
+type flowServiceRotateEncryptionKeyServer struct {
+	grpc.ServerStream
+}
+
+func (x *flowServiceRotateEncryptionKeyServer) Send(m *RotateEncryptionKeyProgress) error {
+	return x.ServerStream.SendMsg(m)
+}
+
 // FlowService_ServiceDesc is the grpc.ServiceDesc for FlowService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -272,6 +641,10 @@ var FlowService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "CreatePeer",
 			Handler:    _FlowService_CreatePeer_Handler,
 		},
+		{
+			MethodName: "UpdatePeer",
+			Handler:    _FlowService_UpdatePeer_Handler,
+		},
 		{
 			MethodName: "CreateCDCFlow",
 			Handler:    _FlowService_CreateCDCFlow_Handler,
@@ -284,11 +657,46 @@ var FlowService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "ShutdownFlow",
 			Handler:    _FlowService_ShutdownFlow_Handler,
 		},
+		{
+			MethodName: "PauseFlow",
+			Handler:    _FlowService_PauseFlow_Handler,
+		},
+		{
+			MethodName: "ResumeFlow",
+			Handler:    _FlowService_ResumeFlow_Handler,
+		},
+		{
+			MethodName: "AddTablesToFlow",
+			Handler:    _FlowService_AddTablesToFlow_Handler,
+		},
+		{
+			MethodName: "PauseTable",
+			Handler:    _FlowService_PauseTable_Handler,
+		},
+		{
+			MethodName: "RemoveTableFromFlow",
+			Handler:    _FlowService_RemoveTableFromFlow_Handler,
+		},
+		{
+			MethodName: "ResyncMirror",
+			Handler:    _FlowService_ResyncMirror_Handler,
+		},
 		{
 			MethodName: "MirrorStatus",
 			Handler:    _FlowService_MirrorStatus_Handler,
 		},
 	},
-	Streams:  []grpc.StreamDesc{},
+	Streams: []grpc.StreamDesc{
+		{
+			StreamName:    "WatchMirrorStatus",
+			Handler:       _FlowService_WatchMirrorStatus_Handler,
+			ServerStreams: true,
+		},
+		{
+			StreamName:    "RotateEncryptionKey",
+			Handler:       _FlowService_RotateEncryptionKey_Handler,
+			ServerStreams: true,
+		},
+	},
 	Metadata: "route.proto",
 }
@@ -24,6 +24,8 @@ type PullRecordsRequest struct {
 	SrcTableIDNameMapping map[uint32]string
 	// source to destination table name mapping
 	TableNameMapping map[string]string
+	// source table to the columns that are never replicated
+	ExcludedColumns map[string]map[string]struct{}
 	// tablename to schema mapping
 	TableNameSchemaMapping map[string]*protos.TableSchema
 	// override publication name
@@ -206,6 +208,8 @@ type InsertRecord struct {
 	CheckPointID int64
 	// CommitID is the ID of the commit corresponding to this record.
 	CommitID int64
+	// CommitTime is when the transaction of the record was committed at the source.
+	CommitTime time.Time
 	// Items is a map of column name to value.
 	Items *RecordItems
 }
@@ -236,6 +240,8 @@ type UpdateRecord struct {
 	NewItems *RecordItems
 	// unchanged toast columns
 	UnchangedToastColumns map[string]struct{}
+	// CommitTime is when the transaction of the record was committed at the source.
+	CommitTime time.Time
 }
 
 // Implement Record interface for UpdateRecord.
@@ -261,6 +267,8 @@ type DeleteRecord struct {
 	CheckPointID int64
 	// Items is a map of column name to value.
 	Items *RecordItems
+	// CommitTime is when the transaction of the record was committed at the source.
+	CommitTime time.Time
 }
 
 // Implement Record interface for DeleteRecord.
@@ -276,6 +284,21 @@ func (r *DeleteRecord) GetItems() *RecordItems {
 	return r.Items
 }
 
+// GetCommitTime returns when the transaction of the record was committed at the source, the zero time
+// for records without one.
+func GetCommitTime(record Record) time.Time {
+	switch r := record.(type) {
+	case *InsertRecord:
+		return r.CommitTime
+	case *UpdateRecord:
+		return r.CommitTime
+	case *DeleteRecord:
+		return r.CommitTime
+	default:
+		return time.Time{}
+	}
+}
+
 type TableWithPkey struct {
 	TableName  string
 	PkeyColVal string
@@ -292,6 +315,37 @@ type RecordBatch struct {
 	TablePKeyLastSeen map[TableWithPkey]int
 }
 
+// DropTables removes the records of the given destination tables from the batch.
+func (r *RecordBatch) DropTables(destinationTableNames map[string]struct{}) {
+	newIndexes := make(map[int]int, len(r.Records))
+	records := make([]Record, 0, len(r.Records))
+	for i, record := range r.Records {
+		if _, ok := destinationTableNames[DestinationTableName(record)]; ok {
+			continue
+		}
+		newIndexes[i] = len(records)
+		records = append(records, record)
+	}
+	r.Records = records
+
+	for tablePkey, i := range r.TablePKeyLastSeen {
+		if newIndex, ok := newIndexes[i]; ok {
+			r.TablePKeyLastSeen[tablePkey] = newIndex
+		} else {
+			delete(r.TablePKeyLastSeen, tablePkey)
+		}
+	}
+}
+
+// DestinationTableName returns the destination table of the record, delete records
+// report their source table as their table name.
+func DestinationTableName(record Record) string {
+	if deleteRecord, ok := record.(*DeleteRecord); ok {
+		return deleteRecord.DestinationTableName
+	}
+	return record.GetTableName()
+}
+
 type SyncRecordsRequest struct {
 	Records *RecordBatch
 	// FlowJobName is the name of the flow job.
@@ -300,15 +354,56 @@ type SyncRecordsRequest struct {
 	SyncMode protos.QRepSyncMode
 	// Staging path for AVRO files in CDC
 	StagingPath string
+	// StagingIntegration is the Snowflake storage integration to use for an S3 staging path.
+	StagingIntegration string
 	// PushBatchSize is the number of records to push in a batch for EventHub.
 	PushBatchSize int64
 	// PushParallelism is the number of batches in Event Hub to push in parallel.
 	PushParallelism int64
+	// OrderingKey is how records are keyed on queue destinations.
+	OrderingKey protos.OrderingKey
+	// PartitionKeys is the partition_key column of each destination table, for ORDERING_KEY_PARTITION_KEY.
+	PartitionKeys map[string]string
 }
 
 type NormalizeRecordsRequest struct {
 	FlowJobName string
 	SoftDelete  bool
+	// the column deleted records are marked in, the connector's default when empty.
+	SoftDeleteColName string
+	// the column records are stamped with the time they were synced in, none when empty.
+	SyncedAtColName string
+	// destination tables paused with the buffer policy, their records stay in the raw table.
+	PausedTables map[string]struct{}
+	// destination tables resumed after being paused with the buffer policy, to the last batch
+	// normalized before they were paused.
+	ResumedTables map[string]int64
+}
+
+// IsPaused returns whether records of the destination table should be left in the raw table.
+func (r *NormalizeRecordsRequest) IsPaused(destinationTableName string) bool {
+	_, ok := r.PausedTables[destinationTableName]
+	return ok
+}
+
+// TableNormalizeBatchID returns the batch after which records of the destination table are still to
+// be normalized, resumed tables go back to where they were paused.
+func (r *NormalizeRecordsRequest) TableNormalizeBatchID(destinationTableName string, normalizeBatchID int64) int64 {
+	if pausedAfter, ok := r.ResumedTables[destinationTableName]; ok && pausedAfter < normalizeBatchID {
+		return pausedAfter
+	}
+	return normalizeBatchID
+}
+
+// MinNormalizeBatchID returns the batch after which records of any destination table are still to
+// be normalized.
+func (r *NormalizeRecordsRequest) MinNormalizeBatchID(normalizeBatchID int64) int64 {
+	for _, pausedAfter := range r.ResumedTables {
+		if pausedAfter < normalizeBatchID {
+			normalizeBatchID = pausedAfter
+		}
+	}
+	return normalizeBatchID
 }
 
 type SyncResponse struct {
@@ -0,0 +1,77 @@
+package qvalue
+
+import (
+	"fmt"
+	"math/big"
+	"strings"
+	"unicode"
+)
+
+// MoneyFormat is how a Postgres server renders money values, it depends on the
+// lc_monetary setting of the server.
+type MoneyFormat struct {
+	CurrencySymbol string
+	// zero for currencies without fractional digits.
+	DecimalSeparator rune
+}
+
+// DetectMoneyFormat derives the money format from a value with fractional
+// digits as rendered by the server, e.g. "$0.50" or "0,50 €".
+func DetectMoneyFormat(sample string) MoneyFormat {
+	runes := []rune(sample)
+	first, last := -1, -1
+	for i, r := range runes {
+		if unicode.IsDigit(r) {
+			if first == -1 {
+				first = i
+			}
+			last = i
+		}
+	}
+
+	format := MoneyFormat{}
+	if first == -1 {
+		format.CurrencySymbol = cleanCurrencySymbol(sample)
+		return format
+	}
+	for _, r := range runes[first : last+1] {
+		if !unicode.IsDigit(r) {
+			format.DecimalSeparator = r
+		}
+	}
+	format.CurrencySymbol = cleanCurrencySymbol(string(runes[:first]) + string(runes[last+1:]))
+	return format
+}
+
+func cleanCurrencySymbol(s string) string {
+	return strings.TrimSpace(strings.Trim(s, "-() "))
+}
+
+// ParseMoney parses a money value rendered by the server in the given format,
+// dropping the currency symbol and the thousands separators.
+func ParseMoney(text string, format MoneyFormat) (*big.Rat, error) {
+	value := strings.TrimSpace(text)
+	if format.CurrencySymbol != "" {
+		value = strings.Replace(value, format.CurrencySymbol, "", 1)
+	}
+	negative := strings.Contains(value, "-") || strings.HasPrefix(strings.TrimSpace(value), "(")
+
+	var b strings.Builder
+	if negative {
+		b.WriteRune('-')
+	}
+	for _, r := range value {
+		switch {
+		case unicode.IsDigit(r):
+			b.WriteRune(r)
+		case format.DecimalSeparator != 0 && r == format.DecimalSeparator:
+			b.WriteRune('.')
+		}
+	}
+
+	rat, ok := new(big.Rat).SetString(b.String())
+	if !ok {
+		return nil, fmt.Errorf("unable to parse money value %q", text)
+	}
+	return rat, nil
+}
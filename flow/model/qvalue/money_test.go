@@ -0,0 +1,64 @@
+package qvalue
+
+import (
+	"math/big"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestParseMoney(t *testing.T) {
+	tests := []struct {
+		name   string
+		sample string
+		value  string
+		symbol string
+		want   *big.Rat
+	}{
+		{
+			name:   "en_US",
+			sample: "$0.50",
+			value:  "$1,234.56",
+			symbol: "$",
+			want:   big.NewRat(123456, 100),
+		},
+		{
+			name:   "en_US negative",
+			sample: "$0.50",
+			value:  "-$1,234.56",
+			symbol: "$",
+			want:   big.NewRat(-123456, 100),
+		},
+		{
+			name:   "de_DE",
+			sample: "0,50 €",
+			value:  "1.234,56 €",
+			symbol: "€",
+			want:   big.NewRat(123456, 100),
+		},
+		{
+			name:   "parenthesized negative",
+			sample: "$0.50",
+			value:  "($12.00)",
+			symbol: "$",
+			want:   big.NewRat(-12, 1),
+		},
+		{
+			name:   "no fractional digits",
+			sample: "¥1",
+			value:  "¥1,235",
+			symbol: "¥",
+			want:   big.NewRat(1235, 1),
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			format := DetectMoneyFormat(tt.sample)
+			assert.Equal(t, tt.symbol, format.CurrencySymbol)
+			got, err := ParseMoney(tt.value, format)
+			assert.NoError(t, err)
+			assert.Equal(t, 0, got.Cmp(tt.want))
+		})
+	}
+}
@@ -6,14 +6,45 @@ const (
 	CDCFlowSignalName     = "peer-flow-signal"
 )
 
+// carries the table mappings to add to a running cdc flow.
+const CDCFlowAddTablesSignalName = "cdc-flow-add-tables-signal"
+
+// carries a table of a running cdc flow to pause or resume.
+const CDCFlowPauseTableSignalName = "cdc-flow-pause-table-signal"
+
+// carries a table mapping to remove from a running cdc flow.
+const CDCFlowRemoveTableSignalName = "cdc-flow-remove-table-signal"
+
+// carries the table mappings of a running cdc flow to copy over again.
+const CDCFlowResyncSignalName = "cdc-flow-resync-signal"
+
+// carries the table mappings of a running cdc flow to truncate and copy over again in place.
+const CDCFlowTruncateResyncSignalName = "cdc-flow-truncate-resync-signal"
+
+// carries a new config of a peer of a running cdc flow, e.g. with rotated credentials.
+const CDCFlowUpdatePeerSignalName = "cdc-flow-update-peer-signal"
+
 type CDCFlowSignal int64
 type ContextKey string
 
 const (
 	NoopSignal CDCFlowSignal = iota
 	ShutdownSignal
+	// pauses the cdc flow until it is signalled with NoopSignal.
+	PauseSignal
 	EnableMetricsKey    ContextKey = "enableMetrics"
 	CDCMirrorMonitorKey ContextKey = "cdcMirrorMonitor"
 )
 
 const FetchAndChannelSize = 256 * 1024
+
+// suffix of the column that carries the currency of a money column, on postgres
+// sources that replicate it.
+const MoneyCurrencyColumnSuffix = "_currency"
+
+// columns stamped on the rows of mirrors that track their latency, with when the change of the row was
+// committed at the source and when it was applied to the destination.
+const (
+	CommitTimeColumnName = "_peerdb_commit_time"
+	AppliedAtColumnName  = "_peerdb_applied_at"
+)
@@ -0,0 +1,36 @@
+package shared
+
+// MirrorErrorCategory is the kind of failure that keeps a mirror from being
+// created, it is returned to the client of CREATE MIRROR so that automation can
+// branch on it.
+type MirrorErrorCategory string
+
+const (
+	// the source can't create the replication slot of the mirror.
+	MirrorErrorSlotCreation MirrorErrorCategory = "slot_creation"
+	// the destination rejected the credentials of its peer.
+	MirrorErrorDestinationAuth MirrorErrorCategory = "destination_auth"
+	// a column of a source table can't be replicated into the destination table.
+	MirrorErrorTypeMismatch MirrorErrorCategory = "type_mismatch"
+)
+
+// grpc trailers that carry a MirrorError to the client, the message is the
+// status message. Detail and hint are binary so that they can carry any text.
+const (
+	MirrorErrorCategoryTrailer = "x-peerdb-error-category"
+	MirrorErrorDetailTrailer   = "x-peerdb-error-detail-bin"
+	MirrorErrorHintTrailer     = "x-peerdb-error-hint-bin"
+)
+
+type MirrorError struct {
+	Category MirrorErrorCategory
+	Message  string
+	// what was found, e.g. the setting or the column at fault.
+	Detail string
+	// what the user can do about it.
+	Hint string
+}
+
+func (e *MirrorError) Error() string {
+	return e.Message
+}
@@ -13,11 +13,13 @@ import (
 	"go.temporal.io/sdk/log"
 	"go.temporal.io/sdk/temporal"
 	"go.temporal.io/sdk/workflow"
+	"google.golang.org/protobuf/proto"
 )
 
 const (
-	CDCFlowStatusQuery     = "q-cdc-flow-status"
-	maxSyncFlowsPerCDCFlow = 32
+	CDCFlowStatusQuery       = "q-cdc-flow-status"
+	CDCFlowApplyBacklogQuery = "q-cdc-flow-apply-backlog"
+	maxSyncFlowsPerCDCFlow   = 32
 )
 
 type CDCFlowLimits struct {
@@ -69,6 +71,42 @@ type CDCFlowState struct {
 	// Global mapping of relation IDs to RelationMessages sent as a part of logical replication.
 	// Needed to support schema changes.
 	RelationMessageMapping model.RelationMessageMapping
+	// Tables signalled to be added to the peer flow that haven't been set up yet.
+	PendingTableMappings []*protos.TableMapping
+	// Destination tables paused with the buffer policy, to the last batch normalized before they were paused.
+	BufferedTables map[string]int64
+	// Tables signalled to be removed from the peer flow.
+	RemovedTableMappings []*protos.TableMapping
+	// Tables signalled to be copied over again.
+	ResyncTableMappings []*protos.TableMapping
+	// Tables signalled to be truncated and copied over again in place.
+	TruncateResyncTableMappings []*protos.TableMapping
+	// Batches synced outside the apply window of the peer flow that weren't normalized yet.
+	ApplyBacklog ApplyBacklog
+	// When the schemas mirrored as a whole were last checked for new tables.
+	LastSchemaRefresh time.Time
+	// New configs of the source or destination peer signalled since the last sync flow.
+	UpdatedPeers []*protos.Peer
+}
+
+// ApplyBacklog is what a peer flow with an apply window has buffered in the raw table since the window closed.
+type ApplyBacklog struct {
+	Batches int64
+	Records int64
+}
+
+// inApplyWindow is whether batches are normalized at the time, peer flows without an apply window normalize
+// batches as they are synced.
+func inApplyWindow(window *protos.ApplyWindow, now time.Time) bool {
+	if window == nil || window.StartMinute == window.EndMinute {
+		return true
+	}
+	now = now.UTC()
+	minute := uint32(now.Hour()*60 + now.Minute())
+	if window.StartMinute < window.EndMinute {
+		return minute >= window.StartMinute && minute < window.EndMinute
+	}
+	return minute >= window.StartMinute || minute < window.EndMinute
 }
 
 // returns a new empty PeerFlowState
@@ -104,6 +142,64 @@ func (s *CDCFlowState) TruncateProgress() {
 	}
 }
 
+// the last batch normalized on the destination as far as the peer flow knows, normalize flows often
+// run behind the sync flows.
+func (s *CDCFlowState) lastNormalizedBatchID() int64 {
+	for i := len(s.NormalizeFlowStatuses) - 1; i >= 0; i-- {
+		if s.NormalizeFlowStatuses[i] != nil {
+			return s.NormalizeFlowStatuses[i].EndBatchID
+		}
+	}
+	return 0
+}
+
+// setTablePaused pauses or resumes replication of a destination table of the peer flow. Tables paused
+// with the buffer policy remember the last batch normalized before the pause, resuming them goes back
+// to that batch to normalize the records buffered in the raw table since.
+func (s *CDCFlowState) setTablePaused(cfg *protos.FlowConnectionConfigs, req *protos.PauseTableRequest) {
+	tableName := req.TableIdentifier
+	if req.Paused {
+		if _, ok := cfg.PausedTables[tableName]; ok {
+			return
+		}
+		if cfg.PausedTables == nil {
+			cfg.PausedTables = make(map[string]protos.TablePausePolicy)
+		}
+		cfg.PausedTables[tableName] = req.Policy
+		if req.Policy != protos.TablePausePolicy_TABLE_PAUSE_POLICY_BUFFER {
+			return
+		}
+
+		pausedAfter := s.lastNormalizedBatchID()
+		// a table resumed and paused again before its buffered records were normalized still needs them.
+		if resumedAfter, ok := cfg.ResumedTables[tableName]; ok {
+			if resumedAfter < pausedAfter {
+				pausedAfter = resumedAfter
+			}
+			delete(cfg.ResumedTables, tableName)
+		}
+		if s.BufferedTables == nil {
+			s.BufferedTables = make(map[string]int64)
+		}
+		s.BufferedTables[tableName] = pausedAfter
+		return
+	}
+
+	policy, ok := cfg.PausedTables[tableName]
+	if !ok {
+		return
+	}
+	delete(cfg.PausedTables, tableName)
+	if policy != protos.TablePausePolicy_TABLE_PAUSE_POLICY_BUFFER {
+		return
+	}
+	if cfg.ResumedTables == nil {
+		cfg.ResumedTables = make(map[string]int64)
+	}
+	cfg.ResumedTables[tableName] = s.BufferedTables[tableName]
+	delete(s.BufferedTables, tableName)
+}
+
 func (s *CDCFlowState) SendWALHeartbeat(ctx workflow.Context, cfg *protos.FlowConnectionConfigs) error {
 	walHeartbeatCtx := workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
 		StartToCloseTimeout: 5 * time.Minute,
@@ -147,6 +243,268 @@ func GetChildWorkflowID(
 	return childWorkflowID, nil
 }
 
+// addTables sets up the added tables on the destination, adds them to the publication and copies them
+// over, then adds them to the config the following sync and normalize flows run with.
+func (w *CDCFlowWorkflowExecution) addTables(
+	ctx workflow.Context,
+	cfg *protos.FlowConnectionConfigs,
+	tableMappings []*protos.TableMapping,
+) error {
+	addedCfg := proto.Clone(cfg).(*protos.FlowConnectionConfigs)
+	addedCfg.TableMappings = tableMappings
+
+	setupFlowID, err := GetChildWorkflowID(ctx, "add-tables-setup-flow", cfg.FlowJobName)
+	if err != nil {
+		return err
+	}
+	setupFlowCtx := workflow.WithChildOptions(ctx, workflow.ChildWorkflowOptions{
+		WorkflowID:        setupFlowID,
+		ParentClosePolicy: enums.PARENT_CLOSE_POLICY_REQUEST_CANCEL,
+		RetryPolicy: &temporal.RetryPolicy{
+			MaximumAttempts: 20,
+		},
+	})
+	setupFlowFuture := workflow.ExecuteChildWorkflow(setupFlowCtx, SetupFlowWorkflow, addedCfg)
+	if err := setupFlowFuture.Get(setupFlowCtx, &addedCfg); err != nil {
+		return fmt.Errorf("failed to set up added tables: %w", err)
+	}
+
+	publicationCtx := workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
+		StartToCloseTimeout: 5 * time.Minute,
+	})
+	publicationFuture := workflow.ExecuteActivity(publicationCtx, flowable.AddTablesToPublication,
+		cfg, tableMappings)
+	if err := publicationFuture.Get(publicationCtx, nil); err != nil {
+		return fmt.Errorf("failed to add tables to publication: %w", err)
+	}
+
+	if cfg.DoInitialCopy {
+		snapshotFlowID, err := GetChildWorkflowID(ctx, "add-tables-snapshot-flow", cfg.FlowJobName)
+		if err != nil {
+			return err
+		}
+		snapshotFlowCtx := workflow.WithChildOptions(ctx, workflow.ChildWorkflowOptions{
+			WorkflowID:        snapshotFlowID,
+			ParentClosePolicy: enums.PARENT_CLOSE_POLICY_REQUEST_CANCEL,
+			RetryPolicy: &temporal.RetryPolicy{
+				MaximumAttempts: 20,
+			},
+		})
+		snapshotFlowFuture := workflow.ExecuteChildWorkflow(snapshotFlowCtx, AddedTablesSnapshotFlowWorkflow,
+			addedCfg)
+		if err := snapshotFlowFuture.Get(snapshotFlowCtx, nil); err != nil {
+			return fmt.Errorf("failed to copy added tables: %w", err)
+		}
+	}
+
+	for relID, tableName := range addedCfg.SrcTableIdNameMapping {
+		cfg.SrcTableIdNameMapping[relID] = tableName
+	}
+	for tableName, tableSchema := range addedCfg.TableNameSchemaMapping {
+		cfg.TableNameSchemaMapping[tableName] = tableSchema
+	}
+	cfg.TableMappings = append(cfg.TableMappings, tableMappings...)
+	return nil
+}
+
+// getNewSchemaTables returns the tables created in the mirrored schemas that aren't mapped or pending yet.
+func (w *CDCFlowWorkflowExecution) getNewSchemaTables(
+	ctx workflow.Context,
+	cfg *protos.FlowConnectionConfigs,
+	pendingTableMappings []*protos.TableMapping,
+) ([]*protos.TableMapping, error) {
+	refreshCfg := proto.Clone(cfg).(*protos.FlowConnectionConfigs)
+	refreshCfg.TableMappings = append(refreshCfg.TableMappings, pendingTableMappings...)
+
+	refreshCtx := workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
+		StartToCloseTimeout: 5 * time.Minute,
+	})
+	var newTableMappings []*protos.TableMapping
+	refreshFuture := workflow.ExecuteActivity(refreshCtx, flowable.GetNewSchemaTables, refreshCfg)
+	if err := refreshFuture.Get(refreshCtx, &newTableMappings); err != nil {
+		return nil, err
+	}
+	return newTableMappings, nil
+}
+
+// RemoveTableMapping removes a table mapping and everything the config keeps about its tables, the
+// following sync flows ignore changes to the source table.
+func RemoveTableMapping(cfg *protos.FlowConnectionConfigs, tableMapping *protos.TableMapping) {
+	tableMappings := make([]*protos.TableMapping, 0, len(cfg.TableMappings))
+	for _, mapping := range cfg.TableMappings {
+		if mapping.SourceTableIdentifier != tableMapping.SourceTableIdentifier {
+			tableMappings = append(tableMappings, mapping)
+		}
+	}
+	cfg.TableMappings = tableMappings
+
+	for relID, tableName := range cfg.SrcTableIdNameMapping {
+		if tableName == tableMapping.SourceTableIdentifier {
+			delete(cfg.SrcTableIdNameMapping, relID)
+		}
+	}
+	delete(cfg.TableNameSchemaMapping, tableMapping.DestinationTableIdentifier)
+	delete(cfg.PausedTables, tableMapping.DestinationTableIdentifier)
+	delete(cfg.ResumedTables, tableMapping.DestinationTableIdentifier)
+}
+
+// removeTables stops replicating the removed tables, then drops them from the publication.
+func (w *CDCFlowWorkflowExecution) removeTables(
+	ctx workflow.Context,
+	state *CDCFlowState,
+	cfg *protos.FlowConnectionConfigs,
+	tableMappings []*protos.TableMapping,
+) error {
+	for _, tableMapping := range tableMappings {
+		RemoveTableMapping(cfg, tableMapping)
+		delete(state.BufferedTables, tableMapping.DestinationTableIdentifier)
+	}
+
+	publicationCtx := workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
+		StartToCloseTimeout: 5 * time.Minute,
+	})
+	publicationFuture := workflow.ExecuteActivity(publicationCtx, flowable.RemoveTablesFromPublication,
+		cfg, tableMappings)
+	if err := publicationFuture.Get(publicationCtx, nil); err != nil {
+		return fmt.Errorf("failed to remove tables from publication: %w", err)
+	}
+	return nil
+}
+
+// resyncTableSuffix is appended to the destination tables the resynced tables are copied into.
+const resyncTableSuffix = "_resync"
+
+// resyncTables copies the tables over again into new destination tables, then replaces the destination
+// tables with them. Changes made while the copy runs are merged into the new tables by the following
+// normalize flows.
+func (w *CDCFlowWorkflowExecution) resyncTables(
+	ctx workflow.Context,
+	cfg *protos.FlowConnectionConfigs,
+	tableMappings []*protos.TableMapping,
+) error {
+	resyncCfg := proto.Clone(cfg).(*protos.FlowConnectionConfigs)
+	resyncCfg.TableMappings = make([]*protos.TableMapping, 0, len(tableMappings))
+	copyIdentifiers := make([]string, 0, len(tableMappings))
+	replacements := make(map[string]string, len(tableMappings))
+	for _, tableMapping := range tableMappings {
+		copyMapping := proto.Clone(tableMapping).(*protos.TableMapping)
+		copyMapping.DestinationTableIdentifier += resyncTableSuffix
+		resyncCfg.TableMappings = append(resyncCfg.TableMappings, copyMapping)
+		copyIdentifiers = append(copyIdentifiers, copyMapping.DestinationTableIdentifier)
+		replacements[tableMapping.DestinationTableIdentifier] = copyMapping.DestinationTableIdentifier
+	}
+
+	tablesCtx := workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
+		StartToCloseTimeout: 15 * time.Minute,
+	})
+	// copies left behind by an earlier resync that didn't finish would otherwise be copied into.
+	dropFuture := workflow.ExecuteActivity(tablesCtx, flowable.DropDestinationTables, cfg, copyIdentifiers)
+	if err := dropFuture.Get(tablesCtx, nil); err != nil {
+		return fmt.Errorf("failed to drop earlier copies of resynced tables: %w", err)
+	}
+
+	setupFlowID, err := GetChildWorkflowID(ctx, "resync-setup-flow", cfg.FlowJobName)
+	if err != nil {
+		return err
+	}
+	setupFlowCtx := workflow.WithChildOptions(ctx, workflow.ChildWorkflowOptions{
+		WorkflowID:        setupFlowID,
+		ParentClosePolicy: enums.PARENT_CLOSE_POLICY_REQUEST_CANCEL,
+		RetryPolicy: &temporal.RetryPolicy{
+			MaximumAttempts: 20,
+		},
+	})
+	setupFlowFuture := workflow.ExecuteChildWorkflow(setupFlowCtx, SetupFlowWorkflow, resyncCfg)
+	if err := setupFlowFuture.Get(setupFlowCtx, &resyncCfg); err != nil {
+		return fmt.Errorf("failed to set up resynced tables: %w", err)
+	}
+
+	snapshotFlowID, err := GetChildWorkflowID(ctx, "resync-snapshot-flow", cfg.FlowJobName)
+	if err != nil {
+		return err
+	}
+	snapshotFlowCtx := workflow.WithChildOptions(ctx, workflow.ChildWorkflowOptions{
+		WorkflowID:        snapshotFlowID,
+		ParentClosePolicy: enums.PARENT_CLOSE_POLICY_REQUEST_CANCEL,
+		RetryPolicy: &temporal.RetryPolicy{
+			MaximumAttempts: 20,
+		},
+	})
+	snapshotFlowFuture := workflow.ExecuteChildWorkflow(snapshotFlowCtx, AddedTablesSnapshotFlowWorkflow, resyncCfg)
+	if err := snapshotFlowFuture.Get(snapshotFlowCtx, nil); err != nil {
+		return fmt.Errorf("failed to copy resynced tables: %w", err)
+	}
+
+	replaceFuture := workflow.ExecuteActivity(tablesCtx, flowable.ReplaceDestinationTables, cfg, replacements)
+	if err := replaceFuture.Get(tablesCtx, nil); err != nil {
+		return fmt.Errorf("failed to replace resynced tables: %w", err)
+	}
+
+	// the new tables were created with the current schema of the source tables.
+	for tableName, copyName := range replacements {
+		if tableSchema, ok := resyncCfg.TableNameSchemaMapping[copyName]; ok {
+			cfg.TableNameSchemaMapping[tableName] = tableSchema
+		}
+	}
+	return nil
+}
+
+// truncateResyncTables truncates the destination tables and copies the tables over again into them,
+// the destination tables are empty until the copy finishes. Changes made while the copy runs are
+// merged in by the following normalize flows.
+func (w *CDCFlowWorkflowExecution) truncateResyncTables(
+	ctx workflow.Context,
+	cfg *protos.FlowConnectionConfigs,
+	tableMappings []*protos.TableMapping,
+) error {
+	resyncCfg := proto.Clone(cfg).(*protos.FlowConnectionConfigs)
+	resyncCfg.TableMappings = tableMappings
+	tableIdentifiers := make([]string, 0, len(tableMappings))
+	for _, tableMapping := range tableMappings {
+		tableIdentifiers = append(tableIdentifiers, tableMapping.DestinationTableIdentifier)
+	}
+
+	tablesCtx := workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
+		StartToCloseTimeout: 15 * time.Minute,
+	})
+	truncateFuture := workflow.ExecuteActivity(tablesCtx, flowable.TruncateDestinationTables, cfg, tableIdentifiers)
+	if err := truncateFuture.Get(tablesCtx, nil); err != nil {
+		return fmt.Errorf("failed to truncate resynced tables: %w", err)
+	}
+
+	snapshotFlowID, err := GetChildWorkflowID(ctx, "truncate-resync-snapshot-flow", cfg.FlowJobName)
+	if err != nil {
+		return err
+	}
+	snapshotFlowCtx := workflow.WithChildOptions(ctx, workflow.ChildWorkflowOptions{
+		WorkflowID:        snapshotFlowID,
+		ParentClosePolicy: enums.PARENT_CLOSE_POLICY_REQUEST_CANCEL,
+		RetryPolicy: &temporal.RetryPolicy{
+			MaximumAttempts: 20,
+		},
+	})
+	snapshotFlowFuture := workflow.ExecuteChildWorkflow(snapshotFlowCtx, AddedTablesSnapshotFlowWorkflow, resyncCfg)
+	if err := snapshotFlowFuture.Get(snapshotFlowCtx, nil); err != nil {
+		return fmt.Errorf("failed to copy resynced tables: %w", err)
+	}
+	return nil
+}
+
+// ReplacePeerConfig swaps in the new config of the peer wherever the flow uses it, flows that don't use
+// the peer are left alone. Returns whether the flow uses the peer.
+func ReplacePeerConfig(cfg *protos.FlowConnectionConfigs, peer *protos.Peer) bool {
+	replaced := false
+	if cfg.Source != nil && cfg.Source.Name == peer.Name {
+		cfg.Source = peer
+		replaced = true
+	}
+	if cfg.Destination != nil && cfg.Destination.Name == peer.Name {
+		cfg.Destination = peer
+		replaced = true
+	}
+	return replaced
+}
+
 // CDCFlowWorkflowResult is the result of the PeerFlowWorkflow.
 type CDCFlowWorkflowResult = CDCFlowState
 
@@ -177,6 +535,12 @@ func CDCFlowWorkflowWithConfig(
 	if err != nil {
 		return state, fmt.Errorf("failed to set `%s` query handler: %w", CDCFlowStatusQuery, err)
 	}
+	err = workflow.SetQueryHandler(ctx, CDCFlowApplyBacklogQuery, func(jobName string) (ApplyBacklog, error) {
+		return state.ApplyBacklog, nil
+	})
+	if err != nil {
+		return state, fmt.Errorf("failed to set `%s` query handler: %w", CDCFlowApplyBacklogQuery, err)
+	}
 
 	signalChan := workflow.GetSignalChannel(ctx, shared.CDCFlowSignalName)
 	signalHandler := func(_ workflow.Context, v shared.CDCFlowSignal) {
@@ -192,6 +556,60 @@ func CDCFlowWorkflowWithConfig(
 		signalHandler(ctx, signalVal)
 	})
 
+	// Support a signal to add tables to the peer flow.
+	addTablesChan := workflow.GetSignalChannel(ctx, shared.CDCFlowAddTablesSignalName)
+	selector.AddReceive(addTablesChan, func(c workflow.ReceiveChannel, more bool) {
+		var tableMappings []*protos.TableMapping
+		c.Receive(ctx, &tableMappings)
+		w.logger.Info("received tables to add - ", len(tableMappings))
+		state.PendingTableMappings = append(state.PendingTableMappings, tableMappings...)
+	})
+
+	// Support a signal to pause or resume a single table of the peer flow.
+	pauseTableChan := workflow.GetSignalChannel(ctx, shared.CDCFlowPauseTableSignalName)
+	selector.AddReceive(pauseTableChan, func(c workflow.ReceiveChannel, more bool) {
+		var pauseTableReq *protos.PauseTableRequest
+		c.Receive(ctx, &pauseTableReq)
+		w.logger.Info("received table pause - ", pauseTableReq.TableIdentifier, pauseTableReq.Paused)
+		state.setTablePaused(cfg, pauseTableReq)
+	})
+
+	// Support a signal to remove a table from the peer flow.
+	removeTableChan := workflow.GetSignalChannel(ctx, shared.CDCFlowRemoveTableSignalName)
+	selector.AddReceive(removeTableChan, func(c workflow.ReceiveChannel, more bool) {
+		var tableMapping *protos.TableMapping
+		c.Receive(ctx, &tableMapping)
+		w.logger.Info("received table to remove - ", tableMapping.SourceTableIdentifier)
+		state.RemovedTableMappings = append(state.RemovedTableMappings, tableMapping)
+	})
+
+	// Support a signal to copy tables of the peer flow over again.
+	resyncChan := workflow.GetSignalChannel(ctx, shared.CDCFlowResyncSignalName)
+	selector.AddReceive(resyncChan, func(c workflow.ReceiveChannel, more bool) {
+		var tableMappings []*protos.TableMapping
+		c.Receive(ctx, &tableMappings)
+		w.logger.Info("received tables to resync - ", len(tableMappings))
+		state.ResyncTableMappings = append(state.ResyncTableMappings, tableMappings...)
+	})
+
+	// Support a signal to truncate tables of the peer flow and copy them over again in place.
+	truncateResyncChan := workflow.GetSignalChannel(ctx, shared.CDCFlowTruncateResyncSignalName)
+	selector.AddReceive(truncateResyncChan, func(c workflow.ReceiveChannel, more bool) {
+		var tableMappings []*protos.TableMapping
+		c.Receive(ctx, &tableMappings)
+		w.logger.Info("received tables to truncate and resync - ", len(tableMappings))
+		state.TruncateResyncTableMappings = append(state.TruncateResyncTableMappings, tableMappings...)
+	})
+
+	// Support a signal to update the config of a peer of the peer flow.
+	updatePeerChan := workflow.GetSignalChannel(ctx, shared.CDCFlowUpdatePeerSignalName)
+	selector.AddReceive(updatePeerChan, func(c workflow.ReceiveChannel, more bool) {
+		var peer *protos.Peer
+		c.Receive(ctx, &peer)
+		w.logger.Info("received new config of peer - ", peer.Name)
+		state.UpdatedPeers = append(state.UpdatedPeers, peer)
+	})
+
 	if !state.SetupComplete {
 		// start the SetupFlow workflow as a child workflow, and wait for it to complete
 		// it should return the table schema for the source peer
@@ -232,9 +650,30 @@ func CDCFlowWorkflowWithConfig(
 		}
 
 		state.SetupComplete = true
+		state.LastSchemaRefresh = workflow.Now(ctx)
 		state.Progress = append(state.Progress, "executed setup flow and snapshot flow")
 	}
 
+	// a snapshot-only peer flow is done once the initial copy is, the slot and publication
+	// it was set up with are dropped and the workflow completes.
+	if cfg.InitialCopyOnly {
+		dropFlowCtx := workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
+			StartToCloseTimeout: 5 * time.Minute,
+		})
+		dropFlowFuture := workflow.ExecuteActivity(dropFlowCtx, flowable.DropFlow, &protos.ShutdownRequest{
+			FlowJobName:     cfg.FlowJobName,
+			SourcePeer:      cfg.Source,
+			DestinationPeer: cfg.Destination,
+		})
+		if err := dropFlowFuture.Get(dropFlowCtx, nil); err != nil {
+			return state, fmt.Errorf("failed to clean up snapshot-only peer flow: %w", err)
+		}
+
+		w.logger.Info("snapshot-only peer flow has completed")
+		state.Progress = append(state.Progress, "completed initial copy")
+		return state, nil
+	}
+
 	syncFlowOptions := &protos.SyncFlowOptions{
 		BatchSize: int32(limits.MaxBatchSize),
 	}
@@ -248,6 +687,80 @@ func CDCFlowWorkflowWithConfig(
 			return state, nil
 		}
 
+		// a paused peer flow runs no sync flows, signals are still handled until it is resumed or shut down.
+		if state.ActiveSignal == shared.PauseSignal {
+			w.logger.Info("peer flow has been paused")
+			for state.ActiveSignal == shared.PauseSignal {
+				selector.Select(ctx)
+			}
+			continue
+		}
+
+		// new peer configs are swapped in between batches, the next sync flow connects with them.
+		if len(state.UpdatedPeers) > 0 {
+			for _, peer := range state.UpdatedPeers {
+				if ReplacePeerConfig(cfg, peer) {
+					state.Progress = append(state.Progress, "updated config of peer "+peer.Name)
+				}
+			}
+			state.UpdatedPeers = nil
+		}
+
+		// removed tables are dropped before the next sync flow, which then ignores their changes.
+		if len(state.RemovedTableMappings) > 0 {
+			if err := w.removeTables(ctx, state, cfg, state.RemovedTableMappings); err != nil {
+				w.logger.Error("failed to remove tables: ", err)
+				state.SyncFlowErrors = multierror.Append(state.SyncFlowErrors, err)
+			} else {
+				state.Progress = append(state.Progress, "removed tables from peer flow")
+			}
+			state.RemovedTableMappings = nil
+		}
+
+		// tables created in the mirrored schemas are added like the tables signalled to be added.
+		schemaRefreshInterval := time.Duration(cfg.SchemaRefreshIntervalSeconds) * time.Second
+		if schemaRefreshInterval > 0 && workflow.Now(ctx).Sub(state.LastSchemaRefresh) >= schemaRefreshInterval {
+			newTableMappings, err := w.getNewSchemaTables(ctx, cfg, state.PendingTableMappings)
+			if err != nil {
+				w.logger.Error("failed to get new tables of mirrored schemas: ", err)
+				state.SyncFlowErrors = multierror.Append(state.SyncFlowErrors, err)
+			} else {
+				state.PendingTableMappings = append(state.PendingTableMappings, newTableMappings...)
+			}
+			state.LastSchemaRefresh = workflow.Now(ctx)
+		}
+
+		// added tables are copied before the next sync flow, which picks up their changes.
+		if len(state.PendingTableMappings) > 0 {
+			if err := w.addTables(ctx, cfg, state.PendingTableMappings); err != nil {
+				w.logger.Error("failed to add tables: ", err)
+				state.SyncFlowErrors = multierror.Append(state.SyncFlowErrors, err)
+			} else {
+				state.Progress = append(state.Progress, "added tables to peer flow")
+			}
+			state.PendingTableMappings = nil
+		}
+
+		// resynced tables are swapped in before the next sync flow, whose changes are merged into them.
+		if len(state.ResyncTableMappings) > 0 {
+			if err := w.resyncTables(ctx, cfg, state.ResyncTableMappings); err != nil {
+				w.logger.Error("failed to resync tables: ", err)
+				state.SyncFlowErrors = multierror.Append(state.SyncFlowErrors, err)
+			} else {
+				state.Progress = append(state.Progress, "resynced tables of peer flow")
+			}
+			state.ResyncTableMappings = nil
+		}
+		if len(state.TruncateResyncTableMappings) > 0 {
+			if err := w.truncateResyncTables(ctx, cfg, state.TruncateResyncTableMappings); err != nil {
+				w.logger.Error("failed to truncate and resync tables: ", err)
+				state.SyncFlowErrors = multierror.Append(state.SyncFlowErrors, err)
+			} else {
+				state.Progress = append(state.Progress, "truncated and resynced tables of peer flow")
+			}
+			state.TruncateResyncTableMappings = nil
+		}
+
 		// check if total sync flows have been completed
 		if limits.TotalSyncFlows != 0 && currentSyncFlowNum == limits.TotalSyncFlows {
 			w.logger.Info("All the syncflows have completed successfully, there was a"+
@@ -332,6 +845,8 @@ func CDCFlowWorkflowWithConfig(
 				w.logger.Error("failed to execute schema update at source: ", err)
 				state.SyncFlowErrors = multierror.Append(state.SyncFlowErrors, err)
 			} else {
+				removeExcludedColumns(cfg, getModifiedSchemaRes.TableNameSchemaMapping)
+				addLatencyColumns(cfg, getModifiedSchemaRes.TableNameSchemaMapping)
 				for i := range modifiedSrcTables {
 					cfg.TableNameSchemaMapping[modifiedDstTables[i]] =
 						getModifiedSchemaRes.TableNameSchemaMapping[modifiedSrcTables[i]]
@@ -339,6 +854,24 @@ func CDCFlowWorkflowWithConfig(
 			}
 		}
 
+		// outside the apply window the synced records stay in the raw table, the first normalize flow in the
+		// window catches up on them. signals are still handled in the meantime.
+		if !inApplyWindow(cfg.ApplyWindow, workflow.Now(ctx)) {
+			if childSyncFlowRes != nil && childSyncFlowRes.NumRecordsSynced > 0 {
+				state.ApplyBacklog.Batches++
+				state.ApplyBacklog.Records += childSyncFlowRes.NumRecordsSynced
+			}
+			for selector.HasPending() {
+				selector.Select(ctx)
+			}
+			continue
+		}
+
+		// resumed tables are caught up by the first normalize flow that goes through.
+		normalizedResumedTables := make(map[string]int64, len(cfg.ResumedTables))
+		for tableName, pausedAfter := range cfg.ResumedTables {
+			normalizedResumedTables[tableName] = pausedAfter
+		}
 		childNormalizeFlowFuture := workflow.ExecuteChildWorkflow(
 			ctx,
 			NormalizeFlowWorkflow,
@@ -352,6 +885,14 @@ func CDCFlowWorkflowWithConfig(
 				state.NormalizeFlowErrors = multierror.Append(state.NormalizeFlowErrors, err)
 			} else {
 				state.NormalizeFlowStatuses = append(state.NormalizeFlowStatuses, childNormalizeFlowRes)
+				if childNormalizeFlowRes != nil && childNormalizeFlowRes.Done {
+					state.ApplyBacklog = ApplyBacklog{}
+					for tableName, pausedAfter := range normalizedResumedTables {
+						if cfg.ResumedTables[tableName] == pausedAfter {
+							delete(cfg.ResumedTables, tableName)
+						}
+					}
+				}
 			}
 		})
 		selector.Select(ctx)
@@ -8,6 +8,7 @@ import (
 	"github.com/PeerDB-io/peer-flow/generated/protos"
 	"github.com/PeerDB-io/peer-flow/shared"
 	"github.com/google/uuid"
+	"github.com/robfig/cron"
 	"go.temporal.io/api/enums/v1"
 	"go.temporal.io/sdk/log"
 	"go.temporal.io/sdk/temporal"
@@ -238,6 +239,22 @@ func (q *QRepFlowExecution) consolidatePartitions(ctx workflow.Context) error {
 	return nil
 }
 
+// refreshTableStatistics refreshes the statistics of the destination table once enough rows were
+// loaded into it, a failed refresh only leaves them stale.
+func (q *QRepFlowExecution) refreshTableStatistics(ctx workflow.Context) {
+	ctx = workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
+		StartToCloseTimeout: time.Hour,
+		RetryPolicy: &temporal.RetryPolicy{
+			MaximumAttempts: 3,
+		},
+	})
+
+	if err := workflow.ExecuteActivity(ctx, flowable.RefreshTableStatistics, q.config.DestinationPeer,
+		[]string{q.config.DestinationTableIdentifier}, q.config.RefreshStatisticsThreshold).Get(ctx, nil); err != nil {
+		q.logger.Warn("failed to refresh table statistics: ", err)
+	}
+}
+
 func QRepFlowWorkflow(
 	ctx workflow.Context,
 	config *protos.QRepConfig,
@@ -332,6 +349,10 @@ func QRepFlowWorkflow(
 		return err
 	}
 
+	if config.RefreshStatisticsThreshold > 0 && len(partitions.Partitions) > 0 {
+		q.refreshTableStatistics(ctx)
+	}
+
 	if config.InitialCopyOnly {
 		q.logger.Info("initial copy completed for peer flow - ", config.FlowJobName)
 		return nil
@@ -352,7 +373,15 @@ func QRepFlowWorkflow(
 		return nil
 	}
 
-	// sleep for a while and continue the workflow
+	// sleep until the next scheduled run, or for a while, and continue the workflow
+	if config.CronSchedule != "" {
+		schedule, err := cron.ParseStandard(config.CronSchedule)
+		if err != nil {
+			return fmt.Errorf("invalid schedule %s: %w", config.CronSchedule, err)
+		}
+		now := workflow.Now(ctx)
+		waitBetweenBatches = schedule.Next(now).Sub(now)
+	}
 	err = workflow.Sleep(ctx, waitBetweenBatches)
 	if err != nil {
 		return fmt.Errorf("failed to sleep: %w", err)
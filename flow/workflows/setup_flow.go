@@ -3,10 +3,13 @@ package peerflow
 import (
 	"fmt"
 	"sort"
+	"strings"
 	"time"
 
 	"github.com/PeerDB-io/peer-flow/activities"
 	"github.com/PeerDB-io/peer-flow/generated/protos"
+	"github.com/PeerDB-io/peer-flow/model/qvalue"
+	"github.com/PeerDB-io/peer-flow/shared"
 	"golang.org/x/exp/maps"
 
 	"go.temporal.io/sdk/log"
@@ -189,6 +192,8 @@ func (s *SetupFlowExecution) fetchTableSchemaAndSetupNormalizedTables(
 	}
 
 	tableNameSchemaMapping := tblSchemaOutput.TableNameSchemaMapping
+	removeExcludedColumns(flowConnectionConfigs, tableNameSchemaMapping)
+	addLatencyColumns(flowConnectionConfigs, tableNameSchemaMapping)
 	sortedSourceTables := maps.Keys(tableNameSchemaMapping)
 	sort.Strings(sortedSourceTables)
 
@@ -205,6 +210,8 @@ func (s *SetupFlowExecution) fetchTableSchemaAndSetupNormalizedTables(
 	setupConfig := &protos.SetupNormalizedTableBatchInput{
 		PeerConnectionConfig:   flowConnectionConfigs.Destination,
 		TableNameSchemaMapping: normalizedTableMapping,
+		SoftDeleteColName:      flowConnectionConfigs.SoftDeleteColName,
+		SyncedAtColName:        flowConnectionConfigs.SyncedAtColName,
 	}
 
 	future = workflow.ExecuteActivity(ctx, flowable.CreateNormalizedTable, setupConfig)
@@ -218,6 +225,55 @@ func (s *SetupFlowExecution) fetchTableSchemaAndSetupNormalizedTables(
 	return normalizedTableMapping, nil
 }
 
+// removeExcludedColumns drops the columns excluded by the table mappings from the schemas of their
+// source tables, along with the currency columns of excluded money columns.
+func removeExcludedColumns(cfg *protos.FlowConnectionConfigs, srcTableSchemas map[string]*protos.TableSchema) {
+	for _, mapping := range cfg.TableMappings {
+		tableSchema, ok := srcTableSchemas[mapping.SourceTableIdentifier]
+		if !ok {
+			continue
+		}
+		for _, column := range mapping.Exclude {
+			currencyColumn := column + shared.MoneyCurrencyColumnSuffix
+			if isCurrencyColumn(cfg, tableSchema, currencyColumn) {
+				delete(tableSchema.Columns, currencyColumn)
+			}
+			delete(tableSchema.Columns, column)
+			delete(tableSchema.ColumnTypes, column)
+		}
+	}
+}
+
+// addLatencyColumns adds the columns rows are stamped with to the schemas of the source tables for
+// mirrors that track their latency.
+func addLatencyColumns(cfg *protos.FlowConnectionConfigs, srcTableSchemas map[string]*protos.TableSchema) {
+	if !cfg.TrackLatency {
+		return
+	}
+	for _, tableSchema := range srcTableSchemas {
+		tableSchema.Columns[shared.CommitTimeColumnName] = string(qvalue.QValueKindTimestampTZ)
+		tableSchema.Columns[shared.AppliedAtColumnName] = string(qvalue.QValueKindTimestampTZ)
+	}
+}
+
+// isLatencyColumn is whether the column is stamped on the rows by the flow instead of coming from the
+// source table.
+func isLatencyColumn(cfg *protos.FlowConnectionConfigs, column string) bool {
+	return cfg.TrackLatency && (column == shared.CommitTimeColumnName || column == shared.AppliedAtColumnName)
+}
+
+// isCurrencyColumn is whether the column carries the currency of a money column of the source table
+// instead of coming from the source table itself.
+func isCurrencyColumn(cfg *protos.FlowConnectionConfigs, tableSchema *protos.TableSchema, column string) bool {
+	moneyMapping := cfg.Source.GetPostgresConfig().GetMoneyMapping()
+	if moneyMapping != protos.MoneyMapping_MONEY_MAPPING_NUMERIC_WITH_CURRENCY ||
+		!strings.HasSuffix(column, shared.MoneyCurrencyColumnSuffix) {
+		return false
+	}
+	moneyColumn := strings.TrimSuffix(column, shared.MoneyCurrencyColumnSuffix)
+	return tableSchema.Columns[moneyColumn] == string(qvalue.QValueKindNumeric)
+}
+
 // executeSetupFlow executes the setup flow.
 func (s *SetupFlowExecution) executeSetupFlow(
 	ctx workflow.Context,
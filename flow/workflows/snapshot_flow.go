@@ -3,12 +3,15 @@ package peerflow
 import (
 	"fmt"
 	"regexp"
+	"sort"
+	"strings"
 	"time"
 
 	"github.com/PeerDB-io/peer-flow/concurrency"
 	"github.com/PeerDB-io/peer-flow/generated/protos"
 	"github.com/PeerDB-io/peer-flow/shared"
 	"github.com/google/uuid"
+	"github.com/jackc/pgx/v5"
 	logrus "github.com/sirupsen/logrus"
 	"go.temporal.io/sdk/log"
 	"go.temporal.io/sdk/temporal"
@@ -130,7 +133,8 @@ func (s *SnapshotFlowExecution) cloneTable(
 		partitionCol = mapping.PartitionKey
 	}
 
-	query := fmt.Sprintf("SELECT * FROM %s WHERE %s BETWEEN {{.start}} AND {{.end}}", srcName, partitionCol)
+	query := fmt.Sprintf("SELECT %s FROM %s WHERE %s BETWEEN {{.start}} AND {{.end}}",
+		s.selectedColumns(mapping), srcName, partitionCol)
 
 	numWorkers := uint32(8)
 	if s.config.SnapshotMaxParallelWorkers > 0 {
@@ -155,9 +159,13 @@ func (s *SnapshotFlowExecution) cloneTable(
 		SyncMode:                   s.config.SnapshotSyncMode,
 		MaxParallelWorkers:         numWorkers,
 		StagingPath:                s.config.SnapshotStagingPath,
+		StagingIntegration:         s.config.StagingIntegration,
 		WriteMode: &protos.QRepWriteMode{
 			WriteType: protos.QRepWriteType_QREP_WRITE_MODE_APPEND,
 		},
+		RefreshStatisticsThreshold: s.config.RefreshStatisticsThreshold,
+		SoftDeleteColName:          s.config.SoftDeleteColName,
+		SyncedAtColName:            s.config.SyncedAtColName,
 	}
 
 	numPartitionsProcessed := 0
@@ -166,6 +174,24 @@ func (s *SnapshotFlowExecution) cloneTable(
 	return nil
 }
 
+// selectedColumns returns the select list of the query that copies the table, which leaves out the
+// columns excluded by the table mapping and the columns the flow stamps rows with.
+func (s *SnapshotFlowExecution) selectedColumns(mapping *protos.TableMapping) string {
+	tableSchema, ok := s.config.TableNameSchemaMapping[mapping.DestinationTableIdentifier]
+	if (len(mapping.Exclude) == 0 && !s.config.TrackLatency) || !ok {
+		return "*"
+	}
+
+	columns := make([]string, 0, len(tableSchema.Columns))
+	for column := range tableSchema.Columns {
+		if !isCurrencyColumn(s.config, tableSchema, column) && !isLatencyColumn(s.config, column) {
+			columns = append(columns, pgx.Identifier{column}.Sanitize())
+		}
+	}
+	sort.Strings(columns)
+	return strings.Join(columns, ",")
+}
+
 // startChildQrepWorkflow starts a child workflow for query based replication.
 func (s *SnapshotFlowExecution) cloneTables(
 	ctx workflow.Context,
@@ -257,3 +283,24 @@ func SnapshotFlowWorkflow(ctx workflow.Context, config *protos.FlowConnectionCon
 
 	return nil
 }
+
+// AddedTablesSnapshotFlowWorkflow copies tables that were added to a running cdc flow. There is no
+// replication slot snapshot to copy them at, so changes made while the copy runs are streamed as well
+// and merged on the destination once the flow resumes.
+func AddedTablesSnapshotFlowWorkflow(ctx workflow.Context, config *protos.FlowConnectionConfigs) error {
+	logger := workflow.GetLogger(ctx)
+
+	se := &SnapshotFlowExecution{
+		config: config,
+		logger: logger,
+	}
+
+	numTablesInParallel := int(config.SnapshotNumTablesInParallel)
+	if numTablesInParallel <= 0 {
+		numTablesInParallel = 1
+	}
+
+	logger.Info("cloning added tables in parallel: ", numTablesInParallel)
+	se.cloneTables(ctx, &protos.SetupReplicationOutput{}, numTablesInParallel)
+	return nil
+}
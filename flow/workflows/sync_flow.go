@@ -7,6 +7,7 @@ import (
 	"github.com/PeerDB-io/peer-flow/generated/protos"
 	"github.com/PeerDB-io/peer-flow/model"
 	"go.temporal.io/sdk/log"
+	"go.temporal.io/sdk/temporal"
 	"go.temporal.io/sdk/workflow"
 )
 
@@ -167,5 +168,24 @@ func (s *NormalizeFlowExecution) executeNormalizeFlow(
 		return nil, fmt.Errorf("failed to flow: %w", err)
 	}
 
+	if config.RefreshStatisticsThreshold > 0 && normalizeResponse != nil && normalizeResponse.Done {
+		tableIdentifiers := make([]string, 0, len(config.TableMappings))
+		for _, tableMapping := range config.TableMappings {
+			tableIdentifiers = append(tableIdentifiers, tableMapping.DestinationTableIdentifier)
+		}
+
+		// stale statistics only slow down queries, so a failed refresh doesn't fail the normalize flow.
+		statisticsCtx := workflow.WithActivityOptions(ctx, workflow.ActivityOptions{
+			StartToCloseTimeout: time.Hour,
+			RetryPolicy: &temporal.RetryPolicy{
+				MaximumAttempts: 3,
+			},
+		})
+		if err := workflow.ExecuteActivity(statisticsCtx, flowable.RefreshTableStatistics, config.Destination,
+			tableIdentifiers, config.RefreshStatisticsThreshold).Get(statisticsCtx, nil); err != nil {
+			s.logger.Warn("failed to refresh table statistics: ", err)
+		}
+	}
+
 	return normalizeResponse, nil
 }
@@ -0,0 +1,589 @@
+// what of the tables of a federated query is read from their peers.
+
+use std::ops::ControlFlow;
+
+use sqlparser::ast::{
+    visit_expressions, visit_expressions_mut, BinaryOperator, Expr, Ident, JoinConstraint,
+    JoinOperator, ObjectName, Select, SelectItem, SetExpr, Statement, TableFactor, UnaryOperator,
+    Value,
+};
+
+/// The part of a table of a federated query the query reads, which is all
+/// nexus stages of the table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pushdown {
+    /// The columns of the table the query reads, None if it reads all of them
+    /// or its columns can't be told apart from those of the other tables.
+    pub columns: Option<Vec<Ident>>,
+    /// The conditions of the query over the table alone, with its columns
+    /// unqualified, which the peer filters the rows of the table with.
+    pub predicates: Vec<Expr>,
+    /// The columns the query inner joins the table on with a column of
+    /// another of its tables.
+    pub join_keys: Vec<JoinKey>,
+}
+
+/// A column of a table of a federated query equal to a column of another
+/// of its tables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinKey {
+    pub column: Ident,
+    /// The other table, by its index among the tables of the query.
+    pub other: usize,
+    pub other_column: Ident,
+}
+
+// a relation in the FROM clause of a query, with the table of the query it
+// is, if it is one.
+struct Relation<'a> {
+    name: &'a ObjectName,
+    alias: Option<&'a Ident>,
+    table: Option<usize>,
+}
+
+// what a column reference of a query refers to.
+enum Column {
+    Table(usize, Ident),
+    // a column of a relation that isn't one of the tables, or an output
+    // column of the query.
+    Other,
+    // a column that can't be attributed to a relation.
+    Unknown,
+}
+
+/// Works out what of each of the tables of a federated query the query
+/// reads. Only a plain SELECT is pushed down, without subqueries or joins
+/// on USING; the conditions only when all its joins are inner joins and
+/// each table is read once.
+pub fn pushdown(stmt: &Statement, tables: &[ObjectName]) -> Vec<Pushdown> {
+    let mut pushdowns = vec![Pushdown::default(); tables.len()];
+    let query = match stmt {
+        Statement::Query(query) if query.with.is_none() => query,
+        _ => return pushdowns,
+    };
+    let select = match query.body.as_ref() {
+        SetExpr::Select(select) => select,
+        _ => return pushdowns,
+    };
+    // subqueries have relations of their own, which their columns may refer to.
+    let has_subquery = visit_expressions(stmt, |expr| match expr {
+        Expr::Subquery(_) | Expr::Exists { .. } | Expr::InSubquery { .. } => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    });
+    if has_subquery.is_break() {
+        return pushdowns;
+    }
+    let resolver = match Resolver::new(select, tables) {
+        Some(resolver) => resolver,
+        None => return pushdowns,
+    };
+
+    // the columns of each table the query reads, None once it reads all of them.
+    let mut columns: Vec<Option<Vec<Ident>>> = vec![Some(vec![]); tables.len()];
+    let mut unknown = false;
+    for item in &select.projection {
+        match item {
+            SelectItem::Wildcard(_) => unknown = true,
+            SelectItem::QualifiedWildcard(name, _) => match resolver.relation(&name.0) {
+                Some(Some(table)) => columns[table] = None,
+                Some(None) => {}
+                None => unknown = true,
+            },
+            _ => {}
+        }
+    }
+    let mut read = |column: Column| match column {
+        Column::Table(table, ident) => {
+            if let Some(columns) = &mut columns[table] {
+                if !columns.iter().any(|column| same_ident(column, &ident)) {
+                    columns.push(ident);
+                }
+            }
+        }
+        Column::Other => {}
+        Column::Unknown => unknown = true,
+    };
+    let _ = visit_expressions(select.as_ref(), |expr| {
+        if let Some(column) = resolver.column(expr, &[]) {
+            read(column);
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    // ORDER BY may refer to the output columns of the query by their name.
+    let outputs: Vec<&Ident> = select
+        .projection
+        .iter()
+        .filter_map(|item| match item {
+            SelectItem::ExprWithAlias { alias, .. } => Some(alias),
+            _ => None,
+        })
+        .collect();
+    for order_by in &query.order_by {
+        let _ = visit_expressions(&order_by.expr, |expr| {
+            if let Some(column) = resolver.column(expr, &outputs) {
+                read(column);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+    if !unknown {
+        for (pushdown, columns) in pushdowns.iter_mut().zip(columns) {
+            // a table none of whose columns are read is staged whole, a query
+            // of no columns isn't valid on every peer.
+            pushdown.columns = columns.filter(|columns| !columns.is_empty());
+        }
+    }
+
+    if !resolver.inner_joins {
+        return pushdowns;
+    }
+    let mut conditions = vec![];
+    for condition in resolver.conditions.iter().copied().chain(&select.selection) {
+        conjuncts(condition, &mut conditions);
+    }
+    for condition in conditions {
+        if let Some((Column::Table(table, column), Column::Table(other, other_column))) =
+            join_key(&resolver, condition)
+        {
+            if table != other && resolver.read_once(table) && resolver.read_once(other) {
+                pushdowns[table].join_keys.push(JoinKey {
+                    column: column.clone(),
+                    other,
+                    other_column: other_column.clone(),
+                });
+                pushdowns[other].join_keys.push(JoinKey {
+                    column: other_column,
+                    other: table,
+                    other_column: column,
+                });
+                continue;
+            }
+        }
+        if let Some(table) = resolver.condition_table(condition) {
+            pushdowns[table].predicates.push(unqualified(condition));
+        }
+    }
+    pushdowns
+}
+
+struct Resolver<'a> {
+    relations: Vec<Relation<'a>>,
+    // the conditions of the joins of the query.
+    conditions: Vec<&'a Expr>,
+    inner_joins: bool,
+}
+
+impl<'a> Resolver<'a> {
+    // None if a relation of the query isn't a table, or a join of it is on
+    // columns that aren't named by an expression.
+    fn new(select: &'a Select, tables: &[ObjectName]) -> Option<Self> {
+        let mut resolver = Resolver {
+            relations: vec![],
+            conditions: vec![],
+            inner_joins: true,
+        };
+        for from in &select.from {
+            resolver.add_relation(&from.relation, tables)?;
+            for join in &from.joins {
+                resolver.add_relation(&join.relation, tables)?;
+                let constraint = match &join.join_operator {
+                    JoinOperator::Inner(constraint) => constraint,
+                    JoinOperator::LeftOuter(constraint)
+                    | JoinOperator::RightOuter(constraint)
+                    | JoinOperator::FullOuter(constraint) => {
+                        resolver.inner_joins = false;
+                        constraint
+                    }
+                    JoinOperator::CrossJoin => continue,
+                    _ => return None,
+                };
+                match constraint {
+                    JoinConstraint::On(condition) => resolver.conditions.push(condition),
+                    JoinConstraint::None => {}
+                    JoinConstraint::Using(_) | JoinConstraint::Natural => return None,
+                }
+            }
+        }
+        Some(resolver)
+    }
+
+    fn add_relation(&mut self, relation: &'a TableFactor, tables: &[ObjectName]) -> Option<()> {
+        match relation {
+            TableFactor::Table { name, alias, .. } => {
+                self.relations.push(Relation {
+                    name,
+                    alias: alias.as_ref().map(|alias| &alias.name),
+                    table: tables.iter().position(|table| table == name),
+                });
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn read_once(&self, table: usize) -> bool {
+        self.relations
+            .iter()
+            .filter(|relation| relation.table == Some(table))
+            .count()
+            == 1
+    }
+
+    // the relation a column qualifier refers to, Some(None) if it isn't one
+    // of the tables, None if it refers to no relation or to more than one.
+    fn relation(&self, qualifier: &[Ident]) -> Option<Option<usize>> {
+        let mut relations = self
+            .relations
+            .iter()
+            .filter(|relation| match relation.alias {
+                Some(alias) => matches!(qualifier, [name] if same_ident(name, alias)),
+                None => {
+                    let name = &relation.name.0;
+                    qualifier.len() <= name.len()
+                        && name[name.len() - qualifier.len()..]
+                            .iter()
+                            .zip(qualifier)
+                            .all(|(a, b)| same_ident(a, b))
+                }
+            });
+        match (relations.next(), relations.next()) {
+            (Some(relation), None) => Some(relation.table),
+            _ => None,
+        }
+    }
+
+    // the column an expression refers to, None if it isn't a column. An
+    // unqualified column is only attributed if it is one of the outputs.
+    fn column(&self, expr: &Expr, outputs: &[&Ident]) -> Option<Column> {
+        match expr {
+            Expr::Identifier(ident) if outputs.iter().any(|output| same_ident(output, ident)) => {
+                Some(Column::Other)
+            }
+            Expr::Identifier(_) => Some(Column::Unknown),
+            Expr::CompoundIdentifier(idents) => {
+                let (column, qualifier) = idents.split_last()?;
+                Some(match self.relation(qualifier) {
+                    Some(Some(table)) => Column::Table(table, column.clone()),
+                    Some(None) => Column::Other,
+                    None => Column::Unknown,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    // the table a condition is over alone, if the peer of the table can
+    // evaluate it as nexus would.
+    fn condition_table(&self, condition: &Expr) -> Option<usize> {
+        if !is_simple(condition) {
+            return None;
+        }
+        let mut tables = vec![];
+        let res = visit_expressions(condition, |expr| match self.column(expr, &[]) {
+            Some(Column::Table(table, _)) => {
+                tables.push(table);
+                ControlFlow::Continue(())
+            }
+            Some(_) => ControlFlow::Break(()),
+            None => ControlFlow::Continue(()),
+        });
+        match tables.split_first() {
+            Some((table, rest))
+                if res.is_continue()
+                    && rest.iter().all(|other| other == table)
+                    && self.read_once(*table) =>
+            {
+                Some(*table)
+            }
+            _ => None,
+        }
+    }
+}
+
+// the columns of an equality of two columns.
+fn join_key(resolver: &Resolver, condition: &Expr) -> Option<(Column, Column)> {
+    match condition {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => Some((resolver.column(left, &[])?, resolver.column(right, &[])?)),
+        _ => None,
+    }
+}
+
+// the conditions a condition is the conjunction of.
+fn conjuncts<'a>(condition: &'a Expr, conditions: &mut Vec<&'a Expr>) {
+    match condition {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            conjuncts(left, conditions);
+            conjuncts(right, conditions);
+        }
+        Expr::Nested(nested)
+            if matches!(
+                nested.as_ref(),
+                Expr::BinaryOp {
+                    op: BinaryOperator::And,
+                    ..
+                }
+            ) =>
+        {
+            conjuncts(nested, conditions)
+        }
+        _ => conditions.push(condition),
+    }
+}
+
+// whether a condition only compares columns with literals, which every peer
+// evaluates the same way.
+fn is_simple(expr: &Expr) -> bool {
+    match expr {
+        Expr::Identifier(_) | Expr::CompoundIdentifier(_) => true,
+        Expr::Value(Value::Placeholder(_)) => false,
+        Expr::Value(_) => true,
+        Expr::Nested(expr) | Expr::IsNull(expr) | Expr::IsNotNull(expr) => is_simple(expr),
+        Expr::UnaryOp {
+            op: UnaryOperator::Not | UnaryOperator::Minus,
+            expr,
+        } => is_simple(expr),
+        Expr::BinaryOp { left, op, right } => {
+            matches!(
+                op,
+                BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::Lt
+                    | BinaryOperator::LtEq
+                    | BinaryOperator::Gt
+                    | BinaryOperator::GtEq
+                    | BinaryOperator::And
+                    | BinaryOperator::Or
+            ) && is_simple(left)
+                && is_simple(right)
+        }
+        Expr::InList { expr, list, .. } => is_simple(expr) && list.iter().all(is_simple),
+        Expr::Between {
+            expr, low, high, ..
+        } => is_simple(expr) && is_simple(low) && is_simple(high),
+        Expr::Like { expr, pattern, .. } => is_simple(expr) && is_simple(pattern),
+        _ => false,
+    }
+}
+
+// a condition over a single table with its columns unqualified, for the
+// query the table is staged with.
+fn unqualified(condition: &Expr) -> Expr {
+    let mut condition = condition.clone();
+    let _ = visit_expressions_mut(&mut condition, |expr| {
+        if let Expr::CompoundIdentifier(idents) = expr {
+            if let Some(column) = idents.pop() {
+                *expr = Expr::Identifier(column);
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    condition
+}
+
+// whether two identifiers name the same thing, unquoted ones are case
+// insensitive.
+fn same_ident(a: &Ident, b: &Ident) -> bool {
+    let normalized = |ident: &Ident| match ident.quote_style {
+        Some(_) => ident.value.clone(),
+        None => ident.value.to_lowercase(),
+    };
+    normalized(a) == normalized(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlparser::{dialect::PostgreSqlDialect, parser::Parser};
+
+    fn parse(sql: &str) -> Statement {
+        Parser::parse_sql(&PostgreSqlDialect {}, sql)
+            .unwrap()
+            .pop()
+            .unwrap()
+    }
+
+    fn tables() -> Vec<ObjectName> {
+        vec![
+            ObjectName(vec![
+                Ident::new("pg1"),
+                Ident::new("public"),
+                Ident::new("users"),
+            ]),
+            ObjectName(vec![
+                Ident::new("pg2"),
+                Ident::new("public"),
+                Ident::new("orders"),
+            ]),
+        ]
+    }
+
+    fn columns(pushdown: &Pushdown) -> Option<Vec<String>> {
+        pushdown
+            .columns
+            .as_ref()
+            .map(|columns| columns.iter().map(|column| column.value.clone()).collect())
+    }
+
+    fn predicates(pushdown: &Pushdown) -> Vec<String> {
+        pushdown
+            .predicates
+            .iter()
+            .map(|predicate| predicate.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_pushdown_columns_and_predicates() {
+        let stmt = parse(
+            "SELECT u.name, o.total FROM pg1.public.users u JOIN pg2.public.orders o \
+             ON u.id = o.user_id AND o.status = 'paid' WHERE u.age > 30 OR u.vip \
+             ORDER BY o.total",
+        );
+        let pushdowns = pushdown(&stmt, &tables());
+        assert_eq!(
+            columns(&pushdowns[0]),
+            Some(vec![
+                "name".to_owned(),
+                "id".to_owned(),
+                "age".to_owned(),
+                "vip".to_owned()
+            ])
+        );
+        assert_eq!(
+            columns(&pushdowns[1]),
+            Some(vec![
+                "total".to_owned(),
+                "user_id".to_owned(),
+                "status".to_owned()
+            ])
+        );
+        assert_eq!(predicates(&pushdowns[0]), vec!["age > 30 OR vip"]);
+        assert_eq!(predicates(&pushdowns[1]), vec!["status = 'paid'"]);
+        assert_eq!(
+            pushdowns[0].join_keys,
+            vec![JoinKey {
+                column: Ident::new("id"),
+                other: 1,
+                other_column: Ident::new("user_id"),
+            }]
+        );
+        assert_eq!(pushdowns[1].join_keys[0].other, 0);
+    }
+
+    #[test]
+    fn test_pushdown_qualified_by_table_name() {
+        let stmt = parse(
+            "SELECT users.name, orders.total FROM pg1.public.users, pg2.public.orders \
+             WHERE users.id = orders.user_id AND orders.total BETWEEN 10 AND 20",
+        );
+        let pushdowns = pushdown(&stmt, &tables());
+        assert_eq!(
+            columns(&pushdowns[0]),
+            Some(vec!["name".to_owned(), "id".to_owned()])
+        );
+        assert_eq!(predicates(&pushdowns[1]), vec!["total BETWEEN 10 AND 20"]);
+    }
+
+    #[test]
+    fn test_pushdown_unattributed_columns() {
+        let stmt = parse(
+            "SELECT name, o.total FROM pg1.public.users u JOIN pg2.public.orders o \
+             ON u.id = o.user_id WHERE o.total > 10",
+        );
+        let pushdowns = pushdown(&stmt, &tables());
+        assert_eq!(columns(&pushdowns[0]), None);
+        assert_eq!(columns(&pushdowns[1]), None);
+        // the conditions are still pushed down, they are qualified.
+        assert_eq!(predicates(&pushdowns[1]), vec!["total > 10"]);
+
+        let stmt = parse(
+            "SELECT u.*, o.total FROM pg1.public.users u JOIN pg2.public.orders o \
+             ON u.id = o.user_id",
+        );
+        let pushdowns = pushdown(&stmt, &tables());
+        assert_eq!(columns(&pushdowns[0]), None);
+        assert_eq!(
+            columns(&pushdowns[1]),
+            Some(vec!["total".to_owned(), "user_id".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_pushdown_order_by_output() {
+        let stmt = parse(
+            "SELECT u.name AS customer, o.total FROM pg1.public.users u \
+             JOIN pg2.public.orders o ON u.id = o.user_id ORDER BY customer",
+        );
+        let pushdowns = pushdown(&stmt, &tables());
+        assert_eq!(
+            columns(&pushdowns[0]),
+            Some(vec!["name".to_owned(), "id".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_no_predicates_pushed_past_outer_joins() {
+        let stmt = parse(
+            "SELECT u.name, o.total FROM pg1.public.users u LEFT JOIN pg2.public.orders o \
+             ON u.id = o.user_id WHERE o.total > 10",
+        );
+        let pushdowns = pushdown(&stmt, &tables());
+        assert!(pushdowns[1].predicates.is_empty());
+        assert!(pushdowns[0].join_keys.is_empty());
+        assert_eq!(
+            columns(&pushdowns[1]),
+            Some(vec!["total".to_owned(), "user_id".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_no_pushdown() {
+        for sql in [
+            "SELECT u.name FROM pg1.public.users u \
+             JOIN (SELECT user_id FROM pg2.public.orders) o ON u.id = o.user_id",
+            "SELECT u.name FROM pg1.public.users u JOIN pg2.public.orders o USING (id)",
+            "SELECT u.name FROM pg1.public.users u JOIN pg2.public.orders o ON u.id = o.user_id \
+             WHERE o.total > (SELECT avg(total) FROM pg2.public.orders)",
+        ] {
+            let pushdowns = pushdown(&parse(sql), &tables());
+            assert_eq!(pushdowns, vec![Pushdown::default(); 2], "{}", sql);
+        }
+    }
+
+    #[test]
+    fn test_no_predicates_for_functions_or_self_joins() {
+        let stmt = parse(
+            "SELECT u.name, o.total FROM pg1.public.users u JOIN pg2.public.orders o \
+             ON u.id = o.user_id WHERE lower(u.name) = 'bob' AND o.total > $1",
+        );
+        let pushdowns = pushdown(&stmt, &tables());
+        assert!(pushdowns[0].predicates.is_empty());
+        assert!(pushdowns[1].predicates.is_empty());
+
+        let stmt = parse(
+            "SELECT a.name, o.total FROM pg1.public.users a JOIN pg1.public.users b \
+             ON a.id = b.referrer JOIN pg2.public.orders o ON b.id = o.user_id \
+             WHERE a.age > 30",
+        );
+        let pushdowns = pushdown(&stmt, &tables());
+        assert!(pushdowns[0].predicates.is_empty());
+        assert!(pushdowns[0].join_keys.is_empty());
+        assert_eq!(
+            columns(&pushdowns[0]),
+            Some(vec![
+                "name".to_owned(),
+                "id".to_owned(),
+                "referrer".to_owned(),
+                "age".to_owned()
+            ])
+        );
+    }
+}
@@ -7,18 +7,42 @@ use std::{
 };
 
 use anyhow::Context;
+use catalog::{Privilege, ResourceGroup};
+use options::{peer_options_schema, validate_options, CDC_MIRROR_OPTIONS};
+use peerdb_sql::CreateMirror::{Select, CDC};
 use pt::{
-    flow_model::{FlowJob, FlowJobTableMapping, FlowSyncMode, QRepFlowJob},
+    flow_model::{
+        FlowApplyWindow, FlowJob, FlowJobSchemaMapping, FlowJobTableMapping, FlowOrderingKey,
+        FlowSyncMode, QRepFlowJob,
+    },
+    peerdb_flow::TablePausePolicy,
     peerdb_peers::{
-        peer::Config, BigqueryConfig, DbType, EventHubConfig, MongoConfig, Peer, PostgresConfig,
-        S3Config, SnowflakeConfig, SqlServerConfig,
+        peer::Config, BigqueryConfig, DbType, EventHubConfig, FileConfig, FileFormat, MoneyMapping,
+        MongoConfig, Peer, PineconeConfig, PostgresConfig, S3Config, SnowflakeConfig,
+        SqlServerConfig, WeaviateConfig, WebhookConfig,
     },
 };
-use qrep::process_options;
-use sqlparser::ast::CreateMirror::{Select, CDC};
-use sqlparser::ast::{visit_relations, visit_statements, FetchDirection, SqlOption, Statement};
+use qrep::{check_query, process_options};
+use sqlparser::ast::{
+    visit_relations, visit_statements, Expr, FetchDirection, Ident, ObjectName,
+    ShowStatementFilter, SqlOption, Statement,
+};
+use sqlparser::dialect::PostgreSqlDialect;
+use variables::expand_create_mirror;
 
+mod federated;
+mod options;
+mod peerdb_sql;
 mod qrep;
+mod variables;
+
+pub use federated::{JoinKey, Pushdown};
+pub use peerdb_sql::{
+    parse_peerdb_statement, AlterMirrorOperation, AlterResourceGroupOperation, CreateMirror,
+    CreateMirrorForCDC, CreateMirrorForSelect, MappingOptions, NotificationStatement,
+    PeerDBPrivilege, PeerDBStatement, PEERDB_STATEMENTS,
+};
+pub use variables::SessionVariables;
 
 pub trait StatementAnalyzer {
     type Output;
@@ -53,15 +77,24 @@ impl<'a> StatementAnalyzer for PeerExistanceAnalyzer<'a> {
 
         // This is necessary as visit relations was not visiting drop table's object names,
         // causing DROP commands for Postgres peer being interpreted as
-        // catalog queries.
+        // catalog queries. The same holds for the target table of a COPY.
         visit_statements(statement, |stmt| {
-            if let &Statement::Drop { names, .. } = &stmt {
-                for name in names {
-                    let peer_name = &name.0[0].value.to_lowercase();
+            match stmt {
+                Statement::Drop { names, .. } => {
+                    for name in names {
+                        let peer_name = &name.0[0].value.to_lowercase();
+                        if self.peers.contains_key(peer_name) {
+                            peers_touched.insert(peer_name.into());
+                        }
+                    }
+                }
+                Statement::Copy { table_name, .. } => {
+                    let peer_name = &table_name.0[0].value.to_lowercase();
                     if self.peers.contains_key(peer_name) {
                         peers_touched.insert(peer_name.into());
                     }
                 }
+                _ => {}
             }
             ControlFlow::<()>::Continue(())
         });
@@ -85,54 +118,132 @@ impl<'a> StatementAnalyzer for PeerExistanceAnalyzer<'a> {
     }
 }
 
-/// PeerDDLAnalyzer is a statement analyzer that checks if the given
-/// statement is a PeerDB DDL statement. If it is, it returns the type of
-/// DDL statement.
-pub struct PeerDDLAnalyzer<'a> {
+/// FederatedQueryAnalyzer is a statement analyzer that checks if the given
+/// statement is a query over the tables of more than one peer, which nexus
+/// runs itself rather than routing it to a peer. It returns the peer tables
+/// the query reads.
+pub struct FederatedQueryAnalyzer<'a> {
     peers: &'a HashMap<String, Peer>,
 }
 
-impl<'a> PeerDDLAnalyzer<'a> {
+impl<'a> FederatedQueryAnalyzer<'a> {
     pub fn new(peers: &'a HashMap<String, Peer>) -> Self {
         Self { peers }
     }
 }
 
+/// A table of a peer read by a federated query.
 #[derive(Debug, Clone)]
-pub enum PeerDDL {
-    CreatePeer {
-        peer: Box<pt::peerdb_peers::Peer>,
-        if_not_exists: bool,
-    },
-    CreateMirrorForCDC {
-        if_not_exists: bool,
-        flow_job: FlowJob,
-    },
-    CreateMirrorForSelect {
-        if_not_exists: bool,
-        qrep_flow_job: QRepFlowJob,
-    },
-    ExecuteMirrorForSelect {
-        flow_job_name: String,
-    },
-    DropMirror {
-        if_exists: bool,
-        flow_job_name: String,
-    },
+pub struct FederatedTable {
+    pub name: ObjectName,
+    pub peer: Box<Peer>,
+    /// The columns and conditions of the query pushed down to the peer.
+    pub pushdown: Pushdown,
 }
 
-impl<'a> StatementAnalyzer for PeerDDLAnalyzer<'a> {
-    type Output = Option<PeerDDL>;
+impl FederatedTable {
+    /// The query the table is staged with: the columns the federated query
+    /// reads of it, filtered by its conditions over the table and the given
+    /// ones.
+    pub fn staging_query(&self, predicates: &[Expr]) -> String {
+        let columns = match &self.pushdown.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|column| column.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => "*".to_owned(),
+        };
+        let predicates = self
+            .pushdown
+            .predicates
+            .iter()
+            .chain(predicates)
+            .map(|predicate| format!("({})", predicate))
+            .collect::<Vec<_>>();
+        if predicates.is_empty() {
+            format!("SELECT {} FROM {}", columns, self.name)
+        } else {
+            format!(
+                "SELECT {} FROM {} WHERE {}",
+                columns,
+                self.name,
+                predicates.join(" AND ")
+            )
+        }
+    }
+}
+
+impl<'a> StatementAnalyzer for FederatedQueryAnalyzer<'a> {
+    type Output = Option<Vec<FederatedTable>>;
 
     fn analyze(&self, statement: &Statement) -> anyhow::Result<Self::Output> {
+        if !matches!(statement, Statement::Query(_)) {
+            return Ok(None);
+        }
+
+        let mut tables: Vec<FederatedTable> = vec![];
+        visit_relations(statement, |relation| {
+            let peer = match relation.0.as_slice() {
+                [peer_name, _, ..] => self.peers.get(&peer_name.value.to_lowercase()),
+                _ => None,
+            };
+            if let Some(peer) = peer {
+                if !tables.iter().any(|table| &table.name == relation) {
+                    tables.push(FederatedTable {
+                        name: relation.clone(),
+                        peer: Box::new(peer.clone()),
+                        pushdown: Pushdown::default(),
+                    });
+                }
+            }
+            ControlFlow::<()>::Continue(())
+        });
+
+        let peers_touched: HashSet<&str> = tables
+            .iter()
+            .map(|table| table.peer.name.as_str())
+            .collect();
+        if peers_touched.len() < 2 {
+            return Ok(None);
+        }
+
+        let names: Vec<ObjectName> = tables.iter().map(|table| table.name.clone()).collect();
+        for (table, pushdown) in tables
+            .iter_mut()
+            .zip(federated::pushdown(statement, &names))
+        {
+            table.pushdown = pushdown;
+        }
+        Ok(Some(tables))
+    }
+}
+
+/// PeerDDLAnalyzer is a statement analyzer that checks if the given
+/// statement is a PeerDB DDL statement. If it is, it returns the type of
+/// DDL statement. The session variables referenced by a CREATE MIRROR are
+/// expanded before it is analyzed.
+pub struct PeerDDLAnalyzer<'a> {
+    peers: &'a HashMap<String, Peer>,
+    variables: &'a SessionVariables,
+}
+
+impl<'a> PeerDDLAnalyzer<'a> {
+    pub fn new(peers: &'a HashMap<String, Peer>, variables: &'a SessionVariables) -> Self {
+        Self { peers, variables }
+    }
+
+    /// Analyzes the statements nexus parses itself, see `PEERDB_STATEMENTS`.
+    pub fn analyze_peerdb(&self, statement: &PeerDBStatement) -> anyhow::Result<PeerDDL> {
         match statement {
-            Statement::CreatePeer {
+            PeerDBStatement::CreatePeer {
                 if_not_exists,
                 peer_name,
                 peer_type,
                 with_options,
+                validate_only,
             } => {
-                let db_type = DbType::from(peer_type.clone());
+                let db_type = *peer_type;
                 let config = parse_db_options(self.peers, db_type, with_options.clone())?;
                 let peer = Peer {
                     name: peer_name.to_string().to_lowercase(),
@@ -140,38 +251,60 @@ impl<'a> StatementAnalyzer for PeerDDLAnalyzer<'a> {
                     config,
                 };
 
-                Ok(Some(PeerDDL::CreatePeer {
+                if *validate_only {
+                    return Ok(PeerDDL::ValidatePeer {
+                        peer: Box::new(peer),
+                    });
+                }
+                Ok(PeerDDL::CreatePeer {
                     peer: Box::new(peer),
                     if_not_exists: *if_not_exists,
-                }))
+                })
             }
-            Statement::CreateMirror {
+            PeerDBStatement::CreateMirror {
                 if_not_exists,
                 create_mirror,
             } => {
-                match create_mirror {
+                let create_mirror = expand_create_mirror(create_mirror, self.variables)?;
+                match &create_mirror {
                     CDC(cdc) => {
+                        let target_peer =
+                            self.peers.get(&cdc.target_peer.to_string().to_lowercase());
                         let mut flow_job_table_mappings = vec![];
+                        let mut flow_job_schema_mappings = vec![];
                         for table_mapping in &cdc.mapping_options {
+                            if is_wildcard(&table_mapping.source) {
+                                flow_job_schema_mappings.push(schema_mapping(
+                                    target_peer,
+                                    &table_mapping.source,
+                                    &table_mapping.destination,
+                                    table_mapping.partition_key.is_some(),
+                                    &table_mapping.exclude,
+                                )?);
+                                continue;
+                            }
                             flow_job_table_mappings.push(FlowJobTableMapping {
-                                source_table_identifier: table_mapping
-                                    .source
-                                    .to_string()
-                                    .to_lowercase(),
-                                destination_table_identifier: table_mapping
-                                    .destination
-                                    .to_string()
-                                    .to_lowercase(),
+                                source_table_identifier: table_identifier(&table_mapping.source),
+                                destination_table_identifier: destination_table_identifier(
+                                    target_peer,
+                                    &table_mapping.destination,
+                                )?,
                                 partition_key: table_mapping
                                     .partition_key
                                     .clone()
                                     .map(|s| s.to_string()),
+                                exclude: table_mapping.exclude.iter().map(column_name).collect(),
                             });
                         }
 
                         // get do_initial_copy from with_options
+                        let with_options = validate_options(
+                            "CREATE MIRROR",
+                            CDC_MIRROR_OPTIONS,
+                            &cdc.with_options,
+                        )?;
                         let mut raw_options = HashMap::new();
-                        for option in &cdc.with_options {
+                        for option in &with_options {
                             raw_options.insert(&option.name.value as &str, &option.value);
                         }
                         let do_initial_copy = match raw_options.remove("do_initial_copy") {
@@ -253,11 +386,57 @@ impl<'a> StatementAnalyzer for PeerDDLAnalyzer<'a> {
                             _ => None,
                         };
 
+                        let staging_integration = match raw_options.remove("staging_integration") {
+                            Some(sqlparser::ast::Value::SingleQuotedString(s)) => Some(s.clone()),
+                            _ => None,
+                        };
+
+                        let soft_delete_column = match raw_options.remove("soft_delete_column") {
+                            Some(sqlparser::ast::Value::SingleQuotedString(s)) => {
+                                Some(metadata_column_name("soft_delete_column", s)?)
+                            }
+                            _ => None,
+                        };
+
+                        let synced_at_column = match raw_options.remove("synced_at_column") {
+                            Some(sqlparser::ast::Value::SingleQuotedString(s)) => {
+                                Some(metadata_column_name("synced_at_column", s)?)
+                            }
+                            _ => None,
+                        };
+
+                        // naming the column deleted rows are marked in implies soft deletes.
                         let soft_delete = match raw_options.remove("soft_delete") {
+                            Some(sqlparser::ast::Value::Boolean(false))
+                                if soft_delete_column.is_some() =>
+                            {
+                                return Err(anyhow::anyhow!(
+                                    "soft_delete_column can't be set when soft_delete is false."
+                                ));
+                            }
+                            Some(sqlparser::ast::Value::Boolean(b)) => *b,
+                            _ => soft_delete_column.is_some(),
+                        };
+
+                        let initial_copy_only = match raw_options.remove("initial_copy_only") {
                             Some(sqlparser::ast::Value::Boolean(b)) => *b,
                             _ => false,
                         };
 
+                        let track_latency = match raw_options.remove("track_latency") {
+                            Some(sqlparser::ast::Value::Boolean(b)) => *b,
+                            _ => false,
+                        };
+
+                        let ordering_key: Option<FlowOrderingKey> = match raw_options
+                            .remove("ordering_key")
+                        {
+                            Some(sqlparser::ast::Value::SingleQuotedString(s)) => Some(
+                                FlowOrderingKey::parse_string(s).map_err(|e| anyhow::anyhow!(e))?,
+                            ),
+                            _ => None,
+                        };
+
                         let push_parallelism: Option<i64> = match raw_options
                             .remove("push_parallelism")
                         {
@@ -278,11 +457,36 @@ impl<'a> StatementAnalyzer for PeerDDLAnalyzer<'a> {
                             _ => None,
                         };
 
+                        let refresh_statistics_threshold: Option<u32> = match raw_options
+                            .remove("refresh_statistics_threshold")
+                        {
+                            Some(sqlparser::ast::Value::Number(n, _)) => Some(n.parse::<u32>()?),
+                            _ => None,
+                        };
+
+                        let apply_window: Option<FlowApplyWindow> = match raw_options
+                            .remove("apply_window")
+                        {
+                            Some(sqlparser::ast::Value::SingleQuotedString(s)) => Some(
+                                FlowApplyWindow::parse_string(s).map_err(|e| anyhow::anyhow!(e))?,
+                            ),
+                            _ => None,
+                        };
+
+                        let schema_refresh_interval: Option<u32> = match raw_options
+                            .remove("schema_refresh_interval")
+                        {
+                            Some(sqlparser::ast::Value::Number(n, _)) => Some(n.parse::<u32>()?),
+                            _ => None,
+                        };
+
                         let flow_job = FlowJob {
                             name: cdc.mirror_name.to_string().to_lowercase(),
                             source_peer: cdc.source_peer.to_string().to_lowercase(),
                             target_peer: cdc.target_peer.to_string().to_lowercase(),
                             table_mappings: flow_job_table_mappings,
+                            schema_mappings: flow_job_schema_mappings,
+                            schema_refresh_interval,
                             description: "".to_string(), // TODO: add description
                             do_initial_copy,
                             publication_name,
@@ -294,10 +498,18 @@ impl<'a> StatementAnalyzer for PeerDDLAnalyzer<'a> {
                             cdc_sync_mode,
                             cdc_staging_path,
                             soft_delete,
+                            soft_delete_column,
+                            synced_at_column,
                             replication_slot_name,
                             push_batch_size,
                             push_parallelism,
                             max_batch_size,
+                            initial_copy_only,
+                            ordering_key,
+                            staging_integration,
+                            refresh_statistics_threshold,
+                            apply_window,
+                            track_latency,
                         };
 
                         // Error reporting
@@ -309,10 +521,118 @@ impl<'a> StatementAnalyzer for PeerDDLAnalyzer<'a> {
                             ));
                         }
 
-                        Ok(Some(PeerDDL::CreateMirrorForCDC {
+                        if let Some(ordering_key) = &flow_job.ordering_key {
+                            let is_queue_destination = self
+                                .peers
+                                .get(&flow_job.target_peer)
+                                .map(|peer| {
+                                    peer.r#type == DbType::Eventhub as i32
+                                        || peer.r#type == DbType::EventhubGroup as i32
+                                })
+                                .unwrap_or(false);
+                            if !is_queue_destination {
+                                return Err(anyhow::anyhow!(
+                                    "ordering_key is only supported for queue destinations."
+                                ));
+                            }
+                            if *ordering_key == FlowOrderingKey::PartitionKey
+                                && !flow_job.schema_mappings.is_empty()
+                            {
+                                return Err(anyhow::anyhow!(
+                                    "ordering_key partition_key can't be used with schema.* table mappings."
+                                ));
+                            }
+                            if *ordering_key == FlowOrderingKey::PartitionKey {
+                                if let Some(mapping) = flow_job
+                                    .table_mappings
+                                    .iter()
+                                    .find(|mapping| mapping.partition_key.is_none())
+                                {
+                                    return Err(anyhow::anyhow!(
+                                        "ordering_key partition_key requires a key for table {}.",
+                                        mapping.source_table_identifier
+                                    ));
+                                }
+                            }
+                        }
+
+                        // tables are partitioned by their partition_key during the initial copy.
+                        if let Some(mapping) = flow_job.table_mappings.iter().find(|mapping| {
+                            mapping
+                                .partition_key
+                                .as_ref()
+                                .map_or(false, |key| mapping.exclude.contains(key))
+                        }) {
+                            return Err(anyhow::anyhow!(
+                                "the partition_key of table {} can't be excluded.",
+                                mapping.source_table_identifier
+                            ));
+                        }
+
+                        // only postgres sources report when the changes were committed.
+                        if flow_job.track_latency
+                            && self
+                                .peers
+                                .get(&flow_job.source_peer)
+                                .map(|peer| peer.r#type)
+                                != Some(DbType::Postgres as i32)
+                        {
+                            return Err(anyhow::anyhow!(
+                                "track_latency is only supported for postgres sources."
+                            ));
+                        }
+
+                        // only the snowflake connector keeps deleted rows and stamps synced rows.
+                        if flow_job.soft_delete || flow_job.synced_at_column.is_some() {
+                            let is_snowflake_destination = self
+                                .peers
+                                .get(&flow_job.target_peer)
+                                .map(|peer| peer.r#type)
+                                == Some(DbType::Snowflake as i32);
+                            if !is_snowflake_destination {
+                                return Err(anyhow::anyhow!(
+                                    "soft_delete and synced_at_column are only supported for snowflake destinations."
+                                ));
+                            }
+                        }
+                        if flow_job.soft_delete_column.is_some()
+                            && flow_job.soft_delete_column == flow_job.synced_at_column
+                        {
+                            return Err(anyhow::anyhow!(
+                                "soft_delete_column and synced_at_column must be different columns."
+                            ));
+                        }
+
+                        // schemas are expanded into their tables from the catalog of the source.
+                        if !flow_job.schema_mappings.is_empty()
+                            && self
+                                .peers
+                                .get(&flow_job.source_peer)
+                                .map(|peer| peer.r#type)
+                                != Some(DbType::Postgres as i32)
+                        {
+                            return Err(anyhow::anyhow!(
+                                "schema.* table mappings are only supported for postgres sources."
+                            ));
+                        }
+                        if flow_job.schema_refresh_interval.is_some()
+                            && flow_job.schema_mappings.is_empty()
+                        {
+                            return Err(anyhow::anyhow!(
+                                "schema_refresh_interval requires a schema.* table mapping."
+                            ));
+                        }
+
+                        if flow_job.initial_copy_only && !flow_job.do_initial_copy {
+                            return Err(anyhow::anyhow!(
+                                "initial_copy_only requires do_initial_copy to be set."
+                            ));
+                        }
+
+                        Ok(PeerDDL::CreateMirrorForCDC {
                             if_not_exists: *if_not_exists,
                             flow_job,
-                        }))
+                        })
                     }
                     Select(select) => {
                         let mut raw_options = HashMap::new();
@@ -322,47 +642,605 @@ impl<'a> StatementAnalyzer for PeerDDLAnalyzer<'a> {
 
                         // we treat disabled as a special option, and do not pass it to the
                         // flow server, this is primarily used for external orchestration.
-                        let mut disabled = false;
-                        if let Some(sqlparser::ast::Value::Boolean(b)) =
-                            raw_options.remove("disabled")
-                        {
-                            disabled = *b;
+                        let disabled = match raw_options.remove("disabled") {
+                            Some(value) => bool_option("disabled", value)?,
+                            None => false,
+                        };
+
+                        let mut processed_options = process_options(raw_options)?;
+                        let query_string = select.query_string.to_string();
+                        check_query(&query_string, &mut processed_options)?;
+
+                        let qrep_flow_job = QRepFlowJob {
+                            name: select.mirror_name.to_string().to_lowercase(),
+                            source_peer: select.source_peer.to_string().to_lowercase(),
+                            target_peer: select.target_peer.to_string().to_lowercase(),
+                            query_string,
+                            flow_options: processed_options,
+                            description: "".to_string(), // TODO: add description
+                            disabled,
+                        };
+
+                        Ok(PeerDDL::CreateMirrorForSelect {
+                            if_not_exists: *if_not_exists,
+                            qrep_flow_job,
+                        })
+                    }
+                }
+            }
+            PeerDBStatement::ValidatePeer { peer_name } => {
+                let peer_name = peer_name.to_string().to_lowercase();
+                let peer = self
+                    .peers
+                    .get(&peer_name)
+                    .cloned()
+                    .with_context(|| format!("no such peer: {}", peer_name))?;
+                Ok(PeerDDL::ValidatePeer {
+                    peer: Box::new(peer),
+                })
+            }
+            PeerDBStatement::ExecuteOnPeer {
+                query_string,
+                peer_name,
+            } => {
+                let peer_name = peer_name.to_string().to_lowercase();
+                let peer = self
+                    .peers
+                    .get(&peer_name)
+                    .cloned()
+                    .with_context(|| format!("no such peer: {}", peer_name))?;
+                Ok(PeerDDL::ExecuteOnPeer {
+                    peer: Box::new(peer),
+                    query: query_string.clone(),
+                })
+            }
+            PeerDBStatement::DropMirror {
+                if_exists,
+                mirror_name,
+                with_options,
+            } => {
+                let mut drop_destination_tables = false;
+                for option in with_options {
+                    match option.name.value.as_str() {
+                        "drop_destination_tables" => {
+                            drop_destination_tables = match &option.value {
+                                sqlparser::ast::Value::Boolean(b) => *b,
+                                // also support "true" and "false" as strings
+                                sqlparser::ast::Value::SingleQuotedString(s) => match s.as_ref() {
+                                    "true" => true,
+                                    "false" => false,
+                                    _ => anyhow::bail!("drop_destination_tables must be a boolean"),
+                                },
+                                _ => anyhow::bail!("drop_destination_tables must be a boolean"),
+                            }
                         }
+                        name => anyhow::bail!("unknown option for DROP MIRROR: {}", name),
+                    }
+                }
+
+                Ok(PeerDDL::DropMirror {
+                    if_exists: *if_exists,
+                    flow_job_name: mirror_name.to_string().to_lowercase(),
+                    drop_destination_tables,
+                })
+            }
+            PeerDBStatement::AlterPeer {
+                peer_name,
+                with_options,
+            } => {
+                let peer_name = peer_name.to_string().to_lowercase();
+                let mut peer = self
+                    .peers
+                    .get(&peer_name)
+                    .cloned()
+                    .with_context(|| format!("no such peer: {}", peer_name))?;
+                let config = peer
+                    .config
+                    .as_mut()
+                    .with_context(|| format!("peer {} has no config", peer_name))?;
+                alter_db_options(config, with_options.clone())?;
 
-                        let processed_options = process_options(raw_options)?;
+                Ok(PeerDDL::AlterPeer {
+                    peer: Box::new(peer),
+                })
+            }
+            PeerDBStatement::ShowPeerTables { peer_name, schema } => {
+                let peer_name = peer_name.to_string().to_lowercase();
+                let peer = self
+                    .peers
+                    .get(&peer_name)
+                    .cloned()
+                    .with_context(|| format!("no such peer: {}", peer_name))?;
+                Ok(PeerDDL::ShowPeerTables {
+                    peer: Box::new(peer),
+                    schema: schema.as_ref().map(|schema| schema.value.clone()),
+                })
+            }
+            PeerDBStatement::DescribeMirror { mirror_name } => Ok(PeerDDL::DescribeMirror {
+                flow_job_name: mirror_name.to_string().to_lowercase(),
+            }),
+            PeerDBStatement::PauseMirror { mirror_name } => Ok(PeerDDL::PauseMirror {
+                flow_job_name: mirror_name.to_string().to_lowercase(),
+            }),
+            PeerDBStatement::ResumeMirror { mirror_name } => Ok(PeerDDL::ResumeMirror {
+                flow_job_name: mirror_name.to_string().to_lowercase(),
+            }),
+            PeerDBStatement::ResyncMirror {
+                mirror_name,
+                table,
+                with_options,
+            } => {
+                let mut truncate_destination_table = false;
+                for option in with_options {
+                    match option.name.value.as_str() {
+                        "truncate_destination_table" => {
+                            truncate_destination_table =
+                                bool_option("truncate_destination_table", &option.value)?
+                        }
+                        name => anyhow::bail!("unknown option for RESYNC MIRROR: {}", name),
+                    }
+                }
+                Ok(PeerDDL::ResyncMirror {
+                    flow_job_name: mirror_name.to_string().to_lowercase(),
+                    table_identifier: table.as_ref().map(table_identifier),
+                    truncate_destination_table,
+                })
+            }
+            PeerDBStatement::AlterMirror {
+                mirror_name,
+                operation,
+            } => match operation {
+                AlterMirrorOperation::AddTable {
+                    source_table,
+                    destination_table,
+                } => {
+                    // the table keeps its name on the destination unless one is given.
+                    let source_table_identifier = table_identifier(source_table);
+                    let destination_table_identifier = destination_table
+                        .as_ref()
+                        .map(table_identifier)
+                        .unwrap_or_else(|| source_table_identifier.clone());
+                    Ok(PeerDDL::AlterMirrorAddTable {
+                        flow_job_name: mirror_name.to_string().to_lowercase(),
+                        table_mapping: FlowJobTableMapping {
+                            source_table_identifier,
+                            destination_table_identifier,
+                            partition_key: None,
+                            exclude: vec![],
+                        },
+                    })
+                }
+                AlterMirrorOperation::PauseTable {
+                    table,
+                    with_options,
+                } => {
+                    // changes to a paused table are buffered unless told otherwise.
+                    let mut policy = TablePausePolicy::Buffer;
+                    for option in with_options {
+                        match option.name.value.as_str() {
+                            "policy" => {
+                                policy = match &option.value {
+                                    sqlparser::ast::Value::SingleQuotedString(s) => {
+                                        match s.to_lowercase().as_str() {
+                                            "buffer" => TablePausePolicy::Buffer,
+                                            "skip" => TablePausePolicy::Skip,
+                                            _ => anyhow::bail!("policy must be 'buffer' or 'skip'"),
+                                        }
+                                    }
+                                    _ => anyhow::bail!("policy must be 'buffer' or 'skip'"),
+                                }
+                            }
+                            name => anyhow::bail!("unknown option for PAUSE TABLE: {}", name),
+                        }
+                    }
+                    Ok(PeerDDL::AlterMirrorPauseTable {
+                        flow_job_name: mirror_name.to_string().to_lowercase(),
+                        table_identifier: table_identifier(table),
+                        policy,
+                    })
+                }
+                AlterMirrorOperation::ResumeTable { table } => {
+                    Ok(PeerDDL::AlterMirrorResumeTable {
+                        flow_job_name: mirror_name.to_string().to_lowercase(),
+                        table_identifier: table_identifier(table),
+                    })
+                }
+                AlterMirrorOperation::DropTable {
+                    table,
+                    with_options,
+                } => {
+                    // the destination table is left as it is unless told otherwise.
+                    let mut truncate_destination_table = false;
+                    for option in with_options {
+                        match option.name.value.as_str() {
+                            "truncate_destination_table" => {
+                                truncate_destination_table =
+                                    bool_option("truncate_destination_table", &option.value)?
+                            }
+                            name => anyhow::bail!("unknown option for DROP TABLE: {}", name),
+                        }
+                    }
+                    Ok(PeerDDL::AlterMirrorDropTable {
+                        flow_job_name: mirror_name.to_string().to_lowercase(),
+                        table_identifier: table_identifier(table),
+                        truncate_destination_table,
+                    })
+                }
+            },
+            PeerDBStatement::DropPeer {
+                if_exists,
+                peer_name,
+                cascade,
+            } => Ok(PeerDDL::DropPeer {
+                if_exists: *if_exists,
+                peer_name: peer_name.to_string().to_lowercase(),
+                cascade: *cascade,
+            }),
+            PeerDBStatement::ReplacePeer {
+                old_peer_name,
+                new_peer_name,
+            } => {
+                let get_peer = |peer_name: &ObjectName| {
+                    let peer_name = peer_name.to_string().to_lowercase();
+                    self.peers
+                        .get(&peer_name)
+                        .cloned()
+                        .with_context(|| format!("no such peer: {}", peer_name))
+                };
+                let old_peer = get_peer(old_peer_name)?;
+                let new_peer = get_peer(new_peer_name)?;
+                if old_peer.name == new_peer.name {
+                    anyhow::bail!("peer {} cannot replace itself", old_peer.name);
+                }
+                if old_peer.r#type != new_peer.r#type {
+                    anyhow::bail!(
+                        "peer {} cannot replace {}, they are of different types",
+                        new_peer.name,
+                        old_peer.name
+                    );
+                }
+
+                Ok(PeerDDL::ReplacePeer {
+                    old_peer: Box::new(old_peer),
+                    new_peer: Box::new(new_peer),
+                })
+            }
+            PeerDBStatement::CreateUser {
+                if_not_exists,
+                name,
+                password,
+            } => Ok(PeerDDL::CreateUser {
+                name: user_name(name),
+                password: password.clone(),
+                if_not_exists: *if_not_exists,
+            }),
+            PeerDBStatement::AlterUser { name, password } => Ok(PeerDDL::AlterUser {
+                name: user_name(name),
+                password: password.clone(),
+            }),
+            PeerDBStatement::DropUser { if_exists, name } => Ok(PeerDDL::DropUser {
+                name: user_name(name),
+                if_exists: *if_exists,
+            }),
+            PeerDBStatement::Grant {
+                privilege,
+                grantees,
+            } => Ok(PeerDDL::Grant {
+                privilege: self.privilege(privilege)?,
+                grantees: grantees.iter().map(user_name).collect(),
+            }),
+            PeerDBStatement::Revoke {
+                privilege,
+                grantees,
+            } => Ok(PeerDDL::Revoke {
+                privilege: self.privilege(privilege)?,
+                grantees: grantees.iter().map(user_name).collect(),
+            }),
+            PeerDBStatement::Explain { statement } => Ok(PeerDDL::ExplainMirror {
+                ddl: Box::new(self.analyze_peerdb(statement)?),
+            }),
+            PeerDBStatement::CommentOnPeer { peer_name, comment } => Ok(PeerDDL::CommentOnPeer {
+                peer_name: peer_name.to_string().to_lowercase(),
+                comment: comment.clone(),
+            }),
+            PeerDBStatement::CommentOnMirror {
+                mirror_name,
+                comment,
+            } => Ok(PeerDDL::CommentOnMirror {
+                flow_job_name: mirror_name.to_string().to_lowercase(),
+                comment: comment.clone(),
+            }),
+            PeerDBStatement::CreateResourceGroup {
+                if_not_exists,
+                name,
+                with_options,
+            } => {
+                let (weight, max_concurrency) = resource_group_options(with_options)?;
+                Ok(PeerDDL::CreateResourceGroup {
+                    group: ResourceGroup {
+                        name: user_name(name),
+                        weight: weight.unwrap_or(1),
+                        max_concurrency: max_concurrency.flatten(),
+                    },
+                    if_not_exists: *if_not_exists,
+                })
+            }
+            PeerDBStatement::AlterResourceGroup { name, operation } => match operation {
+                AlterResourceGroupOperation::SetOptions(with_options) => {
+                    let (weight, max_concurrency) = resource_group_options(with_options)?;
+                    Ok(PeerDDL::AlterResourceGroup {
+                        name: user_name(name),
+                        weight,
+                        max_concurrency,
+                    })
+                }
+                AlterResourceGroupOperation::AddUsers(users) => {
+                    Ok(PeerDDL::AddResourceGroupMembers {
+                        name: user_name(name),
+                        user_names: users.iter().map(user_name).collect(),
+                    })
+                }
+                AlterResourceGroupOperation::DropUsers(users) => {
+                    Ok(PeerDDL::RemoveResourceGroupMembers {
+                        name: user_name(name),
+                        user_names: users.iter().map(user_name).collect(),
+                    })
+                }
+            },
+            PeerDBStatement::DropResourceGroup { if_exists, name } => {
+                Ok(PeerDDL::DropResourceGroup {
+                    name: user_name(name),
+                    if_exists: *if_exists,
+                })
+            }
+            // these run on the peers, see `PeerShowAnalyzer::analyze_peerdb`
+            // and `NexusStatement::Notification`.
+            PeerDBStatement::DescribePeer { .. } | PeerDBStatement::Notification(_) => {
+                anyhow::bail!("not a statement on peers and mirrors: {:?}", statement)
+            }
+        }
+    }
+
+    fn privilege(&self, privilege: &PeerDBPrivilege) -> anyhow::Result<Privilege> {
+        match privilege {
+            PeerDBPrivilege::CreatePeer => Ok(Privilege::CreatePeer),
+            PeerDBPrivilege::CreateMirror => Ok(Privilege::CreateMirror),
+            PeerDBPrivilege::UsageOnPeer { peer_name } => {
+                let peer_name = peer_name.to_string().to_lowercase();
+                if !self.peers.contains_key(&peer_name) {
+                    anyhow::bail!("no such peer: {}", peer_name);
+                }
+                Ok(Privilege::UsageOnPeer(peer_name))
+            }
+            PeerDBPrivilege::OperateOnMirror { mirror_name } => Ok(Privilege::OperateOnMirror(
+                mirror_name.to_string().to_lowercase(),
+            )),
+            PeerDBPrivilege::ReadOnly => Ok(Privilege::ReadOnly),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum PeerDDL {
+    CreatePeer {
+        peer: Box<pt::peerdb_peers::Peer>,
+        if_not_exists: bool,
+    },
+    CreateMirrorForCDC {
+        if_not_exists: bool,
+        flow_job: FlowJob,
+    },
+    CreateMirrorForSelect {
+        if_not_exists: bool,
+        qrep_flow_job: QRepFlowJob,
+    },
+    ExecuteMirrorForSelect {
+        flow_job_name: String,
+    },
+    /// `EXECUTE '...' ON PEER`, the statement is passed to the peer as it is.
+    ExecuteOnPeer {
+        peer: Box<pt::peerdb_peers::Peer>,
+        query: String,
+    },
+    DropMirror {
+        if_exists: bool,
+        flow_job_name: String,
+        drop_destination_tables: bool,
+    },
+    PauseMirror {
+        flow_job_name: String,
+    },
+    ResumeMirror {
+        flow_job_name: String,
+    },
+    AlterMirrorAddTable {
+        flow_job_name: String,
+        table_mapping: FlowJobTableMapping,
+    },
+    AlterMirrorPauseTable {
+        flow_job_name: String,
+        table_identifier: String,
+        policy: TablePausePolicy,
+    },
+    AlterMirrorResumeTable {
+        flow_job_name: String,
+        table_identifier: String,
+    },
+    AlterMirrorDropTable {
+        flow_job_name: String,
+        table_identifier: String,
+        truncate_destination_table: bool,
+    },
+    ResyncMirror {
+        flow_job_name: String,
+        // every table of the mirror is resynced when none is given.
+        table_identifier: Option<String>,
+        // truncate the destination tables and copy into them in place, instead
+        // of copying into new tables that replace them.
+        truncate_destination_table: bool,
+    },
+    DropPeer {
+        if_exists: bool,
+        peer_name: String,
+        cascade: bool,
+    },
+    AlterPeer {
+        peer: Box<pt::peerdb_peers::Peer>,
+    },
+    ReplacePeer {
+        old_peer: Box<pt::peerdb_peers::Peer>,
+        new_peer: Box<pt::peerdb_peers::Peer>,
+    },
+    ShowPeers,
+    /// `SHOW TABLES IN PEER name [SCHEMA schema]`.
+    ShowPeerTables {
+        peer: Box<pt::peerdb_peers::Peer>,
+        schema: Option<String>,
+    },
+    ShowMirrors,
+    DescribeMirror {
+        flow_job_name: String,
+    },
+    /// `COMMENT ON PEER name IS '...'`, the comment is removed with `IS NULL`.
+    CommentOnPeer {
+        peer_name: String,
+        comment: Option<String>,
+    },
+    /// `COMMENT ON MIRROR name IS '...'`, kept as the description of the
+    /// mirror.
+    CommentOnMirror {
+        flow_job_name: String,
+        comment: Option<String>,
+    },
+    /// `VALIDATE PEER name` or `CREATE PEER ... VALIDATE ONLY`, the peer is
+    /// checked but not created.
+    ValidatePeer {
+        peer: Box<pt::peerdb_peers::Peer>,
+    },
+    /// `EXPLAIN CREATE MIRROR ...`, the mirror is previewed but not created.
+    ExplainMirror {
+        ddl: Box<PeerDDL>,
+    },
+    /// `CREATE USER name WITH PASSWORD '...'`, a user that logs in to nexus
+    /// with its own password and is subject to access control.
+    CreateUser {
+        name: String,
+        password: String,
+        if_not_exists: bool,
+    },
+    /// `ALTER USER name WITH PASSWORD '...'`, the old password no longer
+    /// logs in.
+    AlterUser {
+        name: String,
+        password: String,
+    },
+    DropUser {
+        name: String,
+        if_exists: bool,
+    },
+    /// `GRANT privilege TO grantee, ...`, a grantee is a user or a resource
+    /// group whose members share the privilege.
+    Grant {
+        privilege: Privilege,
+        grantees: Vec<String>,
+    },
+    Revoke {
+        privilege: Privilege,
+        grantees: Vec<String>,
+    },
+    /// `CREATE RESOURCE GROUP name WITH (weight = 2, max_concurrency = 4)`,
+    /// the share of the concurrency of each peer its members get.
+    CreateResourceGroup {
+        group: ResourceGroup,
+        if_not_exists: bool,
+    },
+    /// `ALTER RESOURCE GROUP name WITH (...)`, only the options given change
+    /// and `max_concurrency = NULL` lifts the limit.
+    AlterResourceGroup {
+        name: String,
+        weight: Option<i32>,
+        max_concurrency: Option<Option<i32>>,
+    },
+    /// `ALTER RESOURCE GROUP name ADD USER user, ...`, a user is a member of
+    /// one group at a time and leaves the group it was in.
+    AddResourceGroupMembers {
+        name: String,
+        user_names: Vec<String>,
+    },
+    RemoveResourceGroupMembers {
+        name: String,
+        user_names: Vec<String>,
+    },
+    DropResourceGroup {
+        name: String,
+        if_exists: bool,
+    },
+}
 
-                        let qrep_flow_job = QRepFlowJob {
-                            name: select.mirror_name.to_string().to_lowercase(),
-                            source_peer: select.source_peer.to_string().to_lowercase(),
-                            target_peer: select.target_peer.to_string().to_lowercase(),
-                            query_string: select.query_string.to_string(),
-                            flow_options: processed_options,
-                            description: "".to_string(), // TODO: add description
-                            disabled,
-                        };
+impl<'a> StatementAnalyzer for PeerDDLAnalyzer<'a> {
+    type Output = Option<PeerDDL>;
 
-                        Ok(Some(PeerDDL::CreateMirrorForSelect {
-                            if_not_exists: *if_not_exists,
-                            qrep_flow_job,
-                        }))
-                    }
-                }
-            }
+    fn analyze(&self, statement: &Statement) -> anyhow::Result<Self::Output> {
+        match statement {
             Statement::ExecuteMirror { mirror_name } => Ok(Some(PeerDDL::ExecuteMirrorForSelect {
                 flow_job_name: mirror_name.to_string().to_lowercase(),
             })),
-            Statement::DropMirror {
-                if_exists,
-                mirror_name,
-            } => Ok(Some(PeerDDL::DropMirror {
-                if_exists: *if_exists,
-                flow_job_name: mirror_name.to_string().to_lowercase(),
-            })),
+            Statement::ShowVariable { variable }
+                if variable.len() == 1 && variable[0].value.eq_ignore_ascii_case("peers") =>
+            {
+                Ok(Some(PeerDDL::ShowPeers))
+            }
+            Statement::ShowVariable { variable }
+                if variable.len() == 1 && variable[0].value.eq_ignore_ascii_case("mirrors") =>
+            {
+                Ok(Some(PeerDDL::ShowMirrors))
+            }
             _ => Ok(None),
         }
     }
 }
 
+// the weight and the max_concurrency of a resource group, if given. a
+// max_concurrency of NULL is given as Some(None).
+fn resource_group_options(
+    with_options: &[SqlOption],
+) -> anyhow::Result<(Option<i32>, Option<Option<i32>>)> {
+    let mut weight = None;
+    let mut max_concurrency = None;
+    for option in with_options {
+        match option.name.value.to_lowercase().as_str() {
+            "weight" => weight = Some(positive_option(option)?),
+            "max_concurrency" => {
+                max_concurrency = match option.value {
+                    sqlparser::ast::Value::Null => Some(None),
+                    _ => Some(Some(positive_option(option)?)),
+                }
+            }
+            name => anyhow::bail!("unknown resource group option: {}", name),
+        }
+    }
+    Ok((weight, max_concurrency))
+}
+
+fn positive_option(option: &SqlOption) -> anyhow::Result<i32> {
+    let value = match &option.value {
+        sqlparser::ast::Value::Number(n, _) => n.parse::<i32>().ok(),
+        _ => None,
+    };
+    match value {
+        Some(value) if value > 0 => Ok(value),
+        _ => anyhow::bail!("{} must be a positive integer", option.name.value),
+    }
+}
+
+// users are named like postgres roles, unquoted names are folded to lower case.
+fn user_name(ident: &Ident) -> String {
+    match ident.quote_style {
+        Some(_) => ident.value.clone(),
+        None => ident.value.to_lowercase(),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum CursorEvent {
     Fetch(String, usize),
@@ -417,11 +1295,422 @@ impl StatementAnalyzer for PeerCursorAnalyzer {
     }
 }
 
-fn parse_db_options(
-    peers: &HashMap<String, Peer>,
-    db_type: DbType,
-    with_options: Vec<SqlOption>,
-) -> anyhow::Result<Option<Config>> {
+/// PeerShowAnalyzer is a statement analyzer that rewrites MySQL-style
+/// `SHOW TABLES [FROM schema]` and `SHOW COLUMNS FROM table` statements into
+/// information_schema queries against the session's default peer, and
+/// `DESCRIBE PEER peer[.schema[.table]]` into the same queries against the
+/// named peer.
+pub struct PeerShowAnalyzer<'a> {
+    peers: &'a HashMap<String, Peer>,
+    default_peer: Option<&'a str>,
+}
+
+impl<'a> PeerShowAnalyzer<'a> {
+    pub fn new(peers: &'a HashMap<String, Peer>, default_peer: Option<&'a str>) -> Self {
+        Self {
+            peers,
+            default_peer,
+        }
+    }
+
+    fn default_peer(&self) -> anyhow::Result<&'a Peer> {
+        let peer_name = self
+            .default_peer
+            .context("no default peer set for this session")?;
+        self.peers
+            .get(&peer_name.to_lowercase())
+            .with_context(|| format!("default peer {} does not exist", peer_name))
+    }
+
+    /// Analyzes the statements nexus parses itself that show what a peer has,
+    /// see `PEERDB_STATEMENTS`.
+    pub fn analyze_peerdb(
+        &self,
+        statement: &PeerDBStatement,
+    ) -> anyhow::Result<Option<(Box<Peer>, Statement)>> {
+        match statement {
+            PeerDBStatement::DescribePeer { peer_name } => {
+                let mut parts = peer_name.0.iter().map(|ident| ident.value.clone());
+                let name = parts.next().context("no peer specified")?.to_lowercase();
+                let peer = self
+                    .peers
+                    .get(&name)
+                    .with_context(|| format!("peer {} does not exist", name))?;
+                let path: Vec<String> = parts.collect();
+                let is_bigquery = matches!(peer.config, Some(Config::BigqueryConfig(_)));
+                // a bigquery peer is a single dataset, so its path starts at the table.
+                let query = match (is_bigquery, path.as_slice()) {
+                    (false, []) => show_schemas_query(peer)?,
+                    (false, [schema]) => show_tables_query(peer, Some(schema.clone()), None)?,
+                    (true, []) => show_tables_query(peer, None, None)?,
+                    (true, [table]) => show_columns_query(peer, None, table.clone(), None)?,
+                    (_, [schema, table]) => {
+                        show_columns_query(peer, Some(schema.clone()), table.clone(), None)?
+                    }
+                    _ => anyhow::bail!("invalid name for DESCRIBE PEER: {}", peer_name),
+                };
+                show_query(peer, &query).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<'a> StatementAnalyzer for PeerShowAnalyzer<'a> {
+    type Output = Option<(Box<Peer>, Statement)>;
+
+    fn analyze(&self, statement: &Statement) -> anyhow::Result<Self::Output> {
+        let (peer, query) = match statement {
+            Statement::ShowTables {
+                db_name, filter, ..
+            } => {
+                let peer = self.default_peer()?;
+                let schema = db_name.as_ref().map(|ident| ident.value.clone());
+                let query = show_tables_query(peer, schema, filter.as_ref())?;
+                (peer, query)
+            }
+            Statement::ShowColumns {
+                table_name, filter, ..
+            } => {
+                let peer = self.default_peer()?;
+                let mut parts: Vec<String> = table_name
+                    .0
+                    .iter()
+                    .map(|ident| ident.value.clone())
+                    .collect();
+                let table = parts.pop().context("no table specified")?;
+                let schema = parts.pop();
+                if !parts.is_empty() {
+                    anyhow::bail!("invalid table name for SHOW COLUMNS: {}", table_name);
+                }
+                let query = show_columns_query(peer, schema, table, filter.as_ref())?;
+                (peer, query)
+            }
+            _ => return Ok(None),
+        };
+        show_query(peer, &query).map(Some)
+    }
+}
+
+// the query on the peer a SHOW statement is rewritten to.
+fn show_query(peer: &Peer, query: &str) -> anyhow::Result<(Box<Peer>, Statement)> {
+    let mut stmts = sqlparser::parser::Parser::parse_sql(&PostgreSqlDialect {}, query)?;
+    let stmt = stmts.pop().context("unable to rewrite SHOW statement")?;
+    Ok((Box::new(peer.clone()), stmt))
+}
+
+// checks that the destination of a table mapping names a table the way the
+// target peer expects it, and returns the identifier as the flow uses it.
+fn destination_table_identifier(peer: Option<&Peer>, table: &ObjectName) -> anyhow::Result<String> {
+    let identifier = table_identifier(table);
+    let parts: Vec<String> = table.0.iter().map(column_name).collect();
+    match peer.and_then(|peer| peer.config.as_ref()) {
+        Some(Config::PostgresConfig(_)) => {
+            if parts.len() != 2 {
+                anyhow::bail!(
+                    "destination table {} must be of the form schema.table.",
+                    identifier
+                );
+            }
+            // postgres truncates longer identifiers to NAMEDATALEN - 1 bytes.
+            if parts.iter().any(|part| part.len() > 63) {
+                anyhow::bail!(
+                    "destination table {} has a part longer than 63 characters.",
+                    identifier
+                );
+            }
+            Ok(identifier)
+        }
+        Some(Config::SnowflakeConfig(_)) => {
+            if parts.len() != 2 {
+                anyhow::bail!(
+                    "destination table {} must be of the form schema.table.",
+                    identifier
+                );
+            }
+            if parts.iter().any(|part| part.len() > 255) {
+                anyhow::bail!(
+                    "destination table {} has a part longer than 255 characters.",
+                    identifier
+                );
+            }
+            Ok(identifier)
+        }
+        Some(Config::BigqueryConfig(bq_config)) => {
+            // tables are mirrored into the peer's dataset, which may be named
+            // explicitly as dataset.table.
+            let table_name = match parts.as_slice() {
+                [table_name] => table_name,
+                // dataset names are case sensitive whether quoted or not.
+                [_, table_name] => {
+                    if table.0[0].value != bq_config.dataset_id {
+                        anyhow::bail!(
+                            "destination table {} must be in the dataset {} of the peer.",
+                            identifier,
+                            bq_config.dataset_id
+                        );
+                    }
+                    table_name
+                }
+                _ => anyhow::bail!(
+                    "destination table {} must be of the form table or dataset.table.",
+                    identifier
+                ),
+            };
+            let is_valid = !table_name.is_empty()
+                && table_name.len() <= 1024
+                && table_name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !is_valid {
+                anyhow::bail!(
+                    "destination table {} is not a valid BigQuery table name, \
+                     which can only contain letters, digits and underscores.",
+                    identifier
+                );
+            }
+            Ok(table_name.clone())
+        }
+        _ => Ok(identifier),
+    }
+}
+
+// a table mapping of every table in a schema has `*` as its unquoted table name.
+fn is_wildcard(table: &ObjectName) -> bool {
+    table.0.last().map_or(false, |part| {
+        part.quote_style.is_none() && part.value == "*"
+    })
+}
+
+// `schema.* => schema.*` mirrors the tables of the source schema into the
+// destination schema, or into the dataset of a bigquery peer with `*`.
+fn schema_mapping(
+    peer: Option<&Peer>,
+    source: &ObjectName,
+    destination: &ObjectName,
+    has_partition_key: bool,
+    exclude: &[Ident],
+) -> anyhow::Result<FlowJobSchemaMapping> {
+    let source_schema = match source.0.as_slice() {
+        [schema, _] if schema.value != "*" => column_name(schema),
+        _ => anyhow::bail!("source {} must be of the form schema.*.", source),
+    };
+    if !is_wildcard(destination) {
+        anyhow::bail!(
+            "destination {} of source {} must end in *.",
+            destination,
+            source
+        );
+    }
+    if has_partition_key {
+        anyhow::bail!(
+            "the tables of source {} can't share a partition_key.",
+            source
+        );
+    }
+    let destination_schema = match (
+        peer.and_then(|peer| peer.config.as_ref()),
+        destination.0.as_slice(),
+    ) {
+        (Some(Config::BigqueryConfig(_)), [_]) => "".to_string(),
+        (Some(Config::BigqueryConfig(bq_config)), [dataset, _]) => {
+            if dataset.value != bq_config.dataset_id {
+                anyhow::bail!(
+                    "destination {} must be in the dataset {} of the peer.",
+                    destination,
+                    bq_config.dataset_id
+                );
+            }
+            "".to_string()
+        }
+        (_, [schema, _]) => column_name(schema),
+        _ => anyhow::bail!("destination {} must be of the form schema.*.", destination),
+    };
+    Ok(FlowJobSchemaMapping {
+        source_schema,
+        destination_schema,
+        exclude_tables: exclude.iter().map(column_name).collect(),
+    })
+}
+
+// unquoted names fold to lower case like they do in postgres.
+fn column_name(column: &Ident) -> String {
+    match column.quote_style {
+        Some(_) => column.value.clone(),
+        None => column.value.to_lowercase(),
+    }
+}
+
+// a table as the flow identifies it, the parts that aren't plain lower case
+// names keep their quotes so it can be split on the dots between them.
+fn table_identifier(table: &ObjectName) -> String {
+    table
+        .0
+        .iter()
+        .map(|part| quote_identifier(&column_name(part)))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Quotes a part of a table identifier unless it is a plain lower case name.
+pub fn quote_identifier(name: &str) -> String {
+    let is_plain = name
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if is_plain {
+        name.to_owned()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+// the columns peerdb adds to the destination tables are unquoted identifiers,
+// so they can't collide with the columns of the source tables by case alone.
+fn metadata_column_name(option: &str, name: &str) -> anyhow::Result<String> {
+    let valid = name
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        anyhow::bail!(
+            "{} must start with a letter or an underscore and contain only letters, digits and underscores",
+            option
+        );
+    }
+    Ok(name.to_lowercase())
+}
+
+fn bool_option(name: &str, value: &sqlparser::ast::Value) -> anyhow::Result<bool> {
+    match value {
+        sqlparser::ast::Value::Boolean(b) => Ok(*b),
+        // also support "true" and "false" as strings
+        sqlparser::ast::Value::SingleQuotedString(s) => match s.as_ref() {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => anyhow::bail!("{} must be a boolean", name),
+        },
+        _ => anyhow::bail!("{} must be a boolean", name),
+    }
+}
+
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn show_filter_clause(column: &str, filter: Option<&ShowStatementFilter>) -> String {
+    match filter {
+        Some(ShowStatementFilter::Like(pattern)) => {
+            format!(" AND {} LIKE {}", column, quote_literal(pattern))
+        }
+        Some(ShowStatementFilter::ILike(pattern)) => {
+            format!(
+                " AND LOWER({}) LIKE LOWER({})",
+                column,
+                quote_literal(pattern)
+            )
+        }
+        Some(ShowStatementFilter::Where(expr)) => format!(" AND ({})", expr),
+        None => String::new(),
+    }
+}
+
+fn show_schemas_query(peer: &Peer) -> anyhow::Result<String> {
+    match &peer.config {
+        Some(Config::PostgresConfig(_)) => Ok(
+            "SELECT schema_name FROM information_schema.schemata WHERE schema_name <> 'information_schema' AND LEFT(schema_name, 3) <> 'pg_' ORDER BY schema_name"
+                .to_string(),
+        ),
+        Some(Config::SnowflakeConfig(_)) => Ok(
+            "SELECT schema_name FROM information_schema.schemata WHERE schema_name <> 'INFORMATION_SCHEMA' ORDER BY schema_name"
+                .to_string(),
+        ),
+        _ => anyhow::bail!("DESCRIBE PEER is not supported for peer {}", peer.name),
+    }
+}
+
+fn show_tables_query(
+    peer: &Peer,
+    schema: Option<String>,
+    filter: Option<&ShowStatementFilter>,
+) -> anyhow::Result<String> {
+    match &peer.config {
+        Some(Config::PostgresConfig(_)) => Ok(format!(
+            "SELECT table_name FROM information_schema.tables WHERE table_schema = {}{} ORDER BY table_name",
+            quote_literal(&schema.unwrap_or_else(|| "public".to_string())),
+            show_filter_clause("table_name", filter)
+        )),
+        Some(Config::SnowflakeConfig(_)) => Ok(format!(
+            "SELECT table_name FROM information_schema.tables WHERE table_schema = {}{} ORDER BY table_name",
+            quote_literal(&schema.unwrap_or_else(|| "public".to_string()).to_uppercase()),
+            show_filter_clause("table_name", filter)
+        )),
+        Some(Config::BigqueryConfig(bq_config)) => {
+            // the bigquery executor always targets the peer's dataset.
+            if let Some(schema) = schema {
+                if schema != bq_config.dataset_id {
+                    anyhow::bail!(
+                        "only the dataset {} of peer {} can be listed",
+                        bq_config.dataset_id,
+                        peer.name
+                    );
+                }
+            }
+            Ok(format!(
+                "SELECT table_name FROM {}.INFORMATION_SCHEMA.TABLES WHERE TRUE{} ORDER BY table_name",
+                peer.name,
+                show_filter_clause("table_name", filter)
+            ))
+        }
+        _ => anyhow::bail!("SHOW TABLES is not supported for peer {}", peer.name),
+    }
+}
+
+fn show_columns_query(
+    peer: &Peer,
+    schema: Option<String>,
+    table: String,
+    filter: Option<&ShowStatementFilter>,
+) -> anyhow::Result<String> {
+    match &peer.config {
+        Some(Config::PostgresConfig(_)) => Ok(format!(
+            "SELECT column_name, data_type, is_nullable, column_default FROM information_schema.columns WHERE table_schema = {} AND table_name = {}{} ORDER BY ordinal_position",
+            quote_literal(&schema.unwrap_or_else(|| "public".to_string())),
+            quote_literal(&table),
+            show_filter_clause("column_name", filter)
+        )),
+        Some(Config::SnowflakeConfig(_)) => Ok(format!(
+            "SELECT column_name, data_type, is_nullable, column_default FROM information_schema.columns WHERE table_schema = {} AND table_name = {}{} ORDER BY ordinal_position",
+            quote_literal(&schema.unwrap_or_else(|| "public".to_string()).to_uppercase()),
+            quote_literal(&table.to_uppercase()),
+            show_filter_clause("column_name", filter)
+        )),
+        Some(Config::BigqueryConfig(bq_config)) => {
+            if let Some(schema) = schema {
+                if schema != bq_config.dataset_id {
+                    anyhow::bail!(
+                        "only the dataset {} of peer {} can be described",
+                        bq_config.dataset_id,
+                        peer.name
+                    );
+                }
+            }
+            Ok(format!(
+                "SELECT column_name, data_type, is_nullable, column_default FROM {}.INFORMATION_SCHEMA.COLUMNS WHERE table_name = {}{} ORDER BY ordinal_position",
+                peer.name,
+                quote_literal(&table),
+                show_filter_clause("column_name", filter)
+            ))
+        }
+        _ => anyhow::bail!("SHOW COLUMNS is not supported for peer {}", peer.name),
+    }
+}
+
+fn peer_options(with_options: Vec<SqlOption>) -> HashMap<String, String> {
     let mut opts: HashMap<String, String> = HashMap::new();
     for opt in with_options {
         let key = opt.name.value;
@@ -433,6 +1722,23 @@ fn parse_db_options(
         };
         opts.insert(key, val);
     }
+    opts
+}
+
+fn parse_db_options(
+    peers: &HashMap<String, Peer>,
+    db_type: DbType,
+    with_options: Vec<SqlOption>,
+) -> anyhow::Result<Option<Config>> {
+    let with_options = match peer_options_schema(db_type) {
+        Some(schema) => validate_options(
+            &format!("CREATE PEER of type {}", db_type.as_str_name()),
+            schema,
+            &with_options,
+        )?,
+        None => with_options,
+    };
+    let mut opts = peer_options(with_options);
 
     let config = match db_type {
         DbType::Bigquery => {
@@ -514,6 +1820,7 @@ fn parse_db_options(
                     .context("unable to parse query_timeout")?,
                 password: opts.get("password").map(|s| s.to_string()),
                 s3_integration: s3_int,
+                staging_path: opts.get("staging_path").map(|s| s.to_string()),
             };
             let config = Config::SnowflakeConfig(snowflake_config);
             Some(config)
@@ -546,6 +1853,7 @@ fn parse_db_options(
             Some(config)
         }
         DbType::Postgres => {
+            let money_mapping = parse_money_mapping(opts.get("money_mapping").map(|s| s.as_str()))?;
             let postgres_config = PostgresConfig {
                 host: opts.get("host").context("no host specified")?.to_string(),
                 port: opts
@@ -566,6 +1874,7 @@ fn parse_db_options(
                     .context("no default database specified")?
                     .to_string(),
                 transaction_snapshot: "".to_string(),
+                money_mapping: money_mapping as i32,
             };
             let config = Config::PostgresConfig(postgres_config);
             Some(config)
@@ -716,6 +2025,133 @@ fn parse_db_options(
             let config = Config::EventhubGroupConfig(eventhub_group_config);
             Some(config)
         }
+        DbType::Pinecone => {
+            // split comma separated list of columns and trim
+            let metadata_columns = opts
+                .get("metadata_columns")
+                .map(|columns| {
+                    columns
+                        .split(',')
+                        .map(|column| column.trim().to_string())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            let pinecone_config = PineconeConfig {
+                api_key: opts
+                    .get("api_key")
+                    .context("no pinecone api key specified")?
+                    .to_string(),
+                environment: opts
+                    .get("environment")
+                    .context("no pinecone environment specified")?
+                    .to_string(),
+                project_id: opts
+                    .get("project_id")
+                    .context("no pinecone project id specified")?
+                    .to_string(),
+                index_name: opts
+                    .get("index_name")
+                    .context("no pinecone index specified")?
+                    .to_string(),
+                namespace: opts
+                    .get("namespace")
+                    .map(|s| s.to_string())
+                    .unwrap_or_default(),
+                vector_column: opts
+                    .get("vector_column")
+                    .context("no vector column specified")?
+                    .to_string(),
+                metadata_columns,
+            };
+            let config = Config::PineconeConfig(pinecone_config);
+            Some(config)
+        }
+        DbType::Weaviate => {
+            let weaviate_config = WeaviateConfig {
+                url: opts
+                    .get("url")
+                    .context("no weaviate url specified")?
+                    .to_string(),
+                api_key: opts.get("api_key").map(|s| s.to_string()),
+                class_name: opts
+                    .get("class_name")
+                    .context("no weaviate class specified")?
+                    .to_string(),
+                vector_column: opts
+                    .get("vector_column")
+                    .map(|s| s.to_string())
+                    .unwrap_or_default(),
+            };
+            let config = Config::WeaviateConfig(weaviate_config);
+            Some(config)
+        }
+        DbType::File => {
+            let format = match opts.get("format").map(|s| s.to_lowercase()).as_deref() {
+                None | Some("csv") => FileFormat::Csv,
+                Some("parquet") => FileFormat::Parquet,
+                Some(format) => anyhow::bail!("unsupported file format: {}", format),
+            };
+            let file_config = FileConfig {
+                path: opts
+                    .get("path")
+                    .context("no directory path specified")?
+                    .to_string(),
+                format: format as i32,
+            };
+            let config = Config::FileConfig(file_config);
+            Some(config)
+        }
+        DbType::Webhook => {
+            // headers are given as a comma separated list of name:value pairs
+            let mut headers = HashMap::new();
+            if let Some(raw_headers) = opts.get("headers") {
+                for header in raw_headers.split(',').filter(|h| !h.trim().is_empty()) {
+                    let (name, value) = header.split_once(':').with_context(|| {
+                        format!("invalid header, expected name:value: {}", header)
+                    })?;
+                    headers.insert(name.trim().to_string(), value.trim().to_string());
+                }
+            }
+
+            // max_retries default to 5 if not set, parse as int
+            let max_retries = opts
+                .get("max_retries")
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "5".to_string())
+                .parse::<u32>()
+                .context("unable to parse max_retries as valid int")?;
+
+            // timeout_seconds default to 30 if not set, parse as int
+            let timeout_seconds = opts
+                .get("timeout_seconds")
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "30".to_string())
+                .parse::<u32>()
+                .context("unable to parse timeout_seconds as valid int")?;
+
+            // max_inflight_batches default to 4 if not set, parse as int
+            let max_inflight_batches = opts
+                .get("max_inflight_batches")
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "4".to_string())
+                .parse::<u32>()
+                .context("unable to parse max_inflight_batches as valid int")?;
+
+            let webhook_config = WebhookConfig {
+                url: opts
+                    .get("url")
+                    .context("no webhook url specified")?
+                    .to_string(),
+                signing_secret: opts.get("signing_secret").map(|s| s.to_string()),
+                headers,
+                max_retries,
+                timeout_seconds,
+                max_inflight_batches,
+            };
+            let config = Config::WebhookConfig(webhook_config);
+            Some(config)
+        }
     };
 
     Ok(config)
@@ -754,3 +2190,291 @@ fn parse_metadata_db_info(conn_str: &str) -> anyhow::Result<Option<PostgresConfi
 
     Ok(Some(metadata_db))
 }
+
+fn parse_money_mapping(money_mapping: Option<&str>) -> anyhow::Result<MoneyMapping> {
+    match money_mapping.map(|s| s.to_lowercase()).as_deref() {
+        None | Some("string") => Ok(MoneyMapping::String),
+        Some("numeric") => Ok(MoneyMapping::Numeric),
+        Some("numeric_with_currency") => Ok(MoneyMapping::NumericWithCurrency),
+        Some(money_mapping) => anyhow::bail!("unsupported money_mapping: {}", money_mapping),
+    }
+}
+
+// apply the options of an ALTER PEER statement on top of the existing config,
+// only connection, credential and type mapping options can be changed.
+fn alter_db_options(config: &mut Config, with_options: Vec<SqlOption>) -> anyhow::Result<()> {
+    let opts = peer_options(with_options);
+    match config {
+        Config::PostgresConfig(postgres_config) => {
+            for (key, val) in opts {
+                match key.as_str() {
+                    "host" => postgres_config.host = val,
+                    "port" => {
+                        postgres_config.port = val
+                            .parse::<u32>()
+                            .context("unable to parse port as valid int")?
+                    }
+                    "user" => postgres_config.user = val,
+                    "password" => postgres_config.password = val,
+                    "database" => postgres_config.database = val,
+                    "money_mapping" => {
+                        postgres_config.money_mapping = parse_money_mapping(Some(&val))? as i32
+                    }
+                    _ => anyhow::bail!("option {} cannot be altered for postgres peers", key),
+                }
+            }
+        }
+        Config::SnowflakeConfig(snowflake_config) => {
+            for (key, val) in opts {
+                match key.as_str() {
+                    "account_id" => snowflake_config.account_id = val,
+                    "username" => snowflake_config.username = val,
+                    "private_key" => snowflake_config.private_key = val,
+                    "password" => snowflake_config.password = Some(val),
+                    "database" => snowflake_config.database = val,
+                    "warehouse" => snowflake_config.warehouse = val,
+                    "role" => snowflake_config.role = val,
+                    "query_timeout" => {
+                        snowflake_config.query_timeout = val
+                            .parse::<u64>()
+                            .context("unable to parse query_timeout")?
+                    }
+                    "s3_integration" => snowflake_config.s3_integration = val,
+                    "staging_path" => snowflake_config.staging_path = Some(val),
+                    _ => anyhow::bail!("option {} cannot be altered for snowflake peers", key),
+                }
+            }
+        }
+        Config::BigqueryConfig(bq_config) => {
+            for (key, val) in opts {
+                match key.as_str() {
+                    "private_key" => {
+                        pem::parse(val.as_bytes()).map_err(|err| {
+                            anyhow::anyhow!("unable to parse private_key: {:?}", err)
+                        })?;
+                        bq_config.private_key = val;
+                    }
+                    "type" => bq_config.auth_type = val,
+                    "project_id" => bq_config.project_id = val,
+                    "private_key_id" => bq_config.private_key_id = val,
+                    "client_email" => bq_config.client_email = val,
+                    "client_id" => bq_config.client_id = val,
+                    "auth_uri" => bq_config.auth_uri = val,
+                    "token_uri" => bq_config.token_uri = val,
+                    "auth_provider_x509_cert_url" => bq_config.auth_provider_x509_cert_url = val,
+                    "client_x509_cert_url" => bq_config.client_x509_cert_url = val,
+                    "dataset_id" => bq_config.dataset_id = val,
+                    _ => anyhow::bail!("option {} cannot be altered for bigquery peers", key),
+                }
+            }
+        }
+        Config::MongoConfig(mongo_config) => {
+            for (key, val) in opts {
+                match key.as_str() {
+                    "username" => mongo_config.username = val,
+                    "password" => mongo_config.password = val,
+                    "clusterurl" => mongo_config.clusterurl = val,
+                    "clusterport" => {
+                        mongo_config.clusterport = val
+                            .parse::<i32>()
+                            .context("unable to parse port as valid int")?
+                    }
+                    "database" => mongo_config.database = val,
+                    _ => anyhow::bail!("option {} cannot be altered for mongo peers", key),
+                }
+            }
+        }
+        _ => anyhow::bail!("ALTER PEER is not supported for this peer type"),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pt::peerdb_peers::{peer::Config, BigqueryConfig, DbType, Peer, PostgresConfig, S3Config};
+    use sqlparser::{
+        ast::{Ident, SqlOption, Statement, Value},
+        dialect::PostgreSqlDialect,
+        parser::Parser,
+    };
+
+    use super::{parse_db_options, parse_peerdb_statement, PeerShowAnalyzer, StatementAnalyzer};
+
+    #[test]
+    fn create_snowflake_peer_with_staging_path() {
+        let options = [
+            ("account_id", "account"),
+            ("username", "user"),
+            ("private_key", "key"),
+            ("database", "db"),
+            ("warehouse", "wh"),
+            ("role", "role"),
+            ("query_timeout", "300"),
+            ("staging_path", "s3://bucket/prefix"),
+        ]
+        .into_iter()
+        .map(|(name, value)| SqlOption {
+            name: Ident::new(name),
+            value: Value::SingleQuotedString(value.to_string()),
+        })
+        .collect();
+        let config = parse_db_options(&HashMap::new(), DbType::Snowflake, options).unwrap();
+        match config {
+            Some(Config::SnowflakeConfig(config)) => {
+                assert_eq!(config.staging_path.as_deref(), Some("s3://bucket/prefix"))
+            }
+            _ => panic!("expected a snowflake config"),
+        }
+    }
+
+    fn show_peers() -> HashMap<String, Peer> {
+        let peers = [
+            Peer {
+                name: "pg".to_string(),
+                r#type: DbType::Postgres as i32,
+                config: Some(Config::PostgresConfig(PostgresConfig::default())),
+            },
+            Peer {
+                name: "bq".to_string(),
+                r#type: DbType::Bigquery as i32,
+                config: Some(Config::BigqueryConfig(BigqueryConfig {
+                    dataset_id: "ds".to_string(),
+                    ..Default::default()
+                })),
+            },
+            Peer {
+                name: "s3".to_string(),
+                r#type: DbType::S3 as i32,
+                config: Some(Config::S3Config(S3Config::default())),
+            },
+        ];
+        peers
+            .into_iter()
+            .map(|peer| (peer.name.clone(), peer))
+            .collect()
+    }
+
+    fn parse(sql: &str) -> Statement {
+        Parser::parse_sql(&PostgreSqlDialect {}, sql)
+            .unwrap()
+            .pop()
+            .unwrap()
+    }
+
+    // the peer a SHOW statement runs on and the query it is rewritten to.
+    fn show(default_peer: Option<&str>, sql: &str) -> anyhow::Result<(String, String)> {
+        let peers = show_peers();
+        let analyzer = PeerShowAnalyzer::new(&peers, default_peer);
+        let (peer, query) = analyzer.analyze(&parse(sql))?.expect("a SHOW statement");
+        Ok((peer.name, query.to_string()))
+    }
+
+    fn describe(sql: &str) -> anyhow::Result<(String, String)> {
+        let peers = show_peers();
+        let analyzer = PeerShowAnalyzer::new(&peers, None);
+        let (peer, query) = analyzer
+            .analyze_peerdb(&parse_peerdb_statement(sql).unwrap())?
+            .expect("a DESCRIBE PEER statement");
+        Ok((peer.name, query.to_string()))
+    }
+
+    #[test]
+    fn show_tables_of_default_peer() {
+        let (peer, query) = show(Some("PG"), "SHOW TABLES").unwrap();
+        assert_eq!(peer, "pg");
+        assert!(query.contains("information_schema.tables"), "{}", query);
+        assert!(query.contains("table_schema = 'public'"), "{}", query);
+
+        let (_, query) = show(Some("pg"), "SHOW TABLES FROM sales LIKE 'ord%'").unwrap();
+        assert!(query.contains("table_schema = 'sales'"), "{}", query);
+        assert!(query.contains("table_name LIKE 'ord%'"), "{}", query);
+
+        let (peer, query) = show(Some("bq"), "SHOW TABLES").unwrap();
+        assert_eq!(peer, "bq");
+        assert!(query.contains("bq.INFORMATION_SCHEMA.TABLES"), "{}", query);
+    }
+
+    #[test]
+    fn show_columns_of_default_peer() {
+        let (peer, query) = show(Some("pg"), "SHOW COLUMNS FROM sales.orders").unwrap();
+        assert_eq!(peer, "pg");
+        assert!(query.contains("information_schema.columns"), "{}", query);
+        assert!(query.contains("table_schema = 'sales'"), "{}", query);
+        assert!(query.contains("table_name = 'orders'"), "{}", query);
+
+        let (_, query) = show(Some("pg"), "SHOW COLUMNS FROM orders").unwrap();
+        assert!(query.contains("table_schema = 'public'"), "{}", query);
+
+        let err = show(Some("pg"), "SHOW COLUMNS FROM db.sales.orders").unwrap_err();
+        assert!(err.to_string().contains("invalid table name"), "{}", err);
+    }
+
+    #[test]
+    fn describe_peer() {
+        let (peer, query) = describe("DESCRIBE PEER pg").unwrap();
+        assert_eq!(peer, "pg");
+        assert!(query.contains("information_schema.schemata"), "{}", query);
+
+        let (_, query) = describe("DESCRIBE PEER pg.sales").unwrap();
+        assert!(query.contains("information_schema.tables"), "{}", query);
+        assert!(query.contains("table_schema = 'sales'"), "{}", query);
+
+        let (_, query) = describe("DESCRIBE PEER pg.sales.orders").unwrap();
+        assert!(query.contains("information_schema.columns"), "{}", query);
+        assert!(query.contains("table_name = 'orders'"), "{}", query);
+
+        // a bigquery peer is a single dataset, its path starts at the table.
+        let (_, query) = describe("DESCRIBE PEER bq").unwrap();
+        assert!(query.contains("bq.INFORMATION_SCHEMA.TABLES"), "{}", query);
+        let (_, query) = describe("DESCRIBE PEER bq.orders").unwrap();
+        assert!(query.contains("bq.INFORMATION_SCHEMA.COLUMNS"), "{}", query);
+        assert!(query.contains("table_name = 'orders'"), "{}", query);
+        let (_, query) = describe("DESCRIBE PEER bq.ds.orders").unwrap();
+        assert!(query.contains("table_name = 'orders'"), "{}", query);
+    }
+
+    #[test]
+    fn show_unknown_peer_or_object() {
+        let err = describe("DESCRIBE PEER missing").unwrap_err();
+        assert_eq!(err.to_string(), "peer missing does not exist");
+
+        let err = show(None, "SHOW TABLES").unwrap_err();
+        assert_eq!(err.to_string(), "no default peer set for this session");
+        let err = show(Some("missing"), "SHOW TABLES").unwrap_err();
+        assert_eq!(err.to_string(), "default peer missing does not exist");
+
+        let err = describe("DESCRIBE PEER pg.db.sales.orders").unwrap_err();
+        assert!(
+            err.to_string().contains("invalid name for DESCRIBE PEER"),
+            "{}",
+            err
+        );
+        let err = describe("DESCRIBE PEER bq.other.orders").unwrap_err();
+        assert!(err.to_string().contains("only the dataset ds"), "{}", err);
+        let err = show(Some("bq"), "SHOW TABLES FROM other").unwrap_err();
+        assert!(err.to_string().contains("only the dataset ds"), "{}", err);
+
+        let err = show(Some("s3"), "SHOW TABLES").unwrap_err();
+        assert!(
+            err.to_string().contains("SHOW TABLES is not supported"),
+            "{}",
+            err
+        );
+        let err = describe("DESCRIBE PEER s3").unwrap_err();
+        assert!(
+            err.to_string().contains("DESCRIBE PEER is not supported"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn show_ignores_other_statements() {
+        let peers = show_peers();
+        let analyzer = PeerShowAnalyzer::new(&peers, Some("pg"));
+        assert!(analyzer.analyze(&parse("SELECT 1")).unwrap().is_none());
+    }
+}
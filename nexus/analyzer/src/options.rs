@@ -0,0 +1,352 @@
+use pt::peerdb_peers::DbType;
+use sqlparser::ast::{SqlOption, Value};
+
+// The WITH options a statement accepts. Options are checked against their
+// schema before the statement is analyzed, so that a misspelled option or a
+// value of the wrong type is an error rather than silently ignored. The
+// options of CREATE MIRROR ... AS SELECT are checked the same way by
+// qrep::process_options, against the options it converts for the flow server.
+pub struct OptionSpec {
+    name: &'static str,
+    kind: OptionKind,
+}
+
+enum OptionKind {
+    String,
+    OneOf(&'static [&'static str]),
+    Boolean,
+    Int { min: i64, max: i64 },
+}
+
+impl OptionSpec {
+    const fn string(name: &'static str) -> Self {
+        Self {
+            name,
+            kind: OptionKind::String,
+        }
+    }
+
+    const fn one_of(name: &'static str, values: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            kind: OptionKind::OneOf(values),
+        }
+    }
+
+    const fn boolean(name: &'static str) -> Self {
+        Self {
+            name,
+            kind: OptionKind::Boolean,
+        }
+    }
+
+    const fn int(name: &'static str, min: i64, max: i64) -> Self {
+        Self {
+            name,
+            kind: OptionKind::Int { min, max },
+        }
+    }
+
+    // checks the value of the option, and returns it as the analyzer reads
+    // options of its type: quoted numbers and booleans are accepted for
+    // numeric and boolean options, and choices are lowercased.
+    fn check(&self, value: &Value) -> anyhow::Result<Value> {
+        let name = self.name;
+        match &self.kind {
+            OptionKind::String => match value {
+                Value::SingleQuotedString(s) => Ok(Value::SingleQuotedString(s.clone())),
+                Value::Number(n, _) => Ok(Value::SingleQuotedString(n.clone())),
+                _ => anyhow::bail!("{} must be a string", name),
+            },
+            OptionKind::OneOf(values) => match value {
+                Value::SingleQuotedString(s) if values.contains(&s.to_lowercase().as_str()) => {
+                    Ok(Value::SingleQuotedString(s.to_lowercase()))
+                }
+                _ => anyhow::bail!("{} must be one of {}", name, values.join(", ")),
+            },
+            OptionKind::Boolean => match value {
+                Value::Boolean(b) => Ok(Value::Boolean(*b)),
+                Value::SingleQuotedString(s) if s == "true" || s == "false" => {
+                    Ok(Value::Boolean(s == "true"))
+                }
+                _ => anyhow::bail!("{} must be a boolean", name),
+            },
+            OptionKind::Int { min, max } => {
+                let num = match value {
+                    Value::Number(n, _) | Value::SingleQuotedString(n) => n.parse::<i64>().ok(),
+                    _ => None,
+                };
+                match num {
+                    Some(num) if num >= *min && num <= *max => {
+                        Ok(Value::Number(num.to_string(), false))
+                    }
+                    _ if *min == 1 && *max == u32::MAX as i64 => {
+                        anyhow::bail!("{} must be a positive integer", name)
+                    }
+                    _ if *min == 0 && *max == u32::MAX as i64 => {
+                        anyhow::bail!("{} must be a non-negative integer", name)
+                    }
+                    _ => anyhow::bail!("{} must be an integer between {} and {}", name, min, max),
+                }
+            }
+        }
+    }
+}
+
+const UINT_MAX: i64 = u32::MAX as i64;
+
+pub const CDC_MIRROR_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::boolean("do_initial_copy"),
+    OptionSpec::string("publication_name"),
+    OptionSpec::string("replication_slot_name"),
+    OptionSpec::int("snapshot_num_rows_per_partition", 1, UINT_MAX),
+    OptionSpec::int("snapshot_num_tables_in_parallel", 1, UINT_MAX),
+    OptionSpec::int("snapshot_max_parallel_workers", 1, UINT_MAX),
+    OptionSpec::one_of("snapshot_sync_mode", &["sql", "avro"]),
+    OptionSpec::string("snapshot_staging_path"),
+    OptionSpec::one_of("cdc_sync_mode", &["sql", "avro"]),
+    OptionSpec::string("cdc_staging_path"),
+    OptionSpec::string("staging_integration"),
+    OptionSpec::boolean("soft_delete"),
+    OptionSpec::string("soft_delete_column"),
+    OptionSpec::string("synced_at_column"),
+    OptionSpec::boolean("initial_copy_only"),
+    OptionSpec::one_of("ordering_key", &["primary_key", "table", "partition_key"]),
+    OptionSpec::int("push_parallelism", 1, UINT_MAX),
+    OptionSpec::int("push_batch_size", 1, UINT_MAX),
+    OptionSpec::int("max_batch_size", 1, UINT_MAX),
+    OptionSpec::int("refresh_statistics_threshold", 0, UINT_MAX),
+    OptionSpec::string("apply_window"),
+    OptionSpec::boolean("track_latency"),
+    OptionSpec::int("schema_refresh_interval", 0, UINT_MAX),
+];
+
+const PORT_MAX: i64 = u16::MAX as i64;
+
+const BIGQUERY_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("type"),
+    OptionSpec::string("project_id"),
+    OptionSpec::string("private_key_id"),
+    OptionSpec::string("private_key"),
+    OptionSpec::string("client_email"),
+    OptionSpec::string("client_id"),
+    OptionSpec::string("auth_uri"),
+    OptionSpec::string("token_uri"),
+    OptionSpec::string("auth_provider_x509_cert_url"),
+    OptionSpec::string("client_x509_cert_url"),
+    OptionSpec::string("dataset_id"),
+];
+
+const SNOWFLAKE_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("account_id"),
+    OptionSpec::string("username"),
+    OptionSpec::string("private_key"),
+    OptionSpec::string("password"),
+    OptionSpec::string("database"),
+    OptionSpec::string("warehouse"),
+    OptionSpec::string("role"),
+    OptionSpec::int("query_timeout", 0, i64::MAX),
+    OptionSpec::string("s3_integration"),
+    OptionSpec::string("staging_path"),
+];
+
+const MONGO_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("username"),
+    OptionSpec::string("password"),
+    OptionSpec::string("clusterurl"),
+    OptionSpec::int("clusterport", 1, PORT_MAX),
+    OptionSpec::string("database"),
+];
+
+const POSTGRES_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("host"),
+    OptionSpec::int("port", 1, PORT_MAX),
+    OptionSpec::string("user"),
+    OptionSpec::string("password"),
+    OptionSpec::string("database"),
+    OptionSpec::one_of(
+        "money_mapping",
+        &["string", "numeric", "numeric_with_currency"],
+    ),
+];
+
+const EVENTHUB_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("namespace"),
+    OptionSpec::string("resource_group"),
+    OptionSpec::string("location"),
+    OptionSpec::string("metadata_db"),
+    OptionSpec::string("subscription_id"),
+    OptionSpec::int("partition_count", 1, UINT_MAX),
+    OptionSpec::int("message_retention_in_days", 1, UINT_MAX),
+];
+
+const S3_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("url"),
+    OptionSpec::string("access_key_id"),
+    OptionSpec::string("secret_access_key"),
+    OptionSpec::string("region"),
+    OptionSpec::string("role_arn"),
+    OptionSpec::string("endpoint"),
+    OptionSpec::string("metadata_db"),
+];
+
+const SQLSERVER_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("server"),
+    OptionSpec::int("port", 1, PORT_MAX),
+    OptionSpec::string("user"),
+    OptionSpec::string("password"),
+    OptionSpec::string("database"),
+];
+
+const PINECONE_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("api_key"),
+    OptionSpec::string("environment"),
+    OptionSpec::string("project_id"),
+    OptionSpec::string("index_name"),
+    OptionSpec::string("namespace"),
+    OptionSpec::string("vector_column"),
+    OptionSpec::string("metadata_columns"),
+];
+
+const WEAVIATE_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("url"),
+    OptionSpec::string("api_key"),
+    OptionSpec::string("class_name"),
+    OptionSpec::string("vector_column"),
+];
+
+const FILE_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("path"),
+    OptionSpec::one_of("format", &["csv", "parquet"]),
+];
+
+const WEBHOOK_PEER_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::string("url"),
+    OptionSpec::string("signing_secret"),
+    OptionSpec::string("headers"),
+    OptionSpec::int("max_retries", 0, UINT_MAX),
+    OptionSpec::int("timeout_seconds", 1, UINT_MAX),
+    OptionSpec::int("max_inflight_batches", 1, UINT_MAX),
+];
+
+// the options of CREATE PEER for a type of peer. the options of an eventhub
+// group are the names of its eventhub peers, which are checked on their own.
+pub fn peer_options_schema(db_type: DbType) -> Option<&'static [OptionSpec]> {
+    match db_type {
+        DbType::Bigquery => Some(BIGQUERY_PEER_OPTIONS),
+        DbType::Snowflake => Some(SNOWFLAKE_PEER_OPTIONS),
+        DbType::Mongo => Some(MONGO_PEER_OPTIONS),
+        DbType::Postgres => Some(POSTGRES_PEER_OPTIONS),
+        DbType::Eventhub => Some(EVENTHUB_PEER_OPTIONS),
+        DbType::S3 => Some(S3_PEER_OPTIONS),
+        DbType::Sqlserver => Some(SQLSERVER_PEER_OPTIONS),
+        DbType::EventhubGroup => None,
+        DbType::Pinecone => Some(PINECONE_PEER_OPTIONS),
+        DbType::Weaviate => Some(WEAVIATE_PEER_OPTIONS),
+        DbType::File => Some(FILE_PEER_OPTIONS),
+        DbType::Webhook => Some(WEBHOOK_PEER_OPTIONS),
+    }
+}
+
+pub fn unknown_option_error<'a>(
+    statement: &str,
+    name: &str,
+    valid_options: impl Iterator<Item = &'a str>,
+) -> anyhow::Error {
+    let mut valid_options: Vec<&str> = valid_options.collect();
+    valid_options.sort_unstable();
+    anyhow::anyhow!(
+        "unknown option {} for {}, valid options are: {}",
+        name,
+        statement,
+        valid_options.join(", ")
+    )
+}
+
+// checks the options of a statement against its schema, and returns them as
+// the analyzer reads them, see OptionSpec::check.
+pub fn validate_options(
+    statement: &str,
+    schema: &[OptionSpec],
+    options: &[SqlOption],
+) -> anyhow::Result<Vec<SqlOption>> {
+    options
+        .iter()
+        .map(|option| {
+            let spec = schema
+                .iter()
+                .find(|spec| spec.name == option.name.value)
+                .ok_or_else(|| {
+                    unknown_option_error(
+                        statement,
+                        &option.name.value,
+                        schema.iter().map(|spec| spec.name),
+                    )
+                })?;
+            Ok(SqlOption {
+                name: option.name.clone(),
+                value: spec.check(&option.value)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::ast::{Ident, SqlOption, Value};
+
+    use super::{peer_options_schema, validate_options};
+    use pt::peerdb_peers::DbType;
+
+    fn option(name: &str, value: Value) -> SqlOption {
+        SqlOption {
+            name: Ident::new(name),
+            value,
+        }
+    }
+
+    #[test]
+    fn snowflake_peers_take_a_staging_path() {
+        let schema = peer_options_schema(DbType::Snowflake).unwrap();
+        let options = validate_options(
+            "CREATE PEER",
+            schema,
+            &[option(
+                "staging_path",
+                Value::SingleQuotedString("s3://bucket/prefix".to_string()),
+            )],
+        )
+        .unwrap();
+        assert_eq!(
+            options[0].value,
+            Value::SingleQuotedString("s3://bucket/prefix".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_and_mistyped_options_are_errors() {
+        let schema = peer_options_schema(DbType::Snowflake).unwrap();
+        let err = validate_options(
+            "CREATE PEER",
+            schema,
+            &[option(
+                "staging_pth",
+                Value::SingleQuotedString("s3://bucket".to_string()),
+            )],
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().starts_with("unknown option staging_pth"),
+            "{}",
+            err
+        );
+
+        let err = validate_options(
+            "CREATE PEER",
+            schema,
+            &[option("query_timeout", Value::Boolean(true))],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("query_timeout"), "{}", err);
+    }
+}
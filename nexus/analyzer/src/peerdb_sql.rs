@@ -0,0 +1,1567 @@
+// the statements of nexus that sqlparser doesn't parse, which nexus parses
+// itself with the parser's tokens. the parser leaves them to nexus as it is
+// told where they start, see `PEERDB_STATEMENTS`.
+
+use std::fmt;
+
+use pt::peerdb_peers::DbType;
+use sqlparser::{
+    ast::{Ident, ObjectName, SqlOption, Value},
+    dialect::PostgreSqlDialect,
+    keywords::Keyword,
+    parser::{Parser, ParserError},
+    tokenizer::Token,
+};
+
+/// The words the statements nexus parses itself start with, a `'` stands for
+/// a string.
+pub const PEERDB_STATEMENTS: &[&[&str]] = &[
+    &["create", "peer"],
+    &["alter", "peer"],
+    &["drop", "peer"],
+    &["replace", "peer"],
+    &["validate", "peer"],
+    &["describe", "peer"],
+    &["show", "tables", "in", "peer"],
+    &["execute", "'"],
+    &["comment", "on", "peer"],
+    &["create", "mirror"],
+    &["explain", "create", "mirror"],
+    &["drop", "mirror"],
+    &["pause", "mirror"],
+    &["resume", "mirror"],
+    &["describe", "mirror"],
+    &["resync", "mirror"],
+    &["alter", "mirror"],
+    &["comment", "on", "mirror"],
+    &["create", "user"],
+    &["alter", "user"],
+    &["drop", "user"],
+    &["grant", "create", "peer"],
+    &["grant", "create", "mirror"],
+    &["grant", "usage", "on", "peer"],
+    &["grant", "operate", "on", "mirror"],
+    &["grant", "read", "only"],
+    &["revoke", "create", "peer"],
+    &["revoke", "create", "mirror"],
+    &["revoke", "usage", "on", "peer"],
+    &["revoke", "operate", "on", "mirror"],
+    &["revoke", "read", "only"],
+    &["create", "resource", "group"],
+    &["alter", "resource", "group"],
+    &["drop", "resource", "group"],
+    &["listen"],
+    &["unlisten"],
+    &["notify"],
+];
+
+// the peer types of `CREATE PEER ... FROM <type>`.
+const PEER_TYPES: &[(&str, DbType)] = &[
+    ("bigquery", DbType::Bigquery),
+    ("mongo", DbType::Mongo),
+    ("snowflake", DbType::Snowflake),
+    ("postgres", DbType::Postgres),
+    ("eventhub", DbType::Eventhub),
+    ("eventhubgroup", DbType::EventhubGroup),
+    ("s3", DbType::S3),
+    ("sqlserver", DbType::Sqlserver),
+    ("pinecone", DbType::Pinecone),
+    ("weaviate", DbType::Weaviate),
+    ("file", DbType::File),
+    ("webhook", DbType::Webhook),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeerDBStatement {
+    /// `CREATE PEER [IF NOT EXISTS] name FROM type WITH (...) [VALIDATE ONLY]`
+    CreatePeer {
+        if_not_exists: bool,
+        peer_name: ObjectName,
+        peer_type: DbType,
+        with_options: Vec<SqlOption>,
+        validate_only: bool,
+    },
+    /// `ALTER PEER name WITH (...)`, or `SET (...)`
+    AlterPeer {
+        peer_name: ObjectName,
+        with_options: Vec<SqlOption>,
+    },
+    /// `DROP PEER [IF EXISTS] name [CASCADE]`
+    DropPeer {
+        if_exists: bool,
+        peer_name: ObjectName,
+        cascade: bool,
+    },
+    /// `REPLACE PEER old WITH new`
+    ReplacePeer {
+        old_peer_name: ObjectName,
+        new_peer_name: ObjectName,
+    },
+    /// `VALIDATE PEER name`
+    ValidatePeer { peer_name: ObjectName },
+    /// `DESCRIBE PEER peer[.schema[.table]]`
+    DescribePeer { peer_name: ObjectName },
+    /// `SHOW TABLES IN PEER name [SCHEMA schema]`
+    ShowPeerTables {
+        peer_name: ObjectName,
+        schema: Option<Ident>,
+    },
+    /// `EXECUTE 'query' ON PEER name`
+    ExecuteOnPeer {
+        query_string: String,
+        peer_name: ObjectName,
+    },
+    /// `COMMENT ON PEER name IS 'comment' | NULL`
+    CommentOnPeer {
+        peer_name: ObjectName,
+        comment: Option<String>,
+    },
+    /// `CREATE MIRROR [IF NOT EXISTS] name FROM source TO target ...`
+    CreateMirror {
+        if_not_exists: bool,
+        create_mirror: CreateMirror,
+    },
+    /// `EXPLAIN CREATE MIRROR ...`
+    Explain { statement: Box<PeerDBStatement> },
+    /// `DROP MIRROR [IF EXISTS] name [WITH (drop_destination_tables = true)]`
+    DropMirror {
+        if_exists: bool,
+        mirror_name: ObjectName,
+        with_options: Vec<SqlOption>,
+    },
+    /// `PAUSE MIRROR name`
+    PauseMirror { mirror_name: ObjectName },
+    /// `RESUME MIRROR name`
+    ResumeMirror { mirror_name: ObjectName },
+    /// `DESCRIBE MIRROR name`
+    DescribeMirror { mirror_name: ObjectName },
+    /// `RESYNC MIRROR name [TABLE table] [WITH (truncate_destination_table = true)]`
+    ResyncMirror {
+        mirror_name: ObjectName,
+        table: Option<ObjectName>,
+        with_options: Vec<SqlOption>,
+    },
+    /// `ALTER MIRROR name ADD TABLE ..., PAUSE TABLE ..., RESUME TABLE ... or DROP TABLE ...`
+    AlterMirror {
+        mirror_name: ObjectName,
+        operation: AlterMirrorOperation,
+    },
+    /// `COMMENT ON MIRROR name IS 'comment' | NULL`
+    CommentOnMirror {
+        mirror_name: ObjectName,
+        comment: Option<String>,
+    },
+    /// `CREATE USER [IF NOT EXISTS] name WITH PASSWORD 'password'`
+    CreateUser {
+        if_not_exists: bool,
+        name: Ident,
+        password: String,
+    },
+    /// `ALTER USER name WITH PASSWORD 'password'`
+    AlterUser { name: Ident, password: String },
+    /// `DROP USER [IF EXISTS] name`
+    DropUser { if_exists: bool, name: Ident },
+    /// `GRANT privilege TO user, ...`
+    Grant {
+        privilege: PeerDBPrivilege,
+        grantees: Vec<Ident>,
+    },
+    /// `REVOKE privilege FROM user, ...`
+    Revoke {
+        privilege: PeerDBPrivilege,
+        grantees: Vec<Ident>,
+    },
+    /// `CREATE RESOURCE GROUP [IF NOT EXISTS] name [WITH (weight = 2, max_concurrency = 4)]`
+    CreateResourceGroup {
+        if_not_exists: bool,
+        name: Ident,
+        with_options: Vec<SqlOption>,
+    },
+    /// `ALTER RESOURCE GROUP name WITH (...) | ADD USER user, ... | DROP USER user, ...`
+    AlterResourceGroup {
+        name: Ident,
+        operation: AlterResourceGroupOperation,
+    },
+    /// `DROP RESOURCE GROUP [IF EXISTS] name`
+    DropResourceGroup { if_exists: bool, name: Ident },
+    /// `LISTEN`, `UNLISTEN` or `NOTIFY`
+    Notification(NotificationStatement),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CreateMirror {
+    /// `... WITH TABLE MAPPING (mapping, ...) [WITH (...)]`
+    CDC(CreateMirrorForCDC),
+    /// `... FOR $$query$$ | AS 'query' [WITH (...)]`
+    Select(CreateMirrorForSelect),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateMirrorForCDC {
+    pub mirror_name: ObjectName,
+    pub source_peer: ObjectName,
+    pub target_peer: ObjectName,
+    pub mapping_options: Vec<MappingOptions>,
+    pub with_options: Vec<SqlOption>,
+}
+
+/// A table mapping, `source:destination` or `source => destination`, or
+/// `{from: source, to: destination, key: column}` with a partition key,
+/// followed by `EXCLUDE (column, ...)` for the columns left out. The tables of
+/// a schema are mapped with `schema.*` whose last part is an unquoted `*`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingOptions {
+    pub source: ObjectName,
+    pub destination: ObjectName,
+    pub partition_key: Option<Ident>,
+    pub exclude: Vec<Ident>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateMirrorForSelect {
+    pub mirror_name: ObjectName,
+    pub source_peer: ObjectName,
+    pub target_peer: ObjectName,
+    pub query_string: String,
+    pub with_options: Vec<SqlOption>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterMirrorOperation {
+    /// `ADD TABLE source [AS destination]`
+    AddTable {
+        source_table: ObjectName,
+        destination_table: Option<ObjectName>,
+    },
+    /// `PAUSE TABLE table [WITH (policy = 'buffer' | 'skip')]`
+    PauseTable {
+        table: ObjectName,
+        with_options: Vec<SqlOption>,
+    },
+    /// `RESUME TABLE table`
+    ResumeTable { table: ObjectName },
+    /// `DROP TABLE table [WITH (truncate_destination_table = true)]`
+    DropTable {
+        table: ObjectName,
+        with_options: Vec<SqlOption>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeerDBPrivilege {
+    /// `CREATE PEER`
+    CreatePeer,
+    /// `CREATE MIRROR`
+    CreateMirror,
+    /// `USAGE ON PEER name`
+    UsageOnPeer { peer_name: ObjectName },
+    /// `OPERATE ON MIRROR name`
+    OperateOnMirror { mirror_name: ObjectName },
+    /// `READ ONLY`
+    ReadOnly,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterResourceGroupOperation {
+    SetOptions(Vec<SqlOption>),
+    AddUsers(Vec<Ident>),
+    DropUsers(Vec<Ident>),
+}
+
+/// The statements a session listens to and notifies the channels of its
+/// default peer with, which run on the peer as they are written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationStatement {
+    /// `LISTEN channel`
+    Listen { channel: Ident },
+    /// `UNLISTEN channel | *`, `*` is an unquoted `*`
+    Unlisten { channel: Ident },
+    /// `NOTIFY channel [, 'payload']`
+    Notify {
+        channel: Ident,
+        payload: Option<String>,
+    },
+}
+
+// the statements are written back like they are parsed, e.g. for the logs.
+impl fmt::Display for PeerDBStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PeerDBStatement::CreatePeer {
+                if_not_exists,
+                peer_name,
+                peer_type,
+                with_options,
+                validate_only,
+            } => write!(
+                f,
+                "CREATE PEER {}{} FROM {}{}{}",
+                if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                peer_name,
+                peer_type_name(*peer_type),
+                options(with_options),
+                if *validate_only { " VALIDATE ONLY" } else { "" },
+            ),
+            PeerDBStatement::AlterPeer {
+                peer_name,
+                with_options,
+            } => write!(f, "ALTER PEER {}{}", peer_name, options(with_options)),
+            PeerDBStatement::DropPeer {
+                if_exists,
+                peer_name,
+                cascade,
+            } => write!(
+                f,
+                "DROP PEER {}{}{}",
+                if *if_exists { "IF EXISTS " } else { "" },
+                peer_name,
+                if *cascade { " CASCADE" } else { "" },
+            ),
+            PeerDBStatement::ReplacePeer {
+                old_peer_name,
+                new_peer_name,
+            } => write!(f, "REPLACE PEER {} WITH {}", old_peer_name, new_peer_name),
+            PeerDBStatement::ValidatePeer { peer_name } => write!(f, "VALIDATE PEER {}", peer_name),
+            PeerDBStatement::DescribePeer { peer_name } => write!(f, "DESCRIBE PEER {}", peer_name),
+            PeerDBStatement::ShowPeerTables { peer_name, schema } => {
+                write!(f, "SHOW TABLES IN PEER {}", peer_name)?;
+                if let Some(schema) = schema {
+                    write!(f, " SCHEMA {}", schema)?;
+                }
+                Ok(())
+            }
+            PeerDBStatement::ExecuteOnPeer {
+                query_string,
+                peer_name,
+            } => write!(f, "EXECUTE {} ON PEER {}", string(query_string), peer_name),
+            PeerDBStatement::CommentOnPeer { peer_name, comment } => {
+                write!(f, "COMMENT ON PEER {} IS {}", peer_name, nullable(comment))
+            }
+            PeerDBStatement::CreateMirror {
+                if_not_exists,
+                create_mirror,
+            } => {
+                let (mirror_name, source_peer, target_peer) = match create_mirror {
+                    CreateMirror::CDC(cdc) => {
+                        (&cdc.mirror_name, &cdc.source_peer, &cdc.target_peer)
+                    }
+                    CreateMirror::Select(select) => (
+                        &select.mirror_name,
+                        &select.source_peer,
+                        &select.target_peer,
+                    ),
+                };
+                write!(
+                    f,
+                    "CREATE MIRROR {}{} FROM {} TO {}",
+                    if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    mirror_name,
+                    source_peer,
+                    target_peer,
+                )?;
+                match create_mirror {
+                    CreateMirror::CDC(cdc) => write!(
+                        f,
+                        " WITH TABLE MAPPING ({}){}",
+                        comma_separated(&cdc.mapping_options),
+                        options(&cdc.with_options),
+                    ),
+                    CreateMirror::Select(select) => write!(
+                        f,
+                        " FOR $${}$${}",
+                        select.query_string,
+                        options(&select.with_options),
+                    ),
+                }
+            }
+            PeerDBStatement::Explain { statement } => write!(f, "EXPLAIN {}", statement),
+            PeerDBStatement::DropMirror {
+                if_exists,
+                mirror_name,
+                with_options,
+            } => write!(
+                f,
+                "DROP MIRROR {}{}{}",
+                if *if_exists { "IF EXISTS " } else { "" },
+                mirror_name,
+                options(with_options),
+            ),
+            PeerDBStatement::PauseMirror { mirror_name } => {
+                write!(f, "PAUSE MIRROR {}", mirror_name)
+            }
+            PeerDBStatement::ResumeMirror { mirror_name } => {
+                write!(f, "RESUME MIRROR {}", mirror_name)
+            }
+            PeerDBStatement::DescribeMirror { mirror_name } => {
+                write!(f, "DESCRIBE MIRROR {}", mirror_name)
+            }
+            PeerDBStatement::ResyncMirror {
+                mirror_name,
+                table,
+                with_options,
+            } => {
+                write!(f, "RESYNC MIRROR {}", mirror_name)?;
+                if let Some(table) = table {
+                    write!(f, " TABLE {}", table)?;
+                }
+                write!(f, "{}", options(with_options))
+            }
+            PeerDBStatement::AlterMirror {
+                mirror_name,
+                operation,
+            } => write!(f, "ALTER MIRROR {} {}", mirror_name, operation),
+            PeerDBStatement::CommentOnMirror {
+                mirror_name,
+                comment,
+            } => write!(
+                f,
+                "COMMENT ON MIRROR {} IS {}",
+                mirror_name,
+                nullable(comment)
+            ),
+            PeerDBStatement::CreateUser {
+                if_not_exists,
+                name,
+                password,
+            } => write!(
+                f,
+                "CREATE USER {}{} WITH PASSWORD {}",
+                if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                name,
+                string(password),
+            ),
+            PeerDBStatement::AlterUser { name, password } => {
+                write!(f, "ALTER USER {} WITH PASSWORD {}", name, string(password))
+            }
+            PeerDBStatement::DropUser { if_exists, name } => write!(
+                f,
+                "DROP USER {}{}",
+                if *if_exists { "IF EXISTS " } else { "" },
+                name
+            ),
+            PeerDBStatement::Grant {
+                privilege,
+                grantees,
+            } => write!(f, "GRANT {} TO {}", privilege, comma_separated(grantees)),
+            PeerDBStatement::Revoke {
+                privilege,
+                grantees,
+            } => write!(f, "REVOKE {} FROM {}", privilege, comma_separated(grantees)),
+            PeerDBStatement::CreateResourceGroup {
+                if_not_exists,
+                name,
+                with_options,
+            } => write!(
+                f,
+                "CREATE RESOURCE GROUP {}{}{}",
+                if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                name,
+                options(with_options),
+            ),
+            PeerDBStatement::AlterResourceGroup { name, operation } => {
+                write!(f, "ALTER RESOURCE GROUP {}", name)?;
+                match operation {
+                    AlterResourceGroupOperation::SetOptions(with_options) => {
+                        write!(f, "{}", options(with_options))
+                    }
+                    AlterResourceGroupOperation::AddUsers(users) => {
+                        write!(f, " ADD USER {}", comma_separated(users))
+                    }
+                    AlterResourceGroupOperation::DropUsers(users) => {
+                        write!(f, " DROP USER {}", comma_separated(users))
+                    }
+                }
+            }
+            PeerDBStatement::DropResourceGroup { if_exists, name } => write!(
+                f,
+                "DROP RESOURCE GROUP {}{}",
+                if *if_exists { "IF EXISTS " } else { "" },
+                name
+            ),
+            PeerDBStatement::Notification(statement) => write!(f, "{}", statement),
+        }
+    }
+}
+
+impl fmt::Display for MappingOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.partition_key {
+            Some(partition_key) => write!(
+                f,
+                "{{from: {}, to: {}, key: {}}}",
+                self.source, self.destination, partition_key
+            )?,
+            None => write!(f, "{}:{}", self.source, self.destination)?,
+        }
+        if !self.exclude.is_empty() {
+            write!(f, " EXCLUDE ({})", comma_separated(&self.exclude))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AlterMirrorOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterMirrorOperation::AddTable {
+                source_table,
+                destination_table,
+            } => {
+                write!(f, "ADD TABLE {}", source_table)?;
+                if let Some(destination_table) = destination_table {
+                    write!(f, " AS {}", destination_table)?;
+                }
+                Ok(())
+            }
+            AlterMirrorOperation::PauseTable {
+                table,
+                with_options,
+            } => write!(f, "PAUSE TABLE {}{}", table, options(with_options)),
+            AlterMirrorOperation::ResumeTable { table } => write!(f, "RESUME TABLE {}", table),
+            AlterMirrorOperation::DropTable {
+                table,
+                with_options,
+            } => write!(f, "DROP TABLE {}{}", table, options(with_options)),
+        }
+    }
+}
+
+impl fmt::Display for PeerDBPrivilege {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PeerDBPrivilege::CreatePeer => write!(f, "CREATE PEER"),
+            PeerDBPrivilege::CreateMirror => write!(f, "CREATE MIRROR"),
+            PeerDBPrivilege::UsageOnPeer { peer_name } => write!(f, "USAGE ON PEER {}", peer_name),
+            PeerDBPrivilege::OperateOnMirror { mirror_name } => {
+                write!(f, "OPERATE ON MIRROR {}", mirror_name)
+            }
+            PeerDBPrivilege::ReadOnly => write!(f, "READ ONLY"),
+        }
+    }
+}
+
+impl fmt::Display for NotificationStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NotificationStatement::Listen { channel } => write!(f, "LISTEN {}", channel),
+            NotificationStatement::Unlisten { channel } => write!(f, "UNLISTEN {}", channel),
+            NotificationStatement::Notify { channel, payload } => {
+                write!(f, "NOTIFY {}", channel)?;
+                if let Some(payload) = payload {
+                    write!(f, ", {}", string(payload))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn peer_type_name(peer_type: DbType) -> String {
+    PEER_TYPES
+        .iter()
+        .find(|(_, t)| *t == peer_type)
+        .map_or_else(
+            || format!("{:?}", peer_type),
+            |(name, _)| name.to_uppercase(),
+        )
+}
+
+// ` WITH (name = value, ...)`, or nothing for no options.
+fn options(with_options: &[SqlOption]) -> String {
+    if with_options.is_empty() {
+        return String::new();
+    }
+    format!(" WITH ({})", comma_separated(with_options))
+}
+
+fn comma_separated<T: fmt::Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn string(s: &str) -> Value {
+    Value::SingleQuotedString(s.to_owned())
+}
+
+fn nullable(s: &Option<String>) -> String {
+    match s {
+        Some(s) => string(s).to_string(),
+        None => "NULL".to_owned(),
+    }
+}
+
+/// Parses a statement that starts with one of `PEERDB_STATEMENTS`.
+pub fn parse_peerdb_statement(sql: &str) -> Result<PeerDBStatement, ParserError> {
+    let dialect = PostgreSqlDialect {};
+    let mut parser = Parser::new(&dialect).try_with_sql(sql)?;
+    let statement = parse_statement(&mut parser)?;
+    // a statement may end with a semicolon, like those sqlparser parses.
+    while parser.consume_token(&Token::SemiColon) {}
+    match parser.peek_token().token {
+        Token::EOF => Ok(statement),
+        _ => parser.expected("end of statement", parser.peek_token()),
+    }
+}
+
+fn parse_statement(parser: &mut Parser) -> Result<PeerDBStatement, ParserError> {
+    if parser.parse_keyword(Keyword::CREATE) {
+        return parse_create(parser);
+    }
+    if parser.parse_keyword(Keyword::ALTER) {
+        return parse_alter(parser);
+    }
+    if parser.parse_keyword(Keyword::DROP) {
+        return parse_drop(parser);
+    }
+    if parser.parse_keyword(Keyword::REPLACE) {
+        expect_words(parser, &["peer"])?;
+        let old_peer_name = parser.parse_object_name()?;
+        parser.expect_keyword(Keyword::WITH)?;
+        let new_peer_name = parser.parse_object_name()?;
+        return Ok(PeerDBStatement::ReplacePeer {
+            old_peer_name,
+            new_peer_name,
+        });
+    }
+    if parse_word(parser, "validate") {
+        expect_words(parser, &["peer"])?;
+        let peer_name = parser.parse_object_name()?;
+        return Ok(PeerDBStatement::ValidatePeer { peer_name });
+    }
+    if parser.parse_keyword(Keyword::DESCRIBE) {
+        if parse_word(parser, "peer") {
+            let peer_name = parser.parse_object_name()?;
+            return Ok(PeerDBStatement::DescribePeer { peer_name });
+        }
+        expect_words(parser, &["mirror"])?;
+        let mirror_name = parser.parse_object_name()?;
+        return Ok(PeerDBStatement::DescribeMirror { mirror_name });
+    }
+    if parser.parse_keyword(Keyword::SHOW) {
+        parser.expect_keywords(&[Keyword::TABLES, Keyword::IN])?;
+        expect_words(parser, &["peer"])?;
+        let peer_name = parser.parse_object_name()?;
+        let schema = if parser.parse_keyword(Keyword::SCHEMA) {
+            Some(parser.parse_identifier()?)
+        } else {
+            None
+        };
+        return Ok(PeerDBStatement::ShowPeerTables { peer_name, schema });
+    }
+    if parser.parse_keyword(Keyword::EXECUTE) {
+        let query_string = parse_string(parser)?;
+        parser.expect_keyword(Keyword::ON)?;
+        expect_words(parser, &["peer"])?;
+        let peer_name = parser.parse_object_name()?;
+        return Ok(PeerDBStatement::ExecuteOnPeer {
+            query_string,
+            peer_name,
+        });
+    }
+    if parser.parse_keyword(Keyword::COMMENT) {
+        parser.expect_keyword(Keyword::ON)?;
+        let is_peer = parse_word(parser, "peer");
+        if !is_peer {
+            expect_words(parser, &["mirror"])?;
+        }
+        let name = parser.parse_object_name()?;
+        parser.expect_keyword(Keyword::IS)?;
+        let comment = if parser.parse_keyword(Keyword::NULL) {
+            None
+        } else {
+            Some(parse_string(parser)?)
+        };
+        if is_peer {
+            return Ok(PeerDBStatement::CommentOnPeer {
+                peer_name: name,
+                comment,
+            });
+        }
+        return Ok(PeerDBStatement::CommentOnMirror {
+            mirror_name: name,
+            comment,
+        });
+    }
+    if parser.parse_keyword(Keyword::EXPLAIN) {
+        parser.expect_keyword(Keyword::CREATE)?;
+        expect_words(parser, &["mirror"])?;
+        let statement = parse_create_mirror(parser)?;
+        return Ok(PeerDBStatement::Explain {
+            statement: Box::new(statement),
+        });
+    }
+    if parse_word(parser, "pause") {
+        expect_words(parser, &["mirror"])?;
+        let mirror_name = parser.parse_object_name()?;
+        return Ok(PeerDBStatement::PauseMirror { mirror_name });
+    }
+    if parse_word(parser, "resume") {
+        expect_words(parser, &["mirror"])?;
+        let mirror_name = parser.parse_object_name()?;
+        return Ok(PeerDBStatement::ResumeMirror { mirror_name });
+    }
+    if parse_word(parser, "resync") {
+        expect_words(parser, &["mirror"])?;
+        let mirror_name = parser.parse_object_name()?;
+        let table = if parser.parse_keyword(Keyword::TABLE) {
+            Some(parser.parse_object_name()?)
+        } else {
+            None
+        };
+        let with_options = parser.parse_options(Keyword::WITH)?;
+        return Ok(PeerDBStatement::ResyncMirror {
+            mirror_name,
+            table,
+            with_options,
+        });
+    }
+    if parser.parse_keyword(Keyword::GRANT) {
+        let privilege = parse_privilege(parser)?;
+        parser.expect_keyword(Keyword::TO)?;
+        let grantees = parser.parse_comma_separated(Parser::parse_identifier)?;
+        return Ok(PeerDBStatement::Grant {
+            privilege,
+            grantees,
+        });
+    }
+    if parser.parse_keyword(Keyword::REVOKE) {
+        let privilege = parse_privilege(parser)?;
+        parser.expect_keyword(Keyword::FROM)?;
+        let grantees = parser.parse_comma_separated(Parser::parse_identifier)?;
+        return Ok(PeerDBStatement::Revoke {
+            privilege,
+            grantees,
+        });
+    }
+    if parse_word(parser, "listen") {
+        let channel = parser.parse_identifier()?;
+        return Ok(PeerDBStatement::Notification(
+            NotificationStatement::Listen { channel },
+        ));
+    }
+    if parse_word(parser, "unlisten") {
+        let channel = if parser.consume_token(&Token::Mul) {
+            Ident::new("*")
+        } else {
+            parser.parse_identifier()?
+        };
+        return Ok(PeerDBStatement::Notification(
+            NotificationStatement::Unlisten { channel },
+        ));
+    }
+    if parse_word(parser, "notify") {
+        let channel = parser.parse_identifier()?;
+        let payload = if parser.consume_token(&Token::Comma) {
+            Some(parse_string(parser)?)
+        } else {
+            None
+        };
+        return Ok(PeerDBStatement::Notification(
+            NotificationStatement::Notify { channel, payload },
+        ));
+    }
+    parser.expected("a statement", parser.peek_token())
+}
+
+fn parse_create(parser: &mut Parser) -> Result<PeerDBStatement, ParserError> {
+    if parse_word(parser, "peer") {
+        let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let peer_name = parser.parse_object_name()?;
+        parser.expect_keyword(Keyword::FROM)?;
+        let peer_type = parse_peer_type(parser)?;
+        let with_options = parser.parse_options(Keyword::WITH)?;
+        let validate_only = parse_word(parser, "validate");
+        if validate_only {
+            expect_words(parser, &["only"])?;
+        }
+        return Ok(PeerDBStatement::CreatePeer {
+            if_not_exists,
+            peer_name,
+            peer_type,
+            with_options,
+            validate_only,
+        });
+    }
+    if parse_word(parser, "mirror") {
+        return parse_create_mirror(parser);
+    }
+    if parser.parse_keyword(Keyword::USER) {
+        let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = parser.parse_identifier()?;
+        let password = parse_password(parser)?;
+        return Ok(PeerDBStatement::CreateUser {
+            if_not_exists,
+            name,
+            password,
+        });
+    }
+    if parse_word(parser, "resource") {
+        expect_words(parser, &["group"])?;
+        let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = parser.parse_identifier()?;
+        let with_options = parser.parse_options(Keyword::WITH)?;
+        return Ok(PeerDBStatement::CreateResourceGroup {
+            if_not_exists,
+            name,
+            with_options,
+        });
+    }
+    parser.expected("PEER, MIRROR, USER or RESOURCE GROUP", parser.peek_token())
+}
+
+fn parse_alter(parser: &mut Parser) -> Result<PeerDBStatement, ParserError> {
+    if parse_word(parser, "peer") {
+        let peer_name = parser.parse_object_name()?;
+        let keyword = match parser.parse_one_of_keywords(&[Keyword::WITH, Keyword::SET]) {
+            Some(keyword) => keyword,
+            None => return parser.expected("WITH or SET", parser.peek_token()),
+        };
+        parser.prev_token();
+        let with_options = parser.parse_options(keyword)?;
+        return Ok(PeerDBStatement::AlterPeer {
+            peer_name,
+            with_options,
+        });
+    }
+    if parse_word(parser, "mirror") {
+        let mirror_name = parser.parse_object_name()?;
+        let operation = if parser.parse_keywords(&[Keyword::ADD, Keyword::TABLE]) {
+            let source_table = parser.parse_object_name()?;
+            let destination_table = if parser.parse_keyword(Keyword::AS) {
+                Some(parser.parse_object_name()?)
+            } else {
+                None
+            };
+            AlterMirrorOperation::AddTable {
+                source_table,
+                destination_table,
+            }
+        } else if parse_word(parser, "pause") {
+            parser.expect_keyword(Keyword::TABLE)?;
+            let table = parser.parse_object_name()?;
+            let with_options = parser.parse_options(Keyword::WITH)?;
+            AlterMirrorOperation::PauseTable {
+                table,
+                with_options,
+            }
+        } else if parse_word(parser, "resume") {
+            parser.expect_keyword(Keyword::TABLE)?;
+            let table = parser.parse_object_name()?;
+            AlterMirrorOperation::ResumeTable { table }
+        } else if parser.parse_keywords(&[Keyword::DROP, Keyword::TABLE]) {
+            let table = parser.parse_object_name()?;
+            let with_options = parser.parse_options(Keyword::WITH)?;
+            AlterMirrorOperation::DropTable {
+                table,
+                with_options,
+            }
+        } else {
+            return parser.expected(
+                "ADD TABLE, PAUSE TABLE, RESUME TABLE or DROP TABLE",
+                parser.peek_token(),
+            );
+        };
+        return Ok(PeerDBStatement::AlterMirror {
+            mirror_name,
+            operation,
+        });
+    }
+    if parser.parse_keyword(Keyword::USER) {
+        let name = parser.parse_identifier()?;
+        let password = parse_password(parser)?;
+        return Ok(PeerDBStatement::AlterUser { name, password });
+    }
+    if parse_word(parser, "resource") {
+        expect_words(parser, &["group"])?;
+        let name = parser.parse_identifier()?;
+        let operation = if parser.parse_keyword(Keyword::WITH) {
+            parser.prev_token();
+            AlterResourceGroupOperation::SetOptions(parser.parse_options(Keyword::WITH)?)
+        } else if parser.parse_keywords(&[Keyword::ADD, Keyword::USER]) {
+            AlterResourceGroupOperation::AddUsers(
+                parser.parse_comma_separated(Parser::parse_identifier)?,
+            )
+        } else if parser.parse_keywords(&[Keyword::DROP, Keyword::USER]) {
+            AlterResourceGroupOperation::DropUsers(
+                parser.parse_comma_separated(Parser::parse_identifier)?,
+            )
+        } else {
+            return parser.expected("WITH, ADD USER or DROP USER", parser.peek_token());
+        };
+        return Ok(PeerDBStatement::AlterResourceGroup { name, operation });
+    }
+    parser.expected("PEER, MIRROR, USER or RESOURCE GROUP", parser.peek_token())
+}
+
+fn parse_drop(parser: &mut Parser) -> Result<PeerDBStatement, ParserError> {
+    if parse_word(parser, "peer") {
+        let if_exists = parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let peer_name = parser.parse_object_name()?;
+        let cascade = parser.parse_keyword(Keyword::CASCADE);
+        return Ok(PeerDBStatement::DropPeer {
+            if_exists,
+            peer_name,
+            cascade,
+        });
+    }
+    if parse_word(parser, "mirror") {
+        let if_exists = parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let mirror_name = parser.parse_object_name()?;
+        let with_options = parser.parse_options(Keyword::WITH)?;
+        return Ok(PeerDBStatement::DropMirror {
+            if_exists,
+            mirror_name,
+            with_options,
+        });
+    }
+    if parser.parse_keyword(Keyword::USER) {
+        let if_exists = parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = parser.parse_identifier()?;
+        return Ok(PeerDBStatement::DropUser { if_exists, name });
+    }
+    if parse_word(parser, "resource") {
+        expect_words(parser, &["group"])?;
+        let if_exists = parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = parser.parse_identifier()?;
+        return Ok(PeerDBStatement::DropResourceGroup { if_exists, name });
+    }
+    parser.expected("PEER, MIRROR, USER or RESOURCE GROUP", parser.peek_token())
+}
+
+// the rest of a `CREATE MIRROR`, past its first two words.
+fn parse_create_mirror(parser: &mut Parser) -> Result<PeerDBStatement, ParserError> {
+    let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    let mirror_name = parser.parse_object_name()?;
+    parser.expect_keyword(Keyword::FROM)?;
+    let source_peer = parser.parse_object_name()?;
+    parser.expect_keyword(Keyword::TO)?;
+    let target_peer = parser.parse_object_name()?;
+
+    let create_mirror = if parser.parse_keyword(Keyword::WITH) {
+        parser.expect_keyword(Keyword::TABLE)?;
+        expect_words(parser, &["mapping"])?;
+        parser.expect_token(&Token::LParen)?;
+        let mapping_options = parser.parse_comma_separated(parse_mapping_options)?;
+        parser.expect_token(&Token::RParen)?;
+        let with_options = parser.parse_options(Keyword::WITH)?;
+        CreateMirror::CDC(CreateMirrorForCDC {
+            mirror_name,
+            source_peer,
+            target_peer,
+            mapping_options,
+            with_options,
+        })
+    } else if parser
+        .parse_one_of_keywords(&[Keyword::FOR, Keyword::AS])
+        .is_some()
+    {
+        let query_string = parse_string(parser)?;
+        let with_options = parser.parse_options(Keyword::WITH)?;
+        CreateMirror::Select(CreateMirrorForSelect {
+            mirror_name,
+            source_peer,
+            target_peer,
+            query_string,
+            with_options,
+        })
+    } else {
+        return parser.expected("WITH TABLE MAPPING, FOR or AS", parser.peek_token());
+    };
+    Ok(PeerDBStatement::CreateMirror {
+        if_not_exists,
+        create_mirror,
+    })
+}
+
+fn parse_mapping_options(parser: &mut Parser) -> Result<MappingOptions, ParserError> {
+    let (source, destination, partition_key) = if parser.consume_token(&Token::LBrace) {
+        let mut source = None;
+        let mut destination = None;
+        let mut partition_key = None;
+        loop {
+            let key = parser.parse_identifier()?;
+            parser.expect_token(&Token::Colon)?;
+            match key.value.to_lowercase().as_str() {
+                "from" => source = Some(parse_table_name(parser)?),
+                "to" => destination = Some(parse_table_name(parser)?),
+                "key" => partition_key = Some(parser.parse_identifier()?),
+                _ => return parser.expected("from, to or key", parser.peek_token()),
+            }
+            if !parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        parser.expect_token(&Token::RBrace)?;
+        match (source, destination) {
+            (Some(source), Some(destination)) => (source, destination, partition_key),
+            _ => return parser.expected("from and to", parser.peek_token()),
+        }
+    } else {
+        let source = parse_table_name(parser)?;
+        if !parser.consume_token(&Token::Colon) && !parser.consume_token(&Token::RArrow) {
+            return parser.expected(": or =>", parser.peek_token());
+        }
+        let destination = parse_table_name(parser)?;
+        (source, destination, None)
+    };
+    let exclude = if parse_word(parser, "exclude") {
+        parser.expect_token(&Token::LParen)?;
+        let exclude = parser.parse_comma_separated(Parser::parse_identifier)?;
+        parser.expect_token(&Token::RParen)?;
+        exclude
+    } else {
+        vec![]
+    };
+    Ok(MappingOptions {
+        source,
+        destination,
+        partition_key,
+        exclude,
+    })
+}
+
+// a table name, which may end in an unquoted `*` for the tables of a schema.
+fn parse_table_name(parser: &mut Parser) -> Result<ObjectName, ParserError> {
+    let mut idents = vec![];
+    loop {
+        if parser.consume_token(&Token::Mul) {
+            idents.push(Ident::new("*"));
+            break;
+        }
+        idents.push(parser.parse_identifier()?);
+        if !parser.consume_token(&Token::Period) {
+            break;
+        }
+    }
+    Ok(ObjectName(idents))
+}
+
+fn parse_peer_type(parser: &mut Parser) -> Result<DbType, ParserError> {
+    let token = parser.next_token();
+    let peer_type = match &token.token {
+        Token::Word(w) => PEER_TYPES
+            .iter()
+            .find(|(name, _)| w.value.eq_ignore_ascii_case(name))
+            .map(|(_, peer_type)| *peer_type),
+        _ => None,
+    };
+    match peer_type {
+        Some(peer_type) => Ok(peer_type),
+        None => parser.expected("a peer type", token),
+    }
+}
+
+fn parse_privilege(parser: &mut Parser) -> Result<PeerDBPrivilege, ParserError> {
+    if parser.parse_keyword(Keyword::CREATE) {
+        if parse_word(parser, "peer") {
+            return Ok(PeerDBPrivilege::CreatePeer);
+        }
+        expect_words(parser, &["mirror"])?;
+        return Ok(PeerDBPrivilege::CreateMirror);
+    }
+    if parse_word(parser, "usage") {
+        parser.expect_keyword(Keyword::ON)?;
+        expect_words(parser, &["peer"])?;
+        let peer_name = parser.parse_object_name()?;
+        return Ok(PeerDBPrivilege::UsageOnPeer { peer_name });
+    }
+    if parse_word(parser, "operate") {
+        parser.expect_keyword(Keyword::ON)?;
+        expect_words(parser, &["mirror"])?;
+        let mirror_name = parser.parse_object_name()?;
+        return Ok(PeerDBPrivilege::OperateOnMirror { mirror_name });
+    }
+    if parse_word(parser, "read") {
+        expect_words(parser, &["only"])?;
+        return Ok(PeerDBPrivilege::ReadOnly);
+    }
+    parser.expected(
+        "CREATE PEER, CREATE MIRROR, USAGE ON PEER, OPERATE ON MIRROR or READ ONLY",
+        parser.peek_token(),
+    )
+}
+
+// `WITH PASSWORD 'password'` of a user.
+fn parse_password(parser: &mut Parser) -> Result<String, ParserError> {
+    parser.expect_keyword(Keyword::WITH)?;
+    expect_words(parser, &["password"])?;
+    parse_string(parser)
+}
+
+// a string literal, quoted or dollar quoted like the queries of mirrors.
+fn parse_string(parser: &mut Parser) -> Result<String, ParserError> {
+    let token = parser.next_token();
+    match token.token {
+        Token::SingleQuotedString(s) => Ok(s),
+        Token::DollarQuotedString(s) => Ok(s.value),
+        _ => parser.expected("a string", token),
+    }
+}
+
+// the words of nexus that aren't keywords of sqlparser, unquoted and in any case.
+fn parse_word(parser: &mut Parser, word: &str) -> bool {
+    match parser.peek_token().token {
+        Token::Word(w) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(word) => {
+            parser.next_token();
+            true
+        }
+        _ => false,
+    }
+}
+
+fn expect_words(parser: &mut Parser, words: &[&str]) -> Result<(), ParserError> {
+    for word in words {
+        if !parse_word(parser, word) {
+            return parser.expected(&word.to_uppercase(), parser.peek_token());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // checks that a statement parses, and that it is written back as it is.
+    fn round_trip(sql: &str) -> PeerDBStatement {
+        let statement = parse_peerdb_statement(sql).unwrap();
+        assert_eq!(statement.to_string(), sql);
+        statement
+    }
+
+    // checks that a statement parses like the one written as `canonical`.
+    fn parses_as(sql: &str, canonical: &str) {
+        assert_eq!(parse_peerdb_statement(sql).unwrap(), round_trip(canonical));
+    }
+
+    fn parse_error(sql: &str) -> String {
+        parse_peerdb_statement(sql).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn create_peer() {
+        let statement = round_trip(
+            "CREATE PEER IF NOT EXISTS pg FROM POSTGRES WITH (host = 'localhost', port = 5432)",
+        );
+        match statement {
+            PeerDBStatement::CreatePeer {
+                if_not_exists,
+                peer_name,
+                peer_type,
+                with_options,
+                ..
+            } => {
+                assert!(if_not_exists);
+                assert_eq!(peer_name.to_string(), "pg");
+                assert_eq!(peer_type, DbType::Postgres);
+                assert_eq!(with_options.len(), 2);
+            }
+            _ => panic!("not a CREATE PEER: {:?}", statement),
+        }
+        round_trip("CREATE PEER sf FROM SNOWFLAKE");
+        round_trip("CREATE PEER pc FROM PINECONE WITH (api_key = 'key')");
+        round_trip("CREATE PEER wv FROM WEAVIATE WITH (url = 'http://localhost:8080')");
+        round_trip("CREATE PEER files FROM FILE WITH (path = '/data', format = 'csv')");
+        round_trip("CREATE PEER hook FROM WEBHOOK WITH (url = 'https://example.com/changes')");
+        parses_as(
+            "create peer pg from postgres;",
+            "CREATE PEER pg FROM POSTGRES",
+        );
+
+        assert!(parse_error("CREATE PEER pg FROM oracle").contains("Expected a peer type"));
+        assert!(parse_error("CREATE PEER pg TO POSTGRES").contains("Expected FROM"));
+        assert!(parse_error("CREATE PEER pg FROM POSTGRES x").contains("Expected end of statement"));
+    }
+
+    #[test]
+    fn validate_peer() {
+        let statement = round_trip("CREATE PEER pg FROM POSTGRES WITH (host = 'h') VALIDATE ONLY");
+        assert!(matches!(
+            statement,
+            PeerDBStatement::CreatePeer {
+                validate_only: true,
+                ..
+            }
+        ));
+        round_trip("VALIDATE PEER pg");
+
+        assert!(parse_error("CREATE PEER pg FROM POSTGRES VALIDATE").contains("Expected ONLY"));
+        assert!(parse_error("VALIDATE pg").contains("Expected PEER"));
+    }
+
+    #[test]
+    fn alter_peer() {
+        round_trip("ALTER PEER pg WITH (database = 'postgres')");
+        parses_as(
+            "ALTER PEER pg SET (database = 'postgres')",
+            "ALTER PEER pg WITH (database = 'postgres')",
+        );
+
+        assert!(
+            parse_error("ALTER PEER pg (database = 'postgres')").contains("Expected WITH or SET")
+        );
+        assert!(
+            parse_error("ALTER TABLE t").contains("Expected PEER, MIRROR, USER or RESOURCE GROUP")
+        );
+    }
+
+    #[test]
+    fn drop_peer() {
+        round_trip("DROP PEER pg");
+        let statement = round_trip("DROP PEER IF EXISTS pg CASCADE");
+        assert_eq!(
+            statement,
+            PeerDBStatement::DropPeer {
+                if_exists: true,
+                peer_name: ObjectName(vec![Ident::new("pg")]),
+                cascade: true,
+            }
+        );
+
+        assert!(parse_error("DROP PEER").contains("Expected identifier"));
+        assert!(parse_error("DROP PEER pg RESTRICT").contains("Expected end of statement"));
+    }
+
+    #[test]
+    fn replace_peer() {
+        round_trip("REPLACE PEER pg WITH pg_replica");
+
+        assert!(parse_error("REPLACE PEER pg pg_replica").contains("Expected WITH"));
+        assert!(parse_error("REPLACE MIRROR m WITH n").contains("Expected PEER"));
+    }
+
+    #[test]
+    fn describe_peer() {
+        round_trip("DESCRIBE PEER pg");
+        round_trip("DESCRIBE PEER pg.public.orders");
+
+        assert!(parse_error("DESCRIBE PEER").contains("Expected identifier"));
+    }
+
+    #[test]
+    fn show_peer_tables() {
+        round_trip("SHOW TABLES IN PEER pg");
+        let statement = round_trip("SHOW TABLES IN PEER pg SCHEMA sales");
+        assert_eq!(
+            statement,
+            PeerDBStatement::ShowPeerTables {
+                peer_name: ObjectName(vec![Ident::new("pg")]),
+                schema: Some(Ident::new("sales")),
+            }
+        );
+
+        assert!(parse_error("SHOW TABLES IN pg").contains("Expected PEER"));
+        assert!(parse_error("SHOW TABLES IN PEER pg sales").contains("Expected end of statement"));
+    }
+
+    #[test]
+    fn execute_on_peer() {
+        let statement = round_trip("EXECUTE 'SELECT ''a'' || 1' ON PEER pg");
+        assert_eq!(
+            statement,
+            PeerDBStatement::ExecuteOnPeer {
+                query_string: "SELECT 'a' || 1".to_owned(),
+                peer_name: ObjectName(vec![Ident::new("pg")]),
+            }
+        );
+        parses_as(
+            "EXECUTE $$SELECT 'a' || 1$$ ON PEER pg",
+            "EXECUTE 'SELECT ''a'' || 1' ON PEER pg",
+        );
+
+        assert!(parse_error("EXECUTE 'SELECT 1' ON pg").contains("Expected PEER"));
+        assert!(parse_error("EXECUTE 'SELECT 1' PEER pg").contains("Expected ON"));
+    }
+
+    #[test]
+    fn comment_on_peer_and_mirror() {
+        round_trip("COMMENT ON PEER pg IS 'orders database'");
+        round_trip("COMMENT ON PEER pg IS NULL");
+        round_trip("COMMENT ON MIRROR orders IS 'orders to the warehouse'");
+        let statement = round_trip("COMMENT ON MIRROR orders IS NULL");
+        assert_eq!(
+            statement,
+            PeerDBStatement::CommentOnMirror {
+                mirror_name: ObjectName(vec![Ident::new("orders")]),
+                comment: None,
+            }
+        );
+
+        assert!(parse_error("COMMENT ON PEER pg 'orders'").contains("Expected IS"));
+        assert!(parse_error("COMMENT ON PEER pg IS 1").contains("Expected a string"));
+    }
+
+    #[test]
+    fn create_mirror() {
+        let statement = round_trip(
+            "CREATE MIRROR IF NOT EXISTS orders FROM pg TO sf WITH TABLE MAPPING \
+             (public.orders:public.orders_raw EXCLUDE (notes, audit), \
+             {from: public.items, to: public.items, key: id}) WITH (do_initial_copy = true)",
+        );
+        let cdc = match statement {
+            PeerDBStatement::CreateMirror {
+                if_not_exists: true,
+                create_mirror: CreateMirror::CDC(cdc),
+            } => cdc,
+            _ => panic!("not a CREATE MIRROR for CDC: {:?}", statement),
+        };
+        assert_eq!(cdc.mapping_options.len(), 2);
+        assert_eq!(
+            cdc.mapping_options[0].exclude,
+            vec![Ident::new("notes"), Ident::new("audit")]
+        );
+        assert_eq!(cdc.mapping_options[1].partition_key, Some(Ident::new("id")));
+        round_trip(
+            "CREATE MIRROR sync FROM pg TO sf FOR $$SELECT * FROM t WHERE id > {{.start}}$$ \
+             WITH (watermark_column = 'id')",
+        );
+
+        assert!(
+            parse_error("CREATE MIRROR m FROM pg TO sf WITH TABLE MAPPING (a)")
+                .contains("Expected : or =>")
+        );
+        assert!(
+            parse_error("CREATE MIRROR m FROM pg TO sf WITH TABLE MAPPING ({from: a})")
+                .contains("Expected from and to")
+        );
+        assert!(
+            parse_error("CREATE MIRROR m FROM pg TO sf WITH TABLE MAPPING ({into: a})")
+                .contains("Expected from, to or key")
+        );
+        assert!(
+            parse_error("CREATE MIRROR m FROM pg TO sf").contains("Expected WITH TABLE MAPPING")
+        );
+    }
+
+    #[test]
+    fn create_mirror_with_arrow_mappings() {
+        parses_as(
+            "CREATE MIRROR m FROM pg TO sf WITH TABLE MAPPING (public.orders => analytics.orders_raw)",
+            "CREATE MIRROR m FROM pg TO sf WITH TABLE MAPPING (public.orders:analytics.orders_raw)",
+        );
+    }
+
+    #[test]
+    fn create_mirror_as_string() {
+        parses_as(
+            "CREATE MIRROR sync FROM pg TO sf AS 'SELECT * FROM t' WITH (refresh_interval = 60)",
+            "CREATE MIRROR sync FROM pg TO sf FOR $$SELECT * FROM t$$ WITH (refresh_interval = 60)",
+        );
+
+        assert!(parse_error("CREATE MIRROR sync FROM pg TO sf AS SELECT 1")
+            .contains("Expected a string"));
+    }
+
+    #[test]
+    fn create_mirror_for_schemas() {
+        let statement = round_trip(
+            "CREATE MIRROR m FROM pg TO sf WITH TABLE MAPPING (public.*:analytics.* EXCLUDE (audit))",
+        );
+        match statement {
+            PeerDBStatement::CreateMirror {
+                create_mirror: CreateMirror::CDC(cdc),
+                ..
+            } => assert_eq!(
+                cdc.mapping_options[0].source,
+                ObjectName(vec![Ident::new("public"), Ident::new("*")])
+            ),
+            _ => panic!("not a CREATE MIRROR for CDC: {:?}", statement),
+        }
+        // a quoted `*` is a table named `*`.
+        let statement = parse_peerdb_statement(
+            "CREATE MIRROR m FROM pg TO sf WITH TABLE MAPPING (public.\"*\":s.t)",
+        )
+        .unwrap();
+        match statement {
+            PeerDBStatement::CreateMirror {
+                create_mirror: CreateMirror::CDC(cdc),
+                ..
+            } => assert_eq!(cdc.mapping_options[0].source.0[1].quote_style, Some('"')),
+            _ => panic!("not a CREATE MIRROR for CDC: {:?}", statement),
+        }
+    }
+
+    #[test]
+    fn explain_create_mirror() {
+        let statement = round_trip(
+            "EXPLAIN CREATE MIRROR m FROM pg TO sf WITH TABLE MAPPING (public.t:public.t)",
+        );
+        assert!(matches!(
+            statement,
+            PeerDBStatement::Explain { statement } if matches!(*statement, PeerDBStatement::CreateMirror { .. })
+        ));
+
+        assert!(parse_error("EXPLAIN CREATE PEER pg FROM POSTGRES").contains("Expected MIRROR"));
+    }
+
+    #[test]
+    fn drop_mirror() {
+        round_trip("DROP MIRROR orders");
+        let statement =
+            round_trip("DROP MIRROR IF EXISTS orders WITH (drop_destination_tables = true)");
+        match statement {
+            PeerDBStatement::DropMirror {
+                if_exists,
+                with_options,
+                ..
+            } => {
+                assert!(if_exists);
+                assert_eq!(with_options[0].name, Ident::new("drop_destination_tables"));
+            }
+            _ => panic!("not a DROP MIRROR: {:?}", statement),
+        }
+
+        assert!(
+            parse_error("DROP MIRROR orders WITH drop_destination_tables").contains("Expected (")
+        );
+        assert!(
+            parse_error("DROP TABLE t").contains("Expected PEER, MIRROR, USER or RESOURCE GROUP")
+        );
+    }
+
+    #[test]
+    fn pause_and_resume_mirror() {
+        round_trip("PAUSE MIRROR orders");
+        round_trip("RESUME MIRROR orders");
+
+        assert!(parse_error("PAUSE orders").contains("Expected MIRROR"));
+        assert!(parse_error("RESUME MIRROR").contains("Expected identifier"));
+    }
+
+    #[test]
+    fn describe_mirror() {
+        round_trip("DESCRIBE MIRROR orders");
+
+        assert!(parse_error("DESCRIBE MIRROR orders now").contains("Expected end of statement"));
+    }
+
+    #[test]
+    fn resync_mirror() {
+        round_trip("RESYNC MIRROR orders");
+        let statement = round_trip("RESYNC MIRROR orders TABLE public.orders");
+        match statement {
+            PeerDBStatement::ResyncMirror { table, .. } => {
+                assert_eq!(
+                    table.map(|table| table.to_string()),
+                    Some("public.orders".to_owned())
+                )
+            }
+            _ => panic!("not a RESYNC MIRROR: {:?}", statement),
+        }
+        round_trip("RESYNC MIRROR orders WITH (truncate_destination_table = true)");
+
+        assert!(parse_error("RESYNC MIRROR orders TABLE").contains("Expected identifier"));
+        assert!(parse_error("RESYNC orders").contains("Expected MIRROR"));
+    }
+
+    #[test]
+    fn alter_mirror() {
+        round_trip("ALTER MIRROR orders ADD TABLE public.items");
+        let statement =
+            round_trip("ALTER MIRROR orders ADD TABLE public.items AS public.items_raw");
+        assert!(matches!(
+            statement,
+            PeerDBStatement::AlterMirror {
+                operation: AlterMirrorOperation::AddTable {
+                    destination_table: Some(_),
+                    ..
+                },
+                ..
+            }
+        ));
+        round_trip("ALTER MIRROR orders PAUSE TABLE public.items WITH (policy = 'buffer')");
+        round_trip("ALTER MIRROR orders RESUME TABLE public.items");
+        round_trip(
+            "ALTER MIRROR orders DROP TABLE public.items WITH (truncate_destination_table = true)",
+        );
+
+        assert!(parse_error("ALTER MIRROR orders RENAME TO o")
+            .contains("Expected ADD TABLE, PAUSE TABLE, RESUME TABLE or DROP TABLE"));
+        assert!(parse_error("ALTER MIRROR orders ADD TABLE").contains("Expected identifier"));
+    }
+
+    #[test]
+    fn users() {
+        let statement = round_trip("CREATE USER IF NOT EXISTS alice WITH PASSWORD 'it''s secret'");
+        assert_eq!(
+            statement,
+            PeerDBStatement::CreateUser {
+                if_not_exists: true,
+                name: Ident::new("alice"),
+                password: "it's secret".to_owned(),
+            }
+        );
+        round_trip("DROP USER IF EXISTS alice");
+        round_trip("ALTER USER alice WITH PASSWORD 'new secret'");
+
+        assert!(parse_error("CREATE USER alice PASSWORD 'secret'").contains("Expected WITH"));
+        assert!(parse_error("CREATE USER alice WITH PASSWORD secret").contains("Expected a string"));
+    }
+
+    #[test]
+    fn grant_and_revoke() {
+        round_trip("GRANT CREATE PEER TO alice, bob");
+        round_trip("GRANT CREATE MIRROR TO alice");
+        round_trip("GRANT USAGE ON PEER pg TO alice");
+        let statement = round_trip("REVOKE OPERATE ON MIRROR orders FROM bob");
+        assert_eq!(
+            statement,
+            PeerDBStatement::Revoke {
+                privilege: PeerDBPrivilege::OperateOnMirror {
+                    mirror_name: ObjectName(vec![Ident::new("orders")]),
+                },
+                grantees: vec![Ident::new("bob")],
+            }
+        );
+        round_trip("GRANT READ ONLY TO alice");
+        round_trip("REVOKE READ ONLY FROM alice");
+
+        assert!(parse_error("GRANT USAGE ON PEER pg alice").contains("Expected TO"));
+        assert!(parse_error("REVOKE CREATE PEER TO alice").contains("Expected FROM"));
+    }
+
+    #[test]
+    fn resource_groups() {
+        round_trip(
+            "CREATE RESOURCE GROUP IF NOT EXISTS etl WITH (weight = 2, max_concurrency = 4)",
+        );
+        round_trip("ALTER RESOURCE GROUP etl WITH (max_concurrency = NULL)");
+        let statement = round_trip("ALTER RESOURCE GROUP etl ADD USER alice, bob");
+        assert_eq!(
+            statement,
+            PeerDBStatement::AlterResourceGroup {
+                name: Ident::new("etl"),
+                operation: AlterResourceGroupOperation::AddUsers(vec![
+                    Ident::new("alice"),
+                    Ident::new("bob")
+                ]),
+            }
+        );
+        round_trip("ALTER RESOURCE GROUP etl DROP USER bob");
+        round_trip("DROP RESOURCE GROUP IF EXISTS etl");
+
+        assert!(parse_error("CREATE RESOURCE etl").contains("Expected GROUP"));
+        assert!(parse_error("ALTER RESOURCE GROUP etl SET (weight = 2)")
+            .contains("Expected WITH, ADD USER or DROP USER"));
+    }
+
+    #[test]
+    fn notifications() {
+        round_trip("LISTEN peerdb_events");
+        let statement = round_trip("UNLISTEN *");
+        assert_eq!(
+            statement,
+            PeerDBStatement::Notification(NotificationStatement::Unlisten {
+                channel: Ident::new("*"),
+            })
+        );
+        round_trip("NOTIFY peerdb_events");
+        round_trip("NOTIFY peerdb_events, 'mirror created'");
+
+        assert!(parse_error("NOTIFY peerdb_events, 1").contains("Expected a string"));
+        assert!(parse_error("LISTEN").contains("Expected identifier"));
+    }
+}
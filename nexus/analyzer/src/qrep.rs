@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::ControlFlow};
 
 use serde_json::Value;
-use sqlparser::ast::Value as SqlValue;
+use sqlparser::{ast::visit_relations, ast::Value as SqlValue, dialect::PostgreSqlDialect};
+
+use crate::options::unknown_option_error;
 
 enum QRepOptionType {
     String {
@@ -24,6 +26,12 @@ enum QRepOptionType {
     StringArray {
         name: &'static str,
     },
+    // a number of seconds, or an interval like '15 minutes'.
+    Interval {
+        name: &'static str,
+        min_seconds: u32,
+        default_seconds: u32,
+    },
 }
 
 lazy_static::lazy_static! {
@@ -68,17 +76,28 @@ lazy_static::lazy_static! {
             required: false,
             accepted_values: None,
         },
+        QRepOptionType::String {
+            name: "staging_integration",
+            default_val: None,
+            required: false,
+            accepted_values: None,
+        },
         QRepOptionType::Int {
             name: "parallelism",
             min_value: Some(1),
             default_value: 2,
             required: false,
         },
-        QRepOptionType::Int {
+        QRepOptionType::Interval {
             name: "refresh_interval",
-            min_value: Some(10),
-            default_value: 10,
+            min_seconds: 10,
+            default_seconds: 10,
+        },
+        QRepOptionType::String {
+            name: "schedule",
+            default_val: None,
             required: false,
+            accepted_values: None,
         },
         QRepOptionType::Int {
             name: "batch_size_int",
@@ -98,6 +117,12 @@ lazy_static::lazy_static! {
             default_value: 0,
             required: false,
         },
+        QRepOptionType::Int {
+            name: "refresh_statistics_threshold",
+            min_value: Some(0),
+            default_value: 0,
+            required: false,
+        },
         QRepOptionType::Boolean {
             name: "initial_copy_only",
             default_value: false,
@@ -111,11 +136,42 @@ lazy_static::lazy_static! {
     };
 }
 
+impl QRepOptionType {
+    fn name(&self) -> &'static str {
+        match self {
+            QRepOptionType::String { name, .. }
+            | QRepOptionType::Int { name, .. }
+            | QRepOptionType::Boolean { name, .. }
+            | QRepOptionType::StringArray { name }
+            | QRepOptionType::Interval { name, .. } => name,
+        }
+    }
+}
+
 pub fn process_options(
     raw_opts: HashMap<&str, &SqlValue>,
 ) -> anyhow::Result<HashMap<String, Value>> {
     let mut opts: HashMap<String, Value> = HashMap::new();
 
+    for name in raw_opts.keys() {
+        if !QREP_OPTIONS.iter().any(|opt_type| opt_type.name() == *name) {
+            let valid_options = QREP_OPTIONS
+                .iter()
+                .map(QRepOptionType::name)
+                .chain(std::iter::once("disabled"));
+            return Err(unknown_option_error(
+                "CREATE MIRROR ... AS SELECT",
+                name,
+                valid_options,
+            ));
+        }
+    }
+
+    // a mirror either runs every refresh_interval or on its schedule.
+    if raw_opts.contains_key("refresh_interval") && raw_opts.contains_key("schedule") {
+        anyhow::bail!("refresh_interval and schedule can't both be set");
+    }
+
     for opt_type in &*QREP_OPTIONS {
         match opt_type {
             QRepOptionType::String {
@@ -198,8 +254,214 @@ pub fn process_options(
                     opts.insert((*name).to_string(), Value::Bool(v));
                 }
             }
+            QRepOptionType::Interval {
+                name,
+                min_seconds,
+                default_seconds,
+            } => {
+                let seconds = match raw_opts.get(*name) {
+                    Some(SqlValue::Number(num_str, _)) => num_str.parse::<u32>()?,
+                    Some(SqlValue::SingleQuotedString(str)) => parse_interval(name, str)?,
+                    Some(_) => anyhow::bail!("Invalid value for {}", name),
+                    None => *default_seconds,
+                };
+                if seconds < *min_seconds {
+                    anyhow::bail!("{} must be at least {} seconds", name, min_seconds);
+                }
+                opts.insert((*name).to_string(), Value::Number(seconds.into()));
+            }
+        }
+    }
+
+    if let Some(Value::String(schedule)) = opts.get("schedule") {
+        check_schedule(schedule)?;
+        if opts.get("initial_copy_only") == Some(&Value::Bool(true)) {
+            anyhow::bail!("schedule can't be set with initial_copy_only");
         }
     }
 
     Ok(opts)
 }
+
+// an interval of one or more amounts with units, like '15 minutes' or
+// '1h 30min', an amount without a unit is in seconds.
+fn parse_interval(name: &str, interval: &str) -> anyhow::Result<u32> {
+    let mut rest = interval.trim();
+    if rest.is_empty() {
+        anyhow::bail!("{} must not be empty", name);
+    }
+    let mut seconds: u64 = 0;
+    while !rest.is_empty() {
+        let amount_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if amount_end == 0 {
+            anyhow::bail!("invalid value for {}: \"{}\"", name, interval);
+        }
+        let amount: u64 = rest[..amount_end].parse()?;
+        rest = rest[amount_end..].trim_start();
+        let unit_end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let unit_seconds = match rest[..unit_end].to_lowercase().as_str() {
+            "" | "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "h" | "hour" | "hours" => 60 * 60,
+            "d" | "day" | "days" => 24 * 60 * 60,
+            unit => anyhow::bail!(
+                "invalid unit for {}: \"{}\", valid units are seconds, minutes, hours and days",
+                name,
+                unit
+            ),
+        };
+        seconds = seconds.saturating_add(amount.saturating_mul(unit_seconds));
+        rest = rest[unit_end..].trim_start();
+    }
+    u32::try_from(seconds).map_err(|_| anyhow::anyhow!("{} is too long: \"{}\"", name, interval))
+}
+
+const SCHEDULE_DESCRIPTORS: &[&str] = &[
+    "@yearly",
+    "@annually",
+    "@monthly",
+    "@weekly",
+    "@daily",
+    "@midnight",
+    "@hourly",
+];
+
+// the fields of a cron schedule, with the values each of them takes.
+const SCHEDULE_FIELDS: &[(&str, u32, u32)] = &[
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day of month", 1, 31),
+    ("month", 1, 12),
+    ("day of week", 0, 6),
+];
+
+// checks a cron schedule of five fields, each a list of *, values or ranges
+// of values with an optional step, or one of the descriptors like @daily.
+fn check_schedule(schedule: &str) -> anyhow::Result<()> {
+    let schedule = schedule.trim();
+    if SCHEDULE_DESCRIPTORS.contains(&schedule) {
+        return Ok(());
+    }
+    let fields: Vec<&str> = schedule.split_whitespace().collect();
+    if fields.len() != SCHEDULE_FIELDS.len() {
+        anyhow::bail!(
+            "schedule must be a cron expression of {} fields or one of {:?}",
+            SCHEDULE_FIELDS.len(),
+            SCHEDULE_DESCRIPTORS
+        );
+    }
+    for (field, &(field_name, min, max)) in fields.iter().zip(SCHEDULE_FIELDS) {
+        for part in field.split(',') {
+            check_schedule_part(part, min, max).map_err(|err| {
+                anyhow::anyhow!("invalid {} \"{}\" in schedule: {}", field_name, field, err)
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn check_schedule_part(part: &str, min: u32, max: u32) -> anyhow::Result<()> {
+    let (range, step) = match part.split_once('/') {
+        Some((range, step)) => (range, Some(step)),
+        None => (part, None),
+    };
+    if let Some(step) = step {
+        match step.parse::<u32>() {
+            Ok(step) if step > 0 => {}
+            _ => anyhow::bail!("the step must be a positive integer"),
+        }
+    }
+    if range == "*" {
+        return Ok(());
+    }
+    let value = |value: &str| -> anyhow::Result<u32> {
+        match value.parse::<u32>() {
+            Ok(value) if value >= min && value <= max => Ok(value),
+            _ => anyhow::bail!("values must be between {} and {}", min, max),
+        }
+    };
+    match range.split_once('-') {
+        Some((start, end)) => {
+            if value(start)? > value(end)? {
+                anyhow::bail!("ranges must not end before they start");
+            }
+        }
+        None => {
+            value(range)?;
+        }
+    }
+    Ok(())
+}
+
+const START_PLACEHOLDER: &str = "{{.start}}";
+const END_PLACEHOLDER: &str = "{{.end}}";
+
+// checks the templated query of a mirror against its options: a query that
+// pulls partitions of the watermark column filters on {{.start}} and
+// {{.end}}, which the flow fills in with the bounds of each partition. the
+// watermark table is taken from the query when it reads a single table.
+pub fn check_query(query: &str, opts: &mut HashMap<String, Value>) -> anyhow::Result<()> {
+    let has_start = query.contains(START_PLACEHOLDER);
+    let has_end = query.contains(END_PLACEHOLDER);
+    if has_start != has_end {
+        anyhow::bail!(
+            "the query must filter on both {} and {}",
+            START_PLACEHOLDER,
+            END_PLACEHOLDER
+        );
+    }
+
+    let watermark_column = opts.get("watermark_column").and_then(Value::as_str);
+    match (watermark_column, has_start) {
+        (None, true) => anyhow::bail!(
+            "watermark_column is required for a query that filters on {} and {}",
+            START_PLACEHOLDER,
+            END_PLACEHOLDER
+        ),
+        (Some(column), false) => anyhow::bail!(
+            "the query must filter {} on {} and {}",
+            column,
+            START_PLACEHOLDER,
+            END_PLACEHOLDER
+        ),
+        (None, false) => return Ok(()),
+        (Some(_), true) => {}
+    }
+
+    if opts.contains_key("watermark_table_name") {
+        return Ok(());
+    }
+    // the placeholders are not sql, the query is parsed with them as values.
+    let parseable = query
+        .replace(START_PLACEHOLDER, "NULL")
+        .replace(END_PLACEHOLDER, "NULL");
+    let statements = sqlparser::parser::Parser::parse_sql(&PostgreSqlDialect {}, &parseable)
+        .map_err(|err| anyhow::anyhow!("unable to parse the query of the mirror: {}", err))?;
+    let mut tables: Vec<String> = vec![];
+    for statement in &statements {
+        let _ = visit_relations(statement, |relation| {
+            let table = relation.to_string();
+            if !tables.contains(&table) {
+                tables.push(table);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+    match tables.as_slice() {
+        [table] => {
+            opts.insert(
+                "watermark_table_name".to_string(),
+                Value::String(table.clone()),
+            );
+            Ok(())
+        }
+        _ => anyhow::bail!(
+            "watermark_table_name is required for a query that reads {} tables",
+            tables.len()
+        ),
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use sqlparser::ast::{ObjectName, SqlOption, Value};
+
+use crate::peerdb_sql::CreateMirror;
+
+// the session variables a statement can reference, set with
+// `SET peerdb.<name> = '<value>'` and referenced as {{name}}.
+pub type SessionVariables = HashMap<String, String>;
+
+// replaces the references to session variables in the text. references to
+// names starting with a dot, like the {{.start}} and {{.end}} of a query
+// replication query, are left for the flow to fill in.
+pub fn expand(text: &str, variables: &SessionVariables) -> anyhow::Result<String> {
+    let mut expanded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let end = match rest[start..].find("}}") {
+            Some(end) => start + end,
+            None => break,
+        };
+        let name = rest[start + 2..end].trim();
+        expanded.push_str(&rest[..start]);
+        if name.starts_with('.') {
+            expanded.push_str(&rest[start..end + 2]);
+        } else {
+            let value = variables.get(&name.to_lowercase()).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "variable {} is not set, set it with SET peerdb.{} = '<value>'",
+                    name,
+                    name
+                )
+            })?;
+            expanded.push_str(value);
+        }
+        rest = &rest[end + 2..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+fn expand_name(name: &mut ObjectName, variables: &SessionVariables) -> anyhow::Result<()> {
+    for ident in &mut name.0 {
+        ident.value = expand(&ident.value, variables)?;
+    }
+    Ok(())
+}
+
+fn expand_options(options: &mut [SqlOption], variables: &SessionVariables) -> anyhow::Result<()> {
+    for option in options {
+        if let Value::SingleQuotedString(s) = &option.value {
+            option.value = Value::SingleQuotedString(expand(s, variables)?);
+        }
+    }
+    Ok(())
+}
+
+// expands the session variables referenced by the names of a mirror and its
+// tables, its string options and its query.
+pub fn expand_create_mirror(
+    create_mirror: &CreateMirror,
+    variables: &SessionVariables,
+) -> anyhow::Result<CreateMirror> {
+    let mut create_mirror = create_mirror.clone();
+    match &mut create_mirror {
+        CreateMirror::CDC(cdc) => {
+            expand_name(&mut cdc.mirror_name, variables)?;
+            for table_mapping in &mut cdc.mapping_options {
+                expand_name(&mut table_mapping.source, variables)?;
+                expand_name(&mut table_mapping.destination, variables)?;
+            }
+            expand_options(&mut cdc.with_options, variables)?;
+        }
+        CreateMirror::Select(select) => {
+            expand_name(&mut select.mirror_name, variables)?;
+            select.query_string = expand(&select.query_string, variables)?;
+            expand_options(&mut select.with_options, variables)?;
+        }
+    }
+    Ok(create_mirror)
+}
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS resource_groups (
+  id SERIAL PRIMARY KEY,
+  name TEXT NOT NULL UNIQUE,
+  weight INTEGER NOT NULL DEFAULT 1 CHECK (weight > 0),
+  max_concurrency INTEGER CHECK (max_concurrency > 0)
+);
+
+CREATE TABLE IF NOT EXISTS resource_group_members (
+  user_name TEXT PRIMARY KEY,
+  resource_group_id INTEGER NOT NULL REFERENCES resource_groups(id) ON DELETE CASCADE
+);
@@ -0,0 +1,7 @@
+-- connection history should not prevent a peer from being dropped.
+ALTER TABLE peer_connections
+DROP CONSTRAINT IF EXISTS peer_connections_peer_name_fkey;
+
+ALTER TABLE peer_connections
+ADD CONSTRAINT peer_connections_peer_name_fkey
+FOREIGN KEY (peer_name) REFERENCES peers (name) ON DELETE CASCADE;
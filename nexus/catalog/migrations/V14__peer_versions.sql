@@ -0,0 +1,10 @@
+ALTER TABLE peers ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
+
+-- previous configs of a peer, recorded by ALTER PEER.
+CREATE TABLE IF NOT EXISTS peer_versions (
+  peer_id INTEGER NOT NULL REFERENCES peers(id) ON DELETE CASCADE,
+  version INTEGER NOT NULL,
+  options BYTEA NOT NULL,
+  replaced_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (peer_id, version)
+);
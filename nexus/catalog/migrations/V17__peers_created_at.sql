@@ -0,0 +1,2 @@
+ALTER TABLE peers
+ADD COLUMN IF NOT EXISTS created_at TIMESTAMP NOT NULL DEFAULT NOW();
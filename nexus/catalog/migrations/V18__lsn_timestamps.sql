@@ -0,0 +1,8 @@
+-- samples of the source's current LSN and the time it was read, used to turn
+-- LSNs of cdc batches into times.
+CREATE TABLE IF NOT EXISTS peerdb_stats.lsn_timestamps (
+    flow_name TEXT NOT NULL,
+    lsn NUMERIC NOT NULL,
+    sampled_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (flow_name, lsn)
+);
@@ -0,0 +1,5 @@
+ALTER TABLE peers
+ADD COLUMN IF NOT EXISTS quarantined_at TIMESTAMP;
+
+ALTER TABLE flows
+ADD COLUMN IF NOT EXISTS paused_by_quarantine BOOLEAN NOT NULL DEFAULT FALSE;
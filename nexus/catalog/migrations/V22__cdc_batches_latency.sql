@@ -0,0 +1,5 @@
+-- percentiles of the seconds between the changes of a batch being committed at
+-- the source and being applied to the destination, for mirrors that track it.
+ALTER TABLE peerdb_stats.cdc_batches
+    ADD COLUMN IF NOT EXISTS latency_p50_seconds DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS latency_p99_seconds DOUBLE PRECISION;
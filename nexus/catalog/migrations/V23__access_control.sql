@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS nexus_users (
+  name TEXT PRIMARY KEY,
+  -- md5 of the password followed by the name, as postgres stores md5 passwords.
+  password_hash TEXT NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- the grantee is a user or a resource group, whose members share its grants.
+-- the object is the peer or mirror the privilege is on, empty for privileges
+-- that aren't on an object.
+CREATE TABLE IF NOT EXISTS nexus_grants (
+  grantee TEXT NOT NULL,
+  privilege TEXT NOT NULL CHECK (privilege IN ('create_peer', 'create_mirror', 'usage_on_peer', 'operate_on_mirror')),
+  object_name TEXT NOT NULL DEFAULT '',
+  granted_at TIMESTAMP NOT NULL DEFAULT now(),
+  PRIMARY KEY (grantee, privilege, object_name)
+);
@@ -0,0 +1,3 @@
+-- the comment set with COMMENT ON PEER, mirrors keep theirs as the
+-- description of their flows.
+ALTER TABLE peers ADD COLUMN IF NOT EXISTS comment TEXT;
@@ -0,0 +1,4 @@
+-- the salt and salted password of the SCRAM-SHA-256 secret of a user, users
+-- created before these were kept can only log in with md5.
+ALTER TABLE nexus_users ADD COLUMN IF NOT EXISTS scram_salt BYTEA;
+ALTER TABLE nexus_users ADD COLUMN IF NOT EXISTS scram_salted_password BYTEA;
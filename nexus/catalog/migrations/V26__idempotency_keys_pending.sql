@@ -0,0 +1,3 @@
+-- a key without a response is reserved by a request still in flight, retries
+-- with it wait for the request to record its response or release the key.
+ALTER TABLE idempotency_keys ALTER COLUMN response DROP NOT NULL;
@@ -0,0 +1,330 @@
+use std::fmt;
+
+use anyhow::{anyhow, Context};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    rand::{SecureRandom, SystemRandom},
+};
+
+// the prefix of an encrypted config. a protobuf message can't start with
+// a zero byte, which would be a field number of 0, so plaintext configs,
+// e.g. of the peers created before the catalog had a master key, are told
+// apart from encrypted ones.
+const MAGIC: &[u8; 4] = b"\0PE1";
+const KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+
+/// The key the configs of peers, which carry their passwords and service
+/// account keys, and the configs of mirrors, which embed those of their
+/// peers, are encrypted at rest with. Each config is encrypted with a
+/// data key of its own, which is stored with it, wrapped by the master key:
+///
+/// ```text
+/// magic | key nonce | wrapped data key | data nonce | encrypted config
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct MasterKey {
+    key: [u8; KEY_LEN],
+}
+
+impl MasterKey {
+    /// The master key of its base64 encoding, 32 random bytes, e.g. of
+    /// `openssl rand -base64 32`.
+    pub fn from_base64(encoded: &str) -> anyhow::Result<Self> {
+        let key = STANDARD
+            .decode(encoded.trim())
+            .context("the master key is not valid base64")?;
+        let key = key
+            .try_into()
+            .map_err(|_| anyhow!("the master key must be {} bytes", KEY_LEN))?;
+        Ok(Self { key })
+    }
+
+    fn aead_key(key: &[u8]) -> anyhow::Result<LessSafeKey> {
+        let key = UnboundKey::new(&AES_256_GCM, key).map_err(|_| anyhow!("invalid key"))?;
+        Ok(LessSafeKey::new(key))
+    }
+
+    // encrypts the config of the peer or mirror with a new data key. the
+    // config is bound to its name, so it can't be moved to another one.
+    pub(crate) fn seal(&self, name: &str, config: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let rng = SystemRandom::new();
+        let mut data_key = [0u8; KEY_LEN];
+        rng.fill(&mut data_key)
+            .map_err(|_| anyhow!("unable to generate a data key"))?;
+        let (key_nonce, data_nonce) = (random_nonce(&rng)?, random_nonce(&rng)?);
+        self.seal_with(name, config, &data_key, key_nonce, data_nonce)
+    }
+
+    // encrypts the config with the data key and nonces given, which are random
+    // but in tests.
+    fn seal_with(
+        &self,
+        name: &str,
+        config: &[u8],
+        data_key: &[u8; KEY_LEN],
+        key_nonce: [u8; NONCE_LEN],
+        data_nonce: [u8; NONCE_LEN],
+    ) -> anyhow::Result<Vec<u8>> {
+        let mut blob = MAGIC.to_vec();
+        let mut wrapped_key = data_key.to_vec();
+        seal_in_place(&self.key, key_nonce, MAGIC, &mut wrapped_key)?;
+        blob.extend_from_slice(&key_nonce);
+        blob.extend_from_slice(&wrapped_key);
+
+        let mut encrypted = config.to_vec();
+        seal_in_place(data_key, data_nonce, name.as_bytes(), &mut encrypted)?;
+        blob.extend_from_slice(&data_nonce);
+        blob.extend_from_slice(&encrypted);
+        Ok(blob)
+    }
+
+    fn unwrap_data_key(&self, envelope: &Envelope) -> anyhow::Result<Vec<u8>> {
+        open_in_place(
+            &self.key,
+            envelope.key_nonce,
+            MAGIC,
+            envelope.wrapped_key.to_vec(),
+        )
+        .context("unable to unwrap the data key, is the master key the one it was encrypted with?")
+    }
+
+    pub(crate) fn open_blob(&self, name: &str, blob: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let envelope = Envelope::parse(blob)?;
+        let data_key = self.unwrap_data_key(&envelope)?;
+        open_in_place(
+            &data_key,
+            envelope.data_nonce,
+            name.as_bytes(),
+            envelope.encrypted.to_vec(),
+        )
+    }
+
+    // whether the data key of the encrypted config is wrapped by this key.
+    pub(crate) fn wraps(&self, blob: &[u8]) -> bool {
+        Envelope::parse(blob)
+            .and_then(|envelope| self.unwrap_data_key(&envelope))
+            .is_ok()
+    }
+
+    // the encrypted config with its data key wrapped by the new key instead
+    // of this one. the config itself stays encrypted as it is.
+    pub(crate) fn rewrap(&self, new_key: &MasterKey, blob: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let envelope = Envelope::parse(blob)?;
+        let mut wrapped_key = self.unwrap_data_key(&envelope)?;
+        let key_nonce = random_nonce(&SystemRandom::new())?;
+        seal_in_place(&new_key.key, key_nonce, MAGIC, &mut wrapped_key)?;
+
+        let mut rewrapped = MAGIC.to_vec();
+        rewrapped.extend_from_slice(&key_nonce);
+        rewrapped.extend_from_slice(&wrapped_key);
+        rewrapped.extend_from_slice(envelope.data_nonce);
+        rewrapped.extend_from_slice(envelope.encrypted);
+        Ok(rewrapped)
+    }
+}
+
+// the parts of an encrypted config.
+struct Envelope<'a> {
+    key_nonce: &'a [u8],
+    wrapped_key: &'a [u8],
+    data_nonce: &'a [u8],
+    encrypted: &'a [u8],
+}
+
+impl<'a> Envelope<'a> {
+    fn parse(blob: &'a [u8]) -> anyhow::Result<Self> {
+        let wrapped_len = KEY_LEN + TAG_LEN;
+        let rest = blob
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or_else(|| anyhow!("the config is not encrypted"))?;
+        if rest.len() < 2 * NONCE_LEN + wrapped_len + TAG_LEN {
+            return Err(anyhow!("the encrypted config is truncated"));
+        }
+        let (key_nonce, rest) = rest.split_at(NONCE_LEN);
+        let (wrapped_key, rest) = rest.split_at(wrapped_len);
+        let (data_nonce, encrypted) = rest.split_at(NONCE_LEN);
+        Ok(Self {
+            key_nonce,
+            wrapped_key,
+            data_nonce,
+            encrypted,
+        })
+    }
+}
+
+// the master key is never logged.
+impl fmt::Debug for MasterKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MasterKey(..)")
+    }
+}
+
+pub(crate) fn is_encrypted(blob: &[u8]) -> bool {
+    blob.starts_with(MAGIC)
+}
+
+// the config of the peer or mirror as it is stored, decrypted with the one
+// of the master keys its data key is wrapped by if it is encrypted. while the
+// master key is rotated, configs are wrapped by either the new or the old key.
+pub(crate) fn open(
+    master_keys: &[&MasterKey],
+    name: &str,
+    blob: Vec<u8>,
+) -> anyhow::Result<Vec<u8>> {
+    if !is_encrypted(&blob) {
+        return Ok(blob);
+    }
+    if master_keys.is_empty() {
+        return Err(anyhow!(
+            "the config of {} is encrypted, but no master key is set",
+            name
+        ));
+    }
+    let master_key = master_keys
+        .iter()
+        .find(|master_key| master_key.wraps(&blob))
+        .unwrap_or(&master_keys[0]);
+    master_key
+        .open_blob(name, &blob)
+        .with_context(|| format!("unable to decrypt the config of {}", name))
+}
+
+fn random_nonce(rng: &SystemRandom) -> anyhow::Result<[u8; NONCE_LEN]> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut nonce)
+        .map_err(|_| anyhow!("unable to generate a nonce"))?;
+    Ok(nonce)
+}
+
+// encrypts the data with the nonce, which is never used with the key again.
+fn seal_in_place(
+    key: &[u8],
+    nonce: [u8; NONCE_LEN],
+    aad: &[u8],
+    data: &mut Vec<u8>,
+) -> anyhow::Result<()> {
+    MasterKey::aead_key(key)?
+        .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(aad), data)
+        .map_err(|_| anyhow!("unable to encrypt"))
+}
+
+fn open_in_place(
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    mut data: Vec<u8>,
+) -> anyhow::Result<Vec<u8>> {
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| anyhow!("invalid nonce"))?;
+    let len = MasterKey::aead_key(key)?
+        .open_in_place(nonce, Aad::from(aad), &mut data)
+        .map_err(|_| anyhow!("the encrypted data is not authentic"))?
+        .len();
+    data.truncate(len);
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> MasterKey {
+        MasterKey {
+            key: [byte; KEY_LEN],
+        }
+    }
+
+    #[test]
+    fn sealed_configs_open_with_their_name() {
+        let config = b"\x0a\x09localhost";
+        let blob = key(1).seal("pg", config).unwrap();
+        assert!(is_encrypted(&blob));
+        assert!(!is_encrypted(config));
+        assert_eq!(key(1).open_blob("pg", &blob).unwrap(), config);
+        // each config has a data key of its own.
+        assert_ne!(key(1).seal("pg", config).unwrap(), blob);
+
+        assert!(key(1).open_blob("other", &blob).is_err());
+        assert!(key(2).open_blob("pg", &blob).is_err());
+        assert!(key(1).open_blob("pg", &blob[..blob.len() - 1]).is_err());
+        assert!(key(1).open_blob("pg", config).is_err());
+    }
+
+    #[test]
+    fn plaintext_configs_open_without_a_key() {
+        let config = b"\x0a\x09localhost".to_vec();
+        assert_eq!(open(&[], "pg", config.clone()).unwrap(), config);
+        assert_eq!(open(&[&key(1)], "pg", config.clone()).unwrap(), config);
+
+        let blob = key(1).seal("pg", &config).unwrap();
+        let err = open(&[], "pg", blob.clone()).unwrap_err();
+        assert!(err.to_string().contains("no master key is set"));
+        assert!(open(&[&key(2)], "pg", blob).is_err());
+    }
+
+    // configs the flow API encrypted open here and the other way around: the
+    // envelopes were sealed with the data keys and nonces they list, which
+    // the flow API tests against as well, see
+    // flow/connectors/utils/catalog/encryption_test.go.
+    #[test]
+    fn envelopes_match_the_flow_api() {
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/envelopes.json"
+        ))
+        .unwrap();
+        let fixture: serde_json::Value = serde_json::from_str(&fixture).unwrap();
+        let master_key = MasterKey::from_base64(fixture["master_key"].as_str().unwrap()).unwrap();
+        let envelopes = fixture["envelopes"].as_array().unwrap();
+        assert!(!envelopes.is_empty());
+
+        for envelope in envelopes {
+            let field = |name: &str| STANDARD.decode(envelope[name].as_str().unwrap()).unwrap();
+            let name = envelope["name"].as_str().unwrap();
+            let (config, blob) = (field("config"), field("envelope"));
+
+            assert_eq!(
+                master_key.open_blob(name, &blob).unwrap(),
+                config,
+                "{}",
+                name
+            );
+            let sealed = master_key
+                .seal_with(
+                    name,
+                    &config,
+                    &field("data_key").try_into().unwrap(),
+                    field("key_nonce").try_into().unwrap(),
+                    field("data_nonce").try_into().unwrap(),
+                )
+                .unwrap();
+            assert_eq!(sealed, blob, "{}", name);
+        }
+    }
+
+    #[test]
+    fn rewrapped_configs_open_with_the_new_key() {
+        let (old_key, new_key) = (key(1), key(2));
+        let blob = old_key.seal("pg", b"config").unwrap();
+        let rewrapped = old_key.rewrap(&new_key, &blob).unwrap();
+
+        assert!(new_key.wraps(&rewrapped));
+        assert!(!old_key.wraps(&rewrapped));
+        assert_eq!(new_key.open_blob("pg", &rewrapped).unwrap(), b"config");
+        // while the key is rotated, configs open with either key.
+        for blob in [blob, rewrapped] {
+            assert_eq!(open(&[&new_key, &old_key], "pg", blob).unwrap(), b"config");
+        }
+    }
+
+    #[test]
+    fn rewrap_needs_the_old_key() {
+        let blob = key(1).seal("pg", b"config").unwrap();
+        assert!(key(3).rewrap(&key(2), &blob).is_err());
+        assert!(key(1).rewrap(&key(2), b"config").is_err());
+        // the config stays bound to its name.
+        let rewrapped = key(1).rewrap(&key(2), &blob).unwrap();
+        assert!(key(2).open_blob("other", &rewrapped).is_err());
+    }
+}
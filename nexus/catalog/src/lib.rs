@@ -6,24 +6,101 @@ use peer_postgres::PostgresQueryExecutor;
 use postgres_connection::{connect_postgres, get_pg_connection_string};
 use prost::Message;
 use pt::{
-    flow_model::{FlowJob, QRepFlowJob},
+    flow_model::{FlowJob, FlowJobTableMapping, QRepFlowJob},
+    peerdb_flow::FlowConnectionConfigs,
     peerdb_peers::PostgresConfig,
     peerdb_peers::{peer::Config, DbType, Peer},
 };
 use tokio_postgres::{types, Client};
 
+pub use encryption::MasterKey;
+
+mod encryption;
+
 mod embedded {
     use refinery::embed_migrations;
     embed_migrations!("migrations");
 }
 
+// the configs stored in the catalog, by table, with the name of the peer or
+// mirror they are encrypted for, and how a config is replaced. the update
+// only replaces the config it read, so a concurrent ALTER PEER or update of a
+// mirror is never overwritten.
+const STORED_CONFIGS: &[(&str, &str, &str)] = &[
+    (
+        "peers",
+        "SELECT name, options FROM peers",
+        "UPDATE peers SET options = $1 WHERE name = $2 AND options = $3",
+    ),
+    (
+        "peer_versions",
+        "SELECT DISTINCT p.name, v.options
+         FROM peer_versions v JOIN peers p ON p.id = v.peer_id",
+        "UPDATE peer_versions SET options = $1
+         WHERE peer_id = (SELECT id FROM peers WHERE name = $2) AND options = $3",
+    ),
+    (
+        "flows",
+        "SELECT DISTINCT name, config_proto FROM flows WHERE config_proto IS NOT NULL",
+        "UPDATE flows SET config_proto = $1 WHERE name = $2 AND config_proto = $3",
+    ),
+    (
+        "peerdb_stats.qrep_runs",
+        "SELECT DISTINCT flow_name, config_proto FROM peerdb_stats.qrep_runs
+         WHERE config_proto IS NOT NULL",
+        "UPDATE peerdb_stats.qrep_runs SET config_proto = $1
+         WHERE flow_name = $2 AND config_proto = $3",
+    ),
+];
+
 pub struct Catalog {
     pg: Box<Client>,
     executor: Arc<Box<dyn QueryExecutor>>,
+    master_key: Option<MasterKey>,
+    previous_master_key: Option<MasterKey>,
 }
 
+// applies the migrations the catalog doesn't have yet. a catalog migrated by
+// a newer server is left alone, as this server doesn't know its schema,
+// rather than run against it.
 async fn run_migrations(client: &mut Client) -> anyhow::Result<()> {
-    let migration_report = embedded::migrations::runner()
+    let runner = embedded::migrations::runner();
+    let latest_version = runner
+        .get_migrations()
+        .iter()
+        .map(|migration| migration.version())
+        .max()
+        .unwrap_or_default();
+    // a new catalog has no history of migrations to look the version up in.
+    let has_history: bool = client
+        .query_one(
+            "SELECT to_regclass('refinery_schema_history') IS NOT NULL",
+            &[],
+        )
+        .await
+        .context("Failed to get the schema version of the catalog")?
+        .get(0);
+    let applied = if has_history {
+        runner
+            .get_last_applied_migration_async(client)
+            .await
+            .context("Failed to get the schema version of the catalog")?
+    } else {
+        None
+    };
+    if let Some(applied) = &applied {
+        if applied.version() > latest_version {
+            return Err(anyhow!(
+                "the catalog schema is at version {} ({}), newer than version {} of this \
+                 server, upgrade the server to run against it",
+                applied.version(),
+                applied.name(),
+                latest_version
+            ));
+        }
+    }
+
+    let migration_report = runner
         .run_async(client)
         .await
         .context("Failed to run migrations")?;
@@ -34,6 +111,7 @@ async fn run_migrations(client: &mut Client) -> anyhow::Result<()> {
             migration.version()
         );
     }
+    tracing::info!("catalog schema at version {}", latest_version);
     Ok(())
 }
 
@@ -44,6 +122,29 @@ pub struct CatalogConfig {
     pub user: String,
     pub password: String,
     pub database: String,
+    /// The key the configs of peers are encrypted with, they are stored in
+    /// plaintext without one.
+    pub master_key: Option<MasterKey>,
+    /// The master key before the one it is rotated to, the configs whose data
+    /// keys are still wrapped by it are decrypted with it until the rotation
+    /// is done, see [`Catalog::rotate_master_key`].
+    pub previous_master_key: Option<MasterKey>,
+}
+
+// the progress of a key rotation is reported every this many configs of a
+// table, and once all of its configs are rotated.
+const KEY_ROTATION_PROGRESS_INTERVAL: usize = 100;
+
+/// The configs [`Catalog::rotate_master_key`] rotated.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeyRotation {
+    /// The configs whose data keys are wrapped by the new key instead.
+    pub rotated: usize,
+    /// The configs that were stored in plaintext, encrypted with the new key.
+    pub encrypted: usize,
+    /// The configs in the catalog that were checked to decrypt with the new
+    /// key once all were rotated.
+    pub verified: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -53,6 +154,118 @@ pub struct WorkflowDetails {
     pub destination_peer: pt::peerdb_peers::Peer,
 }
 
+/// A peer as recorded in the catalog, see [`Catalog::get_peer_infos`].
+#[derive(Debug, Clone)]
+pub struct PeerInfo {
+    pub peer: Peer,
+    pub created_at: chrono::NaiveDateTime,
+    /// Set while the peer is quarantined for failing health checks.
+    pub quarantined_at: Option<chrono::NaiveDateTime>,
+    /// The comment set with `COMMENT ON PEER`.
+    pub comment: Option<String>,
+}
+
+/// A mirror as recorded in the catalog, see [`Catalog::get_mirrors`].
+#[derive(Debug, Clone)]
+pub struct MirrorInfo {
+    pub name: String,
+    pub source_peer: String,
+    pub destination_peer: String,
+    pub is_cdc: bool,
+    pub paused: bool,
+    /// Whether the mirror was paused because one of its peers was quarantined.
+    pub paused_by_quarantine: bool,
+    /// The comment set with `COMMENT ON MIRROR`.
+    pub comment: Option<String>,
+}
+
+/// The passwords of a user of nexus as they are stored in the catalog, see
+/// [`Catalog::get_user_password`].
+#[derive(Debug, Clone)]
+pub struct UserPassword {
+    /// The md5 of the password followed by the name.
+    pub md5_hash: String,
+    /// The SCRAM-SHA-256 secret, unset for users created before it was kept.
+    pub scram: Option<ScramSecret>,
+}
+
+/// The salt of a SCRAM-SHA-256 secret and the password salted with it.
+#[derive(Debug, Clone)]
+pub struct ScramSecret {
+    pub salt: Vec<u8>,
+    pub salted_password: Vec<u8>,
+}
+
+/// A resource group bounds the share of peer concurrency that its
+/// members can use on a shared nexus instance.
+#[derive(Debug, Clone)]
+pub struct ResourceGroup {
+    pub name: String,
+    pub weight: i32,
+    pub max_concurrency: Option<i32>,
+}
+
+impl Default for ResourceGroup {
+    fn default() -> Self {
+        Self {
+            name: "default".to_string(),
+            weight: 1,
+            max_concurrency: None,
+        }
+    }
+}
+
+/// A privilege that access control requires of users other than the admin,
+/// granted with `GRANT` and revoked with `REVOKE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Privilege {
+    CreatePeer,
+    CreateMirror,
+    /// Querying the peer and using it in mirrors.
+    UsageOnPeer(String),
+    /// Altering, pausing, resuming, resyncing, describing and dropping the mirror.
+    OperateOnMirror(String),
+    /// Only running statements that read, a restriction rather than a
+    /// privilege, for users of a read-only analytics endpoint.
+    ReadOnly,
+}
+
+impl Privilege {
+    // the privilege and the object it is on as they are stored in the catalog.
+    fn to_row(&self) -> (&'static str, &str) {
+        match self {
+            Privilege::CreatePeer => ("create_peer", ""),
+            Privilege::CreateMirror => ("create_mirror", ""),
+            Privilege::UsageOnPeer(peer) => ("usage_on_peer", peer),
+            Privilege::OperateOnMirror(mirror) => ("operate_on_mirror", mirror),
+            Privilege::ReadOnly => ("read_only", ""),
+        }
+    }
+
+    fn from_row(privilege: &str, object_name: String) -> Option<Self> {
+        match privilege {
+            "create_peer" => Some(Privilege::CreatePeer),
+            "create_mirror" => Some(Privilege::CreateMirror),
+            "usage_on_peer" => Some(Privilege::UsageOnPeer(object_name)),
+            "operate_on_mirror" => Some(Privilege::OperateOnMirror(object_name)),
+            "read_only" => Some(Privilege::ReadOnly),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Privilege {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Privilege::CreatePeer => write!(f, "CREATE PEER"),
+            Privilege::CreateMirror => write!(f, "CREATE MIRROR"),
+            Privilege::UsageOnPeer(peer) => write!(f, "USAGE ON PEER {}", peer),
+            Privilege::OperateOnMirror(mirror) => write!(f, "OPERATE ON MIRROR {}", mirror),
+            Privilege::ReadOnly => write!(f, "READ ONLY"),
+        }
+    }
+}
+
 impl CatalogConfig {
     pub fn new(host: String, port: u16, user: String, password: String, database: String) -> Self {
         Self {
@@ -61,6 +274,8 @@ impl CatalogConfig {
             user,
             password,
             database,
+            master_key: None,
+            previous_master_key: None,
         }
     }
 
@@ -73,6 +288,7 @@ impl CatalogConfig {
             password: self.password.clone(),
             database: self.database.clone(),
             transaction_snapshot: "".to_string(),
+            money_mapping: 0,
         }
     }
 
@@ -81,6 +297,77 @@ impl CatalogConfig {
     }
 }
 
+// encode the config of the peer as it is stored in the catalog
+fn encode_peer_config(peer: &Peer) -> anyhow::Result<Vec<u8>> {
+    let config = peer.config.clone().context("invalid peer config")?;
+    let mut buf = Vec::new();
+
+    match config {
+        Config::SnowflakeConfig(snowflake_config) => {
+            let config_len = snowflake_config.encoded_len();
+            buf.reserve(config_len);
+            snowflake_config.encode(&mut buf)?;
+        }
+        Config::BigqueryConfig(bigquery_config) => {
+            let config_len = bigquery_config.encoded_len();
+            buf.reserve(config_len);
+            bigquery_config.encode(&mut buf)?;
+        }
+        Config::MongoConfig(mongo_config) => {
+            let config_len = mongo_config.encoded_len();
+            buf.reserve(config_len);
+            mongo_config.encode(&mut buf)?;
+        }
+        Config::PostgresConfig(postgres_config) => {
+            let config_len = postgres_config.encoded_len();
+            buf.reserve(config_len);
+            postgres_config.encode(&mut buf)?;
+        }
+        Config::EventhubConfig(eventhub_config) => {
+            let config_len = eventhub_config.encoded_len();
+            buf.reserve(config_len);
+            eventhub_config.encode(&mut buf)?;
+        }
+        Config::S3Config(s3_config) => {
+            let config_len = s3_config.encoded_len();
+            buf.reserve(config_len);
+            s3_config.encode(&mut buf)?;
+        }
+        Config::SqlserverConfig(sqlserver_config) => {
+            let config_len = sqlserver_config.encoded_len();
+            buf.reserve(config_len);
+            sqlserver_config.encode(&mut buf)?;
+        }
+        Config::EventhubGroupConfig(eventhub_group_config) => {
+            let config_len = eventhub_group_config.encoded_len();
+            buf.reserve(config_len);
+            eventhub_group_config.encode(&mut buf)?;
+        }
+        Config::PineconeConfig(pinecone_config) => {
+            let config_len = pinecone_config.encoded_len();
+            buf.reserve(config_len);
+            pinecone_config.encode(&mut buf)?;
+        }
+        Config::WeaviateConfig(weaviate_config) => {
+            let config_len = weaviate_config.encoded_len();
+            buf.reserve(config_len);
+            weaviate_config.encode(&mut buf)?;
+        }
+        Config::FileConfig(file_config) => {
+            let config_len = file_config.encoded_len();
+            buf.reserve(config_len);
+            file_config.encode(&mut buf)?;
+        }
+        Config::WebhookConfig(webhook_config) => {
+            let config_len = webhook_config.encoded_len();
+            buf.reserve(config_len);
+            webhook_config.encode(&mut buf)?;
+        }
+    };
+
+    Ok(buf)
+}
+
 impl Catalog {
     pub async fn new(catalog_config: &CatalogConfig) -> anyhow::Result<Self> {
         let pt_config = catalog_config.to_postgres_config();
@@ -91,9 +378,13 @@ impl Catalog {
         Ok(Self {
             pg: Box::new(client),
             executor: Arc::new(boxed_trait),
+            master_key: catalog_config.master_key.clone(),
+            previous_master_key: catalog_config.previous_master_key.clone(),
         })
     }
 
+    /// Brings the catalog schema up to the version of this server, with the
+    /// migrations in `migrations/`. Fails when the catalog has a newer schema.
     pub async fn run_migrations(&mut self) -> anyhow::Result<()> {
         run_migrations(&mut self.pg).await
     }
@@ -102,56 +393,151 @@ impl Catalog {
         self.executor.clone()
     }
 
-    pub async fn create_peer(&self, peer: &Peer) -> anyhow::Result<i64> {
-        let config_blob = {
-            let config = peer.config.clone().context("invalid peer config")?;
-            let mut buf = Vec::new();
-
-            match config {
-                Config::SnowflakeConfig(snowflake_config) => {
-                    let config_len = snowflake_config.encoded_len();
-                    buf.reserve(config_len);
-                    snowflake_config.encode(&mut buf)?;
-                }
-                Config::BigqueryConfig(bigquery_config) => {
-                    let config_len = bigquery_config.encoded_len();
-                    buf.reserve(config_len);
-                    bigquery_config.encode(&mut buf)?;
-                }
-                Config::MongoConfig(mongo_config) => {
-                    let config_len = mongo_config.encoded_len();
-                    buf.reserve(config_len);
-                    mongo_config.encode(&mut buf)?;
-                }
-                Config::PostgresConfig(postgres_config) => {
-                    let config_len = postgres_config.encoded_len();
-                    buf.reserve(config_len);
-                    postgres_config.encode(&mut buf)?;
-                }
-                Config::EventhubConfig(eventhub_config) => {
-                    let config_len = eventhub_config.encoded_len();
-                    buf.reserve(config_len);
-                    eventhub_config.encode(&mut buf)?;
+    // the config of the peer as it is stored, encrypted with the master key
+    // if there is one.
+    fn seal_peer_config(&self, peer: &Peer) -> anyhow::Result<Vec<u8>> {
+        let config_blob = encode_peer_config(peer)?;
+        match &self.master_key {
+            Some(master_key) => master_key.seal(&peer.name, &config_blob),
+            None => Ok(config_blob),
+        }
+    }
+
+    /// Encrypts the configs stored in plaintext, those of the peers created
+    /// before the master key was set and their previous versions, and the
+    /// mirror configs the flow API stored, which carry the configs of their
+    /// peers. Returns the number of configs it encrypted.
+    pub async fn encrypt_plaintext_configs(&self) -> anyhow::Result<usize> {
+        let master_key = match &self.master_key {
+            Some(master_key) => master_key,
+            None => return Ok(0),
+        };
+        let mut encrypted = 0;
+        for (_, select, update) in STORED_CONFIGS {
+            for row in self.pg.query(*select, &[]).await? {
+                let name: String = row.get(0);
+                let config: Vec<u8> = row.get(1);
+                if encryption::is_encrypted(&config) {
+                    continue;
                 }
-                Config::S3Config(s3_config) => {
-                    let config_len = s3_config.encoded_len();
-                    buf.reserve(config_len);
-                    s3_config.encode(&mut buf)?;
+                let sealed = master_key.seal(&name, &config)?;
+                encrypted += self.pg.execute(*update, &[&sealed, &name, &config]).await? as usize;
+            }
+        }
+        Ok(encrypted)
+    }
+
+    // the master keys configs are decrypted with, the previous one only while
+    // the master key is rotated.
+    fn master_keys(&self) -> Vec<&MasterKey> {
+        self.master_key
+            .iter()
+            .chain(self.previous_master_key.iter())
+            .collect()
+    }
+
+    /// Wraps the data keys of the configs stored in the catalog with the new
+    /// master key instead of the old one, and encrypts the configs stored in
+    /// plaintext with it. Configs are rotated one at a time while the catalog
+    /// is in use, each is checked to decrypt to the same config with the new
+    /// key before it replaces the old one, and once all are, every config in
+    /// the catalog is checked to decrypt with the new key.
+    ///
+    /// The new key must be the master key of the catalog, so that the configs
+    /// stored while they are rotated are encrypted with it, and the old key
+    /// its previous one. `progress` is called as the configs of each table
+    /// are rotated, with its name, how many of its configs are done and how
+    /// many it has.
+    pub async fn rotate_master_key(
+        &self,
+        old_key: &MasterKey,
+        new_key: &MasterKey,
+        mut progress: impl FnMut(&str, usize, usize),
+    ) -> anyhow::Result<KeyRotation> {
+        if self.master_key.as_ref() != Some(new_key) {
+            return Err(anyhow!(
+                "the new key is not the master key of the catalog, configs stored while they \
+                 are rotated would be encrypted with another key"
+            ));
+        }
+        if self.previous_master_key.as_ref() != Some(old_key) {
+            return Err(anyhow!(
+                "the old key is not the previous master key of the catalog, the configs still \
+                 encrypted with it couldn't be read while they are rotated"
+            ));
+        }
+
+        let mut rotation = KeyRotation::default();
+        for (table, select, update) in STORED_CONFIGS {
+            let rows = self.pg.query(*select, &[]).await?;
+            for (done, row) in rows.iter().enumerate() {
+                let name: String = row.get(0);
+                let config: Vec<u8> = row.get(1);
+                let stored_encrypted = encryption::is_encrypted(&config);
+                // the configs stored with the new key since the rotation
+                // started, or rotated by an earlier one, are left as they are.
+                if !stored_encrypted || !new_key.wraps(&config) {
+                    let (replacement, plaintext) = if stored_encrypted {
+                        let plaintext = old_key.open_blob(&name, &config).with_context(|| {
+                            format!(
+                                "the config of {} in {} is encrypted with neither key",
+                                name, table
+                            )
+                        })?;
+                        (old_key.rewrap(new_key, &config)?, plaintext)
+                    } else {
+                        (new_key.seal(&name, &config)?, config.clone())
+                    };
+                    if new_key.open_blob(&name, &replacement)? != plaintext {
+                        return Err(anyhow!(
+                            "the rotated config of {} in {} doesn't decrypt to the config, it \
+                             is left as it was",
+                            name,
+                            table
+                        ));
+                    }
+
+                    let replaced = self
+                        .pg
+                        .execute(*update, &[&replacement, &name, &config])
+                        .await? as usize;
+                    if stored_encrypted {
+                        rotation.rotated += replaced;
+                    } else {
+                        rotation.encrypted += replaced;
+                    }
                 }
-                Config::SqlserverConfig(sqlserver_config) => {
-                    let config_len = sqlserver_config.encoded_len();
-                    buf.reserve(config_len);
-                    sqlserver_config.encode(&mut buf)?;
+                if (done + 1) % KEY_ROTATION_PROGRESS_INTERVAL == 0 || done + 1 == rows.len() {
+                    progress(table, done + 1, rows.len());
                 }
-                Config::EventhubGroupConfig(eventhub_group_config) => {
-                    let config_len = eventhub_group_config.encoded_len();
-                    buf.reserve(config_len);
-                    eventhub_group_config.encode(&mut buf)?;
+            }
+        }
+
+        // configs an update raced with, or stored by a process running with
+        // the old key, are left for another rotation.
+        for (table, select, _) in STORED_CONFIGS {
+            for row in self.pg.query(*select, &[]).await? {
+                let name: String = row.get(0);
+                let config: Vec<u8> = row.get(1);
+                if !encryption::is_encrypted(&config) || !new_key.wraps(&config) {
+                    return Err(anyhow!(
+                        "the config of {} in {} was stored with another key while the configs \
+                         were rotated, rotate them again once every server runs with the new key",
+                        name,
+                        table
+                    ));
                 }
-            };
+                new_key.open_blob(&name, &config).with_context(|| {
+                    format!("the config of {} in {} doesn't decrypt", name, table)
+                })?;
+                rotation.verified += 1;
+            }
+        }
+        Ok(rotation)
+    }
 
-            buf
-        };
+    pub async fn create_peer(&self, peer: &Peer) -> anyhow::Result<i64> {
+        let config_blob = self.seal_peer_config(peer)?;
 
         let stmt = self
             .pg
@@ -168,6 +554,34 @@ impl Catalog {
         self.get_peer_id(&peer.name).await
     }
 
+    // replace the config of an existing peer, the previous config is kept in
+    // peer_versions. returns the new version of the peer.
+    pub async fn update_peer(&self, peer: &Peer) -> anyhow::Result<i32> {
+        let config_blob = self.seal_peer_config(peer)?;
+
+        let stmt = self
+            .pg
+            .prepare_typed(
+                "WITH old AS (
+                    SELECT id, version, options FROM peers WHERE name = $1 FOR UPDATE
+                 ), archived AS (
+                    INSERT INTO peer_versions (peer_id, version, options)
+                    SELECT id, version, options FROM old
+                 )
+                 UPDATE peers SET options = $2, version = old.version + 1
+                 FROM old WHERE peers.id = old.id
+                 RETURNING peers.version",
+                &[types::Type::TEXT, types::Type::BYTEA],
+            )
+            .await?;
+
+        self.pg
+            .query_opt(&stmt, &[&peer.name, &config_blob])
+            .await?
+            .map(|row| row.get(0))
+            .with_context(|| format!("no such peer: {}", peer.name))
+    }
+
     async fn get_peer_id(&self, peer_name: &str) -> anyhow::Result<i64> {
         let id = self.get_peer_id_i32(peer_name).await?;
         Ok(id as i64)
@@ -187,6 +601,267 @@ impl Catalog {
             .context("Failed to get peer id")
     }
 
+    // get the resource group a user is assigned to, if any
+    pub async fn get_resource_group_for_user(
+        &self,
+        user_name: &str,
+    ) -> anyhow::Result<Option<ResourceGroup>> {
+        let stmt = self
+            .pg
+            .prepare_typed(
+                "SELECT g.name, g.weight, g.max_concurrency FROM resource_groups g
+                 JOIN resource_group_members m ON m.resource_group_id = g.id
+                 WHERE m.user_name = $1",
+                &[types::Type::TEXT],
+            )
+            .await?;
+
+        let resource_group =
+            self.pg
+                .query_opt(&stmt, &[&user_name])
+                .await?
+                .map(|row| ResourceGroup {
+                    name: row.get(0),
+                    weight: row.get(1),
+                    max_concurrency: row.get(2),
+                });
+        Ok(resource_group)
+    }
+
+    // creates a resource group, returns false if the group already exists.
+    pub async fn create_resource_group(&self, group: &ResourceGroup) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute(
+                "INSERT INTO resource_groups (name, weight, max_concurrency) VALUES ($1, $2, $3)
+                 ON CONFLICT (name) DO NOTHING",
+                &[&group.name, &group.weight, &group.max_concurrency],
+            )
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // sets the weight and the max_concurrency of a resource group that are
+    // given, returns false if no such group exists.
+    pub async fn alter_resource_group(
+        &self,
+        name: &str,
+        weight: Option<i32>,
+        max_concurrency: Option<Option<i32>>,
+    ) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute(
+                "UPDATE resource_groups
+                 SET weight = COALESCE($2, weight),
+                     max_concurrency = CASE WHEN $3 THEN $4 ELSE max_concurrency END
+                 WHERE name = $1",
+                &[
+                    &name,
+                    &weight,
+                    &max_concurrency.is_some(),
+                    &max_concurrency.flatten(),
+                ],
+            )
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // drops a resource group along with its grants, its members are back in
+    // the default group. returns false if no such group exists.
+    pub async fn drop_resource_group(&self, name: &str) -> anyhow::Result<bool> {
+        self.pg
+            .execute("DELETE FROM nexus_grants WHERE grantee = $1", &[&name])
+            .await?;
+        let rows = self
+            .pg
+            .execute("DELETE FROM resource_groups WHERE name = $1", &[&name])
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // moves a user into a resource group, out of the one it was in. returns
+    // false if no such group exists.
+    pub async fn add_resource_group_member(
+        &self,
+        name: &str,
+        user_name: &str,
+    ) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute(
+                "INSERT INTO resource_group_members (user_name, resource_group_id)
+                 SELECT $2, id FROM resource_groups WHERE name = $1
+                 ON CONFLICT (user_name) DO UPDATE
+                 SET resource_group_id = EXCLUDED.resource_group_id",
+                &[&name, &user_name],
+            )
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // moves a user out of a resource group, back into the default group.
+    // returns false if the user isn't a member of the group.
+    pub async fn remove_resource_group_member(
+        &self,
+        name: &str,
+        user_name: &str,
+    ) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute(
+                "DELETE FROM resource_group_members m USING resource_groups g
+                 WHERE m.resource_group_id = g.id AND g.name = $1 AND m.user_name = $2",
+                &[&name, &user_name],
+            )
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // creates a user of nexus, returns false if the user already exists.
+    pub async fn create_user(
+        &self,
+        name: &str,
+        password_hash: &str,
+        scram: &ScramSecret,
+    ) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute(
+                "INSERT INTO nexus_users (name, password_hash, scram_salt, scram_salted_password)
+                 VALUES ($1, $2, $3, $4)
+                 ON CONFLICT (name) DO NOTHING",
+                &[&name, &password_hash, &scram.salt, &scram.salted_password],
+            )
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // sets the passwords of a user of nexus, returns false if no such user
+    // exists.
+    pub async fn set_user_password(
+        &self,
+        name: &str,
+        password_hash: &str,
+        scram: &ScramSecret,
+    ) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute(
+                "UPDATE nexus_users
+                 SET password_hash = $2, scram_salt = $3, scram_salted_password = $4
+                 WHERE name = $1",
+                &[&name, &password_hash, &scram.salt, &scram.salted_password],
+            )
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // drops a user of nexus along with its grants, returns false if no such
+    // user exists.
+    pub async fn drop_user(&self, name: &str) -> anyhow::Result<bool> {
+        self.pg
+            .execute("DELETE FROM nexus_grants WHERE grantee = $1", &[&name])
+            .await?;
+        let rows = self
+            .pg
+            .execute("DELETE FROM nexus_users WHERE name = $1", &[&name])
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // get the passwords of a user of nexus, if it exists
+    pub async fn get_user_password(&self, name: &str) -> anyhow::Result<Option<UserPassword>> {
+        let row = self
+            .pg
+            .query_opt(
+                "SELECT password_hash, scram_salt, scram_salted_password
+                 FROM nexus_users WHERE name = $1",
+                &[&name],
+            )
+            .await?;
+        Ok(row.map(|row| {
+            let salt: Option<Vec<u8>> = row.get(1);
+            let salted_password: Option<Vec<u8>> = row.get(2);
+            UserPassword {
+                md5_hash: row.get(0),
+                scram: salt
+                    .zip(salted_password)
+                    .map(|(salt, salted_password)| ScramSecret {
+                        salt,
+                        salted_password,
+                    }),
+            }
+        }))
+    }
+
+    pub async fn grant_privilege(
+        &self,
+        privilege: &Privilege,
+        grantee: &str,
+    ) -> anyhow::Result<()> {
+        let (privilege, object_name) = privilege.to_row();
+        self.pg
+            .execute(
+                "INSERT INTO nexus_grants (grantee, privilege, object_name) VALUES ($1, $2, $3)
+                 ON CONFLICT DO NOTHING",
+                &[&grantee, &privilege, &object_name],
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn revoke_privilege(
+        &self,
+        privilege: &Privilege,
+        grantee: &str,
+    ) -> anyhow::Result<()> {
+        let (privilege, object_name) = privilege.to_row();
+        self.pg
+            .execute(
+                "DELETE FROM nexus_grants
+                 WHERE grantee = $1 AND privilege = $2 AND object_name = $3",
+                &[&grantee, &privilege, &object_name],
+            )
+            .await?;
+        Ok(())
+    }
+
+    // revokes a privilege from everyone it was granted to, for when the peer
+    // or mirror it is on is deleted.
+    async fn revoke_privilege_from_all(&self, privilege: &Privilege) -> anyhow::Result<()> {
+        let (privilege, object_name) = privilege.to_row();
+        self.pg
+            .execute(
+                "DELETE FROM nexus_grants WHERE privilege = $1 AND object_name = $2",
+                &[&privilege, &object_name],
+            )
+            .await?;
+        Ok(())
+    }
+
+    // get the privileges granted to a user, directly or through the resource
+    // group it is assigned to.
+    pub async fn get_privileges_for_user(&self, user_name: &str) -> anyhow::Result<Vec<Privilege>> {
+        let rows = self
+            .pg
+            .query(
+                "SELECT privilege, object_name FROM nexus_grants
+                 WHERE grantee = $1 OR grantee IN (
+                    SELECT g.name FROM resource_groups g
+                    JOIN resource_group_members m ON m.resource_group_id = g.id
+                    WHERE m.user_name = $1
+                 )",
+                &[&user_name],
+            )
+            .await?;
+        let privileges = rows
+            .iter()
+            .filter_map(|row| Privilege::from_row(row.get(0), row.get(1)))
+            .collect();
+        Ok(privileges)
+    }
+
     // get the database type for a given peer id
     pub async fn get_peer_type_for_id(&self, peer_id: i32) -> anyhow::Result<DbType> {
         let stmt = self
@@ -230,6 +905,111 @@ impl Catalog {
         Ok(peers)
     }
 
+    /// The version of each peer by its name, which altering the peer bumps.
+    pub async fn get_peer_versions(&self) -> anyhow::Result<HashMap<String, i32>> {
+        let stmt = self
+            .pg
+            .prepare_typed("SELECT name, version FROM peers", &[])
+            .await?;
+
+        let rows = self.pg.query(&stmt, &[]).await?;
+        Ok(rows
+            .iter()
+            .map(|row| (row.get::<_, String>(0).to_lowercase(), row.get(1)))
+            .collect())
+    }
+
+    /// Returns every peer along with when it was created and quarantined,
+    /// ordered by name.
+    pub async fn get_peer_infos(&self) -> anyhow::Result<Vec<PeerInfo>> {
+        let stmt = self
+            .pg
+            .prepare_typed(
+                "SELECT name, type, options, created_at, quarantined_at, comment
+                 FROM peers ORDER BY name",
+                &[],
+            )
+            .await?;
+
+        let rows = self.pg.query(&stmt, &[]).await?;
+
+        let mut peers = Vec::with_capacity(rows.len());
+        for row in rows {
+            let name: String = row.get(0);
+            let peer_type: i32 = row.get(1);
+            let options: Vec<u8> = row.get(2);
+            let created_at: chrono::NaiveDateTime = row.get(3);
+            let quarantined_at: Option<chrono::NaiveDateTime> = row.get(4);
+            let comment: Option<String> = row.get(5);
+            let db_type = DbType::from_i32(peer_type);
+            let config = self.get_config(db_type, &name, options).await?;
+
+            let peer = Peer {
+                name: name.to_lowercase(),
+                r#type: peer_type,
+                config,
+            };
+            peers.push(PeerInfo {
+                peer,
+                created_at,
+                quarantined_at,
+                comment,
+            });
+        }
+
+        Ok(peers)
+    }
+
+    // quarantining a peer that is already quarantined keeps the time it was
+    // first quarantined.
+    pub async fn set_peer_quarantined(
+        &self,
+        peer_name: &str,
+        quarantined: bool,
+    ) -> anyhow::Result<()> {
+        let rows = self
+            .pg
+            .execute(
+                "UPDATE peers SET quarantined_at =
+                    CASE WHEN $2 THEN COALESCE(quarantined_at, NOW()) ELSE NULL END
+                 WHERE name = $1",
+                &[&peer_name, &quarantined],
+            )
+            .await?;
+        if rows == 0 {
+            return Err(anyhow!("unable to find peer {}", peer_name));
+        }
+        Ok(())
+    }
+
+    // set or remove the comment of a peer, returns false if there is no such
+    // peer.
+    pub async fn set_peer_comment(
+        &self,
+        peer_name: &str,
+        comment: Option<&str>,
+    ) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute(
+                "UPDATE peers SET comment = $2 WHERE name = $1",
+                &[&peer_name, &comment],
+            )
+            .await?;
+        Ok(rows > 0)
+    }
+
+    pub async fn get_quarantined_peers(&self) -> anyhow::Result<Vec<String>> {
+        let rows = self
+            .pg
+            .query(
+                "SELECT name FROM peers WHERE quarantined_at IS NOT NULL",
+                &[],
+            )
+            .await?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
     pub async fn get_peer(&self, peer_name: &str) -> anyhow::Result<Peer> {
         let stmt = self
             .pg
@@ -293,6 +1073,7 @@ impl Catalog {
         name: &str,
         options: Vec<u8>,
     ) -> anyhow::Result<Option<Config>> {
+        let options = encryption::open(&self.master_keys(), name, options)?;
         match db_type {
             Some(DbType::Snowflake) => {
                 let err = format!("unable to decode {} options for peer {}", "snowflake", name);
@@ -346,6 +1127,30 @@ impl Catalog {
                         .context(err)?;
                 Ok(Some(Config::EventhubGroupConfig(eventhub_group_config)))
             }
+            Some(DbType::Pinecone) => {
+                let err = format!("unable to decode {} options for peer {}", "pinecone", name);
+                let pinecone_config =
+                    pt::peerdb_peers::PineconeConfig::decode(options.as_slice()).context(err)?;
+                Ok(Some(Config::PineconeConfig(pinecone_config)))
+            }
+            Some(DbType::Weaviate) => {
+                let err = format!("unable to decode {} options for peer {}", "weaviate", name);
+                let weaviate_config =
+                    pt::peerdb_peers::WeaviateConfig::decode(options.as_slice()).context(err)?;
+                Ok(Some(Config::WeaviateConfig(weaviate_config)))
+            }
+            Some(DbType::File) => {
+                let err = format!("unable to decode {} options for peer {}", "file", name);
+                let file_config =
+                    pt::peerdb_peers::FileConfig::decode(options.as_slice()).context(err)?;
+                Ok(Some(Config::FileConfig(file_config)))
+            }
+            Some(DbType::Webhook) => {
+                let err = format!("unable to decode {} options for peer {}", "webhook", name);
+                let webhook_config =
+                    pt::peerdb_peers::WebhookConfig::decode(options.as_slice()).context(err)?;
+                Ok(Some(Config::WebhookConfig(webhook_config)))
+            }
             None => Ok(None),
         }
     }
@@ -379,12 +1184,14 @@ impl Catalog {
             .pg
             .prepare_typed(
                 "INSERT INTO flows (name, source_peer, destination_peer, description,
-                     source_table_identifier, destination_table_identifier) VALUES ($1, $2, $3, $4, $5, $6)",
+                     source_table_identifier, destination_table_identifier, flow_spec) VALUES ($1, $2, $3, $4, $5, $6, $7)",
                 &[types::Type::TEXT, types::Type::INT4, types::Type::INT4, types::Type::TEXT,
-                 types::Type::TEXT, types::Type::TEXT],
+                 types::Type::TEXT, types::Type::TEXT, types::Type::JSONB],
             )
             .await?;
 
+        let flow_spec = serde_json::to_value(job).context("unable to serialize flow job")?;
+
         for table_mapping in &job.table_mappings {
             let _rows = self
                 .pg
@@ -407,6 +1214,7 @@ impl Catalog {
                                 destination_peer_id,
                             )
                             .await?,
+                        &flow_spec,
                     ],
                 )
                 .await?;
@@ -459,9 +1267,9 @@ impl Catalog {
             .pg
             .prepare_typed(
                 "INSERT INTO flows (name, source_peer, destination_peer, description,
-                     destination_table_identifier, query_string, flow_metadata) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+                     destination_table_identifier, query_string, flow_metadata, flow_spec) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
                 &[types::Type::TEXT, types::Type::INT4, types::Type::INT4, types::Type::TEXT,
-                 types::Type::TEXT, types::Type::TEXT, types::Type::JSONB],
+                 types::Type::TEXT, types::Type::TEXT, types::Type::JSONB, types::Type::JSONB],
             )
             .await?;
 
@@ -482,6 +1290,7 @@ impl Catalog {
                     &job.query_string,
                     &serde_json::to_value(job.flow_options.clone())
                         .context("unable to serialize flow options")?,
+                    &serde_json::to_value(job).context("unable to serialize flow job")?,
                 ],
             )
             .await?;
@@ -489,6 +1298,23 @@ impl Catalog {
         Ok(())
     }
 
+    // get the statement a mirror was created from, as it was serialized when the
+    // mirror was created, tables added or dropped since don't change it. mirrors
+    // created before the spec was recorded have none.
+    pub async fn get_flow_job_spec(
+        &self,
+        flow_job_name: &str,
+    ) -> anyhow::Result<Option<serde_json::Value>> {
+        let row = self
+            .pg
+            .query_opt(
+                "SELECT flow_spec FROM flows WHERE name = $1 AND flow_spec IS NOT NULL LIMIT 1",
+                &[&flow_job_name],
+            )
+            .await?;
+        Ok(row.map(|row| row.get(0)))
+    }
+
     pub async fn update_workflow_id_for_flow_job(
         &self,
         flow_job_name: &str,
@@ -554,6 +1380,44 @@ impl Catalog {
         }))
     }
 
+    pub async fn set_flow_job_paused(
+        &self,
+        flow_job_name: &str,
+        paused: bool,
+    ) -> anyhow::Result<()> {
+        let rows = self
+            .pg
+            .execute(
+                "UPDATE flows SET paused = $1, paused_by_quarantine = FALSE WHERE name = $2",
+                &[&paused, &flow_job_name],
+            )
+            .await?;
+        if rows == 0 {
+            return Err(anyhow!("unable to find metadata for flow"));
+        }
+        Ok(())
+    }
+
+    // like set_flow_job_paused, but remembers that the flow job was paused
+    // for a quarantined peer so that it can be resumed once the peer recovers.
+    pub async fn set_flow_job_paused_by_quarantine(
+        &self,
+        flow_job_name: &str,
+        paused: bool,
+    ) -> anyhow::Result<()> {
+        let rows = self
+            .pg
+            .execute(
+                "UPDATE flows SET paused = $1, paused_by_quarantine = $1 WHERE name = $2",
+                &[&paused, &flow_job_name],
+            )
+            .await?;
+        if rows == 0 {
+            return Err(anyhow!("unable to find metadata for flow"));
+        }
+        Ok(())
+    }
+
     pub async fn delete_flow_job_entry(&self, flow_job_name: &str) -> anyhow::Result<()> {
         let rows = self
             .pg
@@ -562,6 +1426,448 @@ impl Catalog {
         if rows == 0 {
             return Err(anyhow!("unable to delete flow job metadata"));
         }
+        self.revoke_privilege_from_all(&Privilege::OperateOnMirror(flow_job_name.to_owned()))
+            .await
+    }
+
+    /// Returns every mirror in the catalog, ordered by name.
+    pub async fn get_mirrors(&self) -> anyhow::Result<Vec<MirrorInfo>> {
+        // cdc flow jobs have a row per table mapping, they all share the
+        // same peers and state.
+        let rows = self
+            .pg
+            .query(
+                "SELECT DISTINCT ON (f.name) f.name, sp.name, dp.name,
+                        f.query_string IS NULL, f.paused, f.paused_by_quarantine,
+                        NULLIF(f.description, '')
+                 FROM flows f
+                 JOIN peers sp ON sp.id = f.source_peer
+                 JOIN peers dp ON dp.id = f.destination_peer
+                 ORDER BY f.name",
+                &[],
+            )
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| MirrorInfo {
+                name: row.get(0),
+                source_peer: row.get(1),
+                destination_peer: row.get(2),
+                is_cdc: row.get(3),
+                paused: row.get(4),
+                paused_by_quarantine: row.get(5),
+                comment: row.get(6),
+            })
+            .collect())
+    }
+
+    // set the description of a flow job on every row of it, an empty
+    // description removes it. returns false if there is no such flow job.
+    pub async fn set_flow_job_description(
+        &self,
+        flow_job_name: &str,
+        description: &str,
+    ) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute(
+                "UPDATE flows SET description = $2 WHERE name = $1",
+                &[&flow_job_name, &description],
+            )
+            .await?;
+        Ok(rows > 0)
+    }
+
+    pub async fn get_flow_job_description(
+        &self,
+        flow_job_name: &str,
+    ) -> anyhow::Result<Option<String>> {
+        let row = self
+            .pg
+            .query_opt(
+                "SELECT NULLIF(description, '') FROM flows WHERE name = $1 LIMIT 1",
+                &[&flow_job_name],
+            )
+            .await?;
+        Ok(row.and_then(|row| row.get(0)))
+    }
+
+    // get the tables that a flow job writes to on its destination peer
+    pub async fn get_destination_tables_for_flow_job(
+        &self,
+        flow_job_name: &str,
+    ) -> anyhow::Result<Vec<String>> {
+        let rows = self
+            .pg
+            .query(
+                "SELECT DISTINCT destination_table_identifier FROM flows
+                 WHERE name = $1 AND destination_table_identifier IS NOT NULL",
+                &[&flow_job_name],
+            )
+            .await?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    // add a table to a cdc flow job, returns false if the flow job already
+    // replicates from the source table or to the destination table. the spec
+    // of the flow job stays the statement it was created from.
+    pub async fn add_table_mapping_to_flow_job(
+        &self,
+        flow_job_name: &str,
+        table_mapping: &FlowJobTableMapping,
+    ) -> anyhow::Result<bool> {
+        let row = self
+            .pg
+            .query_opt(
+                "SELECT source_peer, destination_peer FROM flows
+                 WHERE name = $1 AND query_string IS NULL LIMIT 1",
+                &[&flow_job_name],
+            )
+            .await?
+            .ok_or_else(|| anyhow!("unable to find metadata for flow"))?;
+        let source_peer_id: i32 = row.get(0);
+        let destination_peer_id: i32 = row.get(1);
+
+        let source_table_identifier = self
+            .normalize_schema_for_table_identifier(
+                &table_mapping.source_table_identifier,
+                source_peer_id,
+            )
+            .await?;
+        let destination_table_identifier = self
+            .normalize_schema_for_table_identifier(
+                &table_mapping.destination_table_identifier,
+                destination_peer_id,
+            )
+            .await?;
+
+        let existing = self
+            .pg
+            .query_opt(
+                "SELECT 1 FROM flows WHERE name = $1
+                 AND (source_table_identifier = $2 OR destination_table_identifier = $3)",
+                &[
+                    &flow_job_name,
+                    &source_table_identifier,
+                    &destination_table_identifier,
+                ],
+            )
+            .await?;
+        if existing.is_some() {
+            return Ok(false);
+        }
+
+        // the new row shares everything but the tables with the rows already
+        // recorded for the flow job.
+        self.pg
+            .execute(
+                "INSERT INTO flows (name, source_peer, destination_peer, description,
+                     source_table_identifier, destination_table_identifier, workflow_id,
+                     config_proto, flow_spec, paused)
+                 SELECT name, source_peer, destination_peer, description, $2, $3,
+                     workflow_id, config_proto, flow_spec, paused
+                 FROM flows WHERE name = $1 AND query_string IS NULL LIMIT 1",
+                &[
+                    &flow_job_name,
+                    &source_table_identifier,
+                    &destination_table_identifier,
+                ],
+            )
+            .await?;
+        Ok(true)
+    }
+
+    // find the table mapping of a cdc flow job that replicates from the source
+    // table, identifiers are normalized like they are when the flow is created.
+    pub async fn get_table_mapping_for_flow_job(
+        &self,
+        flow_job_name: &str,
+        source_table_identifier: &str,
+    ) -> anyhow::Result<Option<FlowJobTableMapping>> {
+        let row = self
+            .pg
+            .query_opt(
+                "SELECT source_peer FROM flows
+                 WHERE name = $1 AND query_string IS NULL LIMIT 1",
+                &[&flow_job_name],
+            )
+            .await?
+            .ok_or_else(|| anyhow!("unable to find metadata for flow"))?;
+        let source_peer_id: i32 = row.get(0);
+        let source_table_identifier = self
+            .normalize_schema_for_table_identifier(source_table_identifier, source_peer_id)
+            .await?;
+
+        let row = self
+            .pg
+            .query_opt(
+                "SELECT source_table_identifier, destination_table_identifier FROM flows
+                 WHERE name = $1 AND source_table_identifier = $2",
+                &[&flow_job_name, &source_table_identifier],
+            )
+            .await?;
+        Ok(row.map(|row| FlowJobTableMapping {
+            source_table_identifier: row.get(0),
+            destination_table_identifier: row.get(1),
+            partition_key: None,
+            exclude: vec![],
+        }))
+    }
+
+    // remove a table from a cdc flow job, the mapping is the one returned by
+    // get_table_mapping_for_flow_job.
+    pub async fn remove_table_mapping_from_flow_job(
+        &self,
+        flow_job_name: &str,
+        table_mapping: &FlowJobTableMapping,
+    ) -> anyhow::Result<()> {
+        self.pg
+            .execute(
+                "DELETE FROM flows WHERE name = $1 AND source_table_identifier = $2",
+                &[&flow_job_name, &table_mapping.source_table_identifier],
+            )
+            .await?;
         Ok(())
     }
+
+    // get the names of all flow jobs that use the peer as source or destination
+    pub async fn get_flow_jobs_for_peer(&self, peer_name: &str) -> anyhow::Result<Vec<String>> {
+        let rows = self
+            .pg
+            .query(
+                "SELECT DISTINCT f.name FROM flows f
+                 JOIN peers p ON p.id = f.source_peer OR p.id = f.destination_peer
+                 WHERE p.name = $1",
+                &[&peer_name],
+            )
+            .await?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    // get the config the flow server stored for a cdc flow job, query
+    // replication jobs and jobs that were never started have none.
+    pub async fn get_cdc_flow_config(
+        &self,
+        flow_job_name: &str,
+    ) -> anyhow::Result<Option<FlowConnectionConfigs>> {
+        let row = self
+            .pg
+            .query_opt(
+                "SELECT config_proto FROM flows
+                 WHERE name = $1 AND query_string IS NULL AND config_proto IS NOT NULL
+                 LIMIT 1",
+                &[&flow_job_name],
+            )
+            .await?;
+
+        row.map(|row| {
+            let config_proto: Vec<u8> = row.get(0);
+            let config_proto = encryption::open(&self.master_keys(), flow_job_name, config_proto)?;
+            FlowConnectionConfigs::decode(config_proto.as_slice())
+                .with_context(|| format!("unable to decode config of flow job {}", flow_job_name))
+        })
+        .transpose()
+    }
+
+    // point the flow job at new_peer wherever it used old_peer
+    pub async fn replace_peer_for_flow_job(
+        &self,
+        flow_job_name: &str,
+        old_peer_name: &str,
+        new_peer_name: &str,
+    ) -> anyhow::Result<()> {
+        let old_peer_id = self.get_peer_id_i32(old_peer_name).await?;
+        let new_peer_id = self.get_peer_id_i32(new_peer_name).await?;
+        let rows = self
+            .pg
+            .execute(
+                "UPDATE flows SET
+                    source_peer = CASE WHEN source_peer = $2 THEN $3 ELSE source_peer END,
+                    destination_peer = CASE WHEN destination_peer = $2 THEN $3 ELSE destination_peer END,
+                    flow_spec = jsonb_set(jsonb_set(flow_spec,
+                        '{source_peer}', CASE WHEN flow_spec->>'source_peer' = $4
+                            THEN to_jsonb($5::TEXT) ELSE flow_spec->'source_peer' END),
+                        '{target_peer}', CASE WHEN flow_spec->>'target_peer' = $4
+                            THEN to_jsonb($5::TEXT) ELSE flow_spec->'target_peer' END)
+                 WHERE name = $1",
+                &[&flow_job_name, &old_peer_id, &new_peer_id, &old_peer_name, &new_peer_name],
+            )
+            .await?;
+        if rows == 0 {
+            return Err(anyhow!("unable to find metadata for flow"));
+        }
+        Ok(())
+    }
+
+    // delete the peer, returns false if no such peer exists
+    pub async fn delete_peer(&self, peer_name: &str) -> anyhow::Result<bool> {
+        let rows = self
+            .pg
+            .execute("DELETE FROM peers WHERE name = $1", &[&peer_name])
+            .await?;
+        self.revoke_privilege_from_all(&Privilege::UsageOnPeer(peer_name.to_owned()))
+            .await?;
+        Ok(rows > 0)
+    }
+
+    // creates a table staged by a session in the session's schema, a table
+    // left behind by a staging that failed is replaced.
+    pub async fn create_session_table(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[(String, String)],
+    ) -> anyhow::Result<()> {
+        let schema = quote_identifier(schema);
+        let table = format!("{}.{}", schema, quote_identifier(table));
+        let columns = columns
+            .iter()
+            .map(|(name, data_type)| format!("{} {}", quote_identifier(name), data_type))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.pg
+            .batch_execute(&format!(
+                "CREATE SCHEMA IF NOT EXISTS {}; DROP TABLE IF EXISTS {}; CREATE TABLE {} ({})",
+                schema, table, table, columns
+            ))
+            .await?;
+        Ok(())
+    }
+
+    // inserts rows into a session table, given as a json array of objects
+    // keyed by column name. returns the number of rows inserted.
+    pub async fn insert_session_table_rows(
+        &self,
+        schema: &str,
+        table: &str,
+        rows: &serde_json::Value,
+    ) -> anyhow::Result<u64> {
+        let table = format!("{}.{}", quote_identifier(schema), quote_identifier(table));
+        let stmt = format!(
+            "INSERT INTO {} SELECT * FROM json_populate_recordset(NULL::{}, $1)",
+            table, table
+        );
+        Ok(self.pg.execute(&stmt, &[rows]).await?)
+    }
+
+    // drops the schema of a session along with the tables it staged.
+    pub async fn drop_session_schema(&self, schema: &str) -> anyhow::Result<()> {
+        self.pg
+            .batch_execute(&format!(
+                "DROP SCHEMA IF EXISTS {} CASCADE",
+                quote_identifier(schema)
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(name: &str) -> String {
+        std::env::var(name).unwrap_or_else(|_| panic!("{} is not set", name))
+    }
+
+    // the catalog postgres of the environment, connected to the database of
+    // the given name.
+    fn postgres_config(database: &str) -> PostgresConfig {
+        PostgresConfig {
+            host: env("PEERDB_CATALOG_HOST"),
+            port: env("PEERDB_CATALOG_PORT").parse().unwrap(),
+            user: env("PEERDB_CATALOG_USER"),
+            password: env("PEERDB_CATALOG_PASSWORD"),
+            database: database.to_owned(),
+            transaction_snapshot: "".to_string(),
+            money_mapping: 0,
+        }
+    }
+
+    // an empty database of its own for a test to migrate, recreated for each run.
+    async fn empty_database(name: &str) -> Client {
+        let admin = connect_postgres(&postgres_config(&env("PEERDB_CATALOG_DATABASE")))
+            .await
+            .unwrap();
+        let quoted = quote_identifier(name);
+        // each on its own, as neither runs in a transaction.
+        for statement in [
+            format!("DROP DATABASE IF EXISTS {} WITH (FORCE)", quoted),
+            format!("CREATE DATABASE {}", quoted),
+        ] {
+            admin.batch_execute(&statement).await.unwrap();
+        }
+        connect_postgres(&postgres_config(name)).await.unwrap()
+    }
+
+    async fn schema_version(client: &Client) -> i32 {
+        client
+            .query_one("SELECT max(version) FROM refinery_schema_history", &[])
+            .await
+            .unwrap()
+            .get(0)
+    }
+
+    fn latest_version() -> i32 {
+        embedded::migrations::runner()
+            .get_migrations()
+            .iter()
+            .map(|migration| migration.version() as i32)
+            .max()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_migrate_new_catalog() {
+        let mut client = empty_database("catalog_test_new").await;
+        let has_history: bool = client
+            .query_one(
+                "SELECT to_regclass('refinery_schema_history') IS NOT NULL",
+                &[],
+            )
+            .await
+            .unwrap()
+            .get(0);
+        assert!(!has_history);
+
+        run_migrations(&mut client).await.unwrap();
+        assert_eq!(schema_version(&client).await, latest_version());
+        client.query("SELECT name FROM peers", &[]).await.unwrap();
+
+        // a migrated catalog is left as it is.
+        run_migrations(&mut client).await.unwrap();
+        assert_eq!(schema_version(&client).await, latest_version());
+    }
+
+    #[tokio::test]
+    async fn test_newer_catalog_schema_rejected() {
+        let mut client = empty_database("catalog_test_newer").await;
+        run_migrations(&mut client).await.unwrap();
+        let newer_version = latest_version() + 1;
+        client
+            .execute(
+                "INSERT INTO refinery_schema_history (version, name, applied_on, checksum)
+                 VALUES ($1, 'from_a_newer_server', '2024-01-01T00:00:00Z', '0')",
+                &[&newer_version],
+            )
+            .await
+            .unwrap();
+
+        let err = run_migrations(&mut client).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "the catalog schema is at version {} (from_a_newer_server), newer than \
+                 version {} of this server, upgrade the server to run against it",
+                newer_version,
+                latest_version()
+            )
+        );
+        assert_eq!(schema_version(&client).await, newer_version);
+    }
 }
@@ -0,0 +1,213 @@
+use pt::{
+    peerdb_flow::{FlowConnectionConfigs, OrderingKey, QRepSyncMode, TableMapping},
+    peerdb_peers::Peer,
+    peerdb_route::CreateCdcFlowRequest,
+};
+
+/// Builds a request to create a CDC mirror between two peers. Only the name,
+/// the peers and the table mappings are required, the flow server picks
+/// defaults for everything left unset.
+#[derive(Debug, Clone)]
+pub struct CreateCdcFlow {
+    config: FlowConnectionConfigs,
+    create_catalog_entry: bool,
+    idempotency_key: String,
+}
+
+impl CreateCdcFlow {
+    pub fn new(flow_job_name: impl Into<String>, source: Peer, destination: Peer) -> Self {
+        Self {
+            config: FlowConnectionConfigs {
+                source: Some(source),
+                destination: Some(destination),
+                flow_job_name: flow_job_name.into(),
+                ..Default::default()
+            },
+            create_catalog_entry: true,
+            idempotency_key: String::new(),
+        }
+    }
+
+    pub fn table_mapping(
+        mut self,
+        source_table_identifier: impl Into<String>,
+        destination_table_identifier: impl Into<String>,
+    ) -> Self {
+        self.config.table_mappings.push(TableMapping {
+            source_table_identifier: source_table_identifier.into(),
+            destination_table_identifier: destination_table_identifier.into(),
+            partition_key: String::new(),
+            exclude: vec![],
+        });
+        self
+    }
+
+    pub fn partitioned_table_mapping(
+        mut self,
+        source_table_identifier: impl Into<String>,
+        destination_table_identifier: impl Into<String>,
+        partition_key: impl Into<String>,
+    ) -> Self {
+        self.config.table_mappings.push(TableMapping {
+            source_table_identifier: source_table_identifier.into(),
+            destination_table_identifier: destination_table_identifier.into(),
+            partition_key: partition_key.into(),
+            exclude: vec![],
+        });
+        self
+    }
+
+    pub fn metadata_peer(mut self, metadata_peer: Peer) -> Self {
+        self.config.metadata_peer = Some(metadata_peer);
+        self
+    }
+
+    pub fn do_initial_copy(mut self, do_initial_copy: bool) -> Self {
+        self.config.do_initial_copy = do_initial_copy;
+        self
+    }
+
+    pub fn publication_name(mut self, publication_name: impl Into<String>) -> Self {
+        self.config.publication_name = publication_name.into();
+        self
+    }
+
+    pub fn replication_slot_name(mut self, replication_slot_name: impl Into<String>) -> Self {
+        self.config.replication_slot_name = replication_slot_name.into();
+        self
+    }
+
+    pub fn max_batch_size(mut self, max_batch_size: u32) -> Self {
+        self.config.max_batch_size = max_batch_size;
+        self
+    }
+
+    pub fn snapshot_num_rows_per_partition(mut self, num_rows: u32) -> Self {
+        self.config.snapshot_num_rows_per_partition = num_rows;
+        self
+    }
+
+    pub fn snapshot_max_parallel_workers(mut self, max_parallel_workers: u32) -> Self {
+        self.config.snapshot_max_parallel_workers = max_parallel_workers;
+        self
+    }
+
+    pub fn snapshot_num_tables_in_parallel(mut self, num_tables: u32) -> Self {
+        self.config.snapshot_num_tables_in_parallel = num_tables;
+        self
+    }
+
+    pub fn snapshot_sync_mode(mut self, sync_mode: QRepSyncMode) -> Self {
+        self.config.snapshot_sync_mode = sync_mode as i32;
+        self
+    }
+
+    pub fn snapshot_staging_path(mut self, staging_path: impl Into<String>) -> Self {
+        self.config.snapshot_staging_path = staging_path.into();
+        self
+    }
+
+    pub fn cdc_sync_mode(mut self, sync_mode: QRepSyncMode) -> Self {
+        self.config.cdc_sync_mode = sync_mode as i32;
+        self
+    }
+
+    pub fn cdc_staging_path(mut self, staging_path: impl Into<String>) -> Self {
+        self.config.cdc_staging_path = staging_path.into();
+        self
+    }
+
+    /// Snowflake storage integration to stage through, overrides the one set
+    /// on the destination peer.
+    pub fn staging_integration(mut self, staging_integration: impl Into<String>) -> Self {
+        self.config.staging_integration = staging_integration.into();
+        self
+    }
+
+    /// Refresh the statistics of destination tables once at least this many
+    /// rows were loaded into them since the last refresh.
+    pub fn refresh_statistics_threshold(mut self, threshold: u32) -> Self {
+        self.config.refresh_statistics_threshold = threshold;
+        self
+    }
+
+    pub fn soft_delete(mut self, soft_delete: bool) -> Self {
+        self.config.soft_delete = soft_delete;
+        self
+    }
+
+    /// Mark deleted rows in this column instead of `_PEERDB_IS_DELETED`, turns
+    /// on soft deletes.
+    pub fn soft_delete_column(mut self, column: impl Into<String>) -> Self {
+        self.config.soft_delete = true;
+        self.config.soft_delete_col_name = column.into();
+        self
+    }
+
+    /// Stamp the destination rows with the time they were last synced in this
+    /// column.
+    pub fn synced_at_column(mut self, column: impl Into<String>) -> Self {
+        self.config.synced_at_col_name = column.into();
+        self
+    }
+
+    /// Stamp the replicated rows with the commit time of their change at the
+    /// source and the time it was applied, and report the latency in between.
+    pub fn track_latency(mut self, track_latency: bool) -> Self {
+        self.config.track_latency = track_latency;
+        self
+    }
+
+    /// Shut the mirror down once the initial copy completes instead of
+    /// continuing with CDC.
+    pub fn initial_copy_only(mut self, initial_copy_only: bool) -> Self {
+        self.config.initial_copy_only = initial_copy_only;
+        self
+    }
+
+    /// Only applies to eventhub destinations.
+    pub fn push_batch_size(mut self, push_batch_size: i64) -> Self {
+        self.config.push_batch_size = push_batch_size;
+        self
+    }
+
+    /// Only applies to eventhub destinations.
+    pub fn push_parallelism(mut self, push_parallelism: i64) -> Self {
+        self.config.push_parallelism = push_parallelism;
+        self
+    }
+
+    /// Only applies to queue destinations.
+    pub fn ordering_key(mut self, ordering_key: OrderingKey) -> Self {
+        self.config.ordering_key = ordering_key as i32;
+        self
+    }
+
+    /// Whether the flow server should record the mirror in the catalog,
+    /// defaults to `true`.
+    pub fn create_catalog_entry(mut self, create_catalog_entry: bool) -> Self {
+        self.create_catalog_entry = create_catalog_entry;
+        self
+    }
+
+    /// Retrying the request with the same key returns the workflow started by
+    /// the first request instead of starting another one.
+    pub fn idempotency_key(mut self, idempotency_key: impl Into<String>) -> Self {
+        self.idempotency_key = idempotency_key.into();
+        self
+    }
+
+    pub fn build(self) -> CreateCdcFlowRequest {
+        CreateCdcFlowRequest {
+            connection_configs: Some(self.config),
+            create_catalog_entry: self.create_catalog_entry,
+            idempotency_key: self.idempotency_key,
+        }
+    }
+}
+
+impl From<CreateCdcFlow> for CreateCdcFlowRequest {
+    fn from(builder: CreateCdcFlow) -> Self {
+        builder.build()
+    }
+}
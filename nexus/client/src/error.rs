@@ -0,0 +1,52 @@
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub enum Error {
+    #[snafu(display("invalid flow server endpoint {}: {}", endpoint, source))]
+    InvalidEndpoint {
+        endpoint: String,
+        source: tonic::codegen::http::uri::InvalidUri,
+    },
+
+    #[snafu(display("unable to connect to flow server at {}: {}", endpoint, source))]
+    Connect {
+        endpoint: String,
+        source: tonic::transport::Error,
+    },
+
+    #[snafu(display("flow server request failed: {}", source))]
+    Rpc { source: tonic::Status },
+
+    #[snafu(display("peer {} is invalid: {}", peer_name, message))]
+    InvalidPeer { peer_name: String, message: String },
+
+    #[snafu(display("unable to create peer {}: {}", peer_name, message))]
+    PeerCreation { peer_name: String, message: String },
+
+    #[snafu(display("unable to shutdown mirror {}: {}", flow_job_name, message))]
+    Shutdown {
+        flow_job_name: String,
+        message: String,
+    },
+
+    #[snafu(display("unable to pause mirror {}: {}", flow_job_name, message))]
+    Pause {
+        flow_job_name: String,
+        message: String,
+    },
+
+    #[snafu(display("unable to resume mirror {}: {}", flow_job_name, message))]
+    Resume {
+        flow_job_name: String,
+        message: String,
+    },
+
+    #[snafu(display("unable to get status of mirror {}: {}", flow_job_name, message))]
+    MirrorStatus {
+        flow_job_name: String,
+        message: String,
+    },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,231 @@
+//! A thin client for the PeerDB flow service, wrapping the generated tonic
+//! client with builders and typed errors.
+
+use pt::{
+    peerdb_peers::Peer,
+    peerdb_route::{
+        flow_service_client::FlowServiceClient, mirror_status_response, CdcMirrorStatus,
+        CreateCdcFlowRequest, CreatePeerRequest, CreatePeerStatus, MirrorStatusRequest,
+        PauseFlowRequest, QRepMirrorStatus, ResumeFlowRequest, ShutdownRequest,
+        ValidatePeerRequest, ValidatePeerStatus,
+    },
+};
+use snafu::ResultExt;
+use tonic::transport::Channel;
+
+mod builder;
+mod error;
+
+pub use builder::CreateCdcFlow;
+pub use error::{Error, Result};
+
+use error::{
+    ConnectSnafu, InvalidEndpointSnafu, InvalidPeerSnafu, MirrorStatusSnafu, PauseSnafu,
+    PeerCreationSnafu, ResumeSnafu, RpcSnafu, ShutdownSnafu,
+};
+
+/// The status of a mirror as reported by the flow server.
+#[derive(Debug, Clone)]
+pub enum MirrorStatus {
+    QRep(QRepMirrorStatus),
+    Cdc(CdcMirrorStatus),
+}
+
+#[derive(Debug, Clone)]
+pub struct PeerdbClient {
+    client: FlowServiceClient<Channel>,
+}
+
+impl PeerdbClient {
+    /// Connects to the flow server at `endpoint`, e.g. `http://localhost:8112`.
+    pub async fn connect(endpoint: impl Into<String>) -> Result<Self> {
+        let endpoint = endpoint.into();
+        let channel = Channel::from_shared(endpoint.clone())
+            .context(InvalidEndpointSnafu {
+                endpoint: endpoint.clone(),
+            })?
+            .connect()
+            .await
+            .context(ConnectSnafu { endpoint })?;
+        Ok(Self::new(channel))
+    }
+
+    pub fn new(channel: Channel) -> Self {
+        Self {
+            client: FlowServiceClient::new(channel),
+        }
+    }
+
+    pub async fn validate_peer(&mut self, peer: Peer) -> Result<()> {
+        let peer_name = peer.name.clone();
+        let response = self
+            .client
+            .validate_peer(ValidatePeerRequest { peer: Some(peer) })
+            .await
+            .context(RpcSnafu)?
+            .into_inner();
+
+        if response.status() != ValidatePeerStatus::Valid {
+            return InvalidPeerSnafu {
+                peer_name,
+                message: response.message,
+            }
+            .fail();
+        }
+        Ok(())
+    }
+
+    pub async fn create_peer(&mut self, peer: Peer) -> Result<()> {
+        let peer_name = peer.name.clone();
+        let response = self
+            .client
+            .create_peer(CreatePeerRequest {
+                peer: Some(peer),
+                idempotency_key: String::new(),
+            })
+            .await
+            .context(RpcSnafu)?
+            .into_inner();
+
+        if response.status() != CreatePeerStatus::Created {
+            return PeerCreationSnafu {
+                peer_name,
+                message: response.message,
+            }
+            .fail();
+        }
+        Ok(())
+    }
+
+    /// Starts a CDC mirror and returns the id of the workflow backing it.
+    pub async fn create_cdc_flow(
+        &mut self,
+        request: impl Into<CreateCdcFlowRequest>,
+    ) -> Result<String> {
+        let response = self
+            .client
+            .create_cdc_flow(request.into())
+            .await
+            .context(RpcSnafu)?
+            .into_inner();
+        Ok(response.worflow_id)
+    }
+
+    pub async fn mirror_status(
+        &mut self,
+        flow_job_name: impl Into<String>,
+    ) -> Result<MirrorStatus> {
+        let flow_job_name = flow_job_name.into();
+        let response = self
+            .client
+            .mirror_status(MirrorStatusRequest {
+                flow_job_name: flow_job_name.clone(),
+            })
+            .await
+            .context(RpcSnafu)?
+            .into_inner();
+
+        if !response.error_message.is_empty() {
+            return MirrorStatusSnafu {
+                flow_job_name,
+                message: response.error_message,
+            }
+            .fail();
+        }
+        match response.status {
+            Some(mirror_status_response::Status::QrepStatus(status)) => {
+                Ok(MirrorStatus::QRep(status))
+            }
+            Some(mirror_status_response::Status::CdcStatus(status)) => {
+                Ok(MirrorStatus::Cdc(status))
+            }
+            None => MirrorStatusSnafu {
+                flow_job_name,
+                message: "flow server returned no status",
+            }
+            .fail(),
+        }
+    }
+
+    pub async fn shutdown_flow(
+        &mut self,
+        workflow_id: impl Into<String>,
+        flow_job_name: impl Into<String>,
+        source_peer: Peer,
+        destination_peer: Peer,
+    ) -> Result<()> {
+        let flow_job_name = flow_job_name.into();
+        let response = self
+            .client
+            .shutdown_flow(ShutdownRequest {
+                workflow_id: workflow_id.into(),
+                flow_job_name: flow_job_name.clone(),
+                source_peer: Some(source_peer),
+                destination_peer: Some(destination_peer),
+            })
+            .await
+            .context(RpcSnafu)?
+            .into_inner();
+
+        if !response.ok {
+            return ShutdownSnafu {
+                flow_job_name,
+                message: response.error_message,
+            }
+            .fail();
+        }
+        Ok(())
+    }
+
+    pub async fn pause_flow(
+        &mut self,
+        workflow_id: impl Into<String>,
+        flow_job_name: impl Into<String>,
+    ) -> Result<()> {
+        let flow_job_name = flow_job_name.into();
+        let response = self
+            .client
+            .pause_flow(PauseFlowRequest {
+                workflow_id: workflow_id.into(),
+                flow_job_name: flow_job_name.clone(),
+            })
+            .await
+            .context(RpcSnafu)?
+            .into_inner();
+
+        if !response.ok {
+            return PauseSnafu {
+                flow_job_name,
+                message: response.error_message,
+            }
+            .fail();
+        }
+        Ok(())
+    }
+
+    pub async fn resume_flow(
+        &mut self,
+        workflow_id: impl Into<String>,
+        flow_job_name: impl Into<String>,
+    ) -> Result<()> {
+        let flow_job_name = flow_job_name.into();
+        let response = self
+            .client
+            .resume_flow(ResumeFlowRequest {
+                workflow_id: workflow_id.into(),
+                flow_job_name: flow_job_name.clone(),
+            })
+            .await
+            .context(RpcSnafu)?
+            .into_inner();
+
+        if !response.ok {
+            return ResumeSnafu {
+                flow_job_name,
+                message: response.error_message,
+            }
+            .fail();
+        }
+        Ok(())
+    }
+}
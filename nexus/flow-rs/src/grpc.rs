@@ -1,20 +1,72 @@
-use std::time::Duration;
+use std::{future::Future, time::Duration};
 
 use anyhow::Context;
 use catalog::WorkflowDetails;
 use pt::{
-    flow_model::{FlowJob, QRepFlowJob},
+    flow_model::{FlowJob, FlowJobTableMapping, QRepFlowJob},
     peerdb_flow::{QRepWriteMode, QRepWriteType},
     peerdb_route,
 };
 use serde_json::Value;
+use tokio::time::Instant;
 use tonic_health::pb::health_client;
 
+tokio::task_local! {
+    // deadline of the request the current task serves, see `with_request_deadline`.
+    static REQUEST_DEADLINE: Instant;
+}
+
+/// Runs `f` on behalf of a request that must finish by `deadline`. Calls to the
+/// flow server made while it runs carry the remaining time as their grpc-timeout,
+/// so the flow server gives up on them when the request does.
+pub async fn with_request_deadline<F: Future>(deadline: Instant, f: F) -> F::Output {
+    REQUEST_DEADLINE.scope(deadline, f).await
+}
+
+fn request<T>(message: T) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    if let Ok(deadline) = REQUEST_DEADLINE.try_with(|deadline| *deadline) {
+        request.set_timeout(deadline.saturating_duration_since(Instant::now()));
+    }
+    request
+}
+
 pub enum PeerValidationResult {
     Valid,
     Invalid(String),
 }
 
+/// A failure the flow server reports for a mirror it can't create. The flow
+/// server sends its category, detail and hint in the trailers of the response.
+pub struct MirrorError {
+    pub category: String,
+    pub message: String,
+    pub detail: Option<String>,
+    pub hint: Option<String>,
+}
+
+impl MirrorError {
+    // the mirror error carried by the error of a call to the flow server, if any.
+    pub fn from_error(err: &anyhow::Error) -> Option<Self> {
+        let status = err.downcast_ref::<tonic::Status>()?;
+        let metadata = status.metadata();
+        let category = metadata.get("x-peerdb-error-category")?.to_str().ok()?;
+        let text = |key: &str| {
+            metadata
+                .get_bin(key)
+                .and_then(|value| value.to_bytes().ok())
+                .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+                .filter(|value| !value.is_empty())
+        };
+        Some(Self {
+            category: category.to_owned(),
+            message: status.message().to_owned(),
+            detail: text("x-peerdb-error-detail-bin"),
+            hint: text("x-peerdb-error-hint-bin"),
+        })
+    }
+}
+
 pub struct FlowGrpcClient {
     client: peerdb_route::flow_service_client::FlowServiceClient<tonic::transport::Channel>,
     health_client: health_client::HealthClient<tonic::transport::Channel>,
@@ -75,15 +127,28 @@ impl FlowGrpcClient {
         })
     }
 
+    // the underlying route client, it shares the channel and can be cloned freely.
+    pub fn route_client(
+        &self,
+    ) -> peerdb_route::flow_service_client::FlowServiceClient<tonic::transport::Channel> {
+        self.client.clone()
+    }
+
     async fn start_query_replication_flow(
         &mut self,
         qrep_config: &pt::peerdb_flow::QRepConfig,
     ) -> anyhow::Result<String> {
+        // query replication mirrors are run again by EXECUTE MIRROR under the
+        // same name, so their requests are not keyed.
         let create_qrep_flow_req = pt::peerdb_route::CreateQRepFlowRequest {
             qrep_config: Some(qrep_config.clone()),
-            create_catalog_entry:false
+            create_catalog_entry: false,
+            idempotency_key: "".to_owned(),
         };
-        let response = self.client.create_q_rep_flow(create_qrep_flow_req).await?;
+        let response = self
+            .client
+            .create_q_rep_flow(request(create_qrep_flow_req))
+            .await?;
         let workflow_id = response.into_inner().worflow_id;
         Ok(workflow_id)
     }
@@ -95,7 +160,10 @@ impl FlowGrpcClient {
         let validate_peer_req = pt::peerdb_route::ValidatePeerRequest {
             peer: validate_request.peer.clone(),
         };
-        let response = self.client.validate_peer(validate_peer_req).await?;
+        let response = self
+            .client
+            .validate_peer(request(validate_peer_req))
+            .await?;
         let response_body = &response.into_inner();
         let message = response_body.message.clone();
         let status = response_body.status;
@@ -106,19 +174,72 @@ impl FlowGrpcClient {
         }
     }
 
+    /// Validate the peer and return each check made on it, including the
+    /// checks of what the peer needs to be the source of a cdc mirror.
+    pub async fn get_peer_validation_checks(
+        &mut self,
+        peer: &pt::peerdb_peers::Peer,
+    ) -> anyhow::Result<Vec<pt::peerdb_route::PeerValidationCheck>> {
+        let validate_peer_req = pt::peerdb_route::ValidatePeerRequest {
+            peer: Some(peer.clone()),
+        };
+        let response = self
+            .client
+            .validate_peer(request(validate_peer_req))
+            .await?;
+        Ok(response.into_inner().checks)
+    }
+
+    /// Has the running cdc mirrors of the peer reconnect with its new config,
+    /// which the caller saved in the catalog, at their next batch. Returns the
+    /// mirrors that were updated.
+    pub async fn update_peer(
+        &mut self,
+        peer: &pt::peerdb_peers::Peer,
+    ) -> anyhow::Result<Vec<String>> {
+        let update_peer_req = pt::peerdb_route::UpdatePeerRequest {
+            peer: Some(peer.clone()),
+            catalog_updated: true,
+        };
+        let response = self.client.update_peer(request(update_peer_req)).await?;
+        let update_peer_response = response.into_inner();
+        if update_peer_response.ok {
+            Ok(update_peer_response.flow_job_names)
+        } else {
+            Err(anyhow::anyhow!(format!(
+                "failed to update mirrors of peer: {:?}",
+                update_peer_response.error_message
+            )))
+        }
+    }
+
     async fn start_peer_flow(
         &mut self,
         peer_flow_config: pt::peerdb_flow::FlowConnectionConfigs,
+        idempotency_key: String,
     ) -> anyhow::Result<String> {
         let create_peer_flow_req = pt::peerdb_route::CreateCdcFlowRequest {
             connection_configs: Some(peer_flow_config),
             create_catalog_entry: false,
+            idempotency_key,
         };
-        let response = self.client.create_cdc_flow(create_peer_flow_req).await?;
+        let response = self
+            .client
+            .create_cdc_flow(request(create_peer_flow_req))
+            .await?;
         let workflow_id = response.into_inner().worflow_id;
         Ok(workflow_id)
     }
 
+    // start a cdc flow from a config the flow server stored earlier, used to
+    // restart a mirror after its peers changed.
+    pub async fn restart_peer_flow_job(
+        &mut self,
+        flow_conn_cfg: pt::peerdb_flow::FlowConnectionConfigs,
+    ) -> anyhow::Result<String> {
+        self.start_peer_flow(flow_conn_cfg, "".to_owned()).await
+    }
+
     pub async fn shutdown_flow_job(
         &mut self,
         flow_job_name: &str,
@@ -130,7 +251,10 @@ impl FlowGrpcClient {
             source_peer: Some(workflow_details.source_peer),
             destination_peer: Some(workflow_details.destination_peer),
         };
-        let response = self.client.shutdown_flow(shutdown_flow_req).await?;
+        let response = self
+            .client
+            .shutdown_flow(request(shutdown_flow_req))
+            .await?;
         let shutdown_response = response.into_inner();
         if shutdown_response.ok {
             Ok(())
@@ -142,6 +266,208 @@ impl FlowGrpcClient {
         }
     }
 
+    pub async fn pause_flow_job(
+        &mut self,
+        flow_job_name: &str,
+        workflow_details: WorkflowDetails,
+    ) -> anyhow::Result<()> {
+        let pause_flow_req = pt::peerdb_route::PauseFlowRequest {
+            workflow_id: workflow_details.workflow_id,
+            flow_job_name: flow_job_name.to_string(),
+        };
+        let response = self.client.pause_flow(request(pause_flow_req)).await?;
+        let pause_response = response.into_inner();
+        if pause_response.ok {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(format!(
+                "failed to pause flow job: {:?}",
+                pause_response.error_message
+            )))
+        }
+    }
+
+    pub async fn resume_flow_job(
+        &mut self,
+        flow_job_name: &str,
+        workflow_details: WorkflowDetails,
+    ) -> anyhow::Result<()> {
+        let resume_flow_req = pt::peerdb_route::ResumeFlowRequest {
+            workflow_id: workflow_details.workflow_id,
+            flow_job_name: flow_job_name.to_string(),
+        };
+        let response = self.client.resume_flow(request(resume_flow_req)).await?;
+        let resume_response = response.into_inner();
+        if resume_response.ok {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(format!(
+                "failed to resume flow job: {:?}",
+                resume_response.error_message
+            )))
+        }
+    }
+
+    pub async fn add_tables_to_flow_job(
+        &mut self,
+        flow_job_name: &str,
+        workflow_details: WorkflowDetails,
+        table_mappings: &[FlowJobTableMapping],
+    ) -> anyhow::Result<()> {
+        let add_tables_req = pt::peerdb_route::AddTablesToFlowRequest {
+            workflow_id: workflow_details.workflow_id,
+            flow_job_name: flow_job_name.to_string(),
+            table_mappings: table_mappings
+                .iter()
+                .map(|mapping| pt::peerdb_flow::TableMapping {
+                    source_table_identifier: mapping.source_table_identifier.clone(),
+                    destination_table_identifier: mapping.destination_table_identifier.clone(),
+                    partition_key: mapping.partition_key.clone().unwrap_or_default(),
+                    exclude: mapping.exclude.clone(),
+                })
+                .collect(),
+        };
+        let response = self
+            .client
+            .add_tables_to_flow(request(add_tables_req))
+            .await?;
+        let add_tables_response = response.into_inner();
+        if add_tables_response.ok {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(format!(
+                "failed to add tables to flow job: {:?}",
+                add_tables_response.error_message
+            )))
+        }
+    }
+
+    pub async fn remove_table_from_flow_job(
+        &mut self,
+        flow_job_name: &str,
+        workflow_details: WorkflowDetails,
+        source_table_identifier: &str,
+    ) -> anyhow::Result<()> {
+        let remove_table_req = pt::peerdb_route::RemoveTableFromFlowRequest {
+            workflow_id: workflow_details.workflow_id,
+            flow_job_name: flow_job_name.to_string(),
+            source_table_identifier: source_table_identifier.to_string(),
+        };
+        let response = self
+            .client
+            .remove_table_from_flow(request(remove_table_req))
+            .await?;
+        let remove_table_response = response.into_inner();
+        if remove_table_response.ok {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(format!(
+                "failed to remove table from flow job: {:?}",
+                remove_table_response.error_message
+            )))
+        }
+    }
+
+    // resyncs every table of the flow job when no source table is given.
+    pub async fn resync_flow_job(
+        &mut self,
+        flow_job_name: &str,
+        workflow_details: WorkflowDetails,
+        source_table_identifier: Option<&str>,
+        truncate_destination_tables: bool,
+    ) -> anyhow::Result<()> {
+        let resync_req = pt::peerdb_route::ResyncMirrorRequest {
+            workflow_id: workflow_details.workflow_id,
+            flow_job_name: flow_job_name.to_string(),
+            source_table_identifier: source_table_identifier.unwrap_or_default().to_string(),
+            truncate_destination_tables,
+        };
+        let response = self.client.resync_mirror(request(resync_req)).await?;
+        let resync_response = response.into_inner();
+        if resync_response.ok {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(format!(
+                "failed to resync flow job: {:?}",
+                resync_response.error_message
+            )))
+        }
+    }
+
+    pub async fn pause_table(
+        &mut self,
+        flow_job_name: &str,
+        workflow_details: WorkflowDetails,
+        table_identifier: &str,
+        policy: pt::peerdb_flow::TablePausePolicy,
+    ) -> anyhow::Result<()> {
+        self.set_table_paused(
+            flow_job_name,
+            workflow_details,
+            table_identifier,
+            true,
+            policy,
+        )
+        .await
+    }
+
+    pub async fn resume_table(
+        &mut self,
+        flow_job_name: &str,
+        workflow_details: WorkflowDetails,
+        table_identifier: &str,
+    ) -> anyhow::Result<()> {
+        self.set_table_paused(
+            flow_job_name,
+            workflow_details,
+            table_identifier,
+            false,
+            pt::peerdb_flow::TablePausePolicy::Buffer,
+        )
+        .await
+    }
+
+    async fn set_table_paused(
+        &mut self,
+        flow_job_name: &str,
+        workflow_details: WorkflowDetails,
+        table_identifier: &str,
+        paused: bool,
+        policy: pt::peerdb_flow::TablePausePolicy,
+    ) -> anyhow::Result<()> {
+        let pause_table_req = pt::peerdb_route::PauseTableRequest {
+            workflow_id: workflow_details.workflow_id,
+            flow_job_name: flow_job_name.to_string(),
+            table_identifier: table_identifier.to_string(),
+            paused,
+            policy: policy as i32,
+        };
+        let response = self.client.pause_table(request(pause_table_req)).await?;
+        let pause_table_response = response.into_inner();
+        if pause_table_response.ok {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(format!(
+                "failed to change state of table {}: {:?}",
+                table_identifier, pause_table_response.error_message
+            )))
+        }
+    }
+
+    pub async fn get_mirror_status(
+        &mut self,
+        flow_job_name: &str,
+    ) -> anyhow::Result<peerdb_route::MirrorStatusResponse> {
+        let mirror_status_req = pt::peerdb_route::MirrorStatusRequest {
+            flow_job_name: flow_job_name.to_string(),
+        };
+        let response = self
+            .client
+            .mirror_status(request(mirror_status_req))
+            .await?;
+        Ok(response.into_inner())
+    }
+
     pub async fn start_peer_flow_job(
         &mut self,
         job: &FlowJob,
@@ -154,9 +480,20 @@ impl FlowGrpcClient {
                 source_table_identifier: mapping.source_table_identifier.clone(),
                 destination_table_identifier: mapping.destination_table_identifier.clone(),
                 partition_key: mapping.partition_key.clone().unwrap_or_default(),
+                exclude: mapping.exclude.clone(),
             });
         });
 
+        let schema_mappings = job
+            .schema_mappings
+            .iter()
+            .map(|mapping| pt::peerdb_flow::SchemaMapping {
+                source_schema: mapping.source_schema.clone(),
+                destination_schema: mapping.destination_schema.clone(),
+                exclude_tables: mapping.exclude_tables.clone(),
+            })
+            .collect();
+
         let do_initial_copy = job.do_initial_copy;
         let publication_name = job.publication_name.clone();
         let replication_slot_name = job.replication_slot_name.clone();
@@ -187,14 +524,31 @@ impl FlowGrpcClient {
                 .unwrap_or(0),
             cdc_staging_path: job.cdc_staging_path.clone().unwrap_or_default(),
             soft_delete: job.soft_delete,
+            soft_delete_col_name: job.soft_delete_column.clone().unwrap_or_default(),
+            synced_at_col_name: job.synced_at_column.clone().unwrap_or_default(),
             replication_slot_name: replication_slot_name.unwrap_or_default(),
             push_batch_size: job.push_batch_size.unwrap_or_default(),
             push_parallelism: job.push_parallelism.unwrap_or_default(),
             max_batch_size: job.max_batch_size.unwrap_or_default(),
+            initial_copy_only: job.initial_copy_only,
+            ordering_key: job
+                .ordering_key
+                .as_ref()
+                .map(|k| k.as_proto_ordering_key())
+                .unwrap_or(0),
+            staging_integration: job.staging_integration.clone().unwrap_or_default(),
+            refresh_statistics_threshold: job.refresh_statistics_threshold.unwrap_or_default(),
+            apply_window: job.apply_window.as_ref().map(|w| w.as_proto_apply_window()),
+            track_latency: job.track_latency,
+            schema_mappings,
+            schema_refresh_interval_seconds: job.schema_refresh_interval.unwrap_or_default(),
             ..Default::default()
         };
 
-        self.start_peer_flow(flow_conn_cfg).await
+        // keyed by the mirror, the flow server forgets the key once the mirror
+        // is dropped, so a retried CREATE MIRROR doesn't start a second flow.
+        let idempotency_key = format!("create-mirror-{}", job.name);
+        self.start_peer_flow(flow_conn_cfg, idempotency_key).await
     }
 
     pub async fn start_qrep_flow_job(
@@ -251,6 +605,8 @@ impl FlowGrpcClient {
                         }
                     }
                     "staging_path" => cfg.staging_path = s.clone(),
+                    "staging_integration" => cfg.staging_integration = s.clone(),
+                    "schedule" => cfg.cron_schedule = s.clone(),
                     _ => return anyhow::Result::Err(anyhow::anyhow!("invalid str option {}", key)),
                 },
                 Value::Number(n) => match key.as_str() {
@@ -279,6 +635,11 @@ impl FlowGrpcClient {
                             cfg.num_rows_per_partition = n as u32;
                         }
                     }
+                    "refresh_statistics_threshold" => {
+                        if let Some(n) = n.as_i64() {
+                            cfg.refresh_statistics_threshold = n as u32;
+                        }
+                    }
                     _ => return anyhow::Result::Err(anyhow::anyhow!("invalid num option {}", key)),
                 },
                 Value::Bool(v) => {
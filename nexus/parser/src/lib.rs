@@ -1,22 +1,80 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    ops::ControlFlow,
+    sync::{Arc, Mutex as StdMutex},
+};
 
 use analyzer::{
-    CursorEvent, PeerCursorAnalyzer, PeerDDL, PeerDDLAnalyzer, PeerExistanceAnalyzer,
-    QueryAssocation, StatementAnalyzer,
+    parse_peerdb_statement, CursorEvent, FederatedQueryAnalyzer, FederatedTable,
+    NotificationStatement, PeerCursorAnalyzer, PeerDBStatement, PeerDDL, PeerDDLAnalyzer,
+    PeerExistanceAnalyzer, PeerShowAnalyzer, QueryAssocation, SessionVariables, StatementAnalyzer,
+    PEERDB_STATEMENTS,
 };
 use async_trait::async_trait;
 use catalog::Catalog;
+use peer_cursor::util::cte_names;
 use pgwire::{
     api::{stmt::QueryParser, Type},
     error::{ErrorInfo, PgWireError, PgWireResult},
 };
-use sqlparser::{ast::Statement, dialect::PostgreSqlDialect, parser::Parser};
+use sqlparser::{
+    ast::{
+        visit_expressions, visit_relations_mut, CopyLegacyCsvOption, CopyLegacyOption, CopyOption,
+        CopyTarget, Expr, Function, FunctionArg, FunctionArgExpr, Ident, ObjectName, SelectItem,
+        SetExpr, Statement, Value,
+    },
+    dialect::PostgreSqlDialect,
+    parser::Parser,
+};
 use tokio::sync::Mutex;
 
 const DIALECT: PostgreSqlDialect = PostgreSqlDialect {};
 
+/// The settings a session keeps in nexus rather than setting them on the
+/// catalog, most clients set some of these on connect. The ones after
+/// `timezone` can only be shown.
+pub const SESSION_SETTINGS: &[&str] = &[
+    "application_name",
+    "client_encoding",
+    "datestyle",
+    "extra_float_digits",
+    "idle_session_timeout",
+    "intervalstyle",
+    "search_path",
+    "statement_timeout",
+    "timezone",
+    "integer_datetimes",
+    "server_encoding",
+    "server_version",
+    "standard_conforming_strings",
+];
+
+/// The session variable that sets the default peer of the session, set with
+/// `SET peerdb.default_peer = '<peer>'`.
+pub const DEFAULT_PEER_VARIABLE: &str = "default_peer";
+
+/// The functions nexus answers itself instead of routing the query to a peer,
+/// with the number of arguments they take, e.g. `SELECT peerdb_version()`.
+/// `version()` reports a postgres version string, which drivers and
+/// connection pools parse.
+pub const BUILTIN_FUNCTIONS: &[(&str, usize)] = &[
+    ("version", 0),
+    ("peerdb_version", 0),
+    ("peerdb_mirror_lag", 1),
+    ("peerdb_peer_type", 1),
+    ("peerdb_connections", 0),
+    ("peerdb_peak_connections", 0),
+    ("peerdb.rotate_encryption_key", 2),
+];
+
 pub struct NexusQueryParser {
     catalog: Arc<Mutex<Catalog>>,
+    default_peer: Option<String>,
+    // the database the client connected to, which is the default peer of the
+    // session when it names a peer.
+    database: StdMutex<Option<String>>,
+    variables: StdMutex<SessionVariables>,
 }
 
 #[derive(Debug, Clone)]
@@ -33,16 +91,201 @@ pub enum NexusStatement {
         stmt: Statement,
         cursor: CursorEvent,
     },
+    /// `CREATE TEMP TABLE ... AS SELECT`, the result of the query is staged
+    /// in a table of the session, see `SessionTables` in the server.
+    CreateTempTable {
+        table_name: String,
+        query: Statement,
+        assoc: QueryAssocation,
+    },
+    /// A query joining the tables of several peers. The tables are staged in
+    /// the catalog and the query runs there, see the server.
+    FederatedQuery {
+        stmt: Statement,
+        tables: Vec<FederatedTable>,
+    },
+    /// `SET peerdb.<name> = '<value>'`, sets a variable of the session that
+    /// CREATE MIRROR statements reference as {{name}}, or unsets it when the
+    /// value is `DEFAULT`.
+    SetVariable {
+        name: String,
+        value: Option<String>,
+    },
+    /// `SET <name> = <value>` of a setting, the ones other than the
+    /// `SESSION_SETTINGS` are kept but can't be shown. The setting is reset
+    /// to its default when the value is `DEFAULT`.
+    SetSetting {
+        name: String,
+        value: Option<String>,
+    },
+    /// `SHOW <name>` of one of the `SESSION_SETTINGS` or `SHOW peerdb.<name>`
+    /// of a session variable.
+    ShowSetting {
+        name: String,
+    },
+    /// `SELECT <function>(<args>)` of one of the `BUILTIN_FUNCTIONS`, the
+    /// result is a single row of a column named after the function unless it
+    /// is aliased.
+    BuiltinFunction {
+        name: String,
+        args: Vec<String>,
+        column: String,
+    },
+    /// `SELECT <integer>`, which connection pools check their connections
+    /// with, answered by nexus itself. The result is a single row of an int4
+    /// column named `?column?` unless it is aliased.
+    SelectConstant {
+        value: i32,
+        column: String,
+    },
+    /// `COPY <table> TO STDOUT` or `COPY (<query>) TO STDOUT`, the rows of the
+    /// query, or of the one the table is read with, are written in the format
+    /// of the COPY, a line per row.
+    CopyOut {
+        stmt: Statement,
+        assoc: QueryAssocation,
+        format: CopyOutFormat,
+    },
+    /// `BEGIN`, `COMMIT` or `ROLLBACK` when the session's default peer is a
+    /// Postgres peer, the transaction is opened on the connection of the
+    /// session to the peer.
+    Transaction {
+        stmt: Statement,
+        peer: Box<pt::peerdb_peers::Peer>,
+    },
+    /// `LISTEN`, `UNLISTEN` or `NOTIFY`, run on the connection of the session
+    /// to its default peer, which has to be a Postgres peer. The notifications
+    /// of the channels it listens to are forwarded to the client.
+    Notification {
+        stmt: NotificationStatement,
+        peer: Box<pt::peerdb_peers::Peer>,
+    },
     Empty,
 }
 
+/// How `COPY ... TO STDOUT` writes rows, the text format of postgres unless
+/// it is CSV.
+#[derive(Debug, Clone)]
+pub struct CopyOutFormat {
+    pub csv: bool,
+    pub delimiter: char,
+    pub null: String,
+    pub quote: char,
+    pub header: bool,
+}
+
+impl CopyOutFormat {
+    fn new(csv: bool) -> Self {
+        Self {
+            csv,
+            delimiter: if csv { ',' } else { '\t' },
+            null: if csv { String::new() } else { "\\N".to_owned() },
+            quote: '"',
+            header: false,
+        }
+    }
+}
+
 impl NexusStatement {
     pub fn new(
         peers: HashMap<String, pt::peerdb_peers::Peer>,
         stmt: &Statement,
+        default_peer: Option<&str>,
+        variables: &SessionVariables,
     ) -> PgWireResult<Self> {
+        if let Some((name, value)) = session_variable(stmt)? {
+            return Ok(NexusStatement::SetVariable { name, value });
+        }
+        if let Some((name, value)) = session_setting(stmt)? {
+            return Ok(NexusStatement::SetSetting { name, value });
+        }
+        if let Some(name) = shown_setting(stmt) {
+            return Ok(NexusStatement::ShowSetting { name });
+        }
+        if let Some(nexus_stmt) = health_check(stmt)? {
+            return Ok(nexus_stmt);
+        }
+
+        // a default peer set for the session takes the place of the one of
+        // the server, and queries that don't refer to a peer run on it.
+        let session_peer = variables
+            .get(DEFAULT_PEER_VARIABLE)
+            .filter(|peer| !peer.is_empty())
+            .map(|peer| peer.to_lowercase());
+        let default_peer = session_peer.as_deref().or(default_peer);
+
+        if let Some((query, format)) = copy_out(stmt)? {
+            return match NexusStatement::new(peers, &query, default_peer, variables)? {
+                NexusStatement::PeerQuery { stmt, assoc } => Ok(NexusStatement::CopyOut {
+                    stmt,
+                    assoc,
+                    format,
+                }),
+                // the queries nexus answers itself are copied out of the
+                // catalog, which answers them the same.
+                NexusStatement::BuiltinFunction { .. } => Ok(NexusStatement::CopyOut {
+                    stmt: query,
+                    assoc: QueryAssocation::Catalog,
+                    format,
+                }),
+                _ => Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "0A000".to_owned(),
+                    "COPY TO STDOUT is only supported for tables, and queries of a single peer"
+                        .to_owned(),
+                )))),
+            };
+        }
+
+        if let Some(peer) = transaction_peer(&peers, stmt, default_peer)? {
+            return Ok(NexusStatement::Transaction {
+                stmt: stmt.clone(),
+                peer: Box::new(peer),
+            });
+        }
+
+        if let Some(sql) = peerdb_statement(stmt) {
+            let statement =
+                parse_peerdb_statement(sql).map_err(|e| PgWireError::ApiError(Box::new(e)))?;
+            if let PeerDBStatement::Notification(notification) = statement {
+                let peer = notification_peer(&peers, &notification, default_peer)?;
+                return Ok(NexusStatement::Notification {
+                    stmt: notification,
+                    peer: Box::new(peer),
+                });
+            }
+            let show = {
+                let psa = PeerShowAnalyzer::new(&peers, default_peer);
+                psa.analyze_peerdb(&statement).map_err(|e| {
+                    PgWireError::UserError(Box::new(ErrorInfo::new(
+                        "ERROR".to_owned(),
+                        "feature_not_supported".to_owned(),
+                        e.to_string(),
+                    )))
+                })
+            }?;
+            if let Some((peer, stmt)) = show {
+                return Ok(NexusStatement::PeerQuery {
+                    stmt,
+                    assoc: QueryAssocation::Peer(peer),
+                });
+            }
+            let pdl: PeerDDLAnalyzer = PeerDDLAnalyzer::new(&peers, variables);
+            let ddl = pdl.analyze_peerdb(&statement).map_err(|e| {
+                PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "internal_error".to_owned(),
+                    e.to_string(),
+                )))
+            })?;
+            return Ok(NexusStatement::PeerDDL {
+                stmt: stmt.clone(),
+                ddl: Box::new(ddl),
+            });
+        }
+
         let ddl = {
-            let pdl: PeerDDLAnalyzer = PeerDDLAnalyzer::new(&peers);
+            let pdl: PeerDDLAnalyzer = PeerDDLAnalyzer::new(&peers, variables);
             pdl.analyze(stmt).map_err(|e| {
                 PgWireError::UserError(Box::new(ErrorInfo::new(
                     "ERROR".to_owned(),
@@ -67,6 +310,72 @@ impl NexusStatement {
             });
         }
 
+        let show = {
+            let psa = PeerShowAnalyzer::new(&peers, default_peer);
+            psa.analyze(stmt).map_err(|e| {
+                PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "feature_not_supported".to_owned(),
+                    e.to_string(),
+                )))
+            })
+        }?;
+
+        if let Some((peer, stmt)) = show {
+            return Ok(NexusStatement::PeerQuery {
+                stmt,
+                assoc: QueryAssocation::Peer(peer),
+            });
+        }
+
+        if let Statement::CreateTable {
+            temporary: true,
+            name,
+            query: Some(query),
+            ..
+        } = stmt
+        {
+            if name.0.len() != 1 {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "42P16".to_owned(),
+                    "cannot create temporary relation in non-temporary schema".to_owned(),
+                ))));
+            }
+            let query = Statement::Query(query.clone());
+            let assoc = {
+                let pea = PeerExistanceAnalyzer::new(&peers);
+                pea.analyze(&query).map_err(|e| {
+                    PgWireError::UserError(Box::new(ErrorInfo::new(
+                        "ERROR".to_owned(),
+                        "feature_not_supported".to_owned(),
+                        e.to_string(),
+                    )))
+                })
+            }?;
+            return Ok(NexusStatement::CreateTempTable {
+                table_name: name.0[0].value.to_lowercase(),
+                query,
+                assoc,
+            });
+        }
+
+        let federated = FederatedQueryAnalyzer::new(&peers)
+            .analyze(stmt)
+            .map_err(|e| {
+                PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "feature_not_supported".to_owned(),
+                    e.to_string(),
+                )))
+            })?;
+        if let Some(tables) = federated {
+            return Ok(NexusStatement::FederatedQuery {
+                stmt: stmt.clone(),
+                tables,
+            });
+        }
+
         let assoc = {
             let pea = PeerExistanceAnalyzer::new(&peers);
             pea.analyze(stmt).map_err(|e| {
@@ -78,6 +387,24 @@ impl NexusStatement {
             })
         }?;
 
+        if let (QueryAssocation::Catalog, Some(peer_name), Statement::Query(_)) =
+            (&assoc, &session_peer, stmt)
+        {
+            let peer = peers.get(peer_name).ok_or_else(|| {
+                PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "42704".to_owned(),
+                    format!("default peer {} does not exist", peer_name),
+                )))
+            })?;
+            let mut stmt = stmt.clone();
+            qualify_with_peer(&mut stmt, peer_name);
+            return Ok(NexusStatement::PeerQuery {
+                stmt,
+                assoc: QueryAssocation::Peer(Box::new(peer.clone())),
+            });
+        }
+
         Ok(NexusStatement::PeerQuery {
             stmt: stmt.clone(),
             assoc,
@@ -85,15 +412,741 @@ impl NexusStatement {
     }
 }
 
+// qualifies the tables a query refers to with the name of the peer, so that
+// the query runs on the peer. references to the common table expressions of
+// the query are left alone.
+fn qualify_with_peer(stmt: &mut Statement, peer_name: &str) {
+    let ctes = match stmt {
+        Statement::Query(query) => cte_names(query),
+        _ => return,
+    };
+    visit_relations_mut(stmt, |relation| {
+        if relation.0.len() > 1 || !ctes.contains(&relation.0[0].value.to_lowercase()) {
+            relation.0.insert(0, Ident::new(peer_name));
+        }
+        ControlFlow::<()>::Continue(())
+    });
+}
+
+// the peer a transaction statement runs on, the default peer of the session.
+// without a default peer the statement runs on the catalog like before, only
+// postgres peers can open transactions.
+fn transaction_peer(
+    peers: &HashMap<String, pt::peerdb_peers::Peer>,
+    stmt: &Statement,
+    default_peer: Option<&str>,
+) -> PgWireResult<Option<pt::peerdb_peers::Peer>> {
+    match stmt {
+        Statement::StartTransaction { .. }
+        | Statement::Commit { .. }
+        | Statement::Rollback { .. } => {}
+        _ => return Ok(None),
+    }
+    let peer = match default_peer.and_then(|peer_name| peers.get(peer_name)) {
+        Some(peer) => peer,
+        None => return Ok(None),
+    };
+    if peer.r#type != pt::peerdb_peers::DbType::Postgres as i32 {
+        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "0A000".to_owned(),
+            format!(
+                "transactions are only supported on postgres peers, peer {} is not one",
+                peer.name
+            ),
+        ))));
+    }
+    Ok(Some(peer.clone()))
+}
+
+// the peer LISTEN, UNLISTEN and NOTIFY run on, the default peer of the
+// session, which has to be a postgres peer for its notifications to be
+// forwarded.
+fn notification_peer(
+    peers: &HashMap<String, pt::peerdb_peers::Peer>,
+    stmt: &NotificationStatement,
+    default_peer: Option<&str>,
+) -> PgWireResult<pt::peerdb_peers::Peer> {
+    let command = match stmt {
+        NotificationStatement::Listen { .. } => "LISTEN",
+        NotificationStatement::Unlisten { .. } => "UNLISTEN",
+        NotificationStatement::Notify { .. } => "NOTIFY",
+    };
+    match default_peer.and_then(|peer_name| peers.get(peer_name)) {
+        Some(peer) if peer.r#type == pt::peerdb_peers::DbType::Postgres as i32 => Ok(peer.clone()),
+        _ => Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "0A000".to_owned(),
+            format!(
+                "{} is only supported when the default peer of the session is a postgres peer",
+                command
+            ),
+        )))),
+    }
+}
+
+// the single value a variable or setting is set to, None for `DEFAULT`.
+fn set_value(name: &str, value: &[Expr]) -> PgWireResult<Option<String>> {
+    match value {
+        [Expr::Value(Value::SingleQuotedString(s))] => Ok(Some(s.clone())),
+        [Expr::Value(Value::Number(n, _))] => Ok(Some(n.clone())),
+        [Expr::Identifier(ident)] if ident.value.eq_ignore_ascii_case("default") => Ok(None),
+        [Expr::Identifier(ident)] => Ok(Some(ident.value.clone())),
+        _ => Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "22023".to_owned(),
+            format!("{} must be set to a single value", name),
+        )))),
+    }
+}
+
+// the value a setting is set to, settings like search_path take a list of
+// values, which is kept the way postgres shows it, separated by commas.
+fn set_setting_value(name: &str, value: &[Expr]) -> PgWireResult<Option<String>> {
+    if value.len() < 2 {
+        return set_value(name, value);
+    }
+    let values = value
+        .iter()
+        .map(|expr| match expr {
+            Expr::Value(Value::SingleQuotedString(s)) => Ok(s.clone()),
+            Expr::Value(Value::Number(n, _)) => Ok(n.clone()),
+            Expr::Identifier(ident) => Ok(ident.to_string()),
+            _ => Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                "ERROR".to_owned(),
+                "22023".to_owned(),
+                format!("{} must be set to a list of names or literals", name),
+            )))),
+        })
+        .collect::<PgWireResult<Vec<String>>>()?;
+    Ok(Some(values.join(", ")))
+}
+
+// the setting set by a `SET <name> = ...`, and the value it is set to. like
+// postgres takes unknown settings with a prefix, nexus takes the ones clients
+// set that it doesn't know of instead of an error, they can't be shown.
+fn session_setting(stmt: &Statement) -> PgWireResult<Option<(String, Option<String>)>> {
+    match stmt {
+        Statement::SetVariable {
+            variable, value, ..
+        } if variable.0.len() == 1 => {
+            let name = variable.0[0].value.to_lowercase();
+            let value = set_setting_value(&name, value)?;
+            Ok(Some((name, value)))
+        }
+        Statement::SetTimeZone { value, .. } => {
+            let value = set_value("timezone", std::slice::from_ref(value))?;
+            Ok(Some(("timezone".to_owned(), value)))
+        }
+        _ => Ok(None),
+    }
+}
+
+// the setting or session variable shown by a `SHOW <name>`, lowercased.
+fn shown_setting(stmt: &Statement) -> Option<String> {
+    let variable = match stmt {
+        Statement::ShowVariable { variable } => variable,
+        _ => return None,
+    };
+    let name = variable
+        .iter()
+        .map(|ident| ident.value.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(".");
+    match variable.as_slice() {
+        [_] if SESSION_SETTINGS.contains(&name.as_str()) => Some(name),
+        [prefix, _] if prefix.value.eq_ignore_ascii_case("peerdb") => Some(name),
+        _ => None,
+    }
+}
+
+// the query of a `COPY (<query>) TO STDOUT`, or the one a `COPY <table>
+// [(<columns>)] TO STDOUT` reads the table with, and the format it writes the
+// rows in. the options are those of postgres, along with the ones of the
+// syntax before postgres 9.0.
+fn copy_out(stmt: &Statement) -> PgWireResult<Option<(Statement, CopyOutFormat)>> {
+    let unsupported = |msg: &str| {
+        PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "0A000".to_owned(),
+            msg.to_owned(),
+        )))
+    };
+    let (table_name, columns, options, legacy_options) = match stmt {
+        Statement::Copy {
+            table_name,
+            columns,
+            to: true,
+            target: CopyTarget::Stdout,
+            options,
+            legacy_options,
+            ..
+        } => (table_name, columns, options, legacy_options),
+        Statement::Copy { table_name, .. } if copy_query(table_name).is_some() => {
+            return Err(unsupported("COPY of a query is only supported TO STDOUT"))
+        }
+        _ => return Ok(None),
+    };
+
+    let is_csv = options.iter().any(|option| {
+        matches!(option, CopyOption::Format(format) if format.value.eq_ignore_ascii_case("csv"))
+    }) || legacy_options
+        .iter()
+        .any(|option| matches!(option, CopyLegacyOption::Csv(_)));
+    let mut format = CopyOutFormat::new(is_csv);
+    for option in options {
+        match option {
+            CopyOption::Format(name) => match name.value.to_lowercase().as_str() {
+                "csv" | "text" => {}
+                _ => {
+                    return Err(unsupported(
+                        "COPY TO STDOUT supports the text and csv formats",
+                    ))
+                }
+            },
+            CopyOption::Delimiter(delimiter) => format.delimiter = *delimiter,
+            CopyOption::Null(null) => format.null = null.clone(),
+            CopyOption::Quote(quote) => format.quote = *quote,
+            CopyOption::Header(header) => format.header = *header,
+            _ => {
+                return Err(unsupported(&format!(
+                    "COPY option {} is not supported",
+                    option
+                )))
+            }
+        }
+    }
+    for option in legacy_options {
+        match option {
+            CopyLegacyOption::Delimiter(delimiter) => format.delimiter = *delimiter,
+            CopyLegacyOption::Null(null) => format.null = null.clone(),
+            CopyLegacyOption::Csv(csv_options) => {
+                for csv_option in csv_options {
+                    match csv_option {
+                        CopyLegacyCsvOption::Header => format.header = true,
+                        CopyLegacyCsvOption::Quote(quote) => format.quote = *quote,
+                        _ => {
+                            return Err(unsupported(&format!(
+                                "COPY option {} is not supported",
+                                csv_option
+                            )))
+                        }
+                    }
+                }
+            }
+            CopyLegacyOption::Binary => {
+                return Err(unsupported(
+                    "COPY TO STDOUT supports the text and csv formats",
+                ))
+            }
+        }
+    }
+
+    let query = match copy_query(table_name) {
+        Some(_) if !columns.is_empty() => {
+            return Err(unsupported("COPY of a query doesn't take a column list"))
+        }
+        Some(query) => query.to_owned(),
+        None if columns.is_empty() => format!("SELECT * FROM {}", table_name),
+        None => format!(
+            "SELECT {} FROM {}",
+            columns
+                .iter()
+                .map(|column| column.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            table_name
+        ),
+    };
+    let query = Parser::parse_sql(&DIALECT, &query)
+        .map_err(|err| PgWireError::ApiError(Box::new(err)))?
+        .pop()
+        .ok_or_else(|| unsupported("COPY TO STDOUT is only supported for tables"))?;
+    Ok(Some((query, format)))
+}
+
+// `SELECT 1`, `SELECT version()` and the other queries nexus answers without the peers.
+fn health_check(stmt: &Statement) -> PgWireResult<Option<NexusStatement>> {
+    if let Some((value, column)) = select_constant(stmt) {
+        return Ok(Some(NexusStatement::SelectConstant { value, column }));
+    }
+    Ok(
+        builtin_function(stmt)?.map(|(name, args, column)| NexusStatement::BuiltinFunction {
+            name,
+            args,
+            column,
+        }),
+    )
+}
+
+// the integer and column of a `SELECT <integer>`, None for other statements
+// and for integers that don't fit an int4.
+fn select_constant(stmt: &Statement) -> Option<(i32, String)> {
+    let select = match stmt {
+        Statement::Query(query)
+            if query.with.is_none()
+                && query.order_by.is_empty()
+                && query.limit.is_none()
+                && query.offset.is_none() =>
+        {
+            match query.body.as_ref() {
+                SetExpr::Select(select)
+                    if select.from.is_empty()
+                        && select.selection.is_none()
+                        && select.having.is_none() =>
+                {
+                    select
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let (n, column) = match select.projection.as_slice() {
+        [SelectItem::UnnamedExpr(Expr::Value(Value::Number(n, _)))] => (n, "?column?".to_owned()),
+        [SelectItem::ExprWithAlias {
+            expr: Expr::Value(Value::Number(n, _)),
+            alias,
+        }] => {
+            let column = match alias.quote_style {
+                Some(_) => alias.value.clone(),
+                None => alias.value.to_lowercase(),
+            };
+            (n, column)
+        }
+        _ => return None,
+    };
+    Some((n.parse().ok()?, column))
+}
+
+// the builtin function called by a `SELECT <function>(<args>)` without a FROM
+// clause, with its arguments and the name of its column. The arguments are
+// string literals, other functions are left for the peers to run.
+fn builtin_function(stmt: &Statement) -> PgWireResult<Option<(String, Vec<String>, String)>> {
+    let select = match stmt {
+        Statement::Query(query) => match query.body.as_ref() {
+            SetExpr::Select(select) if select.from.is_empty() => select,
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    let (function, alias) = match select.projection.as_slice() {
+        [SelectItem::UnnamedExpr(Expr::Function(function))] => (function, None),
+        [SelectItem::ExprWithAlias {
+            expr: Expr::Function(function),
+            alias,
+        }] => (function, Some(alias)),
+        _ => return Ok(None),
+    };
+    let Function { name, args, .. } = function;
+    let (name, unqualified) = match name.0.as_slice() {
+        [name] => (name.value.to_lowercase(), name.value.to_lowercase()),
+        [schema, name] if schema.value.eq_ignore_ascii_case("peerdb") => (
+            format!("peerdb.{}", name.value.to_lowercase()),
+            name.value.to_lowercase(),
+        ),
+        _ => return Ok(None),
+    };
+    let num_args = match BUILTIN_FUNCTIONS
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+    {
+        Some((_, num_args)) => *num_args,
+        None => return Ok(None),
+    };
+
+    let args = args
+        .iter()
+        .map(|arg| match arg {
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                Value::SingleQuotedString(s),
+            ))) => Ok(s.clone()),
+            _ => Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                "ERROR".to_owned(),
+                "22023".to_owned(),
+                format!("the arguments of {} must be string literals", name),
+            )))),
+        })
+        .collect::<PgWireResult<Vec<_>>>()?;
+    if args.len() != num_args {
+        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "42883".to_owned(),
+            format!("function {} takes {} arguments", name, num_args),
+        ))));
+    }
+    let column = alias
+        .map(|alias| match alias.quote_style {
+            Some(_) => alias.value.clone(),
+            None => alias.value.to_lowercase(),
+        })
+        .unwrap_or(unqualified);
+    Ok(Some((name, args, column)))
+}
+
+// the variable set by a `SET peerdb.<name> = ...`, None for other statements.
+// other settings, e.g. of the catalog, are left for the catalog to set.
+fn session_variable(stmt: &Statement) -> PgWireResult<Option<(String, Option<String>)>> {
+    let (variable, value) = match stmt {
+        Statement::SetVariable {
+            variable, value, ..
+        } if variable.0.len() == 2 && variable.0[0].value.eq_ignore_ascii_case("peerdb") => {
+            (variable, value)
+        }
+        _ => return Ok(None),
+    };
+    let value = set_value(&variable.to_string(), value)?;
+    Ok(Some((variable.0[1].value.to_lowercase(), value)))
+}
+
 #[derive(Debug, Clone)]
 pub struct NexusParsedStatement {
     pub statement: NexusStatement,
     pub query: String,
+    /// The types of the parameters `$1`, `$2`, ... of the statement, the
+    /// ones the client left unspecified are text.
+    pub parameter_types: Vec<Type>,
+}
+
+// psql qualifies the operators of the catalog queries of its meta-commands,
+// e.g. `c.relname OPERATOR(pg_catalog.~) '^(orders)$'`, which the parser
+// doesn't know. The operators are left unqualified instead, there are no
+// others of the same names in the catalog.
+fn unqualify_operators(sql: &str) -> Cow<'_, str> {
+    const QUALIFIED: &str = "OPERATOR(pg_catalog.";
+    if !sql.contains(QUALIFIED) {
+        return Cow::Borrowed(sql);
+    }
+    let mut unqualified = String::with_capacity(sql.len());
+    let mut rest = sql;
+    while let Some(start) = rest.find(QUALIFIED) {
+        let operator_start = start + QUALIFIED.len();
+        let len = match rest[operator_start..].find(')') {
+            Some(len) => len,
+            None => break,
+        };
+        unqualified.push_str(&rest[..start]);
+        unqualified.push_str(&rest[operator_start..operator_start + len]);
+        rest = &rest[operator_start + len + 1..];
+    }
+    unqualified.push_str(rest);
+    Cow::Owned(unqualified)
+}
+
+// the parser only knows the COPY of tables, a `COPY (<query>) ...` is left to
+// it as the COPY of a table named after the query instead, which no table is
+// named with as the name starts with this prefix. `copy_out` reads the query
+// back out of the name.
+const COPY_QUERY_PREFIX: &str = "\u{1}copy query:";
+
+fn copy_queries(sql: &str) -> Cow<'_, str> {
+    if !sql.to_ascii_lowercase().contains("copy") {
+        return Cow::Borrowed(sql);
+    }
+    let bytes = sql.as_bytes();
+    let mut rewritten = String::with_capacity(sql.len());
+    // the end of the part of the statements copied into the rewritten ones.
+    let mut copied = 0;
+    let mut statement_start = true;
+    let mut i = 0;
+    while i < bytes.len() {
+        if let Some(end) = skip_quoted(bytes, i) {
+            // a comment leaves the start of the statement where it is.
+            statement_start &= matches!(bytes[i], b'-' | b'/');
+            i = end;
+            continue;
+        }
+        if bytes[i].is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        if bytes[i] == b';' {
+            i += 1;
+            statement_start = true;
+            continue;
+        }
+        let word_start = i;
+        while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+            i += 1;
+        }
+        if i == word_start {
+            i += 1;
+            statement_start = false;
+            continue;
+        }
+        let is_copy = statement_start && sql[word_start..i].eq_ignore_ascii_case("copy");
+        statement_start = false;
+        if !is_copy {
+            continue;
+        }
+        let open = match bytes[i..].iter().position(|b| !b.is_ascii_whitespace()) {
+            Some(offset) if bytes[i + offset] == b'(' => i + offset,
+            _ => continue,
+        };
+        let close = match closing_paren(bytes, open) {
+            Some(close) => close,
+            None => break,
+        };
+        rewritten.push_str(&sql[copied..word_start]);
+        rewritten.push_str("COPY \"");
+        rewritten.push_str(COPY_QUERY_PREFIX);
+        rewritten.push_str(&sql[open + 1..close].replace('"', "\"\""));
+        rewritten.push('"');
+        copied = close + 1;
+        i = close + 1;
+    }
+    if copied == 0 {
+        return Cow::Borrowed(sql);
+    }
+    rewritten.push_str(&sql[copied..]);
+    Cow::Owned(rewritten)
+}
+
+// the query of a `COPY (<query>) ...`, see `copy_queries`.
+fn copy_query(table_name: &ObjectName) -> Option<&str> {
+    match table_name.0.as_slice() {
+        [name] if name.quote_style == Some('"') => name.value.strip_prefix(COPY_QUERY_PREFIX),
+        _ => None,
+    }
+}
+
+// the statements nexus parses itself are left to the parser as a select of a
+// column named after the statement, which no column is named with as the
+// name starts with this prefix. `peerdb_statement` reads the statement back
+// out of the name.
+const PEERDB_STATEMENT_PREFIX: &str = "\u{1}peerdb statement:";
+
+fn peerdb_statements(sql: &str) -> Cow<'_, str> {
+    let bytes = sql.as_bytes();
+    let mut rewritten = String::new();
+    // the end of the part of the statements copied into the rewritten ones.
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_whitespace() || bytes[i] == b';' {
+            i += 1;
+            continue;
+        }
+        if let (b'-' | b'/', Some(end)) = (bytes[i], skip_quoted(bytes, i)) {
+            i = end;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i] != b';' {
+            i = skip_quoted(bytes, i).unwrap_or(i + 1);
+        }
+        let statement = &sql[start..i];
+        let is_peerdb = PEERDB_STATEMENTS.iter().any(|words| {
+            let mut leading = statement.split_ascii_whitespace();
+            words.iter().all(|word| {
+                leading.next().map_or(false, |w| match *word {
+                    "'" => w.starts_with('\'') || dollar_quote(w.as_bytes(), 0).is_some(),
+                    word => w.eq_ignore_ascii_case(word),
+                })
+            })
+        });
+        if !is_peerdb {
+            continue;
+        }
+        rewritten.push_str(&sql[copied..start]);
+        rewritten.push_str("SELECT \"");
+        rewritten.push_str(PEERDB_STATEMENT_PREFIX);
+        rewritten.push_str(&statement.replace('"', "\"\""));
+        rewritten.push('"');
+        copied = i;
+    }
+    if copied == 0 {
+        return Cow::Borrowed(sql);
+    }
+    rewritten.push_str(&sql[copied..]);
+    Cow::Owned(rewritten)
+}
+
+// the text of a statement nexus parses itself, see `peerdb_statements`.
+fn peerdb_statement(stmt: &Statement) -> Option<&str> {
+    let select = match stmt {
+        Statement::Query(query) => match query.body.as_ref() {
+            SetExpr::Select(select) => select,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    match select.projection.as_slice() {
+        [SelectItem::UnnamedExpr(Expr::Identifier(name))] if name.quote_style == Some('"') => {
+            name.value.strip_prefix(PEERDB_STATEMENT_PREFIX)
+        }
+        _ => None,
+    }
+}
+
+// the end of the parenthesis opened at `open`, past the ones it nests.
+fn closing_paren(bytes: &[u8], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = open;
+    while i < bytes.len() {
+        if let Some(end) = skip_quoted(bytes, i) {
+            i = end;
+            continue;
+        }
+        match bytes[i] {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+// the end of the string, quoted identifier or comment that starts at `i`, if
+// one does. parentheses and semicolons in them are not those of the
+// statement.
+fn skip_quoted(bytes: &[u8], i: usize) -> Option<usize> {
+    if let Some(tag_len) = dollar_quote(bytes, i) {
+        let tag = &bytes[i..i + tag_len];
+        return Some(
+            bytes[i + tag_len..]
+                .windows(tag_len)
+                .position(|w| w == tag)
+                .map_or(bytes.len(), |offset| i + tag_len + offset + tag_len),
+        );
+    }
+    match (bytes[i], bytes.get(i + 1)) {
+        (quote @ (b'\'' | b'"'), _) => {
+            let mut end = i + 1;
+            while end < bytes.len() {
+                if bytes[end] == quote {
+                    // a doubled quote is one inside the string.
+                    if bytes.get(end + 1) == Some(&quote) {
+                        end += 2;
+                        continue;
+                    }
+                    return Some(end + 1);
+                }
+                end += 1;
+            }
+            Some(bytes.len())
+        }
+        (b'-', Some(b'-')) => Some(
+            bytes[i..]
+                .iter()
+                .position(|b| *b == b'\n')
+                .map_or(bytes.len(), |offset| i + offset + 1),
+        ),
+        (b'/', Some(b'*')) => Some(
+            bytes[i + 2..]
+                .windows(2)
+                .position(|w| w == b"*/")
+                .map_or(bytes.len(), |offset| i + 2 + offset + 2),
+        ),
+        _ => None,
+    }
+}
+
+// the length of the `$tag$` that opens a dollar quoted string at `i`, if one
+// does. like in postgres, the tag is empty or an identifier, so `$1` is a
+// parameter, and a `$` inside an identifier doesn't start a string.
+fn dollar_quote(bytes: &[u8], i: usize) -> Option<usize> {
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    if bytes[i] != b'$' || (i > 0 && (is_ident(bytes[i - 1]) || bytes[i - 1] == b'$')) {
+        return None;
+    }
+    let tag_end = i + 1 + bytes[i + 1..].iter().take_while(|b| is_ident(**b)).count();
+    match bytes.get(tag_end) {
+        Some(b'$') if !bytes.get(i + 1).map_or(false, u8::is_ascii_digit) => Some(tag_end + 1 - i),
+        _ => None,
+    }
+}
+
+// the types of the parameters of a statement, one for each parameter up to
+// the highest numbered one it refers to. like postgres, the client may
+// specify the types of only the first parameters, and unspecified parameters
+// are taken as text, which peers coerce like a literal of unknown type.
+fn parameter_types(stmt: &Statement, specified: &[Type]) -> Vec<Type> {
+    let mut count = specified.len();
+    let _ = visit_expressions(stmt, |expr| {
+        if let Expr::Value(Value::Placeholder(placeholder)) = expr {
+            if let Some(n) = placeholder
+                .strip_prefix('$')
+                .and_then(|n| n.parse::<usize>().ok())
+            {
+                count = count.max(n);
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    (0..count)
+        .map(|idx| match specified.get(idx) {
+            Some(param_type) if *param_type != Type::UNKNOWN => param_type.clone(),
+            _ => Type::TEXT,
+        })
+        .collect()
 }
 
 impl NexusQueryParser {
-    pub fn new(catalog: Arc<Mutex<Catalog>>) -> Self {
-        Self { catalog }
+    pub fn new(catalog: Arc<Mutex<Catalog>>, default_peer: Option<String>) -> Self {
+        Self {
+            catalog,
+            default_peer,
+            database: StdMutex::new(None),
+            variables: StdMutex::new(SessionVariables::new()),
+        }
+    }
+
+    /// Sets the database the client connected to, e.g. with `dbname=<peer>`,
+    /// the queries that don't refer to a peer run on the peer it names.
+    pub fn set_database(&self, database: Option<String>) {
+        *self.database.lock().unwrap() = database.map(|database| database.to_lowercase());
+    }
+
+    // the default peer of the server, unless the database the client connected
+    // to names a peer.
+    fn connection_default_peer(
+        &self,
+        peers: &HashMap<String, pt::peerdb_peers::Peer>,
+    ) -> Option<String> {
+        self.database
+            .lock()
+            .unwrap()
+            .clone()
+            .filter(|database| peers.contains_key(database))
+            .or_else(|| self.default_peer.clone())
+    }
+
+    // sets a variable of the session, a variable set to DEFAULT is unset.
+    pub fn set_variable(&self, name: String, value: Option<String>) {
+        let mut variables = self.variables.lock().unwrap();
+        match value {
+            Some(value) => variables.insert(name, value),
+            None => variables.remove(&name),
+        };
+    }
+
+    pub fn get_variable(&self, name: &str) -> Option<String> {
+        self.variables.lock().unwrap().get(name).cloned()
+    }
+
+    // the default peer of the session, the one the session set, or else the
+    // one its database names, or else the one of the server.
+    pub fn default_peer(&self) -> Option<String> {
+        if let Some(peer) = self
+            .get_variable(DEFAULT_PEER_VARIABLE)
+            .filter(|peer| !peer.is_empty())
+        {
+            return Some(peer);
+        }
+        if self.database.lock().unwrap().is_none() {
+            return self.default_peer.clone();
+        }
+        match self.get_peers_bridge() {
+            Ok(peers) => self.connection_default_peer(&peers),
+            Err(_) => self.default_peer.clone(),
+        }
     }
 
     pub fn get_peers_bridge(&self) -> PgWireResult<HashMap<String, pt::peerdb_peers::Peer>> {
@@ -113,12 +1166,30 @@ impl NexusQueryParser {
         })
     }
 
+    // split a query into its statements. the statements of a query are
+    // analyzed one at a time, right before they run, as a statement can depend
+    // on the ones before it, e.g. a query on a peer the script just created.
+    pub fn split_sql(&self, sql: &str) -> PgWireResult<Vec<Statement>> {
+        let sql = peerdb_statements(sql);
+        Parser::parse_sql(&DIALECT, &copy_queries(&unqualify_operators(&sql)))
+            .map_err(|e| PgWireError::ApiError(Box::new(e)))
+    }
+
+    pub fn analyze(&self, stmt: &Statement) -> PgWireResult<NexusStatement> {
+        if let Some(nexus_stmt) = health_check(stmt)? {
+            return Ok(nexus_stmt);
+        }
+        let peers = self.get_peers_bridge()?;
+        let variables = self.variables.lock().unwrap().clone();
+        let default_peer = self.connection_default_peer(&peers);
+        NexusStatement::new(peers, stmt, default_peer.as_deref(), &variables)
+    }
+
     pub fn parse_simple_sql(&self, sql: &str) -> PgWireResult<NexusParsedStatement> {
-        let mut stmts =
-            Parser::parse_sql(&DIALECT, sql).map_err(|e| PgWireError::ApiError(Box::new(e)))?;
+        let mut stmts = self.split_sql(sql)?;
         if stmts.len() > 1 {
             let err_msg = format!("unsupported sql: {}, statements: {:?}", sql, stmts);
-            // TODO (kaushik): Better error message for this. When do we start seeing multiple statements?
+            // the extended query protocol allows a single statement per query.
             Err(PgWireError::UserError(Box::new(ErrorInfo::new(
                 "ERROR".to_owned(),
                 "42P14".to_owned(),
@@ -128,14 +1199,15 @@ impl NexusQueryParser {
             Ok(NexusParsedStatement {
                 statement: NexusStatement::Empty,
                 query: sql.to_owned(),
+                parameter_types: vec![],
             })
         } else {
             let stmt = stmts.remove(0);
-            let peers = self.get_peers_bridge()?;
-            let nexus_stmt = NexusStatement::new(peers, &stmt)?;
+            let nexus_stmt = self.analyze(&stmt)?;
             Ok(NexusParsedStatement {
                 statement: nexus_stmt,
                 query: sql.to_owned(),
+                parameter_types: vec![],
             })
         }
     }
@@ -145,9 +1217,8 @@ impl NexusQueryParser {
 impl QueryParser for NexusQueryParser {
     type Statement = NexusParsedStatement;
 
-    async fn parse_sql(&self, sql: &str, _types: &[Type]) -> PgWireResult<Self::Statement> {
-        let mut stmts =
-            Parser::parse_sql(&DIALECT, sql).map_err(|e| PgWireError::ApiError(Box::new(e)))?;
+    async fn parse_sql(&self, sql: &str, types: &[Type]) -> PgWireResult<Self::Statement> {
+        let mut stmts = self.split_sql(sql)?;
         if stmts.len() > 1 {
             let err_msg = format!("unsupported sql: {}, statements: {:?}", sql, stmts);
             Err(PgWireError::UserError(Box::new(ErrorInfo::new(
@@ -159,15 +1230,58 @@ impl QueryParser for NexusQueryParser {
             Ok(NexusParsedStatement {
                 statement: NexusStatement::Empty,
                 query: sql.to_owned(),
+                parameter_types: types.to_vec(),
             })
         } else {
             let stmt = stmts.remove(0);
-            let peers = self.get_peers_bridge()?;
-            let nexus_stmt = NexusStatement::new(peers, &stmt)?;
+            let nexus_stmt = self.analyze(&stmt)?;
             Ok(NexusParsedStatement {
                 statement: nexus_stmt,
                 query: sql.to_owned(),
+                parameter_types: parameter_types(&stmt, types),
             })
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skip_quoted_dollar_quotes() {
+        assert_eq!(skip_quoted(b"$$a;b$$ c", 0), Some(7));
+        // the string only ends at its own tag.
+        assert_eq!(skip_quoted(b"$fn$ $$;$$ $fn$;", 0), Some(15));
+        assert_eq!(skip_quoted(b"$$a;b", 0), Some(5));
+        // parameters and `$` inside identifiers don't start strings.
+        assert_eq!(skip_quoted(b"$1 $$", 0), None);
+        assert_eq!(skip_quoted(b"a$b$", 1), None);
+        assert_eq!(skip_quoted(b"$", 0), None);
+    }
+
+    #[test]
+    fn peerdb_statements_in_dollar_quotes() {
+        assert_eq!(
+            peerdb_statements("EXECUTE $$SELECT 1; SELECT 2$$ ON PEER pg; SELECT 3"),
+            "SELECT \"\u{1}peerdb statement:EXECUTE $$SELECT 1; SELECT 2$$ ON PEER pg\"; SELECT 3"
+        );
+        assert_eq!(
+            peerdb_statements("CREATE MIRROR m FROM pg TO sf FOR $q$SELECT ';' FROM t$q$"),
+            "SELECT \"\u{1}peerdb statement:CREATE MIRROR m FROM pg TO sf FOR $q$SELECT ';' FROM t$q$\""
+        );
+        // the `CREATE PEER` in the string is not a statement.
+        let sql = "SELECT $$;CREATE PEER pg FROM POSTGRES$$";
+        assert!(matches!(peerdb_statements(sql), Cow::Borrowed(s) if s == sql));
+    }
+
+    #[test]
+    fn copy_queries_in_dollar_quotes() {
+        assert_eq!(
+            copy_queries("COPY (SELECT $$);$$) TO STDOUT; SELECT 1"),
+            "COPY \"\u{1}copy query:SELECT $$);$$\" TO STDOUT; SELECT 1"
+        );
+        let sql = "SELECT $$; COPY (SELECT 1) TO STDOUT$$";
+        assert!(matches!(copy_queries(sql), Cow::Borrowed(s) if s == sql));
+    }
+}
@@ -1,11 +1,13 @@
 use std::ops::ControlFlow;
 
+use peer_cursor::util::cte_names;
 use sqlparser::ast::Value::Number;
 
 use sqlparser::ast::{
     visit_expressions_mut, visit_function_arg_mut, visit_relations_mut, visit_setexpr_mut, Array,
     BinaryOperator, DataType, DateTimeField, Expr, Function, FunctionArg, FunctionArgExpr, Ident,
-    ObjectName, Query, SetExpr, SetOperator, SetQuantifier, TimezoneInfo,
+    ObjectName, Query, SetExpr, SetOperator, SetQuantifier, TableFactor, TimezoneInfo, Visit,
+    Visitor,
 };
 
 #[derive(Default)]
@@ -70,9 +72,17 @@ impl BigqueryAst {
     }
 
     pub fn rewrite(&self, dataset: &str, query: &mut Query) -> anyhow::Result<()> {
-        // replace peername with the connected dataset.
+        if has_lateral_subquery(query) {
+            anyhow::bail!("LATERAL subqueries are not supported on BigQuery peers");
+        }
+
+        // replace peername with the connected dataset, references to CTEs
+        // are left as they are.
+        let ctes = cte_names(query);
         visit_relations_mut(query, |table| {
-            table.0[0] = dataset.into();
+            if table.0.len() > 1 || !ctes.contains(&table.0[0].value.to_lowercase()) {
+                table.0[0] = dataset.into();
+            }
             ControlFlow::<()>::Continue(())
         });
 
@@ -368,3 +378,36 @@ impl BigqueryAst {
         Ok(list)
     }
 }
+
+// BigQuery only correlates joins with arrays, so a LATERAL subquery has no
+// rewrite for it.
+fn has_lateral_subquery(query: &Query) -> bool {
+    struct Lateral;
+
+    fn set_expr_has_lateral(body: &SetExpr) -> bool {
+        match body {
+            SetExpr::Select(select) => select.from.iter().any(|table| {
+                std::iter::once(&table.relation)
+                    .chain(table.joins.iter().map(|join| &join.relation))
+                    .any(|relation| matches!(relation, TableFactor::Derived { lateral: true, .. }))
+            }),
+            SetExpr::SetOperation { left, right, .. } => {
+                set_expr_has_lateral(left) || set_expr_has_lateral(right)
+            }
+            _ => false,
+        }
+    }
+
+    impl Visitor for Lateral {
+        type Break = ();
+
+        fn pre_visit_query(&mut self, query: &Query) -> ControlFlow<()> {
+            if set_expr_has_lateral(&query.body) {
+                return ControlFlow::Break(());
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    query.visit(&mut Lateral).is_break()
+}
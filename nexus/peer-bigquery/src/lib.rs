@@ -2,20 +2,32 @@ use std::{sync::Arc, time::Duration};
 
 use anyhow::Context;
 use cursor::BigQueryCursorManager;
+use futures::StreamExt;
 use gcp_bigquery_client::{
     model::{query_request::QueryRequest, query_response::ResultSet},
     Client,
 };
+use load::LoadJob;
 use peer_connections::PeerConnectionTracker;
-use peer_cursor::{CursorModification, QueryExecutor, QueryOutput, SchemaRef};
+use peer_cursor::{
+    batch::ROW_BATCH_SIZE,
+    connector::{Capabilities, Connector},
+    copy::CopyInDecoder,
+    error::peer_error,
+    util::{copy_values_to_rows, peer_table_name},
+    CopyInStream, CursorModification, QueryExecutor, QueryOutput, SchemaRef,
+};
 use pgerror::PgError;
 use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
-use pt::peerdb_peers::BigqueryConfig;
-use sqlparser::ast::{CloseCursor, Expr, FetchDirection, Statement, Value};
+use pt::peerdb_peers::{peer::Config, BigqueryConfig, DbType, Peer};
+use sqlparser::ast::{
+    CloseCursor, CopyTarget, Expr, FetchDirection, Ident, ObjectName, ObjectType, Statement, Value,
+};
 use stream::{BqRecordStream, BqSchema};
 
 mod ast;
 mod cursor;
+mod load;
 mod stream;
 
 pub struct BigQueryQueryExecutor {
@@ -26,8 +38,8 @@ pub struct BigQueryQueryExecutor {
     cursor_manager: BigQueryCursorManager,
 }
 
-pub async fn bq_client_from_config(config: BigqueryConfig) -> anyhow::Result<Client> {
-    let sa_key = yup_oauth2::ServiceAccountKey {
+pub(crate) fn service_account_key(config: &BigqueryConfig) -> yup_oauth2::ServiceAccountKey {
+    yup_oauth2::ServiceAccountKey {
         key_type: Some(config.auth_type.clone()),
         project_id: Some(config.project_id.clone()),
         private_key_id: Some(config.private_key_id.clone()),
@@ -38,7 +50,11 @@ pub async fn bq_client_from_config(config: BigqueryConfig) -> anyhow::Result<Cli
         token_uri: config.token_uri.clone(),
         auth_provider_x509_cert_url: Some(config.auth_provider_x509_cert_url.clone()),
         client_x509_cert_url: Some(config.client_x509_cert_url.clone()),
-    };
+    }
+}
+
+pub async fn bq_client_from_config(config: BigqueryConfig) -> anyhow::Result<Client> {
+    let sa_key = service_account_key(&config);
     let client = Client::from_service_account_key(sa_key, false)
         .await
         .context("unable to create GcpClient.")?;
@@ -67,6 +83,8 @@ impl BigQueryQueryExecutor {
     async fn run_tracked(&self, query: &str) -> PgWireResult<ResultSet> {
         let mut query_req = QueryRequest::new(query);
         query_req.timeout_ms = Some(Duration::from_secs(120).as_millis() as i32);
+        // the rest of the rows are fetched a page at a time as they are sent.
+        query_req.max_results = Some(ROW_BATCH_SIZE as i32);
 
         let token = self
             .peer_connections
@@ -86,9 +104,7 @@ impl BigQueryQueryExecutor {
             .await
             .map_err(|err| {
                 tracing::error!("error running query: {}", err);
-                PgWireError::ApiError(Box::new(PgError::Internal {
-                    err_msg: err.to_string(),
-                }))
+                peer_error(err.to_string())
             })?;
 
         token.end().await.map_err(|err| {
@@ -100,6 +116,39 @@ impl BigQueryQueryExecutor {
 
         Ok(result_set)
     }
+
+    fn record_stream(&self, result_set: ResultSet) -> BqRecordStream {
+        BqRecordStream::new(
+            (*self.client).clone(),
+            self.config.project_id.clone(),
+            result_set,
+        )
+    }
+
+    // tables always live in the peer's dataset.
+    fn table_id(&self, table_name: &ObjectName) -> PgWireResult<String> {
+        let table_name = peer_table_name(&self.peer_name, table_name)?;
+        Ok(table_name.0.last().unwrap().value.clone())
+    }
+
+    // loads rows into a table of the peer's dataset with a load job.
+    async fn load_rows(
+        &self,
+        table_id: &str,
+        columns: &[Ident],
+        rows: Vec<&[Option<String>]>,
+    ) -> anyhow::Result<usize> {
+        let mut job = LoadJob::new(&self.config, table_id).await?;
+        for row in rows {
+            job.write_row(columns, row).await?;
+        }
+        job.finish().await
+    }
+}
+
+fn copy_error(err: anyhow::Error) -> PgWireError {
+    tracing::error!("error copying rows: {}", err);
+    peer_error(err.to_string())
 }
 
 #[async_trait::async_trait]
@@ -116,7 +165,7 @@ impl QueryExecutor for BigQueryQueryExecutor {
                     .context("unable to rewrite query")
                     .map_err(|err| {
                         PgWireError::ApiError(Box::new(PgError::Internal {
-                            err_msg: err.to_string(),
+                            err_msg: format!("{:#}", err),
                         }))
                     })?;
 
@@ -125,7 +174,7 @@ impl QueryExecutor for BigQueryQueryExecutor {
 
                 let result_set = self.run_tracked(&query).await?;
 
-                let cursor = BqRecordStream::new(result_set);
+                let cursor = self.record_stream(result_set);
                 tracing::info!(
                     "retrieved {} rows for query {}",
                     cursor.get_num_records(),
@@ -198,6 +247,46 @@ impl QueryExecutor for BigQueryQueryExecutor {
                     closed_cursors,
                 )))
             }
+            Statement::Drop {
+                object_type: ObjectType::Table,
+                if_exists,
+                names,
+                ..
+            } => {
+                for name in names {
+                    let table_id = self.table_id(name)?;
+                    let query = format!(
+                        "DROP TABLE {}{}.{}",
+                        if *if_exists { "IF EXISTS " } else { "" },
+                        self.config.dataset_id,
+                        table_id
+                    );
+                    tracing::info!("bq drop table: {}", query);
+                    self.run_tracked(&query).await?;
+                }
+                Ok(QueryOutput::AffectedRows(0))
+            }
+            Statement::Copy {
+                table_name,
+                columns,
+                to: false,
+                target: CopyTarget::Stdin,
+                values,
+                ..
+            } => {
+                let rows = copy_values_to_rows(columns.len(), values)?;
+                let table_id = self.table_id(table_name)?;
+                tracing::info!(
+                    "copying {} rows into bigquery table {}",
+                    rows.len(),
+                    table_id
+                );
+                let rows_copied = self
+                    .load_rows(&table_id, columns, rows)
+                    .await
+                    .map_err(copy_error)?;
+                Ok(QueryOutput::AffectedRows(rows_copied))
+            }
             _ => {
                 let error = format!(
                     "only SELECT statements are supported in bigquery. got: {}",
@@ -226,7 +315,7 @@ impl QueryExecutor for BigQueryQueryExecutor {
                     .context("unable to rewrite query")
                     .map_err(|err| {
                         PgWireError::ApiError(Box::new(PgError::Internal {
-                            err_msg: err.to_string(),
+                            err_msg: format!("{:#}", err),
                         }))
                     })?;
 
@@ -255,6 +344,59 @@ impl QueryExecutor for BigQueryQueryExecutor {
             )))),
         }
     }
+
+    async fn execute_raw(&self, query: &str) -> PgWireResult<QueryOutput> {
+        tracing::info!("bq raw query: {}", query);
+        let result_set = self.run_tracked(query).await?;
+
+        // statements other than queries, e.g. DDL and DML, return no fields.
+        let response = result_set.query_response();
+        let has_fields = response
+            .schema
+            .as_ref()
+            .and_then(|schema| schema.fields.as_ref())
+            .map_or(false, |fields| !fields.is_empty());
+        if !has_fields {
+            let rows_affected = response
+                .num_dml_affected_rows
+                .as_deref()
+                .and_then(|rows| rows.parse().ok())
+                .unwrap_or(0);
+            return Ok(QueryOutput::AffectedRows(rows_affected));
+        }
+
+        let cursor = self.record_stream(result_set);
+        Ok(QueryOutput::Stream(Box::pin(cursor)))
+    }
+
+    // the rows are decoded as the client sends them and uploaded to a single
+    // load job, so that the table gets all of them or none.
+    async fn copy_in(&self, stmt: &Statement, mut data: CopyInStream) -> PgWireResult<usize> {
+        let mut decoder = CopyInDecoder::new(stmt)?;
+        let (table_id, columns) = match stmt {
+            Statement::Copy {
+                table_name,
+                columns,
+                ..
+            } => (self.table_id(table_name)?, columns),
+            _ => unreachable!("CopyInDecoder only decodes the data of COPY statements"),
+        };
+        tracing::info!("loading copied rows into bigquery table {}", table_id);
+
+        let mut job = LoadJob::new(&self.config, &table_id)
+            .await
+            .map_err(copy_error)?;
+        while let Some(chunk) = data.next().await {
+            for row in decoder.decode(&chunk?)? {
+                job.write_row(columns, &row).await.map_err(copy_error)?;
+            }
+        }
+        if let Some(row) = decoder.finish()? {
+            job.write_row(columns, &row).await.map_err(copy_error)?;
+        }
+        job.finish().await.map_err(copy_error)
+    }
+
     async fn is_connection_valid(&self) -> anyhow::Result<bool> {
         let sql = "SELECT 1;";
         let _result_set = self
@@ -265,3 +407,46 @@ impl QueryExecutor for BigQueryQueryExecutor {
         Ok(true)
     }
 }
+
+pub struct BigQueryConnector {
+    peer_connections: Arc<PeerConnectionTracker>,
+}
+
+impl BigQueryConnector {
+    pub fn new(peer_connections: Arc<PeerConnectionTracker>) -> Self {
+        Self { peer_connections }
+    }
+}
+
+#[async_trait::async_trait]
+impl Connector for BigQueryConnector {
+    fn name(&self) -> &'static str {
+        "bigquery"
+    }
+
+    fn db_type(&self) -> DbType {
+        DbType::Bigquery
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            execute_raw: true,
+            manage_tables: true,
+            pooled: false,
+        }
+    }
+
+    async fn connect(&self, peer: &Peer) -> anyhow::Result<Box<dyn QueryExecutor>> {
+        match &peer.config {
+            Some(Config::BigqueryConfig(config)) => Ok(Box::new(
+                BigQueryQueryExecutor::new(
+                    peer.name.clone(),
+                    config,
+                    self.peer_connections.clone(),
+                )
+                .await?,
+            )),
+            _ => anyhow::bail!("peer {} is not a bigquery peer", peer.name),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use pt::peerdb_peers::BigqueryConfig;
+use reqwest::{header, redirect, StatusCode};
+use serde_json::json;
+use sqlparser::ast::Ident;
+use tokio::time::sleep;
+
+use crate::service_account_key;
+
+const UPLOAD_URL: &str = "https://bigquery.googleapis.com/upload/bigquery/v2/projects";
+const JOBS_URL: &str = "https://bigquery.googleapis.com/bigquery/v2/projects";
+const BIGQUERY_SCOPE: &str = "https://www.googleapis.com/auth/bigquery";
+
+// the size of the chunks the rows are uploaded in, the chunks of a resumable
+// upload are multiples of 256 KiB but for the last one.
+const UPLOAD_CHUNK_SIZE: usize = 32 * 256 * 1024;
+const JOB_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A load job that appends rows to a table of the peer's dataset. The rows
+/// are uploaded as newline-delimited JSON while they are written, a chunk of
+/// a resumable upload at a time, and loaded once the last chunk is uploaded.
+/// Unlike streaming inserts, the rows are loaded all at once or not at all.
+pub struct LoadJob<'a> {
+    config: &'a BigqueryConfig,
+    table_id: String,
+    client: reqwest::Client,
+    token: String,
+    // the session of the upload, started along with the first chunk.
+    session_url: Option<String>,
+    buf: Vec<u8>,
+    uploaded: u64,
+}
+
+impl<'a> LoadJob<'a> {
+    pub async fn new(config: &'a BigqueryConfig, table_id: &str) -> anyhow::Result<LoadJob<'a>> {
+        let auth = yup_oauth2::ServiceAccountAuthenticator::builder(service_account_key(config))
+            .build()
+            .await
+            .context("unable to create the authenticator of the service account")?;
+        let token = auth.token(&[BIGQUERY_SCOPE]).await?.as_str().to_owned();
+        // an unfinished upload is answered with a 308 that isn't a redirect.
+        let client = reqwest::Client::builder()
+            .redirect(redirect::Policy::none())
+            .build()?;
+        Ok(Self {
+            config,
+            table_id: table_id.to_owned(),
+            client,
+            token,
+            session_url: None,
+            buf: vec![],
+            uploaded: 0,
+        })
+    }
+
+    pub async fn write_row(
+        &mut self,
+        columns: &[Ident],
+        row: &[Option<String>],
+    ) -> anyhow::Result<()> {
+        let object: serde_json::Map<String, serde_json::Value> = columns
+            .iter()
+            .zip(row.iter())
+            .map(|(column, value)| {
+                let value = match value {
+                    Some(value) => serde_json::Value::String(value.clone()),
+                    None => serde_json::Value::Null,
+                };
+                (column.value.clone(), value)
+            })
+            .collect();
+        serde_json::to_writer(&mut self.buf, &object)?;
+        self.buf.push(b'\n');
+        // the last chunk is never empty, so that it carries the size of the upload.
+        while self.buf.len() > UPLOAD_CHUNK_SIZE {
+            self.upload_chunk(UPLOAD_CHUNK_SIZE, false).await?;
+        }
+        Ok(())
+    }
+
+    /// Uploads the rest of the rows and waits for them to be loaded, returns
+    /// the number of rows loaded. No job is run for no rows.
+    pub async fn finish(mut self) -> anyhow::Result<usize> {
+        if self.session_url.is_none() && self.buf.is_empty() {
+            return Ok(0);
+        }
+        let job = self.upload_chunk(self.buf.len(), true).await?;
+        self.wait(job).await
+    }
+
+    async fn start_upload(&self) -> anyhow::Result<String> {
+        let job_id = format!("peerdb_copy_{}", uuid::Uuid::new_v4());
+        let job = json!({
+            "jobReference": {
+                "projectId": self.config.project_id,
+                "jobId": job_id,
+            },
+            "configuration": {
+                "load": {
+                    "destinationTable": {
+                        "projectId": self.config.project_id,
+                        "datasetId": self.config.dataset_id,
+                        "tableId": self.table_id,
+                    },
+                    "sourceFormat": "NEWLINE_DELIMITED_JSON",
+                    "writeDisposition": "WRITE_APPEND",
+                },
+            },
+        });
+        let response = self
+            .client
+            .post(format!("{}/{}/jobs", UPLOAD_URL, self.config.project_id))
+            .query(&[("uploadType", "resumable")])
+            .bearer_auth(&self.token)
+            .header("X-Upload-Content-Type", "application/octet-stream")
+            .json(&job)
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!(
+                "failed to start load job {}: {} {}",
+                job_id,
+                status,
+                response.text().await?
+            );
+        }
+        let session_url = response
+            .headers()
+            .get(header::LOCATION)
+            .context("no upload session for the load job")?
+            .to_str()?
+            .to_owned();
+        Ok(session_url)
+    }
+
+    // uploads the first len bytes of the buffer, the last chunk of the upload
+    // returns the job it started.
+    async fn upload_chunk(&mut self, len: usize, last: bool) -> anyhow::Result<serde_json::Value> {
+        let session_url = match &self.session_url {
+            Some(session_url) => session_url.clone(),
+            None => {
+                let session_url = self.start_upload().await?;
+                self.session_url = Some(session_url.clone());
+                session_url
+            }
+        };
+        let end = self.uploaded + len as u64;
+        let content_range = match (len, last) {
+            (0, _) => format!("bytes */{}", end),
+            (_, true) => format!("bytes {}-{}/{}", self.uploaded, end - 1, end),
+            (_, false) => format!("bytes {}-{}/*", self.uploaded, end - 1),
+        };
+        let chunk: Vec<u8> = self.buf.drain(..len).collect();
+        let response = self
+            .client
+            .put(&session_url)
+            .bearer_auth(&self.token)
+            .header(header::CONTENT_RANGE, content_range)
+            .body(chunk)
+            .send()
+            .await?;
+        self.uploaded = end;
+
+        let status = response.status();
+        match status {
+            // Resume Incomplete, the session takes the next chunk.
+            StatusCode::PERMANENT_REDIRECT if !last => Ok(serde_json::Value::Null),
+            _ if status.is_success() && last => Ok(response.json().await?),
+            _ => anyhow::bail!(
+                "failed to upload rows to the load job: {} {}",
+                status,
+                response.text().await?
+            ),
+        }
+    }
+
+    // polls the job until it is done, returns the number of rows it loaded.
+    async fn wait(&self, mut job: serde_json::Value) -> anyhow::Result<usize> {
+        loop {
+            let status = &job["status"];
+            if let Some(error) = status.get("errorResult") {
+                anyhow::bail!(
+                    "failed to load rows into {}: {} {}",
+                    self.table_id,
+                    error["message"],
+                    status["errors"]
+                );
+            }
+            if status["state"] == "DONE" {
+                let output_rows = job["statistics"]["load"]["outputRows"]
+                    .as_str()
+                    .unwrap_or("0");
+                return Ok(output_rows.parse()?);
+            }
+
+            sleep(JOB_POLL_INTERVAL).await;
+            let reference = &job["jobReference"];
+            let job_id = reference["jobId"].as_str().context("load job has no id")?;
+            let mut request = self
+                .client
+                .get(format!(
+                    "{}/{}/jobs/{}",
+                    JOBS_URL, self.config.project_id, job_id
+                ))
+                .bearer_auth(&self.token);
+            if let Some(location) = reference["location"].as_str() {
+                request = request.query(&[("location", location)]);
+            }
+            let response = request.send().await?;
+            let status = response.status();
+            if !status.is_success() {
+                anyhow::bail!(
+                    "failed to get load job {}: {} {}",
+                    job_id,
+                    status,
+                    response.text().await?
+                );
+            }
+            job = response.json().await?;
+        }
+    }
+}
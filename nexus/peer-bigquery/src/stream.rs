@@ -1,22 +1,33 @@
 use std::{
     pin::Pin,
     str::FromStr,
+    sync::Arc,
     task::{Context, Poll},
 };
 
+use anyhow::Context as _;
+
 use chrono::{NaiveDateTime, TimeZone, Utc};
 use futures::Stream;
-use gcp_bigquery_client::model::{
-    field_type::FieldType, query_response::ResultSet, table_field_schema::TableFieldSchema,
+use gcp_bigquery_client::{
+    model::{
+        field_type::FieldType,
+        get_query_results_parameters::GetQueryResultsParameters,
+        query_response::{QueryResponse, ResultSet},
+        table_field_schema::TableFieldSchema,
+    },
+    Client,
+};
+use peer_cursor::{
+    batch::{RecordBatchSender, RecordBatchStream, ROW_BATCH_SIZE},
+    Record, RecordStream, Schema, SchemaRef,
 };
-use peer_cursor::{Record, RecordStream, Schema, SchemaRef};
-use pgerror::PgError;
 use pgwire::{
     api::{
         results::{FieldFormat, FieldInfo},
         Type,
     },
-    error::{PgWireError, PgWireResult},
+    error::PgWireResult,
 };
 use rust_decimal::Decimal;
 use value::Value;
@@ -28,8 +39,7 @@ pub struct BqSchema {
 }
 
 pub struct BqRecordStream {
-    result_set: ResultSet,
-    schema: BqSchema,
+    rows: RecordBatchStream,
     num_records: usize,
 }
 
@@ -80,27 +90,10 @@ impl BqSchema {
     pub fn schema(&self) -> SchemaRef {
         self.schema.clone()
     }
-}
-
-impl BqRecordStream {
-    pub fn new(result_set: ResultSet) -> Self {
-        let bq_schema = BqSchema::from_result_set(&result_set);
-        let num_records = result_set.row_count();
-
-        Self {
-            result_set,
-            schema: bq_schema,
-            num_records,
-        }
-    }
-
-    pub fn get_num_records(&self) -> usize {
-        self.num_records
-    }
 
     pub fn convert_result_set_item(&self, result_set: &ResultSet) -> anyhow::Result<Record> {
         let mut values = Vec::new();
-        for field in &self.schema.fields {
+        for field in &self.fields {
             let field_type = &field.r#type;
             let field_name = &field.name;
 
@@ -163,33 +156,85 @@ impl BqRecordStream {
 
         Ok(Record {
             values,
-            schema: self.schema.schema(),
+            schema: self.schema(),
         })
     }
 }
 
+impl BqRecordStream {
+    /// Streams the rows of the query whose first page the result set holds,
+    /// the pages after it are fetched while the rows are sent.
+    pub fn new(client: Client, project_id: String, result_set: ResultSet) -> Self {
+        let bq_schema = Arc::new(BqSchema::from_result_set(&result_set));
+        let num_records = result_set
+            .query_response()
+            .total_rows
+            .as_deref()
+            .and_then(|rows| rows.parse().ok())
+            .unwrap_or_else(|| result_set.row_count());
+        let rows = RecordBatchStream::spawn(bq_schema.schema(), move |sender| {
+            fetch_pages(client, project_id, bq_schema, result_set, sender)
+        });
+
+        Self { rows, num_records }
+    }
+
+    pub fn get_num_records(&self) -> usize {
+        self.num_records
+    }
+}
+
+// sends the rows of each page, fetching the next page once the client caught
+// up enough for the rows to be sent.
+async fn fetch_pages(
+    client: Client,
+    project_id: String,
+    schema: Arc<BqSchema>,
+    mut result_set: ResultSet,
+    sender: RecordBatchSender,
+) -> anyhow::Result<()> {
+    loop {
+        let mut batch = Vec::with_capacity(result_set.row_count());
+        while result_set.next_row() {
+            batch.push(schema.convert_result_set_item(&result_set)?);
+        }
+        if !sender.send(batch).await {
+            return Ok(());
+        }
+
+        let response = result_set.query_response();
+        let (page_token, job_reference) = match (&response.page_token, &response.job_reference) {
+            (Some(page_token), Some(job_reference)) => (page_token.clone(), job_reference),
+            _ => return Ok(()),
+        };
+        let job_id = job_reference
+            .job_id
+            .clone()
+            .context("the query has more rows but no job to fetch them from")?;
+        let parameters = GetQueryResultsParameters {
+            page_token: Some(page_token),
+            location: job_reference.location.clone(),
+            max_results: Some(ROW_BATCH_SIZE as i32),
+            ..Default::default()
+        };
+        let page = client
+            .job()
+            .get_query_results(&project_id, &job_id, parameters)
+            .await?;
+        result_set = ResultSet::new(QueryResponse::from(page));
+    }
+}
+
 impl Stream for BqRecordStream {
     type Item = PgWireResult<Record>;
 
-    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.get_mut();
-        match this.result_set.next_row() {
-            true => {
-                let record = this.convert_result_set_item(&this.result_set);
-                let result = record.map_err(|e| {
-                    PgWireError::ApiError(Box::new(PgError::Internal {
-                        err_msg: format!("error getting curent row: {}", e),
-                    }))
-                });
-                Poll::Ready(Some(result))
-            }
-            false => Poll::Ready(None),
-        }
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.rows).poll_next(cx)
     }
 }
 
 impl RecordStream for BqRecordStream {
     fn schema(&self) -> SchemaRef {
-        self.schema.schema()
+        self.rows.schema()
     }
 }
@@ -0,0 +1,96 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+use pgwire::error::PgWireResult;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use crate::{error::peer_error, Record, RecordStream, SchemaRef};
+
+/// The number of rows peers fetch and convert at a time.
+pub const ROW_BATCH_SIZE: usize = 1024;
+
+/// The number of batches fetched ahead of the batch being sent to the client.
+pub const PREFETCHED_BATCHES: usize = 2;
+
+/// The rows of a result, fetched in batches by a task while they are sent to
+/// the client. The task is at most [`PREFETCHED_BATCHES`] batches ahead of the
+/// client, so only those are held in memory, and it is aborted when the stream
+/// is dropped before the result is sent in full.
+pub struct RecordBatchStream {
+    schema: SchemaRef,
+    batches: mpsc::Receiver<PgWireResult<Vec<Record>>>,
+    batch: std::vec::IntoIter<Record>,
+    producer: JoinHandle<()>,
+}
+
+/// Where the task fetching the rows of a [`RecordBatchStream`] sends them.
+pub struct RecordBatchSender(mpsc::Sender<PgWireResult<Vec<Record>>>);
+
+impl RecordBatchSender {
+    /// Waits for room for the batch, false when the stream was dropped and the
+    /// rest of the rows doesn't need to be fetched.
+    pub async fn send(&self, batch: Vec<Record>) -> bool {
+        batch.is_empty() || self.0.send(Ok(batch)).await.is_ok()
+    }
+}
+
+impl RecordBatchStream {
+    /// Spawns the task fetching the rows, an error it returns is sent after the
+    /// rows it sent before it.
+    pub fn spawn<F, Fut>(schema: SchemaRef, produce: F) -> Self
+    where
+        F: FnOnce(RecordBatchSender) -> Fut,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        let (sender, batches) = mpsc::channel(PREFETCHED_BATCHES);
+        let errors = sender.clone();
+        let produced = produce(RecordBatchSender(sender));
+        let producer = tokio::spawn(async move {
+            if let Err(err) = produced.await {
+                tracing::error!("error fetching rows: {:?}", err);
+                let err = peer_error(format!("error fetching rows: {:#}", err));
+                let _ = errors.send(Err(err)).await;
+            }
+        });
+        Self {
+            schema,
+            batches,
+            batch: Vec::new().into_iter(),
+            producer,
+        }
+    }
+}
+
+impl Drop for RecordBatchStream {
+    fn drop(&mut self) {
+        self.producer.abort();
+    }
+}
+
+impl Stream for RecordBatchStream {
+    type Item = PgWireResult<Record>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(record) = self.batch.next() {
+                return Poll::Ready(Some(Ok(record)));
+            }
+            match self.batches.poll_recv(cx) {
+                Poll::Ready(Some(Ok(batch))) => self.batch = batch.into_iter(),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl RecordStream for RecordBatchStream {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
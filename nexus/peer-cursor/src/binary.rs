@@ -0,0 +1,259 @@
+use std::{collections::HashMap, error::Error};
+
+use bytes::{BufMut, BytesMut};
+use pgwire::types::ToSqlText;
+use postgres_types::{to_sql_checked, IsNull, Kind, ToSql, Type};
+use rust_decimal::Decimal;
+use value::{array::ArrayValue, hstore_text, time_with_time_zone_text, Value};
+
+type EncodeResult = Result<IsNull, Box<dyn Error + Sync + Send>>;
+
+/// The types whose columns are sent in binary when the client asks for it,
+/// the columns of other types, like geometric types, are sent as text
+/// regardless.
+pub fn has_binary_encoding(datatype: &Type) -> bool {
+    matches!(
+        *datatype,
+        Type::BOOL
+            | Type::CHAR
+            | Type::INT2
+            | Type::INT4
+            | Type::INT8
+            | Type::OID
+            | Type::FLOAT4
+            | Type::FLOAT8
+            | Type::NUMERIC
+            | Type::TEXT
+            | Type::VARCHAR
+            | Type::BPCHAR
+            | Type::NAME
+            | Type::BYTEA
+            | Type::DATE
+            | Type::TIME
+            | Type::TIMETZ
+            | Type::TIMESTAMP
+            | Type::TIMESTAMPTZ
+            | Type::INTERVAL
+            | Type::UUID
+            | Type::JSON
+            | Type::JSONB
+            | Type::INET
+            | Type::CIDR
+    ) || matches!(datatype.kind(), Kind::Enum(_))
+        || is_hstore(datatype)
+        || is_binary_array(datatype)
+}
+
+// hstore is an extension, so its columns are known by the name of their type.
+fn is_hstore(datatype: &Type) -> bool {
+    <HashMap<String, Option<String>> as ToSql>::accepts(datatype)
+}
+
+fn is_binary_array(datatype: &Type) -> bool {
+    matches!(
+        *datatype,
+        Type::BOOL_ARRAY
+            | Type::INT2_ARRAY
+            | Type::INT4_ARRAY
+            | Type::INT8_ARRAY
+            | Type::FLOAT4_ARRAY
+            | Type::FLOAT8_ARRAY
+            | Type::NUMERIC_ARRAY
+            | Type::CHAR_ARRAY
+            | Type::TEXT_ARRAY
+            | Type::VARCHAR_ARRAY
+            | Type::BPCHAR_ARRAY
+            | Type::BYTEA_ARRAY
+            | Type::DATE_ARRAY
+            | Type::TIME_ARRAY
+            | Type::TIMESTAMP_ARRAY
+            | Type::TIMESTAMPTZ_ARRAY
+    )
+}
+
+/// A value of a column sent in binary. Peers don't always convert their values
+/// to the variant of the column's type, e.g. a BigQuery INT64 may sit in a
+/// numeric column, so the value is converted to the column's type first.
+#[derive(Debug)]
+pub(crate) struct BinaryValue<'a>(pub &'a Value);
+
+impl ToSql for BinaryValue<'_> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> EncodeResult {
+        match (self.0, ty) {
+            (Value::Null, _) => return Ok(IsNull::Yes),
+            (Value::Bool(v), &Type::BOOL) => out.put_u8(*v as u8),
+            (Value::Char(c), &Type::CHAR) => out.put_u8(u8::try_from(u32::from(*c))?),
+            (value, &Type::INT2) => out.put_i16(i16::try_from(integer(value, ty)?)?),
+            (value, &Type::INT4) => out.put_i32(i32::try_from(integer(value, ty)?)?),
+            (value, &Type::INT8) => out.put_i64(integer(value, ty)?),
+            (value, &Type::OID) => out.put_u32(u32::try_from(integer(value, ty)?)?),
+            (value, &Type::FLOAT4) => out.put_f32(float(value, ty)? as f32),
+            (value, &Type::FLOAT8) => out.put_f64(float(value, ty)?),
+            (value, &Type::NUMERIC) => return numeric(value, ty)?.to_sql(ty, out),
+            (Value::Binary(b) | Value::VarBinary(b), &Type::BYTEA) => out.put_slice(b),
+            (Value::Date(d), &Type::DATE) => return d.to_sql(ty, out),
+            (Value::Time(t), &Type::TIME) => return t.to_sql(ty, out),
+            // the time, followed by its offset in seconds west of UTC.
+            (Value::TimeWithTimeZone(t, offset), &Type::TIMETZ) => {
+                t.to_sql(&Type::TIME, out)?;
+                out.put_i32(-offset.local_minus_utc());
+            }
+            (Value::Time(t), &Type::TIMETZ) => {
+                t.to_sql(&Type::TIME, out)?;
+                out.put_i32(0);
+            }
+            (Value::Interval(i), &Type::INTERVAL) => return i.to_sql(ty, out),
+            (
+                Value::Timestamp(ts) | Value::TimestampWithTimeZone(ts),
+                &Type::TIMESTAMP | &Type::TIMESTAMPTZ,
+            ) => return ts.naive_utc().to_sql(&Type::TIMESTAMP, out),
+            (Value::PostgresTimestamp(ts), &Type::TIMESTAMP | &Type::TIMESTAMPTZ) => {
+                return ts.to_sql(&Type::TIMESTAMP, out)
+            }
+            (Value::Uuid(u), &Type::UUID) => out.put_slice(u.as_bytes()),
+            (Value::IpAddr(ip), &Type::INET | &Type::CIDR) => return ip.to_sql(ty, out),
+            // jsonb is its text after the version of its format.
+            (Value::Json(j) | Value::JsonB(j), &Type::JSONB) => {
+                out.put_u8(1);
+                out.put_slice(j.to_string().as_bytes());
+            }
+            (Value::Text(j) | Value::VarChar(j), &Type::JSONB) => {
+                out.put_u8(1);
+                out.put_slice(j.as_bytes());
+            }
+            // an enum is its label.
+            (Value::Enum(label) | Value::Text(label) | Value::VarChar(label), _)
+                if matches!(ty.kind(), Kind::Enum(_)) =>
+            {
+                out.put_slice(label.as_bytes())
+            }
+            (Value::Hstore(h), _) if is_hstore(ty) => {
+                let hstore: HashMap<String, Option<String>> = h
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Some(value.clone())))
+                    .collect();
+                return hstore.to_sql(ty, out);
+            }
+            (Value::Array(array), _) if is_binary_array(ty) => return encode_array(array, ty, out),
+            (value, &Type::TEXT | &Type::VARCHAR | &Type::BPCHAR | &Type::NAME | &Type::JSON) => {
+                match text(value) {
+                    Some(text) => out.put_slice(text.as_bytes()),
+                    None => return Err(unsupported(value, ty)),
+                }
+            }
+            (value, _) => return Err(unsupported(value, ty)),
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        has_binary_encoding(ty)
+    }
+
+    to_sql_checked!();
+}
+
+// the columns of a BinaryValue are always sent in binary.
+impl ToSqlText for BinaryValue<'_> {
+    fn to_sql_text(&self, ty: &Type, _out: &mut BytesMut) -> EncodeResult {
+        Err(format!("a value encoded in binary was sent as text {}", ty).into())
+    }
+}
+
+fn encode_array(array: &ArrayValue, ty: &Type, out: &mut BytesMut) -> EncodeResult {
+    match (array, ty) {
+        // an array of no dimensions of the column's element type.
+        (ArrayValue::Empty, _) => {
+            let element_oid = match ty.kind() {
+                Kind::Array(element) => element.oid(),
+                _ => 0,
+            };
+            out.put_i32(0);
+            out.put_i32(0);
+            out.put_u32(element_oid);
+            Ok(IsNull::No)
+        }
+        (ArrayValue::TinyInt(arr), &Type::INT2_ARRAY) => {
+            let arr: Vec<i16> = arr.iter().map(|v| *v as i16).collect();
+            arr.to_sql(ty, out)
+        }
+        (ArrayValue::Numeric(arr), &Type::NUMERIC_ARRAY) => {
+            let arr = arr
+                .iter()
+                .map(|v| v.parse::<Decimal>())
+                .collect::<Result<Vec<_>, _>>()?;
+            arr.to_sql(ty, out)
+        }
+        (ArrayValue::Binary(arr) | ArrayValue::VarBinary(arr), &Type::BYTEA_ARRAY) => {
+            let arr: Vec<&[u8]> = arr.iter().map(|b| b.as_ref()).collect();
+            arr.to_sql(ty, out)
+        }
+        (ArrayValue::Bool(_), &Type::BOOL_ARRAY)
+        | (ArrayValue::SmallInt(_), &Type::INT2_ARRAY)
+        | (ArrayValue::Integer(_), &Type::INT4_ARRAY)
+        | (ArrayValue::BigInt(_), &Type::INT8_ARRAY)
+        | (ArrayValue::Float(_), &Type::FLOAT4_ARRAY)
+        | (ArrayValue::Double(_), &Type::FLOAT8_ARRAY)
+        | (ArrayValue::Char(_), &Type::CHAR_ARRAY)
+        | (
+            ArrayValue::Text(_) | ArrayValue::VarChar(_),
+            &Type::TEXT_ARRAY | &Type::VARCHAR_ARRAY | &Type::BPCHAR_ARRAY,
+        )
+        | (ArrayValue::Date(_), &Type::DATE_ARRAY)
+        | (ArrayValue::Time(_), &Type::TIME_ARRAY)
+        | (
+            ArrayValue::Timestamp(_) | ArrayValue::TimestampWithTimeZone(_),
+            &Type::TIMESTAMP_ARRAY | &Type::TIMESTAMPTZ_ARRAY,
+        ) => array.to_sql(ty, out),
+        _ => Err(format!("cannot send array {:?} as binary {}", array, ty).into()),
+    }
+}
+
+fn integer(value: &Value, ty: &Type) -> Result<i64, Box<dyn Error + Sync + Send>> {
+    match value {
+        Value::TinyInt(v) => Ok(*v as i64),
+        Value::SmallInt(v) => Ok(*v as i64),
+        Value::Integer(v) => Ok(*v as i64),
+        Value::BigInt(v) => Ok(*v),
+        Value::Oid(v) => Ok(*v as i64),
+        _ => Err(unsupported(value, ty)),
+    }
+}
+
+fn float(value: &Value, ty: &Type) -> Result<f64, Box<dyn Error + Sync + Send>> {
+    match value {
+        Value::Float(v) => Ok(*v as f64),
+        Value::Double(v) => Ok(*v),
+        other => Ok(integer(other, ty)? as f64),
+    }
+}
+
+fn numeric(value: &Value, ty: &Type) -> Result<Decimal, Box<dyn Error + Sync + Send>> {
+    match value {
+        Value::Numeric(v) => Ok(*v),
+        Value::Float(v) => Ok(Decimal::try_from(*v)?),
+        Value::Double(v) => Ok(Decimal::try_from(*v)?),
+        other => Ok(Decimal::from(integer(other, ty)?)),
+    }
+}
+
+// the text of the values a text column holds, which is also their binary
+// format.
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::Text(v) | Value::VarChar(v) | Value::Enum(v) => Some(v.clone()),
+        Value::Char(v) => Some(v.to_string()),
+        Value::Json(v) | Value::JsonB(v) => Some(v.to_string()),
+        Value::Numeric(v) => Some(v.to_string()),
+        Value::Interval(v) => Some(v.to_string()),
+        Value::TimeWithTimeZone(t, offset) => Some(time_with_time_zone_text(t, offset)),
+        Value::Hstore(v) => Some(hstore_text(v)),
+        Value::Uuid(v) => Some(v.to_string()),
+        Value::IpAddr(v) => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+fn unsupported(value: &Value, ty: &Type) -> Box<dyn Error + Sync + Send> {
+    format!("cannot send {:?} as binary {}", value, ty).into()
+}
@@ -0,0 +1,268 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use pt::peerdb_peers::{DbType, Peer};
+
+use crate::QueryExecutor;
+
+/// What nexus can do with the peers of a connector besides querying them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Capabilities {
+    /// Statements can be passed through to the peer with `EXECUTE ... ON PEER`.
+    pub execute_raw: bool,
+    /// Tables on the peer can be dropped and truncated, e.g. the destination
+    /// tables of a mirror that is dropped or resynced.
+    pub manage_tables: bool,
+    /// Connections to the peers can be handed from one session to the next
+    /// between transactions, in transaction pooling mode.
+    pub pooled: bool,
+}
+
+/// A table of a peer as listed by `SHOW TABLES IN PEER`.
+#[derive(Debug, Clone)]
+pub struct TableInfo {
+    /// The qualified name of the table.
+    pub name: String,
+    /// The rows of the table as estimated by the peer, None if it has no
+    /// estimate.
+    pub estimated_rows: Option<i64>,
+    /// Whether the table has a primary key, which CDC mirrors need.
+    pub has_primary_key: bool,
+}
+
+/// A column of a table of a peer.
+#[derive(Debug, Clone)]
+pub struct ColumnInfo {
+    /// The qualified name of the table of the column.
+    pub table: String,
+    pub name: String,
+    /// The type of the column as the peer names it.
+    pub data_type: String,
+    pub nullable: bool,
+}
+
+/// A Connector serves one type of peer: it checks the peers of that type,
+/// connects to them and lists their tables. Nexus finds the connector of a
+/// peer in the ConnectorRegistry, so a new type of peer only has to register
+/// its connector to be queried through nexus, see [`register_connector`].
+#[async_trait::async_trait]
+pub trait Connector: Send + Sync {
+    /// The name the connector is registered under, e.g. `postgres`.
+    fn name(&self) -> &'static str;
+
+    /// The type of the peers the connector serves.
+    fn db_type(&self) -> DbType;
+
+    fn capabilities(&self) -> Capabilities;
+
+    /// Connects to the peer, the executor runs the statements routed to it.
+    async fn connect(&self, peer: &Peer) -> anyhow::Result<Box<dyn QueryExecutor>>;
+
+    /// Checks that nexus can connect to the peer, before the peer is created.
+    async fn validate(&self, peer: &Peer) -> anyhow::Result<()> {
+        let executor = self.connect(peer).await?;
+        executor.is_connection_valid().await?;
+        Ok(())
+    }
+
+    /// Lists the tables of the peer by their qualified names.
+    async fn discover(&self, _peer: &Peer) -> anyhow::Result<Vec<String>> {
+        anyhow::bail!(
+            "listing the tables of {} peers is not supported",
+            self.db_type().as_str_name()
+        )
+    }
+
+    /// Lists the tables of the peer, or of one of its schemas, with their
+    /// estimated rows and whether they have a primary key.
+    async fn describe_tables(
+        &self,
+        _peer: &Peer,
+        _schema: Option<&str>,
+    ) -> anyhow::Result<Vec<TableInfo>> {
+        anyhow::bail!(
+            "describing the tables of {} peers is not supported",
+            self.db_type().as_str_name()
+        )
+    }
+
+    /// Lists the columns of the tables of the peer, or of one of its schemas,
+    /// in the order of the columns of each table.
+    async fn describe_columns(
+        &self,
+        _peer: &Peer,
+        _schema: Option<&str>,
+    ) -> anyhow::Result<Vec<ColumnInfo>> {
+        anyhow::bail!(
+            "describing the columns of {} peers is not supported",
+            self.db_type().as_str_name()
+        )
+    }
+}
+
+/// The connectors of the types of peers nexus connects to, keyed by their
+/// names. There is one connector for each type of peer.
+#[derive(Default)]
+pub struct ConnectorRegistry {
+    connectors: HashMap<&'static str, Arc<dyn Connector>>,
+}
+
+impl ConnectorRegistry {
+    /// Registers a connector, in place of any connector registered earlier
+    /// under the same name or for the same type of peer.
+    pub fn register(&mut self, connector: impl Connector + 'static) {
+        self.register_arc(Arc::new(connector));
+    }
+
+    fn register_arc(&mut self, connector: Arc<dyn Connector>) {
+        let db_type = connector.db_type();
+        self.connectors
+            .retain(|_, registered| registered.db_type() != db_type);
+        self.connectors.insert(connector.name(), connector);
+    }
+
+    /// Registers the connectors registered with [`register_connector`], in
+    /// place of the connectors registered so far for the same types of peers.
+    pub fn register_hooked(&mut self) {
+        let factories = CONNECTOR_FACTORIES.lock().unwrap();
+        for factory in factories.iter() {
+            self.register_arc(factory());
+        }
+    }
+
+    /// The connector of the peer's type, None for peers nexus doesn't connect
+    /// to itself.
+    pub fn get(&self, peer: &Peer) -> Option<Arc<dyn Connector>> {
+        self.connectors
+            .values()
+            .find(|connector| connector.db_type() as i32 == peer.r#type)
+            .cloned()
+    }
+
+    /// The connector registered under the name.
+    pub fn get_by_name(&self, name: &str) -> Option<Arc<dyn Connector>> {
+        self.connectors.get(name).cloned()
+    }
+
+    pub fn capabilities(&self, peer: &Peer) -> Capabilities {
+        self.get(peer)
+            .map(|connector| connector.capabilities())
+            .unwrap_or_default()
+    }
+}
+
+type ConnectorFactory = Box<dyn Fn() -> Arc<dyn Connector> + Send>;
+
+static CONNECTOR_FACTORIES: Mutex<Vec<ConnectorFactory>> = Mutex::new(Vec::new());
+
+/// Registers a connector with the registries nexus builds for its sessions
+/// from then on, in place of the built-in connector for the same type of peer
+/// if there is one. This is how a connector maintained out of tree is added to
+/// a build of the server: its crate calls this once before the server accepts
+/// connections. The factory is called for each registry, so that a connector
+/// may keep state per session.
+pub fn register_connector<F, C>(factory: F)
+where
+    F: Fn() -> C + Send + 'static,
+    C: Connector + 'static,
+{
+    CONNECTOR_FACTORIES
+        .lock()
+        .unwrap()
+        .push(Box::new(move || Arc::new(factory()) as Arc<dyn Connector>));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestConnector {
+        name: &'static str,
+        db_type: DbType,
+    }
+
+    #[async_trait::async_trait]
+    impl Connector for TestConnector {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn db_type(&self) -> DbType {
+            self.db_type
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::default()
+        }
+
+        async fn connect(&self, _peer: &Peer) -> anyhow::Result<Box<dyn QueryExecutor>> {
+            anyhow::bail!("test connectors don't connect")
+        }
+    }
+
+    fn peer_of(db_type: DbType) -> Peer {
+        Peer {
+            name: "peer".to_string(),
+            r#type: db_type as i32,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn connectors_are_found_by_name_and_type() {
+        let mut registry = ConnectorRegistry::default();
+        registry.register(TestConnector {
+            name: "postgres",
+            db_type: DbType::Postgres,
+        });
+
+        assert_eq!(registry.get_by_name("postgres").unwrap().name(), "postgres");
+        assert!(registry.get_by_name("bigquery").is_none());
+        assert_eq!(
+            registry.get(&peer_of(DbType::Postgres)).unwrap().name(),
+            "postgres"
+        );
+        assert!(registry.get(&peer_of(DbType::Bigquery)).is_none());
+    }
+
+    #[test]
+    fn a_connector_replaces_the_one_for_the_same_type() {
+        let mut registry = ConnectorRegistry::default();
+        registry.register(TestConnector {
+            name: "postgres",
+            db_type: DbType::Postgres,
+        });
+        registry.register(TestConnector {
+            name: "postgres-fork",
+            db_type: DbType::Postgres,
+        });
+
+        assert!(registry.get_by_name("postgres").is_none());
+        assert_eq!(
+            registry.get(&peer_of(DbType::Postgres)).unwrap().name(),
+            "postgres-fork"
+        );
+    }
+
+    #[test]
+    fn hooked_connectors_replace_the_builtin_ones() {
+        register_connector(|| TestConnector {
+            name: "hooked-snowflake",
+            db_type: DbType::Snowflake,
+        });
+
+        let mut registry = ConnectorRegistry::default();
+        registry.register(TestConnector {
+            name: "snowflake",
+            db_type: DbType::Snowflake,
+        });
+        registry.register_hooked();
+
+        assert_eq!(
+            registry.get(&peer_of(DbType::Snowflake)).unwrap().name(),
+            "hooked-snowflake"
+        );
+    }
+}
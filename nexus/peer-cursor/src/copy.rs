@@ -0,0 +1,409 @@
+use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
+use sqlparser::ast::{CopyLegacyCsvOption, CopyLegacyOption, CopyOption, Statement};
+
+/// A row of the data of a `COPY ... FROM STDIN`, NULL values are `None`.
+pub type CopyRow = Vec<Option<String>>;
+
+// the text and csv formats a client sends the data of a COPY in, with the
+// options of postgres and those of the syntax before postgres 9.0.
+#[derive(Debug, Clone)]
+struct CopyInFormat {
+    csv: bool,
+    delimiter: u8,
+    null: Vec<u8>,
+    quote: u8,
+    escape: Option<u8>,
+    header: bool,
+}
+
+impl CopyInFormat {
+    fn new(csv: bool) -> Self {
+        Self {
+            csv,
+            delimiter: if csv { b',' } else { b'\t' },
+            null: if csv { vec![] } else { b"\\N".to_vec() },
+            quote: b'"',
+            escape: None,
+            header: false,
+        }
+    }
+
+    fn from_options(
+        options: &[CopyOption],
+        legacy_options: &[CopyLegacyOption],
+    ) -> PgWireResult<Self> {
+        let is_csv = options.iter().any(|option| {
+            matches!(option, CopyOption::Format(format) if format.value.eq_ignore_ascii_case("csv"))
+        }) || legacy_options
+            .iter()
+            .any(|option| matches!(option, CopyLegacyOption::Csv(_)));
+        let mut format = CopyInFormat::new(is_csv);
+        for option in options {
+            match option {
+                CopyOption::Format(name) => match name.value.to_lowercase().as_str() {
+                    "csv" | "text" => {}
+                    "binary" => return Err(binary_unsupported()),
+                    _ => {
+                        return Err(unsupported(format!(
+                            "COPY format {} is not supported",
+                            name
+                        )))
+                    }
+                },
+                CopyOption::Delimiter(delimiter) => format.delimiter = single_byte(*delimiter)?,
+                CopyOption::Null(null) => format.null = null.as_bytes().to_vec(),
+                CopyOption::Quote(quote) => format.quote = single_byte(*quote)?,
+                CopyOption::Escape(escape) => format.escape = Some(single_byte(*escape)?),
+                CopyOption::Header(header) => format.header = *header,
+                // rows are only ever appended, FREEZE changes nothing for the peer.
+                CopyOption::Freeze(_) => {}
+                _ => {
+                    return Err(unsupported(format!(
+                        "COPY option {} is not supported",
+                        option
+                    )))
+                }
+            }
+        }
+        for option in legacy_options {
+            match option {
+                CopyLegacyOption::Delimiter(delimiter) => {
+                    format.delimiter = single_byte(*delimiter)?
+                }
+                CopyLegacyOption::Null(null) => format.null = null.as_bytes().to_vec(),
+                CopyLegacyOption::Csv(csv_options) => {
+                    for csv_option in csv_options {
+                        match csv_option {
+                            CopyLegacyCsvOption::Header => format.header = true,
+                            CopyLegacyCsvOption::Quote(quote) => {
+                                format.quote = single_byte(*quote)?
+                            }
+                            CopyLegacyCsvOption::Escape(escape) => {
+                                format.escape = Some(single_byte(*escape)?)
+                            }
+                            _ => {
+                                return Err(unsupported(format!(
+                                    "COPY option {} is not supported",
+                                    csv_option
+                                )))
+                            }
+                        }
+                    }
+                }
+                CopyLegacyOption::Binary => return Err(binary_unsupported()),
+            }
+        }
+        Ok(format)
+    }
+
+    fn escape(&self) -> u8 {
+        self.escape.unwrap_or(self.quote)
+    }
+}
+
+/// Decodes the data a client streams for a `COPY ... FROM STDIN` into rows,
+/// for the peers that load rows rather than taking the data as it is sent.
+/// The data is fed a CopyData message at a time, a row may span messages.
+pub struct CopyInDecoder {
+    format: CopyInFormat,
+    num_columns: usize,
+    buf: Vec<u8>,
+    // how much of the buffer was searched for the end of the next row, and
+    // whether the search stopped in a quoted csv value.
+    scanned: usize,
+    in_quotes: bool,
+    header_skipped: bool,
+    // the end-of-data marker was read, the rest of the data is ignored.
+    done: bool,
+}
+
+impl CopyInDecoder {
+    /// The decoder of the data of a COPY with an explicit column list,
+    /// in its text or csv format.
+    pub fn new(stmt: &Statement) -> PgWireResult<Self> {
+        let (num_columns, format) = match stmt {
+            Statement::Copy {
+                columns,
+                options,
+                legacy_options,
+                ..
+            } => (
+                columns.len(),
+                CopyInFormat::from_options(options, legacy_options)?,
+            ),
+            _ => return Err(unsupported("not a COPY statement".to_owned())),
+        };
+        if num_columns == 0 {
+            return Err(unsupported(
+                "COPY FROM STDIN into a peer requires an explicit column list".to_owned(),
+            ));
+        }
+        Ok(Self {
+            header_skipped: !format.header,
+            format,
+            num_columns,
+            buf: vec![],
+            scanned: 0,
+            in_quotes: false,
+            done: false,
+        })
+    }
+
+    /// The rows that end in the chunk, the rest is kept for the next one.
+    pub fn decode(&mut self, chunk: &[u8]) -> PgWireResult<Vec<CopyRow>> {
+        if self.done {
+            return Ok(vec![]);
+        }
+        self.buf.extend_from_slice(chunk);
+        let mut rows = vec![];
+        let mut start = 0;
+        while let Some(end) = self.row_end() {
+            let row = self.row(start, end)?;
+            start = end + 1;
+            if let Some(row) = row {
+                rows.push(row);
+            }
+            if self.done {
+                break;
+            }
+        }
+        self.buf.drain(..start);
+        self.scanned -= start;
+        Ok(rows)
+    }
+
+    /// The last row, when the data doesn't end with a newline.
+    pub fn finish(mut self) -> PgWireResult<Option<CopyRow>> {
+        if self.done || self.buf.is_empty() {
+            return Ok(None);
+        }
+        if self.in_quotes {
+            return Err(bad_copy_data("unterminated CSV quoted field".to_owned()));
+        }
+        let end = self.buf.len();
+        self.row(0, end)
+    }
+
+    // the end of the next row in the buffer, a newline outside of quotes.
+    fn row_end(&mut self) -> Option<usize> {
+        let escape = self.format.escape();
+        while self.scanned < self.buf.len() {
+            let i = self.scanned;
+            let b = self.buf[i];
+            if self.format.csv && self.in_quotes && b == escape && escape != self.format.quote {
+                // the escaped character may be in the next chunk.
+                if i + 1 == self.buf.len() {
+                    return None;
+                }
+                self.scanned += 2;
+                continue;
+            }
+            self.scanned += 1;
+            if self.format.csv && b == self.format.quote {
+                self.in_quotes = !self.in_quotes;
+            } else if b == b'\n' && !self.in_quotes {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    // the row of the line of the buffer, none for the header and the marker
+    // of the end of the data.
+    fn row(&mut self, start: usize, end: usize) -> PgWireResult<Option<CopyRow>> {
+        let mut line = &self.buf[start..end];
+        if let [rest @ .., b'\r'] = line {
+            line = rest;
+        }
+        if line == b"\\." {
+            self.done = true;
+            return Ok(None);
+        }
+        if !self.header_skipped {
+            self.header_skipped = true;
+            return Ok(None);
+        }
+        let values = if self.format.csv {
+            csv_values(&self.format, line)
+        } else {
+            text_values(&self.format, line)
+        };
+        if values.len() != self.num_columns {
+            return Err(bad_copy_data(format!(
+                "COPY data has {} values in a row, expected {} columns",
+                values.len(),
+                self.num_columns
+            )));
+        }
+        values
+            .into_iter()
+            .map(|value| {
+                value
+                    .map(|value| {
+                        String::from_utf8(value).map_err(|_| {
+                            copy_data_error(
+                                "22021",
+                                "invalid byte sequence for encoding \"UTF8\"".to_owned(),
+                            )
+                        })
+                    })
+                    .transpose()
+            })
+            .collect::<PgWireResult<CopyRow>>()
+            .map(Some)
+    }
+}
+
+// the values of a line in the text format of postgres, with the backslash
+// escapes decoded and NULL values as none.
+fn text_values(format: &CopyInFormat, line: &[u8]) -> Vec<Option<Vec<u8>>> {
+    let mut values = vec![];
+    let mut start = 0;
+    let mut i = 0;
+    while i <= line.len() {
+        if i < line.len() && line[i] == b'\\' {
+            i = (i + 2).min(line.len());
+            continue;
+        }
+        if i >= line.len() || line[i] == format.delimiter {
+            let raw = &line[start..i.min(line.len())];
+            values.push(if raw == format.null.as_slice() {
+                None
+            } else {
+                Some(unescape_text(raw))
+            });
+            start = i + 1;
+        }
+        i += 1;
+    }
+    values
+}
+
+fn unescape_text(raw: &[u8]) -> Vec<u8> {
+    let mut value = Vec::with_capacity(raw.len());
+    let mut i = 0;
+    while i < raw.len() {
+        if raw[i] != b'\\' || i + 1 == raw.len() {
+            value.push(raw[i]);
+            i += 1;
+            continue;
+        }
+        let c = raw[i + 1];
+        i += 2;
+        match c {
+            b'b' => value.push(0x08),
+            b'f' => value.push(0x0c),
+            b'n' => value.push(b'\n'),
+            b'r' => value.push(b'\r'),
+            b't' => value.push(b'\t'),
+            b'v' => value.push(0x0b),
+            b'0'..=b'7' => {
+                let mut byte = u32::from(c - b'0');
+                for _ in 0..2 {
+                    match raw.get(i) {
+                        Some(d @ b'0'..=b'7') => {
+                            byte = byte * 8 + u32::from(d - b'0');
+                            i += 1;
+                        }
+                        _ => break,
+                    }
+                }
+                value.push(byte as u8);
+            }
+            b'x' if raw.get(i).map_or(false, u8::is_ascii_hexdigit) => {
+                let mut byte = 0;
+                for _ in 0..2 {
+                    match raw.get(i).and_then(|d| (*d as char).to_digit(16)) {
+                        Some(d) => {
+                            byte = byte * 16 + d;
+                            i += 1;
+                        }
+                        None => break,
+                    }
+                }
+                value.push(byte as u8);
+            }
+            c => value.push(c),
+        }
+    }
+    value
+}
+
+// the values of a csv line, NULL values are the unquoted ones that match the
+// null string.
+fn csv_values(format: &CopyInFormat, line: &[u8]) -> Vec<Option<Vec<u8>>> {
+    let escape = format.escape();
+    let mut values = vec![];
+    let mut value = vec![];
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut i = 0;
+    while i < line.len() {
+        let b = line[i];
+        if in_quotes {
+            let next = line.get(i + 1).copied();
+            if b == escape && (next == Some(format.quote) || next == Some(escape)) {
+                value.push(line[i + 1]);
+                i += 2;
+                continue;
+            }
+            if b == format.quote {
+                in_quotes = false;
+            } else {
+                value.push(b);
+            }
+        } else if b == format.delimiter {
+            values.push(csv_value(format, std::mem::take(&mut value), quoted));
+            quoted = false;
+        } else if b == format.quote {
+            in_quotes = true;
+            quoted = true;
+        } else {
+            value.push(b);
+        }
+        i += 1;
+    }
+    values.push(csv_value(format, value, quoted));
+    values
+}
+
+fn csv_value(format: &CopyInFormat, value: Vec<u8>, quoted: bool) -> Option<Vec<u8>> {
+    if !quoted && value == format.null {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn single_byte(c: char) -> PgWireResult<u8> {
+    if c.is_ascii() {
+        Ok(c as u8)
+    } else {
+        Err(unsupported(format!(
+            "COPY delimiter, quote and escape must be single one-byte characters, got {}",
+            c
+        )))
+    }
+}
+
+fn binary_unsupported() -> PgWireError {
+    unsupported(
+        "COPY FROM STDIN in the binary format is only supported for postgres peers".to_owned(),
+    )
+}
+
+fn unsupported(message: String) -> PgWireError {
+    copy_data_error("0A000", message)
+}
+
+fn bad_copy_data(message: String) -> PgWireError {
+    copy_data_error("22P04", message)
+}
+
+fn copy_data_error(code: &str, message: String) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        code.to_owned(),
+        message,
+    )))
+}
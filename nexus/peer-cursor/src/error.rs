@@ -0,0 +1,78 @@
+use pgerror::PgError;
+use pgwire::error::{ErrorInfo, PgWireError};
+
+pub const UNIQUE_VIOLATION: &str = "23505";
+pub const INSUFFICIENT_PRIVILEGE: &str = "42501";
+pub const CONNECTION_FAILURE: &str = "08006";
+pub const QUERY_CANCELED: &str = "57014";
+
+// the failures told apart by the messages of peers whose errors carry no
+// SQLSTATE, checked in order, so that e.g. a connection that timed out is a
+// connection failure rather than a timeout of the query.
+const MESSAGE_PATTERNS: &[(&str, &[&str])] = &[
+    (
+        UNIQUE_VIOLATION,
+        &["duplicate key", "unique constraint", "duplicate row"],
+    ),
+    (
+        INSUFFICIENT_PRIVILEGE,
+        &[
+            "permission denied",
+            "access denied",
+            "insufficient privileges",
+            "not authorized",
+            "403 forbidden",
+        ],
+    ),
+    (
+        CONNECTION_FAILURE,
+        &[
+            "connection refused",
+            "connection reset",
+            "connection closed",
+            "connection timed out",
+            "broken pipe",
+            "error connecting",
+            "error trying to connect",
+            "failed to lookup address",
+        ],
+    ),
+    (
+        QUERY_CANCELED,
+        &[
+            "timed out",
+            "timeout",
+            "deadline exceeded",
+            "canceling statement",
+        ],
+    ),
+];
+
+/// The SQLSTATE of the failure an error message of a peer describes, none
+/// when it isn't one clients tell apart.
+pub fn sqlstate_from_message(message: &str) -> Option<&'static str> {
+    let message = message.to_lowercase();
+    MESSAGE_PATTERNS
+        .iter()
+        .find(|(_, patterns)| patterns.iter().any(|pattern| message.contains(pattern)))
+        .map(|(code, _)| *code)
+}
+
+/// The error of a peer, sent to the client with the SQLSTATE of the failure
+/// so that it can tell the ones worth retrying from the others.
+pub fn peer_error_with_code(code: &str, err_msg: String) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        code.to_owned(),
+        err_msg,
+    )))
+}
+
+/// The error of a peer that only reports a message, with the SQLSTATE the
+/// message tells of, or an internal error when it tells of none.
+pub fn peer_error(err_msg: String) -> PgWireError {
+    match sqlstate_from_message(&err_msg) {
+        Some(code) => peer_error_with_code(code, err_msg),
+        None => PgWireError::ApiError(Box::new(PgError::Internal { err_msg })),
+    }
+}
@@ -1,10 +1,19 @@
 use std::{pin::Pin, sync::Arc};
 
+use bytes::Bytes;
 use futures::Stream;
-use pgwire::{api::results::FieldInfo, error::PgWireResult};
+use pgwire::{
+    api::results::FieldInfo,
+    error::{ErrorInfo, PgWireError, PgWireResult},
+};
 use sqlparser::ast::Statement;
 use value::Value;
 
+pub mod batch;
+pub mod binary;
+pub mod connector;
+pub mod copy;
+pub mod error;
 pub mod util;
 
 #[derive(Debug, Clone)]
@@ -25,11 +34,24 @@ pub trait RecordStream: Stream<Item = PgWireResult<Record>> {
 
 pub type SendableStream = Pin<Box<dyn RecordStream + Send>>;
 
+/// The data a client streams for a `COPY ... FROM STDIN`, the chunks of the
+/// CopyData messages it sends, in the format of the COPY.
+pub type CopyInStream = Pin<Box<dyn Stream<Item = PgWireResult<Bytes>> + Send>>;
+
 pub struct Records {
     pub records: Vec<Record>,
     pub schema: SchemaRef,
 }
 
+/// A notification of a channel the session listens to on a peer, sent to the
+/// client as the peer sent it.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub process_id: i32,
+    pub channel: String,
+    pub payload: String,
+}
+
 #[derive(Debug, Clone)]
 pub enum CursorModification {
     Created(String),
@@ -52,5 +74,69 @@ pub trait QueryExecutor: Send + Sync {
 
     async fn describe(&self, stmt: &Statement) -> PgWireResult<Option<SchemaRef>>;
 
+    /// Runs a statement as it is on the peer, for `EXECUTE ... ON PEER`.
+    async fn execute_raw(&self, query: &str) -> PgWireResult<QueryOutput>;
+
+    /// Runs a statement with placeholders prepared on the peer, with the text
+    /// of its parameters, for the peers that prepare statements themselves.
+    async fn execute_prepared(
+        &self,
+        _stmt: &Statement,
+        _parameters: &[Option<String>],
+    ) -> PgWireResult<QueryOutput> {
+        Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "0A000".to_owned(),
+            "the peer doesn't prepare statements".to_owned(),
+        ))))
+    }
+
+    /// Copies the data a client streams for a `COPY ... FROM STDIN` into the
+    /// table of the statement, and returns the number of rows copied. Nothing
+    /// is copied when the data fails part way, e.g. the client sent CopyFail.
+    async fn copy_in(&self, _stmt: &Statement, _data: CopyInStream) -> PgWireResult<usize> {
+        Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "0A000".to_owned(),
+            "the peer doesn't support COPY FROM STDIN with streamed data".to_owned(),
+        ))))
+    }
+
     async fn is_connection_valid(&self) -> anyhow::Result<bool>;
+
+    /// Cancels what the executor is running on the peer, when the client sends
+    /// a cancel request. It is a no-op when nothing is running.
+    async fn cancel(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Sets a setting of the session on the peer, or resets it when there is
+    /// no value. Only the peers whose queries depend on it set it.
+    async fn set_setting(&self, _name: &str, _value: Option<&str>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Resets what the statements of a session left on the connection, like
+    /// its settings, before the connection is handed to another session.
+    async fn reset_session(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// The notices and warnings the peer sent since they were last taken,
+    /// they are sent to the client along with the results of its query.
+    fn take_notices(&self) -> Vec<ErrorInfo> {
+        vec![]
+    }
+
+    /// The notifications of the channels the session listens to on the peer
+    /// that arrived since they were last taken.
+    fn take_notifications(&self) -> Vec<Notification> {
+        vec![]
+    }
+
+    /// Waits for notifications to arrive, which never happens on the peers
+    /// that don't send any.
+    async fn notifications_arrived(&self) {
+        std::future::pending().await
+    }
 }
@@ -1,16 +1,29 @@
-use std::sync::Arc;
+use std::{collections::HashSet, ops::ControlFlow, sync::Arc};
 
 use futures::{stream, StreamExt};
-use pgerror::PgError;
 use pgwire::{
-    api::results::{DataRowEncoder, FieldInfo, QueryResponse, Response},
-    error::{PgWireError, PgWireResult},
+    api::{
+        portal::Format,
+        results::{DataRowEncoder, FieldFormat, FieldInfo, QueryResponse, Response},
+    },
+    error::{ErrorInfo, PgWireError, PgWireResult},
 };
-use value::Value;
+use sqlparser::ast::{ObjectName, Query, Visit, Visitor};
+use value::{hstore_text, time_with_time_zone_text, Value};
 
-use crate::{Records, SchemaRef, SendableStream};
+use crate::{
+    binary::{has_binary_encoding, BinaryValue},
+    Records, Schema, SchemaRef, SendableStream,
+};
 
-fn encode_value(value: &Value, builder: &mut DataRowEncoder) -> PgWireResult<()> {
+fn encode_value(
+    value: &Value,
+    field: &FieldInfo,
+    builder: &mut DataRowEncoder,
+) -> PgWireResult<()> {
+    if *field.format() == FieldFormat::Binary {
+        return builder.encode_field(&BinaryValue(value));
+    }
     match value {
         Value::Null => builder.encode_field(&None::<&i8>),
         Value::Bool(v) => builder.encode_field(v),
@@ -35,12 +48,14 @@ fn encode_value(value: &Value, builder: &mut DataRowEncoder) -> PgWireResult<()>
         }
         Value::Date(d) => builder.encode_field(d),
         Value::Time(t) => builder.encode_field(t),
-        Value::TimeWithTimeZone(t) => builder.encode_field(t),
+        Value::TimeWithTimeZone(t, offset) => {
+            builder.encode_field(&time_with_time_zone_text(t, offset))
+        }
         Value::Timestamp(ts) => builder.encode_field(ts),
         Value::PostgresTimestamp(pgts) => builder.encode_field(pgts),
         Value::TimestampWithTimeZone(ts) => builder.encode_field(ts),
         Value::IpAddr(ip) => builder.encode_field(&ip.to_string()),
-        Value::Interval(i) => builder.encode_field(i),
+        Value::Interval(i) => builder.encode_field(&i.to_string()),
         Value::Array(a) => builder.encode_field(a),
         Value::Json(j) => builder.encode_field(&j.to_string()),
         Value::JsonB(j) => builder.encode_field(&j.to_string()),
@@ -48,17 +63,35 @@ fn encode_value(value: &Value, builder: &mut DataRowEncoder) -> PgWireResult<()>
             let s = u.to_string();
             builder.encode_field(&s)
         }
-        Value::Enum(_) | Value::Hstore(_) => {
-            Err(PgWireError::ApiError(Box::new(PgError::Internal {
-                err_msg: format!(
-                    "cannot write value {:?} in postgres protocol: unimplemented",
-                    &value
-                ),
-            })))
-        }
+        Value::Enum(e) => builder.encode_field(e),
+        Value::Hstore(h) => builder.encode_field(&hstore_text(h)),
     }
 }
 
+/// The schema with the formats the client asked the columns of the result to
+/// be sent in, see [`has_binary_encoding`] for the columns sent in binary.
+pub fn with_result_format(schema: &SchemaRef, format: &Format) -> SchemaRef {
+    let fields = schema
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            let field_format = match format.format_for(idx) {
+                FieldFormat::Binary if has_binary_encoding(field.datatype()) => FieldFormat::Binary,
+                _ => FieldFormat::Text,
+            };
+            FieldInfo::new(
+                field.name().to_owned(),
+                *field.table_id(),
+                *field.column_id(),
+                field.datatype().clone(),
+                field_format,
+            )
+        })
+        .collect();
+    Arc::new(Schema { fields })
+}
+
 pub fn sendable_stream_to_query_response<'a>(
     schema: SchemaRef,
     record_stream: SendableStream,
@@ -70,8 +103,8 @@ pub fn sendable_stream_to_query_response<'a>(
         .map(move |record_result| {
             record_result.and_then(|record| {
                 let mut encoder = DataRowEncoder::new(schema_copy.clone());
-                for value in record.values.iter() {
-                    encode_value(value, &mut encoder)?;
+                for (value, field) in record.values.iter().zip(schema_copy.iter()) {
+                    encode_value(value, field, &mut encoder)?;
                 }
                 encoder.finish()
             })
@@ -91,8 +124,8 @@ pub fn records_to_query_response<'a>(records: Records) -> PgWireResult<Response<
     let data_row_stream = stream::iter(records.records)
         .map(move |record| {
             let mut encoder = DataRowEncoder::new(schema_copy.clone());
-            for value in record.values.iter() {
-                encode_value(value, &mut encoder)?;
+            for (value, field) in record.values.iter().zip(schema_copy.iter()) {
+                encode_value(value, field, &mut encoder)?;
             }
             encoder.finish()
         })
@@ -103,3 +136,82 @@ pub fn records_to_query_response<'a>(records: Records) -> PgWireResult<Response<
         data_row_stream,
     )))
 }
+
+/// Splits the inline tab-separated data of a `COPY ... FROM STDIN` statement
+/// into rows of `num_columns` values each.
+pub fn copy_values_to_rows(
+    num_columns: usize,
+    values: &[Option<String>],
+) -> PgWireResult<Vec<&[Option<String>]>> {
+    if num_columns == 0 {
+        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "feature_not_supported".to_owned(),
+            "COPY FROM STDIN into a peer requires an explicit column list".to_owned(),
+        ))));
+    }
+    if values.is_empty() {
+        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "feature_not_supported".to_owned(),
+            "COPY FROM STDIN has no data sent along with the statement".to_owned(),
+        ))));
+    }
+    if values.len() % num_columns != 0 {
+        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "22P04".to_owned(),
+            format!(
+                "COPY data has {} values, expected a multiple of {} columns",
+                values.len(),
+                num_columns
+            ),
+        ))));
+    }
+    Ok(values.chunks(num_columns).collect())
+}
+
+/// The table of a peer a statement routed to the peer names, without the
+/// name of the peer it is qualified with. Names that aren't qualified with the
+/// peer are the peer's own, a name that is just the peer's names no table.
+pub fn peer_table_name(peer_name: &str, table_name: &ObjectName) -> PgWireResult<ObjectName> {
+    match table_name.0.as_slice() {
+        [peer, rest @ ..] if peer.value.eq_ignore_ascii_case(peer_name) => {
+            if rest.is_empty() {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "42P01".to_owned(),
+                    format!("{} is a peer rather than one of its tables", table_name),
+                ))));
+            }
+            Ok(ObjectName(rest.to_vec()))
+        }
+        _ => Ok(table_name.clone()),
+    }
+}
+
+/// The names of the common table expressions a query defines, at any level of
+/// nesting, lowercased. Relations by these names refer to the CTEs rather than
+/// to tables of the peer, and are left alone when a query is rewritten.
+pub fn cte_names(query: &Query) -> HashSet<String> {
+    struct CteNames(HashSet<String>);
+
+    impl Visitor for CteNames {
+        type Break = ();
+
+        fn pre_visit_query(&mut self, query: &Query) -> ControlFlow<()> {
+            if let Some(with) = &query.with {
+                self.0.extend(
+                    with.cte_tables
+                        .iter()
+                        .map(|cte| cte.alias.name.value.to_lowercase()),
+                );
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut names = CteNames(HashSet::new());
+    let _ = query.visit(&mut names);
+    names.0
+}
@@ -0,0 +1,237 @@
+use std::path::{Path, PathBuf};
+
+use peer_cursor::{
+    batch::RecordBatchStream,
+    connector::{Capabilities, Connector},
+    error::peer_error,
+    QueryExecutor, QueryOutput, SchemaRef,
+};
+use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
+use pt::peerdb_peers::{peer::Config, DbType, FileConfig, FileFormat, Peer};
+use sqlparser::ast::{
+    Expr, ObjectName, Query, Select, SelectItem, SetExpr, Statement, TableFactor, Value,
+};
+
+mod scan;
+
+/// A scan of a table of a file peer, the only query these peers answer.
+struct TableScan {
+    table: String,
+    /// The selected columns, None for all of them.
+    columns: Option<Vec<String>>,
+    limit: Option<usize>,
+}
+
+pub struct FileQueryExecutor {
+    peer_name: String,
+    path: PathBuf,
+    format: FileFormat,
+}
+
+fn unsupported(stmt: impl std::fmt::Display) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        "0A000".to_owned(),
+        format!(
+            "only SELECT of columns from a table, with an optional LIMIT, is supported on file peers. got: {}",
+            stmt
+        ),
+    )))
+}
+
+impl FileQueryExecutor {
+    pub fn new(peer_name: String, config: &FileConfig) -> Self {
+        Self {
+            peer_name,
+            path: PathBuf::from(&config.path),
+            format: config.format(),
+        }
+    }
+
+    // the table the name refers to, the name of the peer it may be qualified
+    // with is left out, and the rest of it is the name the table's files were
+    // written under, e.g. public.users.
+    fn table_name(&self, name: &ObjectName) -> String {
+        let parts = match name.0.split_first() {
+            Some((first, rest))
+                if !rest.is_empty() && first.value.eq_ignore_ascii_case(&self.peer_name) =>
+            {
+                rest
+            }
+            _ => &name.0[..],
+        };
+        parts
+            .iter()
+            .map(|part| part.value.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn table_scan(&self, query: &Query) -> PgWireResult<TableScan> {
+        let select = match query.body.as_ref() {
+            SetExpr::Select(select) if query.with.is_none() && query.order_by.is_empty() => select,
+            _ => return Err(unsupported(query)),
+        };
+        let (projection, from, group_by) = match select.as_ref() {
+            Select {
+                projection,
+                from,
+                selection: None,
+                group_by,
+                having: None,
+                distinct: None,
+                ..
+            } => (projection, from, group_by),
+            _ => return Err(unsupported(query)),
+        };
+        if !group_by.is_empty() || from.len() != 1 || !from[0].joins.is_empty() {
+            return Err(unsupported(query));
+        }
+        let table = match &from[0].relation {
+            TableFactor::Table { name, .. } => self.table_name(name),
+            _ => return Err(unsupported(query)),
+        };
+
+        let columns = match projection.as_slice() {
+            [SelectItem::Wildcard(_)] => None,
+            items => Some(
+                items
+                    .iter()
+                    .map(|item| match item {
+                        SelectItem::UnnamedExpr(Expr::Identifier(ident)) => Ok(ident.value.clone()),
+                        SelectItem::UnnamedExpr(Expr::CompoundIdentifier(idents)) => Ok(idents
+                            .last()
+                            .map(|ident| ident.value.clone())
+                            .unwrap_or_default()),
+                        _ => Err(unsupported(query)),
+                    })
+                    .collect::<PgWireResult<Vec<_>>>()?,
+            ),
+        };
+
+        let limit = match &query.limit {
+            None => None,
+            Some(Expr::Value(Value::Number(n, _))) => {
+                Some(n.parse::<usize>().map_err(|_| unsupported(query))?)
+            }
+            Some(_) => return Err(unsupported(query)),
+        };
+        if query.offset.is_some() || query.fetch.is_some() {
+            return Err(unsupported(query));
+        }
+
+        Ok(TableScan {
+            table,
+            columns,
+            limit,
+        })
+    }
+
+    // the files of the table and the schema of the selected columns, which are
+    // the columns of its first file.
+    fn plan(&self, scan: &TableScan) -> anyhow::Result<(Vec<PathBuf>, SchemaRef)> {
+        let files = scan::table_files(&self.path, self.format, &scan.table)?;
+        let columns = match files.first() {
+            Some(file) => scan::file_columns(file, self.format)?,
+            None => vec![],
+        };
+        let schema = scan::select_schema(&columns, scan.columns.as_deref())?;
+        Ok((files, schema))
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryExecutor for FileQueryExecutor {
+    #[tracing::instrument(skip(self, stmt), fields(stmt = %stmt))]
+    async fn execute(&self, stmt: &Statement) -> PgWireResult<QueryOutput> {
+        let query = match stmt {
+            Statement::Query(query) => query,
+            _ => return Err(unsupported(stmt)),
+        };
+        let scan = self.table_scan(query)?;
+        let (files, schema) = self
+            .plan(&scan)
+            .map_err(|err| peer_error(format!("{:#}", err)))?;
+        tracing::info!(
+            "scanning {} files of table {} of file peer {}",
+            files.len(),
+            scan.table,
+            self.peer_name
+        );
+
+        // the files are read on a blocking thread, a batch at a time as the
+        // client takes the rows.
+        let format = self.format;
+        let limit = scan.limit;
+        let rows = RecordBatchStream::spawn(schema.clone(), move |sender| async move {
+            let runtime = tokio::runtime::Handle::current();
+            tokio::task::spawn_blocking(move || {
+                scan::scan(&files, format, &schema, limit, |batch| {
+                    runtime.block_on(sender.send(batch))
+                })
+            })
+            .await?
+        });
+        Ok(QueryOutput::Stream(Box::pin(rows)))
+    }
+
+    async fn describe(&self, stmt: &Statement) -> PgWireResult<Option<SchemaRef>> {
+        let query = match stmt {
+            Statement::Query(query) => query,
+            _ => return Err(unsupported(stmt)),
+        };
+        let scan = self.table_scan(query)?;
+        let (_, schema) = self
+            .plan(&scan)
+            .map_err(|err| peer_error(format!("{:#}", err)))?;
+        Ok(Some(schema))
+    }
+
+    async fn execute_raw(&self, query: &str) -> PgWireResult<QueryOutput> {
+        Err(unsupported(query))
+    }
+
+    async fn is_connection_valid(&self) -> anyhow::Result<bool> {
+        if !self.path.is_dir() {
+            anyhow::bail!("{} is not a directory", self.path.display());
+        }
+        Ok(true)
+    }
+}
+
+/// The connector of file peers, directories of csv or parquet files that
+/// mirrors write to, whose tables are scanned by nexus itself.
+pub struct FileConnector;
+
+#[async_trait::async_trait]
+impl Connector for FileConnector {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn db_type(&self) -> DbType {
+        DbType::File
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
+    async fn connect(&self, peer: &Peer) -> anyhow::Result<Box<dyn QueryExecutor>> {
+        match &peer.config {
+            Some(Config::FileConfig(config)) => {
+                Ok(Box::new(FileQueryExecutor::new(peer.name.clone(), config)))
+            }
+            _ => anyhow::bail!("peer {} is not a file peer", peer.name),
+        }
+    }
+
+    async fn discover(&self, peer: &Peer) -> anyhow::Result<Vec<String>> {
+        match &peer.config {
+            Some(Config::FileConfig(config)) => {
+                scan::list_tables(Path::new(&config.path), config.format())
+            }
+            _ => anyhow::bail!("peer {} is not a file peer", peer.name),
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use bytes::Bytes;
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use parquet::{
+    basic::{LogicalType, TimeUnit, Type as PhysicalType},
+    file::reader::{FileReader, SerializedFileReader},
+    record::Field,
+    schema::types::Type as ParquetType,
+};
+use peer_cursor::{batch::ROW_BATCH_SIZE, Record, Schema, SchemaRef};
+use pgwire::api::{
+    results::{FieldFormat, FieldInfo},
+    Type,
+};
+use pt::peerdb_peers::FileFormat;
+use value::Value;
+
+pub fn extension(format: FileFormat) -> &'static str {
+    match format {
+        FileFormat::Csv => "csv",
+        FileFormat::Parquet => "parquet",
+    }
+}
+
+/// The files of a table, either a single `<table>.<ext>` file in the directory
+/// of the peer or the files of the `<table>` directory, which is how mirrors
+/// write them, a file for each partition.
+pub fn table_files(dir: &Path, format: FileFormat, table: &str) -> anyhow::Result<Vec<PathBuf>> {
+    if table.is_empty() || table == "." || table == ".." || table.contains(['/', '\\']) {
+        anyhow::bail!("{} is not a valid table name", table);
+    }
+
+    let ext = extension(format);
+    let file = dir.join(format!("{}.{}", table, ext));
+    if file.is_file() {
+        return Ok(vec![file]);
+    }
+
+    let table_dir = dir.join(table);
+    if !table_dir.is_dir() {
+        anyhow::bail!("table {} doesn't exist", table);
+    }
+    // files still being written end in .tmp, and are left out.
+    let mut files = std::fs::read_dir(&table_dir)
+        .with_context(|| format!("unable to list {}", table_dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().map_or(false, |e| e == ext))
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+/// The tables of the directory of the peer, the directories in it and the
+/// files of the format.
+pub fn list_tables(dir: &Path, format: FileFormat) -> anyhow::Result<Vec<String>> {
+    let ext = extension(format);
+    let mut tables = vec![];
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("unable to list {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let name = if path.is_dir() {
+            path.file_name()
+        } else if path.extension().map_or(false, |e| e == ext) {
+            path.file_stem()
+        } else {
+            None
+        };
+        match name.and_then(|name| name.to_str()) {
+            Some(name) if !name.starts_with('.') => tables.push(name.to_owned()),
+            _ => {}
+        }
+    }
+    tables.sort();
+    Ok(tables)
+}
+
+/// The columns of a file with their types, the columns of csv files are text.
+pub fn file_columns(path: &Path, format: FileFormat) -> anyhow::Result<Vec<(String, Type)>> {
+    match format {
+        FileFormat::Csv => {
+            let mut reader = csv::Reader::from_path(path)
+                .with_context(|| format!("unable to open {}", path.display()))?;
+            Ok(reader
+                .headers()?
+                .iter()
+                .map(|name| (name.to_owned(), Type::TEXT))
+                .collect())
+        }
+        FileFormat::Parquet => {
+            let reader = parquet_reader(path)?;
+            let columns = reader
+                .metadata()
+                .file_metadata()
+                .schema_descr()
+                .root_schema()
+                .get_fields()
+                .iter()
+                .map(|field| (field.name().to_owned(), parquet_column_type(field)))
+                .collect();
+            Ok(columns)
+        }
+    }
+}
+
+/// The schema of the selected columns, all of the columns of the file when
+/// none are selected.
+pub fn select_schema(
+    columns: &[(String, Type)],
+    selected: Option<&[String]>,
+) -> anyhow::Result<SchemaRef> {
+    let fields = match selected {
+        None => columns.to_vec(),
+        Some(selected) => selected
+            .iter()
+            .map(|name| {
+                columns
+                    .iter()
+                    .find(|(column, _)| column == name)
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("column {} doesn't exist", name))
+            })
+            .collect::<anyhow::Result<_>>()?,
+    };
+    Ok(SchemaRef::new(Schema {
+        fields: fields
+            .into_iter()
+            .map(|(name, datatype)| FieldInfo::new(name, None, None, datatype, FieldFormat::Text))
+            .collect(),
+    }))
+}
+
+/// Reads the rows of the files, at most `limit` of them, handing them to
+/// `send` a batch at a time until it returns false. Columns are matched by
+/// name, so files that lack a column, e.g. ones written before it was added,
+/// have nulls for it.
+pub fn scan(
+    files: &[PathBuf],
+    format: FileFormat,
+    schema: &SchemaRef,
+    limit: Option<usize>,
+    mut send: impl FnMut(Vec<Record>) -> bool,
+) -> anyhow::Result<()> {
+    let mut remaining = limit.unwrap_or(usize::MAX);
+    if remaining == 0 {
+        return Ok(());
+    }
+    let mut batch = Vec::with_capacity(ROW_BATCH_SIZE);
+    // false once the limit is reached or the rows are no longer wanted.
+    let mut push = |values: Vec<Value>| {
+        batch.push(Record {
+            values,
+            schema: schema.clone(),
+        });
+        remaining -= 1;
+        if batch.len() == ROW_BATCH_SIZE
+            && !send(std::mem::replace(
+                &mut batch,
+                Vec::with_capacity(ROW_BATCH_SIZE),
+            ))
+        {
+            return false;
+        }
+        remaining > 0
+    };
+
+    'files: for path in files {
+        match format {
+            FileFormat::Csv => {
+                let mut reader = csv::Reader::from_path(path)
+                    .with_context(|| format!("unable to open {}", path.display()))?;
+                let headers = reader.headers()?.clone();
+                let indexes = schema
+                    .fields
+                    .iter()
+                    .map(|field| headers.iter().position(|name| name == field.name()))
+                    .collect::<Vec<_>>();
+                for row in reader.records() {
+                    let row = row.with_context(|| format!("unable to read {}", path.display()))?;
+                    let values = indexes
+                        .iter()
+                        .map(|index| match index.and_then(|index| row.get(index)) {
+                            // the writer leaves the fields of nulls empty.
+                            None | Some("") => Value::Null,
+                            Some(value) => Value::Text(value.to_owned()),
+                        })
+                        .collect();
+                    if !push(values) {
+                        break 'files;
+                    }
+                }
+            }
+            FileFormat::Parquet => {
+                let reader = parquet_reader(path)?;
+                let names = reader
+                    .metadata()
+                    .file_metadata()
+                    .schema_descr()
+                    .root_schema()
+                    .get_fields()
+                    .iter()
+                    .map(|field| field.name().to_owned())
+                    .collect::<Vec<_>>();
+                let indexes = schema
+                    .fields
+                    .iter()
+                    .map(|field| names.iter().position(|name| name == field.name()))
+                    .collect::<Vec<_>>();
+                for row in reader.get_row_iter(None)? {
+                    let row = row.with_context(|| format!("unable to read {}", path.display()))?;
+                    let fields = row
+                        .get_column_iter()
+                        .map(|(_, field)| field)
+                        .collect::<Vec<_>>();
+                    let values = indexes
+                        .iter()
+                        .zip(schema.fields.iter())
+                        .map(
+                            |(index, info)| match index.and_then(|index| fields.get(index)) {
+                                Some(field) => parquet_value(field, info.datatype()),
+                                None => Value::Null,
+                            },
+                        )
+                        .collect();
+                    if !push(values) {
+                        break 'files;
+                    }
+                }
+            }
+        }
+    }
+
+    if !batch.is_empty() {
+        send(batch);
+    }
+    Ok(())
+}
+
+fn parquet_reader(path: &Path) -> anyhow::Result<SerializedFileReader<File>> {
+    let file = File::open(path).with_context(|| format!("unable to open {}", path.display()))?;
+    SerializedFileReader::new(file).with_context(|| format!("unable to read {}", path.display()))
+}
+
+// the type a column of a parquet file is sent as, columns without an
+// equivalent, like lists and decimals, are sent as text.
+fn parquet_column_type(field: &ParquetType) -> Type {
+    if !field.is_primitive() {
+        return Type::TEXT;
+    }
+    match (
+        field.get_physical_type(),
+        field.get_basic_info().logical_type(),
+    ) {
+        (PhysicalType::BOOLEAN, _) => Type::BOOL,
+        (PhysicalType::INT32, Some(LogicalType::Integer { bit_width: 16, .. })) => Type::INT2,
+        (PhysicalType::INT32, None | Some(LogicalType::Integer { bit_width: 32, .. })) => {
+            Type::INT4
+        }
+        (PhysicalType::INT32, Some(LogicalType::Date)) => Type::DATE,
+        (PhysicalType::INT64, None | Some(LogicalType::Integer { bit_width: 64, .. })) => {
+            Type::INT8
+        }
+        (
+            PhysicalType::INT64,
+            Some(LogicalType::Time {
+                unit: TimeUnit::MICROS(_),
+                ..
+            }),
+        ) => Type::TIME,
+        (
+            PhysicalType::INT64,
+            Some(LogicalType::Timestamp {
+                is_adjusted_to_u_t_c,
+                ..
+            }),
+        ) => {
+            if is_adjusted_to_u_t_c {
+                Type::TIMESTAMPTZ
+            } else {
+                Type::TIMESTAMP
+            }
+        }
+        (PhysicalType::FLOAT, _) => Type::FLOAT4,
+        (PhysicalType::DOUBLE, _) => Type::FLOAT8,
+        (PhysicalType::BYTE_ARRAY, None) => Type::BYTEA,
+        _ => Type::TEXT,
+    }
+}
+
+fn parquet_value(field: &Field, datatype: &Type) -> Value {
+    match field {
+        Field::Null => Value::Null,
+        Field::Bool(v) if *datatype == Type::BOOL => Value::Bool(*v),
+        Field::Short(v) if *datatype == Type::INT2 => Value::SmallInt(*v),
+        Field::Int(v) if *datatype == Type::INT4 => Value::Integer(*v),
+        Field::Long(v) if *datatype == Type::INT8 => Value::BigInt(*v),
+        Field::Long(v) if *datatype == Type::TIME => {
+            let seconds = (*v).div_euclid(1_000_000) as u32;
+            let nanos = ((*v).rem_euclid(1_000_000) * 1000) as u32;
+            NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanos)
+                .map_or(Value::Null, Value::Time)
+        }
+        Field::Float(v) if *datatype == Type::FLOAT4 => Value::Float(*v),
+        Field::Double(v) if *datatype == Type::FLOAT8 => Value::Double(*v),
+        Field::Bytes(v) if *datatype == Type::BYTEA => {
+            Value::Binary(Bytes::copy_from_slice(v.data()))
+        }
+        Field::Date(days) if *datatype == Type::DATE => NaiveDate::from_ymd_opt(1970, 1, 1)
+            .and_then(|epoch| epoch.checked_add_signed(Duration::days(*days as i64)))
+            .map_or(Value::Null, Value::Date),
+        Field::TimestampMicros(v) => {
+            timestamp_value(NaiveDateTime::from_timestamp_micros(*v), datatype)
+        }
+        Field::TimestampMillis(v) => {
+            timestamp_value(NaiveDateTime::from_timestamp_millis(*v), datatype)
+        }
+        Field::Str(v) => Value::Text(v.clone()),
+        other => Value::Text(other.to_string()),
+    }
+}
+
+fn timestamp_value(timestamp: Option<NaiveDateTime>, datatype: &Type) -> Value {
+    match timestamp {
+        Some(timestamp) if *datatype == Type::TIMESTAMPTZ => {
+            Value::TimestampWithTimeZone(Utc.from_utc_datetime(&timestamp))
+        }
+        Some(timestamp) => Value::Timestamp(Utc.from_utc_datetime(&timestamp)),
+        None => Value::Null,
+    }
+}
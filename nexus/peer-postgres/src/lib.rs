@@ -1,49 +1,393 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    error::Error,
+    sync::{Arc, Mutex},
+};
 
-use peer_cursor::{QueryExecutor, QueryOutput, Schema, SchemaRef};
+use bytes::{BufMut, BytesMut};
+use futures::{SinkExt, StreamExt};
+use peer_cursor::{
+    connector::{Capabilities, ColumnInfo, Connector, TableInfo},
+    error,
+    util::{copy_values_to_rows, peer_table_name},
+    CopyInStream, Notification, QueryExecutor, QueryOutput, Record, Records, Schema, SchemaRef,
+};
 use pgerror::PgError;
 use pgwire::{
-    api::results::{FieldFormat, FieldInfo},
-    error::{PgWireError, PgWireResult},
+    api::{
+        results::{FieldFormat, FieldInfo},
+        Type,
+    },
+    error::{ErrorInfo, PgWireError, PgWireResult},
+};
+use postgres_connection::Notifications;
+use pt::peerdb_peers::{peer::Config, DbType, Peer, PostgresConfig};
+use sqlparser::ast::{CopyTarget, ObjectName, Statement};
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio_postgres::{
+    error::DbError,
+    types::{to_sql_checked, Format, IsNull, ToSql},
+    Client, SimpleQueryMessage,
 };
-use pt::peerdb_peers::PostgresConfig;
-use sqlparser::ast::Statement;
-use tokio_postgres::Client;
+use value::Value;
 
 mod ast;
 mod stream;
 
+// the number of rows of a COPY FROM STDIN sent to the peer at a time.
+const COPY_BATCH_ROWS: usize = 1000;
+
+// the statements prepared on a connection are kept up to this many, the
+// oldest ones are closed to make room for more.
+const MAX_PREPARED_STATEMENTS: usize = 256;
+
+// the error of a statement the peer failed, with the SQLSTATE the peer
+// reported, or that of a connection failure when the connection to the peer
+// closed.
+pub(crate) fn peer_error(what: &str, e: &(dyn Error + 'static)) -> PgWireError {
+    tracing::error!("error {}: {}", what, e);
+    let pg_error = std::iter::successors(Some(e), |e| (*e).source())
+        .find_map(|e| e.downcast_ref::<tokio_postgres::Error>());
+    match pg_error {
+        Some(pg_error) => match pg_error.as_db_error() {
+            Some(db_error) => {
+                let mut info = ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    db_error.code().code().to_owned(),
+                    format!("error {}: {}", what, db_error.message()),
+                );
+                info.set_detail(db_error.detail().map(str::to_owned));
+                info.set_hint(db_error.hint().map(str::to_owned));
+                PgWireError::UserError(Box::new(info))
+            }
+            None if pg_error.is_closed() => error::peer_error_with_code(
+                error::CONNECTION_FAILURE,
+                format!("error {}: {}", what, e),
+            ),
+            None => error::peer_error(format!("error {}: {}", what, e)),
+        },
+        None => error::peer_error(format!("error {}: {}", what, e)),
+    }
+}
+
+// a parameter sent as the text the client bound it with, the peer parses it
+// into the type it inferred for its placeholder.
+#[derive(Debug)]
+struct TextParameter<'a>(Option<&'a str>);
+
+impl ToSql for TextParameter<'_> {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match self.0 {
+            Some(value) => {
+                out.put_slice(value.as_bytes());
+                Ok(IsNull::No)
+            }
+            None => Ok(IsNull::Yes),
+        }
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    fn encode_format(&self, _ty: &Type) -> Format {
+        Format::Text
+    }
+
+    to_sql_checked!();
+}
+
 // PostgresQueryExecutor is a QueryExecutor that uses a Postgres database as its
 // backing store.
 pub struct PostgresQueryExecutor {
     config: PostgresConfig,
     peername: Option<String>,
     client: Box<Client>,
+    notices: Mutex<UnboundedReceiver<DbError>>,
+    notifications: Arc<Notifications>,
+    // the statements prepared for execute_prepared, by their rewritten query,
+    // along with the order they were prepared in.
+    prepared: Mutex<(HashMap<String, tokio_postgres::Statement>, Vec<String>)>,
 }
 
 impl PostgresQueryExecutor {
     pub async fn new(peername: Option<String>, config: &PostgresConfig) -> anyhow::Result<Self> {
-        let client = postgres_connection::connect_postgres(config).await?;
+        let (client, notices, notifications) =
+            postgres_connection::connect_postgres_with_messages(config).await?;
         Ok(Self {
             config: config.clone(),
             peername,
             client: Box::new(client),
+            notices: Mutex::new(notices),
+            notifications,
+            prepared: Mutex::new((HashMap::new(), vec![])),
         })
     }
 
     pub async fn schema_from_query(&self, query: &str) -> anyhow::Result<SchemaRef> {
         let prepared = self.client.prepare_typed(query, &[]).await?;
+        Ok(schema_from_statement(&prepared))
+    }
 
-        let fields: Vec<FieldInfo> = prepared
-            .columns()
+    // the statement prepared for the query on the connection, prepared once
+    // and reused by the executions that follow.
+    async fn prepared_statement(&self, query: &str) -> anyhow::Result<tokio_postgres::Statement> {
+        if let Some(statement) = self.prepared.lock().unwrap().0.get(query) {
+            return Ok(statement.clone());
+        }
+        let statement = self.client.prepare(query).await?;
+
+        let mut prepared = self.prepared.lock().unwrap();
+        let (statements, order) = &mut *prepared;
+        if statements
+            .insert(query.to_owned(), statement.clone())
+            .is_none()
+        {
+            order.push(query.to_owned());
+        }
+        // dropping the last handle of a statement closes it on the peer.
+        if order.len() > MAX_PREPARED_STATEMENTS {
+            let oldest = order.remove(0);
+            statements.remove(&oldest);
+        }
+        Ok(statement)
+    }
+
+    // get the columns of the table, in order.
+    pub async fn get_columns(&self, table: &str) -> anyhow::Result<Vec<String>> {
+        let rows = self
+            .client
+            .query(
+                "SELECT attname::text FROM pg_attribute
+                 WHERE attrelid = $1::text::regclass AND attnum > 0 AND NOT attisdropped
+                 ORDER BY attnum",
+                &[&table],
+            )
+            .await?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    // get the columns of the table with the names of their types, in order.
+    pub async fn get_column_types(&self, table: &str) -> anyhow::Result<Vec<(String, String)>> {
+        let rows = self
+            .client
+            .query(
+                "SELECT a.attname::text, t.typname::text FROM pg_attribute a
+                 JOIN pg_type t ON t.oid = a.atttypid
+                 WHERE a.attrelid = $1::text::regclass AND a.attnum > 0 AND NOT a.attisdropped
+                 ORDER BY a.attnum",
+                &[&table],
+            )
+            .await?;
+        Ok(rows.iter().map(|row| (row.get(0), row.get(1))).collect())
+    }
+
+    // get the number of rows of the table as estimated by the planner, None if
+    // the table was never vacuumed or analyzed.
+    pub async fn get_estimated_rows(&self, table: &str) -> anyhow::Result<Option<i64>> {
+        let row = self
+            .client
+            .query_one(
+                "SELECT reltuples::int8 FROM pg_class WHERE oid = $1::text::regclass",
+                &[&table],
+            )
+            .await?;
+        let rows: i64 = row.get(0);
+        Ok((rows >= 0).then_some(rows))
+    }
+
+    // get the primary key columns of the table, empty if it has no primary key.
+    pub async fn get_primary_key_columns(&self, table: &str) -> anyhow::Result<Vec<String>> {
+        let rows = self
+            .client
+            .query(
+                "SELECT a.attname::text FROM pg_index i
+                 JOIN pg_attribute a ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey)
+                 WHERE i.indrelid = $1::text::regclass AND i.indisprimary",
+                &[&table],
+            )
+            .await?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    // get the total size of the tables on disk, including their indexes and
+    // toast tables, in bytes.
+    pub async fn get_tables_size(&self, tables: &[String]) -> anyhow::Result<i64> {
+        let row = self
+            .client
+            .query_one(
+                "SELECT coalesce(sum(pg_total_relation_size(t::regclass)), 0)::int8
+                 FROM unnest($1::text[]) t",
+                &[&tables],
+            )
+            .await?;
+        Ok(row.get(0))
+    }
+
+    // get the current WAL write position, in bytes.
+    pub async fn get_current_wal_lsn(&self) -> anyhow::Result<i64> {
+        let row = self
+            .client
+            .query_one("SELECT (pg_current_wal_lsn() - '0/0')::int8", &[])
+            .await?;
+        Ok(row.get(0))
+    }
+
+    // get the most WAL a replication slot can retain before it is invalidated,
+    // in bytes, None if it is unlimited or the server doesn't have the setting.
+    pub async fn get_max_slot_wal_keep_size(&self) -> anyhow::Result<Option<i64>> {
+        let row = self
+            .client
+            .query_opt(
+                "SELECT pg_size_bytes(current_setting('max_slot_wal_keep_size'))
+                 FROM pg_settings WHERE name = 'max_slot_wal_keep_size'
+                 AND setting::int8 >= 0",
+                &[],
+            )
+            .await?;
+        Ok(row.map(|row| row.get(0)))
+    }
+
+    // get the tables of the database outside of the system schemas, as
+    // schema-qualified names.
+    pub async fn get_tables(&self) -> anyhow::Result<Vec<String>> {
+        let rows = self
+            .client
+            .query(
+                "SELECT table_schema || '.' || table_name FROM information_schema.tables
+                 WHERE table_schema NOT IN ('pg_catalog', 'information_schema')
+                 ORDER BY 1",
+                &[],
+            )
+            .await?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    // get the names of the tables of the schema, leaving out the partitions of
+    // partitioned tables, which are replicated through their parent.
+    pub async fn get_tables_in_schema(&self, schema: &str) -> anyhow::Result<Vec<String>> {
+        let rows = self
+            .client
+            .query(
+                "SELECT c.relname::text FROM pg_class c
+                 JOIN pg_namespace n ON n.oid = c.relnamespace
+                 WHERE c.relkind IN ('r', 'p') AND NOT c.relispartition AND n.nspname = $1
+                 ORDER BY 1",
+                &[&schema],
+            )
+            .await?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    // get the tables of the database outside of the system schemas, or of the
+    // given schema, with their planner estimated rows and whether they have a
+    // primary key.
+    pub async fn get_table_infos(&self, schema: Option<&str>) -> anyhow::Result<Vec<TableInfo>> {
+        let rows = self
+            .client
+            .query(
+                "SELECT n.nspname || '.' || c.relname, c.reltuples::int8,
+                 EXISTS(SELECT 1 FROM pg_index i WHERE i.indrelid = c.oid AND i.indisprimary)
+                 FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace
+                 WHERE c.relkind IN ('r', 'p')
+                 AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+                 AND n.nspname NOT LIKE 'pg_toast%'
+                 AND ($1::text IS NULL OR n.nspname = $1)
+                 ORDER BY 1",
+                &[&schema],
+            )
+            .await?;
+        Ok(rows
             .iter()
-            .map(|c| {
-                let name = c.name().to_string();
-                FieldInfo::new(name, None, None, c.type_().clone(), FieldFormat::Text)
+            .map(|row| {
+                let estimated_rows: i64 = row.get(1);
+                TableInfo {
+                    name: row.get(0),
+                    estimated_rows: (estimated_rows >= 0).then_some(estimated_rows),
+                    has_primary_key: row.get(2),
+                }
             })
-            .collect();
+            .collect())
+    }
 
-        Ok(Arc::new(Schema { fields }))
+    // get the columns of the tables get_table_infos lists, by table and in
+    // the order of their columns.
+    pub async fn get_column_infos(&self, schema: Option<&str>) -> anyhow::Result<Vec<ColumnInfo>> {
+        let rows = self
+            .client
+            .query(
+                "SELECT n.nspname || '.' || c.relname, a.attname,
+                 format_type(a.atttypid, a.atttypmod), NOT a.attnotnull
+                 FROM pg_attribute a
+                 JOIN pg_class c ON c.oid = a.attrelid
+                 JOIN pg_namespace n ON n.oid = c.relnamespace
+                 WHERE c.relkind IN ('r', 'p') AND a.attnum > 0 AND NOT a.attisdropped
+                 AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+                 AND n.nspname NOT LIKE 'pg_toast%'
+                 AND ($1::text IS NULL OR n.nspname = $1)
+                 ORDER BY 1, a.attnum",
+                &[&schema],
+            )
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| ColumnInfo {
+                table: row.get(0),
+                name: row.get(1),
+                data_type: row.get(2),
+                nullable: row.get(3),
+            })
+            .collect())
+    }
+
+    // the table of the peer a COPY copies into, without the name of the peer
+    // it is qualified with.
+    fn copy_table_name(&self, table_name: &ObjectName) -> PgWireResult<ObjectName> {
+        match &self.peername {
+            Some(peername) => peer_table_name(peername, table_name),
+            None => Ok(table_name.clone()),
+        }
+    }
+
+    // bulk load rows into a table using the COPY protocol. the rows are sent
+    // in batches of COPY_BATCH_ROWS, so only a batch is encoded at a time
+    // rather than the whole payload.
+    async fn copy_rows(
+        &self,
+        copy_stmt: &str,
+        rows: Vec<&[Option<String>]>,
+    ) -> anyhow::Result<u64> {
+        let sink = self.client.copy_in(copy_stmt).await?;
+        futures::pin_mut!(sink);
+        for batch in rows.chunks(COPY_BATCH_ROWS) {
+            let mut buf = BytesMut::new();
+            for row in batch {
+                for (idx, value) in row.iter().enumerate() {
+                    if idx > 0 {
+                        buf.put_u8(b'\t');
+                    }
+                    match value {
+                        Some(value) => {
+                            let escaped = value
+                                .replace('\\', "\\\\")
+                                .replace('\t', "\\t")
+                                .replace('\n', "\\n")
+                                .replace('\r', "\\r");
+                            buf.put_slice(escaped.as_bytes());
+                        }
+                        None => buf.put_slice(b"\\N"),
+                    }
+                }
+                buf.put_u8(b'\n');
+            }
+            sink.send(buf.freeze()).await?;
+        }
+        let rows_copied = sink.finish().await?;
+        Ok(rows_copied)
     }
 }
 
@@ -69,12 +413,7 @@ impl QueryExecutor for PostgresQueryExecutor {
                 let schema = self
                     .schema_from_query(&rewritten_query)
                     .await
-                    .map_err(|e| {
-                        tracing::error!("error getting schema: {}", e);
-                        PgWireError::ApiError(Box::new(PgError::Internal {
-                            err_msg: format!("error getting schema: {}", e),
-                        }))
-                    })?;
+                    .map_err(|e| peer_error("getting schema", e.as_ref()))?;
 
                 tracing::info!("[peer-postgres] rewritten query: {}", rewritten_query);
                 // given that there could be a lot of rows returned, we
@@ -84,12 +423,7 @@ impl QueryExecutor for PostgresQueryExecutor {
                     .client
                     .query_raw(&rewritten_query, std::iter::empty::<&str>())
                     .await
-                    .map_err(|e| {
-                        tracing::error!("error executing query: {}", e);
-                        PgWireError::ApiError(Box::new(PgError::Internal {
-                            err_msg: format!("error executing query: {}", e),
-                        }))
-                    })?;
+                    .map_err(|e| peer_error("executing query", &e))?;
 
                 // log that raw query execution has completed
                 tracing::info!("[peer-postgres] raw query execution completed");
@@ -97,6 +431,29 @@ impl QueryExecutor for PostgresQueryExecutor {
                 let cursor = stream::PgRecordStream::new(stream, schema);
                 Ok(QueryOutput::Stream(Box::pin(cursor)))
             }
+            Statement::Copy {
+                table_name,
+                columns,
+                to: false,
+                target: CopyTarget::Stdin,
+                values,
+                ..
+            } => {
+                let table_name = self.copy_table_name(table_name)?;
+                let rows = copy_values_to_rows(columns.len(), values)?;
+                let column_names: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+                let copy_stmt = format!(
+                    "COPY {} ({}) FROM STDIN",
+                    table_name,
+                    column_names.join(", ")
+                );
+                tracing::info!("[peer-postgres] copying {} rows: {}", rows.len(), copy_stmt);
+                let rows_copied = self
+                    .copy_rows(&copy_stmt, rows)
+                    .await
+                    .map_err(|e| peer_error("copying rows", e.as_ref()))?;
+                Ok(QueryOutput::AffectedRows(rows_copied as usize))
+            }
             _ => {
                 let mut rewritten_stmt = stmt.clone();
                 ast.rewrite_statement(&mut rewritten_stmt).map_err(|e| {
@@ -107,41 +464,330 @@ impl QueryExecutor for PostgresQueryExecutor {
                 })?;
                 let rewritten_query = rewritten_stmt.to_string();
                 tracing::info!("[peer-postgres] rewritten statement: {}", rewritten_query);
-                let rows_affected =
-                    self.client
-                        .execute(&rewritten_query, &[])
-                        .await
-                        .map_err(|e| {
-                            tracing::error!("error executing query: {}", e);
-                            PgWireError::ApiError(Box::new(PgError::Internal {
-                                err_msg: format!("error executing query: {}", e),
-                            }))
-                        })?;
+                let rows_affected = self
+                    .client
+                    .execute(&rewritten_query, &[])
+                    .await
+                    .map_err(|e| peer_error("executing query", &e))?;
                 Ok(QueryOutput::AffectedRows(rows_affected as usize))
             }
         }
     }
 
+    // the data is passed on to the peer as the client sends it, in the format
+    // and with the options of the client's COPY. the COPY is aborted on the
+    // peer when the sink is dropped before it is finished.
+    async fn copy_in(&self, stmt: &Statement, mut data: CopyInStream) -> PgWireResult<usize> {
+        let mut copy_stmt = stmt.clone();
+        if let Statement::Copy {
+            table_name, values, ..
+        } = &mut copy_stmt
+        {
+            *table_name = self.copy_table_name(table_name)?;
+            values.clear();
+        }
+        let copy_stmt = copy_stmt.to_string();
+        tracing::info!("[peer-postgres] streaming copy: {}", copy_stmt);
+
+        let sink = self
+            .client
+            .copy_in(&copy_stmt)
+            .await
+            .map_err(|e| peer_error("copying rows", &e))?;
+        futures::pin_mut!(sink);
+        while let Some(chunk) = data.next().await {
+            sink.send(chunk?)
+                .await
+                .map_err(|e| peer_error("copying rows", &e))?;
+        }
+        let rows_copied = sink
+            .finish()
+            .await
+            .map_err(|e| peer_error("copying rows", &e))?;
+        Ok(rows_copied as usize)
+    }
+
+    // the statement is rewritten like execute does, and prepared the first
+    // time it runs on the connection, so that the analysis and planning of
+    // the peer are done once for a statement the client runs repeatedly.
+    async fn execute_prepared(
+        &self,
+        stmt: &Statement,
+        parameters: &[Option<String>],
+    ) -> PgWireResult<QueryOutput> {
+        let ast = ast::PostgresAst {
+            peername: self.peername.clone(),
+        };
+        let mut rewritten_stmt = stmt.clone();
+        match &mut rewritten_stmt {
+            Statement::Query(query) => ast.rewrite_query(query),
+            stmt => ast.rewrite_statement(stmt).map_err(|e| {
+                PgWireError::ApiError(Box::new(PgError::Internal {
+                    err_msg: format!("error rewriting statement: {}", e),
+                }))
+            })?,
+        }
+        let rewritten_query = rewritten_stmt.to_string();
+        tracing::info!("[peer-postgres] prepared statement: {}", rewritten_query);
+
+        let statement = self
+            .prepared_statement(&rewritten_query)
+            .await
+            .map_err(|e| peer_error("preparing statement", e.as_ref()))?;
+        let parameters = parameters
+            .iter()
+            .map(|parameter| TextParameter(parameter.as_deref()));
+
+        if statement.columns().is_empty() {
+            let rows_affected = self
+                .client
+                .execute_raw(&statement, parameters)
+                .await
+                .map_err(|e| peer_error("executing statement", &e))?;
+            return Ok(QueryOutput::AffectedRows(rows_affected as usize));
+        }
+        let schema = schema_from_statement(&statement);
+        let stream = self
+            .client
+            .query_raw(&statement, parameters)
+            .await
+            .map_err(|e| peer_error("executing query", &e))?;
+        Ok(QueryOutput::Stream(Box::pin(stream::PgRecordStream::new(
+            stream, schema,
+        ))))
+    }
+
     async fn describe(&self, stmt: &Statement) -> PgWireResult<Option<SchemaRef>> {
         match stmt {
             Statement::Query(_query) => {
                 let schema = self
                     .schema_from_query(&stmt.to_string())
                     .await
-                    .map_err(|e| {
-                        tracing::error!("error getting schema: {}", e);
-                        PgWireError::ApiError(Box::new(PgError::Internal {
-                            err_msg: format!("error getting schema: {}", e),
-                        }))
-                    })?;
+                    .map_err(|e| peer_error("getting schema", e.as_ref()))?;
                 Ok(Some(schema))
             }
             _ => Ok(None),
         }
     }
 
+    // runs the statement over the simple query protocol, so that any statement
+    // is accepted and rows come back as text. the rows of the last statement
+    // that returned any are returned.
+    async fn execute_raw(&self, query: &str) -> PgWireResult<QueryOutput> {
+        tracing::info!("[peer-postgres] executing raw statement: {}", query);
+        let messages = self
+            .client
+            .simple_query(query)
+            .await
+            .map_err(|e| peer_error("executing query", &e))?;
+
+        let mut rows = vec![];
+        let mut statement_rows = vec![];
+        let mut rows_affected = 0;
+        for message in messages {
+            match message {
+                SimpleQueryMessage::Row(row) => statement_rows.push(row),
+                SimpleQueryMessage::CommandComplete(n) => {
+                    if !statement_rows.is_empty() {
+                        rows = std::mem::take(&mut statement_rows);
+                    }
+                    rows_affected = n;
+                }
+                _ => {}
+            }
+        }
+
+        let first_row = match rows.first() {
+            Some(row) => row,
+            None => return Ok(QueryOutput::AffectedRows(rows_affected as usize)),
+        };
+        let schema = Arc::new(Schema {
+            fields: first_row
+                .columns()
+                .iter()
+                .map(|column| {
+                    FieldInfo::new(
+                        column.name().to_owned(),
+                        None,
+                        None,
+                        Type::TEXT,
+                        FieldFormat::Text,
+                    )
+                })
+                .collect(),
+        });
+        let records = rows
+            .iter()
+            .map(|row| Record {
+                values: (0..row.len())
+                    .map(|idx| match row.get(idx) {
+                        Some(value) => Value::Text(value.to_owned()),
+                        None => Value::Null,
+                    })
+                    .collect(),
+                schema: schema.clone(),
+            })
+            .collect();
+        Ok(QueryOutput::Records(Records { records, schema }))
+    }
+
     async fn is_connection_valid(&self) -> anyhow::Result<bool> {
         let _ = PostgresQueryExecutor::new(None, &self.config).await?;
         Ok(true)
     }
+
+    async fn cancel(&self) -> anyhow::Result<()> {
+        let tls_connector = postgres_connection::tls_connector()?;
+        self.client
+            .cancel_token()
+            .cancel_query(tls_connector)
+            .await?;
+        Ok(())
+    }
+
+    async fn set_setting(&self, name: &str, value: Option<&str>) -> anyhow::Result<()> {
+        match value {
+            Some(value) => {
+                self.client
+                    .execute("SELECT set_config($1, $2, false)", &[&name, &value])
+                    .await?;
+            }
+            None => {
+                self.client
+                    .batch_execute(&format!("RESET \"{}\"", name.replace('"', "\"\"")))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    // what DISCARD ALL resets, except for the statements prepared on the
+    // connection, which stay cached for the next session.
+    async fn reset_session(&self) -> anyhow::Result<()> {
+        self.client
+            .batch_execute(
+                "CLOSE ALL; RESET ALL; UNLISTEN *; SELECT pg_advisory_unlock_all(); \
+                 DISCARD TEMP; DISCARD SEQUENCES;",
+            )
+            .await?;
+        Ok(())
+    }
+
+    fn take_notices(&self) -> Vec<ErrorInfo> {
+        let mut notices = self.notices.lock().unwrap();
+        let mut infos = vec![];
+        while let Ok(notice) = notices.try_recv() {
+            let mut info = ErrorInfo::new(
+                notice.severity().to_owned(),
+                notice.code().code().to_owned(),
+                notice.message().to_owned(),
+            );
+            info.set_detail(notice.detail().map(|detail| detail.to_owned()));
+            info.set_hint(notice.hint().map(|hint| hint.to_owned()));
+            infos.push(info);
+        }
+        infos
+    }
+
+    fn take_notifications(&self) -> Vec<Notification> {
+        self.notifications
+            .take()
+            .into_iter()
+            .map(|notification| Notification {
+                process_id: notification.process_id(),
+                channel: notification.channel().to_owned(),
+                payload: notification.payload().to_owned(),
+            })
+            .collect()
+    }
+
+    async fn notifications_arrived(&self) {
+        self.notifications.arrived().await
+    }
+}
+
+fn schema_from_statement(statement: &tokio_postgres::Statement) -> SchemaRef {
+    let fields: Vec<FieldInfo> = statement
+        .columns()
+        .iter()
+        .map(|c| {
+            let name = c.name().to_string();
+            FieldInfo::new(name, None, None, c.type_().clone(), FieldFormat::Text)
+        })
+        .collect();
+    Arc::new(Schema { fields })
+}
+
+pub struct PostgresConnector;
+
+#[async_trait::async_trait]
+impl Connector for PostgresConnector {
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+
+    fn db_type(&self) -> DbType {
+        DbType::Postgres
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            execute_raw: true,
+            manage_tables: true,
+            pooled: true,
+        }
+    }
+
+    async fn connect(&self, peer: &Peer) -> anyhow::Result<Box<dyn QueryExecutor>> {
+        match &peer.config {
+            Some(Config::PostgresConfig(config)) => Ok(Box::new(
+                PostgresQueryExecutor::new(Some(peer.name.clone()), config).await?,
+            )),
+            _ => anyhow::bail!("peer {} is not a postgres peer", peer.name),
+        }
+    }
+
+    async fn discover(&self, peer: &Peer) -> anyhow::Result<Vec<String>> {
+        match &peer.config {
+            Some(Config::PostgresConfig(config)) => {
+                PostgresQueryExecutor::new(Some(peer.name.clone()), config)
+                    .await?
+                    .get_tables()
+                    .await
+            }
+            _ => anyhow::bail!("peer {} is not a postgres peer", peer.name),
+        }
+    }
+
+    async fn describe_tables(
+        &self,
+        peer: &Peer,
+        schema: Option<&str>,
+    ) -> anyhow::Result<Vec<TableInfo>> {
+        match &peer.config {
+            Some(Config::PostgresConfig(config)) => {
+                PostgresQueryExecutor::new(Some(peer.name.clone()), config)
+                    .await?
+                    .get_table_infos(schema)
+                    .await
+            }
+            _ => anyhow::bail!("peer {} is not a postgres peer", peer.name),
+        }
+    }
+
+    async fn describe_columns(
+        &self,
+        peer: &Peer,
+        schema: Option<&str>,
+    ) -> anyhow::Result<Vec<ColumnInfo>> {
+        match &peer.config {
+            Some(Config::PostgresConfig(config)) => {
+                PostgresQueryExecutor::new(Some(peer.name.clone()), config)
+                    .await?
+                    .get_column_infos(schema)
+                    .await
+            }
+            _ => anyhow::bail!("peer {} is not a postgres peer", peer.name),
+        }
+    }
 }
@@ -1,18 +1,21 @@
 use bytes::Bytes;
-use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use futures::Stream;
 use peer_cursor::{Record, RecordStream, SchemaRef};
-use pgerror::PgError;
-use pgwire::error::{PgWireError, PgWireResult};
+use pgwire::error::PgWireResult;
 use postgres_inet::MaskedIpAddr;
 use rust_decimal::Decimal;
 use std::{
+    error::Error,
     pin::Pin,
     task::{Context, Poll},
 };
-use tokio_postgres::{types::Type, Row, RowStream};
+use tokio_postgres::{
+    types::{FromSql, Type},
+    Row, RowStream,
+};
 use uuid::Uuid;
-use value::{array::ArrayValue, Value};
+use value::{array::ArrayValue, interval::Interval, Value};
 pub struct PgRecordStream {
     row_stream: Pin<Box<RowStream>>,
     schema: SchemaRef,
@@ -223,12 +226,13 @@ fn values_from_row(row: &Row) -> Vec<Value> {
                     t.map(Value::Time).unwrap_or(Value::Null)
                 }
                 &Type::TIMETZ => {
-                    let t: Option<NaiveTime> = row.get(i);
-                    t.map(Value::TimeWithTimeZone).unwrap_or(Value::Null)
+                    let t: Option<TimeTz> = row.get(i);
+                    t.map(|TimeTz(time, offset)| Value::TimeWithTimeZone(time, offset))
+                        .unwrap_or(Value::Null)
                 }
                 &Type::INTERVAL => {
-                    let iv: Option<String> = row.get(i);
-                    iv.map(Value::Text).unwrap_or(Value::Null)
+                    let iv: Option<Interval> = row.get(i);
+                    iv.map(Value::Interval).unwrap_or(Value::Null)
                 }
                 &Type::ANY => Value::Text(row.get(i)),
                 &Type::ANYARRAY => {
@@ -254,6 +258,25 @@ fn values_from_row(row: &Row) -> Vec<Value> {
         .collect()
 }
 
+// a timetz, which is its time followed by its offset in seconds west of UTC.
+struct TimeTz(NaiveTime, FixedOffset);
+
+impl<'a> FromSql<'a> for TimeTz {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 12 {
+            return Err(format!("invalid timetz of {} bytes", raw.len()).into());
+        }
+        let time = NaiveTime::from_sql(&Type::TIME, &raw[..8])?;
+        let west = i32::from_be_bytes(raw[8..].try_into()?);
+        let offset = FixedOffset::west_opt(west).ok_or("timetz offset out of range")?;
+        Ok(TimeTz(time, offset))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TIMETZ
+    }
+}
+
 impl Stream for PgRecordStream {
     type Item = PgWireResult<Record>;
 
@@ -268,11 +291,7 @@ impl Stream for PgRecordStream {
                 Poll::Ready(Some(Ok(record)))
             }
             Poll::Ready(Some(Err(e))) => {
-                let err = Box::new(PgError::Internal {
-                    err_msg: e.to_string(),
-                });
-                let err = PgWireError::ApiError(err);
-                Poll::Ready(Some(Err(err)))
+                Poll::Ready(Some(Err(crate::peer_error("streaming rows", &e))))
             }
             Poll::Ready(None) => Poll::Ready(None),
             Poll::Pending => Poll::Pending,
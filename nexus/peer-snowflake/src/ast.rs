@@ -1,5 +1,6 @@
 use std::ops::ControlFlow;
 
+use peer_cursor::util::cte_names;
 use sqlparser::ast::{
     visit_expressions_mut, visit_relations_mut, visit_statements_mut, DataType, Expr, Function,
     FunctionArg, FunctionArgExpr, Ident, JsonOperator, ObjectName, Query, Statement, TimezoneInfo,
@@ -10,8 +11,13 @@ pub struct SnowflakeAst {}
 
 impl SnowflakeAst {
     pub fn rewrite(&self, query: &mut Query) -> anyhow::Result<()> {
+        // drop the peer name from table names, references to CTEs are left
+        // as they are.
+        let ctes = cte_names(query);
         visit_relations_mut(query, |table| {
-            table.0.remove(0);
+            if table.0.len() > 1 || !ctes.contains(&table.0[0].value.to_lowercase()) {
+                table.0.remove(0);
+            }
             ControlFlow::<()>::Continue(())
         });
 
@@ -1,29 +1,43 @@
 use anyhow::Context;
 use async_recursion::async_recursion;
 use cursor::SnowflakeCursorManager;
-use peer_cursor::{CursorModification, QueryExecutor, QueryOutput, SchemaRef};
+use futures::StreamExt;
+use peer_cursor::{
+    connector::{Capabilities, Connector},
+    copy::CopyInDecoder,
+    error::peer_error,
+    util::{copy_values_to_rows, peer_table_name},
+    CopyInStream, CursorModification, QueryExecutor, QueryOutput, SchemaRef,
+};
 use pgerror::PgError;
 use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser;
 use std::cmp::min;
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+    time::Duration,
+};
 use stream::SnowflakeDataType;
 
 use auth::SnowflakeAuth;
-use pt::peerdb_peers::SnowflakeConfig;
+use pt::peerdb_peers::{peer::Config, DbType, Peer, SnowflakeConfig};
 use reqwest::{header, StatusCode};
 use secrecy::ExposeSecret;
 use serde::{Deserialize, Serialize};
-use sqlparser::ast::{CloseCursor, FetchDirection, Query, Statement};
+use sqlparser::ast::{
+    CloseCursor, CopyTarget, FetchDirection, Ident, ObjectName, ObjectType, Query, Statement,
+};
 use tokio::time::sleep;
 use tracing::info;
 
-use crate::stream::SnowflakeSchema;
+use crate::{stage::CopyStage, stream::SnowflakeSchema};
 
 mod ast;
 mod auth;
 mod cursor;
+mod stage;
 mod stream;
 
 const DEFAULT_REFRESH_THRESHOLD: u64 = 3000;
@@ -88,14 +102,15 @@ struct PartitionResult {
 }
 
 pub struct SnowflakeQueryExecutor {
+    peer_name: String,
     config: SnowflakeConfig,
-    partition_number: usize,
-    partition_index: usize,
     endpoint_url: String,
     auth: SnowflakeAuth,
     query_timeout: u64,
     reqwest_client: reqwest::Client,
     cursor_manager: SnowflakeCursorManager,
+    // handles of the statements being polled, for cancel requests.
+    running_statements: Mutex<HashSet<String>>,
 }
 
 enum QueryAttemptResult {
@@ -106,7 +121,7 @@ enum QueryAttemptResult {
 }
 
 impl SnowflakeQueryExecutor {
-    pub async fn new(config: &SnowflakeConfig) -> anyhow::Result<Self> {
+    pub async fn new(peer_name: String, config: &SnowflakeConfig) -> anyhow::Result<Self> {
         let mut default_headers = header::HeaderMap::new();
         default_headers.insert(
             "X-Snowflake-Authorization-Token-Type",
@@ -123,9 +138,8 @@ impl SnowflakeQueryExecutor {
             .build()?;
         let cursor_manager = SnowflakeCursorManager::new();
         Ok(Self {
+            peer_name,
             config: config.clone(),
-            partition_number: 0,
-            partition_index: 0,
             endpoint_url: format!(
                 "{}{}{}",
                 SNOWFLAKE_URL_PREFIX, config.account_id, SNOWFLAKE_URL_SUFFIX
@@ -141,9 +155,19 @@ impl SnowflakeQueryExecutor {
             query_timeout: config.query_timeout,
             reqwest_client,
             cursor_manager,
+            running_statements: Mutex::new(HashSet::new()),
         })
     }
 
+    fn record_stream(&self, result_set: ResultSet) -> stream::SnowflakeRecordStream {
+        stream::SnowflakeRecordStream::new(
+            result_set,
+            self.endpoint_url.clone(),
+            self.auth.clone(),
+            self.reqwest_client.clone(),
+        )
+    }
+
     #[async_recursion]
     #[tracing::instrument(name = "peer_sflake::process_query", skip_all)]
     async fn process_query(&self, query_str: &str) -> anyhow::Result<ResultSet> {
@@ -195,12 +219,20 @@ impl SnowflakeQueryExecutor {
             })?;
 
         let query_json = query_status_res.json::<serde_json::Value>().await?;
-        let query_status = serde_json::from_value(query_json.clone()).map_err(|e| {
+        let query_status: QueryStatus = serde_json::from_value(query_json.clone()).map_err(|e| {
             anyhow::anyhow!("failed in parsing json {:?}, error: {:?}", query_json, e)
         })?;
 
+        let handle = query_status.statementHandle.clone();
+        self.running_statements
+            .lock()
+            .unwrap()
+            .insert(handle.clone());
+        let res = self.query_poll(query_status).await;
+        self.running_statements.lock().unwrap().remove(&handle);
+
         // TODO: remove this blind retry logic for anything other than a SELECT.
-        let res = self.query_poll(query_status).await?;
+        let res = res?;
         Ok(match res {
             Some(result_set) => result_set,
             None => self.process_query(query_str).await?,
@@ -216,14 +248,28 @@ impl SnowflakeQueryExecutor {
         let query_str: String = query.to_string();
         info!("Processing SnowFlake query: {}", query_str);
 
-        let result_set = self.process_query(&query_str).await.map_err(|err| {
-            PgWireError::ApiError(Box::new(PgError::Internal {
-                err_msg: err.to_string(),
-            }))
-        })?;
+        let result_set = self
+            .process_query(&query_str)
+            .await
+            .map_err(|err| peer_error(err.to_string()))?;
         Ok(result_set)
     }
 
+    // the SQL API doesn't support PUT, so rows are staged in s3 rather than
+    // in a stage of snowflake's own.
+    async fn load_rows(
+        &self,
+        table_name: ObjectName,
+        columns: &[Ident],
+        rows: Vec<&[Option<String>]>,
+    ) -> anyhow::Result<usize> {
+        let mut stage = CopyStage::new(self, table_name, columns)?;
+        for row in rows {
+            stage.write_row(row).await?;
+        }
+        stage.finish().await
+    }
+
     async fn query_attempt(
         &self,
         query_status: &QueryStatus,
@@ -324,13 +370,7 @@ impl QueryExecutor for SnowflakeQueryExecutor {
 
                 let result_set = self.query(&query.clone()).await?;
 
-                let cursor = stream::SnowflakeRecordStream::new(
-                    result_set,
-                    self.partition_index,
-                    self.partition_number,
-                    self.endpoint_url.clone(),
-                    self.auth.clone(),
-                );
+                let cursor = self.record_stream(result_set);
                 Ok(QueryOutput::Stream(Box::pin(cursor)))
             }
             Statement::Declare { name, query, .. } => {
@@ -398,6 +438,45 @@ impl QueryExecutor for SnowflakeQueryExecutor {
                     closed_cursors,
                 )))
             }
+            Statement::Drop {
+                object_type: ObjectType::Table,
+                ..
+            } => {
+                // strip the peer name, tables are resolved in the configured database.
+                let mut drop_stmt = stmt.clone();
+                if let Statement::Drop { names, .. } = &mut drop_stmt {
+                    for name in names.iter_mut() {
+                        *name = peer_table_name(&self.peer_name, name)?;
+                    }
+                }
+                info!("Dropping SnowFlake tables: {}", drop_stmt);
+                self.process_query(&drop_stmt.to_string())
+                    .await
+                    .map_err(|err| peer_error(err.to_string()))?;
+                Ok(QueryOutput::AffectedRows(0))
+            }
+            Statement::Copy {
+                table_name,
+                columns,
+                to: false,
+                target: CopyTarget::Stdin,
+                values,
+                ..
+            } => {
+                let rows = copy_values_to_rows(columns.len(), values)?;
+                // strip the peer name, the table is resolved in the configured database.
+                let table_name = peer_table_name(&self.peer_name, table_name)?;
+                info!(
+                    "Copying {} rows into SnowFlake table {}",
+                    rows.len(),
+                    table_name
+                );
+                let rows_copied = self
+                    .load_rows(table_name, columns, rows)
+                    .await
+                    .map_err(|err| peer_error(err.to_string()))?;
+                Ok(QueryOutput::AffectedRows(rows_copied))
+            }
             _ => {
                 let error = format!(
                     "only SELECT statements are supported in snowflake. got: {}",
@@ -445,10 +524,112 @@ impl QueryExecutor for SnowflakeQueryExecutor {
         }
     }
 
+    // snowflake returns a result set for every statement, DDL reports its
+    // status and DML the number of rows it changed.
+    async fn execute_raw(&self, query: &str) -> PgWireResult<QueryOutput> {
+        info!("Processing raw SnowFlake statement: {}", query);
+        let result_set = self
+            .process_query(query)
+            .await
+            .map_err(|err| peer_error(err.to_string()))?;
+
+        let cursor = self.record_stream(result_set);
+        Ok(QueryOutput::Stream(Box::pin(cursor)))
+    }
+
+    // the rows are decoded as the client sends them and staged, they are
+    // loaded once the client is done sending them.
+    async fn copy_in(&self, stmt: &Statement, mut data: CopyInStream) -> PgWireResult<usize> {
+        let mut decoder = CopyInDecoder::new(stmt)?;
+        let (table_name, columns) = match stmt {
+            Statement::Copy {
+                table_name,
+                columns,
+                ..
+            } => (peer_table_name(&self.peer_name, table_name)?, columns),
+            _ => unreachable!("CopyInDecoder only decodes the data of COPY statements"),
+        };
+        info!("Staging copied rows for SnowFlake table {}", table_name);
+
+        let mut stage =
+            CopyStage::new(self, table_name, columns).map_err(|err| peer_error(err.to_string()))?;
+        while let Some(chunk) = data.next().await {
+            for row in decoder.decode(&chunk?)? {
+                stage
+                    .write_row(&row)
+                    .await
+                    .map_err(|err| peer_error(err.to_string()))?;
+            }
+        }
+        if let Some(row) = decoder.finish()? {
+            stage
+                .write_row(&row)
+                .await
+                .map_err(|err| peer_error(err.to_string()))?;
+        }
+        stage
+            .finish()
+            .await
+            .map_err(|err| peer_error(err.to_string()))
+    }
+
     async fn is_connection_valid(&self) -> anyhow::Result<bool> {
         let sql = "SELECT 1;";
         let test_stmt = parser::Parser::parse_sql(&GenericDialect {}, sql)?;
         let _ = self.execute(&test_stmt[0]).await?;
         Ok(true)
     }
+
+    async fn cancel(&self) -> anyhow::Result<()> {
+        let handles: Vec<String> = self.running_statements.lock().unwrap().drain().collect();
+        for handle in handles {
+            let mut auth = self.auth.clone();
+            let jwt = auth.get_jwt()?;
+            let secret = jwt.expose_secret().clone();
+            let response = self
+                .reqwest_client
+                .post(format!("{}/{}/cancel", self.endpoint_url, handle))
+                .bearer_auth(secret)
+                .send()
+                .await?;
+            if !response.status().is_success() {
+                anyhow::bail!(
+                    "unable to cancel statement {}: {}",
+                    handle,
+                    response.text().await?
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct SnowflakeConnector;
+
+#[async_trait::async_trait]
+impl Connector for SnowflakeConnector {
+    fn name(&self) -> &'static str {
+        "snowflake"
+    }
+
+    fn db_type(&self) -> DbType {
+        DbType::Snowflake
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            execute_raw: true,
+            manage_tables: true,
+            pooled: false,
+        }
+    }
+
+    async fn connect(&self, peer: &Peer) -> anyhow::Result<Box<dyn QueryExecutor>> {
+        match &peer.config {
+            Some(Config::SnowflakeConfig(config)) => Ok(Box::new(
+                SnowflakeQueryExecutor::new(peer.name.clone(), config).await?,
+            )),
+            _ => anyhow::bail!("peer {} is not a snowflake peer", peer.name),
+        }
+    }
 }
@@ -0,0 +1,326 @@
+use anyhow::Context;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::header;
+use sha2::{Digest, Sha256};
+use sqlparser::ast::{Ident, ObjectName};
+use tracing::info;
+
+use crate::SnowflakeQueryExecutor;
+
+// the size of the files the rows are staged in, the files of a stage are
+// loaded in parallel.
+const STAGE_FILE_SIZE: usize = 64 * 1024 * 1024;
+
+// the credentials nexus stages rows in s3 with, from the same environment as
+// those of flow.
+struct AwsCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+    region: String,
+    endpoint: Option<String>,
+}
+
+impl AwsCredentials {
+    fn from_env() -> anyhow::Result<Self> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        Ok(Self {
+            access_key_id: var("AWS_ACCESS_KEY_ID")
+                .context("AWS_ACCESS_KEY_ID must be set to stage rows in s3")?,
+            secret_access_key: var("AWS_SECRET_ACCESS_KEY")
+                .context("AWS_SECRET_ACCESS_KEY must be set to stage rows in s3")?,
+            session_token: var("AWS_SESSION_TOKEN"),
+            region: var("AWS_REGION").context("AWS_REGION must be set to stage rows in s3")?,
+            endpoint: var("AWS_ENDPOINT"),
+        })
+    }
+}
+
+/// Rows copied into a table of the peer, staged as csv files under the
+/// staging_path of the peer, in a directory of their own, and loaded with
+/// `COPY INTO` from an external stage over that directory once they are all
+/// staged. Snowflake loads the files of a COPY all at once or not at all.
+pub struct CopyStage<'a> {
+    executor: &'a SnowflakeQueryExecutor,
+    table_name: ObjectName,
+    columns: &'a [Ident],
+    credentials: AwsCredentials,
+    client: reqwest::Client,
+    bucket: String,
+    // the directory of the rows of this COPY in the bucket.
+    prefix: String,
+    name: String,
+    buf: Vec<u8>,
+    files: usize,
+}
+
+impl<'a> CopyStage<'a> {
+    pub fn new(
+        executor: &'a SnowflakeQueryExecutor,
+        table_name: ObjectName,
+        columns: &'a [Ident],
+    ) -> anyhow::Result<Self> {
+        let staging_path = executor
+            .config
+            .staging_path
+            .as_deref()
+            .filter(|staging_path| !staging_path.is_empty())
+            .with_context(|| {
+                format!(
+                    "snowflake peer {} has no staging_path to stage copied rows in",
+                    executor.peer_name
+                )
+            })?;
+        let path = staging_path
+            .strip_prefix("s3://")
+            .with_context(|| format!("staging_path {} is not an s3:// url", staging_path))?;
+        let (bucket, prefix) = path.split_once('/').unwrap_or((path, ""));
+        let name = format!("peerdb_copy_{}", uuid::Uuid::new_v4().to_simple());
+        let prefix = match prefix.trim_matches('/') {
+            "" => name.clone(),
+            prefix => format!("{}/{}", prefix, name),
+        };
+        Ok(Self {
+            executor,
+            table_name,
+            columns,
+            credentials: AwsCredentials::from_env()?,
+            client: reqwest::Client::new(),
+            bucket: bucket.to_owned(),
+            prefix,
+            name,
+            buf: vec![],
+            files: 0,
+        })
+    }
+
+    // NULL values are left empty and the others are quoted, so that empty
+    // strings aren't loaded as NULL.
+    pub async fn write_row(&mut self, row: &[Option<String>]) -> anyhow::Result<()> {
+        for (i, value) in row.iter().enumerate() {
+            if i > 0 {
+                self.buf.push(b',');
+            }
+            if let Some(value) = value {
+                self.buf.push(b'"');
+                self.buf
+                    .extend_from_slice(value.replace('"', "\"\"").as_bytes());
+                self.buf.push(b'"');
+            }
+        }
+        self.buf.push(b'\n');
+        if self.buf.len() >= STAGE_FILE_SIZE {
+            self.upload_file().await?;
+        }
+        Ok(())
+    }
+
+    /// Stages the rest of the rows and loads all of them into the table,
+    /// returns the number of rows loaded. The stage is dropped after the load
+    /// and the files are purged once they are loaded.
+    pub async fn finish(mut self) -> anyhow::Result<usize> {
+        if !self.buf.is_empty() {
+            self.upload_file().await?;
+        }
+        if self.files == 0 {
+            return Ok(0);
+        }
+
+        let stage = self.stage_name();
+        let credentials = if self.executor.config.s3_integration.is_empty() {
+            let mut credentials = format!(
+                "CREDENTIALS = (AWS_KEY_ID = '{}' AWS_SECRET_KEY = '{}'",
+                self.credentials.access_key_id, self.credentials.secret_access_key
+            );
+            if let Some(session_token) = &self.credentials.session_token {
+                credentials.push_str(&format!(" AWS_TOKEN = '{}'", session_token));
+            }
+            credentials.push(')');
+            credentials
+        } else {
+            format!(
+                "STORAGE_INTEGRATION = {}",
+                self.executor.config.s3_integration
+            )
+        };
+        self.executor
+            .process_query(&format!(
+                "CREATE OR REPLACE STAGE {} URL = 's3://{}/{}/' {} FILE_FORMAT = (TYPE = CSV \
+                 FIELD_OPTIONALLY_ENCLOSED_BY = '\"' NULL_IF = () EMPTY_FIELD_AS_NULL = TRUE)",
+                stage, self.bucket, self.prefix, credentials
+            ))
+            .await?;
+
+        let column_names: Vec<String> = self.columns.iter().map(|c| c.to_string()).collect();
+        let copy_stmt = format!(
+            "COPY INTO {} ({}) FROM @{} PURGE = TRUE",
+            self.table_name,
+            column_names.join(", "),
+            stage
+        );
+        info!("loading staged rows: {}", copy_stmt);
+        let loaded = self.executor.process_query(&copy_stmt).await;
+        let dropped = self
+            .executor
+            .process_query(&format!("DROP STAGE IF EXISTS {}", stage))
+            .await;
+        let result_set = loaded?;
+        dropped?;
+
+        // a row for each of the files, with the number of rows loaded from it.
+        let rows_loaded = result_set
+            .resultSetMetaData
+            .rowType
+            .iter()
+            .position(|column| column.name.eq_ignore_ascii_case("rows_loaded"))
+            .context("COPY INTO returned no rows_loaded")?;
+        result_set
+            .data
+            .iter()
+            .map(|row| {
+                let rows = row.get(rows_loaded).cloned().flatten().unwrap_or_default();
+                rows.parse::<usize>()
+                    .with_context(|| format!("invalid rows_loaded {}", rows))
+            })
+            .sum()
+    }
+
+    // the stage is created in the schema of the table, snowflake resolves
+    // unqualified tables in PUBLIC.
+    fn stage_name(&self) -> ObjectName {
+        let mut stage = match self.table_name.0.split_last() {
+            Some((_, [])) | None => vec![Ident::new("PUBLIC")],
+            Some((_, schema)) => schema.to_vec(),
+        };
+        stage.push(Ident::new(&self.name));
+        ObjectName(stage)
+    }
+
+    async fn upload_file(&mut self) -> anyhow::Result<()> {
+        let key = format!("{}/{}.csv", self.prefix, self.files);
+        let body = std::mem::take(&mut self.buf);
+        self.put_object(&key, body).await?;
+        self.files += 1;
+        Ok(())
+    }
+
+    // a PUT of the object, signed with version 4 of the AWS signatures.
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> anyhow::Result<()> {
+        let credentials = &self.credentials;
+        let path = key.split('/').map(uri_encode).collect::<Vec<_>>().join("/");
+        let (url, canonical_uri) = match &credentials.endpoint {
+            // custom endpoints, like minio, are addressed with the bucket in the path.
+            Some(endpoint) => (
+                format!(
+                    "{}/{}/{}",
+                    endpoint.trim_end_matches('/'),
+                    self.bucket,
+                    path
+                ),
+                format!("/{}/{}", uri_encode(&self.bucket), path),
+            ),
+            None => (
+                format!(
+                    "https://{}.s3.{}.amazonaws.com/{}",
+                    self.bucket, credentials.region, path
+                ),
+                format!("/{}", path),
+            ),
+        };
+        let url = reqwest::Url::parse(&url)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_owned(),
+            (None, _) => anyhow::bail!("no host in s3 url {}", url),
+        };
+
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let mut headers = vec![
+            ("host", host),
+            ("x-amz-content-sha256", payload_hash.clone()),
+            ("x-amz-date", amz_date.clone()),
+        ];
+        if let Some(session_token) = &credentials.session_token {
+            headers.push(("x-amz-security-token", session_token.clone()));
+        }
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "PUT\n{}\n\n{}\n{}\n{}",
+            canonical_uri, canonical_headers, signed_headers, payload_hash
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, credentials.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+        let signing_key = [credentials.region.as_str(), "s3", "aws4_request"]
+            .iter()
+            .fold(
+                hmac_sha256(
+                    format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+                    &date,
+                ),
+                |key, part| hmac_sha256(&key, part),
+            );
+        let signature = hex::encode(hmac_sha256(&signing_key, &string_to_sign));
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            credentials.access_key_id, scope, signed_headers, signature
+        );
+
+        let mut request = self
+            .client
+            .put(url)
+            .header(header::AUTHORIZATION, authorization)
+            .body(body);
+        // the host header is set by the client.
+        for (name, value) in headers.into_iter().skip(1) {
+            request = request.header(name, value);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!(
+                "failed to stage rows in s3://{}/{}: {} {}",
+                self.bucket,
+                key,
+                status,
+                response.text().await?
+            );
+        }
+        Ok(())
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any size");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+// percent-encodes all but the unreserved characters, as AWS signatures do.
+fn uri_encode(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
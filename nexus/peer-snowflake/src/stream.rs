@@ -1,15 +1,17 @@
-use crate::{auth::SnowflakeAuth, PartitionResult, ResultSet};
+use crate::{auth::SnowflakeAuth, PartitionResult, ResultSet, ResultSetRowType};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use futures::Stream;
 use peer_cursor::Schema;
-use peer_cursor::{Record, RecordStream, SchemaRef};
-use pgerror::PgError;
+use peer_cursor::{
+    batch::{RecordBatchSender, RecordBatchStream, ROW_BATCH_SIZE},
+    Record, RecordStream, SchemaRef,
+};
 use pgwire::{
     api::{
         results::{FieldFormat, FieldInfo},
         Type,
     },
-    error::{PgWireError, PgWireResult},
+    error::PgWireResult,
 };
 use secrecy::ExposeSecret;
 use serde::Deserialize;
@@ -83,184 +85,162 @@ impl SnowflakeSchema {
 }
 
 pub struct SnowflakeRecordStream {
-    result_set: ResultSet,
-    partition_index: usize,
-    partition_number: usize,
-    schema: SnowflakeSchema,
-    auth: SnowflakeAuth,
-
-    endpoint_url: String,
+    rows: RecordBatchStream,
 }
 
 impl SnowflakeRecordStream {
+    /// Streams the rows of the statement whose first partition the result set
+    /// holds, the partitions after it are fetched while the rows are sent.
     pub fn new(
         result_set: ResultSet,
-        partition_index: usize,
-        partition_number: usize,
         endpoint_url: String,
         auth: SnowflakeAuth,
+        client: reqwest::Client,
     ) -> Self {
-        let sf_schema = SnowflakeSchema::from_result_set(&result_set);
-
-        Self {
-            result_set,
-            schema: sf_schema,
-            partition_index,
-            partition_number,
-            endpoint_url,
-            auth,
-        }
+        let schema = SnowflakeSchema::from_result_set(&result_set).schema();
+        let rows = RecordBatchStream::spawn(schema.clone(), move |sender| {
+            fetch_partitions(result_set, schema, endpoint_url, auth, client, sender)
+        });
+        Self { rows }
     }
+}
 
-    pub fn convert_result_set_item(&mut self) -> anyhow::Result<Record> {
-        let mut row_values = Vec::new();
-
-        for (index, value) in self.result_set.data[self.partition_index]
-            .iter()
-            .enumerate()
-        {
-            const DATE_PARSE_FORMAT: &str = "%Y/%m/%d";
-            const TIME_PARSE_FORMAT: &str = "%H:%M:%S.%9f";
-            const TIMESTAMP_PARSE_FORMAT: &str = "%FT%T.%9f";
-            const TIMESTAMP_TZ_PARSE_FORMAT: &str = "%FT%T%.9f%z";
-            let row_value = match value {
-                None => None,
-                Some(elem) => Some(
-                    match self.result_set.resultSetMetaData.rowType[index].r#type {
-                        SnowflakeDataType::Fixed => match elem.parse::<i64>() {
-                            Ok(_) => BigInt(elem.parse()?),
-                            Err(_) => Text(elem.to_string()),
-                        },
-                        SnowflakeDataType::Real => Float(elem.parse()?),
-                        SnowflakeDataType::Text => Text(elem.to_string()),
-                        SnowflakeDataType::Binary => Binary(hex::decode(elem)?.into()),
-                        SnowflakeDataType::Boolean => Bool(elem.parse()?),
-                        SnowflakeDataType::Date => {
-                            println!("Entered Date. elem: {:#?}", elem);
-                            Date(NaiveDate::parse_from_str(elem, DATE_PARSE_FORMAT)?)
-                        }
-                        SnowflakeDataType::Time => {
-                            Time(NaiveTime::parse_from_str(elem, TIME_PARSE_FORMAT)?)
-                        }
-                        // really hacky workaround for parsing the UTC timezone specifically.
-                        SnowflakeDataType::TimestampLtz => {
-                            match DateTime::parse_from_str(elem, TIMESTAMP_TZ_PARSE_FORMAT) {
-                                Ok(_) => TimestampWithTimeZone(
-                                    Utc.from_utc_datetime(
-                                        &DateTime::parse_from_str(elem, TIMESTAMP_TZ_PARSE_FORMAT)?
-                                            .naive_utc(),
-                                    ),
-                                ),
-                                Err(_) => TimestampWithTimeZone(
-                                    Utc.from_utc_datetime(
-                                        &DateTime::parse_from_str(
-                                            &elem.replace('Z', "+0000"),
-                                            TIMESTAMP_TZ_PARSE_FORMAT,
-                                        )?
-                                        .naive_utc(),
-                                    ),
-                                ),
-                            }
-                        }
-                        SnowflakeDataType::TimestampNtz => PostgresTimestamp(
-                            NaiveDateTime::parse_from_str(elem, TIMESTAMP_PARSE_FORMAT)?,
-                        ),
-                        SnowflakeDataType::TimestampTz => {
-                            match DateTime::parse_from_str(elem, TIMESTAMP_TZ_PARSE_FORMAT) {
-                                Ok(_) => TimestampWithTimeZone(
-                                    Utc.from_utc_datetime(
-                                        &DateTime::parse_from_str(elem, TIMESTAMP_TZ_PARSE_FORMAT)?
-                                            .naive_utc(),
-                                    ),
-                                ),
-                                Err(_) => TimestampWithTimeZone(
-                                    Utc.from_utc_datetime(
-                                        &DateTime::parse_from_str(
-                                            &elem.replace('Z', "+0000"),
-                                            TIMESTAMP_TZ_PARSE_FORMAT,
-                                        )?
-                                        .naive_utc(),
-                                    ),
-                                ),
-                            }
-                        }
-                        SnowflakeDataType::Variant => {
-                            let jsonb: serde_json::Value = serde_json::from_str(elem)?;
-                            Value::JsonB(jsonb)
-                        }
-                    },
-                ),
-            };
-
-            row_values.push(row_value.unwrap_or(Value::Null));
+// sends the rows of each partition in batches, fetching the next partition
+// once the client caught up enough for its rows to be sent.
+async fn fetch_partitions(
+    mut result_set: ResultSet,
+    schema: SchemaRef,
+    endpoint_url: String,
+    mut auth: SnowflakeAuth,
+    client: reqwest::Client,
+    sender: RecordBatchSender,
+) -> anyhow::Result<()> {
+    let row_type = &result_set.resultSetMetaData.rowType;
+    let num_partitions = result_set.resultSetMetaData.partitionInfo.len();
+    let mut data = std::mem::take(&mut result_set.data);
+    let mut partition_number = 0;
+    loop {
+        for rows in data.chunks(ROW_BATCH_SIZE) {
+            let batch = rows
+                .iter()
+                .map(|row| convert_row(row_type, &schema, row))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            if !sender.send(batch).await {
+                return Ok(());
+            }
         }
 
-        self.partition_index += 1;
-
-        Ok(Record {
-            values: row_values,
-            schema: self.schema.schema(),
-        })
-    }
-
-    fn advance_partition(&mut self) -> anyhow::Result<bool> {
-        if (self.partition_number + 1) == self.result_set.resultSetMetaData.partitionInfo.len() {
-            return Ok(false);
+        partition_number += 1;
+        if partition_number >= num_partitions {
+            return Ok(());
         }
-        self.partition_number += 1;
-        self.partition_index = 0;
-        let partition_number = self.partition_number;
-        let secret = self.auth.get_jwt()?.expose_secret().clone();
-        let statement_handle = self.result_set.statementHandle.clone();
-        let url = self.endpoint_url.clone();
-        println!("Secret: {:#?}", secret);
-        let response: PartitionResult = ureq::get(&format!("{}/{}", url, statement_handle))
-            .query("partition", &partition_number.to_string())
-            .set("Authorization", &format!("Bearer {}", secret))
-            .set("X-Snowflake-Authorization-Token-Type", "KEYPAIR_JWT")
-            .set("user-agent", "ureq")
-            .call()?
-            .into_json()
-            .map_err(|_| anyhow::anyhow!("get_partition failed"))?;
-        println!("Response: {:#?}", response.data);
-
-        self.result_set.data = response.data;
-        Ok(true)
+        let secret = auth.get_jwt()?.expose_secret().clone();
+        let response: PartitionResult = client
+            .get(format!("{}/{}", endpoint_url, result_set.statementHandle))
+            .query(&[("partition", partition_number)])
+            .bearer_auth(secret)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+            .map_err(|err| anyhow::anyhow!("get_partition failed: {}", err))?;
+        data = response.data;
     }
+}
 
-    fn advance(&mut self) -> anyhow::Result<bool> {
-        Ok((self.partition_index < self.result_set.data.len()) || self.advance_partition()?)
+fn convert_row(
+    row_type: &[ResultSetRowType],
+    schema: &SchemaRef,
+    row: &[Option<String>],
+) -> anyhow::Result<Record> {
+    let mut row_values = Vec::new();
+
+    for (index, value) in row.iter().enumerate() {
+        const DATE_PARSE_FORMAT: &str = "%Y/%m/%d";
+        const TIME_PARSE_FORMAT: &str = "%H:%M:%S.%9f";
+        const TIMESTAMP_PARSE_FORMAT: &str = "%FT%T.%9f";
+        const TIMESTAMP_TZ_PARSE_FORMAT: &str = "%FT%T%.9f%z";
+        let row_value = match value {
+            None => None,
+            Some(elem) => Some(match row_type[index].r#type {
+                SnowflakeDataType::Fixed => match elem.parse::<i64>() {
+                    Ok(_) => BigInt(elem.parse()?),
+                    Err(_) => Text(elem.to_string()),
+                },
+                SnowflakeDataType::Real => Float(elem.parse()?),
+                SnowflakeDataType::Text => Text(elem.to_string()),
+                SnowflakeDataType::Binary => Binary(hex::decode(elem)?.into()),
+                SnowflakeDataType::Boolean => Bool(elem.parse()?),
+                SnowflakeDataType::Date => {
+                    println!("Entered Date. elem: {:#?}", elem);
+                    Date(NaiveDate::parse_from_str(elem, DATE_PARSE_FORMAT)?)
+                }
+                SnowflakeDataType::Time => {
+                    Time(NaiveTime::parse_from_str(elem, TIME_PARSE_FORMAT)?)
+                }
+                // really hacky workaround for parsing the UTC timezone specifically.
+                SnowflakeDataType::TimestampLtz => {
+                    match DateTime::parse_from_str(elem, TIMESTAMP_TZ_PARSE_FORMAT) {
+                        Ok(_) => TimestampWithTimeZone(Utc.from_utc_datetime(
+                            &DateTime::parse_from_str(elem, TIMESTAMP_TZ_PARSE_FORMAT)?.naive_utc(),
+                        )),
+                        Err(_) => TimestampWithTimeZone(
+                            Utc.from_utc_datetime(
+                                &DateTime::parse_from_str(
+                                    &elem.replace('Z', "+0000"),
+                                    TIMESTAMP_TZ_PARSE_FORMAT,
+                                )?
+                                .naive_utc(),
+                            ),
+                        ),
+                    }
+                }
+                SnowflakeDataType::TimestampNtz => {
+                    PostgresTimestamp(NaiveDateTime::parse_from_str(elem, TIMESTAMP_PARSE_FORMAT)?)
+                }
+                SnowflakeDataType::TimestampTz => {
+                    match DateTime::parse_from_str(elem, TIMESTAMP_TZ_PARSE_FORMAT) {
+                        Ok(_) => TimestampWithTimeZone(Utc.from_utc_datetime(
+                            &DateTime::parse_from_str(elem, TIMESTAMP_TZ_PARSE_FORMAT)?.naive_utc(),
+                        )),
+                        Err(_) => TimestampWithTimeZone(
+                            Utc.from_utc_datetime(
+                                &DateTime::parse_from_str(
+                                    &elem.replace('Z', "+0000"),
+                                    TIMESTAMP_TZ_PARSE_FORMAT,
+                                )?
+                                .naive_utc(),
+                            ),
+                        ),
+                    }
+                }
+                SnowflakeDataType::Variant => {
+                    let jsonb: serde_json::Value = serde_json::from_str(elem)?;
+                    Value::JsonB(jsonb)
+                }
+            }),
+        };
+
+        row_values.push(row_value.unwrap_or(Value::Null));
     }
+
+    Ok(Record {
+        values: row_values,
+        schema: schema.clone(),
+    })
 }
 
 impl Stream for SnowflakeRecordStream {
     type Item = PgWireResult<Record>;
 
-    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.get_mut();
-
-        match this.advance() {
-            Ok(true) => {
-                let record = this.convert_result_set_item();
-                let result = record.map_err(|e| {
-                    PgWireError::ApiError(Box::new(PgError::Internal {
-                        err_msg: format!("error getting current row: {}", e),
-                    }))
-                });
-                Poll::Ready(Some(result))
-            }
-            Ok(false) => Poll::Ready(None),
-            Err(err) => Poll::Ready(Some(Err(PgWireError::ApiError(Box::new(
-                PgError::Internal {
-                    err_msg: format!("Checking for next row in result set failed: {}", err),
-                },
-            ))))),
-        }
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.rows).poll_next(cx)
     }
 }
 
 impl RecordStream for SnowflakeRecordStream {
     fn schema(&self) -> SchemaRef {
-        self.schema.schema()
+        self.rows.schema()
     }
 }
@@ -1,6 +1,14 @@
+use std::sync::{Arc, Mutex};
+
+use futures::{stream, StreamExt};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
 use pt::peerdb_peers::PostgresConfig;
+use tokio::sync::{
+    mpsc::{self, UnboundedReceiver},
+    Notify,
+};
+use tokio_postgres::{error::DbError, AsyncMessage, Notification};
 
 pub fn get_pg_connection_string(config: &PostgresConfig) -> String {
     let mut connection_string = String::from("postgres://");
@@ -23,24 +31,90 @@ pub fn get_pg_connection_string(config: &PostgresConfig) -> String {
     connection_string
 }
 
-pub async fn connect_postgres(config: &PostgresConfig) -> anyhow::Result<tokio_postgres::Client> {
-    let connection_string = get_pg_connection_string(config);
-
+/// The TLS connector peers are reached with, it doesn't verify certificates.
+pub fn tls_connector() -> anyhow::Result<MakeTlsConnector> {
     let mut builder = SslConnector::builder(SslMethod::tls())?;
     builder.set_verify(SslVerifyMode::NONE);
+    Ok(MakeTlsConnector::new(builder.build()))
+}
+
+pub async fn connect_postgres(config: &PostgresConfig) -> anyhow::Result<tokio_postgres::Client> {
+    let (client, _, _) = connect_postgres_with_messages(config).await?;
+    Ok(client)
+}
+
+/// The notifications of the channels a connection listens to, kept until
+/// they are taken.
+#[derive(Default)]
+pub struct Notifications {
+    pending: Mutex<Vec<Notification>>,
+    arrived: Notify,
+}
 
-    let tls_connector = MakeTlsConnector::new(builder.build());
-    let (client, connection) = tokio_postgres::connect(&connection_string, tls_connector)
+impl Notifications {
+    pub fn take(&self) -> Vec<Notification> {
+        std::mem::take(&mut *self.pending.lock().unwrap())
+    }
+
+    /// Waits for notifications to be pending.
+    pub async fn arrived(&self) {
+        loop {
+            let arrived = self.arrived.notified();
+            if !self.pending.lock().unwrap().is_empty() {
+                return;
+            }
+            arrived.await;
+        }
+    }
+
+    fn push(&self, notification: Notification) {
+        self.pending.lock().unwrap().push(notification);
+        self.arrived.notify_waiters();
+    }
+}
+
+/// Connects like `connect_postgres`, and hands out the notices and warnings
+/// the server sends on the connection, which are only logged otherwise, and
+/// the notifications of the channels the connection listens to.
+pub async fn connect_postgres_with_messages(
+    config: &PostgresConfig,
+) -> anyhow::Result<(
+    tokio_postgres::Client,
+    UnboundedReceiver<DbError>,
+    Arc<Notifications>,
+)> {
+    let connection_string = get_pg_connection_string(config);
+
+    let tls_connector = tls_connector()?;
+    let (client, mut connection) = tokio_postgres::connect(&connection_string, tls_connector)
         .await
         .map_err(|e| anyhow::anyhow!("error encountered while connecting to postgres {:?}", e))?;
 
+    let (notices_tx, notices_rx) = mpsc::unbounded_channel();
+    let notifications = Arc::new(Notifications::default());
+    let connection_notifications = notifications.clone();
     tokio::task::Builder::new()
         .name("PostgresQueryExecutor connection")
         .spawn(async move {
-            if let Err(e) = connection.await {
-                tracing::info!("connection error: {}", e)
+            let mut messages = stream::poll_fn(move |cx| connection.poll_message(cx));
+            while let Some(message) = messages.next().await {
+                match message {
+                    Ok(AsyncMessage::Notice(notice)) => {
+                        tracing::info!("notice: {}", notice);
+                        // nobody is listening once the receiver is dropped.
+                        let _ = notices_tx.send(notice);
+                    }
+                    Ok(AsyncMessage::Notification(notification)) => {
+                        connection_notifications.push(notification);
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::info!("connection error: {}", e);
+                        break;
+                    }
+                }
             }
         })?;
 
-    Ok(client)
+    Ok((client, notices_rx, notifications))
 }
@@ -10,6 +10,18 @@ pub struct FlowJobTableMapping {
     pub source_table_identifier: String,
     pub destination_table_identifier: String,
     pub partition_key: Option<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+// a schema mirrored as a whole with `schema.* => schema.*`, its tables are
+// added to the table mappings of the flow job.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+pub struct FlowJobSchemaMapping {
+    pub source_schema: String,
+    // empty for destinations whose tables aren't in schemas, like bigquery.
+    pub destination_schema: String,
+    pub exclude_tables: Vec<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
@@ -56,12 +68,82 @@ impl ToString for FlowSyncMode {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+pub enum FlowOrderingKey {
+    PrimaryKey,
+    Table,
+    PartitionKey,
+}
+
+impl FlowOrderingKey {
+    pub fn parse_string(s: &str) -> Result<FlowOrderingKey, String> {
+        match s {
+            "primary_key" => Ok(FlowOrderingKey::PrimaryKey),
+            "table" => Ok(FlowOrderingKey::Table),
+            "partition_key" => Ok(FlowOrderingKey::PartitionKey),
+            _ => Err(format!("{} is not a valid ordering key", s)),
+        }
+    }
+
+    pub fn as_proto_ordering_key(&self) -> i32 {
+        match self {
+            FlowOrderingKey::PrimaryKey => peerdb_flow::OrderingKey::PrimaryKey as i32,
+            FlowOrderingKey::Table => peerdb_flow::OrderingKey::Table as i32,
+            FlowOrderingKey::PartitionKey => peerdb_flow::OrderingKey::PartitionKey as i32,
+        }
+    }
+}
+
+// the daily window of UTC time a mirror normalizes batches in, in minutes
+// since midnight. it spans midnight when it ends before it starts.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+pub struct FlowApplyWindow {
+    pub start_minute: u32,
+    pub end_minute: u32,
+}
+
+impl FlowApplyWindow {
+    // parses a window written as HH:MM-HH:MM, e.g. 00:00-06:00.
+    pub fn parse_string(s: &str) -> Result<FlowApplyWindow, String> {
+        let invalid = || format!("{} is not a valid apply window, expected HH:MM-HH:MM", s);
+        let minute = |time: &str| -> Option<u32> {
+            let (hours, minutes) = time.trim().split_once(':')?;
+            let hours: u32 = hours.parse().ok()?;
+            let minutes: u32 = minutes.parse().ok()?;
+            (hours < 24 && minutes < 60).then_some(hours * 60 + minutes)
+        };
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let start_minute = minute(start).ok_or_else(invalid)?;
+        let end_minute = minute(end).ok_or_else(invalid)?;
+        if start_minute == end_minute {
+            return Err(format!("apply window {} is empty", s));
+        }
+        Ok(FlowApplyWindow {
+            start_minute,
+            end_minute,
+        })
+    }
+
+    pub fn as_proto_apply_window(&self) -> peerdb_flow::ApplyWindow {
+        peerdb_flow::ApplyWindow {
+            start_minute: self.start_minute,
+            end_minute: self.end_minute,
+        }
+    }
+}
+
+// the spec of a mirror is recorded in the catalog when it is created, the
+// fields added since default when an older spec is read.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
 pub struct FlowJob {
     pub name: String,
     pub source_peer: String,
     pub target_peer: String,
     pub table_mappings: Vec<FlowJobTableMapping>,
+    #[serde(default)]
+    pub schema_mappings: Vec<FlowJobSchemaMapping>,
+    #[serde(default)]
+    pub schema_refresh_interval: Option<u32>,
     pub description: String,
     pub do_initial_copy: bool,
     pub publication_name: Option<String>,
@@ -73,10 +155,26 @@ pub struct FlowJob {
     pub cdc_sync_mode: Option<FlowSyncMode>,
     pub cdc_staging_path: Option<String>,
     pub soft_delete: bool,
+    #[serde(default)]
+    pub soft_delete_column: Option<String>,
+    #[serde(default)]
+    pub synced_at_column: Option<String>,
     pub replication_slot_name: Option<String>,
     pub push_parallelism: Option<i64>,
     pub push_batch_size: Option<i64>,
     pub max_batch_size: Option<u32>,
+    #[serde(default)]
+    pub initial_copy_only: bool,
+    #[serde(default)]
+    pub ordering_key: Option<FlowOrderingKey>,
+    #[serde(default)]
+    pub staging_integration: Option<String>,
+    #[serde(default)]
+    pub refresh_statistics_threshold: Option<u32>,
+    #[serde(default)]
+    pub apply_window: Option<FlowApplyWindow>,
+    #[serde(default)]
+    pub track_latency: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
@@ -36,6 +36,20 @@ pub struct TableMapping {
     pub destination_table_identifier: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub partition_key: ::prost::alloc::string::String,
+    /// columns of the source table that are never read or replicated.
+    #[prost(string, repeated, tag="4")]
+    pub exclude: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SchemaMapping {
+    #[prost(string, tag="1")]
+    pub source_schema: ::prost::alloc::string::String,
+    /// empty for destinations without schemas, whose tables keep their names.
+    #[prost(string, tag="2")]
+    pub destination_schema: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="3")]
+    pub exclude_tables: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -75,6 +89,11 @@ pub struct FlowConnectionConfigs {
     pub snapshot_sync_mode: i32,
     #[prost(enumeration="QRepSyncMode", tag="16")]
     pub cdc_sync_mode: i32,
+    /// where the mirror stages the files it loads, so mirrors can stage through
+    /// buckets of their own: an s3:// path for snowflake, nothing for its
+    /// internal stage, and a bucket name or gs://bucket/prefix for bigquery.
+    /// snowflake stages the records of cdc through cdc_staging_path too, rather
+    /// than always through its internal stage.
     #[prost(string, tag="17")]
     pub snapshot_staging_path: ::prost::alloc::string::String,
     #[prost(string, tag="18")]
@@ -89,6 +108,66 @@ pub struct FlowConnectionConfigs {
     pub push_batch_size: i64,
     #[prost(int64, tag="22")]
     pub push_parallelism: i64,
+    /// shut the flow down once the initial copy completes, and drop the
+    /// replication slot and publication, instead of moving on to CDC.
+    #[prost(bool, tag="23")]
+    pub initial_copy_only: bool,
+    /// how records are keyed on queue destinations, records with the same key
+    /// are delivered in order.
+    #[prost(enumeration="OrderingKey", tag="24")]
+    pub ordering_key: i32,
+    /// snowflake storage integration to stage through, overrides the one on the
+    /// destination peer so mirrors can use their own buckets.
+    #[prost(string, tag="25")]
+    pub staging_integration: ::prost::alloc::string::String,
+    /// destination tables whose replication is paused, to the policy for the
+    /// changes made to them while paused.
+    #[prost(map="string, enumeration(TablePausePolicy)", tag="26")]
+    pub paused_tables: ::std::collections::HashMap<::prost::alloc::string::String, i32>,
+    /// destination tables resumed after being paused with the buffer policy, to
+    /// the last batch normalized before they were paused.
+    #[prost(map="string, int64", tag="27")]
+    pub resumed_tables: ::std::collections::HashMap<::prost::alloc::string::String, i64>,
+    /// destination tables with at least this many rows loaded since their
+    /// statistics were last refreshed get them refreshed after a snapshot or a
+    /// normalized batch, 0 leaves them to the destination.
+    #[prost(uint32, tag="28")]
+    pub refresh_statistics_threshold: u32,
+    /// batches are only normalized into the destination tables inside this
+    /// window, outside it they are buffered in the raw table. unset normalizes
+    /// batches as they are synced.
+    #[prost(message, optional, tag="29")]
+    pub apply_window: ::core::option::Option<ApplyWindow>,
+    /// stamp the replicated rows with the commit time of their change at the
+    /// source and the time it was applied, and report the latency in between.
+    #[prost(bool, tag="30")]
+    pub track_latency: bool,
+    /// the column deleted rows are marked in with soft_delete,
+    /// _PEERDB_IS_DELETED when empty.
+    #[prost(string, tag="31")]
+    pub soft_delete_col_name: ::prost::alloc::string::String,
+    /// the column rows are stamped with the time they were last synced in, no
+    /// column is added when empty.
+    #[prost(string, tag="32")]
+    pub synced_at_col_name: ::prost::alloc::string::String,
+    /// the schemas mirrored as a whole, their tables at creation are part of the
+    /// table mappings.
+    #[prost(message, repeated, tag="33")]
+    pub schema_mappings: ::prost::alloc::vec::Vec<SchemaMapping>,
+    /// how often the schemas are checked for new tables to add to the mirror,
+    /// they are only expanded at creation when 0.
+    #[prost(uint32, tag="34")]
+    pub schema_refresh_interval_seconds: u32,
+}
+/// a daily window of UTC time, in minutes since midnight. a window that ends
+/// before it starts spans midnight.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApplyWindow {
+    #[prost(uint32, tag="1")]
+    pub start_minute: u32,
+    #[prost(uint32, tag="2")]
+    pub end_minute: u32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -249,6 +328,32 @@ pub struct TableSchema {
     pub primary_key_columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(bool, tag="4")]
     pub is_replica_identity_full: bool,
+    /// source types of the enum and composite columns, Postgres destinations
+    /// create equivalent types for them. Elsewhere enums are replicated as
+    /// strings and composites as json objects.
+    #[prost(map="string, message", tag="5")]
+    pub column_types: ::std::collections::HashMap<::prost::alloc::string::String, PostgresType>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostgresType {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// labels of an enum type, in order.
+    #[prost(string, repeated, tag="2")]
+    pub enum_labels: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// attributes of a composite type, in order.
+    #[prost(message, repeated, tag="3")]
+    pub attributes: ::prost::alloc::vec::Vec<PostgresTypeAttribute>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostgresTypeAttribute {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// as formatted by format_type, e.g. "numeric(10,2)".
+    #[prost(string, tag="2")]
+    pub r#type: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -281,6 +386,10 @@ pub struct SetupNormalizedTableBatchInput {
     pub peer_connection_config: ::core::option::Option<super::peerdb_peers::Peer>,
     #[prost(map="string, message", tag="2")]
     pub table_name_schema_mapping: ::std::collections::HashMap<::prost::alloc::string::String, TableSchema>,
+    #[prost(string, tag="3")]
+    pub soft_delete_col_name: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub synced_at_col_name: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -406,6 +515,25 @@ pub struct QRepConfig {
     /// Creates the watermark table on the destination as-is, can be used for some queries.
     #[prost(bool, tag="17")]
     pub setup_watermark_table_on_destination: bool,
+    /// snowflake storage integration used with an s3:// staging_path, overrides
+    /// the one on the destination peer.
+    #[prost(string, tag="18")]
+    pub staging_integration: ::prost::alloc::string::String,
+    /// the destination table gets its statistics refreshed after a batch of
+    /// partitions when at least this many rows were loaded since they were last
+    /// refreshed, 0 leaves them to the destination.
+    #[prost(uint32, tag="19")]
+    pub refresh_statistics_threshold: u32,
+    /// the metadata columns of the destination table of a cdc mirror, which
+    /// aren't copied from the source.
+    #[prost(string, tag="20")]
+    pub soft_delete_col_name: ::prost::alloc::string::String,
+    #[prost(string, tag="21")]
+    pub synced_at_col_name: ::prost::alloc::string::String,
+    /// runs the mirror on this cron schedule in UTC, instead of waiting
+    /// wait_between_batches_seconds after each run.
+    #[prost(string, tag="22")]
+    pub cron_schedule: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -463,6 +591,68 @@ pub struct ReplayTableSchemaDeltaInput {
     #[prost(message, repeated, tag="2")]
     pub table_schema_deltas: ::prost::alloc::vec::Vec<TableSchemaDelta>,
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TablePausePolicy {
+    /// changes keep landing in the raw table and are normalized on resume.
+    Buffer = 0,
+    /// changes are dropped, the destination table misses them.
+    Skip = 1,
+}
+impl TablePausePolicy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TablePausePolicy::Buffer => "TABLE_PAUSE_POLICY_BUFFER",
+            TablePausePolicy::Skip => "TABLE_PAUSE_POLICY_SKIP",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TABLE_PAUSE_POLICY_BUFFER" => Some(Self::Buffer),
+            "TABLE_PAUSE_POLICY_SKIP" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OrderingKey {
+    /// the destination's default, usually the destination table name.
+    Unspecified = 0,
+    PrimaryKey = 1,
+    Table = 2,
+    /// the partition_key column of each table mapping.
+    PartitionKey = 3,
+}
+impl OrderingKey {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OrderingKey::Unspecified => "ORDERING_KEY_UNSPECIFIED",
+            OrderingKey::PrimaryKey => "ORDERING_KEY_PRIMARY_KEY",
+            OrderingKey::Table => "ORDERING_KEY_TABLE",
+            OrderingKey::PartitionKey => "ORDERING_KEY_PARTITION_KEY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ORDERING_KEY_UNSPECIFIED" => Some(Self::Unspecified),
+            "ORDERING_KEY_PRIMARY_KEY" => Some(Self::PrimaryKey),
+            "ORDERING_KEY_TABLE" => Some(Self::Table),
+            "ORDERING_KEY_PARTITION_KEY" => Some(Self::PartitionKey),
+            _ => None,
+        }
+    }
+}
 /// protos for qrep
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
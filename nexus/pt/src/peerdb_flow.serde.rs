@@ -1,4 +1,122 @@
 // @generated
+impl serde::Serialize for ApplyWindow {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_minute != 0 {
+            len += 1;
+        }
+        if self.end_minute != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_flow.ApplyWindow", len)?;
+        if self.start_minute != 0 {
+            struct_ser.serialize_field("startMinute", &self.start_minute)?;
+        }
+        if self.end_minute != 0 {
+            struct_ser.serialize_field("endMinute", &self.end_minute)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ApplyWindow {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_minute",
+            "startMinute",
+            "end_minute",
+            "endMinute",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartMinute,
+            EndMinute,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startMinute" | "start_minute" => Ok(GeneratedField::StartMinute),
+                            "endMinute" | "end_minute" => Ok(GeneratedField::EndMinute),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ApplyWindow;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_flow.ApplyWindow")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ApplyWindow, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_minute__ = None;
+                let mut end_minute__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::StartMinute => {
+                            if start_minute__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startMinute"));
+                            }
+                            start_minute__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndMinute => {
+                            if end_minute__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endMinute"));
+                            }
+                            end_minute__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ApplyWindow {
+                    start_minute: start_minute__.unwrap_or_default(),
+                    end_minute: end_minute__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_flow.ApplyWindow", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CreateRawTableInput {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -991,6 +1109,42 @@ impl serde::Serialize for FlowConnectionConfigs {
         if self.push_parallelism != 0 {
             len += 1;
         }
+        if self.initial_copy_only {
+            len += 1;
+        }
+        if self.ordering_key != 0 {
+            len += 1;
+        }
+        if !self.staging_integration.is_empty() {
+            len += 1;
+        }
+        if !self.paused_tables.is_empty() {
+            len += 1;
+        }
+        if !self.resumed_tables.is_empty() {
+            len += 1;
+        }
+        if self.refresh_statistics_threshold != 0 {
+            len += 1;
+        }
+        if self.apply_window.is_some() {
+            len += 1;
+        }
+        if self.track_latency {
+            len += 1;
+        }
+        if !self.soft_delete_col_name.is_empty() {
+            len += 1;
+        }
+        if !self.synced_at_col_name.is_empty() {
+            len += 1;
+        }
+        if !self.schema_mappings.is_empty() {
+            len += 1;
+        }
+        if self.schema_refresh_interval_seconds != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_flow.FlowConnectionConfigs", len)?;
         if let Some(v) = self.source.as_ref() {
             struct_ser.serialize_field("source", v)?;
@@ -1062,6 +1216,44 @@ impl serde::Serialize for FlowConnectionConfigs {
         if self.push_parallelism != 0 {
             struct_ser.serialize_field("pushParallelism", ToString::to_string(&self.push_parallelism).as_str())?;
         }
+        if self.initial_copy_only {
+            struct_ser.serialize_field("initialCopyOnly", &self.initial_copy_only)?;
+        }
+        if self.ordering_key != 0 {
+            let v = OrderingKey::from_i32(self.ordering_key)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.ordering_key)))?;
+            struct_ser.serialize_field("orderingKey", &v)?;
+        }
+        if !self.staging_integration.is_empty() {
+            struct_ser.serialize_field("stagingIntegration", &self.staging_integration)?;
+        }
+        if !self.paused_tables.is_empty() {
+            struct_ser.serialize_field("pausedTables", &self.paused_tables)?;
+        }
+        if !self.resumed_tables.is_empty() {
+            struct_ser.serialize_field("resumedTables", &self.resumed_tables)?;
+        }
+        if self.refresh_statistics_threshold != 0 {
+            struct_ser.serialize_field("refreshStatisticsThreshold", &self.refresh_statistics_threshold)?;
+        }
+        if let Some(v) = self.apply_window.as_ref() {
+            struct_ser.serialize_field("applyWindow", v)?;
+        }
+        if self.track_latency {
+            struct_ser.serialize_field("trackLatency", &self.track_latency)?;
+        }
+        if !self.soft_delete_col_name.is_empty() {
+            struct_ser.serialize_field("softDeleteColName", &self.soft_delete_col_name)?;
+        }
+        if !self.synced_at_col_name.is_empty() {
+            struct_ser.serialize_field("syncedAtColName", &self.synced_at_col_name)?;
+        }
+        if !self.schema_mappings.is_empty() {
+            struct_ser.serialize_field("schemaMappings", &self.schema_mappings)?;
+        }
+        if self.schema_refresh_interval_seconds != 0 {
+            struct_ser.serialize_field("schemaRefreshIntervalSeconds", &self.schema_refresh_interval_seconds)?;
+        }
         struct_ser.end()
     }
 }
@@ -1114,6 +1306,30 @@ impl<'de> serde::Deserialize<'de> for FlowConnectionConfigs {
             "pushBatchSize",
             "push_parallelism",
             "pushParallelism",
+            "initial_copy_only",
+            "initialCopyOnly",
+            "ordering_key",
+            "orderingKey",
+            "staging_integration",
+            "stagingIntegration",
+            "paused_tables",
+            "pausedTables",
+            "resumed_tables",
+            "resumedTables",
+            "refresh_statistics_threshold",
+            "refreshStatisticsThreshold",
+            "apply_window",
+            "applyWindow",
+            "track_latency",
+            "trackLatency",
+            "soft_delete_col_name",
+            "softDeleteColName",
+            "synced_at_col_name",
+            "syncedAtColName",
+            "schema_mappings",
+            "schemaMappings",
+            "schema_refresh_interval_seconds",
+            "schemaRefreshIntervalSeconds",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1140,6 +1356,18 @@ impl<'de> serde::Deserialize<'de> for FlowConnectionConfigs {
             ReplicationSlotName,
             PushBatchSize,
             PushParallelism,
+            InitialCopyOnly,
+            OrderingKey,
+            StagingIntegration,
+            PausedTables,
+            ResumedTables,
+            RefreshStatisticsThreshold,
+            ApplyWindow,
+            TrackLatency,
+            SoftDeleteColName,
+            SyncedAtColName,
+            SchemaMappings,
+            SchemaRefreshIntervalSeconds,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1184,6 +1412,18 @@ impl<'de> serde::Deserialize<'de> for FlowConnectionConfigs {
                             "replicationSlotName" | "replication_slot_name" => Ok(GeneratedField::ReplicationSlotName),
                             "pushBatchSize" | "push_batch_size" => Ok(GeneratedField::PushBatchSize),
                             "pushParallelism" | "push_parallelism" => Ok(GeneratedField::PushParallelism),
+                            "initialCopyOnly" | "initial_copy_only" => Ok(GeneratedField::InitialCopyOnly),
+                            "orderingKey" | "ordering_key" => Ok(GeneratedField::OrderingKey),
+                            "stagingIntegration" | "staging_integration" => Ok(GeneratedField::StagingIntegration),
+                            "pausedTables" | "paused_tables" => Ok(GeneratedField::PausedTables),
+                            "resumedTables" | "resumed_tables" => Ok(GeneratedField::ResumedTables),
+                            "refreshStatisticsThreshold" | "refresh_statistics_threshold" => Ok(GeneratedField::RefreshStatisticsThreshold),
+                            "applyWindow" | "apply_window" => Ok(GeneratedField::ApplyWindow),
+                            "trackLatency" | "track_latency" => Ok(GeneratedField::TrackLatency),
+                            "softDeleteColName" | "soft_delete_col_name" => Ok(GeneratedField::SoftDeleteColName),
+                            "syncedAtColName" | "synced_at_col_name" => Ok(GeneratedField::SyncedAtColName),
+                            "schemaMappings" | "schema_mappings" => Ok(GeneratedField::SchemaMappings),
+                            "schemaRefreshIntervalSeconds" | "schema_refresh_interval_seconds" => Ok(GeneratedField::SchemaRefreshIntervalSeconds),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1225,6 +1465,18 @@ impl<'de> serde::Deserialize<'de> for FlowConnectionConfigs {
                 let mut replication_slot_name__ = None;
                 let mut push_batch_size__ = None;
                 let mut push_parallelism__ = None;
+                let mut initial_copy_only__ = None;
+                let mut ordering_key__ = None;
+                let mut staging_integration__ = None;
+                let mut paused_tables__ = None;
+                let mut resumed_tables__ = None;
+                let mut refresh_statistics_threshold__ = None;
+                let mut apply_window__ = None;
+                let mut track_latency__ = None;
+                let mut soft_delete_col_name__ = None;
+                let mut synced_at_col_name__ = None;
+                let mut schema_mappings__ = None;
+                let mut schema_refresh_interval_seconds__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Source => {
@@ -1376,6 +1628,88 @@ impl<'de> serde::Deserialize<'de> for FlowConnectionConfigs {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::InitialCopyOnly => {
+                            if initial_copy_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialCopyOnly"));
+                            }
+                            initial_copy_only__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::OrderingKey => {
+                            if ordering_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderingKey"));
+                            }
+                            ordering_key__ = Some(map.next_value::<OrderingKey>()? as i32);
+                        }
+                        GeneratedField::StagingIntegration => {
+                            if staging_integration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stagingIntegration"));
+                            }
+                            staging_integration__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::PausedTables => {
+                            if paused_tables__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pausedTables"));
+                            }
+                            paused_tables__ = Some(
+                                map.next_value::<std::collections::HashMap<_, TablePausePolicy>>()?
+                                    .into_iter().map(|(k,v)| (k, v as i32)).collect()
+                            );
+                        }
+                        GeneratedField::ResumedTables => {
+                            if resumed_tables__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resumedTables"));
+                            }
+                            resumed_tables__ = Some(
+                                map.next_value::<std::collections::HashMap<_, ::pbjson::private::NumberDeserialize<i64>>>()?
+                                    .into_iter().map(|(k,v)| (k, v.0)).collect()
+                            );
+                        }
+                        GeneratedField::RefreshStatisticsThreshold => {
+                            if refresh_statistics_threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refreshStatisticsThreshold"));
+                            }
+                            refresh_statistics_threshold__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ApplyWindow => {
+                            if apply_window__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("applyWindow"));
+                            }
+                            apply_window__ = map.next_value()?;
+                        }
+                        GeneratedField::TrackLatency => {
+                            if track_latency__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trackLatency"));
+                            }
+                            track_latency__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SoftDeleteColName => {
+                            if soft_delete_col_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("softDeleteColName"));
+                            }
+                            soft_delete_col_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SyncedAtColName => {
+                            if synced_at_col_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("syncedAtColName"));
+                            }
+                            synced_at_col_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SchemaMappings => {
+                            if schema_mappings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schemaMappings"));
+                            }
+                            schema_mappings__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SchemaRefreshIntervalSeconds => {
+                            if schema_refresh_interval_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schemaRefreshIntervalSeconds"));
+                            }
+                            schema_refresh_interval_seconds__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1404,6 +1738,18 @@ impl<'de> serde::Deserialize<'de> for FlowConnectionConfigs {
                     replication_slot_name: replication_slot_name__.unwrap_or_default(),
                     push_batch_size: push_batch_size__.unwrap_or_default(),
                     push_parallelism: push_parallelism__.unwrap_or_default(),
+                    initial_copy_only: initial_copy_only__.unwrap_or_default(),
+                    ordering_key: ordering_key__.unwrap_or_default(),
+                    staging_integration: staging_integration__.unwrap_or_default(),
+                    paused_tables: paused_tables__.unwrap_or_default(),
+                    resumed_tables: resumed_tables__.unwrap_or_default(),
+                    refresh_statistics_threshold: refresh_statistics_threshold__.unwrap_or_default(),
+                    apply_window: apply_window__,
+                    track_latency: track_latency__.unwrap_or_default(),
+                    soft_delete_col_name: soft_delete_col_name__.unwrap_or_default(),
+                    synced_at_col_name: synced_at_col_name__.unwrap_or_default(),
+                    schema_mappings: schema_mappings__.unwrap_or_default(),
+                    schema_refresh_interval_seconds: schema_refresh_interval_seconds__.unwrap_or_default(),
                 })
             }
         }
@@ -2065,6 +2411,85 @@ impl<'de> serde::Deserialize<'de> for NormalizeFlowOptions {
         deserializer.deserialize_struct("peerdb_flow.NormalizeFlowOptions", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for OrderingKey {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "ORDERING_KEY_UNSPECIFIED",
+            Self::PrimaryKey => "ORDERING_KEY_PRIMARY_KEY",
+            Self::Table => "ORDERING_KEY_TABLE",
+            Self::PartitionKey => "ORDERING_KEY_PARTITION_KEY",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for OrderingKey {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ORDERING_KEY_UNSPECIFIED",
+            "ORDERING_KEY_PRIMARY_KEY",
+            "ORDERING_KEY_TABLE",
+            "ORDERING_KEY_PARTITION_KEY",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OrderingKey;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(OrderingKey::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(OrderingKey::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "ORDERING_KEY_UNSPECIFIED" => Ok(OrderingKey::Unspecified),
+                    "ORDERING_KEY_PRIMARY_KEY" => Ok(OrderingKey::PrimaryKey),
+                    "ORDERING_KEY_TABLE" => Ok(OrderingKey::Table),
+                    "ORDERING_KEY_PARTITION_KEY" => Ok(OrderingKey::PartitionKey),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for PartitionRange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2164,37 +2589,265 @@ impl<'de> serde::Deserialize<'de> for PartitionRange {
                             if range__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("intRange"));
                             }
-                            range__ = map.next_value::<::std::option::Option<_>>()?.map(partition_range::Range::IntRange)
-;
+                            range__ = map.next_value::<::std::option::Option<_>>()?.map(partition_range::Range::IntRange)
+;
+                        }
+                        GeneratedField::TimestampRange => {
+                            if range__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestampRange"));
+                            }
+                            range__ = map.next_value::<::std::option::Option<_>>()?.map(partition_range::Range::TimestampRange)
+;
+                        }
+                        GeneratedField::TidRange => {
+                            if range__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tidRange"));
+                            }
+                            range__ = map.next_value::<::std::option::Option<_>>()?.map(partition_range::Range::TidRange)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PartitionRange {
+                    range: range__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_flow.PartitionRange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PostgresTableIdentifier {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rel_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_flow.PostgresTableIdentifier", len)?;
+        if self.rel_id != 0 {
+            struct_ser.serialize_field("relId", &self.rel_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PostgresTableIdentifier {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rel_id",
+            "relId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RelId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "relId" | "rel_id" => Ok(GeneratedField::RelId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PostgresTableIdentifier;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_flow.PostgresTableIdentifier")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PostgresTableIdentifier, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rel_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::RelId => {
+                            if rel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("relId"));
+                            }
+                            rel_id__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PostgresTableIdentifier {
+                    rel_id: rel_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_flow.PostgresTableIdentifier", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PostgresType {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.enum_labels.is_empty() {
+            len += 1;
+        }
+        if !self.attributes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_flow.PostgresType", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.enum_labels.is_empty() {
+            struct_ser.serialize_field("enumLabels", &self.enum_labels)?;
+        }
+        if !self.attributes.is_empty() {
+            struct_ser.serialize_field("attributes", &self.attributes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PostgresType {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "enum_labels",
+            "enumLabels",
+            "attributes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            EnumLabels,
+            Attributes,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "enumLabels" | "enum_labels" => Ok(GeneratedField::EnumLabels),
+                            "attributes" => Ok(GeneratedField::Attributes),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PostgresType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_flow.PostgresType")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PostgresType, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut enum_labels__ = None;
+                let mut attributes__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
                         }
-                        GeneratedField::TimestampRange => {
-                            if range__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("timestampRange"));
+                        GeneratedField::EnumLabels => {
+                            if enum_labels__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("enumLabels"));
                             }
-                            range__ = map.next_value::<::std::option::Option<_>>()?.map(partition_range::Range::TimestampRange)
-;
+                            enum_labels__ = Some(map.next_value()?);
                         }
-                        GeneratedField::TidRange => {
-                            if range__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tidRange"));
+                        GeneratedField::Attributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attributes"));
                             }
-                            range__ = map.next_value::<::std::option::Option<_>>()?.map(partition_range::Range::TidRange)
-;
+                            attributes__ = Some(map.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PartitionRange {
-                    range: range__,
+                Ok(PostgresType {
+                    name: name__.unwrap_or_default(),
+                    enum_labels: enum_labels__.unwrap_or_default(),
+                    attributes: attributes__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_flow.PartitionRange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_flow.PostgresType", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PostgresTableIdentifier {
+impl serde::Serialize for PostgresTypeAttribute {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2202,30 +2855,37 @@ impl serde::Serialize for PostgresTableIdentifier {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rel_id != 0 {
+        if !self.name.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("peerdb_flow.PostgresTableIdentifier", len)?;
-        if self.rel_id != 0 {
-            struct_ser.serialize_field("relId", &self.rel_id)?;
+        if !self.r#type.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_flow.PostgresTypeAttribute", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.r#type.is_empty() {
+            struct_ser.serialize_field("type", &self.r#type)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PostgresTableIdentifier {
+impl<'de> serde::Deserialize<'de> for PostgresTypeAttribute {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rel_id",
-            "relId",
+            "name",
+            "type",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RelId,
+            Name,
+            Type,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2248,7 +2908,8 @@ impl<'de> serde::Deserialize<'de> for PostgresTableIdentifier {
                         E: serde::de::Error,
                     {
                         match value {
-                            "relId" | "rel_id" => Ok(GeneratedField::RelId),
+                            "name" => Ok(GeneratedField::Name),
+                            "type" => Ok(GeneratedField::Type),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2258,38 +2919,44 @@ impl<'de> serde::Deserialize<'de> for PostgresTableIdentifier {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PostgresTableIdentifier;
+            type Value = PostgresTypeAttribute;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct peerdb_flow.PostgresTableIdentifier")
+                formatter.write_str("struct peerdb_flow.PostgresTypeAttribute")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<PostgresTableIdentifier, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PostgresTypeAttribute, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut rel_id__ = None;
+                let mut name__ = None;
+                let mut r#type__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::RelId => {
-                            if rel_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("relId"));
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
                             }
-                            rel_id__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PostgresTableIdentifier {
-                    rel_id: rel_id__.unwrap_or_default(),
+                Ok(PostgresTypeAttribute {
+                    name: name__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_flow.PostgresTableIdentifier", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_flow.PostgresTypeAttribute", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for QRepConfig {
@@ -2351,6 +3018,21 @@ impl serde::Serialize for QRepConfig {
         if self.setup_watermark_table_on_destination {
             len += 1;
         }
+        if !self.staging_integration.is_empty() {
+            len += 1;
+        }
+        if self.refresh_statistics_threshold != 0 {
+            len += 1;
+        }
+        if !self.soft_delete_col_name.is_empty() {
+            len += 1;
+        }
+        if !self.synced_at_col_name.is_empty() {
+            len += 1;
+        }
+        if !self.cron_schedule.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_flow.QRepConfig", len)?;
         if !self.flow_job_name.is_empty() {
             struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
@@ -2405,6 +3087,21 @@ impl serde::Serialize for QRepConfig {
         if self.setup_watermark_table_on_destination {
             struct_ser.serialize_field("setupWatermarkTableOnDestination", &self.setup_watermark_table_on_destination)?;
         }
+        if !self.staging_integration.is_empty() {
+            struct_ser.serialize_field("stagingIntegration", &self.staging_integration)?;
+        }
+        if self.refresh_statistics_threshold != 0 {
+            struct_ser.serialize_field("refreshStatisticsThreshold", &self.refresh_statistics_threshold)?;
+        }
+        if !self.soft_delete_col_name.is_empty() {
+            struct_ser.serialize_field("softDeleteColName", &self.soft_delete_col_name)?;
+        }
+        if !self.synced_at_col_name.is_empty() {
+            struct_ser.serialize_field("syncedAtColName", &self.synced_at_col_name)?;
+        }
+        if !self.cron_schedule.is_empty() {
+            struct_ser.serialize_field("cronSchedule", &self.cron_schedule)?;
+        }
         struct_ser.end()
     }
 }
@@ -2448,6 +3145,16 @@ impl<'de> serde::Deserialize<'de> for QRepConfig {
             "numRowsPerPartition",
             "setup_watermark_table_on_destination",
             "setupWatermarkTableOnDestination",
+            "staging_integration",
+            "stagingIntegration",
+            "refresh_statistics_threshold",
+            "refreshStatisticsThreshold",
+            "soft_delete_col_name",
+            "softDeleteColName",
+            "synced_at_col_name",
+            "syncedAtColName",
+            "cron_schedule",
+            "cronSchedule",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2469,6 +3176,11 @@ impl<'de> serde::Deserialize<'de> for QRepConfig {
             StagingPath,
             NumRowsPerPartition,
             SetupWatermarkTableOnDestination,
+            StagingIntegration,
+            RefreshStatisticsThreshold,
+            SoftDeleteColName,
+            SyncedAtColName,
+            CronSchedule,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2508,6 +3220,11 @@ impl<'de> serde::Deserialize<'de> for QRepConfig {
                             "stagingPath" | "staging_path" => Ok(GeneratedField::StagingPath),
                             "numRowsPerPartition" | "num_rows_per_partition" => Ok(GeneratedField::NumRowsPerPartition),
                             "setupWatermarkTableOnDestination" | "setup_watermark_table_on_destination" => Ok(GeneratedField::SetupWatermarkTableOnDestination),
+                            "stagingIntegration" | "staging_integration" => Ok(GeneratedField::StagingIntegration),
+                            "refreshStatisticsThreshold" | "refresh_statistics_threshold" => Ok(GeneratedField::RefreshStatisticsThreshold),
+                            "softDeleteColName" | "soft_delete_col_name" => Ok(GeneratedField::SoftDeleteColName),
+                            "syncedAtColName" | "synced_at_col_name" => Ok(GeneratedField::SyncedAtColName),
+                            "cronSchedule" | "cron_schedule" => Ok(GeneratedField::CronSchedule),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2544,6 +3261,11 @@ impl<'de> serde::Deserialize<'de> for QRepConfig {
                 let mut staging_path__ = None;
                 let mut num_rows_per_partition__ = None;
                 let mut setup_watermark_table_on_destination__ = None;
+                let mut staging_integration__ = None;
+                let mut refresh_statistics_threshold__ = None;
+                let mut soft_delete_col_name__ = None;
+                let mut synced_at_col_name__ = None;
+                let mut cron_schedule__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::FlowJobName => {
@@ -2658,6 +3380,38 @@ impl<'de> serde::Deserialize<'de> for QRepConfig {
                             }
                             setup_watermark_table_on_destination__ = Some(map.next_value()?);
                         }
+                        GeneratedField::StagingIntegration => {
+                            if staging_integration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stagingIntegration"));
+                            }
+                            staging_integration__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::RefreshStatisticsThreshold => {
+                            if refresh_statistics_threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refreshStatisticsThreshold"));
+                            }
+                            refresh_statistics_threshold__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SoftDeleteColName => {
+                            if soft_delete_col_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("softDeleteColName"));
+                            }
+                            soft_delete_col_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SyncedAtColName => {
+                            if synced_at_col_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("syncedAtColName"));
+                            }
+                            synced_at_col_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::CronSchedule => {
+                            if cron_schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cronSchedule"));
+                            }
+                            cron_schedule__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2681,6 +3435,11 @@ impl<'de> serde::Deserialize<'de> for QRepConfig {
                     staging_path: staging_path__.unwrap_or_default(),
                     num_rows_per_partition: num_rows_per_partition__.unwrap_or_default(),
                     setup_watermark_table_on_destination: setup_watermark_table_on_destination__.unwrap_or_default(),
+                    staging_integration: staging_integration__.unwrap_or_default(),
+                    refresh_statistics_threshold: refresh_statistics_threshold__.unwrap_or_default(),
+                    soft_delete_col_name: soft_delete_col_name__.unwrap_or_default(),
+                    synced_at_col_name: synced_at_col_name__.unwrap_or_default(),
+                    cron_schedule: cron_schedule__.unwrap_or_default(),
                 })
             }
         }
@@ -3674,6 +4433,138 @@ impl<'de> serde::Deserialize<'de> for ReplayTableSchemaDeltaInput {
         deserializer.deserialize_struct("peerdb_flow.ReplayTableSchemaDeltaInput", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SchemaMapping {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.source_schema.is_empty() {
+            len += 1;
+        }
+        if !self.destination_schema.is_empty() {
+            len += 1;
+        }
+        if !self.exclude_tables.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_flow.SchemaMapping", len)?;
+        if !self.source_schema.is_empty() {
+            struct_ser.serialize_field("sourceSchema", &self.source_schema)?;
+        }
+        if !self.destination_schema.is_empty() {
+            struct_ser.serialize_field("destinationSchema", &self.destination_schema)?;
+        }
+        if !self.exclude_tables.is_empty() {
+            struct_ser.serialize_field("excludeTables", &self.exclude_tables)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SchemaMapping {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "source_schema",
+            "sourceSchema",
+            "destination_schema",
+            "destinationSchema",
+            "exclude_tables",
+            "excludeTables",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SourceSchema,
+            DestinationSchema,
+            ExcludeTables,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sourceSchema" | "source_schema" => Ok(GeneratedField::SourceSchema),
+                            "destinationSchema" | "destination_schema" => Ok(GeneratedField::DestinationSchema),
+                            "excludeTables" | "exclude_tables" => Ok(GeneratedField::ExcludeTables),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SchemaMapping;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_flow.SchemaMapping")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SchemaMapping, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut source_schema__ = None;
+                let mut destination_schema__ = None;
+                let mut exclude_tables__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::SourceSchema => {
+                            if source_schema__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceSchema"));
+                            }
+                            source_schema__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::DestinationSchema => {
+                            if destination_schema__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationSchema"));
+                            }
+                            destination_schema__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ExcludeTables => {
+                            if exclude_tables__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("excludeTables"));
+                            }
+                            exclude_tables__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SchemaMapping {
+                    source_schema: source_schema__.unwrap_or_default(),
+                    destination_schema: destination_schema__.unwrap_or_default(),
+                    exclude_tables: exclude_tables__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_flow.SchemaMapping", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for SetupNormalizedTableBatchInput {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3688,6 +4579,12 @@ impl serde::Serialize for SetupNormalizedTableBatchInput {
         if !self.table_name_schema_mapping.is_empty() {
             len += 1;
         }
+        if !self.soft_delete_col_name.is_empty() {
+            len += 1;
+        }
+        if !self.synced_at_col_name.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_flow.SetupNormalizedTableBatchInput", len)?;
         if let Some(v) = self.peer_connection_config.as_ref() {
             struct_ser.serialize_field("peerConnectionConfig", v)?;
@@ -3695,6 +4592,12 @@ impl serde::Serialize for SetupNormalizedTableBatchInput {
         if !self.table_name_schema_mapping.is_empty() {
             struct_ser.serialize_field("tableNameSchemaMapping", &self.table_name_schema_mapping)?;
         }
+        if !self.soft_delete_col_name.is_empty() {
+            struct_ser.serialize_field("softDeleteColName", &self.soft_delete_col_name)?;
+        }
+        if !self.synced_at_col_name.is_empty() {
+            struct_ser.serialize_field("syncedAtColName", &self.synced_at_col_name)?;
+        }
         struct_ser.end()
     }
 }
@@ -3709,12 +4612,18 @@ impl<'de> serde::Deserialize<'de> for SetupNormalizedTableBatchInput {
             "peerConnectionConfig",
             "table_name_schema_mapping",
             "tableNameSchemaMapping",
+            "soft_delete_col_name",
+            "softDeleteColName",
+            "synced_at_col_name",
+            "syncedAtColName",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PeerConnectionConfig,
             TableNameSchemaMapping,
+            SoftDeleteColName,
+            SyncedAtColName,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3739,6 +4648,8 @@ impl<'de> serde::Deserialize<'de> for SetupNormalizedTableBatchInput {
                         match value {
                             "peerConnectionConfig" | "peer_connection_config" => Ok(GeneratedField::PeerConnectionConfig),
                             "tableNameSchemaMapping" | "table_name_schema_mapping" => Ok(GeneratedField::TableNameSchemaMapping),
+                            "softDeleteColName" | "soft_delete_col_name" => Ok(GeneratedField::SoftDeleteColName),
+                            "syncedAtColName" | "synced_at_col_name" => Ok(GeneratedField::SyncedAtColName),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3760,6 +4671,8 @@ impl<'de> serde::Deserialize<'de> for SetupNormalizedTableBatchInput {
             {
                 let mut peer_connection_config__ = None;
                 let mut table_name_schema_mapping__ = None;
+                let mut soft_delete_col_name__ = None;
+                let mut synced_at_col_name__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::PeerConnectionConfig => {
@@ -3776,6 +4689,18 @@ impl<'de> serde::Deserialize<'de> for SetupNormalizedTableBatchInput {
                                 map.next_value::<std::collections::HashMap<_, _>>()?
                             );
                         }
+                        GeneratedField::SoftDeleteColName => {
+                            if soft_delete_col_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("softDeleteColName"));
+                            }
+                            soft_delete_col_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SyncedAtColName => {
+                            if synced_at_col_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("syncedAtColName"));
+                            }
+                            synced_at_col_name__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -3784,6 +4709,8 @@ impl<'de> serde::Deserialize<'de> for SetupNormalizedTableBatchInput {
                 Ok(SetupNormalizedTableBatchInput {
                     peer_connection_config: peer_connection_config__,
                     table_name_schema_mapping: table_name_schema_mapping__.unwrap_or_default(),
+                    soft_delete_col_name: soft_delete_col_name__.unwrap_or_default(),
+                    synced_at_col_name: synced_at_col_name__.unwrap_or_default(),
                 })
             }
         }
@@ -5170,6 +6097,9 @@ impl serde::Serialize for TableMapping {
         if !self.partition_key.is_empty() {
             len += 1;
         }
+        if !self.exclude.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_flow.TableMapping", len)?;
         if !self.source_table_identifier.is_empty() {
             struct_ser.serialize_field("sourceTableIdentifier", &self.source_table_identifier)?;
@@ -5180,6 +6110,9 @@ impl serde::Serialize for TableMapping {
         if !self.partition_key.is_empty() {
             struct_ser.serialize_field("partitionKey", &self.partition_key)?;
         }
+        if !self.exclude.is_empty() {
+            struct_ser.serialize_field("exclude", &self.exclude)?;
+        }
         struct_ser.end()
     }
 }
@@ -5196,6 +6129,7 @@ impl<'de> serde::Deserialize<'de> for TableMapping {
             "destinationTableIdentifier",
             "partition_key",
             "partitionKey",
+            "exclude",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5203,6 +6137,7 @@ impl<'de> serde::Deserialize<'de> for TableMapping {
             SourceTableIdentifier,
             DestinationTableIdentifier,
             PartitionKey,
+            Exclude,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5228,6 +6163,7 @@ impl<'de> serde::Deserialize<'de> for TableMapping {
                             "sourceTableIdentifier" | "source_table_identifier" => Ok(GeneratedField::SourceTableIdentifier),
                             "destinationTableIdentifier" | "destination_table_identifier" => Ok(GeneratedField::DestinationTableIdentifier),
                             "partitionKey" | "partition_key" => Ok(GeneratedField::PartitionKey),
+                            "exclude" => Ok(GeneratedField::Exclude),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5250,6 +6186,7 @@ impl<'de> serde::Deserialize<'de> for TableMapping {
                 let mut source_table_identifier__ = None;
                 let mut destination_table_identifier__ = None;
                 let mut partition_key__ = None;
+                let mut exclude__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::SourceTableIdentifier => {
@@ -5270,6 +6207,12 @@ impl<'de> serde::Deserialize<'de> for TableMapping {
                             }
                             partition_key__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Exclude => {
+                            if exclude__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("exclude"));
+                            }
+                            exclude__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -5279,6 +6222,7 @@ impl<'de> serde::Deserialize<'de> for TableMapping {
                     source_table_identifier: source_table_identifier__.unwrap_or_default(),
                     destination_table_identifier: destination_table_identifier__.unwrap_or_default(),
                     partition_key: partition_key__.unwrap_or_default(),
+                    exclude: exclude__.unwrap_or_default(),
                 })
             }
         }
@@ -5399,6 +6343,79 @@ impl<'de> serde::Deserialize<'de> for TableNameMapping {
         deserializer.deserialize_struct("peerdb_flow.TableNameMapping", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for TablePausePolicy {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Buffer => "TABLE_PAUSE_POLICY_BUFFER",
+            Self::Skip => "TABLE_PAUSE_POLICY_SKIP",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for TablePausePolicy {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "TABLE_PAUSE_POLICY_BUFFER",
+            "TABLE_PAUSE_POLICY_SKIP",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TablePausePolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(TablePausePolicy::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(TablePausePolicy::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "TABLE_PAUSE_POLICY_BUFFER" => Ok(TablePausePolicy::Buffer),
+                    "TABLE_PAUSE_POLICY_SKIP" => Ok(TablePausePolicy::Skip),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TableSchema {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -5419,6 +6436,9 @@ impl serde::Serialize for TableSchema {
         if self.is_replica_identity_full {
             len += 1;
         }
+        if !self.column_types.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_flow.TableSchema", len)?;
         if !self.table_identifier.is_empty() {
             struct_ser.serialize_field("tableIdentifier", &self.table_identifier)?;
@@ -5432,6 +6452,9 @@ impl serde::Serialize for TableSchema {
         if self.is_replica_identity_full {
             struct_ser.serialize_field("isReplicaIdentityFull", &self.is_replica_identity_full)?;
         }
+        if !self.column_types.is_empty() {
+            struct_ser.serialize_field("columnTypes", &self.column_types)?;
+        }
         struct_ser.end()
     }
 }
@@ -5449,6 +6472,8 @@ impl<'de> serde::Deserialize<'de> for TableSchema {
             "primaryKeyColumns",
             "is_replica_identity_full",
             "isReplicaIdentityFull",
+            "column_types",
+            "columnTypes",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5457,6 +6482,7 @@ impl<'de> serde::Deserialize<'de> for TableSchema {
             Columns,
             PrimaryKeyColumns,
             IsReplicaIdentityFull,
+            ColumnTypes,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5483,6 +6509,7 @@ impl<'de> serde::Deserialize<'de> for TableSchema {
                             "columns" => Ok(GeneratedField::Columns),
                             "primaryKeyColumns" | "primary_key_columns" => Ok(GeneratedField::PrimaryKeyColumns),
                             "isReplicaIdentityFull" | "is_replica_identity_full" => Ok(GeneratedField::IsReplicaIdentityFull),
+                            "columnTypes" | "column_types" => Ok(GeneratedField::ColumnTypes),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5506,6 +6533,7 @@ impl<'de> serde::Deserialize<'de> for TableSchema {
                 let mut columns__ = None;
                 let mut primary_key_columns__ = None;
                 let mut is_replica_identity_full__ = None;
+                let mut column_types__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TableIdentifier => {
@@ -5534,6 +6562,14 @@ impl<'de> serde::Deserialize<'de> for TableSchema {
                             }
                             is_replica_identity_full__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ColumnTypes => {
+                            if column_types__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("columnTypes"));
+                            }
+                            column_types__ = Some(
+                                map.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -5544,6 +6580,7 @@ impl<'de> serde::Deserialize<'de> for TableSchema {
                     columns: columns__.unwrap_or_default(),
                     primary_key_columns: primary_key_columns__.unwrap_or_default(),
                     is_replica_identity_full: is_replica_identity_full__.unwrap_or_default(),
+                    column_types: column_types__.unwrap_or_default(),
                 })
             }
         }
@@ -20,6 +20,10 @@ pub struct SnowflakeConfig {
     pub s3_integration: ::prost::alloc::string::String,
     #[prost(string, optional, tag="10")]
     pub password: ::core::option::Option<::prost::alloc::string::String>,
+    /// the s3:// url that the rows copied into the peer through nexus are staged
+    /// under before they are loaded, read through s3_integration when it is set.
+    #[prost(string, optional, tag="11")]
+    pub staging_path: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -77,6 +81,9 @@ pub struct PostgresConfig {
     /// this is used only in query replication mode right now.
     #[prost(string, tag="6")]
     pub transaction_snapshot: ::prost::alloc::string::String,
+    /// how money columns are replicated when this peer is the source.
+    #[prost(enumeration="MoneyMapping", tag="7")]
+    pub money_mapping: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -144,12 +151,81 @@ pub struct SqlServerConfig {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PineconeConfig {
+    #[prost(string, tag="1")]
+    pub api_key: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub environment: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub project_id: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub index_name: ::prost::alloc::string::String,
+    /// vectors are upserted into the default namespace if this is empty.
+    #[prost(string, tag="5")]
+    pub namespace: ::prost::alloc::string::String,
+    /// pgvector column holding the embedding of each row.
+    #[prost(string, tag="6")]
+    pub vector_column: ::prost::alloc::string::String,
+    /// columns stored as vector metadata, all other columns if this is empty.
+    #[prost(string, repeated, tag="7")]
+    pub metadata_columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WeaviateConfig {
+    /// e.g. https://my-cluster.weaviate.network
+    #[prost(string, tag="1")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub api_key: ::core::option::Option<::prost::alloc::string::String>,
+    /// class that mirrored rows are written to as objects.
+    #[prost(string, tag="3")]
+    pub class_name: ::prost::alloc::string::String,
+    /// column holding the vector of each row, weaviate vectorizes the object if this is empty.
+    #[prost(string, tag="4")]
+    pub vector_column: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileConfig {
+    /// local directory that partitions and batches are written to, or scanned
+    /// for files when the peer is used as a source.
+    #[prost(string, tag="1")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(enumeration="FileFormat", tag="2")]
+    pub format: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WebhookConfig {
+    /// endpoint that change batches are POSTed to as JSON.
+    #[prost(string, tag="1")]
+    pub url: ::prost::alloc::string::String,
+    /// when set, each request carries an X-PeerDB-Signature header with the
+    /// hex encoded HMAC-SHA256 of the body.
+    #[prost(string, optional, tag="2")]
+    pub signing_secret: ::core::option::Option<::prost::alloc::string::String>,
+    /// additional headers sent with every request, e.g. for authorization.
+    #[prost(map="string, string", tag="3")]
+    pub headers: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// failed deliveries are retried with exponential backoff, 5 if unset.
+    #[prost(uint32, tag="4")]
+    pub max_retries: u32,
+    #[prost(uint32, tag="5")]
+    pub timeout_seconds: u32,
+    /// number of batches that can be awaiting delivery before the mirror stops
+    /// pulling changes from the source.
+    #[prost(uint32, tag="6")]
+    pub max_inflight_batches: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Peer {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
     #[prost(enumeration="DbType", tag="2")]
     pub r#type: i32,
-    #[prost(oneof="peer::Config", tags="3, 4, 5, 6, 7, 8, 9, 10")]
+    #[prost(oneof="peer::Config", tags="3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14")]
     pub config: ::core::option::Option<peer::Config>,
 }
 /// Nested message and enum types in `Peer`.
@@ -173,6 +249,71 @@ pub mod peer {
         SqlserverConfig(super::SqlServerConfig),
         #[prost(message, tag="10")]
         EventhubGroupConfig(super::EventHubGroupConfig),
+        #[prost(message, tag="11")]
+        PineconeConfig(super::PineconeConfig),
+        #[prost(message, tag="12")]
+        WeaviateConfig(super::WeaviateConfig),
+        #[prost(message, tag="13")]
+        FileConfig(super::FileConfig),
+        #[prost(message, tag="14")]
+        WebhookConfig(super::WebhookConfig),
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MoneyMapping {
+    /// the value as rendered by the server, e.g. "$1,234.50".
+    String = 0,
+    Numeric = 1,
+    /// a numeric column and a <column>_currency string column with the symbol.
+    NumericWithCurrency = 2,
+}
+impl MoneyMapping {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MoneyMapping::String => "MONEY_MAPPING_STRING",
+            MoneyMapping::Numeric => "MONEY_MAPPING_NUMERIC",
+            MoneyMapping::NumericWithCurrency => "MONEY_MAPPING_NUMERIC_WITH_CURRENCY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MONEY_MAPPING_STRING" => Some(Self::String),
+            "MONEY_MAPPING_NUMERIC" => Some(Self::Numeric),
+            "MONEY_MAPPING_NUMERIC_WITH_CURRENCY" => Some(Self::NumericWithCurrency),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FileFormat {
+    Csv = 0,
+    Parquet = 1,
+}
+impl FileFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            FileFormat::Csv => "CSV",
+            FileFormat::Parquet => "PARQUET",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CSV" => Some(Self::Csv),
+            "PARQUET" => Some(Self::Parquet),
+            _ => None,
+        }
     }
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -186,6 +327,10 @@ pub enum DbType {
     S3 = 5,
     Sqlserver = 6,
     EventhubGroup = 7,
+    Pinecone = 8,
+    Weaviate = 9,
+    File = 10,
+    Webhook = 11,
 }
 impl DbType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -202,6 +347,10 @@ impl DbType {
             DbType::S3 => "S3",
             DbType::Sqlserver => "SQLSERVER",
             DbType::EventhubGroup => "EVENTHUB_GROUP",
+            DbType::Pinecone => "PINECONE",
+            DbType::Weaviate => "WEAVIATE",
+            DbType::File => "FILE",
+            DbType::Webhook => "WEBHOOK",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -215,6 +364,10 @@ impl DbType {
             "S3" => Some(Self::S3),
             "SQLSERVER" => Some(Self::Sqlserver),
             "EVENTHUB_GROUP" => Some(Self::EventhubGroup),
+            "PINECONE" => Some(Self::Pinecone),
+            "WEAVIATE" => Some(Self::Weaviate),
+            "FILE" => Some(Self::File),
+            "WEBHOOK" => Some(Self::Webhook),
             _ => None,
         }
     }
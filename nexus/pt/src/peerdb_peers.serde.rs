@@ -290,6 +290,10 @@ impl serde::Serialize for DbType {
             Self::S3 => "S3",
             Self::Sqlserver => "SQLSERVER",
             Self::EventhubGroup => "EVENTHUB_GROUP",
+            Self::Pinecone => "PINECONE",
+            Self::Weaviate => "WEAVIATE",
+            Self::File => "FILE",
+            Self::Webhook => "WEBHOOK",
         };
         serializer.serialize_str(variant)
     }
@@ -309,6 +313,10 @@ impl<'de> serde::Deserialize<'de> for DbType {
             "S3",
             "SQLSERVER",
             "EVENTHUB_GROUP",
+            "PINECONE",
+            "WEAVIATE",
+            "FILE",
+            "WEBHOOK",
         ];
 
         struct GeneratedVisitor;
@@ -359,6 +367,10 @@ impl<'de> serde::Deserialize<'de> for DbType {
                     "S3" => Ok(DbType::S3),
                     "SQLSERVER" => Ok(DbType::Sqlserver),
                     "EVENTHUB_GROUP" => Ok(DbType::EventhubGroup),
+                    "PINECONE" => Ok(DbType::Pinecone),
+                    "WEAVIATE" => Ok(DbType::Weaviate),
+                    "FILE" => Ok(DbType::File),
+                    "WEBHOOK" => Ok(DbType::Webhook),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -705,6 +717,269 @@ impl<'de> serde::Deserialize<'de> for EventHubGroupConfig {
         deserializer.deserialize_struct("peerdb_peers.EventHubGroupConfig", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for FileConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.path.is_empty() {
+            len += 1;
+        }
+        if self.format != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_peers.FileConfig", len)?;
+        if !self.path.is_empty() {
+            struct_ser.serialize_field("path", &self.path)?;
+        }
+        if self.format != 0 {
+            let v = FileFormat::from_i32(self.format)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.format)))?;
+            struct_ser.serialize_field("format", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FileConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "path",
+            "format",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Path,
+            Format,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "path" => Ok(GeneratedField::Path),
+                            "format" => Ok(GeneratedField::Format),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FileConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_peers.FileConfig")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FileConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut path__ = None;
+                let mut format__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Path => {
+                            if path__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("path"));
+                            }
+                            path__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Format => {
+                            if format__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("format"));
+                            }
+                            format__ = Some(map.next_value::<FileFormat>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FileConfig {
+                    path: path__.unwrap_or_default(),
+                    format: format__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_peers.FileConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FileFormat {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Csv => "CSV",
+            Self::Parquet => "PARQUET",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for FileFormat {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "CSV",
+            "PARQUET",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FileFormat;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(FileFormat::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(FileFormat::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "CSV" => Ok(FileFormat::Csv),
+                    "PARQUET" => Ok(FileFormat::Parquet),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MoneyMapping {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::String => "MONEY_MAPPING_STRING",
+            Self::Numeric => "MONEY_MAPPING_NUMERIC",
+            Self::NumericWithCurrency => "MONEY_MAPPING_NUMERIC_WITH_CURRENCY",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for MoneyMapping {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "MONEY_MAPPING_STRING",
+            "MONEY_MAPPING_NUMERIC",
+            "MONEY_MAPPING_NUMERIC_WITH_CURRENCY",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MoneyMapping;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(MoneyMapping::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(MoneyMapping::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "MONEY_MAPPING_STRING" => Ok(MoneyMapping::String),
+                    "MONEY_MAPPING_NUMERIC" => Ok(MoneyMapping::Numeric),
+                    "MONEY_MAPPING_NUMERIC_WITH_CURRENCY" => Ok(MoneyMapping::NumericWithCurrency),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for MongoConfig {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -922,6 +1197,18 @@ impl serde::Serialize for Peer {
                 peer::Config::EventhubGroupConfig(v) => {
                     struct_ser.serialize_field("eventhubGroupConfig", v)?;
                 }
+                peer::Config::PineconeConfig(v) => {
+                    struct_ser.serialize_field("pineconeConfig", v)?;
+                }
+                peer::Config::WeaviateConfig(v) => {
+                    struct_ser.serialize_field("weaviateConfig", v)?;
+                }
+                peer::Config::FileConfig(v) => {
+                    struct_ser.serialize_field("fileConfig", v)?;
+                }
+                peer::Config::WebhookConfig(v) => {
+                    struct_ser.serialize_field("webhookConfig", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -952,6 +1239,14 @@ impl<'de> serde::Deserialize<'de> for Peer {
             "sqlserverConfig",
             "eventhub_group_config",
             "eventhubGroupConfig",
+            "pinecone_config",
+            "pineconeConfig",
+            "weaviate_config",
+            "weaviateConfig",
+            "file_config",
+            "fileConfig",
+            "webhook_config",
+            "webhookConfig",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -966,6 +1261,10 @@ impl<'de> serde::Deserialize<'de> for Peer {
             S3Config,
             SqlserverConfig,
             EventhubGroupConfig,
+            PineconeConfig,
+            WeaviateConfig,
+            FileConfig,
+            WebhookConfig,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -998,6 +1297,10 @@ impl<'de> serde::Deserialize<'de> for Peer {
                             "s3Config" | "s3_config" => Ok(GeneratedField::S3Config),
                             "sqlserverConfig" | "sqlserver_config" => Ok(GeneratedField::SqlserverConfig),
                             "eventhubGroupConfig" | "eventhub_group_config" => Ok(GeneratedField::EventhubGroupConfig),
+                            "pineconeConfig" | "pinecone_config" => Ok(GeneratedField::PineconeConfig),
+                            "weaviateConfig" | "weaviate_config" => Ok(GeneratedField::WeaviateConfig),
+                            "fileConfig" | "file_config" => Ok(GeneratedField::FileConfig),
+                            "webhookConfig" | "webhook_config" => Ok(GeneratedField::WebhookConfig),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1073,36 +1376,266 @@ impl<'de> serde::Deserialize<'de> for Peer {
                             if config__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("s3Config"));
                             }
-                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::S3Config)
-;
+                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::S3Config)
+;
+                        }
+                        GeneratedField::SqlserverConfig => {
+                            if config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sqlserverConfig"));
+                            }
+                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::SqlserverConfig)
+;
+                        }
+                        GeneratedField::EventhubGroupConfig => {
+                            if config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventhubGroupConfig"));
+                            }
+                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::EventhubGroupConfig)
+;
+                        }
+                        GeneratedField::PineconeConfig => {
+                            if config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pineconeConfig"));
+                            }
+                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::PineconeConfig)
+;
+                        }
+                        GeneratedField::WeaviateConfig => {
+                            if config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("weaviateConfig"));
+                            }
+                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::WeaviateConfig)
+;
+                        }
+                        GeneratedField::FileConfig => {
+                            if config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fileConfig"));
+                            }
+                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::FileConfig)
+;
+                        }
+                        GeneratedField::WebhookConfig => {
+                            if config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("webhookConfig"));
+                            }
+                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::WebhookConfig)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Peer {
+                    name: name__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                    config: config__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_peers.Peer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PineconeConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.api_key.is_empty() {
+            len += 1;
+        }
+        if !self.environment.is_empty() {
+            len += 1;
+        }
+        if !self.project_id.is_empty() {
+            len += 1;
+        }
+        if !self.index_name.is_empty() {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.vector_column.is_empty() {
+            len += 1;
+        }
+        if !self.metadata_columns.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_peers.PineconeConfig", len)?;
+        if !self.api_key.is_empty() {
+            struct_ser.serialize_field("apiKey", &self.api_key)?;
+        }
+        if !self.environment.is_empty() {
+            struct_ser.serialize_field("environment", &self.environment)?;
+        }
+        if !self.project_id.is_empty() {
+            struct_ser.serialize_field("projectId", &self.project_id)?;
+        }
+        if !self.index_name.is_empty() {
+            struct_ser.serialize_field("indexName", &self.index_name)?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.vector_column.is_empty() {
+            struct_ser.serialize_field("vectorColumn", &self.vector_column)?;
+        }
+        if !self.metadata_columns.is_empty() {
+            struct_ser.serialize_field("metadataColumns", &self.metadata_columns)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PineconeConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "api_key",
+            "apiKey",
+            "environment",
+            "project_id",
+            "projectId",
+            "index_name",
+            "indexName",
+            "namespace",
+            "vector_column",
+            "vectorColumn",
+            "metadata_columns",
+            "metadataColumns",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ApiKey,
+            Environment,
+            ProjectId,
+            IndexName,
+            Namespace,
+            VectorColumn,
+            MetadataColumns,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "apiKey" | "api_key" => Ok(GeneratedField::ApiKey),
+                            "environment" => Ok(GeneratedField::Environment),
+                            "projectId" | "project_id" => Ok(GeneratedField::ProjectId),
+                            "indexName" | "index_name" => Ok(GeneratedField::IndexName),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "vectorColumn" | "vector_column" => Ok(GeneratedField::VectorColumn),
+                            "metadataColumns" | "metadata_columns" => Ok(GeneratedField::MetadataColumns),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PineconeConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_peers.PineconeConfig")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PineconeConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut api_key__ = None;
+                let mut environment__ = None;
+                let mut project_id__ = None;
+                let mut index_name__ = None;
+                let mut namespace__ = None;
+                let mut vector_column__ = None;
+                let mut metadata_columns__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ApiKey => {
+                            if api_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("apiKey"));
+                            }
+                            api_key__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Environment => {
+                            if environment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("environment"));
+                            }
+                            environment__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ProjectId => {
+                            if project_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("projectId"));
+                            }
+                            project_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::IndexName => {
+                            if index_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("indexName"));
+                            }
+                            index_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map.next_value()?);
                         }
-                        GeneratedField::SqlserverConfig => {
-                            if config__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sqlserverConfig"));
+                        GeneratedField::VectorColumn => {
+                            if vector_column__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vectorColumn"));
                             }
-                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::SqlserverConfig)
-;
+                            vector_column__ = Some(map.next_value()?);
                         }
-                        GeneratedField::EventhubGroupConfig => {
-                            if config__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("eventhubGroupConfig"));
+                        GeneratedField::MetadataColumns => {
+                            if metadata_columns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadataColumns"));
                             }
-                            config__ = map.next_value::<::std::option::Option<_>>()?.map(peer::Config::EventhubGroupConfig)
-;
+                            metadata_columns__ = Some(map.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Peer {
-                    name: name__.unwrap_or_default(),
-                    r#type: r#type__.unwrap_or_default(),
-                    config: config__,
+                Ok(PineconeConfig {
+                    api_key: api_key__.unwrap_or_default(),
+                    environment: environment__.unwrap_or_default(),
+                    project_id: project_id__.unwrap_or_default(),
+                    index_name: index_name__.unwrap_or_default(),
+                    namespace: namespace__.unwrap_or_default(),
+                    vector_column: vector_column__.unwrap_or_default(),
+                    metadata_columns: metadata_columns__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_peers.Peer", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_peers.PineconeConfig", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for PostgresConfig {
@@ -1131,6 +1664,9 @@ impl serde::Serialize for PostgresConfig {
         if !self.transaction_snapshot.is_empty() {
             len += 1;
         }
+        if self.money_mapping != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_peers.PostgresConfig", len)?;
         if !self.host.is_empty() {
             struct_ser.serialize_field("host", &self.host)?;
@@ -1150,6 +1686,11 @@ impl serde::Serialize for PostgresConfig {
         if !self.transaction_snapshot.is_empty() {
             struct_ser.serialize_field("transactionSnapshot", &self.transaction_snapshot)?;
         }
+        if self.money_mapping != 0 {
+            let v = MoneyMapping::from_i32(self.money_mapping)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.money_mapping)))?;
+            struct_ser.serialize_field("moneyMapping", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1167,6 +1708,8 @@ impl<'de> serde::Deserialize<'de> for PostgresConfig {
             "database",
             "transaction_snapshot",
             "transactionSnapshot",
+            "money_mapping",
+            "moneyMapping",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1177,6 +1720,7 @@ impl<'de> serde::Deserialize<'de> for PostgresConfig {
             Password,
             Database,
             TransactionSnapshot,
+            MoneyMapping,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1205,6 +1749,7 @@ impl<'de> serde::Deserialize<'de> for PostgresConfig {
                             "password" => Ok(GeneratedField::Password),
                             "database" => Ok(GeneratedField::Database),
                             "transactionSnapshot" | "transaction_snapshot" => Ok(GeneratedField::TransactionSnapshot),
+                            "moneyMapping" | "money_mapping" => Ok(GeneratedField::MoneyMapping),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1230,6 +1775,7 @@ impl<'de> serde::Deserialize<'de> for PostgresConfig {
                 let mut password__ = None;
                 let mut database__ = None;
                 let mut transaction_snapshot__ = None;
+                let mut money_mapping__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Host => {
@@ -1270,6 +1816,12 @@ impl<'de> serde::Deserialize<'de> for PostgresConfig {
                             }
                             transaction_snapshot__ = Some(map.next_value()?);
                         }
+                        GeneratedField::MoneyMapping => {
+                            if money_mapping__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("moneyMapping"));
+                            }
+                            money_mapping__ = Some(map.next_value::<MoneyMapping>()? as i32);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1282,6 +1834,7 @@ impl<'de> serde::Deserialize<'de> for PostgresConfig {
                     password: password__.unwrap_or_default(),
                     database: database__.unwrap_or_default(),
                     transaction_snapshot: transaction_snapshot__.unwrap_or_default(),
+                    money_mapping: money_mapping__.unwrap_or_default(),
                 })
             }
         }
@@ -1524,6 +2077,9 @@ impl serde::Serialize for SnowflakeConfig {
         if self.password.is_some() {
             len += 1;
         }
+        if self.staging_path.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_peers.SnowflakeConfig", len)?;
         if !self.account_id.is_empty() {
             struct_ser.serialize_field("accountId", &self.account_id)?;
@@ -1552,6 +2108,9 @@ impl serde::Serialize for SnowflakeConfig {
         if let Some(v) = self.password.as_ref() {
             struct_ser.serialize_field("password", v)?;
         }
+        if let Some(v) = self.staging_path.as_ref() {
+            struct_ser.serialize_field("stagingPath", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1575,6 +2134,8 @@ impl<'de> serde::Deserialize<'de> for SnowflakeConfig {
             "s3_integration",
             "s3Integration",
             "password",
+            "staging_path",
+            "stagingPath",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1588,6 +2149,7 @@ impl<'de> serde::Deserialize<'de> for SnowflakeConfig {
             QueryTimeout,
             S3Integration,
             Password,
+            StagingPath,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1619,6 +2181,7 @@ impl<'de> serde::Deserialize<'de> for SnowflakeConfig {
                             "queryTimeout" | "query_timeout" => Ok(GeneratedField::QueryTimeout),
                             "s3Integration" | "s3_integration" => Ok(GeneratedField::S3Integration),
                             "password" => Ok(GeneratedField::Password),
+                            "stagingPath" | "staging_path" => Ok(GeneratedField::StagingPath),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1647,6 +2210,7 @@ impl<'de> serde::Deserialize<'de> for SnowflakeConfig {
                 let mut query_timeout__ = None;
                 let mut s3_integration__ = None;
                 let mut password__ = None;
+                let mut staging_path__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::AccountId => {
@@ -1705,6 +2269,12 @@ impl<'de> serde::Deserialize<'de> for SnowflakeConfig {
                             }
                             password__ = map.next_value()?;
                         }
+                        GeneratedField::StagingPath => {
+                            if staging_path__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stagingPath"));
+                            }
+                            staging_path__ = map.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1720,6 +2290,7 @@ impl<'de> serde::Deserialize<'de> for SnowflakeConfig {
                     query_timeout: query_timeout__.unwrap_or_default(),
                     s3_integration: s3_integration__.unwrap_or_default(),
                     password: password__,
+                    staging_path: staging_path__,
                 })
             }
         }
@@ -1891,3 +2462,344 @@ impl<'de> serde::Deserialize<'de> for SqlServerConfig {
         deserializer.deserialize_struct("peerdb_peers.SqlServerConfig", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for WeaviateConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.url.is_empty() {
+            len += 1;
+        }
+        if self.api_key.is_some() {
+            len += 1;
+        }
+        if !self.class_name.is_empty() {
+            len += 1;
+        }
+        if !self.vector_column.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_peers.WeaviateConfig", len)?;
+        if !self.url.is_empty() {
+            struct_ser.serialize_field("url", &self.url)?;
+        }
+        if let Some(v) = self.api_key.as_ref() {
+            struct_ser.serialize_field("apiKey", v)?;
+        }
+        if !self.class_name.is_empty() {
+            struct_ser.serialize_field("className", &self.class_name)?;
+        }
+        if !self.vector_column.is_empty() {
+            struct_ser.serialize_field("vectorColumn", &self.vector_column)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WeaviateConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "url",
+            "api_key",
+            "apiKey",
+            "class_name",
+            "className",
+            "vector_column",
+            "vectorColumn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Url,
+            ApiKey,
+            ClassName,
+            VectorColumn,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "url" => Ok(GeneratedField::Url),
+                            "apiKey" | "api_key" => Ok(GeneratedField::ApiKey),
+                            "className" | "class_name" => Ok(GeneratedField::ClassName),
+                            "vectorColumn" | "vector_column" => Ok(GeneratedField::VectorColumn),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WeaviateConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_peers.WeaviateConfig")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<WeaviateConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut url__ = None;
+                let mut api_key__ = None;
+                let mut class_name__ = None;
+                let mut vector_column__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Url => {
+                            if url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("url"));
+                            }
+                            url__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ApiKey => {
+                            if api_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("apiKey"));
+                            }
+                            api_key__ = map.next_value()?;
+                        }
+                        GeneratedField::ClassName => {
+                            if class_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("className"));
+                            }
+                            class_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::VectorColumn => {
+                            if vector_column__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vectorColumn"));
+                            }
+                            vector_column__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(WeaviateConfig {
+                    url: url__.unwrap_or_default(),
+                    api_key: api_key__,
+                    class_name: class_name__.unwrap_or_default(),
+                    vector_column: vector_column__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_peers.WeaviateConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WebhookConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.url.is_empty() {
+            len += 1;
+        }
+        if self.signing_secret.is_some() {
+            len += 1;
+        }
+        if !self.headers.is_empty() {
+            len += 1;
+        }
+        if self.max_retries != 0 {
+            len += 1;
+        }
+        if self.timeout_seconds != 0 {
+            len += 1;
+        }
+        if self.max_inflight_batches != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_peers.WebhookConfig", len)?;
+        if !self.url.is_empty() {
+            struct_ser.serialize_field("url", &self.url)?;
+        }
+        if let Some(v) = self.signing_secret.as_ref() {
+            struct_ser.serialize_field("signingSecret", v)?;
+        }
+        if !self.headers.is_empty() {
+            struct_ser.serialize_field("headers", &self.headers)?;
+        }
+        if self.max_retries != 0 {
+            struct_ser.serialize_field("maxRetries", &self.max_retries)?;
+        }
+        if self.timeout_seconds != 0 {
+            struct_ser.serialize_field("timeoutSeconds", &self.timeout_seconds)?;
+        }
+        if self.max_inflight_batches != 0 {
+            struct_ser.serialize_field("maxInflightBatches", &self.max_inflight_batches)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WebhookConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "url",
+            "signing_secret",
+            "signingSecret",
+            "headers",
+            "max_retries",
+            "maxRetries",
+            "timeout_seconds",
+            "timeoutSeconds",
+            "max_inflight_batches",
+            "maxInflightBatches",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Url,
+            SigningSecret,
+            Headers,
+            MaxRetries,
+            TimeoutSeconds,
+            MaxInflightBatches,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "url" => Ok(GeneratedField::Url),
+                            "signingSecret" | "signing_secret" => Ok(GeneratedField::SigningSecret),
+                            "headers" => Ok(GeneratedField::Headers),
+                            "maxRetries" | "max_retries" => Ok(GeneratedField::MaxRetries),
+                            "timeoutSeconds" | "timeout_seconds" => Ok(GeneratedField::TimeoutSeconds),
+                            "maxInflightBatches" | "max_inflight_batches" => Ok(GeneratedField::MaxInflightBatches),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WebhookConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_peers.WebhookConfig")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<WebhookConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut url__ = None;
+                let mut signing_secret__ = None;
+                let mut headers__ = None;
+                let mut max_retries__ = None;
+                let mut timeout_seconds__ = None;
+                let mut max_inflight_batches__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Url => {
+                            if url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("url"));
+                            }
+                            url__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SigningSecret => {
+                            if signing_secret__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signingSecret"));
+                            }
+                            signing_secret__ = map.next_value()?;
+                        }
+                        GeneratedField::Headers => {
+                            if headers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("headers"));
+                            }
+                            headers__ = Some(
+                                map.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::MaxRetries => {
+                            if max_retries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxRetries"));
+                            }
+                            max_retries__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TimeoutSeconds => {
+                            if timeout_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutSeconds"));
+                            }
+                            timeout_seconds__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxInflightBatches => {
+                            if max_inflight_batches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxInflightBatches"));
+                            }
+                            max_inflight_batches__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(WebhookConfig {
+                    url: url__.unwrap_or_default(),
+                    signing_secret: signing_secret__,
+                    headers: headers__.unwrap_or_default(),
+                    max_retries: max_retries__.unwrap_or_default(),
+                    timeout_seconds: timeout_seconds__.unwrap_or_default(),
+                    max_inflight_batches: max_inflight_batches__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_peers.WebhookConfig", FIELDS, GeneratedVisitor)
+    }
+}
@@ -6,6 +6,10 @@ pub struct CreateCdcFlowRequest {
     pub connection_configs: ::core::option::Option<super::peerdb_flow::FlowConnectionConfigs>,
     #[prost(bool, tag="2")]
     pub create_catalog_entry: bool,
+    /// a retry with the key of an earlier request gets the response of that
+    /// request instead of creating the flow again.
+    #[prost(string, tag="3")]
+    pub idempotency_key: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -20,6 +24,9 @@ pub struct CreateQRepFlowRequest {
     pub qrep_config: ::core::option::Option<super::peerdb_flow::QRepConfig>,
     #[prost(bool, tag="2")]
     pub create_catalog_entry: bool,
+    /// see CreateCDCFlowRequest.
+    #[prost(string, tag="3")]
+    pub idempotency_key: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -49,6 +56,123 @@ pub struct ShutdownResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PauseFlowRequest {
+    #[prost(string, tag="1")]
+    pub workflow_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub flow_job_name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PauseFlowResponse {
+    #[prost(bool, tag="1")]
+    pub ok: bool,
+    #[prost(string, tag="2")]
+    pub error_message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResumeFlowRequest {
+    #[prost(string, tag="1")]
+    pub workflow_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub flow_job_name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResumeFlowResponse {
+    #[prost(bool, tag="1")]
+    pub ok: bool,
+    #[prost(string, tag="2")]
+    pub error_message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddTablesToFlowRequest {
+    #[prost(string, tag="1")]
+    pub workflow_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub flow_job_name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="3")]
+    pub table_mappings: ::prost::alloc::vec::Vec<super::peerdb_flow::TableMapping>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddTablesToFlowResponse {
+    #[prost(bool, tag="1")]
+    pub ok: bool,
+    #[prost(string, tag="2")]
+    pub error_message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PauseTableRequest {
+    #[prost(string, tag="1")]
+    pub workflow_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub flow_job_name: ::prost::alloc::string::String,
+    /// source or destination table of one of the flow's table mappings.
+    #[prost(string, tag="3")]
+    pub table_identifier: ::prost::alloc::string::String,
+    /// false resumes the table.
+    #[prost(bool, tag="4")]
+    pub paused: bool,
+    #[prost(enumeration="super::peerdb_flow::TablePausePolicy", tag="5")]
+    pub policy: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PauseTableResponse {
+    #[prost(bool, tag="1")]
+    pub ok: bool,
+    #[prost(string, tag="2")]
+    pub error_message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveTableFromFlowRequest {
+    #[prost(string, tag="1")]
+    pub workflow_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub flow_job_name: ::prost::alloc::string::String,
+    /// source table of one of the flow's table mappings.
+    #[prost(string, tag="3")]
+    pub source_table_identifier: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveTableFromFlowResponse {
+    #[prost(bool, tag="1")]
+    pub ok: bool,
+    #[prost(string, tag="2")]
+    pub error_message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResyncMirrorRequest {
+    #[prost(string, tag="1")]
+    pub workflow_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub flow_job_name: ::prost::alloc::string::String,
+    /// source table of one of the flow's table mappings, every table of the flow
+    /// is resynced when empty.
+    #[prost(string, tag="3")]
+    pub source_table_identifier: ::prost::alloc::string::String,
+    /// truncate the destination tables and copy into them in place, instead of
+    /// copying into new tables that replace them.
+    #[prost(bool, tag="4")]
+    pub truncate_destination_tables: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResyncMirrorResponse {
+    #[prost(bool, tag="1")]
+    pub ok: bool,
+    #[prost(string, tag="2")]
+    pub error_message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatePeerRequest {
     #[prost(message, optional, tag="1")]
     pub peer: ::core::option::Option<super::peerdb_peers::Peer>,
@@ -58,6 +182,24 @@ pub struct ValidatePeerRequest {
 pub struct CreatePeerRequest {
     #[prost(message, optional, tag="1")]
     pub peer: ::core::option::Option<super::peerdb_peers::Peer>,
+    /// see CreateCDCFlowRequest.
+    #[prost(string, tag="2")]
+    pub idempotency_key: ::prost::alloc::string::String,
+}
+/// a check made on a peer while validating it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PeerValidationCheck {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub passed: bool,
+    #[prost(string, tag="3")]
+    pub message: ::prost::alloc::string::String,
+    /// whether a failed check makes the peer invalid, checks of what only
+    /// sources of cdc mirrors need, like the wal_level of postgres, don't.
+    #[prost(bool, tag="4")]
+    pub required: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -66,6 +208,8 @@ pub struct ValidatePeerResponse {
     pub status: i32,
     #[prost(string, tag="2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="3")]
+    pub checks: ::prost::alloc::vec::Vec<PeerValidationCheck>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -75,6 +219,29 @@ pub struct CreatePeerResponse {
     #[prost(string, tag="2")]
     pub message: ::prost::alloc::string::String,
 }
+/// a new config of an existing peer, usually with rotated credentials. the
+/// running cdc mirrors of the peer reconnect with it at their next batch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePeerRequest {
+    #[prost(message, optional, tag="1")]
+    pub peer: ::core::option::Option<super::peerdb_peers::Peer>,
+    /// the caller already saved the config in the catalog, e.g. nexus, which
+    /// encrypts it, so only the mirrors of the peer are updated.
+    #[prost(bool, tag="2")]
+    pub catalog_updated: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePeerResponse {
+    #[prost(bool, tag="1")]
+    pub ok: bool,
+    #[prost(string, tag="2")]
+    pub error_message: ::prost::alloc::string::String,
+    /// the cdc mirrors told to reconnect with the new config.
+    #[prost(string, repeated, tag="3")]
+    pub flow_job_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MirrorStatusRequest {
@@ -116,6 +283,17 @@ pub struct CdcSyncStatus {
     pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag="5")]
     pub end_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// seconds between the source writing end_lsn and the batch finishing, unset
+    /// if no sample of the source's LSN is old enough to cover end_lsn.
+    #[prost(double, optional, tag="6")]
+    pub lag_seconds: ::core::option::Option<f64>,
+    /// percentiles of the seconds between the changes of the batch being
+    /// committed at the source and being applied to the destination, unset if
+    /// the mirror doesn't track its latency.
+    #[prost(double, optional, tag="7")]
+    pub latency_p50_seconds: ::core::option::Option<f64>,
+    #[prost(double, optional, tag="8")]
+    pub latency_p99_seconds: ::core::option::Option<f64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -132,6 +310,21 @@ pub struct CdcMirrorStatus {
     pub snapshot_status: ::core::option::Option<SnapshotStatus>,
     #[prost(message, repeated, tag="3")]
     pub cdc_syncs: ::prost::alloc::vec::Vec<CdcSyncStatus>,
+    /// batches synced outside the apply window of the mirror and not normalized
+    /// yet, and the records in them.
+    #[prost(int64, tag="4")]
+    pub apply_backlog_batches: i64,
+    #[prost(int64, tag="5")]
+    pub apply_backlog_records: i64,
+    /// the latency of the most recent batch that measured it.
+    #[prost(double, optional, tag="6")]
+    pub latency_p50_seconds: ::core::option::Option<f64>,
+    #[prost(double, optional, tag="7")]
+    pub latency_p99_seconds: ::core::option::Option<f64>,
+    /// whether a snapshot-only mirror is done with its initial copy and has been
+    /// cleaned up.
+    #[prost(bool, tag="8")]
+    pub completed: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -154,6 +347,36 @@ pub mod mirror_status_response {
         CdcStatus(super::CdcMirrorStatus),
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RotateEncryptionKeyRequest {
+    /// the master key the configs in the catalog are encrypted with, and the one
+    /// they are rotated to, 32 bytes in base64 each.
+    #[prost(string, tag="1")]
+    pub old_key: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub new_key: ::prost::alloc::string::String,
+}
+/// the progress of a key rotation, sent as the configs of each table are rotated,
+/// and once more with the counts when every config is verified.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RotateEncryptionKeyProgress {
+    #[prost(string, tag="1")]
+    pub table: ::prost::alloc::string::String,
+    #[prost(int64, tag="2")]
+    pub done: i64,
+    #[prost(int64, tag="3")]
+    pub total: i64,
+    #[prost(int64, tag="4")]
+    pub rotated: i64,
+    #[prost(int64, tag="5")]
+    pub encrypted: i64,
+    #[prost(int64, tag="6")]
+    pub verified: i64,
+    #[prost(bool, tag="7")]
+    pub completed: bool,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ValidatePeerStatus {
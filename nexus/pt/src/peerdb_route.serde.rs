@@ -1,4 +1,249 @@
 // @generated
+impl serde::Serialize for AddTablesToFlowRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if !self.flow_job_name.is_empty() {
+            len += 1;
+        }
+        if !self.table_mappings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.AddTablesToFlowRequest", len)?;
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if !self.flow_job_name.is_empty() {
+            struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
+        }
+        if !self.table_mappings.is_empty() {
+            struct_ser.serialize_field("tableMappings", &self.table_mappings)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AddTablesToFlowRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_id",
+            "workflowId",
+            "flow_job_name",
+            "flowJobName",
+            "table_mappings",
+            "tableMappings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowId,
+            FlowJobName,
+            TableMappings,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
+                            "tableMappings" | "table_mappings" => Ok(GeneratedField::TableMappings),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AddTablesToFlowRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.AddTablesToFlowRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<AddTablesToFlowRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_id__ = None;
+                let mut flow_job_name__ = None;
+                let mut table_mappings__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::FlowJobName => {
+                            if flow_job_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flowJobName"));
+                            }
+                            flow_job_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TableMappings => {
+                            if table_mappings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tableMappings"));
+                            }
+                            table_mappings__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AddTablesToFlowRequest {
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    flow_job_name: flow_job_name__.unwrap_or_default(),
+                    table_mappings: table_mappings__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.AddTablesToFlowRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AddTablesToFlowResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ok {
+            len += 1;
+        }
+        if !self.error_message.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.AddTablesToFlowResponse", len)?;
+        if self.ok {
+            struct_ser.serialize_field("ok", &self.ok)?;
+        }
+        if !self.error_message.is_empty() {
+            struct_ser.serialize_field("errorMessage", &self.error_message)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AddTablesToFlowResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ok",
+            "error_message",
+            "errorMessage",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Ok,
+            ErrorMessage,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ok" => Ok(GeneratedField::Ok),
+                            "errorMessage" | "error_message" => Ok(GeneratedField::ErrorMessage),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AddTablesToFlowResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.AddTablesToFlowResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<AddTablesToFlowResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ok__ = None;
+                let mut error_message__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Ok => {
+                            if ok__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ok"));
+                            }
+                            ok__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ErrorMessage => {
+                            if error_message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorMessage"));
+                            }
+                            error_message__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AddTablesToFlowResponse {
+                    ok: ok__.unwrap_or_default(),
+                    error_message: error_message__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.AddTablesToFlowResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CdcMirrorStatus {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -16,6 +261,21 @@ impl serde::Serialize for CdcMirrorStatus {
         if !self.cdc_syncs.is_empty() {
             len += 1;
         }
+        if self.apply_backlog_batches != 0 {
+            len += 1;
+        }
+        if self.apply_backlog_records != 0 {
+            len += 1;
+        }
+        if self.latency_p50_seconds.is_some() {
+            len += 1;
+        }
+        if self.latency_p99_seconds.is_some() {
+            len += 1;
+        }
+        if self.completed {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_route.CDCMirrorStatus", len)?;
         if let Some(v) = self.config.as_ref() {
             struct_ser.serialize_field("config", v)?;
@@ -26,6 +286,21 @@ impl serde::Serialize for CdcMirrorStatus {
         if !self.cdc_syncs.is_empty() {
             struct_ser.serialize_field("cdcSyncs", &self.cdc_syncs)?;
         }
+        if self.apply_backlog_batches != 0 {
+            struct_ser.serialize_field("applyBacklogBatches", ToString::to_string(&self.apply_backlog_batches).as_str())?;
+        }
+        if self.apply_backlog_records != 0 {
+            struct_ser.serialize_field("applyBacklogRecords", ToString::to_string(&self.apply_backlog_records).as_str())?;
+        }
+        if let Some(v) = self.latency_p50_seconds.as_ref() {
+            struct_ser.serialize_field("latencyP50Seconds", v)?;
+        }
+        if let Some(v) = self.latency_p99_seconds.as_ref() {
+            struct_ser.serialize_field("latencyP99Seconds", v)?;
+        }
+        if self.completed {
+            struct_ser.serialize_field("completed", &self.completed)?;
+        }
         struct_ser.end()
     }
 }
@@ -41,6 +316,15 @@ impl<'de> serde::Deserialize<'de> for CdcMirrorStatus {
             "snapshotStatus",
             "cdc_syncs",
             "cdcSyncs",
+            "apply_backlog_batches",
+            "applyBacklogBatches",
+            "apply_backlog_records",
+            "applyBacklogRecords",
+            "latency_p50_seconds",
+            "latencyP50Seconds",
+            "latency_p99_seconds",
+            "latencyP99Seconds",
+            "completed",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -48,6 +332,11 @@ impl<'de> serde::Deserialize<'de> for CdcMirrorStatus {
             Config,
             SnapshotStatus,
             CdcSyncs,
+            ApplyBacklogBatches,
+            ApplyBacklogRecords,
+            LatencyP50Seconds,
+            LatencyP99Seconds,
+            Completed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -73,6 +362,11 @@ impl<'de> serde::Deserialize<'de> for CdcMirrorStatus {
                             "config" => Ok(GeneratedField::Config),
                             "snapshotStatus" | "snapshot_status" => Ok(GeneratedField::SnapshotStatus),
                             "cdcSyncs" | "cdc_syncs" => Ok(GeneratedField::CdcSyncs),
+                            "applyBacklogBatches" | "apply_backlog_batches" => Ok(GeneratedField::ApplyBacklogBatches),
+                            "applyBacklogRecords" | "apply_backlog_records" => Ok(GeneratedField::ApplyBacklogRecords),
+                            "latencyP50Seconds" | "latency_p50_seconds" => Ok(GeneratedField::LatencyP50Seconds),
+                            "latencyP99Seconds" | "latency_p99_seconds" => Ok(GeneratedField::LatencyP99Seconds),
+                            "completed" => Ok(GeneratedField::Completed),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -95,6 +389,11 @@ impl<'de> serde::Deserialize<'de> for CdcMirrorStatus {
                 let mut config__ = None;
                 let mut snapshot_status__ = None;
                 let mut cdc_syncs__ = None;
+                let mut apply_backlog_batches__ = None;
+                let mut apply_backlog_records__ = None;
+                let mut latency_p50_seconds__ = None;
+                let mut latency_p99_seconds__ = None;
+                let mut completed__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Config => {
@@ -115,6 +414,44 @@ impl<'de> serde::Deserialize<'de> for CdcMirrorStatus {
                             }
                             cdc_syncs__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ApplyBacklogBatches => {
+                            if apply_backlog_batches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("applyBacklogBatches"));
+                            }
+                            apply_backlog_batches__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ApplyBacklogRecords => {
+                            if apply_backlog_records__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("applyBacklogRecords"));
+                            }
+                            apply_backlog_records__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LatencyP50Seconds => {
+                            if latency_p50_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latencyP50Seconds"));
+                            }
+                            latency_p50_seconds__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::LatencyP99Seconds => {
+                            if latency_p99_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latencyP99Seconds"));
+                            }
+                            latency_p99_seconds__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Completed => {
+                            if completed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("completed"));
+                            }
+                            completed__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -124,6 +461,11 @@ impl<'de> serde::Deserialize<'de> for CdcMirrorStatus {
                     config: config__,
                     snapshot_status: snapshot_status__,
                     cdc_syncs: cdc_syncs__.unwrap_or_default(),
+                    apply_backlog_batches: apply_backlog_batches__.unwrap_or_default(),
+                    apply_backlog_records: apply_backlog_records__.unwrap_or_default(),
+                    latency_p50_seconds: latency_p50_seconds__,
+                    latency_p99_seconds: latency_p99_seconds__,
+                    completed: completed__.unwrap_or_default(),
                 })
             }
         }
@@ -153,6 +495,15 @@ impl serde::Serialize for CdcSyncStatus {
         if self.end_time.is_some() {
             len += 1;
         }
+        if self.lag_seconds.is_some() {
+            len += 1;
+        }
+        if self.latency_p50_seconds.is_some() {
+            len += 1;
+        }
+        if self.latency_p99_seconds.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_route.CDCSyncStatus", len)?;
         if self.start_lsn != 0 {
             struct_ser.serialize_field("startLsn", ToString::to_string(&self.start_lsn).as_str())?;
@@ -169,6 +520,15 @@ impl serde::Serialize for CdcSyncStatus {
         if let Some(v) = self.end_time.as_ref() {
             struct_ser.serialize_field("endTime", v)?;
         }
+        if let Some(v) = self.lag_seconds.as_ref() {
+            struct_ser.serialize_field("lagSeconds", v)?;
+        }
+        if let Some(v) = self.latency_p50_seconds.as_ref() {
+            struct_ser.serialize_field("latencyP50Seconds", v)?;
+        }
+        if let Some(v) = self.latency_p99_seconds.as_ref() {
+            struct_ser.serialize_field("latencyP99Seconds", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -189,6 +549,12 @@ impl<'de> serde::Deserialize<'de> for CdcSyncStatus {
             "startTime",
             "end_time",
             "endTime",
+            "lag_seconds",
+            "lagSeconds",
+            "latency_p50_seconds",
+            "latencyP50Seconds",
+            "latency_p99_seconds",
+            "latencyP99Seconds",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -198,6 +564,9 @@ impl<'de> serde::Deserialize<'de> for CdcSyncStatus {
             NumRows,
             StartTime,
             EndTime,
+            LagSeconds,
+            LatencyP50Seconds,
+            LatencyP99Seconds,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -225,6 +594,9 @@ impl<'de> serde::Deserialize<'de> for CdcSyncStatus {
                             "numRows" | "num_rows" => Ok(GeneratedField::NumRows),
                             "startTime" | "start_time" => Ok(GeneratedField::StartTime),
                             "endTime" | "end_time" => Ok(GeneratedField::EndTime),
+                            "lagSeconds" | "lag_seconds" => Ok(GeneratedField::LagSeconds),
+                            "latencyP50Seconds" | "latency_p50_seconds" => Ok(GeneratedField::LatencyP50Seconds),
+                            "latencyP99Seconds" | "latency_p99_seconds" => Ok(GeneratedField::LatencyP99Seconds),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -249,6 +621,9 @@ impl<'de> serde::Deserialize<'de> for CdcSyncStatus {
                 let mut num_rows__ = None;
                 let mut start_time__ = None;
                 let mut end_time__ = None;
+                let mut lag_seconds__ = None;
+                let mut latency_p50_seconds__ = None;
+                let mut latency_p99_seconds__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::StartLsn => {
@@ -287,6 +662,30 @@ impl<'de> serde::Deserialize<'de> for CdcSyncStatus {
                             }
                             end_time__ = map.next_value()?;
                         }
+                        GeneratedField::LagSeconds => {
+                            if lag_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lagSeconds"));
+                            }
+                            lag_seconds__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::LatencyP50Seconds => {
+                            if latency_p50_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latencyP50Seconds"));
+                            }
+                            latency_p50_seconds__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::LatencyP99Seconds => {
+                            if latency_p99_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latencyP99Seconds"));
+                            }
+                            latency_p99_seconds__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -298,6 +697,9 @@ impl<'de> serde::Deserialize<'de> for CdcSyncStatus {
                     num_rows: num_rows__.unwrap_or_default(),
                     start_time: start_time__,
                     end_time: end_time__,
+                    lag_seconds: lag_seconds__,
+                    latency_p50_seconds: latency_p50_seconds__,
+                    latency_p99_seconds: latency_p99_seconds__,
                 })
             }
         }
@@ -318,6 +720,9 @@ impl serde::Serialize for CreateCdcFlowRequest {
         if self.create_catalog_entry {
             len += 1;
         }
+        if !self.idempotency_key.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_route.CreateCDCFlowRequest", len)?;
         if let Some(v) = self.connection_configs.as_ref() {
             struct_ser.serialize_field("connectionConfigs", v)?;
@@ -325,6 +730,9 @@ impl serde::Serialize for CreateCdcFlowRequest {
         if self.create_catalog_entry {
             struct_ser.serialize_field("createCatalogEntry", &self.create_catalog_entry)?;
         }
+        if !self.idempotency_key.is_empty() {
+            struct_ser.serialize_field("idempotencyKey", &self.idempotency_key)?;
+        }
         struct_ser.end()
     }
 }
@@ -339,12 +747,15 @@ impl<'de> serde::Deserialize<'de> for CreateCdcFlowRequest {
             "connectionConfigs",
             "create_catalog_entry",
             "createCatalogEntry",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ConnectionConfigs,
             CreateCatalogEntry,
+            IdempotencyKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -369,6 +780,7 @@ impl<'de> serde::Deserialize<'de> for CreateCdcFlowRequest {
                         match value {
                             "connectionConfigs" | "connection_configs" => Ok(GeneratedField::ConnectionConfigs),
                             "createCatalogEntry" | "create_catalog_entry" => Ok(GeneratedField::CreateCatalogEntry),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -390,6 +802,7 @@ impl<'de> serde::Deserialize<'de> for CreateCdcFlowRequest {
             {
                 let mut connection_configs__ = None;
                 let mut create_catalog_entry__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ConnectionConfigs => {
@@ -404,6 +817,12 @@ impl<'de> serde::Deserialize<'de> for CreateCdcFlowRequest {
                             }
                             create_catalog_entry__ = Some(map.next_value()?);
                         }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -412,6 +831,7 @@ impl<'de> serde::Deserialize<'de> for CreateCdcFlowRequest {
                 Ok(CreateCdcFlowRequest {
                     connection_configs: connection_configs__,
                     create_catalog_entry: create_catalog_entry__.unwrap_or_default(),
+                    idempotency_key: idempotency_key__.unwrap_or_default(),
                 })
             }
         }
@@ -525,10 +945,16 @@ impl serde::Serialize for CreatePeerRequest {
         if self.peer.is_some() {
             len += 1;
         }
+        if !self.idempotency_key.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_route.CreatePeerRequest", len)?;
         if let Some(v) = self.peer.as_ref() {
             struct_ser.serialize_field("peer", v)?;
         }
+        if !self.idempotency_key.is_empty() {
+            struct_ser.serialize_field("idempotencyKey", &self.idempotency_key)?;
+        }
         struct_ser.end()
     }
 }
@@ -540,11 +966,14 @@ impl<'de> serde::Deserialize<'de> for CreatePeerRequest {
     {
         const FIELDS: &[&str] = &[
             "peer",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Peer,
+            IdempotencyKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -568,6 +997,7 @@ impl<'de> serde::Deserialize<'de> for CreatePeerRequest {
                     {
                         match value {
                             "peer" => Ok(GeneratedField::Peer),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -588,6 +1018,7 @@ impl<'de> serde::Deserialize<'de> for CreatePeerRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut peer__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Peer => {
@@ -596,6 +1027,12 @@ impl<'de> serde::Deserialize<'de> for CreatePeerRequest {
                             }
                             peer__ = map.next_value()?;
                         }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -603,6 +1040,7 @@ impl<'de> serde::Deserialize<'de> for CreatePeerRequest {
                 }
                 Ok(CreatePeerRequest {
                     peer: peer__,
+                    idempotency_key: idempotency_key__.unwrap_or_default(),
                 })
             }
         }
@@ -813,6 +1251,9 @@ impl serde::Serialize for CreateQRepFlowRequest {
         if self.create_catalog_entry {
             len += 1;
         }
+        if !self.idempotency_key.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_route.CreateQRepFlowRequest", len)?;
         if let Some(v) = self.qrep_config.as_ref() {
             struct_ser.serialize_field("qrepConfig", v)?;
@@ -820,6 +1261,9 @@ impl serde::Serialize for CreateQRepFlowRequest {
         if self.create_catalog_entry {
             struct_ser.serialize_field("createCatalogEntry", &self.create_catalog_entry)?;
         }
+        if !self.idempotency_key.is_empty() {
+            struct_ser.serialize_field("idempotencyKey", &self.idempotency_key)?;
+        }
         struct_ser.end()
     }
 }
@@ -834,12 +1278,1644 @@ impl<'de> serde::Deserialize<'de> for CreateQRepFlowRequest {
             "qrepConfig",
             "create_catalog_entry",
             "createCatalogEntry",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             QrepConfig,
             CreateCatalogEntry,
+            IdempotencyKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "qrepConfig" | "qrep_config" => Ok(GeneratedField::QrepConfig),
+                            "createCatalogEntry" | "create_catalog_entry" => Ok(GeneratedField::CreateCatalogEntry),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CreateQRepFlowRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.CreateQRepFlowRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CreateQRepFlowRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut qrep_config__ = None;
+                let mut create_catalog_entry__ = None;
+                let mut idempotency_key__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::QrepConfig => {
+                            if qrep_config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("qrepConfig"));
+                            }
+                            qrep_config__ = map.next_value()?;
+                        }
+                        GeneratedField::CreateCatalogEntry => {
+                            if create_catalog_entry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createCatalogEntry"));
+                            }
+                            create_catalog_entry__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CreateQRepFlowRequest {
+                    qrep_config: qrep_config__,
+                    create_catalog_entry: create_catalog_entry__.unwrap_or_default(),
+                    idempotency_key: idempotency_key__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.CreateQRepFlowRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CreateQRepFlowResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.worflow_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.CreateQRepFlowResponse", len)?;
+        if !self.worflow_id.is_empty() {
+            struct_ser.serialize_field("worflowId", &self.worflow_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CreateQRepFlowResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "worflow_id",
+            "worflowId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorflowId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "worflowId" | "worflow_id" => Ok(GeneratedField::WorflowId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CreateQRepFlowResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.CreateQRepFlowResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CreateQRepFlowResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut worflow_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::WorflowId => {
+                            if worflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("worflowId"));
+                            }
+                            worflow_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CreateQRepFlowResponse {
+                    worflow_id: worflow_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.CreateQRepFlowResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MirrorStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.flow_job_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.MirrorStatusRequest", len)?;
+        if !self.flow_job_name.is_empty() {
+            struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MirrorStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "flow_job_name",
+            "flowJobName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FlowJobName,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MirrorStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.MirrorStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<MirrorStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut flow_job_name__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::FlowJobName => {
+                            if flow_job_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flowJobName"));
+                            }
+                            flow_job_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MirrorStatusRequest {
+                    flow_job_name: flow_job_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.MirrorStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MirrorStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.flow_job_name.is_empty() {
+            len += 1;
+        }
+        if !self.error_message.is_empty() {
+            len += 1;
+        }
+        if self.status.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.MirrorStatusResponse", len)?;
+        if !self.flow_job_name.is_empty() {
+            struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
+        }
+        if !self.error_message.is_empty() {
+            struct_ser.serialize_field("errorMessage", &self.error_message)?;
+        }
+        if let Some(v) = self.status.as_ref() {
+            match v {
+                mirror_status_response::Status::QrepStatus(v) => {
+                    struct_ser.serialize_field("qrepStatus", v)?;
+                }
+                mirror_status_response::Status::CdcStatus(v) => {
+                    struct_ser.serialize_field("cdcStatus", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MirrorStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "flow_job_name",
+            "flowJobName",
+            "error_message",
+            "errorMessage",
+            "qrep_status",
+            "qrepStatus",
+            "cdc_status",
+            "cdcStatus",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FlowJobName,
+            ErrorMessage,
+            QrepStatus,
+            CdcStatus,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
+                            "errorMessage" | "error_message" => Ok(GeneratedField::ErrorMessage),
+                            "qrepStatus" | "qrep_status" => Ok(GeneratedField::QrepStatus),
+                            "cdcStatus" | "cdc_status" => Ok(GeneratedField::CdcStatus),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MirrorStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.MirrorStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<MirrorStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut flow_job_name__ = None;
+                let mut error_message__ = None;
+                let mut status__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::FlowJobName => {
+                            if flow_job_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flowJobName"));
+                            }
+                            flow_job_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ErrorMessage => {
+                            if error_message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorMessage"));
+                            }
+                            error_message__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::QrepStatus => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("qrepStatus"));
+                            }
+                            status__ = map.next_value::<::std::option::Option<_>>()?.map(mirror_status_response::Status::QrepStatus)
+;
+                        }
+                        GeneratedField::CdcStatus => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cdcStatus"));
+                            }
+                            status__ = map.next_value::<::std::option::Option<_>>()?.map(mirror_status_response::Status::CdcStatus)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MirrorStatusResponse {
+                    flow_job_name: flow_job_name__.unwrap_or_default(),
+                    error_message: error_message__.unwrap_or_default(),
+                    status: status__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.MirrorStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartitionStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.partition_id.is_empty() {
+            len += 1;
+        }
+        if self.start_time.is_some() {
+            len += 1;
+        }
+        if self.end_time.is_some() {
+            len += 1;
+        }
+        if self.num_rows != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.PartitionStatus", len)?;
+        if !self.partition_id.is_empty() {
+            struct_ser.serialize_field("partitionId", &self.partition_id)?;
+        }
+        if let Some(v) = self.start_time.as_ref() {
+            struct_ser.serialize_field("startTime", v)?;
+        }
+        if let Some(v) = self.end_time.as_ref() {
+            struct_ser.serialize_field("endTime", v)?;
+        }
+        if self.num_rows != 0 {
+            struct_ser.serialize_field("numRows", &self.num_rows)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartitionStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "partition_id",
+            "partitionId",
+            "start_time",
+            "startTime",
+            "end_time",
+            "endTime",
+            "num_rows",
+            "numRows",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartitionId,
+            StartTime,
+            EndTime,
+            NumRows,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partitionId" | "partition_id" => Ok(GeneratedField::PartitionId),
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "endTime" | "end_time" => Ok(GeneratedField::EndTime),
+                            "numRows" | "num_rows" => Ok(GeneratedField::NumRows),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartitionStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.PartitionStatus")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PartitionStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut partition_id__ = None;
+                let mut start_time__ = None;
+                let mut end_time__ = None;
+                let mut num_rows__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PartitionId => {
+                            if partition_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partitionId"));
+                            }
+                            partition_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = map.next_value()?;
+                        }
+                        GeneratedField::EndTime => {
+                            if end_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endTime"));
+                            }
+                            end_time__ = map.next_value()?;
+                        }
+                        GeneratedField::NumRows => {
+                            if num_rows__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numRows"));
+                            }
+                            num_rows__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PartitionStatus {
+                    partition_id: partition_id__.unwrap_or_default(),
+                    start_time: start_time__,
+                    end_time: end_time__,
+                    num_rows: num_rows__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.PartitionStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PauseFlowRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if !self.flow_job_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.PauseFlowRequest", len)?;
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if !self.flow_job_name.is_empty() {
+            struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PauseFlowRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_id",
+            "workflowId",
+            "flow_job_name",
+            "flowJobName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowId,
+            FlowJobName,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PauseFlowRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.PauseFlowRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PauseFlowRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_id__ = None;
+                let mut flow_job_name__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::FlowJobName => {
+                            if flow_job_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flowJobName"));
+                            }
+                            flow_job_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PauseFlowRequest {
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    flow_job_name: flow_job_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.PauseFlowRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PauseFlowResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ok {
+            len += 1;
+        }
+        if !self.error_message.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.PauseFlowResponse", len)?;
+        if self.ok {
+            struct_ser.serialize_field("ok", &self.ok)?;
+        }
+        if !self.error_message.is_empty() {
+            struct_ser.serialize_field("errorMessage", &self.error_message)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PauseFlowResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ok",
+            "error_message",
+            "errorMessage",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Ok,
+            ErrorMessage,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ok" => Ok(GeneratedField::Ok),
+                            "errorMessage" | "error_message" => Ok(GeneratedField::ErrorMessage),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PauseFlowResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.PauseFlowResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PauseFlowResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ok__ = None;
+                let mut error_message__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Ok => {
+                            if ok__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ok"));
+                            }
+                            ok__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ErrorMessage => {
+                            if error_message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorMessage"));
+                            }
+                            error_message__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PauseFlowResponse {
+                    ok: ok__.unwrap_or_default(),
+                    error_message: error_message__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.PauseFlowResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PauseTableRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if !self.flow_job_name.is_empty() {
+            len += 1;
+        }
+        if !self.table_identifier.is_empty() {
+            len += 1;
+        }
+        if self.paused {
+            len += 1;
+        }
+        if self.policy != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.PauseTableRequest", len)?;
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if !self.flow_job_name.is_empty() {
+            struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
+        }
+        if !self.table_identifier.is_empty() {
+            struct_ser.serialize_field("tableIdentifier", &self.table_identifier)?;
+        }
+        if self.paused {
+            struct_ser.serialize_field("paused", &self.paused)?;
+        }
+        if self.policy != 0 {
+            let v = super::peerdb_flow::TablePausePolicy::from_i32(self.policy)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.policy)))?;
+            struct_ser.serialize_field("policy", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PauseTableRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_id",
+            "workflowId",
+            "flow_job_name",
+            "flowJobName",
+            "table_identifier",
+            "tableIdentifier",
+            "paused",
+            "policy",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowId,
+            FlowJobName,
+            TableIdentifier,
+            Paused,
+            Policy,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
+                            "tableIdentifier" | "table_identifier" => Ok(GeneratedField::TableIdentifier),
+                            "paused" => Ok(GeneratedField::Paused),
+                            "policy" => Ok(GeneratedField::Policy),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PauseTableRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.PauseTableRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PauseTableRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_id__ = None;
+                let mut flow_job_name__ = None;
+                let mut table_identifier__ = None;
+                let mut paused__ = None;
+                let mut policy__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::FlowJobName => {
+                            if flow_job_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flowJobName"));
+                            }
+                            flow_job_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TableIdentifier => {
+                            if table_identifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tableIdentifier"));
+                            }
+                            table_identifier__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Paused => {
+                            if paused__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paused"));
+                            }
+                            paused__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Policy => {
+                            if policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("policy"));
+                            }
+                            policy__ = Some(map.next_value::<super::peerdb_flow::TablePausePolicy>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PauseTableRequest {
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    flow_job_name: flow_job_name__.unwrap_or_default(),
+                    table_identifier: table_identifier__.unwrap_or_default(),
+                    paused: paused__.unwrap_or_default(),
+                    policy: policy__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.PauseTableRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PauseTableResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ok {
+            len += 1;
+        }
+        if !self.error_message.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.PauseTableResponse", len)?;
+        if self.ok {
+            struct_ser.serialize_field("ok", &self.ok)?;
+        }
+        if !self.error_message.is_empty() {
+            struct_ser.serialize_field("errorMessage", &self.error_message)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PauseTableResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ok",
+            "error_message",
+            "errorMessage",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Ok,
+            ErrorMessage,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ok" => Ok(GeneratedField::Ok),
+                            "errorMessage" | "error_message" => Ok(GeneratedField::ErrorMessage),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PauseTableResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.PauseTableResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PauseTableResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ok__ = None;
+                let mut error_message__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Ok => {
+                            if ok__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ok"));
+                            }
+                            ok__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ErrorMessage => {
+                            if error_message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorMessage"));
+                            }
+                            error_message__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PauseTableResponse {
+                    ok: ok__.unwrap_or_default(),
+                    error_message: error_message__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.PauseTableResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PeerValidationCheck {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.passed {
+            len += 1;
+        }
+        if !self.message.is_empty() {
+            len += 1;
+        }
+        if self.required {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.PeerValidationCheck", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.passed {
+            struct_ser.serialize_field("passed", &self.passed)?;
+        }
+        if !self.message.is_empty() {
+            struct_ser.serialize_field("message", &self.message)?;
+        }
+        if self.required {
+            struct_ser.serialize_field("required", &self.required)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PeerValidationCheck {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "passed",
+            "message",
+            "required",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Passed,
+            Message,
+            Required,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "passed" => Ok(GeneratedField::Passed),
+                            "message" => Ok(GeneratedField::Message),
+                            "required" => Ok(GeneratedField::Required),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PeerValidationCheck;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.PeerValidationCheck")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PeerValidationCheck, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut passed__ = None;
+                let mut message__ = None;
+                let mut required__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Passed => {
+                            if passed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passed"));
+                            }
+                            passed__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Required => {
+                            if required__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("required"));
+                            }
+                            required__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PeerValidationCheck {
+                    name: name__.unwrap_or_default(),
+                    passed: passed__.unwrap_or_default(),
+                    message: message__.unwrap_or_default(),
+                    required: required__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.PeerValidationCheck", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QRepMirrorStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.config.is_some() {
+            len += 1;
+        }
+        if !self.partitions.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.QRepMirrorStatus", len)?;
+        if let Some(v) = self.config.as_ref() {
+            struct_ser.serialize_field("config", v)?;
+        }
+        if !self.partitions.is_empty() {
+            struct_ser.serialize_field("partitions", &self.partitions)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QRepMirrorStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "config",
+            "partitions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Config,
+            Partitions,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "config" => Ok(GeneratedField::Config),
+                            "partitions" => Ok(GeneratedField::Partitions),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QRepMirrorStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.QRepMirrorStatus")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<QRepMirrorStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut config__ = None;
+                let mut partitions__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Config => {
+                            if config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("config"));
+                            }
+                            config__ = map.next_value()?;
+                        }
+                        GeneratedField::Partitions => {
+                            if partitions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partitions"));
+                            }
+                            partitions__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QRepMirrorStatus {
+                    config: config__,
+                    partitions: partitions__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.QRepMirrorStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RemoveTableFromFlowRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if !self.flow_job_name.is_empty() {
+            len += 1;
+        }
+        if !self.source_table_identifier.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.RemoveTableFromFlowRequest", len)?;
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if !self.flow_job_name.is_empty() {
+            struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
+        }
+        if !self.source_table_identifier.is_empty() {
+            struct_ser.serialize_field("sourceTableIdentifier", &self.source_table_identifier)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RemoveTableFromFlowRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_id",
+            "workflowId",
+            "flow_job_name",
+            "flowJobName",
+            "source_table_identifier",
+            "sourceTableIdentifier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowId,
+            FlowJobName,
+            SourceTableIdentifier,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
+                            "sourceTableIdentifier" | "source_table_identifier" => Ok(GeneratedField::SourceTableIdentifier),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RemoveTableFromFlowRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.RemoveTableFromFlowRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RemoveTableFromFlowRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_id__ = None;
+                let mut flow_job_name__ = None;
+                let mut source_table_identifier__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::FlowJobName => {
+                            if flow_job_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flowJobName"));
+                            }
+                            flow_job_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SourceTableIdentifier => {
+                            if source_table_identifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceTableIdentifier"));
+                            }
+                            source_table_identifier__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(RemoveTableFromFlowRequest {
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    flow_job_name: flow_job_name__.unwrap_or_default(),
+                    source_table_identifier: source_table_identifier__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.RemoveTableFromFlowRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RemoveTableFromFlowResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ok {
+            len += 1;
+        }
+        if !self.error_message.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.RemoveTableFromFlowResponse", len)?;
+        if self.ok {
+            struct_ser.serialize_field("ok", &self.ok)?;
+        }
+        if !self.error_message.is_empty() {
+            struct_ser.serialize_field("errorMessage", &self.error_message)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RemoveTableFromFlowResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ok",
+            "error_message",
+            "errorMessage",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Ok,
+            ErrorMessage,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ok" => Ok(GeneratedField::Ok),
+                            "errorMessage" | "error_message" => Ok(GeneratedField::ErrorMessage),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RemoveTableFromFlowResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.RemoveTableFromFlowResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RemoveTableFromFlowResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ok__ = None;
+                let mut error_message__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Ok => {
+                            if ok__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ok"));
+                            }
+                            ok__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ErrorMessage => {
+                            if error_message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorMessage"));
+                            }
+                            error_message__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(RemoveTableFromFlowResponse {
+                    ok: ok__.unwrap_or_default(),
+                    error_message: error_message__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.RemoveTableFromFlowResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ResumeFlowRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if !self.flow_job_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.ResumeFlowRequest", len)?;
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if !self.flow_job_name.is_empty() {
+            struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ResumeFlowRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_id",
+            "workflowId",
+            "flow_job_name",
+            "flowJobName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowId,
+            FlowJobName,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -862,8 +2938,8 @@ impl<'de> serde::Deserialize<'de> for CreateQRepFlowRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "qrepConfig" | "qrep_config" => Ok(GeneratedField::QrepConfig),
-                            "createCatalogEntry" | "create_catalog_entry" => Ok(GeneratedField::CreateCatalogEntry),
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -873,47 +2949,47 @@ impl<'de> serde::Deserialize<'de> for CreateQRepFlowRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CreateQRepFlowRequest;
+            type Value = ResumeFlowRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct peerdb_route.CreateQRepFlowRequest")
+                formatter.write_str("struct peerdb_route.ResumeFlowRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<CreateQRepFlowRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ResumeFlowRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut qrep_config__ = None;
-                let mut create_catalog_entry__ = None;
+                let mut workflow_id__ = None;
+                let mut flow_job_name__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::QrepConfig => {
-                            if qrep_config__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("qrepConfig"));
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
                             }
-                            qrep_config__ = map.next_value()?;
+                            workflow_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::CreateCatalogEntry => {
-                            if create_catalog_entry__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("createCatalogEntry"));
+                        GeneratedField::FlowJobName => {
+                            if flow_job_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flowJobName"));
                             }
-                            create_catalog_entry__ = Some(map.next_value()?);
+                            flow_job_name__ = Some(map.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(CreateQRepFlowRequest {
-                    qrep_config: qrep_config__,
-                    create_catalog_entry: create_catalog_entry__.unwrap_or_default(),
+                Ok(ResumeFlowRequest {
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    flow_job_name: flow_job_name__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_route.CreateQRepFlowRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_route.ResumeFlowRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for CreateQRepFlowResponse {
+impl serde::Serialize for ResumeFlowResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -921,30 +2997,38 @@ impl serde::Serialize for CreateQRepFlowResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.worflow_id.is_empty() {
+        if self.ok {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("peerdb_route.CreateQRepFlowResponse", len)?;
-        if !self.worflow_id.is_empty() {
-            struct_ser.serialize_field("worflowId", &self.worflow_id)?;
+        if !self.error_message.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.ResumeFlowResponse", len)?;
+        if self.ok {
+            struct_ser.serialize_field("ok", &self.ok)?;
+        }
+        if !self.error_message.is_empty() {
+            struct_ser.serialize_field("errorMessage", &self.error_message)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for CreateQRepFlowResponse {
+impl<'de> serde::Deserialize<'de> for ResumeFlowResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "worflow_id",
-            "worflowId",
+            "ok",
+            "error_message",
+            "errorMessage",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            WorflowId,
+            Ok,
+            ErrorMessage,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -967,7 +3051,8 @@ impl<'de> serde::Deserialize<'de> for CreateQRepFlowResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "worflowId" | "worflow_id" => Ok(GeneratedField::WorflowId),
+                            "ok" => Ok(GeneratedField::Ok),
+                            "errorMessage" | "error_message" => Ok(GeneratedField::ErrorMessage),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -977,39 +3062,47 @@ impl<'de> serde::Deserialize<'de> for CreateQRepFlowResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CreateQRepFlowResponse;
+            type Value = ResumeFlowResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct peerdb_route.CreateQRepFlowResponse")
+                formatter.write_str("struct peerdb_route.ResumeFlowResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<CreateQRepFlowResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ResumeFlowResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut worflow_id__ = None;
+                let mut ok__ = None;
+                let mut error_message__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::WorflowId => {
-                            if worflow_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("worflowId"));
+                        GeneratedField::Ok => {
+                            if ok__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ok"));
                             }
-                            worflow_id__ = Some(map.next_value()?);
+                            ok__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ErrorMessage => {
+                            if error_message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorMessage"));
+                            }
+                            error_message__ = Some(map.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(CreateQRepFlowResponse {
-                    worflow_id: worflow_id__.unwrap_or_default(),
+                Ok(ResumeFlowResponse {
+                    ok: ok__.unwrap_or_default(),
+                    error_message: error_message__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_route.CreateQRepFlowResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_route.ResumeFlowResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for MirrorStatusRequest {
+impl serde::Serialize for ResyncMirrorRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1017,30 +3110,57 @@ impl serde::Serialize for MirrorStatusRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
         if !self.flow_job_name.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("peerdb_route.MirrorStatusRequest", len)?;
+        if !self.source_table_identifier.is_empty() {
+            len += 1;
+        }
+        if self.truncate_destination_tables {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.ResyncMirrorRequest", len)?;
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
         if !self.flow_job_name.is_empty() {
             struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
         }
+        if !self.source_table_identifier.is_empty() {
+            struct_ser.serialize_field("sourceTableIdentifier", &self.source_table_identifier)?;
+        }
+        if self.truncate_destination_tables {
+            struct_ser.serialize_field("truncateDestinationTables", &self.truncate_destination_tables)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for MirrorStatusRequest {
+impl<'de> serde::Deserialize<'de> for ResyncMirrorRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "workflow_id",
+            "workflowId",
             "flow_job_name",
             "flowJobName",
+            "source_table_identifier",
+            "sourceTableIdentifier",
+            "truncate_destination_tables",
+            "truncateDestinationTables",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            WorkflowId,
             FlowJobName,
+            SourceTableIdentifier,
+            TruncateDestinationTables,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1063,7 +3183,10 @@ impl<'de> serde::Deserialize<'de> for MirrorStatusRequest {
                         E: serde::de::Error,
                     {
                         match value {
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
                             "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
+                            "sourceTableIdentifier" | "source_table_identifier" => Ok(GeneratedField::SourceTableIdentifier),
+                            "truncateDestinationTables" | "truncate_destination_tables" => Ok(GeneratedField::TruncateDestinationTables),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1073,39 +3196,63 @@ impl<'de> serde::Deserialize<'de> for MirrorStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = MirrorStatusRequest;
+            type Value = ResyncMirrorRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct peerdb_route.MirrorStatusRequest")
+                formatter.write_str("struct peerdb_route.ResyncMirrorRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<MirrorStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ResyncMirrorRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut workflow_id__ = None;
                 let mut flow_job_name__ = None;
+                let mut source_table_identifier__ = None;
+                let mut truncate_destination_tables__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map.next_value()?);
+                        }
                         GeneratedField::FlowJobName => {
                             if flow_job_name__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("flowJobName"));
                             }
                             flow_job_name__ = Some(map.next_value()?);
                         }
+                        GeneratedField::SourceTableIdentifier => {
+                            if source_table_identifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceTableIdentifier"));
+                            }
+                            source_table_identifier__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TruncateDestinationTables => {
+                            if truncate_destination_tables__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("truncateDestinationTables"));
+                            }
+                            truncate_destination_tables__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(MirrorStatusRequest {
+                Ok(ResyncMirrorRequest {
+                    workflow_id: workflow_id__.unwrap_or_default(),
                     flow_job_name: flow_job_name__.unwrap_or_default(),
+                    source_table_identifier: source_table_identifier__.unwrap_or_default(),
+                    truncate_destination_tables: truncate_destination_tables__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_route.MirrorStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_route.ResyncMirrorRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for MirrorStatusResponse {
+impl serde::Serialize for ResyncMirrorResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1113,58 +3260,38 @@ impl serde::Serialize for MirrorStatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.flow_job_name.is_empty() {
+        if self.ok {
             len += 1;
         }
         if !self.error_message.is_empty() {
             len += 1;
         }
-        if self.status.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("peerdb_route.MirrorStatusResponse", len)?;
-        if !self.flow_job_name.is_empty() {
-            struct_ser.serialize_field("flowJobName", &self.flow_job_name)?;
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.ResyncMirrorResponse", len)?;
+        if self.ok {
+            struct_ser.serialize_field("ok", &self.ok)?;
         }
         if !self.error_message.is_empty() {
             struct_ser.serialize_field("errorMessage", &self.error_message)?;
         }
-        if let Some(v) = self.status.as_ref() {
-            match v {
-                mirror_status_response::Status::QrepStatus(v) => {
-                    struct_ser.serialize_field("qrepStatus", v)?;
-                }
-                mirror_status_response::Status::CdcStatus(v) => {
-                    struct_ser.serialize_field("cdcStatus", v)?;
-                }
-            }
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for MirrorStatusResponse {
+impl<'de> serde::Deserialize<'de> for ResyncMirrorResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "flow_job_name",
-            "flowJobName",
+            "ok",
             "error_message",
             "errorMessage",
-            "qrep_status",
-            "qrepStatus",
-            "cdc_status",
-            "cdcStatus",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            FlowJobName,
+            Ok,
             ErrorMessage,
-            QrepStatus,
-            CdcStatus,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1187,10 +3314,8 @@ impl<'de> serde::Deserialize<'de> for MirrorStatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "flowJobName" | "flow_job_name" => Ok(GeneratedField::FlowJobName),
+                            "ok" => Ok(GeneratedField::Ok),
                             "errorMessage" | "error_message" => Ok(GeneratedField::ErrorMessage),
-                            "qrepStatus" | "qrep_status" => Ok(GeneratedField::QrepStatus),
-                            "cdcStatus" | "cdc_status" => Ok(GeneratedField::CdcStatus),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1200,26 +3325,25 @@ impl<'de> serde::Deserialize<'de> for MirrorStatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = MirrorStatusResponse;
+            type Value = ResyncMirrorResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct peerdb_route.MirrorStatusResponse")
+                formatter.write_str("struct peerdb_route.ResyncMirrorResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<MirrorStatusResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ResyncMirrorResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut flow_job_name__ = None;
+                let mut ok__ = None;
                 let mut error_message__ = None;
-                let mut status__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::FlowJobName => {
-                            if flow_job_name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("flowJobName"));
+                        GeneratedField::Ok => {
+                            if ok__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ok"));
                             }
-                            flow_job_name__ = Some(map.next_value()?);
+                            ok__ = Some(map.next_value()?);
                         }
                         GeneratedField::ErrorMessage => {
                             if error_message__.is_some() {
@@ -1227,36 +3351,21 @@ impl<'de> serde::Deserialize<'de> for MirrorStatusResponse {
                             }
                             error_message__ = Some(map.next_value()?);
                         }
-                        GeneratedField::QrepStatus => {
-                            if status__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("qrepStatus"));
-                            }
-                            status__ = map.next_value::<::std::option::Option<_>>()?.map(mirror_status_response::Status::QrepStatus)
-;
-                        }
-                        GeneratedField::CdcStatus => {
-                            if status__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("cdcStatus"));
-                            }
-                            status__ = map.next_value::<::std::option::Option<_>>()?.map(mirror_status_response::Status::CdcStatus)
-;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(MirrorStatusResponse {
-                    flow_job_name: flow_job_name__.unwrap_or_default(),
+                Ok(ResyncMirrorResponse {
+                    ok: ok__.unwrap_or_default(),
                     error_message: error_message__.unwrap_or_default(),
-                    status: status__,
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_route.MirrorStatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_route.ResyncMirrorResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PartitionStatus {
+impl serde::Serialize for RotateEncryptionKeyProgress {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1264,57 +3373,77 @@ impl serde::Serialize for PartitionStatus {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.partition_id.is_empty() {
+        if !self.table.is_empty() {
             len += 1;
         }
-        if self.start_time.is_some() {
+        if self.done != 0 {
             len += 1;
         }
-        if self.end_time.is_some() {
+        if self.total != 0 {
             len += 1;
         }
-        if self.num_rows != 0 {
+        if self.rotated != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("peerdb_route.PartitionStatus", len)?;
-        if !self.partition_id.is_empty() {
-            struct_ser.serialize_field("partitionId", &self.partition_id)?;
+        if self.encrypted != 0 {
+            len += 1;
         }
-        if let Some(v) = self.start_time.as_ref() {
-            struct_ser.serialize_field("startTime", v)?;
+        if self.verified != 0 {
+            len += 1;
         }
-        if let Some(v) = self.end_time.as_ref() {
-            struct_ser.serialize_field("endTime", v)?;
+        if self.completed {
+            len += 1;
         }
-        if self.num_rows != 0 {
-            struct_ser.serialize_field("numRows", &self.num_rows)?;
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.RotateEncryptionKeyProgress", len)?;
+        if !self.table.is_empty() {
+            struct_ser.serialize_field("table", &self.table)?;
+        }
+        if self.done != 0 {
+            struct_ser.serialize_field("done", ToString::to_string(&self.done).as_str())?;
+        }
+        if self.total != 0 {
+            struct_ser.serialize_field("total", ToString::to_string(&self.total).as_str())?;
+        }
+        if self.rotated != 0 {
+            struct_ser.serialize_field("rotated", ToString::to_string(&self.rotated).as_str())?;
+        }
+        if self.encrypted != 0 {
+            struct_ser.serialize_field("encrypted", ToString::to_string(&self.encrypted).as_str())?;
+        }
+        if self.verified != 0 {
+            struct_ser.serialize_field("verified", ToString::to_string(&self.verified).as_str())?;
+        }
+        if self.completed {
+            struct_ser.serialize_field("completed", &self.completed)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PartitionStatus {
+impl<'de> serde::Deserialize<'de> for RotateEncryptionKeyProgress {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "partition_id",
-            "partitionId",
-            "start_time",
-            "startTime",
-            "end_time",
-            "endTime",
-            "num_rows",
-            "numRows",
+            "table",
+            "done",
+            "total",
+            "rotated",
+            "encrypted",
+            "verified",
+            "completed",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PartitionId,
-            StartTime,
-            EndTime,
-            NumRows,
+            Table,
+            Done,
+            Total,
+            Rotated,
+            Encrypted,
+            Verified,
+            Completed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1337,10 +3466,13 @@ impl<'de> serde::Deserialize<'de> for PartitionStatus {
                         E: serde::de::Error,
                     {
                         match value {
-                            "partitionId" | "partition_id" => Ok(GeneratedField::PartitionId),
-                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
-                            "endTime" | "end_time" => Ok(GeneratedField::EndTime),
-                            "numRows" | "num_rows" => Ok(GeneratedField::NumRows),
+                            "table" => Ok(GeneratedField::Table),
+                            "done" => Ok(GeneratedField::Done),
+                            "total" => Ok(GeneratedField::Total),
+                            "rotated" => Ok(GeneratedField::Rotated),
+                            "encrypted" => Ok(GeneratedField::Encrypted),
+                            "verified" => Ok(GeneratedField::Verified),
+                            "completed" => Ok(GeneratedField::Completed),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1350,65 +3482,97 @@ impl<'de> serde::Deserialize<'de> for PartitionStatus {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PartitionStatus;
+            type Value = RotateEncryptionKeyProgress;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct peerdb_route.PartitionStatus")
+                formatter.write_str("struct peerdb_route.RotateEncryptionKeyProgress")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<PartitionStatus, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RotateEncryptionKeyProgress, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut partition_id__ = None;
-                let mut start_time__ = None;
-                let mut end_time__ = None;
-                let mut num_rows__ = None;
+                let mut table__ = None;
+                let mut done__ = None;
+                let mut total__ = None;
+                let mut rotated__ = None;
+                let mut encrypted__ = None;
+                let mut verified__ = None;
+                let mut completed__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::PartitionId => {
-                            if partition_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("partitionId"));
+                        GeneratedField::Table => {
+                            if table__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("table"));
                             }
-                            partition_id__ = Some(map.next_value()?);
+                            table__ = Some(map.next_value()?);
                         }
-                        GeneratedField::StartTime => {
-                            if start_time__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startTime"));
+                        GeneratedField::Done => {
+                            if done__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("done"));
                             }
-                            start_time__ = map.next_value()?;
+                            done__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::EndTime => {
-                            if end_time__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endTime"));
+                        GeneratedField::Total => {
+                            if total__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("total"));
+                            }
+                            total__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Rotated => {
+                            if rotated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rotated"));
+                            }
+                            rotated__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Encrypted => {
+                            if encrypted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encrypted"));
                             }
-                            end_time__ = map.next_value()?;
+                            encrypted__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::NumRows => {
-                            if num_rows__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("numRows"));
+                        GeneratedField::Verified => {
+                            if verified__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verified"));
                             }
-                            num_rows__ = 
+                            verified__ = 
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Completed => {
+                            if completed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("completed"));
+                            }
+                            completed__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PartitionStatus {
-                    partition_id: partition_id__.unwrap_or_default(),
-                    start_time: start_time__,
-                    end_time: end_time__,
-                    num_rows: num_rows__.unwrap_or_default(),
+                Ok(RotateEncryptionKeyProgress {
+                    table: table__.unwrap_or_default(),
+                    done: done__.unwrap_or_default(),
+                    total: total__.unwrap_or_default(),
+                    rotated: rotated__.unwrap_or_default(),
+                    encrypted: encrypted__.unwrap_or_default(),
+                    verified: verified__.unwrap_or_default(),
+                    completed: completed__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_route.PartitionStatus", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_route.RotateEncryptionKeyProgress", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for QRepMirrorStatus {
+impl serde::Serialize for RotateEncryptionKeyRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1416,37 +3580,39 @@ impl serde::Serialize for QRepMirrorStatus {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.config.is_some() {
+        if !self.old_key.is_empty() {
             len += 1;
         }
-        if !self.partitions.is_empty() {
+        if !self.new_key.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("peerdb_route.QRepMirrorStatus", len)?;
-        if let Some(v) = self.config.as_ref() {
-            struct_ser.serialize_field("config", v)?;
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.RotateEncryptionKeyRequest", len)?;
+        if !self.old_key.is_empty() {
+            struct_ser.serialize_field("oldKey", &self.old_key)?;
         }
-        if !self.partitions.is_empty() {
-            struct_ser.serialize_field("partitions", &self.partitions)?;
+        if !self.new_key.is_empty() {
+            struct_ser.serialize_field("newKey", &self.new_key)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for QRepMirrorStatus {
+impl<'de> serde::Deserialize<'de> for RotateEncryptionKeyRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "config",
-            "partitions",
+            "old_key",
+            "oldKey",
+            "new_key",
+            "newKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Config,
-            Partitions,
+            OldKey,
+            NewKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1469,8 +3635,8 @@ impl<'de> serde::Deserialize<'de> for QRepMirrorStatus {
                         E: serde::de::Error,
                     {
                         match value {
-                            "config" => Ok(GeneratedField::Config),
-                            "partitions" => Ok(GeneratedField::Partitions),
+                            "oldKey" | "old_key" => Ok(GeneratedField::OldKey),
+                            "newKey" | "new_key" => Ok(GeneratedField::NewKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1480,44 +3646,44 @@ impl<'de> serde::Deserialize<'de> for QRepMirrorStatus {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = QRepMirrorStatus;
+            type Value = RotateEncryptionKeyRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct peerdb_route.QRepMirrorStatus")
+                formatter.write_str("struct peerdb_route.RotateEncryptionKeyRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<QRepMirrorStatus, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RotateEncryptionKeyRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut config__ = None;
-                let mut partitions__ = None;
+                let mut old_key__ = None;
+                let mut new_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Config => {
-                            if config__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("config"));
+                        GeneratedField::OldKey => {
+                            if old_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldKey"));
                             }
-                            config__ = map.next_value()?;
+                            old_key__ = Some(map.next_value()?);
                         }
-                        GeneratedField::Partitions => {
-                            if partitions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("partitions"));
+                        GeneratedField::NewKey => {
+                            if new_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newKey"));
                             }
-                            partitions__ = Some(map.next_value()?);
+                            new_key__ = Some(map.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(QRepMirrorStatus {
-                    config: config__,
-                    partitions: partitions__.unwrap_or_default(),
+                Ok(RotateEncryptionKeyRequest {
+                    old_key: old_key__.unwrap_or_default(),
+                    new_key: new_key__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("peerdb_route.QRepMirrorStatus", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("peerdb_route.RotateEncryptionKeyRequest", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for ShutdownRequest {
@@ -1878,6 +4044,250 @@ impl<'de> serde::Deserialize<'de> for SnapshotStatus {
         deserializer.deserialize_struct("peerdb_route.SnapshotStatus", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UpdatePeerRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.peer.is_some() {
+            len += 1;
+        }
+        if self.catalog_updated {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.UpdatePeerRequest", len)?;
+        if let Some(v) = self.peer.as_ref() {
+            struct_ser.serialize_field("peer", v)?;
+        }
+        if self.catalog_updated {
+            struct_ser.serialize_field("catalogUpdated", &self.catalog_updated)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdatePeerRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "peer",
+            "catalog_updated",
+            "catalogUpdated",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Peer,
+            CatalogUpdated,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "peer" => Ok(GeneratedField::Peer),
+                            "catalogUpdated" | "catalog_updated" => Ok(GeneratedField::CatalogUpdated),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdatePeerRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.UpdatePeerRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpdatePeerRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut peer__ = None;
+                let mut catalog_updated__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Peer => {
+                            if peer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("peer"));
+                            }
+                            peer__ = map.next_value()?;
+                        }
+                        GeneratedField::CatalogUpdated => {
+                            if catalog_updated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("catalogUpdated"));
+                            }
+                            catalog_updated__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UpdatePeerRequest {
+                    peer: peer__,
+                    catalog_updated: catalog_updated__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.UpdatePeerRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdatePeerResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ok {
+            len += 1;
+        }
+        if !self.error_message.is_empty() {
+            len += 1;
+        }
+        if !self.flow_job_names.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("peerdb_route.UpdatePeerResponse", len)?;
+        if self.ok {
+            struct_ser.serialize_field("ok", &self.ok)?;
+        }
+        if !self.error_message.is_empty() {
+            struct_ser.serialize_field("errorMessage", &self.error_message)?;
+        }
+        if !self.flow_job_names.is_empty() {
+            struct_ser.serialize_field("flowJobNames", &self.flow_job_names)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdatePeerResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ok",
+            "error_message",
+            "errorMessage",
+            "flow_job_names",
+            "flowJobNames",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Ok,
+            ErrorMessage,
+            FlowJobNames,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ok" => Ok(GeneratedField::Ok),
+                            "errorMessage" | "error_message" => Ok(GeneratedField::ErrorMessage),
+                            "flowJobNames" | "flow_job_names" => Ok(GeneratedField::FlowJobNames),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdatePeerResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct peerdb_route.UpdatePeerResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpdatePeerResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ok__ = None;
+                let mut error_message__ = None;
+                let mut flow_job_names__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Ok => {
+                            if ok__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ok"));
+                            }
+                            ok__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ErrorMessage => {
+                            if error_message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorMessage"));
+                            }
+                            error_message__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::FlowJobNames => {
+                            if flow_job_names__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flowJobNames"));
+                            }
+                            flow_job_names__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UpdatePeerResponse {
+                    ok: ok__.unwrap_or_default(),
+                    error_message: error_message__.unwrap_or_default(),
+                    flow_job_names: flow_job_names__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("peerdb_route.UpdatePeerResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ValidatePeerRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1987,6 +4397,9 @@ impl serde::Serialize for ValidatePeerResponse {
         if !self.message.is_empty() {
             len += 1;
         }
+        if !self.checks.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("peerdb_route.ValidatePeerResponse", len)?;
         if self.status != 0 {
             let v = ValidatePeerStatus::from_i32(self.status)
@@ -1996,6 +4409,9 @@ impl serde::Serialize for ValidatePeerResponse {
         if !self.message.is_empty() {
             struct_ser.serialize_field("message", &self.message)?;
         }
+        if !self.checks.is_empty() {
+            struct_ser.serialize_field("checks", &self.checks)?;
+        }
         struct_ser.end()
     }
 }
@@ -2008,12 +4424,14 @@ impl<'de> serde::Deserialize<'de> for ValidatePeerResponse {
         const FIELDS: &[&str] = &[
             "status",
             "message",
+            "checks",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Status,
             Message,
+            Checks,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2038,6 +4456,7 @@ impl<'de> serde::Deserialize<'de> for ValidatePeerResponse {
                         match value {
                             "status" => Ok(GeneratedField::Status),
                             "message" => Ok(GeneratedField::Message),
+                            "checks" => Ok(GeneratedField::Checks),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2059,6 +4478,7 @@ impl<'de> serde::Deserialize<'de> for ValidatePeerResponse {
             {
                 let mut status__ = None;
                 let mut message__ = None;
+                let mut checks__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Status => {
@@ -2073,6 +4493,12 @@ impl<'de> serde::Deserialize<'de> for ValidatePeerResponse {
                             }
                             message__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Checks => {
+                            if checks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("checks"));
+                            }
+                            checks__ = Some(map.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2081,6 +4507,7 @@ impl<'de> serde::Deserialize<'de> for ValidatePeerResponse {
                 Ok(ValidatePeerResponse {
                     status: status__.unwrap_or_default(),
                     message: message__.unwrap_or_default(),
+                    checks: checks__.unwrap_or_default(),
                 })
             }
         }
@@ -137,6 +137,33 @@ pub mod flow_service_client {
                 .insert(GrpcMethod::new("peerdb_route.FlowService", "CreatePeer"));
             self.inner.unary(req, path, codec).await
         }
+        /// saves a new config of a peer, and has its running cdc mirrors reconnect with
+        /// it at their next batch instead of being dropped and created again.
+        pub async fn update_peer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdatePeerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdatePeerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/UpdatePeer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("peerdb_route.FlowService", "UpdatePeer"));
+            self.inner.unary(req, path, codec).await
+        }
         ///
         pub async fn create_cdc_flow(
             &mut self,
@@ -216,6 +243,165 @@ pub mod flow_service_client {
             self.inner.unary(req, path, codec).await
         }
         ///
+        pub async fn pause_flow(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PauseFlowRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PauseFlowResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/PauseFlow",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("peerdb_route.FlowService", "PauseFlow"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        pub async fn resume_flow(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ResumeFlowRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResumeFlowResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/ResumeFlow",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("peerdb_route.FlowService", "ResumeFlow"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// snapshots the tables and adds them to a running cdc flow.
+        pub async fn add_tables_to_flow(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddTablesToFlowRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddTablesToFlowResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/AddTablesToFlow",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("peerdb_route.FlowService", "AddTablesToFlow"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// pauses or resumes replication of one table of a running cdc flow.
+        pub async fn pause_table(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PauseTableRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PauseTableResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/PauseTable",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("peerdb_route.FlowService", "PauseTable"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// stops replicating one table of a running cdc flow.
+        pub async fn remove_table_from_flow(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveTableFromFlowRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveTableFromFlowResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/RemoveTableFromFlow",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("peerdb_route.FlowService", "RemoveTableFromFlow"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// copies tables of a running cdc flow again and replaces the destination
+        /// tables with the copies.
+        pub async fn resync_mirror(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ResyncMirrorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResyncMirrorResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/ResyncMirror",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("peerdb_route.FlowService", "ResyncMirror"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
         pub async fn mirror_status(
             &mut self,
             request: impl tonic::IntoRequest<super::MirrorStatusRequest>,
@@ -241,6 +427,65 @@ pub mod flow_service_client {
                 .insert(GrpcMethod::new("peerdb_route.FlowService", "MirrorStatus"));
             self.inner.unary(req, path, codec).await
         }
+        /// streams the status of the mirror whenever it changes.
+        pub async fn watch_mirror_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MirrorStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::MirrorStatusResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/WatchMirrorStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("peerdb_route.FlowService", "WatchMirrorStatus"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// rotates the master key the configs in the catalog are encrypted with, the
+        /// way rotate_encryption_key of nexus does, and streams its progress.
+        pub async fn rotate_encryption_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RotateEncryptionKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::RotateEncryptionKeyProgress>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peerdb_route.FlowService/RotateEncryptionKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("peerdb_route.FlowService", "RotateEncryptionKey"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -266,6 +511,15 @@ pub mod flow_service_server {
             tonic::Response<super::CreatePeerResponse>,
             tonic::Status,
         >;
+        /// saves a new config of a peer, and has its running cdc mirrors reconnect with
+        /// it at their next batch instead of being dropped and created again.
+        async fn update_peer(
+            &self,
+            request: tonic::Request<super::UpdatePeerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdatePeerResponse>,
+            tonic::Status,
+        >;
         ///
         async fn create_cdc_flow(
             &self,
@@ -291,6 +545,55 @@ pub mod flow_service_server {
             tonic::Status,
         >;
         ///
+        async fn pause_flow(
+            &self,
+            request: tonic::Request<super::PauseFlowRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PauseFlowResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn resume_flow(
+            &self,
+            request: tonic::Request<super::ResumeFlowRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResumeFlowResponse>,
+            tonic::Status,
+        >;
+        /// snapshots the tables and adds them to a running cdc flow.
+        async fn add_tables_to_flow(
+            &self,
+            request: tonic::Request<super::AddTablesToFlowRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddTablesToFlowResponse>,
+            tonic::Status,
+        >;
+        /// pauses or resumes replication of one table of a running cdc flow.
+        async fn pause_table(
+            &self,
+            request: tonic::Request<super::PauseTableRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PauseTableResponse>,
+            tonic::Status,
+        >;
+        /// stops replicating one table of a running cdc flow.
+        async fn remove_table_from_flow(
+            &self,
+            request: tonic::Request<super::RemoveTableFromFlowRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveTableFromFlowResponse>,
+            tonic::Status,
+        >;
+        /// copies tables of a running cdc flow again and replaces the destination
+        /// tables with the copies.
+        async fn resync_mirror(
+            &self,
+            request: tonic::Request<super::ResyncMirrorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResyncMirrorResponse>,
+            tonic::Status,
+        >;
+        ///
         async fn mirror_status(
             &self,
             request: tonic::Request<super::MirrorStatusRequest>,
@@ -298,6 +601,35 @@ pub mod flow_service_server {
             tonic::Response<super::MirrorStatusResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the WatchMirrorStatus method.
+        type WatchMirrorStatusStream: futures_core::Stream<
+                Item = std::result::Result<super::MirrorStatusResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// streams the status of the mirror whenever it changes.
+        async fn watch_mirror_status(
+            &self,
+            request: tonic::Request<super::MirrorStatusRequest>,
+        ) -> std::result::Result<tonic::Response<Self::WatchMirrorStatusStream>, tonic::Status>;
+        /// Server streaming response type for the RotateEncryptionKey method.
+        type RotateEncryptionKeyStream: futures_core::Stream<
+                Item = std::result::Result<
+                    super::RotateEncryptionKeyProgress,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// rotates the master key the configs in the catalog are encrypted with, the
+        /// way rotate_encryption_key of nexus does, and streams its progress.
+        async fn rotate_encryption_key(
+            &self,
+            request: tonic::Request<super::RotateEncryptionKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::RotateEncryptionKeyStream>,
+            tonic::Status,
+        >;
     }
     ///
     #[derive(Debug)]
@@ -469,6 +801,50 @@ pub mod flow_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/peerdb_route.FlowService/UpdatePeer" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdatePeerSvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::UnaryService<super::UpdatePeerRequest>
+                    for UpdatePeerSvc<T> {
+                        type Response = super::UpdatePeerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdatePeerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).update_peer(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdatePeerSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/peerdb_route.FlowService/CreateCDCFlow" => {
                     #[allow(non_camel_case_types)]
                     struct CreateCDCFlowSvc<T: FlowService>(pub Arc<T>);
@@ -607,7 +983,277 @@ pub mod flow_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/peerdb_route.FlowService/MirrorStatus" => {
+                "/peerdb_route.FlowService/PauseFlow" => {
+                    #[allow(non_camel_case_types)]
+                    struct PauseFlowSvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::UnaryService<super::PauseFlowRequest>
+                    for PauseFlowSvc<T> {
+                        type Response = super::PauseFlowResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PauseFlowRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).pause_flow(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PauseFlowSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/peerdb_route.FlowService/ResumeFlow" => {
+                    #[allow(non_camel_case_types)]
+                    struct ResumeFlowSvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::UnaryService<super::ResumeFlowRequest>
+                    for ResumeFlowSvc<T> {
+                        type Response = super::ResumeFlowResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ResumeFlowRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).resume_flow(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ResumeFlowSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/peerdb_route.FlowService/AddTablesToFlow" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddTablesToFlowSvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::UnaryService<super::AddTablesToFlowRequest>
+                    for AddTablesToFlowSvc<T> {
+                        type Response = super::AddTablesToFlowResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddTablesToFlowRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_tables_to_flow(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddTablesToFlowSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/peerdb_route.FlowService/PauseTable" => {
+                    #[allow(non_camel_case_types)]
+                    struct PauseTableSvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::UnaryService<super::PauseTableRequest>
+                    for PauseTableSvc<T> {
+                        type Response = super::PauseTableResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PauseTableRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).pause_table(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PauseTableSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/peerdb_route.FlowService/RemoveTableFromFlow" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveTableFromFlowSvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::UnaryService<super::RemoveTableFromFlowRequest>
+                    for RemoveTableFromFlowSvc<T> {
+                        type Response = super::RemoveTableFromFlowResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveTableFromFlowRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_table_from_flow(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveTableFromFlowSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/peerdb_route.FlowService/ResyncMirror" => {
+                    #[allow(non_camel_case_types)]
+                    struct ResyncMirrorSvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::UnaryService<super::ResyncMirrorRequest>
+                    for ResyncMirrorSvc<T> {
+                        type Response = super::ResyncMirrorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ResyncMirrorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).resync_mirror(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ResyncMirrorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/peerdb_route.FlowService/MirrorStatus" => {
                     #[allow(non_camel_case_types)]
                     struct MirrorStatusSvc<T: FlowService>(pub Arc<T>);
                     impl<
@@ -653,6 +1299,101 @@ pub mod flow_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/peerdb_route.FlowService/WatchMirrorStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchMirrorStatusSvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::ServerStreamingService<super::MirrorStatusRequest>
+                    for WatchMirrorStatusSvc<T> {
+                        type Response = super::MirrorStatusResponse;
+                        type ResponseStream = T::WatchMirrorStatusStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MirrorStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).watch_mirror_status(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WatchMirrorStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/peerdb_route.FlowService/RotateEncryptionKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct RotateEncryptionKeySvc<T: FlowService>(pub Arc<T>);
+                    impl<
+                        T: FlowService,
+                    > tonic::server::ServerStreamingService<
+                        super::RotateEncryptionKeyRequest,
+                    > for RotateEncryptionKeySvc<T> {
+                        type Response = super::RotateEncryptionKeyProgress;
+                        type ResponseStream = T::RotateEncryptionKeyStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RotateEncryptionKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).rotate_encryption_key(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RotateEncryptionKeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
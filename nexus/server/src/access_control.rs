@@ -0,0 +1,512 @@
+use std::ops::ControlFlow;
+
+use analyzer::{NotificationStatement, PeerDDL, QueryAssocation};
+use catalog::{Privilege, ScramSecret};
+use peerdb_parser::NexusStatement;
+use pgwire::api::auth::scram::gen_salted_password;
+use rand::Rng;
+use sqlparser::ast::{
+    visit_expressions, Expr, ObjectName, Query, SetExpr, Statement, TableFactor, TableWithJoins,
+};
+
+use crate::{catalog_emulation, session_tables::SessionTables};
+
+/// The iterations passwords are salted with for SCRAM-SHA-256, the default of
+/// postgres.
+pub const SCRAM_ITERATIONS: usize = 4096;
+
+/// What a statement requires of the session's user when access control is
+/// enabled, the admin can run every statement.
+pub enum Requirement {
+    Nothing,
+    Admin,
+    Privileges(Vec<Privilege>),
+}
+
+pub fn requirement(stmt: &NexusStatement, session_tables: &SessionTables) -> Requirement {
+    match stmt {
+        NexusStatement::PeerDDL { ddl, .. } => ddl_requirement(ddl),
+        NexusStatement::PeerQuery { stmt, assoc } => match assoc {
+            QueryAssocation::Peer(peer) => usage(&[peer.name.as_str()]),
+            // the catalog holds the configs of every peer, other users only
+            // get to query the tables of their session.
+            QueryAssocation::Catalog if session_tables.is_only_referenced(stmt) => {
+                Requirement::Nothing
+            }
+            // the emulated catalog relations only show the peers and their
+            // tables, like SHOW PEERS does.
+            QueryAssocation::Catalog
+                if catalog_emulation::is_only_emulated(stmt, |relation| {
+                    session_tables.is_session_table(relation)
+                }) =>
+            {
+                Requirement::Nothing
+            }
+            QueryAssocation::Catalog => Requirement::Admin,
+        },
+        NexusStatement::CreateTempTable { query, assoc, .. }
+        | NexusStatement::CopyOut {
+            stmt: query, assoc, ..
+        } => match assoc {
+            QueryAssocation::Peer(peer) => usage(&[peer.name.as_str()]),
+            QueryAssocation::Catalog if session_tables.is_only_referenced(query) => {
+                Requirement::Nothing
+            }
+            QueryAssocation::Catalog => Requirement::Admin,
+        },
+        NexusStatement::FederatedQuery { tables, .. } => {
+            let peers: Vec<&str> = tables
+                .iter()
+                .map(|table| table.peer.name.as_str())
+                .collect();
+            usage(&peers)
+        }
+        NexusStatement::BuiltinFunction { name, args, .. } => {
+            match (name.as_str(), args.as_slice()) {
+                ("peerdb_mirror_lag", [flow_job_name]) => {
+                    Requirement::Privileges(vec![Privilege::OperateOnMirror(
+                        flow_job_name.to_lowercase(),
+                    )])
+                }
+                ("peerdb_peer_type", [peer_name]) => usage(&[peer_name.to_lowercase().as_str()]),
+                ("peerdb.rotate_encryption_key", _) => Requirement::Admin,
+                _ => Requirement::Nothing,
+            }
+        }
+        NexusStatement::Transaction { peer, .. } | NexusStatement::Notification { peer, .. } => {
+            usage(&[peer.name.as_str()])
+        }
+        // the cursors of a session were declared by queries that were checked.
+        NexusStatement::PeerCursor { .. }
+        | NexusStatement::SetVariable { .. }
+        | NexusStatement::SetSetting { .. }
+        | NexusStatement::ShowSetting { .. }
+        | NexusStatement::SelectConstant { .. }
+        | NexusStatement::Empty => Requirement::Nothing,
+    }
+}
+
+fn ddl_requirement(ddl: &PeerDDL) -> Requirement {
+    match ddl {
+        // validating a peer goes along with creating it, the peer may not
+        // exist yet.
+        PeerDDL::CreatePeer { .. } | PeerDDL::ValidatePeer { .. } => {
+            Requirement::Privileges(vec![Privilege::CreatePeer])
+        }
+        PeerDDL::CreateMirrorForCDC { flow_job, .. } => {
+            let mut privileges = vec![Privilege::CreateMirror];
+            privileges.extend(usage_privileges(&[
+                flow_job.source_peer.as_str(),
+                flow_job.target_peer.as_str(),
+            ]));
+            Requirement::Privileges(privileges)
+        }
+        PeerDDL::CreateMirrorForSelect { qrep_flow_job, .. } => {
+            let mut privileges = vec![Privilege::CreateMirror];
+            privileges.extend(usage_privileges(&[
+                qrep_flow_job.source_peer.as_str(),
+                qrep_flow_job.target_peer.as_str(),
+            ]));
+            Requirement::Privileges(privileges)
+        }
+        PeerDDL::ExplainMirror { ddl } => ddl_requirement(ddl),
+        PeerDDL::ExecuteMirrorForSelect { flow_job_name }
+        | PeerDDL::DropMirror { flow_job_name, .. }
+        | PeerDDL::PauseMirror { flow_job_name }
+        | PeerDDL::ResumeMirror { flow_job_name }
+        | PeerDDL::AlterMirrorAddTable { flow_job_name, .. }
+        | PeerDDL::AlterMirrorPauseTable { flow_job_name, .. }
+        | PeerDDL::AlterMirrorResumeTable { flow_job_name, .. }
+        | PeerDDL::AlterMirrorDropTable { flow_job_name, .. }
+        | PeerDDL::ResyncMirror { flow_job_name, .. }
+        | PeerDDL::CommentOnMirror { flow_job_name, .. }
+        | PeerDDL::DescribeMirror { flow_job_name } => {
+            Requirement::Privileges(vec![Privilege::OperateOnMirror(flow_job_name.clone())])
+        }
+        PeerDDL::ExecuteOnPeer { peer, .. } | PeerDDL::ShowPeerTables { peer, .. } => {
+            usage(&[peer.name.as_str()])
+        }
+        PeerDDL::ShowPeers | PeerDDL::ShowMirrors => Requirement::Nothing,
+        PeerDDL::DropPeer { .. }
+        | PeerDDL::AlterPeer { .. }
+        | PeerDDL::ReplacePeer { .. }
+        | PeerDDL::CommentOnPeer { .. }
+        | PeerDDL::CreateUser { .. }
+        | PeerDDL::AlterUser { .. }
+        | PeerDDL::DropUser { .. }
+        | PeerDDL::Grant { .. }
+        | PeerDDL::Revoke { .. }
+        | PeerDDL::CreateResourceGroup { .. }
+        | PeerDDL::AlterResourceGroup { .. }
+        | PeerDDL::AddResourceGroupMembers { .. }
+        | PeerDDL::RemoveResourceGroupMembers { .. }
+        | PeerDDL::DropResourceGroup { .. } => Requirement::Admin,
+    }
+}
+
+/// Whether the statement changes peers, mirrors, users, resource groups or the
+/// data of peers, which read-only servers and users don't run.
+pub fn is_write(stmt: &NexusStatement) -> bool {
+    match stmt {
+        NexusStatement::PeerDDL { ddl, .. } => is_write_ddl(ddl),
+        NexusStatement::PeerQuery { stmt, .. } => !is_read_query(stmt),
+        NexusStatement::Notification { stmt, .. } => {
+            matches!(stmt, NotificationStatement::Notify { .. })
+        }
+        NexusStatement::BuiltinFunction { name, .. } => name == "peerdb.rotate_encryption_key",
+        // the tables a session stages only live as long as the session.
+        NexusStatement::CreateTempTable { .. }
+        | NexusStatement::CopyOut { .. }
+        | NexusStatement::FederatedQuery { .. }
+        | NexusStatement::Transaction { .. }
+        | NexusStatement::PeerCursor { .. }
+        | NexusStatement::SetVariable { .. }
+        | NexusStatement::SetSetting { .. }
+        | NexusStatement::ShowSetting { .. }
+        | NexusStatement::SelectConstant { .. }
+        | NexusStatement::Empty => false,
+    }
+}
+
+fn is_write_ddl(ddl: &PeerDDL) -> bool {
+    match ddl {
+        PeerDDL::ValidatePeer { .. }
+        | PeerDDL::ExplainMirror { .. }
+        | PeerDDL::DescribeMirror { .. }
+        | PeerDDL::ShowPeerTables { .. }
+        | PeerDDL::ShowPeers
+        | PeerDDL::ShowMirrors => false,
+        // the statements passed through to a peer may write, nexus doesn't
+        // tell them apart.
+        PeerDDL::ExecuteOnPeer { .. }
+        | PeerDDL::CreatePeer { .. }
+        | PeerDDL::CreateMirrorForCDC { .. }
+        | PeerDDL::CreateMirrorForSelect { .. }
+        | PeerDDL::ExecuteMirrorForSelect { .. }
+        | PeerDDL::DropMirror { .. }
+        | PeerDDL::PauseMirror { .. }
+        | PeerDDL::ResumeMirror { .. }
+        | PeerDDL::AlterMirrorAddTable { .. }
+        | PeerDDL::AlterMirrorPauseTable { .. }
+        | PeerDDL::AlterMirrorResumeTable { .. }
+        | PeerDDL::AlterMirrorDropTable { .. }
+        | PeerDDL::ResyncMirror { .. }
+        | PeerDDL::CommentOnMirror { .. }
+        | PeerDDL::DropPeer { .. }
+        | PeerDDL::AlterPeer { .. }
+        | PeerDDL::ReplacePeer { .. }
+        | PeerDDL::CommentOnPeer { .. }
+        | PeerDDL::CreateUser { .. }
+        | PeerDDL::AlterUser { .. }
+        | PeerDDL::DropUser { .. }
+        | PeerDDL::Grant { .. }
+        | PeerDDL::Revoke { .. }
+        | PeerDDL::CreateResourceGroup { .. }
+        | PeerDDL::AlterResourceGroup { .. }
+        | PeerDDL::AddResourceGroupMembers { .. }
+        | PeerDDL::RemoveResourceGroupMembers { .. }
+        | PeerDDL::DropResourceGroup { .. } => true,
+    }
+}
+
+/// The functions read-only servers and users may call, the others may have
+/// side effects like `nextval` or `pg_terminate_backend`.
+const READ_ONLY_FUNCTIONS: &[&str] = &[
+    "abs",
+    "array_agg",
+    "array_length",
+    "array_to_string",
+    "avg",
+    "bool_and",
+    "bool_or",
+    "ceil",
+    "char_length",
+    "coalesce",
+    "col_description",
+    "concat",
+    "concat_ws",
+    "count",
+    "current_database",
+    "current_date",
+    "current_schema",
+    "current_schemas",
+    "current_setting",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "date_part",
+    "date_trunc",
+    "floor",
+    "format",
+    "format_type",
+    "greatest",
+    "jsonb_build_object",
+    "json_agg",
+    "json_build_object",
+    "least",
+    "left",
+    "length",
+    "lower",
+    "lpad",
+    "ltrim",
+    "max",
+    "md5",
+    "min",
+    "now",
+    "nullif",
+    "obj_description",
+    "pg_encoding_to_char",
+    "pg_get_constraintdef",
+    "pg_get_expr",
+    "pg_get_indexdef",
+    "pg_get_userbyid",
+    "pg_get_viewdef",
+    "pg_table_is_visible",
+    "pg_typeof",
+    "quote_ident",
+    "regexp_replace",
+    "replace",
+    "right",
+    "round",
+    "row_number",
+    "rpad",
+    "rtrim",
+    "session_user",
+    "split_part",
+    "string_agg",
+    "substr",
+    "sum",
+    "to_char",
+    "to_timestamp",
+    "trunc",
+    "unnest",
+    "upper",
+    "version",
+];
+
+// EXPLAIN ANALYZE runs the statement it explains.
+fn is_read_query(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::Query(query) => is_read_only_query(query) && !calls_writing_functions(stmt),
+        Statement::ShowVariable { .. }
+        | Statement::ShowTables { .. }
+        | Statement::ShowColumns { .. }
+        | Statement::ExplainTable { .. } => true,
+        Statement::Explain {
+            statement, analyze, ..
+        } => !*analyze || is_read_query(statement),
+        _ => false,
+    }
+}
+
+// `SELECT ... INTO` creates a table, `FOR UPDATE` locks rows and the CTEs of a
+// query may insert, update or delete.
+fn is_read_only_query(query: &Query) -> bool {
+    let ctes_read = match &query.with {
+        Some(with) => with
+            .cte_tables
+            .iter()
+            .all(|cte| is_read_only_query(&cte.query)),
+        None => true,
+    };
+    ctes_read && query.locks.is_empty() && is_read_only_body(&query.body)
+}
+
+fn is_read_only_body(body: &SetExpr) -> bool {
+    match body {
+        SetExpr::Select(select) => {
+            select.into.is_none() && select.from.iter().all(is_read_only_table)
+        }
+        SetExpr::Query(query) => is_read_only_query(query),
+        SetExpr::SetOperation { left, right, .. } => {
+            is_read_only_body(left) && is_read_only_body(right)
+        }
+        SetExpr::Values(_) | SetExpr::Table(_) => true,
+        SetExpr::Insert(_) | SetExpr::Update(_) => false,
+    }
+}
+
+fn is_read_only_table(table: &TableWithJoins) -> bool {
+    std::iter::once(&table.relation)
+        .chain(table.joins.iter().map(|join| &join.relation))
+        .all(|relation| match relation {
+            // the function a table is selected from, e.g. `FROM nextval('s')`.
+            TableFactor::Table {
+                name,
+                args: Some(_),
+                ..
+            } => is_read_only_function(name),
+            TableFactor::Derived { subquery, .. } => is_read_only_query(subquery),
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => is_read_only_table(table_with_joins),
+            _ => true,
+        })
+}
+
+fn calls_writing_functions(stmt: &Statement) -> bool {
+    visit_expressions(stmt, |expr| match expr {
+        Expr::Function(function) if !is_read_only_function(&function.name) => {
+            ControlFlow::Break(())
+        }
+        Expr::Subquery(query)
+        | Expr::ArraySubquery(query)
+        | Expr::Exists {
+            subquery: query, ..
+        }
+        | Expr::InSubquery {
+            subquery: query, ..
+        } if !is_read_only_query(query) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_break()
+}
+
+// a function of another schema than pg_catalog may be one that writes.
+fn is_read_only_function(name: &ObjectName) -> bool {
+    let function = match name.0.as_slice() {
+        [function] => function,
+        [schema, function] if schema.value.eq_ignore_ascii_case("pg_catalog") => function,
+        _ => return false,
+    };
+    READ_ONLY_FUNCTIONS.contains(&function.value.to_lowercase().as_str())
+}
+
+fn usage(peers: &[&str]) -> Requirement {
+    Requirement::Privileges(usage_privileges(peers))
+}
+
+fn usage_privileges(peers: &[&str]) -> Vec<Privilege> {
+    let mut privileges: Vec<Privilege> = vec![];
+    for peer in peers {
+        let privilege = Privilege::UsageOnPeer(peer.to_string());
+        if !privileges.contains(&privilege) {
+            privileges.push(privilege);
+        }
+    }
+    privileges
+}
+
+/// The privilege on the peer or mirror a statement creates, it is granted to
+/// the user that created it.
+pub fn created_object(stmt: &NexusStatement) -> Option<Privilege> {
+    let ddl = match stmt {
+        NexusStatement::PeerDDL { ddl, .. } => ddl,
+        _ => return None,
+    };
+    match ddl.as_ref() {
+        PeerDDL::CreatePeer { peer, .. } => Some(Privilege::UsageOnPeer(peer.name.clone())),
+        PeerDDL::CreateMirrorForCDC { flow_job, .. } => {
+            Some(Privilege::OperateOnMirror(flow_job.name.clone()))
+        }
+        PeerDDL::CreateMirrorForSelect { qrep_flow_job, .. } => {
+            Some(Privilege::OperateOnMirror(qrep_flow_job.name.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// The password of a user as it is stored in the catalog, the md5 of the
+/// password followed by the name like postgres stores md5 passwords.
+pub fn password_hash(user_name: &str, password: &str) -> String {
+    format!("{:x}", md5::compute(format!("{}{}", password, user_name)))
+}
+
+/// The response a client authenticating with md5 is expected to send for a
+/// user with the password hash, given the salt it was sent.
+pub fn salted_password_hash(password_hash: &str, salt: &[u8]) -> String {
+    let mut salted = password_hash.as_bytes().to_vec();
+    salted.extend_from_slice(salt);
+    format!("md5{:x}", md5::compute(salted))
+}
+
+/// The SCRAM-SHA-256 secret of a password, salted with a random salt.
+pub fn scram_secret(password: &str) -> ScramSecret {
+    let salt = rand::thread_rng().gen::<[u8; 16]>().to_vec();
+    let salted_password = gen_salted_password(password, &salt, SCRAM_ITERATIONS);
+    ScramSecret {
+        salt,
+        salted_password,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::{
+        ast::{LockClause, LockType, Statement},
+        dialect::PostgreSqlDialect,
+        parser::Parser,
+    };
+
+    use super::is_read_query;
+
+    fn is_read(sql: &str) -> bool {
+        let stmts = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap();
+        is_read_query(&stmts[0])
+    }
+
+    #[test]
+    fn queries_are_reads() {
+        assert!(is_read("SELECT * FROM t"));
+        assert!(is_read(
+            "SELECT count(*), max(id) FROM t WHERE name = lower('A')"
+        ));
+        assert!(is_read("SELECT pg_catalog.format_type(1, 2)"));
+        assert!(is_read("SELECT * FROM unnest(ARRAY[1, 2])"));
+        assert!(is_read("WITH x AS (SELECT * FROM t) SELECT * FROM x"));
+        assert!(is_read(
+            "SELECT * FROM t UNION SELECT * FROM (SELECT * FROM u) AS v"
+        ));
+        assert!(is_read("SELECT * FROM t WHERE id IN (SELECT id FROM u)"));
+        assert!(is_read("EXPLAIN DELETE FROM t"));
+    }
+
+    #[test]
+    fn select_into_is_a_write() {
+        assert!(!is_read("SELECT * INTO t2 FROM t"));
+        assert!(!is_read("SELECT 1 UNION SELECT * INTO t2 FROM t"));
+    }
+
+    #[test]
+    fn data_modifying_ctes_are_writes() {
+        assert!(!is_read(
+            "WITH x AS (INSERT INTO t VALUES (1) RETURNING *) SELECT * FROM x"
+        ));
+        assert!(!is_read(
+            "WITH x AS (UPDATE t SET id = 1 RETURNING *) SELECT * FROM x"
+        ));
+        assert!(!is_read(
+            "WITH x AS (WITH y AS (UPDATE t SET id = 1 RETURNING *) SELECT * FROM y) SELECT * FROM x"
+        ));
+    }
+
+    // the parser doesn't take `FOR UPDATE` from postgres queries yet.
+    #[test]
+    fn locking_rows_is_a_write() {
+        let mut stmts = Parser::parse_sql(&PostgreSqlDialect {}, "SELECT * FROM t").unwrap();
+        if let Statement::Query(query) = &mut stmts[0] {
+            query.locks.push(LockClause {
+                lock_type: LockType::Update,
+                of: None,
+                nonblock: None,
+            });
+        }
+        assert!(!is_read_query(&stmts[0]));
+    }
+
+    #[test]
+    fn calling_other_functions_is_a_write() {
+        assert!(!is_read("SELECT pg_terminate_backend(1)"));
+        assert!(!is_read("SELECT nextval('s')"));
+        assert!(!is_read("SELECT * FROM t WHERE id = setval('s', 1)"));
+        assert!(!is_read("SELECT public.count(*) FROM t"));
+        assert!(!is_read("SELECT * FROM nextval('s')"));
+        assert!(!is_read(
+            "SELECT * FROM t JOIN (SELECT nextval('s')) AS u ON true"
+        ));
+        assert!(!is_read(
+            "SELECT * FROM t WHERE EXISTS (SELECT nextval('s'))"
+        ));
+        assert!(!is_read("EXPLAIN ANALYZE SELECT nextval('s')"));
+    }
+}
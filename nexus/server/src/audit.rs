@@ -0,0 +1,269 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use analyzer::{PeerDDL, QueryAssocation};
+use futures::Stream;
+use peer_cursor::{Record, RecordStream, SchemaRef, SendableStream};
+use peerdb_parser::NexusStatement;
+use pgwire::error::{PgWireError, PgWireResult};
+use sqlparser::ast::Statement;
+
+/// Which statements are written to the audit log.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum AuditLog {
+    None,
+    /// The statements that create, alter or drop peers, mirrors, users and the
+    /// tables of peers, and those passed through to peers.
+    Ddl,
+    All,
+}
+
+/// The audit record of a statement, logged to the `peerdb::audit` target once
+/// the statement ran and the rows it returned were sent, i.e. when the last
+/// reference to it, which the rows being sent hold, is dropped.
+pub struct AuditEntry {
+    user: String,
+    // the peers the statement was routed to, none for the catalog and nexus.
+    peers: Vec<String>,
+    statement: String,
+    started: Instant,
+    rows: Mutex<Option<usize>>,
+    error: Mutex<Option<String>>,
+}
+
+impl AuditEntry {
+    /// The entry of the statement, None when the audit log leaves it out.
+    pub fn new(log: AuditLog, user: String, stmt: &NexusStatement) -> Option<Arc<Self>> {
+        match log {
+            AuditLog::None => return None,
+            AuditLog::Ddl if !is_ddl(stmt) => return None,
+            _ => {}
+        }
+        Some(Arc::new(Self {
+            user,
+            peers: peers(stmt),
+            statement: describe(stmt),
+            started: Instant::now(),
+            rows: Mutex::new(None),
+            error: Mutex::new(None),
+        }))
+    }
+
+    /// Counts rows the statement returned or affected.
+    pub fn add_rows(&self, rows: usize) {
+        *self.rows.lock().unwrap().get_or_insert(0) += rows;
+    }
+
+    pub fn fail(&self, err: &PgWireError) {
+        *self.error.lock().unwrap() = Some(err.to_string());
+    }
+}
+
+impl Drop for AuditEntry {
+    fn drop(&mut self) {
+        let rows = *self.rows.lock().unwrap();
+        let error = self.error.lock().unwrap().take();
+        tracing::info!(
+            target: "peerdb::audit",
+            user = %self.user,
+            peers = %self.peers.join(","),
+            statement = %self.statement,
+            rows = ?rows,
+            duration_ms = self.started.elapsed().as_millis() as u64,
+            error = ?error,
+            "statement ran"
+        );
+    }
+}
+
+/// Counts the rows of the stream into the audit entry of its statement.
+pub fn audited(rows: SendableStream, entry: Option<Arc<AuditEntry>>) -> SendableStream {
+    match entry {
+        Some(entry) => Box::pin(AuditedStream { rows, entry }),
+        None => rows,
+    }
+}
+
+struct AuditedStream {
+    rows: SendableStream,
+    entry: Arc<AuditEntry>,
+}
+
+impl Stream for AuditedStream {
+    type Item = PgWireResult<Record>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let polled = self.rows.as_mut().poll_next(cx);
+        match &polled {
+            Poll::Ready(Some(Ok(_))) => self.entry.add_rows(1),
+            Poll::Ready(Some(Err(err))) => self.entry.fail(err),
+            _ => {}
+        }
+        polled
+    }
+}
+
+impl RecordStream for AuditedStream {
+    fn schema(&self) -> SchemaRef {
+        self.rows.schema()
+    }
+}
+
+fn is_ddl(stmt: &NexusStatement) -> bool {
+    match stmt {
+        NexusStatement::PeerDDL { ddl, .. } => !matches!(
+            ddl.as_ref(),
+            PeerDDL::ShowPeers
+                | PeerDDL::ShowMirrors
+                | PeerDDL::ShowPeerTables { .. }
+                | PeerDDL::DescribeMirror { .. }
+                | PeerDDL::ExplainMirror { .. }
+        ),
+        NexusStatement::PeerQuery { stmt, .. } => matches!(
+            stmt,
+            Statement::CreateTable { .. }
+                | Statement::CreateView { .. }
+                | Statement::CreateIndex { .. }
+                | Statement::CreateSchema { .. }
+                | Statement::AlterTable { .. }
+                | Statement::AlterIndex { .. }
+                | Statement::Drop { .. }
+                | Statement::Truncate { .. }
+        ),
+        _ => false,
+    }
+}
+
+fn peers(stmt: &NexusStatement) -> Vec<String> {
+    let mut peers: Vec<String> = match stmt {
+        NexusStatement::PeerQuery { assoc, .. }
+        | NexusStatement::CreateTempTable { assoc, .. }
+        | NexusStatement::CopyOut { assoc, .. } => match assoc {
+            QueryAssocation::Peer(peer) => vec![peer.name.clone()],
+            QueryAssocation::Catalog => vec![],
+        },
+        NexusStatement::FederatedQuery { tables, .. } => {
+            tables.iter().map(|table| table.peer.name.clone()).collect()
+        }
+        NexusStatement::Transaction { peer, .. } | NexusStatement::Notification { peer, .. } => {
+            vec![peer.name.clone()]
+        }
+        NexusStatement::PeerDDL { ddl, .. } => match ddl.as_ref() {
+            PeerDDL::ExecuteOnPeer { peer, .. } | PeerDDL::ShowPeerTables { peer, .. } => {
+                vec![peer.name.clone()]
+            }
+            _ => vec![],
+        },
+        _ => vec![],
+    };
+    peers.sort();
+    peers.dedup();
+    peers
+}
+
+/// The statement as it was routed, the statements on peers after nexus
+/// rewrote them. Peer and user DDL is described without the options and
+/// passwords it carries, so that it can be logged.
+pub fn describe(stmt: &NexusStatement) -> String {
+    match stmt {
+        NexusStatement::PeerDDL { ddl, .. } => describe_ddl(ddl),
+        NexusStatement::PeerQuery { stmt, .. }
+        | NexusStatement::PeerCursor { stmt, .. }
+        | NexusStatement::FederatedQuery { stmt, .. }
+        | NexusStatement::CopyOut { stmt, .. }
+        | NexusStatement::Transaction { stmt, .. } => stmt.to_string(),
+        NexusStatement::Notification { stmt, .. } => stmt.to_string(),
+        NexusStatement::CreateTempTable {
+            table_name, query, ..
+        } => format!("CREATE TEMP TABLE {} AS {}", table_name, query),
+        NexusStatement::SetVariable { name, value }
+        | NexusStatement::SetSetting { name, value } => {
+            format!("SET {} = {}", name, value.as_deref().unwrap_or("DEFAULT"))
+        }
+        NexusStatement::ShowSetting { name } => format!("SHOW {}", name),
+        // the keys the encryption key is rotated with are never logged.
+        NexusStatement::BuiltinFunction { name, .. } if name == "peerdb.rotate_encryption_key" => {
+            format!("SELECT {}(***, ***)", name)
+        }
+        NexusStatement::BuiltinFunction { name, args, .. } => {
+            format!("SELECT {}({})", name, args.join(", "))
+        }
+        NexusStatement::SelectConstant { value, .. } => format!("SELECT {}", value),
+        NexusStatement::Empty => String::new(),
+    }
+}
+
+fn describe_ddl(ddl: &PeerDDL) -> String {
+    match ddl {
+        PeerDDL::CreatePeer { peer, .. } => format!("CREATE PEER {}", peer.name),
+        PeerDDL::CreateMirrorForCDC { flow_job, .. } => {
+            format!("CREATE MIRROR {}", flow_job.name)
+        }
+        PeerDDL::CreateMirrorForSelect { qrep_flow_job, .. } => {
+            format!("CREATE MIRROR {}", qrep_flow_job.name)
+        }
+        PeerDDL::ExecuteMirrorForSelect { flow_job_name } => {
+            format!("EXECUTE MIRROR {}", flow_job_name)
+        }
+        PeerDDL::ExecuteOnPeer { peer, query } => {
+            format!("EXECUTE '{}' ON PEER {}", query, peer.name)
+        }
+        PeerDDL::DropMirror { flow_job_name, .. } => format!("DROP MIRROR {}", flow_job_name),
+        PeerDDL::PauseMirror { flow_job_name } => format!("PAUSE MIRROR {}", flow_job_name),
+        PeerDDL::ResumeMirror { flow_job_name } => format!("RESUME MIRROR {}", flow_job_name),
+        PeerDDL::AlterMirrorAddTable { flow_job_name, .. }
+        | PeerDDL::AlterMirrorPauseTable { flow_job_name, .. }
+        | PeerDDL::AlterMirrorResumeTable { flow_job_name, .. }
+        | PeerDDL::AlterMirrorDropTable { flow_job_name, .. } => {
+            format!("ALTER MIRROR {}", flow_job_name)
+        }
+        PeerDDL::ResyncMirror { flow_job_name, .. } => format!("RESYNC MIRROR {}", flow_job_name),
+        PeerDDL::DropPeer { peer_name, .. } => format!("DROP PEER {}", peer_name),
+        PeerDDL::AlterPeer { peer } => format!("ALTER PEER {}", peer.name),
+        PeerDDL::ReplacePeer { new_peer, .. } => {
+            format!("CREATE OR REPLACE PEER {}", new_peer.name)
+        }
+        PeerDDL::ShowPeers => "SHOW PEERS".to_owned(),
+        PeerDDL::ShowPeerTables { peer, .. } => format!("SHOW TABLES IN PEER {}", peer.name),
+        PeerDDL::ShowMirrors => "SHOW MIRRORS".to_owned(),
+        PeerDDL::DescribeMirror { flow_job_name } => {
+            format!("DESCRIBE MIRROR {}", flow_job_name)
+        }
+        PeerDDL::CommentOnPeer { peer_name, .. } => format!("COMMENT ON PEER {}", peer_name),
+        PeerDDL::CommentOnMirror { flow_job_name, .. } => {
+            format!("COMMENT ON MIRROR {}", flow_job_name)
+        }
+        PeerDDL::ValidatePeer { peer } => format!("VALIDATE PEER {}", peer.name),
+        PeerDDL::ExplainMirror { ddl } => format!("EXPLAIN {}", describe_ddl(ddl)),
+        PeerDDL::CreateUser { name, .. } => format!("CREATE USER {}", name),
+        PeerDDL::AlterUser { name, .. } => format!("ALTER USER {}", name),
+        PeerDDL::DropUser { name, .. } => format!("DROP USER {}", name),
+        PeerDDL::Grant {
+            privilege,
+            grantees,
+        } => format!("GRANT {} TO {}", privilege, grantees.join(", ")),
+        PeerDDL::Revoke {
+            privilege,
+            grantees,
+        } => format!("REVOKE {} FROM {}", privilege, grantees.join(", ")),
+        PeerDDL::CreateResourceGroup { group, .. } => {
+            format!("CREATE RESOURCE GROUP {}", group.name)
+        }
+        PeerDDL::AlterResourceGroup { name, .. } => format!("ALTER RESOURCE GROUP {}", name),
+        PeerDDL::AddResourceGroupMembers { name, user_names } => format!(
+            "ALTER RESOURCE GROUP {} ADD USER {}",
+            name,
+            user_names.join(", ")
+        ),
+        PeerDDL::RemoveResourceGroupMembers { name, user_names } => format!(
+            "ALTER RESOURCE GROUP {} DROP USER {}",
+            name,
+            user_names.join(", ")
+        ),
+        PeerDDL::DropResourceGroup { name, .. } => format!("DROP RESOURCE GROUP {}", name),
+    }
+}
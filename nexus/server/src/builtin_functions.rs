@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use peer_cursor::{Record, Records, Schema, SchemaRef};
+use pgwire::api::{
+    results::{FieldFormat, FieldInfo},
+    Type,
+};
+use value::Value;
+
+// the version nexus reports for peerdb_version(), the one of the server.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// the version nexus reports for version(), in the format of postgres, of the
+// version in the server_version parameter, so that drivers parsing it treat
+// nexus like that postgres.
+pub fn postgres_version() -> String {
+    format!(
+        "PostgreSQL 14.0 (PeerDB {}) on {}-{}, 64-bit",
+        VERSION,
+        std::env::consts::ARCH,
+        std::env::consts::OS
+    )
+}
+
+fn datatype(name: &str) -> Type {
+    match name {
+        "peerdb_mirror_lag" => Type::FLOAT8,
+        "peerdb_connections" | "peerdb_peak_connections" => Type::INT8,
+        _ => Type::TEXT,
+    }
+}
+
+// like postgres, the result of a function is a single row of a single
+// column.
+pub fn schema(name: &str, column: &str) -> SchemaRef {
+    column_schema(column, datatype(name))
+}
+
+pub fn records(name: &str, column: &str, value: Value) -> Records {
+    single_value(schema(name, column), value)
+}
+
+// the result of `SELECT <integer>`, an int4 column like in postgres.
+pub fn constant_schema(column: &str) -> SchemaRef {
+    column_schema(column, Type::INT4)
+}
+
+pub fn constant_records(column: &str, value: i32) -> Records {
+    single_value(constant_schema(column), Value::Integer(value))
+}
+
+fn column_schema(column: &str, datatype: Type) -> SchemaRef {
+    Arc::new(Schema {
+        fields: vec![FieldInfo::new(
+            column.to_owned(),
+            None,
+            None,
+            datatype,
+            FieldFormat::Text,
+        )],
+    })
+}
+
+fn single_value(schema: SchemaRef, value: Value) -> Records {
+    Records {
+        records: vec![Record {
+            values: vec![value],
+            schema: schema.clone(),
+        }],
+        schema,
+    }
+}
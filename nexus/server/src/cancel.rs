@@ -0,0 +1,184 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    net::SocketAddr,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+};
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use futures::Sink;
+use pgwire::{
+    api::{auth::StartupHandler, ClientInfo, PgWireConnectionState},
+    error::{PgWireError, PgWireResult},
+    messages::{PgWireBackendMessage, PgWireFrontendMessage},
+};
+use tokio::{io::AsyncReadExt, net::TcpStream};
+
+// the code of the CancelRequest message, sent in place of a protocol version
+// on a new connection, followed by the backend key data of the session.
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// the process id and secret key a client was sent at startup.
+type BackendKey = (i32, i32);
+
+/// A session the cancel requests of its client are handed to.
+#[async_trait]
+pub trait CancelSession: Send + Sync {
+    async fn cancel(&self);
+}
+
+/// The sessions of the server, by the backend key data of their clients.
+#[derive(Default)]
+pub struct CancelKeys {
+    sessions: DashMap<BackendKey, Weak<dyn CancelSession>>,
+}
+
+impl CancelKeys {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels what the session with the key is running, a key that matches
+    /// no session is ignored like postgres does.
+    pub async fn cancel(&self, key: BackendKey) {
+        let session = self.sessions.get(&key).and_then(|s| s.upgrade());
+        match session {
+            Some(session) => session.cancel().await,
+            None => tracing::info!("cancel request for an unknown session"),
+        }
+    }
+}
+
+/// The backend key data of the cancel request the client connected to send,
+/// if it did. The socket is only peeked at otherwise, so that the startup of
+/// other clients is still handled by pgwire.
+pub async fn read_cancel_request(socket: &mut TcpStream) -> std::io::Result<Option<BackendKey>> {
+    let mut buf = [0u8; 16];
+    let n = socket.peek(&mut buf).await?;
+    if n < 8 {
+        return Ok(None);
+    }
+    let len = i32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]);
+    let code = i32::from_be_bytes([buf[4], buf[5], buf[6], buf[7]]);
+    if len != 16 || code != CANCEL_REQUEST_CODE {
+        return Ok(None);
+    }
+
+    socket.read_exact(&mut buf).await?;
+    let pid = i32::from_be_bytes([buf[8], buf[9], buf[10], buf[11]]);
+    let secret_key = i32::from_be_bytes([buf[12], buf[13], buf[14], buf[15]]);
+    Ok(Some((pid, secret_key)))
+}
+
+/// Registers the session of a connection under the backend key data pgwire
+/// sends once the client is authenticated, the authentication itself is left
+/// to the wrapped handler. The session is unregistered when the connection
+/// ends and the handler is dropped.
+pub struct CancelKeyStartupHandler<H> {
+    inner: Arc<H>,
+    keys: Arc<CancelKeys>,
+    session: Weak<dyn CancelSession>,
+    key: Mutex<Option<BackendKey>>,
+}
+
+impl<H> CancelKeyStartupHandler<H> {
+    pub fn new(inner: Arc<H>, keys: Arc<CancelKeys>, session: Weak<dyn CancelSession>) -> Self {
+        Self {
+            inner,
+            keys,
+            session,
+            key: Mutex::new(None),
+        }
+    }
+}
+
+impl<H> Drop for CancelKeyStartupHandler<H> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.get_mut().unwrap().take() {
+            self.keys.sessions.remove(&key);
+        }
+    }
+}
+
+#[async_trait]
+impl<H: StartupHandler> StartupHandler for CancelKeyStartupHandler<H> {
+    async fn on_startup<C>(
+        &self,
+        client: &mut C,
+        message: PgWireFrontendMessage,
+    ) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        let mut client = KeyDataSink { client, key: None };
+        self.inner.on_startup(&mut client, message).await?;
+        if let Some(key) = client.key {
+            *self.key.lock().unwrap() = Some(key);
+            self.keys.sessions.insert(key, self.session.clone());
+        }
+        Ok(())
+    }
+}
+
+// the client of a connection, which remembers the backend key data sent to it.
+struct KeyDataSink<'a, C> {
+    client: &'a mut C,
+    key: Option<BackendKey>,
+}
+
+impl<C: ClientInfo> ClientInfo for KeyDataSink<'_, C> {
+    fn socket_addr(&self) -> &SocketAddr {
+        self.client.socket_addr()
+    }
+
+    fn is_secure(&self) -> bool {
+        self.client.is_secure()
+    }
+
+    fn state(&self) -> &PgWireConnectionState {
+        self.client.state()
+    }
+
+    fn set_state(&mut self, new_state: PgWireConnectionState) {
+        self.client.set_state(new_state)
+    }
+
+    fn metadata(&self) -> &HashMap<String, String> {
+        self.client.metadata()
+    }
+
+    fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        self.client.metadata_mut()
+    }
+}
+
+impl<C> Sink<PgWireBackendMessage> for KeyDataSink<'_, C>
+where
+    C: Sink<PgWireBackendMessage> + Unpin,
+{
+    type Error = C::Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut *self.client).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: PgWireBackendMessage) -> Result<(), Self::Error> {
+        if let PgWireBackendMessage::BackendKeyData(key_data) = &item {
+            self.key = Some((*key_data.pid(), *key_data.secret_key()));
+        }
+        Pin::new(&mut *self.client).start_send(item)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut *self.client).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut *self.client).poll_close(cx)
+    }
+}
@@ -0,0 +1,413 @@
+use std::{collections::HashMap, ops::ControlFlow};
+
+use peer_cursor::{
+    connector::{ColumnInfo, TableInfo},
+    util::cte_names,
+};
+use sqlparser::{
+    ast::{
+        visit_expressions_mut, visit_relations, visit_relations_mut, Expr, ObjectName, Query,
+        Statement, Value,
+    },
+    dialect::PostgreSqlDialect,
+    parser::Parser,
+};
+
+// the first oid handed out to the emulated schemas and tables. The oids are
+// far above those of the objects of the catalog database, so the system
+// relations psql joins the emulated ones with, e.g. pg_index, have no rows
+// for them.
+const FIRST_OID: u32 = 1 << 31;
+
+// the system relations psql's `\d` reads besides the emulated ones, which
+// hold nothing of the catalog for the emulated oids.
+const DESCRIBE_RELATIONS: &[&str] = &[
+    "pg_am",
+    "pg_attrdef",
+    "pg_collation",
+    "pg_constraint",
+    "pg_foreign_table",
+    "pg_index",
+    "pg_inherits",
+    "pg_partitioned_table",
+    "pg_policy",
+    "pg_publication",
+    "pg_publication_namespace",
+    "pg_publication_rel",
+    "pg_rewrite",
+    "pg_statistic_ext",
+    "pg_tablespace",
+    "pg_trigger",
+    "pg_type",
+];
+
+/// The catalog relations BI tools query on connect that nexus answers
+/// itself. Each peer is a schema, and the tables of the peers are its
+/// tables. The relations are emulated with common table expressions named
+/// after them, so queries on them run on the catalog like other catalog
+/// queries do.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EmulatedRelation {
+    PgNamespace,
+    PgClass,
+    PgAttribute,
+    PgTables,
+    Schemata,
+    Tables,
+}
+
+impl EmulatedRelation {
+    // the relation a name refers to, the pg_catalog relations are also found
+    // unqualified like they are on the search path.
+    fn from_name(name: &ObjectName) -> Option<Self> {
+        let parts: Vec<String> = name
+            .0
+            .iter()
+            .map(|part| part.value.to_lowercase())
+            .collect();
+        let parts: Vec<&str> = parts.iter().map(String::as_str).collect();
+        match parts.as_slice() {
+            ["pg_namespace"] | ["pg_catalog", "pg_namespace"] => Some(Self::PgNamespace),
+            ["pg_class"] | ["pg_catalog", "pg_class"] => Some(Self::PgClass),
+            ["pg_attribute"] | ["pg_catalog", "pg_attribute"] => Some(Self::PgAttribute),
+            ["pg_tables"] | ["pg_catalog", "pg_tables"] => Some(Self::PgTables),
+            ["information_schema", "schemata"] => Some(Self::Schemata),
+            ["information_schema", "tables"] => Some(Self::Tables),
+            _ => None,
+        }
+    }
+
+    fn cte_name(self) -> &'static str {
+        match self {
+            Self::PgNamespace => "pg_namespace",
+            Self::PgClass => "pg_class",
+            Self::PgAttribute => "pg_attribute",
+            Self::PgTables => "pg_tables",
+            Self::Schemata => "schemata",
+            Self::Tables => "tables",
+        }
+    }
+
+    fn columns(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::PgNamespace => &[
+                ("oid", "oid"),
+                ("nspname", "name"),
+                ("nspowner", "oid"),
+                ("nspacl", "aclitem[]"),
+            ],
+            Self::PgClass => &[
+                ("oid", "oid"),
+                ("relname", "name"),
+                ("relnamespace", "oid"),
+                ("reltype", "oid"),
+                ("relowner", "oid"),
+                ("relam", "oid"),
+                ("relkind", "\"char\""),
+                ("reltuples", "real"),
+                ("relhasindex", "bool"),
+                ("relpersistence", "\"char\""),
+                ("relispartition", "bool"),
+                ("relacl", "aclitem[]"),
+                ("relchecks", "int2"),
+                ("relhasrules", "bool"),
+                ("relhastriggers", "bool"),
+                ("relrowsecurity", "bool"),
+                ("relforcerowsecurity", "bool"),
+                ("reloptions", "text[]"),
+                ("reltoastrelid", "oid"),
+                ("reltablespace", "oid"),
+                ("reloftype", "oid"),
+                ("relreplident", "\"char\""),
+                ("relpartbound", "text"),
+            ],
+            Self::PgAttribute => &[
+                ("attrelid", "oid"),
+                ("attname", "name"),
+                ("atttypid", "oid"),
+                ("atttypmod", "int4"),
+                ("attnum", "int2"),
+                ("attnotnull", "bool"),
+                ("atthasdef", "bool"),
+                ("attisdropped", "bool"),
+                ("attidentity", "\"char\""),
+                ("attgenerated", "\"char\""),
+                ("attcollation", "oid"),
+                ("attstorage", "\"char\""),
+                ("attcompression", "\"char\""),
+                ("attstattarget", "int2"),
+            ],
+            Self::PgTables => &[
+                ("schemaname", "name"),
+                ("tablename", "name"),
+                ("tableowner", "name"),
+                ("tablespace", "name"),
+                ("hasindexes", "bool"),
+                ("hasrules", "bool"),
+                ("hastriggers", "bool"),
+                ("rowsecurity", "bool"),
+            ],
+            Self::Schemata => &[
+                ("catalog_name", "name"),
+                ("schema_name", "name"),
+                ("schema_owner", "name"),
+            ],
+            Self::Tables => &[
+                ("table_catalog", "name"),
+                ("table_schema", "name"),
+                ("table_name", "name"),
+                ("table_type", "text"),
+                ("is_insertable_into", "text"),
+                ("is_typed", "text"),
+            ],
+        }
+    }
+
+    fn lists_tables(self) -> bool {
+        matches!(
+            self,
+            Self::PgClass | Self::PgAttribute | Self::PgTables | Self::Tables
+        )
+    }
+}
+
+/// A peer as the emulated catalog shows it, the tables and columns are empty
+/// for peers whose tables can't be listed.
+pub struct EmulatedPeer {
+    pub name: String,
+    pub tables: Vec<TableInfo>,
+    pub columns: Vec<ColumnInfo>,
+}
+
+fn emulated_relations(stmt: &Statement) -> Vec<EmulatedRelation> {
+    let mut relations = vec![];
+    let ctes = match stmt {
+        Statement::Query(query) => cte_names(query),
+        _ => return relations,
+    };
+    let _ = visit_relations(stmt, |relation| {
+        let is_cte = relation.0.len() == 1 && ctes.contains(&relation.0[0].value.to_lowercase());
+        if let Some(emulated) = EmulatedRelation::from_name(relation).filter(|_| !is_cte) {
+            if !relations.contains(&emulated) {
+                relations.push(emulated);
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    relations
+}
+
+/// Whether the catalog query reads the emulated catalog relations, only
+/// queries are emulated.
+pub fn is_emulated(stmt: &Statement) -> bool {
+    !emulated_relations(stmt).is_empty()
+}
+
+/// Whether the emulated catalog relations list the tables of the peers, which
+/// then have to be looked up.
+pub fn lists_tables(stmt: &Statement) -> bool {
+    emulated_relations(stmt)
+        .iter()
+        .any(|relation| relation.lists_tables())
+}
+
+/// Whether the emulated catalog relations list the columns of the tables of
+/// the peers, as psql's `\d table` reads them.
+pub fn lists_columns(stmt: &Statement) -> bool {
+    emulated_relations(stmt).contains(&EmulatedRelation::PgAttribute)
+}
+
+// whether the relation is one of the system relations psql describes tables
+// with.
+fn is_describe_relation(name: &ObjectName) -> bool {
+    let parts: Vec<String> = name
+        .0
+        .iter()
+        .map(|part| part.value.to_lowercase())
+        .collect();
+    match parts
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [relation] | ["pg_catalog", relation] => DESCRIBE_RELATIONS.contains(relation),
+        _ => false,
+    }
+}
+
+/// Whether every relation of the query is an emulated catalog relation, a
+/// system relation psql describes tables with, or passes the check, e.g. of
+/// being a table of the session.
+pub fn is_only_emulated(stmt: &Statement, is_allowed: impl Fn(&ObjectName) -> bool) -> bool {
+    let other = visit_relations(stmt, |relation| {
+        if EmulatedRelation::from_name(relation).is_none()
+            && !is_describe_relation(relation)
+            && !is_allowed(relation)
+        {
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(())
+    });
+    other.is_continue() && is_emulated(stmt)
+}
+
+/// Rewrites the query to read the emulated catalog relations of the peers
+/// instead of those of the catalog.
+pub fn emulate(stmt: &mut Statement, peers: &[EmulatedPeer]) -> anyhow::Result<()> {
+    let relations = emulated_relations(stmt);
+    let query = match stmt {
+        Statement::Query(query) if !relations.is_empty() => query,
+        _ => return Ok(()),
+    };
+    let ctes = cte_names(query);
+
+    let mut with = emulated_ctes(&relations, peers)?;
+    visit_relations_mut(query.as_mut(), |relation| {
+        let is_cte = relation.0.len() == 1 && ctes.contains(&relation.0[0].value.to_lowercase());
+        if let Some(emulated) = EmulatedRelation::from_name(relation).filter(|_| !is_cte) {
+            *relation = ObjectName(vec![emulated.cte_name().into()]);
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    // the emulated tables aren't on the search path of the catalog, psql only
+    // lists the visible ones.
+    visit_expressions_mut(query.as_mut(), |expr| {
+        let is_visibility_check = matches!(expr, Expr::Function(function)
+        if function.name.0.last().map_or(false, |part| {
+            part.value.eq_ignore_ascii_case("pg_table_is_visible")
+        }));
+        if is_visibility_check {
+            *expr = Expr::Value(Value::Boolean(true));
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    // the emulated relations come first, so the common table expressions of
+    // the query can read them.
+    if let Some(query_with) = query.with.take() {
+        with.cte_tables.extend(query_with.cte_tables);
+        with.recursive = query_with.recursive;
+    }
+    query.with = Some(with);
+    Ok(())
+}
+
+fn emulated_ctes(
+    relations: &[EmulatedRelation],
+    peers: &[EmulatedPeer],
+) -> anyhow::Result<sqlparser::ast::With> {
+    let ctes: Vec<String> = relations
+        .iter()
+        .map(|relation| {
+            let columns = relation.columns();
+            let rows = rows(*relation, peers);
+            let casts = columns
+                .iter()
+                .map(|(name, datatype)| format!("{}::{} AS {}", name, datatype, name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let names = columns
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            // VALUES can't be empty, an empty relation is a row of nulls
+            // that is filtered out.
+            let (values, filter) = if rows.is_empty() {
+                (
+                    format!("({})", vec!["NULL"; columns.len()].join(", ")),
+                    " WHERE false",
+                )
+            } else {
+                (rows.join(", "), "")
+            };
+            format!(
+                "{} AS (SELECT {} FROM (VALUES {}) AS v({}){})",
+                relation.cte_name(),
+                casts,
+                values,
+                names,
+                filter
+            )
+        })
+        .collect();
+
+    let sql = format!("WITH {} SELECT 1", ctes.join(", "));
+    let mut stmts = Parser::parse_sql(&PostgreSqlDialect {}, &sql)?;
+    match stmts.pop() {
+        Some(Statement::Query(query)) => {
+            let Query { with, .. } = *query;
+            with.ok_or_else(|| anyhow::anyhow!("emulated catalog relations are missing"))
+        }
+        _ => anyhow::bail!("emulated catalog relations are not a query"),
+    }
+}
+
+fn rows(relation: EmulatedRelation, peers: &[EmulatedPeer]) -> Vec<String> {
+    let mut rows = vec![];
+    let mut table_oid = FIRST_OID + peers.len() as u32;
+    for (idx, peer) in peers.iter().enumerate() {
+        let namespace_oid = FIRST_OID + idx as u32;
+        let name = literal(&peer.name);
+        let mut columns: HashMap<&str, Vec<&ColumnInfo>> = HashMap::new();
+        if relation == EmulatedRelation::PgAttribute {
+            for column in &peer.columns {
+                columns
+                    .entry(column.table.as_str())
+                    .or_default()
+                    .push(column);
+            }
+        }
+        match relation {
+            EmulatedRelation::PgNamespace => {
+                rows.push(format!("({}, {}, 10, NULL)", namespace_oid, name));
+            }
+            EmulatedRelation::Schemata => {
+                rows.push(format!("(current_database(), {}, current_user)", name));
+            }
+            _ => {}
+        }
+        for table in &peer.tables {
+            let table_name = literal(&table.name);
+            let estimated_rows = table.estimated_rows.unwrap_or(-1);
+            match relation {
+                EmulatedRelation::PgClass => rows.push(format!(
+                    "({}, {}, {}, 0, 10, 2, 'r', {}, {}, 'p', false, NULL, \
+                     0, false, false, false, false, NULL, 0, 0, 0, 'd', NULL)",
+                    table_oid, table_name, namespace_oid, estimated_rows, table.has_primary_key
+                )),
+                // the types are looked up in the catalog by the names the
+                // peer gives them, the ones postgres doesn't know are text.
+                EmulatedRelation::PgAttribute => {
+                    let table_columns = columns.get(table.name.as_str());
+                    for (idx, column) in table_columns.into_iter().flatten().enumerate() {
+                        rows.push(format!(
+                            "({}, {}, COALESCE(to_regtype({}), 'text'::regtype), -1, {}, {}, \
+                             false, false, '', '', 0, 'p', '', -1)",
+                            table_oid,
+                            literal(&column.name),
+                            literal(&column.data_type),
+                            idx + 1,
+                            !column.nullable
+                        ));
+                    }
+                }
+                EmulatedRelation::PgTables => rows.push(format!(
+                    "({}, {}, current_user, NULL, {}, false, false, false)",
+                    name, table_name, table.has_primary_key
+                )),
+                EmulatedRelation::Tables => rows.push(format!(
+                    "(current_database(), {}, {}, 'BASE TABLE', 'NO', 'NO')",
+                    name, table_name
+                )),
+                _ => {}
+            }
+            table_oid += 1;
+        }
+    }
+    rows
+}
+
+fn literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
@@ -0,0 +1,95 @@
+use std::sync::{Arc, RwLock};
+
+use anyhow::Context;
+
+/// A setting the server reads again when it receives SIGHUP. Sessions get the
+/// value current when they need it, e.g. a new connection gets the current
+/// TLS certificate, so the connections of clients are left alone.
+pub struct Reloadable<T> {
+    value: RwLock<Arc<T>>,
+}
+
+impl<T> Reloadable<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value: RwLock::new(Arc::new(value)),
+        }
+    }
+
+    pub fn get(&self) -> Arc<T> {
+        self.value.read().unwrap().clone()
+    }
+
+    pub fn set(&self, value: T) {
+        *self.value.write().unwrap() = Arc::new(value);
+    }
+}
+
+/// The settings of the config file, which take the place of the arguments of
+/// the same name and are read again on SIGHUP. Like postgresql.conf, it has a
+/// `name = value` line per setting, with `#` comments and values optionally
+/// in single quotes:
+///
+/// ```text
+/// password = 'rotated secret'
+/// log_level = 'info,peerdb::audit=off'
+/// ```
+#[derive(Debug, Default)]
+pub struct ConfigFile {
+    /// The password of the postgres interface.
+    pub password: Option<String>,
+    /// The directives of the log filter, like `RUST_LOG`.
+    pub log_level: Option<String>,
+}
+
+impl ConfigFile {
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        let contents =
+            std::fs::read_to_string(path).with_context(|| format!("unable to read {}", path))?;
+        Self::parse(&contents).with_context(|| format!("invalid config file {}", path))
+    }
+
+    fn parse(contents: &str) -> anyhow::Result<Self> {
+        let mut config = Self::default();
+        for (i, line) in contents.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (name, value) = line
+                .split_once('=')
+                .with_context(|| format!("line {}: expected `name = value`", i + 1))?;
+            let value = unquote(value.trim());
+            match name.trim() {
+                "password" => config.password = Some(value),
+                "log_level" => config.log_level = Some(value),
+                other => anyhow::bail!("line {}: unknown setting {}", i + 1, other),
+            }
+        }
+        Ok(config)
+    }
+}
+
+// the line up to a `#` outside of quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+// a value in single quotes, with quotes in it doubled like in SQL.
+fn unquote(value: &str) -> String {
+    match value
+        .strip_prefix('\'')
+        .and_then(|value| value.strip_suffix('\''))
+    {
+        Some(value) => value.replace("''", "'"),
+        None => value.to_owned(),
+    }
+}
@@ -0,0 +1,281 @@
+use std::{collections::HashMap, io, net::SocketAddr, sync::Arc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{channel::mpsc, SinkExt, StreamExt};
+use peer_cursor::Notification;
+use pgwire::{
+    api::{
+        auth::StartupHandler, query::ExtendedQueryHandler, ClientInfo, ClientInfoHolder,
+        PgWireConnectionState,
+    },
+    error::{ErrorInfo, PgWireError, PgWireResult},
+    messages::{
+        copy::CopyInResponse,
+        response::{ReadyForQuery, READY_STATUS_IDLE},
+        simplequery::Query,
+        PgWireBackendMessage, PgWireFrontendMessage,
+    },
+    tokio::PgWireMessageServerCodec,
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+};
+use tokio_rustls::TlsAcceptor;
+use tokio_util::codec::Framed;
+
+use crate::{copy_in, notifications, tls};
+
+/// The stream a client's messages are read off and written to, the TCP
+/// connection or TLS over it.
+pub trait Transport: AsyncRead + AsyncWrite + Unpin + Send + Sync {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send + Sync> Transport for T {}
+
+/// The connection of a client, framed into the messages of the protocol.
+pub type Client = Framed<Box<dyn Transport>, PgWireMessageServerCodec>;
+
+/// The session of a client, which runs its requests, and sends the client
+/// messages of its own while it waits for its next request.
+#[async_trait]
+pub trait Session: ExtendedQueryHandler {
+    /// Runs the simple query of the client, the statements of which may speak
+    /// the COPY subprotocol with it through `relay_copy`.
+    async fn on_query(&self, client: &mut Client, query: Query) -> PgWireResult<()>;
+
+    /// The notifications of the channels the session listens to, once some
+    /// arrived.
+    async fn notifications(&self) -> Vec<Notification>;
+
+    /// The error the client is disconnected with, once the session is to be
+    /// closed.
+    async fn closing(&self) -> ErrorInfo;
+}
+
+/// Serves the client of the connection like pgwire's `process_socket`, over
+/// TLS when the client asks for it and the server takes it. Every message is
+/// read and written through the one framed connection, which pgwire drops
+/// the messages of the COPY subprotocol from, so that the statements of a
+/// simple query can speak it with a TLS client too. The messages the session
+/// sends of its own are never written in the middle of a response.
+pub async fn serve<A, S>(
+    mut socket: TcpStream,
+    tls_acceptor: Option<Arc<TlsAcceptor>>,
+    startup_handler: Arc<A>,
+    session: Arc<S>,
+) -> io::Result<()>
+where
+    A: StartupHandler + 'static,
+    S: Session + 'static,
+{
+    let addr = socket.peer_addr()?;
+    socket.set_nodelay(true)?;
+    let (transport, is_secure): (Box<dyn Transport>, bool) =
+        match answer_tls_request(&mut socket, tls_acceptor).await? {
+            Some(tls_acceptor) => (Box::new(tls_acceptor.accept(socket).await?), true),
+            None => (Box::new(socket), false),
+        };
+    let codec = PgWireMessageServerCodec::new(ClientInfoHolder::new(addr, is_secure));
+    let mut client = Framed::new(transport, codec);
+    serve_client(&mut client, &*startup_handler, &*session).await
+}
+
+// answers the SSLRequest the client may open the connection with, the
+// acceptor is returned when the client is to switch to TLS.
+async fn answer_tls_request(
+    socket: &mut TcpStream,
+    tls_acceptor: Option<Arc<TlsAcceptor>>,
+) -> io::Result<Option<Arc<TlsAcceptor>>> {
+    if !tls::requests_tls(socket).await? {
+        return Ok(None);
+    }
+    socket.read_exact(&mut [0u8; 8]).await?;
+    let answer = if tls_acceptor.is_some() { b"S" } else { b"N" };
+    socket.write_all(answer).await?;
+    Ok(tls_acceptor)
+}
+
+/// What a statement of a simple query asks of the client's connection, to
+/// speak the COPY subprotocol with the client while the query runs.
+pub enum CopyRequest {
+    /// Sends the CopyInResponse and reads the data the client sends after it.
+    In(CopyInResponse, mpsc::Sender<PgWireResult<Bytes>>),
+    /// Sends the messages of a COPY out, in one write.
+    Out(Vec<PgWireBackendMessage>),
+}
+
+/// Hands the request to the relay, which only stops taking requests once the
+/// client disconnected.
+pub async fn request_copy(
+    requests: &mut mpsc::Sender<CopyRequest>,
+    request: CopyRequest,
+) -> PgWireResult<()> {
+    requests
+        .send(request)
+        .await
+        .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe).into())
+}
+
+/// Speaks the COPY subprotocol with the client as the statements of its
+/// query request, until they are done with the requests. The connection is
+/// only read and written here while the query runs, the statements see the
+/// client through a `ClientSnapshot` of it.
+pub async fn relay_copy(
+    client: &mut Client,
+    mut requests: mpsc::Receiver<CopyRequest>,
+) -> PgWireResult<()> {
+    while let Some(request) = requests.next().await {
+        match request {
+            CopyRequest::In(response, data) => {
+                client
+                    .send(PgWireBackendMessage::CopyInResponse(response))
+                    .await?;
+                copy_in::read_data(client, data).await?;
+            }
+            CopyRequest::Out(messages) => {
+                for message in messages {
+                    client.feed(message).await?;
+                }
+                client.flush().await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The info of a client as its query started, which the query runs with
+/// while the connection relays its COPY.
+pub struct ClientSnapshot(ClientInfoHolder);
+
+impl ClientSnapshot {
+    pub fn of(client: &Client) -> Self {
+        let mut info = ClientInfoHolder::new(*client.socket_addr(), client.is_secure());
+        info.set_state(client.state().clone());
+        *info.metadata_mut() = client.metadata().clone();
+        Self(info)
+    }
+}
+
+impl ClientInfo for ClientSnapshot {
+    fn socket_addr(&self) -> &SocketAddr {
+        self.0.socket_addr()
+    }
+
+    fn is_secure(&self) -> bool {
+        *self.0.is_secure()
+    }
+
+    fn state(&self) -> &PgWireConnectionState {
+        self.0.state()
+    }
+
+    fn set_state(&mut self, new_state: PgWireConnectionState) {
+        self.0.set_state(new_state);
+    }
+
+    fn metadata(&self) -> &HashMap<String, String> {
+        self.0.metadata()
+    }
+
+    fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        self.0.metadata_mut()
+    }
+}
+
+async fn serve_client<A, S>(client: &mut Client, startup_handler: &A, session: &S) -> io::Result<()>
+where
+    A: StartupHandler,
+    S: Session,
+{
+    loop {
+        // the session only sends messages of its own in between requests.
+        let waiting = matches!(client.state(), PgWireConnectionState::ReadyForQuery);
+        let running = matches!(client.state(), PgWireConnectionState::QueryInProgress);
+        let message = tokio::select! {
+            message = client.next() => message,
+            notifications = session.notifications(), if waiting => {
+                client
+                    .write_buffer_mut()
+                    .extend_from_slice(&notifications::encode(&notifications));
+                client.flush().await?;
+                continue;
+            }
+            error_info = session.closing(), if !running => {
+                client
+                    .send(PgWireBackendMessage::ErrorResponse(error_info.into()))
+                    .await?;
+                return client.close().await;
+            }
+        };
+        let message = match message {
+            Some(Ok(message)) => message,
+            _ => return Ok(()),
+        };
+        if let Err(err) = process_message(client, message, startup_handler, session).await {
+            process_error(client, err).await?;
+        }
+    }
+}
+
+async fn process_message<A, S>(
+    client: &mut Client,
+    message: PgWireFrontendMessage,
+    startup_handler: &A,
+    session: &S,
+) -> PgWireResult<()>
+where
+    A: StartupHandler,
+    S: Session,
+{
+    match client.state() {
+        PgWireConnectionState::AwaitingStartup
+        | PgWireConnectionState::AuthenticationInProgress => {
+            startup_handler.on_startup(client, message).await
+        }
+        _ => match message {
+            PgWireFrontendMessage::Query(query) => session.on_query(client, query).await,
+            PgWireFrontendMessage::Parse(parse) => session.on_parse(client, parse).await,
+            PgWireFrontendMessage::Bind(bind) => session.on_bind(client, bind).await,
+            PgWireFrontendMessage::Execute(execute) => session.on_execute(client, execute).await,
+            PgWireFrontendMessage::Describe(describe) => {
+                session.on_describe(client, describe).await
+            }
+            PgWireFrontendMessage::Sync(sync) => session.on_sync(client, sync).await,
+            PgWireFrontendMessage::Close(close) => session.on_close(client, close).await,
+            _ => Ok(()),
+        },
+    }
+}
+
+// like pgwire, the errors of a request are sent along with ReadyForQuery, and
+// the connection is closed on the others. the client waits for its next
+// request after a failed query.
+async fn process_error(client: &mut Client, error: PgWireError) -> io::Result<()> {
+    if matches!(client.state(), PgWireConnectionState::QueryInProgress) {
+        client.set_state(PgWireConnectionState::ReadyForQuery);
+    }
+    let error_info = match error {
+        PgWireError::UserError(error_info) => *error_info,
+        PgWireError::ApiError(err) => {
+            ErrorInfo::new("ERROR".to_owned(), "XX000".to_owned(), err.to_string())
+        }
+        err => {
+            let error_info =
+                ErrorInfo::new("FATAL".to_owned(), "XX000".to_owned(), err.to_string());
+            client
+                .send(PgWireBackendMessage::ErrorResponse(error_info.into()))
+                .await?;
+            return client.close().await;
+        }
+    };
+    client
+        .feed(PgWireBackendMessage::ErrorResponse(error_info.into()))
+        .await?;
+    client
+        .feed(PgWireBackendMessage::ReadyForQuery(ReadyForQuery::new(
+            READY_STATUS_IDLE,
+        )))
+        .await?;
+    client.flush().await
+}
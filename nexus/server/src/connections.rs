@@ -0,0 +1,338 @@
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use async_trait::async_trait;
+use bytes::{BufMut, BytesMut};
+use dashmap::DashMap;
+use futures::Sink;
+use pgwire::{
+    api::{auth::StartupHandler, ClientInfo},
+    error::{ErrorInfo, PgWireError, PgWireResult},
+    messages::{PgWireBackendMessage, PgWireFrontendMessage},
+};
+use tokio::{io::AsyncWriteExt, net::TcpStream};
+
+/// The clients connected to the server, limited overall and per user. A limit
+/// of 0 doesn't limit them.
+pub struct Connections {
+    max_connections: usize,
+    max_connections_per_user: usize,
+    current: AtomicUsize,
+    peak: AtomicUsize,
+    users: DashMap<String, usize>,
+}
+
+/// The slot a connection holds, it is released when the slot is dropped.
+pub struct ConnectionSlot {
+    connections: Arc<Connections>,
+    user: Mutex<Option<String>>,
+}
+
+impl Connections {
+    pub fn new(max_connections: usize, max_connections_per_user: usize) -> Self {
+        Self {
+            max_connections,
+            max_connections_per_user,
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            users: DashMap::new(),
+        }
+    }
+
+    /// A slot for a new connection, none when the server has as many clients
+    /// as it takes.
+    pub fn try_acquire(self: &Arc<Self>) -> Option<ConnectionSlot> {
+        let max_connections = self.max_connections;
+        let acquired = self
+            .current
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                (max_connections == 0 || n < max_connections).then_some(n + 1)
+            });
+        match acquired {
+            Ok(n) => {
+                self.peak.fetch_max(n + 1, Ordering::SeqCst);
+                Some(ConnectionSlot {
+                    connections: self.clone(),
+                    user: Mutex::new(None),
+                })
+            }
+            Err(_) => None,
+        }
+    }
+
+    pub fn current(&self) -> usize {
+        self.current.load(Ordering::SeqCst)
+    }
+
+    /// The most clients that were connected at once since the server started.
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+
+    fn acquire_user(&self, user: &str) -> bool {
+        let mut count = self.users.entry(user.to_owned()).or_insert(0);
+        if self.max_connections_per_user > 0 && *count >= self.max_connections_per_user {
+            drop(count);
+            self.users.remove_if(user, |_, count| *count == 0);
+            return false;
+        }
+        *count += 1;
+        true
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.connections.current.fetch_sub(1, Ordering::SeqCst);
+        if let Some(user) = self.user.get_mut().unwrap().take() {
+            if let Some(mut count) = self.connections.users.get_mut(&user) {
+                *count -= 1;
+            }
+            self.connections
+                .users
+                .remove_if(&user, |_, count| *count == 0);
+        }
+    }
+}
+
+/// Holds the slot of a connection for as long as the connection lasts, and
+/// turns away the clients of users that have as many connections as they may
+/// before they authenticate.
+pub struct ConnectionLimitStartupHandler<H> {
+    inner: Arc<H>,
+    slot: ConnectionSlot,
+}
+
+impl<H> ConnectionLimitStartupHandler<H> {
+    pub fn new(inner: Arc<H>, slot: ConnectionSlot) -> Self {
+        Self { inner, slot }
+    }
+}
+
+#[async_trait]
+impl<H: StartupHandler> StartupHandler for ConnectionLimitStartupHandler<H> {
+    async fn on_startup<C>(
+        &self,
+        client: &mut C,
+        message: PgWireFrontendMessage,
+    ) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        if let PgWireFrontendMessage::Startup(startup) = &message {
+            let user = startup
+                .parameters()
+                .get("user")
+                .cloned()
+                .unwrap_or_default();
+            if !self.slot.connections.acquire_user(&user) {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "FATAL".to_owned(),
+                    "53300".to_owned(),
+                    format!("too many connections for role \"{}\"", user),
+                ))));
+            }
+            *self.slot.user.lock().unwrap() = Some(user);
+        }
+        self.inner.on_startup(client, message).await
+    }
+}
+
+/// Turns away a client before its startup message, with an error response
+/// like the ones postgres sends then.
+pub async fn reject(mut socket: TcpStream, code: &str, message: &str) -> std::io::Result<()> {
+    let mut fields = BytesMut::new();
+    for (field, value) in [
+        (b'S', "FATAL"),
+        (b'V', "FATAL"),
+        (b'C', code),
+        (b'M', message),
+    ] {
+        fields.put_u8(field);
+        fields.put(value.as_bytes());
+        fields.put_u8(0);
+    }
+    fields.put_u8(0);
+
+    let mut buf = BytesMut::with_capacity(fields.len() + 5);
+    buf.put_u8(b'E');
+    buf.put_i32(fields.len() as i32 + 4);
+    buf.put(fields);
+    socket.write_all(&buf).await?;
+    socket.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        net::SocketAddr,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use pgwire::{
+        api::{ClientInfoHolder, PgWireConnectionState},
+        messages::startup::Startup,
+    };
+
+    use super::*;
+
+    struct TestClient {
+        info: ClientInfoHolder,
+    }
+
+    impl ClientInfo for TestClient {
+        fn socket_addr(&self) -> &SocketAddr {
+            self.info.socket_addr()
+        }
+
+        fn is_secure(&self) -> bool {
+            *self.info.is_secure()
+        }
+
+        fn state(&self) -> &PgWireConnectionState {
+            self.info.state()
+        }
+
+        fn set_state(&mut self, new_state: PgWireConnectionState) {
+            self.info.set_state(new_state);
+        }
+
+        fn metadata(&self) -> &HashMap<String, String> {
+            self.info.metadata()
+        }
+
+        fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+            self.info.metadata_mut()
+        }
+    }
+
+    impl Sink<PgWireBackendMessage> for TestClient {
+        type Error = PgWireError;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, _: PgWireBackendMessage) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    // the handler clients are handed on to, which lets every one of them in.
+    struct AcceptingHandler;
+
+    #[async_trait]
+    impl StartupHandler for AcceptingHandler {
+        async fn on_startup<C>(&self, _: &mut C, _: PgWireFrontendMessage) -> PgWireResult<()>
+        where
+            C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+            C::Error: Debug,
+            PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+        {
+            Ok(())
+        }
+    }
+
+    // connects a client logging in as the user, the handler holds its slot for
+    // as long as it is kept.
+    async fn connect(
+        connections: &Arc<Connections>,
+        user: &str,
+    ) -> Result<ConnectionLimitStartupHandler<AcceptingHandler>, String> {
+        let slot = connections
+            .try_acquire()
+            .ok_or_else(|| "53300".to_owned())?;
+        let handler = ConnectionLimitStartupHandler::new(Arc::new(AcceptingHandler), slot);
+
+        let mut message = Startup::new();
+        message
+            .parameters_mut()
+            .insert("user".to_owned(), user.to_owned());
+        let mut client = TestClient {
+            info: ClientInfoHolder::new("127.0.0.1:5432".parse().unwrap(), false),
+        };
+        match handler
+            .on_startup(&mut client, PgWireFrontendMessage::Startup(message))
+            .await
+        {
+            Ok(()) => Ok(handler),
+            Err(PgWireError::UserError(info)) => Err(info.code().clone()),
+            Err(err) => panic!("unexpected error: {:?}", err),
+        }
+    }
+
+    fn user_connections(connections: &Connections, user: &str) -> usize {
+        connections.users.get(user).map_or(0, |count| *count)
+    }
+
+    #[tokio::test]
+    async fn test_max_connections() {
+        let connections = Arc::new(Connections::new(2, 0));
+        let first = connect(&connections, "alice").await.unwrap();
+        let _second = connect(&connections, "bob").await.unwrap();
+        assert_eq!(connections.current(), 2);
+        assert_eq!(connect(&connections, "carol").await.err().unwrap(), "53300");
+
+        drop(first);
+        assert_eq!(connections.current(), 1);
+        let _third = connect(&connections, "carol").await.unwrap();
+        assert_eq!(connections.current(), 2);
+        assert_eq!(connections.peak(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_max_connections_per_user() {
+        let connections = Arc::new(Connections::new(0, 2));
+        let first = connect(&connections, "alice").await.unwrap();
+        let _second = connect(&connections, "alice").await.unwrap();
+        let code = connect(&connections, "alice").await.err().unwrap();
+        assert_eq!(code, "53300");
+        // the rejected client gives its slot back, and other users connect.
+        assert_eq!(connections.current(), 2);
+        assert_eq!(user_connections(&connections, "alice"), 2);
+        let _other = connect(&connections, "bob").await.unwrap();
+
+        drop(first);
+        assert_eq!(user_connections(&connections, "alice"), 1);
+        let _third = connect(&connections, "alice").await.unwrap();
+        assert_eq!(connections.current(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_releases_slot() {
+        let connections = Arc::new(Connections::new(1, 1));
+        for _ in 0..3 {
+            let client = connect(&connections, "alice").await.unwrap();
+            assert_eq!(connections.current(), 1);
+            assert_eq!(user_connections(&connections, "alice"), 1);
+            drop(client);
+            assert_eq!(connections.current(), 0);
+            // users without connections are forgotten.
+            assert!(connections.users.is_empty());
+        }
+
+        // a slot taken before the startup message is released as well.
+        let slot = connections.try_acquire().unwrap();
+        assert!(connections.try_acquire().is_none());
+        drop(slot);
+        assert!(connections.try_acquire().is_some());
+        assert_eq!(connections.peak(), 1);
+    }
+}
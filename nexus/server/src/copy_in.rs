@@ -0,0 +1,131 @@
+use bytes::Bytes;
+use futures::{channel::mpsc, SinkExt, StreamExt};
+use pgwire::{
+    error::{ErrorInfo, PgWireError, PgWireResult},
+    messages::{copy::CopyInResponse, PgWireFrontendMessage},
+};
+use sqlparser::ast::{CopyLegacyOption, CopyOption, CopyTarget, Statement};
+
+use crate::connection::Client;
+
+/// Whether the statement is a `COPY ... FROM STDIN` whose data the client
+/// streams with CopyData messages, rather than sending it along with the
+/// statement.
+pub fn streams_data(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::Copy {
+            to: false,
+            target: CopyTarget::Stdin,
+            values,
+            ..
+        } if values.is_empty()
+    )
+}
+
+/// The CopyInResponse message that has the client send the data of the COPY,
+/// in the binary format of postgres or as text, which csv is too.
+pub fn response(stmt: &Statement) -> CopyInResponse {
+    let (num_columns, binary) = match stmt {
+        Statement::Copy {
+            columns,
+            options,
+            legacy_options,
+            ..
+        } => (columns.len(), is_binary(options, legacy_options)),
+        _ => (0, false),
+    };
+    let format = i16::from(binary);
+    CopyInResponse::new(format as i8, num_columns as i16, vec![format; num_columns])
+}
+
+fn is_binary(options: &[CopyOption], legacy_options: &[CopyLegacyOption]) -> bool {
+    let binary_format = |option: &CopyOption| match option {
+        CopyOption::Format(format) => format.value.eq_ignore_ascii_case("binary"),
+        _ => false,
+    };
+    options.iter().any(binary_format)
+        || legacy_options
+            .iter()
+            .any(|option| matches!(option, CopyLegacyOption::Binary))
+}
+
+/// Reads the data the client sends after the CopyInResponse into `data`, a
+/// CopyData message at a time, until it sends CopyDone. When the COPY fails,
+/// as the client sent CopyFail or a message that has no place in a COPY, an
+/// error takes the place of the rest of the data so that the peer aborts the
+/// COPY. The client's messages are read up to its CopyDone even once the peer
+/// stopped taking them, it only reads the error of the COPY after that.
+pub async fn read_data(
+    client: &mut Client,
+    mut data: mpsc::Sender<PgWireResult<Bytes>>,
+) -> PgWireResult<()> {
+    let res = read_messages(client, &mut data).await;
+    if res.is_err() {
+        let _ = data
+            .send(Err(copy_error(
+                "57014",
+                "COPY from stdin failed".to_owned(),
+            )))
+            .await;
+    }
+    res
+}
+
+async fn read_messages(
+    client: &mut Client,
+    data: &mut mpsc::Sender<PgWireResult<Bytes>>,
+) -> PgWireResult<()> {
+    loop {
+        let message = match client.next().await {
+            Some(message) => message?,
+            None => return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into()),
+        };
+        match message {
+            // the data is dropped once the peer failed the COPY.
+            PgWireFrontendMessage::CopyData(copy_data) => {
+                let _ = data.send(Ok(copy_data.data().clone())).await;
+            }
+            PgWireFrontendMessage::CopyDone(_) => return Ok(()),
+            PgWireFrontendMessage::CopyFail(copy_fail) => {
+                return Err(copy_error(
+                    "57014",
+                    format!("COPY from stdin failed: {}", copy_fail.message()),
+                ));
+            }
+            // postgres ignores these during a COPY too.
+            PgWireFrontendMessage::Flush(_) | PgWireFrontendMessage::Sync(_) => {}
+            message => {
+                return Err(copy_error(
+                    "08P01",
+                    format!(
+                        "unexpected message type 0x{:02x} during COPY from stdin",
+                        message_type(&message)
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+// the type byte of a message that has no place in a COPY.
+fn message_type(message: &PgWireFrontendMessage) -> u8 {
+    match message {
+        PgWireFrontendMessage::Query(_) => b'Q',
+        PgWireFrontendMessage::Parse(_) => b'P',
+        PgWireFrontendMessage::Bind(_) => b'B',
+        PgWireFrontendMessage::Describe(_) => b'D',
+        PgWireFrontendMessage::Execute(_) => b'E',
+        PgWireFrontendMessage::Close(_) => b'C',
+        PgWireFrontendMessage::Terminate(_) => b'X',
+        _ => b'p',
+    }
+}
+
+fn copy_error(code: &str, message: String) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        code.to_owned(),
+        message,
+    )))
+}
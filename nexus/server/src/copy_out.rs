@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use bytes::{BufMut, BytesMut};
+use futures::{channel::mpsc, stream, stream::BoxStream, StreamExt};
+use peer_cursor::{Record, Schema, SchemaRef};
+use peerdb_parser::CopyOutFormat;
+use pgwire::{
+    api::{
+        results::{DataRowEncoder, FieldFormat, FieldInfo, QueryResponse, Response},
+        Type,
+    },
+    error::PgWireResult,
+    messages::{
+        copy::{CopyData, CopyDone, CopyOutResponse},
+        PgWireBackendMessage,
+    },
+};
+use value::Value;
+
+use crate::connection::{self, CopyRequest};
+
+// the CopyData messages are written to the client in writes of about this
+// size.
+const WRITE_BUFFER_SIZE: usize = 64 * 1024;
+
+// the COPY out subprotocol is spoken to the client through its connection,
+// which only relays it while the COPY is the only statement of a simple query.
+// in the extended query protocol, and in scripts, the lines are sent as the
+// rows of a single column instead, which `psql -At` prints as they are.
+pub fn schema() -> SchemaRef {
+    Arc::new(Schema {
+        fields: vec![FieldInfo::new(
+            "copy".to_owned(),
+            None,
+            None,
+            Type::TEXT,
+            FieldFormat::Text,
+        )],
+    })
+}
+
+/// Sends the CopyOutResponse, the lines of the COPY as CopyData messages as
+/// the peer produces the rows, and CopyDone to the client, and returns the
+/// number of rows copied.
+pub async fn write(
+    copy_requests: &mut mpsc::Sender<CopyRequest>,
+    columns: SchemaRef,
+    mut rows: BoxStream<'static, PgWireResult<Record>>,
+    format: CopyOutFormat,
+) -> PgWireResult<usize> {
+    let num_columns = columns.fields.len();
+    let mut messages = vec![PgWireBackendMessage::CopyOutResponse(CopyOutResponse::new(
+        0,
+        num_columns as i16,
+        vec![0; num_columns],
+    ))];
+    let mut buffered = 0;
+    if let Some(header) = header_line(&columns, &format) {
+        buffered += header.len();
+        messages.push(copy_data(&header));
+    }
+
+    let mut rows_copied = 0;
+    while let Some(row) = rows.next().await {
+        let record = match row {
+            Ok(record) => record,
+            Err(err) => {
+                // the client gets the lines copied before the error.
+                connection::request_copy(copy_requests, CopyRequest::Out(messages)).await?;
+                return Err(err);
+            }
+        };
+        let line = row_line(&record, &format);
+        buffered += line.len();
+        messages.push(copy_data(&line));
+        rows_copied += 1;
+        if buffered >= WRITE_BUFFER_SIZE {
+            let batch = std::mem::take(&mut messages);
+            connection::request_copy(copy_requests, CopyRequest::Out(batch)).await?;
+            buffered = 0;
+        }
+    }
+    messages.push(PgWireBackendMessage::CopyDone(CopyDone::new()));
+    connection::request_copy(copy_requests, CopyRequest::Out(messages)).await?;
+    Ok(rows_copied)
+}
+
+fn copy_data(line: &str) -> PgWireBackendMessage {
+    let mut data = BytesMut::with_capacity(line.len() + 1);
+    data.put_slice(line.as_bytes());
+    data.put_u8(b'\n');
+    PgWireBackendMessage::CopyData(CopyData::new(data.freeze()))
+}
+
+/// Writes the rows as the lines of the COPY, as the peer produces them.
+pub fn response<'a>(
+    columns: SchemaRef,
+    rows: BoxStream<'static, PgWireResult<Record>>,
+    format: CopyOutFormat,
+) -> PgWireResult<Response<'a>> {
+    let header = header_line(&columns, &format).map(Ok);
+    let lines = stream::iter(header)
+        .chain(rows.map(move |row| row.map(|record| row_line(&record, &format))));
+
+    let fields = Arc::new(schema().fields.clone());
+    let row_fields = fields.clone();
+    let data_rows = lines
+        .map(move |line| {
+            line.and_then(|line| {
+                let mut encoder = DataRowEncoder::new(row_fields.clone());
+                encoder.encode_field(&line)?;
+                encoder.finish()
+            })
+        })
+        .boxed();
+    Ok(Response::Query(QueryResponse::new(fields, data_rows)))
+}
+
+fn header_line(columns: &SchemaRef, format: &CopyOutFormat) -> Option<String> {
+    format.header.then(|| {
+        let names: Vec<Option<String>> = columns
+            .fields
+            .iter()
+            .map(|field| Some(field.name().to_owned()))
+            .collect();
+        line(&names, format)
+    })
+}
+
+fn row_line(record: &Record, format: &CopyOutFormat) -> String {
+    let values: Vec<Option<String>> = record.values.iter().map(text).collect();
+    line(&values, format)
+}
+
+fn line(values: &[Option<String>], format: &CopyOutFormat) -> String {
+    values
+        .iter()
+        .map(|value| match value {
+            None => format.null.clone(),
+            Some(value) if format.csv => csv_field(value, format),
+            Some(value) => text_field(value, format.delimiter),
+        })
+        .collect::<Vec<_>>()
+        .join(&format.delimiter.to_string())
+}
+
+// like postgres, a field is quoted when it could be mistaken for a null or
+// the end of the field or line.
+fn csv_field(value: &str, format: &CopyOutFormat) -> String {
+    let needs_quotes = value == format.null
+        || value.contains(format.delimiter)
+        || value.contains(format.quote)
+        || value.contains('\n')
+        || value.contains('\r');
+    if !needs_quotes {
+        return value.to_owned();
+    }
+    let quote = format.quote.to_string();
+    format!(
+        "{}{}{}",
+        quote,
+        value.replace(&quote, &format!("{}{}", quote, quote)),
+        quote
+    )
+}
+
+fn text_field(value: &str, delimiter: char) -> String {
+    let mut field = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => field.push_str("\\\\"),
+            '\n' => field.push_str("\\n"),
+            '\r' => field.push_str("\\r"),
+            '\t' => field.push_str("\\t"),
+            c if c == delimiter => {
+                field.push('\\');
+                field.push(c);
+            }
+            c => field.push(c),
+        }
+    }
+    field
+}
+
+// the text of a value, as postgres would write it where it can be told apart,
+// and as JSON otherwise.
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::Bool(b) => Some(if *b { "t" } else { "f" }.to_owned()),
+        Value::Binary(b) | Value::VarBinary(b) => {
+            let hex: String = b.iter().map(|byte| format!("{:02x}", byte)).collect();
+            Some(format!("\\x{}", hex))
+        }
+        Value::Json(j) | Value::JsonB(j) => Some(j.to_string()),
+        value => match value.to_serde_json_value() {
+            serde_json::Value::String(s) => Some(s),
+            other => Some(other.to_string()),
+        },
+    }
+}
@@ -27,4 +27,8 @@ impl PeerCursors {
     pub fn get_peer(&self, name: &str) -> Option<&Peer> {
         self.cursors.get(name).map(|peer| peer.as_ref())
     }
+
+    pub fn has_cursors_on(&self, peer_name: &str) -> bool {
+        self.cursors.values().any(|peer| peer.name == peer_name)
+    }
 }
@@ -0,0 +1,48 @@
+use std::{fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+use futures::Sink;
+use peerdb_parser::NexusQueryParser;
+use pgwire::{
+    api::{auth::StartupHandler, ClientInfo},
+    error::{PgWireError, PgWireResult},
+    messages::{PgWireBackendMessage, PgWireFrontendMessage},
+};
+
+/// Hands the database of the startup message to the query parser of the
+/// session, so that a client connecting with `dbname=<peer>` has the queries
+/// that don't refer to a peer run on that peer. The authentication itself is
+/// left to the wrapped handler.
+pub struct DatabasePeerStartupHandler<H> {
+    inner: Arc<H>,
+    query_parser: Arc<NexusQueryParser>,
+}
+
+impl<H> DatabasePeerStartupHandler<H> {
+    pub fn new(inner: Arc<H>, query_parser: Arc<NexusQueryParser>) -> Self {
+        Self {
+            inner,
+            query_parser,
+        }
+    }
+}
+
+#[async_trait]
+impl<H: StartupHandler> StartupHandler for DatabasePeerStartupHandler<H> {
+    async fn on_startup<C>(
+        &self,
+        client: &mut C,
+        message: PgWireFrontendMessage,
+    ) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        if let PgWireFrontendMessage::Startup(startup) = &message {
+            let database = startup.parameters().get("database").cloned();
+            self.query_parser.set_database(database);
+        }
+        self.inner.on_startup(client, message).await
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use peer_cursor::{Record, Records, Schema, SchemaRef};
+use pgwire::api::{
+    results::{FieldFormat, FieldInfo},
+    Type,
+};
+use pt::peerdb_route::{
+    mirror_status_response::Status, CdcSyncStatus, MirrorStatusResponse, QRepMirrorStatus,
+};
+use value::Value;
+
+// every row is either a cdc sync batch or a partition of a snapshot clone or
+// a query replication run, columns that don't apply to the kind are null. A
+// mirror with a comment has it on a leading row of its own.
+pub fn schema() -> SchemaRef {
+    let field = |name: &str, datatype: Type| {
+        FieldInfo::new(name.to_owned(), None, None, datatype, FieldFormat::Text)
+    };
+    Arc::new(Schema {
+        fields: vec![
+            field("kind", Type::TEXT),
+            field("table_name", Type::TEXT),
+            field("partition_id", Type::TEXT),
+            field("start_lsn", Type::INT8),
+            field("end_lsn", Type::INT8),
+            field("num_rows", Type::INT4),
+            field("start_time", Type::TIMESTAMP),
+            field("end_time", Type::TIMESTAMP),
+            field("lag_seconds", Type::FLOAT8),
+            field("comment", Type::TEXT),
+        ],
+    })
+}
+
+pub fn records(status: &MirrorStatusResponse, comment: Option<String>) -> Records {
+    let schema = schema();
+    let mut rows = Vec::new();
+    if let Some(comment) = comment {
+        rows.push(comment_row(comment));
+    }
+    match &status.status {
+        Some(Status::CdcStatus(cdc)) => {
+            if let Some(snapshot) = &cdc.snapshot_status {
+                for clone in &snapshot.clones {
+                    partition_rows("snapshot", clone, &mut rows);
+                }
+            }
+            rows.extend(cdc.cdc_syncs.iter().map(sync_row));
+            if cdc.apply_backlog_batches > 0 {
+                rows.push(apply_backlog_row(cdc.apply_backlog_records));
+            }
+        }
+        Some(Status::QrepStatus(qrep)) => partition_rows("partition", qrep, &mut rows),
+        None => {}
+    }
+
+    let records = rows
+        .into_iter()
+        .map(|values| Record {
+            values,
+            schema: schema.clone(),
+        })
+        .collect();
+    Records { records, schema }
+}
+
+fn partition_rows(kind: &str, qrep: &QRepMirrorStatus, rows: &mut Vec<Vec<Value>>) {
+    let table_name = qrep
+        .config
+        .as_ref()
+        .map(|config| Value::Text(config.destination_table_identifier.clone()))
+        .unwrap_or(Value::Null);
+    rows.extend(qrep.partitions.iter().map(|partition| {
+        vec![
+            Value::Text(kind.to_owned()),
+            table_name.clone(),
+            Value::Text(partition.partition_id.clone()),
+            Value::Null,
+            Value::Null,
+            Value::Integer(partition.num_rows),
+            timestamp(&partition.start_time),
+            timestamp(&partition.end_time),
+            Value::Null,
+            Value::Null,
+        ]
+    }));
+}
+
+fn sync_row(sync: &CdcSyncStatus) -> Vec<Value> {
+    vec![
+        Value::Text("cdc_batch".to_owned()),
+        Value::Null,
+        Value::Null,
+        Value::BigInt(sync.start_lsn),
+        Value::BigInt(sync.end_lsn),
+        Value::Integer(sync.num_rows),
+        timestamp(&sync.start_time),
+        timestamp(&sync.end_time),
+        sync.lag_seconds.map(Value::Double).unwrap_or(Value::Null),
+        Value::Null,
+    ]
+}
+
+// the records buffered in the raw table until the apply window of the mirror
+// opens.
+fn apply_backlog_row(records: i64) -> Vec<Value> {
+    vec![
+        Value::Text("apply_backlog".to_owned()),
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Integer(records.min(i32::MAX as i64) as i32),
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Null,
+    ]
+}
+
+fn comment_row(comment: String) -> Vec<Value> {
+    vec![
+        Value::Text("comment".to_owned()),
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Text(comment),
+    ]
+}
+
+fn timestamp(ts: &Option<pbjson_types::Timestamp>) -> Value {
+    ts.as_ref()
+        .and_then(|ts| chrono::NaiveDateTime::from_timestamp_opt(ts.seconds, ts.nanos as u32))
+        .map(Value::PostgresTimestamp)
+        .unwrap_or(Value::Null)
+}
@@ -0,0 +1,241 @@
+use std::sync::Arc;
+
+use peer_cursor::{Record, Records, Schema, SchemaRef};
+use pgwire::api::{
+    results::{FieldFormat, FieldInfo},
+    Type,
+};
+use pt::peerdb_peers::DbType;
+use value::Value;
+
+// the column snowflake destinations mark deleted rows with.
+const SNOWFLAKE_IS_DELETED_COLUMN: &str = "_PEERDB_IS_DELETED";
+
+// what a mirror would do for one of its tables.
+pub struct TablePlan {
+    pub source_table: String,
+    pub destination_table: String,
+    // the columns of the source table with the names of their postgres types,
+    // None if the destination table isn't created by the mirror or the source
+    // isn't postgres.
+    pub columns: Option<Vec<(String, String)>>,
+    pub primary_key: Vec<String>,
+    pub partitioning: String,
+    pub estimated_rows: Option<i64>,
+}
+
+// every row is a table of the mirror, the destination ddl and the estimated
+// rows are null when they can't be known before the mirror runs.
+pub fn schema() -> SchemaRef {
+    let field = |name: &str, datatype: Type| {
+        FieldInfo::new(name.to_owned(), None, None, datatype, FieldFormat::Text)
+    };
+    Arc::new(Schema {
+        fields: vec![
+            field("source_table", Type::TEXT),
+            field("destination_table", Type::TEXT),
+            field("destination_ddl", Type::TEXT),
+            field("partitioning", Type::TEXT),
+            field("estimated_rows", Type::INT8),
+        ],
+    })
+}
+
+pub fn records(plans: &[TablePlan], destination: i32) -> Records {
+    let schema = schema();
+    let records = plans
+        .iter()
+        .map(|plan| Record {
+            values: vec![
+                Value::Text(plan.source_table.clone()),
+                Value::Text(plan.destination_table.clone()),
+                destination_ddl(plan, destination)
+                    .map(Value::Text)
+                    .unwrap_or(Value::Null),
+                Value::Text(plan.partitioning.clone()),
+                plan.estimated_rows
+                    .map(Value::BigInt)
+                    .unwrap_or(Value::Null),
+            ],
+            schema: schema.clone(),
+        })
+        .collect();
+    Records { records, schema }
+}
+
+// the kinds of values the flow converts the postgres types of the source to.
+#[derive(Clone, Copy)]
+enum Kind {
+    Boolean,
+    Int16,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    Numeric,
+    String,
+    Bytes,
+    Json,
+    Uuid,
+    Time,
+    Date,
+    Timestamp,
+    TimestampTz,
+    Bit,
+    ArrayInt32,
+    ArrayInt64,
+    ArrayFloat32,
+    ArrayFloat64,
+    ArrayString,
+    Other,
+}
+
+fn kind(source_type: &str) -> Kind {
+    match source_type {
+        "bool" => Kind::Boolean,
+        "int2" => Kind::Int16,
+        "int4" => Kind::Int32,
+        "int8" => Kind::Int64,
+        "float4" => Kind::Float32,
+        "float8" => Kind::Float64,
+        "numeric" => Kind::Numeric,
+        "text" | "varchar" | "bpchar" => Kind::String,
+        "bytea" => Kind::Bytes,
+        "json" | "jsonb" => Kind::Json,
+        "uuid" => Kind::Uuid,
+        "time" => Kind::Time,
+        "date" => Kind::Date,
+        "timestamp" => Kind::Timestamp,
+        "timestamptz" => Kind::TimestampTz,
+        "bit" | "varbit" => Kind::Bit,
+        "_int2" | "_int4" => Kind::ArrayInt32,
+        "_int8" => Kind::ArrayInt64,
+        "_float4" => Kind::ArrayFloat32,
+        "_float8" => Kind::ArrayFloat64,
+        "_text" | "_varchar" | "_bpchar" => Kind::ArrayString,
+        _ => Kind::Other,
+    }
+}
+
+fn postgres_type(kind: Kind) -> &'static str {
+    match kind {
+        Kind::Boolean => "BOOLEAN",
+        Kind::Int16 => "SMALLINT",
+        Kind::Int32 => "INTEGER",
+        Kind::Int64 => "BIGINT",
+        Kind::Float32 => "REAL",
+        Kind::Float64 => "DOUBLE PRECISION",
+        Kind::Numeric => "NUMERIC",
+        Kind::Bytes => "BYTEA",
+        Kind::Json => "JSONB",
+        Kind::Uuid => "UUID",
+        Kind::Time => "TIME",
+        Kind::Date => "DATE",
+        Kind::Timestamp => "TIMESTAMP",
+        Kind::TimestampTz => "TIMESTAMPTZ",
+        Kind::Bit => "BIT",
+        Kind::ArrayInt32 => "INTEGER[]",
+        Kind::ArrayInt64 => "BIGINT[]",
+        Kind::ArrayFloat32 => "REAL[]",
+        Kind::ArrayFloat64 => "DOUBLE PRECISION[]",
+        Kind::ArrayString => "TEXT[]",
+        Kind::String | Kind::Other => "TEXT",
+    }
+}
+
+fn snowflake_type(kind: Kind) -> &'static str {
+    match kind {
+        Kind::Boolean => "BOOLEAN",
+        Kind::Int16 | Kind::Int32 | Kind::Int64 => "INTEGER",
+        Kind::Float32 | Kind::Float64 => "FLOAT",
+        Kind::Numeric => "NUMBER(38, 9)",
+        Kind::Json => "VARIANT",
+        Kind::Time => "TIME",
+        Kind::Date => "DATE",
+        Kind::Timestamp => "TIMESTAMP_NTZ",
+        Kind::TimestampTz => "TIMESTAMP_TZ",
+        Kind::Bit | Kind::Bytes => "BINARY",
+        Kind::ArrayInt32
+        | Kind::ArrayInt64
+        | Kind::ArrayFloat32
+        | Kind::ArrayFloat64
+        | Kind::ArrayString => "VARIANT",
+        Kind::String | Kind::Uuid | Kind::Other => "STRING",
+    }
+}
+
+fn bigquery_type(kind: Kind) -> &'static str {
+    match kind {
+        Kind::Boolean => "BOOL",
+        Kind::Int16 | Kind::Int32 | Kind::Int64 => "INT64",
+        Kind::Float32 | Kind::Float64 => "FLOAT64",
+        Kind::Numeric => "NUMERIC",
+        Kind::Time => "TIME",
+        Kind::Date => "DATE",
+        Kind::Timestamp | Kind::TimestampTz => "TIMESTAMP",
+        Kind::Bit | Kind::Bytes => "BYTES",
+        Kind::ArrayInt32 | Kind::ArrayInt64 => "ARRAY<INT64>",
+        Kind::ArrayFloat32 | Kind::ArrayFloat64 => "ARRAY<FLOAT64>",
+        Kind::ArrayString => "ARRAY<STRING>",
+        Kind::String | Kind::Json | Kind::Uuid | Kind::Other => "STRING",
+    }
+}
+
+// the statement the destination table of the plan would be created with, the
+// same as the flow creates it with. None for destinations without tables or
+// of an unknown type.
+fn destination_ddl(plan: &TablePlan, destination: i32) -> Option<String> {
+    let columns = plan.columns.as_ref()?;
+    let destination = DbType::from_i32(destination)?;
+    let quoted = |name: &str| format!("\"{}\"", name);
+    let mut definitions: Vec<String> = match destination {
+        DbType::Postgres => columns
+            .iter()
+            .map(|(name, ty)| format!("{} {}", quoted(name), postgres_type(kind(ty))))
+            .collect(),
+        DbType::Snowflake => columns
+            .iter()
+            .map(|(name, ty)| {
+                format!(
+                    "{} {}",
+                    quoted(&name.to_uppercase()),
+                    snowflake_type(kind(ty))
+                )
+            })
+            .chain(std::iter::once(format!(
+                "{} BOOLEAN DEFAULT FALSE",
+                quoted(SNOWFLAKE_IS_DELETED_COLUMN)
+            )))
+            .collect(),
+        // bigquery tables have no primary key.
+        DbType::Bigquery => {
+            let definitions = columns
+                .iter()
+                .map(|(name, ty)| format!("`{}` {}", name, bigquery_type(kind(ty))))
+                .collect::<Vec<_>>();
+            return Some(format!(
+                "CREATE TABLE IF NOT EXISTS {}({})",
+                plan.destination_table,
+                definitions.join(", ")
+            ));
+        }
+        _ => return None,
+    };
+
+    if !plan.primary_key.is_empty() {
+        let primary_key: Vec<String> = plan
+            .primary_key
+            .iter()
+            .map(|column| match destination {
+                DbType::Snowflake => quoted(&column.to_uppercase()),
+                _ => quoted(column),
+            })
+            .collect();
+        definitions.push(format!("PRIMARY KEY({})", primary_key.join(", ")));
+    }
+    Some(format!(
+        "CREATE TABLE IF NOT EXISTS {}({})",
+        plan.destination_table,
+        definitions.join(", ")
+    ))
+}
@@ -0,0 +1,512 @@
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, State,
+    },
+    http::{header, Request, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use pt::peerdb_route::{
+    flow_service_client::FlowServiceClient, AddTablesToFlowRequest, AddTablesToFlowResponse,
+    CreateCdcFlowRequest, CreateCdcFlowResponse, CreatePeerRequest, CreatePeerResponse,
+    CreateQRepFlowRequest, CreateQRepFlowResponse, MirrorStatusRequest, MirrorStatusResponse,
+    PauseFlowRequest, PauseFlowResponse, PauseTableRequest, PauseTableResponse,
+    RemoveTableFromFlowRequest, RemoveTableFromFlowResponse, ResumeFlowRequest, ResumeFlowResponse,
+    ResyncMirrorRequest, ResyncMirrorResponse, ShutdownRequest, ShutdownResponse,
+    UpdatePeerRequest, UpdatePeerResponse, ValidatePeerRequest, ValidatePeerResponse,
+};
+use serde::Serialize;
+use tonic::{codec::Streaming, transport::Channel, Code};
+
+use crate::config_file::Reloadable;
+
+type RouteClient = FlowServiceClient<Channel>;
+
+// how often mirror status is polled for flow servers that can't stream it.
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+// GatewayError carries a failed RPC back to the caller in the same shape the
+// flow api's grpc-gateway uses, so existing HTTP clients keep working.
+struct GatewayError(tonic::Status);
+
+fn http_status(code: Code) -> StatusCode {
+    match code {
+        Code::Ok => StatusCode::OK,
+        Code::Cancelled => StatusCode::REQUEST_TIMEOUT,
+        Code::InvalidArgument | Code::OutOfRange | Code::FailedPrecondition => {
+            StatusCode::BAD_REQUEST
+        }
+        Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        Code::NotFound => StatusCode::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+        Code::PermissionDenied => StatusCode::FORBIDDEN,
+        Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::Unknown | Code::Internal | Code::DataLoss => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+fn error_body(status: &tonic::Status) -> serde_json::Value {
+    serde_json::json!({
+        "code": status.code() as i32,
+        "message": status.message(),
+        "details": [],
+    })
+}
+
+impl IntoResponse for GatewayError {
+    fn into_response(self) -> Response {
+        (http_status(self.0.code()), Json(error_body(&self.0))).into_response()
+    }
+}
+
+impl From<tonic::Status> for GatewayError {
+    fn from(status: tonic::Status) -> Self {
+        Self(status)
+    }
+}
+
+type GatewayResult<T> = Result<Json<T>, GatewayError>;
+
+// the token is compared in constant time, so its prefixes can't be guessed from
+// how long a request takes to be rejected.
+fn token_matches(given: &[u8], token: &[u8]) -> bool {
+    given.len() == token.len()
+        && given
+            .iter()
+            .zip(token)
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+// require_auth rejects the requests that don't carry the token of the gateway as
+// a bearer token, an empty token rejects every request.
+async fn require_auth<B>(
+    State(token): State<Arc<Reloadable<String>>>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let given = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    let token = token.get();
+    match given {
+        Some(given) if !token.is_empty() && token_matches(given.as_bytes(), token.as_bytes()) => {
+            next.run(req).await
+        }
+        _ => {
+            let status = tonic::Status::unauthenticated("missing or invalid bearer token");
+            (
+                StatusCode::UNAUTHORIZED,
+                [(header::WWW_AUTHENTICATE, "Bearer")],
+                Json(error_body(&status)),
+            )
+                .into_response()
+        }
+    }
+}
+
+async fn validate_peer(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<ValidatePeerRequest>,
+) -> GatewayResult<ValidatePeerResponse> {
+    Ok(Json(client.validate_peer(req).await?.into_inner()))
+}
+
+async fn create_peer(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<CreatePeerRequest>,
+) -> GatewayResult<CreatePeerResponse> {
+    Ok(Json(client.create_peer(req).await?.into_inner()))
+}
+
+async fn update_peer(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<UpdatePeerRequest>,
+) -> GatewayResult<UpdatePeerResponse> {
+    Ok(Json(client.update_peer(req).await?.into_inner()))
+}
+
+async fn create_cdc_flow(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<CreateCdcFlowRequest>,
+) -> GatewayResult<CreateCdcFlowResponse> {
+    Ok(Json(client.create_cdc_flow(req).await?.into_inner()))
+}
+
+async fn create_qrep_flow(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<CreateQRepFlowRequest>,
+) -> GatewayResult<CreateQRepFlowResponse> {
+    Ok(Json(client.create_q_rep_flow(req).await?.into_inner()))
+}
+
+async fn shutdown_flow(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<ShutdownRequest>,
+) -> GatewayResult<ShutdownResponse> {
+    Ok(Json(client.shutdown_flow(req).await?.into_inner()))
+}
+
+async fn pause_flow(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<PauseFlowRequest>,
+) -> GatewayResult<PauseFlowResponse> {
+    Ok(Json(client.pause_flow(req).await?.into_inner()))
+}
+
+async fn resume_flow(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<ResumeFlowRequest>,
+) -> GatewayResult<ResumeFlowResponse> {
+    Ok(Json(client.resume_flow(req).await?.into_inner()))
+}
+
+async fn add_tables_to_flow(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<AddTablesToFlowRequest>,
+) -> GatewayResult<AddTablesToFlowResponse> {
+    Ok(Json(client.add_tables_to_flow(req).await?.into_inner()))
+}
+
+async fn pause_table(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<PauseTableRequest>,
+) -> GatewayResult<PauseTableResponse> {
+    Ok(Json(client.pause_table(req).await?.into_inner()))
+}
+
+async fn remove_table_from_flow(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<RemoveTableFromFlowRequest>,
+) -> GatewayResult<RemoveTableFromFlowResponse> {
+    Ok(Json(client.remove_table_from_flow(req).await?.into_inner()))
+}
+
+async fn resync_mirror(
+    State(mut client): State<RouteClient>,
+    Json(req): Json<ResyncMirrorRequest>,
+) -> GatewayResult<ResyncMirrorResponse> {
+    Ok(Json(client.resync_mirror(req).await?.into_inner()))
+}
+
+async fn mirror_status(
+    State(mut client): State<RouteClient>,
+    Path(flow_job_name): Path<String>,
+) -> GatewayResult<MirrorStatusResponse> {
+    let req = MirrorStatusRequest { flow_job_name };
+    Ok(Json(client.mirror_status(req).await?.into_inner()))
+}
+
+async fn watch_mirror_status(
+    State(client): State<RouteClient>,
+    Path(flow_job_name): Path<String>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    ws.on_upgrade(move |socket| stream_mirror_status(socket, client, flow_job_name))
+}
+
+// stream_mirror_status sends every status update of the mirror to the socket as
+// a JSON text message, until either side goes away. A failed RPC is reported in
+// the same shape as the HTTP errors before the socket is closed.
+async fn stream_mirror_status(
+    mut socket: WebSocket,
+    mut client: RouteClient,
+    flow_job_name: String,
+) {
+    let req = MirrorStatusRequest { flow_job_name };
+    let result = match client.watch_mirror_status(req.clone()).await {
+        Ok(updates) => forward_status_updates(&mut socket, updates.into_inner()).await,
+        // older flow servers don't stream status, fall back to polling it
+        Err(status) if status.code() == Code::Unimplemented => {
+            poll_mirror_status(&mut socket, &mut client, req).await
+        }
+        Err(status) => Err(status),
+    };
+
+    if let Err(status) = result {
+        let _ = send_json(&mut socket, &error_body(&status)).await;
+    }
+    let _ = socket.close().await;
+}
+
+async fn forward_status_updates(
+    socket: &mut WebSocket,
+    mut updates: Streaming<MirrorStatusResponse>,
+) -> Result<(), tonic::Status> {
+    while let Some(update) = updates.message().await? {
+        if send_json(socket, &update).await.is_err() {
+            // the client disconnected
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+async fn poll_mirror_status(
+    socket: &mut WebSocket,
+    client: &mut RouteClient,
+    req: MirrorStatusRequest,
+) -> Result<(), tonic::Status> {
+    let mut interval = tokio::time::interval(STATUS_POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        let update = client.mirror_status(req.clone()).await?.into_inner();
+        if send_json(socket, &update).await.is_err() {
+            // the client disconnected
+            return Ok(());
+        }
+    }
+}
+
+async fn send_json(socket: &mut WebSocket, value: &impl Serialize) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(value).map_err(axum::Error::new)?;
+    socket.send(Message::Text(text)).await
+}
+
+// the routes mirror the google.api.http annotations in route.proto, ShutdownFlow
+// has no annotation so it gets a route alongside the other flow endpoints. Every
+// route requires the token of the gateway.
+fn router(client: RouteClient, token: Arc<Reloadable<String>>) -> Router {
+    Router::new()
+        .route("/v1/peers/validate", post(validate_peer))
+        .route("/v1/peers/create", post(create_peer))
+        .route("/v1/peers/update", post(update_peer))
+        .route("/v1/flows/cdc/create", post(create_cdc_flow))
+        .route("/v1/flows/qrep/create", post(create_qrep_flow))
+        .route("/v1/flows/shutdown", post(shutdown_flow))
+        .route("/v1/flows/pause", post(pause_flow))
+        .route("/v1/flows/resume", post(resume_flow))
+        .route("/v1/flows/cdc/tables/add", post(add_tables_to_flow))
+        .route("/v1/flows/cdc/tables/pause", post(pause_table))
+        .route("/v1/flows/cdc/tables/remove", post(remove_table_from_flow))
+        .route("/v1/flows/cdc/resync", post(resync_mirror))
+        .route("/v1/mirrors/:flow_job_name", get(mirror_status))
+        .route("/v1/mirrors/:flow_job_name/watch", get(watch_mirror_status))
+        .route_layer(middleware::from_fn_with_state(token, require_auth))
+        .with_state(client)
+}
+
+pub async fn serve(
+    addr: SocketAddr,
+    client: RouteClient,
+    token: Arc<Reloadable<String>>,
+) -> anyhow::Result<()> {
+    tracing::info!("REST gateway listening on {}", addr);
+    axum::Server::bind(&addr)
+        .serve(router(client, token).into_make_service())
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use tonic::transport::Endpoint;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const TOKEN: &str = "gateway-token";
+
+    // the flow api the routes call is unreachable, so every RPC fails once a
+    // request gets past the gateway.
+    fn test_router(token: &str) -> Router {
+        let channel = Endpoint::from_static("http://127.0.0.1:1").connect_lazy();
+        router(
+            FlowServiceClient::new(channel),
+            Arc::new(Reloadable::new(token.to_string())),
+        )
+    }
+
+    fn request(method: &str, uri: &str, authorization: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(authorization) = authorization {
+            builder = builder.header(header::AUTHORIZATION, authorization);
+        }
+        let body = match method {
+            "POST" => {
+                builder = builder.header(header::CONTENT_TYPE, "application/json");
+                Body::from("{}")
+            }
+            _ => Body::empty(),
+        };
+        builder.body(body).unwrap()
+    }
+
+    async fn send(router: Router, req: Request<Body>) -> (StatusCode, Response) {
+        let res = router.oneshot(req).await.unwrap();
+        (res.status(), res)
+    }
+
+    async fn json_body(res: Response) -> serde_json::Value {
+        let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    // the code of the failed RPC a response carries, after checking the status
+    // of the response is the one of the code.
+    async fn rpc_error(status: StatusCode, res: Response) -> Code {
+        let body = json_body(res).await;
+        let code = Code::from(body["code"].as_i64().unwrap() as i32);
+        assert_eq!(status, http_status(code), "{}", body);
+        assert_eq!(body["details"], serde_json::json!([]));
+        code
+    }
+
+    #[tokio::test]
+    async fn test_missing_token() {
+        let req = request("GET", "/v1/mirrors/m", None);
+        let (status, res) = send(test_router(TOKEN), req).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(res.headers()[header::WWW_AUTHENTICATE], "Bearer");
+        let body = json_body(res).await;
+        assert_eq!(body["code"], Code::Unauthenticated as i32);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_token() {
+        for authorization in [
+            "Bearer wrong-token",
+            "Bearer gateway-toke",
+            "Basic gateway-token",
+            TOKEN,
+        ] {
+            let req = request("GET", "/v1/mirrors/m", Some(authorization));
+            let (status, _) = send(test_router(TOKEN), req).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", authorization);
+        }
+
+        // an empty token rejects every request, even one with an empty token.
+        let req = request("GET", "/v1/mirrors/m", Some("Bearer "));
+        let (status, _) = send(test_router(""), req).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_valid_token() {
+        let authorization = format!("Bearer {}", TOKEN);
+        let req = request("GET", "/v1/mirrors/m", Some(&authorization));
+        let (status, res) = send(test_router(TOKEN), req).await;
+        assert_ne!(status, StatusCode::UNAUTHORIZED);
+        let code = rpc_error(status, res).await;
+        assert_ne!(code, Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn test_routes() {
+        let authorization = format!("Bearer {}", TOKEN);
+        let routes = [
+            ("POST", "/v1/peers/validate"),
+            ("POST", "/v1/peers/create"),
+            ("POST", "/v1/peers/update"),
+            ("POST", "/v1/flows/cdc/create"),
+            ("POST", "/v1/flows/qrep/create"),
+            ("POST", "/v1/flows/shutdown"),
+            ("POST", "/v1/flows/pause"),
+            ("POST", "/v1/flows/resume"),
+            ("POST", "/v1/flows/cdc/tables/add"),
+            ("POST", "/v1/flows/cdc/tables/pause"),
+            ("POST", "/v1/flows/cdc/tables/remove"),
+            ("POST", "/v1/flows/cdc/resync"),
+            ("GET", "/v1/mirrors/m"),
+        ];
+        for (method, uri) in routes {
+            let req = request(method, uri, None);
+            let (status, _) = send(test_router(TOKEN), req).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED, "{} {}", method, uri);
+
+            // the RPC of the route fails, and comes back with the status of
+            // its code.
+            let req = request(method, uri, Some(&authorization));
+            let (status, res) = send(test_router(TOKEN), req).await;
+            let code = rpc_error(status, res).await;
+            assert_ne!(code, Code::Ok, "{} {}", method, uri);
+            assert_ne!(code, Code::Unauthenticated, "{} {}", method, uri);
+        }
+
+        let req = request("GET", "/v1/peers/validate", Some(&authorization));
+        let (status, _) = send(test_router(TOKEN), req).await;
+        assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+        let req = request("GET", "/v1/unknown", Some(&authorization));
+        let (status, _) = send(test_router(TOKEN), req).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_watch_route() {
+        let authorization = format!("Bearer {}", TOKEN);
+        let req = request("GET", "/v1/mirrors/m/watch", None);
+        let (status, _) = send(test_router(TOKEN), req).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // the status socket is only opened for a websocket upgrade.
+        let req = request("GET", "/v1/mirrors/m/watch", Some(&authorization));
+        let (status, _) = send(test_router(TOKEN), req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        // the handshake is accepted, but a request that isn't served over a
+        // connection can't be upgraded.
+        let mut req = request("GET", "/v1/mirrors/m/watch", Some(&authorization));
+        let headers = req.headers_mut();
+        headers.insert(header::CONNECTION, "upgrade".parse().unwrap());
+        headers.insert(header::UPGRADE, "websocket".parse().unwrap());
+        headers.insert(header::SEC_WEBSOCKET_VERSION, "13".parse().unwrap());
+        headers.insert(
+            header::SEC_WEBSOCKET_KEY,
+            "dGhlIHNhbXBsZSBub25jZQ==".parse().unwrap(),
+        );
+        let (status, _) = send(test_router(TOKEN), req).await;
+        assert_eq!(status, StatusCode::UPGRADE_REQUIRED);
+    }
+
+    #[test]
+    fn test_http_status() {
+        let statuses = [
+            (Code::Ok, StatusCode::OK),
+            (Code::Cancelled, StatusCode::REQUEST_TIMEOUT),
+            (Code::InvalidArgument, StatusCode::BAD_REQUEST),
+            (Code::OutOfRange, StatusCode::BAD_REQUEST),
+            (Code::FailedPrecondition, StatusCode::BAD_REQUEST),
+            (Code::DeadlineExceeded, StatusCode::GATEWAY_TIMEOUT),
+            (Code::NotFound, StatusCode::NOT_FOUND),
+            (Code::AlreadyExists, StatusCode::CONFLICT),
+            (Code::Aborted, StatusCode::CONFLICT),
+            (Code::PermissionDenied, StatusCode::FORBIDDEN),
+            (Code::Unauthenticated, StatusCode::UNAUTHORIZED),
+            (Code::ResourceExhausted, StatusCode::TOO_MANY_REQUESTS),
+            (Code::Unimplemented, StatusCode::NOT_IMPLEMENTED),
+            (Code::Unavailable, StatusCode::SERVICE_UNAVAILABLE),
+            (Code::Unknown, StatusCode::INTERNAL_SERVER_ERROR),
+            (Code::Internal, StatusCode::INTERNAL_SERVER_ERROR),
+            (Code::DataLoss, StatusCode::INTERNAL_SERVER_ERROR),
+        ];
+        for (code, status) in statuses {
+            assert_eq!(http_status(code), status, "{:?}", code);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_response() {
+        let res = GatewayError(tonic::Status::not_found("no mirror m")).into_response();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        let body = json_body(res).await;
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "code": Code::NotFound as i32,
+                "message": "no mirror m",
+                "details": [],
+            })
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use std::{collections::HashSet, fmt::Debug, net::IpAddr, sync::Arc};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use futures::Sink;
+use pgwire::{
+    api::{
+        auth::{finish_authentication, save_startup_parameters_to_metadata, StartupHandler},
+        ClientInfo,
+    },
+    error::{ErrorInfo, PgWireError, PgWireResult},
+    messages::{PgWireBackendMessage, PgWireFrontendMessage},
+};
+
+use crate::NexusServerParameterProvider;
+
+/// How the clients a rule matches log in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HbaMethod {
+    /// Logs the client in without asking for a password.
+    Trust,
+    /// Asks the client for its password, with the auth method of the server.
+    Password,
+    /// Turns the client away.
+    Reject,
+}
+
+// the connections a rule applies to, like the first field of a pg_hba.conf
+// line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ConnectionType {
+    // the clients of the Unix socket.
+    Local,
+    // the clients connecting over TCP, with or without TLS.
+    Host,
+    HostSsl,
+    HostNoSsl,
+}
+
+// a range of addresses, `10.0.0.0/8` or a single address.
+#[derive(Clone, Copy, Debug)]
+struct Cidr {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl Cidr {
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (s, None),
+        };
+        let addr: IpAddr = addr
+            .parse()
+            .with_context(|| format!("invalid address {}", s))?;
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len
+                .parse()
+                .ok()
+                .filter(|len| *len <= max_len)
+                .with_context(|| format!("invalid prefix length in {}", s))?,
+            None => max_len,
+        };
+        Ok(Self { addr, prefix_len })
+    }
+
+    // IPv4 clients connecting to a dual-stack listener show up as mapped
+    // IPv6 addresses, they match the IPv4 ranges.
+    fn contains(&self, addr: IpAddr) -> bool {
+        let addr = match addr {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(addr),
+            v4 => v4,
+        };
+        match (self.addr, addr) {
+            (IpAddr::V4(net), IpAddr::V4(addr)) => {
+                prefix_matches(&net.octets(), &addr.octets(), self.prefix_len)
+            }
+            (IpAddr::V6(net), IpAddr::V6(addr)) => {
+                prefix_matches(&net.octets(), &addr.octets(), self.prefix_len)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn prefix_matches(net: &[u8], addr: &[u8], prefix_len: u8) -> bool {
+    let full_bytes = (prefix_len / 8) as usize;
+    if net[..full_bytes] != addr[..full_bytes] {
+        return false;
+    }
+    let rest = prefix_len % 8;
+    if rest == 0 {
+        return true;
+    }
+    let mask = 0xffu8 << (8 - rest);
+    net[full_bytes] & mask == addr[full_bytes] & mask
+}
+
+#[derive(Debug)]
+struct HbaRule {
+    connection: ConnectionType,
+    // None for `all`.
+    users: Option<HashSet<String>>,
+    // None for `all`, and for the clients of the Unix socket.
+    network: Option<Cidr>,
+    method: HbaMethod,
+}
+
+/// The rules clients are checked against when they connect, read from a file
+/// in the format of postgres' pg_hba.conf, without the database field:
+///
+/// ```text
+/// # type     users        address         method
+/// local      all                          trust
+/// hostssl    alice,bob    10.0.0.0/8      password
+/// host       all          0.0.0.0/0       reject
+/// ```
+///
+/// The first rule that matches the connection, the user and the address of a
+/// client decides how it logs in, a client no rule matches is turned away.
+#[derive(Debug)]
+pub struct HbaRules {
+    rules: Vec<HbaRule>,
+}
+
+impl HbaRules {
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        let contents =
+            std::fs::read_to_string(path).with_context(|| format!("unable to read {}", path))?;
+        Self::parse(&contents).with_context(|| format!("invalid hba file {}", path))
+    }
+
+    fn parse(contents: &str) -> anyhow::Result<Self> {
+        let mut rules = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.is_empty() {
+                continue;
+            }
+            let rule = parse_rule(&fields).with_context(|| format!("line {}", i + 1))?;
+            rules.push(rule);
+        }
+        Ok(Self { rules })
+    }
+
+    // how the client logs in, None when no rule matches it.
+    fn method(&self, local: bool, secure: bool, addr: IpAddr, user: &str) -> Option<HbaMethod> {
+        self.rules
+            .iter()
+            .find(|rule| {
+                let connection_matches = match rule.connection {
+                    ConnectionType::Local => local,
+                    ConnectionType::Host => !local,
+                    ConnectionType::HostSsl => !local && secure,
+                    ConnectionType::HostNoSsl => !local && !secure,
+                };
+                let user_matches = match &rule.users {
+                    Some(users) => users.contains(user),
+                    None => true,
+                };
+                let address_matches = match &rule.network {
+                    Some(network) => network.contains(addr),
+                    None => true,
+                };
+                connection_matches && user_matches && address_matches
+            })
+            .map(|rule| rule.method)
+    }
+}
+
+fn parse_rule(fields: &[&str]) -> anyhow::Result<HbaRule> {
+    let connection = match fields[0] {
+        "local" => ConnectionType::Local,
+        "host" => ConnectionType::Host,
+        "hostssl" => ConnectionType::HostSsl,
+        "hostnossl" => ConnectionType::HostNoSsl,
+        other => anyhow::bail!("unknown connection type {}", other),
+    };
+    // the rules of the Unix socket have no address.
+    let (users, network, method) = match (connection, fields) {
+        (ConnectionType::Local, [_, users, method]) => (*users, None, *method),
+        (ConnectionType::Local, _) => {
+            anyhow::bail!("expected `local <users> <method>`")
+        }
+        (_, [_, users, address, method]) => {
+            let network = match *address {
+                "all" => None,
+                address => Some(Cidr::parse(address)?),
+            };
+            (*users, network, *method)
+        }
+        (_, _) => anyhow::bail!("expected `{} <users> <address> <method>`", fields[0]),
+    };
+    let users = match users {
+        "all" => None,
+        users => Some(users.split(',').map(str::to_owned).collect()),
+    };
+    let method = match method {
+        "trust" => HbaMethod::Trust,
+        "password" => HbaMethod::Password,
+        "reject" => HbaMethod::Reject,
+        other => anyhow::bail!("unknown method {}", other),
+    };
+    Ok(HbaRule {
+        connection,
+        users,
+        network,
+        method,
+    })
+}
+
+/// Checks the client against the hba rules of the server before it
+/// authenticates, turning it away, or logging it in without a password, as
+/// the rule it matches says. Clients are left to the wrapped handler when the
+/// server has no rules.
+pub struct HbaStartupHandler<H> {
+    inner: Arc<H>,
+    rules: Option<Arc<HbaRules>>,
+    // whether the client connected over the Unix socket.
+    local: bool,
+}
+
+impl<H> HbaStartupHandler<H> {
+    pub fn new(inner: Arc<H>, rules: Option<Arc<HbaRules>>, local: bool) -> Self {
+        Self {
+            inner,
+            rules,
+            local,
+        }
+    }
+}
+
+#[async_trait]
+impl<H: StartupHandler> StartupHandler for HbaStartupHandler<H> {
+    async fn on_startup<C>(
+        &self,
+        client: &mut C,
+        message: PgWireFrontendMessage,
+    ) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        if let (Some(rules), PgWireFrontendMessage::Startup(startup)) = (&self.rules, &message) {
+            let user = startup
+                .parameters()
+                .get("user")
+                .cloned()
+                .unwrap_or_default();
+            let addr = client.socket_addr().ip();
+            let host = if self.local {
+                "[local]".to_owned()
+            } else {
+                addr.to_string()
+            };
+            match rules.method(self.local, client.is_secure(), addr, &user) {
+                Some(HbaMethod::Trust) => {
+                    save_startup_parameters_to_metadata(client, startup);
+                    finish_authentication(client, &NexusServerParameterProvider).await;
+                    return Ok(());
+                }
+                Some(HbaMethod::Password) => {}
+                Some(HbaMethod::Reject) => {
+                    return Err(hba_error(format!(
+                        "hba rules reject connection for host \"{}\", user \"{}\"",
+                        host, user
+                    )))
+                }
+                None => {
+                    return Err(hba_error(format!(
+                        "no hba entry for host \"{}\", user \"{}\"",
+                        host, user
+                    )))
+                }
+            }
+        }
+        self.inner.on_startup(client, message).await
+    }
+}
+
+fn hba_error(message: String) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "FATAL".to_owned(),
+        "28000".to_owned(),
+        message,
+    )))
+}
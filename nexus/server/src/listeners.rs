@@ -0,0 +1,66 @@
+use std::net::SocketAddr;
+
+use clap::ValueEnum;
+use futures::future::select_all;
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::AuthMethod;
+
+/// A further address the server listens on, with settings of its own for the
+/// clients that connect to it, e.g. an internal admin port and an external
+/// port that is read only and requires TLS. It is the address followed by its
+/// settings, separated by commas:
+///
+/// ```text
+/// 0.0.0.0:9901,read_only,require_tls,auth_method=md5,hba_file=/etc/peerdb/external.hba
+/// ```
+///
+/// The settings left out are those of the server, a listener can only make
+/// them stricter, e.g. a read-only server has no listener that allows writes.
+#[derive(Clone, Debug)]
+pub struct ListenerSpec {
+    pub addr: String,
+    pub read_only: bool,
+    pub require_tls: bool,
+    pub auth_method: Option<AuthMethod>,
+    pub hba_file: Option<String>,
+}
+
+pub fn parse_listener(spec: &str) -> Result<ListenerSpec, String> {
+    let mut settings = spec.split(',').map(str::trim);
+    let addr = match settings.next() {
+        Some(addr) if !addr.is_empty() => addr.to_owned(),
+        _ => return Err(format!("listener {} has no address", spec)),
+    };
+    let mut listener = ListenerSpec {
+        addr,
+        read_only: false,
+        require_tls: false,
+        auth_method: None,
+        hba_file: None,
+    };
+    for setting in settings {
+        match setting.split_once('=') {
+            None if setting == "read_only" => listener.read_only = true,
+            None if setting == "require_tls" => listener.require_tls = true,
+            Some(("auth_method", method)) => {
+                listener.auth_method = Some(AuthMethod::from_str(method, true)?);
+            }
+            Some(("hba_file", path)) => listener.hba_file = Some(path.to_owned()),
+            _ => return Err(format!("unknown setting {} of listener {}", setting, spec)),
+        }
+    }
+    Ok(listener)
+}
+
+/// Accepts the next client of any of the listeners, along with the index of
+/// the listener it connected to.
+pub async fn accept(
+    listeners: &[TcpListener],
+) -> (usize, std::io::Result<(TcpStream, SocketAddr)>) {
+    let accepts = listeners
+        .iter()
+        .enumerate()
+        .map(|(i, listener)| Box::pin(async move { (i, listener.accept().await) }));
+    select_all(accepts).await.0
+}
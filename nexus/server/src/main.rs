@@ -1,103 +1,995 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    ops::ControlFlow,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex as StdMutex, Weak,
+    },
+    time::Duration,
+};
 
-use analyzer::{PeerDDL, QueryAssocation};
+use access_control::Requirement;
+use analyzer::{FederatedTable, NotificationStatement, PeerDDL, QueryAssocation};
+use anyhow::Context;
 use async_trait::async_trait;
 use bytes::{BufMut, BytesMut};
-use catalog::{Catalog, CatalogConfig, WorkflowDetails};
+use catalog::{Catalog, CatalogConfig, MasterKey, Privilege, ResourceGroup, WorkflowDetails};
 use clap::Parser;
 use cursor::PeerCursors;
-use dashmap::DashMap;
-use flow_rs::grpc::{FlowGrpcClient, PeerValidationResult};
-use peer_bigquery::BigQueryQueryExecutor;
+use dashmap::{DashMap, DashSet};
+use flow_rs::grpc::{FlowGrpcClient, MirrorError, PeerValidationResult};
+use futures::{
+    channel::mpsc,
+    stream::{self, BoxStream},
+    Sink, SinkExt, StreamExt,
+};
 use peer_connections::{PeerConnectionTracker, PeerConnections};
 use peer_cursor::{
-    util::{records_to_query_response, sendable_stream_to_query_response},
-    QueryExecutor, QueryOutput, SchemaRef,
+    connector::ConnectorRegistry,
+    error::peer_error,
+    util::{records_to_query_response, sendable_stream_to_query_response, with_result_format},
+    Notification, QueryExecutor, QueryOutput, Record, Records, SchemaRef, SendableStream,
+};
+use peerdb_parser::{
+    NexusParsedStatement, NexusQueryParser, NexusStatement, DEFAULT_PEER_VARIABLE,
 };
-use peerdb_parser::{NexusParsedStatement, NexusQueryParser, NexusStatement};
 use pgerror::PgError;
 use pgwire::{
     api::{
         auth::{
-            md5pass::{hash_md5_password, MakeMd5PasswordAuthStartupHandler},
-            AuthSource, LoginInfo, Password, ServerParameterProvider,
+            md5pass::{
+                hash_md5_password, MakeMd5PasswordAuthStartupHandler, Md5PasswordAuthStartupHandler,
+            },
+            scram::{MakeSASLScramAuthStartupHandler, SASLScramAuthStartupHandler},
+            AuthSource, LoginInfo, Password, ServerParameterProvider, StartupHandler,
         },
         portal::{Format, Portal},
-        query::{ExtendedQueryHandler, SimpleQueryHandler, StatementOrPortal},
+        query::{
+            send_execution_response, send_query_response, ExtendedQueryHandler, SimpleQueryHandler,
+            StatementOrPortal,
+        },
         results::{DescribeResponse, Response, Tag},
-        store::MemPortalStore,
-        ClientInfo, MakeHandler, Type,
+        store::{MemPortalStore, PortalStore},
+        ClientInfo, MakeHandler, PgWireConnectionState, Type, DEFAULT_NAME, METADATA_USER,
     },
     error::{ErrorInfo, PgWireError, PgWireResult},
-    tokio::process_socket,
+    messages::{
+        extendedquery::{Execute, Sync as PgSync},
+        response::{EmptyQueryResponse, ReadyForQuery, READY_STATUS_IDLE},
+        simplequery::Query,
+        PgWireBackendMessage, PgWireFrontendMessage,
+    },
 };
 use pt::{
-    flow_model::QRepFlowJob,
-    peerdb_peers::{peer::Config, Peer},
+    flow_model::{FlowJob, FlowJobTableMapping, FlowOrderingKey, QRepFlowJob},
+    peerdb_flow::TablePausePolicy,
+    peerdb_peers::{peer::Config, DbType, Peer},
 };
 use rand::Rng;
-use tokio::sync::{Mutex, MutexGuard};
-use tokio::{io::AsyncWriteExt, net::TcpListener};
+use scheduler::{QueryPermit, QueryScheduler};
+use session_settings::SessionSettings;
+use session_tables::SessionTables;
+use sqlparser::{
+    ast::{visit_relations_mut, Expr, Ident, ObjectName, Statement},
+    dialect::PostgreSqlDialect,
+};
+use tokio::sync::{Mutex, MutexGuard, Notify, OnceCell};
+use tokio::{
+    io::AsyncWriteExt,
+    net::TcpListener,
+    signal::unix::{signal, SignalKind},
+};
 use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::{fmt, prelude::*, EnvFilter};
+use tracing_subscriber::{fmt, prelude::*, reload, EnvFilter, Layer, Registry};
+use value::Value;
 
+mod access_control;
+mod audit;
+mod builtin_functions;
+mod cancel;
+mod catalog_emulation;
+mod config_file;
+mod connection;
+mod connections;
+mod copy_in;
+mod copy_out;
 mod cursor;
+mod database_peer;
+mod describe_mirror;
+mod explain_mirror;
+#[cfg(feature = "rest-gateway")]
+mod gateway;
+mod hba;
+mod listeners;
+mod notifications;
+mod parameters;
+mod peer_health;
+mod peer_pool;
+mod scheduler;
+mod session_settings;
+mod session_tables;
+mod show_mirrors;
+mod show_peers;
+mod show_tables;
+mod shutdown;
+mod tls;
+mod unix_socket;
+mod validate_peer;
+
+/// How clients prove they know their password when they log in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum AuthMethod {
+    Md5,
+    #[value(name = "scram-sha-256")]
+    ScramSha256,
+}
+
+// the startup handlers of the auth methods, a connection is authenticated by a
+// handler made for it.
+#[derive(Clone)]
+enum Authenticator {
+    Md5(Arc<MakeMd5PasswordAuthStartupHandler<NexusAuthSource, NexusServerParameterProvider>>),
+    Scram(Arc<MakeSASLScramAuthStartupHandler<NexusAuthSource, NexusServerParameterProvider>>),
+}
+
+impl Authenticator {
+    fn make(&self) -> PasswordStartupHandler {
+        match self {
+            Authenticator::Md5(authenticator) => PasswordStartupHandler::Md5(authenticator.make()),
+            Authenticator::Scram(authenticator) => {
+                PasswordStartupHandler::Scram(authenticator.make())
+            }
+        }
+    }
+}
+
+// the startup handler an authenticator makes for a connection, which the
+// handlers of the rest of the startup wrap.
+enum PasswordStartupHandler {
+    Md5(Arc<Md5PasswordAuthStartupHandler<NexusAuthSource, NexusServerParameterProvider>>),
+    Scram(Arc<SASLScramAuthStartupHandler<NexusAuthSource, NexusServerParameterProvider>>),
+}
+
+#[async_trait]
+impl StartupHandler for PasswordStartupHandler {
+    async fn on_startup<C>(
+        &self,
+        client: &mut C,
+        message: PgWireFrontendMessage,
+    ) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        match self {
+            PasswordStartupHandler::Md5(handler) => handler.on_startup(client, message).await,
+            PasswordStartupHandler::Scram(handler) => handler.on_startup(client, message).await,
+        }
+    }
+}
 
-struct FixedPasswordAuthSource {
-    password: String,
+// every user logs in with the password of the server, unless access control
+// is enabled, with which users other than the admin log in with the password
+// they were created with.
+struct NexusAuthSource {
+    // the password of the server, which SIGHUP reloads from the config file.
+    password: Arc<config_file::Reloadable<String>>,
+    method: AuthMethod,
+    // the admin and the catalog users are looked up in, set when access
+    // control is enabled.
+    access_control: Option<(String, Arc<Mutex<Catalog>>)>,
 }
 
-impl FixedPasswordAuthSource {
-    pub fn new(password: String) -> Self {
-        Self { password }
+impl NexusAuthSource {
+    pub fn new(
+        password: Arc<config_file::Reloadable<String>>,
+        method: AuthMethod,
+        access_control: Option<(String, Arc<Mutex<Catalog>>)>,
+    ) -> Self {
+        Self {
+            password,
+            method,
+            access_control,
+        }
     }
 }
 
 #[async_trait]
-impl AuthSource for FixedPasswordAuthSource {
+impl AuthSource for NexusAuthSource {
+    // the password is the md5 hash salted for the login with md5, and the
+    // salted password of the SCRAM-SHA-256 secret along with its salt with
+    // SCRAM-SHA-256.
     async fn get_password(&self, login_info: &LoginInfo) -> PgWireResult<Password> {
         tracing::info!("login info: {:?}", login_info);
 
-        // randomly generate a 4 byte salt
-        let salt = rand::thread_rng().gen::<[u8; 4]>().to_vec();
-        let password = &self.password;
-        let hash_password = hash_md5_password(
-            login_info.user().map(|s| s.as_str()).unwrap_or(""),
-            password,
-            salt.as_ref(),
-        );
-        Ok(Password::new(Some(salt), hash_password.as_bytes().to_vec()))
+        let user_name = login_info.user().map(|s| s.as_str()).unwrap_or("");
+        let user_password = match &self.access_control {
+            Some((admin_user, catalog)) if user_name != admin_user => {
+                let catalog = catalog.lock().await;
+                let user_password = catalog.get_user_password(user_name).await.map_err(|err| {
+                    PgWireError::ApiError(Box::new(PgError::Internal {
+                        err_msg: format!("unable to look up user: {:?}", err),
+                    }))
+                })?;
+                match user_password {
+                    Some(user_password) => Some(user_password),
+                    None => {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "FATAL".to_owned(),
+                            "28000".to_owned(),
+                            format!("user {} does not exist", user_name),
+                        ))))
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        match self.method {
+            AuthMethod::Md5 => {
+                // randomly generate a 4 byte salt
+                let salt = rand::thread_rng().gen::<[u8; 4]>().to_vec();
+                let hash_password = match user_password {
+                    Some(user_password) => {
+                        access_control::salted_password_hash(&user_password.md5_hash, &salt)
+                    }
+                    None => hash_md5_password(user_name, &self.password.get(), salt.as_ref()),
+                };
+                Ok(Password::new(Some(salt), hash_password.as_bytes().to_vec()))
+            }
+            AuthMethod::ScramSha256 => {
+                let secret = match user_password {
+                    Some(user_password) => user_password.scram.ok_or_else(|| {
+                        PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "FATAL".to_owned(),
+                            "28P01".to_owned(),
+                            format!(
+                                "user {} has no SCRAM-SHA-256 password, recreate the user \
+                                 or log in with md5",
+                                user_name
+                            ),
+                        )))
+                    })?,
+                    None => access_control::scram_secret(&self.password.get()),
+                };
+                Ok(Password::new(Some(secret.salt), secret.salted_password))
+            }
+        }
     }
 }
 
 pub struct NexusBackend {
     catalog: Arc<Mutex<Catalog>>,
-    peer_connections: Arc<PeerConnectionTracker>,
+    connectors: Arc<ConnectorRegistry>,
     portal_store: Arc<MemPortalStore<NexusParsedStatement>>,
     query_parser: Arc<NexusQueryParser>,
+    // the analysis of the prepared statements that query a peer, by their
+    // name and query, so that their executions skip the analyzer.
+    prepared_statements: DashMap<(String, String), NexusStatement>,
     peer_cursors: Arc<Mutex<PeerCursors>>,
     executors: Arc<DashMap<String, Arc<Box<dyn QueryExecutor>>>>,
+    // the catalog version of the peer each of the executors connected with, and
+    // the versions of the peers as of the request running, see
+    // `drop_stale_executors`.
+    executor_versions: DashMap<String, i32>,
+    peer_versions: StdMutex<HashMap<String, i32>>,
     flow_handler: Option<Arc<Mutex<FlowGrpcClient>>>,
     peerdb_fdw_mode: bool,
+    scheduler: Arc<QueryScheduler>,
+    resource_group: OnceCell<ResourceGroup>,
+    quarantined_peers: Arc<DashSet<String>>,
+    execute_on_peer: bool,
+    federated_query_max_rows: usize,
+    session_tables: Mutex<SessionTables>,
+    settings: Mutex<SessionSettings>,
+    // set once the settings of the startup message are applied.
+    startup_settings: OnceCell<()>,
+    // the peer the session has a transaction open on, its statements can't
+    // run on other peers until the transaction ends.
+    transaction_peer: Mutex<Option<Box<Peer>>>,
+    // notified when the client sends a cancel request for the session.
+    cancel_requested: Notify,
+    session_id: uuid::Uuid,
+    // set when access control is enabled, the statements of other users are
+    // checked against the privileges granted to them.
+    admin_user: Option<String>,
+    // rejects the statements that write, see `access_control::is_write`.
+    read_only: bool,
+    audit_log: audit::AuditLog,
+    // the audit entry of the statement running, which the rows it streams
+    // count into.
+    audit_entry: StdMutex<Option<Arc<audit::AuditEntry>>>,
+    // the scheduler slots of the statement running, which the rows it streams
+    // hold until they are sent.
+    query_permit: StdMutex<Option<Arc<QueryPermit>>>,
+    // the deadline of the statement running and its statement timeout, which
+    // the rows it streams are bound by.
+    request_deadline: StdMutex<Option<(tokio::time::Instant, Duration)>>,
+    connections: Arc<connections::Connections>,
+    shutdown: Arc<shutdown::Shutdown>,
+    // set in transaction pooling mode, the connections of the session to the
+    // peers it pools are handed back to it between transactions.
+    peer_pool: Option<Arc<peer_pool::PeerConnectionPool>>,
+    // the peers the session's connections to are handed back to the pool.
+    pooled_executors: DashSet<String>,
+    running_queries: Arc<shutdown::RunningQueries>,
+    // set when a portal of the extended query the client is pipelining failed,
+    // the portals after it are skipped until the client syncs.
+    pipeline_aborted: AtomicBool,
+    // the channels the session LISTENs to, and the peer it listens on.
+    listening: Mutex<Option<notifications::Listening>>,
+    // notified when the session starts or stops listening to channels.
+    listening_changed: Notify,
+    // the notices nexus raised while the statement ran, sent along with the
+    // ones of the peers.
+    notices: StdMutex<Vec<ErrorInfo>>,
+    // the requests of the statements of a simple query to speak the COPY
+    // subprotocol with the client, for the connection to relay while the
+    // query runs.
+    copy_requests: StdMutex<Option<mpsc::Sender<connection::CopyRequest>>>,
+    // set while the only statement of a simple query runs, before any of the
+    // responses of the query are sent.
+    copy_ready: AtomicBool,
 }
 
+// rows of a session table are inserted into the catalog in batches of this size.
+const SESSION_TABLE_BATCH_SIZE: usize = 1000;
+
+// a table of a federated query is staged with the rows that join with a table
+// staged before it, as long as that has at most this many distinct join keys.
+const FEDERATED_JOIN_MAX_KEYS: usize = 1000;
+
+// the CopyData messages of a client read ahead of the peer copying them.
+const COPY_IN_BUFFERED_MESSAGES: usize = 16;
+
+// the WAL headroom check of CREATE MIRROR samples the rate the source writes
+// WAL at over this interval, and assumes each table is snapshotted at this
+// rate.
+const WAL_RATE_SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+const SNAPSHOT_BYTES_PER_SECOND: f64 = 32.0 * 1024.0 * 1024.0;
+// the defaults the flow partitions the initial snapshot of a cdc mirror and
+// the runs of a query replication mirror with.
+const SNAPSHOT_NUM_ROWS_PER_PARTITION: u32 = 500000;
+const SNAPSHOT_MAX_PARALLEL_WORKERS: u32 = 8;
+const QREP_MAX_PARALLEL_WORKERS: u64 = 16;
+// how long past the drain timeout the server waits for connections to close
+// on shutdown.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(1);
+// how long the server waits to accept clients again after failing to, such
+// as when it ran out of file descriptors, rather than retrying right away.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
 impl NexusBackend {
-    pub fn new(
-        catalog: Arc<Mutex<Catalog>>,
-        peer_connections: Arc<PeerConnectionTracker>,
-        flow_handler: Option<Arc<Mutex<FlowGrpcClient>>>,
-        peerdb_fdw_mode: bool,
-    ) -> Self {
-        let query_parser = NexusQueryParser::new(catalog.clone());
+    pub fn new(catalog: Arc<Mutex<Catalog>>, options: NexusServerOptions) -> Self {
+        let NexusServerOptions {
+            connectors,
+            flow_handler,
+            peerdb_fdw_mode,
+            default_peer,
+            scheduler,
+            request_timeout,
+            idle_session_timeout,
+            quarantined_peers,
+            execute_on_peer,
+            federated_query_max_rows,
+            admin_user,
+            read_only,
+            audit_log,
+            connections,
+            shutdown,
+            peer_pool,
+        } = options;
+        let query_parser = NexusQueryParser::new(catalog.clone(), default_peer);
         Self {
             catalog,
-            peer_connections,
+            connectors,
             portal_store: Arc::new(MemPortalStore::new()),
             query_parser: Arc::new(query_parser),
+            prepared_statements: DashMap::new(),
             peer_cursors: Arc::new(Mutex::new(PeerCursors::new())),
             executors: Arc::new(DashMap::new()),
+            executor_versions: DashMap::new(),
+            peer_versions: StdMutex::new(HashMap::new()),
             flow_handler,
             peerdb_fdw_mode,
+            scheduler,
+            resource_group: OnceCell::new(),
+            quarantined_peers,
+            execute_on_peer,
+            federated_query_max_rows,
+            session_tables: Mutex::new(SessionTables::new()),
+            settings: Mutex::new(SessionSettings::new(request_timeout, idle_session_timeout)),
+            startup_settings: OnceCell::new(),
+            transaction_peer: Mutex::new(None),
+            cancel_requested: Notify::new(),
+            session_id: uuid::Uuid::new_v4(),
+            admin_user,
+            read_only,
+            audit_log,
+            audit_entry: StdMutex::new(None),
+            query_permit: StdMutex::new(None),
+            request_deadline: StdMutex::new(None),
+            connections,
+            shutdown,
+            peer_pool,
+            pooled_executors: DashSet::new(),
+            running_queries: Arc::new(shutdown::RunningQueries::default()),
+            pipeline_aborted: AtomicBool::new(false),
+            listening: Mutex::new(None),
+            listening_changed: Notify::new(),
+            notices: StdMutex::new(vec![]),
+            copy_requests: StdMutex::new(None),
+            copy_ready: AtomicBool::new(false),
+        }
+    }
+
+    // runs a request to completion, or cancels it once the statement timeout
+    // of the session elapses or the client sends a cancel request. everything
+    // the request awaits on, the catalog, flow server and peers, is dropped
+    // along with it, so requests that change the catalog or mirrors aren't
+    // cancellable, they could be left half done. the rows the request streams
+    // are bound by the same deadline, see `hold_rows`.
+    async fn with_request_timeout<T>(
+        &self,
+        cancellable: bool,
+        request: impl std::future::Future<Output = PgWireResult<T>>,
+    ) -> PgWireResult<T> {
+        if !cancellable {
+            return request.await;
+        }
+        let statement_timeout = self.settings.lock().await.statement_timeout();
+        let timed_request = async move {
+            let timeout = match statement_timeout {
+                Some(timeout) => timeout,
+                None => return request.await,
+            };
+
+            let deadline = tokio::time::Instant::now() + timeout;
+            *self.request_deadline.lock().unwrap() = Some((deadline, timeout));
+            let request = flow_rs::grpc::with_request_deadline(deadline, request);
+            match tokio::time::timeout_at(deadline, request).await {
+                Ok(result) => result,
+                Err(_) => {
+                    self.cancel_peer_queries().await;
+                    Err(session_settings::statement_timeout_error(timeout))
+                }
+            }
+        };
+
+        tokio::select! {
+            result = timed_request => result,
+            _ = self.cancel_requested.notified() => Err(PgWireError::UserError(Box::new(
+                ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "57014".to_owned(),
+                    "canceling statement due to user request".to_owned(),
+                ),
+            ))),
+        }
+    }
+
+    // waits for the session to run no query for its idle_session_timeout.
+    async fn idle_session_expired(&self) {
+        self.running_queries
+            .idle_for(|| async { self.settings.lock().await.idle_session_timeout() })
+            .await
+    }
+
+    // asks the peers to cancel what they run for the session, the queries a
+    // request is dropped with would keep running on them otherwise.
+    async fn cancel_peer_queries(&self) {
+        let executors: Vec<Arc<Box<dyn QueryExecutor>>> = self
+            .executors
+            .iter()
+            .map(|executor| executor.value().clone())
+            .collect();
+        for executor in executors {
+            if let Err(err) = executor.cancel().await {
+                tracing::warn!("unable to cancel the query on a peer: {:?}", err);
+            }
+        }
+    }
+
+    // the statement of a portal along with its parameters. a query on a peer
+    // is analyzed once per prepared statement and its parameters are bound on
+    // each execution, as the text postgres peers prepare it with, and as
+    // literals for the other peers. other statements are analyzed again
+    // once their parameters are bound, like a simple query would be.
+    fn bind_portal(
+        &self,
+        portal: &Portal<NexusParsedStatement>,
+    ) -> PgWireResult<(NexusStatement, Option<Vec<Option<String>>>)> {
+        let stmt = portal.statement().statement();
+        let key = (portal.statement().id().to_owned(), stmt.query.clone());
+        let cached = self
+            .prepared_statements
+            .get(&key)
+            .map(|analyzed| analyzed.value().clone());
+        let prepared = match cached {
+            Some(analyzed) => Some(analyzed),
+            None => {
+                let unbound = self.query_parser.split_sql(&stmt.query)?.pop();
+                match unbound.map(|unbound| self.query_parser.analyze(&unbound)) {
+                    Some(Ok(
+                        analyzed @ NexusStatement::PeerQuery {
+                            stmt:
+                                Statement::Query(_)
+                                | Statement::Insert { .. }
+                                | Statement::Update { .. }
+                                | Statement::Delete { .. },
+                            assoc: QueryAssocation::Peer(_),
+                        },
+                    )) => {
+                        self.prepared_statements.insert(key, analyzed.clone());
+                        Some(analyzed)
+                    }
+                    _ => None,
+                }
+            }
+        };
+
+        if let Some(NexusStatement::PeerQuery {
+            mut stmt,
+            assoc: QueryAssocation::Peer(peer),
+        }) = prepared
+        {
+            let text_parameters = if peer.r#type == DbType::Postgres as i32 {
+                parameters::text_parameters(portal)
+            } else {
+                None
+            };
+            if text_parameters.is_none() {
+                parameters::bind(&mut stmt, portal)?;
+            }
+            let nexus_stmt = NexusStatement::PeerQuery {
+                stmt,
+                assoc: QueryAssocation::Peer(peer),
+            };
+            return Ok((nexus_stmt, text_parameters));
+        }
+
+        let nexus_stmt = match self.query_parser.split_sql(&stmt.query)?.pop() {
+            Some(mut bound) => {
+                parameters::bind(&mut bound, portal)?;
+                self.query_parser.analyze(&bound)?
+            }
+            None => NexusStatement::Empty,
+        };
+        Ok((nexus_stmt, None))
+    }
+
+    // raises a notice, sent to the client ahead of the results of the
+    // statement.
+    fn notice(&self, message: String) {
+        self.notices.lock().unwrap().push(ErrorInfo::new(
+            "NOTICE".to_owned(),
+            "00000".to_owned(),
+            message,
+        ));
+    }
+
+    // sends the client the notices and warnings the peers of the session, and
+    // nexus itself, raised since they were last sent.
+    async fn send_peer_notices<C>(&self, client: &mut C) -> PgWireResult<()>
+    where
+        C: Sink<PgWireBackendMessage> + Unpin + Send,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        let notices = std::mem::take(&mut *self.notices.lock().unwrap());
+        for notice in notices {
+            client
+                .feed(PgWireBackendMessage::NoticeResponse(notice.into()))
+                .await?;
+        }
+        let executors: Vec<Arc<Box<dyn QueryExecutor>>> = self
+            .executors
+            .iter()
+            .map(|executor| executor.value().clone())
+            .collect();
+        for executor in executors {
+            for notice in executor.take_notices() {
+                client
+                    .feed(PgWireBackendMessage::NoticeResponse(notice.into()))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    // the executor of the peer the session listens on, if it listens to any
+    // channels.
+    async fn listening_executor(&self) -> Option<Arc<Box<dyn QueryExecutor>>> {
+        let listening = self.listening.lock().await;
+        let peer = &listening.as_ref()?.peer;
+        self.executors
+            .get(&peer.name)
+            .map(|executor| executor.value().clone())
+    }
+
+    // the peer stops sending the notifications of the channels the session
+    // listened to once its client disconnects.
+    async fn stop_listening(&self) {
+        let listening = self.listening.lock().await.take();
+        if let Some(listening) = listening {
+            if let Some(executor) = self.executors.get(&listening.peer.name) {
+                if let Err(err) = executor.execute_raw("UNLISTEN *").await {
+                    tracing::error!(
+                        "unable to unlisten on peer {}: {:?}",
+                        listening.peer.name,
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    // in transaction pooling mode, the connections to pooled peers are handed
+    // back once the session is outside of a transaction. the connection the
+    // session listens on, or has cursors open on, is held until it is done
+    // with them.
+    async fn release_peer_executors(&self) {
+        let peer_pool = match &self.peer_pool {
+            Some(peer_pool) => peer_pool,
+            None => return,
+        };
+        if self.transaction_peer.lock().await.is_some() {
+            return;
+        }
+        let listening_peer = self
+            .listening
+            .lock()
+            .await
+            .as_ref()
+            .map(|listening| listening.peer.name.clone());
+        let peer_names: Vec<String> = self
+            .pooled_executors
+            .iter()
+            .map(|peer_name| peer_name.key().clone())
+            .collect();
+        for peer_name in peer_names {
+            if listening_peer.as_ref() == Some(&peer_name)
+                || self.peer_cursors.lock().await.has_cursors_on(&peer_name)
+            {
+                continue;
+            }
+            self.pooled_executors.remove(&peer_name);
+            let version = self.executor_versions.remove(&peer_name);
+            if let (Some((_, executor)), Some((_, version))) =
+                (self.executors.remove(&peer_name), version)
+            {
+                peer_pool.checkin(&peer_name, version, executor).await;
+            }
+        }
+    }
+
+    // a session only sees the peers other sessions, or the flow api, altered
+    // or dropped through their versions in the catalog. before each request,
+    // the session closes its connections to the peers whose version changed
+    // since it connected to them, unless it holds them for a transaction,
+    // cursors or the channels it listens to, and analyzes the statements it
+    // prepared against the old peers again.
+    async fn drop_stale_executors(&self) -> PgWireResult<()> {
+        let versions = self
+            .catalog
+            .lock()
+            .await
+            .get_peer_versions()
+            .await
+            .map_err(|err| {
+                PgWireError::ApiError(Box::new(PgError::Internal {
+                    err_msg: format!("unable to query catalog for peer versions: {:?}", err),
+                }))
+            })?;
+        let stale: Vec<String> = self
+            .executor_versions
+            .iter()
+            .filter(|executor| versions.get(executor.key()) != Some(executor.value()))
+            .map(|executor| executor.key().clone())
+            .collect();
+        if !stale.is_empty() {
+            let transaction_peer = self
+                .transaction_peer
+                .lock()
+                .await
+                .as_ref()
+                .map(|peer| peer.name.clone());
+            let listening_peer = self
+                .listening
+                .lock()
+                .await
+                .as_ref()
+                .map(|listening| listening.peer.name.clone());
+            for peer_name in stale {
+                if transaction_peer.as_ref() == Some(&peer_name)
+                    || listening_peer.as_ref() == Some(&peer_name)
+                    || self.peer_cursors.lock().await.has_cursors_on(&peer_name)
+                {
+                    continue;
+                }
+                tracing::info!("peer {} changed, reconnecting to it", peer_name);
+                self.executor_versions.remove(&peer_name);
+                self.pooled_executors.remove(&peer_name);
+                self.executors.remove(&peer_name);
+            }
+        }
+        let mut peer_versions = self.peer_versions.lock().unwrap();
+        if *peer_versions != versions {
+            self.prepared_statements.clear();
+            *peer_versions = versions;
+        }
+        Ok(())
+    }
+
+    // the version of the peer as of the request, or as of now for the peers
+    // created by it.
+    async fn peer_version(&self, peer_name: &str) -> anyhow::Result<i32> {
+        let version = self.peer_versions.lock().unwrap().get(peer_name).copied();
+        match version {
+            Some(version) => Ok(version),
+            None => self
+                .catalog
+                .lock()
+                .await
+                .get_peer_versions()
+                .await?
+                .get(peer_name)
+                .copied()
+                .ok_or_else(|| anyhow::anyhow!("no such peer: {}", peer_name)),
+        }
+    }
+
+    // the settings of the startup message, like a statement_timeout in the
+    // options of PGOPTIONS, are applied before the first statement of the
+    // session runs.
+    async fn apply_startup_settings<C: ClientInfo>(&self, client: &C) -> PgWireResult<()> {
+        self.startup_settings
+            .get_or_try_init(|| async {
+                let mut settings = self.settings.lock().await;
+                for (name, value) in session_settings::startup_settings(client.metadata()) {
+                    settings.set(&name, Some(value)).map_err(|err| {
+                        PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "22023".to_owned(),
+                            err.to_string(),
+                        )))
+                    })?;
+                }
+                Ok::<(), PgWireError>(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    // queries that run on a peer are admitted through the resource group
+    // of the session's user and queued for the peer, the slots are held
+    // until the permit is dropped.
+    async fn acquire_query_permit<C: ClientInfo>(
+        &self,
+        client: &C,
+        nexus_stmt: &NexusStatement,
+    ) -> PgWireResult<Option<QueryPermit>> {
+        let peer_name = match nexus_stmt {
+            NexusStatement::PeerQuery {
+                assoc: QueryAssocation::Peer(peer),
+                ..
+            }
+            | NexusStatement::CreateTempTable {
+                assoc: QueryAssocation::Peer(peer),
+                ..
+            }
+            | NexusStatement::CopyOut {
+                assoc: QueryAssocation::Peer(peer),
+                ..
+            } => Some(peer.name.clone()),
+            NexusStatement::PeerDDL { ddl, .. } => match ddl.as_ref() {
+                PeerDDL::ExecuteOnPeer { peer, .. } => Some(peer.name.clone()),
+                _ => return Ok(None),
+            },
+            NexusStatement::PeerCursor { cursor, .. } => match cursor {
+                analyzer::CursorEvent::Fetch(c, _) => {
+                    let peer_cursors = self.peer_cursors.lock().await;
+                    peer_cursors.get_peer(c).map(|peer| peer.name.clone())
+                }
+                _ => None,
+            },
+            _ => return Ok(None),
+        };
+
+        let resource_group = self
+            .resource_group
+            .get_or_try_init(|| async {
+                let user_name = session_user(client);
+                let catalog = self.catalog.lock().await;
+                catalog
+                    .get_resource_group_for_user(&user_name)
+                    .await
+                    .map(Option::unwrap_or_default)
+            })
+            .await
+            .map_err(|err| {
+                PgWireError::ApiError(Box::new(PgError::Internal {
+                    err_msg: format!("unable to get resource group: {:?}", err),
+                }))
+            })?;
+
+        let permit = self
+            .scheduler
+            .acquire(resource_group, self.session_id, peer_name.as_deref())
+            .await;
+        Ok(Some(permit))
+    }
+
+    // with access control, the statements of users other than the admin are
+    // checked against the privileges granted to them before they run.
+    async fn check_access<C: ClientInfo>(
+        &self,
+        client: &C,
+        nexus_stmt: &NexusStatement,
+    ) -> PgWireResult<()> {
+        let write = access_control::is_write(nexus_stmt);
+        if write && self.read_only {
+            return Err(read_only_error(
+                "cannot change peers, mirrors or their data on a read-only server".to_owned(),
+            ));
+        }
+        let admin_user = match &self.admin_user {
+            Some(admin_user) => admin_user,
+            None => return Ok(()),
+        };
+        let user_name = session_user(client);
+        if &user_name == admin_user {
+            return Ok(());
+        }
+
+        let requirement = {
+            let session_tables = self.session_tables.lock().await;
+            access_control::requirement(nexus_stmt, &session_tables)
+        };
+        // the statements that write are checked against READ ONLY grants.
+        let required = match requirement {
+            Requirement::Nothing if !write => return Ok(()),
+            Requirement::Nothing => vec![],
+            Requirement::Admin => {
+                return Err(insufficient_privilege(format!(
+                    "only {} can run this statement",
+                    admin_user
+                )))
+            }
+            Requirement::Privileges(required) => required,
+        };
+
+        let catalog = self.catalog.lock().await;
+        let granted = catalog
+            .get_privileges_for_user(&user_name)
+            .await
+            .map_err(|err| {
+                PgWireError::ApiError(Box::new(PgError::Internal {
+                    err_msg: format!("unable to get privileges of user: {:?}", err),
+                }))
+            })?;
+        if write && granted.contains(&Privilege::ReadOnly) {
+            return Err(read_only_error(format!(
+                "user {} is read only and cannot change peers, mirrors or their data",
+                user_name
+            )));
+        }
+        match required
+            .iter()
+            .find(|privilege| !granted.contains(*privilege))
+        {
+            Some(missing) => Err(insufficient_privilege(format!(
+                "user {} was not granted {}",
+                user_name, missing
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    // the admin, users and their privileges are only managed with access
+    // control enabled.
+    fn access_control_admin(&self) -> PgWireResult<&str> {
+        self.admin_user.as_deref().ok_or_else(|| {
+            PgWireError::UserError(Box::new(ErrorInfo::new(
+                "ERROR".to_owned(),
+                "feature_not_supported".to_owned(),
+                "access control is not enabled, see --access-control".to_owned(),
+            )))
+        })
+    }
+
+    // the privilege on the peer or mirror the statement creates, for users
+    // the privilege is granted to once it is created. nothing is granted for
+    // CREATE ... IF NOT EXISTS of an object that already exists.
+    async fn privilege_for_creator<C: ClientInfo>(
+        &self,
+        client: &C,
+        nexus_stmt: &NexusStatement,
+    ) -> PgWireResult<Option<(String, Privilege)>> {
+        let admin_user = match &self.admin_user {
+            Some(admin_user) => admin_user,
+            None => return Ok(None),
+        };
+        let user_name = session_user(client);
+        let privilege = match access_control::created_object(nexus_stmt) {
+            Some(privilege) if &user_name != admin_user => privilege,
+            _ => return Ok(None),
+        };
+
+        let catalog = self.catalog.lock().await;
+        let exists = match &privilege {
+            Privilege::UsageOnPeer(peer_name) => catalog
+                .get_peers()
+                .await
+                .map(|peers| peers.contains_key(peer_name)),
+            Privilege::OperateOnMirror(flow_job_name) => catalog
+                .get_workflow_details_for_flow_job(flow_job_name)
+                .await
+                .map(|details| details.is_some()),
+            _ => Ok(false),
+        }
+        .map_err(|err| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to query catalog: {:?}", err),
+            }))
+        })?;
+        Ok((!exists).then_some((user_name, privilege)))
+    }
+
+    // the requests of the COPY subprotocol go to the connection of the
+    // client, which relays them while the simple query runs. the client can
+    // only be switched to it before any response of its query is sent, so
+    // the COPY has to be the only statement of the query.
+    fn copy_requests(&self) -> Option<mpsc::Sender<connection::CopyRequest>> {
+        if !self.copy_ready.load(Ordering::SeqCst) {
+            return None;
+        }
+        self.copy_requests.lock().unwrap().clone()
+    }
+
+    // streams the data of a `COPY ... FROM STDIN` from the client to the peer.
+    async fn copy_in_from_client(
+        &self,
+        executor: &Arc<Box<dyn QueryExecutor>>,
+        stmt: &Statement,
+    ) -> PgWireResult<usize> {
+        let mut copy_requests = match self.copy_requests() {
+            Some(copy_requests) => copy_requests,
+            None => {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "0A000".to_owned(),
+                    "COPY FROM STDIN streams its data only as the only statement of a simple \
+                     query"
+                        .to_owned(),
+                ))))
+            }
+        };
+        let (sender, receiver) = mpsc::channel(COPY_IN_BUFFERED_MESSAGES);
+        let request = connection::CopyRequest::In(copy_in::response(stmt), sender);
+        connection::request_copy(&mut copy_requests, request).await?;
+        executor.copy_in(stmt, Box::pin(receiver)).await
+    }
+
+    // the rows a peer streams keep the query running, and its scheduler slots,
+    // and count into the audit entry of the statement, until they are sent or
+    // the statement timeout elapses.
+    fn hold_rows(&self, rows: SendableStream) -> SendableStream {
+        let entry = self.audit_entry.lock().unwrap().clone();
+        let rows = match *self.request_deadline.lock().unwrap() {
+            Some((deadline, timeout)) => session_settings::with_deadline(rows, deadline, timeout),
+            None => rows,
+        };
+        let rows = match self.query_permit.lock().unwrap().clone() {
+            Some(permit) => permit.hold(rows),
+            None => rows,
+        };
+        audit::audited(self.running_queries.start().hold(rows), entry)
+    }
+
+    fn audit_rows(&self, rows: usize) {
+        if let Some(entry) = self.audit_entry.lock().unwrap().as_ref() {
+            entry.add_rows(rows);
         }
     }
 
@@ -107,20 +999,47 @@ impl NexusBackend {
         executor: Arc<Box<dyn QueryExecutor>>,
         stmt: &sqlparser::ast::Statement,
         peer_holder: Option<Box<Peer>>,
+        parameters: Option<&[Option<String>]>,
+        result_format: &Format,
     ) -> PgWireResult<Vec<Response<'a>>> {
-        let res = executor.execute(stmt).await?;
+        if copy_in::streams_data(stmt) {
+            let rows = self.copy_in_from_client(&executor, stmt).await?;
+            self.audit_rows(rows);
+            return Ok(vec![Response::Execution(Tag::new_for_execution(
+                "COPY",
+                Some(rows),
+            ))]);
+        }
+        let res = match parameters {
+            Some(parameters) => executor.execute_prepared(stmt, parameters).await?,
+            None => executor.execute(stmt).await?,
+        };
         match res {
-            QueryOutput::AffectedRows(rows) => Ok(vec![Response::Execution(
-                Tag::new_for_execution("OK", Some(rows)),
-            )]),
+            QueryOutput::AffectedRows(rows) => {
+                self.audit_rows(rows);
+                let tag = match stmt {
+                    sqlparser::ast::Statement::Copy { .. } => "COPY",
+                    _ => "OK",
+                };
+                Ok(vec![Response::Execution(Tag::new_for_execution(
+                    tag,
+                    Some(rows),
+                ))])
+            }
             QueryOutput::Stream(rows) => {
-                let schema = rows.schema();
+                let rows = self.hold_rows(rows);
+                let schema = with_result_format(&rows.schema(), result_format);
                 // todo: why is this a vector of response rather than a single response?
                 // can this be because of multiple statements?
                 let res = sendable_stream_to_query_response(schema, rows)?;
                 Ok(vec![res])
             }
             QueryOutput::Records(records) => {
+                self.audit_rows(records.records.len());
+                let records = Records {
+                    schema: with_result_format(&records.schema, result_format),
+                    records: records.records,
+                };
                 let res = records_to_query_response(records)?;
                 Ok(vec![res])
             }
@@ -149,15 +1068,6 @@ impl NexusBackend {
         }
     }
 
-    fn is_peer_validity_supported(peer_type: i32) -> bool {
-        let unsupported_peer_types = [
-            4, // EVENTHUB
-            5, // S3
-            7,
-        ];
-        !unsupported_peer_types.contains(&peer_type)
-    }
-
     async fn check_for_mirror(
         catalog: &MutexGuard<'_, Catalog>,
         flow_name: &str,
@@ -173,55 +1083,733 @@ impl NexusBackend {
         Ok(workflow_details)
     }
 
-    async fn get_peer_of_mirror(
-        catalog: &MutexGuard<'_, Catalog>,
-        peer_name: String,
-    ) -> PgWireResult<Peer> {
-        let peer = catalog.get_peer(&peer_name).await.map_err(|err| {
-            PgWireError::ApiError(Box::new(PgError::Internal {
-                err_msg: format!("unable to get peer {:?}: {:?}", peer_name, err),
-            }))
-        })?;
-        Ok(peer)
-    }
-
-    fn handle_mirror_existence(
-        if_not_exists: bool,
-        flow_name: String,
-    ) -> PgWireResult<Vec<Response<'static>>> {
-        if if_not_exists {
-            let existing_mirror_success = "MIRROR ALREADY EXISTS";
-            Ok(vec![Response::Execution(Tag::new_for_execution(
-                existing_mirror_success,
-                None,
-            ))])
-        } else {
-            Err(PgWireError::UserError(Box::new(ErrorInfo::new(
-                "ERROR".to_owned(),
-                "error".to_owned(),
-                format!("mirror already exists: {:?}", flow_name),
-            ))))
+    // check that every table the old peer serves for a mirror has the same columns
+    // on the new peer. only postgres peers can be described this way, other peer
+    // types are not checked.
+    async fn check_replacement_schema(
+        &self,
+        old_peer: &Peer,
+        new_peer: &Peer,
+        tables: &[&str],
+    ) -> PgWireResult<()> {
+        if !matches!(old_peer.config, Some(Config::PostgresConfig(_))) {
+            return Ok(());
         }
-    }
 
-    async fn validate_peer<'a>(&self, peer_type: i32, peer: &Peer) -> anyhow::Result<()> {
-        if peer_type != 6 {
-            let peer_executor = self.get_peer_executor(peer).await.map_err(|err| {
-                PgWireError::ApiError(Box::new(PgError::Internal {
-                    err_msg: format!("unable to get peer executor: {:?}", err),
-                }))
-            })?;
-            peer_executor.is_connection_valid().await.map_err(|e| {
-                self.executors.remove(&peer.name); // Otherwise it will keep returning the earlier configured executor
+        let executor_err =
+            |err: anyhow::Error| peer_error(format!("unable to get peer executor: {:?}", err));
+        let old_executor = self
+            .get_peer_executor(old_peer)
+            .await
+            .map_err(executor_err)?;
+        let new_executor = self
+            .get_peer_executor(new_peer)
+            .await
+            .map_err(executor_err)?;
+
+        for table in tables {
+            let query = format!("SELECT * FROM {}", table);
+            let stmt = sqlparser::parser::Parser::parse_sql(&PostgreSqlDialect {}, &query)
+                .ok()
+                .and_then(|mut stmts| stmts.pop())
+                .ok_or_else(|| {
+                    PgWireError::ApiError(Box::new(PgError::Internal {
+                        err_msg: format!("invalid table identifier: {}", table),
+                    }))
+                })?;
+
+            let old_schema = old_executor.describe(&stmt).await?;
+            let new_schema = new_executor.describe(&stmt).await.map_err(|err| {
+                PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    format!(
+                        "table {} is not readable on peer {}: {}",
+                        table, new_peer.name, err
+                    ),
+                )))
+            })?;
+
+            let columns = |schema: Option<SchemaRef>| {
+                schema
+                    .map(|schema| {
+                        schema
+                            .fields
+                            .iter()
+                            .map(|field| (field.name().to_owned(), field.datatype().clone()))
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default()
+            };
+            if columns(old_schema) != columns(new_schema) {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    format!(
+                        "columns of table {} differ between peers {} and {}",
+                        table, old_peer.name, new_peer.name
+                    ),
+                ))));
+            }
+        }
+        Ok(())
+    }
+
+    // drop the given tables on the peer, tables that no longer exist are skipped.
+    async fn drop_tables(&self, peer: &Peer, tables: &[String]) -> PgWireResult<()> {
+        self.execute_for_tables(peer, "DROP TABLE IF EXISTS", tables)
+            .await
+    }
+
+    async fn truncate_tables(&self, peer: &Peer, tables: &[String]) -> PgWireResult<()> {
+        self.execute_for_tables(peer, "TRUNCATE TABLE", tables)
+            .await
+    }
+
+    // run the statement on the peer for each of the given tables.
+    async fn execute_for_tables(
+        &self,
+        peer: &Peer,
+        statement: &str,
+        tables: &[String],
+    ) -> PgWireResult<()> {
+        let executor = self
+            .get_peer_executor(peer)
+            .await
+            .map_err(|err| peer_error(format!("unable to get peer executor: {:?}", err)))?;
+
+        for table in tables {
+            let query = format!("{} {}.{}", statement, peer.name, table);
+            let stmt = sqlparser::parser::Parser::parse_sql(&PostgreSqlDialect {}, &query)
+                .ok()
+                .and_then(|mut stmts| stmts.pop())
+                .ok_or_else(|| {
+                    PgWireError::ApiError(Box::new(PgError::Internal {
+                        err_msg: format!("invalid table identifier: {}", table),
+                    }))
+                })?;
+            tracing::info!("running {} {} on peer {}", statement, table, peer.name);
+            executor.execute(&stmt).await?;
+        }
+        Ok(())
+    }
+
+    // pause or resume the mirror through the flow service, and record its state in the catalog.
+    async fn set_mirror_paused<'a>(
+        &self,
+        flow_job_name: &str,
+        paused: bool,
+    ) -> PgWireResult<Vec<Response<'a>>> {
+        if self.flow_handler.is_none() {
+            return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: "flow service is not configured".to_owned(),
+            })));
+        }
+
+        let catalog = self.catalog.lock().await;
+        let workflow_details = Self::check_for_mirror(&catalog, flow_job_name)
+            .await?
+            .ok_or_else(|| {
+                PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    format!("no such mirror: {:?}", flow_job_name),
+                )))
+            })?;
+
+        let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+        let result = if paused {
+            flow_handler
+                .pause_flow_job(flow_job_name, workflow_details)
+                .await
+        } else {
+            flow_handler
+                .resume_flow_job(flow_job_name, workflow_details)
+                .await
+        };
+        result.map_err(|err| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to change state of flow job: {:?}", err),
+            }))
+        })?;
+
+        catalog
+            .set_flow_job_paused(flow_job_name, paused)
+            .await
+            .map_err(|err| {
+                PgWireError::ApiError(Box::new(PgError::Internal {
+                    err_msg: format!("unable to save job metadata: {:?}", err),
+                }))
+            })?;
+
+        let command = if paused { "PAUSE" } else { "RESUME" };
+        let success = format!("{} MIRROR {}", command, flow_job_name);
+        Ok(vec![Response::Execution(Tag::new_for_execution(
+            &success, None,
+        ))])
+    }
+
+    // only CDC mirrors can have their tables changed, returns the workflow
+    // running the mirror.
+    async fn check_for_cdc_mirror(
+        catalog: &MutexGuard<'_, Catalog>,
+        flow_job_name: &str,
+    ) -> PgWireResult<WorkflowDetails> {
+        let mirrors = catalog.get_mirrors().await.map_err(|err| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to list mirrors: {:?}", err),
+            }))
+        })?;
+        let is_cdc = mirrors
+            .iter()
+            .find(|mirror| mirror.name == flow_job_name)
+            .map(|mirror| mirror.is_cdc);
+        let no_such_mirror = || {
+            PgWireError::UserError(Box::new(ErrorInfo::new(
+                "ERROR".to_owned(),
+                "error".to_owned(),
+                format!("no such mirror: {:?}", flow_job_name),
+            )))
+        };
+        match is_cdc {
+            Some(true) => {}
+            Some(false) => {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    format!("{:?} is not a CDC mirror", flow_job_name),
+                ))))
+            }
+            None => return Err(no_such_mirror()),
+        }
+        Self::check_for_mirror(catalog, flow_job_name)
+            .await?
+            .ok_or_else(no_such_mirror)
+    }
+
+    async fn set_mirror_table_paused<'a>(
+        &self,
+        flow_job_name: &str,
+        table_identifier: &str,
+        pause_policy: Option<TablePausePolicy>,
+    ) -> PgWireResult<Vec<Response<'a>>> {
+        if self.flow_handler.is_none() {
+            return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: "flow service is not configured".to_owned(),
+            })));
+        }
+
+        let catalog = self.catalog.lock().await;
+        let workflow_details = Self::check_for_cdc_mirror(&catalog, flow_job_name).await?;
+
+        // the flow server knows the tables of the mirror and whether they are paused.
+        let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+        let result = match pause_policy {
+            Some(policy) => {
+                flow_handler
+                    .pause_table(flow_job_name, workflow_details, table_identifier, policy)
+                    .await
+            }
+            None => {
+                flow_handler
+                    .resume_table(flow_job_name, workflow_details, table_identifier)
+                    .await
+            }
+        };
+        result.map_err(|err| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to change state of table: {:?}", err),
+            }))
+        })?;
+
+        let success = format!("ALTER MIRROR {}", flow_job_name);
+        Ok(vec![Response::Execution(Tag::new_for_execution(
+            &success, None,
+        ))])
+    }
+
+    // check that the columns the mirror orders records by exist on every source
+    // table, only postgres sources are checked.
+    async fn validate_ordering_key(
+        flow_job: &FlowJob,
+        ordering_key: &FlowOrderingKey,
+        src_peer: &Peer,
+    ) -> PgWireResult<()> {
+        let config = match &src_peer.config {
+            Some(Config::PostgresConfig(config)) => config,
+            _ => return Ok(()),
+        };
+        if *ordering_key == FlowOrderingKey::Table {
+            return Ok(());
+        }
+
+        let internal_err = |err: anyhow::Error| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to validate ordering key: {:?}", err),
+            }))
+        };
+        let executor = peer_postgres::PostgresQueryExecutor::new(None, config)
+            .await
+            .map_err(internal_err)?;
+
+        for mapping in &flow_job.table_mappings {
+            let table = &mapping.source_table_identifier;
+            let missing_key = match ordering_key {
+                FlowOrderingKey::PrimaryKey => executor
+                    .get_primary_key_columns(table)
+                    .await
+                    .map_err(internal_err)?
+                    .is_empty()
+                    .then(|| format!("table {} has no primary key", table)),
+                FlowOrderingKey::PartitionKey => {
+                    let partition_key = mapping.partition_key.clone().unwrap_or_default();
+                    let columns = executor.get_columns(table).await.map_err(internal_err)?;
+                    (!columns.contains(&partition_key))
+                        .then(|| format!("table {} has no column {}", table, partition_key))
+                }
+                FlowOrderingKey::Table => None,
+            };
+            if let Some(message) = missing_key {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    format!("invalid ordering key: {}", message),
+                ))));
+            }
+        }
+        Ok(())
+    }
+
+    // check that the excluded columns exist on their source tables and aren't
+    // part of the primary key, which every replicated change is keyed by. Only
+    // postgres sources are checked.
+    async fn validate_excluded_columns(flow_job: &FlowJob, src_peer: &Peer) -> PgWireResult<()> {
+        if flow_job
+            .table_mappings
+            .iter()
+            .all(|mapping| mapping.exclude.is_empty())
+        {
+            return Ok(());
+        }
+        let config = match &src_peer.config {
+            Some(Config::PostgresConfig(config)) => config,
+            _ => return Ok(()),
+        };
+
+        let internal_err = |err: anyhow::Error| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to validate excluded columns: {:?}", err),
+            }))
+        };
+        let executor = peer_postgres::PostgresQueryExecutor::new(None, config)
+            .await
+            .map_err(internal_err)?;
+
+        for mapping in &flow_job.table_mappings {
+            if mapping.exclude.is_empty() {
+                continue;
+            }
+            let table = &mapping.source_table_identifier;
+            let columns = executor.get_columns(table).await.map_err(internal_err)?;
+            let primary_key_columns = executor
+                .get_primary_key_columns(table)
+                .await
+                .map_err(internal_err)?;
+            let invalid_column = mapping.exclude.iter().find_map(|column| {
+                if !columns.contains(column) {
+                    Some(format!("table {} has no column {}", table, column))
+                } else if primary_key_columns.contains(column) {
+                    Some(format!(
+                        "column {} is part of the primary key of table {}",
+                        column, table
+                    ))
+                } else {
+                    None
+                }
+            });
+            if let Some(message) = invalid_column {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    format!("invalid excluded column: {}", message),
+                ))));
+            }
+        }
+        Ok(())
+    }
+
+    // estimate how much WAL the source retains while the initial snapshot runs,
+    // from the size of the source tables and the rate the source writes WAL at,
+    // and return a warning if it is more than the slot of the mirror may keep.
+    // Postgres doesn't expose free disk space over SQL, so the headroom is
+    // max_slot_wal_keep_size and sources without the limit aren't checked.
+    // The check is advisory, failures to run it don't fail the mirror.
+    async fn check_wal_headroom(flow_job: &FlowJob, src_peer: &Peer) -> Option<String> {
+        let config = match &src_peer.config {
+            Some(Config::PostgresConfig(config)) if flow_job.do_initial_copy => config,
+            _ => return None,
+        };
+
+        let estimate = async {
+            let executor = peer_postgres::PostgresQueryExecutor::new(None, config).await?;
+            let headroom = match executor.get_max_slot_wal_keep_size().await? {
+                Some(headroom) => headroom,
+                None => return Ok(None),
+            };
+            let tables: Vec<String> = flow_job
+                .table_mappings
+                .iter()
+                .map(|mapping| mapping.source_table_identifier.clone())
+                .collect();
+            let snapshot_size = executor.get_tables_size(&tables).await?;
+
+            let start_lsn = executor.get_current_wal_lsn().await?;
+            tokio::time::sleep(WAL_RATE_SAMPLE_INTERVAL).await;
+            let end_lsn = executor.get_current_wal_lsn().await?;
+            let wal_rate =
+                (end_lsn - start_lsn).max(0) as f64 / WAL_RATE_SAMPLE_INTERVAL.as_secs_f64();
+
+            let parallelism = flow_job
+                .snapshot_num_tables_in_parallel
+                .unwrap_or(1)
+                .clamp(1, tables.len().max(1) as u32);
+            let snapshot_secs =
+                snapshot_size as f64 / (SNAPSHOT_BYTES_PER_SECOND * parallelism as f64);
+            let retained_wal = (wal_rate * snapshot_secs) as i64;
+            anyhow::Ok((retained_wal > headroom).then(|| {
+                format!(
+                    "snapshot of {} bytes is estimated to take {}s, in which the source would \
+                     retain {} bytes of WAL, more than max_slot_wal_keep_size of {} bytes",
+                    snapshot_size, snapshot_secs as i64, retained_wal, headroom
+                )
+            }))
+        };
+
+        match estimate.await {
+            Ok(warning) => warning,
+            Err(err) => {
+                tracing::warn!(
+                    "unable to check WAL headroom for mirror {}: {:?}",
+                    flow_job.name,
+                    err
+                );
+                None
+            }
+        }
+    }
+
+    // add the tables of the schemas the mirror replicates as a whole to its
+    // table mappings, the tables that are excluded or already mapped are left
+    // out. Only postgres sources have schema mappings.
+    async fn expand_schema_mappings(flow_job: &FlowJob, src_peer: &Peer) -> PgWireResult<FlowJob> {
+        let mut flow_job = flow_job.clone();
+        let config = match &src_peer.config {
+            Some(Config::PostgresConfig(config)) if !flow_job.schema_mappings.is_empty() => config,
+            _ => return Ok(flow_job),
+        };
+
+        let internal_err = |err: anyhow::Error| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to get the tables of the source schemas: {:?}", err),
+            }))
+        };
+        let executor = peer_postgres::PostgresQueryExecutor::new(None, config)
+            .await
+            .map_err(internal_err)?;
+
+        for mapping in &flow_job.schema_mappings {
+            let tables = executor
+                .get_tables_in_schema(&mapping.source_schema)
+                .await
+                .map_err(internal_err)?;
+            if tables.is_empty() {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    format!("schema {} has no tables to mirror", mapping.source_schema),
+                ))));
+            }
+            for table in tables {
+                if mapping.exclude_tables.contains(&table) {
+                    continue;
+                }
+                let source_table_identifier = format!(
+                    "{}.{}",
+                    analyzer::quote_identifier(&mapping.source_schema),
+                    analyzer::quote_identifier(&table)
+                );
+                if flow_job
+                    .table_mappings
+                    .iter()
+                    .any(|existing| existing.source_table_identifier == source_table_identifier)
+                {
+                    continue;
+                }
+                let destination_table_identifier = if mapping.destination_schema.is_empty() {
+                    table
+                } else {
+                    format!(
+                        "{}.{}",
+                        analyzer::quote_identifier(&mapping.destination_schema),
+                        analyzer::quote_identifier(&table)
+                    )
+                };
+                flow_job.table_mappings.push(FlowJobTableMapping {
+                    source_table_identifier,
+                    destination_table_identifier,
+                    partition_key: None,
+                    exclude: vec![],
+                });
+            }
+        }
+        Ok(flow_job)
+    }
+
+    async fn get_peer_of_mirror(
+        catalog: &MutexGuard<'_, Catalog>,
+        peer_name: String,
+    ) -> PgWireResult<Peer> {
+        let peer = catalog.get_peer(&peer_name).await.map_err(|err| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to get peer {:?}: {:?}", peer_name, err),
+            }))
+        })?;
+        Ok(peer)
+    }
+
+    // preview a mirror without creating it, the tables it replicates with the
+    // ddl of their destination tables, how their rows are partitioned and the
+    // estimated rows of its initial load. Tables are only inspected on postgres
+    // sources.
+    async fn explain_mirror(&self, ddl: &PeerDDL) -> PgWireResult<Vec<Response<'static>>> {
+        let internal_err = |err: anyhow::Error| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to explain mirror: {:?}", err),
+            }))
+        };
+        let (source_peer, target_peer) = match ddl {
+            PeerDDL::CreateMirrorForCDC { flow_job, .. } => {
+                (&flow_job.source_peer, &flow_job.target_peer)
+            }
+            PeerDDL::CreateMirrorForSelect { qrep_flow_job, .. } => {
+                (&qrep_flow_job.source_peer, &qrep_flow_job.target_peer)
+            }
+            _ => {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    "only CREATE MIRROR can be explained".to_owned(),
+                ))))
+            }
+        };
+        let (src_peer, dst_peer) = {
+            let catalog = self.catalog.lock().await;
+            (
+                Self::get_peer_of_mirror(&catalog, source_peer.clone()).await?,
+                Self::get_peer_of_mirror(&catalog, target_peer.clone()).await?,
+            )
+        };
+        let executor = match &src_peer.config {
+            Some(Config::PostgresConfig(config)) => Some(
+                peer_postgres::PostgresQueryExecutor::new(None, config)
+                    .await
+                    .map_err(internal_err)?,
+            ),
+            _ => None,
+        };
+
+        let mut plans = Vec::new();
+        match ddl {
+            PeerDDL::CreateMirrorForCDC { flow_job, .. } => {
+                let flow_job = &Self::expand_schema_mappings(flow_job, &src_peer).await?;
+                let num_rows_per_partition = flow_job
+                    .snapshot_num_rows_per_partition
+                    .unwrap_or(SNAPSHOT_NUM_ROWS_PER_PARTITION);
+                let max_parallel_workers = flow_job
+                    .snapshot_max_parallel_workers
+                    .unwrap_or(SNAPSHOT_MAX_PARALLEL_WORKERS);
+                for mapping in &flow_job.table_mappings {
+                    let table = &mapping.source_table_identifier;
+                    let partitioning = if flow_job.do_initial_copy {
+                        format!(
+                            "{} ranges of {} rows, {} workers",
+                            mapping.partition_key.as_deref().unwrap_or("ctid"),
+                            num_rows_per_partition,
+                            max_parallel_workers
+                        )
+                    } else {
+                        "none, the mirror has no initial load".to_owned()
+                    };
+                    let mut plan = explain_mirror::TablePlan {
+                        source_table: table.clone(),
+                        destination_table: mapping.destination_table_identifier.clone(),
+                        columns: None,
+                        primary_key: vec![],
+                        partitioning,
+                        estimated_rows: None,
+                    };
+                    if let Some(executor) = &executor {
+                        let columns = executor
+                            .get_column_types(table)
+                            .await
+                            .map_err(internal_err)?;
+                        plan.columns = Some(
+                            columns
+                                .into_iter()
+                                .filter(|(name, _)| !mapping.exclude.contains(name))
+                                .collect(),
+                        );
+                        plan.primary_key = executor
+                            .get_primary_key_columns(table)
+                            .await
+                            .map_err(internal_err)?;
+                        if flow_job.do_initial_copy {
+                            plan.estimated_rows = executor
+                                .get_estimated_rows(table)
+                                .await
+                                .map_err(internal_err)?;
+                        }
+                    }
+                    plans.push(plan);
+                }
+            }
+            PeerDDL::CreateMirrorForSelect { qrep_flow_job, .. } => {
+                let option = |name: &str| qrep_flow_job.flow_options.get(name);
+                let watermark_table = option("watermark_table_name")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default()
+                    .to_owned();
+                let max_parallel_workers = option("parallelism")
+                    .and_then(serde_json::Value::as_u64)
+                    .unwrap_or(QREP_MAX_PARALLEL_WORKERS);
+                let partitioning = match (
+                    option("watermark_column").and_then(serde_json::Value::as_str),
+                    option("num_rows_per_partition").and_then(serde_json::Value::as_u64),
+                ) {
+                    (None, _) => "none, the query is run as a single partition".to_owned(),
+                    (Some(column), Some(num_rows)) if num_rows > 0 => format!(
+                        "{} ranges of {} rows, {} workers",
+                        column, num_rows, max_parallel_workers
+                    ),
+                    (Some(column), _) => format!(
+                        "{} ranges between its minimum and maximum, {} workers",
+                        column, max_parallel_workers
+                    ),
+                };
+                let mut plan = explain_mirror::TablePlan {
+                    source_table: watermark_table.clone(),
+                    destination_table: option("destination_table_name")
+                        .and_then(serde_json::Value::as_str)
+                        .unwrap_or_default()
+                        .to_owned(),
+                    columns: None,
+                    primary_key: vec![],
+                    partitioning,
+                    estimated_rows: None,
+                };
+                // the destination table is only created from the watermark
+                // table when the mirror is asked to, otherwise it must exist.
+                if let Some(executor) = executor.as_ref().filter(|_| !watermark_table.is_empty()) {
+                    let setup_destination_table = option("setup_watermark_table_on_destination")
+                        .and_then(serde_json::Value::as_bool)
+                        .unwrap_or(false);
+                    if setup_destination_table {
+                        plan.columns = Some(
+                            executor
+                                .get_column_types(&watermark_table)
+                                .await
+                                .map_err(internal_err)?,
+                        );
+                    }
+                    plan.estimated_rows = executor
+                        .get_estimated_rows(&watermark_table)
+                        .await
+                        .map_err(internal_err)?;
+                }
+                plans.push(plan);
+            }
+            _ => {}
+        }
+
+        Ok(vec![records_to_query_response(explain_mirror::records(
+            &plans,
+            dst_peer.r#type,
+        ))?])
+    }
+
+    // a mirror that already exists is only left alone for IF NOT EXISTS when it
+    // was created from the same statement, a mirror whose statement wasn't
+    // recorded, or can't be read, is assumed to match. the statements are
+    // compared as they are read back, with the defaults of the options added
+    // since the mirror was created, and as normalized to what they specify.
+    async fn handle_mirror_existence<T: serde::de::DeserializeOwned + PartialEq>(
+        &self,
+        catalog: &MutexGuard<'_, Catalog>,
+        if_not_exists: bool,
+        flow_name: &str,
+        flow_job: &T,
+        normalize: impl Fn(&T) -> T,
+    ) -> PgWireResult<Vec<Response<'static>>> {
+        if !if_not_exists {
+            return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                "ERROR".to_owned(),
+                "error".to_owned(),
+                format!("mirror already exists: {:?}", flow_name),
+            ))));
+        }
+
+        let existing_spec = catalog.get_flow_job_spec(flow_name).await.map_err(|err| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to query catalog for mirror config: {:?}", err),
+            }))
+        })?;
+        let existing_job = existing_spec.and_then(|spec| match serde_json::from_value::<T>(spec) {
+            Ok(job) => Some(job),
+            Err(err) => {
+                tracing::warn!(
+                    "unable to read the config of mirror {}: {:?}",
+                    flow_name,
+                    err
+                );
+                None
+            }
+        });
+        match existing_job {
+            Some(existing_job) if normalize(&existing_job) != normalize(flow_job) => {
+                Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "error".to_owned(),
+                    format!(
+                        "mirror {:?} already exists with a different configuration",
+                        flow_name
+                    ),
+                ))))
+            }
+            _ => {
+                self.notice(format!("mirror {} already exists, skipping", flow_name));
+                tracing::info!("mirror {} already exists, skipping", flow_name);
+                let existing_mirror_success = "MIRROR ALREADY EXISTS";
+                Ok(vec![Response::Execution(Tag::new_for_execution(
+                    existing_mirror_success,
+                    None,
+                ))])
+            }
+        }
+    }
+
+    // validates the peer with its connector, or through the flow api for
+    // SQLSERVER peers. other peers are not validated.
+    async fn validate_peer(&self, peer: &Peer) -> anyhow::Result<()> {
+        if let Some(connector) = self.connectors.get(peer) {
+            connector.validate(peer).await.map_err(|e| {
                 PgWireError::UserError(Box::new(ErrorInfo::new(
                     "ERROR".to_owned(),
                     "internal_error".to_owned(),
                     format!("[peer]: invalid configuration: {}", e),
                 )))
             })?;
-            self.executors.remove(&peer.name);
             Ok(())
-        } else {
+        } else if peer.r#type == DbType::Sqlserver as i32 {
             let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
             let validate_request = pt::peerdb_route::ValidatePeerRequest {
                 peer: Some(Peer {
@@ -248,12 +1836,18 @@ impl NexusBackend {
             } else {
                 Ok(())
             }
+        } else {
+            Ok(())
         }
     }
 
+    // the rows of queries on peers are sent in the result format, the ones of
+    // the statements nexus answers itself are always sent as text.
     async fn handle_query<'a>(
         &self,
         nexus_stmt: NexusStatement,
+        parameters: Option<&[Option<String>]>,
+        result_format: &Format,
     ) -> PgWireResult<Vec<Response<'a>>> {
         let mut peer_holder: Option<Box<Peer>> = None;
         match nexus_stmt {
@@ -262,16 +1856,13 @@ impl NexusBackend {
                     peer,
                     if_not_exists: _,
                 } => {
-                    let peer_type = peer.r#type;
-                    if Self::is_peer_validity_supported(peer_type) {
-                        self.validate_peer(peer_type, peer).await.map_err(|e| {
-                            PgWireError::UserError(Box::new(ErrorInfo::new(
-                                "ERROR".to_owned(),
-                                "internal_error".to_owned(),
-                                e.to_string(),
-                            )))
-                        })?;
-                    }
+                    self.validate_peer(peer).await.map_err(|e| {
+                        PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "internal_error".to_owned(),
+                            e.to_string(),
+                        )))
+                    })?;
 
                     let catalog = self.catalog.lock().await;
                     catalog.create_peer(peer.as_ref()).await.map_err(|e| {
@@ -295,8 +1886,20 @@ impl NexusBackend {
                         })));
                     }
                     let catalog = self.catalog.lock().await;
+                    let src_peer =
+                        Self::get_peer_of_mirror(&catalog, flow_job.source_peer.clone()).await?;
+                    let flow_job = &Self::expand_schema_mappings(flow_job, &src_peer).await?;
                     let mirror_details = Self::check_for_mirror(&catalog, &flow_job.name).await?;
                     if mirror_details.is_none() {
+                        if let Some(ordering_key) = &flow_job.ordering_key {
+                            Self::validate_ordering_key(flow_job, ordering_key, &src_peer).await?;
+                        }
+                        Self::validate_excluded_columns(flow_job, &src_peer).await?;
+                        let wal_warning = Self::check_wal_headroom(flow_job, &src_peer).await;
+                        if let Some(warning) = &wal_warning {
+                            tracing::warn!("mirror {}: {}", flow_job.name, warning);
+                        }
+
                         catalog
                             .create_flow_job_entry(flow_job)
                             .await
@@ -322,11 +1925,7 @@ impl NexusBackend {
                         let workflow_id = flow_handler
                             .start_peer_flow_job(flow_job, src_peer, dst_peer)
                             .await
-                            .map_err(|err| {
-                                PgWireError::ApiError(Box::new(PgError::Internal {
-                                    err_msg: format!("unable to submit job: {:?}", err),
-                                }))
-                            })?;
+                            .map_err(mirror_creation_error)?;
 
                         catalog
                             .update_workflow_id_for_flow_job(&flow_job.name, &workflow_id)
@@ -337,13 +1936,27 @@ impl NexusBackend {
                                 }))
                             })?;
 
-                        let create_mirror_success = format!("CREATE MIRROR {}", flow_job.name);
+                        // the warning is reported in the command tag, as the
+                        // simple query handler can't send notices.
+                        let create_mirror_success = match wal_warning {
+                            Some(warning) => {
+                                format!("CREATE MIRROR {} WARNING: {}", flow_job.name, warning)
+                            }
+                            None => format!("CREATE MIRROR {}", flow_job.name),
+                        };
                         Ok(vec![Response::Execution(Tag::new_for_execution(
                             &create_mirror_success,
                             None,
                         ))])
                     } else {
-                        Self::handle_mirror_existence(*if_not_exists, flow_job.name.clone())
+                        self.handle_mirror_existence(
+                            &catalog,
+                            *if_not_exists,
+                            &flow_job.name,
+                            flow_job,
+                            given_flow_job,
+                        )
+                        .await
                     }
                 }
                 PeerDDL::CreateMirrorForSelect {
@@ -386,14 +1999,22 @@ impl NexusBackend {
                             ))]);
                         }
 
-                        let _workflow_id = self.run_qrep_mirror(qrep_flow_job).await?;
+                        let _workflow_id = Box::pin(self.run_qrep_mirror(qrep_flow_job)).await?;
                         let create_mirror_success = format!("CREATE MIRROR {}", qrep_flow_job.name);
                         Ok(vec![Response::Execution(Tag::new_for_execution(
                             &create_mirror_success,
                             None,
                         ))])
                     } else {
-                        Self::handle_mirror_existence(*if_not_exists, qrep_flow_job.name.clone())
+                        let catalog = self.catalog.lock().await;
+                        self.handle_mirror_existence(
+                            &catalog,
+                            *if_not_exists,
+                            &qrep_flow_job.name,
+                            qrep_flow_job,
+                            QRepFlowJob::clone,
+                        )
+                        .await
                     }
                 }
                 PeerDDL::ExecuteMirrorForSelect { flow_job_name } => {
@@ -414,7 +2035,7 @@ impl NexusBackend {
                                 }))
                             })?
                     } {
-                        let workflow_id = self.run_qrep_mirror(&job).await?;
+                        let workflow_id = Box::pin(self.run_qrep_mirror(&job)).await?;
                         let create_mirror_success = format!("STARTED WORKFLOW {}", workflow_id);
                         Ok(vec![Response::Execution(Tag::new_for_execution(
                             &create_mirror_success,
@@ -428,12 +2049,62 @@ impl NexusBackend {
                         ))))
                     }
                 }
-                PeerDDL::DropMirror {
-                    if_exists,
-                    flow_job_name,
-                } => {
-                    if self.flow_handler.is_none() {
-                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                PeerDDL::ExecuteOnPeer { peer, query } => {
+                    if !self.execute_on_peer {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42501".to_owned(),
+                            "EXECUTE ... ON PEER is disabled on this server".to_owned(),
+                        ))));
+                    }
+                    if !self.connectors.capabilities(peer).execute_raw {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "feature_not_supported".to_owned(),
+                            format!("cannot execute statements on peer {}", peer.name),
+                        ))));
+                    }
+
+                    tracing::info!("executing on peer[{}]: {}", peer.name, query);
+                    let executor = self.get_query_executor(peer).await?;
+                    let res = match executor.execute_raw(query).await {
+                        Ok(res) => res,
+                        Err(err) => {
+                            tracing::error!("query execution failed: {:?}", err);
+                            return Err(err);
+                        }
+                    };
+                    match res {
+                        QueryOutput::AffectedRows(rows) => {
+                            self.audit_rows(rows);
+                            Ok(vec![Response::Execution(Tag::new_for_execution(
+                                "EXECUTE",
+                                Some(rows),
+                            ))])
+                        }
+                        QueryOutput::Stream(rows) => {
+                            let rows = self.hold_rows(rows);
+                            let schema = rows.schema();
+                            Ok(vec![sendable_stream_to_query_response(schema, rows)?])
+                        }
+                        QueryOutput::Records(records) => {
+                            self.audit_rows(records.records.len());
+                            Ok(vec![records_to_query_response(records)?])
+                        }
+                        QueryOutput::Cursor(_) => {
+                            Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: "unexpected cursor from EXECUTE ... ON PEER".to_owned(),
+                            })))
+                        }
+                    }
+                }
+                PeerDDL::DropMirror {
+                    if_exists,
+                    flow_job_name,
+                    drop_destination_tables,
+                } => {
+                    if self.flow_handler.is_none() {
+                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
                             err_msg: "flow service is not configured".to_owned(),
                         })));
                     }
@@ -456,6 +2127,39 @@ impl NexusBackend {
                         workflow_details.as_ref().map(|w| &w.workflow_id)
                     );
                     if let Some(workflow_details) = workflow_details {
+                        // look up the destination tables before the job metadata is gone
+                        let destination = if *drop_destination_tables {
+                            let destination_peer = workflow_details.destination_peer.clone();
+                            if !self
+                                .connectors
+                                .capabilities(&destination_peer)
+                                .manage_tables
+                            {
+                                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                                    "ERROR".to_owned(),
+                                    "feature_not_supported".to_owned(),
+                                    format!(
+                                        "cannot drop destination tables on peer {}",
+                                        destination_peer.name
+                                    ),
+                                ))));
+                            }
+                            let tables = catalog
+                                .get_destination_tables_for_flow_job(flow_job_name)
+                                .await
+                                .map_err(|err| {
+                                    PgWireError::ApiError(Box::new(PgError::Internal {
+                                        err_msg: format!(
+                                            "unable to query catalog for job metadata: {:?}",
+                                            err
+                                        ),
+                                    }))
+                                })?;
+                            Some((destination_peer, tables))
+                        } else {
+                            None
+                        };
+
                         let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
                         flow_handler
                             .shutdown_flow_job(flow_job_name, workflow_details)
@@ -473,6 +2177,9 @@ impl NexusBackend {
                                     err_msg: format!("unable to delete job metadata: {:?}", err),
                                 }))
                             })?;
+                        if let Some((destination_peer, tables)) = destination {
+                            self.drop_tables(&destination_peer, &tables).await?;
+                        }
                         let drop_mirror_success = format!("DROP MIRROR {}", flow_job_name);
                         Ok(vec![Response::Execution(Tag::new_for_execution(
                             &drop_mirror_success,
@@ -492,32 +2199,1167 @@ impl NexusBackend {
                         ))))
                     }
                 }
-            },
-            NexusStatement::PeerQuery { stmt, assoc } => {
-                // get the query executor
-                let executor = match assoc {
-                    QueryAssocation::Peer(peer) => {
-                        tracing::info!("handling peer[{}] query: {}", peer.name, stmt);
-                        peer_holder = Some(peer.clone());
-                        self.get_peer_executor(&peer).await.map_err(|err| {
+                PeerDDL::ShowPeers => {
+                    let catalog = self.catalog.lock().await;
+                    let peers = catalog.get_peer_infos().await.map_err(|err| {
+                        PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!("unable to list peers: {:?}", err),
+                        }))
+                    })?;
+                    let records = show_peers::records(peers, |peer_name| {
+                        self.scheduler.peer_stats(peer_name)
+                    });
+                    Ok(vec![records_to_query_response(records)?])
+                }
+                PeerDDL::ShowPeerTables { peer, schema } => {
+                    let connector = self.connectors.get(peer).ok_or_else(|| {
+                        PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "feature_not_supported".to_owned(),
+                            format!("cannot list the tables of peer {}", peer.name),
+                        )))
+                    })?;
+                    let tables = connector
+                        .describe_tables(peer, schema.as_deref())
+                        .await
+                        .map_err(|err| {
+                            PgWireError::UserError(Box::new(ErrorInfo::new(
+                                "ERROR".to_owned(),
+                                "error".to_owned(),
+                                format!("unable to list the tables of peer {}: {}", peer.name, err),
+                            )))
+                        })?;
+                    Ok(vec![records_to_query_response(show_tables::records(
+                        tables,
+                    ))?])
+                }
+                PeerDDL::ShowMirrors => {
+                    let catalog = self.catalog.lock().await;
+                    let mirrors = catalog.get_mirrors().await.map_err(|err| {
+                        PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!("unable to list mirrors: {:?}", err),
+                        }))
+                    })?;
+                    drop(catalog);
+
+                    let mut statuses = Vec::with_capacity(mirrors.len());
+                    if let Some(flow_handler) = self.flow_handler.as_ref() {
+                        let mut flow_handler = flow_handler.lock().await;
+                        for mirror in &mirrors {
+                            // a mirror the flow server can't report on is still listed.
+                            let status = flow_handler
+                                .get_mirror_status(&mirror.name)
+                                .await
+                                .map_err(|err| {
+                                    tracing::warn!(
+                                        "unable to get status of mirror {}: {:?}",
+                                        mirror.name,
+                                        err
+                                    );
+                                })
+                                .ok();
+                            statuses.push(status);
+                        }
+                    } else {
+                        statuses.resize(mirrors.len(), None);
+                    }
+
+                    let records = show_mirrors::records(mirrors.into_iter().zip(statuses));
+                    Ok(vec![records_to_query_response(records)?])
+                }
+                PeerDDL::DescribeMirror { flow_job_name } => {
+                    if self.flow_handler.is_none() {
+                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: "flow service is not configured".to_owned(),
+                        })));
+                    }
+
+                    let catalog = self.catalog.lock().await;
+                    if Self::check_for_mirror(&catalog, flow_job_name)
+                        .await?
+                        .is_none()
+                    {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "error".to_owned(),
+                            format!("no such mirror: {:?}", flow_job_name),
+                        ))));
+                    }
+                    let comment = catalog
+                        .get_flow_job_description(flow_job_name)
+                        .await
+                        .map_err(|err| {
                             PgWireError::ApiError(Box::new(PgError::Internal {
-                                err_msg: format!("unable to get peer executor: {:?}", err),
+                                err_msg: format!("unable to get comment of mirror: {:?}", err),
                             }))
-                        })?
+                        })?;
+                    drop(catalog);
+
+                    let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+                    let status = flow_handler
+                        .get_mirror_status(flow_job_name)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to get status of mirror: {:?}", err),
+                            }))
+                        })?;
+                    if !status.error_message.is_empty() {
+                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!(
+                                "unable to get status of mirror: {}",
+                                status.error_message
+                            ),
+                        })));
                     }
-                    QueryAssocation::Catalog => {
-                        tracing::info!("handling catalog query: {}", stmt);
-                        let catalog = self.catalog.lock().await;
-                        catalog.get_executor()
+
+                    Ok(vec![records_to_query_response(describe_mirror::records(
+                        &status, comment,
+                    ))?])
+                }
+                PeerDDL::CommentOnPeer { peer_name, comment } => {
+                    let catalog = self.catalog.lock().await;
+                    let updated = catalog
+                        .set_peer_comment(peer_name, comment.as_deref())
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to set comment of peer: {:?}", err),
+                            }))
+                        })?;
+                    if !updated {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42704".to_owned(),
+                            format!("peer {} does not exist", peer_name),
+                        ))));
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "COMMENT", None,
+                    ))])
+                }
+                PeerDDL::CommentOnMirror {
+                    flow_job_name,
+                    comment,
+                } => {
+                    let catalog = self.catalog.lock().await;
+                    let updated = catalog
+                        .set_flow_job_description(
+                            flow_job_name,
+                            comment.as_deref().unwrap_or_default(),
+                        )
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to set comment of mirror: {:?}", err),
+                            }))
+                        })?;
+                    if !updated {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "error".to_owned(),
+                            format!("no such mirror: {:?}", flow_job_name),
+                        ))));
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "COMMENT", None,
+                    ))])
+                }
+                PeerDDL::ExplainMirror { ddl } => Box::pin(self.explain_mirror(ddl)).await,
+                PeerDDL::CreateUser {
+                    name,
+                    password,
+                    if_not_exists,
+                } => {
+                    let admin_user = self.access_control_admin()?;
+                    if name == admin_user {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42710".to_owned(),
+                            format!("user {} is the admin", name),
+                        ))));
+                    }
+
+                    let catalog = self.catalog.lock().await;
+                    let password_hash = access_control::password_hash(name, password);
+                    let scram_secret = access_control::scram_secret(password);
+                    let created = catalog
+                        .create_user(name, &password_hash, &scram_secret)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to create user: {:?}", err),
+                            }))
+                        })?;
+                    if !created && !*if_not_exists {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42710".to_owned(),
+                            format!("user {} already exists", name),
+                        ))));
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "CREATE USER",
+                        None,
+                    ))])
+                }
+                PeerDDL::AlterUser { name, password } => {
+                    let admin_user = self.access_control_admin()?;
+                    if name == admin_user {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "feature_not_supported".to_owned(),
+                            format!(
+                                "user {} is the admin, its password is the one of the server",
+                                name
+                            ),
+                        ))));
+                    }
+
+                    let catalog = self.catalog.lock().await;
+                    let password_hash = access_control::password_hash(name, password);
+                    let scram_secret = access_control::scram_secret(password);
+                    let altered = catalog
+                        .set_user_password(name, &password_hash, &scram_secret)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to alter user: {:?}", err),
+                            }))
+                        })?;
+                    if !altered {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42704".to_owned(),
+                            format!("user {} does not exist", name),
+                        ))));
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "ALTER USER",
+                        None,
+                    ))])
+                }
+                PeerDDL::DropUser { name, if_exists } => {
+                    self.access_control_admin()?;
+                    let catalog = self.catalog.lock().await;
+                    let dropped = catalog.drop_user(name).await.map_err(|err| {
+                        PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!("unable to drop user: {:?}", err),
+                        }))
+                    })?;
+                    if !dropped && !*if_exists {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42704".to_owned(),
+                            format!("user {} does not exist", name),
+                        ))));
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "DROP USER",
+                        None,
+                    ))])
+                }
+                PeerDDL::Grant {
+                    privilege,
+                    grantees,
+                } => {
+                    self.access_control_admin()?;
+                    let catalog = self.catalog.lock().await;
+                    for grantee in grantees {
+                        catalog
+                            .grant_privilege(privilege, grantee)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!("unable to grant privilege: {:?}", err),
+                                }))
+                            })?;
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "GRANT", None,
+                    ))])
+                }
+                PeerDDL::Revoke {
+                    privilege,
+                    grantees,
+                } => {
+                    self.access_control_admin()?;
+                    let catalog = self.catalog.lock().await;
+                    for grantee in grantees {
+                        catalog
+                            .revoke_privilege(privilege, grantee)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!("unable to revoke privilege: {:?}", err),
+                                }))
+                            })?;
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "REVOKE", None,
+                    ))])
+                }
+                // resource groups apply to the users that log in whether or
+                // not access control is enabled, sessions look their group up
+                // on their first query.
+                PeerDDL::CreateResourceGroup {
+                    group,
+                    if_not_exists,
+                } => {
+                    let catalog = self.catalog.lock().await;
+                    let created = catalog.create_resource_group(group).await.map_err(|err| {
+                        PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!("unable to create resource group: {:?}", err),
+                        }))
+                    })?;
+                    if !created && !*if_not_exists {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42710".to_owned(),
+                            format!("resource group {} already exists", group.name),
+                        ))));
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "CREATE RESOURCE GROUP",
+                        None,
+                    ))])
+                }
+                PeerDDL::AlterResourceGroup {
+                    name,
+                    weight,
+                    max_concurrency,
+                } => {
+                    let catalog = self.catalog.lock().await;
+                    let altered = catalog
+                        .alter_resource_group(name, *weight, *max_concurrency)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to alter resource group: {:?}", err),
+                            }))
+                        })?;
+                    if !altered {
+                        return Err(resource_group_does_not_exist(name));
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "ALTER RESOURCE GROUP",
+                        None,
+                    ))])
+                }
+                PeerDDL::AddResourceGroupMembers { name, user_names } => {
+                    let catalog = self.catalog.lock().await;
+                    for user_name in user_names {
+                        let added = catalog
+                            .add_resource_group_member(name, user_name)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!(
+                                        "unable to add user to resource group: {:?}",
+                                        err
+                                    ),
+                                }))
+                            })?;
+                        if !added {
+                            return Err(resource_group_does_not_exist(name));
+                        }
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "ALTER RESOURCE GROUP",
+                        None,
+                    ))])
+                }
+                PeerDDL::RemoveResourceGroupMembers { name, user_names } => {
+                    let catalog = self.catalog.lock().await;
+                    for user_name in user_names {
+                        let removed = catalog
+                            .remove_resource_group_member(name, user_name)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!(
+                                        "unable to drop user from resource group: {:?}",
+                                        err
+                                    ),
+                                }))
+                            })?;
+                        if !removed {
+                            return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                                "ERROR".to_owned(),
+                                "42704".to_owned(),
+                                format!(
+                                    "user {} is not a member of resource group {}",
+                                    user_name, name
+                                ),
+                            ))));
+                        }
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "ALTER RESOURCE GROUP",
+                        None,
+                    ))])
+                }
+                PeerDDL::DropResourceGroup { name, if_exists } => {
+                    let catalog = self.catalog.lock().await;
+                    let dropped = catalog.drop_resource_group(name).await.map_err(|err| {
+                        PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!("unable to drop resource group: {:?}", err),
+                        }))
+                    })?;
+                    if !dropped && !*if_exists {
+                        return Err(resource_group_does_not_exist(name));
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "DROP RESOURCE GROUP",
+                        None,
+                    ))])
+                }
+                PeerDDL::ValidatePeer { peer } => {
+                    if self.flow_handler.is_none() {
+                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: "flow service is not configured".to_owned(),
+                        })));
+                    }
+
+                    let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+                    let checks = flow_handler
+                        .get_peer_validation_checks(peer)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to validate peer: {:?}", err),
+                            }))
+                        })?;
+                    Ok(vec![records_to_query_response(validate_peer::records(
+                        &checks,
+                    ))?])
+                }
+                PeerDDL::PauseMirror { flow_job_name } => {
+                    self.set_mirror_paused(flow_job_name, true).await
+                }
+                PeerDDL::ResumeMirror { flow_job_name } => {
+                    self.set_mirror_paused(flow_job_name, false).await
+                }
+                PeerDDL::AlterMirrorAddTable {
+                    flow_job_name,
+                    table_mapping,
+                } => {
+                    if self.flow_handler.is_none() {
+                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: "flow service is not configured".to_owned(),
+                        })));
+                    }
+
+                    let catalog = self.catalog.lock().await;
+                    let workflow_details =
+                        Self::check_for_cdc_mirror(&catalog, flow_job_name).await?;
+
+                    let added = catalog
+                        .add_table_mapping_to_flow_job(flow_job_name, table_mapping)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to add table to mirror: {:?}", err),
+                            }))
+                        })?;
+                    if !added {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "error".to_owned(),
+                            format!(
+                                "mirror {:?} already replicates {:?}",
+                                flow_job_name, table_mapping.source_table_identifier
+                            ),
+                        ))));
+                    }
+
+                    // the running flow snapshots the table before it streams changes to it.
+                    let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+                    flow_handler
+                        .add_tables_to_flow_job(
+                            flow_job_name,
+                            workflow_details,
+                            std::slice::from_ref(table_mapping),
+                        )
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to add table to flow job: {:?}", err),
+                            }))
+                        })?;
+
+                    let alter_mirror_success = format!("ALTER MIRROR {}", flow_job_name);
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        &alter_mirror_success,
+                        None,
+                    ))])
+                }
+                PeerDDL::AlterMirrorPauseTable {
+                    flow_job_name,
+                    table_identifier,
+                    policy,
+                } => {
+                    self.set_mirror_table_paused(flow_job_name, table_identifier, Some(*policy))
+                        .await
+                }
+                PeerDDL::AlterMirrorResumeTable {
+                    flow_job_name,
+                    table_identifier,
+                } => {
+                    self.set_mirror_table_paused(flow_job_name, table_identifier, None)
+                        .await
+                }
+                PeerDDL::AlterMirrorDropTable {
+                    flow_job_name,
+                    table_identifier,
+                    truncate_destination_table,
+                } => {
+                    if self.flow_handler.is_none() {
+                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: "flow service is not configured".to_owned(),
+                        })));
+                    }
+
+                    let catalog = self.catalog.lock().await;
+                    let workflow_details =
+                        Self::check_for_cdc_mirror(&catalog, flow_job_name).await?;
+                    let table_mapping = catalog
+                        .get_table_mapping_for_flow_job(flow_job_name, table_identifier)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!(
+                                    "unable to query catalog for job metadata: {:?}",
+                                    err
+                                ),
+                            }))
+                        })?
+                        .ok_or_else(|| {
+                            PgWireError::UserError(Box::new(ErrorInfo::new(
+                                "ERROR".to_owned(),
+                                "error".to_owned(),
+                                format!(
+                                    "mirror {:?} does not replicate {:?}",
+                                    flow_job_name, table_identifier
+                                ),
+                            )))
+                        })?;
+
+                    let destination_peer = workflow_details.destination_peer.clone();
+                    if *truncate_destination_table
+                        && !self
+                            .connectors
+                            .capabilities(&destination_peer)
+                            .manage_tables
+                    {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "feature_not_supported".to_owned(),
+                            format!(
+                                "cannot truncate destination tables on peer {}",
+                                destination_peer.name
+                            ),
+                        ))));
+                    }
+
+                    let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+                    flow_handler
+                        .remove_table_from_flow_job(
+                            flow_job_name,
+                            workflow_details,
+                            &table_mapping.source_table_identifier,
+                        )
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to remove table from flow job: {:?}", err),
+                            }))
+                        })?;
+
+                    catalog
+                        .remove_table_mapping_from_flow_job(flow_job_name, &table_mapping)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to save job metadata: {:?}", err),
+                            }))
+                        })?;
+                    if *truncate_destination_table {
+                        self.truncate_tables(
+                            &destination_peer,
+                            &[table_mapping.destination_table_identifier],
+                        )
+                        .await?;
+                    }
+
+                    let alter_mirror_success = format!("ALTER MIRROR {}", flow_job_name);
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        &alter_mirror_success,
+                        None,
+                    ))])
+                }
+                PeerDDL::ResyncMirror {
+                    flow_job_name,
+                    table_identifier,
+                    truncate_destination_table,
+                } => {
+                    if self.flow_handler.is_none() {
+                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: "flow service is not configured".to_owned(),
+                        })));
+                    }
+
+                    let catalog = self.catalog.lock().await;
+                    let workflow_details =
+                        Self::check_for_cdc_mirror(&catalog, flow_job_name).await?;
+                    let source_table_identifier = match table_identifier {
+                        Some(table_identifier) => {
+                            let table_mapping = catalog
+                                .get_table_mapping_for_flow_job(flow_job_name, table_identifier)
+                                .await
+                                .map_err(|err| {
+                                    PgWireError::ApiError(Box::new(PgError::Internal {
+                                        err_msg: format!(
+                                            "unable to query catalog for job metadata: {:?}",
+                                            err
+                                        ),
+                                    }))
+                                })?
+                                .ok_or_else(|| {
+                                    PgWireError::UserError(Box::new(ErrorInfo::new(
+                                        "ERROR".to_owned(),
+                                        "error".to_owned(),
+                                        format!(
+                                            "mirror {:?} does not replicate {:?}",
+                                            flow_job_name, table_identifier
+                                        ),
+                                    )))
+                                })?;
+                            Some(table_mapping.source_table_identifier)
+                        }
+                        None => None,
+                    };
+
+                    let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+                    flow_handler
+                        .resync_flow_job(
+                            flow_job_name,
+                            workflow_details,
+                            source_table_identifier.as_deref(),
+                            *truncate_destination_table,
+                        )
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("unable to resync flow job: {:?}", err),
+                            }))
+                        })?;
+
+                    let resync_mirror_success = format!("RESYNC MIRROR {}", flow_job_name);
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        &resync_mirror_success,
+                        None,
+                    ))])
+                }
+                PeerDDL::DropPeer {
+                    if_exists,
+                    peer_name,
+                    cascade,
+                } => {
+                    let catalog = self.catalog.lock().await;
+                    let flow_job_names =
+                        catalog
+                            .get_flow_jobs_for_peer(peer_name)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!(
+                                        "unable to query catalog for mirrors of peer: {:?}",
+                                        err
+                                    ),
+                                }))
+                            })?;
+
+                    if !flow_job_names.is_empty() {
+                        if !*cascade {
+                            return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                                "ERROR".to_owned(),
+                                "2BP01".to_owned(),
+                                format!(
+                                    "cannot drop peer {} because mirrors depend on it: {}",
+                                    peer_name,
+                                    flow_job_names.join(", ")
+                                ),
+                            ))));
+                        }
+                        if self.flow_handler.is_none() {
+                            return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: "flow service is not configured".to_owned(),
+                            })));
+                        }
+
+                        let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+                        for flow_job_name in &flow_job_names {
+                            let workflow_details = catalog
+                                .get_workflow_details_for_flow_job(flow_job_name)
+                                .await
+                                .map_err(|err| {
+                                    PgWireError::ApiError(Box::new(PgError::Internal {
+                                        err_msg: format!(
+                                            "unable to query catalog for job metadata: {:?}",
+                                            err
+                                        ),
+                                    }))
+                                })?;
+                            if let Some(workflow_details) = workflow_details {
+                                tracing::info!(
+                                    "dropping mirror {} of peer {}",
+                                    flow_job_name,
+                                    peer_name
+                                );
+                                flow_handler
+                                    .shutdown_flow_job(flow_job_name, workflow_details)
+                                    .await
+                                    .map_err(|err| {
+                                        PgWireError::ApiError(Box::new(PgError::Internal {
+                                            err_msg: format!(
+                                                "unable to shutdown flow job: {:?}",
+                                                err
+                                            ),
+                                        }))
+                                    })?;
+                            }
+                            catalog
+                                .delete_flow_job_entry(flow_job_name)
+                                .await
+                                .map_err(|err| {
+                                    PgWireError::ApiError(Box::new(PgError::Internal {
+                                        err_msg: format!(
+                                            "unable to delete job metadata: {:?}",
+                                            err
+                                        ),
+                                    }))
+                                })?;
+                        }
+                    }
+
+                    let deleted = catalog.delete_peer(peer_name).await.map_err(|err| {
+                        PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!("unable to delete peer: {:?}", err),
+                        }))
+                    })?;
+                    if deleted {
+                        self.executors.remove(peer_name);
+                        self.executor_versions.remove(peer_name);
+                        if let Some(peer_pool) = &self.peer_pool {
+                            peer_pool.evict(peer_name);
+                        }
+                        let drop_peer_success = format!("DROP PEER {}", peer_name);
+                        Ok(vec![Response::Execution(Tag::new_for_execution(
+                            &drop_peer_success,
+                            None,
+                        ))])
+                    } else if *if_exists {
+                        let no_peer_success = "NO SUCH PEER";
+                        Ok(vec![Response::Execution(Tag::new_for_execution(
+                            no_peer_success,
+                            None,
+                        ))])
+                    } else {
+                        Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "error".to_owned(),
+                            format!("no such peer: {:?}", peer_name),
+                        ))))
+                    }
+                }
+                PeerDDL::AlterPeer { peer } => {
+                    // drop the executor for the old config, so that the peer is validated
+                    // and later queried with the new one
+                    self.executors.remove(&peer.name);
+                    self.executor_versions.remove(&peer.name);
+                    if let Some(peer_pool) = &self.peer_pool {
+                        peer_pool.evict(&peer.name);
+                    }
+                    self.validate_peer(peer).await.map_err(|e| {
+                        PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "internal_error".to_owned(),
+                            e.to_string(),
+                        )))
+                    })?;
+
+                    let catalog = self.catalog.lock().await;
+                    let version = catalog.update_peer(peer.as_ref()).await.map_err(|e| {
+                        PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "internal_error".to_owned(),
+                            e.to_string(),
+                        )))
+                    })?;
+                    tracing::info!("peer {} altered, now at version {}", peer.name, version);
+
+                    // the running mirrors of the peer reconnect with the new config at
+                    // their next batch, so rotating its credentials doesn't drop them.
+                    let flow_job_names =
+                        catalog
+                            .get_flow_jobs_for_peer(&peer.name)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!(
+                                        "unable to query catalog for mirrors of peer: {:?}",
+                                        err
+                                    ),
+                                }))
+                            })?;
+                    match &self.flow_handler {
+                        Some(flow_handler) if !flow_job_names.is_empty() => {
+                            let mut flow_handler = flow_handler.lock().await;
+                            let updated = flow_handler.update_peer(peer).await.map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!(
+                                        "peer {} was altered, but its mirrors were not updated: {:?}",
+                                        peer.name, err
+                                    ),
+                                }))
+                            })?;
+                            tracing::info!(
+                                "mirrors of peer {} reconnect with its new config: {}",
+                                peer.name,
+                                updated.join(", ")
+                            );
+                        }
+                        _ => {}
+                    }
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        "ALTER PEER",
+                        None,
+                    ))])
+                }
+                PeerDDL::ReplacePeer { old_peer, new_peer } => {
+                    if self.flow_handler.is_none() {
+                        return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: "flow service is not configured".to_owned(),
+                        })));
+                    }
+
+                    let catalog = self.catalog.lock().await;
+                    let flow_job_names = catalog
+                        .get_flow_jobs_for_peer(&old_peer.name)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!(
+                                    "unable to query catalog for mirrors of peer: {:?}",
+                                    err
+                                ),
+                            }))
+                        })?;
+
+                    // collect the configs of all mirrors up front, and make sure the
+                    // new peer serves the same tables before any mirror is touched.
+                    let mut flow_configs = Vec::with_capacity(flow_job_names.len());
+                    for flow_job_name in &flow_job_names {
+                        let flow_config = catalog
+                            .get_cdc_flow_config(flow_job_name)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!(
+                                        "unable to query catalog for job metadata: {:?}",
+                                        err
+                                    ),
+                                }))
+                            })?
+                            .ok_or_else(|| {
+                                PgWireError::UserError(Box::new(ErrorInfo::new(
+                                    "ERROR".to_owned(),
+                                    "error".to_owned(),
+                                    format!(
+                                        "only CDC mirrors can be moved, {} is not one",
+                                        flow_job_name
+                                    ),
+                                )))
+                            })?;
+
+                        let uses_old_peer = |peer: &Option<Peer>| {
+                            peer.as_ref().map(|p| &p.name) == Some(&old_peer.name)
+                        };
+                        let replaces_source = uses_old_peer(&flow_config.source);
+                        let replaces_destination = uses_old_peer(&flow_config.destination);
+                        let mut tables = vec![];
+                        for mapping in &flow_config.table_mappings {
+                            if replaces_source {
+                                tables.push(mapping.source_table_identifier.as_str());
+                            }
+                            if replaces_destination {
+                                tables.push(mapping.destination_table_identifier.as_str());
+                            }
+                        }
+                        self.check_replacement_schema(old_peer, new_peer, &tables)
+                            .await?;
+                        flow_configs.push(flow_config);
+                    }
+
+                    let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+                    for mut flow_config in flow_configs {
+                        let flow_job_name = flow_config.flow_job_name.clone();
+                        tracing::info!(
+                            "moving mirror {} from peer {} to {}",
+                            flow_job_name,
+                            old_peer.name,
+                            new_peer.name
+                        );
+
+                        if let Some(workflow_details) =
+                            Self::check_for_mirror(&catalog, &flow_job_name).await?
+                        {
+                            flow_handler
+                                .shutdown_flow_job(&flow_job_name, workflow_details)
+                                .await
+                                .map_err(|err| {
+                                    PgWireError::ApiError(Box::new(PgError::Internal {
+                                        err_msg: format!("unable to shutdown flow job: {:?}", err),
+                                    }))
+                                })?;
+                        }
+
+                        catalog
+                            .replace_peer_for_flow_job(
+                                &flow_job_name,
+                                &old_peer.name,
+                                &new_peer.name,
+                            )
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!("unable to save job metadata: {:?}", err),
+                                }))
+                            })?;
+
+                        for peer in [&mut flow_config.source, &mut flow_config.destination]
+                            .into_iter()
+                            .flatten()
+                        {
+                            if peer.name == old_peer.name {
+                                *peer = new_peer.as_ref().clone();
+                            }
+                        }
+
+                        // shutting down the mirror dropped its replication slot, so it is
+                        // restarted from its original configuration.
+                        let workflow_id = flow_handler
+                            .restart_peer_flow_job(flow_config)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!("unable to submit job: {:?}", err),
+                                }))
+                            })?;
+                        catalog
+                            .update_workflow_id_for_flow_job(&flow_job_name, &workflow_id)
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!("unable to save job metadata: {:?}", err),
+                                }))
+                            })?;
+                    }
+
+                    let replace_peer_success =
+                        format!("REPLACE PEER {} WITH {}", old_peer.name, new_peer.name);
+                    Ok(vec![Response::Execution(Tag::new_for_execution(
+                        &replace_peer_success,
+                        None,
+                    ))])
+                }
+            },
+            NexusStatement::PeerQuery { mut stmt, assoc } => {
+                let mut dropped_session_tables = vec![];
+                // get the query executor
+                let executor = match assoc {
+                    QueryAssocation::Peer(peer) => {
+                        if self.session_tables.lock().await.is_referenced(&stmt) {
+                            return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                                "ERROR".to_owned(),
+                                "feature_not_supported".to_owned(),
+                                "queries joining session tables with peer tables are not supported"
+                                    .to_owned(),
+                            ))));
+                        }
+                        tracing::info!("handling peer[{}] query: {}", peer.name, stmt);
+                        let executor = self.get_query_executor(&peer).await?;
+                        peer_holder = Some(peer);
+                        executor
+                    }
+                    QueryAssocation::Catalog => {
+                        {
+                            let session_tables = self.session_tables.lock().await;
+                            dropped_session_tables = session_tables.dropped_tables(&stmt);
+                            session_tables.qualify(&mut stmt);
+                        }
+                        self.emulate_catalog(&mut stmt).await?;
+                        tracing::info!("handling catalog query: {}", stmt);
+                        let catalog = self.catalog.lock().await;
+                        catalog.get_executor()
+                    }
+                };
+
+                let res = Box::pin(self.execute_statement(
+                    executor,
+                    &stmt,
+                    peer_holder,
+                    parameters,
+                    result_format,
+                ))
+                .await;
+                // log the error if execution failed
+                if let Err(err) = &res {
+                    tracing::error!("query execution failed: {:?}", err);
+                } else if !dropped_session_tables.is_empty() {
+                    let mut session_tables = self.session_tables.lock().await;
+                    for table in &dropped_session_tables {
+                        session_tables.remove(table);
+                    }
+                }
+                res
+            }
+
+            NexusStatement::FederatedQuery { mut stmt, tables } => {
+                // every peer table the query reads is staged in the catalog,
+                // and the query runs on the staged tables along with any
+                // session tables it joins them with. Only the columns and
+                // rows of a table the query reads are staged, and the tables
+                // the peers estimate to be smaller are staged first, so the
+                // rows of the larger ones can be cut down to those that join
+                // with them.
+                let staged_schema = self.session_tables.lock().await.schema().to_owned();
+                let mut estimated_rows = Vec::with_capacity(tables.len());
+                for table in &tables {
+                    estimated_rows.push(self.estimated_rows(table).await);
+                }
+                let mut order: Vec<usize> = (0..tables.len()).collect();
+                order.sort_by_key(|idx| estimated_rows[*idx].unwrap_or(i64::MAX));
+
+                let mut staged: Vec<Option<ObjectName>> = vec![None; tables.len()];
+                for idx in order {
+                    let table = &tables[idx];
+                    let staged_table = format!("_peerdb_federated_{}", idx);
+                    let reductions = self.federated_join_reductions(table, &staged).await?;
+                    let query = table.staging_query(&reductions);
+                    let query = sqlparser::parser::Parser::parse_sql(&PostgreSqlDialect {}, &query)
+                        .ok()
+                        .and_then(|mut stmts| stmts.pop())
+                        .ok_or_else(|| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!("invalid table identifier: {}", table.name),
+                            }))
+                        })?;
+                    tracing::info!(
+                        "staging {} from peer[{}] for federated query: {}",
+                        table.name,
+                        table.peer.name,
+                        query
+                    );
+                    let executor = self.get_query_executor(&table.peer).await?;
+                    Box::pin(self.stage_session_table(
+                        executor,
+                        &staged_table,
+                        &query,
+                        Some(self.federated_query_max_rows),
+                    ))
+                    .await
+                    .map_err(|err| {
+                        tracing::error!("staging {} failed: {:?}", table.name, err);
+                        err
+                    })?;
+
+                    let staged_name = ObjectName(vec![
+                        Ident::with_quote('"', staged_schema.clone()),
+                        Ident::new(staged_table),
+                    ]);
+                    visit_relations_mut(&mut stmt, |relation| {
+                        if *relation == table.name {
+                            *relation = staged_name.clone();
+                        }
+                        ControlFlow::<()>::Continue(())
+                    });
+                    staged[idx] = Some(staged_name);
+                }
+                self.session_tables.lock().await.qualify(&mut stmt);
+
+                tracing::info!("handling federated query: {}", stmt);
+                let executor = self.catalog.lock().await.get_executor();
+                let res =
+                    Box::pin(self.execute_statement(executor, &stmt, None, None, result_format))
+                        .await;
+                if let Err(err) = &res {
+                    tracing::error!("federated query execution failed: {:?}", err);
+                }
+                res
+            }
+
+            NexusStatement::CreateTempTable {
+                table_name,
+                mut query,
+                assoc,
+            } => {
+                let executor = {
+                    let session_tables = self.session_tables.lock().await;
+                    if session_tables.contains(&table_name) {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42P07".to_owned(),
+                            format!("relation \"{}\" already exists", table_name),
+                        ))));
+                    }
+                    match assoc {
+                        QueryAssocation::Peer(peer) => {
+                            if session_tables.is_referenced(&query) {
+                                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                                    "ERROR".to_owned(),
+                                    "feature_not_supported".to_owned(),
+                                    "queries joining session tables with peer tables are not supported"
+                                        .to_owned(),
+                                ))));
+                            }
+                            tracing::info!(
+                                "staging session table {} from peer[{}] query: {}",
+                                table_name,
+                                peer.name,
+                                query
+                            );
+                            self.get_query_executor(&peer).await?
+                        }
+                        QueryAssocation::Catalog => {
+                            session_tables.qualify(&mut query);
+                            tracing::info!(
+                                "staging session table {} from catalog query: {}",
+                                table_name,
+                                query
+                            );
+                            let catalog = self.catalog.lock().await;
+                            catalog.get_executor()
+                        }
+                    }
+                };
+
+                let res =
+                    Box::pin(self.stage_session_table(executor, &table_name, &query, None)).await;
+                match res {
+                    Ok(rows) => {
+                        self.session_tables.lock().await.insert(table_name);
+                        Ok(vec![Response::Execution(Tag::new_for_execution(
+                            "SELECT",
+                            Some(rows),
+                        ))])
+                    }
+                    Err(err) => {
+                        tracing::error!("staging session table failed: {:?}", err);
+                        Err(err)
                     }
-                };
-
-                let res = self.execute_statement(executor, &stmt, peer_holder).await;
-                // log the error if execution failed
-                if let Err(err) = &res {
-                    tracing::error!("query execution failed: {:?}", err);
                 }
-                res
             }
 
             NexusStatement::PeerCursor { stmt, cursor } => {
@@ -534,20 +3376,423 @@ impl NexusBackend {
                             catalog.get_executor()
                         }
                         Some(peer) => self.get_peer_executor(peer).await.map_err(|err| {
-                            PgWireError::ApiError(Box::new(PgError::Internal {
-                                err_msg: format!("unable to get peer executor: {:?}", err),
-                            }))
+                            peer_error(format!("unable to get peer executor: {:?}", err))
                         })?,
                     }
                 };
 
-                self.execute_statement(executor, &stmt, peer_holder).await
+                Box::pin(self.execute_statement(executor, &stmt, peer_holder, None, result_format))
+                    .await
+            }
+
+            NexusStatement::SetVariable { name, value } => {
+                if let (DEFAULT_PEER_VARIABLE, Some(peer_name)) = (name.as_str(), &value) {
+                    let peer_name = peer_name.to_lowercase();
+                    let catalog = self.catalog.lock().await;
+                    let peers = catalog.get_peers().await.map_err(|err| {
+                        PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!("unable to get peers: {:?}", err),
+                        }))
+                    })?;
+                    if !peer_name.is_empty() && !peers.contains_key(&peer_name) {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "42704".to_owned(),
+                            format!("peer {} does not exist", peer_name),
+                        ))));
+                    }
+                }
+                self.query_parser.set_variable(name, value);
+                Ok(vec![Response::Execution(Tag::new_for_execution(
+                    "SET", None,
+                ))])
+            }
+
+            NexusStatement::SetSetting { name, value } => {
+                self.settings
+                    .lock()
+                    .await
+                    .set(&name, value.clone())
+                    .map_err(|err| {
+                        PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "22023".to_owned(),
+                            err.to_string(),
+                        )))
+                    })?;
+                if session_settings::is_forwarded(&name) {
+                    let executors: Vec<Arc<Box<dyn QueryExecutor>>> = self
+                        .executors
+                        .iter()
+                        .map(|executor| executor.value().clone())
+                        .collect();
+                    for executor in executors {
+                        executor
+                            .set_setting(&name, value.as_deref())
+                            .await
+                            .map_err(|err| {
+                                PgWireError::ApiError(Box::new(PgError::Internal {
+                                    err_msg: format!("unable to set {} on a peer: {:?}", name, err),
+                                }))
+                            })?;
+                    }
+                }
+                Ok(vec![Response::Execution(Tag::new_for_execution(
+                    "SET", None,
+                ))])
+            }
+
+            NexusStatement::ShowSetting { name } => {
+                let value = match name.strip_prefix("peerdb.") {
+                    Some(DEFAULT_PEER_VARIABLE) => {
+                        self.query_parser.default_peer().unwrap_or_default()
+                    }
+                    Some(variable) => {
+                        self.query_parser.get_variable(variable).ok_or_else(|| {
+                            PgWireError::UserError(Box::new(ErrorInfo::new(
+                                "ERROR".to_owned(),
+                                "42704".to_owned(),
+                                format!("variable {} is not set", name),
+                            )))
+                        })?
+                    }
+                    None => self.settings.lock().await.get(&name),
+                };
+                Ok(vec![records_to_query_response(session_settings::records(
+                    &name, value,
+                ))?])
+            }
+
+            NexusStatement::BuiltinFunction { name, args, column } => {
+                let value = Box::pin(self.builtin_function_value(&name, &args)).await?;
+                let records = builtin_functions::records(&name, &column, value);
+                Ok(vec![records_to_query_response(Records {
+                    schema: with_result_format(&records.schema, result_format),
+                    records: records.records,
+                })?])
+            }
+
+            NexusStatement::SelectConstant { value, column } => {
+                let records = builtin_functions::constant_records(&column, value);
+                Ok(vec![records_to_query_response(Records {
+                    schema: with_result_format(&records.schema, result_format),
+                    records: records.records,
+                })?])
+            }
+
+            NexusStatement::CopyOut {
+                mut stmt,
+                assoc,
+                format,
+            } => {
+                let executor = match assoc {
+                    QueryAssocation::Peer(peer) => {
+                        tracing::info!("copying out of peer[{}]: {}", peer.name, stmt);
+                        self.get_query_executor(&peer).await?
+                    }
+                    QueryAssocation::Catalog => {
+                        self.session_tables.lock().await.qualify(&mut stmt);
+                        tracing::info!("copying out of catalog: {}", stmt);
+                        let catalog = self.catalog.lock().await;
+                        catalog.get_executor()
+                    }
+                };
+                let (schema, rows): (SchemaRef, BoxStream<'static, PgWireResult<Record>>) =
+                    match executor.execute(&stmt).await? {
+                        QueryOutput::Stream(stream) => (stream.schema(), stream.boxed()),
+                        QueryOutput::Records(records) => (
+                            records.schema,
+                            stream::iter(records.records.into_iter().map(Ok)).boxed(),
+                        ),
+                        _ => {
+                            return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: "COPY TO STDOUT query returned no rows".to_owned(),
+                            })))
+                        }
+                    };
+                match self.copy_requests() {
+                    Some(mut copy_requests) => {
+                        let rows =
+                            copy_out::write(&mut copy_requests, schema, rows, format).await?;
+                        Ok(vec![Response::Execution(Tag::new_for_execution(
+                            "COPY",
+                            Some(rows),
+                        ))])
+                    }
+                    None => Ok(vec![copy_out::response(schema, rows, format)?]),
+                }
+            }
+
+            NexusStatement::Transaction { stmt, peer } => {
+                Box::pin(self.execute_transaction_statement(&stmt, peer)).await
+            }
+
+            NexusStatement::Notification { stmt, peer } => {
+                Box::pin(self.execute_notification_statement(&stmt, peer)).await
             }
 
             NexusStatement::Empty => Ok(vec![Response::EmptyQuery]),
         }
     }
 
+    // BEGIN opens a transaction on the session's connection to the peer, the
+    // statements on the peer run in it until COMMIT or ROLLBACK ends it. like
+    // postgres, ending a transaction when there is none is not an error.
+    async fn execute_transaction_statement<'a>(
+        &self,
+        stmt: &Statement,
+        peer: Box<Peer>,
+    ) -> PgWireResult<Vec<Response<'a>>> {
+        let tag = match stmt {
+            Statement::StartTransaction { .. } => "BEGIN",
+            Statement::Commit { .. } => "COMMIT",
+            _ => "ROLLBACK",
+        };
+        if tag == "BEGIN" {
+            if self.transaction_peer.lock().await.is_some() {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "25001".to_owned(),
+                    "there is already a transaction in progress".to_owned(),
+                ))));
+            }
+            let executor = self.get_query_executor(&peer).await?;
+            tracing::info!("beginning transaction on peer[{}]", peer.name);
+            executor.execute(stmt).await?;
+            *self.transaction_peer.lock().await = Some(peer);
+        } else {
+            // the transaction is over even when ending it fails, postgres
+            // rolls back a transaction that failed to commit.
+            let transaction_peer = self.transaction_peer.lock().await.take();
+            if let Some(peer) = transaction_peer {
+                let executor = self.get_query_executor(&peer).await?;
+                tracing::info!("ending transaction on peer[{}]: {}", peer.name, stmt);
+                executor.execute(stmt).await?;
+            }
+        }
+        Ok(vec![Response::Execution(Tag::new_for_execution(tag, None))])
+    }
+
+    // LISTEN and UNLISTEN run on the session's connection to the peer, which
+    // the notifications of the channels arrive on, so a session listens on one
+    // peer at a time. NOTIFY runs on the peer like any other statement.
+    async fn execute_notification_statement<'a>(
+        &self,
+        stmt: &NotificationStatement,
+        peer: Box<Peer>,
+    ) -> PgWireResult<Vec<Response<'a>>> {
+        let mut listening = self.listening.lock().await;
+        let tag = match stmt {
+            NotificationStatement::Listen { .. } => {
+                if let Some(listening) = listening.as_ref() {
+                    if listening.peer.name != peer.name {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "0A000".to_owned(),
+                            format!(
+                                "cannot listen on peer {}, the session listens on peer {}",
+                                peer.name, listening.peer.name
+                            ),
+                        ))));
+                    }
+                }
+                "LISTEN"
+            }
+            NotificationStatement::Unlisten { .. } => "UNLISTEN",
+            NotificationStatement::Notify { .. } => "NOTIFY",
+        };
+        let executor = self.get_query_executor(&peer).await?;
+        tracing::info!("running on peer[{}]: {}", peer.name, stmt);
+        executor.execute_raw(&stmt.to_string()).await?;
+        match stmt {
+            NotificationStatement::Listen { channel } => {
+                listening
+                    .get_or_insert_with(|| notifications::Listening::new(peer))
+                    .listen(channel);
+            }
+            NotificationStatement::Unlisten { channel } => {
+                let stopped = match listening.as_mut() {
+                    Some(listening) => listening.unlisten(channel),
+                    None => false,
+                };
+                if stopped {
+                    *listening = None;
+                }
+            }
+            NotificationStatement::Notify { .. } => {}
+        }
+        drop(listening);
+        self.listening_changed.notify_waiters();
+        Ok(vec![Response::Execution(Tag::new_for_execution(tag, None))])
+    }
+
+    // rewrite a catalog query on the pg_catalog and information_schema
+    // relations BI tools browse to read the peers and their tables instead,
+    // see `catalog_emulation`. Peers whose tables can't be listed are shown
+    // without tables.
+    async fn emulate_catalog(&self, stmt: &mut Statement) -> PgWireResult<()> {
+        if !catalog_emulation::is_emulated(stmt) {
+            return Ok(());
+        }
+        let peers = self.catalog.lock().await.get_peers().await.map_err(|err| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to list peers: {:?}", err),
+            }))
+        })?;
+        let mut peers: Vec<Peer> = peers.into_values().collect();
+        peers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let lists_tables = catalog_emulation::lists_tables(stmt);
+        let lists_columns = catalog_emulation::lists_columns(stmt);
+        let mut emulated_peers = Vec::with_capacity(peers.len());
+        for peer in peers {
+            let mut tables = vec![];
+            let mut columns = vec![];
+            if let Some(connector) = self.connectors.get(&peer).filter(|_| lists_tables) {
+                match connector.describe_tables(&peer, None).await {
+                    Ok(peer_tables) => tables = peer_tables,
+                    Err(err) => tracing::warn!(
+                        "unable to list the tables of peer {} for a catalog query: {:?}",
+                        peer.name,
+                        err
+                    ),
+                }
+                if lists_columns && !tables.is_empty() {
+                    match connector.describe_columns(&peer, None).await {
+                        Ok(peer_columns) => columns = peer_columns,
+                        Err(err) => tracing::warn!(
+                            "unable to list the columns of peer {} for a catalog query: {:?}",
+                            peer.name,
+                            err
+                        ),
+                    }
+                }
+            }
+            emulated_peers.push(catalog_emulation::EmulatedPeer {
+                name: peer.name,
+                tables,
+                columns,
+            });
+        }
+
+        catalog_emulation::emulate(stmt, &emulated_peers).map_err(|err| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to emulate catalog relations: {:?}", err),
+            }))
+        })
+    }
+
+    // the result of one of the functions nexus answers itself: the version of
+    // the server, or of the postgres it poses as, the seconds since a mirror last synced, null if it never
+    // did, or the type of a peer.
+    async fn builtin_function_value(&self, name: &str, args: &[String]) -> PgWireResult<Value> {
+        match (name, args) {
+            ("peerdb_mirror_lag", [flow_job_name]) => {
+                if self.flow_handler.is_none() {
+                    return Err(PgWireError::ApiError(Box::new(PgError::Internal {
+                        err_msg: "flow service is not configured".to_owned(),
+                    })));
+                }
+                let flow_job_name = flow_job_name.to_lowercase();
+                {
+                    let catalog = self.catalog.lock().await;
+                    if Self::check_for_mirror(&catalog, &flow_job_name)
+                        .await?
+                        .is_none()
+                    {
+                        return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                            "ERROR".to_owned(),
+                            "error".to_owned(),
+                            format!("no such mirror: {:?}", flow_job_name),
+                        ))));
+                    }
+                }
+
+                let mut flow_handler = self.flow_handler.as_ref().unwrap().lock().await;
+                let status = flow_handler
+                    .get_mirror_status(&flow_job_name)
+                    .await
+                    .map_err(|err| {
+                        PgWireError::ApiError(Box::new(PgError::Internal {
+                            err_msg: format!("unable to get status of mirror: {:?}", err),
+                        }))
+                    })?;
+                let now_millis = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|now| now.as_millis() as i64)
+                    .unwrap_or_default();
+                Ok(show_mirrors::last_sync_time(&status)
+                    .map(|last_sync_time| {
+                        let lag_millis = (now_millis - last_sync_time.timestamp_millis()).max(0);
+                        Value::Double(lag_millis as f64 / 1000.0)
+                    })
+                    .unwrap_or(Value::Null))
+            }
+            ("peerdb_peer_type", [peer_name]) => {
+                let peer_name = peer_name.to_lowercase();
+                let peers = self.catalog.lock().await.get_peers().await.map_err(|err| {
+                    PgWireError::ApiError(Box::new(PgError::Internal {
+                        err_msg: format!("unable to list peers: {:?}", err),
+                    }))
+                })?;
+                let peer = peers.get(&peer_name).ok_or_else(|| {
+                    PgWireError::UserError(Box::new(ErrorInfo::new(
+                        "ERROR".to_owned(),
+                        "42704".to_owned(),
+                        format!("peer {} does not exist", peer_name),
+                    )))
+                })?;
+                let peer_type = DbType::from_i32(peer.r#type)
+                    .map(|db_type| db_type.as_str_name().to_owned())
+                    .unwrap_or_else(|| peer.r#type.to_string());
+                Ok(Value::Text(peer_type))
+            }
+            ("peerdb_connections", []) => Ok(Value::BigInt(self.connections.current() as i64)),
+            ("peerdb_peak_connections", []) => Ok(Value::BigInt(self.connections.peak() as i64)),
+            ("version", []) => Ok(Value::Text(builtin_functions::postgres_version())),
+            ("peerdb.rotate_encryption_key", [old_key, new_key]) => {
+                self.rotate_encryption_key(old_key, new_key).await
+            }
+            _ => Ok(Value::Text(builtin_functions::VERSION.to_owned())),
+        }
+    }
+
+    // rotates the master key of the catalog, reporting the progress of each
+    // table in a notice. the result summarizes the configs it rotated.
+    async fn rotate_encryption_key(&self, old_key: &str, new_key: &str) -> PgWireResult<Value> {
+        let parse_key = |which: &str, encoded: &str| {
+            MasterKey::from_base64(encoded).map_err(|err| {
+                PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "22023".to_owned(),
+                    format!("invalid {} key: {}", which, err),
+                )))
+            })
+        };
+        let old_key = parse_key("old", old_key)?;
+        let new_key = parse_key("new", new_key)?;
+
+        let catalog = self.catalog.lock().await;
+        let rotation = catalog
+            .rotate_master_key(&old_key, &new_key, |table, done, total| {
+                tracing::info!("rotated {} of {} configs in {}", done, total, table);
+                self.notice(format!(
+                    "rotated {} of {} configs in {}",
+                    done, total, table
+                ));
+            })
+            .await
+            .map_err(|err| {
+                PgWireError::ApiError(Box::new(PgError::Internal {
+                    err_msg: format!("unable to rotate the encryption key: {:?}", err),
+                }))
+            })?;
+        Ok(Value::Text(format!(
+            "rotated {} configs, encrypted {} stored in plaintext, verified {}",
+            rotation.rotated, rotation.encrypted, rotation.verified
+        )))
+    }
+
     async fn run_qrep_mirror(&self, qrep_flow_job: &QRepFlowJob) -> PgWireResult<String> {
         let catalog = self.catalog.lock().await;
 
@@ -593,84 +3838,518 @@ impl NexusBackend {
         Ok(workflow_id)
     }
 
-    async fn get_peer_executor(&self, peer: &Peer) -> anyhow::Result<Arc<Box<dyn QueryExecutor>>> {
-        if let Some(executor) = self.executors.get(&peer.name) {
-            return Ok(Arc::clone(executor.value()));
+    // the executor for a query routed to a peer, quarantined peers don't take queries.
+    async fn get_query_executor(&self, peer: &Peer) -> PgWireResult<Arc<Box<dyn QueryExecutor>>> {
+        if self.quarantined_peers.contains(&peer.name) {
+            return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                "ERROR".to_owned(),
+                "57P03".to_owned(),
+                format!(
+                    "peer {} is quarantined after failing health checks",
+                    peer.name
+                ),
+            ))));
         }
-
-        let executor = match &peer.config {
-            Some(Config::BigqueryConfig(ref c)) => {
-                let peer_name = peer.name.clone();
-                let executor =
-                    BigQueryQueryExecutor::new(peer_name, c, self.peer_connections.clone()).await?;
-                Arc::new(Box::new(executor) as Box<dyn QueryExecutor>)
+        if let Some(transaction_peer) = self.transaction_peer.lock().await.as_ref() {
+            if transaction_peer.name != peer.name {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "25000".to_owned(),
+                    format!(
+                        "cannot run statements on peer {} in a transaction on peer {}",
+                        peer.name, transaction_peer.name
+                    ),
+                ))));
             }
-            Some(Config::PostgresConfig(ref c)) => {
-                let peername = Some(peer.name.clone());
-                let executor = peer_postgres::PostgresQueryExecutor::new(peername, c).await?;
-                Arc::new(Box::new(executor) as Box<dyn QueryExecutor>)
+        }
+        self.get_peer_executor(peer)
+            .await
+            .map_err(|err| peer_error(format!("unable to get peer executor: {:?}", err)))
+    }
+
+    // the rows of a table of a federated query as its peer estimates them,
+    // None if the peer can't describe its tables or has no estimate.
+    async fn estimated_rows(&self, table: &FederatedTable) -> Option<i64> {
+        let connector = self.connectors.get(&table.peer)?;
+        let parts: Vec<&str> = table.name.0[1..]
+            .iter()
+            .map(|ident| ident.value.as_str())
+            .collect();
+        let schema = parts.len().checked_sub(2).map(|idx| parts[idx]);
+        let name = parts.join(".").to_lowercase();
+        let infos = connector
+            .describe_tables(&table.peer, schema)
+            .await
+            .map_err(|err| {
+                tracing::debug!("unable to estimate the rows of {}: {:?}", table.name, err);
+                err
+            })
+            .ok()?;
+        infos
+            .into_iter()
+            .find(|info| {
+                let info_name = info.name.to_lowercase();
+                info_name == name || info_name.ends_with(&format!(".{}", name))
+            })
+            .and_then(|info| info.estimated_rows)
+    }
+
+    // the conditions that cut the rows staged of a table of a federated
+    // query down to those that join with the tables already staged: its join
+    // key is one of the values of the key of a staged table, as long as it
+    // has no more than FEDERATED_JOIN_MAX_KEYS of them.
+    async fn federated_join_reductions(
+        &self,
+        table: &FederatedTable,
+        staged: &[Option<ObjectName>],
+    ) -> PgWireResult<Vec<Expr>> {
+        let mut reductions = vec![];
+        for key in &table.pushdown.join_keys {
+            let staged_name = match &staged[key.other] {
+                Some(staged_name) => staged_name,
+                None => continue,
+            };
+            let query = format!(
+                "SELECT DISTINCT {} FROM {} WHERE {} IS NOT NULL LIMIT {}",
+                key.other_column,
+                staged_name,
+                key.other_column,
+                FEDERATED_JOIN_MAX_KEYS + 1
+            );
+            let query = sqlparser::parser::Parser::parse_sql(&PostgreSqlDialect {}, &query)
+                .ok()
+                .and_then(|mut stmts| stmts.pop())
+                .ok_or_else(|| {
+                    PgWireError::ApiError(Box::new(PgError::Internal {
+                        err_msg: format!("invalid join key: {}", key.other_column),
+                    }))
+                })?;
+            let executor = self.catalog.lock().await.get_executor();
+            let records = match executor.execute(&query).await? {
+                QueryOutput::Stream(stream) => stream
+                    .collect::<Vec<_>>()
+                    .await
+                    .into_iter()
+                    .collect::<PgWireResult<Vec<_>>>()?,
+                QueryOutput::Records(records) => records.records,
+                _ => continue,
+            };
+            if records.len() > FEDERATED_JOIN_MAX_KEYS {
+                continue;
             }
-            Some(Config::SnowflakeConfig(ref c)) => {
-                let executor = peer_snowflake::SnowflakeQueryExecutor::new(c).await?;
-                Arc::new(Box::new(executor) as Box<dyn QueryExecutor>)
+            let keys = records
+                .iter()
+                .map(|record| record.values.first().and_then(join_key_literal))
+                .collect::<Option<Vec<_>>>();
+            match keys {
+                // none of the rows of the staged table join with any row.
+                Some(keys) if keys.is_empty() => {
+                    reductions.push(Expr::Value(sqlparser::ast::Value::Boolean(false)))
+                }
+                Some(keys) => reductions.push(Expr::InList {
+                    expr: Box::new(Expr::Identifier(key.column.clone())),
+                    list: keys.into_iter().map(Expr::Value).collect(),
+                    negated: false,
+                }),
+                None => {}
             }
-            _ => {
-                panic!("peer type not supported: {:?}", peer)
+        }
+        Ok(reductions)
+    }
+
+    // runs a query and stages its result in a table of the session in the
+    // catalog, returns the number of rows. fails once the query returns more
+    // than max_rows rows, if set.
+    async fn stage_session_table(
+        &self,
+        executor: Arc<Box<dyn QueryExecutor>>,
+        table_name: &str,
+        query: &sqlparser::ast::Statement,
+        max_rows: Option<usize>,
+    ) -> PgWireResult<usize> {
+        let internal_err = |err: anyhow::Error| {
+            PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to stage session table: {:?}", err),
+            }))
+        };
+
+        let (schema, records): (SchemaRef, BoxStream<'static, PgWireResult<Record>>) =
+            match executor.execute(query).await? {
+                QueryOutput::Stream(stream) => (stream.schema(), stream.boxed()),
+                QueryOutput::Records(records) => (
+                    records.schema,
+                    stream::iter(records.records.into_iter().map(Ok)).boxed(),
+                ),
+                _ => {
+                    return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                        "ERROR".to_owned(),
+                        "42601".to_owned(),
+                        "CREATE TEMP TABLE ... AS requires a query that returns rows".to_owned(),
+                    ))))
+                }
+            };
+
+        let columns: Vec<(String, String)> = schema
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    field.name().to_owned(),
+                    session_column_type(field.datatype()),
+                )
+            })
+            .collect();
+        let session_schema = {
+            let mut session_tables = self.session_tables.lock().await;
+            session_tables.set_schema_created();
+            session_tables.schema().to_owned()
+        };
+        {
+            let catalog = self.catalog.lock().await;
+            catalog
+                .create_session_table(&session_schema, table_name, &columns)
+                .await
+                .map_err(internal_err)?;
+        }
+
+        // the catalog is locked per batch rather than for the whole result,
+        // so the session's other requests aren't held up by a slow peer.
+        let mut rows = 0;
+        let mut batches = records.chunks(SESSION_TABLE_BATCH_SIZE);
+        while let Some(batch) = batches.next().await {
+            let batch = batch
+                .into_iter()
+                .map(|record| record.map(|record| record_to_json(&columns, &record)))
+                .collect::<PgWireResult<Vec<_>>>()?;
+            let catalog = self.catalog.lock().await;
+            rows += catalog
+                .insert_session_table_rows(
+                    &session_schema,
+                    table_name,
+                    &serde_json::Value::Array(batch),
+                )
+                .await
+                .map_err(internal_err)? as usize;
+            if let Some(max_rows) = max_rows.filter(|max_rows| rows > *max_rows) {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "54000".to_owned(),
+                    format!("query returned more than {} rows", max_rows),
+                ))));
             }
+        }
+        Ok(rows)
+    }
+
+    // drops the tables the session staged, once the session ends.
+    async fn drop_session_tables(&self) {
+        let session_tables = self.session_tables.lock().await;
+        if !session_tables.schema_created() {
+            return;
+        }
+        let catalog = self.catalog.lock().await;
+        if let Err(err) = catalog.drop_session_schema(session_tables.schema()).await {
+            tracing::error!(
+                "unable to drop session schema {}: {:?}",
+                session_tables.schema(),
+                err
+            );
+        }
+    }
+
+    async fn get_peer_executor(&self, peer: &Peer) -> anyhow::Result<Arc<Box<dyn QueryExecutor>>> {
+        if let Some(executor) = self.executors.get(&peer.name) {
+            return Ok(Arc::clone(executor.value()));
+        }
+
+        let connector = self
+            .connectors
+            .get(peer)
+            .ok_or_else(|| anyhow::anyhow!("peer type not supported: {:?}", peer.r#type))?;
+        let peer_pool = self
+            .peer_pool
+            .as_ref()
+            .filter(|_| connector.capabilities().pooled);
+        let version = self.peer_version(&peer.name).await?;
+        let executor = match peer_pool.and_then(|pool| pool.checkout(&peer.name, version)) {
+            Some(executor) => executor,
+            None => Arc::new(connector.connect(peer).await?),
         };
+        if peer_pool.is_some() {
+            self.pooled_executors.insert(peer.name.clone());
+        }
+        // the connection runs the queries of the session with its settings.
+        let forwarded = self.settings.lock().await.forwarded();
+        for (name, value) in forwarded {
+            executor.set_setting(&name, Some(&value)).await?;
+        }
 
         self.executors
             .insert(peer.name.clone(), Arc::clone(&executor));
+        self.executor_versions.insert(peer.name.clone(), version);
         Ok(executor)
     }
+
+    async fn execute_simple_statement<'a, C>(
+        &self,
+        client: &C,
+        nexus_stmt: NexusStatement,
+        parameters: Option<&[Option<String>]>,
+        result_format: &Format,
+    ) -> PgWireResult<Vec<Response<'a>>>
+    where
+        C: ClientInfo + Unpin + Send + Sync,
+    {
+        self.apply_startup_settings(client).await?;
+        // statements were analyzed against the peers and the default peer,
+        // which these may change.
+        if matches!(
+            nexus_stmt,
+            NexusStatement::PeerDDL { .. } | NexusStatement::SetVariable { .. }
+        ) {
+            self.prepared_statements.clear();
+        }
+        let audit_entry = audit::AuditEntry::new(self.audit_log, session_user(client), &nexus_stmt);
+        *self.audit_entry.lock().unwrap() = audit_entry.clone();
+        let cancellable = !changes_catalog(&nexus_stmt);
+        let responses = self
+            .with_request_timeout(cancellable, async {
+                self.check_access(client, &nexus_stmt).await?;
+                let creator = self.privilege_for_creator(client, &nexus_stmt).await?;
+                let permit = self.acquire_query_permit(client, &nexus_stmt).await?;
+                *self.query_permit.lock().unwrap() = permit.map(Arc::new);
+                // the future of a statement is as large as that of the
+                // largest statement nexus runs, it is kept on the heap rather
+                // than on the stack of the worker polling it.
+                let responses =
+                    Box::pin(self.handle_query(nexus_stmt, parameters, result_format)).await?;
+
+                if let Some((user_name, privilege)) = creator {
+                    let catalog = self.catalog.lock().await;
+                    catalog
+                        .grant_privilege(&privilege, &user_name)
+                        .await
+                        .map_err(|err| {
+                            PgWireError::ApiError(Box::new(PgError::Internal {
+                                err_msg: format!(
+                                    "created, but unable to grant {} to {}: {:?}",
+                                    privilege, user_name, err
+                                ),
+                            }))
+                        })?;
+                }
+                Ok(responses)
+            })
+            .await;
+        self.audit_entry.lock().unwrap().take();
+        // the rows streamed after the statement returns hold its slots until
+        // they are sent.
+        self.query_permit.lock().unwrap().take();
+        self.request_deadline.lock().unwrap().take();
+        if let (Some(entry), Err(err)) = (&audit_entry, &responses) {
+            entry.fail(err);
+        }
+        responses
+    }
 }
 
 #[async_trait]
 impl SimpleQueryHandler for NexusBackend {
-    async fn do_query<'a, C>(&self, _client: &C, sql: &'a str) -> PgWireResult<Vec<Response<'a>>>
+    async fn do_query<'a, C>(&self, client: &C, sql: &'a str) -> PgWireResult<Vec<Response<'a>>>
     where
         C: ClientInfo + Unpin + Send + Sync,
     {
-        let parsed = self.query_parser.parse_simple_sql(sql)?;
-        let nexus_stmt = parsed.statement;
-        self.handle_query(nexus_stmt).await
+        self.drop_stale_executors().await?;
+        let stmts = self.query_parser.split_sql(sql)?;
+        match stmts.as_slice() {
+            [] => {
+                return self
+                    .execute_simple_statement(
+                        client,
+                        NexusStatement::Empty,
+                        None,
+                        &Format::UnifiedText,
+                    )
+                    .await
+            }
+            [stmt] => {
+                let nexus_stmt = self.query_parser.analyze(stmt)?;
+                self.copy_ready.store(true, Ordering::SeqCst);
+                let responses = self
+                    .execute_simple_statement(client, nexus_stmt, None, &Format::UnifiedText)
+                    .await;
+                self.copy_ready.store(false, Ordering::SeqCst);
+                return responses;
+            }
+            _ => {}
+        }
+
+        // scripts send several statements in one query, they run in order and
+        // each gets its own command completion. like postgres, the statements
+        // after one that fails are skipped.
+        let mut responses = vec![];
+        for stmt in stmts {
+            let result = match self.query_parser.analyze(&stmt) {
+                Ok(nexus_stmt) => {
+                    self.execute_simple_statement(client, nexus_stmt, None, &Format::UnifiedText)
+                        .await
+                }
+                Err(err) => Err(err),
+            };
+            match result {
+                Ok(stmt_responses) => responses.extend(stmt_responses),
+                Err(err) => {
+                    responses.push(Response::Error(Box::new(error_info(err))));
+                    break;
+                }
+            }
+        }
+        Ok(responses)
+    }
+}
+
+// the type of a session table column, that of the query's field for the types
+// postgres reads back from their json representation. binary values, which
+// are base64 in json, and intervals, which are in microseconds, are kept as text.
+// the flow server reports the common reasons a mirror can't be created with a
+// category, they are returned to the client as distinct SQLSTATEs along with
+// the detail and hint of the error, so that automation can branch on them.
+fn mirror_creation_error(err: anyhow::Error) -> PgWireError {
+    let mirror_err = match MirrorError::from_error(&err) {
+        Some(mirror_err) => mirror_err,
+        None => {
+            return PgWireError::ApiError(Box::new(PgError::Internal {
+                err_msg: format!("unable to submit job: {:?}", err),
+            }))
+        }
+    };
+    let code = match mirror_err.category.as_str() {
+        // object_not_in_prerequisite_state
+        "slot_creation" => "55000",
+        // invalid_authorization_specification
+        "destination_auth" => "28000",
+        // datatype_mismatch
+        "type_mismatch" => "42804",
+        _ => "XX000",
+    };
+    let mut info = ErrorInfo::new("ERROR".to_owned(), code.to_owned(), mirror_err.message);
+    info.set_detail(mirror_err.detail);
+    info.set_hint(mirror_err.hint);
+    PgWireError::UserError(Box::new(info))
+}
+
+fn session_user<C: ClientInfo>(client: &C) -> String {
+    client
+        .metadata()
+        .get(METADATA_USER)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn insufficient_privilege(message: String) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        "42501".to_owned(),
+        message,
+    )))
+}
+
+// a flow job as its statement gives it, without the tables of the schemas it
+// mirrors as a whole, which change as tables are created in them.
+fn given_flow_job(flow_job: &FlowJob) -> FlowJob {
+    let mut flow_job = flow_job.clone();
+    let schemas: Vec<String> = flow_job
+        .schema_mappings
+        .iter()
+        .map(|mapping| format!("{}.", analyzer::quote_identifier(&mapping.source_schema)))
+        .collect();
+    flow_job.table_mappings.retain(|mapping| {
+        !schemas
+            .iter()
+            .any(|schema| mapping.source_table_identifier.starts_with(schema))
+    });
+    flow_job
+}
+
+// the statements that change the catalog or the mirrors of the flow server,
+// rather than only the data of peers.
+fn changes_catalog(stmt: &NexusStatement) -> bool {
+    match stmt {
+        NexusStatement::PeerDDL { ddl, .. } => {
+            !matches!(ddl.as_ref(), PeerDDL::ExecuteOnPeer { .. }) && access_control::is_write(stmt)
+        }
+        NexusStatement::BuiltinFunction { .. } => access_control::is_write(stmt),
+        _ => false,
+    }
+}
+
+fn resource_group_does_not_exist(name: &str) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        "42704".to_owned(),
+        format!("resource group {} does not exist", name),
+    )))
+}
+
+// the error of a statement that writes on a read-only server or by a
+// read-only user, read_only_sql_transaction like a write in a read-only
+// transaction of postgres.
+fn read_only_error(message: String) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        "25006".to_owned(),
+        message,
+    )))
+}
+
+// the error response for an error of a statement in a multi-statement query.
+fn error_info(err: PgWireError) -> ErrorInfo {
+    match err {
+        PgWireError::UserError(info) => *info,
+        err => ErrorInfo::new("ERROR".to_owned(), "XX000".to_owned(), err.to_string()),
     }
 }
 
-fn parameter_to_string(portal: &Portal<NexusParsedStatement>, idx: usize) -> PgWireResult<String> {
-    // the index is managed from portal's parameters count so it's safe to
-    // unwrap here.
-    let param_type = portal.statement().parameter_types().get(idx).unwrap();
-    match param_type {
-        &Type::VARCHAR | &Type::TEXT => Ok(format!(
-            "'{}'",
-            portal.parameter::<String>(idx)?.as_deref().unwrap_or("")
-        )),
-        &Type::BOOL => Ok(portal
-            .parameter::<bool>(idx)?
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "".to_owned())),
-        &Type::INT4 => Ok(portal
-            .parameter::<i32>(idx)?
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "".to_owned())),
-        &Type::INT8 => Ok(portal
-            .parameter::<i64>(idx)?
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "".to_owned())),
-        &Type::FLOAT4 => Ok(portal
-            .parameter::<f32>(idx)?
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "".to_owned())),
-        &Type::FLOAT8 => Ok(portal
-            .parameter::<f64>(idx)?
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "".to_owned())),
-        _ => Err(PgWireError::UserError(Box::new(ErrorInfo::new(
-            "ERROR".to_owned(),
-            "22023".to_owned(),
-            "unsupported_parameter_value".to_owned(),
-        )))),
+fn session_column_type(datatype: &Type) -> String {
+    let name = datatype.name();
+    let (element, array) = match name.strip_prefix('_') {
+        Some(element) => (element, "[]"),
+        None => (name, ""),
+    };
+    let element = match element {
+        "bytea" | "interval" => "text",
+        _ => element,
+    };
+    format!("{}{}", element, array)
+}
+
+fn record_to_json(columns: &[(String, String)], record: &Record) -> serde_json::Value {
+    let row = columns
+        .iter()
+        .zip(record.values.iter())
+        .map(|((name, _), value)| (name.clone(), value.to_serde_json_value()))
+        .collect();
+    serde_json::Value::Object(row)
+}
+
+// the literal of a join key of a staged table, for the join keys a table of a
+// federated query is staged with. None for the types a peer may not compare
+// with the literal the way the catalog did.
+fn join_key_literal(value: &Value) -> Option<sqlparser::ast::Value> {
+    let number = |n: String| Some(sqlparser::ast::Value::Number(n, false));
+    match value {
+        Value::TinyInt(n) => number(n.to_string()),
+        Value::SmallInt(n) => number(n.to_string()),
+        Value::Integer(n) => number(n.to_string()),
+        Value::BigInt(n) => number(n.to_string()),
+        Value::Oid(n) => number(n.to_string()),
+        Value::Numeric(n) => number(n.to_string()),
+        Value::Char(c) => Some(sqlparser::ast::Value::SingleQuotedString(c.to_string())),
+        Value::VarChar(s) | Value::Text(s) => {
+            Some(sqlparser::ast::Value::SingleQuotedString(s.clone()))
+        }
+        Value::Uuid(uuid) => Some(sqlparser::ast::Value::SingleQuotedString(uuid.to_string())),
+        _ => None,
     }
 }
 
@@ -690,25 +4369,28 @@ impl ExtendedQueryHandler for NexusBackend {
 
     async fn do_query<'a, C>(
         &self,
-        _client: &mut C,
+        client: &mut C,
         portal: &'a Portal<Self::Statement>,
         _max_rows: usize,
     ) -> PgWireResult<Response<'a>>
     where
         C: ClientInfo + Unpin + Send + Sync,
     {
+        self.shutdown.check()?;
+        let _running = self.running_queries.start();
         let stmt = portal.statement().statement();
-        tracing::info!("[eqp] do_query: {}", stmt.query);
-
-        // manually replace variables in prepared statement
-        let mut sql = stmt.query.clone();
-        for i in 0..portal.parameter_len() {
-            sql = sql.replace(&format!("${}", i + 1), &parameter_to_string(portal, i)?);
-        }
+        tracing::info!("[eqp] do_query: {}", audit::describe(&stmt.statement));
 
-        let parsed = self.query_parser.parse_simple_sql(&sql)?;
-        let nexus_stmt = parsed.statement;
-        let result = self.handle_query(nexus_stmt).await?;
+        self.drop_stale_executors().await?;
+        let (nexus_stmt, parameters) = self.bind_portal(portal)?;
+        let result = self
+            .execute_simple_statement(
+                &*client,
+                nexus_stmt,
+                parameters.as_deref(),
+                portal.result_column_format(),
+            )
+            .await?;
         if result.is_empty() {
             Ok(Response::EmptyQuery)
         } else {
@@ -716,18 +4398,89 @@ impl ExtendedQueryHandler for NexusBackend {
         }
     }
 
+    // like the default, except that the error of a portal is sent in place of
+    // its results rather than ending the extended query, and the portals the
+    // client pipelined after it are skipped until it syncs, like postgres
+    // does. the results are only flushed when the client syncs or flushes, so
+    // that pipelining clients get those of several portals in one round trip.
+    async fn on_execute<C>(&self, client: &mut C, message: Execute) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send + Sync,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        if self.pipeline_aborted.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        self.shutdown.check()?;
+        let portal_name = message.name().as_deref().unwrap_or(DEFAULT_NAME);
+        let portal = match self.portal_store.get_portal(portal_name) {
+            Some(portal) => portal,
+            None => return Err(PgWireError::PortalNotFound(portal_name.to_owned())),
+        };
+
+        let result = ExtendedQueryHandler::do_query(
+            self,
+            client,
+            portal.as_ref(),
+            *message.max_rows() as usize,
+        )
+        .await;
+        self.send_peer_notices(client).await?;
+        let err = match result {
+            Ok(Response::EmptyQuery) => {
+                client
+                    .feed(PgWireBackendMessage::EmptyQueryResponse(
+                        EmptyQueryResponse::new(),
+                    ))
+                    .await?;
+                return Ok(());
+            }
+            Ok(Response::Query(results)) => {
+                return send_query_response(client, results, false).await
+            }
+            Ok(Response::Execution(tag)) => return send_execution_response(client, tag).await,
+            Ok(Response::Error(err)) => *err,
+            Err(err) => error_info(err),
+        };
+        self.pipeline_aborted.store(true, Ordering::SeqCst);
+        client
+            .feed(PgWireBackendMessage::ErrorResponse(err.into()))
+            .await?;
+        Ok(())
+    }
+
+    async fn on_sync<C>(&self, client: &mut C, _message: PgSync) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send + Sync,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        self.pipeline_aborted.store(false, Ordering::SeqCst);
+        self.release_peer_executors().await;
+        client
+            .feed(PgWireBackendMessage::ReadyForQuery(ReadyForQuery::new(
+                READY_STATUS_IDLE,
+            )))
+            .await?;
+        client.flush().await?;
+        Ok(())
+    }
+
     async fn do_describe<C>(
         &self,
-        _client: &mut C,
+        client: &mut C,
         target: StatementOrPortal<'_, Self::Statement>,
     ) -> PgWireResult<DescribeResponse>
     where
         C: ClientInfo + Unpin + Send + Sync,
     {
-        let (param_types, stmt, _format) = match target {
+        // the columns of a statement are described as the text they are sent
+        // in by default, and those of a portal in the format they are bound to.
+        let (param_types, stmt, format) = match target {
             StatementOrPortal::Statement(stmt) => {
-                let param_types = Some(stmt.parameter_types().clone());
-                (param_types, stmt.statement(), &Format::UnifiedBinary)
+                let param_types = Some(stmt.statement().parameter_types.clone());
+                (param_types, stmt.statement(), &Format::UnifiedText)
             }
             StatementOrPortal::Portal(portal) => (
                 None,
@@ -736,65 +4489,81 @@ impl ExtendedQueryHandler for NexusBackend {
             ),
         };
 
-        tracing::info!("[eqp] do_describe: {}", stmt.query);
         let stmt = &stmt.statement;
+        tracing::info!("[eqp] do_describe: {}", audit::describe(stmt));
+        self.check_access(&*client, stmt).await?;
         match stmt {
-            NexusStatement::PeerDDL { .. } => Ok(DescribeResponse::no_data()),
+            NexusStatement::PeerDDL { ddl, .. } => match ddl.as_ref() {
+                PeerDDL::ShowPeers => Ok(DescribeResponse::new(
+                    param_types,
+                    show_peers::schema().fields.clone(),
+                )),
+                PeerDDL::ShowPeerTables { .. } => Ok(DescribeResponse::new(
+                    param_types,
+                    show_tables::schema().fields.clone(),
+                )),
+                PeerDDL::ShowMirrors => Ok(DescribeResponse::new(
+                    param_types,
+                    show_mirrors::schema().fields.clone(),
+                )),
+                PeerDDL::DescribeMirror { .. } => Ok(DescribeResponse::new(
+                    param_types,
+                    describe_mirror::schema().fields.clone(),
+                )),
+                PeerDDL::ExplainMirror { .. } => Ok(DescribeResponse::new(
+                    param_types,
+                    explain_mirror::schema().fields.clone(),
+                )),
+                PeerDDL::ValidatePeer { .. } => Ok(DescribeResponse::new(
+                    param_types,
+                    validate_peer::schema().fields.clone(),
+                )),
+                _ => Ok(DescribeResponse::no_data()),
+            },
             NexusStatement::PeerCursor { .. } => Ok(DescribeResponse::no_data()),
+            NexusStatement::CreateTempTable { .. } => Ok(DescribeResponse::no_data()),
+            // describing a federated query would stage the tables it reads.
+            NexusStatement::FederatedQuery { .. } => Ok(DescribeResponse::no_data()),
+            NexusStatement::SetVariable { .. } => Ok(DescribeResponse::no_data()),
+            NexusStatement::SetSetting { .. } => Ok(DescribeResponse::no_data()),
+            NexusStatement::ShowSetting { name } => Ok(DescribeResponse::new(
+                param_types,
+                session_settings::schema(name).fields.clone(),
+            )),
+            NexusStatement::BuiltinFunction { name, column, .. } => Ok(DescribeResponse::new(
+                param_types,
+                with_result_format(&builtin_functions::schema(name, column), format)
+                    .fields
+                    .clone(),
+            )),
+            NexusStatement::SelectConstant { column, .. } => Ok(DescribeResponse::new(
+                param_types,
+                with_result_format(&builtin_functions::constant_schema(column), format)
+                    .fields
+                    .clone(),
+            )),
+            NexusStatement::CopyOut { .. } => Ok(DescribeResponse::new(
+                param_types,
+                copy_out::schema().fields.clone(),
+            )),
+            NexusStatement::Transaction { .. } => Ok(DescribeResponse::no_data()),
+            NexusStatement::Notification { .. } => Ok(DescribeResponse::no_data()),
             NexusStatement::Empty => Ok(DescribeResponse::no_data()),
             NexusStatement::PeerQuery { stmt, assoc } => {
                 let schema: Option<SchemaRef> = match assoc {
                     QueryAssocation::Peer(peer) => {
-                        // if the peer is of type bigquery, let us route the query to bq.
-                        match &peer.config {
-                            Some(Config::BigqueryConfig(_)) => {
-                                let executor =
-                                    self.get_peer_executor(peer).await.map_err(|err| {
-                                        PgWireError::ApiError(Box::new(PgError::Internal {
-                                            err_msg: format!(
-                                                "unable to get peer executor: {:?}",
-                                                err
-                                            ),
-                                        }))
-                                    })?;
-                                executor.describe(stmt).await?
-                            }
-                            Some(Config::PostgresConfig(_)) => {
-                                let executor =
-                                    self.get_peer_executor(peer).await.map_err(|err| {
-                                        PgWireError::ApiError(Box::new(PgError::Internal {
-                                            err_msg: format!(
-                                                "unable to get peer executor: {:?}",
-                                                err
-                                            ),
-                                        }))
-                                    })?;
-                                executor.describe(stmt).await?
-                            }
-                            Some(Config::SnowflakeConfig(_)) => {
-                                let executor =
-                                    self.get_peer_executor(peer).await.map_err(|err| {
-                                        PgWireError::ApiError(Box::new(PgError::Internal {
-                                            err_msg: format!(
-                                                "unable to get peer executor: {:?}",
-                                                err
-                                            ),
-                                        }))
-                                    })?;
-                                executor.describe(stmt).await?
-                            }
-                            Some(_peer) => {
-                                panic!("peer type not supported: {:?}", peer)
-                            }
-                            None => {
-                                panic!("peer type not supported: {:?}", peer)
-                            }
-                        }
+                        let executor = self.get_peer_executor(peer).await.map_err(|err| {
+                            peer_error(format!("unable to get peer executor: {:?}", err))
+                        })?;
+                        executor.describe(stmt).await?
                     }
                     QueryAssocation::Catalog => {
+                        let mut stmt = stmt.clone();
+                        self.session_tables.lock().await.qualify(&mut stmt);
+                        self.emulate_catalog(&mut stmt).await?;
                         let catalog = self.catalog.lock().await;
                         let executor = catalog.get_executor();
-                        executor.describe(stmt).await?
+                        executor.describe(&stmt).await?
                     }
                 };
                 if let Some(described_schema) = schema {
@@ -803,7 +4572,7 @@ impl ExtendedQueryHandler for NexusBackend {
                     } else {
                         Ok(DescribeResponse::new(
                             param_types,
-                            described_schema.fields.clone(),
+                            with_result_format(&described_schema, format).fields.clone(),
                         ))
                     }
                 } else {
@@ -814,25 +4583,170 @@ impl ExtendedQueryHandler for NexusBackend {
     }
 }
 
-struct MakeNexusBackend {
-    catalog: Arc<Mutex<Catalog>>,
-    peer_connections: Arc<PeerConnectionTracker>,
+// a cancel request stops the statement the session is waiting on, and asks the
+// peers to cancel what they run for the session, so that the rows a peer is
+// still streaming stop too.
+#[async_trait]
+impl cancel::CancelSession for NexusBackend {
+    async fn cancel(&self) {
+        self.cancel_requested.notify_waiters();
+        self.cancel_peer_queries().await;
+    }
+}
+
+// the notifications that arrive while the client waits for its next request
+// are forwarded to it right away, the ones that arrive while it runs a query
+// once the query is done.
+#[async_trait]
+impl connection::Session for NexusBackend {
+    // like pgwire's default, while the connection relays the COPY the
+    // statements of the query speak with the client, along with the notices
+    // the peers sent while the query ran ahead of its results, and the ones
+    // they sent while its rows were streamed after them.
+    async fn on_query(&self, client: &mut connection::Client, query: Query) -> PgWireResult<()> {
+        self.shutdown.check()?;
+        let _running = self.running_queries.start();
+        client.set_state(PgWireConnectionState::QueryInProgress);
+        let (copy_requests, relayed_requests) = mpsc::channel(1);
+        *self.copy_requests.lock().unwrap() = Some(copy_requests);
+        let snapshot = connection::ClientSnapshot::of(client);
+        let run = async {
+            let responses = SimpleQueryHandler::do_query(self, &snapshot, query.query()).await;
+            // the relay is done once the statements are.
+            self.copy_requests.lock().unwrap().take();
+            responses
+        };
+        let (responses, relayed) =
+            tokio::join!(run, connection::relay_copy(client, relayed_requests));
+        relayed?;
+        let responses = responses?;
+        self.send_peer_notices(client).await?;
+        for response in responses {
+            match response {
+                Response::EmptyQuery => {
+                    client
+                        .feed(PgWireBackendMessage::EmptyQueryResponse(
+                            EmptyQueryResponse::new(),
+                        ))
+                        .await?;
+                }
+                Response::Query(results) => send_query_response(client, results, true).await?,
+                Response::Execution(tag) => send_execution_response(client, tag).await?,
+                Response::Error(err) => {
+                    client
+                        .feed(PgWireBackendMessage::ErrorResponse((*err).into()))
+                        .await?;
+                }
+            }
+        }
+        self.send_peer_notices(client).await?;
+        self.release_peer_executors().await;
+        client
+            .feed(PgWireBackendMessage::ReadyForQuery(ReadyForQuery::new(
+                READY_STATUS_IDLE,
+            )))
+            .await?;
+        client.flush().await?;
+        client.set_state(PgWireConnectionState::ReadyForQuery);
+        Ok(())
+    }
+
+    async fn notifications(&self) -> Vec<Notification> {
+        loop {
+            let changed = self.listening_changed.notified();
+            let executor = match self.listening_executor().await {
+                Some(executor) => executor,
+                None => {
+                    changed.await;
+                    continue;
+                }
+            };
+            tokio::select! {
+                _ = executor.notifications_arrived() => {}
+                _ = changed => continue,
+            }
+            let notifications = executor.take_notifications();
+            if !notifications.is_empty() {
+                return notifications;
+            }
+        }
+    }
+
+    // a session is closed once the server drains and its queries finished,
+    // or once it ran no query for its idle_session_timeout.
+    async fn closing(&self) -> ErrorInfo {
+        let drained = async {
+            self.shutdown.draining().await;
+            self.running_queries.idle().await;
+        };
+        tokio::select! {
+            _ = drained => ErrorInfo::new(
+                "FATAL".to_owned(),
+                shutdown::ADMIN_SHUTDOWN_CODE.to_owned(),
+                shutdown::ADMIN_SHUTDOWN_MESSAGE.to_owned(),
+            ),
+            _ = self.idle_session_expired() => {
+                tracing::info!("closing a session that was idle for its idle_session_timeout");
+                ErrorInfo::new(
+                    "FATAL".to_owned(),
+                    "57P05".to_owned(),
+                    "terminating connection due to idle-session timeout".to_owned(),
+                )
+            }
+        }
+    }
+}
+
+// the connectors of the peers nexus queries itself, those built in with their
+// features and those registered with peer_cursor::connector::register_connector,
+// which take the place of the built-in ones for the same types of peers.
+#[cfg_attr(not(feature = "bigquery"), allow(unused_variables))]
+fn builtin_connectors(peer_connections: Arc<PeerConnectionTracker>) -> ConnectorRegistry {
+    let mut connectors = ConnectorRegistry::default();
+    #[cfg(feature = "postgres")]
+    connectors.register(peer_postgres::PostgresConnector);
+    #[cfg(feature = "bigquery")]
+    connectors.register(peer_bigquery::BigQueryConnector::new(peer_connections));
+    #[cfg(feature = "snowflake")]
+    connectors.register(peer_snowflake::SnowflakeConnector);
+    #[cfg(feature = "file")]
+    connectors.register(peer_file::FileConnector);
+    connectors.register_hooked();
+    connectors
+}
+
+/// The settings the sessions of a client are made with, those of the server
+/// and of the listener the client connected to.
+#[derive(Clone)]
+pub struct NexusServerOptions {
+    connectors: Arc<ConnectorRegistry>,
     flow_handler: Option<Arc<Mutex<FlowGrpcClient>>>,
     peerdb_fdw_mode: bool,
+    default_peer: Option<String>,
+    scheduler: Arc<QueryScheduler>,
+    request_timeout: Option<Duration>,
+    idle_session_timeout: Option<Duration>,
+    quarantined_peers: Arc<DashSet<String>>,
+    execute_on_peer: bool,
+    federated_query_max_rows: usize,
+    admin_user: Option<String>,
+    read_only: bool,
+    audit_log: audit::AuditLog,
+    connections: Arc<connections::Connections>,
+    shutdown: Arc<shutdown::Shutdown>,
+    peer_pool: Option<Arc<peer_pool::PeerConnectionPool>>,
+}
+
+struct MakeNexusBackend {
+    catalog: Arc<Mutex<Catalog>>,
+    options: NexusServerOptions,
 }
 
 impl MakeNexusBackend {
-    fn new(
-        catalog: Catalog,
-        peer_connections: Arc<PeerConnectionTracker>,
-        flow_handler: Option<Arc<Mutex<FlowGrpcClient>>>,
-        peerdb_fdw_mode: bool,
-    ) -> Self {
+    fn new(catalog: Catalog, options: NexusServerOptions) -> Self {
         Self {
             catalog: Arc::new(Mutex::new(catalog)),
-            peer_connections,
-            flow_handler,
-            peerdb_fdw_mode,
+            options,
         }
     }
 }
@@ -843,9 +4757,7 @@ impl MakeHandler for MakeNexusBackend {
     fn make(&self) -> Self::Handler {
         Arc::new(NexusBackend::new(
             self.catalog.clone(),
-            self.peer_connections.clone(),
-            self.flow_handler.clone(),
-            self.peerdb_fdw_mode,
+            self.options.clone(),
         ))
     }
 }
@@ -861,6 +4773,22 @@ struct Args {
     #[clap(short, long, default_value_t = 9900, env = "PEERDB_PORT")]
     port: u16,
 
+    /// Directory of a Unix socket to also listen on, named `.s.PGSQL.<port>`
+    /// like the sockets of postgres, so that `psql -h <dir>` connects to it.
+    #[clap(long, env = "PEERDB_UNIX_SOCKET_DIR")]
+    unix_socket_dir: Option<String>,
+
+    /// Permissions of the Unix socket, in octal.
+    ///
+    /// Defaults to `0770`, only the user and group of the server connect to it.
+    #[clap(
+        long,
+        default_value = "0770",
+        value_parser = parse_permissions,
+        env = "PEERDB_UNIX_SOCKET_PERMISSIONS"
+    )]
+    unix_socket_permissions: u32,
+
     // define args for catalog postgres server - host, port, user, password, database
     /// Catalog postgres server host.
     /// Defaults to `localhost`.
@@ -887,14 +4815,90 @@ struct Args {
     #[clap(long, default_value = "postgres", env = "PEERDB_CATALOG_DATABASE")]
     catalog_database: String,
 
-    /// Path to the TLS certificate file.
+    /// Master key the configs of peers, with their passwords and keys, are
+    /// encrypted with in the catalog, 32 bytes in base64, e.g. of
+    /// `openssl rand -base64 32`.
+    ///
+    /// This is an optional parameter. If not provided, configs are stored in
+    /// plaintext. With it, the configs stored in plaintext are encrypted at
+    /// startup, and a catalog with encrypted configs can't be read without it.
+    #[clap(
+        long,
+        value_parser = parse_master_key,
+        hide_env_values = true,
+        env = "PEERDB_CATALOG_MASTER_KEY"
+    )]
+    catalog_master_key: Option<MasterKey>,
+
+    /// Master key the configs were encrypted with before the current one, to
+    /// rotate the master key online.
+    ///
+    /// To rotate it, restart the servers with the new key as the master key
+    /// and the old one as this, then run
+    /// `SELECT peerdb.rotate_encryption_key('<old>', '<new>')`, or call
+    /// RotateEncryptionKey of the flow api, which wraps the data keys of all
+    /// configs with the new key. Until it does, configs are decrypted with
+    /// either key. This can be unset once it is done.
+    #[clap(
+        long,
+        value_parser = parse_master_key,
+        hide_env_values = true,
+        requires = "catalog_master_key",
+        env = "PEERDB_CATALOG_PREVIOUS_MASTER_KEY"
+    )]
+    catalog_previous_master_key: Option<MasterKey>,
+
+    /// Path to the TLS certificate file, in PEM format.
+    ///
+    /// When set along with the key, clients that request TLS are served over it.
     #[clap(long, requires = "tls_key", env = "PEERDB_TLS_CERT")]
     tls_cert: Option<String>,
 
-    /// Path to the TLS private key file.
+    /// Path to the TLS private key file, in PEM format.
     #[clap(long, requires = "tls_cert", env = "PEERDB_TLS_KEY")]
     tls_key: Option<String>,
 
+    /// Turns away clients that don't request TLS, requires the TLS certificate
+    /// and key.
+    #[clap(long, requires = "tls_cert", env = "PEERDB_REQUIRE_TLS")]
+    require_tls: bool,
+
+    /// Path to the CA certificates client certificates are verified with, in
+    /// PEM format.
+    ///
+    /// When set, clients have to connect over TLS with a certificate whose
+    /// common name or a subject alternative name is the user they log in as,
+    /// and still authenticate with their password.
+    #[clap(long, requires = "tls_cert", env = "PEERDB_TLS_CLIENT_CA")]
+    tls_client_ca: Option<String>,
+
+    /// File of the rules that decide, by the address of a client and the user
+    /// it connects as, whether it logs in with or without a password or is
+    /// turned away, in the format of postgres' pg_hba.conf without the database
+    /// field, e.g. `host all 10.0.0.0/8 password`.
+    ///
+    /// This is an optional parameter. If not provided, all clients log in with
+    /// a password. With it, clients no rule matches are turned away, so nexus
+    /// can be bound to broader interfaces.
+    #[clap(long, env = "PEERDB_HBA_FILE")]
+    hba_file: Option<String>,
+
+    /// Further addresses to listen on, each with settings of its own for its
+    /// clients, e.g. `0.0.0.0:9901,read_only,require_tls` for an external port
+    /// next to an internal admin port that allows DDL. Repeat the flag, or
+    /// separate the listeners with `;` in the environment variable.
+    ///
+    /// A listener is its address followed by `read_only`, `require_tls`,
+    /// `auth_method=<method>` and `hba_file=<path>`, separated by commas. The
+    /// settings left out are those of the server.
+    #[clap(
+        long = "listener",
+        value_parser = listeners::parse_listener,
+        value_delimiter = ';',
+        env = "PEERDB_LISTENERS"
+    )]
+    listeners: Vec<listeners::ListenerSpec>,
+
     /// Path to the directory where peerdb logs will be written to.
     ///
     /// This is only respected in release mode. In debug mode the logs
@@ -908,6 +4912,29 @@ struct Args {
     #[clap(long, env = "PEERDB_PASSWORD", default_value = "peerdb")]
     peerdb_password: String,
 
+    /// Path to a config file of the settings that can change while the server
+    /// runs, `password` and `log_level`, in `name = value` lines like
+    /// postgresql.conf. Its settings take the place of `--peerdb-password` and
+    /// `RUST_LOG`.
+    ///
+    /// This is an optional parameter. On SIGHUP, the server reads it again,
+    /// along with the TLS certificates and the hba file, without dropping the
+    /// connections of clients, so secrets and certificates can be rotated.
+    #[clap(long, env = "PEERDB_CONFIG_FILE")]
+    config_file: Option<String>,
+
+    /// How clients authenticate, with `scram-sha-256` or `md5`.
+    ///
+    /// Defaults to `scram-sha-256`. Users created before SCRAM-SHA-256 secrets
+    /// were kept can only log in with `md5`.
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = AuthMethod::ScramSha256,
+        env = "PEERDB_AUTH_METHOD"
+    )]
+    auth_method: AuthMethod,
+
     /// Points to the URL for the Flow API server.
     ///
     /// This is an optional parameter. If not provided, the MIRROR commands will not be supported.
@@ -916,6 +4943,188 @@ struct Args {
 
     #[clap(long, env = "PEERDB_FDW_MODE", default_value = "false")]
     peerdb_fwd_mode: String,
+
+    /// Name of the peer that `SHOW TABLES` and `SHOW COLUMNS` are resolved against.
+    ///
+    /// This is an optional parameter. If not provided, these commands will not be supported.
+    /// Sessions can choose their own with `SET peerdb.default_peer`, or by connecting to the
+    /// database named after a peer, which also runs the queries that don't refer to a peer on it.
+    #[clap(long, env = "PEERDB_DEFAULT_PEER")]
+    default_peer: Option<String>,
+
+    /// Number of concurrent peer queries granted per unit of resource group weight.
+    ///
+    /// Users that are not assigned to a resource group share a group of weight 1.
+    #[clap(long, default_value_t = 4, env = "PEERDB_RESOURCE_GROUP_CONCURRENCY")]
+    resource_group_concurrency: u32,
+
+    /// Number of queries that can run on a peer at once. Queries beyond that
+    /// wait for a slot, which are handed out round-robin across sessions.
+    ///
+    /// Defaults to `0`, which doesn't limit the queries on a peer.
+    #[clap(long, default_value_t = 0, env = "PEERDB_PEER_CONCURRENCY")]
+    peer_concurrency: u32,
+
+    /// Seconds a query may take before it is canceled, including the time spent
+    /// on the catalog, the Flow API and peers.
+    ///
+    /// Defaults to `0`, which never cancels queries. Sessions can change it with
+    /// `SET statement_timeout`.
+    #[clap(long, default_value_t = 0, env = "PEERDB_REQUEST_TIMEOUT")]
+    request_timeout: u64,
+
+    /// Seconds a session may run no query before it is closed, which frees
+    /// the connections it holds to peers.
+    ///
+    /// Defaults to `0`, which never closes idle sessions. Sessions can change
+    /// it with `SET idle_session_timeout`.
+    #[clap(long, default_value_t = 0, env = "PEERDB_IDLE_SESSION_TIMEOUT")]
+    idle_session_timeout: u64,
+
+    /// Seconds a client connection is idle before TCP keepalives are sent on
+    /// it, so that connections to clients that went away are closed.
+    ///
+    /// Defaults to `0`, which leaves keepalives off unless the interval or
+    /// count are set, with which the system default applies.
+    #[clap(long, default_value_t = 0, env = "PEERDB_TCP_KEEPALIVES_IDLE")]
+    tcp_keepalives_idle: u64,
+
+    /// Seconds between TCP keepalives that aren't acknowledged.
+    ///
+    /// Defaults to `0`, the system default.
+    #[clap(long, default_value_t = 0, env = "PEERDB_TCP_KEEPALIVES_INTERVAL")]
+    tcp_keepalives_interval: u64,
+
+    /// Number of TCP keepalives that may go unacknowledged before the
+    /// connection is closed.
+    ///
+    /// Defaults to `0`, the system default.
+    #[clap(long, default_value_t = 0, env = "PEERDB_TCP_KEEPALIVES_COUNT")]
+    tcp_keepalives_count: u32,
+
+    /// Seconds between health checks of peers.
+    ///
+    /// Defaults to `60`, `0` disables health checks and peer quarantine.
+    #[clap(long, default_value_t = 60, env = "PEERDB_PEER_HEALTH_CHECK_INTERVAL")]
+    peer_health_check_interval: u64,
+
+    /// Number of health checks in a row a peer has to fail to be quarantined,
+    /// and to pass to recover from quarantine.
+    #[clap(long, default_value_t = 3, env = "PEERDB_PEER_QUARANTINE_THRESHOLD")]
+    peer_quarantine_threshold: u32,
+
+    /// Disables `EXECUTE '...' ON PEER`, which runs arbitrary statements on peers.
+    #[clap(long, env = "PEERDB_DISABLE_EXECUTE_ON_PEER")]
+    disable_execute_on_peer: bool,
+
+    /// Rejects the statements that create, alter or drop peers, mirrors and
+    /// users, and those that write to peers, including `EXECUTE '...' ON PEER`,
+    /// for an analytics endpoint that is safe to expose. With access control,
+    /// users can also be made read only with `GRANT READ ONLY TO user`.
+    #[clap(long, env = "PEERDB_READ_ONLY")]
+    read_only: bool,
+
+    /// Which statements are logged, with `none`, `ddl` or `all`, along with the
+    /// user that ran them, the peers they were routed to, the rows they returned
+    /// and how long they took, for operators to audit who ran what.
+    ///
+    /// Defaults to `none`. The entries are logged to the `peerdb::audit` target,
+    /// `ddl` logs the statements that change peers, mirrors, users and the tables
+    /// of peers, and those passed through with `EXECUTE '...' ON PEER`.
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = audit::AuditLog::None,
+        env = "PEERDB_AUDIT_LOG"
+    )]
+    audit_log: audit::AuditLog,
+
+    /// Enables access control, with which users other than the admin log in with
+    /// their own password and have to be granted privileges on peers and mirrors.
+    #[clap(long, env = "PEERDB_ACCESS_CONTROL")]
+    access_control: bool,
+
+    /// User that access control doesn't apply to, it logs in with the password
+    /// of the server and manages users and their privileges.
+    ///
+    /// Defaults to `peerdb`.
+    #[clap(long, default_value = "peerdb", env = "PEERDB_ADMIN_USER")]
+    admin_user: String,
+
+    /// Maximum number of clients connected at once, the clients beyond that
+    /// are turned away. `peerdb_connections()` and `peerdb_peak_connections()`
+    /// report the clients connected now and at most.
+    ///
+    /// Defaults to `0`, which doesn't limit the clients.
+    #[clap(long, default_value_t = 0, env = "PEERDB_MAX_CONNECTIONS")]
+    max_connections: usize,
+
+    /// Maximum number of clients of a user connected at once.
+    ///
+    /// Defaults to `0`, which doesn't limit the clients of a user.
+    #[clap(long, default_value_t = 0, env = "PEERDB_MAX_CONNECTIONS_PER_USER")]
+    max_connections_per_user: usize,
+
+    /// Seconds the server waits on SIGTERM for the queries in flight to finish
+    /// before it closes their connections and exits. Idle clients are
+    /// disconnected right away, and no new clients are accepted.
+    ///
+    /// Defaults to `30`.
+    #[clap(long, default_value_t = 30, env = "PEERDB_SHUTDOWN_DRAIN_TIMEOUT")]
+    shutdown_drain_timeout: u64,
+
+    /// Maximum number of rows a query joining several peers reads from each
+    /// of their tables.
+    #[clap(
+        long,
+        default_value_t = 1_000_000,
+        env = "PEERDB_FEDERATED_QUERY_MAX_ROWS"
+    )]
+    federated_query_max_rows: usize,
+
+    /// How long sessions hold their connections to postgres peers, with
+    /// `session` or `transaction`.
+    ///
+    /// Defaults to `session`. In `transaction` mode a session holds a connection
+    /// for a transaction, or a statement outside of one, and hands it back to a
+    /// pool the next session takes it from, so bursty clients share a few
+    /// connections to a peer. Sessions that LISTEN or have cursors open hold
+    /// theirs until they are done.
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = peer_pool::PoolMode::Session,
+        env = "PEERDB_POOL_MODE"
+    )]
+    pool_mode: peer_pool::PoolMode,
+
+    /// Maximum number of idle connections to a peer kept in `transaction` pool
+    /// mode, the connections handed back beyond that are closed.
+    #[clap(long, default_value_t = 16, env = "PEERDB_POOL_MAX_IDLE_CONNECTIONS")]
+    pool_max_idle_connections: usize,
+
+    /// Port to serve the Flow API over JSON/HTTP on.
+    ///
+    /// This is an optional parameter. If not provided, or if MIRROR commands are disabled,
+    /// the REST gateway is not started.
+    #[cfg(feature = "rest-gateway")]
+    #[clap(long, env = "PEERDB_REST_GATEWAY_PORT")]
+    rest_gateway_port: Option<u16>,
+
+    /// Host the REST gateway listens on. The gateway creates and drops peers and
+    /// mirrors, so it only listens on the loopback interface unless told otherwise.
+    ///
+    /// Defaults to `127.0.0.1`.
+    #[cfg(feature = "rest-gateway")]
+    #[clap(long, default_value = "127.0.0.1", env = "PEERDB_REST_GATEWAY_HOST")]
+    rest_gateway_host: String,
+
+    /// Token the clients of the REST gateway send as `Authorization: Bearer <token>`.
+    ///
+    /// The REST gateway is not started without one.
+    #[cfg(feature = "rest-gateway")]
+    #[clap(long, env = "PEERDB_REST_GATEWAY_TOKEN")]
+    rest_gateway_token: Option<String>,
 }
 
 // Get catalog config from args
@@ -926,9 +5135,15 @@ fn get_catalog_config(args: &Args) -> CatalogConfig {
         user: args.catalog_user.clone(),
         password: args.catalog_password.clone(),
         database: args.catalog_database.clone(),
+        master_key: args.catalog_master_key.clone(),
+        previous_master_key: args.catalog_previous_master_key.clone(),
     }
 }
 
+fn parse_master_key(encoded: &str) -> Result<MasterKey, String> {
+    MasterKey::from_base64(encoded).map_err(|err| err.to_string())
+}
+
 pub struct NexusServerParameterProvider;
 
 impl ServerParameterProvider for NexusServerParameterProvider {
@@ -947,12 +5162,136 @@ impl ServerParameterProvider for NexusServerParameterProvider {
     }
 }
 
+// the settings of the clients of a listener, those of the server for the main
+// listener and, apart from TLS, the Unix socket.
+struct ListenerProfile {
+    authenticator: Authenticator,
+    read_only: bool,
+    require_tls: bool,
+    // the hba file of the listener and its rules, which SIGHUP reloads.
+    hba: Option<(String, config_file::Reloadable<hba::HbaRules>)>,
+}
+
+fn make_authenticator(
+    method: AuthMethod,
+    password: &Arc<config_file::Reloadable<String>>,
+    auth_access_control: &Option<(String, Arc<Mutex<Catalog>>)>,
+) -> Authenticator {
+    let auth_source = Arc::new(NexusAuthSource::new(
+        password.clone(),
+        method,
+        auth_access_control.clone(),
+    ));
+    match method {
+        AuthMethod::Md5 => Authenticator::Md5(Arc::new(MakeMd5PasswordAuthStartupHandler::new(
+            auth_source,
+            Arc::new(NexusServerParameterProvider),
+        ))),
+        AuthMethod::ScramSha256 => {
+            let mut authenticator = MakeSASLScramAuthStartupHandler::new(
+                auth_source,
+                Arc::new(NexusServerParameterProvider),
+            );
+            authenticator.set_iterations(access_control::SCRAM_ITERATIONS);
+            Authenticator::Scram(Arc::new(authenticator))
+        }
+    }
+}
+
+fn load_hba(
+    path: Option<&str>,
+) -> anyhow::Result<Option<(String, config_file::Reloadable<hba::HbaRules>)>> {
+    match path {
+        Some(path) => {
+            let rules = hba::HbaRules::load(path)?;
+            tracing::info!("clients are checked against the hba rules of {}", path);
+            Ok(Some((path.to_owned(), config_file::Reloadable::new(rules))))
+        }
+        None => Ok(None),
+    }
+}
+
+// reads the config file, the TLS certificates and the hba files again on
+// SIGHUP. the settings that fail to load keep their previous value, and the
+// connections of clients are left alone, new ones get the new settings.
+fn reload_settings(
+    args: &Args,
+    tracer: &TracerGuards,
+    password: &config_file::Reloadable<String>,
+    profiles: &[Arc<ListenerProfile>],
+    tls_acceptors: Option<&config_file::Reloadable<tls::TlsAcceptors>>,
+) {
+    tracing::info!("received SIGHUP, reloading settings");
+    let config = match &args.config_file {
+        Some(path) => match config_file::ConfigFile::load(path) {
+            Ok(config) => Some(config),
+            Err(err) => {
+                tracing::error!("unable to reload the config file: {:?}", err);
+                None
+            }
+        },
+        None => Some(config_file::ConfigFile::default()),
+    };
+    if let Some(config) = config {
+        password.set(
+            config
+                .password
+                .unwrap_or_else(|| args.peerdb_password.clone()),
+        );
+        if let Err(err) = tracer.set_log_level(config.log_level.as_deref()) {
+            tracing::error!("unable to reload the log level: {:?}", err);
+        }
+    }
+    for (path, rules) in profiles.iter().filter_map(|profile| profile.hba.as_ref()) {
+        match hba::HbaRules::load(path) {
+            Ok(loaded) => rules.set(loaded),
+            Err(err) => tracing::error!("unable to reload the hba file {}: {:?}", path, err),
+        }
+    }
+    if let (Some(acceptors), Some(cert_path), Some(key_path)) =
+        (tls_acceptors, &args.tls_cert, &args.tls_key)
+    {
+        match tls::TlsAcceptors::new(cert_path, key_path, args.tls_client_ca.as_deref()) {
+            Ok(loaded) => acceptors.set(loaded),
+            Err(err) => tracing::error!("unable to reload the TLS certificates: {:?}", err),
+        }
+    }
+}
+
 struct TracerGuards {
     _rolling_guard: WorkerGuard,
+    // swap the filters of the logs written to stdout and to the log file when
+    // SIGHUP changes the log level. the layer of the tokio console has no
+    // filter, it records the tasks of the runtime regardless of the level.
+    log_filters: [reload::Handle<EnvFilter, Registry>; 2],
+}
+
+impl TracerGuards {
+    // sets the log level of both logs, or of neither when it is invalid.
+    fn set_log_level(&self, log_level: Option<&str>) -> anyhow::Result<()> {
+        let filters = [log_filter(log_level)?, log_filter(log_level)?];
+        for (handle, filter) in self.log_filters.iter().zip(filters) {
+            handle.reload(filter)?;
+        }
+        tracing::info!("log level set to {}", log_level.unwrap_or("the default"));
+        Ok(())
+    }
+}
+
+// the filter of the logs, of the log level of the config file, else RUST_LOG,
+// else info.
+fn log_filter(log_level: Option<&str>) -> anyhow::Result<EnvFilter> {
+    match log_level {
+        Some(log_level) => EnvFilter::try_new(log_level)
+            .map_err(|err| anyhow::anyhow!("invalid log_level {}: {}", log_level, err)),
+        None => Ok(EnvFilter::try_from_default_env()
+            .or_else(|_| EnvFilter::try_new("info"))
+            .unwrap()),
+    }
 }
 
 // setup tracing
-fn setup_tracing(log_dir: &str) -> TracerGuards {
+fn setup_tracing(log_dir: &str, log_level: Option<&str>) -> anyhow::Result<TracerGuards> {
     let console_layer = console_subscriber::spawn();
 
     // also log to peerdb.log in log_dir
@@ -962,23 +5301,26 @@ fn setup_tracing(log_dir: &str) -> TracerGuards {
 
     let fmt_stdout_layer = fmt::layer().with_target(false).with_writer(std::io::stdout);
 
-    // add min tracing as info
-    let filter_layer = EnvFilter::try_from_default_env()
-        .or_else(|_| EnvFilter::try_new("info"))
-        .unwrap();
+    // the logs are filtered apart from the tokio console, each log with a
+    // filter of its own that SIGHUP can swap, see `TracerGuards`.
+    let (stdout_filter, stdout_log_filter) = reload::Layer::new(log_filter(log_level)?);
+    let (file_filter, file_log_filter) = reload::Layer::new(log_filter(log_level)?);
+    let log_layers: Vec<Box<dyn Layer<Registry> + Send + Sync>> = vec![
+        fmt_stdout_layer.with_filter(stdout_filter).boxed(),
+        fmt_file_layer.with_filter(file_filter).boxed(),
+    ];
 
     tracing_subscriber::registry()
+        .with(log_layers)
         .with(console_layer)
-        .with(fmt_stdout_layer)
-        .with(fmt_file_layer)
-        .with(filter_layer)
         .init();
 
     // return guard so that the file appender is not dropped
     // and the file is not closed
-    TracerGuards {
+    Ok(TracerGuards {
         _rolling_guard: _guard,
-    }
+        log_filters: [stdout_log_filter, file_log_filter],
+    })
 }
 
 async fn run_migrations(config: &CatalogConfig) -> anyhow::Result<()> {
@@ -989,6 +5331,10 @@ async fn run_migrations(config: &CatalogConfig) -> anyhow::Result<()> {
         match catalog {
             Ok(mut catalog) => {
                 catalog.run_migrations().await?;
+                let encrypted = catalog.encrypt_plaintext_configs().await?;
+                if encrypted > 0 {
+                    tracing::info!("encrypted {} configs stored in plaintext", encrypted);
+                }
                 return Ok(());
             }
             Err(err) => {
@@ -1004,30 +5350,161 @@ async fn run_migrations(config: &CatalogConfig) -> anyhow::Result<()> {
     Err(anyhow::anyhow!("Failed to connect to catalog"))
 }
 
+// the TCP keepalives of client connections, none unless one of the settings
+// is set. like postgres, a setting of 0 is the system default.
+fn tcp_keepalive(args: &Args) -> Option<socket2::TcpKeepalive> {
+    if args.tcp_keepalives_idle == 0
+        && args.tcp_keepalives_interval == 0
+        && args.tcp_keepalives_count == 0
+    {
+        return None;
+    }
+    let mut keepalive = socket2::TcpKeepalive::new();
+    if args.tcp_keepalives_idle > 0 {
+        keepalive = keepalive.with_time(Duration::from_secs(args.tcp_keepalives_idle));
+    }
+    if args.tcp_keepalives_interval > 0 {
+        keepalive = keepalive.with_interval(Duration::from_secs(args.tcp_keepalives_interval));
+    }
+    if args.tcp_keepalives_count > 0 {
+        keepalive = keepalive.with_retries(args.tcp_keepalives_count);
+    }
+    Some(keepalive)
+}
+
+// parses file permissions written in octal, e.g. `0770`.
+fn parse_permissions(permissions: &str) -> Result<u32, String> {
+    u32::from_str_radix(permissions, 8)
+        .ok()
+        .filter(|permissions| *permissions <= 0o777)
+        .ok_or_else(|| {
+            format!(
+                "invalid permissions {}, expected octal like 0770",
+                permissions
+            )
+        })
+}
+
 #[tokio::main]
 pub async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
 
     let args = Args::parse();
-    let _guard = setup_tracing(&args.log_dir);
+    let config = match &args.config_file {
+        Some(path) => config_file::ConfigFile::load(path)?,
+        None => config_file::ConfigFile::default(),
+    };
+    let tracer = setup_tracing(&args.log_dir, config.log_level.as_deref())?;
 
-    let authenticator = Arc::new(MakeMd5PasswordAuthStartupHandler::new(
-        Arc::new(FixedPasswordAuthSource::new(args.peerdb_password.clone())),
-        Arc::new(NexusServerParameterProvider),
-    ));
     let catalog_config = get_catalog_config(&args);
 
     run_migrations(&catalog_config).await?;
 
+    let admin_user = args.access_control.then(|| args.admin_user.clone());
+    let auth_access_control = match &admin_user {
+        Some(admin_user) => {
+            tracing::info!("access control enabled, admin user: {}", admin_user);
+            let catalog = Catalog::new(&catalog_config).await?;
+            Some((admin_user.clone(), Arc::new(Mutex::new(catalog))))
+        }
+        None => None,
+    };
+    let password = Arc::new(config_file::Reloadable::new(
+        config
+            .password
+            .unwrap_or_else(|| args.peerdb_password.clone()),
+    ));
+    let authenticator = make_authenticator(args.auth_method, &password, &auth_access_control);
+    tracing::info!("clients authenticate with {:?}", args.auth_method);
+
     let peer_conns = {
         let conn_str = catalog_config.to_pg_connection_string();
         let pconns = PeerConnections::new(&conn_str)?;
         Arc::new(pconns)
     };
 
+    let tls_acceptors = match (&args.tls_cert, &args.tls_key) {
+        (Some(cert_path), Some(key_path)) => {
+            let acceptors =
+                tls::TlsAcceptors::new(cert_path, key_path, args.tls_client_ca.as_deref())?;
+            tracing::info!(
+                "TLS enabled, required: {}, client certificates required: {}",
+                args.require_tls || acceptors.requires_client_certificates(),
+                acceptors.requires_client_certificates()
+            );
+            Some(config_file::Reloadable::new(acceptors))
+        }
+        _ => None,
+    };
+    // the main listener has the settings of the server, the further listeners
+    // settings of their own.
+    let mut profiles = vec![Arc::new(ListenerProfile {
+        authenticator,
+        read_only: args.read_only,
+        require_tls: args.require_tls,
+        hba: load_hba(args.hba_file.as_deref())?,
+    })];
+    for spec in &args.listeners {
+        if spec.require_tls && tls_acceptors.is_none() {
+            anyhow::bail!(
+                "listener {} requires TLS, but no certificate is set",
+                spec.addr
+            );
+        }
+        let auth_method = spec.auth_method.unwrap_or(args.auth_method);
+        profiles.push(Arc::new(ListenerProfile {
+            authenticator: make_authenticator(auth_method, &password, &auth_access_control),
+            read_only: args.read_only || spec.read_only,
+            require_tls: args.require_tls || spec.require_tls,
+            hba: load_hba(spec.hba_file.as_deref().or(args.hba_file.as_deref()))?,
+        }));
+    }
+    // the Unix socket has the settings of the server, but its clients connect
+    // without TLS, which postgres clients don't negotiate over a socket. the
+    // permissions of the socket decide who connects to it.
+    let unix_profile = profiles.len();
+    profiles.push(Arc::new(ListenerProfile {
+        authenticator: profiles[0].authenticator.clone(),
+        read_only: args.read_only,
+        require_tls: false,
+        hba: load_hba(args.hba_file.as_deref())?,
+    }));
+    let cancel_keys = Arc::new(cancel::CancelKeys::new());
+    let connections = Arc::new(connections::Connections::new(
+        args.max_connections,
+        args.max_connections_per_user,
+    ));
+    let shutdown = Arc::new(shutdown::Shutdown::new(Duration::from_secs(
+        args.shutdown_drain_timeout,
+    )));
+
     let server_addr = format!("{}:{}", args.host, args.port);
-    let listener = TcpListener::bind(&server_addr).await.unwrap();
+    let mut tcp_listeners = vec![TcpListener::bind(&server_addr)
+        .await
+        .with_context(|| format!("unable to listen on {}", server_addr))?];
     tracing::info!("Listening on {}", server_addr);
+    for (spec, profile) in args.listeners.iter().zip(&profiles[1..]) {
+        let listener = TcpListener::bind(&spec.addr)
+            .await
+            .map_err(|err| anyhow::anyhow!("unable to listen on {}: {}", spec.addr, err))?;
+        tcp_listeners.push(listener);
+        tracing::info!(
+            "Listening on {}, read only: {}, TLS required: {}",
+            spec.addr,
+            profile.read_only,
+            profile.require_tls
+        );
+    }
+    let unix_listener = match &args.unix_socket_dir {
+        Some(dir) => {
+            let unix_listener =
+                unix_socket::UnixSocketListener::bind(dir, args.port, args.unix_socket_permissions)
+                    .await?;
+            tracing::info!("Listening on {}", unix_listener.path().display());
+            Some(unix_listener)
+        }
+        None => None,
+    };
 
     let flow_server_addr = args.flow_api_url.clone();
     let mut flow_handler: Option<Arc<Mutex<FlowGrpcClient>>> = None;
@@ -1044,8 +5521,108 @@ pub async fn main() -> anyhow::Result<()> {
         tracing::info!("MIRROR commands disabled");
     }
 
+    #[cfg(feature = "rest-gateway")]
+    if let Some(port) = args.rest_gateway_port {
+        let token = args
+            .rest_gateway_token
+            .as_ref()
+            .filter(|token| !token.is_empty());
+        match (&flow_handler, token) {
+            (Some(handler), Some(token)) => {
+                let client = handler.lock().await.route_client();
+                let addr = format!("{}:{}", args.rest_gateway_host, port).parse()?;
+                let token = Arc::new(config_file::Reloadable::new(token.clone()));
+                tokio::task::Builder::new()
+                    .name("rest gateway")
+                    .spawn(async move {
+                        if let Err(err) = gateway::serve(addr, client, token).await {
+                            tracing::error!("REST gateway stopped: {:?}", err);
+                        }
+                    })?;
+            }
+            (None, _) => tracing::info!("REST gateway disabled, no flow server"),
+            (_, None) => tracing::warn!("REST gateway disabled, no token set"),
+        }
+    }
+
+    let scheduler = Arc::new(QueryScheduler::new(
+        args.resource_group_concurrency,
+        args.peer_concurrency,
+    ));
+    let peer_pool = (args.pool_mode == peer_pool::PoolMode::Transaction).then(|| {
+        Arc::new(peer_pool::PeerConnectionPool::new(
+            args.pool_max_idle_connections,
+        ))
+    });
+    tracing::info!("peer connections are pooled per {:?}", args.pool_mode);
+    let request_timeout =
+        (args.request_timeout > 0).then(|| Duration::from_secs(args.request_timeout));
+    let idle_session_timeout =
+        (args.idle_session_timeout > 0).then(|| Duration::from_secs(args.idle_session_timeout));
+    let keepalive = tcp_keepalive(&args);
+
+    let quarantined_peers = Arc::new(DashSet::new());
+    if args.peer_health_check_interval > 0 {
+        let tracker = PeerConnectionTracker::new(uuid::Uuid::new_v4(), peer_conns.clone());
+        let monitor = peer_health::PeerHealthMonitor::new(
+            Catalog::new(&catalog_config).await?,
+            flow_handler.clone(),
+            Arc::new(builtin_connectors(Arc::new(tracker))),
+            args.peer_quarantine_threshold,
+            quarantined_peers.clone(),
+        );
+        let interval = Duration::from_secs(args.peer_health_check_interval);
+        tokio::task::Builder::new()
+            .name("peer health monitor")
+            .spawn(monitor.run(interval))?;
+    } else {
+        tracing::info!("peer health checks disabled");
+    }
+
+    let shutdown_signal = shutdown::signal_received();
+    tokio::pin!(shutdown_signal);
+    let mut hangups = signal(SignalKind::hangup())?;
     loop {
-        let (mut socket, _) = listener.accept().await.unwrap();
+        let (mut socket, profile, local) = tokio::select! {
+            (index, accepted) = listeners::accept(&tcp_listeners) => {
+                let socket = match accepted {
+                    Ok((socket, _)) => socket,
+                    Err(err) => {
+                        tracing::error!("unable to accept a client: {:?}", err);
+                        tokio::time::sleep(ACCEPT_RETRY_DELAY).await;
+                        continue;
+                    }
+                };
+                if let Some(keepalive) = &keepalive {
+                    if let Err(err) = socket2::SockRef::from(&socket).set_tcp_keepalive(keepalive) {
+                        tracing::warn!("unable to set TCP keepalives: {:?}", err);
+                    }
+                }
+                (socket, profiles[index].clone(), false)
+            }
+            accepted = unix_socket::accept(unix_listener.as_ref()) => match accepted {
+                Ok(socket) => (socket, profiles[unix_profile].clone(), true),
+                Err(err) => {
+                    tracing::error!("unable to accept a client of the Unix socket: {:?}", err);
+                    tokio::time::sleep(ACCEPT_RETRY_DELAY).await;
+                    continue;
+                }
+            },
+            received = &mut shutdown_signal => {
+                received?;
+                break;
+            }
+            _ = hangups.recv() => {
+                reload_settings(
+                    &args,
+                    &tracer,
+                    &password,
+                    &profiles,
+                    tls_acceptors.as_ref(),
+                );
+                continue;
+            }
+        };
         let catalog = match Catalog::new(&catalog_config).await {
             Ok(c) => c,
             Err(e) => {
@@ -1070,27 +5647,134 @@ pub async fn main() -> anyhow::Result<()> {
         let conn_uuid = uuid::Uuid::new_v4();
         let tracker = PeerConnectionTracker::new(conn_uuid, peer_conns.clone());
 
-        let authenticator_ref = authenticator.make();
+        let authenticator = profile.authenticator.clone();
 
         let peerdb_fdw_mode = matches!(args.peerdb_fwd_mode.as_str(), "true");
         let processor = Arc::new(MakeNexusBackend::new(
             catalog,
-            Arc::new(tracker),
-            flow_handler.clone(),
-            peerdb_fdw_mode,
+            NexusServerOptions {
+                connectors: Arc::new(builtin_connectors(Arc::new(tracker))),
+                flow_handler: flow_handler.clone(),
+                peerdb_fdw_mode,
+                default_peer: args.default_peer.clone(),
+                scheduler: scheduler.clone(),
+                request_timeout,
+                idle_session_timeout,
+                quarantined_peers: quarantined_peers.clone(),
+                execute_on_peer: !args.disable_execute_on_peer,
+                federated_query_max_rows: args.federated_query_max_rows,
+                admin_user: admin_user.clone(),
+                read_only: profile.read_only,
+                audit_log: args.audit_log,
+                connections: connections.clone(),
+                shutdown: shutdown.clone(),
+                peer_pool: peer_pool.clone(),
+            },
         ));
         let processor_ref = processor.make();
+        let (tls_acceptor, client_certificate) = match &tls_acceptors {
+            Some(acceptors) if !local => match acceptors.get().for_connection() {
+                Ok((acceptor, certificate)) => (Some(acceptor), certificate),
+                Err(err) => {
+                    // the client is dropped, the server keeps serving the others.
+                    tracing::error!("unable to set up TLS for a client: {:?}", err);
+                    continue;
+                }
+            },
+            _ => (None, None),
+        };
+        // clients can only present a certificate over TLS.
+        let require_tls = profile.require_tls || client_certificate.is_some();
+        let hba_rules = profile.hba.as_ref().map(|(_, rules)| rules.get());
+        let cancel_keys = cancel_keys.clone();
+        let connections = connections.clone();
+        let shutdown = shutdown.clone();
         tokio::task::Builder::new()
             .name("tcp connection handler")
             .spawn(async move {
-                process_socket(
+                // cancel requests come on a connection of their own.
+                if let Some(key) = cancel::read_cancel_request(&mut socket).await? {
+                    cancel_keys.cancel(key).await;
+                    return Ok(());
+                }
+                let slot = match connections.try_acquire() {
+                    Some(slot) => slot,
+                    None => {
+                        return connections::reject(
+                            socket,
+                            "53300",
+                            "sorry, too many clients already",
+                        )
+                        .await
+                    }
+                };
+                let requests_tls = tls::requests_tls(&socket).await?;
+                if require_tls && !requests_tls {
+                    return tls::reject_plaintext(socket).await;
+                }
+                let session: Weak<dyn cancel::CancelSession> =
+                    Arc::<NexusBackend>::downgrade(&processor_ref);
+                let startup_handler = cancel::CancelKeyStartupHandler::new(
+                    Arc::new(connections::ConnectionLimitStartupHandler::new(
+                        Arc::new(database_peer::DatabasePeerStartupHandler::new(
+                            Arc::new(tls::ClientCertStartupHandler::new(
+                                Arc::new(hba::HbaStartupHandler::new(
+                                    Arc::new(authenticator.make()),
+                                    hba_rules,
+                                    local,
+                                )),
+                                client_certificate,
+                            )),
+                            processor_ref.query_parser(),
+                        )),
+                        slot,
+                    )),
+                    cancel_keys,
+                    session,
+                );
+                let serve = connection::serve(
                     socket,
-                    None,
-                    authenticator_ref,
+                    tls_acceptor,
+                    Arc::new(startup_handler),
                     processor_ref.clone(),
-                    processor_ref,
-                )
-                .await
+                );
+                tokio::pin!(serve);
+                let res = tokio::select! {
+                    res = &mut serve => res,
+                    _ = shutdown.draining() => {
+                        // the session closes itself once its queries in
+                        // flight finish, they are cancelled on the peers when
+                        // they run past the drain timeout.
+                        match tokio::time::timeout(shutdown.drain_timeout(), &mut serve).await {
+                            Ok(res) => res,
+                            Err(_) => {
+                                tracing::info!("closing a connection with queries in flight");
+                                processor_ref.cancel_peer_queries().await;
+                                Ok(())
+                            }
+                        }
+                    }
+                };
+                processor_ref.stop_listening().await;
+                processor_ref.release_peer_executors().await;
+                processor_ref.drop_session_tables().await;
+                res
             })?;
     }
+
+    tracing::info!(
+        "shutting down, draining {} connections",
+        connections.current()
+    );
+    drop(tcp_listeners);
+    drop(unix_listener);
+    shutdown.start();
+    // the connections close themselves once they drain, the server waits for
+    // them a little past the drain timeout.
+    let deadline = tokio::time::Instant::now() + shutdown.drain_timeout() + SHUTDOWN_GRACE;
+    while connections.current() > 0 && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    tracing::info!("shut down with {} connections open", connections.current());
+    Ok(())
 }
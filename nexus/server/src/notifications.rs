@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use bytes::{BufMut, BytesMut};
+use peer_cursor::Notification;
+use pt::peerdb_peers::Peer;
+use sqlparser::ast::Ident;
+
+/// The channels a session listens to, on the one peer it listens on. The
+/// notifications of the channels arrive on the session's connection to the
+/// peer.
+pub struct Listening {
+    pub peer: Box<Peer>,
+    channels: HashSet<String>,
+}
+
+impl Listening {
+    pub fn new(peer: Box<Peer>) -> Self {
+        Self {
+            peer,
+            channels: HashSet::new(),
+        }
+    }
+
+    pub fn listen(&mut self, channel: &Ident) {
+        self.channels.insert(channel_name(channel));
+    }
+
+    /// Stops listening to the channel, or to all of them for `*`, true when
+    /// the session listens to no channel anymore.
+    pub fn unlisten(&mut self, channel: &Ident) -> bool {
+        if channel.quote_style.is_none() && channel.value == "*" {
+            self.channels.clear();
+        } else {
+            self.channels.remove(&channel_name(channel));
+        }
+        self.channels.is_empty()
+    }
+}
+
+// like postgres, channel names are folded to lower case unless quoted.
+fn channel_name(channel: &Ident) -> String {
+    match channel.quote_style {
+        Some(_) => channel.value.clone(),
+        None => channel.value.to_lowercase(),
+    }
+}
+
+/// The NotificationResponse messages of the notifications, written to the
+/// socket of an idle client in one go.
+pub fn encode(notifications: &[Notification]) -> BytesMut {
+    let mut buf = BytesMut::new();
+    for notification in notifications {
+        let len = 4 + 4 + notification.channel.len() + 1 + notification.payload.len() + 1;
+        buf.put_u8(b'A');
+        buf.put_i32(len as i32);
+        buf.put_i32(notification.process_id);
+        buf.put(notification.channel.as_bytes());
+        buf.put_u8(0);
+        buf.put(notification.payload.as_bytes());
+        buf.put_u8(0);
+    }
+    buf
+}
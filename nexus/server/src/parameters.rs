@@ -0,0 +1,178 @@
+use std::ops::ControlFlow;
+
+use peerdb_parser::NexusParsedStatement;
+use pgwire::{
+    api::{portal::Portal, results::FieldFormat, Type},
+    error::{ErrorInfo, PgWireError, PgWireResult},
+};
+use sqlparser::ast::{visit_expressions_mut, Expr, Statement, Value};
+
+/// Substitutes the parameters bound to a portal for the placeholders `$1`,
+/// `$2`, ... of its statement, as literals of the types the statement was
+/// prepared with. The statement then runs on a peer like any other query.
+pub fn bind(stmt: &mut Statement, portal: &Portal<NexusParsedStatement>) -> PgWireResult<()> {
+    let parameter_types = &portal.statement().statement().parameter_types;
+    let parameters = portal.parameters();
+    let res = visit_expressions_mut(stmt, |expr| {
+        let idx = match expr {
+            Expr::Value(Value::Placeholder(placeholder)) => placeholder
+                .strip_prefix('$')
+                .and_then(|n| n.parse::<usize>().ok())
+                .filter(|n| *n > 0),
+            _ => None,
+        };
+        if let Some(idx) = idx.map(|n| n - 1) {
+            let value = match parameters.get(idx) {
+                Some(value) => value.as_deref(),
+                None => {
+                    return ControlFlow::Break(PgWireError::UserError(Box::new(ErrorInfo::new(
+                        "ERROR".to_owned(),
+                        "42P02".to_owned(),
+                        format!("there is no parameter ${}", idx + 1),
+                    ))))
+                }
+            };
+            let param_type = parameter_types.get(idx).unwrap_or(&Type::TEXT);
+            let format = portal.parameter_format().format_for(idx);
+            match literal(param_type, format, value) {
+                Ok(literal) => *expr = Expr::Value(literal),
+                Err(err) => return ControlFlow::Break(err),
+            }
+        }
+        ControlFlow::Continue(())
+    });
+    match res {
+        ControlFlow::Break(err) => Err(err),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+/// The text of the parameters bound to a portal, for the peers that prepare
+/// its statement themselves. None when a parameter is sent in binary, which
+/// only nexus knows how to decode.
+pub fn text_parameters(portal: &Portal<NexusParsedStatement>) -> Option<Vec<Option<String>>> {
+    portal
+        .parameters()
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            if portal.parameter_format().format_for(idx) == FieldFormat::Binary {
+                return None;
+            }
+            match value {
+                Some(value) => std::str::from_utf8(value).ok().map(|s| Some(s.to_owned())),
+                None => Some(None),
+            }
+        })
+        .collect()
+}
+
+// the literal a parameter is substituted with. numbers and booleans are
+// checked before they become literals, so that a parameter can't smuggle sql
+// into the statement, everything else is a quoted string.
+fn literal(param_type: &Type, format: FieldFormat, value: Option<&[u8]>) -> PgWireResult<Value> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(Value::Null),
+    };
+    if format == FieldFormat::Binary {
+        return binary_literal(param_type, value);
+    }
+
+    let text = std::str::from_utf8(value)
+        .map_err(|_| invalid_parameter("parameter is not valid UTF-8".to_owned()))?;
+    match *param_type {
+        Type::BOOL => match text.to_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok(Value::Boolean(true)),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok(Value::Boolean(false)),
+            _ => Err(invalid_parameter(format!(
+                "invalid input syntax for type boolean: \"{}\"",
+                text
+            ))),
+        },
+        Type::INT2 | Type::INT4 | Type::INT8 | Type::OID => match text.trim().parse::<i64>() {
+            Ok(n) => Ok(Value::Number(n.to_string(), false)),
+            Err(_) => Err(invalid_parameter(format!(
+                "invalid input syntax for type integer: \"{}\"",
+                text
+            ))),
+        },
+        Type::FLOAT4 | Type::FLOAT8 | Type::NUMERIC => {
+            let number = text.trim();
+            let is_number = !number.is_empty()
+                && number
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+                && number.parse::<f64>().is_ok();
+            if is_number {
+                Ok(Value::Number(number.to_owned(), false))
+            } else {
+                // NaN and Infinity have no literal, postgres takes them quoted.
+                Ok(Value::SingleQuotedString(number.to_owned()))
+            }
+        }
+        _ => Ok(Value::SingleQuotedString(text.to_owned())),
+    }
+}
+
+fn binary_literal(param_type: &Type, value: &[u8]) -> PgWireResult<Value> {
+    let number = |n: String| Ok(Value::Number(n, false));
+    match *param_type {
+        Type::BOOL => Ok(Value::Boolean(fixed::<1>(param_type, value)?[0] != 0)),
+        Type::INT2 => number(i16::from_be_bytes(fixed(param_type, value)?).to_string()),
+        Type::INT4 => number(i32::from_be_bytes(fixed(param_type, value)?).to_string()),
+        Type::OID => number(u32::from_be_bytes(fixed(param_type, value)?).to_string()),
+        Type::INT8 => number(i64::from_be_bytes(fixed(param_type, value)?).to_string()),
+        Type::FLOAT4 => float_literal(f32::from_be_bytes(fixed(param_type, value)?) as f64),
+        Type::FLOAT8 => float_literal(f64::from_be_bytes(fixed(param_type, value)?)),
+        // these are sent as their text in binary too.
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::UNKNOWN | Type::JSON => {
+            std::str::from_utf8(value)
+                .map(|text| Value::SingleQuotedString(text.to_owned()))
+                .map_err(|_| invalid_parameter("parameter is not valid UTF-8".to_owned()))
+        }
+        Type::BYTEA => {
+            let hex: String = value.iter().map(|b| format!("{:02x}", b)).collect();
+            Ok(Value::SingleQuotedString(format!("\\x{}", hex)))
+        }
+        _ => Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "ERROR".to_owned(),
+            "0A000".to_owned(),
+            format!(
+                "binary parameters of type {} are not supported, send them as text",
+                param_type
+            ),
+        )))),
+    }
+}
+
+// the bytes of a binary parameter of a fixed size type.
+fn fixed<const N: usize>(param_type: &Type, value: &[u8]) -> PgWireResult<[u8; N]> {
+    value.try_into().map_err(|_| {
+        invalid_parameter(format!(
+            "invalid length {} for a binary parameter of type {}",
+            value.len(),
+            param_type
+        ))
+    })
+}
+
+fn float_literal(v: f64) -> PgWireResult<Value> {
+    if v.is_finite() {
+        Ok(Value::Number(v.to_string(), false))
+    } else if v.is_nan() {
+        Ok(Value::SingleQuotedString("NaN".to_owned()))
+    } else if v > 0.0 {
+        Ok(Value::SingleQuotedString("Infinity".to_owned()))
+    } else {
+        Ok(Value::SingleQuotedString("-Infinity".to_owned()))
+    }
+}
+
+fn invalid_parameter(err_msg: String) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        "22P02".to_owned(),
+        err_msg,
+    )))
+}
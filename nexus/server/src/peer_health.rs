@@ -0,0 +1,209 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use catalog::{Catalog, MirrorInfo};
+use dashmap::DashSet;
+use flow_rs::grpc::FlowGrpcClient;
+use peer_cursor::connector::ConnectorRegistry;
+use pt::peerdb_peers::Peer;
+use tokio::sync::Mutex;
+
+// a probe that takes longer than this counts as a failed health check.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(30);
+
+// PeerHealthMonitor probes every peer the nexus can connect to on an interval.
+// A peer that fails `threshold` probes in a row is quarantined in the catalog
+// and the query replication mirrors reading from or writing to it are paused,
+// once it passes `threshold` probes in a row it recovers and those mirrors are
+// resumed. The set of quarantined peers is shared with the backends, which
+// reject queries to them.
+pub struct PeerHealthMonitor {
+    catalog: Catalog,
+    flow_handler: Option<Arc<Mutex<FlowGrpcClient>>>,
+    connectors: Arc<ConnectorRegistry>,
+    threshold: u32,
+    quarantined_peers: Arc<DashSet<String>>,
+    failures: HashMap<String, u32>,
+    successes: HashMap<String, u32>,
+}
+
+impl PeerHealthMonitor {
+    pub fn new(
+        catalog: Catalog,
+        flow_handler: Option<Arc<Mutex<FlowGrpcClient>>>,
+        connectors: Arc<ConnectorRegistry>,
+        threshold: u32,
+        quarantined_peers: Arc<DashSet<String>>,
+    ) -> Self {
+        Self {
+            catalog,
+            flow_handler,
+            connectors,
+            threshold: threshold.max(1),
+            quarantined_peers,
+            failures: HashMap::new(),
+            successes: HashMap::new(),
+        }
+    }
+
+    pub async fn run(mut self, interval: Duration) {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+            if let Err(err) = self.check_peers().await {
+                tracing::error!("unable to check health of peers: {:?}", err);
+            }
+        }
+    }
+
+    async fn check_peers(&mut self) -> anyhow::Result<()> {
+        let peers = self.catalog.get_peer_infos().await?;
+        self.failures
+            .retain(|name, _| peers.iter().any(|info| &info.peer.name == name));
+        self.successes
+            .retain(|name, _| peers.iter().any(|info| &info.peer.name == name));
+
+        for info in &peers {
+            let name = &info.peer.name;
+            let healthy = match self.probe(&info.peer).await {
+                Some(healthy) => healthy,
+                None => continue,
+            };
+            let quarantined = info.quarantined_at.is_some();
+            if healthy {
+                self.failures.remove(name);
+                let successes = self.successes.entry(name.clone()).or_default();
+                *successes += 1;
+                if quarantined && *successes >= self.threshold {
+                    self.recover(name).await?;
+                }
+            } else {
+                self.successes.remove(name);
+                let failures = self.failures.entry(name.clone()).or_default();
+                *failures += 1;
+                if !quarantined && *failures >= self.threshold {
+                    self.quarantine(name).await?;
+                }
+            }
+        }
+
+        // peers can also be quarantined by other nexus instances, the catalog
+        // is the source of truth.
+        let quarantined_peers = self.catalog.get_quarantined_peers().await?;
+        self.quarantined_peers
+            .retain(|name| quarantined_peers.contains(name));
+        for name in quarantined_peers {
+            self.quarantined_peers.insert(name);
+        }
+        Ok(())
+    }
+
+    // returns whether the peer is reachable, or None for peers the nexus
+    // doesn't connect to itself.
+    async fn probe(&self, peer: &Peer) -> Option<bool> {
+        match tokio::time::timeout(PROBE_TIMEOUT, self.check_connection(peer)).await {
+            Ok(Ok(healthy)) => healthy,
+            Ok(Err(err)) => {
+                tracing::warn!("health check of peer {} failed: {:?}", peer.name, err);
+                Some(false)
+            }
+            Err(_) => {
+                tracing::warn!("health check of peer {} timed out", peer.name);
+                Some(false)
+            }
+        }
+    }
+
+    async fn check_connection(&self, peer: &Peer) -> anyhow::Result<Option<bool>> {
+        let connector = match self.connectors.get(peer) {
+            Some(connector) => connector,
+            None => return Ok(None),
+        };
+        let executor = connector.connect(peer).await?;
+        Ok(Some(executor.is_connection_valid().await?))
+    }
+
+    async fn quarantine(&self, peer_name: &str) -> anyhow::Result<()> {
+        tracing::warn!(
+            "quarantining peer {} after {} failed health checks",
+            peer_name,
+            self.threshold
+        );
+        self.catalog.set_peer_quarantined(peer_name, true).await?;
+
+        for mirror in self.qrep_mirrors_of(peer_name).await? {
+            if !mirror.paused {
+                self.set_mirror_paused(&mirror.name, true).await;
+            }
+        }
+        Ok(())
+    }
+
+    async fn recover(&self, peer_name: &str) -> anyhow::Result<()> {
+        tracing::info!("peer {} recovered from quarantine", peer_name);
+        self.catalog.set_peer_quarantined(peer_name, false).await?;
+
+        // a mirror between two quarantined peers stays paused until both recover.
+        let quarantined_peers = self.catalog.get_quarantined_peers().await?;
+        for mirror in self.qrep_mirrors_of(peer_name).await? {
+            if mirror.paused_by_quarantine
+                && !quarantined_peers.contains(&mirror.source_peer)
+                && !quarantined_peers.contains(&mirror.destination_peer)
+            {
+                self.set_mirror_paused(&mirror.name, false).await;
+            }
+        }
+        Ok(())
+    }
+
+    async fn qrep_mirrors_of(&self, peer_name: &str) -> anyhow::Result<Vec<MirrorInfo>> {
+        let mirrors = self.catalog.get_mirrors().await?;
+        Ok(mirrors
+            .into_iter()
+            .filter(|mirror| {
+                !mirror.is_cdc
+                    && (mirror.source_peer == peer_name || mirror.destination_peer == peer_name)
+            })
+            .collect())
+    }
+
+    // failing to pause or resume a mirror doesn't stop the peer from being
+    // quarantined or recovered, the mirror is left as it is.
+    async fn set_mirror_paused(&self, flow_job_name: &str, paused: bool) {
+        if let Err(err) = self.try_set_mirror_paused(flow_job_name, paused).await {
+            let action = if paused { "pause" } else { "resume" };
+            tracing::error!("unable to {} mirror {}: {:?}", action, flow_job_name, err);
+        }
+    }
+
+    async fn try_set_mirror_paused(&self, flow_job_name: &str, paused: bool) -> anyhow::Result<()> {
+        let flow_handler = match &self.flow_handler {
+            Some(flow_handler) => flow_handler,
+            None => return Ok(()),
+        };
+        // query replication mirrors that were created disabled never started.
+        let workflow_details = match self
+            .catalog
+            .get_workflow_details_for_flow_job(flow_job_name)
+            .await
+        {
+            Ok(Some(workflow_details)) => workflow_details,
+            _ => return Ok(()),
+        };
+
+        {
+            let mut flow_handler = flow_handler.lock().await;
+            if paused {
+                flow_handler
+                    .pause_flow_job(flow_job_name, workflow_details)
+                    .await?;
+            } else {
+                flow_handler
+                    .resume_flow_job(flow_job_name, workflow_details)
+                    .await?;
+            }
+        }
+        self.catalog
+            .set_flow_job_paused_by_quarantine(flow_job_name, paused)
+            .await
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use peer_cursor::QueryExecutor;
+
+/// How long sessions hold their connections to peers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum PoolMode {
+    /// A session holds its connection to a peer until it ends.
+    Session,
+    /// A session holds its connection to a peer for a transaction, or for a
+    /// statement outside of one, and hands it back to the pool after.
+    Transaction,
+}
+
+// PeerConnectionPool keeps the connections to peers sessions in transaction
+// pooling mode handed back, by the name of their peer, for the next session
+// running a statement on the peer. Like pgbouncer, the state sessions leave
+// on a connection is reset before it is handed to the next one, so bursty
+// clients share a few connections to a peer instead of holding one each. The
+// connections are kept along with the catalog version of the peer they were
+// made with, a session doesn't get one made before the peer was altered.
+pub struct PeerConnectionPool {
+    idle: DashMap<String, Vec<(i32, Arc<Box<dyn QueryExecutor>>)>>,
+    max_idle_per_peer: usize,
+}
+
+impl PeerConnectionPool {
+    pub fn new(max_idle_per_peer: usize) -> Self {
+        Self {
+            idle: DashMap::new(),
+            max_idle_per_peer,
+        }
+    }
+
+    /// An idle connection to the peer, None when the session has to connect.
+    /// The connections to older versions of the peer are closed.
+    pub fn checkout(&self, peer_name: &str, version: i32) -> Option<Arc<Box<dyn QueryExecutor>>> {
+        let mut idle = self.idle.get_mut(peer_name)?;
+        idle.retain(|(idle_version, _)| *idle_version == version);
+        idle.pop().map(|(_, executor)| executor)
+    }
+
+    /// Hands a connection back once the session is done with it. It is closed
+    /// instead when something still uses it, its state can't be reset, or the
+    /// pool already keeps enough connections to the peer.
+    pub async fn checkin(
+        &self,
+        peer_name: &str,
+        version: i32,
+        executor: Arc<Box<dyn QueryExecutor>>,
+    ) {
+        if Arc::strong_count(&executor) > 1 {
+            return;
+        }
+        if let Err(err) = executor.reset_session().await {
+            tracing::warn!(
+                "closing a connection to peer {} that could not be reset: {:?}",
+                peer_name,
+                err
+            );
+            return;
+        }
+        // the notices the peer sent while the session ended aren't the next
+        // session's.
+        executor.take_notices();
+        executor.take_notifications();
+        let mut idle = self.idle.entry(peer_name.to_owned()).or_default();
+        if idle.len() < self.max_idle_per_peer {
+            idle.push((version, executor));
+        }
+    }
+
+    /// Closes the idle connections to the peer, once it is altered or dropped.
+    pub fn evict(&self, peer_name: &str) {
+        self.idle.remove(peer_name);
+    }
+}
@@ -0,0 +1,536 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use catalog::ResourceGroup;
+use dashmap::DashMap;
+use futures::Stream;
+use peer_cursor::{Record, RecordStream, SchemaRef, SendableStream};
+use pgwire::error::PgWireResult;
+use tokio::sync::{oneshot, OwnedSemaphorePermit, Semaphore};
+use uuid::Uuid;
+
+// QueryScheduler bounds the number of peer queries each resource group can
+// run at once, so that a single tenant can't monopolize warehouse concurrency.
+// A group is granted `permits_per_weight` concurrent queries per unit of weight,
+// unless it sets an explicit max_concurrency.
+//
+// Weights only apply to how many queries a group runs at once, and to its share
+// of the slots of a peer: nexus doesn't see the CPU or IO a query costs the
+// warehouse, so a group running few expensive queries isn't held back more than
+// one running as many cheap ones.
+//
+// Admitted queries then wait for one of the `peer_concurrency` slots of the
+// peer they run on, which are handed out fairly across sessions, see PeerQueue.
+pub struct QueryScheduler {
+    permits_per_weight: u32,
+    peer_concurrency: usize,
+    groups: DashMap<String, Arc<GroupSlots>>,
+    peers: DashMap<String, Arc<PeerQueue>>,
+}
+
+/// The slots a query holds while it runs, they are released when the permit
+/// is dropped.
+pub struct QueryPermit {
+    _group: GroupPermit,
+    _peer: Option<PeerPermit>,
+}
+
+impl QueryPermit {
+    /// Keeps the slots of the query for as long as its rows are streamed, a
+    /// peer runs the query until its rows are read.
+    pub fn hold(self: Arc<Self>, rows: SendableStream) -> SendableStream {
+        Box::pin(HeldStream {
+            rows,
+            _permit: self,
+        })
+    }
+}
+
+struct HeldStream {
+    rows: SendableStream,
+    _permit: Arc<QueryPermit>,
+}
+
+impl Stream for HeldStream {
+    type Item = PgWireResult<Record>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rows.as_mut().poll_next(cx)
+    }
+}
+
+impl RecordStream for HeldStream {
+    fn schema(&self) -> SchemaRef {
+        self.rows.schema()
+    }
+}
+
+/// The queries running on a peer and waiting for it, as reported by SHOW PEERS.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PeerQueueStats {
+    pub running: usize,
+    pub queued: usize,
+}
+
+impl QueryScheduler {
+    pub fn new(permits_per_weight: u32, peer_concurrency: u32) -> Self {
+        Self {
+            permits_per_weight,
+            peer_concurrency: peer_concurrency as usize,
+            groups: DashMap::new(),
+            peers: DashMap::new(),
+        }
+    }
+
+    fn permits_for(&self, group: &ResourceGroup) -> usize {
+        match group.max_concurrency {
+            Some(max_concurrency) => max_concurrency.max(1) as usize,
+            None => group.weight.max(1) as usize * self.permits_per_weight.max(1) as usize,
+        }
+    }
+
+    // wait for a free slot in the group, and on the peer the query runs on if
+    // peers are limited. a session is weighted by the weight of its group.
+    pub async fn acquire(
+        &self,
+        group: &ResourceGroup,
+        session: Uuid,
+        peer_name: Option<&str>,
+    ) -> QueryPermit {
+        let group_permit = self.acquire_group(group).await;
+        let peer_permit = match peer_name {
+            Some(peer_name) if self.peer_concurrency > 0 => {
+                let queue = Arc::clone(
+                    self.peers
+                        .entry(peer_name.to_owned())
+                        .or_insert_with(|| Arc::new(PeerQueue::new(self.peer_concurrency)))
+                        .value(),
+                );
+                Some(queue.acquire(session, group.weight.max(1) as usize).await)
+            }
+            _ => None,
+        };
+        QueryPermit {
+            _group: group_permit,
+            _peer: peer_permit,
+        }
+    }
+
+    async fn acquire_group(&self, group: &ResourceGroup) -> GroupPermit {
+        let permits = self.permits_for(group);
+        let slots = {
+            let entry = self
+                .groups
+                .entry(group.name.clone())
+                .or_insert_with(|| Arc::new(GroupSlots::new(permits)));
+            // the group was reconfigured in the catalog.
+            entry.resize(permits);
+            Arc::clone(entry.value())
+        };
+
+        // the semaphore is never closed, so acquiring cannot fail.
+        let permit = Arc::clone(&slots.semaphore).acquire_owned().await.unwrap();
+        GroupPermit {
+            slots,
+            permit: Some(permit),
+        }
+    }
+
+    pub fn peer_stats(&self, peer_name: &str) -> PeerQueueStats {
+        self.peers
+            .get(peer_name)
+            .map(|queue| queue.stats())
+            .unwrap_or_default()
+    }
+}
+
+// the slots of a resource group. A group reconfigured with fewer slots than
+// its queries hold retires the excess ones as the queries finish, rather than
+// handing them to the queries waiting.
+struct GroupSlots {
+    semaphore: Arc<Semaphore>,
+    state: Mutex<GroupSlotsState>,
+}
+
+struct GroupSlotsState {
+    permits: usize,
+    // slots held by running queries that are retired once they are released.
+    excess: usize,
+}
+
+struct GroupPermit {
+    slots: Arc<GroupSlots>,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl GroupSlots {
+    fn new(permits: usize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(permits)),
+            state: Mutex::new(GroupSlotsState { permits, excess: 0 }),
+        }
+    }
+
+    fn resize(&self, permits: usize) {
+        let mut state = self.state.lock().unwrap();
+        if permits > state.permits {
+            // slots still to be retired are kept instead of adding new ones.
+            let added = permits - state.permits;
+            let kept = added.min(state.excess);
+            state.excess -= kept;
+            self.semaphore.add_permits(added - kept);
+        } else if permits < state.permits {
+            let removed = state.permits - permits;
+            let mut forgotten = 0;
+            while forgotten < removed {
+                match self.semaphore.try_acquire() {
+                    Ok(permit) => permit.forget(),
+                    Err(_) => break,
+                }
+                forgotten += 1;
+            }
+            state.excess += removed - forgotten;
+        }
+        state.permits = permits;
+    }
+
+    fn release(&self, permit: OwnedSemaphorePermit) {
+        let mut state = self.state.lock().unwrap();
+        if state.excess > 0 {
+            state.excess -= 1;
+            permit.forget();
+        }
+    }
+}
+
+impl Drop for GroupPermit {
+    fn drop(&mut self) {
+        if let Some(permit) = self.permit.take() {
+            self.slots.release(permit);
+        }
+    }
+}
+
+// PeerQueue hands out the slots of a peer. A query that finds no free slot
+// waits in the queue of its session, and a freed slot goes to the session at
+// the front of the round, which takes as many slots in a row as its weight
+// before it moves to the back. A session thus waits for at most one round of
+// the other sessions, however many queries they have queued.
+struct PeerQueue {
+    capacity: usize,
+    state: Mutex<PeerQueueState>,
+}
+
+#[derive(Default)]
+struct PeerQueueState {
+    running: usize,
+    // sessions with waiting queries, in round-robin order.
+    round: VecDeque<SessionQueue>,
+    // slots the session at the front of the round can still take in a row.
+    credit: usize,
+}
+
+struct SessionQueue {
+    session: Uuid,
+    weight: usize,
+    waiters: VecDeque<oneshot::Sender<()>>,
+}
+
+struct PeerPermit {
+    queue: Arc<PeerQueue>,
+}
+
+// a query waiting for a slot. one that is canceled while waiting, e.g. by the
+// request timeout, gives up its place, or the slot it was just handed.
+struct Waiter {
+    queue: Arc<PeerQueue>,
+    receiver: Option<oneshot::Receiver<()>>,
+}
+
+impl PeerQueue {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(PeerQueueState::default()),
+        }
+    }
+
+    async fn acquire(self: &Arc<Self>, session: Uuid, weight: usize) -> PeerPermit {
+        let receiver = {
+            let mut state = self.state.lock().unwrap();
+            if state.running < self.capacity && state.round.is_empty() {
+                state.running += 1;
+                return PeerPermit {
+                    queue: Arc::clone(self),
+                };
+            }
+            let (sender, receiver) = oneshot::channel();
+            state.enqueue(session, weight, sender);
+            receiver
+        };
+
+        let mut waiter = Waiter {
+            queue: Arc::clone(self),
+            receiver: Some(receiver),
+        };
+        if let Some(receiver) = waiter.receiver.as_mut() {
+            // senders are only dropped by the queue once their receiver is closed.
+            let _ = receiver.await;
+        }
+        waiter.receiver = None;
+        PeerPermit {
+            queue: Arc::clone(self),
+        }
+    }
+
+    // the slot of a finished query goes to the next waiting query, if any.
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        while let Some(sender) = state.next_waiter() {
+            if sender.send(()).is_ok() {
+                return;
+            }
+        }
+        state.running -= 1;
+    }
+
+    fn stats(&self) -> PeerQueueStats {
+        let state = self.state.lock().unwrap();
+        let queued = state
+            .round
+            .iter()
+            .flat_map(|queue| queue.waiters.iter())
+            .filter(|sender| !sender.is_closed())
+            .count();
+        PeerQueueStats {
+            running: state.running,
+            queued,
+        }
+    }
+}
+
+impl PeerQueueState {
+    fn enqueue(&mut self, session: Uuid, weight: usize, sender: oneshot::Sender<()>) {
+        match self.round.iter_mut().find(|queue| queue.session == session) {
+            Some(queue) => queue.waiters.push_back(sender),
+            None => {
+                if self.round.is_empty() {
+                    self.credit = weight;
+                }
+                self.round.push_back(SessionQueue {
+                    session,
+                    weight,
+                    waiters: VecDeque::from([sender]),
+                });
+            }
+        }
+    }
+
+    // takes the next waiting query in round-robin order.
+    fn next_waiter(&mut self) -> Option<oneshot::Sender<()>> {
+        let front = self.round.front_mut()?;
+        let waiter = front.waiters.pop_front();
+        self.credit = self.credit.saturating_sub(1);
+        if front.waiters.is_empty() {
+            self.round.pop_front();
+        } else if self.credit == 0 {
+            self.round.rotate_left(1);
+        } else {
+            return waiter;
+        }
+        self.credit = self.round.front().map_or(0, |queue| queue.weight);
+        waiter
+    }
+}
+
+impl Drop for PeerPermit {
+    fn drop(&mut self) {
+        self.queue.release();
+    }
+}
+
+impl Drop for Waiter {
+    fn drop(&mut self) {
+        if let Some(mut receiver) = self.receiver.take() {
+            receiver.close();
+            if receiver.try_recv().is_ok() {
+                self.queue.release();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_slots_resize() {
+        let slots = GroupSlots::new(2);
+        let first = Arc::clone(&slots.semaphore).try_acquire_owned().unwrap();
+        let second = Arc::clone(&slots.semaphore).try_acquire_owned().unwrap();
+
+        // both slots are held, they are retired as their queries finish.
+        slots.resize(1);
+        slots.release(first);
+        assert_eq!(slots.semaphore.available_permits(), 0);
+        slots.release(second);
+        assert_eq!(slots.semaphore.available_permits(), 1);
+
+        slots.resize(3);
+        assert_eq!(slots.semaphore.available_permits(), 3);
+        slots.resize(2);
+        assert_eq!(slots.semaphore.available_permits(), 2);
+    }
+
+    #[test]
+    fn test_group_slots_grow_keeps_retiring_slots() {
+        let slots = GroupSlots::new(2);
+        let first = Arc::clone(&slots.semaphore).try_acquire_owned().unwrap();
+        let second = Arc::clone(&slots.semaphore).try_acquire_owned().unwrap();
+
+        slots.resize(0);
+        slots.resize(1);
+        // one of the held slots is kept rather than a new one added.
+        assert_eq!(slots.semaphore.available_permits(), 0);
+        slots.release(first);
+        assert_eq!(slots.semaphore.available_permits(), 0);
+        slots.release(second);
+        assert_eq!(slots.semaphore.available_permits(), 1);
+    }
+
+    // hands out every waiting query in turn, naming the session it came from.
+    fn drain(state: &mut PeerQueueState, waiters: &mut [(&str, oneshot::Receiver<()>)]) -> String {
+        let mut order = vec![];
+        while let Some(sender) = state.next_waiter() {
+            sender.send(()).unwrap();
+            let handed = waiters
+                .iter_mut()
+                .position(|(_, receiver)| receiver.try_recv().is_ok())
+                .unwrap();
+            order.push(waiters[handed].0);
+        }
+        order.join(" ")
+    }
+
+    fn enqueue<'a>(
+        state: &mut PeerQueueState,
+        waiters: &mut Vec<(&'a str, oneshot::Receiver<()>)>,
+        name: &'a str,
+        session: Uuid,
+        weight: usize,
+    ) {
+        let (sender, receiver) = oneshot::channel();
+        state.enqueue(session, weight, sender);
+        waiters.push((name, receiver));
+    }
+
+    #[test]
+    fn test_peer_queue_weighted_round_robin() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut state = PeerQueueState::default();
+        let mut waiters = vec![];
+        for name in ["a1", "a2", "a3", "a4", "a5"] {
+            enqueue(&mut state, &mut waiters, name, a, 2);
+        }
+        for name in ["b1", "b2"] {
+            enqueue(&mut state, &mut waiters, name, b, 1);
+        }
+        for name in ["c1", "c2", "c3"] {
+            enqueue(&mut state, &mut waiters, name, c, 1);
+        }
+
+        // a session takes as many slots in a row as its weight, however many
+        // queries the sessions queued.
+        assert_eq!(
+            drain(&mut state, &mut waiters),
+            "a1 a2 b1 c1 a3 a4 b2 c2 a5 c3"
+        );
+        assert!(state.round.is_empty());
+        assert_eq!(state.credit, 0);
+    }
+
+    #[test]
+    fn test_peer_queue_credit_reset() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut state = PeerQueueState::default();
+        let mut waiters = vec![];
+        enqueue(&mut state, &mut waiters, "a1", a, 1);
+        assert_eq!(state.credit, 1);
+        enqueue(&mut state, &mut waiters, "a2", a, 1);
+        for name in ["b1", "b2", "b3", "b4"] {
+            enqueue(&mut state, &mut waiters, name, b, 3);
+        }
+        // joining the round leaves the credit of the session at its front.
+        assert_eq!(state.credit, 1);
+
+        // a rotation hands the new front the credit of its own weight, rather
+        // than what the previous one had left.
+        assert!(state.next_waiter().is_some());
+        assert_eq!(state.round.front().unwrap().session, b);
+        assert_eq!(state.credit, 3);
+        assert!(state.next_waiter().is_some());
+        assert_eq!(state.credit, 2);
+        assert!(state.next_waiter().is_some());
+        assert!(state.next_waiter().is_some());
+        assert_eq!(state.round.front().unwrap().session, a);
+        assert_eq!(state.credit, 1);
+
+        // so does a session leaving the round once its queries ran.
+        assert!(state.next_waiter().is_some());
+        assert_eq!(state.round.front().unwrap().session, b);
+        assert_eq!(state.credit, 3);
+        assert!(state.next_waiter().is_some());
+        assert!(state.next_waiter().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_peer_queue_canceled_waiter() {
+        let queue = Arc::new(PeerQueue::new(1));
+        let permit = queue.acquire(Uuid::new_v4(), 1).await;
+
+        // a query canceled while waiting gives up its place.
+        let canceled = tokio::time::timeout(
+            std::time::Duration::from_millis(10),
+            queue.acquire(Uuid::new_v4(), 1),
+        )
+        .await;
+        assert!(canceled.is_err());
+        assert_eq!(queue.stats().queued, 0);
+
+        // a query canceled once it was handed the slot passes it on.
+        let (sender, receiver) = oneshot::channel();
+        queue
+            .state
+            .lock()
+            .unwrap()
+            .enqueue(Uuid::new_v4(), 1, sender);
+        let handed = Waiter {
+            queue: Arc::clone(&queue),
+            receiver: Some(receiver),
+        };
+        let next = tokio::spawn({
+            let queue = Arc::clone(&queue);
+            async move { queue.acquire(Uuid::new_v4(), 1).await }
+        });
+        while queue.stats().queued < 2 {
+            tokio::task::yield_now().await;
+        }
+
+        drop(permit);
+        assert_eq!(queue.stats().running, 1);
+        drop(handed);
+        let next = next.await.unwrap();
+        let stats = queue.stats();
+        assert_eq!((stats.running, stats.queued), (1, 0));
+
+        drop(next);
+        let stats = queue.stats();
+        assert_eq!((stats.running, stats.queued), (0, 0));
+        assert!(queue.state.lock().unwrap().round.is_empty());
+    }
+}
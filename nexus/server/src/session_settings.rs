@@ -0,0 +1,288 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context as TaskContext, Poll},
+    time::Duration,
+};
+
+use anyhow::Context;
+use futures::Stream;
+use peer_cursor::{Record, RecordStream, Records, Schema, SchemaRef, SendableStream};
+use peerdb_parser::SESSION_SETTINGS;
+use pgwire::{
+    api::{
+        results::{FieldFormat, FieldInfo},
+        Type,
+    },
+    error::{ErrorInfo, PgWireError, PgWireResult},
+};
+use tokio::time::{Instant, Sleep};
+use value::Value;
+
+// the settings a session can show but not set.
+const READ_ONLY_SETTINGS: &[&str] = &[
+    "integer_datetimes",
+    "server_encoding",
+    "server_version",
+    "standard_conforming_strings",
+];
+
+// the settings that change how a postgres peer runs the queries of a session,
+// they are set on the connection of the session to the peer too.
+const FORWARDED_SETTINGS: &[&str] = &[
+    "application_name",
+    "datestyle",
+    "intervalstyle",
+    "search_path",
+    "timezone",
+];
+
+/// The settings nexus keeps for a session, see `SESSION_SETTINGS` of the
+/// parser. The statement timeout of a session cancels its statements like
+/// the request timeout of the server, which it defaults to, and the idle
+/// session timeout closes the session once it ran no query for that long.
+pub struct SessionSettings {
+    values: HashMap<String, String>,
+    default_statement_timeout: Option<Duration>,
+    statement_timeout: Option<Duration>,
+    default_idle_session_timeout: Option<Duration>,
+    idle_session_timeout: Option<Duration>,
+}
+
+impl SessionSettings {
+    pub fn new(
+        default_statement_timeout: Option<Duration>,
+        default_idle_session_timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            values: HashMap::new(),
+            default_statement_timeout,
+            statement_timeout: default_statement_timeout,
+            default_idle_session_timeout,
+            idle_session_timeout: default_idle_session_timeout,
+        }
+    }
+
+    // sets a setting, or resets it to its default when there is no value.
+    pub fn set(&mut self, name: &str, value: Option<String>) -> anyhow::Result<()> {
+        if READ_ONLY_SETTINGS.contains(&name) {
+            anyhow::bail!("parameter \"{}\" cannot be changed", name);
+        }
+        if name == "client_encoding" {
+            let encoding = value.as_deref().unwrap_or("UTF8").to_uppercase();
+            if !matches!(encoding.as_str(), "UTF8" | "UTF-8" | "UNICODE") {
+                anyhow::bail!(
+                    "client_encoding {} is not supported, nexus only sends UTF8",
+                    encoding
+                );
+            }
+        }
+        if name == "statement_timeout" {
+            self.statement_timeout = match &value {
+                Some(value) => parse_timeout(name, value)?,
+                None => self.default_statement_timeout,
+            };
+        }
+        if name == "idle_session_timeout" {
+            self.idle_session_timeout = match &value {
+                Some(value) => parse_timeout(name, value)?,
+                None => self.default_idle_session_timeout,
+            };
+        }
+        match value {
+            Some(value) => self.values.insert(name.to_owned(), value),
+            None => self.values.remove(name),
+        };
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> String {
+        if name == "statement_timeout" {
+            return format_timeout(self.statement_timeout);
+        }
+        if name == "idle_session_timeout" {
+            return format_timeout(self.idle_session_timeout);
+        }
+        self.values
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| default_value(name).to_owned())
+    }
+
+    pub fn statement_timeout(&self) -> Option<Duration> {
+        self.statement_timeout
+    }
+
+    pub fn idle_session_timeout(&self) -> Option<Duration> {
+        self.idle_session_timeout
+    }
+
+    /// The settings the session set that are set on its postgres peers too.
+    pub fn forwarded(&self) -> Vec<(String, String)> {
+        self.values
+            .iter()
+            .filter(|(name, _)| is_forwarded(name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect()
+    }
+}
+
+pub fn is_forwarded(name: &str) -> bool {
+    FORWARDED_SETTINGS.contains(&name)
+}
+
+/// The settings a client sends in its startup message, as parameters of
+/// their own or as `-c name=value` in its options, like PGOPTIONS sets them.
+/// Parameters that aren't settings nexus keeps, like the user, are left out.
+pub fn startup_settings(parameters: &HashMap<String, String>) -> Vec<(String, String)> {
+    let mut settings: Vec<(String, String)> = parameters
+        .iter()
+        .map(|(name, value)| (name.to_lowercase(), value.clone()))
+        .collect();
+    if let Some(options) = parameters.get("options") {
+        let mut options = options.split_whitespace();
+        while let Some(option) = options.next() {
+            let setting = match option {
+                "-c" => options.next(),
+                option => option
+                    .strip_prefix("-c")
+                    .or_else(|| option.strip_prefix("--")),
+            };
+            if let Some((name, value)) = setting.and_then(|s| s.split_once('=')) {
+                settings.push((name.to_lowercase().replace('-', "_"), value.to_owned()));
+            }
+        }
+    }
+    settings.retain(|(name, _)| {
+        SESSION_SETTINGS.contains(&name.as_str()) && !READ_ONLY_SETTINGS.contains(&name.as_str())
+    });
+    settings
+}
+
+// the values of the settings a session didn't set, the same as the parameters
+// reported to clients on connect.
+fn default_value(name: &str) -> &'static str {
+    match name {
+        "client_encoding" | "server_encoding" => "UTF8",
+        "datestyle" => "ISO, MDY",
+        "extra_float_digits" => "1",
+        "intervalstyle" => "postgres",
+        "search_path" => "\"$user\", public",
+        "timezone" => "UTC",
+        "integer_datetimes" | "standard_conforming_strings" => "on",
+        "server_version" => "14",
+        _ => "",
+    }
+}
+
+// a timeout the way postgres takes it, in milliseconds unless it has a unit.
+// a timeout of 0 disables it.
+fn parse_timeout(name: &str, value: &str) -> anyhow::Result<Option<Duration>> {
+    let value = value.trim();
+    let (amount, unit) = value.split_at(
+        value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len()),
+    );
+    let amount: u64 = amount
+        .parse()
+        .with_context(|| format!("invalid value for {}: \"{}\"", name, value))?;
+    let unit_millis = match unit.trim() {
+        "" | "ms" => 1,
+        "s" => 1000,
+        "min" => 60 * 1000,
+        "h" => 60 * 60 * 1000,
+        "d" => 24 * 60 * 60 * 1000,
+        unit => anyhow::bail!(
+            "invalid unit for {}: \"{}\", valid units are ms, s, min, h and d",
+            name,
+            unit
+        ),
+    };
+    Ok((amount > 0).then(|| Duration::from_millis(amount * unit_millis)))
+}
+
+pub fn format_timeout(timeout: Option<Duration>) -> String {
+    match timeout {
+        None => "0".to_owned(),
+        Some(timeout) if timeout.as_millis() % 1000 == 0 => format!("{}s", timeout.as_secs()),
+        Some(timeout) => format!("{}ms", timeout.as_millis()),
+    }
+}
+
+pub fn statement_timeout_error(timeout: Duration) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_owned(),
+        "57014".to_owned(),
+        format!(
+            "canceling statement due to statement timeout of {}",
+            format_timeout(Some(timeout))
+        ),
+    )))
+}
+
+/// Ends the rows of a statement with a statement timeout error once its
+/// deadline passes, the rows a peer streams after the statement returns are
+/// bound by its statement timeout too.
+pub fn with_deadline(rows: SendableStream, deadline: Instant, timeout: Duration) -> SendableStream {
+    Box::pin(DeadlineStream {
+        rows,
+        deadline: Box::pin(tokio::time::sleep_until(deadline)),
+        timeout,
+        expired: false,
+    })
+}
+
+struct DeadlineStream {
+    rows: SendableStream,
+    deadline: Pin<Box<Sleep>>,
+    timeout: Duration,
+    expired: bool,
+}
+
+impl Stream for DeadlineStream {
+    type Item = PgWireResult<Record>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        if self.expired {
+            return Poll::Ready(None);
+        }
+        if self.deadline.as_mut().poll(cx).is_ready() {
+            self.expired = true;
+            return Poll::Ready(Some(Err(statement_timeout_error(self.timeout))));
+        }
+        self.rows.as_mut().poll_next(cx)
+    }
+}
+
+impl RecordStream for DeadlineStream {
+    fn schema(&self) -> SchemaRef {
+        self.rows.schema()
+    }
+}
+
+// like postgres, a setting is shown as a single row of a column named after it.
+pub fn schema(name: &str) -> SchemaRef {
+    Arc::new(Schema {
+        fields: vec![FieldInfo::new(
+            name.to_owned(),
+            None,
+            None,
+            Type::TEXT,
+            FieldFormat::Text,
+        )],
+    })
+}
+
+pub fn records(name: &str, value: String) -> Records {
+    let schema = schema(name);
+    Records {
+        records: vec![Record {
+            values: vec![Value::Text(value)],
+            schema: schema.clone(),
+        }],
+        schema,
+    }
+}
@@ -0,0 +1,134 @@
+use std::{collections::HashSet, ops::ControlFlow};
+
+use sqlparser::ast::{
+    visit_relations, visit_relations_mut, Ident, ObjectName, ObjectType, Statement,
+};
+
+/// The tables a session staged with `CREATE TEMP TABLE ... AS SELECT`. They
+/// live in a schema of the catalog that belongs to the session, queries refer
+/// to them by their unqualified names and the schema is dropped along with
+/// the tables when the session ends.
+pub struct SessionTables {
+    schema: String,
+    tables: HashSet<String>,
+    // whether the schema may exist in the catalog, it is created along with
+    // the first table the session stages.
+    schema_created: bool,
+}
+
+impl SessionTables {
+    pub fn new() -> Self {
+        Self {
+            schema: format!("_peerdb_session_{}", uuid::Uuid::new_v4().to_simple()),
+            tables: HashSet::new(),
+            schema_created: false,
+        }
+    }
+
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    pub fn schema_created(&self) -> bool {
+        self.schema_created
+    }
+
+    pub fn set_schema_created(&mut self) {
+        self.schema_created = true;
+    }
+
+    pub fn contains(&self, table: &str) -> bool {
+        self.tables.contains(table)
+    }
+
+    pub fn insert(&mut self, table: String) {
+        self.tables.insert(table);
+    }
+
+    pub fn remove(&mut self, table: &str) {
+        self.tables.remove(table);
+    }
+
+    fn session_table<'a>(&self, name: &'a ObjectName) -> Option<&'a Ident> {
+        match name.0.as_slice() {
+            [table] if self.contains(&table.value.to_lowercase()) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Whether the relation is one of the session's tables.
+    pub fn is_session_table(&self, name: &ObjectName) -> bool {
+        self.session_table(name).is_some()
+    }
+
+    /// Whether the statement refers to any of the session's tables.
+    pub fn is_referenced(&self, stmt: &Statement) -> bool {
+        let referenced = visit_relations(stmt, |relation| {
+            if self.session_table(relation).is_some() {
+                return ControlFlow::Break(());
+            }
+            ControlFlow::Continue(())
+        });
+        referenced.is_break() || !self.dropped_tables(stmt).is_empty()
+    }
+
+    /// Whether the statement refers to the session's tables and no others.
+    pub fn is_only_referenced(&self, stmt: &Statement) -> bool {
+        if let Statement::Drop {
+            object_type: ObjectType::Table,
+            names,
+            ..
+        } = stmt
+        {
+            return names.iter().all(|name| self.session_table(name).is_some());
+        }
+        let other = visit_relations(stmt, |relation| {
+            if self.session_table(relation).is_none() {
+                return ControlFlow::Break(());
+            }
+            ControlFlow::Continue(())
+        });
+        other.is_continue() && self.is_referenced(stmt)
+    }
+
+    /// The session tables that a `DROP TABLE` statement drops.
+    pub fn dropped_tables(&self, stmt: &Statement) -> Vec<String> {
+        match stmt {
+            Statement::Drop {
+                object_type: ObjectType::Table,
+                names,
+                ..
+            } => names
+                .iter()
+                .filter_map(|name| self.session_table(name))
+                .map(|table| table.value.to_lowercase())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Qualifies the references to the session's tables with the schema of
+    /// the session, so the statement can run on the catalog.
+    pub fn qualify(&self, stmt: &mut Statement) {
+        let qualify_name = |name: &mut ObjectName| {
+            if self.session_table(name).is_some() {
+                name.0
+                    .insert(0, Ident::with_quote('"', self.schema.clone()));
+            }
+        };
+
+        // visit_relations_mut doesn't visit the names of dropped tables.
+        if let Statement::Drop {
+            object_type: ObjectType::Table,
+            names,
+            ..
+        } = stmt
+        {
+            names.iter_mut().for_each(qualify_name);
+        }
+        visit_relations_mut(stmt, |relation| {
+            qualify_name(relation);
+            ControlFlow::<()>::Continue(())
+        });
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use catalog::MirrorInfo;
+use peer_cursor::{Record, Records, Schema, SchemaRef};
+use pgwire::api::{
+    results::{FieldFormat, FieldInfo},
+    Type,
+};
+use pt::peerdb_route::{mirror_status_response::Status, MirrorStatusResponse};
+use value::Value;
+
+pub fn schema() -> SchemaRef {
+    let field = |name: &str, datatype: Type| {
+        FieldInfo::new(name.to_owned(), None, None, datatype, FieldFormat::Text)
+    };
+    Arc::new(Schema {
+        fields: vec![
+            field("name", Type::TEXT),
+            field("source_peer", Type::TEXT),
+            field("destination_peer", Type::TEXT),
+            field("type", Type::TEXT),
+            field("state", Type::TEXT),
+            field("last_sync_time", Type::TIMESTAMP),
+            field("comment", Type::TEXT),
+        ],
+    })
+}
+
+pub fn records(
+    mirrors: impl Iterator<Item = (MirrorInfo, Option<MirrorStatusResponse>)>,
+) -> Records {
+    let schema = schema();
+    let records = mirrors
+        .map(|(mirror, status)| {
+            let mirror_type = if mirror.is_cdc { "CDC" } else { "QRep" };
+            let last_sync_time = status
+                .as_ref()
+                .and_then(last_sync_time)
+                .map(Value::PostgresTimestamp)
+                .unwrap_or(Value::Null);
+            Record {
+                values: vec![
+                    Value::Text(mirror.name.clone()),
+                    Value::Text(mirror.source_peer.clone()),
+                    Value::Text(mirror.destination_peer.clone()),
+                    Value::Text(mirror_type.to_owned()),
+                    Value::Text(state(&mirror, status.as_ref()).to_owned()),
+                    last_sync_time,
+                    mirror
+                        .comment
+                        .clone()
+                        .map(Value::Text)
+                        .unwrap_or(Value::Null),
+                ],
+                schema: schema.clone(),
+            }
+        })
+        .collect();
+    Records { records, schema }
+}
+
+// state is derived from what the flow server reports about the mirror, a
+// mirror it can't report on is `unknown`.
+fn state(mirror: &MirrorInfo, status: Option<&MirrorStatusResponse>) -> &'static str {
+    if mirror.paused {
+        return "paused";
+    }
+    let status = match status {
+        Some(status) => status,
+        None => return "unknown",
+    };
+    if !status.error_message.is_empty() {
+        return "errored";
+    }
+    match &status.status {
+        Some(Status::CdcStatus(cdc)) => {
+            if cdc.completed {
+                "completed"
+            } else if !cdc.cdc_syncs.is_empty() {
+                "streaming"
+            } else if cdc
+                .snapshot_status
+                .as_ref()
+                .map(|snapshot| !snapshot.clones.is_empty())
+                .unwrap_or(false)
+            {
+                "snapshot"
+            } else {
+                "setup"
+            }
+        }
+        Some(Status::QrepStatus(qrep)) => {
+            if qrep.partitions.is_empty() {
+                "setup"
+            } else if qrep
+                .config
+                .as_ref()
+                .map(|config| config.initial_copy_only)
+                .unwrap_or(false)
+            {
+                "snapshot"
+            } else {
+                "streaming"
+            }
+        }
+        None => "setup",
+    }
+}
+
+// the end of the most recent cdc batch or qrep partition.
+pub fn last_sync_time(status: &MirrorStatusResponse) -> Option<chrono::NaiveDateTime> {
+    let end_times: Vec<_> = match &status.status {
+        Some(Status::CdcStatus(cdc)) => cdc.cdc_syncs.iter().map(|s| &s.end_time).collect(),
+        Some(Status::QrepStatus(qrep)) => qrep.partitions.iter().map(|p| &p.end_time).collect(),
+        None => vec![],
+    };
+    end_times
+        .into_iter()
+        .flatten()
+        .filter_map(|ts| chrono::NaiveDateTime::from_timestamp_opt(ts.seconds, ts.nanos as u32))
+        .max()
+}
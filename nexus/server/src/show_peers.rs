@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use catalog::PeerInfo;
+use peer_cursor::{Record, Records, Schema, SchemaRef};
+use pgwire::api::{
+    results::{FieldFormat, FieldInfo},
+    Type,
+};
+use pt::peerdb_peers::{DbType, Peer};
+use value::Value;
+
+use crate::scheduler::PeerQueueStats;
+
+const REDACTED: &str = "********";
+
+// keys of the JSON encoded peer configs whose values are credentials.
+const SECRET_OPTIONS: &[&str] = &[
+    "password",
+    "privateKey",
+    "privateKeyId",
+    "accessKeyId",
+    "secretAccessKey",
+    "token",
+    "apiKey",
+    "signingSecret",
+];
+
+pub fn schema() -> SchemaRef {
+    let field = |name: &str, datatype: Type| {
+        FieldInfo::new(name.to_owned(), None, None, datatype, FieldFormat::Text)
+    };
+    Arc::new(Schema {
+        fields: vec![
+            field("name", Type::TEXT),
+            field("type", Type::TEXT),
+            field("created_at", Type::TIMESTAMP),
+            field("state", Type::TEXT),
+            field("running_queries", Type::INT8),
+            field("queued_queries", Type::INT8),
+            field("options", Type::JSONB),
+            field("comment", Type::TEXT),
+        ],
+    })
+}
+
+// the query counts are those of this nexus instance.
+pub fn records(peers: Vec<PeerInfo>, stats: impl Fn(&str) -> PeerQueueStats) -> Records {
+    let schema = schema();
+    let records = peers
+        .into_iter()
+        .map(|info| {
+            let peer = &info.peer;
+            let peer_type = DbType::from_i32(peer.r#type)
+                .map(|db_type| db_type.as_str_name().to_owned())
+                .unwrap_or_else(|| peer.r#type.to_string());
+            let state = if info.quarantined_at.is_some() {
+                "quarantined"
+            } else {
+                "active"
+            };
+            let stats = stats(&peer.name);
+            Record {
+                values: vec![
+                    Value::Text(peer.name.clone()),
+                    Value::Text(peer_type),
+                    Value::PostgresTimestamp(info.created_at),
+                    Value::Text(state.to_owned()),
+                    Value::BigInt(stats.running as i64),
+                    Value::BigInt(stats.queued as i64),
+                    Value::JsonB(redacted_options(peer)),
+                    info.comment.clone().map(Value::Text).unwrap_or(Value::Null),
+                ],
+                schema: schema.clone(),
+            }
+        })
+        .collect();
+    Records { records, schema }
+}
+
+// the options of a peer are the fields of its config, as they appear in the
+// JSON form of the peer.
+fn redacted_options(peer: &Peer) -> serde_json::Value {
+    let mut options = match serde_json::to_value(peer) {
+        Ok(serde_json::Value::Object(fields)) => fields
+            .into_iter()
+            .find(|(key, _)| key != "name" && key != "type")
+            .map(|(_, config)| config)
+            .unwrap_or_else(|| serde_json::json!({})),
+        _ => serde_json::json!({}),
+    };
+    redact(&mut options);
+    options
+}
+
+fn redact(options: &mut serde_json::Value) {
+    match options {
+        serde_json::Value::Object(fields) => {
+            for (key, value) in fields.iter_mut() {
+                if SECRET_OPTIONS.contains(&key.as_str()) {
+                    *value = serde_json::Value::String(REDACTED.to_owned());
+                } else if key == "headers" {
+                    // webhook headers usually carry authorization values.
+                    if let serde_json::Value::Object(headers) = value {
+                        for header in headers.values_mut() {
+                            *header = serde_json::Value::String(REDACTED.to_owned());
+                        }
+                    }
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use peer_cursor::{connector::TableInfo, Record, Records, Schema, SchemaRef};
+use pgwire::api::{
+    results::{FieldFormat, FieldInfo},
+    Type,
+};
+use value::Value;
+
+// tables without a primary key can only be mirrored with cdc once their
+// replica identity is full.
+pub fn schema() -> SchemaRef {
+    let field = |name: &str, datatype: Type| {
+        FieldInfo::new(name.to_owned(), None, None, datatype, FieldFormat::Text)
+    };
+    Arc::new(Schema {
+        fields: vec![
+            field("table_name", Type::TEXT),
+            field("estimated_rows", Type::INT8),
+            field("has_primary_key", Type::BOOL),
+        ],
+    })
+}
+
+pub fn records(tables: Vec<TableInfo>) -> Records {
+    let schema = schema();
+    let records = tables
+        .into_iter()
+        .map(|table| Record {
+            values: vec![
+                Value::Text(table.name),
+                table
+                    .estimated_rows
+                    .map(Value::BigInt)
+                    .unwrap_or(Value::Null),
+                Value::Bool(table.has_primary_key),
+            ],
+            schema: schema.clone(),
+        })
+        .collect();
+    Records { records, schema }
+}
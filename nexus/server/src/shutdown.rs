@@ -0,0 +1,193 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::Stream;
+use peer_cursor::{Record, RecordStream, SchemaRef, SendableStream};
+use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    sync::{watch, Notify},
+};
+
+/// The error clients are turned away with once the server is shutting down.
+pub const ADMIN_SHUTDOWN_CODE: &str = "57P01";
+pub const ADMIN_SHUTDOWN_MESSAGE: &str = "terminating connection due to administrator command";
+
+/// Waits for the server to be asked to stop, by SIGTERM or Ctrl-C.
+pub async fn signal_received() -> std::io::Result<()> {
+    let mut terminate = signal(SignalKind::terminate())?;
+    tokio::select! {
+        _ = terminate.recv() => Ok(()),
+        result = tokio::signal::ctrl_c() => result,
+    }
+}
+
+/// The shutdown of the server. Once it starts, sessions run no new queries and
+/// their connections are closed as soon as the queries they run finish, or
+/// once the drain timeout elapses.
+pub struct Shutdown {
+    draining: watch::Sender<bool>,
+    drain_timeout: Duration,
+}
+
+impl Shutdown {
+    pub fn new(drain_timeout: Duration) -> Self {
+        let (draining, _) = watch::channel(false);
+        Self {
+            draining,
+            drain_timeout,
+        }
+    }
+
+    pub fn start(&self) {
+        self.draining.send_replace(true);
+    }
+
+    pub fn is_draining(&self) -> bool {
+        *self.draining.borrow()
+    }
+
+    pub fn drain_timeout(&self) -> Duration {
+        self.drain_timeout
+    }
+
+    /// Waits for the shutdown to start.
+    pub async fn draining(&self) {
+        let mut draining = self.draining.subscribe();
+        while !*draining.borrow_and_update() {
+            if draining.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+
+    /// The error a query is refused with once the shutdown started.
+    pub fn check(&self) -> PgWireResult<()> {
+        if !self.is_draining() {
+            return Ok(());
+        }
+        Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+            "FATAL".to_owned(),
+            ADMIN_SHUTDOWN_CODE.to_owned(),
+            ADMIN_SHUTDOWN_MESSAGE.to_owned(),
+        ))))
+    }
+}
+
+/// The queries a session is running, including the rows of peers it is still
+/// streaming to its client.
+pub struct RunningQueries {
+    count: AtomicUsize,
+    idle: Notify,
+    // when the last query finished, or the session started.
+    idle_since: Mutex<Instant>,
+}
+
+impl Default for RunningQueries {
+    fn default() -> Self {
+        Self {
+            count: AtomicUsize::new(0),
+            idle: Notify::new(),
+            idle_since: Mutex::new(Instant::now()),
+        }
+    }
+}
+
+impl RunningQueries {
+    /// Counts a query as running until the returned guard is dropped.
+    pub fn start(self: &Arc<Self>) -> RunningQuery {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        RunningQuery(self.clone())
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.count.load(Ordering::SeqCst) == 0
+    }
+
+    /// Since when the session runs no queries, None while it runs some.
+    pub fn idle_since(&self) -> Option<Instant> {
+        let idle_since = *self.idle_since.lock().unwrap();
+        self.is_idle().then_some(idle_since)
+    }
+
+    /// Waits for the session to have run no queries for the timeout, the
+    /// timeout is looked up again whenever a query finishes.
+    pub async fn idle_for<F>(&self, timeout: impl Fn() -> F)
+    where
+        F: std::future::Future<Output = Option<Duration>>,
+    {
+        loop {
+            let finished = self.idle.notified();
+            match (timeout().await, self.idle_since()) {
+                (Some(timeout), Some(since)) => {
+                    tokio::select! {
+                        _ = tokio::time::sleep_until((since + timeout).into()) => {
+                            if self.idle_since() == Some(since) {
+                                return;
+                            }
+                        }
+                        _ = finished => {}
+                    }
+                }
+                _ => finished.await,
+            }
+        }
+    }
+
+    /// Waits for the session to run no queries.
+    pub async fn idle(&self) {
+        loop {
+            let idle = self.idle.notified();
+            if self.is_idle() {
+                return;
+            }
+            idle.await;
+        }
+    }
+}
+
+pub struct RunningQuery(Arc<RunningQueries>);
+
+impl RunningQuery {
+    /// Keeps the query running for as long as the rows are streamed.
+    pub fn hold(self, rows: SendableStream) -> SendableStream {
+        Box::pin(HeldStream { rows, _query: self })
+    }
+}
+
+impl Drop for RunningQuery {
+    fn drop(&mut self) {
+        let mut idle_since = self.0.idle_since.lock().unwrap();
+        if self.0.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            *idle_since = Instant::now();
+            drop(idle_since);
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+struct HeldStream {
+    rows: SendableStream,
+    _query: RunningQuery,
+}
+
+impl Stream for HeldStream {
+    type Item = PgWireResult<Record>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rows.as_mut().poll_next(cx)
+    }
+}
+
+impl RecordStream for HeldStream {
+    fn schema(&self) -> SchemaRef {
+        self.rows.schema()
+    }
+}
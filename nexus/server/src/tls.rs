@@ -0,0 +1,678 @@
+use std::{
+    fmt::Debug,
+    fs::File,
+    io::BufReader,
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use futures::Sink;
+use pgwire::{
+    api::{auth::StartupHandler, ClientInfo},
+    error::{ErrorInfo, PgWireError, PgWireResult},
+    messages::{PgWireBackendMessage, PgWireFrontendMessage},
+};
+use rustls_pemfile::Item;
+use tokio::net::TcpStream;
+use tokio_rustls::{
+    rustls::{
+        client::HandshakeSignatureValid,
+        server::{AllowAnyAuthenticatedClient, ClientCertVerified, ClientCertVerifier},
+        Certificate, DigitallySignedStruct, DistinguishedName, Error as TlsError, PrivateKey,
+        RootCertStore, ServerConfig, SignatureScheme,
+    },
+    TlsAcceptor,
+};
+use x509_parser::{extensions::GeneralName, parse_x509_certificate};
+
+use crate::connections;
+
+// the code of the SSLRequest message, sent in place of a protocol version by
+// clients that want to negotiate TLS before the startup message.
+const SSL_REQUEST_CODE: i32 = 80877103;
+
+/// The acceptors the clients that request TLS are served with, made from a
+/// PEM certificate chain and the PEM private key of its first certificate.
+/// With client certificate authentication, clients have to present a
+/// certificate signed by one of the client CAs, and each connection gets an
+/// acceptor of its own that keeps the certificate of its client.
+pub struct TlsAcceptors {
+    certs: Vec<Certificate>,
+    key: PrivateKey,
+    acceptor: Arc<TlsAcceptor>,
+    client_verifier: Option<Arc<dyn ClientCertVerifier>>,
+}
+
+impl TlsAcceptors {
+    pub fn new(
+        cert_path: &str,
+        key_path: &str,
+        client_ca_path: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        let certs = read_certs(cert_path)?;
+        let key = read_pem(key_path)?
+            .into_iter()
+            .find_map(|item| match item {
+                Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => Some(PrivateKey(key)),
+                _ => None,
+            })
+            .with_context(|| format!("no private key found in {}", key_path))?;
+
+        let client_verifier = match client_ca_path {
+            Some(client_ca_path) => {
+                let mut roots = RootCertStore::empty();
+                for cert in read_certs(client_ca_path)? {
+                    roots.add(&cert).map_err(|err| {
+                        anyhow::anyhow!("invalid CA certificate in {}: {:?}", client_ca_path, err)
+                    })?;
+                }
+                Some(AllowAnyAuthenticatedClient::new(roots).boxed())
+            }
+            None => None,
+        };
+
+        let config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(certs.clone(), key.clone())
+            .context("invalid TLS certificate or key")?;
+        Ok(Self {
+            certs,
+            key,
+            acceptor: Arc::new(TlsAcceptor::from(Arc::new(config))),
+            client_verifier,
+        })
+    }
+
+    pub fn requires_client_certificates(&self) -> bool {
+        self.client_verifier.is_some()
+    }
+
+    /// The acceptor of a new connection, and the certificate its client
+    /// authenticates with when client certificates are required.
+    pub fn for_connection(&self) -> anyhow::Result<(Arc<TlsAcceptor>, Option<ClientCertificate>)> {
+        let inner = match &self.client_verifier {
+            Some(inner) => inner.clone(),
+            None => return Ok((self.acceptor.clone(), None)),
+        };
+        let certificate = ClientCertificate::default();
+        let verifier = RecordingClientCertVerifier {
+            inner,
+            certificate: certificate.clone(),
+        };
+        let config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(Arc::new(verifier))
+            .with_single_cert(self.certs.clone(), self.key.clone())
+            .context("invalid TLS certificate or key")?;
+        Ok((
+            Arc::new(TlsAcceptor::from(Arc::new(config))),
+            Some(certificate),
+        ))
+    }
+}
+
+fn read_certs(path: &str) -> anyhow::Result<Vec<Certificate>> {
+    let certs: Vec<Certificate> = read_pem(path)?
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::X509Certificate(cert) => Some(Certificate(cert)),
+            _ => None,
+        })
+        .collect();
+    if certs.is_empty() {
+        anyhow::bail!("no certificates found in {}", path);
+    }
+    Ok(certs)
+}
+
+fn read_pem(path: &str) -> anyhow::Result<Vec<Item>> {
+    let file = File::open(path).with_context(|| format!("unable to open {}", path))?;
+    rustls_pemfile::read_all(&mut BufReader::new(file))
+        .with_context(|| format!("unable to read PEM file {}", path))
+}
+
+/// Whether the first message of the client is an SSLRequest, the socket is
+/// only peeked at so that the message is still handled by pgwire.
+pub async fn requests_tls(socket: &TcpStream) -> std::io::Result<bool> {
+    let mut buf = [0u8; 8];
+    let n = socket.peek(&mut buf).await?;
+    if n < buf.len() {
+        return Ok(false);
+    }
+    let len = i32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]);
+    let code = i32::from_be_bytes([buf[4], buf[5], buf[6], buf[7]]);
+    Ok(len == 8 && code == SSL_REQUEST_CODE)
+}
+
+/// Turns away a client that didn't request TLS when the server requires it,
+/// with the error postgres sends before the startup message.
+pub async fn reject_plaintext(socket: TcpStream) -> std::io::Result<()> {
+    connections::reject(socket, "28000", "the server requires a TLS connection").await
+}
+
+/// The certificate the client of a connection presented, once it is verified.
+#[derive(Clone, Default)]
+pub struct ClientCertificate(Arc<Mutex<Option<Certificate>>>);
+
+impl ClientCertificate {
+    // the names the certificate identifies its holder by: the common names of
+    // its subject and its DNS and email subject alternative names.
+    fn names(&self) -> Vec<String> {
+        let certificate = self.0.lock().unwrap().clone();
+        let der = match certificate {
+            Some(Certificate(der)) => der,
+            None => return vec![],
+        };
+        let cert = match parse_x509_certificate(&der) {
+            Ok((_, cert)) => cert,
+            Err(err) => {
+                tracing::warn!("unable to parse client certificate: {:?}", err);
+                return vec![];
+            }
+        };
+
+        let mut names: Vec<String> = cert
+            .subject()
+            .iter_common_name()
+            .filter_map(|cn| cn.as_str().ok().map(str::to_owned))
+            .collect();
+        if let Ok(Some(san)) = cert.subject_alternative_name() {
+            for name in &san.value.general_names {
+                match name {
+                    GeneralName::DNSName(name) | GeneralName::RFC822Name(name) => {
+                        names.push((*name).to_owned())
+                    }
+                    _ => {}
+                }
+            }
+        }
+        names
+    }
+}
+
+// verifies client certificates like the wrapped verifier does, and keeps the
+// certificate it verified.
+struct RecordingClientCertVerifier {
+    inner: Arc<dyn ClientCertVerifier>,
+    certificate: ClientCertificate,
+}
+
+impl ClientCertVerifier for RecordingClientCertVerifier {
+    fn offer_client_auth(&self) -> bool {
+        self.inner.offer_client_auth()
+    }
+
+    fn client_auth_mandatory(&self) -> bool {
+        self.inner.client_auth_mandatory()
+    }
+
+    fn client_auth_root_subjects(&self) -> &[DistinguishedName] {
+        self.inner.client_auth_root_subjects()
+    }
+
+    fn verify_client_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        now: SystemTime,
+    ) -> Result<ClientCertVerified, TlsError> {
+        let verified = self
+            .inner
+            .verify_client_cert(end_entity, intermediates, now)?;
+        *self.certificate.0.lock().unwrap() = Some(end_entity.clone());
+        Ok(verified)
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &Certificate,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &Certificate,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// Turns away the clients whose certificate doesn't name the user they log in
+/// as, before the wrapped handler authenticates them with their password.
+/// Without client certificate authentication every client is handed on.
+pub struct ClientCertStartupHandler<H> {
+    inner: Arc<H>,
+    certificate: Option<ClientCertificate>,
+}
+
+impl<H> ClientCertStartupHandler<H> {
+    pub fn new(inner: Arc<H>, certificate: Option<ClientCertificate>) -> Self {
+        Self { inner, certificate }
+    }
+}
+
+#[async_trait]
+impl<H: StartupHandler> StartupHandler for ClientCertStartupHandler<H> {
+    async fn on_startup<C>(
+        &self,
+        client: &mut C,
+        message: PgWireFrontendMessage,
+    ) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        if let (Some(certificate), PgWireFrontendMessage::Startup(startup)) =
+            (&self.certificate, &message)
+        {
+            let user = startup
+                .parameters()
+                .get("user")
+                .cloned()
+                .unwrap_or_default();
+            if !client.is_secure() || !certificate.names().contains(&user) {
+                return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "FATAL".to_owned(),
+                    "28000".to_owned(),
+                    format!("certificate authentication failed for user \"{}\"", user),
+                ))));
+            }
+        }
+        self.inner.on_startup(client, message).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        net::SocketAddr,
+        pin::Pin,
+        sync::atomic::{AtomicBool, Ordering},
+        task::{Context, Poll},
+    };
+
+    use pgwire::{
+        api::{ClientInfoHolder, PgWireConnectionState},
+        messages::startup::Startup,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use tokio_rustls::{
+        rustls::{ClientConfig, ServerName},
+        TlsConnector,
+    };
+
+    use super::*;
+
+    // the certificates are made by tests/assets/tls/generate.sh: the client
+    // certificate of alice names her as alice.example.com and bob@example.com.
+    fn asset(name: &str) -> String {
+        format!("{}/tests/assets/tls/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    fn acceptors() -> TlsAcceptors {
+        TlsAcceptors::new(
+            &asset("server.pem"),
+            &asset("server.key"),
+            Some(&asset("ca.pem")),
+        )
+        .unwrap()
+    }
+
+    // a client connected to a listener, and the socket the server accepted it
+    // with.
+    async fn connect() -> (TcpStream, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        let (server, _) = listener.accept().await.unwrap();
+        (client, server)
+    }
+
+    #[test]
+    fn test_acceptors_load_certificate_and_key() {
+        let acceptors =
+            TlsAcceptors::new(&asset("server.pem"), &asset("server.key"), None).unwrap();
+        assert!(!acceptors.requires_client_certificates());
+        assert!(acceptors().requires_client_certificates());
+    }
+
+    #[test]
+    fn test_acceptors_without_certificate_or_key() {
+        let err = TlsAcceptors::new(&asset("server.key"), &asset("server.key"), None)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("no certificates found"));
+        let err = TlsAcceptors::new(&asset("server.pem"), &asset("server.pem"), None)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("no private key found"));
+        let err = TlsAcceptors::new(
+            &asset("server.pem"),
+            &asset("server.key"),
+            Some(&asset("server.key")),
+        )
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("no certificates found"));
+    }
+
+    #[tokio::test]
+    async fn test_ssl_request_is_peeked() {
+        let (mut client, mut server) = connect().await;
+        let mut request = 8i32.to_be_bytes().to_vec();
+        request.extend(SSL_REQUEST_CODE.to_be_bytes());
+        client.write_all(&request).await.unwrap();
+        assert!(requests_tls(&server).await.unwrap());
+
+        // the request is left for pgwire to read.
+        let mut buf = [0u8; 8];
+        server.read_exact(&mut buf).await.unwrap();
+        assert_eq!(buf.as_slice(), request.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_startup_message_is_not_ssl_request() {
+        let (mut client, server) = connect().await;
+        // a startup message of protocol 3.0 without parameters.
+        let mut startup = 9i32.to_be_bytes().to_vec();
+        startup.extend(196608i32.to_be_bytes());
+        startup.push(0);
+        client.write_all(&startup).await.unwrap();
+        assert!(!requests_tls(&server).await.unwrap());
+
+        let (mut client, server) = connect().await;
+        client.write_all(&[0, 0]).await.unwrap();
+        assert!(!requests_tls(&server).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_reject_plaintext() {
+        let (mut client, server) = connect().await;
+        reject_plaintext(server).await.unwrap();
+
+        let mut response = vec![];
+        client.read_to_end(&mut response).await.unwrap();
+        assert_eq!(response[0], b'E');
+        let len = i32::from_be_bytes([response[1], response[2], response[3], response[4]]);
+        assert_eq!(len as usize, response.len() - 1);
+        let fields = String::from_utf8_lossy(&response[5..]);
+        assert!(fields.contains("SFATAL\0"));
+        assert!(fields.contains("C28000\0"));
+        assert!(fields.contains("Mthe server requires a TLS connection\0"));
+    }
+
+    fn read_certificate(name: &str) -> Certificate {
+        read_certs(&asset(&format!("{}.pem", name)))
+            .unwrap()
+            .remove(0)
+    }
+
+    fn client_config(client: Option<&str>) -> ClientConfig {
+        let mut roots = RootCertStore::empty();
+        roots.add(&read_certificate("ca")).unwrap();
+        let builder = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots);
+        match client {
+            Some(client) => {
+                let key = read_pem(&asset(&format!("{}.key", client)))
+                    .unwrap()
+                    .into_iter()
+                    .find_map(|item| match item {
+                        Item::PKCS8Key(key) => Some(PrivateKey(key)),
+                        _ => None,
+                    })
+                    .unwrap();
+                builder
+                    .with_client_auth_cert(vec![read_certificate(client)], key)
+                    .unwrap()
+            }
+            None => builder.with_no_client_auth(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handshake_without_client_certificates() {
+        let acceptors =
+            TlsAcceptors::new(&asset("server.pem"), &asset("server.key"), None).unwrap();
+        let (acceptor, certificate) = acceptors.for_connection().unwrap();
+        assert!(certificate.is_none());
+        let connector = TlsConnector::from(Arc::new(client_config(None)));
+        let (client_io, server_io) = tokio::io::duplex(16 * 1024);
+        let server_name = ServerName::try_from("localhost").unwrap();
+        let (connected, accepted) = tokio::join!(
+            connector.connect(server_name, client_io),
+            acceptor.accept(server_io)
+        );
+        assert!(connected.is_ok());
+        assert!(accepted.is_ok());
+    }
+
+    // runs the handshake of a client presenting the certificate of the given
+    // name, returns whether the server accepted it and the certificate it kept.
+    async fn handshake(client: Option<&str>) -> (bool, ClientCertificate) {
+        let (acceptor, certificate) = acceptors().for_connection().unwrap();
+        let certificate = certificate.expect("client certificates are required");
+        let connector = TlsConnector::from(Arc::new(client_config(client)));
+        let (client_io, server_io) = tokio::io::duplex(16 * 1024);
+        let server_name = ServerName::try_from("localhost").unwrap();
+        let (_, accepted) = tokio::join!(
+            connector.connect(server_name, client_io),
+            acceptor.accept(server_io)
+        );
+        (accepted.is_ok(), certificate)
+    }
+
+    fn recorded(certificate: &ClientCertificate) -> Option<Certificate> {
+        certificate.0.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn test_client_certificate_accepted() {
+        let (accepted, certificate) = handshake(Some("client")).await;
+        assert!(accepted);
+        assert_eq!(recorded(&certificate), Some(read_certificate("client")));
+        assert_eq!(
+            certificate.names(),
+            vec!["alice", "alice.example.com", "bob@example.com"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_client_certificate_rejected() {
+        let (accepted, certificate) = handshake(None).await;
+        assert!(!accepted);
+        assert_eq!(recorded(&certificate), None);
+        assert!(certificate.names().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_untrusted_client_certificate_rejected() {
+        let (accepted, certificate) = handshake(Some("untrusted-client")).await;
+        assert!(!accepted);
+        assert_eq!(recorded(&certificate), None);
+    }
+
+    #[test]
+    fn test_verifier_records_verified_certificates_only() {
+        let certificate = ClientCertificate::default();
+        let mut roots = RootCertStore::empty();
+        roots.add(&read_certificate("ca")).unwrap();
+        let verifier = RecordingClientCertVerifier {
+            inner: AllowAnyAuthenticatedClient::new(roots).boxed(),
+            certificate: certificate.clone(),
+        };
+        assert!(verifier.client_auth_mandatory());
+
+        let untrusted = read_certificate("untrusted-client");
+        assert!(verifier
+            .verify_client_cert(&untrusted, &[], SystemTime::now())
+            .is_err());
+        assert_eq!(recorded(&certificate), None);
+
+        let client = read_certificate("client");
+        assert!(verifier
+            .verify_client_cert(&client, &[], SystemTime::now())
+            .is_ok());
+        assert_eq!(recorded(&certificate), Some(client));
+    }
+
+    struct TestClient {
+        info: ClientInfoHolder,
+    }
+
+    impl TestClient {
+        fn new(is_secure: bool) -> Self {
+            let addr: SocketAddr = "127.0.0.1:5432".parse().unwrap();
+            Self {
+                info: ClientInfoHolder::new(addr, is_secure),
+            }
+        }
+    }
+
+    impl ClientInfo for TestClient {
+        fn socket_addr(&self) -> &SocketAddr {
+            self.info.socket_addr()
+        }
+
+        fn is_secure(&self) -> bool {
+            *self.info.is_secure()
+        }
+
+        fn state(&self) -> &PgWireConnectionState {
+            self.info.state()
+        }
+
+        fn set_state(&mut self, new_state: PgWireConnectionState) {
+            self.info.set_state(new_state);
+        }
+
+        fn metadata(&self) -> &HashMap<String, String> {
+            self.info.metadata()
+        }
+
+        fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+            self.info.metadata_mut()
+        }
+    }
+
+    impl Sink<PgWireBackendMessage> for TestClient {
+        type Error = PgWireError;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, _: PgWireBackendMessage) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    // the handler clients are handed on to, which lets every one of them in.
+    #[derive(Default)]
+    struct AcceptingHandler {
+        called: AtomicBool,
+    }
+
+    #[async_trait]
+    impl StartupHandler for AcceptingHandler {
+        async fn on_startup<C>(&self, _: &mut C, _: PgWireFrontendMessage) -> PgWireResult<()>
+        where
+            C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+            C::Error: Debug,
+            PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+        {
+            self.called.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    // starts up a client logging in as the user, with the certificate of the
+    // given name if any, and returns the error code it is turned away with.
+    async fn startup(
+        user: &str,
+        client: Option<&str>,
+        required: bool,
+        is_secure: bool,
+    ) -> Option<String> {
+        let certificate = required.then(ClientCertificate::default);
+        if let (Some(certificate), Some(client)) = (&certificate, client) {
+            *certificate.0.lock().unwrap() = Some(read_certificate(client));
+        }
+        let inner = Arc::new(AcceptingHandler::default());
+        let handler = ClientCertStartupHandler::new(inner.clone(), certificate);
+
+        let mut message = Startup::new();
+        message
+            .parameters_mut()
+            .insert("user".to_owned(), user.to_owned());
+        let res = handler
+            .on_startup(
+                &mut TestClient::new(is_secure),
+                PgWireFrontendMessage::Startup(message),
+            )
+            .await;
+        assert_eq!(inner.called.load(Ordering::SeqCst), res.is_ok());
+        match res {
+            Ok(()) => None,
+            Err(PgWireError::UserError(info)) => Some(info.code().clone()),
+            Err(err) => panic!("unexpected error: {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_certificate_names_user() {
+        for user in ["alice", "alice.example.com", "bob@example.com"] {
+            assert_eq!(startup(user, Some("client"), true, true).await, None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_certificate_of_another_user_rejected() {
+        let code = startup("mallory", Some("client"), true, true).await;
+        assert_eq!(code.as_deref(), Some("28000"));
+        // names are matched exactly.
+        let code = startup("Alice", Some("client"), true, true).await;
+        assert_eq!(code.as_deref(), Some("28000"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_certificate_rejected() {
+        let code = startup("alice", None, true, true).await;
+        assert_eq!(code.as_deref(), Some("28000"));
+        // the certificate of a connection that isn't secure isn't trusted.
+        let code = startup("alice", Some("client"), true, false).await;
+        assert_eq!(code.as_deref(), Some("28000"));
+    }
+
+    #[tokio::test]
+    async fn test_without_client_certificates() {
+        assert_eq!(startup("mallory", None, false, false).await, None);
+    }
+}
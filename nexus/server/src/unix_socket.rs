@@ -0,0 +1,230 @@
+use std::{
+    net::SocketAddr,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Context;
+use dashmap::DashSet;
+use tokio::{
+    net::{TcpListener, TcpSocket, TcpStream, UnixListener, UnixStream},
+    task::JoinHandle,
+};
+
+/// The Unix socket clients on the same host connect to, at the path postgres
+/// clients look it up at, `<dir>/.s.PGSQL.<port>`. Access to it is controlled
+/// with the permissions of the socket file.
+///
+/// pgwire only serves TCP connections, so each client of the socket is
+/// forwarded to a listener on the loopback interface, which only accepts the
+/// connections the socket forwards.
+pub struct UnixSocketListener {
+    path: PathBuf,
+    loopback: TcpListener,
+    // the local addresses of the connections forwarded to the loopback
+    // listener that it has yet to accept.
+    forwarded: Arc<DashSet<SocketAddr>>,
+    forwarder: JoinHandle<()>,
+}
+
+impl UnixSocketListener {
+    pub async fn bind(dir: &str, port: u16, permissions: u32) -> anyhow::Result<Self> {
+        let path = Path::new(dir).join(format!(".s.PGSQL.{}", port));
+        // a socket left behind by a server that didn't shut down is replaced,
+        // a server still listening on it is left alone.
+        if path.exists() {
+            if UnixStream::connect(&path).await.is_ok() {
+                anyhow::bail!("another server is listening on {}", path.display());
+            }
+            std::fs::remove_file(&path)
+                .with_context(|| format!("unable to remove stale socket {}", path.display()))?;
+        }
+        let unix = UnixListener::bind(&path)
+            .with_context(|| format!("unable to listen on {}", path.display()))?;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(permissions))
+            .with_context(|| format!("unable to set permissions of {}", path.display()))?;
+
+        let loopback = TcpListener::bind("127.0.0.1:0").await?;
+        let loopback_addr = loopback.local_addr()?;
+        let forwarded = Arc::new(DashSet::new());
+        let forwarder = tokio::spawn(forward(unix, loopback_addr, forwarded.clone()));
+        Ok(Self {
+            path,
+            loopback,
+            forwarded,
+            forwarder,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The next client of the socket, as the connection it is forwarded over.
+    pub async fn accept(&self) -> std::io::Result<TcpStream> {
+        loop {
+            let (socket, addr) = self.loopback.accept().await?;
+            if self.forwarded.remove(&addr).is_some() {
+                return Ok(socket);
+            }
+            tracing::warn!(
+                "refused a connection to the Unix socket listener from {}",
+                addr
+            );
+        }
+    }
+}
+
+impl Drop for UnixSocketListener {
+    fn drop(&mut self) {
+        self.forwarder.abort();
+        if let Err(err) = std::fs::remove_file(&self.path) {
+            tracing::warn!("unable to remove {}: {:?}", self.path.display(), err);
+        }
+    }
+}
+
+/// The next client of the socket, never for a server without one.
+pub async fn accept(listener: Option<&UnixSocketListener>) -> std::io::Result<TcpStream> {
+    match listener {
+        Some(listener) => listener.accept().await,
+        None => std::future::pending().await,
+    }
+}
+
+async fn forward(
+    unix: UnixListener,
+    loopback_addr: SocketAddr,
+    forwarded: Arc<DashSet<SocketAddr>>,
+) {
+    loop {
+        let mut client = match unix.accept().await {
+            Ok((client, _)) => client,
+            Err(err) => {
+                tracing::error!("unable to accept a client of the Unix socket: {:?}", err);
+                continue;
+            }
+        };
+        let forwarded = forwarded.clone();
+        tokio::spawn(async move {
+            // the local address is known before connecting, so the loopback
+            // listener can tell the connection was forwarded when it accepts it.
+            let connect = async {
+                let socket = TcpSocket::new_v4()?;
+                socket.bind("127.0.0.1:0".parse().unwrap())?;
+                let addr = socket.local_addr()?;
+                forwarded.insert(addr);
+                let connected = socket.connect(loopback_addr).await;
+                if connected.is_err() {
+                    forwarded.remove(&addr);
+                }
+                connected
+            };
+            let result = match connect.await {
+                Ok(mut server) => tokio::io::copy_bidirectional(&mut client, &mut server)
+                    .await
+                    .map(|_| ()),
+                Err(err) => Err(err),
+            };
+            if let Err(err) = result {
+                tracing::info!("Unix socket client disconnected: {:?}", err);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+
+    // each test listens in a directory of its own, so they run side by side.
+    fn socket_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "peerdb_unix_socket_{}_{}",
+            name,
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_forwarded_connection() {
+        let dir = socket_dir("forwarded");
+        let listener = UnixSocketListener::bind(dir.to_str().unwrap(), 5432, 0o770)
+            .await
+            .unwrap();
+        let mode = std::fs::metadata(listener.path())
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o770);
+
+        let mut client = UnixStream::connect(listener.path()).await.unwrap();
+        let mut server = listener.accept().await.unwrap();
+        // every forwarded connection is accepted once, then leaves the allow-list.
+        assert!(listener.forwarded.is_empty());
+
+        client.write_all(b"ping").await.unwrap();
+        let mut buf = [0; 4];
+        server.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"ping");
+        server.write_all(b"pong").await.unwrap();
+        client.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"pong");
+    }
+
+    #[tokio::test]
+    async fn test_refuse_loopback_connection() {
+        let dir = socket_dir("refuse");
+        let listener = UnixSocketListener::bind(dir.to_str().unwrap(), 5432, 0o770)
+            .await
+            .unwrap();
+        let loopback_addr = listener.loopback.local_addr().unwrap();
+
+        // a connection to the loopback listener that the socket didn't forward
+        // is closed, and the listener keeps waiting for the clients of the socket.
+        let mut stranger = TcpStream::connect(loopback_addr).await.unwrap();
+        let accepted = tokio::time::timeout(Duration::from_millis(200), listener.accept()).await;
+        assert!(accepted.is_err());
+        let mut buf = [0; 1];
+        assert_eq!(stranger.read(&mut buf).await.unwrap(), 0);
+
+        let _client = UnixStream::connect(listener.path()).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), listener.accept())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replace_stale_socket() {
+        let dir = socket_dir("stale");
+        let path = dir.join(".s.PGSQL.5432");
+        // a socket left behind by a server that exited without removing it.
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+
+        let listener = UnixSocketListener::bind(dir.to_str().unwrap(), 5432, 0o770)
+            .await
+            .unwrap();
+        let _client = UnixStream::connect(listener.path()).await.unwrap();
+        listener.accept().await.unwrap();
+
+        // a socket a server still listens on is not taken over.
+        let err = UnixSocketListener::bind(dir.to_str().unwrap(), 5432, 0o770)
+            .await
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("another server is listening on"));
+        assert!(path.exists());
+
+        drop(listener);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use peer_cursor::{Record, Records, Schema, SchemaRef};
+use pgwire::api::{
+    results::{FieldFormat, FieldInfo},
+    Type,
+};
+use pt::peerdb_route::PeerValidationCheck;
+use value::Value;
+
+// every row is a check made on the peer, the peer is invalid if a required
+// check didn't pass.
+pub fn schema() -> SchemaRef {
+    let field = |name: &str, datatype: Type| {
+        FieldInfo::new(name.to_owned(), None, None, datatype, FieldFormat::Text)
+    };
+    Arc::new(Schema {
+        fields: vec![
+            field("check", Type::TEXT),
+            field("passed", Type::BOOL),
+            field("required", Type::BOOL),
+            field("message", Type::TEXT),
+        ],
+    })
+}
+
+pub fn records(checks: &[PeerValidationCheck]) -> Records {
+    let schema = schema();
+    let records = checks
+        .iter()
+        .map(|check| Record {
+            values: vec![
+                Value::Text(check.name.clone()),
+                Value::Bool(check.passed),
+                Value::Bool(check.required),
+                Value::Text(check.message.clone()),
+            ],
+            schema: schema.clone(),
+        })
+        .collect();
+    Records { records, schema }
+}
@@ -0,0 +1,39 @@
+#!/bin/sh
+# Generates the certificates the TLS tests of the server use: a CA, a server
+# certificate for localhost and a client certificate for alice signed by it,
+# and a client certificate for alice signed by a CA the server doesn't trust.
+set -e
+cd "$(dirname "$0")"
+tmp=$(mktemp -d)
+trap 'rm -rf "$tmp"' EXIT
+
+key() {
+  openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-256 -out "$1"
+}
+
+ca() {
+  key "$tmp/$1.key"
+  openssl req -x509 -new -key "$tmp/$1.key" -days 36500 -subj "/CN=$2" \
+    -addext "basicConstraints=critical,CA:TRUE" \
+    -addext "keyUsage=critical,keyCertSign,cRLSign" \
+    -out "$1.pem"
+}
+
+# leaf name ca subject extensions
+leaf() {
+  key "$1.key"
+  openssl req -new -key "$1.key" -subj "/CN=$3" -out "$tmp/$1.csr"
+  printf '%s\n' "basicConstraints=critical,CA:FALSE" "keyUsage=critical,digitalSignature" "$4" \
+    > "$tmp/$1.ext"
+  openssl x509 -req -in "$tmp/$1.csr" -CA "$2.pem" -CAkey "$tmp/$2.key" -CAcreateserial \
+    -CAserial "$tmp/$2.srl" -days 36500 -extfile "$tmp/$1.ext" -out "$1.pem"
+}
+
+ca ca "peerdb test CA"
+ca untrusted-ca "untrusted test CA"
+leaf server ca localhost "extendedKeyUsage=serverAuth
+subjectAltName=DNS:localhost"
+leaf client ca alice "extendedKeyUsage=clientAuth
+subjectAltName=DNS:alice.example.com,email:bob@example.com"
+leaf untrusted-client untrusted-ca alice "extendedKeyUsage=clientAuth"
+rm -f untrusted-ca.pem
@@ -1,11 +1,16 @@
-use postgres::{Client, NoTls, SimpleQueryMessage};
+use postgres::{
+    error::SqlState, fallible_iterator::FallibleIterator, types::Type, Client, NoTls,
+    SimpleQueryMessage,
+};
 use std::{
     fs::{read_dir, File},
     io::{prelude::*, BufReader, Write},
+    net::TcpStream,
     path::Path,
     process::Command,
+    sync::{Arc, Mutex},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 mod create_peers;
 fn input_files() -> Vec<String> {
@@ -42,8 +47,14 @@ struct PeerDBServer {
 
 impl PeerDBServer {
     fn new() -> Self {
+        Self::with_env(&[])
+    }
+
+    // starts the server with settings of its own on top of the environment.
+    fn with_env(envs: &[(&str, &str)]) -> Self {
         let mut server_start = Command::new("cargo");
         server_start.envs(std::env::vars());
+        server_start.envs(envs.iter().copied());
         server_start.args(["run"]);
         tracing::info!("Starting server...");
 
@@ -207,6 +218,90 @@ fn extended_query_protocol_no_params_catalog() {
     assert!(res > 0);
 }
 
+#[test]
+fn extended_query_protocol_binds_params_catalog() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    // parameters of unspecified type are described as text.
+    let rows = client
+        .query("SELECT name FROM peers WHERE name = $1;", &[&"pg_test"])
+        .expect("Failed to query with a text parameter");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<_, String>(0), "pg_test");
+
+    // parameters of a specified type are sent in binary.
+    let stmt = client
+        .prepare_typed("SELECT count(*) FROM peers WHERE id > $1;", &[Type::INT4])
+        .expect("Failed to prepare query with a typed parameter");
+    let rows = client
+        .query(&stmt, &[&0i32])
+        .expect("Failed to query with an int parameter");
+    assert!(rows[0].get::<_, i64>(0) > 0);
+}
+
+#[test]
+fn pipelined_extended_queries_complete_in_order() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to start a runtime");
+    runtime.block_on(async {
+        let connection_string = "host=localhost port=9900 password=peerdb user=peerdb";
+        let (client, connection) = tokio_postgres::connect(connection_string, NoTls)
+            .await
+            .expect("Failed to connect to server");
+        tokio::spawn(connection);
+
+        // the queries are pipelined, each is sent before the results of the
+        // ones before it are read. the one that fails doesn't hold up the others.
+        let (peers, unknown, count) = futures::join!(
+            client.query("SELECT name FROM peers WHERE name = $1;", &[&"pg_test"]),
+            client.query("SELECT * FROM unknown_peer.test_table;", &[]),
+            client.query("SELECT count(*) FROM peers;", &[]),
+        );
+        let peers = peers.expect("Failed to run the first pipelined query");
+        assert_eq!(peers[0].get::<_, String>(0), "pg_test");
+        assert!(unknown.is_err());
+        let count = count.expect("Failed to run the last pipelined query");
+        assert!(count[0].get::<_, i64>(0) > 0);
+    });
+}
+
+#[test]
+fn prepared_statements_run_repeatedly_on_postgres_peers() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+    client
+        .simple_query("SET peerdb.default_peer = 'pg_test';")
+        .expect("Failed to set the default peer");
+
+    // text parameters are sent to the statement prepared on the peer.
+    let stmt = client
+        .prepare_typed("SELECT length($1::text);", &[Type::TEXT])
+        .expect("Failed to prepare query on the peer");
+    for value in ["a", "peerdb", ""] {
+        let row = client
+            .query_one(&stmt, &[&value])
+            .expect("Failed to execute prepared statement");
+        assert_eq!(row.get::<_, i32>(0), value.len() as i32);
+    }
+
+    // binary parameters are bound as literals.
+    let stmt = client
+        .prepare_typed("SELECT $1::int4 + 1;", &[Type::INT4])
+        .expect("Failed to prepare query with a typed parameter");
+    for value in [1i32, 41] {
+        let row = client
+            .query_one(&stmt, &[&value])
+            .expect("Failed to execute prepared statement");
+        assert_eq!(row.get::<_, i32>(0), value + 1);
+    }
+}
+
 #[test]
 fn query_unknown_peer_doesnt_crash_server() {
     let server = PeerDBServer::new();
@@ -223,6 +318,1183 @@ fn query_unknown_peer_doesnt_crash_server() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn multi_statement_query_completes_each_statement() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+
+    // each statement of the query runs and completes on its own.
+    let query = "SELECT * FROM peers; SELECT 1; SELECT 2;";
+    let messages = client
+        .simple_query(query)
+        .expect("Failed to run multi-statement query");
+    let completions = messages
+        .iter()
+        .filter(|message| matches!(message, SimpleQueryMessage::CommandComplete(_)))
+        .count();
+    assert_eq!(completions, 3);
+
+    // a failing statement skips the ones after it, the server keeps serving.
+    let query = "SELECT 1; SELECT * FROM unknown_peer.test_table; SELECT 2;";
+    assert!(client.simple_query(query).is_err());
+    assert!(client.simple_query("SELECT 1;").is_ok());
+}
+
+#[test]
+fn create_mirror_expands_session_variables() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+
+    client
+        .simple_query("SET peerdb.env = 'staging';")
+        .expect("Failed to set session variable");
+
+    // a variable that isn't set fails the statement before the mirror is created.
+    let query = "CREATE MIRROR \"orders_{{env}}\" FROM pg_test TO pg_test \
+        WITH TABLE MAPPING (public.orders:\"analytics_{{region}}\".orders);";
+    let err = client
+        .simple_query(query)
+        .expect_err("CREATE MIRROR with an unset variable should fail");
+    assert!(err.to_string().contains("region is not set"));
+}
+
+#[test]
+fn database_named_after_a_peer_is_the_default_peer() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    // clients connecting to the database of a peer run unqualified queries on it.
+    let connection_string = "host=localhost port=9900 password=peerdb user=peerdb dbname=pg_test";
+    let mut peer_client = Client::connect(connection_string, NoTls)
+        .expect("Failed to connect to the peer's database");
+    let rows = peer_client
+        .query("SHOW peerdb.default_peer;", &[])
+        .expect("Failed to show the default peer");
+    assert_eq!(rows[0].get::<_, String>(0), "pg_test");
+    let rows = peer_client
+        .query("SELECT 1::int4;", &[])
+        .expect("Failed to run a query on the default peer");
+    assert_eq!(rows[0].get::<_, i32>(0), 1);
+
+    // a database that names no peer leaves the server's default peer.
+    let rows = client
+        .query("SHOW peerdb.default_peer;", &[])
+        .expect("Failed to show the default peer");
+    assert_eq!(rows[0].get::<_, String>(0), "");
+}
+
+#[test]
+fn notifications_of_listened_channels_reach_idle_clients() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let connection_string = "host=localhost port=9900 password=peerdb user=peerdb dbname=pg_test";
+    let mut listener = Client::connect(connection_string, NoTls)
+        .expect("Failed to connect to the peer's database");
+    listener
+        .batch_execute("LISTEN peerdb_events;")
+        .expect("Failed to listen to a channel");
+    let mut notifier = Client::connect(connection_string, NoTls)
+        .expect("Failed to connect to the peer's database");
+    notifier
+        .batch_execute("NOTIFY peerdb_events, 'mirror created';")
+        .expect("Failed to notify the channel");
+
+    // the notification is forwarded to the listener while it runs no query.
+    let notification = listener
+        .notifications()
+        .timeout_iter(Duration::from_secs(10))
+        .next()
+        .expect("Failed to receive the notification")
+        .expect("notification error");
+    assert_eq!(notification.channel(), "peerdb_events");
+    assert_eq!(notification.payload(), "mirror created");
+
+    // a session without a postgres default peer can't listen.
+    let err = client
+        .batch_execute("LISTEN peerdb_events;")
+        .expect_err("listening without a postgres default peer should fail");
+    assert_eq!(err.code(), Some(&SqlState::FEATURE_NOT_SUPPORTED));
+}
+
+#[test]
+fn sessions_share_peer_connections_in_transaction_pool_mode() {
+    let server = PeerDBServer::with_env(&[("PEERDB_POOL_MODE", "transaction")]);
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let backend_pid = |client: &mut Client| -> i32 {
+        let rows = client
+            .query("EXECUTE 'SELECT pg_backend_pid()' ON PEER pg_test;", &[])
+            .expect("Failed to query the backend of the peer connection");
+        rows[0].get(0)
+    };
+    let first_pid = backend_pid(&mut client);
+
+    // the connection the first session handed back is reused by the next.
+    let mut other_client = server.connect_dying();
+    assert_eq!(backend_pid(&mut other_client), first_pid);
+
+    // a session holds its connection until its transaction ends.
+    let connection_string = "host=localhost port=9900 password=peerdb user=peerdb dbname=pg_test";
+    let mut peer_client = Client::connect(connection_string, NoTls)
+        .expect("Failed to connect to the peer's database");
+    peer_client
+        .batch_execute("BEGIN;")
+        .expect("Failed to begin a transaction");
+    let transaction_pid = backend_pid(&mut peer_client);
+    assert_eq!(backend_pid(&mut peer_client), transaction_pid);
+    assert_ne!(backend_pid(&mut client), transaction_pid);
+    peer_client
+        .batch_execute("COMMIT;")
+        .expect("Failed to commit the transaction");
+}
+
+#[test]
+fn sessions_reconnect_to_peers_other_sessions_altered() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    dotenvy::dotenv().ok();
+    let env = |name: &str| std::env::var(name).unwrap_or_else(|_| panic!("{} not set", name));
+    client
+        .simple_query(&format!(
+            "CREATE PEER IF NOT EXISTS pg_altered FROM POSTGRES WITH
+            (host = '{}', port = '{}', user = '{}', password = '{}', database = '{}');",
+            env("PEERDB_CATALOG_HOST"),
+            env("PEERDB_CATALOG_PORT"),
+            env("PEERDB_CATALOG_USER"),
+            env("PEERDB_CATALOG_PASSWORD"),
+            env("PEERDB_CATALOG_DATABASE"),
+        ))
+        .expect("Failed to create peer");
+
+    let current_database = |client: &mut Client| -> String {
+        let rows = client
+            .query(
+                "EXECUTE 'SELECT current_database()' ON PEER pg_altered;",
+                &[],
+            )
+            .expect("Failed to query the database of the peer");
+        rows[0].get(0)
+    };
+    assert_eq!(
+        current_database(&mut client),
+        env("PEERDB_CATALOG_DATABASE")
+    );
+
+    // the session connected to the peer before another session altered it
+    // queries it with its new config.
+    let mut other_client = server.connect_dying();
+    other_client
+        .simple_query("ALTER PEER pg_altered WITH (database = 'postgres');")
+        .expect("Failed to alter peer");
+    assert_eq!(current_database(&mut client), "postgres");
+
+    other_client
+        .simple_query("DROP PEER pg_altered;")
+        .expect("Failed to drop peer");
+}
+
+#[test]
+fn read_only_server_rejects_writes() {
+    {
+        let server = PeerDBServer::new();
+        let mut client = server.connect_dying();
+        create_peers::create_pg::create(&mut client);
+    }
+    let server = PeerDBServer::with_env(&[("PEERDB_READ_ONLY", "true")]);
+    let mut client = server.connect_dying();
+
+    // peers can still be listed and queried.
+    client
+        .simple_query("SHOW PEERS;")
+        .expect("Failed to show peers on a read-only server");
+    let connection_string = "host=localhost port=9900 password=peerdb user=peerdb dbname=pg_test";
+    let mut peer_client = Client::connect(connection_string, NoTls)
+        .expect("Failed to connect to the peer's database");
+    let rows = peer_client
+        .query("SELECT 1::int4;", &[])
+        .expect("Failed to query a peer on a read-only server");
+    assert_eq!(rows[0].get::<_, i32>(0), 1);
+
+    let assert_read_only = |conn: &mut Client, stmt: &str| {
+        let err = conn
+            .batch_execute(stmt)
+            .expect_err("writes should fail on a read-only server");
+        assert_eq!(
+            err.code(),
+            Some(&SqlState::READ_ONLY_SQL_TRANSACTION),
+            "{}",
+            stmt
+        );
+    };
+    assert_read_only(&mut client, "DROP PEER pg_test;");
+    assert_read_only(&mut client, "EXECUTE 'DELETE FROM t' ON PEER pg_test;");
+    assert_read_only(&mut peer_client, "CREATE TABLE read_only_test (id int);");
+    assert_read_only(&mut peer_client, "SELECT 1 AS id INTO read_only_test;");
+    assert_read_only(
+        &mut peer_client,
+        "WITH t AS (INSERT INTO read_only_test VALUES (1) RETURNING *) SELECT * FROM t;",
+    );
+    assert_read_only(&mut peer_client, "SELECT pg_terminate_backend(1);");
+}
+
+#[test]
+fn hba_rules_decide_how_clients_log_in() {
+    std::fs::write(
+        "hba_test.conf",
+        "host peerdb 127.0.0.1/32 trust\nhost all all reject\n",
+    )
+    .expect("unable to write hba_test.conf");
+    let server = PeerDBServer::with_env(&[("PEERDB_HBA_FILE", "hba_test.conf")]);
+    server.connect_dying();
+
+    // the trusted user logs in without a password.
+    let mut client = Client::connect("host=127.0.0.1 port=9900 user=peerdb", NoTls)
+        .expect("Failed to log in as a trusted user");
+    client
+        .simple_query("SHOW PEERS;")
+        .expect("Failed to query as a trusted user");
+
+    let err = Client::connect("host=127.0.0.1 port=9900 user=other password=peerdb", NoTls)
+        .err()
+        .expect("rejected users should not log in");
+    assert_eq!(
+        err.code(),
+        Some(&SqlState::INVALID_AUTHORIZATION_SPECIFICATION)
+    );
+}
+
+#[test]
+fn users_log_in_with_their_own_password() {
+    let server = PeerDBServer::with_env(&[("PEERDB_ACCESS_CONTROL", "true")]);
+    let mut admin_client = server.connect_dying();
+    admin_client
+        .batch_execute("DROP USER IF EXISTS auth_test;")
+        .expect("Failed to drop the user");
+    admin_client
+        .batch_execute("CREATE USER auth_test WITH PASSWORD 'auth_secret';")
+        .expect("Failed to create the user");
+
+    // the user logs in with the password it was created with, not that of the
+    // server, which the admin keeps logging in with.
+    Client::connect(
+        "host=localhost port=9900 user=auth_test password=auth_secret",
+        NoTls,
+    )
+    .expect("Failed to log in with the password of the user");
+    let err = Client::connect(
+        "host=localhost port=9900 user=auth_test password=peerdb",
+        NoTls,
+    )
+    .err()
+    .expect("the user should not log in with the password of the server");
+    assert_eq!(err.code(), Some(&SqlState::INVALID_PASSWORD));
+    server.connect_dying();
+
+    let err = Client::connect(
+        "host=localhost port=9900 user=no_such_user password=peerdb",
+        NoTls,
+    )
+    .err()
+    .expect("unknown users should not log in");
+    assert_eq!(
+        err.code(),
+        Some(&SqlState::INVALID_AUTHORIZATION_SPECIFICATION)
+    );
+    assert!(err.to_string().contains("user no_such_user does not exist"));
+}
+
+#[test]
+fn users_without_scram_secret_log_in_with_md5() {
+    let server = PeerDBServer::with_env(&[("PEERDB_ACCESS_CONTROL", "true")]);
+    let mut admin_client = server.connect_dying();
+    admin_client
+        .batch_execute("DROP USER IF EXISTS md5_test;")
+        .expect("Failed to drop the user");
+    admin_client
+        .batch_execute("CREATE USER md5_test WITH PASSWORD 'md5_secret';")
+        .expect("Failed to create the user");
+    // as the users created before SCRAM-SHA-256 secrets were kept.
+    catalog_client()
+        .execute(
+            "UPDATE nexus_users SET scram_salt = NULL, scram_salted_password = NULL
+             WHERE name = 'md5_test'",
+            &[],
+        )
+        .expect("Failed to clear the SCRAM-SHA-256 secret");
+    let connection_string = "host=localhost port=9900 user=md5_test password=md5_secret";
+
+    // SCRAM-SHA-256, the default, needs the secret.
+    let err = Client::connect(connection_string, NoTls)
+        .err()
+        .expect("the user should not log in with SCRAM-SHA-256");
+    assert_eq!(err.code(), Some(&SqlState::INVALID_PASSWORD));
+    assert!(err.to_string().contains("log in with md5"));
+    drop(server);
+
+    // md5 only needs the hash every user has.
+    let server = PeerDBServer::with_env(&[
+        ("PEERDB_ACCESS_CONTROL", "true"),
+        ("PEERDB_AUTH_METHOD", "md5"),
+    ]);
+    server.connect_dying();
+    Client::connect(connection_string, NoTls).expect("Failed to log in with md5");
+}
+
+#[test]
+fn listeners_have_settings_of_their_own() {
+    let server = PeerDBServer::with_env(&[("PEERDB_LISTENERS", "127.0.0.1:9901,read_only")]);
+    let mut admin_client = server.connect_dying();
+    create_peers::create_pg::create(&mut admin_client);
+
+    let mut client = Client::connect(
+        "host=127.0.0.1 port=9901 password=peerdb user=peerdb",
+        NoTls,
+    )
+    .expect("Failed to connect to the read-only listener");
+    client
+        .simple_query("SHOW PEERS;")
+        .expect("Failed to show peers on the read-only listener");
+    let err = client
+        .batch_execute("DROP PEER pg_test;")
+        .expect_err("writes should fail on the read-only listener");
+    assert_eq!(err.code(), Some(&SqlState::READ_ONLY_SQL_TRANSACTION));
+
+    // the main listener still allows them.
+    admin_client
+        .simple_query("SHOW MIRRORS;")
+        .expect("Failed to query the main listener");
+}
+
+#[test]
+fn results_are_sent_in_binary_when_requested() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    // the extended protocol of the postgres crate asks for every column in
+    // binary.
+    let connection_string = "host=localhost port=9900 password=peerdb user=peerdb dbname=pg_test";
+    let mut peer_client = Client::connect(connection_string, NoTls)
+        .expect("Failed to connect to the peer's database");
+    let rows = peer_client
+        .query(
+            "SELECT '2024-01-02 03:04:05'::timestamp, '2024-01-02'::date, \
+             '\\x0102'::bytea, 'hello'::text, ARRAY[1, 2]::int4[], \
+             '6f1b1a3c-52a0-4bd2-9e7e-1d2b3c4d5e6f'::uuid::text;",
+            &[],
+        )
+        .expect("Failed to query values in binary");
+    let timestamp: chrono::NaiveDateTime = rows[0].get(0);
+    assert_eq!(timestamp.to_string(), "2024-01-02 03:04:05");
+    let date: chrono::NaiveDate = rows[0].get(1);
+    assert_eq!(date.to_string(), "2024-01-02");
+    assert_eq!(rows[0].get::<_, Vec<u8>>(2), vec![1, 2]);
+    assert_eq!(rows[0].get::<_, String>(3), "hello");
+    assert_eq!(rows[0].get::<_, Vec<i32>>(4), vec![1, 2]);
+    assert_eq!(
+        rows[0].get::<_, String>(5),
+        "6f1b1a3c-52a0-4bd2-9e7e-1d2b3c4d5e6f"
+    );
+}
+
+#[test]
+fn statements_are_written_to_the_audit_log() {
+    let server = PeerDBServer::with_env(&[("PEERDB_AUDIT_LOG", "all")]);
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let connection_string = "host=localhost port=9900 password=peerdb user=peerdb dbname=pg_test";
+    let mut peer_client = Client::connect(connection_string, NoTls)
+        .expect("Failed to connect to the peer's database");
+    peer_client
+        .query("SELECT 41 + 1 AS audited;", &[])
+        .expect("Failed to run a query on the default peer");
+    thread::sleep(Duration::from_millis(500));
+
+    // the entry names the user, the peer, the statement and the rows.
+    let log = std::fs::read_to_string("server.log").expect("unable to read server.log");
+    let entry = log
+        .lines()
+        .find(|line| line.contains("statement ran") && line.contains("audited"))
+        .expect("the query should be in the audit log");
+    assert!(entry.contains("user=peerdb"), "{}", entry);
+    assert!(entry.contains("peers=pg_test"), "{}", entry);
+    assert!(entry.contains("rows=Some(1)"), "{}", entry);
+
+    // the passwords of peers aren't.
+    assert!(log
+        .lines()
+        .any(|line| line.contains("statement=CREATE PEER pg_test")));
+}
+
+#[test]
+fn session_settings_are_kept_per_session() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+
+    // settings clients send on connect are kept by nexus and can be shown.
+    client
+        .simple_query("SET statement_timeout = '30s'; SET application_name = 'orm';")
+        .expect("Failed to set session settings");
+    let rows = client
+        .query("SHOW statement_timeout;", &[])
+        .expect("Failed to show statement_timeout");
+    assert_eq!(rows[0].get::<_, String>(0), "30s");
+
+    let mut other_client = server.connect_dying();
+    let rows = other_client
+        .query("SHOW application_name;", &[])
+        .expect("Failed to show application_name");
+    assert_eq!(rows[0].get::<_, String>(0), "");
+
+    // the default peer has to exist.
+    let err = client
+        .simple_query("SET peerdb.default_peer = 'unknown_peer';")
+        .expect_err("setting an unknown default peer should fail");
+    assert!(err.to_string().contains("does not exist"));
+}
+
+#[test]
+fn client_settings_are_kept_and_set_on_postgres_peers() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    client
+        .simple_query("SET search_path = test, public; SET unknown_client_setting = 3;")
+        .expect("Failed to set client settings");
+    let row = client
+        .query_one("SHOW search_path;", &[])
+        .expect("Failed to show search_path");
+    assert_eq!(row.get::<_, String>(0), "test, public");
+
+    // the settings are set on the connection to the peer, before and after
+    // it is opened.
+    client
+        .simple_query("SET peerdb.default_peer = 'pg_test'; SET application_name = 'orm';")
+        .expect("Failed to set application_name");
+    let row = client
+        .query_one("SELECT current_setting('search_path');", &[])
+        .expect("Failed to get search_path of the peer");
+    assert_eq!(row.get::<_, String>(0), "test, public");
+    client
+        .simple_query("SET TimeZone = 'Asia/Kolkata';")
+        .expect("Failed to set the time zone");
+    let row = client
+        .query_one("SELECT current_setting('TimeZone');", &[])
+        .expect("Failed to get the time zone of the peer");
+    assert_eq!(row.get::<_, String>(0), "Asia/Kolkata");
+
+    let err = client
+        .simple_query("SET client_encoding = 'LATIN1';")
+        .expect_err("encodings other than UTF8 should fail");
+    assert!(err.to_string().contains("not supported"));
+}
+
+#[test]
+fn notices_of_peers_are_sent_to_clients() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let notices = Arc::new(Mutex::new(vec![]));
+    let received = notices.clone();
+    let mut config: postgres::Config = "host=localhost port=9900 password=peerdb user=peerdb"
+        .parse()
+        .unwrap();
+    config
+        .notice_callback(move |notice| received.lock().unwrap().push(notice.message().to_owned()));
+    let mut client = config.connect(NoTls).expect("Failed to connect");
+    client
+        .simple_query("SET peerdb.default_peer = 'pg_test'; DROP TABLE IF EXISTS no_such_table;")
+        .expect("Failed to drop a table that doesn't exist");
+    assert!(notices
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|notice| notice.contains("does not exist, skipping")));
+}
+
+#[test]
+fn builtin_functions_are_answered_by_nexus() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let rows = client
+        .query("SELECT peerdb_version();", &[])
+        .expect("Failed to get the version");
+    assert!(!rows[0].get::<_, String>(0).is_empty());
+
+    let rows = client
+        .query("SELECT peerdb_peer_type('pg_test') AS peer_type;", &[])
+        .expect("Failed to get the type of the peer");
+    assert_eq!(rows[0].columns()[0].name(), "peer_type");
+    assert_eq!(rows[0].get::<_, String>(0), "POSTGRES");
+
+    let err = client
+        .simple_query("SELECT peerdb_peer_type('unknown_peer');")
+        .expect_err("the type of an unknown peer should fail");
+    assert!(err.to_string().contains("does not exist"));
+
+    let _other_client = server.connect_dying();
+    let current: i64 = client
+        .query_one("SELECT peerdb_connections();", &[])
+        .expect("Failed to get the connections of the server")
+        .get(0);
+    let peak: i64 = client
+        .query_one("SELECT peerdb_peak_connections();", &[])
+        .expect("Failed to get the peak connections of the server")
+        .get(0);
+    assert!(current >= 2);
+    assert!(peak >= current);
+}
+
+#[test]
+fn health_check_queries_are_answered_by_nexus() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+
+    let rows = client
+        .query("SELECT 1;", &[])
+        .expect("Failed to run SELECT 1");
+    assert_eq!(rows[0].columns()[0].name(), "?column?");
+    assert_eq!(rows[0].get::<_, i32>(0), 1);
+
+    let version: String = client
+        .query_one("SELECT version();", &[])
+        .expect("Failed to get the version")
+        .get(0);
+    assert!(version.starts_with("PostgreSQL 14"), "{}", version);
+
+    client
+        .simple_query("")
+        .expect("Failed to run an empty query");
+}
+
+#[test]
+fn comments_on_peers_are_shown() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    client
+        .simple_query("COMMENT ON PEER pg_test IS 'orders database';")
+        .expect("Failed to comment on peer");
+    let rows = client
+        .query("SHOW PEERS;", &[])
+        .expect("Failed to show peers");
+    let peer = rows
+        .iter()
+        .find(|row| row.get::<_, String>("name") == "pg_test")
+        .expect("pg_test should be shown");
+    assert_eq!(
+        peer.get::<_, Option<String>>("comment").as_deref(),
+        Some("orders database")
+    );
+
+    let err = client
+        .simple_query("COMMENT ON MIRROR unknown_mirror IS 'nothing';")
+        .expect_err("commenting on an unknown mirror should fail");
+    assert!(err.to_string().contains("no such mirror"));
+}
+
+#[test]
+fn catalog_queries_show_peers_as_schemas() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let rows = client
+        .query(
+            "SELECT n.nspname FROM pg_catalog.pg_namespace n ORDER BY n.nspname;",
+            &[],
+        )
+        .expect("Failed to query pg_namespace");
+    let schemas: Vec<String> = rows.iter().map(|row| row.get(0)).collect();
+    assert!(schemas.contains(&"pg_test".to_owned()));
+
+    let rows = client
+        .query(
+            "SELECT count(*) FROM information_schema.tables WHERE table_schema = 'pg_test';",
+            &[],
+        )
+        .expect("Failed to query information_schema.tables");
+    assert!(rows[0].get::<_, i64>(0) > 0);
+}
+
+#[test]
+fn psql_meta_commands_describe_peers_and_their_tables() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    // \dn
+    let rows = client
+        .query(
+            "SELECT n.nspname AS \"Name\",
+              pg_catalog.pg_get_userbyid(n.nspowner) AS \"Owner\"
+            FROM pg_catalog.pg_namespace n
+            WHERE n.nspname !~ '^pg_' AND n.nspname <> 'information_schema'
+            ORDER BY 1;",
+            &[],
+        )
+        .expect("Failed to run \\dn");
+    assert!(rows.iter().any(|row| row.get::<_, String>(0) == "pg_test"));
+
+    // \dt
+    let rows = client
+        .query(
+            "SELECT n.nspname as \"Schema\",
+              c.relname as \"Name\",
+              CASE c.relkind WHEN 'r' THEN 'table' WHEN 'p' THEN 'partitioned table' END as \"Type\",
+              pg_catalog.pg_get_userbyid(c.relowner) as \"Owner\"
+            FROM pg_catalog.pg_class c
+                 LEFT JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+                 LEFT JOIN pg_catalog.pg_am am ON am.oid = c.relam
+            WHERE c.relkind IN ('r','p','')
+                  AND n.nspname <> 'pg_catalog'
+                  AND n.nspname !~ '^pg_toast'
+                  AND n.nspname <> 'information_schema'
+              AND pg_catalog.pg_table_is_visible(c.oid)
+            ORDER BY 1,2;",
+            &[],
+        )
+        .expect("Failed to run \\dt");
+    let table = rows
+        .iter()
+        .find(|row| row.get::<_, String>(0) == "pg_test")
+        .expect("the tables of pg_test should be listed");
+    let table_name: String = table.get(1);
+    assert_eq!(table.get::<_, String>(2), "table");
+
+    // \d table looks the table up by a pattern, then its columns by oid.
+    let rows = client
+        .query(
+            "SELECT c.oid, n.nspname, c.relname
+            FROM pg_catalog.pg_class c
+                 LEFT JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relname OPERATOR(pg_catalog.=) $1 COLLATE pg_catalog.default
+              AND n.nspname OPERATOR(pg_catalog.~) '^(pg_test)$' COLLATE pg_catalog.default
+            ORDER BY 2, 3;",
+            &[&table_name],
+        )
+        .expect("Failed to look up the table for \\d");
+    assert_eq!(rows.len(), 1);
+    let oid: u32 = rows[0].get(0);
+    let rows = client
+        .query(
+            &format!(
+                "SELECT a.attname,
+                  pg_catalog.format_type(a.atttypid, a.atttypmod),
+                  (SELECT pg_catalog.pg_get_expr(d.adbin, d.adrelid, true)
+                   FROM pg_catalog.pg_attrdef d
+                   WHERE d.adrelid = a.attrelid AND d.adnum = a.attnum AND a.atthasdef),
+                  a.attnotnull
+                FROM pg_catalog.pg_attribute a
+                WHERE a.attrelid = '{}' AND a.attnum > 0 AND NOT a.attisdropped
+                ORDER BY a.attnum;",
+                oid
+            ),
+            &[],
+        )
+        .expect("Failed to describe the columns for \\d");
+    assert!(!rows.is_empty());
+}
+
+#[test]
+fn copy_to_stdout_writes_csv_lines() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let rows = client
+        .query(
+            "COPY peers (name) TO STDOUT WITH (FORMAT csv, HEADER true);",
+            &[],
+        )
+        .expect("Failed to copy out of peers");
+    let lines: Vec<String> = rows.iter().map(|row| row.get(0)).collect();
+    assert_eq!(lines[0], "name");
+    assert!(lines.contains(&"pg_test".to_owned()));
+}
+
+// the postgres crate only copies over the extended query protocol, clients
+// like psql copy over the simple one, which these speak.
+fn write_message(stream: &mut TcpStream, tag: u8, body: &[u8]) {
+    let mut message = vec![tag];
+    message.extend_from_slice(&(body.len() as i32 + 4).to_be_bytes());
+    message.extend_from_slice(body);
+    stream
+        .write_all(&message)
+        .expect("Failed to send a message");
+}
+
+fn read_message(stream: &mut TcpStream) -> (u8, Vec<u8>) {
+    let mut header = [0; 5];
+    stream
+        .read_exact(&mut header)
+        .expect("Failed to read a message");
+    let len = i32::from_be_bytes([header[1], header[2], header[3], header[4]]);
+    let mut body = vec![0; len as usize - 4];
+    stream
+        .read_exact(&mut body)
+        .expect("Failed to read a message");
+    (header[0], body)
+}
+
+// the messages up to the ReadyForQuery the server ends a query with.
+fn read_until_ready(stream: &mut TcpStream) -> Vec<(u8, Vec<u8>)> {
+    let mut messages = vec![];
+    loop {
+        let (tag, body) = read_message(stream);
+        if tag == b'Z' {
+            return messages;
+        }
+        messages.push((tag, body));
+    }
+}
+
+// a connection of the trusted user, which logs in without a password.
+fn connect_trusted() -> TcpStream {
+    let mut stream = TcpStream::connect("127.0.0.1:9900").expect("Failed to connect");
+    let mut startup = 196608i32.to_be_bytes().to_vec();
+    startup.extend_from_slice(b"user\0peerdb\0\0");
+    let mut message = (startup.len() as i32 + 4).to_be_bytes().to_vec();
+    message.extend_from_slice(&startup);
+    stream.write_all(&message).expect("Failed to start up");
+    let messages = read_until_ready(&mut stream);
+    assert!(
+        messages.iter().all(|(tag, _)| *tag != b'E'),
+        "{:?}",
+        messages
+    );
+    stream
+}
+
+// a connection of the peerdb user to a server started with
+// PEERDB_AUTH_METHOD=md5, which it logs in to with its md5 password.
+fn connect_md5() -> TcpStream {
+    let mut stream = TcpStream::connect("127.0.0.1:9900").expect("Failed to connect");
+    let mut startup = 196608i32.to_be_bytes().to_vec();
+    startup.extend_from_slice(b"user\0peerdb\0\0");
+    let mut message = (startup.len() as i32 + 4).to_be_bytes().to_vec();
+    message.extend_from_slice(&startup);
+    stream.write_all(&message).expect("Failed to start up");
+    let (tag, body) = read_message(&mut stream);
+    assert_eq!(tag, b'R', "the server should ask for a password");
+    assert_eq!(body[..4], 5i32.to_be_bytes(), "the password should be md5");
+    let mut salted = format!("{:x}", md5::compute("peerdbpeerdb")).into_bytes();
+    salted.extend_from_slice(&body[4..8]);
+    let password = format!("md5{:x}\0", md5::compute(salted));
+    write_message(&mut stream, b'p', password.as_bytes());
+    let messages = read_until_ready(&mut stream);
+    assert!(
+        messages.iter().all(|(tag, _)| *tag != b'E'),
+        "{:?}",
+        messages
+    );
+    stream
+}
+
+fn copy_from_stdin(stream: &mut TcpStream, query: &str, data: Option<&[u8]>) -> Vec<(u8, Vec<u8>)> {
+    write_message(stream, b'Q', format!("{}\0", query).as_bytes());
+    let (tag, _) = read_message(stream);
+    assert_eq!(tag, b'G', "the server should ask for the data of the COPY");
+    match data {
+        Some(data) => {
+            write_message(stream, b'd', data);
+            write_message(stream, b'c', &[]);
+        }
+        None => write_message(stream, b'f', b"canceled by the client\0"),
+    }
+    read_until_ready(stream)
+}
+
+#[test]
+fn copy_from_stdin_streams_data_to_postgres_peers() {
+    let server = PeerDBServer::with_env(&[("PEERDB_AUTH_METHOD", "md5")]);
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+    client
+        .simple_query(
+            "EXECUTE 'DROP TABLE IF EXISTS public.copy_in_test; \
+             CREATE TABLE public.copy_in_test (id int, name text)' ON PEER pg_test;",
+        )
+        .expect("Failed to create the table to copy into");
+
+    let mut stream = connect_md5();
+
+    let messages = copy_from_stdin(
+        &mut stream,
+        "COPY pg_test.public.copy_in_test (id, name) FROM STDIN;",
+        Some(b"1\tada\n2\t\\N\n"),
+    );
+    assert_eq!(messages, vec![(b'C', b"COPY 2\0".to_vec())]);
+
+    // nothing is copied when the client fails the COPY.
+    let messages = copy_from_stdin(
+        &mut stream,
+        "COPY pg_test.public.copy_in_test (id, name) FROM STDIN;",
+        None,
+    );
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].0, b'E');
+
+    let rows = client
+        .query(
+            "SELECT id, name FROM pg_test.public.copy_in_test ORDER BY id;",
+            &[],
+        )
+        .expect("Failed to select the copied rows");
+    let rows: Vec<(i32, Option<String>)> =
+        rows.iter().map(|row| (row.get(0), row.get(1))).collect();
+    assert_eq!(rows, vec![(1, Some("ada".to_owned())), (2, None)]);
+}
+
+#[test]
+fn copy_to_stdout_sends_copy_data() {
+    let server = PeerDBServer::with_env(&[("PEERDB_AUTH_METHOD", "md5")]);
+    server.connect_dying();
+    let mut stream = connect_md5();
+
+    // the options of postgres, and those of the syntax before them.
+    for options in ["WITH (FORMAT csv, HEADER true)", "CSV HEADER"] {
+        let query = format!(
+            "COPY (SELECT 1 AS a, 'x,y' AS b UNION ALL SELECT 2, NULL ORDER BY 1) TO STDOUT {};\0",
+            options
+        );
+        write_message(&mut stream, b'Q', query.as_bytes());
+        let messages = read_until_ready(&mut stream);
+        let tags: Vec<u8> = messages.iter().map(|(tag, _)| *tag).collect();
+        assert_eq!(tags, b"HdddcC".to_vec(), "{:?}", messages);
+        let lines: Vec<&[u8]> = messages[1..4].iter().map(|(_, body)| &body[..]).collect();
+        assert_eq!(
+            lines,
+            vec![&b"a,b\n"[..], &b"1,\"x,y\"\n"[..], &b"2,\n"[..]]
+        );
+        assert_eq!(messages[5].1, b"COPY 2\0".to_vec());
+    }
+}
+
+#[test]
+fn peer_slots_are_held_until_the_rows_are_read() {
+    std::fs::write(
+        "hba_scheduler_test.conf",
+        "host peerdb 127.0.0.1/32 trust\n",
+    )
+    .expect("unable to write hba_scheduler_test.conf");
+    let server = PeerDBServer::with_env(&[
+        ("PEERDB_HBA_FILE", "hba_scheduler_test.conf"),
+        ("PEERDB_PEER_CONCURRENCY", "1"),
+    ]);
+    server.connect_dying();
+    let mut client = Client::connect("host=127.0.0.1 port=9900 user=peerdb", NoTls)
+        .expect("Failed to connect to the server");
+    create_peers::create_pg::create(&mut client);
+
+    // the rows outgrow the buffers of the socket, the server sends them as
+    // the client reads them and keeps the slot of the peer until then.
+    let mut stream = connect_trusted();
+    write_message(
+        &mut stream,
+        b'Q',
+        b"SET peerdb.default_peer = 'pg_test'; \
+          SELECT g, repeat('x', 100) FROM generate_series(1, 200000) g;\0",
+    );
+    thread::sleep(Duration::from_secs(2));
+
+    let waiting = thread::spawn(move || {
+        let started = Instant::now();
+        client
+            .simple_query(
+                "SET peerdb.default_peer = 'pg_test'; \
+                 SELECT count(*) FROM generate_series(1, 10);",
+            )
+            .expect("the query should run once the slot is free");
+        started.elapsed()
+    });
+    thread::sleep(Duration::from_secs(3));
+    let messages = read_until_ready(&mut stream);
+    assert!(messages.iter().all(|(tag, _)| *tag != b'E'));
+    assert_eq!(
+        messages.iter().filter(|(tag, _)| *tag == b'D').count(),
+        200000
+    );
+
+    let waited = waiting.join().unwrap();
+    assert!(
+        waited >= Duration::from_secs(3),
+        "the query ran while the rows of another were being streamed"
+    );
+}
+
+#[test]
+fn errors_of_postgres_peers_keep_their_sqlstate() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    // clients see the SQLSTATE the peer failed the statement with.
+    let err = client
+        .simple_query("SELECT * FROM pg_test.test.no_such_table;")
+        .expect_err("querying a missing table should fail");
+    assert_eq!(err.code(), Some(&SqlState::UNDEFINED_TABLE));
+    let err = client
+        .simple_query("EXECUTE 'SELECT 1 / 0' ON PEER pg_test;")
+        .expect_err("dividing by zero should fail");
+    assert_eq!(err.code(), Some(&SqlState::DIVISION_BY_ZERO));
+}
+
+#[test]
+fn transactions_run_on_the_default_postgres_peer() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    client
+        .simple_query("SET peerdb.default_peer = 'pg_test'; BEGIN;")
+        .expect("Failed to begin a transaction on the default peer");
+    let err = client
+        .simple_query("BEGIN;")
+        .expect_err("beginning a transaction twice should fail");
+    assert!(err
+        .to_string()
+        .contains("already a transaction in progress"));
+    client
+        .simple_query("SELECT COUNT(*) FROM pg_test.test.test_table; ROLLBACK;")
+        .expect("Failed to query in the transaction and roll it back");
+
+    // ending a transaction when there is none is not an error.
+    client
+        .simple_query("COMMIT;")
+        .expect("Failed to commit without a transaction");
+}
+
+#[test]
+fn statement_timeout_from_startup_options_cancels_peer_queries() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let mut client = Client::connect(
+        "host=localhost port=9900 password=peerdb user=peerdb options='-c statement_timeout=1s'",
+        NoTls,
+    )
+    .expect("Failed to connect with startup options");
+    let row = client
+        .query_one("SHOW statement_timeout;", &[])
+        .expect("Failed to show statement_timeout");
+    assert_eq!(row.get::<_, String>(0), "1s");
+
+    let started = Instant::now();
+    let err = client
+        .simple_query("SET peerdb.default_peer = 'pg_test'; SELECT pg_sleep(60);")
+        .expect_err("the query should have timed out");
+    assert!(err.to_string().contains("statement timeout"));
+    assert!(started.elapsed() < Duration::from_secs(30));
+}
+
+#[test]
+fn statement_timeout_bounds_the_rows_streamed() {
+    std::fs::write("hba_timeout_test.conf", "host peerdb 127.0.0.1/32 trust\n")
+        .expect("unable to write hba_timeout_test.conf");
+    let server = PeerDBServer::with_env(&[("PEERDB_HBA_FILE", "hba_timeout_test.conf")]);
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    // the client doesn't read the rows until the statement timeout elapses.
+    let mut stream = connect_trusted();
+    write_message(
+        &mut stream,
+        b'Q',
+        b"SET statement_timeout = '1s'; SET peerdb.default_peer = 'pg_test'; \
+          SELECT g, repeat('x', 100) FROM generate_series(1, 200000) g;\0",
+    );
+    thread::sleep(Duration::from_secs(3));
+    let messages = read_until_ready(&mut stream);
+    let (_, error) = messages
+        .iter()
+        .find(|(tag, _)| *tag == b'E')
+        .expect("the rows should have been ended by the statement timeout");
+    assert!(String::from_utf8_lossy(error).contains("57014"));
+}
+
+#[test]
+fn cancel_request_stops_a_running_peer_query() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let cancel_token = client.cancel_token();
+    let canceller = thread::spawn(move || {
+        thread::sleep(Duration::from_secs(2));
+        cancel_token
+            .cancel_query(NoTls)
+            .expect("Failed to send the cancel request");
+    });
+
+    let started = Instant::now();
+    let err = client
+        .simple_query("SET peerdb.default_peer = 'pg_test'; SELECT pg_sleep(60);")
+        .expect_err("the query should have been cancelled");
+    canceller.join().unwrap();
+    assert!(err.to_string().contains("canceling statement"));
+    assert!(started.elapsed() < Duration::from_secs(30));
+}
+
+#[test]
+fn idle_sessions_are_closed_after_their_timeout() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+
+    client
+        .simple_query("SET idle_session_timeout = '2s';")
+        .expect("Failed to set idle_session_timeout");
+    let row = client
+        .query_one("SHOW idle_session_timeout;", &[])
+        .expect("Failed to show idle_session_timeout");
+    assert_eq!(row.get::<_, String>(0), "2s");
+
+    // a session that keeps running queries stays open.
+    for _ in 0..3 {
+        thread::sleep(Duration::from_secs(1));
+        client
+            .simple_query("SELECT 1;")
+            .expect("an active session should stay open");
+    }
+
+    thread::sleep(Duration::from_secs(4));
+    client
+        .simple_query("SELECT 1;")
+        .expect_err("the idle session should have been closed");
+}
+
+#[test]
+fn sighup_reloads_the_config_file() {
+    std::fs::write(
+        "config_test.conf",
+        "password = 'first'\nlog_level = 'error'\n",
+    )
+    .expect("unable to write config_test.conf");
+    let server = PeerDBServer::with_env(&[("PEERDB_CONFIG_FILE", "config_test.conf")]);
+    let mut client = Client::connect("host=localhost port=9900 password=first user=peerdb", NoTls)
+        .expect("Failed to log in with the password of the config file");
+    let info_logged = || {
+        std::fs::read_to_string("server.log")
+            .expect("unable to read server.log")
+            .contains("INFO")
+    };
+    assert!(
+        !info_logged(),
+        "no info should be logged at the error level"
+    );
+
+    std::fs::write(
+        "config_test.conf",
+        "password = 'second'\nlog_level = 'info'\n",
+    )
+    .expect("unable to write config_test.conf");
+    let status = Command::new("kill")
+        .args(["-HUP", &server.server.id().to_string()])
+        .status()
+        .expect("Failed to signal the server");
+    assert!(status.success());
+    thread::sleep(Duration::from_secs(1));
+
+    // new clients log in with the new password, connected ones stay.
+    Client::connect(
+        "host=localhost port=9900 password=second user=peerdb",
+        NoTls,
+    )
+    .expect("Failed to log in with the reloaded password");
+    assert!(
+        Client::connect("host=localhost port=9900 password=first user=peerdb", NoTls).is_err(),
+        "the old password should no longer log in"
+    );
+    client
+        .simple_query("SELECT 1;")
+        .expect("connected clients should stay connected");
+    assert!(
+        info_logged(),
+        "info should be logged once the level is reloaded"
+    );
+}
+
+#[test]
+fn shutdown_drains_queries_in_flight() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+    let mut idle_client = server.connect_dying();
+
+    let running = thread::spawn(move || {
+        client.simple_query("SET peerdb.default_peer = 'pg_test'; SELECT pg_sleep(3);")
+    });
+    thread::sleep(Duration::from_secs(1));
+    let status = Command::new("kill")
+        .args(["-TERM", &server.server.id().to_string()])
+        .status()
+        .expect("Failed to signal the server");
+    assert!(status.success());
+
+    // the query in flight finishes, idle clients are disconnected and new
+    // clients are turned away.
+    running
+        .join()
+        .unwrap()
+        .expect("the query in flight should finish");
+    idle_client
+        .simple_query("SELECT 1;")
+        .expect_err("idle clients should be disconnected");
+    assert!(
+        Client::connect(
+            "host=localhost port=9900 password=peerdb user=peerdb",
+            NoTls
+        )
+        .is_err(),
+        "new clients should be turned away"
+    );
+}
+
+#[test]
+fn new_connections_are_refused_once_draining_starts() {
+    let server = PeerDBServer::with_env(&[("PEERDB_LISTENERS", "127.0.0.1:9901")]);
+    let mut client = server.connect_dying();
+    create_peers::create_pg::create(&mut client);
+
+    let running = thread::spawn(move || {
+        client.simple_query("SET peerdb.default_peer = 'pg_test'; SELECT pg_sleep(5);")
+    });
+    thread::sleep(Duration::from_secs(1));
+    let status = Command::new("kill")
+        .args(["-TERM", &server.server.id().to_string()])
+        .status()
+        .expect("Failed to signal the server");
+    assert!(status.success());
+    thread::sleep(Duration::from_secs(1));
+
+    // every listener is closed while the query in flight still runs.
+    for port in [9900, 9901] {
+        let err = TcpStream::connect(("127.0.0.1", port))
+            .expect_err("the listener should be closed while draining");
+        assert_eq!(
+            err.kind(),
+            std::io::ErrorKind::ConnectionRefused,
+            "port {}",
+            port
+        );
+    }
+    running
+        .join()
+        .unwrap()
+        .expect("the query in flight should finish");
+}
+
 #[test]
 #[ignore = "requires some work for extended query prepares on bigquery."]
 fn extended_query_protocol_no_params_bq() {
@@ -242,3 +1514,69 @@ fn extended_query_protocol_no_params_bq() {
     // check that the result is non-empty.
     assert!(res > 0);
 }
+
+#[test]
+fn select_from_a_csv_table_of_a_file_peer() {
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+
+    // a table written by a mirror is a directory with a file per partition.
+    let dir = std::env::temp_dir().join("peerdb_file_peer_test");
+    std::fs::create_dir_all(dir.join("users")).expect("Failed to create the table directory");
+    std::fs::write(dir.join("users").join("1.csv"), "id,name\n1,ada\n2,\n")
+        .expect("Failed to write the first partition");
+    std::fs::write(dir.join("users").join("2.csv"), "id,name\n3,grace\n")
+        .expect("Failed to write the second partition");
+    client
+        .simple_query(&format!(
+            "CREATE PEER IF NOT EXISTS file_test FROM FILE WITH (path = '{}', format = 'csv');",
+            dir.display()
+        ))
+        .expect("Failed to create the file peer");
+
+    let rows = client
+        .query("SELECT name, id FROM file_test.users;", &[])
+        .expect("Failed to select from the file peer");
+    let rows: Vec<(Option<String>, String)> =
+        rows.iter().map(|row| (row.get(0), row.get(1))).collect();
+    assert_eq!(
+        rows,
+        vec![
+            (Some("ada".to_owned()), "1".to_owned()),
+            (None, "2".to_owned()),
+            (Some("grace".to_owned()), "3".to_owned()),
+        ]
+    );
+
+    let rows = client
+        .query("SELECT * FROM file_test.users LIMIT 1;", &[])
+        .expect("Failed to select from the file peer with a limit");
+    assert_eq!(rows.len(), 1);
+}
+
+#[test]
+fn rotate_encryption_key_checks_the_keys_first() {
+    // a server with a master key encrypts the configs of the catalog as it
+    // starts, which the other tests read without one, so this one has none.
+    let server = PeerDBServer::new();
+    let mut client = server.connect_dying();
+    let mut rotate = |old: &str, new: &str| {
+        client
+            .simple_query(&format!(
+                "SELECT peerdb.rotate_encryption_key('{}', '{}');",
+                old, new
+            ))
+            .expect_err("the keys should have been rejected")
+    };
+
+    let key = "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=";
+    let err = rotate("not base64", key);
+    assert_eq!(err.code(), Some(&SqlState::INVALID_PARAMETER_VALUE));
+    assert!(err.to_string().contains("invalid old key"));
+    let err = rotate(key, "AQEB");
+    assert!(err.to_string().contains("invalid new key"));
+    let err = rotate(key, "AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI=");
+    assert!(err
+        .to_string()
+        .contains("the new key is not the master key"));
+}
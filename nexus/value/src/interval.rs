@@ -0,0 +1,103 @@
+use std::{error::Error, fmt};
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+/// An interval as postgres keeps it, the months and days apart from the time
+/// as their length varies.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Interval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl Interval {
+    pub fn from_microseconds(microseconds: i64) -> Self {
+        Interval {
+            months: 0,
+            days: 0,
+            microseconds,
+        }
+    }
+}
+
+/// The interval in the `postgres` style of postgres, e.g. `1 year 2 mons -3 days +04:05:06`.
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        // after a negative part, the positive parts are signed too.
+        let mut negative = false;
+        let units = [
+            (self.months / 12, "year"),
+            (self.months % 12, "mon"),
+            (self.days, "day"),
+        ];
+        for (value, unit) in units {
+            if value == 0 {
+                continue;
+            }
+            let sign = if negative && value > 0 { "+" } else { "" };
+            let plural = if value == 1 { "" } else { "s" };
+            parts.push(format!("{}{} {}{}", sign, value, unit, plural));
+            negative |= value < 0;
+        }
+        if self.microseconds != 0 || parts.is_empty() {
+            let sign = if self.microseconds < 0 {
+                "-"
+            } else if negative {
+                "+"
+            } else {
+                ""
+            };
+            let microseconds = self.microseconds.unsigned_abs();
+            let seconds = microseconds / 1_000_000;
+            parts.push(format!(
+                "{}{:02}:{:02}:{:02}{}",
+                sign,
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60,
+                crate::fractional_seconds((microseconds % 1_000_000) as u32)
+            ));
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+// the binary format of an interval is its microseconds, days and months.
+impl ToSql for Interval {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.put_i64(self.microseconds);
+        out.put_i32(self.days);
+        out.put_i32(self.months);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err(format!("invalid interval of {} bytes", raw.len()).into());
+        }
+        Ok(Interval {
+            microseconds: i64::from_be_bytes(raw[..8].try_into()?),
+            days: i32::from_be_bytes(raw[8..12].try_into()?),
+            months: i32::from_be_bytes(raw[12..].try_into()?),
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+}
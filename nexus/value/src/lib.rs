@@ -1,12 +1,14 @@
 use array::ArrayValue;
 use base64::prelude::{Engine as _, BASE64_STANDARD};
 use bytes::Bytes;
-use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use interval::Interval;
 use rust_decimal::Decimal;
 use std::collections::HashMap;
 use std::str::FromStr;
 use uuid::Uuid;
 pub mod array;
+pub mod interval;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Value {
@@ -27,12 +29,12 @@ pub enum Value {
     VarBinary(Bytes),
     Date(NaiveDate),
     Time(NaiveTime),
-    TimeWithTimeZone(NaiveTime),
+    TimeWithTimeZone(NaiveTime, FixedOffset),
     Timestamp(DateTime<Utc>),
     PostgresTimestamp(NaiveDateTime),
     TimestampWithTimeZone(DateTime<Utc>),
     IpAddr(postgres_inet::MaskedIpAddr),
-    Interval(i64),
+    Interval(Interval),
     Array(ArrayValue),
     Json(serde_json::Value),
     JsonB(serde_json::Value),
@@ -108,8 +110,8 @@ impl Value {
         Value::Time(value)
     }
 
-    pub fn time_with_time_zone(value: NaiveTime) -> Self {
-        Value::TimeWithTimeZone(value)
+    pub fn time_with_time_zone(value: NaiveTime, offset: FixedOffset) -> Self {
+        Value::TimeWithTimeZone(value, offset)
     }
 
     pub fn timestamp(value: DateTime<Utc>) -> Self {
@@ -128,7 +130,7 @@ impl Value {
         Value::IpAddr(value)
     }
 
-    pub fn interval(value: i64) -> Self {
+    pub fn interval(value: Interval) -> Self {
         Value::Interval(value)
     }
 
@@ -241,12 +243,14 @@ impl Value {
             Value::VarBinary(b) => serde_json::Value::String(BASE64_STANDARD.encode(b)),
             Value::Date(d) => serde_json::Value::String(d.to_string()),
             Value::Time(t) => serde_json::Value::String(t.to_string()),
-            Value::TimeWithTimeZone(t) => serde_json::Value::String(t.to_string()),
+            Value::TimeWithTimeZone(t, offset) => {
+                serde_json::Value::String(time_with_time_zone_text(t, offset))
+            }
             Value::PostgresTimestamp(t) => serde_json::Value::String(t.to_string()),
             Value::Timestamp(ts) => serde_json::Value::String(ts.to_rfc3339()),
             Value::TimestampWithTimeZone(ts) => serde_json::Value::String(ts.to_rfc3339()),
             Value::IpAddr(ip) => serde_json::Value::String(ip.to_string()),
-            Value::Interval(i) => serde_json::Value::Number(serde_json::Number::from(*i)),
+            Value::Interval(i) => serde_json::Value::String(i.to_string()),
             Value::Array(arr) => arr.to_serde_json_value(),
             Value::Json(s) => s.clone(),
             Value::JsonB(s) => s.clone(),
@@ -263,6 +267,49 @@ impl Value {
     }
 }
 
+/// A timetz as postgres writes it, e.g. `04:05:06.789+05:30`.
+pub fn time_with_time_zone_text(time: &NaiveTime, offset: &FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.unsigned_abs();
+    let mut text = format!(
+        "{}{}{}{:02}",
+        time.format("%H:%M:%S"),
+        fractional_seconds(time.nanosecond() / 1000),
+        sign,
+        seconds / 3600
+    );
+    if seconds % 3600 != 0 {
+        text.push_str(&format!(":{:02}", seconds / 60 % 60));
+    }
+    if seconds % 60 != 0 {
+        text.push_str(&format!(":{:02}", seconds % 60));
+    }
+    text
+}
+
+/// An hstore as postgres writes it, e.g. `"a"=>"1", "b"=>"2"`.
+pub fn hstore_text(hstore: &HashMap<String, String>) -> String {
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+    let mut pairs: Vec<_> = hstore.iter().collect();
+    pairs.sort();
+    pairs
+        .into_iter()
+        .map(|(key, value)| format!("{}=>{}", quote(key), quote(value)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// the fraction of a second of a time, without its trailing zeros.
+fn fractional_seconds(microseconds: u32) -> String {
+    match microseconds {
+        0 => String::new(),
+        _ => format!(".{:06}", microseconds)
+            .trim_end_matches('0')
+            .to_string(),
+    }
+}
+
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.to_string() {
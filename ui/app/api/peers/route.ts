@@ -3,6 +3,7 @@ import {
   UCreatePeerResponse,
   UValidatePeerResponse,
 } from '@/app/dto/PeersDTO';
+import { openPeerConfig } from '@/app/utils/encryption';
 import prisma from '@/app/utils/prisma';
 import {
   BigqueryConfig,
@@ -96,7 +97,7 @@ export async function GET(request: Request) {
       name: peer.name,
       type: peer.type,
     };
-    const options = peer.options;
+    const options = openPeerConfig(peer.name, peer.options);
     let config:
       | BigqueryConfig
       | SnowflakeConfig
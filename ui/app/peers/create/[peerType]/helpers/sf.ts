@@ -65,6 +65,19 @@ export const snowflakeSetting: PeerSetting[] = [
     helpfulLink:
       'https://docs.snowflake.com/en/user-guide/data-load-s3-config-storage-integration',
   },
+  {
+    label: 'Staging Path',
+    stateHandler: (value, setter) => {
+      if (!value.length) {
+        setter((curr) => {
+          delete curr['stagingPath'];
+          return curr;
+        });
+      } else setter((curr) => ({ ...curr, stagingPath: value }));
+    },
+    optional: true,
+    tips: 'This is needed only if you plan to COPY rows into the peer through PeerDB. The rows are staged under this s3:// url before they are loaded.',
+  },
   {
     label: 'Password',
     stateHandler: (value, setter) => {
@@ -105,4 +105,11 @@ export const sfSchema = z.object({
     })
     .max(255, 's3Integration must be less than 255 characters')
     .optional(),
+  stagingPath: z
+    .string({
+      invalid_type_error: 'Staging path must be a string',
+    })
+    .startsWith('s3://', 'Staging path must be an s3:// url')
+    .optional()
+    .transform((e) => (e === '' ? undefined : e)),
 });
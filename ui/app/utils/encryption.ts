@@ -0,0 +1,62 @@
+import { createDecipheriv } from 'crypto';
+
+// The configs of peers are encrypted in the catalog when it has a master key,
+// see nexus/catalog/src/encryption.rs:
+//   magic | key nonce | wrapped data key | data nonce | encrypted config
+const MAGIC = Buffer.from('\0PE1', 'binary');
+const KEY_LEN = 32;
+const NONCE_LEN = 12;
+const TAG_LEN = 16;
+
+const masterKey = (env: string) => {
+  const encoded = process.env[env]?.trim();
+  if (!encoded) {
+    return undefined;
+  }
+  const key = Buffer.from(encoded, 'base64');
+  if (key.length !== KEY_LEN) {
+    throw new Error(`${env} must be ${KEY_LEN} bytes`);
+  }
+  return key;
+};
+
+const decrypt = (key: Buffer, nonce: Buffer, aad: Buffer, sealed: Buffer) => {
+  const decipher = createDecipheriv('aes-256-gcm', key, nonce);
+  decipher.setAAD(aad);
+  decipher.setAuthTag(sealed.subarray(sealed.length - TAG_LEN));
+  return Buffer.concat([
+    decipher.update(sealed.subarray(0, sealed.length - TAG_LEN)),
+    decipher.final(),
+  ]);
+};
+
+// The config of the peer as it is stored, decrypted if it is encrypted.
+export const openPeerConfig = (name: string, blob: Buffer) => {
+  if (!blob.subarray(0, MAGIC.length).equals(MAGIC)) {
+    return blob;
+  }
+  const key = masterKey('PEERDB_CATALOG_MASTER_KEY');
+  if (!key) {
+    throw new Error(
+      `the config of ${name} is encrypted, but PEERDB_CATALOG_MASTER_KEY is not set`
+    );
+  }
+  const wrappedLen = KEY_LEN + TAG_LEN;
+  let offset = MAGIC.length;
+  const keyNonce = blob.subarray(offset, (offset += NONCE_LEN));
+  const wrappedKey = blob.subarray(offset, (offset += wrappedLen));
+  const dataNonce = blob.subarray(offset, (offset += NONCE_LEN));
+  let dataKey: Buffer;
+  try {
+    dataKey = decrypt(key, keyNonce, MAGIC, wrappedKey);
+  } catch (err) {
+    // while the master key is rotated, the data key may still be wrapped by
+    // the previous one.
+    const previousKey = masterKey('PEERDB_CATALOG_PREVIOUS_MASTER_KEY');
+    if (!previousKey) {
+      throw err;
+    }
+    dataKey = decrypt(previousKey, keyNonce, MAGIC, wrappedKey);
+  }
+  return decrypt(dataKey, dataNonce, Buffer.from(name), blob.subarray(offset));
+};
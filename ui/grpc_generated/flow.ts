@@ -6,6 +6,88 @@ import { Peer } from "./peers";
 
 export const protobufPackage = "peerdb_flow";
 
+export enum TablePausePolicy {
+  /** TABLE_PAUSE_POLICY_BUFFER - changes keep landing in the raw table and are normalized on resume. */
+  TABLE_PAUSE_POLICY_BUFFER = 0,
+  /** TABLE_PAUSE_POLICY_SKIP - changes are dropped, the destination table misses them. */
+  TABLE_PAUSE_POLICY_SKIP = 1,
+  UNRECOGNIZED = -1,
+}
+
+export function tablePausePolicyFromJSON(object: any): TablePausePolicy {
+  switch (object) {
+    case 0:
+    case "TABLE_PAUSE_POLICY_BUFFER":
+      return TablePausePolicy.TABLE_PAUSE_POLICY_BUFFER;
+    case 1:
+    case "TABLE_PAUSE_POLICY_SKIP":
+      return TablePausePolicy.TABLE_PAUSE_POLICY_SKIP;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return TablePausePolicy.UNRECOGNIZED;
+  }
+}
+
+export function tablePausePolicyToJSON(object: TablePausePolicy): string {
+  switch (object) {
+    case TablePausePolicy.TABLE_PAUSE_POLICY_BUFFER:
+      return "TABLE_PAUSE_POLICY_BUFFER";
+    case TablePausePolicy.TABLE_PAUSE_POLICY_SKIP:
+      return "TABLE_PAUSE_POLICY_SKIP";
+    case TablePausePolicy.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export enum OrderingKey {
+  /** ORDERING_KEY_UNSPECIFIED - the destination's default, usually the destination table name. */
+  ORDERING_KEY_UNSPECIFIED = 0,
+  ORDERING_KEY_PRIMARY_KEY = 1,
+  ORDERING_KEY_TABLE = 2,
+  /** ORDERING_KEY_PARTITION_KEY - the partition_key column of each table mapping. */
+  ORDERING_KEY_PARTITION_KEY = 3,
+  UNRECOGNIZED = -1,
+}
+
+export function orderingKeyFromJSON(object: any): OrderingKey {
+  switch (object) {
+    case 0:
+    case "ORDERING_KEY_UNSPECIFIED":
+      return OrderingKey.ORDERING_KEY_UNSPECIFIED;
+    case 1:
+    case "ORDERING_KEY_PRIMARY_KEY":
+      return OrderingKey.ORDERING_KEY_PRIMARY_KEY;
+    case 2:
+    case "ORDERING_KEY_TABLE":
+      return OrderingKey.ORDERING_KEY_TABLE;
+    case 3:
+    case "ORDERING_KEY_PARTITION_KEY":
+      return OrderingKey.ORDERING_KEY_PARTITION_KEY;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return OrderingKey.UNRECOGNIZED;
+  }
+}
+
+export function orderingKeyToJSON(object: OrderingKey): string {
+  switch (object) {
+    case OrderingKey.ORDERING_KEY_UNSPECIFIED:
+      return "ORDERING_KEY_UNSPECIFIED";
+    case OrderingKey.ORDERING_KEY_PRIMARY_KEY:
+      return "ORDERING_KEY_PRIMARY_KEY";
+    case OrderingKey.ORDERING_KEY_TABLE:
+      return "ORDERING_KEY_TABLE";
+    case OrderingKey.ORDERING_KEY_PARTITION_KEY:
+      return "ORDERING_KEY_PARTITION_KEY";
+    case OrderingKey.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 /** protos for qrep */
 export enum QRepSyncMode {
   QREP_SYNC_MODE_MULTI_INSERT = 0,
@@ -101,6 +183,15 @@ export interface TableMapping {
   sourceTableIdentifier: string;
   destinationTableIdentifier: string;
   partitionKey: string;
+  /** columns of the source table that are never read or replicated. */
+  exclude: string[];
+}
+
+export interface SchemaMapping {
+  sourceSchema: string;
+  /** empty for destinations without schemas, whose tables keep their names. */
+  destinationSchema: string;
+  excludeTables: string[];
 }
 
 export interface FlowConnectionConfigs {
@@ -125,6 +216,13 @@ export interface FlowConnectionConfigs {
   snapshotNumTablesInParallel: number;
   snapshotSyncMode: QRepSyncMode;
   cdcSyncMode: QRepSyncMode;
+  /**
+   * where the mirror stages the files it loads, so mirrors can stage through
+   * buckets of their own: an s3:// path for snowflake, nothing for its
+   * internal stage, and a bucket name or gs://bucket/prefix for bigquery.
+   * snowflake stages the records of cdc through cdc_staging_path too, rather
+   * than always through its internal stage.
+   */
   snapshotStagingPath: string;
   cdcStagingPath: string;
   /** currently only works for snowflake */
@@ -133,6 +231,70 @@ export interface FlowConnectionConfigs {
   /** the below two are for eventhub only */
   pushBatchSize: number;
   pushParallelism: number;
+  /**
+   * shut the flow down once the initial copy completes, and drop the
+   * replication slot and publication, instead of moving on to CDC.
+   */
+  initialCopyOnly: boolean;
+  /**
+   * how records are keyed on queue destinations, records with the same key
+   * are delivered in order.
+   */
+  orderingKey: OrderingKey;
+  /**
+   * snowflake storage integration to stage through, overrides the one on the
+   * destination peer so mirrors can use their own buckets.
+   */
+  stagingIntegration: string;
+  /**
+   * destination tables whose replication is paused, to the policy for the
+   * changes made to them while paused.
+   */
+  pausedTables: { [key: string]: TablePausePolicy };
+  /**
+   * destination tables resumed after being paused with the buffer policy, to
+   * the last batch normalized before they were paused.
+   */
+  resumedTables: { [key: string]: number };
+  /**
+   * destination tables with at least this many rows loaded since their
+   * statistics were last refreshed get them refreshed after a snapshot or a
+   * normalized batch, 0 leaves them to the destination.
+   */
+  refreshStatisticsThreshold: number;
+  /**
+   * batches are only normalized into the destination tables inside this
+   * window, outside it they are buffered in the raw table. unset normalizes
+   * batches as they are synced.
+   */
+  applyWindow:
+    | ApplyWindow
+    | undefined;
+  /**
+   * stamp the replicated rows with the commit time of their change at the
+   * source and the time it was applied, and report the latency in between.
+   */
+  trackLatency: boolean;
+  /**
+   * the column deleted rows are marked in with soft_delete,
+   * _PEERDB_IS_DELETED when empty.
+   */
+  softDeleteColName: string;
+  /**
+   * the column rows are stamped with the time they were last synced in, no
+   * column is added when empty.
+   */
+  syncedAtColName: string;
+  /**
+   * the schemas mirrored as a whole, their tables at creation are part of the
+   * table mappings.
+   */
+  schemaMappings: SchemaMapping[];
+  /**
+   * how often the schemas are checked for new tables to add to the mirror,
+   * they are only expanded at creation when 0.
+   */
+  schemaRefreshIntervalSeconds: number;
 }
 
 export interface FlowConnectionConfigs_SrcTableIdNameMappingEntry {
@@ -145,6 +307,25 @@ export interface FlowConnectionConfigs_TableNameSchemaMappingEntry {
   value: TableSchema | undefined;
 }
 
+export interface FlowConnectionConfigs_PausedTablesEntry {
+  key: string;
+  value: TablePausePolicy;
+}
+
+export interface FlowConnectionConfigs_ResumedTablesEntry {
+  key: string;
+  value: number;
+}
+
+/**
+ * a daily window of UTC time, in minutes since midnight. a window that ends
+ * before it starts spans midnight.
+ */
+export interface ApplyWindow {
+  startMinute: number;
+  endMinute: number;
+}
+
 export interface SyncFlowOptions {
   batchSize: number;
   relationMessageMapping: { [key: number]: RelationMessage };
@@ -264,6 +445,12 @@ export interface TableSchema {
   columns: { [key: string]: string };
   primaryKeyColumns: string[];
   isReplicaIdentityFull: boolean;
+  /**
+   * source types of the enum and composite columns, Postgres destinations
+   * create equivalent types for them. Elsewhere enums are replicated as
+   * strings and composites as json objects.
+   */
+  columnTypes: { [key: string]: PostgresType };
 }
 
 export interface TableSchema_ColumnsEntry {
@@ -271,6 +458,25 @@ export interface TableSchema_ColumnsEntry {
   value: string;
 }
 
+export interface TableSchema_ColumnTypesEntry {
+  key: string;
+  value: PostgresType | undefined;
+}
+
+export interface PostgresType {
+  name: string;
+  /** labels of an enum type, in order. */
+  enumLabels: string[];
+  /** attributes of a composite type, in order. */
+  attributes: PostgresTypeAttribute[];
+}
+
+export interface PostgresTypeAttribute {
+  name: string;
+  /** as formatted by format_type, e.g. "numeric(10,2)". */
+  type: string;
+}
+
 export interface GetTableSchemaBatchInput {
   peerConnectionConfig: Peer | undefined;
   tableIdentifiers: string[];
@@ -294,6 +500,8 @@ export interface SetupNormalizedTableInput {
 export interface SetupNormalizedTableBatchInput {
   peerConnectionConfig: Peer | undefined;
   tableNameSchemaMapping: { [key: string]: TableSchema };
+  softDeleteColName: string;
+  syncedAtColName: string;
 }
 
 export interface SetupNormalizedTableBatchInput_TableNameSchemaMappingEntry {
@@ -382,6 +590,28 @@ export interface QRepConfig {
   numRowsPerPartition: number;
   /** Creates the watermark table on the destination as-is, can be used for some queries. */
   setupWatermarkTableOnDestination: boolean;
+  /**
+   * snowflake storage integration used with an s3:// staging_path, overrides
+   * the one on the destination peer.
+   */
+  stagingIntegration: string;
+  /**
+   * the destination table gets its statistics refreshed after a batch of
+   * partitions when at least this many rows were loaded since they were last
+   * refreshed, 0 leaves them to the destination.
+   */
+  refreshStatisticsThreshold: number;
+  /**
+   * the metadata columns of the destination table of a cdc mirror, which
+   * aren't copied from the source.
+   */
+  softDeleteColName: string;
+  syncedAtColName: string;
+  /**
+   * runs the mirror on this cron schedule in UTC, instead of waiting
+   * wait_between_batches_seconds after each run.
+   */
+  cronSchedule: string;
 }
 
 export interface QRepPartition {
@@ -672,7 +902,7 @@ export const RelationMessage = {
 };
 
 function createBaseTableMapping(): TableMapping {
-  return { sourceTableIdentifier: "", destinationTableIdentifier: "", partitionKey: "" };
+  return { sourceTableIdentifier: "", destinationTableIdentifier: "", partitionKey: "", exclude: [] };
 }
 
 export const TableMapping = {
@@ -686,6 +916,9 @@ export const TableMapping = {
     if (message.partitionKey !== "") {
       writer.uint32(26).string(message.partitionKey);
     }
+    for (const v of message.exclude) {
+      writer.uint32(34).string(v!);
+    }
     return writer;
   },
 
@@ -717,6 +950,13 @@ export const TableMapping = {
 
           message.partitionKey = reader.string();
           continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.exclude.push(reader.string());
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -733,6 +973,7 @@ export const TableMapping = {
         ? String(object.destinationTableIdentifier)
         : "",
       partitionKey: isSet(object.partitionKey) ? String(object.partitionKey) : "",
+      exclude: Array.isArray(object?.exclude) ? object.exclude.map((e: any) => String(e)) : [],
     };
   },
 
@@ -747,6 +988,9 @@ export const TableMapping = {
     if (message.partitionKey !== "") {
       obj.partitionKey = message.partitionKey;
     }
+    if (message.exclude?.length) {
+      obj.exclude = message.exclude;
+    }
     return obj;
   },
 
@@ -758,6 +1002,96 @@ export const TableMapping = {
     message.sourceTableIdentifier = object.sourceTableIdentifier ?? "";
     message.destinationTableIdentifier = object.destinationTableIdentifier ?? "";
     message.partitionKey = object.partitionKey ?? "";
+    message.exclude = object.exclude?.map((e) => e) || [];
+    return message;
+  },
+};
+
+function createBaseSchemaMapping(): SchemaMapping {
+  return { sourceSchema: "", destinationSchema: "", excludeTables: [] };
+}
+
+export const SchemaMapping = {
+  encode(message: SchemaMapping, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.sourceSchema !== "") {
+      writer.uint32(10).string(message.sourceSchema);
+    }
+    if (message.destinationSchema !== "") {
+      writer.uint32(18).string(message.destinationSchema);
+    }
+    for (const v of message.excludeTables) {
+      writer.uint32(26).string(v!);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): SchemaMapping {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSchemaMapping();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sourceSchema = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.destinationSchema = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.excludeTables.push(reader.string());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SchemaMapping {
+    return {
+      sourceSchema: isSet(object.sourceSchema) ? String(object.sourceSchema) : "",
+      destinationSchema: isSet(object.destinationSchema) ? String(object.destinationSchema) : "",
+      excludeTables: Array.isArray(object?.excludeTables) ? object.excludeTables.map((e: any) => String(e)) : [],
+    };
+  },
+
+  toJSON(message: SchemaMapping): unknown {
+    const obj: any = {};
+    if (message.sourceSchema !== "") {
+      obj.sourceSchema = message.sourceSchema;
+    }
+    if (message.destinationSchema !== "") {
+      obj.destinationSchema = message.destinationSchema;
+    }
+    if (message.excludeTables?.length) {
+      obj.excludeTables = message.excludeTables;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SchemaMapping>, I>>(base?: I): SchemaMapping {
+    return SchemaMapping.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SchemaMapping>, I>>(object: I): SchemaMapping {
+    const message = createBaseSchemaMapping();
+    message.sourceSchema = object.sourceSchema ?? "";
+    message.destinationSchema = object.destinationSchema ?? "";
+    message.excludeTables = object.excludeTables?.map((e) => e) || [];
     return message;
   },
 };
@@ -786,6 +1120,18 @@ function createBaseFlowConnectionConfigs(): FlowConnectionConfigs {
     replicationSlotName: "",
     pushBatchSize: 0,
     pushParallelism: 0,
+    initialCopyOnly: false,
+    orderingKey: 0,
+    stagingIntegration: "",
+    pausedTables: {},
+    resumedTables: {},
+    refreshStatisticsThreshold: 0,
+    applyWindow: undefined,
+    trackLatency: false,
+    softDeleteColName: "",
+    syncedAtColName: "",
+    schemaMappings: [],
+    schemaRefreshIntervalSeconds: 0,
   };
 }
 
@@ -859,6 +1205,42 @@ export const FlowConnectionConfigs = {
     if (message.pushParallelism !== 0) {
       writer.uint32(176).int64(message.pushParallelism);
     }
+    if (message.initialCopyOnly === true) {
+      writer.uint32(184).bool(message.initialCopyOnly);
+    }
+    if (message.orderingKey !== 0) {
+      writer.uint32(192).int32(message.orderingKey);
+    }
+    if (message.stagingIntegration !== "") {
+      writer.uint32(202).string(message.stagingIntegration);
+    }
+    Object.entries(message.pausedTables).forEach(([key, value]) => {
+      FlowConnectionConfigs_PausedTablesEntry.encode({ key: key as any, value }, writer.uint32(210).fork()).ldelim();
+    });
+    Object.entries(message.resumedTables).forEach(([key, value]) => {
+      FlowConnectionConfigs_ResumedTablesEntry.encode({ key: key as any, value }, writer.uint32(218).fork()).ldelim();
+    });
+    if (message.refreshStatisticsThreshold !== 0) {
+      writer.uint32(224).uint32(message.refreshStatisticsThreshold);
+    }
+    if (message.applyWindow !== undefined) {
+      ApplyWindow.encode(message.applyWindow, writer.uint32(234).fork()).ldelim();
+    }
+    if (message.trackLatency === true) {
+      writer.uint32(240).bool(message.trackLatency);
+    }
+    if (message.softDeleteColName !== "") {
+      writer.uint32(250).string(message.softDeleteColName);
+    }
+    if (message.syncedAtColName !== "") {
+      writer.uint32(258).string(message.syncedAtColName);
+    }
+    for (const v of message.schemaMappings) {
+      SchemaMapping.encode(v!, writer.uint32(266).fork()).ldelim();
+    }
+    if (message.schemaRefreshIntervalSeconds !== 0) {
+      writer.uint32(272).uint32(message.schemaRefreshIntervalSeconds);
+    }
     return writer;
   },
 
@@ -1029,6 +1411,96 @@ export const FlowConnectionConfigs = {
 
           message.pushParallelism = longToNumber(reader.int64() as Long);
           continue;
+        case 23:
+          if (tag !== 184) {
+            break;
+          }
+
+          message.initialCopyOnly = reader.bool();
+          continue;
+        case 24:
+          if (tag !== 192) {
+            break;
+          }
+
+          message.orderingKey = reader.int32() as any;
+          continue;
+        case 25:
+          if (tag !== 202) {
+            break;
+          }
+
+          message.stagingIntegration = reader.string();
+          continue;
+        case 26:
+          if (tag !== 210) {
+            break;
+          }
+
+          const entry26 = FlowConnectionConfigs_PausedTablesEntry.decode(reader, reader.uint32());
+          if (entry26.value !== undefined) {
+            message.pausedTables[entry26.key] = entry26.value;
+          }
+          continue;
+        case 27:
+          if (tag !== 218) {
+            break;
+          }
+
+          const entry27 = FlowConnectionConfigs_ResumedTablesEntry.decode(reader, reader.uint32());
+          if (entry27.value !== undefined) {
+            message.resumedTables[entry27.key] = entry27.value;
+          }
+          continue;
+        case 28:
+          if (tag !== 224) {
+            break;
+          }
+
+          message.refreshStatisticsThreshold = reader.uint32();
+          continue;
+        case 29:
+          if (tag !== 234) {
+            break;
+          }
+
+          message.applyWindow = ApplyWindow.decode(reader, reader.uint32());
+          continue;
+        case 30:
+          if (tag !== 240) {
+            break;
+          }
+
+          message.trackLatency = reader.bool();
+          continue;
+        case 31:
+          if (tag !== 250) {
+            break;
+          }
+
+          message.softDeleteColName = reader.string();
+          continue;
+        case 32:
+          if (tag !== 258) {
+            break;
+          }
+
+          message.syncedAtColName = reader.string();
+          continue;
+        case 33:
+          if (tag !== 266) {
+            break;
+          }
+
+          message.schemaMappings.push(SchemaMapping.decode(reader, reader.uint32()));
+          continue;
+        case 34:
+          if (tag !== 272) {
+            break;
+          }
+
+          message.schemaRefreshIntervalSeconds = reader.uint32();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1080,6 +1552,34 @@ export const FlowConnectionConfigs = {
       replicationSlotName: isSet(object.replicationSlotName) ? String(object.replicationSlotName) : "",
       pushBatchSize: isSet(object.pushBatchSize) ? Number(object.pushBatchSize) : 0,
       pushParallelism: isSet(object.pushParallelism) ? Number(object.pushParallelism) : 0,
+      initialCopyOnly: isSet(object.initialCopyOnly) ? Boolean(object.initialCopyOnly) : false,
+      orderingKey: isSet(object.orderingKey) ? orderingKeyFromJSON(object.orderingKey) : 0,
+      stagingIntegration: isSet(object.stagingIntegration) ? String(object.stagingIntegration) : "",
+      pausedTables: isObject(object.pausedTables)
+        ? Object.entries(object.pausedTables).reduce<{ [key: string]: TablePausePolicy }>((acc, [key, value]) => {
+          acc[key] = value as TablePausePolicy;
+          return acc;
+        }, {})
+        : {},
+      resumedTables: isObject(object.resumedTables)
+        ? Object.entries(object.resumedTables).reduce<{ [key: string]: number }>((acc, [key, value]) => {
+          acc[key] = Number(value);
+          return acc;
+        }, {})
+        : {},
+      refreshStatisticsThreshold: isSet(object.refreshStatisticsThreshold)
+        ? Number(object.refreshStatisticsThreshold)
+        : 0,
+      applyWindow: isSet(object.applyWindow) ? ApplyWindow.fromJSON(object.applyWindow) : undefined,
+      trackLatency: isSet(object.trackLatency) ? Boolean(object.trackLatency) : false,
+      softDeleteColName: isSet(object.softDeleteColName) ? String(object.softDeleteColName) : "",
+      syncedAtColName: isSet(object.syncedAtColName) ? String(object.syncedAtColName) : "",
+      schemaMappings: Array.isArray(object?.schemaMappings)
+        ? object.schemaMappings.map((e: any) => SchemaMapping.fromJSON(e))
+        : [],
+      schemaRefreshIntervalSeconds: isSet(object.schemaRefreshIntervalSeconds)
+        ? Number(object.schemaRefreshIntervalSeconds)
+        : 0,
     };
   },
 
@@ -1163,6 +1663,54 @@ export const FlowConnectionConfigs = {
     if (message.pushParallelism !== 0) {
       obj.pushParallelism = Math.round(message.pushParallelism);
     }
+    if (message.initialCopyOnly === true) {
+      obj.initialCopyOnly = message.initialCopyOnly;
+    }
+    if (message.orderingKey !== 0) {
+      obj.orderingKey = orderingKeyToJSON(message.orderingKey);
+    }
+    if (message.stagingIntegration !== "") {
+      obj.stagingIntegration = message.stagingIntegration;
+    }
+    if (message.pausedTables) {
+      const entries = Object.entries(message.pausedTables);
+      if (entries.length > 0) {
+        obj.pausedTables = {};
+        entries.forEach(([k, v]) => {
+          obj.pausedTables[k] = tablePausePolicyToJSON(v);
+        });
+      }
+    }
+    if (message.resumedTables) {
+      const entries = Object.entries(message.resumedTables);
+      if (entries.length > 0) {
+        obj.resumedTables = {};
+        entries.forEach(([k, v]) => {
+          obj.resumedTables[k] = Math.round(v);
+        });
+      }
+    }
+    if (message.refreshStatisticsThreshold !== 0) {
+      obj.refreshStatisticsThreshold = Math.round(message.refreshStatisticsThreshold);
+    }
+    if (message.applyWindow !== undefined) {
+      obj.applyWindow = ApplyWindow.toJSON(message.applyWindow);
+    }
+    if (message.trackLatency === true) {
+      obj.trackLatency = message.trackLatency;
+    }
+    if (message.softDeleteColName !== "") {
+      obj.softDeleteColName = message.softDeleteColName;
+    }
+    if (message.syncedAtColName !== "") {
+      obj.syncedAtColName = message.syncedAtColName;
+    }
+    if (message.schemaMappings?.length) {
+      obj.schemaMappings = message.schemaMappings.map((e) => SchemaMapping.toJSON(e));
+    }
+    if (message.schemaRefreshIntervalSeconds !== 0) {
+      obj.schemaRefreshIntervalSeconds = Math.round(message.schemaRefreshIntervalSeconds);
+    }
     return obj;
   },
 
@@ -1215,6 +1763,36 @@ export const FlowConnectionConfigs = {
     message.replicationSlotName = object.replicationSlotName ?? "";
     message.pushBatchSize = object.pushBatchSize ?? 0;
     message.pushParallelism = object.pushParallelism ?? 0;
+    message.initialCopyOnly = object.initialCopyOnly ?? false;
+    message.orderingKey = object.orderingKey ?? 0;
+    message.stagingIntegration = object.stagingIntegration ?? "";
+    message.pausedTables = Object.entries(object.pausedTables ?? {}).reduce<{ [key: string]: TablePausePolicy }>(
+      (acc, [key, value]) => {
+        if (value !== undefined) {
+          acc[key] = value as TablePausePolicy;
+        }
+        return acc;
+      },
+      {},
+    );
+    message.resumedTables = Object.entries(object.resumedTables ?? {}).reduce<{ [key: string]: number }>(
+      (acc, [key, value]) => {
+        if (value !== undefined) {
+          acc[key] = Number(value);
+        }
+        return acc;
+      },
+      {},
+    );
+    message.refreshStatisticsThreshold = object.refreshStatisticsThreshold ?? 0;
+    message.applyWindow = (object.applyWindow !== undefined && object.applyWindow !== null)
+      ? ApplyWindow.fromPartial(object.applyWindow)
+      : undefined;
+    message.trackLatency = object.trackLatency ?? false;
+    message.softDeleteColName = object.softDeleteColName ?? "";
+    message.syncedAtColName = object.syncedAtColName ?? "";
+    message.schemaMappings = object.schemaMappings?.map((e) => SchemaMapping.fromPartial(e)) || [];
+    message.schemaRefreshIntervalSeconds = object.schemaRefreshIntervalSeconds ?? 0;
     return message;
   },
 };
@@ -1380,6 +1958,233 @@ export const FlowConnectionConfigs_TableNameSchemaMappingEntry = {
   },
 };
 
+function createBaseFlowConnectionConfigs_PausedTablesEntry(): FlowConnectionConfigs_PausedTablesEntry {
+  return { key: "", value: 0 };
+}
+
+export const FlowConnectionConfigs_PausedTablesEntry = {
+  encode(message: FlowConnectionConfigs_PausedTablesEntry, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.key !== "") {
+      writer.uint32(10).string(message.key);
+    }
+    if (message.value !== 0) {
+      writer.uint32(16).int32(message.value);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FlowConnectionConfigs_PausedTablesEntry {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFlowConnectionConfigs_PausedTablesEntry();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.key = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.value = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FlowConnectionConfigs_PausedTablesEntry {
+    return {
+      key: isSet(object.key) ? String(object.key) : "",
+      value: isSet(object.value) ? tablePausePolicyFromJSON(object.value) : 0,
+    };
+  },
+
+  toJSON(message: FlowConnectionConfigs_PausedTablesEntry): unknown {
+    const obj: any = {};
+    if (message.key !== "") {
+      obj.key = message.key;
+    }
+    if (message.value !== 0) {
+      obj.value = tablePausePolicyToJSON(message.value);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FlowConnectionConfigs_PausedTablesEntry>, I>>(
+    base?: I,
+  ): FlowConnectionConfigs_PausedTablesEntry {
+    return FlowConnectionConfigs_PausedTablesEntry.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FlowConnectionConfigs_PausedTablesEntry>, I>>(
+    object: I,
+  ): FlowConnectionConfigs_PausedTablesEntry {
+    const message = createBaseFlowConnectionConfigs_PausedTablesEntry();
+    message.key = object.key ?? "";
+    message.value = object.value ?? 0;
+    return message;
+  },
+};
+
+function createBaseFlowConnectionConfigs_ResumedTablesEntry(): FlowConnectionConfigs_ResumedTablesEntry {
+  return { key: "", value: 0 };
+}
+
+export const FlowConnectionConfigs_ResumedTablesEntry = {
+  encode(message: FlowConnectionConfigs_ResumedTablesEntry, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.key !== "") {
+      writer.uint32(10).string(message.key);
+    }
+    if (message.value !== 0) {
+      writer.uint32(16).int64(message.value);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FlowConnectionConfigs_ResumedTablesEntry {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFlowConnectionConfigs_ResumedTablesEntry();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.key = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.value = longToNumber(reader.int64() as Long);
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FlowConnectionConfigs_ResumedTablesEntry {
+    return { key: isSet(object.key) ? String(object.key) : "", value: isSet(object.value) ? Number(object.value) : 0 };
+  },
+
+  toJSON(message: FlowConnectionConfigs_ResumedTablesEntry): unknown {
+    const obj: any = {};
+    if (message.key !== "") {
+      obj.key = message.key;
+    }
+    if (message.value !== 0) {
+      obj.value = Math.round(message.value);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FlowConnectionConfigs_ResumedTablesEntry>, I>>(
+    base?: I,
+  ): FlowConnectionConfigs_ResumedTablesEntry {
+    return FlowConnectionConfigs_ResumedTablesEntry.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FlowConnectionConfigs_ResumedTablesEntry>, I>>(
+    object: I,
+  ): FlowConnectionConfigs_ResumedTablesEntry {
+    const message = createBaseFlowConnectionConfigs_ResumedTablesEntry();
+    message.key = object.key ?? "";
+    message.value = object.value ?? 0;
+    return message;
+  },
+};
+
+function createBaseApplyWindow(): ApplyWindow {
+  return { startMinute: 0, endMinute: 0 };
+}
+
+export const ApplyWindow = {
+  encode(message: ApplyWindow, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.startMinute !== 0) {
+      writer.uint32(8).uint32(message.startMinute);
+    }
+    if (message.endMinute !== 0) {
+      writer.uint32(16).uint32(message.endMinute);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ApplyWindow {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseApplyWindow();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.startMinute = reader.uint32();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.endMinute = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ApplyWindow {
+    return {
+      startMinute: isSet(object.startMinute) ? Number(object.startMinute) : 0,
+      endMinute: isSet(object.endMinute) ? Number(object.endMinute) : 0,
+    };
+  },
+
+  toJSON(message: ApplyWindow): unknown {
+    const obj: any = {};
+    if (message.startMinute !== 0) {
+      obj.startMinute = Math.round(message.startMinute);
+    }
+    if (message.endMinute !== 0) {
+      obj.endMinute = Math.round(message.endMinute);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ApplyWindow>, I>>(base?: I): ApplyWindow {
+    return ApplyWindow.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ApplyWindow>, I>>(object: I): ApplyWindow {
+    const message = createBaseApplyWindow();
+    message.startMinute = object.startMinute ?? 0;
+    message.endMinute = object.endMinute ?? 0;
+    return message;
+  },
+};
+
 function createBaseSyncFlowOptions(): SyncFlowOptions {
   return { batchSize: 0, relationMessageMapping: {} };
 }
@@ -3181,7 +3986,7 @@ export const CreateRawTableOutput = {
 };
 
 function createBaseTableSchema(): TableSchema {
-  return { tableIdentifier: "", columns: {}, primaryKeyColumns: [], isReplicaIdentityFull: false };
+  return { tableIdentifier: "", columns: {}, primaryKeyColumns: [], isReplicaIdentityFull: false, columnTypes: {} };
 }
 
 export const TableSchema = {
@@ -3198,6 +4003,9 @@ export const TableSchema = {
     if (message.isReplicaIdentityFull === true) {
       writer.uint32(32).bool(message.isReplicaIdentityFull);
     }
+    Object.entries(message.columnTypes).forEach(([key, value]) => {
+      TableSchema_ColumnTypesEntry.encode({ key: key as any, value }, writer.uint32(42).fork()).ldelim();
+    });
     return writer;
   },
 
@@ -3239,6 +4047,16 @@ export const TableSchema = {
 
           message.isReplicaIdentityFull = reader.bool();
           continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          const entry5 = TableSchema_ColumnTypesEntry.decode(reader, reader.uint32());
+          if (entry5.value !== undefined) {
+            message.columnTypes[entry5.key] = entry5.value;
+          }
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -3261,6 +4079,12 @@ export const TableSchema = {
         ? object.primaryKeyColumns.map((e: any) => String(e))
         : [],
       isReplicaIdentityFull: isSet(object.isReplicaIdentityFull) ? Boolean(object.isReplicaIdentityFull) : false,
+      columnTypes: isObject(object.columnTypes)
+        ? Object.entries(object.columnTypes).reduce<{ [key: string]: PostgresType }>((acc, [key, value]) => {
+          acc[key] = PostgresType.fromJSON(value);
+          return acc;
+        }, {})
+        : {},
     };
   },
 
@@ -3284,6 +4108,15 @@ export const TableSchema = {
     if (message.isReplicaIdentityFull === true) {
       obj.isReplicaIdentityFull = message.isReplicaIdentityFull;
     }
+    if (message.columnTypes) {
+      const entries = Object.entries(message.columnTypes);
+      if (entries.length > 0) {
+        obj.columnTypes = {};
+        entries.forEach(([k, v]) => {
+          obj.columnTypes[k] = PostgresType.toJSON(v);
+        });
+      }
+    }
     return obj;
   },
 
@@ -3301,6 +4134,15 @@ export const TableSchema = {
     }, {});
     message.primaryKeyColumns = object.primaryKeyColumns?.map((e) => e) || [];
     message.isReplicaIdentityFull = object.isReplicaIdentityFull ?? false;
+    message.columnTypes = Object.entries(object.columnTypes ?? {}).reduce<{ [key: string]: PostgresType }>(
+      (acc, [key, value]) => {
+        if (value !== undefined) {
+          acc[key] = PostgresType.fromPartial(value);
+        }
+        return acc;
+      },
+      {},
+    );
     return message;
   },
 };
@@ -3376,6 +4218,244 @@ export const TableSchema_ColumnsEntry = {
   },
 };
 
+function createBaseTableSchema_ColumnTypesEntry(): TableSchema_ColumnTypesEntry {
+  return { key: "", value: undefined };
+}
+
+export const TableSchema_ColumnTypesEntry = {
+  encode(message: TableSchema_ColumnTypesEntry, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.key !== "") {
+      writer.uint32(10).string(message.key);
+    }
+    if (message.value !== undefined) {
+      PostgresType.encode(message.value, writer.uint32(18).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): TableSchema_ColumnTypesEntry {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseTableSchema_ColumnTypesEntry();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.key = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.value = PostgresType.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): TableSchema_ColumnTypesEntry {
+    return {
+      key: isSet(object.key) ? String(object.key) : "",
+      value: isSet(object.value) ? PostgresType.fromJSON(object.value) : undefined,
+    };
+  },
+
+  toJSON(message: TableSchema_ColumnTypesEntry): unknown {
+    const obj: any = {};
+    if (message.key !== "") {
+      obj.key = message.key;
+    }
+    if (message.value !== undefined) {
+      obj.value = PostgresType.toJSON(message.value);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<TableSchema_ColumnTypesEntry>, I>>(base?: I): TableSchema_ColumnTypesEntry {
+    return TableSchema_ColumnTypesEntry.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<TableSchema_ColumnTypesEntry>, I>>(object: I): TableSchema_ColumnTypesEntry {
+    const message = createBaseTableSchema_ColumnTypesEntry();
+    message.key = object.key ?? "";
+    message.value = (object.value !== undefined && object.value !== null)
+      ? PostgresType.fromPartial(object.value)
+      : undefined;
+    return message;
+  },
+};
+
+function createBasePostgresType(): PostgresType {
+  return { name: "", enumLabels: [], attributes: [] };
+}
+
+export const PostgresType = {
+  encode(message: PostgresType, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.name !== "") {
+      writer.uint32(10).string(message.name);
+    }
+    for (const v of message.enumLabels) {
+      writer.uint32(18).string(v!);
+    }
+    for (const v of message.attributes) {
+      PostgresTypeAttribute.encode(v!, writer.uint32(26).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): PostgresType {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePostgresType();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.enumLabels.push(reader.string());
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.attributes.push(PostgresTypeAttribute.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PostgresType {
+    return {
+      name: isSet(object.name) ? String(object.name) : "",
+      enumLabels: Array.isArray(object?.enumLabels) ? object.enumLabels.map((e: any) => String(e)) : [],
+      attributes: Array.isArray(object?.attributes)
+        ? object.attributes.map((e: any) => PostgresTypeAttribute.fromJSON(e))
+        : [],
+    };
+  },
+
+  toJSON(message: PostgresType): unknown {
+    const obj: any = {};
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.enumLabels?.length) {
+      obj.enumLabels = message.enumLabels;
+    }
+    if (message.attributes?.length) {
+      obj.attributes = message.attributes.map((e) => PostgresTypeAttribute.toJSON(e));
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<PostgresType>, I>>(base?: I): PostgresType {
+    return PostgresType.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<PostgresType>, I>>(object: I): PostgresType {
+    const message = createBasePostgresType();
+    message.name = object.name ?? "";
+    message.enumLabels = object.enumLabels?.map((e) => e) || [];
+    message.attributes = object.attributes?.map((e) => PostgresTypeAttribute.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBasePostgresTypeAttribute(): PostgresTypeAttribute {
+  return { name: "", type: "" };
+}
+
+export const PostgresTypeAttribute = {
+  encode(message: PostgresTypeAttribute, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.name !== "") {
+      writer.uint32(10).string(message.name);
+    }
+    if (message.type !== "") {
+      writer.uint32(18).string(message.type);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): PostgresTypeAttribute {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePostgresTypeAttribute();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.type = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PostgresTypeAttribute {
+    return { name: isSet(object.name) ? String(object.name) : "", type: isSet(object.type) ? String(object.type) : "" };
+  },
+
+  toJSON(message: PostgresTypeAttribute): unknown {
+    const obj: any = {};
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.type !== "") {
+      obj.type = message.type;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<PostgresTypeAttribute>, I>>(base?: I): PostgresTypeAttribute {
+    return PostgresTypeAttribute.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<PostgresTypeAttribute>, I>>(object: I): PostgresTypeAttribute {
+    const message = createBasePostgresTypeAttribute();
+    message.name = object.name ?? "";
+    message.type = object.type ?? "";
+    return message;
+  },
+};
+
 function createBaseGetTableSchemaBatchInput(): GetTableSchemaBatchInput {
   return { peerConnectionConfig: undefined, tableIdentifiers: [] };
 }
@@ -3712,7 +4792,7 @@ export const SetupNormalizedTableInput = {
 };
 
 function createBaseSetupNormalizedTableBatchInput(): SetupNormalizedTableBatchInput {
-  return { peerConnectionConfig: undefined, tableNameSchemaMapping: {} };
+  return { peerConnectionConfig: undefined, tableNameSchemaMapping: {}, softDeleteColName: "", syncedAtColName: "" };
 }
 
 export const SetupNormalizedTableBatchInput = {
@@ -3726,6 +4806,12 @@ export const SetupNormalizedTableBatchInput = {
         writer.uint32(18).fork(),
       ).ldelim();
     });
+    if (message.softDeleteColName !== "") {
+      writer.uint32(26).string(message.softDeleteColName);
+    }
+    if (message.syncedAtColName !== "") {
+      writer.uint32(34).string(message.syncedAtColName);
+    }
     return writer;
   },
 
@@ -3753,6 +4839,20 @@ export const SetupNormalizedTableBatchInput = {
             message.tableNameSchemaMapping[entry2.key] = entry2.value;
           }
           continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.softDeleteColName = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.syncedAtColName = reader.string();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -3771,6 +4871,8 @@ export const SetupNormalizedTableBatchInput = {
           return acc;
         }, {})
         : {},
+      softDeleteColName: isSet(object.softDeleteColName) ? String(object.softDeleteColName) : "",
+      syncedAtColName: isSet(object.syncedAtColName) ? String(object.syncedAtColName) : "",
     };
   },
 
@@ -3788,6 +4890,12 @@ export const SetupNormalizedTableBatchInput = {
         });
       }
     }
+    if (message.softDeleteColName !== "") {
+      obj.softDeleteColName = message.softDeleteColName;
+    }
+    if (message.syncedAtColName !== "") {
+      obj.syncedAtColName = message.syncedAtColName;
+    }
     return obj;
   },
 
@@ -3809,6 +4917,8 @@ export const SetupNormalizedTableBatchInput = {
       }
       return acc;
     }, {});
+    message.softDeleteColName = object.softDeleteColName ?? "";
+    message.syncedAtColName = object.syncedAtColName ?? "";
     return message;
   },
 };
@@ -4622,6 +5732,11 @@ function createBaseQRepConfig(): QRepConfig {
     stagingPath: "",
     numRowsPerPartition: 0,
     setupWatermarkTableOnDestination: false,
+    stagingIntegration: "",
+    refreshStatisticsThreshold: 0,
+    softDeleteColName: "",
+    syncedAtColName: "",
+    cronSchedule: "",
   };
 }
 
@@ -4678,6 +5793,21 @@ export const QRepConfig = {
     if (message.setupWatermarkTableOnDestination === true) {
       writer.uint32(136).bool(message.setupWatermarkTableOnDestination);
     }
+    if (message.stagingIntegration !== "") {
+      writer.uint32(146).string(message.stagingIntegration);
+    }
+    if (message.refreshStatisticsThreshold !== 0) {
+      writer.uint32(152).uint32(message.refreshStatisticsThreshold);
+    }
+    if (message.softDeleteColName !== "") {
+      writer.uint32(162).string(message.softDeleteColName);
+    }
+    if (message.syncedAtColName !== "") {
+      writer.uint32(170).string(message.syncedAtColName);
+    }
+    if (message.cronSchedule !== "") {
+      writer.uint32(178).string(message.cronSchedule);
+    }
     return writer;
   },
 
@@ -4807,6 +5937,41 @@ export const QRepConfig = {
 
           message.setupWatermarkTableOnDestination = reader.bool();
           continue;
+        case 18:
+          if (tag !== 146) {
+            break;
+          }
+
+          message.stagingIntegration = reader.string();
+          continue;
+        case 19:
+          if (tag !== 152) {
+            break;
+          }
+
+          message.refreshStatisticsThreshold = reader.uint32();
+          continue;
+        case 20:
+          if (tag !== 162) {
+            break;
+          }
+
+          message.softDeleteColName = reader.string();
+          continue;
+        case 21:
+          if (tag !== 170) {
+            break;
+          }
+
+          message.syncedAtColName = reader.string();
+          continue;
+        case 22:
+          if (tag !== 178) {
+            break;
+          }
+
+          message.cronSchedule = reader.string();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -4839,6 +6004,13 @@ export const QRepConfig = {
       setupWatermarkTableOnDestination: isSet(object.setupWatermarkTableOnDestination)
         ? Boolean(object.setupWatermarkTableOnDestination)
         : false,
+      stagingIntegration: isSet(object.stagingIntegration) ? String(object.stagingIntegration) : "",
+      refreshStatisticsThreshold: isSet(object.refreshStatisticsThreshold)
+        ? Number(object.refreshStatisticsThreshold)
+        : 0,
+      softDeleteColName: isSet(object.softDeleteColName) ? String(object.softDeleteColName) : "",
+      syncedAtColName: isSet(object.syncedAtColName) ? String(object.syncedAtColName) : "",
+      cronSchedule: isSet(object.cronSchedule) ? String(object.cronSchedule) : "",
     };
   },
 
@@ -4895,6 +6067,21 @@ export const QRepConfig = {
     if (message.setupWatermarkTableOnDestination === true) {
       obj.setupWatermarkTableOnDestination = message.setupWatermarkTableOnDestination;
     }
+    if (message.stagingIntegration !== "") {
+      obj.stagingIntegration = message.stagingIntegration;
+    }
+    if (message.refreshStatisticsThreshold !== 0) {
+      obj.refreshStatisticsThreshold = Math.round(message.refreshStatisticsThreshold);
+    }
+    if (message.softDeleteColName !== "") {
+      obj.softDeleteColName = message.softDeleteColName;
+    }
+    if (message.syncedAtColName !== "") {
+      obj.syncedAtColName = message.syncedAtColName;
+    }
+    if (message.cronSchedule !== "") {
+      obj.cronSchedule = message.cronSchedule;
+    }
     return obj;
   },
 
@@ -4926,6 +6113,11 @@ export const QRepConfig = {
     message.stagingPath = object.stagingPath ?? "";
     message.numRowsPerPartition = object.numRowsPerPartition ?? 0;
     message.setupWatermarkTableOnDestination = object.setupWatermarkTableOnDestination ?? false;
+    message.stagingIntegration = object.stagingIntegration ?? "";
+    message.refreshStatisticsThreshold = object.refreshStatisticsThreshold ?? 0;
+    message.softDeleteColName = object.softDeleteColName ?? "";
+    message.syncedAtColName = object.syncedAtColName ?? "";
+    message.cronSchedule = object.cronSchedule ?? "";
     return message;
   },
 };
@@ -4,6 +4,80 @@ import _m0 from "protobufjs/minimal";
 
 export const protobufPackage = "peerdb_peers";
 
+export enum MoneyMapping {
+  /** MONEY_MAPPING_STRING - the value as rendered by the server, e.g. "$1,234.50". */
+  MONEY_MAPPING_STRING = 0,
+  MONEY_MAPPING_NUMERIC = 1,
+  /** MONEY_MAPPING_NUMERIC_WITH_CURRENCY - a numeric column and a <column>_currency string column with the symbol. */
+  MONEY_MAPPING_NUMERIC_WITH_CURRENCY = 2,
+  UNRECOGNIZED = -1,
+}
+
+export function moneyMappingFromJSON(object: any): MoneyMapping {
+  switch (object) {
+    case 0:
+    case "MONEY_MAPPING_STRING":
+      return MoneyMapping.MONEY_MAPPING_STRING;
+    case 1:
+    case "MONEY_MAPPING_NUMERIC":
+      return MoneyMapping.MONEY_MAPPING_NUMERIC;
+    case 2:
+    case "MONEY_MAPPING_NUMERIC_WITH_CURRENCY":
+      return MoneyMapping.MONEY_MAPPING_NUMERIC_WITH_CURRENCY;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return MoneyMapping.UNRECOGNIZED;
+  }
+}
+
+export function moneyMappingToJSON(object: MoneyMapping): string {
+  switch (object) {
+    case MoneyMapping.MONEY_MAPPING_STRING:
+      return "MONEY_MAPPING_STRING";
+    case MoneyMapping.MONEY_MAPPING_NUMERIC:
+      return "MONEY_MAPPING_NUMERIC";
+    case MoneyMapping.MONEY_MAPPING_NUMERIC_WITH_CURRENCY:
+      return "MONEY_MAPPING_NUMERIC_WITH_CURRENCY";
+    case MoneyMapping.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export enum FileFormat {
+  CSV = 0,
+  PARQUET = 1,
+  UNRECOGNIZED = -1,
+}
+
+export function fileFormatFromJSON(object: any): FileFormat {
+  switch (object) {
+    case 0:
+    case "CSV":
+      return FileFormat.CSV;
+    case 1:
+    case "PARQUET":
+      return FileFormat.PARQUET;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return FileFormat.UNRECOGNIZED;
+  }
+}
+
+export function fileFormatToJSON(object: FileFormat): string {
+  switch (object) {
+    case FileFormat.CSV:
+      return "CSV";
+    case FileFormat.PARQUET:
+      return "PARQUET";
+    case FileFormat.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 export enum DBType {
   BIGQUERY = 0,
   SNOWFLAKE = 1,
@@ -13,6 +87,10 @@ export enum DBType {
   S3 = 5,
   SQLSERVER = 6,
   EVENTHUB_GROUP = 7,
+  PINECONE = 8,
+  WEAVIATE = 9,
+  FILE = 10,
+  WEBHOOK = 11,
   UNRECOGNIZED = -1,
 }
 
@@ -42,6 +120,18 @@ export function dBTypeFromJSON(object: any): DBType {
     case 7:
     case "EVENTHUB_GROUP":
       return DBType.EVENTHUB_GROUP;
+    case 8:
+    case "PINECONE":
+      return DBType.PINECONE;
+    case 9:
+    case "WEAVIATE":
+      return DBType.WEAVIATE;
+    case 10:
+    case "FILE":
+      return DBType.FILE;
+    case 11:
+    case "WEBHOOK":
+      return DBType.WEBHOOK;
     case -1:
     case "UNRECOGNIZED":
     default:
@@ -67,6 +157,14 @@ export function dBTypeToJSON(object: DBType): string {
       return "SQLSERVER";
     case DBType.EVENTHUB_GROUP:
       return "EVENTHUB_GROUP";
+    case DBType.PINECONE:
+      return "PINECONE";
+    case DBType.WEAVIATE:
+      return "WEAVIATE";
+    case DBType.FILE:
+      return "FILE";
+    case DBType.WEBHOOK:
+      return "WEBHOOK";
     case DBType.UNRECOGNIZED:
     default:
       return "UNRECOGNIZED";
@@ -82,7 +180,14 @@ export interface SnowflakeConfig {
   role: string;
   queryTimeout: number;
   s3Integration: string;
-  password?: string | undefined;
+  password?:
+    | string
+    | undefined;
+  /**
+   * the s3:// url that the rows copied into the peer through nexus are staged
+   * under before they are loaded, read through s3_integration when it is set.
+   */
+  stagingPath?: string | undefined;
 }
 
 export interface BigqueryConfig {
@@ -115,6 +220,8 @@ export interface PostgresConfig {
   database: string;
   /** this is used only in query replication mode right now. */
   transactionSnapshot: string;
+  /** how money columns are replicated when this peer is the source. */
+  moneyMapping: MoneyMapping;
 }
 
 export interface EventHubConfig {
@@ -162,6 +269,67 @@ export interface SqlServerConfig {
   database: string;
 }
 
+export interface PineconeConfig {
+  apiKey: string;
+  environment: string;
+  projectId: string;
+  indexName: string;
+  /** vectors are upserted into the default namespace if this is empty. */
+  namespace: string;
+  /** pgvector column holding the embedding of each row. */
+  vectorColumn: string;
+  /** columns stored as vector metadata, all other columns if this is empty. */
+  metadataColumns: string[];
+}
+
+export interface WeaviateConfig {
+  /** e.g. https://my-cluster.weaviate.network */
+  url: string;
+  apiKey?:
+    | string
+    | undefined;
+  /** class that mirrored rows are written to as objects. */
+  className: string;
+  /** column holding the vector of each row, weaviate vectorizes the object if this is empty. */
+  vectorColumn: string;
+}
+
+export interface FileConfig {
+  /**
+   * local directory that partitions and batches are written to, or scanned
+   * for files when the peer is used as a source.
+   */
+  path: string;
+  format: FileFormat;
+}
+
+export interface WebhookConfig {
+  /** endpoint that change batches are POSTed to as JSON. */
+  url: string;
+  /**
+   * when set, each request carries an X-PeerDB-Signature header with the
+   * hex encoded HMAC-SHA256 of the body.
+   */
+  signingSecret?:
+    | string
+    | undefined;
+  /** additional headers sent with every request, e.g. for authorization. */
+  headers: { [key: string]: string };
+  /** failed deliveries are retried with exponential backoff, 5 if unset. */
+  maxRetries: number;
+  timeoutSeconds: number;
+  /**
+   * number of batches that can be awaiting delivery before the mirror stops
+   * pulling changes from the source.
+   */
+  maxInflightBatches: number;
+}
+
+export interface WebhookConfig_HeadersEntry {
+  key: string;
+  value: string;
+}
+
 export interface Peer {
   name: string;
   type: DBType;
@@ -173,6 +341,10 @@ export interface Peer {
   s3Config?: S3Config | undefined;
   sqlserverConfig?: SqlServerConfig | undefined;
   eventhubGroupConfig?: EventHubGroupConfig | undefined;
+  pineconeConfig?: PineconeConfig | undefined;
+  weaviateConfig?: WeaviateConfig | undefined;
+  fileConfig?: FileConfig | undefined;
+  webhookConfig?: WebhookConfig | undefined;
 }
 
 function createBaseSnowflakeConfig(): SnowflakeConfig {
@@ -186,6 +358,7 @@ function createBaseSnowflakeConfig(): SnowflakeConfig {
     queryTimeout: 0,
     s3Integration: "",
     password: undefined,
+    stagingPath: undefined,
   };
 }
 
@@ -218,6 +391,9 @@ export const SnowflakeConfig = {
     if (message.password !== undefined) {
       writer.uint32(82).string(message.password);
     }
+    if (message.stagingPath !== undefined) {
+      writer.uint32(90).string(message.stagingPath);
+    }
     return writer;
   },
 
@@ -291,6 +467,13 @@ export const SnowflakeConfig = {
 
           message.password = reader.string();
           continue;
+        case 11:
+          if (tag !== 90) {
+            break;
+          }
+
+          message.stagingPath = reader.string();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -311,6 +494,7 @@ export const SnowflakeConfig = {
       queryTimeout: isSet(object.queryTimeout) ? Number(object.queryTimeout) : 0,
       s3Integration: isSet(object.s3Integration) ? String(object.s3Integration) : "",
       password: isSet(object.password) ? String(object.password) : undefined,
+      stagingPath: isSet(object.stagingPath) ? String(object.stagingPath) : undefined,
     };
   },
 
@@ -343,6 +527,9 @@ export const SnowflakeConfig = {
     if (message.password !== undefined) {
       obj.password = message.password;
     }
+    if (message.stagingPath !== undefined) {
+      obj.stagingPath = message.stagingPath;
+    }
     return obj;
   },
 
@@ -360,6 +547,7 @@ export const SnowflakeConfig = {
     message.queryTimeout = object.queryTimeout ?? 0;
     message.s3Integration = object.s3Integration ?? "";
     message.password = object.password ?? undefined;
+    message.stagingPath = object.stagingPath ?? undefined;
     return message;
   },
 };
@@ -705,7 +893,7 @@ export const MongoConfig = {
 };
 
 function createBasePostgresConfig(): PostgresConfig {
-  return { host: "", port: 0, user: "", password: "", database: "", transactionSnapshot: "" };
+  return { host: "", port: 0, user: "", password: "", database: "", transactionSnapshot: "", moneyMapping: 0 };
 }
 
 export const PostgresConfig = {
@@ -728,6 +916,9 @@ export const PostgresConfig = {
     if (message.transactionSnapshot !== "") {
       writer.uint32(50).string(message.transactionSnapshot);
     }
+    if (message.moneyMapping !== 0) {
+      writer.uint32(56).int32(message.moneyMapping);
+    }
     return writer;
   },
 
@@ -780,6 +971,13 @@ export const PostgresConfig = {
 
           message.transactionSnapshot = reader.string();
           continue;
+        case 7:
+          if (tag !== 56) {
+            break;
+          }
+
+          message.moneyMapping = reader.int32() as any;
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -797,6 +995,7 @@ export const PostgresConfig = {
       password: isSet(object.password) ? String(object.password) : "",
       database: isSet(object.database) ? String(object.database) : "",
       transactionSnapshot: isSet(object.transactionSnapshot) ? String(object.transactionSnapshot) : "",
+      moneyMapping: isSet(object.moneyMapping) ? moneyMappingFromJSON(object.moneyMapping) : 0,
     };
   },
 
@@ -820,6 +1019,9 @@ export const PostgresConfig = {
     if (message.transactionSnapshot !== "") {
       obj.transactionSnapshot = message.transactionSnapshot;
     }
+    if (message.moneyMapping !== 0) {
+      obj.moneyMapping = moneyMappingToJSON(message.moneyMapping);
+    }
     return obj;
   },
 
@@ -834,6 +1036,7 @@ export const PostgresConfig = {
     message.password = object.password ?? "";
     message.database = object.database ?? "";
     message.transactionSnapshot = object.transactionSnapshot ?? "";
+    message.moneyMapping = object.moneyMapping ?? 0;
     return message;
   },
 };
@@ -1468,6 +1671,565 @@ export const SqlServerConfig = {
   },
 };
 
+function createBasePineconeConfig(): PineconeConfig {
+  return {
+    apiKey: "",
+    environment: "",
+    projectId: "",
+    indexName: "",
+    namespace: "",
+    vectorColumn: "",
+    metadataColumns: [],
+  };
+}
+
+export const PineconeConfig = {
+  encode(message: PineconeConfig, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.apiKey !== "") {
+      writer.uint32(10).string(message.apiKey);
+    }
+    if (message.environment !== "") {
+      writer.uint32(18).string(message.environment);
+    }
+    if (message.projectId !== "") {
+      writer.uint32(26).string(message.projectId);
+    }
+    if (message.indexName !== "") {
+      writer.uint32(34).string(message.indexName);
+    }
+    if (message.namespace !== "") {
+      writer.uint32(42).string(message.namespace);
+    }
+    if (message.vectorColumn !== "") {
+      writer.uint32(50).string(message.vectorColumn);
+    }
+    for (const v of message.metadataColumns) {
+      writer.uint32(58).string(v!);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): PineconeConfig {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePineconeConfig();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.apiKey = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.environment = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.projectId = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.indexName = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.namespace = reader.string();
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.vectorColumn = reader.string();
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.metadataColumns.push(reader.string());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PineconeConfig {
+    return {
+      apiKey: isSet(object.apiKey) ? String(object.apiKey) : "",
+      environment: isSet(object.environment) ? String(object.environment) : "",
+      projectId: isSet(object.projectId) ? String(object.projectId) : "",
+      indexName: isSet(object.indexName) ? String(object.indexName) : "",
+      namespace: isSet(object.namespace) ? String(object.namespace) : "",
+      vectorColumn: isSet(object.vectorColumn) ? String(object.vectorColumn) : "",
+      metadataColumns: Array.isArray(object?.metadataColumns) ? object.metadataColumns.map((e: any) => String(e)) : [],
+    };
+  },
+
+  toJSON(message: PineconeConfig): unknown {
+    const obj: any = {};
+    if (message.apiKey !== "") {
+      obj.apiKey = message.apiKey;
+    }
+    if (message.environment !== "") {
+      obj.environment = message.environment;
+    }
+    if (message.projectId !== "") {
+      obj.projectId = message.projectId;
+    }
+    if (message.indexName !== "") {
+      obj.indexName = message.indexName;
+    }
+    if (message.namespace !== "") {
+      obj.namespace = message.namespace;
+    }
+    if (message.vectorColumn !== "") {
+      obj.vectorColumn = message.vectorColumn;
+    }
+    if (message.metadataColumns?.length) {
+      obj.metadataColumns = message.metadataColumns;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<PineconeConfig>, I>>(base?: I): PineconeConfig {
+    return PineconeConfig.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<PineconeConfig>, I>>(object: I): PineconeConfig {
+    const message = createBasePineconeConfig();
+    message.apiKey = object.apiKey ?? "";
+    message.environment = object.environment ?? "";
+    message.projectId = object.projectId ?? "";
+    message.indexName = object.indexName ?? "";
+    message.namespace = object.namespace ?? "";
+    message.vectorColumn = object.vectorColumn ?? "";
+    message.metadataColumns = object.metadataColumns?.map((e) => e) || [];
+    return message;
+  },
+};
+
+function createBaseWeaviateConfig(): WeaviateConfig {
+  return { url: "", apiKey: undefined, className: "", vectorColumn: "" };
+}
+
+export const WeaviateConfig = {
+  encode(message: WeaviateConfig, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.url !== "") {
+      writer.uint32(10).string(message.url);
+    }
+    if (message.apiKey !== undefined) {
+      writer.uint32(18).string(message.apiKey);
+    }
+    if (message.className !== "") {
+      writer.uint32(26).string(message.className);
+    }
+    if (message.vectorColumn !== "") {
+      writer.uint32(34).string(message.vectorColumn);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): WeaviateConfig {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseWeaviateConfig();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.url = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.apiKey = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.className = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.vectorColumn = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): WeaviateConfig {
+    return {
+      url: isSet(object.url) ? String(object.url) : "",
+      apiKey: isSet(object.apiKey) ? String(object.apiKey) : undefined,
+      className: isSet(object.className) ? String(object.className) : "",
+      vectorColumn: isSet(object.vectorColumn) ? String(object.vectorColumn) : "",
+    };
+  },
+
+  toJSON(message: WeaviateConfig): unknown {
+    const obj: any = {};
+    if (message.url !== "") {
+      obj.url = message.url;
+    }
+    if (message.apiKey !== undefined) {
+      obj.apiKey = message.apiKey;
+    }
+    if (message.className !== "") {
+      obj.className = message.className;
+    }
+    if (message.vectorColumn !== "") {
+      obj.vectorColumn = message.vectorColumn;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<WeaviateConfig>, I>>(base?: I): WeaviateConfig {
+    return WeaviateConfig.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<WeaviateConfig>, I>>(object: I): WeaviateConfig {
+    const message = createBaseWeaviateConfig();
+    message.url = object.url ?? "";
+    message.apiKey = object.apiKey ?? undefined;
+    message.className = object.className ?? "";
+    message.vectorColumn = object.vectorColumn ?? "";
+    return message;
+  },
+};
+
+function createBaseFileConfig(): FileConfig {
+  return { path: "", format: 0 };
+}
+
+export const FileConfig = {
+  encode(message: FileConfig, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.path !== "") {
+      writer.uint32(10).string(message.path);
+    }
+    if (message.format !== 0) {
+      writer.uint32(16).int32(message.format);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FileConfig {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFileConfig();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.path = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.format = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FileConfig {
+    return {
+      path: isSet(object.path) ? String(object.path) : "",
+      format: isSet(object.format) ? fileFormatFromJSON(object.format) : 0,
+    };
+  },
+
+  toJSON(message: FileConfig): unknown {
+    const obj: any = {};
+    if (message.path !== "") {
+      obj.path = message.path;
+    }
+    if (message.format !== 0) {
+      obj.format = fileFormatToJSON(message.format);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FileConfig>, I>>(base?: I): FileConfig {
+    return FileConfig.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FileConfig>, I>>(object: I): FileConfig {
+    const message = createBaseFileConfig();
+    message.path = object.path ?? "";
+    message.format = object.format ?? 0;
+    return message;
+  },
+};
+
+function createBaseWebhookConfig(): WebhookConfig {
+  return { url: "", signingSecret: undefined, headers: {}, maxRetries: 0, timeoutSeconds: 0, maxInflightBatches: 0 };
+}
+
+export const WebhookConfig = {
+  encode(message: WebhookConfig, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.url !== "") {
+      writer.uint32(10).string(message.url);
+    }
+    if (message.signingSecret !== undefined) {
+      writer.uint32(18).string(message.signingSecret);
+    }
+    Object.entries(message.headers).forEach(([key, value]) => {
+      WebhookConfig_HeadersEntry.encode({ key: key as any, value }, writer.uint32(26).fork()).ldelim();
+    });
+    if (message.maxRetries !== 0) {
+      writer.uint32(32).uint32(message.maxRetries);
+    }
+    if (message.timeoutSeconds !== 0) {
+      writer.uint32(40).uint32(message.timeoutSeconds);
+    }
+    if (message.maxInflightBatches !== 0) {
+      writer.uint32(48).uint32(message.maxInflightBatches);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): WebhookConfig {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseWebhookConfig();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.url = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.signingSecret = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          const entry3 = WebhookConfig_HeadersEntry.decode(reader, reader.uint32());
+          if (entry3.value !== undefined) {
+            message.headers[entry3.key] = entry3.value;
+          }
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.maxRetries = reader.uint32();
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.timeoutSeconds = reader.uint32();
+          continue;
+        case 6:
+          if (tag !== 48) {
+            break;
+          }
+
+          message.maxInflightBatches = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): WebhookConfig {
+    return {
+      url: isSet(object.url) ? String(object.url) : "",
+      signingSecret: isSet(object.signingSecret) ? String(object.signingSecret) : undefined,
+      headers: isObject(object.headers)
+        ? Object.entries(object.headers).reduce<{ [key: string]: string }>((acc, [key, value]) => {
+          acc[key] = String(value);
+          return acc;
+        }, {})
+        : {},
+      maxRetries: isSet(object.maxRetries) ? Number(object.maxRetries) : 0,
+      timeoutSeconds: isSet(object.timeoutSeconds) ? Number(object.timeoutSeconds) : 0,
+      maxInflightBatches: isSet(object.maxInflightBatches) ? Number(object.maxInflightBatches) : 0,
+    };
+  },
+
+  toJSON(message: WebhookConfig): unknown {
+    const obj: any = {};
+    if (message.url !== "") {
+      obj.url = message.url;
+    }
+    if (message.signingSecret !== undefined) {
+      obj.signingSecret = message.signingSecret;
+    }
+    if (message.headers) {
+      const entries = Object.entries(message.headers);
+      if (entries.length > 0) {
+        obj.headers = {};
+        entries.forEach(([k, v]) => {
+          obj.headers[k] = v;
+        });
+      }
+    }
+    if (message.maxRetries !== 0) {
+      obj.maxRetries = Math.round(message.maxRetries);
+    }
+    if (message.timeoutSeconds !== 0) {
+      obj.timeoutSeconds = Math.round(message.timeoutSeconds);
+    }
+    if (message.maxInflightBatches !== 0) {
+      obj.maxInflightBatches = Math.round(message.maxInflightBatches);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<WebhookConfig>, I>>(base?: I): WebhookConfig {
+    return WebhookConfig.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<WebhookConfig>, I>>(object: I): WebhookConfig {
+    const message = createBaseWebhookConfig();
+    message.url = object.url ?? "";
+    message.signingSecret = object.signingSecret ?? undefined;
+    message.headers = Object.entries(object.headers ?? {}).reduce<{ [key: string]: string }>((acc, [key, value]) => {
+      if (value !== undefined) {
+        acc[key] = String(value);
+      }
+      return acc;
+    }, {});
+    message.maxRetries = object.maxRetries ?? 0;
+    message.timeoutSeconds = object.timeoutSeconds ?? 0;
+    message.maxInflightBatches = object.maxInflightBatches ?? 0;
+    return message;
+  },
+};
+
+function createBaseWebhookConfig_HeadersEntry(): WebhookConfig_HeadersEntry {
+  return { key: "", value: "" };
+}
+
+export const WebhookConfig_HeadersEntry = {
+  encode(message: WebhookConfig_HeadersEntry, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.key !== "") {
+      writer.uint32(10).string(message.key);
+    }
+    if (message.value !== "") {
+      writer.uint32(18).string(message.value);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): WebhookConfig_HeadersEntry {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseWebhookConfig_HeadersEntry();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.key = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.value = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): WebhookConfig_HeadersEntry {
+    return { key: isSet(object.key) ? String(object.key) : "", value: isSet(object.value) ? String(object.value) : "" };
+  },
+
+  toJSON(message: WebhookConfig_HeadersEntry): unknown {
+    const obj: any = {};
+    if (message.key !== "") {
+      obj.key = message.key;
+    }
+    if (message.value !== "") {
+      obj.value = message.value;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<WebhookConfig_HeadersEntry>, I>>(base?: I): WebhookConfig_HeadersEntry {
+    return WebhookConfig_HeadersEntry.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<WebhookConfig_HeadersEntry>, I>>(object: I): WebhookConfig_HeadersEntry {
+    const message = createBaseWebhookConfig_HeadersEntry();
+    message.key = object.key ?? "";
+    message.value = object.value ?? "";
+    return message;
+  },
+};
+
 function createBasePeer(): Peer {
   return {
     name: "",
@@ -1480,6 +2242,10 @@ function createBasePeer(): Peer {
     s3Config: undefined,
     sqlserverConfig: undefined,
     eventhubGroupConfig: undefined,
+    pineconeConfig: undefined,
+    weaviateConfig: undefined,
+    fileConfig: undefined,
+    webhookConfig: undefined,
   };
 }
 
@@ -1515,6 +2281,18 @@ export const Peer = {
     if (message.eventhubGroupConfig !== undefined) {
       EventHubGroupConfig.encode(message.eventhubGroupConfig, writer.uint32(82).fork()).ldelim();
     }
+    if (message.pineconeConfig !== undefined) {
+      PineconeConfig.encode(message.pineconeConfig, writer.uint32(90).fork()).ldelim();
+    }
+    if (message.weaviateConfig !== undefined) {
+      WeaviateConfig.encode(message.weaviateConfig, writer.uint32(98).fork()).ldelim();
+    }
+    if (message.fileConfig !== undefined) {
+      FileConfig.encode(message.fileConfig, writer.uint32(106).fork()).ldelim();
+    }
+    if (message.webhookConfig !== undefined) {
+      WebhookConfig.encode(message.webhookConfig, writer.uint32(114).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -1595,6 +2373,34 @@ export const Peer = {
 
           message.eventhubGroupConfig = EventHubGroupConfig.decode(reader, reader.uint32());
           continue;
+        case 11:
+          if (tag !== 90) {
+            break;
+          }
+
+          message.pineconeConfig = PineconeConfig.decode(reader, reader.uint32());
+          continue;
+        case 12:
+          if (tag !== 98) {
+            break;
+          }
+
+          message.weaviateConfig = WeaviateConfig.decode(reader, reader.uint32());
+          continue;
+        case 13:
+          if (tag !== 106) {
+            break;
+          }
+
+          message.fileConfig = FileConfig.decode(reader, reader.uint32());
+          continue;
+        case 14:
+          if (tag !== 114) {
+            break;
+          }
+
+          message.webhookConfig = WebhookConfig.decode(reader, reader.uint32());
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1618,6 +2424,10 @@ export const Peer = {
       eventhubGroupConfig: isSet(object.eventhubGroupConfig)
         ? EventHubGroupConfig.fromJSON(object.eventhubGroupConfig)
         : undefined,
+      pineconeConfig: isSet(object.pineconeConfig) ? PineconeConfig.fromJSON(object.pineconeConfig) : undefined,
+      weaviateConfig: isSet(object.weaviateConfig) ? WeaviateConfig.fromJSON(object.weaviateConfig) : undefined,
+      fileConfig: isSet(object.fileConfig) ? FileConfig.fromJSON(object.fileConfig) : undefined,
+      webhookConfig: isSet(object.webhookConfig) ? WebhookConfig.fromJSON(object.webhookConfig) : undefined,
     };
   },
 
@@ -1653,6 +2463,18 @@ export const Peer = {
     if (message.eventhubGroupConfig !== undefined) {
       obj.eventhubGroupConfig = EventHubGroupConfig.toJSON(message.eventhubGroupConfig);
     }
+    if (message.pineconeConfig !== undefined) {
+      obj.pineconeConfig = PineconeConfig.toJSON(message.pineconeConfig);
+    }
+    if (message.weaviateConfig !== undefined) {
+      obj.weaviateConfig = WeaviateConfig.toJSON(message.weaviateConfig);
+    }
+    if (message.fileConfig !== undefined) {
+      obj.fileConfig = FileConfig.toJSON(message.fileConfig);
+    }
+    if (message.webhookConfig !== undefined) {
+      obj.webhookConfig = WebhookConfig.toJSON(message.webhookConfig);
+    }
     return obj;
   },
 
@@ -1687,6 +2509,18 @@ export const Peer = {
     message.eventhubGroupConfig = (object.eventhubGroupConfig !== undefined && object.eventhubGroupConfig !== null)
       ? EventHubGroupConfig.fromPartial(object.eventhubGroupConfig)
       : undefined;
+    message.pineconeConfig = (object.pineconeConfig !== undefined && object.pineconeConfig !== null)
+      ? PineconeConfig.fromPartial(object.pineconeConfig)
+      : undefined;
+    message.weaviateConfig = (object.weaviateConfig !== undefined && object.weaviateConfig !== null)
+      ? WeaviateConfig.fromPartial(object.weaviateConfig)
+      : undefined;
+    message.fileConfig = (object.fileConfig !== undefined && object.fileConfig !== null)
+      ? FileConfig.fromPartial(object.fileConfig)
+      : undefined;
+    message.webhookConfig = (object.webhookConfig !== undefined && object.webhookConfig !== null)
+      ? WebhookConfig.fromPartial(object.webhookConfig)
+      : undefined;
     return message;
   },
 };
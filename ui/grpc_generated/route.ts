@@ -4,7 +4,9 @@ import {
   ChannelCredentials,
   Client,
   ClientOptions,
+  ClientReadableStream,
   ClientUnaryCall,
+  handleServerStreamingCall,
   handleUnaryCall,
   makeGenericClientConstructor,
   Metadata,
@@ -13,7 +15,14 @@ import {
 } from "@grpc/grpc-js";
 import Long from "long";
 import _m0 from "protobufjs/minimal";
-import { FlowConnectionConfigs, QRepConfig } from "./flow";
+import {
+  FlowConnectionConfigs,
+  QRepConfig,
+  TableMapping,
+  TablePausePolicy,
+  tablePausePolicyFromJSON,
+  tablePausePolicyToJSON,
+} from "./flow";
 import { Timestamp } from "./google/protobuf/timestamp";
 import { Peer } from "./peers";
 
@@ -100,6 +109,11 @@ export function createPeerStatusToJSON(object: CreatePeerStatus): string {
 export interface CreateCDCFlowRequest {
   connectionConfigs: FlowConnectionConfigs | undefined;
   createCatalogEntry: boolean;
+  /**
+   * a retry with the key of an earlier request gets the response of that
+   * request instead of creating the flow again.
+   */
+  idempotencyKey: string;
 }
 
 export interface CreateCDCFlowResponse {
@@ -109,6 +123,8 @@ export interface CreateCDCFlowResponse {
 export interface CreateQRepFlowRequest {
   qrepConfig: QRepConfig | undefined;
   createCatalogEntry: boolean;
+  /** see CreateCDCFlowRequest. */
+  idempotencyKey: string;
 }
 
 export interface CreateQRepFlowResponse {
@@ -127,17 +143,112 @@ export interface ShutdownResponse {
   errorMessage: string;
 }
 
+export interface PauseFlowRequest {
+  workflowId: string;
+  flowJobName: string;
+}
+
+export interface PauseFlowResponse {
+  ok: boolean;
+  errorMessage: string;
+}
+
+export interface ResumeFlowRequest {
+  workflowId: string;
+  flowJobName: string;
+}
+
+export interface ResumeFlowResponse {
+  ok: boolean;
+  errorMessage: string;
+}
+
+export interface AddTablesToFlowRequest {
+  workflowId: string;
+  flowJobName: string;
+  tableMappings: TableMapping[];
+}
+
+export interface AddTablesToFlowResponse {
+  ok: boolean;
+  errorMessage: string;
+}
+
+export interface PauseTableRequest {
+  workflowId: string;
+  flowJobName: string;
+  /** source or destination table of one of the flow's table mappings. */
+  tableIdentifier: string;
+  /** false resumes the table. */
+  paused: boolean;
+  policy: TablePausePolicy;
+}
+
+export interface PauseTableResponse {
+  ok: boolean;
+  errorMessage: string;
+}
+
+export interface RemoveTableFromFlowRequest {
+  workflowId: string;
+  flowJobName: string;
+  /** source table of one of the flow's table mappings. */
+  sourceTableIdentifier: string;
+}
+
+export interface RemoveTableFromFlowResponse {
+  ok: boolean;
+  errorMessage: string;
+}
+
+export interface ResyncMirrorRequest {
+  workflowId: string;
+  flowJobName: string;
+  /**
+   * source table of one of the flow's table mappings, every table of the flow
+   * is resynced when empty.
+   */
+  sourceTableIdentifier: string;
+  /**
+   * truncate the destination tables and copy into them in place, instead of
+   * copying into new tables that replace them.
+   */
+  truncateDestinationTables: boolean;
+}
+
+export interface ResyncMirrorResponse {
+  ok: boolean;
+  errorMessage: string;
+}
+
 export interface ValidatePeerRequest {
   peer: Peer | undefined;
 }
 
 export interface CreatePeerRequest {
-  peer: Peer | undefined;
+  peer:
+    | Peer
+    | undefined;
+  /** see CreateCDCFlowRequest. */
+  idempotencyKey: string;
+}
+
+/** a check made on a peer while validating it. */
+export interface PeerValidationCheck {
+  name: string;
+  passed: boolean;
+  message: string;
+  /**
+   * whether a failed check makes the peer invalid, checks of what only
+   * sources of cdc mirrors need, like the wal_level of postgres, don't.
+   */
+  required: boolean;
 }
 
 export interface ValidatePeerResponse {
   status: ValidatePeerStatus;
   message: string;
+  checks: PeerValidationCheck[];
 }
 
 export interface CreatePeerResponse {
@@ -145,6 +256,28 @@ export interface CreatePeerResponse {
   message: string;
 }
 
+/**
+ * a new config of an existing peer, usually with rotated credentials. the
+ * running cdc mirrors of the peer reconnect with it at their next batch.
+ */
+export interface UpdatePeerRequest {
+  peer:
+    | Peer
+    | undefined;
+  /**
+   * the caller already saved the config in the catalog, e.g. nexus, which
+   * encrypts it, so only the mirrors of the peer are updated.
+   */
+  catalogUpdated: boolean;
+}
+
+export interface UpdatePeerResponse {
+  ok: boolean;
+  errorMessage: string;
+  /** the cdc mirrors told to reconnect with the new config. */
+  flowJobNames: string[];
+}
+
 export interface MirrorStatusRequest {
   flowJobName: string;
 }
@@ -172,7 +305,23 @@ export interface CDCSyncStatus {
   endLsn: number;
   numRows: number;
   startTime: Date | undefined;
-  endTime: Date | undefined;
+  endTime:
+    | Date
+    | undefined;
+  /**
+   * seconds between the source writing end_lsn and the batch finishing, unset
+   * if no sample of the source's LSN is old enough to cover end_lsn.
+   */
+  lagSeconds?:
+    | number
+    | undefined;
+  /**
+   * percentiles of the seconds between the changes of the batch being
+   * committed at the source and being applied to the destination, unset if
+   * the mirror doesn't track its latency.
+   */
+  latencyP50Seconds?: number | undefined;
+  latencyP99Seconds?: number | undefined;
 }
 
 export interface SnapshotStatus {
@@ -183,6 +332,22 @@ export interface CDCMirrorStatus {
   config: FlowConnectionConfigs | undefined;
   snapshotStatus: SnapshotStatus | undefined;
   cdcSyncs: CDCSyncStatus[];
+  /**
+   * batches synced outside the apply window of the mirror and not normalized
+   * yet, and the records in them.
+   */
+  applyBacklogBatches: number;
+  applyBacklogRecords: number;
+  /** the latency of the most recent batch that measured it. */
+  latencyP50Seconds?: number | undefined;
+  latencyP99Seconds?:
+    | number
+    | undefined;
+  /**
+   * whether a snapshot-only mirror is done with its initial copy and has been
+   * cleaned up.
+   */
+  completed: boolean;
 }
 
 export interface MirrorStatusResponse {
@@ -192,8 +357,31 @@ export interface MirrorStatusResponse {
   errorMessage: string;
 }
 
+export interface RotateEncryptionKeyRequest {
+  /**
+   * the master key the configs in the catalog are encrypted with, and the one
+   * they are rotated to, 32 bytes in base64 each.
+   */
+  oldKey: string;
+  newKey: string;
+}
+
+/**
+ * the progress of a key rotation, sent as the configs of each table are rotated,
+ * and once more with the counts when every config is verified.
+ */
+export interface RotateEncryptionKeyProgress {
+  table: string;
+  done: number;
+  total: number;
+  rotated: number;
+  encrypted: number;
+  verified: number;
+  completed: boolean;
+}
+
 function createBaseCreateCDCFlowRequest(): CreateCDCFlowRequest {
-  return { connectionConfigs: undefined, createCatalogEntry: false };
+  return { connectionConfigs: undefined, createCatalogEntry: false, idempotencyKey: "" };
 }
 
 export const CreateCDCFlowRequest = {
@@ -204,6 +392,9 @@ export const CreateCDCFlowRequest = {
     if (message.createCatalogEntry === true) {
       writer.uint32(16).bool(message.createCatalogEntry);
     }
+    if (message.idempotencyKey !== "") {
+      writer.uint32(26).string(message.idempotencyKey);
+    }
     return writer;
   },
 
@@ -228,6 +419,13 @@ export const CreateCDCFlowRequest = {
 
           message.createCatalogEntry = reader.bool();
           continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.idempotencyKey = reader.string();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -243,6 +441,7 @@ export const CreateCDCFlowRequest = {
         ? FlowConnectionConfigs.fromJSON(object.connectionConfigs)
         : undefined,
       createCatalogEntry: isSet(object.createCatalogEntry) ? Boolean(object.createCatalogEntry) : false,
+      idempotencyKey: isSet(object.idempotencyKey) ? String(object.idempotencyKey) : "",
     };
   },
 
@@ -254,6 +453,9 @@ export const CreateCDCFlowRequest = {
     if (message.createCatalogEntry === true) {
       obj.createCatalogEntry = message.createCatalogEntry;
     }
+    if (message.idempotencyKey !== "") {
+      obj.idempotencyKey = message.idempotencyKey;
+    }
     return obj;
   },
 
@@ -266,6 +468,7 @@ export const CreateCDCFlowRequest = {
       ? FlowConnectionConfigs.fromPartial(object.connectionConfigs)
       : undefined;
     message.createCatalogEntry = object.createCatalogEntry ?? false;
+    message.idempotencyKey = object.idempotencyKey ?? "";
     return message;
   },
 };
@@ -328,7 +531,7 @@ export const CreateCDCFlowResponse = {
 };
 
 function createBaseCreateQRepFlowRequest(): CreateQRepFlowRequest {
-  return { qrepConfig: undefined, createCatalogEntry: false };
+  return { qrepConfig: undefined, createCatalogEntry: false, idempotencyKey: "" };
 }
 
 export const CreateQRepFlowRequest = {
@@ -339,6 +542,9 @@ export const CreateQRepFlowRequest = {
     if (message.createCatalogEntry === true) {
       writer.uint32(16).bool(message.createCatalogEntry);
     }
+    if (message.idempotencyKey !== "") {
+      writer.uint32(26).string(message.idempotencyKey);
+    }
     return writer;
   },
 
@@ -363,6 +569,13 @@ export const CreateQRepFlowRequest = {
 
           message.createCatalogEntry = reader.bool();
           continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.idempotencyKey = reader.string();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -376,6 +589,7 @@ export const CreateQRepFlowRequest = {
     return {
       qrepConfig: isSet(object.qrepConfig) ? QRepConfig.fromJSON(object.qrepConfig) : undefined,
       createCatalogEntry: isSet(object.createCatalogEntry) ? Boolean(object.createCatalogEntry) : false,
+      idempotencyKey: isSet(object.idempotencyKey) ? String(object.idempotencyKey) : "",
     };
   },
 
@@ -387,6 +601,9 @@ export const CreateQRepFlowRequest = {
     if (message.createCatalogEntry === true) {
       obj.createCatalogEntry = message.createCatalogEntry;
     }
+    if (message.idempotencyKey !== "") {
+      obj.idempotencyKey = message.idempotencyKey;
+    }
     return obj;
   },
 
@@ -399,6 +616,7 @@ export const CreateQRepFlowRequest = {
       ? QRepConfig.fromPartial(object.qrepConfig)
       : undefined;
     message.createCatalogEntry = object.createCatalogEntry ?? false;
+    message.idempotencyKey = object.idempotencyKey ?? "";
     return message;
   },
 };
@@ -642,22 +860,25 @@ export const ShutdownResponse = {
   },
 };
 
-function createBaseValidatePeerRequest(): ValidatePeerRequest {
-  return { peer: undefined };
+function createBasePauseFlowRequest(): PauseFlowRequest {
+  return { workflowId: "", flowJobName: "" };
 }
 
-export const ValidatePeerRequest = {
-  encode(message: ValidatePeerRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
-    if (message.peer !== undefined) {
-      Peer.encode(message.peer, writer.uint32(10).fork()).ldelim();
+export const PauseFlowRequest = {
+  encode(message: PauseFlowRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.workflowId !== "") {
+      writer.uint32(10).string(message.workflowId);
+    }
+    if (message.flowJobName !== "") {
+      writer.uint32(18).string(message.flowJobName);
     }
     return writer;
   },
 
-  decode(input: _m0.Reader | Uint8Array, length?: number): ValidatePeerRequest {
+  decode(input: _m0.Reader | Uint8Array, length?: number): PauseFlowRequest {
     const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseValidatePeerRequest();
+    const message = createBasePauseFlowRequest();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -666,7 +887,14 @@ export const ValidatePeerRequest = {
             break;
           }
 
-          message.peer = Peer.decode(reader, reader.uint32());
+          message.workflowId = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.flowJobName = reader.string();
           continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
@@ -677,53 +905,70 @@ export const ValidatePeerRequest = {
     return message;
   },
 
-  fromJSON(object: any): ValidatePeerRequest {
-    return { peer: isSet(object.peer) ? Peer.fromJSON(object.peer) : undefined };
+  fromJSON(object: any): PauseFlowRequest {
+    return {
+      workflowId: isSet(object.workflowId) ? String(object.workflowId) : "",
+      flowJobName: isSet(object.flowJobName) ? String(object.flowJobName) : "",
+    };
   },
 
-  toJSON(message: ValidatePeerRequest): unknown {
+  toJSON(message: PauseFlowRequest): unknown {
     const obj: any = {};
-    if (message.peer !== undefined) {
-      obj.peer = Peer.toJSON(message.peer);
+    if (message.workflowId !== "") {
+      obj.workflowId = message.workflowId;
+    }
+    if (message.flowJobName !== "") {
+      obj.flowJobName = message.flowJobName;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<ValidatePeerRequest>, I>>(base?: I): ValidatePeerRequest {
-    return ValidatePeerRequest.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<PauseFlowRequest>, I>>(base?: I): PauseFlowRequest {
+    return PauseFlowRequest.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<ValidatePeerRequest>, I>>(object: I): ValidatePeerRequest {
-    const message = createBaseValidatePeerRequest();
-    message.peer = (object.peer !== undefined && object.peer !== null) ? Peer.fromPartial(object.peer) : undefined;
+  fromPartial<I extends Exact<DeepPartial<PauseFlowRequest>, I>>(object: I): PauseFlowRequest {
+    const message = createBasePauseFlowRequest();
+    message.workflowId = object.workflowId ?? "";
+    message.flowJobName = object.flowJobName ?? "";
     return message;
   },
 };
 
-function createBaseCreatePeerRequest(): CreatePeerRequest {
-  return { peer: undefined };
+function createBasePauseFlowResponse(): PauseFlowResponse {
+  return { ok: false, errorMessage: "" };
 }
 
-export const CreatePeerRequest = {
-  encode(message: CreatePeerRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
-    if (message.peer !== undefined) {
-      Peer.encode(message.peer, writer.uint32(10).fork()).ldelim();
+export const PauseFlowResponse = {
+  encode(message: PauseFlowResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.ok === true) {
+      writer.uint32(8).bool(message.ok);
+    }
+    if (message.errorMessage !== "") {
+      writer.uint32(18).string(message.errorMessage);
     }
     return writer;
   },
 
-  decode(input: _m0.Reader | Uint8Array, length?: number): CreatePeerRequest {
+  decode(input: _m0.Reader | Uint8Array, length?: number): PauseFlowResponse {
     const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCreatePeerRequest();
+    const message = createBasePauseFlowResponse();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
-          if (tag !== 10) {
+          if (tag !== 8) {
             break;
           }
 
-          message.peer = Peer.decode(reader, reader.uint32());
+          message.ok = reader.bool();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.errorMessage = reader.string();
           continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
@@ -734,63 +979,70 @@ export const CreatePeerRequest = {
     return message;
   },
 
-  fromJSON(object: any): CreatePeerRequest {
-    return { peer: isSet(object.peer) ? Peer.fromJSON(object.peer) : undefined };
+  fromJSON(object: any): PauseFlowResponse {
+    return {
+      ok: isSet(object.ok) ? Boolean(object.ok) : false,
+      errorMessage: isSet(object.errorMessage) ? String(object.errorMessage) : "",
+    };
   },
 
-  toJSON(message: CreatePeerRequest): unknown {
+  toJSON(message: PauseFlowResponse): unknown {
     const obj: any = {};
-    if (message.peer !== undefined) {
-      obj.peer = Peer.toJSON(message.peer);
+    if (message.ok === true) {
+      obj.ok = message.ok;
+    }
+    if (message.errorMessage !== "") {
+      obj.errorMessage = message.errorMessage;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<CreatePeerRequest>, I>>(base?: I): CreatePeerRequest {
-    return CreatePeerRequest.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<PauseFlowResponse>, I>>(base?: I): PauseFlowResponse {
+    return PauseFlowResponse.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<CreatePeerRequest>, I>>(object: I): CreatePeerRequest {
-    const message = createBaseCreatePeerRequest();
-    message.peer = (object.peer !== undefined && object.peer !== null) ? Peer.fromPartial(object.peer) : undefined;
+  fromPartial<I extends Exact<DeepPartial<PauseFlowResponse>, I>>(object: I): PauseFlowResponse {
+    const message = createBasePauseFlowResponse();
+    message.ok = object.ok ?? false;
+    message.errorMessage = object.errorMessage ?? "";
     return message;
   },
 };
 
-function createBaseValidatePeerResponse(): ValidatePeerResponse {
-  return { status: 0, message: "" };
+function createBaseResumeFlowRequest(): ResumeFlowRequest {
+  return { workflowId: "", flowJobName: "" };
 }
 
-export const ValidatePeerResponse = {
-  encode(message: ValidatePeerResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
-    if (message.status !== 0) {
-      writer.uint32(8).int32(message.status);
+export const ResumeFlowRequest = {
+  encode(message: ResumeFlowRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.workflowId !== "") {
+      writer.uint32(10).string(message.workflowId);
     }
-    if (message.message !== "") {
-      writer.uint32(18).string(message.message);
+    if (message.flowJobName !== "") {
+      writer.uint32(18).string(message.flowJobName);
     }
     return writer;
   },
 
-  decode(input: _m0.Reader | Uint8Array, length?: number): ValidatePeerResponse {
+  decode(input: _m0.Reader | Uint8Array, length?: number): ResumeFlowRequest {
     const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseValidatePeerResponse();
+    const message = createBaseResumeFlowRequest();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
-          if (tag !== 8) {
+          if (tag !== 10) {
             break;
           }
 
-          message.status = reader.int32() as any;
+          message.workflowId = reader.string();
           continue;
         case 2:
           if (tag !== 18) {
             break;
           }
 
-          message.message = reader.string();
+          message.flowJobName = reader.string();
           continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
@@ -801,54 +1053,54 @@ export const ValidatePeerResponse = {
     return message;
   },
 
-  fromJSON(object: any): ValidatePeerResponse {
+  fromJSON(object: any): ResumeFlowRequest {
     return {
-      status: isSet(object.status) ? validatePeerStatusFromJSON(object.status) : 0,
-      message: isSet(object.message) ? String(object.message) : "",
+      workflowId: isSet(object.workflowId) ? String(object.workflowId) : "",
+      flowJobName: isSet(object.flowJobName) ? String(object.flowJobName) : "",
     };
   },
 
-  toJSON(message: ValidatePeerResponse): unknown {
+  toJSON(message: ResumeFlowRequest): unknown {
     const obj: any = {};
-    if (message.status !== 0) {
-      obj.status = validatePeerStatusToJSON(message.status);
+    if (message.workflowId !== "") {
+      obj.workflowId = message.workflowId;
     }
-    if (message.message !== "") {
-      obj.message = message.message;
+    if (message.flowJobName !== "") {
+      obj.flowJobName = message.flowJobName;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<ValidatePeerResponse>, I>>(base?: I): ValidatePeerResponse {
-    return ValidatePeerResponse.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<ResumeFlowRequest>, I>>(base?: I): ResumeFlowRequest {
+    return ResumeFlowRequest.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<ValidatePeerResponse>, I>>(object: I): ValidatePeerResponse {
-    const message = createBaseValidatePeerResponse();
-    message.status = object.status ?? 0;
-    message.message = object.message ?? "";
+  fromPartial<I extends Exact<DeepPartial<ResumeFlowRequest>, I>>(object: I): ResumeFlowRequest {
+    const message = createBaseResumeFlowRequest();
+    message.workflowId = object.workflowId ?? "";
+    message.flowJobName = object.flowJobName ?? "";
     return message;
   },
 };
 
-function createBaseCreatePeerResponse(): CreatePeerResponse {
-  return { status: 0, message: "" };
+function createBaseResumeFlowResponse(): ResumeFlowResponse {
+  return { ok: false, errorMessage: "" };
 }
 
-export const CreatePeerResponse = {
-  encode(message: CreatePeerResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
-    if (message.status !== 0) {
-      writer.uint32(8).int32(message.status);
+export const ResumeFlowResponse = {
+  encode(message: ResumeFlowResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.ok === true) {
+      writer.uint32(8).bool(message.ok);
     }
-    if (message.message !== "") {
-      writer.uint32(18).string(message.message);
+    if (message.errorMessage !== "") {
+      writer.uint32(18).string(message.errorMessage);
     }
     return writer;
   },
 
-  decode(input: _m0.Reader | Uint8Array, length?: number): CreatePeerResponse {
+  decode(input: _m0.Reader | Uint8Array, length?: number): ResumeFlowResponse {
     const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCreatePeerResponse();
+    const message = createBaseResumeFlowResponse();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -857,14 +1109,14 @@ export const CreatePeerResponse = {
             break;
           }
 
-          message.status = reader.int32() as any;
+          message.ok = reader.bool();
           continue;
         case 2:
           if (tag !== 18) {
             break;
           }
 
-          message.message = reader.string();
+          message.errorMessage = reader.string();
           continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
@@ -875,7 +1127,1106 @@ export const CreatePeerResponse = {
     return message;
   },
 
-  fromJSON(object: any): CreatePeerResponse {
+  fromJSON(object: any): ResumeFlowResponse {
+    return {
+      ok: isSet(object.ok) ? Boolean(object.ok) : false,
+      errorMessage: isSet(object.errorMessage) ? String(object.errorMessage) : "",
+    };
+  },
+
+  toJSON(message: ResumeFlowResponse): unknown {
+    const obj: any = {};
+    if (message.ok === true) {
+      obj.ok = message.ok;
+    }
+    if (message.errorMessage !== "") {
+      obj.errorMessage = message.errorMessage;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ResumeFlowResponse>, I>>(base?: I): ResumeFlowResponse {
+    return ResumeFlowResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ResumeFlowResponse>, I>>(object: I): ResumeFlowResponse {
+    const message = createBaseResumeFlowResponse();
+    message.ok = object.ok ?? false;
+    message.errorMessage = object.errorMessage ?? "";
+    return message;
+  },
+};
+
+function createBaseAddTablesToFlowRequest(): AddTablesToFlowRequest {
+  return { workflowId: "", flowJobName: "", tableMappings: [] };
+}
+
+export const AddTablesToFlowRequest = {
+  encode(message: AddTablesToFlowRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.workflowId !== "") {
+      writer.uint32(10).string(message.workflowId);
+    }
+    if (message.flowJobName !== "") {
+      writer.uint32(18).string(message.flowJobName);
+    }
+    for (const v of message.tableMappings) {
+      TableMapping.encode(v!, writer.uint32(26).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): AddTablesToFlowRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseAddTablesToFlowRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.workflowId = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.flowJobName = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.tableMappings.push(TableMapping.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): AddTablesToFlowRequest {
+    return {
+      workflowId: isSet(object.workflowId) ? String(object.workflowId) : "",
+      flowJobName: isSet(object.flowJobName) ? String(object.flowJobName) : "",
+      tableMappings: Array.isArray(object?.tableMappings)
+        ? object.tableMappings.map((e: any) => TableMapping.fromJSON(e))
+        : [],
+    };
+  },
+
+  toJSON(message: AddTablesToFlowRequest): unknown {
+    const obj: any = {};
+    if (message.workflowId !== "") {
+      obj.workflowId = message.workflowId;
+    }
+    if (message.flowJobName !== "") {
+      obj.flowJobName = message.flowJobName;
+    }
+    if (message.tableMappings?.length) {
+      obj.tableMappings = message.tableMappings.map((e) => TableMapping.toJSON(e));
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<AddTablesToFlowRequest>, I>>(base?: I): AddTablesToFlowRequest {
+    return AddTablesToFlowRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<AddTablesToFlowRequest>, I>>(object: I): AddTablesToFlowRequest {
+    const message = createBaseAddTablesToFlowRequest();
+    message.workflowId = object.workflowId ?? "";
+    message.flowJobName = object.flowJobName ?? "";
+    message.tableMappings = object.tableMappings?.map((e) => TableMapping.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBaseAddTablesToFlowResponse(): AddTablesToFlowResponse {
+  return { ok: false, errorMessage: "" };
+}
+
+export const AddTablesToFlowResponse = {
+  encode(message: AddTablesToFlowResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.ok === true) {
+      writer.uint32(8).bool(message.ok);
+    }
+    if (message.errorMessage !== "") {
+      writer.uint32(18).string(message.errorMessage);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): AddTablesToFlowResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseAddTablesToFlowResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.ok = reader.bool();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.errorMessage = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): AddTablesToFlowResponse {
+    return {
+      ok: isSet(object.ok) ? Boolean(object.ok) : false,
+      errorMessage: isSet(object.errorMessage) ? String(object.errorMessage) : "",
+    };
+  },
+
+  toJSON(message: AddTablesToFlowResponse): unknown {
+    const obj: any = {};
+    if (message.ok === true) {
+      obj.ok = message.ok;
+    }
+    if (message.errorMessage !== "") {
+      obj.errorMessage = message.errorMessage;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<AddTablesToFlowResponse>, I>>(base?: I): AddTablesToFlowResponse {
+    return AddTablesToFlowResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<AddTablesToFlowResponse>, I>>(object: I): AddTablesToFlowResponse {
+    const message = createBaseAddTablesToFlowResponse();
+    message.ok = object.ok ?? false;
+    message.errorMessage = object.errorMessage ?? "";
+    return message;
+  },
+};
+
+function createBasePauseTableRequest(): PauseTableRequest {
+  return { workflowId: "", flowJobName: "", tableIdentifier: "", paused: false, policy: 0 };
+}
+
+export const PauseTableRequest = {
+  encode(message: PauseTableRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.workflowId !== "") {
+      writer.uint32(10).string(message.workflowId);
+    }
+    if (message.flowJobName !== "") {
+      writer.uint32(18).string(message.flowJobName);
+    }
+    if (message.tableIdentifier !== "") {
+      writer.uint32(26).string(message.tableIdentifier);
+    }
+    if (message.paused === true) {
+      writer.uint32(32).bool(message.paused);
+    }
+    if (message.policy !== 0) {
+      writer.uint32(40).int32(message.policy);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): PauseTableRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePauseTableRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.workflowId = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.flowJobName = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.tableIdentifier = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.paused = reader.bool();
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.policy = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PauseTableRequest {
+    return {
+      workflowId: isSet(object.workflowId) ? String(object.workflowId) : "",
+      flowJobName: isSet(object.flowJobName) ? String(object.flowJobName) : "",
+      tableIdentifier: isSet(object.tableIdentifier) ? String(object.tableIdentifier) : "",
+      paused: isSet(object.paused) ? Boolean(object.paused) : false,
+      policy: isSet(object.policy) ? tablePausePolicyFromJSON(object.policy) : 0,
+    };
+  },
+
+  toJSON(message: PauseTableRequest): unknown {
+    const obj: any = {};
+    if (message.workflowId !== "") {
+      obj.workflowId = message.workflowId;
+    }
+    if (message.flowJobName !== "") {
+      obj.flowJobName = message.flowJobName;
+    }
+    if (message.tableIdentifier !== "") {
+      obj.tableIdentifier = message.tableIdentifier;
+    }
+    if (message.paused === true) {
+      obj.paused = message.paused;
+    }
+    if (message.policy !== 0) {
+      obj.policy = tablePausePolicyToJSON(message.policy);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<PauseTableRequest>, I>>(base?: I): PauseTableRequest {
+    return PauseTableRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<PauseTableRequest>, I>>(object: I): PauseTableRequest {
+    const message = createBasePauseTableRequest();
+    message.workflowId = object.workflowId ?? "";
+    message.flowJobName = object.flowJobName ?? "";
+    message.tableIdentifier = object.tableIdentifier ?? "";
+    message.paused = object.paused ?? false;
+    message.policy = object.policy ?? 0;
+    return message;
+  },
+};
+
+function createBasePauseTableResponse(): PauseTableResponse {
+  return { ok: false, errorMessage: "" };
+}
+
+export const PauseTableResponse = {
+  encode(message: PauseTableResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.ok === true) {
+      writer.uint32(8).bool(message.ok);
+    }
+    if (message.errorMessage !== "") {
+      writer.uint32(18).string(message.errorMessage);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): PauseTableResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePauseTableResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.ok = reader.bool();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.errorMessage = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PauseTableResponse {
+    return {
+      ok: isSet(object.ok) ? Boolean(object.ok) : false,
+      errorMessage: isSet(object.errorMessage) ? String(object.errorMessage) : "",
+    };
+  },
+
+  toJSON(message: PauseTableResponse): unknown {
+    const obj: any = {};
+    if (message.ok === true) {
+      obj.ok = message.ok;
+    }
+    if (message.errorMessage !== "") {
+      obj.errorMessage = message.errorMessage;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<PauseTableResponse>, I>>(base?: I): PauseTableResponse {
+    return PauseTableResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<PauseTableResponse>, I>>(object: I): PauseTableResponse {
+    const message = createBasePauseTableResponse();
+    message.ok = object.ok ?? false;
+    message.errorMessage = object.errorMessage ?? "";
+    return message;
+  },
+};
+
+function createBaseRemoveTableFromFlowRequest(): RemoveTableFromFlowRequest {
+  return { workflowId: "", flowJobName: "", sourceTableIdentifier: "" };
+}
+
+export const RemoveTableFromFlowRequest = {
+  encode(message: RemoveTableFromFlowRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.workflowId !== "") {
+      writer.uint32(10).string(message.workflowId);
+    }
+    if (message.flowJobName !== "") {
+      writer.uint32(18).string(message.flowJobName);
+    }
+    if (message.sourceTableIdentifier !== "") {
+      writer.uint32(26).string(message.sourceTableIdentifier);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RemoveTableFromFlowRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRemoveTableFromFlowRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.workflowId = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.flowJobName = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.sourceTableIdentifier = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RemoveTableFromFlowRequest {
+    return {
+      workflowId: isSet(object.workflowId) ? String(object.workflowId) : "",
+      flowJobName: isSet(object.flowJobName) ? String(object.flowJobName) : "",
+      sourceTableIdentifier: isSet(object.sourceTableIdentifier) ? String(object.sourceTableIdentifier) : "",
+    };
+  },
+
+  toJSON(message: RemoveTableFromFlowRequest): unknown {
+    const obj: any = {};
+    if (message.workflowId !== "") {
+      obj.workflowId = message.workflowId;
+    }
+    if (message.flowJobName !== "") {
+      obj.flowJobName = message.flowJobName;
+    }
+    if (message.sourceTableIdentifier !== "") {
+      obj.sourceTableIdentifier = message.sourceTableIdentifier;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RemoveTableFromFlowRequest>, I>>(base?: I): RemoveTableFromFlowRequest {
+    return RemoveTableFromFlowRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RemoveTableFromFlowRequest>, I>>(object: I): RemoveTableFromFlowRequest {
+    const message = createBaseRemoveTableFromFlowRequest();
+    message.workflowId = object.workflowId ?? "";
+    message.flowJobName = object.flowJobName ?? "";
+    message.sourceTableIdentifier = object.sourceTableIdentifier ?? "";
+    return message;
+  },
+};
+
+function createBaseRemoveTableFromFlowResponse(): RemoveTableFromFlowResponse {
+  return { ok: false, errorMessage: "" };
+}
+
+export const RemoveTableFromFlowResponse = {
+  encode(message: RemoveTableFromFlowResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.ok === true) {
+      writer.uint32(8).bool(message.ok);
+    }
+    if (message.errorMessage !== "") {
+      writer.uint32(18).string(message.errorMessage);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RemoveTableFromFlowResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRemoveTableFromFlowResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.ok = reader.bool();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.errorMessage = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RemoveTableFromFlowResponse {
+    return {
+      ok: isSet(object.ok) ? Boolean(object.ok) : false,
+      errorMessage: isSet(object.errorMessage) ? String(object.errorMessage) : "",
+    };
+  },
+
+  toJSON(message: RemoveTableFromFlowResponse): unknown {
+    const obj: any = {};
+    if (message.ok === true) {
+      obj.ok = message.ok;
+    }
+    if (message.errorMessage !== "") {
+      obj.errorMessage = message.errorMessage;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RemoveTableFromFlowResponse>, I>>(base?: I): RemoveTableFromFlowResponse {
+    return RemoveTableFromFlowResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RemoveTableFromFlowResponse>, I>>(object: I): RemoveTableFromFlowResponse {
+    const message = createBaseRemoveTableFromFlowResponse();
+    message.ok = object.ok ?? false;
+    message.errorMessage = object.errorMessage ?? "";
+    return message;
+  },
+};
+
+function createBaseResyncMirrorRequest(): ResyncMirrorRequest {
+  return { workflowId: "", flowJobName: "", sourceTableIdentifier: "", truncateDestinationTables: false };
+}
+
+export const ResyncMirrorRequest = {
+  encode(message: ResyncMirrorRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.workflowId !== "") {
+      writer.uint32(10).string(message.workflowId);
+    }
+    if (message.flowJobName !== "") {
+      writer.uint32(18).string(message.flowJobName);
+    }
+    if (message.sourceTableIdentifier !== "") {
+      writer.uint32(26).string(message.sourceTableIdentifier);
+    }
+    if (message.truncateDestinationTables === true) {
+      writer.uint32(32).bool(message.truncateDestinationTables);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ResyncMirrorRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseResyncMirrorRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.workflowId = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.flowJobName = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.sourceTableIdentifier = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.truncateDestinationTables = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ResyncMirrorRequest {
+    return {
+      workflowId: isSet(object.workflowId) ? String(object.workflowId) : "",
+      flowJobName: isSet(object.flowJobName) ? String(object.flowJobName) : "",
+      sourceTableIdentifier: isSet(object.sourceTableIdentifier) ? String(object.sourceTableIdentifier) : "",
+      truncateDestinationTables: isSet(object.truncateDestinationTables)
+        ? Boolean(object.truncateDestinationTables)
+        : false,
+    };
+  },
+
+  toJSON(message: ResyncMirrorRequest): unknown {
+    const obj: any = {};
+    if (message.workflowId !== "") {
+      obj.workflowId = message.workflowId;
+    }
+    if (message.flowJobName !== "") {
+      obj.flowJobName = message.flowJobName;
+    }
+    if (message.sourceTableIdentifier !== "") {
+      obj.sourceTableIdentifier = message.sourceTableIdentifier;
+    }
+    if (message.truncateDestinationTables === true) {
+      obj.truncateDestinationTables = message.truncateDestinationTables;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ResyncMirrorRequest>, I>>(base?: I): ResyncMirrorRequest {
+    return ResyncMirrorRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ResyncMirrorRequest>, I>>(object: I): ResyncMirrorRequest {
+    const message = createBaseResyncMirrorRequest();
+    message.workflowId = object.workflowId ?? "";
+    message.flowJobName = object.flowJobName ?? "";
+    message.sourceTableIdentifier = object.sourceTableIdentifier ?? "";
+    message.truncateDestinationTables = object.truncateDestinationTables ?? false;
+    return message;
+  },
+};
+
+function createBaseResyncMirrorResponse(): ResyncMirrorResponse {
+  return { ok: false, errorMessage: "" };
+}
+
+export const ResyncMirrorResponse = {
+  encode(message: ResyncMirrorResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.ok === true) {
+      writer.uint32(8).bool(message.ok);
+    }
+    if (message.errorMessage !== "") {
+      writer.uint32(18).string(message.errorMessage);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ResyncMirrorResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseResyncMirrorResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.ok = reader.bool();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.errorMessage = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ResyncMirrorResponse {
+    return {
+      ok: isSet(object.ok) ? Boolean(object.ok) : false,
+      errorMessage: isSet(object.errorMessage) ? String(object.errorMessage) : "",
+    };
+  },
+
+  toJSON(message: ResyncMirrorResponse): unknown {
+    const obj: any = {};
+    if (message.ok === true) {
+      obj.ok = message.ok;
+    }
+    if (message.errorMessage !== "") {
+      obj.errorMessage = message.errorMessage;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ResyncMirrorResponse>, I>>(base?: I): ResyncMirrorResponse {
+    return ResyncMirrorResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ResyncMirrorResponse>, I>>(object: I): ResyncMirrorResponse {
+    const message = createBaseResyncMirrorResponse();
+    message.ok = object.ok ?? false;
+    message.errorMessage = object.errorMessage ?? "";
+    return message;
+  },
+};
+
+function createBaseValidatePeerRequest(): ValidatePeerRequest {
+  return { peer: undefined };
+}
+
+export const ValidatePeerRequest = {
+  encode(message: ValidatePeerRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.peer !== undefined) {
+      Peer.encode(message.peer, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ValidatePeerRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseValidatePeerRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.peer = Peer.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ValidatePeerRequest {
+    return { peer: isSet(object.peer) ? Peer.fromJSON(object.peer) : undefined };
+  },
+
+  toJSON(message: ValidatePeerRequest): unknown {
+    const obj: any = {};
+    if (message.peer !== undefined) {
+      obj.peer = Peer.toJSON(message.peer);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ValidatePeerRequest>, I>>(base?: I): ValidatePeerRequest {
+    return ValidatePeerRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ValidatePeerRequest>, I>>(object: I): ValidatePeerRequest {
+    const message = createBaseValidatePeerRequest();
+    message.peer = (object.peer !== undefined && object.peer !== null) ? Peer.fromPartial(object.peer) : undefined;
+    return message;
+  },
+};
+
+function createBaseCreatePeerRequest(): CreatePeerRequest {
+  return { peer: undefined, idempotencyKey: "" };
+}
+
+export const CreatePeerRequest = {
+  encode(message: CreatePeerRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.peer !== undefined) {
+      Peer.encode(message.peer, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.idempotencyKey !== "") {
+      writer.uint32(18).string(message.idempotencyKey);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): CreatePeerRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCreatePeerRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.peer = Peer.decode(reader, reader.uint32());
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.idempotencyKey = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CreatePeerRequest {
+    return {
+      peer: isSet(object.peer) ? Peer.fromJSON(object.peer) : undefined,
+      idempotencyKey: isSet(object.idempotencyKey) ? String(object.idempotencyKey) : "",
+    };
+  },
+
+  toJSON(message: CreatePeerRequest): unknown {
+    const obj: any = {};
+    if (message.peer !== undefined) {
+      obj.peer = Peer.toJSON(message.peer);
+    }
+    if (message.idempotencyKey !== "") {
+      obj.idempotencyKey = message.idempotencyKey;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CreatePeerRequest>, I>>(base?: I): CreatePeerRequest {
+    return CreatePeerRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CreatePeerRequest>, I>>(object: I): CreatePeerRequest {
+    const message = createBaseCreatePeerRequest();
+    message.peer = (object.peer !== undefined && object.peer !== null) ? Peer.fromPartial(object.peer) : undefined;
+    message.idempotencyKey = object.idempotencyKey ?? "";
+    return message;
+  },
+};
+
+function createBasePeerValidationCheck(): PeerValidationCheck {
+  return { name: "", passed: false, message: "", required: false };
+}
+
+export const PeerValidationCheck = {
+  encode(message: PeerValidationCheck, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.name !== "") {
+      writer.uint32(10).string(message.name);
+    }
+    if (message.passed === true) {
+      writer.uint32(16).bool(message.passed);
+    }
+    if (message.message !== "") {
+      writer.uint32(26).string(message.message);
+    }
+    if (message.required === true) {
+      writer.uint32(32).bool(message.required);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): PeerValidationCheck {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePeerValidationCheck();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.passed = reader.bool();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.message = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.required = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PeerValidationCheck {
+    return {
+      name: isSet(object.name) ? String(object.name) : "",
+      passed: isSet(object.passed) ? Boolean(object.passed) : false,
+      message: isSet(object.message) ? String(object.message) : "",
+      required: isSet(object.required) ? Boolean(object.required) : false,
+    };
+  },
+
+  toJSON(message: PeerValidationCheck): unknown {
+    const obj: any = {};
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.passed === true) {
+      obj.passed = message.passed;
+    }
+    if (message.message !== "") {
+      obj.message = message.message;
+    }
+    if (message.required === true) {
+      obj.required = message.required;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<PeerValidationCheck>, I>>(base?: I): PeerValidationCheck {
+    return PeerValidationCheck.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<PeerValidationCheck>, I>>(object: I): PeerValidationCheck {
+    const message = createBasePeerValidationCheck();
+    message.name = object.name ?? "";
+    message.passed = object.passed ?? false;
+    message.message = object.message ?? "";
+    message.required = object.required ?? false;
+    return message;
+  },
+};
+
+function createBaseValidatePeerResponse(): ValidatePeerResponse {
+  return { status: 0, message: "", checks: [] };
+}
+
+export const ValidatePeerResponse = {
+  encode(message: ValidatePeerResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.status !== 0) {
+      writer.uint32(8).int32(message.status);
+    }
+    if (message.message !== "") {
+      writer.uint32(18).string(message.message);
+    }
+    for (const v of message.checks) {
+      PeerValidationCheck.encode(v!, writer.uint32(26).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ValidatePeerResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseValidatePeerResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.status = reader.int32() as any;
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.message = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.checks.push(PeerValidationCheck.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ValidatePeerResponse {
+    return {
+      status: isSet(object.status) ? validatePeerStatusFromJSON(object.status) : 0,
+      message: isSet(object.message) ? String(object.message) : "",
+      checks: Array.isArray(object?.checks) ? object.checks.map((e: any) => PeerValidationCheck.fromJSON(e)) : [],
+    };
+  },
+
+  toJSON(message: ValidatePeerResponse): unknown {
+    const obj: any = {};
+    if (message.status !== 0) {
+      obj.status = validatePeerStatusToJSON(message.status);
+    }
+    if (message.message !== "") {
+      obj.message = message.message;
+    }
+    if (message.checks?.length) {
+      obj.checks = message.checks.map((e) => PeerValidationCheck.toJSON(e));
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ValidatePeerResponse>, I>>(base?: I): ValidatePeerResponse {
+    return ValidatePeerResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ValidatePeerResponse>, I>>(object: I): ValidatePeerResponse {
+    const message = createBaseValidatePeerResponse();
+    message.status = object.status ?? 0;
+    message.message = object.message ?? "";
+    message.checks = object.checks?.map((e) => PeerValidationCheck.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBaseCreatePeerResponse(): CreatePeerResponse {
+  return { status: 0, message: "" };
+}
+
+export const CreatePeerResponse = {
+  encode(message: CreatePeerResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.status !== 0) {
+      writer.uint32(8).int32(message.status);
+    }
+    if (message.message !== "") {
+      writer.uint32(18).string(message.message);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): CreatePeerResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCreatePeerResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.status = reader.int32() as any;
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.message = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CreatePeerResponse {
     return {
       status: isSet(object.status) ? createPeerStatusFromJSON(object.status) : 0,
       message: isSet(object.message) ? String(object.message) : "",
@@ -904,6 +2255,169 @@ export const CreatePeerResponse = {
   },
 };
 
+function createBaseUpdatePeerRequest(): UpdatePeerRequest {
+  return { peer: undefined, catalogUpdated: false };
+}
+
+export const UpdatePeerRequest = {
+  encode(message: UpdatePeerRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.peer !== undefined) {
+      Peer.encode(message.peer, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.catalogUpdated === true) {
+      writer.uint32(16).bool(message.catalogUpdated);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpdatePeerRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpdatePeerRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.peer = Peer.decode(reader, reader.uint32());
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.catalogUpdated = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpdatePeerRequest {
+    return {
+      peer: isSet(object.peer) ? Peer.fromJSON(object.peer) : undefined,
+      catalogUpdated: isSet(object.catalogUpdated) ? Boolean(object.catalogUpdated) : false,
+    };
+  },
+
+  toJSON(message: UpdatePeerRequest): unknown {
+    const obj: any = {};
+    if (message.peer !== undefined) {
+      obj.peer = Peer.toJSON(message.peer);
+    }
+    if (message.catalogUpdated === true) {
+      obj.catalogUpdated = message.catalogUpdated;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UpdatePeerRequest>, I>>(base?: I): UpdatePeerRequest {
+    return UpdatePeerRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UpdatePeerRequest>, I>>(object: I): UpdatePeerRequest {
+    const message = createBaseUpdatePeerRequest();
+    message.peer = (object.peer !== undefined && object.peer !== null) ? Peer.fromPartial(object.peer) : undefined;
+    message.catalogUpdated = object.catalogUpdated ?? false;
+    return message;
+  },
+};
+
+function createBaseUpdatePeerResponse(): UpdatePeerResponse {
+  return { ok: false, errorMessage: "", flowJobNames: [] };
+}
+
+export const UpdatePeerResponse = {
+  encode(message: UpdatePeerResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.ok === true) {
+      writer.uint32(8).bool(message.ok);
+    }
+    if (message.errorMessage !== "") {
+      writer.uint32(18).string(message.errorMessage);
+    }
+    for (const v of message.flowJobNames) {
+      writer.uint32(26).string(v!);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpdatePeerResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpdatePeerResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.ok = reader.bool();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.errorMessage = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.flowJobNames.push(reader.string());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpdatePeerResponse {
+    return {
+      ok: isSet(object.ok) ? Boolean(object.ok) : false,
+      errorMessage: isSet(object.errorMessage) ? String(object.errorMessage) : "",
+      flowJobNames: Array.isArray(object?.flowJobNames) ? object.flowJobNames.map((e: any) => String(e)) : [],
+    };
+  },
+
+  toJSON(message: UpdatePeerResponse): unknown {
+    const obj: any = {};
+    if (message.ok === true) {
+      obj.ok = message.ok;
+    }
+    if (message.errorMessage !== "") {
+      obj.errorMessage = message.errorMessage;
+    }
+    if (message.flowJobNames?.length) {
+      obj.flowJobNames = message.flowJobNames;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UpdatePeerResponse>, I>>(base?: I): UpdatePeerResponse {
+    return UpdatePeerResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UpdatePeerResponse>, I>>(object: I): UpdatePeerResponse {
+    const message = createBaseUpdatePeerResponse();
+    message.ok = object.ok ?? false;
+    message.errorMessage = object.errorMessage ?? "";
+    message.flowJobNames = object.flowJobNames?.map((e) => e) || [];
+    return message;
+  },
+};
+
 function createBaseMirrorStatusRequest(): MirrorStatusRequest {
   return { flowJobName: "" };
 }
@@ -1144,7 +2658,16 @@ export const QRepMirrorStatus = {
 };
 
 function createBaseCDCSyncStatus(): CDCSyncStatus {
-  return { startLsn: 0, endLsn: 0, numRows: 0, startTime: undefined, endTime: undefined };
+  return {
+    startLsn: 0,
+    endLsn: 0,
+    numRows: 0,
+    startTime: undefined,
+    endTime: undefined,
+    lagSeconds: undefined,
+    latencyP50Seconds: undefined,
+    latencyP99Seconds: undefined,
+  };
 }
 
 export const CDCSyncStatus = {
@@ -1164,6 +2687,15 @@ export const CDCSyncStatus = {
     if (message.endTime !== undefined) {
       Timestamp.encode(toTimestamp(message.endTime), writer.uint32(42).fork()).ldelim();
     }
+    if (message.lagSeconds !== undefined) {
+      writer.uint32(49).double(message.lagSeconds);
+    }
+    if (message.latencyP50Seconds !== undefined) {
+      writer.uint32(57).double(message.latencyP50Seconds);
+    }
+    if (message.latencyP99Seconds !== undefined) {
+      writer.uint32(65).double(message.latencyP99Seconds);
+    }
     return writer;
   },
 
@@ -1207,7 +2739,28 @@ export const CDCSyncStatus = {
             break;
           }
 
-          message.endTime = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          message.endTime = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 6:
+          if (tag !== 49) {
+            break;
+          }
+
+          message.lagSeconds = reader.double();
+          continue;
+        case 7:
+          if (tag !== 57) {
+            break;
+          }
+
+          message.latencyP50Seconds = reader.double();
+          continue;
+        case 8:
+          if (tag !== 65) {
+            break;
+          }
+
+          message.latencyP99Seconds = reader.double();
           continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
@@ -1225,6 +2778,9 @@ export const CDCSyncStatus = {
       numRows: isSet(object.numRows) ? Number(object.numRows) : 0,
       startTime: isSet(object.startTime) ? fromJsonTimestamp(object.startTime) : undefined,
       endTime: isSet(object.endTime) ? fromJsonTimestamp(object.endTime) : undefined,
+      lagSeconds: isSet(object.lagSeconds) ? Number(object.lagSeconds) : undefined,
+      latencyP50Seconds: isSet(object.latencyP50Seconds) ? Number(object.latencyP50Seconds) : undefined,
+      latencyP99Seconds: isSet(object.latencyP99Seconds) ? Number(object.latencyP99Seconds) : undefined,
     };
   },
 
@@ -1245,6 +2801,15 @@ export const CDCSyncStatus = {
     if (message.endTime !== undefined) {
       obj.endTime = message.endTime.toISOString();
     }
+    if (message.lagSeconds !== undefined) {
+      obj.lagSeconds = message.lagSeconds;
+    }
+    if (message.latencyP50Seconds !== undefined) {
+      obj.latencyP50Seconds = message.latencyP50Seconds;
+    }
+    if (message.latencyP99Seconds !== undefined) {
+      obj.latencyP99Seconds = message.latencyP99Seconds;
+    }
     return obj;
   },
 
@@ -1258,6 +2823,9 @@ export const CDCSyncStatus = {
     message.numRows = object.numRows ?? 0;
     message.startTime = object.startTime ?? undefined;
     message.endTime = object.endTime ?? undefined;
+    message.lagSeconds = object.lagSeconds ?? undefined;
+    message.latencyP50Seconds = object.latencyP50Seconds ?? undefined;
+    message.latencyP99Seconds = object.latencyP99Seconds ?? undefined;
     return message;
   },
 };
@@ -1320,7 +2888,16 @@ export const SnapshotStatus = {
 };
 
 function createBaseCDCMirrorStatus(): CDCMirrorStatus {
-  return { config: undefined, snapshotStatus: undefined, cdcSyncs: [] };
+  return {
+    config: undefined,
+    snapshotStatus: undefined,
+    cdcSyncs: [],
+    applyBacklogBatches: 0,
+    applyBacklogRecords: 0,
+    latencyP50Seconds: undefined,
+    latencyP99Seconds: undefined,
+    completed: false,
+  };
 }
 
 export const CDCMirrorStatus = {
@@ -1334,6 +2911,21 @@ export const CDCMirrorStatus = {
     for (const v of message.cdcSyncs) {
       CDCSyncStatus.encode(v!, writer.uint32(26).fork()).ldelim();
     }
+    if (message.applyBacklogBatches !== 0) {
+      writer.uint32(32).int64(message.applyBacklogBatches);
+    }
+    if (message.applyBacklogRecords !== 0) {
+      writer.uint32(40).int64(message.applyBacklogRecords);
+    }
+    if (message.latencyP50Seconds !== undefined) {
+      writer.uint32(49).double(message.latencyP50Seconds);
+    }
+    if (message.latencyP99Seconds !== undefined) {
+      writer.uint32(57).double(message.latencyP99Seconds);
+    }
+    if (message.completed === true) {
+      writer.uint32(64).bool(message.completed);
+    }
     return writer;
   },
 
@@ -1365,6 +2957,41 @@ export const CDCMirrorStatus = {
 
           message.cdcSyncs.push(CDCSyncStatus.decode(reader, reader.uint32()));
           continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.applyBacklogBatches = longToNumber(reader.int64() as Long);
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.applyBacklogRecords = longToNumber(reader.int64() as Long);
+          continue;
+        case 6:
+          if (tag !== 49) {
+            break;
+          }
+
+          message.latencyP50Seconds = reader.double();
+          continue;
+        case 7:
+          if (tag !== 57) {
+            break;
+          }
+
+          message.latencyP99Seconds = reader.double();
+          continue;
+        case 8:
+          if (tag !== 64) {
+            break;
+          }
+
+          message.completed = reader.bool();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1379,6 +3006,11 @@ export const CDCMirrorStatus = {
       config: isSet(object.config) ? FlowConnectionConfigs.fromJSON(object.config) : undefined,
       snapshotStatus: isSet(object.snapshotStatus) ? SnapshotStatus.fromJSON(object.snapshotStatus) : undefined,
       cdcSyncs: Array.isArray(object?.cdcSyncs) ? object.cdcSyncs.map((e: any) => CDCSyncStatus.fromJSON(e)) : [],
+      applyBacklogBatches: isSet(object.applyBacklogBatches) ? Number(object.applyBacklogBatches) : 0,
+      applyBacklogRecords: isSet(object.applyBacklogRecords) ? Number(object.applyBacklogRecords) : 0,
+      latencyP50Seconds: isSet(object.latencyP50Seconds) ? Number(object.latencyP50Seconds) : undefined,
+      latencyP99Seconds: isSet(object.latencyP99Seconds) ? Number(object.latencyP99Seconds) : undefined,
+      completed: isSet(object.completed) ? Boolean(object.completed) : false,
     };
   },
 
@@ -1393,6 +3025,21 @@ export const CDCMirrorStatus = {
     if (message.cdcSyncs?.length) {
       obj.cdcSyncs = message.cdcSyncs.map((e) => CDCSyncStatus.toJSON(e));
     }
+    if (message.applyBacklogBatches !== 0) {
+      obj.applyBacklogBatches = Math.round(message.applyBacklogBatches);
+    }
+    if (message.applyBacklogRecords !== 0) {
+      obj.applyBacklogRecords = Math.round(message.applyBacklogRecords);
+    }
+    if (message.latencyP50Seconds !== undefined) {
+      obj.latencyP50Seconds = message.latencyP50Seconds;
+    }
+    if (message.latencyP99Seconds !== undefined) {
+      obj.latencyP99Seconds = message.latencyP99Seconds;
+    }
+    if (message.completed === true) {
+      obj.completed = message.completed;
+    }
     return obj;
   },
 
@@ -1408,6 +3055,11 @@ export const CDCMirrorStatus = {
       ? SnapshotStatus.fromPartial(object.snapshotStatus)
       : undefined;
     message.cdcSyncs = object.cdcSyncs?.map((e) => CDCSyncStatus.fromPartial(e)) || [];
+    message.applyBacklogBatches = object.applyBacklogBatches ?? 0;
+    message.applyBacklogRecords = object.applyBacklogRecords ?? 0;
+    message.latencyP50Seconds = object.latencyP50Seconds ?? undefined;
+    message.latencyP99Seconds = object.latencyP99Seconds ?? undefined;
+    message.completed = object.completed ?? false;
     return message;
   },
 };
@@ -1520,6 +3172,229 @@ export const MirrorStatusResponse = {
   },
 };
 
+function createBaseRotateEncryptionKeyRequest(): RotateEncryptionKeyRequest {
+  return { oldKey: "", newKey: "" };
+}
+
+export const RotateEncryptionKeyRequest = {
+  encode(message: RotateEncryptionKeyRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.oldKey !== "") {
+      writer.uint32(10).string(message.oldKey);
+    }
+    if (message.newKey !== "") {
+      writer.uint32(18).string(message.newKey);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RotateEncryptionKeyRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRotateEncryptionKeyRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.oldKey = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.newKey = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RotateEncryptionKeyRequest {
+    return {
+      oldKey: isSet(object.oldKey) ? String(object.oldKey) : "",
+      newKey: isSet(object.newKey) ? String(object.newKey) : "",
+    };
+  },
+
+  toJSON(message: RotateEncryptionKeyRequest): unknown {
+    const obj: any = {};
+    if (message.oldKey !== "") {
+      obj.oldKey = message.oldKey;
+    }
+    if (message.newKey !== "") {
+      obj.newKey = message.newKey;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RotateEncryptionKeyRequest>, I>>(base?: I): RotateEncryptionKeyRequest {
+    return RotateEncryptionKeyRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RotateEncryptionKeyRequest>, I>>(object: I): RotateEncryptionKeyRequest {
+    const message = createBaseRotateEncryptionKeyRequest();
+    message.oldKey = object.oldKey ?? "";
+    message.newKey = object.newKey ?? "";
+    return message;
+  },
+};
+
+function createBaseRotateEncryptionKeyProgress(): RotateEncryptionKeyProgress {
+  return { table: "", done: 0, total: 0, rotated: 0, encrypted: 0, verified: 0, completed: false };
+}
+
+export const RotateEncryptionKeyProgress = {
+  encode(message: RotateEncryptionKeyProgress, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.table !== "") {
+      writer.uint32(10).string(message.table);
+    }
+    if (message.done !== 0) {
+      writer.uint32(16).int64(message.done);
+    }
+    if (message.total !== 0) {
+      writer.uint32(24).int64(message.total);
+    }
+    if (message.rotated !== 0) {
+      writer.uint32(32).int64(message.rotated);
+    }
+    if (message.encrypted !== 0) {
+      writer.uint32(40).int64(message.encrypted);
+    }
+    if (message.verified !== 0) {
+      writer.uint32(48).int64(message.verified);
+    }
+    if (message.completed === true) {
+      writer.uint32(56).bool(message.completed);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RotateEncryptionKeyProgress {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRotateEncryptionKeyProgress();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.table = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.done = longToNumber(reader.int64() as Long);
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.total = longToNumber(reader.int64() as Long);
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.rotated = longToNumber(reader.int64() as Long);
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.encrypted = longToNumber(reader.int64() as Long);
+          continue;
+        case 6:
+          if (tag !== 48) {
+            break;
+          }
+
+          message.verified = longToNumber(reader.int64() as Long);
+          continue;
+        case 7:
+          if (tag !== 56) {
+            break;
+          }
+
+          message.completed = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RotateEncryptionKeyProgress {
+    return {
+      table: isSet(object.table) ? String(object.table) : "",
+      done: isSet(object.done) ? Number(object.done) : 0,
+      total: isSet(object.total) ? Number(object.total) : 0,
+      rotated: isSet(object.rotated) ? Number(object.rotated) : 0,
+      encrypted: isSet(object.encrypted) ? Number(object.encrypted) : 0,
+      verified: isSet(object.verified) ? Number(object.verified) : 0,
+      completed: isSet(object.completed) ? Boolean(object.completed) : false,
+    };
+  },
+
+  toJSON(message: RotateEncryptionKeyProgress): unknown {
+    const obj: any = {};
+    if (message.table !== "") {
+      obj.table = message.table;
+    }
+    if (message.done !== 0) {
+      obj.done = Math.round(message.done);
+    }
+    if (message.total !== 0) {
+      obj.total = Math.round(message.total);
+    }
+    if (message.rotated !== 0) {
+      obj.rotated = Math.round(message.rotated);
+    }
+    if (message.encrypted !== 0) {
+      obj.encrypted = Math.round(message.encrypted);
+    }
+    if (message.verified !== 0) {
+      obj.verified = Math.round(message.verified);
+    }
+    if (message.completed === true) {
+      obj.completed = message.completed;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RotateEncryptionKeyProgress>, I>>(base?: I): RotateEncryptionKeyProgress {
+    return RotateEncryptionKeyProgress.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RotateEncryptionKeyProgress>, I>>(object: I): RotateEncryptionKeyProgress {
+    const message = createBaseRotateEncryptionKeyProgress();
+    message.table = object.table ?? "";
+    message.done = object.done ?? 0;
+    message.total = object.total ?? 0;
+    message.rotated = object.rotated ?? 0;
+    message.encrypted = object.encrypted ?? 0;
+    message.verified = object.verified ?? 0;
+    message.completed = object.completed ?? false;
+    return message;
+  },
+};
+
 export type FlowServiceService = typeof FlowServiceService;
 export const FlowServiceService = {
   validatePeer: {
@@ -1540,6 +3415,19 @@ export const FlowServiceService = {
     responseSerialize: (value: CreatePeerResponse) => Buffer.from(CreatePeerResponse.encode(value).finish()),
     responseDeserialize: (value: Buffer) => CreatePeerResponse.decode(value),
   },
+  /**
+   * saves a new config of a peer, and has its running cdc mirrors reconnect with
+   * it at their next batch instead of being dropped and created again.
+   */
+  updatePeer: {
+    path: "/peerdb_route.FlowService/UpdatePeer",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: UpdatePeerRequest) => Buffer.from(UpdatePeerRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => UpdatePeerRequest.decode(value),
+    responseSerialize: (value: UpdatePeerResponse) => Buffer.from(UpdatePeerResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => UpdatePeerResponse.decode(value),
+  },
   createCdcFlow: {
     path: "/peerdb_route.FlowService/CreateCDCFlow",
     requestStream: false,
@@ -1567,6 +3455,69 @@ export const FlowServiceService = {
     responseSerialize: (value: ShutdownResponse) => Buffer.from(ShutdownResponse.encode(value).finish()),
     responseDeserialize: (value: Buffer) => ShutdownResponse.decode(value),
   },
+  pauseFlow: {
+    path: "/peerdb_route.FlowService/PauseFlow",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: PauseFlowRequest) => Buffer.from(PauseFlowRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => PauseFlowRequest.decode(value),
+    responseSerialize: (value: PauseFlowResponse) => Buffer.from(PauseFlowResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => PauseFlowResponse.decode(value),
+  },
+  resumeFlow: {
+    path: "/peerdb_route.FlowService/ResumeFlow",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ResumeFlowRequest) => Buffer.from(ResumeFlowRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ResumeFlowRequest.decode(value),
+    responseSerialize: (value: ResumeFlowResponse) => Buffer.from(ResumeFlowResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ResumeFlowResponse.decode(value),
+  },
+  /** snapshots the tables and adds them to a running cdc flow. */
+  addTablesToFlow: {
+    path: "/peerdb_route.FlowService/AddTablesToFlow",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: AddTablesToFlowRequest) => Buffer.from(AddTablesToFlowRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => AddTablesToFlowRequest.decode(value),
+    responseSerialize: (value: AddTablesToFlowResponse) => Buffer.from(AddTablesToFlowResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => AddTablesToFlowResponse.decode(value),
+  },
+  /** pauses or resumes replication of one table of a running cdc flow. */
+  pauseTable: {
+    path: "/peerdb_route.FlowService/PauseTable",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: PauseTableRequest) => Buffer.from(PauseTableRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => PauseTableRequest.decode(value),
+    responseSerialize: (value: PauseTableResponse) => Buffer.from(PauseTableResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => PauseTableResponse.decode(value),
+  },
+  /** stops replicating one table of a running cdc flow. */
+  removeTableFromFlow: {
+    path: "/peerdb_route.FlowService/RemoveTableFromFlow",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: RemoveTableFromFlowRequest) =>
+      Buffer.from(RemoveTableFromFlowRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => RemoveTableFromFlowRequest.decode(value),
+    responseSerialize: (value: RemoveTableFromFlowResponse) =>
+      Buffer.from(RemoveTableFromFlowResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => RemoveTableFromFlowResponse.decode(value),
+  },
+  /**
+   * copies tables of a running cdc flow again and replaces the destination
+   * tables with the copies.
+   */
+  resyncMirror: {
+    path: "/peerdb_route.FlowService/ResyncMirror",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ResyncMirrorRequest) => Buffer.from(ResyncMirrorRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ResyncMirrorRequest.decode(value),
+    responseSerialize: (value: ResyncMirrorResponse) => Buffer.from(ResyncMirrorResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ResyncMirrorResponse.decode(value),
+  },
   mirrorStatus: {
     path: "/peerdb_route.FlowService/MirrorStatus",
     requestStream: false,
@@ -1576,15 +3527,65 @@ export const FlowServiceService = {
     responseSerialize: (value: MirrorStatusResponse) => Buffer.from(MirrorStatusResponse.encode(value).finish()),
     responseDeserialize: (value: Buffer) => MirrorStatusResponse.decode(value),
   },
+  /** streams the status of the mirror whenever it changes. */
+  watchMirrorStatus: {
+    path: "/peerdb_route.FlowService/WatchMirrorStatus",
+    requestStream: false,
+    responseStream: true,
+    requestSerialize: (value: MirrorStatusRequest) => Buffer.from(MirrorStatusRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => MirrorStatusRequest.decode(value),
+    responseSerialize: (value: MirrorStatusResponse) => Buffer.from(MirrorStatusResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => MirrorStatusResponse.decode(value),
+  },
+  /**
+   * rotates the master key the configs in the catalog are encrypted with, the
+   * way rotate_encryption_key of nexus does, and streams its progress.
+   */
+  rotateEncryptionKey: {
+    path: "/peerdb_route.FlowService/RotateEncryptionKey",
+    requestStream: false,
+    responseStream: true,
+    requestSerialize: (value: RotateEncryptionKeyRequest) =>
+      Buffer.from(RotateEncryptionKeyRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => RotateEncryptionKeyRequest.decode(value),
+    responseSerialize: (value: RotateEncryptionKeyProgress) =>
+      Buffer.from(RotateEncryptionKeyProgress.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => RotateEncryptionKeyProgress.decode(value),
+  },
 } as const;
 
 export interface FlowServiceServer extends UntypedServiceImplementation {
   validatePeer: handleUnaryCall<ValidatePeerRequest, ValidatePeerResponse>;
   createPeer: handleUnaryCall<CreatePeerRequest, CreatePeerResponse>;
+  /**
+   * saves a new config of a peer, and has its running cdc mirrors reconnect with
+   * it at their next batch instead of being dropped and created again.
+   */
+  updatePeer: handleUnaryCall<UpdatePeerRequest, UpdatePeerResponse>;
   createCdcFlow: handleUnaryCall<CreateCDCFlowRequest, CreateCDCFlowResponse>;
   createQRepFlow: handleUnaryCall<CreateQRepFlowRequest, CreateQRepFlowResponse>;
   shutdownFlow: handleUnaryCall<ShutdownRequest, ShutdownResponse>;
+  pauseFlow: handleUnaryCall<PauseFlowRequest, PauseFlowResponse>;
+  resumeFlow: handleUnaryCall<ResumeFlowRequest, ResumeFlowResponse>;
+  /** snapshots the tables and adds them to a running cdc flow. */
+  addTablesToFlow: handleUnaryCall<AddTablesToFlowRequest, AddTablesToFlowResponse>;
+  /** pauses or resumes replication of one table of a running cdc flow. */
+  pauseTable: handleUnaryCall<PauseTableRequest, PauseTableResponse>;
+  /** stops replicating one table of a running cdc flow. */
+  removeTableFromFlow: handleUnaryCall<RemoveTableFromFlowRequest, RemoveTableFromFlowResponse>;
+  /**
+   * copies tables of a running cdc flow again and replaces the destination
+   * tables with the copies.
+   */
+  resyncMirror: handleUnaryCall<ResyncMirrorRequest, ResyncMirrorResponse>;
   mirrorStatus: handleUnaryCall<MirrorStatusRequest, MirrorStatusResponse>;
+  /** streams the status of the mirror whenever it changes. */
+  watchMirrorStatus: handleServerStreamingCall<MirrorStatusRequest, MirrorStatusResponse>;
+  /**
+   * rotates the master key the configs in the catalog are encrypted with, the
+   * way rotate_encryption_key of nexus does, and streams its progress.
+   */
+  rotateEncryptionKey: handleServerStreamingCall<RotateEncryptionKeyRequest, RotateEncryptionKeyProgress>;
 }
 
 export interface FlowServiceClient extends Client {
@@ -1618,6 +3619,25 @@ export interface FlowServiceClient extends Client {
     options: Partial<CallOptions>,
     callback: (error: ServiceError | null, response: CreatePeerResponse) => void,
   ): ClientUnaryCall;
+  /**
+   * saves a new config of a peer, and has its running cdc mirrors reconnect with
+   * it at their next batch instead of being dropped and created again.
+   */
+  updatePeer(
+    request: UpdatePeerRequest,
+    callback: (error: ServiceError | null, response: UpdatePeerResponse) => void,
+  ): ClientUnaryCall;
+  updatePeer(
+    request: UpdatePeerRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: UpdatePeerResponse) => void,
+  ): ClientUnaryCall;
+  updatePeer(
+    request: UpdatePeerRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: UpdatePeerResponse) => void,
+  ): ClientUnaryCall;
   createCdcFlow(
     request: CreateCDCFlowRequest,
     callback: (error: ServiceError | null, response: CreateCDCFlowResponse) => void,
@@ -1663,6 +3683,103 @@ export interface FlowServiceClient extends Client {
     options: Partial<CallOptions>,
     callback: (error: ServiceError | null, response: ShutdownResponse) => void,
   ): ClientUnaryCall;
+  pauseFlow(
+    request: PauseFlowRequest,
+    callback: (error: ServiceError | null, response: PauseFlowResponse) => void,
+  ): ClientUnaryCall;
+  pauseFlow(
+    request: PauseFlowRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: PauseFlowResponse) => void,
+  ): ClientUnaryCall;
+  pauseFlow(
+    request: PauseFlowRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: PauseFlowResponse) => void,
+  ): ClientUnaryCall;
+  resumeFlow(
+    request: ResumeFlowRequest,
+    callback: (error: ServiceError | null, response: ResumeFlowResponse) => void,
+  ): ClientUnaryCall;
+  resumeFlow(
+    request: ResumeFlowRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ResumeFlowResponse) => void,
+  ): ClientUnaryCall;
+  resumeFlow(
+    request: ResumeFlowRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ResumeFlowResponse) => void,
+  ): ClientUnaryCall;
+  /** snapshots the tables and adds them to a running cdc flow. */
+  addTablesToFlow(
+    request: AddTablesToFlowRequest,
+    callback: (error: ServiceError | null, response: AddTablesToFlowResponse) => void,
+  ): ClientUnaryCall;
+  addTablesToFlow(
+    request: AddTablesToFlowRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: AddTablesToFlowResponse) => void,
+  ): ClientUnaryCall;
+  addTablesToFlow(
+    request: AddTablesToFlowRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: AddTablesToFlowResponse) => void,
+  ): ClientUnaryCall;
+  /** pauses or resumes replication of one table of a running cdc flow. */
+  pauseTable(
+    request: PauseTableRequest,
+    callback: (error: ServiceError | null, response: PauseTableResponse) => void,
+  ): ClientUnaryCall;
+  pauseTable(
+    request: PauseTableRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: PauseTableResponse) => void,
+  ): ClientUnaryCall;
+  pauseTable(
+    request: PauseTableRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: PauseTableResponse) => void,
+  ): ClientUnaryCall;
+  /** stops replicating one table of a running cdc flow. */
+  removeTableFromFlow(
+    request: RemoveTableFromFlowRequest,
+    callback: (error: ServiceError | null, response: RemoveTableFromFlowResponse) => void,
+  ): ClientUnaryCall;
+  removeTableFromFlow(
+    request: RemoveTableFromFlowRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: RemoveTableFromFlowResponse) => void,
+  ): ClientUnaryCall;
+  removeTableFromFlow(
+    request: RemoveTableFromFlowRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: RemoveTableFromFlowResponse) => void,
+  ): ClientUnaryCall;
+  /**
+   * copies tables of a running cdc flow again and replaces the destination
+   * tables with the copies.
+   */
+  resyncMirror(
+    request: ResyncMirrorRequest,
+    callback: (error: ServiceError | null, response: ResyncMirrorResponse) => void,
+  ): ClientUnaryCall;
+  resyncMirror(
+    request: ResyncMirrorRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ResyncMirrorResponse) => void,
+  ): ClientUnaryCall;
+  resyncMirror(
+    request: ResyncMirrorRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ResyncMirrorResponse) => void,
+  ): ClientUnaryCall;
   mirrorStatus(
     request: MirrorStatusRequest,
     callback: (error: ServiceError | null, response: MirrorStatusResponse) => void,
@@ -1678,6 +3795,29 @@ export interface FlowServiceClient extends Client {
     options: Partial<CallOptions>,
     callback: (error: ServiceError | null, response: MirrorStatusResponse) => void,
   ): ClientUnaryCall;
+  /** streams the status of the mirror whenever it changes. */
+  watchMirrorStatus(
+    request: MirrorStatusRequest,
+    options?: Partial<CallOptions>,
+  ): ClientReadableStream<MirrorStatusResponse>;
+  watchMirrorStatus(
+    request: MirrorStatusRequest,
+    metadata?: Metadata,
+    options?: Partial<CallOptions>,
+  ): ClientReadableStream<MirrorStatusResponse>;
+  /**
+   * rotates the master key the configs in the catalog are encrypted with, the
+   * way rotate_encryption_key of nexus does, and streams its progress.
+   */
+  rotateEncryptionKey(
+    request: RotateEncryptionKeyRequest,
+    options?: Partial<CallOptions>,
+  ): ClientReadableStream<RotateEncryptionKeyProgress>;
+  rotateEncryptionKey(
+    request: RotateEncryptionKeyRequest,
+    metadata?: Metadata,
+    options?: Partial<CallOptions>,
+  ): ClientReadableStream<RotateEncryptionKeyProgress>;
 }
 
 export const FlowServiceClient = makeGenericClientConstructor(